@@ -32,10 +32,12 @@ use rooch_proposer::actor::messages::ProposeBlock;
 use rooch_proposer::actor::proposer::ProposerActor;
 use rooch_proposer::proxy::ProposerProxy;
 use rooch_rpc_api::api::rooch_api::RoochAPIServer;
+use rooch_rpc_api::api::DEFAULT_MAX_TX_BATCH_SIZE;
 use rooch_rpc_api::jsonrpc_types::StrView;
 use rooch_rpc_server::server::rooch_server::RoochServer;
 use rooch_rpc_server::service::aggregate_service::AggregateService;
 use rooch_rpc_server::service::rpc_service::RpcService;
+use rooch_rpc_server::service::subscription_service::SubscriptionService;
 use rooch_sequencer::actor::sequencer::SequencerActor;
 use rooch_sequencer::proxy::SequencerProxy;
 use rooch_store::RoochStore;
@@ -87,7 +89,12 @@ fn transaction_query_benchmark(c: &mut Criterion) {
     let rt: Runtime = Runtime::new().unwrap();
     let (rpc_service, aggregate_service) =
         rt.block_on(async { setup_service(&tempdir, &keystore).await.unwrap() });
-    let rooch_server = RoochServer::new(rpc_service.clone(), aggregate_service);
+    let rooch_server = RoochServer::new(
+        rpc_service.clone(),
+        aggregate_service,
+        DEFAULT_MAX_TX_BATCH_SIZE,
+        Network::default().to_num(),
+    );
 
     let default_account = keystore.addresses()[0];
     let mut test_transaction_builder = TestTransactionBuilder::new(default_account.into());
@@ -102,7 +109,11 @@ fn transaction_query_benchmark(c: &mut Criterion) {
     let mut tx_orders = (1..500).cycle().map(|v| v);
     c.bench_function("get_transactions_by_order", |b| {
         b.to_async(Runtime::new().unwrap()).iter(|| {
-            rooch_server.get_transactions_by_order(Some(StrView(tx_orders.next().unwrap())), None)
+            rooch_server.get_transactions_by_order(
+                Some(StrView(tx_orders.next().unwrap())),
+                None,
+                None,
+            )
         })
     });
 }
@@ -205,6 +216,7 @@ async fn setup_service(
         sequencer_proxy,
         proposer_proxy,
         indexer_proxy,
+        SubscriptionService::default(),
     );
     let aggregate_service = AggregateService::new(rpc_service.clone());
 
@@ -0,0 +1,166 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::signer::Signer;
+use crate::transaction_builder::TransactionBuilder;
+use anyhow::{ensure, Result};
+use moveos_types::gas_config::GasConfig;
+use moveos_types::transaction::FunctionCall;
+use rooch_rpc_api::jsonrpc_types::{
+    AnnotatedFunctionResultView, ExecuteTransactionResponseView, KeptVMStatusView,
+};
+use rooch_rpc_client::{Client, ClientBuilder};
+use rooch_types::address::RoochAddress;
+use rooch_types::framework::webauthn_validator::WebauthnValidatorModule;
+use rooch_types::transaction::authenticator::WebauthnAuthenticator;
+use rooch_types::transaction::rooch::{RoochTransaction, RoochTransactionData};
+use rooch_types::webauthn;
+
+/// A thin client of the Rooch RPC, fills the transactions from the chain, signs and submits them.
+#[derive(Debug, Clone)]
+pub struct RoochClient {
+    client: Client,
+}
+
+impl RoochClient {
+    pub fn new(client: Client) -> Self {
+        Self { client }
+    }
+
+    /// Connect to the RPC server with the default client options
+    pub async fn connect(url: impl AsRef<str>) -> Result<Self> {
+        Ok(Self::new(ClientBuilder::default().build(url).await?))
+    }
+
+    /// The underlying RPC client, for the RPC methods not wrapped by the SDK
+    pub fn inner(&self) -> &Client {
+        &self.client
+    }
+
+    pub async fn chain_id(&self) -> Result<u64> {
+        self.client.rooch.get_chain_id().await
+    }
+
+    pub async fn sequence_number(&self, address: RoochAddress) -> Result<u64> {
+        self.client.rooch.get_sequence_number(address).await
+    }
+
+    /// Build the transaction data, the fields not set in the builder are filled from the chain,
+    /// the max gas amount is estimated by the dry run.
+    pub async fn build_transaction(
+        &self,
+        builder: TransactionBuilder,
+    ) -> Result<RoochTransactionData> {
+        let chain_id = match builder.chain_id {
+            Some(chain_id) => chain_id,
+            None => self.chain_id().await?,
+        };
+        let sequence_number = match builder.sequence_number {
+            Some(sequence_number) => sequence_number,
+            None => self.sequence_number(builder.sender).await?,
+        };
+        let estimate_gas = builder.max_gas_amount.is_none();
+        let mut tx_data = builder
+            .chain_id(chain_id)
+            .sequence_number(sequence_number)
+            .build()?;
+        if estimate_gas {
+            // Fallback to the default max gas amount if the dry run failed, so the error is reported by the execution.
+            match self.client.rooch.estimate_gas(&tx_data).await {
+                Ok(estimate) if estimate.status == KeptVMStatusView::Executed => {
+                    tx_data.max_gas_amount = estimate.max_gas_amount;
+                }
+                Ok(estimate) => {
+                    log::debug!("estimate gas failed with status: {:?}", estimate.status);
+                    tx_data.max_gas_amount = GasConfig::DEFAULT_MAX_GAS_AMOUNT;
+                }
+                Err(e) => {
+                    log::debug!("estimate gas failed: {}", e);
+                    tx_data.max_gas_amount = GasConfig::DEFAULT_MAX_GAS_AMOUNT;
+                }
+            }
+        }
+        Ok(tx_data)
+    }
+
+    pub async fn execute(&self, tx: RoochTransaction) -> Result<ExecuteTransactionResponseView> {
+        self.client.rooch.execute_tx(tx).await
+    }
+
+    /// Build the transaction, sign it with the signer and execute it
+    pub async fn sign_and_execute<S>(
+        &self,
+        signer: &S,
+        builder: TransactionBuilder,
+    ) -> Result<ExecuteTransactionResponseView>
+    where
+        S: Signer + ?Sized,
+    {
+        ensure!(
+            builder.sender == signer.address(),
+            "The sender [{}] of the transaction is not the address of the signer [{}]",
+            builder.sender,
+            signer.address()
+        );
+        let tx_data = self.build_transaction(builder).await?;
+        let tx = signer.sign_transaction(tx_data).await?;
+        self.execute(tx).await
+    }
+
+    /// Register the passkey credential for the signer account, the `cose_key` is the credential public key of the attestation,
+    /// the `rp_id` and `origin` are the relying party which the credential is created for.
+    pub async fn register_passkey<S>(
+        &self,
+        signer: &S,
+        cose_key: Vec<u8>,
+        rp_id: &str,
+        origin: &str,
+    ) -> Result<ExecuteTransactionResponseView>
+    where
+        S: Signer + ?Sized,
+    {
+        // Check the COSE key before submitting, the validator aborts on the invalid key
+        webauthn::cose_key_to_public_key(&cose_key)?;
+        let action =
+            WebauthnValidatorModule::rotate_authentication_key_action(cose_key, rp_id, origin);
+        self.sign_and_execute(signer, TransactionBuilder::new(signer.address(), action))
+            .await
+    }
+
+    /// The challenge of the passkey assertion for the transaction data
+    pub fn passkey_challenge(tx_data: &RoochTransactionData) -> String {
+        webauthn::challenge(tx_data.hash().as_bytes())
+    }
+
+    /// Execute the transaction signed by the passkey, the arguments are from the assertion response
+    /// of the challenge returned by `passkey_challenge`, and the `public_key` is the compressed P-256 public key.
+    /// The `rp_id` and `origin` are the relying party the passkey is registered with.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn execute_with_passkey(
+        &self,
+        tx_data: RoochTransactionData,
+        public_key: Vec<u8>,
+        authenticator_data: Vec<u8>,
+        client_data_json: Vec<u8>,
+        der_signature: &[u8],
+        rp_id: &str,
+        origin: &str,
+    ) -> Result<ExecuteTransactionResponseView> {
+        let authenticator = WebauthnAuthenticator::new(
+            public_key,
+            tx_data.hash().as_bytes(),
+            authenticator_data,
+            client_data_json,
+            der_signature,
+            rp_id,
+            origin,
+        )?;
+        self.execute(RoochTransaction::new(tx_data, authenticator.into()))
+            .await
+    }
+
+    /// Call the view function, the state is not changed
+    pub async fn view(&self, call: FunctionCall) -> Result<AnnotatedFunctionResultView> {
+        self.client.rooch.execute_view_function(call).await
+    }
+}
@@ -0,0 +1,20 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+//! The Rust SDK of Rooch, build, sign and submit the transactions without depending on the CLI.
+//!
+//! ```ignore
+//! let client = RoochClient::connect("http://127.0.0.1:50051").await?;
+//! let call = FunctionCallBuilder::parse("0x3::empty::empty")?.build();
+//! let result = client
+//!     .sign_and_execute(&key_pair, TransactionBuilder::function_call(key_pair.address(), call))
+//!     .await?;
+//! ```
+
+pub mod client;
+pub mod signer;
+pub mod transaction_builder;
+
+pub use client::RoochClient;
+pub use signer::{KeystoreSigner, Signer};
+pub use transaction_builder::{FunctionCallBuilder, TransactionBuilder};
@@ -0,0 +1,88 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use anyhow::{ensure, Result};
+use async_trait::async_trait;
+use rooch_key::keystore::account_keystore::AccountKeystore;
+use rooch_types::address::RoochAddress;
+use rooch_types::crypto::RoochKeyPair;
+use rooch_types::transaction::rooch::{RoochTransaction, RoochTransactionData};
+
+/// The signer of the transactions, implement it to sign with the external wallets.
+#[async_trait]
+pub trait Signer: Send + Sync {
+    /// The address of the account the signer signs for
+    fn address(&self) -> RoochAddress;
+
+    async fn sign_transaction(&self, tx_data: RoochTransactionData) -> Result<RoochTransaction>;
+}
+
+#[async_trait]
+impl Signer for RoochKeyPair {
+    fn address(&self) -> RoochAddress {
+        self.public().address()
+    }
+
+    async fn sign_transaction(&self, tx_data: RoochTransactionData) -> Result<RoochTransaction> {
+        ensure!(
+            tx_data.sender == Signer::address(self),
+            "The sender [{}] of the transaction is not the address of the key pair",
+            tx_data.sender
+        );
+        Ok(tx_data.sign(self))
+    }
+}
+
+/// Sign the transactions with the key of an account in the keystore
+pub struct KeystoreSigner<K> {
+    keystore: K,
+    address: RoochAddress,
+    password: Option<String>,
+    auth_validator_id: Option<u64>,
+}
+
+impl<K> KeystoreSigner<K>
+where
+    K: AccountKeystore + Send + Sync,
+{
+    pub fn new(keystore: K, address: RoochAddress, password: Option<String>) -> Self {
+        Self {
+            keystore,
+            address,
+            password,
+            auth_validator_id: None,
+        }
+    }
+
+    /// Sign with the auth validator installed to the account instead of the native validator
+    pub fn with_auth_validator_id(mut self, auth_validator_id: u64) -> Self {
+        self.auth_validator_id = Some(auth_validator_id);
+        self
+    }
+}
+
+#[async_trait]
+impl<K> Signer for KeystoreSigner<K>
+where
+    K: AccountKeystore + Send + Sync,
+{
+    fn address(&self) -> RoochAddress {
+        self.address
+    }
+
+    async fn sign_transaction(&self, tx_data: RoochTransactionData) -> Result<RoochTransaction> {
+        ensure!(
+            tx_data.sender == self.address,
+            "The sender [{}] of the transaction is not the address of the signer [{}]",
+            tx_data.sender,
+            self.address
+        );
+        let tx = self
+            .keystore
+            .sign_transaction(&self.address, tx_data, self.password.clone())?;
+        Ok(match self.auth_validator_id {
+            Some(auth_validator_id) => tx.with_auth_validator_id(auth_validator_id),
+            None => tx,
+        })
+    }
+}
@@ -0,0 +1,140 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use anyhow::{anyhow, Result};
+use move_core_types::identifier::Identifier;
+use move_core_types::language_storage::{ModuleId, TypeTag};
+use moveos_types::gas_config::GasConfig;
+use moveos_types::move_types::FunctionId;
+use moveos_types::transaction::{FunctionCall, MoveAction};
+use rooch_types::address::RoochAddress;
+use rooch_types::transaction::rooch::RoochTransactionData;
+use serde::Serialize;
+use std::str::FromStr;
+
+/// Build a `FunctionCall` with the arguments encoded from the Rust types.
+///
+/// The arguments are BCS encoded, the Rust types have the same encoding as the Move types:
+/// the integers, `bool`, `AccountAddress`, `Vec<T>` as `vector<T>`, `String` as `std::string::String`,
+/// and `Option<T>` as `std::option::Option<T>`.
+#[derive(Debug, Clone)]
+pub struct FunctionCallBuilder {
+    function_id: FunctionId,
+    ty_args: Vec<TypeTag>,
+    args: Vec<Vec<u8>>,
+}
+
+impl FunctionCallBuilder {
+    pub fn new(function_id: FunctionId) -> Self {
+        Self {
+            function_id,
+            ty_args: vec![],
+            args: vec![],
+        }
+    }
+
+    /// Parse the function id, such as `0x3::account::create_account`
+    pub fn parse(function_id: &str) -> Result<Self> {
+        Ok(Self::new(FunctionId::from_str(function_id)?))
+    }
+
+    pub fn from_module(module_id: ModuleId, function_name: &str) -> Result<Self> {
+        Ok(Self::new(FunctionId::new(
+            module_id,
+            Identifier::new(function_name)?,
+        )))
+    }
+
+    pub fn type_arg(mut self, ty_arg: TypeTag) -> Self {
+        self.ty_args.push(ty_arg);
+        self
+    }
+
+    pub fn type_args(mut self, ty_args: Vec<TypeTag>) -> Self {
+        self.ty_args.extend(ty_args);
+        self
+    }
+
+    /// Append the argument, it is BCS encoded
+    pub fn arg<T: Serialize + ?Sized>(mut self, arg: &T) -> Result<Self> {
+        self.args.push(bcs::to_bytes(arg)?);
+        Ok(self)
+    }
+
+    /// Append the argument which is already BCS encoded
+    pub fn raw_arg(mut self, arg: Vec<u8>) -> Self {
+        self.args.push(arg);
+        self
+    }
+
+    pub fn build(self) -> FunctionCall {
+        FunctionCall::new(self.function_id, self.ty_args, self.args)
+    }
+}
+
+/// Build the `RoochTransactionData`.
+///
+/// The sequence number, chain id and max gas amount are optional, `RoochClient::build_transaction`
+/// fills the missing fields from the chain, and `build` requires the sequence number and the chain id.
+#[derive(Debug, Clone)]
+pub struct TransactionBuilder {
+    pub(crate) sender: RoochAddress,
+    pub(crate) action: MoveAction,
+    pub(crate) sequence_number: Option<u64>,
+    pub(crate) chain_id: Option<u64>,
+    pub(crate) max_gas_amount: Option<u64>,
+}
+
+impl TransactionBuilder {
+    pub fn new(sender: RoochAddress, action: MoveAction) -> Self {
+        Self {
+            sender,
+            action,
+            sequence_number: None,
+            chain_id: None,
+            max_gas_amount: None,
+        }
+    }
+
+    pub fn function_call(sender: RoochAddress, call: FunctionCall) -> Self {
+        Self::new(sender, MoveAction::Function(call))
+    }
+
+    pub fn sender(&self) -> RoochAddress {
+        self.sender
+    }
+
+    pub fn sequence_number(mut self, sequence_number: u64) -> Self {
+        self.sequence_number = Some(sequence_number);
+        self
+    }
+
+    pub fn chain_id(mut self, chain_id: u64) -> Self {
+        self.chain_id = Some(chain_id);
+        self
+    }
+
+    /// Set the max gas amount, the gas is estimated by the dry run if it is not set
+    pub fn max_gas_amount(mut self, max_gas_amount: u64) -> Self {
+        self.max_gas_amount = Some(max_gas_amount);
+        self
+    }
+
+    /// Build the transaction data offline, the max gas amount is `GasConfig::DEFAULT_MAX_GAS_AMOUNT` if it is not set
+    pub fn build(self) -> Result<RoochTransactionData> {
+        let sequence_number = self
+            .sequence_number
+            .ok_or_else(|| anyhow!("The sequence number of the transaction is not set"))?;
+        let chain_id = self
+            .chain_id
+            .ok_or_else(|| anyhow!("The chain id of the transaction is not set"))?;
+        Ok(RoochTransactionData::new(
+            self.sender,
+            sequence_number,
+            chain_id,
+            self.max_gas_amount
+                .unwrap_or(GasConfig::DEFAULT_MAX_GAS_AMOUNT),
+            self.action,
+        ))
+    }
+}
@@ -4,6 +4,7 @@
 // Copyright (c) The Starcoin Core Contributors
 // SPDX-License-Identifier: Apache-2.0
 
+use crate::node_role::NodeRole;
 use crate::store_config::R_DEFAULT_DB_DIR;
 use crate::{BaseConfig, ConfigModule, RoochOpt};
 use anyhow::Result;
@@ -15,6 +16,7 @@ use std::str::FromStr;
 use std::sync::Arc;
 
 pub const ROOCH_INDEXER_DB_FILENAME: &str = "indexer.sqlite";
+pub const DEFAULT_INDEXER_PRUNE_INTERVAL_SECS: u64 = 600;
 
 #[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize, Parser)]
 #[clap(name = "Rooch indexer")]
@@ -24,6 +26,88 @@ pub struct IndexerConfig {
     #[serde(skip)]
     #[clap(skip)]
     base: Option<Arc<BaseConfig>>,
+
+    #[serde(skip)]
+    #[clap(skip)]
+    node_role: NodeRole,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[clap(
+        name = "indexer-table-change-sets-retention",
+        long,
+        help = "the number of the latest transactions whose table change sets are retained by the indexer, all are retained if not set"
+    )]
+    pub table_change_sets_retention: Option<u64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[clap(
+        name = "indexer-events-retention",
+        long,
+        help = "the number of the latest transactions whose events are retained by the indexer, all are retained if not set"
+    )]
+    pub events_retention: Option<u64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[clap(
+        name = "indexer-coin-transfers-retention",
+        long,
+        help = "the number of the latest transactions whose coin transfers are retained by the indexer, all are retained if not set"
+    )]
+    pub coin_transfers_retention: Option<u64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[clap(
+        name = "indexer-object-ownership-changes-retention",
+        long,
+        help = "the number of the latest transactions whose object ownership changes are retained by the indexer, all are retained if not set"
+    )]
+    pub object_ownership_changes_retention: Option<u64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[clap(
+        name = "indexer-prune-interval-secs",
+        long,
+        help = "the interval in seconds of the background indexer pruner, default is 600"
+    )]
+    pub prune_interval_secs: Option<u64>,
+}
+
+/// The retention of the indexed history tables, counted in the latest transactions,
+/// the rows of the older transactions are deleted by the indexer pruner.
+/// The retention is not counted in time because the indexed rows do not record the transaction timestamp.
+#[derive(Clone, Copy, Debug, Default, Deserialize, PartialEq, Eq, Serialize)]
+pub struct IndexerRetention {
+    pub table_change_sets: Option<u64>,
+    pub events: Option<u64>,
+    pub coin_transfers: Option<u64>,
+    pub object_ownership_changes: Option<u64>,
+}
+
+impl IndexerRetention {
+    /// Whether all the indexed history is retained
+    pub fn is_unlimited(&self) -> bool {
+        self.table_change_sets.is_none()
+            && self.events.is_none()
+            && self.coin_transfers.is_none()
+            && self.object_ownership_changes.is_none()
+    }
+}
+
+impl std::fmt::Display for IndexerRetention {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let fmt_retention = |retention: Option<u64>| match retention {
+            Some(n) => n.to_string(),
+            None => "all".to_string(),
+        };
+        write!(
+            f,
+            "table_change_sets: {}, events: {}, coin_transfers: {}, object_ownership_changes: {}",
+            fmt_retention(self.table_change_sets),
+            fmt_retention(self.events),
+            fmt_retention(self.coin_transfers),
+            fmt_retention(self.object_ownership_changes)
+        )
+    }
 }
 
 impl IndexerConfig {
@@ -69,6 +153,25 @@ impl IndexerConfig {
             .join(ROOCH_INDEXER_DB_FILENAME)
     }
 
+    /// The indexer tables are decided by the node role
+    pub fn node_role(&self) -> NodeRole {
+        self.node_role
+    }
+
+    pub fn retention(&self) -> IndexerRetention {
+        IndexerRetention {
+            table_change_sets: self.table_change_sets_retention,
+            events: self.events_retention,
+            coin_transfers: self.coin_transfers_retention,
+            object_ownership_changes: self.object_ownership_changes_retention,
+        }
+    }
+
+    pub fn prune_interval_secs(&self) -> u64 {
+        self.prune_interval_secs
+            .unwrap_or(DEFAULT_INDEXER_PRUNE_INTERVAL_SECS)
+    }
+
     pub fn get_mock_indexer_db(data_dir: &DataDirPath) -> PathBuf {
         data_dir
             .path()
@@ -78,8 +181,27 @@ impl IndexerConfig {
 }
 
 impl ConfigModule for IndexerConfig {
-    fn merge_with_opt(&mut self, _opt: &RoochOpt, base: Arc<BaseConfig>) -> Result<()> {
+    fn merge_with_opt(&mut self, opt: &RoochOpt, base: Arc<BaseConfig>) -> Result<()> {
         self.base = Some(base);
+        self.node_role = opt.node_role();
+
+        let indexer_config = opt.indexer.clone();
+        if indexer_config.table_change_sets_retention.is_some() {
+            self.table_change_sets_retention = indexer_config.table_change_sets_retention;
+        }
+        if indexer_config.events_retention.is_some() {
+            self.events_retention = indexer_config.events_retention;
+        }
+        if indexer_config.coin_transfers_retention.is_some() {
+            self.coin_transfers_retention = indexer_config.coin_transfers_retention;
+        }
+        if indexer_config.object_ownership_changes_retention.is_some() {
+            self.object_ownership_changes_retention =
+                indexer_config.object_ownership_changes_retention;
+        }
+        if indexer_config.prune_interval_secs.is_some() {
+            self.prune_interval_secs = indexer_config.prune_interval_secs;
+        }
 
         Ok(())
     }
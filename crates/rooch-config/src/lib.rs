@@ -16,11 +16,18 @@ use rooch_types::chain_id::RoochChainID;
 use rooch_types::crypto::RoochKeyPair;
 
 use crate::da_config::DAConfig;
+use crate::indexer_config::IndexerConfig;
+use crate::node_role::NodeRole;
+use crate::server_config::{
+    CorsConfig, LogConfig, MethodAccessConfig, QueryLimitsConfig, RateLimitConfig, TlsConfig,
+    TracingConfig,
+};
 use crate::store_config::StoreConfig;
 
 pub mod config;
 pub mod da_config;
 pub mod indexer_config;
+pub mod node_role;
 pub mod server_config;
 pub mod store_config;
 
@@ -29,6 +36,8 @@ pub const ROOCH_CONFIR_DIR: &str = "rooch_config";
 pub const ROOCH_CLIENT_CONFIG: &str = "rooch.yaml";
 pub const ROOCH_SERVER_CONFIG: &str = "server.yaml";
 pub const ROOCH_KEYSTORE_FILENAME: &str = "rooch.keystore";
+pub const ROOCH_NONCE_FILENAME: &str = "nonces.yaml";
+pub const ROOCH_CONSOLE_HISTORY_FILENAME: &str = "console_history";
 
 pub static R_DEFAULT_BASE_DATA_DIR: Lazy<PathBuf> = Lazy::new(|| {
     dirs_next::home_dir()
@@ -76,15 +85,50 @@ pub struct RoochOpt {
     #[clap(long, short = 'n', help = R_OPT_NET_HELP)]
     pub chain_id: Option<RoochChainID>,
 
+    /// The role of the node: archive, full or light-index, default is archive.
+    /// The archive node keeps the full history and all the indexer tables,
+    /// the full node prunes the states and disables the indexer,
+    /// the light-index node prunes the states and only indexes the transactions and events.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[clap(long)]
+    pub node_role: Option<NodeRole>,
+
     #[clap(flatten)]
     pub store: StoreConfig,
 
+    #[serde(default)]
+    #[clap(flatten)]
+    pub indexer: IndexerConfig,
+
     /// Optional custom port, which the rooch server should listen on.
     /// The port on which the server should listen defaults to `50051`
     #[serde(skip_serializing_if = "Option::is_none")]
     #[clap(long, short = 'p')]
     pub port: Option<u16>,
 
+    /// Optional port of the gRPC server, the gRPC server will not start if not set.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[clap(long)]
+    pub grpc_port: Option<u16>,
+
+    /// Optional port of the Prometheus metrics server, the metrics are exposed on `/metrics`.
+    /// The metrics server will not start if not set.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[clap(long)]
+    pub metrics_port: Option<u16>,
+
+    /// Optional port of the GraphQL server of the indexer, the GraphiQL IDE is served on the same port.
+    /// The GraphQL server will not start if not set.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[clap(long)]
+    pub graphql_port: Option<u16>,
+
+    /// Optional UDP port of the state sync server, the peers download the transactions and the states
+    /// from it over QUIC with `rooch server sync`. The state sync server will not start if not set.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[clap(long)]
+    pub sync_port: Option<u16>,
+
     /// The Ethereum RPC URL to connect to for relay L1 block and transaction to L2.
     /// If not set, the relayer service will not start.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -120,6 +164,11 @@ pub struct RoochOpt {
     /// The bitcoin network, default is regtest.
     pub btc_network: Option<u8>,
 
+    /// Run the keeper of the on-chain scheduler, it triggers the due scheduled tasks with the relayer account.
+    #[serde(default)]
+    #[clap(long)]
+    pub scheduler_keeper: bool,
+
     /// The address of the sequencer account
     #[clap(long)]
     pub sequencer_account: Option<String>,
@@ -133,6 +182,11 @@ pub struct RoochOpt {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[clap(long)]
     pub da: Option<DAConfig>,
+
+    /// The maximum number of transactions in a `rooch_sendRawTransactionBatch` call, default is 100.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[clap(long)]
+    pub max_tx_batch_size: Option<usize>,
 }
 
 impl std::fmt::Display for RoochOpt {
@@ -150,21 +204,33 @@ impl RoochOpt {
         RoochOpt {
             base_data_dir: Some(PathBuf::from("TMP")),
             chain_id: Some(RoochChainID::LOCAL),
+            node_role: None,
             store: StoreConfig::default(),
+            indexer: IndexerConfig::default(),
             port: None,
+            grpc_port: None,
+            metrics_port: None,
+            graphql_port: None,
+            sync_port: None,
             eth_rpc_url: None,
             btc_rpc_url: None,
             btc_rpc_username: None,
             btc_rpc_password: None,
             btc_start_block_height: None,
             btc_network: Some(Network::default().to_num()),
+            scheduler_keeper: false,
             sequencer_account: None,
             proposer_account: None,
             relayer_account: None,
             da: None,
+            max_tx_batch_size: None,
         }
     }
 
+    pub fn node_role(&self) -> NodeRole {
+        self.node_role.unwrap_or_default()
+    }
+
     pub fn ethereum_relayer_config(&self) -> Option<EthereumRelayerConfig> {
         self.eth_rpc_url
             .as_ref()
@@ -251,6 +317,20 @@ pub struct ServerOpt {
     pub proposer_keypair: Option<RoochKeyPair>,
     pub relayer_keypair: Option<RoochKeyPair>,
     pub active_env: Option<String>,
+    /// The rate limit of the JSON-RPC requests, loaded from the server config file
+    pub rate_limit: Option<RateLimitConfig>,
+    /// The CORS policy of the JSON-RPC server
+    pub cors: Option<CorsConfig>,
+    /// Serve the JSON-RPC over HTTPS if present
+    pub tls: Option<TlsConfig>,
+    /// The access control of the JSON-RPC methods
+    pub method_access: Option<MethodAccessConfig>,
+    /// Export the transaction flow spans by OTLP
+    pub tracing: Option<TracingConfig>,
+    /// The limits of the JSON-RPC queries
+    pub query_limits: Option<QueryLimitsConfig>,
+    /// The format and the filter of the server logs
+    pub log: Option<LogConfig>,
 }
 
 impl std::fmt::Display for ServerOpt {
@@ -270,6 +350,13 @@ impl ServerOpt {
             proposer_keypair: None,
             relayer_keypair: None,
             active_env: None,
+            rate_limit: None,
+            cors: None,
+            tls: None,
+            method_access: None,
+            tracing: None,
+            query_limits: None,
+            log: None,
         }
     }
 
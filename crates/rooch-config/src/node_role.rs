@@ -0,0 +1,102 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use moveos_config::store_config::PruningPolicy;
+use serde::{Deserialize, Serialize};
+
+/// The number of the latest state roots retained by the roles which prune the state.
+pub const DEFAULT_PRUNED_STATE_ROOTS: u64 = 1000;
+
+/// The role of the node, decides what the node stores.
+///
+/// The explicit `pruning-policy` option overrides the pruning policy of the role.
+#[derive(Copy, Clone, Debug, Default, Deserialize, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum NodeRole {
+    /// Keep the full history of the states, and index the transactions, events and states.
+    #[default]
+    Archive,
+    /// Keep the latest states only, without the indexer, for the nodes only executing the transactions.
+    Full,
+    /// Keep the latest states only, and index the transactions and events, but not the states.
+    LightIndex,
+}
+
+impl NodeRole {
+    pub fn default_pruning_policy(&self) -> PruningPolicy {
+        match self {
+            NodeRole::Archive => PruningPolicy::Archive,
+            NodeRole::Full | NodeRole::LightIndex => {
+                PruningPolicy::RetainLatest(DEFAULT_PRUNED_STATE_ROOTS)
+            }
+        }
+    }
+
+    pub fn index_transactions(&self) -> bool {
+        matches!(self, NodeRole::Archive | NodeRole::LightIndex)
+    }
+
+    pub fn index_events(&self) -> bool {
+        matches!(self, NodeRole::Archive | NodeRole::LightIndex)
+    }
+
+    pub fn index_states(&self) -> bool {
+        matches!(self, NodeRole::Archive)
+    }
+}
+
+impl std::fmt::Display for NodeRole {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            NodeRole::Archive => write!(f, "archive"),
+            NodeRole::Full => write!(f, "full"),
+            NodeRole::LightIndex => write!(f, "light-index"),
+        }
+    }
+}
+
+impl std::str::FromStr for NodeRole {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "archive" => Ok(NodeRole::Archive),
+            "full" => Ok(NodeRole::Full),
+            "light-index" => Ok(NodeRole::LightIndex),
+            _ => Err(anyhow::anyhow!(
+                "Invalid node role {}, expect archive, full or light-index",
+                s
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn test_node_role_from_str() {
+        for role in [NodeRole::Archive, NodeRole::Full, NodeRole::LightIndex] {
+            assert_eq!(NodeRole::from_str(&role.to_string()).unwrap(), role);
+        }
+        assert!(NodeRole::from_str("validator").is_err());
+    }
+
+    #[test]
+    fn test_node_role_profiles() {
+        assert!(NodeRole::Archive.default_pruning_policy().is_archive());
+        assert!(NodeRole::Archive.index_states());
+
+        assert!(!NodeRole::Full.default_pruning_policy().is_archive());
+        assert!(!NodeRole::Full.index_transactions());
+        assert!(!NodeRole::Full.index_events());
+        assert!(!NodeRole::Full.index_states());
+
+        assert!(!NodeRole::LightIndex.default_pruning_policy().is_archive());
+        assert!(NodeRole::LightIndex.index_transactions());
+        assert!(NodeRole::LightIndex.index_events());
+        assert!(!NodeRole::LightIndex.index_states());
+    }
+}
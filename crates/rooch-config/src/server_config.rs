@@ -4,13 +4,37 @@
 use crate::config::Config;
 use serde::Deserialize;
 use serde::Serialize;
+use std::collections::BTreeMap;
 use std::fmt::{Display, Formatter, Result, Write};
+use std::net::IpAddr;
+use std::path::PathBuf;
 
 #[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq, Hash)]
 pub struct ServerConfig {
     pub host: String,
     pub port: u16,
     pub block_propose_duration_in_seconds: u16,
+    /// The rate limit of the JSON-RPC requests, no limit if absent
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rate_limit: Option<RateLimitConfig>,
+    /// The CORS policy of the JSON-RPC server, allow `POST` from any origin if absent
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cors: Option<CorsConfig>,
+    /// Serve the JSON-RPC over HTTPS if present
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tls: Option<TlsConfig>,
+    /// The access control of the JSON-RPC methods, all the methods except the `admin_*` methods are allowed if absent
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub method_access: Option<MethodAccessConfig>,
+    /// Export the transaction flow spans to the OpenTelemetry collector if present
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tracing: Option<TracingConfig>,
+    /// The limits of the JSON-RPC queries, the default limits are used if absent
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub query_limits: Option<QueryLimitsConfig>,
+    /// The format and the filter of the server logs, the text logs of the `info` level if absent
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub log: Option<LogConfig>,
 }
 
 impl ServerConfig {
@@ -36,6 +60,27 @@ impl Display for ServerConfig {
 
         writeln!(writer, "host : {}", self.host)?;
         writeln!(writer, "port : {}", self.port)?;
+        if let Some(rate_limit) = &self.rate_limit {
+            writeln!(writer, "rate_limit : {}", rate_limit)?;
+        }
+        if let Some(cors) = &self.cors {
+            writeln!(writer, "cors : {}", cors)?;
+        }
+        if let Some(tls) = &self.tls {
+            writeln!(writer, "tls : {}", tls)?;
+        }
+        if let Some(method_access) = &self.method_access {
+            writeln!(writer, "method_access : {}", method_access)?;
+        }
+        if let Some(tracing) = &self.tracing {
+            writeln!(writer, "tracing : {}", tracing)?;
+        }
+        if let Some(query_limits) = &self.query_limits {
+            writeln!(writer, "query_limits : {}", query_limits)?;
+        }
+        if let Some(log) = &self.log {
+            writeln!(writer, "log : {}", log)?;
+        }
 
         write!(f, "{}", writer)
     }
@@ -47,6 +92,469 @@ impl Default for ServerConfig {
             host: "0.0.0.0".to_string(),
             port: 50051,
             block_propose_duration_in_seconds: 5,
+            rate_limit: None,
+            cors: None,
+            tls: None,
+            method_access: None,
+            tracing: None,
+            query_limits: None,
+            log: None,
+        }
+    }
+}
+
+/// The token bucket rate limit, the bucket holds at most `burst_size` tokens and refills `requests_per_second` tokens per second.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq, Hash)]
+pub struct TokenBucketConfig {
+    pub requests_per_second: u32,
+    pub burst_size: u32,
+}
+
+/// The rate limit and the request quota of the JSON-RPC server.
+/// The client is identified by the `X-Forwarded-For` or `X-Real-IP` header if `trust_proxy_headers` is enabled,
+/// otherwise by the peer ip address. The client of `X-Forwarded-For` is the rightmost address which is not a trusted proxy,
+/// as the addresses on the left are set by the client.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq, Hash)]
+pub struct RateLimitConfig {
+    /// The limit of all the requests of a client, every call in a batch request costs a token,
+    /// so a batch request with more calls than the burst size is always rejected
+    pub per_client: TokenBucketConfig,
+    /// The limits of the given JSON-RPC methods of a client, every call in a batch request counts
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub per_method: BTreeMap<String, TokenBucketConfig>,
+    /// The max size of the request body in bytes
+    #[serde(default = "RateLimitConfig::default_max_request_body_size")]
+    pub max_request_body_size: u32,
+    /// Identify the client by the proxy headers, only enable it if the server is behind a trusted proxy
+    #[serde(default)]
+    pub trust_proxy_headers: bool,
+    /// The addresses of the trusted proxies in front of the nearest proxy, such as the CDN nodes,
+    /// they are skipped when the client is taken from `X-Forwarded-For`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub trusted_proxies: Vec<IpAddr>,
+}
+
+impl RateLimitConfig {
+    pub const DEFAULT_MAX_REQUEST_BODY_SIZE: u32 = 10 * 1024 * 1024;
+
+    fn default_max_request_body_size() -> u32 {
+        Self::DEFAULT_MAX_REQUEST_BODY_SIZE
+    }
+}
+
+impl Display for RateLimitConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(
+            f,
+            "{} requests per second per client, burst size {}, {} method limits, max request body size {}",
+            self.per_client.requests_per_second,
+            self.per_client.burst_size,
+            self.per_method.len(),
+            self.max_request_body_size
+        )
+    }
+}
+
+/// The CORS policy of the JSON-RPC server.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq, Hash)]
+pub struct CorsConfig {
+    /// The allowed origins, any origin is allowed if it is empty or contains `*`
+    #[serde(default)]
+    pub allow_origins: Vec<String>,
+    /// The allowed HTTP methods
+    #[serde(default = "CorsConfig::default_allow_methods")]
+    pub allow_methods: Vec<String>,
+    /// The allowed request headers
+    #[serde(default = "CorsConfig::default_allow_headers")]
+    pub allow_headers: Vec<String>,
+    /// How long the preflight response can be cached by the browser, in seconds
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_age_seconds: Option<u64>,
+}
+
+impl CorsConfig {
+    pub const ANY_ORIGIN: &'static str = "*";
+
+    fn default_allow_methods() -> Vec<String> {
+        vec!["POST".to_owned()]
+    }
+
+    fn default_allow_headers() -> Vec<String> {
+        vec!["content-type".to_owned()]
+    }
+
+    pub fn allow_any_origin(&self) -> bool {
+        self.allow_origins.is_empty()
+            || self
+                .allow_origins
+                .iter()
+                .any(|origin| origin == Self::ANY_ORIGIN)
+    }
+}
+
+impl Default for CorsConfig {
+    fn default() -> Self {
+        Self {
+            allow_origins: vec![],
+            allow_methods: Self::default_allow_methods(),
+            allow_headers: Self::default_allow_headers(),
+            max_age_seconds: None,
+        }
+    }
+}
+
+impl Display for CorsConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        let origins = if self.allow_any_origin() {
+            Self::ANY_ORIGIN.to_owned()
+        } else {
+            self.allow_origins.join(",")
+        };
+        write!(
+            f,
+            "origins [{}], methods [{}], headers [{}]",
+            origins,
+            self.allow_methods.join(","),
+            self.allow_headers.join(",")
+        )
+    }
+}
+
+/// The certificate and the private key for serving the JSON-RPC over HTTPS.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq, Hash)]
+pub struct TlsConfig {
+    /// The PEM file of the certificate chain, the end-entity certificate first
+    pub cert_path: PathBuf,
+    /// The PEM file of the PKCS#8, RSA or SEC1 EC private key
+    pub key_path: PathBuf,
+}
+
+impl Display for TlsConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(
+            f,
+            "cert {}, key {}",
+            self.cert_path.display(),
+            self.key_path.display()
+        )
+    }
+}
+
+/// The access control of the JSON-RPC methods, such as disabling `rooch_sendRawTransaction` on the read nodes.
+/// The method patterns are the method names, or the prefixes ending with `*`, such as `rooch_*`.
+#[derive(Clone, Debug, Default, Deserialize, Serialize, PartialEq, Eq, Hash)]
+pub struct MethodAccessConfig {
+    /// Only the matched methods are allowed if it is not empty
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub allow: Vec<String>,
+    /// The matched methods are denied, it takes precedence over `allow`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub deny: Vec<String>,
+    /// The matched methods are only allowed from the localhost, the `admin_*` methods are always localhost only.
+    /// They are only served if the server listens on the loopback interface, the proxy headers are not trusted.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub localhost_only: Vec<String>,
+    /// Reject the WebSocket connections, the subscriptions are unavailable if disabled
+    #[serde(default)]
+    pub disable_websocket: bool,
+}
+
+impl MethodAccessConfig {
+    /// The admin methods are always only allowed from the localhost
+    pub const ADMIN_METHODS: &'static str = "admin_";
+
+    fn matches(patterns: &[String], method: &str) -> bool {
+        patterns
+            .iter()
+            .any(|pattern| match pattern.strip_suffix('*') {
+                Some(prefix) => method.starts_with(prefix),
+                None => pattern == method,
+            })
+    }
+
+    /// Check if the method is allowed, `from_localhost` is whether the request is from the localhost.
+    pub fn is_allowed(&self, method: &str, from_localhost: bool) -> bool {
+        if Self::matches(&self.deny, method) {
+            return false;
+        }
+        if !self.allow.is_empty() && !Self::matches(&self.allow, method) {
+            return false;
+        }
+        from_localhost
+            || !(method.starts_with(Self::ADMIN_METHODS)
+                || Self::matches(&self.localhost_only, method))
+    }
+}
+
+impl Display for MethodAccessConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(
+            f,
+            "allow [{}], deny [{}], localhost only [{}], websocket {}",
+            self.allow.join(","),
+            self.deny.join(","),
+            self.localhost_only.join(","),
+            if self.disable_websocket {
+                "disabled"
+            } else {
+                "enabled"
+            }
+        )
+    }
+}
+
+/// The OpenTelemetry exporter of the transaction flow spans, the spans are exported by OTLP over gRPC.
+/// The trace context of the JSON-RPC request is extracted from the W3C `traceparent` header,
+/// so the spans of the transaction are attached to the trace of the client.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq, Hash)]
+pub struct TracingConfig {
+    /// The OTLP gRPC endpoint of the collector, such as `http://localhost:4317`
+    pub otlp_endpoint: String,
+    /// The `service.name` resource of the exported spans
+    #[serde(default = "TracingConfig::default_service_name")]
+    pub service_name: String,
+}
+
+impl TracingConfig {
+    pub const DEFAULT_SERVICE_NAME: &'static str = "rooch";
+
+    pub fn new(otlp_endpoint: String) -> Self {
+        Self {
+            otlp_endpoint,
+            service_name: Self::default_service_name(),
+        }
+    }
+
+    fn default_service_name() -> String {
+        Self::DEFAULT_SERVICE_NAME.to_owned()
+    }
+}
+
+impl Display for TracingConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(
+            f,
+            "otlp endpoint {}, service name {}",
+            self.otlp_endpoint, self.service_name
+        )
+    }
+}
+
+/// The limits of the JSON-RPC queries, so the public nodes can defend against the expensive queries.
+/// The queries exceeding the limits are rejected with the `QUERY_LIMIT_EXCEEDED` error instead of being truncated.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq, Hash)]
+pub struct QueryLimitsConfig {
+    /// The page size of the paginated queries if the `limit` is absent
+    #[serde(default = "QueryLimitsConfig::default_page_size")]
+    pub default_page_size: usize,
+    /// The max page size of the paginated queries
+    #[serde(default = "QueryLimitsConfig::default_max_page_size")]
+    pub max_page_size: usize,
+    /// The max page sizes of the given JSON-RPC methods, they override `max_page_size`
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub per_method_max_page_size: BTreeMap<String, usize>,
+    /// The max number of the objects, resources, modules or table keys in the access path of `rooch_getStates`
+    #[serde(default = "QueryLimitsConfig::default_max_access_path_keys")]
+    pub max_access_path_keys: usize,
+    /// The max complexity of the query filters, it is the number of the sub filters of the event filter,
+    /// the number of the tx hashes of the transaction filter, or the number of the terms of the full-text search query
+    #[serde(default = "QueryLimitsConfig::default_max_filter_complexity")]
+    pub max_filter_complexity: usize,
+}
+
+impl QueryLimitsConfig {
+    pub const DEFAULT_PAGE_SIZE: usize = 50;
+    pub const DEFAULT_MAX_PAGE_SIZE: usize = 200;
+    pub const DEFAULT_MAX_ACCESS_PATH_KEYS: usize = 200;
+    pub const DEFAULT_MAX_FILTER_COMPLEXITY: usize = 100;
+
+    fn default_page_size() -> usize {
+        Self::DEFAULT_PAGE_SIZE
+    }
+
+    fn default_max_page_size() -> usize {
+        Self::DEFAULT_MAX_PAGE_SIZE
+    }
+
+    fn default_max_access_path_keys() -> usize {
+        Self::DEFAULT_MAX_ACCESS_PATH_KEYS
+    }
+
+    fn default_max_filter_complexity() -> usize {
+        Self::DEFAULT_MAX_FILTER_COMPLEXITY
+    }
+
+    /// The max page size of the JSON-RPC method
+    pub fn max_page_size_of(&self, method: &str) -> usize {
+        self.per_method_max_page_size
+            .get(method)
+            .copied()
+            .unwrap_or(self.max_page_size)
+    }
+
+    /// The page size of the JSON-RPC method if the `limit` is absent, it never exceeds the max page size of the method
+    pub fn default_page_size_of(&self, method: &str) -> usize {
+        self.default_page_size.min(self.max_page_size_of(method))
+    }
+}
+
+impl Default for QueryLimitsConfig {
+    fn default() -> Self {
+        Self {
+            default_page_size: Self::DEFAULT_PAGE_SIZE,
+            max_page_size: Self::DEFAULT_MAX_PAGE_SIZE,
+            per_method_max_page_size: BTreeMap::new(),
+            max_access_path_keys: Self::DEFAULT_MAX_ACCESS_PATH_KEYS,
+            max_filter_complexity: Self::DEFAULT_MAX_FILTER_COMPLEXITY,
         }
     }
 }
+
+impl Display for QueryLimitsConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(
+            f,
+            "default page size {}, max page size {}, {} method page size limits, max access path keys {}, max filter complexity {}",
+            self.default_page_size,
+            self.max_page_size,
+            self.per_method_max_page_size.len(),
+            self.max_access_path_keys,
+            self.max_filter_complexity
+        )
+    }
+}
+
+/// The format of the server logs, the JSON logs can be collected by the log aggregation systems without parsing.
+#[derive(
+    Clone, Copy, Debug, Default, Deserialize, Serialize, PartialEq, Eq, Hash, clap::ValueEnum,
+)]
+#[serde(rename_all = "lowercase")]
+pub enum LogFormat {
+    #[default]
+    Text,
+    Json,
+}
+
+impl Display for LogFormat {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self {
+            LogFormat::Text => write!(f, "text"),
+            LogFormat::Json => write!(f, "json"),
+        }
+    }
+}
+
+/// The logs of the server, every subsystem logs with its own target, such as `rooch::rpc`, `rooch::sequencer` and `rooch::indexer`,
+/// so the level of a subsystem can be set by the filter, such as `info,rooch::indexer=debug`.
+/// The filter can be reloaded by the `admin_setLogFilter` method without restarting the server.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq, Hash)]
+pub struct LogConfig {
+    #[serde(default)]
+    pub format: LogFormat,
+    /// The filter directives in the `RUST_LOG` syntax
+    #[serde(default = "LogConfig::default_filter")]
+    pub filter: String,
+}
+
+impl LogConfig {
+    pub const DEFAULT_FILTER: &'static str = "info";
+
+    fn default_filter() -> String {
+        Self::DEFAULT_FILTER.to_owned()
+    }
+}
+
+impl Default for LogConfig {
+    fn default() -> Self {
+        Self {
+            format: LogFormat::default(),
+            filter: Self::default_filter(),
+        }
+    }
+}
+
+impl Display for LogConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(f, "format {}, filter {}", self.format, self.filter)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_method_access() {
+        let config = MethodAccessConfig {
+            allow: vec!["rooch_*".to_owned(), "eth_chainId".to_owned()],
+            deny: vec!["rooch_sendRawTransaction".to_owned()],
+            localhost_only: vec!["rooch_executeRawTransaction".to_owned()],
+            ..Default::default()
+        };
+        assert!(config.is_allowed("rooch_getStates", false));
+        assert!(config.is_allowed("eth_chainId", false));
+        assert!(!config.is_allowed("eth_sendRawTransaction", true));
+        assert!(!config.is_allowed("rooch_sendRawTransaction", true));
+        assert!(!config.is_allowed("rooch_executeRawTransaction", false));
+        assert!(config.is_allowed("rooch_executeRawTransaction", true));
+        assert!(!config.is_allowed("admin_setLogFilter", false));
+        assert!(MethodAccessConfig::default().is_allowed("admin_setLogFilter", true));
+
+        // All the methods are allowed if the allow list is empty
+        let config = MethodAccessConfig {
+            deny: vec!["rooch_send*".to_owned()],
+            ..Default::default()
+        };
+        assert!(config.is_allowed("btc_queryUTXOs", false));
+        assert!(!config.is_allowed("rooch_sendRawTransaction", false));
+    }
+
+    #[test]
+    fn test_tracing_config() {
+        let config: ServerConfig = serde_yaml::from_str(
+            "host: 0.0.0.0\nport: 50051\nblock_propose_duration_in_seconds: 5\ntracing:\n  otlp_endpoint: http://localhost:4317\n",
+        )
+        .unwrap();
+        assert_eq!(
+            config.tracing,
+            Some(TracingConfig::new("http://localhost:4317".to_owned()))
+        );
+        assert_eq!(
+            config.tracing.unwrap().service_name,
+            TracingConfig::DEFAULT_SERVICE_NAME
+        );
+    }
+
+    #[test]
+    fn test_query_limits_config() {
+        let config: QueryLimitsConfig = serde_yaml::from_str(
+            "max_page_size: 100\nper_method_max_page_size:\n  rooch_queryEvents: 20\n",
+        )
+        .unwrap();
+        assert_eq!(
+            config.default_page_size,
+            QueryLimitsConfig::DEFAULT_PAGE_SIZE
+        );
+        assert_eq!(
+            config.max_access_path_keys,
+            QueryLimitsConfig::DEFAULT_MAX_ACCESS_PATH_KEYS
+        );
+        assert_eq!(config.max_page_size_of("rooch_queryTransactions"), 100);
+        assert_eq!(config.max_page_size_of("rooch_queryEvents"), 20);
+        // The default page size is capped by the max page size of the method
+        assert_eq!(config.default_page_size_of("rooch_queryTransactions"), 50);
+        assert_eq!(config.default_page_size_of("rooch_queryEvents"), 20);
+    }
+
+    #[test]
+    fn test_log_config() {
+        let config: ServerConfig = serde_yaml::from_str(
+            "host: 0.0.0.0\nport: 50051\nblock_propose_duration_in_seconds: 5\nlog:\n  format: json\n",
+        )
+        .unwrap();
+        let log = config.log.unwrap();
+        assert_eq!(log.format, LogFormat::Json);
+        assert_eq!(log.filter, LogConfig::DEFAULT_FILTER);
+        assert_eq!(LogConfig::default().format, LogFormat::Text);
+    }
+}
@@ -4,10 +4,14 @@
 // Copyright (c) The Starcoin Core Contributors
 // SPDX-License-Identifier: Apache-2.0
 
+use crate::node_role::NodeRole;
 use crate::{BaseConfig, ConfigModule, RoochOpt};
 use anyhow::Result;
 use clap::Parser;
-use moveos_config::store_config::RocksdbConfig;
+use moveos_config::store_config::{
+    ColumnFamilyConfig, NamedColumnFamilyConfig, PruningPolicy, RocksdbConfig,
+    DEFAULT_STATE_NODE_CACHE_SIZE,
+};
 use moveos_config::DataDirPath;
 use once_cell::sync::Lazy;
 use serde::{Deserialize, Serialize};
@@ -20,6 +24,7 @@ static R_DEFAULT_DB_MOVEOS_SUBDIR: Lazy<PathBuf> = Lazy::new(|| PathBuf::from("m
 static R_DEFAULT_DB_ROOCH_SUBDIR: Lazy<PathBuf> = Lazy::new(|| PathBuf::from("rooch_store"));
 
 pub const DEFAULT_CACHE_SIZE: usize = 20000;
+pub const DEFAULT_STATE_CACHE_SIZE: usize = 10000;
 
 #[derive(Clone, Default, Debug, Deserialize, PartialEq, Serialize, Parser)]
 #[serde(deny_unknown_fields)]
@@ -44,6 +49,10 @@ pub struct StoreConfig {
     #[clap(skip)]
     base: Option<Arc<BaseConfig>>,
 
+    #[serde(skip)]
+    #[clap(skip)]
+    node_role: NodeRole,
+
     #[serde(skip_serializing_if = "Option::is_none")]
     #[clap(
         name = "rocksdb-wal-bytes-per-sync",
@@ -55,6 +64,54 @@ pub struct StoreConfig {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[clap(name = "rocksdb-bytes-per-sync", long, help = "rocksdb bytes per sync")]
     pub bytes_per_sync: Option<u64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[clap(
+        name = "pruning-policy",
+        long,
+        help = "state pruning policy: archive, retain-latest:<N> or retain-by-epoch:<EPOCH_SIZE>, default is decided by the node role"
+    )]
+    pub pruning_policy: Option<PruningPolicy>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[clap(
+        name = "state-node-cache-size",
+        long,
+        help = "the number of the state tree nodes cached in memory, 0 to disable the cache"
+    )]
+    pub state_node_cache_size: Option<usize>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[clap(
+        name = "state-cache-size",
+        long,
+        help = "the number of the state reading results cached by the RPC server, keyed by the state root and the access path, 0 to disable the cache"
+    )]
+    pub state_cache_size: Option<usize>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[clap(
+        name = "rocksdb-cf-options",
+        long,
+        help = "the options of all the column families: block_cache_size=<BYTES>,bloom_filter_bits_per_key=<BITS>,compression=<none|snappy|lz4|zstd>,write_buffer_size=<BYTES>"
+    )]
+    pub column_family: Option<ColumnFamilyConfig>,
+
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[clap(
+        name = "rocksdb-named-cf-options",
+        long,
+        help = "the options of a column family as <CF_NAME>:<OPTIONS>, override the options of all the column families, can be repeated"
+    )]
+    pub column_families: Vec<NamedColumnFamilyConfig>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[clap(
+        name = "read-replica-dir",
+        long,
+        help = "run the node as a read replica, the stores in the data dir are opened as the RocksDB secondary instances of the primary node, and the secondary instances keep their info logs in this dir"
+    )]
+    pub read_replica_dir: Option<PathBuf>,
 }
 
 impl StoreConfig {
@@ -118,12 +175,56 @@ impl StoreConfig {
             wal_bytes_per_sync: self
                 .wal_bytes_per_sync
                 .unwrap_or(default.wal_bytes_per_sync),
+            column_family: self.column_family.unwrap_or(default.column_family),
+            column_families: self
+                .column_families
+                .iter()
+                .map(|cf| (cf.name.clone(), cf.config))
+                .collect(),
         }
     }
     pub fn cache_size(&self) -> usize {
         self.cache_size.unwrap_or(DEFAULT_CACHE_SIZE)
     }
 
+    /// The pruning policy, fallback to the default pruning policy of the node role
+    pub fn pruning_policy(&self) -> PruningPolicy {
+        self.pruning_policy
+            .unwrap_or_else(|| self.node_role.default_pruning_policy())
+    }
+
+    pub fn node_role(&self) -> NodeRole {
+        self.node_role
+    }
+
+    pub fn state_node_cache_size(&self) -> usize {
+        self.state_node_cache_size
+            .unwrap_or(DEFAULT_STATE_NODE_CACHE_SIZE)
+    }
+
+    pub fn state_cache_size(&self) -> usize {
+        self.state_cache_size.unwrap_or(DEFAULT_STATE_CACHE_SIZE)
+    }
+
+    /// The dir of the RocksDB secondary instances if the node is a read replica
+    pub fn read_replica_dir(&self) -> Option<&Path> {
+        self.read_replica_dir.as_deref()
+    }
+
+    pub fn is_read_replica(&self) -> bool {
+        self.read_replica_dir.is_some()
+    }
+
+    pub fn get_replica_moveos_store_dir(&self) -> Option<PathBuf> {
+        self.read_replica_dir()
+            .map(|dir| dir.join(R_DEFAULT_DB_MOVEOS_SUBDIR.as_path()))
+    }
+
+    pub fn get_replica_rooch_store_dir(&self) -> Option<PathBuf> {
+        self.read_replica_dir()
+            .map(|dir| dir.join(R_DEFAULT_DB_ROOCH_SUBDIR.as_path()))
+    }
+
     pub fn get_mock_moveos_store_dir(data_dir: &DataDirPath) -> PathBuf {
         data_dir
             .path()
@@ -142,6 +243,7 @@ impl StoreConfig {
 impl ConfigModule for StoreConfig {
     fn merge_with_opt(&mut self, opt: &RoochOpt, base: Arc<BaseConfig>) -> Result<()> {
         self.base = Some(base);
+        self.node_role = opt.node_role();
 
         let store_config = opt.store.clone();
         if store_config.max_open_files.is_some() {
@@ -159,6 +261,24 @@ impl ConfigModule for StoreConfig {
         if store_config.wal_bytes_per_sync.is_some() {
             self.wal_bytes_per_sync = store_config.wal_bytes_per_sync;
         }
+        if store_config.pruning_policy.is_some() {
+            self.pruning_policy = store_config.pruning_policy;
+        }
+        if store_config.state_node_cache_size.is_some() {
+            self.state_node_cache_size = store_config.state_node_cache_size;
+        }
+        if store_config.state_cache_size.is_some() {
+            self.state_cache_size = store_config.state_cache_size;
+        }
+        if store_config.column_family.is_some() {
+            self.column_family = store_config.column_family;
+        }
+        if !store_config.column_families.is_empty() {
+            self.column_families = store_config.column_families;
+        }
+        if store_config.read_replica_dir.is_some() {
+            self.read_replica_dir = store_config.read_replica_dir;
+        }
 
         Ok(())
     }
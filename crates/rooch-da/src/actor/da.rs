@@ -14,7 +14,9 @@ use futures::StreamExt;
 
 use rooch_config::da_config::{DAConfig, InternalDAServerConfigType};
 
-use crate::messages::{Batch, PutBatchMessage};
+use crate::messages::{
+    Batch, BatchMeta, GetBatchMessage, PutBatchMessage, VerifyBatchInclusionMessage,
+};
 use crate::server::celestia::actor::server::DAServerCelestiaActor;
 use crate::server::celestia::proxy::DAServerCelestiaProxy;
 use crate::server::openda::actor::server::DAServerOpenDAActor;
@@ -123,6 +125,39 @@ impl DAActor {
             Ok(())
         }
     }
+
+    /// Get the batch data of the block from the first server which has it
+    pub async fn get_batch(&self, block_number: u128) -> Result<Option<Vec<u8>>> {
+        let servers = self.internal_servers.servers.read().unwrap().to_vec();
+        for server in servers {
+            match server.get_batch(GetBatchMessage { block_number }).await {
+                Ok(Some(data)) => return Ok(Some(data)),
+                Ok(None) => {}
+                Err(e) => {
+                    log::warn!("{:?}, fail to get batch from da server.", e);
+                }
+            }
+        }
+        Ok(None)
+    }
+
+    /// The batch is included if any server verifies its inclusion
+    pub async fn verify_batch_inclusion(&self, meta: BatchMeta) -> Result<bool> {
+        let servers = self.internal_servers.servers.read().unwrap().to_vec();
+        for server in servers {
+            match server
+                .verify_batch_inclusion(VerifyBatchInclusionMessage { meta: meta.clone() })
+                .await
+            {
+                Ok(true) => return Ok(true),
+                Ok(false) => {}
+                Err(e) => {
+                    log::warn!("{:?}, fail to verify batch inclusion by da server.", e);
+                }
+            }
+        }
+        Ok(false)
+    }
 }
 
 #[async_trait]
@@ -131,3 +166,25 @@ impl Handler<Batch> for DAActor {
         self.submit_batch(msg).await
     }
 }
+
+#[async_trait]
+impl Handler<GetBatchMessage> for DAActor {
+    async fn handle(
+        &mut self,
+        msg: GetBatchMessage,
+        _ctx: &mut ActorContext,
+    ) -> Result<Option<Vec<u8>>> {
+        self.get_batch(msg.block_number).await
+    }
+}
+
+#[async_trait]
+impl Handler<VerifyBatchInclusionMessage> for DAActor {
+    async fn handle(
+        &mut self,
+        msg: VerifyBatchInclusionMessage,
+        _ctx: &mut ActorContext,
+    ) -> Result<bool> {
+        self.verify_batch_inclusion(msg.meta).await
+    }
+}
@@ -4,6 +4,7 @@
 use coerce::actor::message::Message;
 use serde::{Deserialize, Serialize};
 
+use moveos_types::h256;
 use moveos_types::h256::H256;
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -23,6 +24,17 @@ pub struct Batch {
     pub data: Vec<u8>,
 }
 
+impl Batch {
+    /// Check the batch data matches the batch hash of the meta
+    pub fn is_hash_matched(&self) -> bool {
+        is_batch_hash_matched(&self.data, &self.meta)
+    }
+}
+
+pub fn is_batch_hash_matched(data: &[u8], meta: &BatchMeta) -> bool {
+    h256::sha3_256_of(data) == meta.batch_hash
+}
+
 impl Message for Batch {
     type Result = anyhow::Result<()>;
 }
@@ -46,3 +58,23 @@ pub struct PutBatchResult {
     // signature result of PutBatchResult
     pub signature: Vec<u8>,
 }
+
+/// Get the batch data of the block from the DA server, `None` if the batch is not found
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct GetBatchMessage {
+    pub block_number: u128,
+}
+
+impl Message for GetBatchMessage {
+    type Result = anyhow::Result<Option<Vec<u8>>>;
+}
+
+/// Verify the batch is included in the DA backend and the batch data matches the batch hash of the meta
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct VerifyBatchInclusionMessage {
+    pub meta: BatchMeta,
+}
+
+impl Message for VerifyBatchInclusionMessage {
+    type Result = anyhow::Result<bool>;
+}
@@ -4,7 +4,7 @@
 use coerce::actor::ActorRef;
 
 use crate::actor::da::DAActor;
-use crate::messages::Batch;
+use crate::messages::{Batch, BatchMeta, GetBatchMessage, VerifyBatchInclusionMessage};
 
 #[derive(Clone)]
 pub struct DAProxy {
@@ -19,4 +19,14 @@ impl DAProxy {
     pub async fn submit_batch(&self, batch: Batch) -> anyhow::Result<()> {
         self.actor.send(batch).await?
     }
+
+    pub async fn get_batch(&self, block_number: u128) -> anyhow::Result<Option<Vec<u8>>> {
+        self.actor.send(GetBatchMessage { block_number }).await?
+    }
+
+    pub async fn verify_batch_inclusion(&self, meta: BatchMeta) -> anyhow::Result<bool> {
+        self.actor
+            .send(VerifyBatchInclusionMessage { meta })
+            .await?
+    }
 }
@@ -7,16 +7,23 @@ use celestia_types::nmt::Namespace;
 use coerce::actor::context::ActorContext;
 use coerce::actor::message::Handler;
 use coerce::actor::Actor;
+use std::collections::BTreeMap;
 
 use rooch_config::da_config::DAServerCelestiaConfig;
 
-use crate::messages::{PutBatchMessage, PutBatchResult};
+use crate::messages::{
+    is_batch_hash_matched, GetBatchMessage, PutBatchMessage, PutBatchResult,
+    VerifyBatchInclusionMessage,
+};
 use crate::segment::{SegmentID, SegmentV0};
-use crate::server::celestia::backend::Backend;
+use crate::server::celestia::backend::{Backend, SubmitBackendResult};
 
 pub struct DAServerCelestiaActor {
     max_segment_size: usize,
     backend: Backend,
+    // block number -> the submit results of the segments in order
+    // TODO persist the segment index
+    submitted_batches: BTreeMap<u128, Vec<SubmitBackendResult>>,
 }
 
 // TODO get request and response
@@ -38,10 +45,11 @@ impl DAServerCelestiaActor {
         Self {
             max_segment_size: cfg.max_segment_size.unwrap() as usize,
             backend: Backend::new(namespace, &conn_str, &token).await,
+            submitted_batches: BTreeMap::new(),
         }
     }
 
-    pub async fn pub_batch(&self, batch: PutBatchMessage) -> Result<PutBatchResult> {
+    pub async fn pub_batch(&mut self, batch: PutBatchMessage) -> Result<PutBatchResult> {
         // TODO using chunk builder to make segments:
         // 1. persist batch into buffer then return ok
         // 2. collect batch for better compression ratio
@@ -69,13 +77,59 @@ impl DAServerCelestiaActor {
             })
             .collect::<Vec<_>>();
 
+        let mut submit_results = Vec::with_capacity(segments.len());
         for segment in segments {
-            // TODO record ok segment in order
             // TODO segment indexer trait (local file, db, etc)
-            self.backend.submit(Box::new(segment)).await?;
+            submit_results.push(self.backend.submit(Box::new(segment)).await?);
         }
+        self.submitted_batches.insert(chunk_id, submit_results);
         Ok(PutBatchResult::default())
     }
+
+    /// Get the batch data by concatenating the segments of the batch submitted by this server
+    pub async fn get_batch(&self, msg: GetBatchMessage) -> Result<Option<Vec<u8>>> {
+        let Some(submit_results) = self.submitted_batches.get(&msg.block_number) else {
+            return Ok(None);
+        };
+        let mut data = vec![];
+        for submit_result in submit_results {
+            let bytes = self
+                .backend
+                .get(submit_result.height, submit_result.commitment)
+                .await?;
+            let segment = SegmentV0::from_bytes(&bytes)?;
+            if segment.id != submit_result.segment_id {
+                return Err(anyhow::anyhow!(
+                    "unexpected segment {} from celestia, expected: {}",
+                    segment.id,
+                    submit_result.segment_id
+                ));
+            }
+            data.extend(segment.data);
+        }
+        Ok(Some(data))
+    }
+
+    /// Verify all the segments of the batch are included in celestia and the batch data matches the meta
+    pub async fn verify_batch_inclusion(&self, msg: VerifyBatchInclusionMessage) -> Result<bool> {
+        let block_number = msg.meta.block_number;
+        let Some(submit_results) = self.submitted_batches.get(&block_number) else {
+            return Ok(false);
+        };
+        for submit_result in submit_results {
+            if !self
+                .backend
+                .is_included(submit_result.height, submit_result.commitment)
+                .await?
+            {
+                return Ok(false);
+            }
+        }
+        let Some(data) = self.get_batch(GetBatchMessage { block_number }).await? else {
+            return Ok(false);
+        };
+        Ok(is_batch_hash_matched(&data, &msg.meta))
+    }
 }
 
 #[async_trait]
@@ -88,3 +142,25 @@ impl Handler<PutBatchMessage> for DAServerCelestiaActor {
         self.pub_batch(msg).await
     }
 }
+
+#[async_trait]
+impl Handler<GetBatchMessage> for DAServerCelestiaActor {
+    async fn handle(
+        &mut self,
+        msg: GetBatchMessage,
+        _ctx: &mut ActorContext,
+    ) -> Result<Option<Vec<u8>>> {
+        self.get_batch(msg).await
+    }
+}
+
+#[async_trait]
+impl Handler<VerifyBatchInclusionMessage> for DAServerCelestiaActor {
+    async fn handle(
+        &mut self,
+        msg: VerifyBatchInclusionMessage,
+        _ctx: &mut ActorContext,
+    ) -> Result<bool> {
+        self.verify_batch_inclusion(msg).await
+    }
+}
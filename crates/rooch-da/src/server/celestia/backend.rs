@@ -14,6 +14,7 @@ pub struct Backend {
     client: Client,
 }
 
+#[derive(Debug, Clone)]
 pub struct SubmitBackendResult {
     pub segment_id: SegmentID,
     pub namespace: Namespace,
@@ -63,4 +64,34 @@ impl Backend {
             }
         }
     }
+
+    /// Get the segment bytes of the blob submitted at the height
+    pub async fn get(&self, height: u64, commitment: Commitment) -> Result<Vec<u8>> {
+        let blob = self
+            .client
+            .blob_get(height, self.namespace, commitment)
+            .await?;
+        Ok(blob.data)
+    }
+
+    /// Check the blob submitted at the height is included in the namespace by the inclusion proofs
+    pub async fn is_included(&self, height: u64, commitment: Commitment) -> Result<bool> {
+        let proofs = self
+            .client
+            .blob_get_proof(height, self.namespace, commitment)
+            .await?;
+        if proofs.is_empty() {
+            return Ok(false);
+        }
+        for proof in proofs.iter() {
+            if !self
+                .client
+                .blob_included(height, self.namespace, proof, commitment)
+                .await?
+            {
+                return Ok(false);
+            }
+        }
+        Ok(true)
+    }
 }
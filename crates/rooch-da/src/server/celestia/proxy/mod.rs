@@ -4,7 +4,9 @@
 use async_trait::async_trait;
 use coerce::actor::ActorRef;
 
-use crate::messages::{PutBatchMessage, PutBatchResult};
+use crate::messages::{
+    GetBatchMessage, PutBatchMessage, PutBatchResult, VerifyBatchInclusionMessage,
+};
 use crate::server::celestia::actor::server::DAServerCelestiaActor;
 use crate::server::serverproxy::DAServerProxy;
 
@@ -21,6 +23,14 @@ impl DAServerCelestiaProxy {
     pub async fn submit_batch(&self, msg: PutBatchMessage) -> anyhow::Result<PutBatchResult> {
         self.actor.send(msg).await?
     }
+
+    pub async fn fetch_batch(&self, msg: GetBatchMessage) -> anyhow::Result<Option<Vec<u8>>> {
+        self.actor.send(msg).await?
+    }
+
+    pub async fn verify_inclusion(&self, msg: VerifyBatchInclusionMessage) -> anyhow::Result<bool> {
+        self.actor.send(msg).await?
+    }
 }
 
 #[async_trait]
@@ -28,4 +38,15 @@ impl DAServerProxy for DAServerCelestiaProxy {
     async fn put_batch(&self, msg: PutBatchMessage) -> anyhow::Result<PutBatchResult> {
         self.submit_batch(msg).await
     }
+
+    async fn get_batch(&self, msg: GetBatchMessage) -> anyhow::Result<Option<Vec<u8>>> {
+        self.fetch_batch(msg).await
+    }
+
+    async fn verify_batch_inclusion(
+        &self,
+        msg: VerifyBatchInclusionMessage,
+    ) -> anyhow::Result<bool> {
+        self.verify_inclusion(msg).await
+    }
 }
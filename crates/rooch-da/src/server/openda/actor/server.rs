@@ -7,14 +7,17 @@ use coerce::actor::context::ActorContext;
 use coerce::actor::message::Handler;
 use coerce::actor::Actor;
 use opendal::layers::RetryLayer;
-use opendal::{Operator, Scheme};
+use opendal::{ErrorKind, Operator, Scheme};
 use std::collections::HashMap;
 use std::path::Path;
 use xxhash_rust::xxh3::xxh3_64;
 
 use rooch_config::da_config::{DAServerOpenDAConfig, OpenDAScheme};
 
-use crate::messages::{PutBatchMessage, PutBatchResult};
+use crate::messages::{
+    is_batch_hash_matched, GetBatchMessage, PutBatchMessage, PutBatchResult,
+    VerifyBatchInclusionMessage,
+};
 use crate::segment::{Segment, SegmentID, SegmentV0, SEGMENT_V0_CHECKSUM_OFFSET};
 
 pub struct DAServerOpenDAActor {
@@ -157,6 +160,44 @@ impl DAServerOpenDAActor {
 
         Ok(PutBatchResult::default())
     }
+
+    /// Get the batch data by reading the segments of the chunk in order until the last one
+    pub async fn get_batch(&self, msg: GetBatchMessage) -> Result<Option<Vec<u8>>> {
+        let mut data = vec![];
+        let mut segment_number = 0;
+        loop {
+            let segment_id = SegmentID {
+                chunk_id: msg.block_number,
+                segment_number,
+            };
+            let bytes = match self.operator.read(&segment_id.to_string()).await {
+                Ok(bytes) => bytes,
+                Err(e) if e.kind() == ErrorKind::NotFound && segment_number == 0 => {
+                    return Ok(None)
+                }
+                Err(e) => return Err(e.into()),
+            };
+            let segment = SegmentV0::from_bytes(&bytes)?;
+            if segment.data_checksum != xxh3_64(&segment.data) {
+                return Err(anyhow!("segment {} data checksum mismatch", segment_id));
+            }
+            data.extend(segment.data);
+            if segment.is_last {
+                return Ok(Some(data));
+            }
+            segment_number += 1;
+        }
+    }
+
+    /// The storage backends have no inclusion proofs, the batch is included if it could be read and matches the meta
+    pub async fn verify_batch_inclusion(&self, msg: VerifyBatchInclusionMessage) -> Result<bool> {
+        let data = self
+            .get_batch(GetBatchMessage {
+                block_number: msg.meta.block_number,
+            })
+            .await?;
+        Ok(data.is_some_and(|data| is_batch_hash_matched(&data, &msg.meta)))
+    }
 }
 
 fn insert_default_from_env_or_const(
@@ -209,3 +250,25 @@ impl Handler<PutBatchMessage> for DAServerOpenDAActor {
         self.pub_batch(msg).await
     }
 }
+
+#[async_trait]
+impl Handler<GetBatchMessage> for DAServerOpenDAActor {
+    async fn handle(
+        &mut self,
+        msg: GetBatchMessage,
+        _ctx: &mut ActorContext,
+    ) -> Result<Option<Vec<u8>>> {
+        self.get_batch(msg).await
+    }
+}
+
+#[async_trait]
+impl Handler<VerifyBatchInclusionMessage> for DAServerOpenDAActor {
+    async fn handle(
+        &mut self,
+        msg: VerifyBatchInclusionMessage,
+        _ctx: &mut ActorContext,
+    ) -> Result<bool> {
+        self.verify_batch_inclusion(msg).await
+    }
+}
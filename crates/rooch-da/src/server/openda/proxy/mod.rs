@@ -1,7 +1,9 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::messages::{PutBatchMessage, PutBatchResult};
+use crate::messages::{
+    GetBatchMessage, PutBatchMessage, PutBatchResult, VerifyBatchInclusionMessage,
+};
 use crate::server::openda::actor::server::DAServerOpenDAActor;
 use crate::server::serverproxy::DAServerProxy;
 use async_trait::async_trait;
@@ -20,6 +22,14 @@ impl DAServerOpenDAProxy {
     pub async fn submit_batch(&self, msg: PutBatchMessage) -> anyhow::Result<PutBatchResult> {
         self.actor.send(msg).await?
     }
+
+    pub async fn fetch_batch(&self, msg: GetBatchMessage) -> anyhow::Result<Option<Vec<u8>>> {
+        self.actor.send(msg).await?
+    }
+
+    pub async fn verify_inclusion(&self, msg: VerifyBatchInclusionMessage) -> anyhow::Result<bool> {
+        self.actor.send(msg).await?
+    }
 }
 
 #[async_trait]
@@ -27,4 +37,15 @@ impl DAServerProxy for DAServerOpenDAProxy {
     async fn put_batch(&self, msg: PutBatchMessage) -> anyhow::Result<PutBatchResult> {
         self.submit_batch(msg).await
     }
+
+    async fn get_batch(&self, msg: GetBatchMessage) -> anyhow::Result<Option<Vec<u8>>> {
+        self.fetch_batch(msg).await
+    }
+
+    async fn verify_batch_inclusion(
+        &self,
+        msg: VerifyBatchInclusionMessage,
+    ) -> anyhow::Result<bool> {
+        self.verify_inclusion(msg).await
+    }
 }
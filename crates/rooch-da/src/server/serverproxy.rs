@@ -4,11 +4,17 @@
 use anyhow::Result;
 use async_trait::async_trait;
 
-use crate::messages::{PutBatchMessage, PutBatchResult};
+use crate::messages::{
+    GetBatchMessage, PutBatchMessage, PutBatchResult, VerifyBatchInclusionMessage,
+};
 
 #[async_trait]
 pub trait DAServerProxy: Sync + Send {
     async fn put_batch(&self, request: PutBatchMessage) -> Result<PutBatchResult>;
+    /// Retrieve the batch data from the DA server, `None` if the batch is not found
+    async fn get_batch(&self, request: GetBatchMessage) -> Result<Option<Vec<u8>>>;
+    /// Verify the batch is included in the DA backend and the data matches the batch hash
+    async fn verify_batch_inclusion(&self, request: VerifyBatchInclusionMessage) -> Result<bool>;
 }
 
 // DAServerNopProxy is a no-op implementation of DAServerProxy
@@ -19,4 +25,12 @@ impl DAServerProxy for DAServerNopProxy {
     async fn put_batch(&self, _request: PutBatchMessage) -> Result<PutBatchResult> {
         Ok(PutBatchResult::default())
     }
+
+    async fn get_batch(&self, _request: GetBatchMessage) -> Result<Option<Vec<u8>>> {
+        Ok(None)
+    }
+
+    async fn verify_batch_inclusion(&self, _request: VerifyBatchInclusionMessage) -> Result<bool> {
+        Ok(false)
+    }
 }
@@ -2,7 +2,8 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use super::messages::{
-    ExecuteTransactionMessage, ExecuteTransactionResult, ResolveMessage, ValidateTransactionMessage,
+    ExecuteTransactionMessage, ExecuteTransactionResult, PruneStatesTick, ResolveMessage,
+    ValidateTransactionMessage, ValidateUnsignedTransactionMessage,
 };
 use accumulator::inmemory::InMemoryAccumulator;
 use anyhow::Result;
@@ -19,6 +20,8 @@ use move_core_types::vm_status::{StatusCode, VMStatus};
 use moveos::gas::table::{get_gas_schedule_entries, initial_cost_schedule, MoveOSGasMeter};
 use moveos::moveos::{GasPaymentAccount, MoveOS};
 use moveos::vm::vm_status_explainer::explain_vm_status;
+use moveos_config::store_config::PruningPolicy;
+use moveos_store::state_store::pruner::StatePruner;
 use moveos_store::transaction_store::TransactionStore;
 use moveos_store::MoveOSStore;
 use moveos_types::genesis_info::GenesisInfo;
@@ -35,6 +38,8 @@ use moveos_types::transaction::{
 use moveos_verifier::metadata::load_module_metadata;
 use rooch_framework::natives::gas_parameter::gas_member::FromOnChainGasSchedule;
 use rooch_genesis::RoochGenesis;
+use rooch_store::meta_store::MetaStore;
+use rooch_store::transaction_store::TransactionStore as SequencerTransactionStore;
 use rooch_store::RoochStore;
 use rooch_types::address::MultiChainAddress;
 use rooch_types::bitcoin::genesis::BitcoinGenesisContext;
@@ -43,6 +48,7 @@ use rooch_types::framework::auth_validator::{AuthValidatorCaller, TxValidateResu
 use rooch_types::framework::genesis::GenesisContext;
 use rooch_types::framework::transaction_validator::TransactionValidator;
 use rooch_types::framework::{system_post_execute_functions, system_pre_execute_functions};
+use rooch_types::transaction::rooch::RoochTransactionData;
 use rooch_types::transaction::{AbstractTransaction, AuthenticatorInfo};
 
 pub struct ExecutorActor {
@@ -51,7 +57,7 @@ pub struct ExecutorActor {
     rooch_store: RoochStore,
 }
 
-type ValidateAuthenticatorResult = Result<
+pub type ValidateAuthenticatorResult = Result<
     (
         TxValidateResult,
         Option<MultiChainAddress>,
@@ -158,10 +164,46 @@ impl ExecutorActor {
 
     pub fn execute(&mut self, tx: VerifiedMoveOSTransaction) -> Result<ExecuteTransactionResult> {
         let tx_hash = tx.ctx.tx_hash();
+        fail::fail_point!(crate::failpoints::EXECUTOR_BEFORE_EXECUTE);
         let (state_root, output) = self.moveos.execute_and_apply(tx)?;
+        fail::fail_point!(crate::failpoints::EXECUTOR_AFTER_APPLY);
         self.handle_tx_output(tx_hash, state_root, output)
     }
 
+    /// Prune the state nodes which are not reachable from the state roots retained by the `policy`.
+    /// The pruning runs in the executor actor, so it does not race with the transaction execution.
+    pub fn prune_states(&self, policy: PruningPolicy) -> Result<usize> {
+        if policy.is_archive() {
+            return Ok(0);
+        }
+        let latest_tx_order = match self.rooch_store.get_sequencer_order()? {
+            Some(sequencer_order) => sequencer_order.last_order,
+            None => return Ok(0),
+        };
+        let retained_tx_orders = (0..=latest_tx_order)
+            .filter(|tx_order| policy.should_retain(*tx_order, latest_tx_order))
+            .collect::<Vec<_>>();
+        let tx_hashes = self
+            .rooch_store
+            .get_tx_sequence_info_mapping_by_order(retained_tx_orders)?
+            .into_iter()
+            .flatten()
+            .map(|mapping| mapping.tx_hash)
+            .collect::<Vec<_>>();
+        let mut retained_roots = self
+            .moveos()
+            .transaction_store()
+            .multi_get_tx_execution_infos(tx_hashes)?
+            .into_iter()
+            .flatten()
+            .map(|execution_info| execution_info.state_root)
+            .collect::<Vec<_>>();
+        retained_roots.push(self.moveos().state().state_root());
+
+        let pruner = StatePruner::new(self.moveos().state().node_store.clone());
+        pruner.prune(retained_roots)
+    }
+
     fn handle_tx_output(
         &mut self,
         tx_hash: H256,
@@ -194,6 +236,8 @@ impl ExecutorActor {
         })
     }
 
+    /// Validate the authenticator and the gas of the transaction. The expiration is not checked here, it is checked when
+    /// the transaction is admitted by the RPC service and the sequencer, so the replay of the history is not affected.
     pub fn validate<T: AbstractTransaction>(&self, tx: T) -> Result<VerifiedMoveOSTransaction> {
         let multi_chain_address_sender = tx.sender();
 
@@ -276,59 +320,42 @@ impl ExecutorActor {
         }
     }
 
+    /// Validate the unsigned transaction without the authenticator, only used by dry run.
+    /// The sender pays the gas, and no session key is activated.
+    pub fn validate_unsigned(
+        &self,
+        tx_data: RoochTransactionData,
+    ) -> Result<VerifiedMoveOSTransaction> {
+        let sender = MultiChainAddress::from(tx_data.sender);
+        let mut moveos_tx: MoveOSTransaction = tx_data.into();
+        // The tx context is built from the tx data of the client, so the error is returned rather than panicking
+        moveos_tx
+            .ctx
+            .add(GasPaymentAccount {
+                account: moveos_tx.ctx.sender,
+                pay_gas_by_module_account: false,
+            })
+            .map_err(|e| {
+                anyhow::anyhow!(
+                    "Validate unsigned transaction failed, add the gas payment account to the tx context: {}",
+                    e
+                )
+            })?;
+        moveos_tx.ctx.add(sender).map_err(|e| {
+            anyhow::anyhow!(
+                "Validate unsigned transaction failed, add the sender to the tx context: {}",
+                e
+            )
+        })?;
+        Ok(self.moveos().verify(moveos_tx)?)
+    }
+
     pub fn validate_authenticator(
         &self,
         ctx: &TxContext,
         authenticator: AuthenticatorInfo,
     ) -> Result<ValidateAuthenticatorResult> {
-        let tx_validator = self.moveos().as_module_binding::<TransactionValidator>();
-        let tx_validate_function_result = tx_validator
-            .validate(ctx, authenticator.clone())?
-            .into_result();
-
-        let vm_result = match tx_validate_function_result {
-            Ok(tx_validate_result) => {
-                let auth_validator_option = tx_validate_result.auth_validator();
-                match auth_validator_option {
-                    Some(auth_validator) => {
-                        let auth_validator_caller =
-                            AuthValidatorCaller::new(self.moveos(), auth_validator);
-                        let auth_validator_function_result = auth_validator_caller
-                            .validate(ctx, authenticator.authenticator.payload)?
-                            .into_result();
-                        match auth_validator_function_result {
-                            Ok(multi_chain_address) => {
-                                // pre_execute_function: AuthValidator
-                                let pre_execute_functions =
-                                    vec![auth_validator_caller.pre_execute_function_call()];
-                                // post_execute_function: AuthValidator
-                                let post_execute_functions =
-                                    vec![auth_validator_caller.post_execute_function_call()];
-                                Ok((
-                                    tx_validate_result,
-                                    multi_chain_address,
-                                    pre_execute_functions,
-                                    post_execute_functions,
-                                ))
-                            }
-                            Err(vm_status) => Err(vm_status),
-                        }
-                    }
-                    None => {
-                        let pre_execute_functions = vec![];
-                        let post_execute_functions = vec![];
-                        Ok((
-                            tx_validate_result,
-                            None,
-                            pre_execute_functions,
-                            post_execute_functions,
-                        ))
-                    }
-                }
-            }
-            Err(vm_status) => Err(vm_status),
-        };
-        Ok(vm_result)
+        validate_authenticator(self.moveos(), ctx, authenticator)
     }
 
     pub fn validate_gas_function(&self, tx: &MoveOSTransaction) -> VMResult<Option<bool>> {
@@ -526,6 +553,17 @@ where
     }
 }
 
+#[async_trait]
+impl Handler<ValidateUnsignedTransactionMessage> for ExecutorActor {
+    async fn handle(
+        &mut self,
+        msg: ValidateUnsignedTransactionMessage,
+        _ctx: &mut ActorContext,
+    ) -> Result<VerifiedMoveOSTransaction> {
+        self.validate_unsigned(msg.tx_data)
+    }
+}
+
 #[async_trait]
 impl Handler<ExecuteTransactionMessage> for ExecutorActor {
     async fn handle(
@@ -536,3 +574,73 @@ impl Handler<ExecuteTransactionMessage> for ExecutorActor {
         self.execute(msg.tx)
     }
 }
+
+#[async_trait]
+impl Handler<PruneStatesTick> for ExecutorActor {
+    async fn handle(&mut self, msg: PruneStatesTick, _ctx: &mut ActorContext) {
+        match self.prune_states(msg.policy) {
+            Ok(pruned) => log::info!(
+                "[PruneStates] policy: {}, pruned state nodes: {}",
+                msg.policy,
+                pruned
+            ),
+            Err(e) => log::error!("[PruneStates] policy: {}, error: {:?}", msg.policy, e),
+        }
+    }
+}
+
+/// Validate the authenticator of the sender in the context via the transaction validator and the auth validator,
+/// the signature is verified against the tx hash of the context.
+pub fn validate_authenticator(
+    moveos: &MoveOS,
+    ctx: &TxContext,
+    authenticator: AuthenticatorInfo,
+) -> Result<ValidateAuthenticatorResult> {
+    let tx_validator = moveos.as_module_binding::<TransactionValidator>();
+    let tx_validate_function_result = tx_validator
+        .validate(ctx, authenticator.clone())?
+        .into_result();
+
+    let vm_result = match tx_validate_function_result {
+        Ok(tx_validate_result) => {
+            let auth_validator_option = tx_validate_result.auth_validator();
+            match auth_validator_option {
+                Some(auth_validator) => {
+                    let auth_validator_caller = AuthValidatorCaller::new(moveos, auth_validator);
+                    let auth_validator_function_result = auth_validator_caller
+                        .validate(ctx, authenticator.authenticator.payload)?
+                        .into_result();
+                    match auth_validator_function_result {
+                        Ok(multi_chain_address) => {
+                            // pre_execute_function: AuthValidator
+                            let pre_execute_functions =
+                                vec![auth_validator_caller.pre_execute_function_call()];
+                            // post_execute_function: AuthValidator
+                            let post_execute_functions =
+                                vec![auth_validator_caller.post_execute_function_call()];
+                            Ok((
+                                tx_validate_result,
+                                multi_chain_address,
+                                pre_execute_functions,
+                                post_execute_functions,
+                            ))
+                        }
+                        Err(vm_status) => Err(vm_status),
+                    }
+                }
+                None => {
+                    let pre_execute_functions = vec![];
+                    let post_execute_functions = vec![];
+                    Ok((
+                        tx_validate_result,
+                        None,
+                        pre_execute_functions,
+                        post_execute_functions,
+                    ))
+                }
+            }
+        }
+        Err(vm_status) => Err(vm_status),
+    };
+    Ok(vm_result)
+}
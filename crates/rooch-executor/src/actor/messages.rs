@@ -3,21 +3,29 @@
 
 use anyhow::Result;
 use coerce::actor::message::Message;
+use coerce::actor::scheduler::timer::TimerTick;
 use move_core_types::account_address::AccountAddress;
 use move_core_types::language_storage::StructTag;
+use moveos_config::store_config::PruningPolicy;
 use moveos_types::access_path::AccessPath;
 use moveos_types::function_return_value::AnnotatedFunctionResult;
 use moveos_types::h256::H256;
 use moveos_types::moveos_std::event::{AnnotatedEvent, Event, EventID};
 use moveos_types::state::{AnnotatedState, KeyState, State};
+use moveos_types::state_proof::StateProof;
 use moveos_types::state_resolver::{AnnotatedStateKV, StateKV};
 use moveos_types::transaction::FunctionCall;
+use moveos_types::transaction::RawTransactionOutput;
 use moveos_types::transaction::TransactionExecutionInfo;
 use moveos_types::transaction::TransactionOutput;
 use moveos_types::transaction::VerifiedMoveOSTransaction;
+use raw_store::rocks::RocksDB;
 use rooch_types::address::MultiChainAddress;
+use rooch_types::transaction::rooch::RoochTransactionData;
+use rooch_types::transaction::view::SignedViewFunctionCall;
 use rooch_types::transaction::AbstractTransaction;
 use serde::{Deserialize, Serialize};
+use std::sync::Arc;
 
 #[derive(Debug)]
 pub struct ValidateTransactionMessage<T> {
@@ -31,6 +39,26 @@ where
     type Result = Result<VerifiedMoveOSTransaction>;
 }
 
+#[derive(Debug)]
+pub struct ValidateUnsignedTransactionMessage {
+    pub tx_data: RoochTransactionData,
+}
+
+impl Message for ValidateUnsignedTransactionMessage {
+    type Result = Result<VerifiedMoveOSTransaction>;
+}
+
+#[derive(Debug)]
+pub struct DryRunTransactionMessage {
+    pub tx: VerifiedMoveOSTransaction,
+    /// Collect the gas breakdown of the execution
+    pub gas_profile: bool,
+}
+
+impl Message for DryRunTransactionMessage {
+    type Result = Result<RawTransactionOutput>;
+}
+
 #[derive(Debug)]
 pub struct ExecuteTransactionMessage {
     pub tx: VerifiedMoveOSTransaction,
@@ -46,6 +74,29 @@ impl Message for ExecuteTransactionMessage {
     type Result = Result<ExecuteTransactionResult>;
 }
 
+#[derive(Debug, Clone)]
+pub struct PruneStatesTick {
+    pub policy: PruningPolicy,
+}
+
+impl Message for PruneStatesTick {
+    type Result = ();
+}
+
+impl TimerTick for PruneStatesTick {}
+
+/// Catch up the secondary dbs of the read replica with the primary node, and refresh the state to the latest state root
+#[derive(Clone)]
+pub struct CatchUpPrimaryTick {
+    pub dbs: Vec<Arc<RocksDB>>,
+}
+
+impl Message for CatchUpPrimaryTick {
+    type Result = ();
+}
+
+impl TimerTick for CatchUpPrimaryTick {}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct ExecuteViewFunctionMessage {
     pub call: FunctionCall,
@@ -55,8 +106,20 @@ impl Message for ExecuteViewFunctionMessage {
     type Result = Result<AnnotatedFunctionResult, anyhow::Error>;
 }
 
+/// Execute the view function with the verified sender of the signed call
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ExecuteSignedViewFunctionMessage {
+    pub call: SignedViewFunctionCall,
+}
+
+impl Message for ExecuteSignedViewFunctionMessage {
+    type Result = Result<AnnotatedFunctionResult, anyhow::Error>;
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct StatesMessage {
+    /// Read the states at the given state root, default to the current state root
+    pub state_root: Option<H256>,
     pub access_path: AccessPath,
 }
 
@@ -64,6 +127,17 @@ impl Message for StatesMessage {
     type Result = Result<Vec<Option<State>>>;
 }
 
+#[derive(Debug, Serialize, Deserialize)]
+pub struct StatesWithProofMessage {
+    /// The global state root to read from, use the latest state root if it is `None`
+    pub state_root: Option<H256>,
+    pub access_path: AccessPath,
+}
+
+impl Message for StatesWithProofMessage {
+    type Result = Result<(H256, Vec<(Option<State>, StateProof)>)>;
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct ResolveMessage {
     pub address: MultiChainAddress,
@@ -75,6 +149,8 @@ impl Message for ResolveMessage {
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct AnnotatedStatesMessage {
+    /// Read the states at the given state root, default to the current state root
+    pub state_root: Option<H256>,
     pub access_path: AccessPath,
 }
 
@@ -84,6 +160,8 @@ impl Message for AnnotatedStatesMessage {
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct ListStatesMessage {
+    /// Read the states at the given state root, default to the current state root
+    pub state_root: Option<H256>,
     pub access_path: AccessPath,
     pub cursor: Option<KeyState>,
     pub limit: usize,
@@ -95,6 +173,8 @@ impl Message for ListStatesMessage {
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct ListAnnotatedStatesMessage {
+    /// Read the states at the given state root, default to the current state root
+    pub state_root: Option<H256>,
     pub access_path: AccessPath,
     pub cursor: Option<KeyState>,
     pub limit: usize,
@@ -109,6 +189,9 @@ pub struct GetAnnotatedEventsByEventHandleMessage {
     pub event_handle_type: StructTag,
     pub cursor: Option<u64>,
     pub limit: u64,
+    pub descending_order: bool,
+    pub from_seq: Option<u64>,
+    pub to_seq: Option<u64>,
 }
 
 impl Message for GetAnnotatedEventsByEventHandleMessage {
@@ -120,6 +203,9 @@ pub struct GetEventsByEventHandleMessage {
     pub event_handle_type: StructTag,
     pub cursor: Option<u64>,
     pub limit: u64,
+    pub descending_order: bool,
+    pub from_seq: Option<u64>,
+    pub to_seq: Option<u64>,
 }
 
 impl Message for GetEventsByEventHandleMessage {
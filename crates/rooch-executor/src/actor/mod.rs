@@ -4,3 +4,4 @@
 pub mod executor;
 pub mod messages;
 pub mod reader_executor;
+pub mod state_cache;
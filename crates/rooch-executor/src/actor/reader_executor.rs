@@ -2,35 +2,53 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use super::messages::{
-    AnnotatedStatesMessage, ExecuteViewFunctionMessage, GetAnnotatedEventsByEventHandleMessage,
-    GetAnnotatedStatesByStateMessage, GetEventsByEventHandleMessage, RefreshStateMessage,
-    StatesMessage,
+    AnnotatedStatesMessage, CatchUpPrimaryTick, DryRunTransactionMessage,
+    ExecuteSignedViewFunctionMessage, ExecuteViewFunctionMessage,
+    GetAnnotatedEventsByEventHandleMessage, GetAnnotatedStatesByStateMessage,
+    GetEventsByEventHandleMessage, RefreshStateMessage, StatesMessage, StatesWithProofMessage,
 };
+use crate::actor::executor::validate_authenticator;
 use crate::actor::messages::{
     GetEventsByEventIDsMessage, GetTxExecutionInfosByHashMessage, ListAnnotatedStatesMessage,
     ListStatesMessage,
 };
+use crate::actor::state_cache::StateCache;
 use anyhow::Result;
 use async_trait::async_trait;
 use coerce::actor::{context::ActorContext, message::Handler, Actor};
 use move_resource_viewer::MoveValueAnnotator;
 use moveos::moveos::MoveOS;
+use moveos::vm::vm_status_explainer::explain_vm_status;
+use moveos_common::utils::now_seconds;
+use moveos_store::state_store::statedb::StateDBStore;
 use moveos_store::transaction_store::TransactionStore;
 use moveos_store::MoveOSStore;
 use moveos_types::function_return_value::AnnotatedFunctionResult;
 use moveos_types::function_return_value::AnnotatedFunctionReturnValue;
+use moveos_types::function_return_value::FunctionResult;
+use moveos_types::gas_config::GasConfig;
+use moveos_types::h256::H256;
+use moveos_types::module_binding::MoveFunctionCaller;
 use moveos_types::moveos_std::event::EventHandle;
 use moveos_types::moveos_std::event::{AnnotatedEvent, Event};
 use moveos_types::state::{AnnotatedState, State};
-use moveos_types::state_resolver::{AnnotatedStateKV, AnnotatedStateReader, StateKV, StateReader};
-use moveos_types::transaction::TransactionExecutionInfo;
+use moveos_types::state_proof::StateProof;
+use moveos_types::state_resolver::{
+    AnnotatedStateKV, AnnotatedStateReader, MoveOSResolverProxy, StateKV, StateReader,
+};
+use moveos_types::transaction::{RawTransactionOutput, TransactionExecutionInfo};
+use raw_store::rocks::RocksDB;
+use rooch_config::store_config::DEFAULT_STATE_CACHE_SIZE;
 use rooch_genesis::RoochGenesis;
 use rooch_store::RoochStore;
+use rooch_types::account::AccountModule;
 use rooch_types::framework::{system_post_execute_functions, system_pre_execute_functions};
+use std::sync::Arc;
 
 pub struct ReaderExecutorActor {
     moveos: MoveOS,
     rooch_store: RoochStore,
+    state_cache: StateCache,
 }
 
 impl ReaderExecutorActor {
@@ -38,6 +56,21 @@ impl ReaderExecutorActor {
         genesis: RoochGenesis,
         moveos_store: MoveOSStore,
         rooch_store: RoochStore,
+    ) -> Result<Self> {
+        Self::new_with_state_cache_size(
+            genesis,
+            moveos_store,
+            rooch_store,
+            DEFAULT_STATE_CACHE_SIZE,
+        )
+    }
+
+    /// Create the actor with a state cache of `state_cache_size` entries, the cache is disabled if the size is 0
+    pub fn new_with_state_cache_size(
+        genesis: RoochGenesis,
+        moveos_store: MoveOSStore,
+        rooch_store: RoochStore,
+        state_cache_size: usize,
     ) -> Result<Self> {
         let moveos = MoveOS::new(
             moveos_store,
@@ -50,6 +83,7 @@ impl ReaderExecutorActor {
         Ok(Self {
             moveos,
             rooch_store,
+            state_cache: StateCache::new(state_cache_size),
         })
     }
 
@@ -60,20 +94,30 @@ impl ReaderExecutorActor {
     pub fn moveos(&self) -> &MoveOS {
         &self.moveos
     }
-}
 
-impl Actor for ReaderExecutorActor {}
+    /// Catch up the secondary dbs with the primary node, and refresh the state if the state root of the primary is changed.
+    /// The modules may be upgraded by the new transactions, so the loader cache is invalidated at the same time.
+    fn catch_up_primary(&mut self, dbs: Vec<Arc<RocksDB>>) -> Result<()> {
+        for db in dbs {
+            db.try_catch_up_with_primary()?;
+        }
+        let Some(startup_info) = self.moveos().config_store().get_startup_info()? else {
+            return Ok(());
+        };
+        if startup_info.state_root_hash != self.moveos().state().state_root() {
+            self.state_cache.invalidate();
+            self.moveos
+                .refresh_state(startup_info.state_root_hash, true)?;
+        }
+        Ok(())
+    }
 
-#[async_trait]
-impl Handler<ExecuteViewFunctionMessage> for ReaderExecutorActor {
-    async fn handle(
-        &mut self,
-        msg: ExecuteViewFunctionMessage,
-        _ctx: &mut ActorContext,
-    ) -> Result<AnnotatedFunctionResult, anyhow::Error> {
+    /// Decode the return values of the function result with the types
+    fn annotate_function_result(
+        &self,
+        function_result: FunctionResult,
+    ) -> Result<AnnotatedFunctionResult> {
         let resoler = self.moveos().moveos_resolver();
-
-        let function_result = self.moveos().execute_view_function(msg.call);
         Ok(AnnotatedFunctionResult {
             vm_status: function_result.vm_status,
             return_values: match function_result.return_values {
@@ -93,6 +137,82 @@ impl Handler<ExecuteViewFunctionMessage> for ReaderExecutorActor {
             },
         })
     }
+
+    /// The resolver reads the historical states at the `state_root`,
+    /// the reading fails if the state nodes of the `state_root` have been pruned.
+    fn resolver_at(&self, state_root: H256) -> MoveOSResolverProxy<StateDBStore> {
+        MoveOSResolverProxy(StateDBStore::new_with_root(
+            self.moveos().state().node_store.clone(),
+            Some(state_root),
+        ))
+    }
+}
+
+impl Actor for ReaderExecutorActor {}
+
+#[async_trait]
+impl Handler<ExecuteViewFunctionMessage> for ReaderExecutorActor {
+    async fn handle(
+        &mut self,
+        msg: ExecuteViewFunctionMessage,
+        _ctx: &mut ActorContext,
+    ) -> Result<AnnotatedFunctionResult, anyhow::Error> {
+        let function_result = self.moveos().execute_view_function(msg.call);
+        self.annotate_function_result(function_result)
+    }
+}
+
+#[async_trait]
+impl Handler<ExecuteSignedViewFunctionMessage> for ReaderExecutorActor {
+    async fn handle(
+        &mut self,
+        msg: ExecuteSignedViewFunctionMessage,
+        _ctx: &mut ActorContext,
+    ) -> Result<AnnotatedFunctionResult, anyhow::Error> {
+        let signed_call = msg.call;
+        signed_call.check_expiration(now_seconds())?;
+        let sender = signed_call.sender();
+        let sequence_number = self
+            .moveos()
+            .as_module_binding::<AccountModule>()
+            .sequence_number(sender.into())?;
+
+        // The signed call does not pay gas, the gas check of the transaction validator is skipped by the zero max gas amount
+        let validate_ctx = signed_call.tx_context(sequence_number, 0);
+        if let Err(vm_status) = validate_authenticator(
+            self.moveos(),
+            &validate_ctx,
+            signed_call.authenticator_info(),
+        )? {
+            return Err(anyhow::anyhow!(
+                "Authenticate the view function call of {} failed: {:?}",
+                sender,
+                explain_vm_status(self.moveos().moveos_resolver(), vm_status)?
+            ));
+        }
+
+        let tx_context = signed_call.tx_context(sequence_number, GasConfig::DEFAULT_MAX_GAS_AMOUNT);
+        let function_result = self
+            .moveos()
+            .execute_readonly_function(&tx_context, signed_call.data.call);
+        self.annotate_function_result(function_result)
+    }
+}
+
+#[async_trait]
+impl Handler<DryRunTransactionMessage> for ReaderExecutorActor {
+    async fn handle(
+        &mut self,
+        msg: DryRunTransactionMessage,
+        _ctx: &mut ActorContext,
+    ) -> Result<RawTransactionOutput, anyhow::Error> {
+        // Execute against the latest state without applying the output
+        if msg.gas_profile {
+            self.moveos().execute_with_gas_profile(msg.tx)
+        } else {
+            self.moveos().execute(msg.tx)
+        }
+    }
 }
 
 #[async_trait]
@@ -102,8 +222,54 @@ impl Handler<StatesMessage> for ReaderExecutorActor {
         msg: StatesMessage,
         _ctx: &mut ActorContext,
     ) -> Result<Vec<Option<State>>, anyhow::Error> {
-        let statedb = self.moveos().moveos_resolver();
-        statedb.get_states(msg.access_path)
+        let StatesMessage {
+            state_root,
+            access_path,
+        } = msg;
+        let cache_root = state_root.unwrap_or_else(|| self.moveos().state().state_root());
+        if let Some(states) = self.state_cache.get_states(cache_root, &access_path) {
+            return Ok(states);
+        }
+        let states = match state_root {
+            Some(state_root) => self
+                .resolver_at(state_root)
+                .get_states(access_path.clone())?,
+            None => self
+                .moveos()
+                .moveos_resolver()
+                .get_states(access_path.clone())?,
+        };
+        self.state_cache
+            .put_states(cache_root, access_path, &states);
+        Ok(states)
+    }
+}
+
+#[async_trait]
+impl Handler<StatesWithProofMessage> for ReaderExecutorActor {
+    async fn handle(
+        &mut self,
+        msg: StatesWithProofMessage,
+        _ctx: &mut ActorContext,
+    ) -> Result<(H256, Vec<(Option<State>, StateProof)>), anyhow::Error> {
+        let StatesWithProofMessage {
+            state_root,
+            access_path,
+        } = msg;
+        let statedb = match state_root {
+            Some(state_root) => StateDBStore::new_with_root(
+                self.moveos().state().node_store.clone(),
+                Some(state_root),
+            ),
+            None => self.moveos().state().clone(),
+        };
+        let (handle, keys) = access_path.into_table_query();
+        let keys = keys.ok_or_else(|| anyhow::anyhow!("AccessPath invalid path"))?;
+        let states = keys
+            .into_iter()
+            .map(|key| statedb.get_with_proof(handle, key))
+            .collect::<Result<Vec<_>>>()?;
+        Ok((statedb.state_root(), states))
     }
 }
 
@@ -114,8 +280,29 @@ impl Handler<AnnotatedStatesMessage> for ReaderExecutorActor {
         msg: AnnotatedStatesMessage,
         _ctx: &mut ActorContext,
     ) -> Result<Vec<Option<AnnotatedState>>, anyhow::Error> {
-        let statedb = self.moveos().moveos_resolver();
-        statedb.get_annotated_states(msg.access_path)
+        let AnnotatedStatesMessage {
+            state_root,
+            access_path,
+        } = msg;
+        let cache_root = state_root.unwrap_or_else(|| self.moveos().state().state_root());
+        if let Some(states) = self
+            .state_cache
+            .get_annotated_states(cache_root, &access_path)
+        {
+            return Ok(states);
+        }
+        let states = match state_root {
+            Some(state_root) => self
+                .resolver_at(state_root)
+                .get_annotated_states(access_path.clone())?,
+            None => self
+                .moveos()
+                .moveos_resolver()
+                .get_annotated_states(access_path.clone())?,
+        };
+        self.state_cache
+            .put_annotated_states(cache_root, access_path, &states);
+        Ok(states)
     }
 }
 
@@ -126,8 +313,22 @@ impl Handler<ListStatesMessage> for ReaderExecutorActor {
         msg: ListStatesMessage,
         _ctx: &mut ActorContext,
     ) -> Result<Vec<StateKV>, anyhow::Error> {
-        let statedb = self.moveos().moveos_resolver();
-        statedb.list_states(msg.access_path, msg.cursor, msg.limit)
+        let ListStatesMessage {
+            state_root,
+            access_path,
+            cursor,
+            limit,
+        } = msg;
+        match state_root {
+            Some(state_root) => {
+                self.resolver_at(state_root)
+                    .list_states(access_path, cursor, limit)
+            }
+            None => self
+                .moveos()
+                .moveos_resolver()
+                .list_states(access_path, cursor, limit),
+        }
     }
 }
 
@@ -138,8 +339,23 @@ impl Handler<ListAnnotatedStatesMessage> for ReaderExecutorActor {
         msg: ListAnnotatedStatesMessage,
         _ctx: &mut ActorContext,
     ) -> Result<Vec<AnnotatedStateKV>, anyhow::Error> {
-        let statedb = self.moveos().moveos_resolver();
-        statedb.list_annotated_states(msg.access_path, msg.cursor, msg.limit)
+        let ListAnnotatedStatesMessage {
+            state_root,
+            access_path,
+            cursor,
+            limit,
+        } = msg;
+        match state_root {
+            Some(state_root) => {
+                self.resolver_at(state_root)
+                    .list_annotated_states(access_path, cursor, limit)
+            }
+            None => {
+                self.moveos()
+                    .moveos_resolver()
+                    .list_annotated_states(access_path, cursor, limit)
+            }
+        }
     }
 }
 
@@ -154,12 +370,22 @@ impl Handler<GetAnnotatedEventsByEventHandleMessage> for ReaderExecutorActor {
             event_handle_type,
             cursor,
             limit,
+            descending_order,
+            from_seq,
+            to_seq,
         } = msg;
         let event_store = self.moveos().event_store();
         let resolver = self.moveos().moveos_resolver();
 
         let event_handle_id = EventHandle::derive_event_handle_id(&event_handle_type);
-        let events = event_store.get_events_by_event_handle_id(&event_handle_id, cursor, limit)?;
+        let events = event_store.get_events_by_event_handle_id(
+            &event_handle_id,
+            cursor,
+            limit,
+            descending_order,
+            from_seq,
+            to_seq,
+        )?;
 
         events
             .into_iter()
@@ -183,11 +409,21 @@ impl Handler<GetEventsByEventHandleMessage> for ReaderExecutorActor {
             event_handle_type,
             cursor,
             limit,
+            descending_order,
+            from_seq,
+            to_seq,
         } = msg;
         let event_store = self.moveos().event_store();
 
         let event_handle_id = EventHandle::derive_event_handle_id(&event_handle_type);
-        event_store.get_events_by_event_handle_id(&event_handle_id, cursor, limit)
+        event_store.get_events_by_event_handle_id(
+            &event_handle_id,
+            cursor,
+            limit,
+            descending_order,
+            from_seq,
+            to_seq,
+        )
     }
 }
 
@@ -259,6 +495,16 @@ impl Handler<RefreshStateMessage> for ReaderExecutorActor {
             new_state_root,
             is_upgrade,
         } = msg;
+        self.state_cache.invalidate();
         self.moveos.refresh_state(new_state_root, is_upgrade)
     }
 }
+
+#[async_trait]
+impl Handler<CatchUpPrimaryTick> for ReaderExecutorActor {
+    async fn handle(&mut self, msg: CatchUpPrimaryTick, _ctx: &mut ActorContext) {
+        if let Err(e) = self.catch_up_primary(msg.dbs) {
+            log::error!("[CatchUpPrimary] error: {:?}", e);
+        }
+    }
+}
@@ -0,0 +1,82 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use lru::LruCache;
+use moveos_types::access_path::AccessPath;
+use moveos_types::h256::H256;
+use moveos_types::state::{AnnotatedState, State};
+use std::num::NonZeroUsize;
+
+type StateCacheKey = (H256, AccessPath);
+
+/// The read-through cache of the states read by the RPC server, keyed by the state root and the access path.
+/// The states of a state root never change, the cache is cleared when the state root is refreshed,
+/// so the cache is only used for the hot keys of the latest state root,
+/// and the annotated states are not kept across the module upgrades.
+pub struct StateCache {
+    states: Option<LruCache<StateCacheKey, Vec<Option<State>>>>,
+    annotated_states: Option<LruCache<StateCacheKey, Vec<Option<AnnotatedState>>>>,
+}
+
+impl StateCache {
+    /// Create the cache of `cache_size` entries, the cache is disabled if the `cache_size` is 0
+    pub fn new(cache_size: usize) -> Self {
+        let size = NonZeroUsize::new(cache_size);
+        Self {
+            states: size.map(LruCache::new),
+            annotated_states: size.map(LruCache::new),
+        }
+    }
+
+    pub fn get_states(
+        &mut self,
+        state_root: H256,
+        access_path: &AccessPath,
+    ) -> Option<Vec<Option<State>>> {
+        self.states
+            .as_mut()
+            .and_then(|cache| cache.get(&(state_root, access_path.clone())).cloned())
+    }
+
+    pub fn put_states(
+        &mut self,
+        state_root: H256,
+        access_path: AccessPath,
+        states: &[Option<State>],
+    ) {
+        if let Some(cache) = self.states.as_mut() {
+            cache.put((state_root, access_path), states.to_vec());
+        }
+    }
+
+    pub fn get_annotated_states(
+        &mut self,
+        state_root: H256,
+        access_path: &AccessPath,
+    ) -> Option<Vec<Option<AnnotatedState>>> {
+        self.annotated_states
+            .as_mut()
+            .and_then(|cache| cache.get(&(state_root, access_path.clone())).cloned())
+    }
+
+    pub fn put_annotated_states(
+        &mut self,
+        state_root: H256,
+        access_path: AccessPath,
+        states: &[Option<AnnotatedState>],
+    ) {
+        if let Some(cache) = self.annotated_states.as_mut() {
+            cache.put((state_root, access_path), states.to_vec());
+        }
+    }
+
+    /// Drop all the cached states, called when the state root is refreshed
+    pub fn invalidate(&mut self) {
+        if let Some(cache) = self.states.as_mut() {
+            cache.clear();
+        }
+        if let Some(cache) = self.annotated_states.as_mut() {
+            cache.clear();
+        }
+    }
+}
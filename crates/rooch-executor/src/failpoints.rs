@@ -0,0 +1,11 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+//! The fail points to inject the faults into the executor, they are no-op unless the `failpoints` feature is enabled.
+//! For example, `fail::cfg(EXECUTOR_AFTER_APPLY, "1%panic")` crashes 1% of the transactions after the state is applied,
+//! but before the execution info is saved, to verify the node can recover from it.
+
+/// Before the transaction is executed
+pub const EXECUTOR_BEFORE_EXECUTE: &str = "executor_before_execute";
+/// After the state changes of the transaction are applied, before the execution info is saved
+pub const EXECUTOR_AFTER_APPLY: &str = "executor_after_apply";
@@ -2,4 +2,5 @@
 // SPDX-License-Identifier: Apache-2.0
 
 pub mod actor;
+pub mod failpoints;
 pub mod proxy;
@@ -2,16 +2,17 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::actor::messages::{
-    GetAnnotatedStatesByStateMessage, GetEventsByEventHandleMessage, GetEventsByEventIDsMessage,
-    GetTxExecutionInfosByHashMessage, ListAnnotatedStatesMessage, ListStatesMessage,
-    RefreshStateMessage,
+    DryRunTransactionMessage, GetAnnotatedStatesByStateMessage, GetEventsByEventHandleMessage,
+    GetEventsByEventIDsMessage, GetTxExecutionInfosByHashMessage, ListAnnotatedStatesMessage,
+    ListStatesMessage, RefreshStateMessage, ValidateUnsignedTransactionMessage,
 };
 use crate::actor::reader_executor::ReaderExecutorActor;
 use crate::actor::{
     executor::ExecutorActor,
     messages::{
-        AnnotatedStatesMessage, ExecuteViewFunctionMessage, GetAnnotatedEventsByEventHandleMessage,
-        ResolveMessage, StatesMessage, ValidateTransactionMessage,
+        AnnotatedStatesMessage, ExecuteSignedViewFunctionMessage, ExecuteViewFunctionMessage,
+        GetAnnotatedEventsByEventHandleMessage, ResolveMessage, StatesMessage,
+        StatesWithProofMessage, ValidateTransactionMessage,
     },
 };
 use anyhow::Result;
@@ -24,8 +25,10 @@ use moveos_types::module_binding::MoveFunctionCaller;
 use moveos_types::moveos_std::event::{Event, EventID};
 use moveos_types::moveos_std::tx_context::TxContext;
 use moveos_types::state::KeyState;
+use moveos_types::state_proof::StateProof;
 use moveos_types::state_resolver::{AnnotatedStateKV, StateKV};
 use moveos_types::transaction::FunctionCall;
+use moveos_types::transaction::RawTransactionOutput;
 use moveos_types::transaction::TransactionExecutionInfo;
 use moveos_types::transaction::TransactionOutput;
 use moveos_types::{access_path::AccessPath, transaction::VerifiedMoveOSTransaction};
@@ -34,6 +37,8 @@ use moveos_types::{
     state::{AnnotatedState, State},
 };
 use rooch_types::address::MultiChainAddress;
+use rooch_types::transaction::rooch::RoochTransactionData;
+use rooch_types::transaction::view::SignedViewFunctionCall;
 use rooch_types::transaction::AbstractTransaction;
 use tokio::runtime::Handle;
 
@@ -61,6 +66,27 @@ impl ExecutorProxy {
         self.actor.send(ValidateTransactionMessage { tx }).await?
     }
 
+    pub async fn validate_unsigned_transaction(
+        &self,
+        tx_data: RoochTransactionData,
+    ) -> Result<VerifiedMoveOSTransaction> {
+        self.actor
+            .send(ValidateUnsignedTransactionMessage { tx_data })
+            .await?
+    }
+
+    /// Execute the transaction against the latest state without committing the output,
+    /// the gas breakdown is collected in the output if `gas_profile` is true.
+    pub async fn dry_run_transaction(
+        &self,
+        tx: VerifiedMoveOSTransaction,
+        gas_profile: bool,
+    ) -> Result<RawTransactionOutput> {
+        self.reader_actor
+            .send(DryRunTransactionMessage { tx, gas_profile })
+            .await?
+    }
+
     //TODO ensure the execute result
     pub async fn execute_transaction(
         &self,
@@ -82,9 +108,39 @@ impl ExecutorProxy {
             .await?
     }
 
-    pub async fn get_states(&self, access_path: AccessPath) -> Result<Vec<Option<State>>> {
+    /// Execute the view function with the sender of the signed call, fails if the signature is invalid or expired
+    pub async fn execute_signed_view_function(
+        &self,
+        call: SignedViewFunctionCall,
+    ) -> Result<AnnotatedFunctionResult> {
+        self.reader_actor
+            .send(ExecuteSignedViewFunctionMessage { call })
+            .await?
+    }
+
+    pub async fn get_states(
+        &self,
+        state_root: Option<H256>,
+        access_path: AccessPath,
+    ) -> Result<Vec<Option<State>>> {
         self.reader_actor
-            .send(StatesMessage { access_path })
+            .send(StatesMessage {
+                state_root,
+                access_path,
+            })
+            .await?
+    }
+
+    pub async fn get_states_with_proof(
+        &self,
+        state_root: Option<H256>,
+        access_path: AccessPath,
+    ) -> Result<(H256, Vec<(Option<State>, StateProof)>)> {
+        self.reader_actor
+            .send(StatesWithProofMessage {
+                state_root,
+                access_path,
+            })
             .await?
     }
 
@@ -94,21 +150,27 @@ impl ExecutorProxy {
 
     pub async fn get_annotated_states(
         &self,
+        state_root: Option<H256>,
         access_path: AccessPath,
     ) -> Result<Vec<Option<AnnotatedState>>> {
         self.reader_actor
-            .send(AnnotatedStatesMessage { access_path })
+            .send(AnnotatedStatesMessage {
+                state_root,
+                access_path,
+            })
             .await?
     }
 
     pub async fn list_states(
         &self,
+        state_root: Option<H256>,
         access_path: AccessPath,
         cursor: Option<KeyState>,
         limit: usize,
     ) -> Result<Vec<StateKV>> {
         self.reader_actor
             .send(ListStatesMessage {
+                state_root,
                 access_path,
                 cursor,
                 limit,
@@ -118,12 +180,14 @@ impl ExecutorProxy {
 
     pub async fn list_annotated_states(
         &self,
+        state_root: Option<H256>,
         access_path: AccessPath,
         cursor: Option<KeyState>,
         limit: usize,
     ) -> Result<Vec<AnnotatedStateKV>> {
         self.reader_actor
             .send(ListAnnotatedStatesMessage {
+                state_root,
                 access_path,
                 cursor,
                 limit,
@@ -136,12 +200,18 @@ impl ExecutorProxy {
         event_handle_type: StructTag,
         cursor: Option<u64>,
         limit: u64,
+        descending_order: bool,
+        from_seq: Option<u64>,
+        to_seq: Option<u64>,
     ) -> Result<Vec<AnnotatedEvent>> {
         self.reader_actor
             .send(GetAnnotatedEventsByEventHandleMessage {
                 event_handle_type,
                 cursor,
                 limit,
+                descending_order,
+                from_seq,
+                to_seq,
             })
             .await?
     }
@@ -151,12 +221,18 @@ impl ExecutorProxy {
         event_handle_type: StructTag,
         cursor: Option<u64>,
         limit: u64,
+        descending_order: bool,
+        from_seq: Option<u64>,
+        to_seq: Option<u64>,
     ) -> Result<Vec<Event>> {
         self.reader_actor
             .send(GetEventsByEventHandleMessage {
                 event_handle_type,
                 cursor,
                 limit,
+                descending_order,
+                from_seq,
+                to_seq,
             })
             .await?
     }
@@ -0,0 +1 @@
+DROP TABLE IF EXISTS dispense_records;
@@ -0,0 +1,12 @@
+CREATE TABLE dispense_records
+(
+    id                          INTEGER        NOT NULL PRIMARY KEY AUTOINCREMENT,
+    recipient                   VARCHAR        NOT NULL,
+    ip                          VARCHAR        NOT NULL,
+    amount                      VARCHAR        NOT NULL,
+    tx_hash                     VARCHAR        NOT NULL,
+    created_at                  BIGINT         NOT NULL
+);
+
+CREATE INDEX idx_dispense_records_recipient ON dispense_records (recipient, created_at);
+CREATE INDEX idx_dispense_records_ip ON dispense_records (ip, created_at);
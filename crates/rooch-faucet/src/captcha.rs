@@ -0,0 +1,75 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::errors::{FaucetError, FaucetResult};
+use async_trait::async_trait;
+use serde::Deserialize;
+
+/// The hook to verify the captcha response token of the faucet requests.
+#[async_trait]
+pub trait CaptchaVerifier: Send + Sync {
+    async fn verify(&self, token: Option<&str>, ip: &str) -> FaucetResult<()>;
+}
+
+/// Accept all the requests, used when the captcha is not configured.
+pub struct NoCaptcha;
+
+#[async_trait]
+impl CaptchaVerifier for NoCaptcha {
+    async fn verify(&self, _token: Option<&str>, _ip: &str) -> FaucetResult<()> {
+        Ok(())
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct SiteVerifyResponse {
+    success: bool,
+    #[serde(default, rename = "error-codes")]
+    error_codes: Vec<String>,
+}
+
+/// Verify the token via the `siteverify` endpoint of the captcha provider,
+/// the endpoint protocol is shared by reCAPTCHA, hCaptcha and Turnstile.
+pub struct SiteVerifyCaptcha {
+    client: reqwest::Client,
+    verify_url: String,
+    secret: String,
+}
+
+impl SiteVerifyCaptcha {
+    pub fn new(verify_url: String, secret: String) -> Self {
+        Self {
+            client: reqwest::Client::new(),
+            verify_url,
+            secret,
+        }
+    }
+}
+
+#[async_trait]
+impl CaptchaVerifier for SiteVerifyCaptcha {
+    async fn verify(&self, token: Option<&str>, ip: &str) -> FaucetResult<()> {
+        let token =
+            token.ok_or_else(|| FaucetError::CaptchaFailed("Missing captcha token".to_owned()))?;
+        let response = self
+            .client
+            .post(&self.verify_url)
+            .form(&[
+                ("secret", self.secret.as_str()),
+                ("response", token),
+                ("remoteip", ip),
+            ])
+            .send()
+            .await
+            .and_then(|response| response.error_for_status())
+            .map_err(|e| FaucetError::CaptchaFailed(e.to_string()))?
+            .json::<SiteVerifyResponse>()
+            .await
+            .map_err(|e| FaucetError::CaptchaFailed(e.to_string()))?;
+        if response.success {
+            Ok(())
+        } else {
+            Err(FaucetError::CaptchaFailed(response.error_codes.join(",")))
+        }
+    }
+}
@@ -0,0 +1,82 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use anyhow::Result;
+use clap::Parser;
+use move_core_types::u256::U256;
+use rooch_config::rooch_config_dir;
+use rooch_types::address::RoochAddress;
+use std::path::PathBuf;
+
+pub const ROOCH_FAUCET_DB_FILENAME: &str = "faucet.sqlite";
+pub const DEFAULT_FAUCET_PORT: u16 = 6868;
+
+#[derive(Clone, Debug, Parser)]
+#[clap(
+    name = "rooch-faucet",
+    about = "Dispense the gas coins to the devnet users"
+)]
+pub struct FaucetConfig {
+    /// The port of the faucet HTTP server
+    #[clap(long, default_value_t = DEFAULT_FAUCET_PORT)]
+    pub port: u16,
+
+    /// The rooch config dir of the faucet wallet, the active address of the wallet dispenses the gas coins
+    #[clap(long)]
+    pub config_dir: Option<PathBuf>,
+
+    /// The address to dispense the gas coins, if absent, use the active address of the wallet
+    #[clap(long)]
+    pub sender: Option<RoochAddress>,
+
+    /// The password of the faucet account if the keystore is encrypted
+    #[clap(long, env = "ROOCH_FAUCET_PASSWORD")]
+    pub password: Option<String>,
+
+    /// The sqlite db file storing the dispense records, if absent, use `faucet.sqlite` in the rooch config dir
+    #[clap(long)]
+    pub db_path: Option<PathBuf>,
+
+    /// The amount of the gas coins of each dispense
+    #[clap(long, default_value = "1000000000")]
+    pub amount: U256,
+
+    /// The max number of the dispenses to a recipient in a quota window
+    #[clap(long, default_value_t = 1)]
+    pub max_per_recipient: u64,
+
+    /// The max number of the dispenses requested from an ip in a quota window
+    #[clap(long, default_value_t = 10)]
+    pub max_per_ip: u64,
+
+    /// The seconds of the quota window
+    #[clap(long, default_value_t = 86400)]
+    pub quota_window_secs: u64,
+
+    /// Use the first ip of the `X-Forwarded-For` header as the client ip, enable it when the faucet is behind a proxy
+    #[clap(long)]
+    pub trust_forwarded_for: bool,
+
+    /// The siteverify url of the captcha provider, the captcha verification is enabled if both the url and the secret are set
+    #[clap(long)]
+    pub captcha_verify_url: Option<String>,
+
+    /// The secret key of the captcha provider
+    #[clap(long, env = "ROOCH_FAUCET_CAPTCHA_SECRET")]
+    pub captcha_secret: Option<String>,
+}
+
+impl FaucetConfig {
+    pub fn db_path(&self) -> Result<PathBuf> {
+        match &self.db_path {
+            Some(db_path) => Ok(db_path.clone()),
+            None => {
+                let config_dir = match &self.config_dir {
+                    Some(config_dir) => config_dir.clone(),
+                    None => rooch_config_dir()?,
+                };
+                Ok(config_dir.join(ROOCH_FAUCET_DB_FILENAME))
+            }
+        }
+    }
+}
@@ -0,0 +1,50 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use hyper::StatusCode;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum FaucetError {
+    #[error("Invalid faucet request: `{0}`")]
+    InvalidRequest(String),
+
+    #[error("Captcha verification failed: `{0}`")]
+    CaptchaFailed(String),
+
+    #[error("The recipient `{0}` has reached the faucet quota, please retry later")]
+    RecipientQuotaExceeded(String),
+
+    #[error("The ip `{0}` has reached the faucet quota, please retry later")]
+    IpQuotaExceeded(String),
+
+    #[error("Faucet failed to read or write the quota store with error: `{0}`")]
+    StoreError(String),
+
+    #[error("Faucet failed to dispense with error: `{0}`")]
+    DispenseError(String),
+}
+
+impl FaucetError {
+    /// The HTTP status code of the error response.
+    pub fn status_code(&self) -> StatusCode {
+        match self {
+            FaucetError::InvalidRequest(_) => StatusCode::BAD_REQUEST,
+            FaucetError::CaptchaFailed(_) => StatusCode::FORBIDDEN,
+            FaucetError::RecipientQuotaExceeded(_) | FaucetError::IpQuotaExceeded(_) => {
+                StatusCode::TOO_MANY_REQUESTS
+            }
+            FaucetError::StoreError(_) | FaucetError::DispenseError(_) => {
+                StatusCode::INTERNAL_SERVER_ERROR
+            }
+        }
+    }
+}
+
+impl From<diesel::result::Error> for FaucetError {
+    fn from(e: diesel::result::Error) -> Self {
+        FaucetError::StoreError(e.to_string())
+    }
+}
+
+pub type FaucetResult<T> = Result<T, FaucetError>;
@@ -0,0 +1,122 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::captcha::CaptchaVerifier;
+use crate::config::FaucetConfig;
+use crate::errors::{FaucetError, FaucetResult};
+use crate::store::{FaucetStore, NewDispenseRecord};
+use crate::types::{FaucetRequest, FaucetResponse};
+use anyhow::{anyhow, Result};
+use move_core_types::u256::U256;
+use moveos_types::state::MoveStructType;
+use rooch_rpc_client::wallet_context::WalletContext;
+use rooch_types::address::RoochAddress;
+use rooch_types::framework::gas_coin::GasCoin;
+use rooch_types::framework::transfer::TransferModule;
+use std::str::FromStr;
+use std::time::{SystemTime, UNIX_EPOCH};
+use tokio::sync::Mutex;
+
+pub struct Faucet {
+    context: WalletContext,
+    sender: RoochAddress,
+    password: Option<String>,
+    amount: U256,
+    max_per_recipient: u64,
+    max_per_ip: u64,
+    quota_window_secs: u64,
+    store: FaucetStore,
+    captcha: Box<dyn CaptchaVerifier>,
+    // Serialize the dispenses, the transactions of the sender use consecutive sequence numbers,
+    // and the quota check and the record insert must be atomic.
+    lock: Mutex<()>,
+}
+
+impl Faucet {
+    pub fn new(
+        config: &FaucetConfig,
+        store: FaucetStore,
+        captcha: Box<dyn CaptchaVerifier>,
+    ) -> Result<Self> {
+        let context = WalletContext::new(config.config_dir.clone())?;
+        let sender = match config.sender {
+            Some(sender) => sender,
+            None => context
+                .client_config
+                .active_address
+                .ok_or_else(|| anyhow!("The faucet wallet has no active address"))?,
+        };
+        Ok(Self {
+            context,
+            sender,
+            password: config.password.clone(),
+            amount: config.amount,
+            max_per_recipient: config.max_per_recipient,
+            max_per_ip: config.max_per_ip,
+            quota_window_secs: config.quota_window_secs,
+            store,
+            captcha,
+            lock: Mutex::new(()),
+        })
+    }
+
+    pub fn sender(&self) -> RoochAddress {
+        self.sender
+    }
+
+    pub async fn dispense(&self, request: FaucetRequest, ip: &str) -> FaucetResult<FaucetResponse> {
+        let recipient = RoochAddress::from_str(request.recipient.as_str())
+            .map_err(|e| FaucetError::InvalidRequest(format!("Invalid recipient: {e}")))?;
+        self.captcha.verify(request.captcha.as_deref(), ip).await?;
+
+        let _guard = self.lock.lock().await;
+        let now = now_seconds();
+        let since = now.saturating_sub(self.quota_window_secs as i64);
+        let recipient_str = recipient.to_string();
+        if self.store.count_by_recipient(&recipient_str, since)? >= self.max_per_recipient {
+            return Err(FaucetError::RecipientQuotaExceeded(recipient_str));
+        }
+        if self.store.count_by_ip(ip, since)? >= self.max_per_ip {
+            return Err(FaucetError::IpQuotaExceeded(ip.to_owned()));
+        }
+
+        let action = TransferModule::create_transfer_coin_action(
+            GasCoin::struct_tag(),
+            recipient.into(),
+            self.amount,
+        );
+        let result = self
+            .context
+            .sign_and_execute(self.sender, action, self.password.clone())
+            .await
+            .and_then(|result| self.context.assert_execute_success(result))
+            .map_err(|e| FaucetError::DispenseError(e.to_string()))?;
+        let tx_hash = result.execution_info.tx_hash.to_string();
+
+        self.store.insert_record(NewDispenseRecord {
+            recipient: recipient_str.clone(),
+            ip: ip.to_owned(),
+            amount: self.amount.to_string(),
+            tx_hash: tx_hash.clone(),
+            created_at: now,
+        })?;
+        tracing::info!(
+            "Dispensed {} gas coins to {}, tx_hash: {}",
+            self.amount,
+            recipient_str,
+            tx_hash
+        );
+        Ok(FaucetResponse {
+            recipient: recipient_str,
+            amount: self.amount.to_string(),
+            tx_hash,
+        })
+    }
+}
+
+fn now_seconds() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs() as i64)
+        .unwrap_or_default()
+}
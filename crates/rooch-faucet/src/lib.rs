@@ -0,0 +1,16 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+pub mod captcha;
+pub mod config;
+pub mod errors;
+pub mod faucet;
+pub mod schema;
+pub mod server;
+pub mod store;
+pub mod types;
+
+/// The path of the dispense endpoint of the faucet server.
+pub const FAUCET_PATH: &str = "/faucet";
+/// The path of the health check endpoint of the faucet server.
+pub const HEALTH_PATH: &str = "/health";
@@ -0,0 +1,39 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use anyhow::Result;
+use clap::Parser;
+use rooch_faucet::captcha::{CaptchaVerifier, NoCaptcha, SiteVerifyCaptcha};
+use rooch_faucet::config::FaucetConfig;
+use rooch_faucet::faucet::Faucet;
+use rooch_faucet::server::FaucetServer;
+use rooch_faucet::store::FaucetStore;
+use std::net::{Ipv4Addr, SocketAddr};
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    tracing_subscriber::fmt::init();
+    let config = FaucetConfig::parse();
+
+    let db_path = config.db_path()?;
+    let store = FaucetStore::new(db_path.to_string_lossy().as_ref())?;
+    let captcha: Box<dyn CaptchaVerifier> = match (
+        config.captcha_verify_url.clone(),
+        config.captcha_secret.clone(),
+    ) {
+        (Some(verify_url), Some(secret)) => Box::new(SiteVerifyCaptcha::new(verify_url, secret)),
+        _ => Box::new(NoCaptcha),
+    };
+    let faucet = Faucet::new(&config, store, captcha)?;
+    tracing::info!(
+        "Faucet dispenses {} gas coins from {}, db: {:?}",
+        config.amount,
+        faucet.sender(),
+        db_path
+    );
+
+    let addr = SocketAddr::from((Ipv4Addr::UNSPECIFIED, config.port));
+    FaucetServer::new(faucet, config.trust_forwarded_for)
+        .serve(addr)
+        .await
+}
@@ -0,0 +1,117 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::errors::FaucetError;
+use crate::faucet::Faucet;
+use crate::types::{FaucetErrorResponse, FaucetRequest};
+use crate::{FAUCET_PATH, HEALTH_PATH};
+use anyhow::Result;
+use hyper::server::conn::AddrStream;
+use hyper::service::{make_service_fn, service_fn};
+use hyper::{Body, Method, Request, Response, Server, StatusCode};
+use serde::Serialize;
+use std::convert::Infallible;
+use std::net::{IpAddr, SocketAddr};
+use std::sync::Arc;
+
+const X_FORWARDED_FOR: &str = "x-forwarded-for";
+
+pub struct FaucetServer {
+    faucet: Arc<Faucet>,
+    trust_forwarded_for: bool,
+}
+
+impl FaucetServer {
+    pub fn new(faucet: Faucet, trust_forwarded_for: bool) -> Self {
+        Self {
+            faucet: Arc::new(faucet),
+            trust_forwarded_for,
+        }
+    }
+
+    pub async fn serve(self, addr: SocketAddr) -> Result<()> {
+        let faucet = self.faucet;
+        let trust_forwarded_for = self.trust_forwarded_for;
+        let make_service = make_service_fn(move |conn: &AddrStream| {
+            let faucet = faucet.clone();
+            let remote_ip = conn.remote_addr().ip();
+            async move {
+                Ok::<_, Infallible>(service_fn(move |req| {
+                    let faucet = faucet.clone();
+                    let ip = client_ip(&req, remote_ip, trust_forwarded_for);
+                    async move { Ok::<_, Infallible>(handle(faucet, req, ip).await) }
+                }))
+            }
+        });
+        tracing::info!("Faucet server listening on {}", addr);
+        Server::try_bind(&addr)?.serve(make_service).await?;
+        Ok(())
+    }
+}
+
+/// The ip of the client, the proxy sets the `X-Forwarded-For` header as `<client>, <proxy1>, <proxy2>`.
+fn client_ip(req: &Request<Body>, remote_ip: IpAddr, trust_forwarded_for: bool) -> String {
+    if trust_forwarded_for {
+        let forwarded_ip = req
+            .headers()
+            .get(X_FORWARDED_FOR)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.split(',').next())
+            .map(|ip| ip.trim())
+            .filter(|ip| !ip.is_empty());
+        if let Some(ip) = forwarded_ip {
+            return ip.to_owned();
+        }
+    }
+    remote_ip.to_string()
+}
+
+async fn handle(faucet: Arc<Faucet>, req: Request<Body>, ip: String) -> Response<Body> {
+    match (req.method(), req.uri().path()) {
+        (&Method::GET, HEALTH_PATH) => json_response(StatusCode::OK, &faucet.sender()),
+        (&Method::POST, FAUCET_PATH) => {
+            let result = match parse_request(req).await {
+                Ok(request) => faucet.dispense(request, ip.as_str()).await,
+                Err(e) => Err(e),
+            };
+            match result {
+                Ok(response) => json_response(StatusCode::OK, &response),
+                Err(e) => {
+                    tracing::warn!("Faucet request from {} failed: {}", ip, e);
+                    error_response(e)
+                }
+            }
+        }
+        _ => json_response(
+            StatusCode::NOT_FOUND,
+            &FaucetErrorResponse {
+                error: "Not found".to_owned(),
+            },
+        ),
+    }
+}
+
+async fn parse_request(req: Request<Body>) -> Result<FaucetRequest, FaucetError> {
+    let body = hyper::body::to_bytes(req.into_body())
+        .await
+        .map_err(|e| FaucetError::InvalidRequest(e.to_string()))?;
+    serde_json::from_slice(&body).map_err(|e| FaucetError::InvalidRequest(e.to_string()))
+}
+
+fn error_response(error: FaucetError) -> Response<Body> {
+    json_response(
+        error.status_code(),
+        &FaucetErrorResponse {
+            error: error.to_string(),
+        },
+    )
+}
+
+fn json_response<T: Serialize>(status: StatusCode, value: &T) -> Response<Body> {
+    let body = serde_json::to_vec(value).expect("Serialize faucet response should success");
+    Response::builder()
+        .status(status)
+        .header(hyper::header::CONTENT_TYPE, "application/json")
+        .body(Body::from(body))
+        .expect("Build faucet response should success")
+}
@@ -0,0 +1,86 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::errors::{FaucetError, FaucetResult};
+use crate::schema::dispense_records;
+use anyhow::{anyhow, Result};
+use diesel::prelude::*;
+use diesel::SqliteConnection;
+use diesel_migrations::{embed_migrations, EmbeddedMigrations, MigrationHarness};
+use std::sync::{Arc, Mutex};
+
+pub const MIGRATIONS: EmbeddedMigrations = embed_migrations!("migrations");
+
+#[derive(Insertable, Debug, Clone)]
+#[diesel(table_name = dispense_records)]
+pub struct NewDispenseRecord {
+    pub recipient: String,
+    pub ip: String,
+    pub amount: String,
+    pub tx_hash: String,
+    /// The seconds when the gas coins are dispensed
+    pub created_at: i64,
+}
+
+/// The store of the dispense records, the quotas of the recipients and the ips are counted by the records.
+#[derive(Clone)]
+pub struct FaucetStore {
+    connection: Arc<Mutex<SqliteConnection>>,
+}
+
+impl FaucetStore {
+    pub fn new(db_url: &str) -> Result<Self> {
+        let mut connection = SqliteConnection::establish(db_url)
+            .map_err(|e| anyhow!("Failed to open faucet db {db_url}: {e}"))?;
+        connection
+            .run_pending_migrations(MIGRATIONS)
+            .map_err(|e| anyhow!("Failed to run faucet db migrations {e}"))?;
+        Ok(Self {
+            connection: Arc::new(Mutex::new(connection)),
+        })
+    }
+
+    fn run<T, F>(&self, f: F) -> FaucetResult<T>
+    where
+        F: FnOnce(&mut SqliteConnection) -> Result<T, diesel::result::Error>,
+    {
+        let mut connection = self
+            .connection
+            .lock()
+            .map_err(|e| FaucetError::StoreError(e.to_string()))?;
+        f(&mut connection).map_err(FaucetError::from)
+    }
+
+    /// Count the dispenses to the recipient since the given seconds.
+    pub fn count_by_recipient(&self, recipient: &str, since: i64) -> FaucetResult<u64> {
+        let count = self.run(|conn| {
+            dispense_records::table
+                .filter(dispense_records::recipient.eq(recipient))
+                .filter(dispense_records::created_at.ge(since))
+                .count()
+                .get_result::<i64>(conn)
+        })?;
+        Ok(count as u64)
+    }
+
+    /// Count the dispenses requested from the ip since the given seconds.
+    pub fn count_by_ip(&self, ip: &str, since: i64) -> FaucetResult<u64> {
+        let count = self.run(|conn| {
+            dispense_records::table
+                .filter(dispense_records::ip.eq(ip))
+                .filter(dispense_records::created_at.ge(since))
+                .count()
+                .get_result::<i64>(conn)
+        })?;
+        Ok(count as u64)
+    }
+
+    pub fn insert_record(&self, record: NewDispenseRecord) -> FaucetResult<()> {
+        self.run(|conn| {
+            diesel::insert_into(dispense_records::table)
+                .values(&record)
+                .execute(conn)
+        })?;
+        Ok(())
+    }
+}
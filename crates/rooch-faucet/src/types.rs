@@ -0,0 +1,26 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FaucetRequest {
+    /// The address to receive the gas coins, the hex literal of the rooch address
+    pub recipient: String,
+    /// The captcha response token, required if the faucet server enables the captcha verification
+    pub captcha: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FaucetResponse {
+    pub recipient: String,
+    /// The amount of the dispensed gas coins
+    pub amount: String,
+    /// The hash of the transfer transaction
+    pub tx_hash: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FaucetErrorResponse {
+    pub error: String,
+}
@@ -4,17 +4,20 @@
 use std::collections::HashMap;
 
 use crate::binding_test;
+use bitcoin::blockdata::constants::genesis_block;
 use bitcoin::consensus::deserialize;
 use bitcoin::{Block, OutPoint, Transaction, TxOut};
 use hex::FromHex;
+use move_core_types::vm_status::{AbortLocation, KeptVMStatus};
 use moveos_types::access_path::AccessPath;
-use moveos_types::module_binding::MoveFunctionCaller;
+use moveos_types::module_binding::{ModuleBinding, MoveFunctionCaller};
 use moveos_types::moveos_std::object_id;
 use moveos_types::state::MoveStructType;
 use moveos_types::state_resolver::StateReader;
 use moveos_types::transaction::MoveAction;
 use rooch_key::keystore::account_keystore::AccountKeystore;
 use rooch_key::keystore::memory_keystore::InMemKeystore;
+use rooch_types::bitcoin::light_client::BitcoinLightClientModule;
 use rooch_types::bitcoin::ord::{Inscription, InscriptionID};
 use rooch_types::bitcoin::types::Header;
 use rooch_types::bitcoin::utxo::{OutputID, UTXO};
@@ -105,6 +108,39 @@ fn test_submit_block() {
     assert_eq!(now_milliseconds, duration.as_millis() as u64);
 }
 
+#[test]
+fn test_submit_block_exceeding_pow_limit() {
+    let _ = tracing_subscriber::fmt::try_init();
+    let mut binding_test = binding_test::RustBindingTest::new().unwrap();
+
+    let keystore = InMemKeystore::new_insecure_for_tests(1);
+    let sender = keystore.addresses()[0];
+
+    // The regtest genesis block meets the target of its bits 0x207fffff, which exceeds the pow limit of the mainnet.
+    // It is the first block of the light client, so it is not linked to a prev block.
+    let block = genesis_block(bitcoin::Network::Regtest);
+    let action = MoveAction::Function(
+        rooch_types::bitcoin::light_client::BitcoinLightClientModule::create_submit_new_block_call(
+            1, block,
+        ),
+    );
+    let tx_data = RoochTransactionData::new_for_test(sender, 0, action);
+    let tx = keystore.sign_transaction(&sender, tx_data, None).unwrap();
+    let status = binding_test
+        .execute_as_result(tx)
+        .unwrap()
+        .transaction_info
+        .status;
+    match status {
+        KeptVMStatus::MoveAbort(AbortLocation::Module(module_id), code) => {
+            assert_eq!(module_id, BitcoinLightClientModule::module_id());
+            // ErrorInvalidProofOfWork = 4
+            assert_eq!(code, 4, "expect ErrorInvalidProofOfWork");
+        }
+        _ => panic!("expect move abort, but got {:?}", status),
+    }
+}
+
 //we temporarily ignore this test because it takes too long time
 //to run this test, use command:
 //RUST_LOG=debug cargo test --release --package rooch-framework-tests --lib -- --include-ignored tests::bitcoin_light_client_test::test_utxo_progress
@@ -6,6 +6,8 @@ mod brc20_test;
 mod empty_tests;
 mod ethereum_light_client_test;
 mod ethereum_validator_tests;
+mod multisig_validator_tests;
 mod native_validator_tests;
 mod ord_test;
+mod scheduler_tests;
 mod transaction_validator_tests;
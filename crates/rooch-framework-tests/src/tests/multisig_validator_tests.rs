@@ -0,0 +1,73 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use moveos_types::module_binding::MoveFunctionCaller;
+use moveos_types::transaction::MoveAction;
+use rooch_key::keystore::account_keystore::AccountKeystore;
+use rooch_key::keystore::memory_keystore::InMemKeystore;
+use rooch_key::multisig::{MultisigKeyPair, MultisigTransaction};
+use rooch_types::framework::empty::Empty;
+use rooch_types::multisig::{MultisigMemberPublicKey, MultisigPublicKey};
+use rooch_types::transaction::authenticator::MultisigAuthenticator;
+use rooch_types::transaction::{
+    rooch::{RoochTransaction, RoochTransactionData},
+    AbstractTransaction,
+};
+
+use crate::binding_test;
+
+#[test]
+fn test_validate() {
+    let binding_test = binding_test::RustBindingTest::new().unwrap();
+    let multisig_validator = binding_test
+        .as_module_binding::<rooch_types::framework::multisig_validator::MultisigValidatorModule>(
+    );
+
+    let keystore = InMemKeystore::new_insecure_for_tests(3);
+    let keypairs = keystore
+        .addresses()
+        .iter()
+        .map(|address| keystore.get_key_pair_with_password(address, None).unwrap())
+        .collect::<Vec<_>>();
+    let public_keys = keypairs
+        .iter()
+        .map(|kp| MultisigMemberPublicKey::try_from(&kp.public()).unwrap())
+        .collect::<Vec<_>>();
+    let multisig_public_key = MultisigPublicKey::new(public_keys, 2).unwrap();
+    let sender = multisig_public_key.address();
+
+    let action = MoveAction::new_function_call(Empty::empty_function_id(), vec![], vec![]);
+    let tx_data = RoochTransactionData::new_for_test(sender, 0, action);
+
+    // Two of the three members approve the transaction one by one
+    let mut multisig_tx = MultisigTransaction::new(multisig_public_key.clone(), tx_data);
+    for kp in keypairs.iter().skip(1) {
+        MultisigKeyPair::new(multisig_public_key.clone(), vec![kp.copy()])
+            .unwrap()
+            .approve(&mut multisig_tx)
+            .unwrap();
+    }
+    assert!(multisig_tx.is_ready());
+    let tx = multisig_tx.clone().into_transaction().unwrap();
+    let auth_info = tx.authenticator_info().unwrap();
+    let move_tx = tx.construct_moveos_transaction(sender.into()).unwrap();
+    multisig_validator
+        .validate(&move_tx.ctx, auth_info.authenticator.payload)
+        .unwrap();
+
+    // The signatures under the threshold should be rejected by the validator
+    let mut authenticator = MultisigAuthenticator::new(
+        multisig_public_key,
+        multisig_tx.tx_data.hash().as_bytes(),
+        multisig_tx.approvals,
+    )
+    .unwrap();
+    authenticator.signer_indexes.truncate(1);
+    authenticator.signatures.truncate(1);
+    let tx = RoochTransaction::new(multisig_tx.tx_data, authenticator.into());
+    let auth_info = tx.authenticator_info().unwrap();
+    let move_tx = tx.construct_moveos_transaction(sender.into()).unwrap();
+    assert!(multisig_validator
+        .validate(&move_tx.ctx, auth_info.authenticator.payload)
+        .is_err());
+}
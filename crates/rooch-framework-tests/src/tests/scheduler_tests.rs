@@ -0,0 +1,14 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::binding_test;
+use moveos_types::module_binding::MoveFunctionCaller;
+use rooch_types::framework::scheduler::SchedulerModule;
+
+#[test]
+fn test_scheduler_tasks() {
+    let binding_test = binding_test::RustBindingTest::new().unwrap();
+    let scheduler = binding_test.as_module_binding::<SchedulerModule>();
+    assert!(scheduler.tasks(0, 10).unwrap().is_empty());
+    assert!(scheduler.due_tasks(10).unwrap().is_empty());
+}
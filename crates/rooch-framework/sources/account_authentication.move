@@ -28,6 +28,8 @@ module rooch_framework::account_authentication {
    const ErrorAuthenticationKeyNotFound: u64 = 4; 
    /// The authentication key already exists in the specified validator
    const ErrorAuthenticationKeyAlreadyExists: u64 = 5; 
+   /// The authentication validator is not installed
+   const ErrorAuthValidatorNotInstalled: u64 = 6;
 
    /// A resource that holds the authentication key for this account.
    /// ValidatorType is a phantom type parameter that is used to distinguish between different auth validator types.
@@ -118,6 +120,16 @@ module rooch_framework::account_authentication {
       }
    }
 
+   /// Return the ids of the authentication validators installed for the account at `account_addr`.
+   public fun installed_auth_validators(ctx: &Context, account_addr: address): vector<u64> {
+      if(context::exists_resource<InstalledAuthValidator>(ctx, account_addr)){
+         let installed_auth_validator = context::borrow_resource<InstalledAuthValidator>(ctx, account_addr);
+         installed_auth_validator.validators
+      }else{
+         vector::empty()
+      }
+   }
+
    //TODO should we init the AuthenticationKey when install auth validator?
    public fun install_auth_validator<ValidatorType: store>(ctx: &mut Context, account_signer: &signer) {
       let validator = auth_validator_registry::borrow_validator_by_type<ValidatorType>(ctx);
@@ -143,6 +155,25 @@ module rooch_framework::account_authentication {
       install_auth_validator<ValidatorType>(ctx, account_signer);
    }
 
+   /// Uninstall the authentication validator for the account, the transactions validated by it are rejected after uninstalled.
+   public fun uninstall_auth_validator<ValidatorType: store>(ctx: &mut Context, account_signer: &signer) {
+      let validator = auth_validator_registry::borrow_validator_by_type<ValidatorType>(ctx);
+      let validator_id = auth_validator::validator_id(validator);
+      let account_addr = signer::address_of(account_signer);
+
+      assert!(
+         is_auth_validator_installed(ctx, account_addr, validator_id),
+         ErrorAuthValidatorNotInstalled);
+
+      let installed_auth_validator = context::borrow_mut_resource<InstalledAuthValidator>(ctx, account_addr);
+      let (_, index) = vector::index_of(&installed_auth_validator.validators, &validator_id);
+      vector::remove(&mut installed_auth_validator.validators, index);
+   }
+
+   public entry fun uninstall_auth_validator_entry<ValidatorType: store>(ctx: &mut Context, account_signer: &signer) {
+      uninstall_auth_validator<ValidatorType>(ctx, account_signer);
+   }
+
    #[test_only]
    struct TestValidator has store {
    }
@@ -3,6 +3,7 @@
 
 module rooch_framework::auth_validator_registry {
 
+    use std::option::{Self, Option};
     use moveos_std::type_info;
     use moveos_std::table::{Self, Table};
     use moveos_std::type_table::{Self, TypeTable};
@@ -74,6 +75,16 @@ module rooch_framework::auth_validator_registry {
         table::borrow(&registry.validators, id)
     }
 
+    /// Return the auth validator with the `id`, or none if the validator is not registered.
+    public fun get_validator(ctx: &Context, id: u64): Option<AuthValidator> {
+        let registry = context::borrow_resource<ValidatorRegistry>(ctx, @rooch_framework);
+        if (table::contains(&registry.validators, id)) {
+            option::some(*table::borrow(&registry.validators, id))
+        } else {
+            option::none()
+        }
+    }
+
     public fun borrow_validator_by_type<ValidatorType: store>(ctx: &Context): &AuthValidator {
         let registry = context::borrow_resource<ValidatorRegistry>(ctx, @rooch_framework);
         assert!(type_table::contains<AuthValidatorWithType<ValidatorType>>(&registry.validators_with_type), ErrorValidatorUnregistered);
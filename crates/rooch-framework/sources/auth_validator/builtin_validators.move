@@ -8,6 +8,9 @@ module rooch_framework::builtin_validators{
     use rooch_framework::native_validator;
     use rooch_framework::ethereum_validator;
     use rooch_framework::bitcoin_validator;
+    use rooch_framework::multisig_validator;
+    use rooch_framework::schnorr_validator;
+    use rooch_framework::webauthn_validator;
 
     friend rooch_framework::genesis;
 
@@ -25,11 +28,26 @@ module rooch_framework::builtin_validators{
         // BITCOIN_AUTH_VALIDATOR_ID: u64 = 2;
         let id = auth_validator_registry::register_internal<bitcoin_validator::BitcoinValidator>(ctx);
         assert!(id == bitcoin_validator::auth_validator_id(), ErrorGenesisInit);
+
+        // MULTISIG_AUTH_VALIDATOR_ID: u64 = 3;
+        let id = auth_validator_registry::register_internal<multisig_validator::MultisigValidator>(ctx);
+        assert!(id == multisig_validator::auth_validator_id(), ErrorGenesisInit);
+
+        // SCHNORR_AUTH_VALIDATOR_ID: u64 = 4;
+        let id = auth_validator_registry::register_internal<schnorr_validator::SchnorrValidator>(ctx);
+        assert!(id == schnorr_validator::auth_validator_id(), ErrorGenesisInit);
+
+        // WEBAUTHN_AUTH_VALIDATOR_ID: u64 = 5;
+        let id = auth_validator_registry::register_internal<webauthn_validator::WebauthnValidator>(ctx);
+        assert!(id == webauthn_validator::auth_validator_id(), ErrorGenesisInit);
     }
 
     public fun is_builtin_auth_validator(auth_validator_id: u64): bool {
         auth_validator_id == native_validator::auth_validator_id()
         || auth_validator_id == ethereum_validator::auth_validator_id()
         || auth_validator_id == bitcoin_validator::auth_validator_id()
+        || auth_validator_id == multisig_validator::auth_validator_id()
+        || auth_validator_id == schnorr_validator::auth_validator_id()
+        || auth_validator_id == webauthn_validator::auth_validator_id()
     }
 }
@@ -0,0 +1,227 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+/// This module implements the k-of-n multisig validator.
+/// A multisig account is controlled by n public keys, and a transaction is valid if it is signed by at least k(threshold) of them.
+/// Every public key is prefixed with the signature scheme flag, the Ed25519 and the Secp256k1 schemes are supported.
+module rooch_framework::multisig_validator {
+
+    use std::vector;
+    use std::option;
+    use std::signer;
+    use moveos_std::bcs;
+    use moveos_std::context::{Self, Context};
+    use rooch_framework::hash;
+    use rooch_framework::account_authentication;
+    use rooch_framework::ed25519;
+    use rooch_framework::ecdsa_k1;
+    use rooch_framework::auth_validator;
+
+    /// there defines auth validator id for each blockchain
+    const MULTISIG_VALIDATOR_ID: u64 = 3;
+
+    /// The signature scheme flags of the multisig public keys
+    const SCHEME_ED25519: u8 = 0;
+    const SCHEME_SECP256K1: u8 = 1;
+
+    /// The max number of the public keys in a multisig account
+    const MAX_PUBLIC_KEYS: u64 = 16;
+
+    // error code
+    const ErrorInvalidThreshold: u64 = 1;
+    const ErrorInvalidPublicKey: u64 = 2;
+    const ErrorTooManyPublicKeys: u64 = 3;
+    const ErrorDuplicatePublicKey: u64 = 4;
+
+    struct MultisigValidator has store, drop {}
+
+    #[data_struct]
+    /// The authenticator payload of the multisig validator.
+    /// The `signer_indexes` are the indexes of the signers in the `public_keys` in ascending order,
+    /// and the `signatures` are the signatures of the tx hash in the same order.
+    struct MultisigPayload has copy, store, drop {
+        threshold: u8,
+        public_keys: vector<vector<u8>>,
+        signer_indexes: vector<u8>,
+        signatures: vector<vector<u8>>,
+    }
+
+    public fun auth_validator_id(): u64 {
+        MULTISIG_VALIDATOR_ID
+    }
+
+    public entry fun rotate_authentication_key_entry(
+        ctx: &mut Context,
+        account: &signer,
+        threshold: u8,
+        public_keys: vector<vector<u8>>
+    ) {
+        check_public_keys(threshold, &public_keys);
+        let authentication_key = public_keys_to_authentication_key(threshold, public_keys);
+        let account_addr = signer::address_of(account);
+        rotate_authentication_key(ctx, account_addr, authentication_key);
+    }
+
+    fun rotate_authentication_key(ctx: &mut Context, account_addr: address, authentication_key: vector<u8>) {
+        account_authentication::rotate_authentication_key<MultisigValidator>(ctx, account_addr, authentication_key);
+    }
+
+    public entry fun remove_authentication_key_entry(ctx: &mut Context, account: &signer) {
+        account_authentication::remove_authentication_key<MultisigValidator>(ctx, signer::address_of(account));
+    }
+
+    /// Check the threshold and the public keys of a multisig account.
+    public fun check_public_keys(threshold: u8, public_keys: &vector<vector<u8>>) {
+        let len = vector::length(public_keys);
+        assert!(len <= MAX_PUBLIC_KEYS, ErrorTooManyPublicKeys);
+        assert!(threshold > 0 && (threshold as u64) <= len, ErrorInvalidThreshold);
+        let i = 0;
+        while (i < len) {
+            let public_key = vector::borrow(public_keys, i);
+            assert!(is_valid_public_key(public_key), ErrorInvalidPublicKey);
+            let j = i + 1;
+            while (j < len) {
+                assert!(public_key != vector::borrow(public_keys, j), ErrorDuplicatePublicKey);
+                j = j + 1;
+            };
+            i = i + 1;
+        };
+    }
+
+    fun is_valid_public_key(public_key: &vector<u8>): bool {
+        let len = vector::length(public_key);
+        if (len == 0) {
+            return false
+        };
+        let scheme = *vector::borrow(public_key, 0);
+        if (scheme == SCHEME_ED25519) {
+            len == 1 + ed25519::public_key_length()
+        } else if (scheme == SCHEME_SECP256K1) {
+            len == 1 + ecdsa_k1::public_key_length()
+        } else {
+            false
+        }
+    }
+
+    /// Get the authentication key of the multisig account, it is the hash of the validator id, the threshold and the public keys.
+    public fun public_keys_to_authentication_key(threshold: u8, public_keys: vector<vector<u8>>): vector<u8> {
+        let bytes = vector::singleton((auth_validator_id() as u8));
+        vector::push_back(&mut bytes, threshold);
+        vector::append(&mut bytes, bcs::to_bytes(&public_keys));
+        hash::blake2b256(&bytes)
+    }
+
+    public fun public_keys_to_address(threshold: u8, public_keys: vector<vector<u8>>): address {
+        moveos_std::bcs::to_address(public_keys_to_authentication_key(threshold, public_keys))
+    }
+
+    /// Get the authentication key of the given account, if it not exist, return the account address as authentication key.
+    public fun get_authentication_key_with_default(ctx: &Context, addr: address): vector<u8> {
+        let auth_key_option = account_authentication::get_authentication_key<MultisigValidator>(ctx, addr);
+        if (option::is_some(&auth_key_option)) {
+            option::extract(&mut auth_key_option)
+        }else {
+            default_authentication_key(addr)
+        }
+    }
+
+    public fun default_authentication_key(addr: address): vector<u8> {
+        moveos_std::bcs::to_bytes(&addr)
+    }
+
+    fun verify_signature(public_key: &vector<u8>, signature: &vector<u8>, tx_hash: &vector<u8>): bool {
+        let scheme = *vector::borrow(public_key, 0);
+        let raw_public_key = vector::empty<u8>();
+        let i = 1;
+        let len = vector::length(public_key);
+        while (i < len) {
+            vector::push_back(&mut raw_public_key, *vector::borrow(public_key, i));
+            i = i + 1;
+        };
+        if (scheme == SCHEME_ED25519) {
+            ed25519::verify(signature, &raw_public_key, tx_hash)
+        } else {
+            ecdsa_k1::verify(signature, &raw_public_key, tx_hash, ecdsa_k1::sha256())
+        }
+    }
+
+    /// Only validate the authenticator's signatures, at least `threshold` distinct signers must sign the tx hash.
+    public fun validate_signature(payload: &MultisigPayload, tx_hash: &vector<u8>) {
+        check_public_keys(payload.threshold, &payload.public_keys);
+        let signature_count = vector::length(&payload.signatures);
+        assert!(
+            signature_count == vector::length(&payload.signer_indexes),
+            auth_validator::error_invalid_authenticator()
+        );
+        assert!(
+            signature_count >= (payload.threshold as u64),
+            auth_validator::error_invalid_authenticator()
+        );
+        let public_key_count = vector::length(&payload.public_keys);
+        let i = 0;
+        while (i < signature_count) {
+            let signer_index = (*vector::borrow(&payload.signer_indexes, i) as u64);
+            assert!(signer_index < public_key_count, auth_validator::error_invalid_authenticator());
+            // The signer indexes must be in strictly ascending order, so every signer only counts once
+            if (i > 0) {
+                assert!(
+                    signer_index > (*vector::borrow(&payload.signer_indexes, i - 1) as u64),
+                    auth_validator::error_invalid_authenticator()
+                );
+            };
+            assert!(
+                verify_signature(
+                    vector::borrow(&payload.public_keys, signer_index),
+                    vector::borrow(&payload.signatures, i),
+                    tx_hash
+                ),
+                auth_validator::error_invalid_authenticator()
+            );
+            i = i + 1;
+        };
+    }
+
+    public fun validate(ctx: &Context, authenticator_payload: vector<u8>) {
+        let tx_hash = context::tx_hash(ctx);
+        let payload = bcs::from_bytes<MultisigPayload>(authenticator_payload);
+        validate_signature(&payload, &tx_hash);
+
+        let auth_key_from_payload = public_keys_to_authentication_key(payload.threshold, payload.public_keys);
+        let auth_key_in_account = get_authentication_key_with_default(ctx, context::sender(ctx));
+        assert!(
+            auth_key_in_account == auth_key_from_payload,
+            auth_validator::error_invalid_account_auth_key()
+        );
+    }
+
+    fun pre_execute(
+        _ctx: &mut Context,
+    ) {}
+
+    fun post_execute(
+        ctx: &mut Context,
+    ) {
+        let account_addr = context::sender(ctx);
+        let auth_key_option = account_authentication::get_authentication_key<MultisigValidator>(ctx, account_addr);
+        // If the account does not have an authentication key, set the account address as the authentication key after the first transaction is executed.
+        if (option::is_none(&auth_key_option)) {
+            let authentication_key = default_authentication_key(account_addr);
+            rotate_authentication_key(ctx, account_addr, authentication_key);
+        }
+    }
+
+    #[test]
+    #[expected_failure(abort_code = ErrorInvalidThreshold, location = Self)]
+    fun test_check_public_keys_invalid_threshold() {
+        let public_keys = vector::singleton(x"003b6a27bcceb6a42d62a3a8d02a6f0d73653215771de243a63ac048a18b59da29");
+        check_public_keys(2, &public_keys);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = ErrorDuplicatePublicKey, location = Self)]
+    fun test_check_public_keys_duplicate() {
+        let public_keys = vector::singleton(x"003b6a27bcceb6a42d62a3a8d02a6f0d73653215771de243a63ac048a18b59da29");
+        vector::push_back(&mut public_keys, x"003b6a27bcceb6a42d62a3a8d02a6f0d73653215771de243a63ac048a18b59da29");
+        check_public_keys(1, &public_keys);
+    }
+}
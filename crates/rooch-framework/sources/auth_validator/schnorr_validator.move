@@ -0,0 +1,158 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+/// This module implements the BIP-340 Schnorr validator.
+/// The account is controlled by a secp256k1 x-only public key, the same key as the Bitcoin taproot key.
+/// The authenticator payload is `flag || signature || public key`.
+module rooch_framework::schnorr_validator {
+
+    use std::vector;
+    use std::option;
+    use std::signer;
+    use moveos_std::context::{Self, Context};
+    use rooch_framework::hash;
+    use rooch_framework::account_authentication;
+    use rooch_framework::schnorr;
+    use rooch_framework::auth_validator;
+
+    /// there defines auth validator id for each blockchain
+    const SCHNORR_VALIDATOR_ID: u64 = 4;
+
+    // error code
+    const ErrorInvalidPublicKeyLength: u64 = 1;
+
+    struct SchnorrValidator has store, drop {}
+
+    public fun auth_validator_id(): u64 {
+        SCHNORR_VALIDATOR_ID
+    }
+
+    public entry fun rotate_authentication_key_entry(
+        ctx: &mut Context,
+        account: &signer,
+        public_key: vector<u8>
+    ) {
+        assert!(
+            vector::length(&public_key) == schnorr::public_key_length(),
+            ErrorInvalidPublicKeyLength
+        );
+
+        let authentication_key = public_key_to_authentication_key(public_key);
+        let account_addr = signer::address_of(account);
+        rotate_authentication_key(ctx, account_addr, authentication_key);
+    }
+
+    fun rotate_authentication_key(ctx: &mut Context, account_addr: address, authentication_key: vector<u8>) {
+        account_authentication::rotate_authentication_key<SchnorrValidator>(ctx, account_addr, authentication_key);
+    }
+
+    public entry fun remove_authentication_key_entry(ctx: &mut Context, account: &signer) {
+        account_authentication::remove_authentication_key<SchnorrValidator>(ctx, signer::address_of(account));
+    }
+
+    fun sub_vector(bytes: &vector<u8>, start: u64, end: u64): vector<u8> {
+        let result = vector::empty<u8>();
+        let i = start;
+        while (i < end) {
+            vector::push_back(&mut result, *vector::borrow(bytes, i));
+            i = i + 1;
+        };
+        result
+    }
+
+    public fun get_public_key_from_authenticator_payload(authenticator_payload: &vector<u8>): vector<u8> {
+        let start = 1 + schnorr::signature_length();
+        sub_vector(authenticator_payload, start, start + schnorr::public_key_length())
+    }
+
+    public fun get_signature_from_authenticator_payload(authenticator_payload: &vector<u8>): vector<u8> {
+        sub_vector(authenticator_payload, 1, 1 + schnorr::signature_length())
+    }
+
+    /// Get the authentication key of the given authenticator from authenticator_payload.
+    public fun get_authentication_key_from_authenticator_payload(authenticator_payload: &vector<u8>): vector<u8> {
+        let public_key = get_public_key_from_authenticator_payload(authenticator_payload);
+        public_key_to_authentication_key(public_key)
+    }
+
+    public fun public_key_to_address(public_key: vector<u8>): address {
+        moveos_std::bcs::to_address(public_key_to_authentication_key(public_key))
+    }
+
+    /// Get the authentication key of the given public key, it is the same as the address derived from the public key.
+    public fun public_key_to_authentication_key(public_key: vector<u8>): vector<u8> {
+        let bytes = vector::singleton((auth_validator_id() as u8));
+        vector::append(&mut bytes, public_key);
+        hash::blake2b256(&bytes)
+    }
+
+    /// Get the authentication key of the given account, if it not exist, return the account address as authentication key.
+    public fun get_authentication_key_with_default(ctx: &Context, addr: address): vector<u8> {
+        let auth_key_option = account_authentication::get_authentication_key<SchnorrValidator>(ctx, addr);
+        if (option::is_some(&auth_key_option)) {
+            option::extract(&mut auth_key_option)
+        }else {
+            default_authentication_key(addr)
+        }
+    }
+
+    public fun default_authentication_key(addr: address): vector<u8> {
+        moveos_std::bcs::to_bytes(&addr)
+    }
+
+    /// Only validate the authenticator's signature.
+    public fun validate_signature(authenticator_payload: &vector<u8>, tx_hash: &vector<u8>) {
+        assert!(
+            vector::length(authenticator_payload) == 1 + schnorr::signature_length() + schnorr::public_key_length(),
+            auth_validator::error_invalid_authenticator()
+        );
+        assert!(
+            schnorr::verify(
+                &get_signature_from_authenticator_payload(authenticator_payload),
+                &get_public_key_from_authenticator_payload(authenticator_payload),
+                tx_hash
+            ),
+            auth_validator::error_invalid_authenticator()
+        );
+    }
+
+    public fun validate(ctx: &Context, authenticator_payload: vector<u8>) {
+        let tx_hash = context::tx_hash(ctx);
+        validate_signature(&authenticator_payload, &tx_hash);
+
+        let auth_key_from_authenticator_payload = get_authentication_key_from_authenticator_payload(&authenticator_payload);
+        let auth_key_in_account = get_authentication_key_with_default(ctx, context::sender(ctx));
+        assert!(
+            auth_key_in_account == auth_key_from_authenticator_payload,
+            auth_validator::error_invalid_account_auth_key()
+        );
+    }
+
+    fun pre_execute(
+        _ctx: &mut Context,
+    ) {}
+
+    fun post_execute(
+        ctx: &mut Context,
+    ) {
+        let account_addr = context::sender(ctx);
+        let auth_key_option = account_authentication::get_authentication_key<SchnorrValidator>(ctx, account_addr);
+        // If the account does not have an authentication key, set the account address as the authentication key after the first transaction is executed.
+        if (option::is_none(&auth_key_option)) {
+            let authentication_key = default_authentication_key(account_addr);
+            rotate_authentication_key(ctx, account_addr, authentication_key);
+        }
+    }
+
+    #[test]
+    fun test_get_public_key_and_signature_from_payload() {
+        let signature = x"6896bd60eeae296db48a229ff71dfe071bde413e6d43f917dc8dcf8c78de33418906d11ac976abccb20b091292bff4ea897efcb639ea871cfa95f6de339e4b0a";
+        let public_key = x"dff1d77f2a671c5f36183726db2341be58feae1da2deced843240f7b502ba659";
+        let payload = vector::singleton((auth_validator_id() as u8));
+        vector::append(&mut payload, signature);
+        vector::append(&mut payload, public_key);
+        assert!(get_signature_from_authenticator_payload(&payload) == signature, 1000);
+        assert!(get_public_key_from_authenticator_payload(&payload) == public_key, 1001);
+        validate_signature(&payload, &x"243f6a8885a308d313198a2e03707344a4093822299f31d0082efa98ec4e6c89");
+    }
+}
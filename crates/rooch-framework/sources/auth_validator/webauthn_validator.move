@@ -0,0 +1,199 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+/// This module implements the WebAuthn(passkey) validator.
+/// A passkey is registered for an account with the COSE_Key of the credential and its relying party, the rp id and the origin,
+/// the account is controlled by the passkey after that. The challenge of the assertion is the tx hash,
+/// and the assertion is only valid for the registered relying party, so the assertions of the other web pages are rejected.
+module rooch_framework::webauthn_validator {
+
+    use std::vector;
+    use std::option;
+    use std::signer;
+    use std::string::{Self, String};
+    use moveos_std::bcs;
+    use moveos_std::context::{Self, Context};
+    use rooch_framework::hash;
+    use rooch_framework::account_authentication;
+    use rooch_framework::webauthn;
+    use rooch_framework::auth_validator;
+
+    /// there defines auth validator id for each blockchain
+    const WEBAUTHN_VALIDATOR_ID: u64 = 5;
+
+    struct WebauthnValidator has store, drop {}
+
+    /// The relying party of the passkey registered for the account.
+    /// The `rp_id_hash` is the sha256 hash of the rp id, such as `rooch.network`, and the `origin` is the origin of the web page, such as `https://rooch.network`.
+    struct WebauthnCredential has key {
+        rp_id_hash: vector<u8>,
+        origin: String,
+    }
+
+    #[data_struct]
+    /// The authenticator payload of the webauthn validator.
+    /// The `signature` is the (r, s) signature of the assertion, and the `public_key` is the compressed P-256 public key of the passkey.
+    struct WebauthnPayload has copy, store, drop {
+        signature: vector<u8>,
+        public_key: vector<u8>,
+        authenticator_data: vector<u8>,
+        client_data_json: vector<u8>,
+    }
+
+    public fun auth_validator_id(): u64 {
+        WEBAUTHN_VALIDATOR_ID
+    }
+
+    /// Register the passkey for the account, the `cose_key` is the credential public key of the attestation,
+    /// the `rp_id` and the `origin` are the relying party which the credential is created for.
+    public entry fun rotate_authentication_key_entry(
+        ctx: &mut Context,
+        account: &signer,
+        cose_key: vector<u8>,
+        rp_id: vector<u8>,
+        origin: vector<u8>,
+    ) {
+        let public_key = webauthn::cose_key_to_public_key(&cose_key);
+        let authentication_key = public_key_to_authentication_key(public_key);
+        let account_addr = signer::address_of(account);
+        rotate_authentication_key(ctx, account_addr, authentication_key);
+
+        let rp_id_hash = hash::sha2_256(rp_id);
+        let origin = string::utf8(origin);
+        if (context::exists_resource<WebauthnCredential>(ctx, account_addr)) {
+            let credential = context::borrow_mut_resource<WebauthnCredential>(ctx, account_addr);
+            credential.rp_id_hash = rp_id_hash;
+            credential.origin = origin;
+        } else {
+            context::move_resource_to(ctx, account, WebauthnCredential { rp_id_hash, origin });
+        }
+    }
+
+    fun rotate_authentication_key(ctx: &mut Context, account_addr: address, authentication_key: vector<u8>) {
+        account_authentication::rotate_authentication_key<WebauthnValidator>(ctx, account_addr, authentication_key);
+    }
+
+    public entry fun remove_authentication_key_entry(ctx: &mut Context, account: &signer) {
+        let account_addr = signer::address_of(account);
+        account_authentication::remove_authentication_key<WebauthnValidator>(ctx, account_addr);
+        if (context::exists_resource<WebauthnCredential>(ctx, account_addr)) {
+            let WebauthnCredential { rp_id_hash: _, origin: _ } = context::move_resource_from<WebauthnCredential>(ctx, account_addr);
+        }
+    }
+
+    /// Get the authentication key of the given public key, it is the hash of the validator id and the compressed public key.
+    public fun public_key_to_authentication_key(public_key: vector<u8>): vector<u8> {
+        let bytes = vector::singleton((auth_validator_id() as u8));
+        vector::append(&mut bytes, public_key);
+        hash::blake2b256(&bytes)
+    }
+
+    public fun public_key_to_address(public_key: vector<u8>): address {
+        moveos_std::bcs::to_address(public_key_to_authentication_key(public_key))
+    }
+
+    /// Get the authentication key of the given account, if it not exist, return the account address as authentication key.
+    public fun get_authentication_key_with_default(ctx: &Context, addr: address): vector<u8> {
+        let auth_key_option = account_authentication::get_authentication_key<WebauthnValidator>(ctx, addr);
+        if (option::is_some(&auth_key_option)) {
+            option::extract(&mut auth_key_option)
+        }else {
+            default_authentication_key(addr)
+        }
+    }
+
+    public fun default_authentication_key(addr: address): vector<u8> {
+        moveos_std::bcs::to_bytes(&addr)
+    }
+
+    /// Only validate the authenticator's assertion, the challenge must be the tx hash,
+    /// and the assertion must be of the relying party with the `rp_id_hash` on the `origin`.
+    public fun validate_signature(payload: &WebauthnPayload, tx_hash: &vector<u8>, rp_id_hash: &vector<u8>, origin: &String) {
+        assert!(
+            vector::length(&payload.signature) == webauthn::signature_length(),
+            auth_validator::error_invalid_authenticator()
+        );
+        assert!(
+            vector::length(&payload.public_key) == webauthn::public_key_length(),
+            auth_validator::error_invalid_authenticator()
+        );
+        assert!(
+            webauthn::verify(
+                &payload.signature,
+                &payload.public_key,
+                &payload.authenticator_data,
+                &payload.client_data_json,
+                tx_hash,
+                rp_id_hash,
+                string::bytes(origin)
+            ),
+            auth_validator::error_invalid_authenticator()
+        );
+    }
+
+    public fun validate(ctx: &Context, authenticator_payload: vector<u8>) {
+        let tx_hash = context::tx_hash(ctx);
+        let sender = context::sender(ctx);
+        // The account without the registered passkey can not be controlled by any passkey
+        assert!(
+            context::exists_resource<WebauthnCredential>(ctx, sender),
+            auth_validator::error_invalid_account_auth_key()
+        );
+        let credential = context::borrow_resource<WebauthnCredential>(ctx, sender);
+        let payload = bcs::from_bytes<WebauthnPayload>(authenticator_payload);
+        validate_signature(&payload, &tx_hash, &credential.rp_id_hash, &credential.origin);
+
+        let auth_key_from_payload = public_key_to_authentication_key(payload.public_key);
+        let auth_key_in_account = get_authentication_key_with_default(ctx, sender);
+        assert!(
+            auth_key_in_account == auth_key_from_payload,
+            auth_validator::error_invalid_account_auth_key()
+        );
+    }
+
+    fun pre_execute(
+        _ctx: &mut Context,
+    ) {}
+
+    fun post_execute(
+        ctx: &mut Context,
+    ) {
+        let account_addr = context::sender(ctx);
+        let auth_key_option = account_authentication::get_authentication_key<WebauthnValidator>(ctx, account_addr);
+        // If the account does not have an authentication key, set the account address as the authentication key after the first transaction is executed.
+        if (option::is_none(&auth_key_option)) {
+            let authentication_key = default_authentication_key(account_addr);
+            rotate_authentication_key(ctx, account_addr, authentication_key);
+        }
+    }
+
+    #[test_only]
+    fun test_payload(): WebauthnPayload {
+        WebauthnPayload {
+            signature: x"81c6d5292a48c690b62f4eda9bee5da02bdb9833923a8bc01a0ffe63f141996c638e3e7bbd1db146ef4b8e8e27043ee5faa72c94cb28b8869974b0674053a319",
+            public_key: x"02c23939956bb7b263b549f1cfc608fcbad4d235a2bc3e30ceb89d2340b2e18263",
+            authenticator_data: x"701e5ca13e155a366732dbcbbbc694edc8c7fc06b03cfebc4c7361057a4f148d0500000001",
+            client_data_json: x"7b2274797065223a22776562617574686e2e676574222c226368616c6c656e6765223a2235525a3747325535654b585a484a7448796954766353576e704a54396653344870624e66512d3345306b38222c226f726967696e223a2268747470733a2f2f726f6f63682e6e6574776f726b222c2263726f73734f726967696e223a66616c73657d",
+        }
+    }
+
+    #[test]
+    fun test_validate_signature() {
+        let tx_hash = x"e5167b1b653978a5d91c9b47ca24ef7125a7a494fd7d2e07a5b35f43edc4d24f";
+        validate_signature(&test_payload(), &tx_hash, &hash::sha2_256(b"rooch.network"), &string::utf8(b"https://rooch.network"));
+    }
+
+    #[test]
+    #[expected_failure(location=Self, abort_code = 1002)]
+    fun test_validate_signature_fails_other_rp_id() {
+        let tx_hash = x"e5167b1b653978a5d91c9b47ca24ef7125a7a494fd7d2e07a5b35f43edc4d24f";
+        validate_signature(&test_payload(), &tx_hash, &hash::sha2_256(b"evil.network"), &string::utf8(b"https://rooch.network"));
+    }
+
+    #[test]
+    #[expected_failure(location=Self, abort_code = 1002)]
+    fun test_validate_signature_fails_other_origin() {
+        let tx_hash = x"e5167b1b653978a5d91c9b47ca24ef7125a7a494fd7d2e07a5b35f43edc4d24f";
+        validate_signature(&test_payload(), &tx_hash, &hash::sha2_256(b"rooch.network"), &string::utf8(b"https://evil.network"));
+    }
+}
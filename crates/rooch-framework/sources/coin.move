@@ -4,6 +4,7 @@
 /// This module provides the foundation for typesafe Coins.
 module rooch_framework::coin {
     use std::string;
+    use std::option::{Self, Option};
     use moveos_std::object_id;
     use moveos_std::object_id::ObjectID;
     use moveos_std::object::{Self, Object};
@@ -81,6 +82,8 @@ module rooch_framework::coin {
         /// Symbol of the coin, usually a shorter version of the name.
         /// For example, Singapore Dollar is SGD.
         symbol: string::String,
+        /// The url of the coin icon, it is displayed by the wallets. Mutable.
+        icon_url: Option<string::String>,
         /// Number of decimals used to get its user representation.
         /// For example, if `decimals` equals `2`, a balance of `505` coins should
         /// be displayed to a user as `5.05` (`505 / 10 ** 2`).
@@ -143,6 +146,11 @@ module rooch_framework::coin {
         coin_info.symbol
     }
 
+    /// Returns the url of the coin icon, if it is set.
+    public fun icon_url<CoinType: key>(coin_info: &CoinInfo<CoinType>): Option<string::String> {
+        coin_info.icon_url
+    }
+
     /// Returns the number of decimals used to get its user representation.
     /// For example, if `decimals` equals `2`, a balance of `505` coins should
     /// be displayed to a user as `5.05` (`505 / 10 ** 2`).
@@ -235,12 +243,24 @@ module rooch_framework::coin {
             coin_type,
             name,
             symbol,
+            icon_url: option::none(),
             decimals,
             supply: 0u256,
         };
         context::new_named_object(ctx, coin_info)
     }
 
+    #[private_generics(CoinType)]
+    /// Set or update the url of the coin icon
+    /// This function is protected by `private_generics`, so it can only be called by the `CoinType` module.
+    public fun upsert_icon_url_extend<CoinType: key>(
+        coin_info_obj: &mut Object<CoinInfo<CoinType>>,
+        icon_url: string::String,
+    ) {
+        let coin_info = object::borrow_mut(coin_info_obj);
+        coin_info.icon_url = option::some(icon_url);
+    }
+
     /// Public coin can mint by anyone with the mutable Object<CoinInfo<CoinType>>
     public fun mint<CoinType: key + store>(coin_info: &mut Object<CoinInfo<CoinType>>, amount: u256): Coin<CoinType> {
         mint_internal(coin_info, amount)
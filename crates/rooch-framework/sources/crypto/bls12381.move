@@ -0,0 +1,196 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+/// This module implements the BLS12-381 signature verification with the min-pk scheme,
+/// the public keys are 48-bytes G1 points and the signatures are 96-bytes G2 points, same as the Ethereum consensus.
+/// The aggregate signature verification requires the proof of possession of every public key,
+/// otherwise a rogue public key can forge the aggregate signature of the other public keys.
+module rooch_framework::bls12381 {
+    use std::vector;
+
+    /// constant codes
+    const BLS12381_PUBKEY_LENGTH: u64 = 48;
+    const BLS12381_SIG_LENGTH: u64 = 96;
+    const BLS12381_G1_LENGTH: u64 = 48;
+    const BLS12381_G2_LENGTH: u64 = 96;
+
+    /// Error if the signature is invalid.
+    const ErrorInvalidSignature: u64 = 1;
+
+    /// Error if the public key is invalid.
+    const ErrorInvalidPubKey: u64 = 2;
+
+    /// Error if the proof of possession is invalid.
+    const ErrorInvalidProofOfPossession: u64 = 3;
+
+    /// The public key whose proof of possession is verified, only the proven public keys can be aggregated.
+    struct ProvenPublicKey has copy, drop, store {
+        public_key: vector<u8>,
+    }
+
+    /// built-in functions
+    public fun public_key_length(): u64 {
+        BLS12381_PUBKEY_LENGTH
+    }
+
+    public fun signature_length(): u64 {
+        BLS12381_SIG_LENGTH
+    }
+
+    /// @param signature: A 96-bytes compressed G2 signature.
+    /// @param public_key: A 48-bytes compressed G1 public key that is used to sign messages.
+    /// @param msg: The message that the signature is signed against.
+    ///
+    /// If the signature is valid to the pubkey and message, return true. Else false.
+    native public fun verify(
+        signature: &vector<u8>,
+        public_key: &vector<u8>,
+        msg: &vector<u8>
+    ): bool;
+
+    /// @param pop: The 96-bytes compressed G2 signature of `b"ROOCH_BLS12381_POP_" || public_key`.
+    /// @param public_key: A 48-bytes compressed G1 public key.
+    ///
+    /// If the proof of possession of the private key is valid to the pubkey, return true. Else false.
+    native public fun verify_proof_of_possession(
+        pop: &vector<u8>,
+        public_key: &vector<u8>
+    ): bool;
+
+    /// Verify the proof of possession of the public key, abort if it is invalid.
+    /// The proven public key should be stored, such as in the validator set, to verify the aggregate signatures later.
+    public fun prove_public_key(pop: &vector<u8>, public_key: vector<u8>): ProvenPublicKey {
+        assert!(verify_proof_of_possession(pop, &public_key), ErrorInvalidProofOfPossession);
+        ProvenPublicKey { public_key }
+    }
+
+    public fun proven_public_key_bytes(proven_public_key: &ProvenPublicKey): &vector<u8> {
+        &proven_public_key.public_key
+    }
+
+    /// @param aggregate_signature: A 96-bytes compressed G2 signature aggregated from the signatures of the public keys.
+    /// @param public_keys: The proven public keys that signed the same message.
+    /// @param msg: The message that the signatures are signed against.
+    ///
+    /// If the aggregate signature is valid to all the pubkeys and the message, return true.
+    /// Else false, including the case of the empty public keys.
+    public fun aggregate_verify(
+        aggregate_signature: &vector<u8>,
+        public_keys: &vector<ProvenPublicKey>,
+        msg: &vector<u8>
+    ): bool {
+        let public_key_bytes = vector::empty<vector<u8>>();
+        let i = 0;
+        let len = vector::length(public_keys);
+        while (i < len) {
+            vector::push_back(&mut public_key_bytes, vector::borrow(public_keys, i).public_key);
+            i = i + 1;
+        };
+        aggregate_verify_internal(aggregate_signature, public_key_bytes, msg)
+    }
+
+    native fun aggregate_verify_internal(
+        aggregate_signature: &vector<u8>,
+        public_keys: vector<vector<u8>>,
+        msg: &vector<u8>
+    ): bool;
+
+    /// Hash the message to a G1 point with the hash-to-curve of the BLS signature ciphersuite,
+    /// return the 48-bytes compressed point.
+    native public fun hash_to_g1(msg: &vector<u8>): vector<u8>;
+
+    /// Hash the message to a G2 point with the hash-to-curve of the BLS signature ciphersuite,
+    /// return the 96-bytes compressed point.
+    native public fun hash_to_g2(msg: &vector<u8>): vector<u8>;
+
+    #[test_only]
+    /// The G1 generator, it is the public key of the private key 1.
+    fun g1_generator(): vector<u8> {
+        x"97f1d3a73197d7942695638c4fa9ac0fc3688c4f9774b905a14e3a3f171bac586c55e83ff97a1aeffb3af00adb22c6bb"
+    }
+
+    #[test_only]
+    fun g2_generator(): vector<u8> {
+        x"93e02b6052719f607dacd3a088274f65596bd0d09920b61ab5da61bbdc7f5049334cf11213945d57e5ac7d055d042b7e024aa2b2f08f0a91260805272dc51051c6e47ad4fa403b02b4510b647ae3d1770bac0326a805bbefd48056c8c121bdb8"
+    }
+
+    #[test]
+    fun test_hash_to_curve() {
+        let msg = b"hello rooch";
+        assert!(std::vector::length(&hash_to_g1(&msg)) == BLS12381_G1_LENGTH, 0);
+        assert!(std::vector::length(&hash_to_g2(&msg)) == BLS12381_G2_LENGTH, 1);
+        assert!(hash_to_g2(&msg) == hash_to_g2(&msg), 2);
+        assert!(hash_to_g2(&msg) != hash_to_g2(&b"hello"), 3);
+    }
+
+    // The signature of the private key 1 is the message hashed to G2
+    #[test]
+    fun test_verify_success() {
+        let msg = b"hello rooch";
+        let sig = hash_to_g2(&msg);
+        assert!(verify(&sig, &g1_generator(), &msg), 0);
+    }
+
+    #[test]
+    fun test_verify_fails_wrong_msg() {
+        let msg = b"hello rooch";
+        let sig = hash_to_g2(&msg);
+        assert!(!verify(&sig, &g1_generator(), &b"hello"), 0);
+        assert!(!verify(&g2_generator(), &g1_generator(), &msg), 1);
+    }
+
+    #[test]
+    #[expected_failure(location=Self, abort_code = ErrorInvalidSignature)]
+    fun test_verify_fails_invalid_sig() {
+        let msg = b"hello rooch";
+        verify(&x"", &g1_generator(), &msg);
+    }
+
+    #[test]
+    #[expected_failure(location=Self, abort_code = ErrorInvalidPubKey)]
+    fun test_verify_fails_invalid_pubkey() {
+        let msg = b"hello rooch";
+        let sig = hash_to_g2(&msg);
+        verify(&sig, &x"", &msg);
+    }
+
+    #[test_only]
+    /// The proof of possession of the private key 1 is the message hashed to G2
+    fun g1_generator_pop(): vector<u8> {
+        let msg = b"ROOCH_BLS12381_POP_";
+        vector::append(&mut msg, g1_generator());
+        hash_to_g2(&msg)
+    }
+
+    #[test]
+    fun test_verify_proof_of_possession() {
+        assert!(verify_proof_of_possession(&g1_generator_pop(), &g1_generator()), 0);
+        // The signature of the public key without the prefix is not a proof of possession
+        assert!(!verify_proof_of_possession(&hash_to_g2(&g1_generator()), &g1_generator()), 1);
+    }
+
+    #[test]
+    #[expected_failure(location=Self, abort_code = ErrorInvalidProofOfPossession)]
+    fun test_prove_public_key_fails_invalid_pop() {
+        prove_public_key(&hash_to_g2(&b"hello rooch"), g1_generator());
+    }
+
+    #[test]
+    fun test_aggregate_verify() {
+        let msg = b"hello rooch";
+        let sig = hash_to_g2(&msg);
+        let public_key = prove_public_key(&g1_generator_pop(), g1_generator());
+        assert!(proven_public_key_bytes(&public_key) == &g1_generator(), 0);
+        assert!(aggregate_verify(&sig, &vector[public_key], &msg), 1);
+        assert!(!aggregate_verify(&sig, &vector[public_key, public_key], &msg), 2);
+        assert!(!aggregate_verify(&sig, &vector[], &msg), 3);
+    }
+
+    #[test]
+    #[expected_failure(location=Self, abort_code = ErrorInvalidPubKey)]
+    fun test_aggregate_verify_fails_invalid_pubkey() {
+        let msg = b"hello rooch";
+        let sig = hash_to_g2(&msg);
+        aggregate_verify_internal(&sig, vector[g1_generator(), x"00"], &msg);
+    }
+}
@@ -0,0 +1,74 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+/// This module implements the ECDSA signature verification over the secp256r1(P-256) curve,
+/// it is the curve used by the WebAuthn/passkey ES256 algorithm.
+module rooch_framework::ecdsa_r1 {
+
+    /// constant codes
+    const ECDSA_R1_COMPRESSED_PUBKEY_LENGTH: u64 = 33;
+    const ECDSA_R1_SIG_LENGTH: u64 = 64;
+
+    /// Error if the signature is invalid.
+    const ErrorInvalidSignature: u64 = 1;
+
+    /// Error if the public key is invalid.
+    const ErrorInvalidPubKey: u64 = 2;
+
+    /// built-in functions
+    public fun public_key_length(): u64 {
+        ECDSA_R1_COMPRESSED_PUBKEY_LENGTH
+    }
+
+    public fun signature_length(): u64 {
+        ECDSA_R1_SIG_LENGTH
+    }
+
+    /// @param signature: A 64-bytes signature in form (r, s) that is signed using
+    /// Ecdsa over the secp256r1 curve.
+    /// @param public_key: A 33-bytes compressed public key that is used to sign messages.
+    /// @param msg: The message that the signature is signed against, the message is hashed with SHA256 when signing.
+    ///
+    /// If the signature is valid to the pubkey and hashed message, return true. Else false.
+    native public fun verify(
+        signature: &vector<u8>,
+        public_key: &vector<u8>,
+        msg: &vector<u8>
+    ): bool;
+
+    #[test]
+    fun test_verify_success() {
+        let msg = x"00010203";
+        let pubkey = x"02471c3e758c4904285bba7e53118ed0f524adeb0757d25bd2f8e7b0d76dfa714c";
+        let sig = x"01fd9860fe2c371203a893b5e40eadf957cc779b2536ef7e7aa860eed83b83d9345de4bf3f7e52ec40067b03093c3d7474b3fca988238f78897d5b83e5a0d1f8";
+        let result = verify(&sig, &pubkey, &msg);
+        assert!(result, 0);
+    }
+
+    #[test]
+    fun test_verify_fails_wrong_msg() {
+        let msg = x"0001020304";
+        let pubkey = x"02471c3e758c4904285bba7e53118ed0f524adeb0757d25bd2f8e7b0d76dfa714c";
+        let sig = x"01fd9860fe2c371203a893b5e40eadf957cc779b2536ef7e7aa860eed83b83d9345de4bf3f7e52ec40067b03093c3d7474b3fca988238f78897d5b83e5a0d1f8";
+        let result = verify(&sig, &pubkey, &msg);
+        assert!(!result, 0);
+    }
+
+    #[test]
+    #[expected_failure(location=Self, abort_code = ErrorInvalidSignature)]
+    fun test_verify_fails_invalid_sig() {
+        let msg = x"00010203";
+        let pubkey = x"02471c3e758c4904285bba7e53118ed0f524adeb0757d25bd2f8e7b0d76dfa714c";
+        let sig = x"";
+        verify(&sig, &pubkey, &msg);
+    }
+
+    #[test]
+    #[expected_failure(location=Self, abort_code = ErrorInvalidPubKey)]
+    fun test_verify_fails_invalid_pubkey() {
+        let msg = x"00010203";
+        let pubkey = x"";
+        let sig = x"01fd9860fe2c371203a893b5e40eadf957cc779b2536ef7e7aa860eed83b83d9345de4bf3f7e52ec40067b03093c3d7474b3fca988238f78897d5b83e5a0d1f8";
+        verify(&sig, &pubkey, &msg);
+    }
+}
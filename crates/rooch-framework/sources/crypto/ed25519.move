@@ -6,6 +6,9 @@ module rooch_framework::ed25519 {
     const ED25519_PUBKEY_LENGTH: u64 = 32;
     const ED25519_SIG_LENGTH: u64 = 64;
 
+    /// Error if the signatures, public keys and messages of the batch have different lengths.
+    const ErrorLengthMismatch: u64 = 1;
+
     /// built-in functions
     public fun public_key_length(): u64 {
         ED25519_PUBKEY_LENGTH
@@ -23,6 +26,20 @@ module rooch_framework::ed25519 {
     /// Otherwise, return false.
     native public fun verify(signature: &vector<u8>, public_key: &vector<u8>, msg: &vector<u8>): bool;
 
+    /// @param signatures: The 64-byte signatures.
+    /// @param public_keys: The 32-byte public keys, the i-th public key signs the i-th message.
+    /// @param msgs: The messages that we test the signatures against.
+    ///
+    /// Verify the signatures together, it is cheaper than verifying the signatures one by one.
+    /// If all the signatures are valid, return true.
+    /// Otherwise false, including the case of the empty batch.
+    /// Abort with ErrorLengthMismatch if the lengths of the vectors are different.
+    native public fun batch_verify(
+        signatures: vector<vector<u8>>,
+        public_keys: vector<vector<u8>>,
+        msgs: vector<vector<u8>>
+    ): bool;
+
     #[test]
     fun test_ed25519_valid_sig() {
         // Test generated from https://github.com/MystenLabs/fastcrypto/blob/874bb52ccadf9800b3bc21e640449705d7ff9ab0/fastcrypto/src/tests/ed25519_tests.rs
@@ -60,4 +77,27 @@ module rooch_framework::ed25519 {
         let verify = verify(&sig, &pk, &msg);
         assert!(verify == false, 0)
     }
+
+    #[test]
+    fun test_ed25519_batch_verify() {
+        let msg = x"315f5bdb76d078c43b8ac0064e4a0164612b1fce77c869345bfc94c75894edd3";
+        let pk = x"cc62332e34bb2d5cd69f60efbb2a36cb916c7eb458301ea36636c4dbb012bd88";
+        let sig = x"cce72947906dbae4c166fc01fd096432784032be43db540909bc901dbc057992b4d655ca4f4355cf0868e1266baacf6919902969f063e74162f8f04bc4056105";
+        let invalid_sig = x"cde72947906dbae4c166fc01fd096432784032be43db540909bc901dbc057992b4d655ca4f4355cf0868e1266baacf6919902969f063e74162f8f04bc4056105";
+
+        assert!(batch_verify(vector[sig, sig], vector[pk, pk], vector[msg, msg]), 0);
+        assert!(!batch_verify(vector[sig, invalid_sig], vector[pk, pk], vector[msg, msg]), 1);
+        assert!(!batch_verify(vector[sig, sig], vector[pk, x""], vector[msg, msg]), 2);
+        assert!(!batch_verify(vector[], vector[], vector[]), 3);
+    }
+
+    #[test]
+    #[expected_failure(location=Self, abort_code = ErrorLengthMismatch)]
+    fun test_ed25519_batch_verify_length_mismatch() {
+        let msg = x"315f5bdb76d078c43b8ac0064e4a0164612b1fce77c869345bfc94c75894edd3";
+        let pk = x"cc62332e34bb2d5cd69f60efbb2a36cb916c7eb458301ea36636c4dbb012bd88";
+        let sig = x"cce72947906dbae4c166fc01fd096432784032be43db540909bc901dbc057992b4d655ca4f4355cf0868e1266baacf6919902969f063e74162f8f04bc4056105";
+
+        batch_verify(vector[sig, sig], vector[pk], vector[msg, msg]);
+    }
 }
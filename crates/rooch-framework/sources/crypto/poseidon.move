@@ -0,0 +1,60 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+/// Module which defines the zk-friendly Poseidon hash function over the BN254 scalar field.
+/// The hash is compatible with the Poseidon of circomlib, so the Move contracts can verify the hashes
+/// and build the Merkle trees computed in the popular circuits.
+module rooch_framework::poseidon {
+   use std::vector;
+
+   /// The inputs are empty or not elements of the BN254 scalar field
+   const ErrorInvalidInput: u64 = 1;
+   /// The number of the inputs is larger than `MAX_INPUTS`
+   const ErrorTooManyInputs: u64 = 2;
+
+   /// The max number of the inputs of one hash
+   const MAX_INPUTS: u64 = 12;
+
+   /// The modulus of the BN254 scalar field
+   const BN254_SCALAR_MODULUS: u256 = 21888242871839275222246405745257275088548364400416034343698204186575808495617;
+
+   /// @param data: The inputs to hash, every input should be smaller than the BN254 scalar field modulus
+   /// Hash the inputs using the circom compatible Poseidon over BN254, up to 12 inputs.
+   public fun poseidon_bn254(data: &vector<u256>): u256 {
+      let len = vector::length(data);
+      assert!(len > 0, ErrorInvalidInput);
+      assert!(len <= MAX_INPUTS, ErrorTooManyInputs);
+      let i = 0;
+      while (i < len) {
+         assert!(*vector::borrow(data, i) < BN254_SCALAR_MODULUS, ErrorInvalidInput);
+         i = i + 1;
+      };
+      poseidon_bn254_internal(*data)
+   }
+
+   native fun poseidon_bn254_internal(data: vector<u256>): u256;
+
+   #[test]
+   fun test_poseidon_bn254() {
+      assert!(poseidon_bn254(&vector[1u256]) == 18586133768512220936620570745912940619677854269274689475585506675881198879027u256, 0);
+      assert!(poseidon_bn254(&vector[1u256, 2u256]) == 7853200120776062878684798364095072458815029376092732009249414926327459813530u256, 1);
+   }
+
+   #[test]
+   #[expected_failure(abort_code = ErrorInvalidInput, location = Self)]
+   fun test_poseidon_bn254_empty_input() {
+      poseidon_bn254(&vector[]);
+   }
+
+   #[test]
+   #[expected_failure(abort_code = ErrorInvalidInput, location = Self)]
+   fun test_poseidon_bn254_non_canonical_input() {
+      poseidon_bn254(&vector[BN254_SCALAR_MODULUS]);
+   }
+
+   #[test]
+   #[expected_failure(abort_code = ErrorTooManyInputs, location = Self)]
+   fun test_poseidon_bn254_too_many_inputs() {
+      poseidon_bn254(&vector[1u256, 2u256, 3u256, 4u256, 5u256, 6u256, 7u256, 8u256, 9u256, 10u256, 11u256, 12u256, 13u256]);
+   }
+}
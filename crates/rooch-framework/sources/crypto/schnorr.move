@@ -0,0 +1,86 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+/// This module implements the BIP-340 Schnorr signature verification over the secp256k1 curve,
+/// it is the signature scheme of the Bitcoin taproot.
+module rooch_framework::schnorr {
+
+    /// constant codes
+    const SCHNORR_PUBKEY_LENGTH: u64 = 32;
+    const SCHNORR_SIG_LENGTH: u64 = 64;
+
+    /// Error if the signature is invalid.
+    const ErrorInvalidSignature: u64 = 1;
+
+    /// Error if the public key is invalid.
+    const ErrorInvalidPubKey: u64 = 2;
+
+    /// Error if the message is not a 32 bytes digest.
+    const ErrorInvalidMessage: u64 = 3;
+
+    /// built-in functions
+    public fun public_key_length(): u64 {
+        SCHNORR_PUBKEY_LENGTH
+    }
+
+    public fun signature_length(): u64 {
+        SCHNORR_SIG_LENGTH
+    }
+
+    /// @param signature: A 64-bytes BIP-340 Schnorr signature.
+    /// @param public_key: A 32-bytes x-only public key that is used to sign messages.
+    /// @param msg: The 32-bytes message digest that the signature is signed against.
+    ///
+    /// If the signature is valid to the pubkey and message digest, return true. Else false.
+    native public fun verify(
+        signature: &vector<u8>,
+        public_key: &vector<u8>,
+        msg: &vector<u8>
+    ): bool;
+
+    // BIP-340 test vector 1
+    #[test]
+    fun test_verify_success() {
+        let msg = x"243f6a8885a308d313198a2e03707344a4093822299f31d0082efa98ec4e6c89";
+        let pubkey = x"dff1d77f2a671c5f36183726db2341be58feae1da2deced843240f7b502ba659";
+        let sig = x"6896bd60eeae296db48a229ff71dfe071bde413e6d43f917dc8dcf8c78de33418906d11ac976abccb20b091292bff4ea897efcb639ea871cfa95f6de339e4b0a";
+        let result = verify(&sig, &pubkey, &msg);
+        assert!(result, 0);
+    }
+
+    #[test]
+    fun test_verify_fails_wrong_msg() {
+        let msg = x"0000000000000000000000000000000000000000000000000000000000000000";
+        let pubkey = x"dff1d77f2a671c5f36183726db2341be58feae1da2deced843240f7b502ba659";
+        let sig = x"6896bd60eeae296db48a229ff71dfe071bde413e6d43f917dc8dcf8c78de33418906d11ac976abccb20b091292bff4ea897efcb639ea871cfa95f6de339e4b0a";
+        let result = verify(&sig, &pubkey, &msg);
+        assert!(!result, 0);
+    }
+
+    #[test]
+    #[expected_failure(location=Self, abort_code = ErrorInvalidSignature)]
+    fun test_verify_fails_invalid_sig() {
+        let msg = x"243f6a8885a308d313198a2e03707344a4093822299f31d0082efa98ec4e6c89";
+        let pubkey = x"dff1d77f2a671c5f36183726db2341be58feae1da2deced843240f7b502ba659";
+        let sig = x"";
+        verify(&sig, &pubkey, &msg);
+    }
+
+    #[test]
+    #[expected_failure(location=Self, abort_code = ErrorInvalidPubKey)]
+    fun test_verify_fails_invalid_pubkey() {
+        let msg = x"243f6a8885a308d313198a2e03707344a4093822299f31d0082efa98ec4e6c89";
+        let pubkey = x"";
+        let sig = x"6896bd60eeae296db48a229ff71dfe071bde413e6d43f917dc8dcf8c78de33418906d11ac976abccb20b091292bff4ea897efcb639ea871cfa95f6de339e4b0a";
+        verify(&sig, &pubkey, &msg);
+    }
+
+    #[test]
+    #[expected_failure(location=Self, abort_code = ErrorInvalidMessage)]
+    fun test_verify_fails_invalid_msg() {
+        let msg = x"00010203";
+        let pubkey = x"dff1d77f2a671c5f36183726db2341be58feae1da2deced843240f7b502ba659";
+        let sig = x"6896bd60eeae296db48a229ff71dfe071bde413e6d43f917dc8dcf8c78de33418906d11ac976abccb20b091292bff4ea897efcb639ea871cfa95f6de339e4b0a";
+        verify(&sig, &pubkey, &msg);
+    }
+}
@@ -0,0 +1,144 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+/// This module implements the verification of the WebAuthn(passkey) assertions.
+/// The credential public key is a COSE_Key encoded in CBOR, only the ES256 algorithm over the P-256 curve is supported.
+/// The authenticator signs `authenticator_data || sha256(client_data_json)`, and the challenge in the client data is base64url encoded.
+/// The authenticator data starts with the sha256 hash of the rp id, and the client data has the origin of the web page,
+/// they are checked against the relying party of the registered credential.
+module rooch_framework::webauthn {
+
+    /// constant codes
+    const WEBAUTHN_PUBKEY_LENGTH: u64 = 33;
+    const WEBAUTHN_SIG_LENGTH: u64 = 64;
+
+    /// Error if the COSE key is not an ES256 key over the P-256 curve.
+    const ErrorInvalidCoseKey: u64 = 1;
+
+    /// Error if the signature is invalid.
+    const ErrorInvalidSignature: u64 = 2;
+
+    /// Error if the public key is invalid.
+    const ErrorInvalidPubKey: u64 = 3;
+
+    /// built-in functions
+    public fun public_key_length(): u64 {
+        WEBAUTHN_PUBKEY_LENGTH
+    }
+
+    public fun signature_length(): u64 {
+        WEBAUTHN_SIG_LENGTH
+    }
+
+    /// Decode the credential public key of the attestation, return the 33-bytes compressed P-256 public key.
+    native public fun cose_key_to_public_key(cose_key: &vector<u8>): vector<u8>;
+
+    /// @param signature: A 64-bytes signature in form (r, s) of the assertion.
+    /// @param public_key: A 33-bytes compressed P-256 public key of the credential.
+    /// @param authenticator_data: The authenticator data of the assertion.
+    /// @param client_data_json: The client data JSON of the assertion.
+    /// @param challenge: The challenge bytes expected in the client data.
+    /// @param rp_id_hash: The sha256 hash of the rp id expected at the start of the authenticator data.
+    /// @param origin: The origin expected in the client data, such as `https://rooch.network`.
+    ///
+    /// Return true if the client data is of the `webauthn.get` type with the challenge on the origin and not cross-origin,
+    /// the authenticator data is of the rp id, the user is present, and the signature is valid to the pubkey. Else false.
+    native public fun verify(
+        signature: &vector<u8>,
+        public_key: &vector<u8>,
+        authenticator_data: &vector<u8>,
+        client_data_json: &vector<u8>,
+        challenge: &vector<u8>,
+        rp_id_hash: &vector<u8>,
+        origin: &vector<u8>
+    ): bool;
+
+    #[test]
+    fun test_cose_key_to_public_key() {
+        let cose_key = x"a5010203262001215820c23939956bb7b263b549f1cfc608fcbad4d235a2bc3e30ceb89d2340b2e18263225820ab2c55b2756103dae7f47ca6363391ffd211385c415ce294dd901f6e8c944c6c";
+        assert!(cose_key_to_public_key(&cose_key) == x"02c23939956bb7b263b549f1cfc608fcbad4d235a2bc3e30ceb89d2340b2e18263", 0);
+    }
+
+    #[test]
+    #[expected_failure(location=Self, abort_code = ErrorInvalidCoseKey)]
+    fun test_cose_key_to_public_key_fails_ed25519_key() {
+        // The Ed25519 COSE key {1: 1, 3: -8}
+        cose_key_to_public_key(&x"a201010327");
+    }
+
+    #[test_only]
+    /// The relying party of the test credential, the sha256 hash of `rooch.network` and the origin `https://rooch.network`
+    fun test_relying_party(): (vector<u8>, vector<u8>) {
+        (x"701e5ca13e155a366732dbcbbbc694edc8c7fc06b03cfebc4c7361057a4f148d", b"https://rooch.network")
+    }
+
+    #[test_only]
+    /// The assertion of the challenge signed by the test credential, the client data is
+    /// {"type":"webauthn.get","challenge":"5RZ7G2U5eKXZHJtHyiTvcSWnpJT9fS4HpbNfQ-3E0k8","origin":"https://rooch.network","crossOrigin":false}
+    fun test_assertion(): (vector<u8>, vector<u8>, vector<u8>, vector<u8>, vector<u8>) {
+        let sig = x"81c6d5292a48c690b62f4eda9bee5da02bdb9833923a8bc01a0ffe63f141996c638e3e7bbd1db146ef4b8e8e27043ee5faa72c94cb28b8869974b0674053a319";
+        let pubkey = x"02c23939956bb7b263b549f1cfc608fcbad4d235a2bc3e30ceb89d2340b2e18263";
+        let authenticator_data = x"701e5ca13e155a366732dbcbbbc694edc8c7fc06b03cfebc4c7361057a4f148d0500000001";
+        let client_data_json = x"7b2274797065223a22776562617574686e2e676574222c226368616c6c656e6765223a2235525a3747325535654b585a484a7448796954766353576e704a54396653344870624e66512d3345306b38222c226f726967696e223a2268747470733a2f2f726f6f63682e6e6574776f726b222c2263726f73734f726967696e223a66616c73657d";
+        let challenge = x"e5167b1b653978a5d91c9b47ca24ef7125a7a494fd7d2e07a5b35f43edc4d24f";
+        (sig, pubkey, authenticator_data, client_data_json, challenge)
+    }
+
+    #[test]
+    fun test_verify_success() {
+        let (sig, pubkey, authenticator_data, client_data_json, challenge) = test_assertion();
+        let (rp_id_hash, origin) = test_relying_party();
+        let result = verify(&sig, &pubkey, &authenticator_data, &client_data_json, &challenge, &rp_id_hash, &origin);
+        assert!(result, 0);
+    }
+
+    #[test]
+    fun test_verify_fails_other_rp_id() {
+        let (sig, pubkey, authenticator_data, client_data_json, challenge) = test_assertion();
+        let (_rp_id_hash, origin) = test_relying_party();
+        let result = verify(&sig, &pubkey, &authenticator_data, &client_data_json, &challenge, &x"00", &origin);
+        assert!(!result, 0);
+    }
+
+    #[test]
+    fun test_verify_fails_other_origin() {
+        let (sig, pubkey, authenticator_data, client_data_json, challenge) = test_assertion();
+        let (rp_id_hash, _origin) = test_relying_party();
+        let result = verify(&sig, &pubkey, &authenticator_data, &client_data_json, &challenge, &rp_id_hash, &b"https://evil.network");
+        assert!(!result, 0);
+    }
+
+    #[test]
+    fun test_verify_fails_wrong_challenge() {
+        let (sig, pubkey, authenticator_data, client_data_json, _challenge) = test_assertion();
+        let (rp_id_hash, origin) = test_relying_party();
+        let result = verify(&sig, &pubkey, &authenticator_data, &client_data_json, &x"00", &rp_id_hash, &origin);
+        assert!(!result, 0);
+    }
+
+    #[test]
+    fun test_verify_fails_user_not_present() {
+        let (sig, pubkey, _authenticator_data, client_data_json, challenge) = test_assertion();
+        // The flags of the authenticator data are cleared
+        let authenticator_data = x"701e5ca13e155a366732dbcbbbc694edc8c7fc06b03cfebc4c7361057a4f148d0000000001";
+        let (rp_id_hash, origin) = test_relying_party();
+        let result = verify(&sig, &pubkey, &authenticator_data, &client_data_json, &challenge, &rp_id_hash, &origin);
+        assert!(!result, 0);
+    }
+
+    #[test]
+    #[expected_failure(location=Self, abort_code = ErrorInvalidSignature)]
+    fun test_verify_fails_invalid_sig() {
+        let (_sig, pubkey, authenticator_data, client_data_json, challenge) = test_assertion();
+        let (rp_id_hash, origin) = test_relying_party();
+        verify(&x"", &pubkey, &authenticator_data, &client_data_json, &challenge, &rp_id_hash, &origin);
+    }
+
+    #[test]
+    #[expected_failure(location=Self, abort_code = ErrorInvalidPubKey)]
+    fun test_verify_fails_invalid_pubkey() {
+        let (sig, _pubkey, authenticator_data, client_data_json, challenge) = test_assertion();
+        let (rp_id_hash, origin) = test_relying_party();
+        verify(&sig, &x"", &authenticator_data, &client_data_json, &challenge, &rp_id_hash, &origin);
+    }
+}
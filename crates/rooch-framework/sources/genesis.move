@@ -4,7 +4,9 @@
 module rooch_framework::genesis {
 
     use std::option;
+    use std::vector;
     use moveos_std::context::{Self, Context};
+    use moveos_std::signer;
     use rooch_framework::account;
     use rooch_framework::auth_validator_registry;
     use rooch_framework::builtin_validators;
@@ -17,8 +19,10 @@ module rooch_framework::genesis {
     use rooch_framework::address_mapping;
     use rooch_framework::ethereum_light_client;
     use rooch_framework::onchain_config;
+    use rooch_framework::scheduler;
 
     const ErrorGenesisInit: u64 = 1;
+    const ErrorGenesisAllocation: u64 = 2;
 
     /// GenesisContext is a genesis init parameters in the TxContext.
     struct GenesisContext has copy,store,drop{
@@ -48,11 +52,24 @@ module rooch_framework::genesis {
         address_mapping::genesis_init(ctx, genesis_account);
         ethereum_light_client::genesis_init(ctx, genesis_account);
         onchain_config::genesis_init(ctx, genesis_account, genesis_context.sequencer, genesis_context.gas_schedule_blob);
+        scheduler::genesis_init(ctx, genesis_account);
     }
 
+    /// Allocate the initial gas coins to the accounts, the `amounts[i]` is allocated to the `addresses[i]`.
+    /// It is called by the genesis transaction after the framework is published, and only the genesis account can call it.
+    entry fun allocate(ctx: &mut Context, genesis_account: &signer, addresses: vector<address>, amounts: vector<u256>){
+        assert!(signer::address_of(genesis_account) == @rooch_framework, ErrorGenesisAllocation);
+        assert!(vector::length(&addresses) == vector::length(&amounts), ErrorGenesisAllocation);
+        let i = 0;
+        let len = vector::length(&addresses);
+        while (i < len) {
+            let addr = *vector::borrow(&addresses, i);
+            let amount = *vector::borrow(&amounts, i);
+            gas_coin::faucet(ctx, addr, amount);
+            i = i + 1;
+        };
+    }
 
-    #[test_only]
-    use std::vector;
 
     #[test_only]
     /// init the genesis context for test, and return the Context with @rooch_framework genesis account
@@ -63,4 +80,23 @@ module rooch_framework::genesis {
         init(&mut ctx);
         ctx
     }
+
+    #[test]
+    fun test_allocate(){
+        let ctx = init_for_test();
+        let genesis_account = moveos_std::signer::module_signer<GenesisContext>();
+        allocate(&mut ctx, &genesis_account, vector[@0x42, @0x43], vector[100u256, 200u256]);
+        assert!(gas_coin::balance(&ctx, @0x42) == 100u256, 1000);
+        assert!(gas_coin::balance(&ctx, @0x43) == 200u256, 1001);
+        moveos_std::context::drop_test_context(ctx);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = ErrorGenesisAllocation, location = Self)]
+    fun test_allocate_mismatched_amounts(){
+        let ctx = init_for_test();
+        let genesis_account = moveos_std::signer::module_signer<GenesisContext>();
+        allocate(&mut ctx, &genesis_account, vector[@0x42], vector[]);
+        moveos_std::context::drop_test_context(ctx);
+    }
 }
@@ -15,6 +15,7 @@ module rooch_framework::onchain_config {
     friend rooch_framework::genesis;
 
     const ErrorNotSequencer: u64 = 1;
+    const ErrorGasScheduleVersionTooOld: u64 = 2;
 
     struct GasScheduleUpdated has store, copy, drop {
         last_updated: u64
@@ -84,23 +85,24 @@ module rooch_framework::onchain_config {
         object::borrow(obj)
     }
 
+    /// Update the onchain gas schedule, the `feature_version` of the new gas schedule must be greater than the current one.
+    /// The new gas schedule takes effect from the next transaction.
     entry fun update_onchain_gas_schedule(ctx: &mut Context, account: &signer, gas_schedule_blob: vector<u8>) {
         let sender_address = signer::address_of(account);
         assert!(sender_address == Self::sequencer(ctx), ErrorNotSequencer);
 
-        let gas_schedule = GasSchedule {
-            feature_version: 0,
-            entries: vector::empty<GasEntry>()
-        };
-
-        if (vector::length(&gas_schedule_blob) > 0) {
-            gas_schedule = bcs::from_bytes<GasSchedule>(gas_schedule_blob);
-        };
+        let gas_schedule = bcs::from_bytes<GasSchedule>(gas_schedule_blob);
+        let current = onchain_gas_schedule_mut(ctx);
+        assert!(gas_schedule.feature_version > current.feature_version, ErrorGasScheduleVersionTooOld);
+        *current = gas_schedule;
 
         context::add(ctx, GasScheduleUpdated {last_updated: 1});
+    }
 
-        let obj = context::new_named_object(ctx, gas_schedule);
-        object::transfer_extend(obj, @rooch_framework);
+    fun onchain_gas_schedule_mut(ctx: &mut Context): &mut GasSchedule {
+        let object_id = object_id::named_object_id<GasSchedule>();
+        let obj = context::borrow_mut_object_extend<GasSchedule>(ctx, object_id);
+        object::borrow_mut(obj)
     }
 
     public fun onchain_gas_schedule(ctx: &Context): &GasSchedule {
@@ -108,4 +110,40 @@ module rooch_framework::onchain_config {
         let obj = context::borrow_object<GasSchedule>(ctx, object_id);
         object::borrow(obj)
     }
+
+    public fun gas_schedule_version(ctx: &Context): u64 {
+        onchain_gas_schedule(ctx).feature_version
+    }
+
+    #[test_only]
+    use rooch_framework::account;
+
+    #[test_only]
+    fun gas_schedule_blob_for_test(feature_version: u64): vector<u8> {
+        let entries = vector::singleton(GasEntry { key: std::string::utf8(b"instr.nop"), val: 1 });
+        std::bcs::to_bytes(&GasSchedule { feature_version, entries })
+    }
+
+    #[test]
+    fun test_update_onchain_gas_schedule() {
+        let ctx = context::new_test_context(@rooch_framework);
+        let genesis_account = account::create_signer_for_test(@rooch_framework);
+        genesis_init(&mut ctx, &genesis_account, @rooch_framework, gas_schedule_blob_for_test(1));
+        assert!(gas_schedule_version(&ctx) == 1, 1000);
+
+        update_onchain_gas_schedule(&mut ctx, &genesis_account, gas_schedule_blob_for_test(2));
+        assert!(gas_schedule_version(&ctx) == 2, 1001);
+        assert!(vector::length(&onchain_gas_schedule(&ctx).entries) == 1, 1002);
+        context::drop_test_context(ctx);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = ErrorGasScheduleVersionTooOld, location = Self)]
+    fun test_update_onchain_gas_schedule_with_old_version() {
+        let ctx = context::new_test_context(@rooch_framework);
+        let genesis_account = account::create_signer_for_test(@rooch_framework);
+        genesis_init(&mut ctx, &genesis_account, @rooch_framework, gas_schedule_blob_for_test(2));
+        update_onchain_gas_schedule(&mut ctx, &genesis_account, gas_schedule_blob_for_test(2));
+        context::drop_test_context(ctx);
+    }
 }
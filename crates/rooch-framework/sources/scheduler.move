@@ -0,0 +1,256 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+/// The scheduler executes the registered Move callbacks periodically, like the cron.
+///
+/// A module registers a task with a witness type `T` defined in the module, and a callback entry function
+/// `entry fun callback(ctx: &mut Context, task_id: u64)` in the same module. The callback must call `run<T>`
+/// first, which checks the task is due and pays the per-run fee from the prefunded gas of the task to the sender.
+/// The keeper of the node, or anyone else, triggers the callback when the task is due.
+module rooch_framework::scheduler {
+
+    use std::option::{Self, Option};
+    use std::string::{Self, String};
+    use std::vector;
+    use moveos_std::context::{Self, Context};
+    use moveos_std::event;
+    use moveos_std::object::{Self, Object};
+    use moveos_std::object_id;
+    use moveos_std::signer;
+    use moveos_std::table::{Self, Table};
+    use moveos_std::type_info;
+    use rooch_framework::account_coin_store;
+    use rooch_framework::coin_store::{Self, CoinStore};
+    use rooch_framework::gas_coin::GasCoin;
+    use rooch_framework::timestamp;
+
+    friend rooch_framework::genesis;
+
+    const ErrorTaskNotFound: u64 = 1;
+    const ErrorInvalidInterval: u64 = 2;
+    const ErrorNotTaskOwner: u64 = 3;
+    const ErrorTaskNotDue: u64 = 4;
+    const ErrorInsufficientPrefund: u64 = 5;
+    const ErrorCallbackTypeMismatch: u64 = 6;
+
+    #[data_struct]
+    /// The registered task, the callback is `callback_address::callback_module::function_name(ctx, task_id)`
+    struct ScheduledTask has copy, drop, store {
+        task_id: u64,
+        owner: address,
+        callback_address: address,
+        callback_module: String,
+        function_name: String,
+        interval_seconds: u64,
+        /// The timestamp in seconds of the next run, the task is due when the current time reaches it
+        next_run_seconds: u64,
+        /// The gas coins paid to the sender of the callback transaction on every run
+        fee_per_run: u256,
+        /// The remaining prefunded gas coins of the task
+        balance: u256,
+        run_count: u64,
+    }
+
+    struct Scheduler has key {
+        next_task_id: u64,
+        /// The ids of the registered tasks, in the order of registration
+        task_ids: vector<u64>,
+        tasks: Table<u64, ScheduledTask>,
+        /// The prefunded gas coins of all the tasks
+        gas_pool: Object<CoinStore<GasCoin>>,
+    }
+
+    struct TaskRegisteredEvent has drop, store {
+        task_id: u64,
+        owner: address,
+    }
+
+    struct TaskExecutedEvent has drop, store {
+        task_id: u64,
+        keeper: address,
+        next_run_seconds: u64,
+    }
+
+    struct TaskCancelledEvent has drop, store {
+        task_id: u64,
+        refund: u256,
+    }
+
+    public(friend) fun genesis_init(ctx: &mut Context, _genesis_account: &signer) {
+        let gas_pool = coin_store::create_coin_store<GasCoin>(ctx);
+        let tasks = context::new_table<u64, ScheduledTask>(ctx);
+        let obj = context::new_named_object(ctx, Scheduler {
+            next_task_id: 0,
+            task_ids: vector::empty(),
+            tasks,
+            gas_pool,
+        });
+        object::transfer_extend(obj, @rooch_framework);
+    }
+
+    fun borrow_scheduler(ctx: &Context): &Scheduler {
+        let object_id = object_id::named_object_id<Scheduler>();
+        object::borrow(context::borrow_object<Scheduler>(ctx, object_id))
+    }
+
+    fun borrow_mut_scheduler(ctx: &mut Context): &mut Scheduler {
+        let object_id = object_id::named_object_id<Scheduler>();
+        object::borrow_mut(context::borrow_mut_object_extend<Scheduler>(ctx, object_id))
+    }
+
+    #[private_generics(T)]
+    /// Register a task calling the `function_name` of the module of `T` every `interval_seconds`.
+    /// The `prefund` is withdrawn from the owner to pay the `fee_per_run` of the runs, the first run is due after one interval.
+    public fun register<T>(
+        ctx: &mut Context,
+        owner: &signer,
+        function_name: String,
+        interval_seconds: u64,
+        fee_per_run: u256,
+        prefund: u256,
+    ): u64 {
+        assert!(interval_seconds > 0, ErrorInvalidInterval);
+        let owner_addr = signer::address_of(owner);
+        let coin = account_coin_store::withdraw<GasCoin>(ctx, owner, prefund);
+        let next_run_seconds = timestamp::now_seconds(ctx) + interval_seconds;
+        let callback_type = type_info::type_of<T>();
+        let scheduler = borrow_mut_scheduler(ctx);
+        coin_store::deposit(&mut scheduler.gas_pool, coin);
+        let task_id = scheduler.next_task_id;
+        scheduler.next_task_id = task_id + 1;
+        table::add(&mut scheduler.tasks, task_id, ScheduledTask {
+            task_id,
+            owner: owner_addr,
+            callback_address: type_info::account_address(&callback_type),
+            callback_module: string::utf8(type_info::module_name(&callback_type)),
+            function_name,
+            interval_seconds,
+            next_run_seconds,
+            fee_per_run,
+            balance: prefund,
+            run_count: 0,
+        });
+        vector::push_back(&mut scheduler.task_ids, task_id);
+        event::emit(TaskRegisteredEvent { task_id, owner: owner_addr });
+        task_id
+    }
+
+    #[private_generics(T)]
+    /// Mark the run of the task, it should be called by the callback of the task before doing the work.
+    /// Aborts if the task is not due or the prefund is not enough, otherwise the fee is paid to the sender.
+    public fun run<T>(ctx: &mut Context, task_id: u64) {
+        let now = timestamp::now_seconds(ctx);
+        let keeper = context::sender(ctx);
+        let callback_type = type_info::type_of<T>();
+        let scheduler = borrow_mut_scheduler(ctx);
+        assert!(table::contains(&scheduler.tasks, task_id), ErrorTaskNotFound);
+        let task = table::borrow_mut(&mut scheduler.tasks, task_id);
+        assert!(
+            task.callback_address == type_info::account_address(&callback_type)
+                && *string::bytes(&task.callback_module) == type_info::module_name(&callback_type),
+            ErrorCallbackTypeMismatch
+        );
+        assert!(now >= task.next_run_seconds, ErrorTaskNotDue);
+        assert!(task.balance >= task.fee_per_run, ErrorInsufficientPrefund);
+        let fee = task.fee_per_run;
+        task.balance = task.balance - fee;
+        task.run_count = task.run_count + 1;
+        // Skip the missed runs, so a task is run at most once for every due time
+        let missed = (now - task.next_run_seconds) / task.interval_seconds;
+        task.next_run_seconds = task.next_run_seconds + (missed + 1) * task.interval_seconds;
+        let next_run_seconds = task.next_run_seconds;
+        let fee_coin = coin_store::withdraw(&mut scheduler.gas_pool, fee);
+        account_coin_store::deposit(ctx, keeper, fee_coin);
+        event::emit(TaskExecutedEvent { task_id, keeper, next_run_seconds });
+    }
+
+    /// Add more gas coins to the prefund of the task, anyone can fund a task.
+    public entry fun fund(ctx: &mut Context, account: &signer, task_id: u64, amount: u256) {
+        let coin = account_coin_store::withdraw<GasCoin>(ctx, account, amount);
+        let scheduler = borrow_mut_scheduler(ctx);
+        assert!(table::contains(&scheduler.tasks, task_id), ErrorTaskNotFound);
+        let task = table::borrow_mut(&mut scheduler.tasks, task_id);
+        task.balance = task.balance + amount;
+        coin_store::deposit(&mut scheduler.gas_pool, coin);
+    }
+
+    /// Cancel the task, the remaining prefund is refunded to the owner.
+    public entry fun cancel(ctx: &mut Context, owner: &signer, task_id: u64) {
+        let owner_addr = signer::address_of(owner);
+        let scheduler = borrow_mut_scheduler(ctx);
+        assert!(table::contains(&scheduler.tasks, task_id), ErrorTaskNotFound);
+        assert!(table::borrow(&scheduler.tasks, task_id).owner == owner_addr, ErrorNotTaskOwner);
+        let task = table::remove(&mut scheduler.tasks, task_id);
+        let (_, index) = vector::index_of(&scheduler.task_ids, &task_id);
+        vector::remove(&mut scheduler.task_ids, index);
+        let refund = coin_store::withdraw(&mut scheduler.gas_pool, task.balance);
+        account_coin_store::deposit(ctx, owner_addr, refund);
+        event::emit(TaskCancelledEvent { task_id, refund: task.balance });
+    }
+
+    public fun task(ctx: &Context, task_id: u64): Option<ScheduledTask> {
+        let scheduler = borrow_scheduler(ctx);
+        if (table::contains(&scheduler.tasks, task_id)) {
+            option::some(*table::borrow(&scheduler.tasks, task_id))
+        } else {
+            option::none()
+        }
+    }
+
+    /// Returns at most `limit` registered tasks whose id is not less than `start_task_id`, in the order of the task id
+    public fun tasks(ctx: &Context, start_task_id: u64, limit: u64): vector<ScheduledTask> {
+        let scheduler = borrow_scheduler(ctx);
+        let result = vector::empty();
+        let len = vector::length(&scheduler.task_ids);
+        let i = 0;
+        while (i < len && vector::length(&result) < limit) {
+            let task_id = *vector::borrow(&scheduler.task_ids, i);
+            if (task_id >= start_task_id) {
+                vector::push_back(&mut result, *table::borrow(&scheduler.tasks, task_id));
+            };
+            i = i + 1;
+        };
+        result
+    }
+
+    /// Returns at most `limit` tasks which are due and have enough prefund to pay the next run
+    public fun due_tasks(ctx: &Context, limit: u64): vector<ScheduledTask> {
+        let now = timestamp::now_seconds(ctx);
+        let scheduler = borrow_scheduler(ctx);
+        let result = vector::empty();
+        let len = vector::length(&scheduler.task_ids);
+        let i = 0;
+        while (i < len && vector::length(&result) < limit) {
+            let task = table::borrow(&scheduler.tasks, *vector::borrow(&scheduler.task_ids, i));
+            if (now >= task.next_run_seconds && task.balance >= task.fee_per_run) {
+                vector::push_back(&mut result, *task);
+            };
+            i = i + 1;
+        };
+        result
+    }
+
+    public fun task_count(ctx: &Context): u64 {
+        vector::length(&borrow_scheduler(ctx).task_ids)
+    }
+
+    public fun task_id(task: &ScheduledTask): u64 {
+        task.task_id
+    }
+
+    public fun owner(task: &ScheduledTask): address {
+        task.owner
+    }
+
+    public fun next_run_seconds(task: &ScheduledTask): u64 {
+        task.next_run_seconds
+    }
+
+    public fun balance(task: &ScheduledTask): u256 {
+        task.balance
+    }
+
+    public fun run_count(task: &ScheduledTask): u64 {
+        task.run_count
+    }
+}
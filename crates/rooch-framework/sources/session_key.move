@@ -6,6 +6,7 @@ module rooch_framework::session_key {
     use std::option::{Self, Option};
     use std::signer;
     use moveos_std::context::{Self, Context};
+    use moveos_std::object_id::ObjectID;
     use moveos_std::table::{Self, Table};
     use moveos_std::tx_meta::{Self, FunctionCallMeta};
     use rooch_framework::auth_validator;
@@ -26,6 +27,8 @@ module rooch_framework::session_key {
     const ErrorFunctionCallBeyondSessionScope: u64 = 5;
     /// The lengths of the parts of the session's scope do not match.
     const ErrorSessionScopePartLengthNotMatch: u64 = 6;
+    /// The max gas amount of the tx exceeds the remaining gas budget of the session
+    const ErrorSessionGasBudgetExceeded: u64 = 7;
 
     /// The session's scope
     struct SessionScope has store,copy,drop {
@@ -50,6 +53,11 @@ module rooch_framework::session_key {
         /// If the session key is not active in this time period, it will be expired
         /// If the max_inactive_interval is 0, the session key will never be expired
         max_inactive_interval: u64,
+        /// The max gas the session key can spend in total
+        /// If the max_gas_budget is 0, the gas of the session key is not limited
+        max_gas_budget: u64,
+        /// The gas spent by the txs validated via the session key
+        gas_used: u64,
     }
 
     struct SessionKeys has key {
@@ -72,6 +80,18 @@ module rooch_framework::session_key {
         return false
     }
 
+    /// The remaining gas budget of the session key, `none` if the gas of the session key is not limited
+    public fun remaining_gas_budget(session_key: &SessionKey) : Option<u64> {
+        if (session_key.max_gas_budget == 0){
+            return option::none()
+        };
+        if (session_key.gas_used >= session_key.max_gas_budget){
+            option::some(0)
+        }else{
+            option::some(session_key.max_gas_budget - session_key.gas_used)
+        }
+    }
+
     public fun is_expired_session_key(ctx: &Context, account_address: address, authentication_key: vector<u8>) : bool {
         let session_key_option = get_session_key(ctx, account_address, authentication_key);
         if (option::is_none(&session_key_option)){
@@ -86,6 +106,15 @@ module rooch_framework::session_key {
         option::is_some(&get_session_key(ctx, account_address, authentication_key))
     }
 
+    /// The handle of the session keys table of the account, `none` if the account has no session key
+    public fun session_keys_handle(ctx: &Context, account_address: address) : Option<ObjectID> {
+        if (!context::exists_resource<SessionKeys>(ctx, account_address)){
+            return option::none()
+        };
+        let session_keys = context::borrow_resource<SessionKeys>(ctx, account_address);
+        option::some(*table::handle(&session_keys.keys))
+    }
+
     /// Get the session key of the account_address by the authentication key
     public fun get_session_key(ctx: &Context, account_address: address, authentication_key: vector<u8>) : Option<SessionKey> {
         if (!context::exists_resource<SessionKeys>(ctx, account_address)){
@@ -100,6 +129,11 @@ module rooch_framework::session_key {
     }
 
     public fun create_session_key(ctx: &mut Context, sender: &signer, authentication_key: vector<u8>, scopes: vector<SessionScope>, max_inactive_interval: u64) {
+        create_session_key_with_budget(ctx, sender, authentication_key, scopes, max_inactive_interval, 0);
+    }
+
+    /// Create a session key which can spend at most `max_gas_budget` gas in total, 0 means no limit
+    public fun create_session_key_with_budget(ctx: &mut Context, sender: &signer, authentication_key: vector<u8>, scopes: vector<SessionScope>, max_inactive_interval: u64, max_gas_budget: u64) {
         //Can not create new session key by the other session key
         assert!(!auth_validator::is_validate_via_session_key(ctx), ErrorSessionKeyCreatePermissionDenied);
         let sender_addr = signer::address_of(sender);
@@ -111,6 +145,8 @@ module rooch_framework::session_key {
             create_time: now_seconds,
             last_active_time: now_seconds,
             max_inactive_interval: max_inactive_interval,
+            max_gas_budget: max_gas_budget,
+            gas_used: 0,
         };
         if (!context::exists_resource<SessionKeys>(ctx, sender_addr)){
             let keys = context::new_table<vector<u8>, SessionKey>(ctx);
@@ -137,6 +173,27 @@ module rooch_framework::session_key {
         scope_module_names: vector<std::ascii::String>, 
         scope_function_names: vector<std::ascii::String>, 
         max_inactive_interval: u64) {
+        let scopes = new_session_scopes(scope_module_addresses, scope_module_names, scope_function_names);
+        create_session_key(ctx, sender, authentication_key, scopes, max_inactive_interval);
+    }
+
+    public entry fun create_session_key_with_budget_entry(
+        ctx: &mut Context, 
+        sender: &signer, 
+        authentication_key: vector<u8>, 
+        scope_module_addresses: vector<address>, 
+        scope_module_names: vector<std::ascii::String>, 
+        scope_function_names: vector<std::ascii::String>, 
+        max_inactive_interval: u64,
+        max_gas_budget: u64) {
+        let scopes = new_session_scopes(scope_module_addresses, scope_module_names, scope_function_names);
+        create_session_key_with_budget(ctx, sender, authentication_key, scopes, max_inactive_interval, max_gas_budget);
+    }
+
+    fun new_session_scopes(
+        scope_module_addresses: vector<address>, 
+        scope_module_names: vector<std::ascii::String>, 
+        scope_function_names: vector<std::ascii::String>) : vector<SessionScope> {
         assert!(
             vector::length<address>(&scope_module_addresses) == vector::length<std::ascii::String>(&scope_module_names) &&
             vector::length<std::ascii::String>(&scope_module_names) == vector::length<std::ascii::String>(&scope_function_names),
@@ -159,8 +216,7 @@ module rooch_framework::session_key {
             
             idx = idx + 1;
         };
-
-        create_session_key(ctx, sender, authentication_key, scopes, max_inactive_interval);
+        scopes
     }
 
     /// Validate the current tx via the session key
@@ -187,6 +243,11 @@ module rooch_framework::session_key {
         
         assert!(in_session_scope(ctx, &session_key), ErrorFunctionCallBeyondSessionScope);
 
+        let remaining_budget = remaining_gas_budget(&session_key);
+        if (option::is_some(&remaining_budget)){
+            assert!(context::max_gas_amount(ctx) <= option::extract(&mut remaining_budget), ErrorSessionGasBudgetExceeded);
+        };
+
         native_validator::validate_signature(&authenticator_payload, &context::tx_hash(ctx));
         option::some(auth_key)
     }
@@ -231,7 +292,8 @@ module rooch_framework::session_key {
         true
     }
 
-    public(friend) fun active_session_key(ctx: &mut Context, authentication_key: vector<u8>) {
+    /// Active the session key and account the gas used by the tx to the session
+    public(friend) fun active_session_key(ctx: &mut Context, authentication_key: vector<u8>, gas_used: u64) {
         let sender_addr = context::sender(ctx);
         let now_seconds = timestamp::now_seconds(ctx);
         assert!(context::exists_resource<SessionKeys>(ctx, sender_addr), ErrorSessionKeyIsInvalid);
@@ -239,11 +301,12 @@ module rooch_framework::session_key {
         assert!(table::contains(&session_keys.keys, authentication_key), ErrorSessionKeyIsInvalid);
         let session_key = table::borrow_mut(&mut session_keys.keys, authentication_key);
         session_key.last_active_time = now_seconds;
+        session_key.gas_used = session_key.gas_used + gas_used;
     }
 
     #[test_only]
-    public fun active_session_key_for_test(ctx: &mut Context, authentication_key: vector<u8>) {
-        active_session_key(ctx, authentication_key);
+    public fun active_session_key_for_test(ctx: &mut Context, authentication_key: vector<u8>, gas_used: u64) {
+        active_session_key(ctx, authentication_key, gas_used);
     }
 
     public fun remove_session_key(ctx: &mut Context, sender: &signer, authentication_key: vector<u8>) {
@@ -0,0 +1,89 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+#[test_only]
+/// This test module is used to test the scheduler, it is also the example of the scheduled task callback
+module rooch_framework::scheduler_test{
+
+    use std::option;
+    use std::string;
+    use std::vector;
+    use moveos_std::context::{Self, Context};
+    use rooch_framework::account;
+    use rooch_framework::gas_coin;
+    use rooch_framework::scheduler;
+    use rooch_framework::timestamp;
+
+    struct TestTask has drop {}
+
+    struct Counter has key {
+        value: u64,
+    }
+
+    entry fun on_schedule(ctx: &mut Context, task_id: u64) {
+        scheduler::run<TestTask>(ctx, task_id);
+        if (context::exists_resource<Counter>(ctx, @rooch_framework)) {
+            let counter = context::borrow_mut_resource<Counter>(ctx, @rooch_framework);
+            counter.value = counter.value + 1;
+        } else {
+            let genesis_account = account::create_signer_for_test(@rooch_framework);
+            context::move_resource_to(ctx, &genesis_account, Counter { value: 1 });
+        };
+    }
+
+    fun register_for_test(ctx: &mut Context, owner: &signer): u64 {
+        scheduler::register<TestTask>(ctx, owner, string::utf8(b"on_schedule"), 10, 100, 250)
+    }
+
+    #[test(owner = @0x42)]
+    fun test_register_and_run(owner: address) {
+        let ctx = rooch_framework::genesis::init_for_test();
+        let owner_signer = account::create_account_for_test(&mut ctx, owner);
+        gas_coin::faucet_for_test(&mut ctx, owner, 1000);
+        let task_id = register_for_test(&mut ctx, &owner_signer);
+        assert!(gas_coin::balance(&ctx, owner) == 750, 1000);
+        assert!(vector::is_empty(&scheduler::due_tasks(&ctx, 10)), 1001);
+
+        timestamp::fast_forward_seconds_for_test(&mut ctx, 25);
+        assert!(vector::length(&scheduler::due_tasks(&ctx, 10)) == 1, 1002);
+        let keeper_balance = gas_coin::balance(&ctx, context::sender(&ctx));
+        on_schedule(&mut ctx, task_id);
+        assert!(gas_coin::balance(&ctx, context::sender(&ctx)) == keeper_balance + 100, 1003);
+        assert!(context::borrow_resource<Counter>(&ctx, @rooch_framework).value == 1, 1004);
+
+        let task = option::extract(&mut scheduler::task(&ctx, task_id));
+        // The missed run at 10s is skipped, the next run is at 30s
+        assert!(scheduler::next_run_seconds(&task) == 30, 1005);
+        assert!(scheduler::balance(&task) == 150, 1006);
+        assert!(scheduler::run_count(&task) == 1, 1007);
+        assert!(vector::is_empty(&scheduler::due_tasks(&ctx, 10)), 1008);
+        context::drop_test_context(ctx);
+    }
+
+    #[test(owner = @0x42)]
+    #[expected_failure(abort_code = 4, location = rooch_framework::scheduler)]
+    fun test_run_not_due(owner: address) {
+        let ctx = rooch_framework::genesis::init_for_test();
+        let owner_signer = account::create_account_for_test(&mut ctx, owner);
+        gas_coin::faucet_for_test(&mut ctx, owner, 1000);
+        let task_id = register_for_test(&mut ctx, &owner_signer);
+        timestamp::fast_forward_seconds_for_test(&mut ctx, 5);
+        on_schedule(&mut ctx, task_id);
+        context::drop_test_context(ctx);
+    }
+
+    #[test(owner = @0x42)]
+    fun test_cancel(owner: address) {
+        let ctx = rooch_framework::genesis::init_for_test();
+        let owner_signer = account::create_account_for_test(&mut ctx, owner);
+        gas_coin::faucet_for_test(&mut ctx, owner, 1000);
+        let task_id = register_for_test(&mut ctx, &owner_signer);
+        scheduler::fund(&mut ctx, &owner_signer, task_id, 50);
+        assert!(gas_coin::balance(&ctx, owner) == 700, 1000);
+        scheduler::cancel(&mut ctx, &owner_signer, task_id);
+        assert!(gas_coin::balance(&ctx, owner) == 1000, 1001);
+        assert!(option::is_none(&scheduler::task(&ctx, task_id)), 1002);
+        assert!(scheduler::task_count(&ctx) == 0, 1003);
+        context::drop_test_context(ctx);
+    }
+}
@@ -28,7 +28,7 @@ module rooch_framework::session_key_test{
         assert!(!session_key::is_expired_session_key(&mut user_ctx, sender_addr, authentication_key), 1001);
         timestamp::fast_forward_seconds_for_test(&mut user_ctx, 9);
         assert!(!session_key::is_expired_session_key(&mut user_ctx, sender_addr, authentication_key), 1002);
-        session_key::active_session_key_for_test(&mut user_ctx, authentication_key);
+        session_key::active_session_key_for_test(&mut user_ctx, authentication_key, 0);
         timestamp::fast_forward_seconds_for_test(&mut user_ctx, 9);
         assert!(!session_key::is_expired_session_key(&mut user_ctx, sender_addr, authentication_key), 1003);
         timestamp::fast_forward_seconds_for_test(&mut user_ctx, 2);
@@ -39,4 +39,27 @@ module rooch_framework::session_key_test{
         context::drop_test_context(genesis_ctx);
     }
 
+    #[test(sender=@0x42)]
+    fun test_session_key_gas_budget(sender:&signer){
+        let genesis_ctx = rooch_framework::genesis::init_for_test();
+        let sender_addr = signer::address_of(sender);
+        let user_ctx = context::new_test_context(sender_addr);
+        let scope = session_key::new_session_scope(@0x1, std::ascii::string(b"*"), std::ascii::string(b"*"));
+        let authentication_key = bcs::to_bytes(&sender_addr);
+        session_key::create_session_key_with_budget(&mut user_ctx, sender, authentication_key, vector::singleton(scope), 0, 100);
+        let session_key = option::destroy_some(session_key::get_session_key(&user_ctx, sender_addr, authentication_key));
+        assert!(session_key::remaining_gas_budget(&session_key) == option::some(100), 1000);
+
+        session_key::active_session_key_for_test(&mut user_ctx, authentication_key, 60);
+        let session_key = option::destroy_some(session_key::get_session_key(&user_ctx, sender_addr, authentication_key));
+        assert!(session_key::remaining_gas_budget(&session_key) == option::some(40), 1001);
+
+        session_key::active_session_key_for_test(&mut user_ctx, authentication_key, 60);
+        let session_key = option::destroy_some(session_key::get_session_key(&user_ctx, sender_addr, authentication_key));
+        assert!(session_key::remaining_gas_budget(&session_key) == option::some(0), 1002);
+        assert!(option::is_some(&session_key::session_keys_handle(&user_ctx, sender_addr)), 1003);
+
+        context::drop_test_context(user_ctx);
+        context::drop_test_context(genesis_ctx);
+    }
 }
@@ -151,18 +151,19 @@ module rooch_framework::transaction_validator {
 
         // Active the session key
 
+        let tx_result = context::tx_result(ctx);
+        let gas_used = tx_result::gas_used(&tx_result);
+
         let session_key_opt = auth_validator::get_session_key_from_ctx_option(ctx);
         if (option::is_some(&session_key_opt)) {
             let session_key = option::extract(&mut session_key_opt);
-            session_key::active_session_key(ctx, session_key);
+            session_key::active_session_key(ctx, session_key, gas_used);
         };
 
         // Increment sequence number
         account::increment_sequence_number(ctx);
 
-        let tx_result = context::tx_result(ctx);
         let gas_payment_account = context::tx_gas_payment_account(ctx);
-        let gas_used = tx_result::gas_used(&tx_result);
         let gas = transaction_fee::calculate_gas(ctx, gas_used);
         let gas_coin = gas_coin::deduct_gas(ctx, gas_payment_account, gas);
         transaction_fee::deposit_fee(ctx, gas_coin);
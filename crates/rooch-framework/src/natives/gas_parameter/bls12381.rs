@@ -0,0 +1,19 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::natives::gas_parameter::native::MUL;
+use crate::natives::rooch_framework::crypto::bls12381::GasParameters;
+
+crate::natives::gas_parameter::native::define_gas_parameters_for_natives!(GasParameters, "bls12381", [
+    [.verify.base, "verify.base", 5000 * MUL],
+    [.verify.per_byte, "verify.per_byte", 30 * MUL],
+    [.verify_proof_of_possession.base, "verify_proof_of_possession.base", 5000 * MUL],
+    [.verify_proof_of_possession.per_byte, "verify_proof_of_possession.per_byte", 30 * MUL],
+    [.aggregate_verify.base, "aggregate_verify.base", 5000 * MUL],
+    [.aggregate_verify.per_pubkey, "aggregate_verify.per_pubkey", 500 * MUL],
+    [.aggregate_verify.per_byte, "aggregate_verify.per_byte", 30 * MUL],
+    [.hash_to_g1.base, "hash_to_g1.base", 1000 * MUL],
+    [.hash_to_g1.per_byte, "hash_to_g1.per_byte", 10 * MUL],
+    [.hash_to_g2.base, "hash_to_g2.base", 2000 * MUL],
+    [.hash_to_g2.per_byte, "hash_to_g2.per_byte", 10 * MUL],
+]);
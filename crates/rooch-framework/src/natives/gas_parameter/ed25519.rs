@@ -7,4 +7,7 @@ use crate::natives::rooch_framework::crypto::ed25519::GasParameters;
 crate::natives::gas_parameter::native::define_gas_parameters_for_natives!(GasParameters, "ed25519", [
     [.verify.base, "verify.base", 1000 * MUL],
     [.verify.per_byte, "verify.per_byte", 30 * MUL],
+    [.batch_verify.base, "batch_verify.base", 1000 * MUL],
+    [.batch_verify.per_signature, "batch_verify.per_signature", 500 * MUL],
+    [.batch_verify.per_byte, "batch_verify.per_byte", 30 * MUL],
 ]);
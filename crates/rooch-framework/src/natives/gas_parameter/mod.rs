@@ -2,8 +2,10 @@
 // SPDX-License-Identifier: Apache-2.0
 mod account;
 mod bcd;
+mod bls12381;
 mod decoding;
 mod ecdsa_k1;
+mod ecdsa_r1;
 mod ed25519;
 mod encoding;
 mod events;
@@ -15,9 +17,12 @@ pub mod move_std;
 pub mod native;
 pub mod nursery;
 mod object;
+mod poseidon;
 //mod ord;
 mod rlp;
+mod schnorr;
 mod signer;
 mod table_extension;
 mod test_helper;
 mod type_info;
+mod webauthn;
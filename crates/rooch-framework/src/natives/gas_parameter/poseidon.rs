@@ -0,0 +1,10 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::natives::gas_parameter::native::MUL;
+use crate::natives::rooch_framework::crypto::poseidon::GasParameters;
+
+crate::natives::gas_parameter::native::define_gas_parameters_for_natives!(GasParameters, "poseidon", [
+    [.poseidon_bn254.base, "poseidon_bn254.base", 2000 * MUL],
+    [.poseidon_bn254.per_input, "poseidon_bn254.per_input", 1000 * MUL],
+]);
@@ -0,0 +1,12 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::natives::gas_parameter::native::MUL;
+use crate::natives::rooch_framework::crypto::webauthn::GasParameters;
+
+crate::natives::gas_parameter::native::define_gas_parameters_for_natives!(GasParameters, "webauthn", [
+    [.cose_key_to_public_key.base, "cose_key_to_public_key.base", 500 * MUL],
+    [.cose_key_to_public_key.per_byte, "cose_key_to_public_key.per_byte", 30 * MUL],
+    [.verify.base, "verify.base", 1500 * MUL],
+    [.verify.per_byte, "verify.per_byte", 30 * MUL],
+]);
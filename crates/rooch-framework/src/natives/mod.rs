@@ -31,6 +31,11 @@ pub struct NativeGasParameters {
     hash: rooch_framework::crypto::hash::GasParameters,
     ed25519: rooch_framework::crypto::ed25519::GasParameters,
     ecdsa_k1: rooch_framework::crypto::ecdsa_k1::GasParameters,
+    ecdsa_r1: rooch_framework::crypto::ecdsa_r1::GasParameters,
+    schnorr: rooch_framework::crypto::schnorr::GasParameters,
+    webauthn: rooch_framework::crypto::webauthn::GasParameters,
+    bls12381: rooch_framework::crypto::bls12381::GasParameters,
+    poseidon: rooch_framework::crypto::poseidon::GasParameters,
     encoding: rooch_framework::crypto::encoding::GasParameters,
     decoding: rooch_framework::crypto::decoding::GasParameters,
     bcs: rooch_framework::bcs::GasParameters,
@@ -45,6 +50,11 @@ impl FromOnChainGasSchedule for NativeGasParameters {
             hash: FromOnChainGasSchedule::from_on_chain_gas_schedule(gas_schedule).unwrap(),
             ed25519: FromOnChainGasSchedule::from_on_chain_gas_schedule(gas_schedule).unwrap(),
             ecdsa_k1: FromOnChainGasSchedule::from_on_chain_gas_schedule(gas_schedule).unwrap(),
+            ecdsa_r1: FromOnChainGasSchedule::from_on_chain_gas_schedule(gas_schedule).unwrap(),
+            schnorr: FromOnChainGasSchedule::from_on_chain_gas_schedule(gas_schedule).unwrap(),
+            webauthn: FromOnChainGasSchedule::from_on_chain_gas_schedule(gas_schedule).unwrap(),
+            bls12381: FromOnChainGasSchedule::from_on_chain_gas_schedule(gas_schedule).unwrap(),
+            poseidon: FromOnChainGasSchedule::from_on_chain_gas_schedule(gas_schedule).unwrap(),
             encoding: FromOnChainGasSchedule::from_on_chain_gas_schedule(gas_schedule).unwrap(),
             decoding: FromOnChainGasSchedule::from_on_chain_gas_schedule(gas_schedule).unwrap(),
             bcs: FromOnChainGasSchedule::from_on_chain_gas_schedule(gas_schedule).unwrap(),
@@ -59,6 +69,11 @@ impl ToOnChainGasSchedule for NativeGasParameters {
         entires.extend(self.hash.to_on_chain_gas_schedule());
         entires.extend(self.ed25519.to_on_chain_gas_schedule());
         entires.extend(self.ecdsa_k1.to_on_chain_gas_schedule());
+        entires.extend(self.ecdsa_r1.to_on_chain_gas_schedule());
+        entires.extend(self.schnorr.to_on_chain_gas_schedule());
+        entires.extend(self.webauthn.to_on_chain_gas_schedule());
+        entires.extend(self.bls12381.to_on_chain_gas_schedule());
+        entires.extend(self.poseidon.to_on_chain_gas_schedule());
         entires.extend(self.encoding.to_on_chain_gas_schedule());
         entires.extend(self.decoding.to_on_chain_gas_schedule());
         entires.extend(self.bcs.to_on_chain_gas_schedule());
@@ -74,6 +89,11 @@ impl InitialGasSchedule for NativeGasParameters {
             hash: InitialGasSchedule::initial(),
             ed25519: InitialGasSchedule::initial(),
             ecdsa_k1: InitialGasSchedule::initial(),
+            ecdsa_r1: InitialGasSchedule::initial(),
+            schnorr: InitialGasSchedule::initial(),
+            webauthn: InitialGasSchedule::initial(),
+            bls12381: InitialGasSchedule::initial(),
+            poseidon: InitialGasSchedule::initial(),
             encoding: InitialGasSchedule::initial(),
             decoding: InitialGasSchedule::initial(),
             bcs: InitialGasSchedule::initial(),
@@ -152,6 +172,11 @@ impl NativeGasParameters {
             hash: rooch_framework::crypto::hash::GasParameters::zeros(),
             ed25519: rooch_framework::crypto::ed25519::GasParameters::zeros(),
             ecdsa_k1: rooch_framework::crypto::ecdsa_k1::GasParameters::zeros(),
+            ecdsa_r1: rooch_framework::crypto::ecdsa_r1::GasParameters::zeros(),
+            schnorr: rooch_framework::crypto::schnorr::GasParameters::zeros(),
+            webauthn: rooch_framework::crypto::webauthn::GasParameters::zeros(),
+            bls12381: rooch_framework::crypto::bls12381::GasParameters::zeros(),
+            poseidon: rooch_framework::crypto::poseidon::GasParameters::zeros(),
             encoding: rooch_framework::crypto::encoding::GasParameters::zeros(),
             decoding: rooch_framework::crypto::decoding::GasParameters::zeros(),
             bcs: rooch_framework::bcs::GasParameters::zeros(),
@@ -189,6 +214,26 @@ pub fn all_natives(gas_params: NativeGasParameters) -> NativeFunctionTable {
         "ecdsa_k1",
         rooch_framework::crypto::ecdsa_k1::make_all(gas_params.ecdsa_k1)
     );
+    add_natives!(
+        "ecdsa_r1",
+        rooch_framework::crypto::ecdsa_r1::make_all(gas_params.ecdsa_r1)
+    );
+    add_natives!(
+        "schnorr",
+        rooch_framework::crypto::schnorr::make_all(gas_params.schnorr)
+    );
+    add_natives!(
+        "webauthn",
+        rooch_framework::crypto::webauthn::make_all(gas_params.webauthn)
+    );
+    add_natives!(
+        "bls12381",
+        rooch_framework::crypto::bls12381::make_all(gas_params.bls12381)
+    );
+    add_natives!(
+        "poseidon",
+        rooch_framework::crypto::poseidon::make_all(gas_params.poseidon)
+    );
     add_natives!(
         "encoding",
         rooch_framework::crypto::encoding::make_all(gas_params.encoding)
@@ -0,0 +1,283 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::natives::helpers::{make_module_natives, make_native};
+use fastcrypto::{
+    bls12381::min_pk::{BLS12381AggregateSignature, BLS12381PublicKey, BLS12381Signature},
+    groups::{
+        bls12381::{G1Element, G2Element},
+        HashToGroupElement,
+    },
+    serde_helpers::ToFromByteArray,
+    traits::{AggregateAuthenticator, ToFromBytes, VerifyingKey},
+};
+use move_binary_format::errors::PartialVMResult;
+use move_core_types::gas_algebra::{
+    InternalGas, InternalGasPerArg, InternalGasPerByte, NumArgs, NumBytes,
+};
+use move_vm_runtime::native_functions::{NativeContext, NativeFunction};
+use move_vm_types::{
+    loaded_data::runtime_types::Type,
+    natives::function::NativeResult,
+    pop_arg,
+    values::{Value, VectorRef},
+};
+use smallvec::smallvec;
+use std::collections::VecDeque;
+
+pub const E_INVALID_SIGNATURE: u64 = 1;
+pub const E_INVALID_PUBKEY: u64 = 2;
+
+/// The prefix of the message signed by the proof of possession, the message is `prefix || public_key`
+pub const PROOF_OF_POSSESSION_MSG_PREFIX: &[u8] = b"ROOCH_BLS12381_POP_";
+
+/// Verify the BLS12-381 signature (min-pk scheme, the public key in G1 and the signature in G2)
+/// of the message with the public key.
+pub fn native_verify(
+    gas_params: &FromBytesGasParameters,
+    _context: &mut NativeContext,
+    ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(ty_args.is_empty());
+    debug_assert!(args.len() == 3);
+
+    let msg = pop_arg!(args, VectorRef);
+    let public_key_bytes = pop_arg!(args, VectorRef);
+    let signature_bytes = pop_arg!(args, VectorRef);
+
+    let msg_ref = msg.as_bytes_ref();
+    let public_key_bytes_ref = public_key_bytes.as_bytes_ref();
+    let signature_bytes_ref = signature_bytes.as_bytes_ref();
+
+    let cost = gas_params.base
+        + gas_params.per_byte * NumBytes::new(msg_ref.len() as u64)
+        + gas_params.per_byte * NumBytes::new(signature_bytes_ref.len() as u64)
+        + gas_params.per_byte * NumBytes::new(public_key_bytes_ref.len() as u64);
+
+    let Ok(sig) = <BLS12381Signature as ToFromBytes>::from_bytes(&signature_bytes_ref) else {
+        return Ok(NativeResult::err(cost, E_INVALID_SIGNATURE));
+    };
+
+    let Ok(public_key) = <BLS12381PublicKey as ToFromBytes>::from_bytes(&public_key_bytes_ref)
+    else {
+        return Ok(NativeResult::err(cost, E_INVALID_PUBKEY));
+    };
+
+    let result = public_key.verify(&msg_ref, &sig).is_ok();
+
+    Ok(NativeResult::ok(cost, smallvec![Value::bool(result)]))
+}
+
+/// Verify the proof of possession of the private key of the public key,
+/// the proof is the signature of `PROOF_OF_POSSESSION_MSG_PREFIX || public_key`.
+pub fn native_verify_proof_of_possession(
+    gas_params: &FromBytesGasParameters,
+    _context: &mut NativeContext,
+    ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(ty_args.is_empty());
+    debug_assert!(args.len() == 2);
+
+    let public_key_bytes = pop_arg!(args, VectorRef);
+    let pop_bytes = pop_arg!(args, VectorRef);
+
+    let public_key_bytes_ref = public_key_bytes.as_bytes_ref();
+    let pop_bytes_ref = pop_bytes.as_bytes_ref();
+
+    let cost = gas_params.base
+        + gas_params.per_byte * NumBytes::new(pop_bytes_ref.len() as u64)
+        + gas_params.per_byte * NumBytes::new(public_key_bytes_ref.len() as u64);
+
+    let Ok(pop) = <BLS12381Signature as ToFromBytes>::from_bytes(&pop_bytes_ref) else {
+        return Ok(NativeResult::err(cost, E_INVALID_SIGNATURE));
+    };
+
+    let Ok(public_key) = <BLS12381PublicKey as ToFromBytes>::from_bytes(&public_key_bytes_ref)
+    else {
+        return Ok(NativeResult::err(cost, E_INVALID_PUBKEY));
+    };
+
+    let mut msg = PROOF_OF_POSSESSION_MSG_PREFIX.to_vec();
+    msg.extend_from_slice(&public_key_bytes_ref);
+    let result = public_key.verify(&msg, &pop).is_ok();
+
+    Ok(NativeResult::ok(cost, smallvec![Value::bool(result)]))
+}
+
+/// Verify the aggregate signature of the same message signed by all the public keys.
+/// The public keys are aggregated without the coefficients, it is only secure if the proof of possession
+/// of every public key is verified, see `native_verify_proof_of_possession`.
+/// Return false if the public keys are empty.
+pub fn native_aggregate_verify(
+    gas_params: &AggregateVerifyGasParameters,
+    _context: &mut NativeContext,
+    ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(ty_args.is_empty());
+    debug_assert!(args.len() == 3);
+
+    let msg = pop_arg!(args, VectorRef);
+    let public_keys = pop_arg!(args, Vec<Value>);
+    let signature_bytes = pop_arg!(args, VectorRef);
+
+    let msg_ref = msg.as_bytes_ref();
+    let signature_bytes_ref = signature_bytes.as_bytes_ref();
+
+    let mut cost = gas_params.base
+        + gas_params.per_byte * NumBytes::new(msg_ref.len() as u64)
+        + gas_params.per_byte * NumBytes::new(signature_bytes_ref.len() as u64)
+        + gas_params.per_pubkey * NumArgs::new(public_keys.len() as u64);
+
+    let mut pks = Vec::with_capacity(public_keys.len());
+    for public_key in public_keys {
+        let public_key_bytes = public_key.value_as::<Vec<u8>>()?;
+        cost += gas_params.per_byte * NumBytes::new(public_key_bytes.len() as u64);
+        let Ok(public_key) = <BLS12381PublicKey as ToFromBytes>::from_bytes(&public_key_bytes)
+        else {
+            return Ok(NativeResult::err(cost, E_INVALID_PUBKEY));
+        };
+        pks.push(public_key);
+    }
+
+    let Ok(sig) = <BLS12381AggregateSignature as ToFromBytes>::from_bytes(&signature_bytes_ref)
+    else {
+        return Ok(NativeResult::err(cost, E_INVALID_SIGNATURE));
+    };
+
+    let result = !pks.is_empty() && sig.verify(&pks, &msg_ref).is_ok();
+
+    Ok(NativeResult::ok(cost, smallvec![Value::bool(result)]))
+}
+
+/// Hash the message to a G1 point, return the 48 bytes compressed point.
+pub fn native_hash_to_g1(
+    gas_params: &FromBytesGasParameters,
+    _context: &mut NativeContext,
+    ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(ty_args.is_empty());
+    debug_assert!(args.len() == 1);
+
+    let msg = pop_arg!(args, VectorRef);
+    let msg_ref = msg.as_bytes_ref();
+
+    let cost = gas_params.base + gas_params.per_byte * NumBytes::new(msg_ref.len() as u64);
+
+    let point = G1Element::hash_to_group_element(&msg_ref);
+
+    Ok(NativeResult::ok(
+        cost,
+        smallvec![Value::vector_u8(point.to_byte_array().to_vec())],
+    ))
+}
+
+/// Hash the message to a G2 point, return the 96 bytes compressed point.
+pub fn native_hash_to_g2(
+    gas_params: &FromBytesGasParameters,
+    _context: &mut NativeContext,
+    ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(ty_args.is_empty());
+    debug_assert!(args.len() == 1);
+
+    let msg = pop_arg!(args, VectorRef);
+    let msg_ref = msg.as_bytes_ref();
+
+    let cost = gas_params.base + gas_params.per_byte * NumBytes::new(msg_ref.len() as u64);
+
+    let point = G2Element::hash_to_group_element(&msg_ref);
+
+    Ok(NativeResult::ok(
+        cost,
+        smallvec![Value::vector_u8(point.to_byte_array().to_vec())],
+    ))
+}
+
+#[derive(Debug, Clone)]
+pub struct FromBytesGasParameters {
+    pub base: InternalGas,
+    pub per_byte: InternalGasPerByte,
+}
+
+impl FromBytesGasParameters {
+    pub fn zeros() -> Self {
+        Self {
+            base: 0.into(),
+            per_byte: 0.into(),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct AggregateVerifyGasParameters {
+    pub base: InternalGas,
+    pub per_pubkey: InternalGasPerArg,
+    pub per_byte: InternalGasPerByte,
+}
+
+impl AggregateVerifyGasParameters {
+    pub fn zeros() -> Self {
+        Self {
+            base: 0.into(),
+            per_pubkey: 0.into(),
+            per_byte: 0.into(),
+        }
+    }
+}
+
+/***************************************************************************************************
+ * module
+ **************************************************************************************************/
+
+#[derive(Debug, Clone)]
+pub struct GasParameters {
+    pub verify: FromBytesGasParameters,
+    pub verify_proof_of_possession: FromBytesGasParameters,
+    pub aggregate_verify: AggregateVerifyGasParameters,
+    pub hash_to_g1: FromBytesGasParameters,
+    pub hash_to_g2: FromBytesGasParameters,
+}
+
+impl GasParameters {
+    pub fn zeros() -> Self {
+        Self {
+            verify: FromBytesGasParameters::zeros(),
+            verify_proof_of_possession: FromBytesGasParameters::zeros(),
+            aggregate_verify: AggregateVerifyGasParameters::zeros(),
+            hash_to_g1: FromBytesGasParameters::zeros(),
+            hash_to_g2: FromBytesGasParameters::zeros(),
+        }
+    }
+}
+
+pub fn make_all(gas_params: GasParameters) -> impl Iterator<Item = (String, NativeFunction)> {
+    let natives = [
+        ("verify", make_native(gas_params.verify, native_verify)),
+        (
+            "verify_proof_of_possession",
+            make_native(
+                gas_params.verify_proof_of_possession,
+                native_verify_proof_of_possession,
+            ),
+        ),
+        (
+            "aggregate_verify_internal",
+            make_native(gas_params.aggregate_verify, native_aggregate_verify),
+        ),
+        (
+            "hash_to_g1",
+            make_native(gas_params.hash_to_g1, native_hash_to_g1),
+        ),
+        (
+            "hash_to_g2",
+            make_native(gas_params.hash_to_g2, native_hash_to_g2),
+        ),
+    ];
+
+    make_module_natives(natives)
+}
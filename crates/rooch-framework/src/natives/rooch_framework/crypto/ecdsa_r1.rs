@@ -0,0 +1,97 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::natives::helpers::{make_module_natives, make_native};
+use fastcrypto::{
+    secp256r1::{Secp256r1PublicKey, Secp256r1Signature},
+    traits::{ToFromBytes, VerifyingKey},
+};
+use move_binary_format::errors::PartialVMResult;
+use move_core_types::gas_algebra::{InternalGas, InternalGasPerByte, NumBytes};
+use move_vm_runtime::native_functions::{NativeContext, NativeFunction};
+use move_vm_types::{
+    loaded_data::runtime_types::Type,
+    natives::function::NativeResult,
+    pop_arg,
+    values::{Value, VectorRef},
+};
+use smallvec::smallvec;
+use std::collections::VecDeque;
+
+pub const E_INVALID_SIGNATURE: u64 = 1;
+pub const E_INVALID_PUBKEY: u64 = 2;
+
+/// The message is hashed with SHA256 before verification, as the ES256 algorithm used by WebAuthn.
+pub fn native_verify(
+    gas_params: &FromBytesGasParameters,
+    _context: &mut NativeContext,
+    ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(ty_args.is_empty());
+    debug_assert!(args.len() == 3);
+
+    let msg = pop_arg!(args, VectorRef);
+    let public_key_bytes = pop_arg!(args, VectorRef);
+    let signature_bytes = pop_arg!(args, VectorRef);
+
+    let msg_ref = msg.as_bytes_ref();
+    let public_key_bytes_ref = public_key_bytes.as_bytes_ref();
+    let signature_bytes_ref = signature_bytes.as_bytes_ref();
+
+    let cost = gas_params.base
+        + gas_params.per_byte * NumBytes::new(msg_ref.len() as u64)
+        + gas_params.per_byte * NumBytes::new(signature_bytes_ref.len() as u64)
+        + gas_params.per_byte * NumBytes::new(public_key_bytes_ref.len() as u64);
+
+    let Ok(sig) = <Secp256r1Signature as ToFromBytes>::from_bytes(&signature_bytes_ref) else {
+        return Ok(NativeResult::err(cost, E_INVALID_SIGNATURE));
+    };
+
+    let Ok(public_key) = <Secp256r1PublicKey as ToFromBytes>::from_bytes(&public_key_bytes_ref)
+    else {
+        return Ok(NativeResult::err(cost, E_INVALID_PUBKEY));
+    };
+
+    let result = public_key.verify(&msg_ref, &sig).is_ok();
+
+    Ok(NativeResult::ok(cost, smallvec![Value::bool(result)]))
+}
+
+#[derive(Debug, Clone)]
+pub struct FromBytesGasParameters {
+    pub base: InternalGas,
+    pub per_byte: InternalGasPerByte,
+}
+
+impl FromBytesGasParameters {
+    pub fn zeros() -> Self {
+        Self {
+            base: 0.into(),
+            per_byte: 0.into(),
+        }
+    }
+}
+
+/***************************************************************************************************
+ * module
+ **************************************************************************************************/
+
+#[derive(Debug, Clone)]
+pub struct GasParameters {
+    pub verify: FromBytesGasParameters,
+}
+
+impl GasParameters {
+    pub fn zeros() -> Self {
+        Self {
+            verify: FromBytesGasParameters::zeros(),
+        }
+    }
+}
+
+pub fn make_all(gas_params: GasParameters) -> impl Iterator<Item = (String, NativeFunction)> {
+    let natives = [("verify", make_native(gas_params.verify, native_verify))];
+
+    make_module_natives(natives)
+}
@@ -16,10 +16,14 @@ use move_vm_types::{
 use crate::natives::helpers::{make_module_natives, make_native};
 use move_vm_runtime::native_functions::{NativeContext, NativeFunction};
 
-use move_core_types::gas_algebra::{InternalGas, InternalGasPerByte, NumBytes};
+use move_core_types::gas_algebra::{
+    InternalGas, InternalGasPerArg, InternalGasPerByte, NumArgs, NumBytes,
+};
 use smallvec::smallvec;
 use std::collections::VecDeque;
 
+pub const E_LENGTH_MISMATCH: u64 = 1;
+
 /***************************************************************************************************
  * native fun ed25519_verify
  * Implementation of the Move native function `ed25519::ed25519_verify(signature: &vector<u8>, public_key: &vector<u8>, msg: &vector<u8>): bool;`
@@ -63,6 +67,68 @@ pub fn native_verify(
     ))
 }
 
+/***************************************************************************************************
+ * native fun batch_verify
+ * Implementation of the Move native function
+ * `ed25519::batch_verify(signatures: vector<vector<u8>>, public_keys: vector<vector<u8>>, msgs: vector<vector<u8>>): bool;`
+ *   gas cost: base + per_signature * signatures.len() + per_byte * total bytes of the arguments
+ * The signatures are verified together, which is cheaper than verifying them one by one.
+ * Return false if any signature is invalid, any public key or signature can not be decoded, or the batch is empty.
+ **************************************************************************************************/
+pub fn native_batch_verify(
+    gas_params: &BatchVerifyGasParameters,
+    _context: &mut NativeContext,
+    ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(ty_args.is_empty());
+    debug_assert!(args.len() == 3);
+
+    let msgs = pop_arg!(args, Vec<Value>);
+    let public_keys = pop_arg!(args, Vec<Value>);
+    let signatures = pop_arg!(args, Vec<Value>);
+
+    let mut cost =
+        gas_params.base + gas_params.per_signature * NumArgs::new(signatures.len() as u64);
+    if signatures.len() != public_keys.len() || signatures.len() != msgs.len() {
+        return Ok(NativeResult::err(cost, E_LENGTH_MISMATCH));
+    }
+
+    let mut msgs_bytes = Vec::with_capacity(msgs.len());
+    for msg in msgs {
+        let msg_bytes = msg.value_as::<Vec<u8>>()?;
+        cost += gas_params.per_byte * NumBytes::new(msg_bytes.len() as u64);
+        msgs_bytes.push(msg_bytes);
+    }
+    let mut pks = Vec::with_capacity(public_keys.len());
+    let mut sigs = Vec::with_capacity(signatures.len());
+    let mut decoded = true;
+    for (public_key, signature) in public_keys.into_iter().zip(signatures) {
+        let public_key_bytes = public_key.value_as::<Vec<u8>>()?;
+        let signature_bytes = signature.value_as::<Vec<u8>>()?;
+        cost += gas_params.per_byte
+            * NumBytes::new((public_key_bytes.len() + signature_bytes.len()) as u64);
+        match (
+            <Ed25519PublicKey as ToFromBytes>::from_bytes(&public_key_bytes),
+            <Ed25519Signature as ToFromBytes>::from_bytes(&signature_bytes),
+        ) {
+            (Ok(public_key), Ok(signature)) => {
+                pks.push(public_key);
+                sigs.push(signature);
+            }
+            _ => decoded = false,
+        }
+    }
+    if !decoded {
+        return Ok(NativeResult::ok(cost, smallvec![Value::bool(false)]));
+    }
+
+    let result =
+        Ed25519PublicKey::verify_batch_empty_fail_different_msg(&msgs_bytes, &pks, &sigs).is_ok();
+
+    Ok(NativeResult::ok(cost, smallvec![Value::bool(result)]))
+}
+
 #[derive(Debug, Clone)]
 pub struct FromBytesGasParameters {
     pub base: InternalGas,
@@ -78,6 +144,23 @@ impl FromBytesGasParameters {
     }
 }
 
+#[derive(Debug, Clone)]
+pub struct BatchVerifyGasParameters {
+    pub base: InternalGas,
+    pub per_signature: InternalGasPerArg,
+    pub per_byte: InternalGasPerByte,
+}
+
+impl BatchVerifyGasParameters {
+    pub fn zeros() -> Self {
+        Self {
+            base: 0.into(),
+            per_signature: 0.into(),
+            per_byte: 0.into(),
+        }
+    }
+}
+
 /***************************************************************************************************
  * module
  **************************************************************************************************/
@@ -85,18 +168,26 @@ impl FromBytesGasParameters {
 #[derive(Debug, Clone)]
 pub struct GasParameters {
     pub verify: FromBytesGasParameters,
+    pub batch_verify: BatchVerifyGasParameters,
 }
 
 impl GasParameters {
     pub fn zeros() -> Self {
         Self {
             verify: FromBytesGasParameters::zeros(),
+            batch_verify: BatchVerifyGasParameters::zeros(),
         }
     }
 }
 
 pub fn make_all(gas_params: GasParameters) -> impl Iterator<Item = (String, NativeFunction)> {
-    let natives = [("verify", make_native(gas_params.verify, native_verify))];
+    let natives = [
+        ("verify", make_native(gas_params.verify, native_verify)),
+        (
+            "batch_verify",
+            make_native(gas_params.batch_verify, native_batch_verify),
+        ),
+    ];
 
     make_module_natives(natives)
 }
@@ -1,8 +1,13 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
 
+pub mod bls12381;
 pub mod decoding;
 pub mod ecdsa_k1;
+pub mod ecdsa_r1;
 pub mod ed25519;
 pub mod encoding;
 pub mod hash;
+pub mod poseidon;
+pub mod schnorr;
+pub mod webauthn;
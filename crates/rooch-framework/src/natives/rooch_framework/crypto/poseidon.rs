@@ -0,0 +1,114 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::natives::helpers::{make_module_natives, make_native};
+use ark_bn254::Fr;
+use light_poseidon::{Poseidon, PoseidonBytesHasher};
+use move_binary_format::errors::PartialVMResult;
+use move_core_types::{
+    gas_algebra::{InternalGas, InternalGasPerArg, NumArgs},
+    u256::U256,
+};
+use move_vm_runtime::native_functions::{NativeContext, NativeFunction};
+use move_vm_types::{
+    loaded_data::runtime_types::Type, natives::function::NativeResult, pop_arg, values::Value,
+};
+use smallvec::smallvec;
+use std::collections::VecDeque;
+
+pub const E_INVALID_INPUT: u64 = 1;
+pub const E_TOO_MANY_INPUTS: u64 = 2;
+
+/// The max number of the inputs supported by the circom compatible Poseidon parameters
+pub const MAX_INPUTS: usize = 12;
+
+/***************************************************************************************************
+ * native fun poseidon_bn254_internal
+ * Implementation of the Move native function `poseidon::poseidon_bn254_internal(data: vector<u256>): u256`
+ *   gas cost: poseidon_bn254_cost_base                               | base cost for function call and fixed opers
+ *              + poseidon_bn254_cost_per_input * data.len()          | cost depends on number of the inputs
+ **************************************************************************************************/
+/// The inputs are the elements of the BN254 scalar field, the hash is compatible with the Poseidon of circomlib.
+pub fn native_poseidon_bn254(
+    gas_params: &PoseidonBn254GasParameters,
+    _context: &mut NativeContext,
+    ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(ty_args.is_empty());
+    debug_assert!(args.len() == 1);
+
+    let inputs = pop_arg!(args, Vec<U256>);
+
+    let cost = gas_params.base + gas_params.per_input * NumArgs::new(inputs.len() as u64);
+
+    if inputs.is_empty() {
+        return Ok(NativeResult::err(cost, E_INVALID_INPUT));
+    }
+    if inputs.len() > MAX_INPUTS {
+        return Ok(NativeResult::err(cost, E_TOO_MANY_INPUTS));
+    }
+
+    let input_bytes = inputs
+        .iter()
+        .map(|input| input.to_le_bytes())
+        .collect::<Vec<_>>();
+    let input_refs = input_bytes
+        .iter()
+        .map(|bytes| bytes.as_slice())
+        .collect::<Vec<_>>();
+
+    let Ok(mut poseidon) = Poseidon::<Fr>::new_circom(inputs.len()) else {
+        return Ok(NativeResult::err(cost, E_TOO_MANY_INPUTS));
+    };
+    // The inputs larger than the field modulus are rejected
+    let Ok(hash) = poseidon.hash_bytes_le(&input_refs) else {
+        return Ok(NativeResult::err(cost, E_INVALID_INPUT));
+    };
+
+    Ok(NativeResult::ok(
+        cost,
+        smallvec![Value::u256(U256::from_le_bytes(&hash))],
+    ))
+}
+
+#[derive(Debug, Clone)]
+pub struct PoseidonBn254GasParameters {
+    pub base: InternalGas,
+    pub per_input: InternalGasPerArg,
+}
+
+impl PoseidonBn254GasParameters {
+    pub fn zeros() -> Self {
+        Self {
+            base: 0.into(),
+            per_input: 0.into(),
+        }
+    }
+}
+
+/***************************************************************************************************
+ * module
+ **************************************************************************************************/
+
+#[derive(Debug, Clone)]
+pub struct GasParameters {
+    pub poseidon_bn254: PoseidonBn254GasParameters,
+}
+
+impl GasParameters {
+    pub fn zeros() -> Self {
+        Self {
+            poseidon_bn254: PoseidonBn254GasParameters::zeros(),
+        }
+    }
+}
+
+pub fn make_all(gas_params: GasParameters) -> impl Iterator<Item = (String, NativeFunction)> {
+    let natives = [(
+        "poseidon_bn254_internal",
+        make_native(gas_params.poseidon_bn254, native_poseidon_bn254),
+    )];
+
+    make_module_natives(natives)
+}
@@ -0,0 +1,170 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::natives::helpers::{make_module_natives, make_native};
+use fastcrypto::{
+    secp256r1::{Secp256r1PublicKey, Secp256r1Signature},
+    traits::{ToFromBytes, VerifyingKey},
+};
+use move_binary_format::errors::PartialVMResult;
+use move_core_types::gas_algebra::{InternalGas, InternalGasPerByte, NumBytes};
+use move_vm_runtime::native_functions::{NativeContext, NativeFunction};
+use move_vm_types::{
+    loaded_data::runtime_types::Type,
+    natives::function::NativeResult,
+    pop_arg,
+    values::{Value, VectorRef},
+};
+use rooch_types::webauthn;
+use smallvec::smallvec;
+use std::collections::VecDeque;
+
+pub const E_INVALID_COSE_KEY: u64 = 1;
+pub const E_INVALID_SIGNATURE: u64 = 2;
+pub const E_INVALID_PUBKEY: u64 = 3;
+
+/***************************************************************************************************
+ * native fun cose_key_to_public_key
+ * Implementation of the Move native function `webauthn::cose_key_to_public_key(cose_key: &vector<u8>): vector<u8>`
+ *   gas cost: base + per_byte * cose_key.len()
+ **************************************************************************************************/
+pub fn native_cose_key_to_public_key(
+    gas_params: &FromBytesGasParameters,
+    _context: &mut NativeContext,
+    ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(ty_args.is_empty());
+    debug_assert!(args.len() == 1);
+
+    let cose_key = pop_arg!(args, VectorRef);
+    let cose_key_ref = cose_key.as_bytes_ref();
+    let cost = gas_params.base + gas_params.per_byte * NumBytes::new(cose_key_ref.len() as u64);
+
+    let Ok(public_key) = webauthn::cose_key_to_public_key(&cose_key_ref) else {
+        return Ok(NativeResult::err(cost, E_INVALID_COSE_KEY));
+    };
+
+    Ok(NativeResult::ok(
+        cost,
+        smallvec![Value::vector_u8(public_key)],
+    ))
+}
+
+/***************************************************************************************************
+ * native fun verify
+ * Implementation of the Move native function
+ * `webauthn::verify(signature: &vector<u8>, public_key: &vector<u8>, authenticator_data: &vector<u8>, client_data_json: &vector<u8>, challenge: &vector<u8>, rp_id_hash: &vector<u8>, origin: &vector<u8>): bool`
+ *   gas cost: base + per_byte * (the length of all the arguments)
+ **************************************************************************************************/
+pub fn native_verify(
+    gas_params: &FromBytesGasParameters,
+    _context: &mut NativeContext,
+    ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(ty_args.is_empty());
+    debug_assert!(args.len() == 7);
+
+    let origin = pop_arg!(args, VectorRef);
+    let rp_id_hash = pop_arg!(args, VectorRef);
+    let challenge = pop_arg!(args, VectorRef);
+    let client_data_json = pop_arg!(args, VectorRef);
+    let authenticator_data = pop_arg!(args, VectorRef);
+    let public_key_bytes = pop_arg!(args, VectorRef);
+    let signature_bytes = pop_arg!(args, VectorRef);
+
+    let origin_ref = origin.as_bytes_ref();
+    let rp_id_hash_ref = rp_id_hash.as_bytes_ref();
+    let challenge_ref = challenge.as_bytes_ref();
+    let client_data_json_ref = client_data_json.as_bytes_ref();
+    let authenticator_data_ref = authenticator_data.as_bytes_ref();
+    let public_key_bytes_ref = public_key_bytes.as_bytes_ref();
+    let signature_bytes_ref = signature_bytes.as_bytes_ref();
+
+    let cost = gas_params.base
+        + gas_params.per_byte
+            * NumBytes::new(
+                (origin_ref.len()
+                    + rp_id_hash_ref.len()
+                    + challenge_ref.len()
+                    + client_data_json_ref.len()
+                    + authenticator_data_ref.len()
+                    + public_key_bytes_ref.len()
+                    + signature_bytes_ref.len()) as u64,
+            );
+
+    let Ok(sig) = <Secp256r1Signature as ToFromBytes>::from_bytes(&signature_bytes_ref) else {
+        return Ok(NativeResult::err(cost, E_INVALID_SIGNATURE));
+    };
+
+    let Ok(public_key) = <Secp256r1PublicKey as ToFromBytes>::from_bytes(&public_key_bytes_ref)
+    else {
+        return Ok(NativeResult::err(cost, E_INVALID_PUBKEY));
+    };
+
+    // The malformed client data or authenticator data fails the verification, the same as a wrong signature,
+    // so does the assertion of the other relying party
+    let client_data_valid = std::str::from_utf8(&origin_ref).map_or(false, |origin| {
+        webauthn::check_client_data(&client_data_json_ref, &challenge_ref, origin).is_ok()
+    });
+    let result = client_data_valid
+        && webauthn::check_authenticator_data(&authenticator_data_ref, &rp_id_hash_ref).is_ok()
+        && public_key
+            .verify(
+                &webauthn::signed_message(&authenticator_data_ref, &client_data_json_ref),
+                &sig,
+            )
+            .is_ok();
+
+    Ok(NativeResult::ok(cost, smallvec![Value::bool(result)]))
+}
+
+#[derive(Debug, Clone)]
+pub struct FromBytesGasParameters {
+    pub base: InternalGas,
+    pub per_byte: InternalGasPerByte,
+}
+
+impl FromBytesGasParameters {
+    pub fn zeros() -> Self {
+        Self {
+            base: 0.into(),
+            per_byte: 0.into(),
+        }
+    }
+}
+
+/***************************************************************************************************
+ * module
+ **************************************************************************************************/
+
+#[derive(Debug, Clone)]
+pub struct GasParameters {
+    pub cose_key_to_public_key: FromBytesGasParameters,
+    pub verify: FromBytesGasParameters,
+}
+
+impl GasParameters {
+    pub fn zeros() -> Self {
+        Self {
+            cose_key_to_public_key: FromBytesGasParameters::zeros(),
+            verify: FromBytesGasParameters::zeros(),
+        }
+    }
+}
+
+pub fn make_all(gas_params: GasParameters) -> impl Iterator<Item = (String, NativeFunction)> {
+    let natives = [
+        (
+            "cose_key_to_public_key",
+            make_native(
+                gas_params.cose_key_to_public_key,
+                native_cose_key_to_public_key,
+            ),
+        ),
+        ("verify", make_native(gas_params.verify, native_verify)),
+    ];
+
+    make_module_natives(natives)
+}
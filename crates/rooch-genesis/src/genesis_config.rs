@@ -0,0 +1,175 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use anyhow::{bail, Result};
+use move_core_types::u256::U256;
+use moveos_stdlib_builder::Stdlib;
+use rooch_framework::natives::{default_gas_schedule, GasSchedule};
+use rooch_types::address::RoochAddress;
+use rooch_types::bitcoin::genesis::BitcoinGenesisContext;
+use rooch_types::bitcoin::network::Network;
+use rooch_types::framework::genesis::GenesisContext;
+use serde::{Deserialize, Serialize};
+use serde_with::{serde_as, DisplayFromStr};
+use std::collections::BTreeSet;
+use std::path::{Path, PathBuf};
+
+/// The configuration of a custom genesis, loaded from a TOML file.
+/// All the inputs of the genesis are in the config, so every validator builds the same genesis from the same config.
+///
+/// ```toml
+/// chain_id = 20240227
+/// timestamp = 1709000000000000
+/// sequencer = "0x42"
+/// bitcoin_network = "regtest"
+/// gas_schedule = "gas_schedule.json"
+///
+/// [[allocations]]
+/// address = "0x42"
+/// amount = "100000000000000000000"
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct GenesisConfig {
+    pub chain_id: u64,
+    /// The genesis timestamp in microseconds
+    #[serde(default)]
+    pub timestamp: u64,
+    pub sequencer: RoochAddress,
+    /// The Bitcoin network of the chain: bitcoin, testnet, signet or regtest
+    #[serde(default = "GenesisConfig::default_bitcoin_network")]
+    pub bitcoin_network: String,
+    /// The gas schedule json file exported by `rooch genesis gas-schedule export`, the default gas schedule is used if absent
+    #[serde(default)]
+    pub gas_schedule: Option<PathBuf>,
+    /// The framework packages file built by the framework release, it overrides the framework packages of this binary
+    #[serde(default)]
+    pub stdlib: Option<PathBuf>,
+    /// The gas coins allocated to the accounts at genesis
+    #[serde(default)]
+    pub allocations: Vec<GenesisAllocation>,
+}
+
+#[serde_as]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct GenesisAllocation {
+    pub address: RoochAddress,
+    /// The amount of the gas coin with decimals, in string because TOML integers are limited to i64
+    #[serde_as(as = "DisplayFromStr")]
+    pub amount: U256,
+}
+
+impl GenesisConfig {
+    fn default_bitcoin_network() -> String {
+        Network::NetworkRegtest.to_string()
+    }
+
+    /// Load the config from the TOML file, the relative paths in the config are resolved against the directory of the file
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let path = path.as_ref();
+        let content = std::fs::read_to_string(path)?;
+        let mut config: Self = toml::from_str(&content)?;
+        if let Some(base_dir) = path.parent() {
+            config.gas_schedule = config.gas_schedule.map(|file| base_dir.join(file));
+            config.stdlib = config.stdlib.map(|file| base_dir.join(file));
+        }
+        config.validate()?;
+        Ok(config)
+    }
+
+    pub fn validate(&self) -> Result<()> {
+        Network::try_from(self.bitcoin_network.as_str())?;
+        let mut addresses = BTreeSet::new();
+        for allocation in &self.allocations {
+            if !addresses.insert(allocation.address) {
+                bail!(
+                    "Duplicate genesis allocation for address {}",
+                    allocation.address
+                );
+            }
+            if allocation.amount == U256::zero() {
+                bail!(
+                    "The genesis allocation for address {} should not be zero",
+                    allocation.address
+                );
+            }
+        }
+        Ok(())
+    }
+
+    pub fn gas_schedule(&self) -> Result<GasSchedule> {
+        match &self.gas_schedule {
+            Some(file) => Ok(serde_json::from_str(&std::fs::read_to_string(file)?)?),
+            None => Ok(default_gas_schedule()),
+        }
+    }
+
+    /// The framework packages of the genesis, `None` if the packages of this binary are used
+    pub fn stdlib(&self) -> Result<Option<Stdlib>> {
+        self.stdlib.as_ref().map(Stdlib::load_from_file).transpose()
+    }
+
+    pub fn genesis_ctx(&self) -> Result<GenesisContext> {
+        let gas_schedule_blob = bcs::to_bytes(&self.gas_schedule()?)?;
+        Ok(GenesisContext::new(
+            self.chain_id,
+            self.timestamp,
+            self.sequencer.into(),
+            gas_schedule_blob,
+        ))
+    }
+
+    pub fn bitcoin_genesis_ctx(&self) -> Result<BitcoinGenesisContext> {
+        let network = Network::try_from(self.bitcoin_network.as_str())?;
+        Ok(BitcoinGenesisContext::new(network.to_num()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_genesis_config() {
+        let config: GenesisConfig = toml::from_str(
+            r#"
+            chain_id = 20240227
+            timestamp = 1709000000000000
+            sequencer = "0x42"
+
+            [[allocations]]
+            address = "0x42"
+            amount = "100000000000000000000"
+            "#,
+        )
+        .unwrap();
+        config.validate().unwrap();
+        assert_eq!(config.bitcoin_network, "regtest");
+        assert_eq!(config.allocations.len(), 1);
+        assert_eq!(
+            config.allocations[0].amount,
+            U256::from(100_000_000_000_000_000_000u128)
+        );
+    }
+
+    #[test]
+    fn test_duplicate_allocation() {
+        let config: GenesisConfig = toml::from_str(
+            r#"
+            chain_id = 20240227
+            sequencer = "0x42"
+
+            [[allocations]]
+            address = "0x42"
+            amount = "1"
+
+            [[allocations]]
+            address = "0x42"
+            amount = "2"
+            "#,
+        )
+        .unwrap();
+        assert!(config.validate().is_err());
+    }
+}
@@ -3,7 +3,7 @@
 
 use anyhow::Result;
 use move_binary_format::{errors::Location, CompiledModule};
-use move_core_types::{account_address::AccountAddress, identifier::Identifier};
+use move_core_types::{account_address::AccountAddress, identifier::Identifier, u256::U256};
 use move_vm_runtime::{config::VMConfig, native_functions::NativeFunction};
 use moveos::moveos::{MoveOS, MoveOSConfig};
 use moveos_stdlib_builder::Stdlib;
@@ -15,10 +15,11 @@ use moveos_types::transaction::MoveAction;
 use once_cell::sync::Lazy;
 use rooch_framework::natives::default_gas_schedule;
 use rooch_framework::natives::gas_parameter::gas_member::InitialGasSchedule;
+use rooch_types::addresses::ROOCH_FRAMEWORK_ADDRESS;
 use rooch_types::bitcoin::genesis::BitcoinGenesisContext;
 use rooch_types::bitcoin::network::Network;
 use rooch_types::error::GenesisError;
-use rooch_types::framework::genesis::GenesisContext;
+use rooch_types::framework::genesis::{GenesisContext, GenesisModule};
 use rooch_types::transaction::rooch::RoochTransaction;
 use rooch_types::{address::RoochAddress, chain_id::RoochChainID};
 use serde::{Deserialize, Serialize};
@@ -29,6 +30,10 @@ use std::{
     path::{Path, PathBuf},
 };
 
+mod genesis_config;
+
+pub use genesis_config::{GenesisAllocation, GenesisConfig};
+
 pub static ROOCH_LOCAL_GENESIS: Lazy<RoochGenesis> = Lazy::new(|| {
     // TODO: For now, ROOCH_LOCAL_GENESIS in only used in integration-test.
     // There is no need to upgrade framework, so we set sequencer to 0x0.
@@ -85,6 +90,31 @@ impl RoochGenesis {
         bitcoin_genesis_ctx: BitcoinGenesisContext,
         option: BuildOption,
     ) -> Result<Self> {
+        let genesis_package = GenesisPackage::build(genesis_ctx, bitcoin_genesis_ctx, option)?;
+        Ok(Self::new(genesis_package))
+    }
+
+    /// Build the genesis from the custom genesis config, the same config always builds the same genesis package
+    pub fn build_with_config(genesis_config: &GenesisConfig) -> Result<Self> {
+        let stdlib = match genesis_config.stdlib()? {
+            Some(stdlib) => stdlib,
+            None => GenesisPackage::load_stdlib()?,
+        };
+        let allocations = genesis_config
+            .allocations
+            .iter()
+            .map(|allocation| (allocation.address.into(), allocation.amount))
+            .collect::<Vec<_>>();
+        let genesis_package = GenesisPackage::build_with_stdlib(
+            genesis_config.genesis_ctx()?,
+            genesis_config.bitcoin_genesis_ctx()?,
+            stdlib,
+            allocations,
+        )?;
+        Ok(Self::new(genesis_package))
+    }
+
+    fn new(genesis_package: GenesisPackage) -> Self {
         let config = MoveOSConfig {
             vm_config: VMConfig::default(),
         };
@@ -95,15 +125,14 @@ impl RoochGenesis {
 
         let rooch_framework_gas_params = rooch_framework::natives::NativeGasParameters::initial();
         let bitcoin_move_gas_params = bitcoin_move::natives::GasParameters::initial();
-        let genesis_package = GenesisPackage::build(genesis_ctx, bitcoin_genesis_ctx, option)?;
 
-        Ok(RoochGenesis {
+        RoochGenesis {
             config,
             config_for_test,
             rooch_framework_gas_params,
             bitcoin_move_gas_params,
             genesis_package,
-        })
+        }
     }
 
     pub fn modules(&self) -> Result<Vec<CompiledModule>> {
@@ -190,10 +219,19 @@ impl GenesisPackage {
             BuildOption::Fresh => Self::build_stdlib()?,
             BuildOption::Release => Self::load_stdlib()?,
         };
+        Self::build_with_stdlib(genesis_ctx, bitcoin_genesis_ctx, stdlib, vec![])
+    }
 
+    /// Build the genesis package with the framework packages, and allocate the gas coins to the accounts after the framework is published
+    fn build_with_stdlib(
+        genesis_ctx: GenesisContext,
+        bitcoin_genesis_ctx: BitcoinGenesisContext,
+        stdlib: Stdlib,
+        allocations: Vec<(AccountAddress, U256)>,
+    ) -> Result<Self> {
         let bundles = stdlib.module_bundles()?;
 
-        let genesis_txs: Vec<RoochTransaction> = bundles
+        let mut genesis_txs: Vec<RoochTransaction> = bundles
             .into_iter()
             .map(|(genesis_account, bundle)| {
                 RoochTransaction::new_genesis_tx(
@@ -203,6 +241,13 @@ impl GenesisPackage {
                 )
             })
             .collect();
+        if !allocations.is_empty() {
+            genesis_txs.push(RoochTransaction::new_genesis_tx(
+                ROOCH_FRAMEWORK_ADDRESS.into(),
+                genesis_ctx.chain_id,
+                GenesisModule::create_allocate_action(allocations),
+            ));
+        }
         //TODO put gas parameters into genesis package
         let gas_parameters = rooch_framework::natives::NativeGasParameters::initial();
         let vm_config = MoveOSConfig {
@@ -339,4 +384,28 @@ mod tests {
             )
             .expect("init genesis failed");
     }
+
+    #[test]
+    fn test_genesis_with_config() {
+        let genesis_config = crate::GenesisConfig {
+            chain_id: RoochChainID::LOCAL.chain_id().id(),
+            timestamp: 0,
+            sequencer: RoochAddress::random(),
+            bitcoin_network: Network::NetworkRegtest.to_string(),
+            gas_schedule: None,
+            stdlib: None,
+            allocations: vec![crate::GenesisAllocation {
+                address: RoochAddress::random(),
+                amount: 100u64.into(),
+            }],
+        };
+        let genesis = super::RoochGenesis::build_with_config(&genesis_config)
+            .expect("build rooch genesis with config failed");
+        // The framework bundles and the allocation transaction
+        assert_eq!(genesis.genesis_package.genesis_txs.len(), 5);
+
+        let rebuilt_genesis = super::RoochGenesis::build_with_config(&genesis_config)
+            .expect("build rooch genesis with config failed");
+        assert_eq!(genesis.genesis_info(), rebuilt_genesis.genesis_info());
+    }
 }
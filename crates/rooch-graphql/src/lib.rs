@@ -0,0 +1,120 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::schema::{build_schema, RoochSchema};
+use anyhow::Result;
+use async_graphql::http::GraphiQLSource;
+use async_graphql::BatchRequest;
+use hyper::header::{
+    HeaderValue, ACCESS_CONTROL_ALLOW_HEADERS, ACCESS_CONTROL_ALLOW_METHODS,
+    ACCESS_CONTROL_ALLOW_ORIGIN, CONTENT_TYPE,
+};
+use hyper::service::{make_service_fn, service_fn};
+use hyper::{Body, Method, Request, Response, Server, StatusCode};
+use rooch_indexer::proxy::IndexerProxy;
+use std::convert::Infallible;
+use std::net::SocketAddr;
+use tokio::sync::oneshot;
+use tokio::task::JoinHandle;
+use tracing::{error, info};
+
+pub mod schema;
+#[cfg(test)]
+mod tests;
+pub mod types;
+
+pub struct GraphQLServerHandle {
+    shutdown_tx: oneshot::Sender<()>,
+    join_handle: JoinHandle<()>,
+}
+
+impl GraphQLServerHandle {
+    pub fn stop(self) -> Result<()> {
+        // The server may have exited already, ignore the error.
+        let _ = self.shutdown_tx.send(());
+        self.join_handle.abort();
+        Ok(())
+    }
+}
+
+async fn serve_graphql(
+    req: Request<Body>,
+    schema: RoochSchema,
+) -> Result<Response<Body>, Infallible> {
+    let mut resp = match (req.method(), req.uri().path()) {
+        // The GraphiQL IDE
+        (&Method::GET, "/") => {
+            let mut resp =
+                Response::new(Body::from(GraphiQLSource::build().endpoint("/").finish()));
+            resp.headers_mut()
+                .insert(CONTENT_TYPE, HeaderValue::from_static("text/html"));
+            resp
+        }
+        (&Method::POST, "/") => match hyper::body::to_bytes(req.into_body())
+            .await
+            .map_err(anyhow::Error::from)
+            .and_then(|body| Ok(serde_json::from_slice::<BatchRequest>(&body)?))
+        {
+            Ok(request) => {
+                let response = schema.execute_batch(request).await;
+                let body = serde_json::to_vec(&response)
+                    .expect("Serialize GraphQL response should success");
+                let mut resp = Response::new(Body::from(body));
+                resp.headers_mut()
+                    .insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
+                resp
+            }
+            Err(e) => {
+                let mut resp = Response::new(Body::from(format!("Invalid GraphQL request: {}", e)));
+                *resp.status_mut() = StatusCode::BAD_REQUEST;
+                resp
+            }
+        },
+        // The CORS preflight request of the browsers
+        (&Method::OPTIONS, "/") => Response::new(Body::empty()),
+        _ => {
+            let mut resp = Response::new(Body::empty());
+            *resp.status_mut() = StatusCode::NOT_FOUND;
+            resp
+        }
+    };
+    let headers = resp.headers_mut();
+    headers.insert(ACCESS_CONTROL_ALLOW_ORIGIN, HeaderValue::from_static("*"));
+    headers.insert(
+        ACCESS_CONTROL_ALLOW_METHODS,
+        HeaderValue::from_static("GET, POST, OPTIONS"),
+    );
+    headers.insert(
+        ACCESS_CONTROL_ALLOW_HEADERS,
+        HeaderValue::from_static("content-type"),
+    );
+    Ok(resp)
+}
+
+// Start GraphQL server
+pub async fn start_graphql_server(
+    addr: SocketAddr,
+    indexer: IndexerProxy,
+) -> Result<GraphQLServerHandle> {
+    let schema = build_schema(indexer);
+    let make_service = make_service_fn(move |_| {
+        let schema = schema.clone();
+        async move { Ok::<_, Infallible>(service_fn(move |req| serve_graphql(req, schema.clone()))) }
+    });
+    let (shutdown_tx, shutdown_rx) = oneshot::channel::<()>();
+    let server = Server::try_bind(&addr)?
+        .serve(make_service)
+        .with_graceful_shutdown(async {
+            shutdown_rx.await.ok();
+        });
+    let join_handle = tokio::spawn(async move {
+        if let Err(e) = server.await {
+            error!("GraphQL server exit with error: {:?}", e);
+        }
+    });
+    info!("GraphQL Server start listening {:?}", addr);
+    Ok(GraphQLServerHandle {
+        shutdown_tx,
+        join_handle,
+    })
+}
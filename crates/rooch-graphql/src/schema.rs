@@ -0,0 +1,192 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::types::{
+    Event, EventFilterInput, GlobalState, GlobalStateFilterInput, TableState, Transaction,
+    TransactionFilterInput,
+};
+use anyhow::Result;
+use async_graphql::connection::{Connection, Edge};
+use async_graphql::{Context, EmptyMutation, EmptySubscription, Object, Schema};
+use moveos_types::moveos_std::object_id::ObjectID;
+use rooch_indexer::proxy::IndexerProxy;
+use rooch_rpc_api::api::{DEFAULT_RESULT_LIMIT_USIZE, MAX_RESULT_LIMIT_USIZE};
+use rooch_types::indexer::event_filter::IndexerEventID;
+use rooch_types::indexer::state::{IndexerStateID, TableStateFilter};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::cmp::min;
+use std::str::FromStr;
+
+/// The max depth of the query, to limit the nested resolvers such as tx -> events -> tx
+const MAX_QUERY_DEPTH: usize = 10;
+
+pub type RoochSchema = Schema<QueryRoot, EmptyMutation, EmptySubscription>;
+
+pub fn build_schema(indexer: IndexerProxy) -> RoochSchema {
+    Schema::build(QueryRoot, EmptyMutation, EmptySubscription)
+        .data(indexer)
+        .limit_depth(MAX_QUERY_DEPTH)
+        .finish()
+}
+
+pub(crate) fn indexer<'a>(ctx: &Context<'a>) -> async_graphql::Result<&'a IndexerProxy> {
+    ctx.data::<IndexerProxy>()
+}
+
+fn limit_of(first: Option<usize>) -> usize {
+    min(
+        first.unwrap_or(DEFAULT_RESULT_LIMIT_USIZE),
+        MAX_RESULT_LIMIT_USIZE,
+    )
+}
+
+/// Encode the indexer id to an opaque cursor, clients should pass it back as is.
+fn encode_cursor<T: Serialize>(id: &T) -> String {
+    hex::encode(bcs::to_bytes(id).expect("Serialize cursor should success"))
+}
+
+fn decode_cursor<T: DeserializeOwned>(cursor: &str) -> Result<T> {
+    let bytes = hex::decode(cursor.strip_prefix("0x").unwrap_or(cursor))?;
+    Ok(bcs::from_bytes(&bytes)?)
+}
+
+/// Fetch one more item than the limit to know whether there is a next page
+fn build_connection<T, N>(
+    mut data: Vec<T>,
+    limit: usize,
+    has_previous_page: bool,
+    cursor_of: impl Fn(&T) -> String,
+) -> Connection<String, N>
+where
+    N: From<T> + async_graphql::OutputType,
+{
+    let has_next_page = data.len() > limit;
+    data.truncate(limit);
+    let mut connection = Connection::new(has_previous_page, has_next_page);
+    connection.edges.extend(
+        data.into_iter()
+            .map(|item| Edge::new(cursor_of(&item), N::from(item))),
+    );
+    connection
+}
+
+pub struct QueryRoot;
+
+#[Object]
+impl QueryRoot {
+    /// Query the transactions, the transactions are returned in descending order of the tx order by default
+    async fn transactions(
+        &self,
+        ctx: &Context<'_>,
+        filter: Option<TransactionFilterInput>,
+        after: Option<String>,
+        first: Option<usize>,
+        descending_order: Option<bool>,
+    ) -> async_graphql::Result<Connection<String, Transaction>> {
+        let limit = limit_of(first);
+        let cursor = after
+            .map(|cursor| decode_cursor::<u64>(&cursor))
+            .transpose()?;
+        let data = indexer(ctx)?
+            .query_transactions(
+                TransactionFilterInput::into_filter(filter)?,
+                cursor,
+                limit + 1,
+                descending_order.unwrap_or(true),
+            )
+            .await?;
+        Ok(build_connection(data, limit, cursor.is_some(), |tx| {
+            encode_cursor(&tx.sequence_info.tx_order)
+        }))
+    }
+
+    async fn transaction(
+        &self,
+        ctx: &Context<'_>,
+        tx_hash: String,
+    ) -> async_graphql::Result<Option<Transaction>> {
+        let filter =
+            TransactionFilterInput::into_filter(Some(TransactionFilterInput::TxHashes(vec![
+                tx_hash,
+            ])))?;
+        let mut data = indexer(ctx)?
+            .query_transactions(filter, None, 1, false)
+            .await?;
+        Ok(data.pop().map(Transaction::from))
+    }
+
+    /// Query the events, the events are returned in descending order of the event id by default
+    async fn events(
+        &self,
+        ctx: &Context<'_>,
+        filter: Option<EventFilterInput>,
+        after: Option<String>,
+        first: Option<usize>,
+        descending_order: Option<bool>,
+    ) -> async_graphql::Result<Connection<String, Event>> {
+        let limit = limit_of(first);
+        let cursor = after
+            .map(|cursor| decode_cursor::<IndexerEventID>(&cursor))
+            .transpose()?;
+        let data = indexer(ctx)?
+            .query_events(
+                EventFilterInput::into_filter(filter)?,
+                cursor,
+                limit + 1,
+                descending_order.unwrap_or(true),
+            )
+            .await?;
+        Ok(build_connection(data, limit, cursor.is_some(), |event| {
+            encode_cursor(&event.indexer_event_id)
+        }))
+    }
+
+    /// Query the global states, the states are returned in descending order of the state id by default
+    async fn global_states(
+        &self,
+        ctx: &Context<'_>,
+        filter: GlobalStateFilterInput,
+        after: Option<String>,
+        first: Option<usize>,
+        descending_order: Option<bool>,
+    ) -> async_graphql::Result<Connection<String, GlobalState>> {
+        let limit = limit_of(first);
+        let cursor = after
+            .map(|cursor| IndexerStateID::from_cursor(&cursor))
+            .transpose()?;
+        let data = indexer(ctx)?
+            .query_global_states(
+                filter.try_into()?,
+                cursor,
+                limit + 1,
+                descending_order.unwrap_or(true),
+            )
+            .await?;
+        Ok(build_connection(data, limit, cursor.is_some(), |state| {
+            IndexerStateID::new(state.tx_order, state.state_index).to_cursor()
+        }))
+    }
+
+    /// Query the items of the table, the states are returned in descending order of the state id by default
+    async fn table_states(
+        &self,
+        ctx: &Context<'_>,
+        table_handle: String,
+        after: Option<String>,
+        first: Option<usize>,
+        descending_order: Option<bool>,
+    ) -> async_graphql::Result<Connection<String, TableState>> {
+        let limit = limit_of(first);
+        let cursor = after
+            .map(|cursor| IndexerStateID::from_cursor(&cursor))
+            .transpose()?;
+        let filter = TableStateFilter::TableHandle(ObjectID::from_str(&table_handle)?);
+        let data = indexer(ctx)?
+            .query_table_states(filter, cursor, limit + 1, descending_order.unwrap_or(true))
+            .await?;
+        Ok(build_connection(data, limit, cursor.is_some(), |state| {
+            IndexerStateID::new(state.tx_order, state.state_index).to_cursor()
+        }))
+    }
+}
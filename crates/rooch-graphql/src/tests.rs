@@ -0,0 +1,214 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::start_graphql_server;
+use anyhow::Result;
+use coerce::actor::system::ActorSystem;
+use coerce::actor::IntoActor;
+use hyper::{Body, Client, Method, Request};
+use move_core_types::account_address::AccountAddress;
+use move_core_types::vm_status::KeptVMStatus;
+use moveos_config::DataDirPath;
+use moveos_store::MoveOSStore;
+use moveos_types::h256::H256;
+use moveos_types::moveos_std::tx_context::TxContext;
+use moveos_types::transaction::{TransactionExecutionInfo, VerifiedMoveOSTransaction};
+use rooch_config::indexer_config::ROOCH_INDEXER_DB_FILENAME;
+use rooch_indexer::actor::indexer::IndexerActor;
+use rooch_indexer::actor::reader_indexer::IndexerReaderActor;
+use rooch_indexer::indexer_reader::IndexerReader;
+use rooch_indexer::proxy::IndexerProxy;
+use rooch_indexer::store::traits::IndexerStoreTrait;
+use rooch_indexer::types::{IndexedEvent, IndexedTransaction};
+use rooch_indexer::IndexerStore;
+use rooch_types::test_utils::{
+    random_bytes, random_event, random_function_calls, random_typed_transaction,
+    random_verified_move_action,
+};
+use rooch_types::transaction::authenticator::Authenticator;
+use rooch_types::transaction::TransactionSequenceInfo;
+use serde_json::{json, Value};
+use std::net::{SocketAddr, TcpListener};
+
+/// The indexer of the mock service, the transactions with the tx order `1..=tx_count` are indexed
+/// with one event each, the tx hashes are returned in the tx order.
+async fn mock_indexer(tmpdir: &DataDirPath, tx_count: u64) -> Result<(IndexerProxy, Vec<H256>)> {
+    let indexer_db = tmpdir.path().join(ROOCH_INDEXER_DB_FILENAME);
+    std::fs::File::create(indexer_db.clone())?;
+    let indexer_db_url = indexer_db
+        .as_path()
+        .to_str()
+        .ok_or(anyhow::anyhow!("Invalid mock indexer db dir"))?;
+    let indexer_store = IndexerStore::new(indexer_db_url)?;
+    indexer_store.create_all_tables_if_not_exists()?;
+    let indexer_reader = IndexerReader::new(indexer_db_url)?;
+
+    let mut tx_hashes = vec![];
+    for tx_order in 1..=tx_count {
+        let transaction = random_typed_transaction();
+        let sequence_info = TransactionSequenceInfo::new(
+            tx_order,
+            Authenticator::new(rand::random(), random_bytes()),
+            H256::random(),
+        );
+        let execution_info = TransactionExecutionInfo::new(
+            transaction.tx_hash(),
+            H256::random(),
+            H256::random(),
+            rand::random(),
+            KeptVMStatus::Executed,
+        );
+        let moveos_tx = VerifiedMoveOSTransaction {
+            ctx: TxContext::new_readonly_ctx(AccountAddress::random()),
+            action: random_verified_move_action(),
+            pre_execute_functions: random_function_calls(),
+            post_execute_functions: random_function_calls(),
+        };
+        let mut event = random_event();
+        event.event_index = 0;
+        let indexed_event = IndexedEvent::new(
+            event,
+            transaction.clone(),
+            sequence_info.clone(),
+            moveos_tx.clone(),
+        );
+        tx_hashes.push(transaction.tx_hash());
+        indexer_store.persist_transactions(vec![IndexedTransaction::new(
+            transaction,
+            sequence_info,
+            execution_info,
+            moveos_tx,
+        )?])?;
+        indexer_store.persist_events(vec![indexed_event])?;
+    }
+
+    let actor_system = ActorSystem::new();
+    let indexer_executor = IndexerActor::new(indexer_store, MoveOSStore::mock_moveos_store()?)?
+        .into_actor(Some("Indexer"), &actor_system)
+        .await?;
+    let indexer_reader_executor = IndexerReaderActor::new(indexer_reader)?
+        .into_actor(Some("IndexerReader"), &actor_system)
+        .await?;
+    let indexer_proxy = IndexerProxy::new(indexer_executor.into(), indexer_reader_executor.into());
+    Ok((indexer_proxy, tx_hashes))
+}
+
+fn free_addr() -> Result<SocketAddr> {
+    Ok(TcpListener::bind("127.0.0.1:0")?.local_addr()?)
+}
+
+/// Post the query to the GraphQL server and return the data of the response, or the error messages
+async fn post_query(addr: SocketAddr, query: &str) -> Result<Value> {
+    let req = Request::builder()
+        .method(Method::POST)
+        .uri(format!("http://{}/", addr))
+        .header("content-type", "application/json")
+        .body(Body::from(json!({ "query": query }).to_string()))?;
+    let resp = Client::new().request(req).await?;
+    let body = hyper::body::to_bytes(resp.into_body()).await?;
+    let mut resp: Value = serde_json::from_slice(&body)?;
+    if let Some(errors) = resp.get("errors") {
+        anyhow::bail!("GraphQL errors: {}", errors);
+    }
+    Ok(resp["data"].take())
+}
+
+fn tx_orders(connection: &Value) -> Vec<u64> {
+    connection["edges"]
+        .as_array()
+        .expect("The edges should be an array")
+        .iter()
+        .map(|edge| edge["node"]["txOrder"].as_u64().unwrap())
+        .collect()
+}
+
+#[tokio::test]
+async fn test_transactions_pagination() -> Result<()> {
+    let tmpdir = moveos_config::temp_dir();
+    let (indexer, _tx_hashes) = mock_indexer(&tmpdir, 3).await?;
+    let addr = free_addr()?;
+    let handle = start_graphql_server(addr, indexer).await?;
+
+    let query = "{ transactions(first: 2) { edges { cursor node { txOrder } } \
+                 pageInfo { hasPreviousPage hasNextPage } } }";
+    let data = post_query(addr, query).await?;
+    let page = &data["transactions"];
+    assert_eq!(tx_orders(page), vec![3, 2]);
+    assert_eq!(page["pageInfo"]["hasPreviousPage"], json!(false));
+    assert_eq!(page["pageInfo"]["hasNextPage"], json!(true));
+
+    // The cursor of the last edge is passed back to fetch the next page
+    let cursor = page["edges"][1]["cursor"].as_str().unwrap();
+    let query = format!(
+        "{{ transactions(first: 2, after: \"{}\") {{ edges {{ node {{ txOrder }} }} \
+         pageInfo {{ hasPreviousPage hasNextPage }} }} }}",
+        cursor
+    );
+    let data = post_query(addr, &query).await?;
+    let page = &data["transactions"];
+    assert_eq!(tx_orders(page), vec![1]);
+    assert_eq!(page["pageInfo"]["hasPreviousPage"], json!(true));
+    assert_eq!(page["pageInfo"]["hasNextPage"], json!(false));
+
+    let data = post_query(
+        addr,
+        "{ transactions(descendingOrder: false) { edges { node { txOrder } } } }",
+    )
+    .await?;
+    assert_eq!(tx_orders(&data["transactions"]), vec![1, 2, 3]);
+
+    // The invalid cursor is returned as the GraphQL error
+    assert!(post_query(
+        addr,
+        "{ transactions(after: \"0xzz\") { edges { cursor } } }"
+    )
+    .await
+    .is_err());
+
+    handle.stop()?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_transaction_with_events() -> Result<()> {
+    let tmpdir = moveos_config::temp_dir();
+    let (indexer, tx_hashes) = mock_indexer(&tmpdir, 2).await?;
+    let addr = free_addr()?;
+    let handle = start_graphql_server(addr, indexer).await?;
+
+    let tx_hash = format!("{:?}", tx_hashes[1]);
+    let query = format!(
+        "{{ transaction(txHash: \"{}\") {{ txHash txOrder events {{ txHash eventIndex }} }} }}",
+        tx_hash
+    );
+    let data = post_query(addr, &query).await?;
+    let tx = &data["transaction"];
+    assert_eq!(tx["txHash"], json!(tx_hash));
+    assert_eq!(tx["txOrder"], json!(2));
+    assert_eq!(
+        tx["events"],
+        json!([{ "txHash": tx_hash.clone(), "eventIndex": 0 }])
+    );
+
+    // The events are filtered by the tx hash, and resolve the transaction which emitted them
+    let query = format!(
+        "{{ events(filter: {{ txHash: \"{}\" }}) {{ edges {{ node {{ txOrder \
+         transaction {{ txHash }} }} }} }} }}",
+        tx_hash
+    );
+    let data = post_query(addr, &query).await?;
+    assert_eq!(
+        data["events"]["edges"],
+        json!([{ "node": { "txOrder": 2, "transaction": { "txHash": tx_hash.clone() } } }])
+    );
+
+    let query = format!(
+        "{{ transaction(txHash: \"{:?}\") {{ txHash }} }}",
+        H256::random()
+    );
+    let data = post_query(addr, &query).await?;
+    assert_eq!(data["transaction"], Value::Null);
+
+    handle.stop()?;
+    Ok(())
+}
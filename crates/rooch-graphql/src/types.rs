@@ -0,0 +1,405 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+//! The GraphQL types of the indexer schema, they are converted from the indexer types,
+//! and the complex Move values are returned as JSON in the format of the JSON-RPC views.
+
+use crate::schema::indexer;
+use anyhow::Result;
+use async_graphql::{Context, InputObject, Json, Object, OneofObject, SimpleObject};
+use move_core_types::account_address::AccountAddress;
+use move_core_types::language_storage::StructTag;
+use moveos_types::h256::H256;
+use moveos_types::moveos_std::object_id::ObjectID;
+use moveos_types::transaction::TransactionExecutionInfo;
+use rooch_rpc_api::api::MAX_RESULT_LIMIT_USIZE;
+use rooch_rpc_api::jsonrpc_types::{KeptVMStatusView, TransactionView};
+use rooch_types::indexer::event_filter::{EventFilter, IndexerEvent};
+use rooch_types::indexer::state::{GlobalStateFilter, IndexerGlobalState, IndexerTableState};
+use rooch_types::indexer::transaction_filter::TransactionFilter;
+use rooch_types::transaction::{TransactionSequenceInfo, TransactionWithInfo};
+use serde::Serialize;
+use std::str::FromStr;
+
+/// The order range filter which matches all the transactions, the indexer stores the order as i64.
+const ALL_TX_ORDER_RANGE: (u64, u64) = (0, i64::MAX as u64);
+
+fn to_json<T: Serialize>(value: &T) -> Json<serde_json::Value> {
+    Json(serde_json::to_value(value).expect("Serialize view to json should success"))
+}
+
+/// The indexer stores the decoded values as JSON strings, fallback to the raw string if it is not a valid JSON
+fn parse_json(value: &str) -> Json<serde_json::Value> {
+    Json(
+        serde_json::from_str(value).unwrap_or_else(|_| serde_json::Value::String(value.to_owned())),
+    )
+}
+
+fn parse_h256(hash: &str) -> Result<H256> {
+    Ok(H256::from_str(hash.strip_prefix("0x").unwrap_or(hash))?)
+}
+
+fn parse_address(address: &str) -> Result<AccountAddress> {
+    Ok(AccountAddress::from_str(address)?)
+}
+
+fn parse_struct_tag(struct_tag: &str) -> Result<StructTag> {
+    Ok(StructTag::from_str(struct_tag)?)
+}
+
+/// The half-open interval `[from, to)`
+#[derive(InputObject, Clone, Debug)]
+pub struct RangeInput {
+    /// left endpoint of the interval, inclusive
+    pub from: u64,
+    /// right endpoint of the interval, exclusive
+    pub to: u64,
+}
+
+#[derive(OneofObject, Clone, Debug)]
+pub enum TransactionFilterInput {
+    /// Query by sender address.
+    Sender(String),
+    /// Query by the transaction hash list.
+    TxHashes(Vec<String>),
+    /// Return transactions created in the time range, milliseconds since epoch
+    TimeRange(RangeInput),
+    /// Return transactions in the tx order range
+    TxOrderRange(RangeInput),
+}
+
+impl TransactionFilterInput {
+    /// Convert to the indexer filter, all the transactions are matched if the filter is not set
+    pub fn into_filter(filter: Option<Self>) -> Result<TransactionFilter> {
+        Ok(match filter {
+            None => TransactionFilter::TxOrderRange {
+                from_order: ALL_TX_ORDER_RANGE.0,
+                to_order: ALL_TX_ORDER_RANGE.1,
+            },
+            Some(Self::Sender(sender)) => TransactionFilter::Sender(parse_address(&sender)?),
+            Some(Self::TxHashes(tx_hashes)) => TransactionFilter::TxHashes(
+                tx_hashes
+                    .iter()
+                    .map(|tx_hash| parse_h256(tx_hash))
+                    .collect::<Result<Vec<_>>>()?,
+            ),
+            Some(Self::TimeRange(range)) => TransactionFilter::TimeRange {
+                start_time: range.from,
+                end_time: range.to,
+            },
+            Some(Self::TxOrderRange(range)) => TransactionFilter::TxOrderRange {
+                from_order: range.from,
+                to_order: range.to,
+            },
+        })
+    }
+}
+
+#[derive(OneofObject, Clone, Debug)]
+pub enum EventFilterInput {
+    /// Query by event type.
+    EventType(String),
+    /// Query by sender address.
+    Sender(String),
+    /// Return events emitted by the given transaction hash.
+    TxHash(String),
+    /// Return events emitted in the time range, milliseconds since epoch
+    TimeRange(RangeInput),
+    /// Return events emitted in the tx order range
+    TxOrderRange(RangeInput),
+    /// Return events emitted by the transaction in the event index range
+    TxEventIndexRange(TxEventIndexRangeInput),
+}
+
+#[derive(InputObject, Clone, Debug)]
+pub struct TxEventIndexRangeInput {
+    pub tx_hash: String,
+    /// The event index range in the transaction
+    pub range: RangeInput,
+}
+
+impl EventFilterInput {
+    /// Convert to the indexer filter, all the events are matched if the filter is not set
+    pub fn into_filter(filter: Option<Self>) -> Result<EventFilter> {
+        Ok(match filter {
+            None => EventFilter::TxOrderRange {
+                from_order: ALL_TX_ORDER_RANGE.0,
+                to_order: ALL_TX_ORDER_RANGE.1,
+            },
+            Some(Self::EventType(event_type)) => {
+                EventFilter::EventType(parse_struct_tag(&event_type)?)
+            }
+            Some(Self::Sender(sender)) => EventFilter::Sender(parse_address(&sender)?),
+            Some(Self::TxHash(tx_hash)) => EventFilter::TxHash(parse_h256(&tx_hash)?),
+            Some(Self::TimeRange(range)) => EventFilter::TimeRange {
+                start_time: range.from,
+                end_time: range.to,
+            },
+            Some(Self::TxOrderRange(range)) => EventFilter::TxOrderRange {
+                from_order: range.from,
+                to_order: range.to,
+            },
+            Some(Self::TxEventIndexRange(input)) => EventFilter::TxEventIndexRange {
+                tx_hash: parse_h256(&input.tx_hash)?,
+                from_index: input.range.from,
+                to_index: input.range.to,
+            },
+        })
+    }
+}
+
+#[derive(InputObject, Clone, Debug)]
+pub struct ObjectTypeWithOwnerInput {
+    pub object_type: String,
+    pub owner: String,
+}
+
+#[derive(OneofObject, Clone, Debug)]
+pub enum GlobalStateFilterInput {
+    /// Query by object type and owner.
+    ObjectTypeWithOwner(ObjectTypeWithOwnerInput),
+    /// Query by object type.
+    ObjectType(String),
+    /// Query by owner.
+    Owner(String),
+    /// Query by object id.
+    ObjectId(String),
+}
+
+impl TryFrom<GlobalStateFilterInput> for GlobalStateFilter {
+    type Error = anyhow::Error;
+
+    fn try_from(filter: GlobalStateFilterInput) -> Result<Self> {
+        Ok(match filter {
+            GlobalStateFilterInput::ObjectTypeWithOwner(input) => {
+                GlobalStateFilter::ObjectTypeWithOwner {
+                    object_type: parse_struct_tag(&input.object_type)?,
+                    owner: parse_address(&input.owner)?,
+                }
+            }
+            GlobalStateFilterInput::ObjectType(object_type) => {
+                GlobalStateFilter::ObjectType(parse_struct_tag(&object_type)?)
+            }
+            GlobalStateFilterInput::Owner(owner) => {
+                GlobalStateFilter::Owner(parse_address(&owner)?)
+            }
+            GlobalStateFilterInput::ObjectId(object_id) => {
+                GlobalStateFilter::ObjectId(ObjectID::from_str(&object_id)?)
+            }
+        })
+    }
+}
+
+pub struct Transaction {
+    transaction: TransactionView,
+    sequence_info: TransactionSequenceInfo,
+    execution_info: TransactionExecutionInfo,
+}
+
+impl From<TransactionWithInfo> for Transaction {
+    fn from(tx: TransactionWithInfo) -> Self {
+        Self {
+            transaction: tx.transaction.into(),
+            sequence_info: tx.sequence_info,
+            execution_info: tx.execution_info,
+        }
+    }
+}
+
+#[Object]
+impl Transaction {
+    async fn tx_hash(&self) -> String {
+        format!("{:?}", self.execution_info.tx_hash)
+    }
+
+    async fn tx_order(&self) -> u64 {
+        self.sequence_info.tx_order
+    }
+
+    async fn tx_accumulator_root(&self) -> String {
+        format!("{:?}", self.sequence_info.tx_accumulator_root)
+    }
+
+    async fn transaction_type(&self) -> Json<serde_json::Value> {
+        to_json(&self.transaction.transaction_type)
+    }
+
+    async fn sequence_number(&self) -> u64 {
+        self.transaction.sequence_number
+    }
+
+    async fn sender(&self) -> &str {
+        &self.transaction.sender
+    }
+
+    /// The decoded action of the transaction
+    async fn action(&self) -> Json<serde_json::Value> {
+        to_json(&self.transaction.action)
+    }
+
+    async fn state_root(&self) -> String {
+        format!("{:?}", self.execution_info.state_root)
+    }
+
+    async fn event_root(&self) -> String {
+        format!("{:?}", self.execution_info.event_root)
+    }
+
+    async fn gas_used(&self) -> u64 {
+        self.execution_info.gas_used
+    }
+
+    async fn status(&self) -> Json<serde_json::Value> {
+        to_json(&KeptVMStatusView::from(self.execution_info.status.clone()))
+    }
+
+    /// The events emitted by the transaction, in the order of the event index
+    async fn events(&self, ctx: &Context<'_>) -> async_graphql::Result<Vec<Event>> {
+        let events = indexer(ctx)?
+            .query_events(
+                EventFilter::TxHash(self.execution_info.tx_hash),
+                None,
+                MAX_RESULT_LIMIT_USIZE,
+                false,
+            )
+            .await?;
+        Ok(events.into_iter().map(Event::from).collect())
+    }
+}
+
+pub struct Event {
+    event: IndexerEvent,
+}
+
+impl From<IndexerEvent> for Event {
+    fn from(event: IndexerEvent) -> Self {
+        Self { event }
+    }
+}
+
+#[Object]
+impl Event {
+    async fn tx_order(&self) -> u64 {
+        self.event.indexer_event_id.tx_order
+    }
+
+    async fn event_index(&self) -> u64 {
+        self.event.indexer_event_id.event_index
+    }
+
+    async fn event_handle_id(&self) -> String {
+        self.event.event_id.event_handle_id.to_string()
+    }
+
+    async fn event_seq(&self) -> u64 {
+        self.event.event_id.event_seq
+    }
+
+    async fn event_type(&self) -> String {
+        self.event.event_type.to_canonical_string()
+    }
+
+    /// The BCS bytes of the event data in hex
+    async fn event_data(&self) -> String {
+        format!("0x{}", hex::encode(&self.event.event_data))
+    }
+
+    /// The event data decoded by the indexer, null if the event is not decoded
+    async fn decoded_data(&self) -> Option<Json<serde_json::Value>> {
+        self.event
+            .event_data_json
+            .as_ref()
+            .map(|json| parse_json(json))
+    }
+
+    async fn tx_hash(&self) -> String {
+        format!("{:?}", self.event.tx_hash)
+    }
+
+    async fn sender(&self) -> String {
+        self.event.sender.to_hex_literal()
+    }
+
+    async fn created_at(&self) -> u64 {
+        self.event.created_at
+    }
+
+    /// The transaction which emitted the event
+    async fn transaction(&self, ctx: &Context<'_>) -> async_graphql::Result<Option<Transaction>> {
+        let mut transactions = indexer(ctx)?
+            .query_transactions(
+                TransactionFilter::TxHashes(vec![self.event.tx_hash]),
+                None,
+                1,
+                false,
+            )
+            .await?;
+        Ok(transactions.pop().map(Transaction::from))
+    }
+}
+
+#[derive(SimpleObject)]
+pub struct GlobalState {
+    pub object_id: String,
+    pub owner: String,
+    pub flag: u8,
+    /// The decoded value of the object
+    pub value: Json<serde_json::Value>,
+    pub object_type: String,
+    pub state_root: String,
+    pub size: u64,
+    pub tx_order: u64,
+    pub state_index: u64,
+    pub created_at: u64,
+    pub updated_at: u64,
+}
+
+impl From<IndexerGlobalState> for GlobalState {
+    fn from(state: IndexerGlobalState) -> Self {
+        Self {
+            object_id: state.object_id.to_string(),
+            owner: state.owner.to_hex_literal(),
+            flag: state.flag,
+            value: parse_json(&state.value),
+            object_type: state.object_type.to_canonical_string(),
+            state_root: state.state_root.to_hex_literal(),
+            size: state.size,
+            tx_order: state.tx_order,
+            state_index: state.state_index,
+            created_at: state.created_at,
+            updated_at: state.updated_at,
+        }
+    }
+}
+
+#[derive(SimpleObject)]
+pub struct TableState {
+    pub table_handle: String,
+    pub key_hex: String,
+    pub key_str: String,
+    /// The decoded value of the table item
+    pub value: Json<serde_json::Value>,
+    pub key_type: String,
+    pub value_type: String,
+    pub tx_order: u64,
+    pub state_index: u64,
+    pub created_at: u64,
+    pub updated_at: u64,
+}
+
+impl From<IndexerTableState> for TableState {
+    fn from(state: IndexerTableState) -> Self {
+        Self {
+            table_handle: state.table_handle.to_string(),
+            key_hex: state.key_hex,
+            key_str: state.key_str,
+            value: parse_json(&state.value),
+            key_type: state.key_type.to_canonical_string(),
+            value_type: state.value_type.to_canonical_string(),
+            tx_order: state.tx_order,
+            state_index: state.state_index,
+            created_at: state.created_at,
+            updated_at: state.updated_at,
+        }
+    }
+}
@@ -0,0 +1,11 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    println!("cargo:rerun-if-changed=proto");
+    tonic_build::configure()
+        .build_client(true)
+        .build_server(true)
+        .compile(&["proto/rooch/v1/rooch.proto"], &["proto"])?;
+    Ok(())
+}
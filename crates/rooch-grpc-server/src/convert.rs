@@ -0,0 +1,197 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+//! Conversions between the domain types, the JSON-RPC view types and the protobuf messages.
+
+use crate::proto;
+use move_core_types::account_address::AccountAddress;
+use move_core_types::language_storage::StructTag;
+use moveos_types::h256::H256;
+use moveos_types::state::{AnnotatedState, KeyState, State};
+use rooch_rpc_api::jsonrpc_types::transaction_view::TransactionWithInfoView;
+use rooch_rpc_api::jsonrpc_types::AnnotatedMoveValueView;
+use rooch_types::indexer::event_filter::{EventFilter, IndexerEvent, IndexerEventID};
+use rooch_types::indexer::transaction_filter::TransactionFilter;
+use rooch_types::transaction::TransactionWithInfo;
+use serde::Serialize;
+use std::str::FromStr;
+use tonic::Status;
+
+fn to_json<T: Serialize>(value: &T) -> String {
+    serde_json::to_string(value).expect("Serialize view to json should success")
+}
+
+pub(crate) fn parse_address(address: &str) -> Result<AccountAddress, Status> {
+    AccountAddress::from_str(address)
+        .map_err(|e| Status::invalid_argument(format!("Invalid address {}: {}", address, e)))
+}
+
+pub(crate) fn parse_h256(bytes: &[u8]) -> Result<H256, Status> {
+    if bytes.len() != H256::len_bytes() {
+        return Err(Status::invalid_argument(format!(
+            "Invalid hash length {}, expect {}",
+            bytes.len(),
+            H256::len_bytes()
+        )));
+    }
+    Ok(H256::from_slice(bytes))
+}
+
+pub(crate) fn parse_struct_tag(struct_tag: &str) -> Result<StructTag, Status> {
+    StructTag::from_str(struct_tag)
+        .map_err(|e| Status::invalid_argument(format!("Invalid struct tag {}: {}", struct_tag, e)))
+}
+
+impl From<State> for proto::State {
+    fn from(state: State) -> Self {
+        Self {
+            value: state.value,
+            value_type: state.value_type.to_string(),
+            decoded_value: None,
+        }
+    }
+}
+
+impl From<AnnotatedState> for proto::State {
+    fn from(state: AnnotatedState) -> Self {
+        Self {
+            value: state.state.value,
+            value_type: state.state.value_type.to_string(),
+            decoded_value: Some(to_json(&AnnotatedMoveValueView::from(state.decoded_value))),
+        }
+    }
+}
+
+impl From<KeyState> for proto::KeyState {
+    fn from(key_state: KeyState) -> Self {
+        Self {
+            key: key_state.key,
+            key_type: key_state.key_type.to_string(),
+        }
+    }
+}
+
+impl From<TransactionWithInfo> for proto::Transaction {
+    fn from(tx: TransactionWithInfo) -> Self {
+        let view = TransactionWithInfoView::from(tx);
+        let transaction_type = serde_json::to_value(&view.transaction.transaction_type)
+            .ok()
+            .and_then(|v| v.as_str().map(ToOwned::to_owned))
+            .unwrap_or_default();
+        let action_type = serde_json::to_value(&view.transaction.action_type)
+            .ok()
+            .and_then(|v| v.as_str().map(ToOwned::to_owned))
+            .unwrap_or_default();
+        Self {
+            transaction_type,
+            sequence_number: view.transaction.sequence_number,
+            sender: view.transaction.sender,
+            action_type,
+            action: to_json(&view.transaction.action),
+            raw: view.transaction.raw.map(|raw| raw.0).unwrap_or_default(),
+            sequence_info: Some(proto::TransactionSequenceInfo {
+                tx_order: view.sequence_info.tx_order.0,
+                tx_order_signature: to_json(&view.sequence_info.tx_order_signature),
+                tx_accumulator_root: view.sequence_info.tx_accumulator_root.0.as_bytes().to_vec(),
+            }),
+            execution_info: Some(proto::TransactionExecutionInfo {
+                tx_hash: view.execution_info.tx_hash.0.as_bytes().to_vec(),
+                state_root: view.execution_info.state_root.0.as_bytes().to_vec(),
+                event_root: view.execution_info.event_root.0.as_bytes().to_vec(),
+                gas_used: view.execution_info.gas_used,
+                status: to_json(&view.execution_info.status),
+            }),
+        }
+    }
+}
+
+impl From<IndexerEventID> for proto::IndexerEventId {
+    fn from(id: IndexerEventID) -> Self {
+        Self {
+            tx_order: id.tx_order,
+            event_index: id.event_index,
+        }
+    }
+}
+
+impl From<proto::IndexerEventId> for IndexerEventID {
+    fn from(id: proto::IndexerEventId) -> Self {
+        IndexerEventID::new(id.tx_order, id.event_index)
+    }
+}
+
+impl From<IndexerEvent> for proto::Event {
+    fn from(event: IndexerEvent) -> Self {
+        Self {
+            indexer_event_id: Some(event.indexer_event_id.into()),
+            event_id: Some(proto::EventId {
+                event_handle_id: AccountAddress::from(event.event_id.event_handle_id).to_vec(),
+                event_seq: event.event_id.event_seq,
+            }),
+            event_type: event.event_type.to_string(),
+            event_data: event.event_data,
+            tx_hash: event.tx_hash.as_bytes().to_vec(),
+            sender: event.sender.to_hex_literal(),
+            created_at: event.created_at,
+        }
+    }
+}
+
+impl TryFrom<proto::TransactionFilter> for TransactionFilter {
+    type Error = Status;
+
+    fn try_from(filter: proto::TransactionFilter) -> Result<Self, Self::Error> {
+        use proto::transaction_filter::Filter;
+        let filter = filter
+            .filter
+            .ok_or_else(|| Status::invalid_argument("Missing transaction filter"))?;
+        Ok(match filter {
+            Filter::Sender(sender) => TransactionFilter::Sender(parse_address(&sender)?),
+            Filter::OriginalAddress(address) => TransactionFilter::OriginalAddress(address),
+            Filter::TxHashes(tx_hashes) => TransactionFilter::TxHashes(
+                tx_hashes
+                    .tx_hashes
+                    .iter()
+                    .map(|tx_hash| parse_h256(tx_hash))
+                    .collect::<Result<Vec<_>, _>>()?,
+            ),
+            Filter::TimeRange(range) => TransactionFilter::TimeRange {
+                start_time: range.start_time,
+                end_time: range.end_time,
+            },
+            Filter::TxOrderRange(range) => TransactionFilter::TxOrderRange {
+                from_order: range.from_order,
+                to_order: range.to_order,
+            },
+        })
+    }
+}
+
+impl TryFrom<proto::EventFilter> for EventFilter {
+    type Error = Status;
+
+    fn try_from(filter: proto::EventFilter) -> Result<Self, Self::Error> {
+        use proto::event_filter::Filter;
+        let filter = filter
+            .filter
+            .ok_or_else(|| Status::invalid_argument("Missing event filter"))?;
+        Ok(match filter {
+            Filter::EventType(event_type) => EventFilter::EventType(parse_struct_tag(&event_type)?),
+            Filter::Sender(sender) => EventFilter::Sender(parse_address(&sender)?),
+            Filter::TxHash(tx_hash) => EventFilter::TxHash(parse_h256(&tx_hash)?),
+            Filter::TimeRange(range) => EventFilter::TimeRange {
+                start_time: range.start_time,
+                end_time: range.end_time,
+            },
+            Filter::TxOrderRange(range) => EventFilter::TxOrderRange {
+                from_order: range.from_order,
+                to_order: range.to_order,
+            },
+            Filter::TxEventIndexRange(range) => EventFilter::TxEventIndexRange {
+                tx_hash: parse_h256(&range.tx_hash)?,
+                from_index: range.from_index,
+                to_index: range.to_index,
+            },
+        })
+    }
+}
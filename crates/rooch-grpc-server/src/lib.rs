@@ -0,0 +1,60 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::proto::rooch_service_server::RoochServiceServer;
+use crate::service::RoochGrpcService;
+use anyhow::Result;
+use rooch_executor::proxy::ExecutorProxy;
+use rooch_indexer::proxy::IndexerProxy;
+use std::net::SocketAddr;
+use tokio::sync::oneshot;
+use tokio::task::JoinHandle;
+use tracing::{error, info};
+
+mod convert;
+pub mod service;
+#[cfg(test)]
+mod tests;
+
+pub mod proto {
+    tonic::include_proto!("rooch.v1");
+}
+
+pub struct GrpcServerHandle {
+    shutdown_tx: oneshot::Sender<()>,
+    join_handle: JoinHandle<()>,
+}
+
+impl GrpcServerHandle {
+    pub fn stop(self) -> Result<()> {
+        // The server may have exited already, ignore the error.
+        let _ = self.shutdown_tx.send(());
+        self.join_handle.abort();
+        Ok(())
+    }
+}
+
+// Start gRPC server
+pub async fn start_grpc_server(
+    addr: SocketAddr,
+    executor: ExecutorProxy,
+    indexer: IndexerProxy,
+) -> Result<GrpcServerHandle> {
+    let service = RoochGrpcService::new(executor, indexer);
+    let (shutdown_tx, shutdown_rx) = oneshot::channel::<()>();
+    let server = tonic::transport::Server::builder()
+        .add_service(RoochServiceServer::new(service))
+        .serve_with_shutdown(addr, async {
+            shutdown_rx.await.ok();
+        });
+    let join_handle = tokio::spawn(async move {
+        if let Err(e) = server.await {
+            error!("gRPC server exit with error: {:?}", e);
+        }
+    });
+    info!("gRPC Server start listening {:?}", addr);
+    Ok(GrpcServerHandle {
+        shutdown_tx,
+        join_handle,
+    })
+}
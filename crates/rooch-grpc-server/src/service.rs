@@ -0,0 +1,321 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::convert::parse_struct_tag;
+use crate::proto::rooch_service_server::RoochService;
+use crate::proto::{
+    Event, GetStatesRequest, GetStatesResponse, ListStatesRequest, ListStatesResponse,
+    OptionalState, QueryEventsRequest, QueryEventsResponse, QueryTransactionsRequest,
+    QueryTransactionsResponse, StateKv, StreamEventsRequest, StreamTransactionsRequest,
+    Transaction,
+};
+use moveos_types::access_path::AccessPath;
+use moveos_types::state::KeyState;
+use rooch_executor::proxy::ExecutorProxy;
+use rooch_indexer::proxy::IndexerProxy;
+use rooch_rpc_api::api::{DEFAULT_RESULT_LIMIT_USIZE, MAX_RESULT_LIMIT_USIZE};
+use rooch_types::indexer::event_filter::{EventFilter, IndexerEventID};
+use rooch_types::indexer::transaction_filter::TransactionFilter;
+use std::cmp::min;
+use std::pin::Pin;
+use std::str::FromStr;
+use std::time::Duration;
+use tokio::sync::mpsc;
+use tokio_stream::wrappers::ReceiverStream;
+use tokio_stream::Stream;
+use tonic::{Request, Response, Status};
+
+/// The number of items fetched from the indexer in one round of the stream.
+const STREAM_BATCH_SIZE: usize = 100;
+/// The interval to poll the indexer when the stream catches up with the latest data.
+const STREAM_POLL_INTERVAL: Duration = Duration::from_secs(1);
+/// The order range filter which matches all the transactions, the indexer stores the order as i64.
+const ALL_TX_ORDER_RANGE: (u64, u64) = (0, i64::MAX as u64);
+
+pub type ResponseStream<T> = Pin<Box<dyn Stream<Item = Result<T, Status>> + Send>>;
+
+#[derive(Clone)]
+pub struct RoochGrpcService {
+    executor: ExecutorProxy,
+    indexer: IndexerProxy,
+}
+
+impl RoochGrpcService {
+    pub fn new(executor: ExecutorProxy, indexer: IndexerProxy) -> Self {
+        Self { executor, indexer }
+    }
+}
+
+fn internal_error(e: anyhow::Error) -> Status {
+    Status::internal(e.to_string())
+}
+
+fn limit_of(limit: Option<u64>) -> usize {
+    min(
+        limit
+            .map(|limit| limit as usize)
+            .unwrap_or(DEFAULT_RESULT_LIMIT_USIZE),
+        MAX_RESULT_LIMIT_USIZE,
+    )
+}
+
+fn parse_access_path(access_path: &str) -> Result<AccessPath, Status> {
+    AccessPath::from_str(access_path).map_err(|e| {
+        Status::invalid_argument(format!("Invalid access path {}: {}", access_path, e))
+    })
+}
+
+#[tonic::async_trait]
+impl RoochService for RoochGrpcService {
+    async fn get_states(
+        &self,
+        request: Request<GetStatesRequest>,
+    ) -> Result<Response<GetStatesResponse>, Status> {
+        let request = request.into_inner();
+        let access_path = parse_access_path(&request.access_path)?;
+        let states = if request.decode {
+            self.executor
+                .get_annotated_states(None, access_path)
+                .await
+                .map_err(internal_error)?
+                .into_iter()
+                .map(|state| OptionalState {
+                    state: state.map(Into::into),
+                })
+                .collect()
+        } else {
+            self.executor
+                .get_states(None, access_path)
+                .await
+                .map_err(internal_error)?
+                .into_iter()
+                .map(|state| OptionalState {
+                    state: state.map(Into::into),
+                })
+                .collect()
+        };
+        Ok(Response::new(GetStatesResponse { states }))
+    }
+
+    async fn list_states(
+        &self,
+        request: Request<ListStatesRequest>,
+    ) -> Result<Response<ListStatesResponse>, Status> {
+        let request = request.into_inner();
+        let access_path = parse_access_path(&request.access_path)?;
+        let limit_of = limit_of(request.limit);
+        let cursor = request
+            .cursor
+            .as_deref()
+            .map(KeyState::from_str)
+            .transpose()
+            .map_err(|e| Status::invalid_argument(format!("Invalid cursor: {}", e)))?;
+
+        let mut data: Vec<(KeyState, StateKv)> = if request.decode {
+            self.executor
+                .list_annotated_states(None, access_path, cursor, limit_of + 1)
+                .await
+                .map_err(internal_error)?
+                .into_iter()
+                .map(|(key_state, state)| {
+                    (
+                        key_state.state.clone(),
+                        StateKv {
+                            key_state: Some(key_state.state.into()),
+                            state: Some(state.into()),
+                        },
+                    )
+                })
+                .collect()
+        } else {
+            self.executor
+                .list_states(None, access_path, cursor, limit_of + 1)
+                .await
+                .map_err(internal_error)?
+                .into_iter()
+                .map(|(key_state, state)| {
+                    (
+                        key_state.clone(),
+                        StateKv {
+                            key_state: Some(key_state.into()),
+                            state: Some(state.into()),
+                        },
+                    )
+                })
+                .collect()
+        };
+
+        let has_next_page = data.len() > limit_of;
+        data.truncate(limit_of);
+        let next_cursor = data
+            .last()
+            .map_or(request.cursor, |(key_state, _)| Some(key_state.to_string()));
+
+        Ok(Response::new(ListStatesResponse {
+            data: data.into_iter().map(|(_, state_kv)| state_kv).collect(),
+            next_cursor,
+            has_next_page,
+        }))
+    }
+
+    async fn query_transactions(
+        &self,
+        request: Request<QueryTransactionsRequest>,
+    ) -> Result<Response<QueryTransactionsResponse>, Status> {
+        let request = request.into_inner();
+        let filter: TransactionFilter = request
+            .filter
+            .ok_or_else(|| Status::invalid_argument("Missing transaction filter"))?
+            .try_into()?;
+        let limit_of = limit_of(request.limit);
+
+        let mut data = self
+            .indexer
+            .query_transactions(
+                filter,
+                request.cursor,
+                limit_of + 1,
+                request.descending_order,
+            )
+            .await
+            .map_err(internal_error)?;
+
+        let has_next_page = data.len() > limit_of;
+        data.truncate(limit_of);
+        let next_cursor = data
+            .last()
+            .map_or(request.cursor, |tx| Some(tx.sequence_info.tx_order));
+
+        Ok(Response::new(QueryTransactionsResponse {
+            data: data.into_iter().map(Transaction::from).collect(),
+            next_cursor,
+            has_next_page,
+        }))
+    }
+
+    async fn query_events(
+        &self,
+        request: Request<QueryEventsRequest>,
+    ) -> Result<Response<QueryEventsResponse>, Status> {
+        let request = request.into_inner();
+        let filter: EventFilter = request
+            .filter
+            .ok_or_else(|| Status::invalid_argument("Missing event filter"))?
+            .try_into()?;
+        let limit_of = limit_of(request.limit);
+        let cursor = request.cursor.map(IndexerEventID::from);
+
+        let mut data = self
+            .indexer
+            .query_events(filter, cursor, limit_of + 1, request.descending_order)
+            .await
+            .map_err(internal_error)?;
+
+        let has_next_page = data.len() > limit_of;
+        data.truncate(limit_of);
+        let next_cursor = data
+            .last()
+            .map_or(cursor, |event| Some(event.indexer_event_id));
+
+        Ok(Response::new(QueryEventsResponse {
+            data: data.into_iter().map(Event::from).collect(),
+            next_cursor: next_cursor.map(Into::into),
+            has_next_page,
+        }))
+    }
+
+    type StreamTransactionsStream = ResponseStream<Transaction>;
+
+    async fn stream_transactions(
+        &self,
+        request: Request<StreamTransactionsRequest>,
+    ) -> Result<Response<Self::StreamTransactionsStream>, Status> {
+        let mut cursor = request.into_inner().cursor;
+        let indexer = self.indexer.clone();
+        let (tx, rx) = mpsc::channel(STREAM_BATCH_SIZE);
+        tokio::spawn(async move {
+            loop {
+                let filter = TransactionFilter::TxOrderRange {
+                    from_order: ALL_TX_ORDER_RANGE.0,
+                    to_order: ALL_TX_ORDER_RANGE.1,
+                };
+                let data = match indexer
+                    .query_transactions(filter, cursor, STREAM_BATCH_SIZE, false)
+                    .await
+                {
+                    Ok(data) => data,
+                    Err(e) => {
+                        let _ = tx.send(Err(internal_error(e))).await;
+                        return;
+                    }
+                };
+                if data.is_empty() {
+                    tokio::time::sleep(STREAM_POLL_INTERVAL).await;
+                    continue;
+                }
+                for transaction in data {
+                    cursor = Some(transaction.sequence_info.tx_order);
+                    if tx.send(Ok(transaction.into())).await.is_err() {
+                        // The client has gone
+                        return;
+                    }
+                }
+            }
+        });
+        Ok(Response::new(
+            Box::pin(ReceiverStream::new(rx)) as Self::StreamTransactionsStream
+        ))
+    }
+
+    type StreamEventsStream = ResponseStream<Event>;
+
+    async fn stream_events(
+        &self,
+        request: Request<StreamEventsRequest>,
+    ) -> Result<Response<Self::StreamEventsStream>, Status> {
+        let request = request.into_inner();
+        let event_type = request
+            .event_type
+            .as_deref()
+            .map(parse_struct_tag)
+            .transpose()?;
+        let mut cursor = request.cursor.map(IndexerEventID::from);
+        let indexer = self.indexer.clone();
+        let (tx, rx) = mpsc::channel(STREAM_BATCH_SIZE);
+        tokio::spawn(async move {
+            loop {
+                let filter = match &event_type {
+                    Some(event_type) => EventFilter::EventType(event_type.clone()),
+                    None => EventFilter::TxOrderRange {
+                        from_order: ALL_TX_ORDER_RANGE.0,
+                        to_order: ALL_TX_ORDER_RANGE.1,
+                    },
+                };
+                let data = match indexer
+                    .query_events(filter, cursor, STREAM_BATCH_SIZE, false)
+                    .await
+                {
+                    Ok(data) => data,
+                    Err(e) => {
+                        let _ = tx.send(Err(internal_error(e))).await;
+                        return;
+                    }
+                };
+                if data.is_empty() {
+                    tokio::time::sleep(STREAM_POLL_INTERVAL).await;
+                    continue;
+                }
+                for event in data {
+                    cursor = Some(event.indexer_event_id);
+                    if tx.send(Ok(event.into())).await.is_err() {
+                        // The client has gone
+                        return;
+                    }
+                }
+            }
+        });
+        Ok(Response::new(
+            Box::pin(ReceiverStream::new(rx)) as Self::StreamEventsStream
+        ))
+    }
+}
@@ -0,0 +1,316 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::proto::rooch_service_client::RoochServiceClient;
+use crate::proto::{
+    event_filter, transaction_filter, EventFilter, GetStatesRequest, QueryEventsRequest,
+    QueryTransactionsRequest, StreamTransactionsRequest, TransactionFilter, TxOrderRange,
+};
+use crate::{start_grpc_server, GrpcServerHandle};
+use anyhow::Result;
+use coerce::actor::system::ActorSystem;
+use coerce::actor::IntoActor;
+use move_core_types::account_address::AccountAddress;
+use move_core_types::vm_status::KeptVMStatus;
+use moveos_config::DataDirPath;
+use moveos_store::MoveOSStore;
+use moveos_types::h256::H256;
+use moveos_types::moveos_std::tx_context::TxContext;
+use moveos_types::transaction::{TransactionExecutionInfo, VerifiedMoveOSTransaction};
+use rooch_config::indexer_config::ROOCH_INDEXER_DB_FILENAME;
+use rooch_executor::actor::executor::ExecutorActor;
+use rooch_executor::actor::reader_executor::ReaderExecutorActor;
+use rooch_executor::proxy::ExecutorProxy;
+use rooch_framework::natives::default_gas_schedule;
+use rooch_indexer::actor::indexer::IndexerActor;
+use rooch_indexer::actor::reader_indexer::IndexerReaderActor;
+use rooch_indexer::indexer_reader::IndexerReader;
+use rooch_indexer::proxy::IndexerProxy;
+use rooch_indexer::store::traits::IndexerStoreTrait;
+use rooch_indexer::types::{IndexedEvent, IndexedTransaction};
+use rooch_indexer::IndexerStore;
+use rooch_store::RoochStore;
+use rooch_types::address::{RoochAddress, RoochSupportedAddress};
+use rooch_types::bitcoin::genesis::BitcoinGenesisContext;
+use rooch_types::bitcoin::network::Network;
+use rooch_types::chain_id::RoochChainID;
+use rooch_types::test_utils::{
+    random_bytes, random_event, random_function_calls, random_typed_transaction,
+    random_verified_move_action,
+};
+use rooch_types::transaction::authenticator::Authenticator;
+use rooch_types::transaction::TransactionSequenceInfo;
+use std::net::{SocketAddr, TcpListener};
+use std::time::Duration;
+use tokio_stream::StreamExt;
+use tonic::transport::Channel;
+use tonic::Code;
+
+const ALL_TX_ORDER_RANGE: TxOrderRange = TxOrderRange {
+    from_order: 0,
+    to_order: i64::MAX as u64,
+};
+
+/// Index the transactions with the tx order `1..=tx_count` with one event each,
+/// the tx hashes are returned in the tx order.
+fn seed_indexer(indexer_store: &IndexerStore, tx_count: u64) -> Result<Vec<H256>> {
+    let mut tx_hashes = vec![];
+    for tx_order in 1..=tx_count {
+        let transaction = random_typed_transaction();
+        let sequence_info = TransactionSequenceInfo::new(
+            tx_order,
+            Authenticator::new(rand::random(), random_bytes()),
+            H256::random(),
+        );
+        let execution_info = TransactionExecutionInfo::new(
+            transaction.tx_hash(),
+            H256::random(),
+            H256::random(),
+            rand::random(),
+            KeptVMStatus::Executed,
+        );
+        let moveos_tx = VerifiedMoveOSTransaction {
+            ctx: TxContext::new_readonly_ctx(AccountAddress::random()),
+            action: random_verified_move_action(),
+            pre_execute_functions: random_function_calls(),
+            post_execute_functions: random_function_calls(),
+        };
+        let mut event = random_event();
+        event.event_index = 0;
+        let indexed_event = IndexedEvent::new(
+            event,
+            transaction.clone(),
+            sequence_info.clone(),
+            moveos_tx.clone(),
+        );
+        tx_hashes.push(transaction.tx_hash());
+        indexer_store.persist_transactions(vec![IndexedTransaction::new(
+            transaction,
+            sequence_info,
+            execution_info,
+            moveos_tx,
+        )?])?;
+        indexer_store.persist_events(vec![indexed_event])?;
+    }
+    Ok(tx_hashes)
+}
+
+/// Start the gRPC server over the genesis state of the mock stores and the seeded indexer,
+/// and connect the client to it.
+async fn start_mock_service(
+    tmpdir: &DataDirPath,
+    tx_count: u64,
+) -> Result<(GrpcServerHandle, RoochServiceClient<Channel>, Vec<H256>)> {
+    let actor_system = ActorSystem::new();
+    let moveos_store = MoveOSStore::mock_moveos_store()?;
+    let rooch_store = RoochStore::mock_rooch_store()?;
+    let gas_schedule_blob = bcs::to_bytes(&default_gas_schedule())?;
+    let executor_actor = ExecutorActor::new(
+        RoochChainID::LOCAL.genesis_ctx(RoochAddress::random(), gas_schedule_blob),
+        BitcoinGenesisContext::new(Network::default().to_num()),
+        moveos_store.clone(),
+        rooch_store.clone(),
+    )?;
+    let reader_executor = ReaderExecutorActor::new(
+        executor_actor.genesis().clone(),
+        moveos_store.clone(),
+        rooch_store,
+    )?
+    .into_actor(Some("ReaderExecutor"), &actor_system)
+    .await?;
+    let executor = executor_actor
+        .into_actor(Some("Executor"), &actor_system)
+        .await?;
+    let executor_proxy = ExecutorProxy::new(executor.into(), reader_executor.into());
+
+    let indexer_db = tmpdir.path().join(ROOCH_INDEXER_DB_FILENAME);
+    std::fs::File::create(indexer_db.clone())?;
+    let indexer_db_url = indexer_db
+        .as_path()
+        .to_str()
+        .ok_or(anyhow::anyhow!("Invalid mock indexer db dir"))?;
+    let indexer_store = IndexerStore::new(indexer_db_url)?;
+    indexer_store.create_all_tables_if_not_exists()?;
+    let tx_hashes = seed_indexer(&indexer_store, tx_count)?;
+    let indexer_executor = IndexerActor::new(indexer_store, moveos_store)?
+        .into_actor(Some("Indexer"), &actor_system)
+        .await?;
+    let indexer_reader_executor = IndexerReaderActor::new(IndexerReader::new(indexer_db_url)?)?
+        .into_actor(Some("IndexerReader"), &actor_system)
+        .await?;
+    let indexer_proxy = IndexerProxy::new(indexer_executor.into(), indexer_reader_executor.into());
+
+    let addr: SocketAddr = TcpListener::bind("127.0.0.1:0")?.local_addr()?;
+    let handle = start_grpc_server(addr, executor_proxy, indexer_proxy).await?;
+    // The server binds the address in the background, retry until it is listening
+    let mut retries = 0;
+    let client = loop {
+        match RoochServiceClient::connect(format!("http://{}", addr)).await {
+            Ok(client) => break client,
+            Err(e) if retries >= 50 => return Err(e.into()),
+            Err(_) => {
+                retries += 1;
+                tokio::time::sleep(Duration::from_millis(100)).await;
+            }
+        }
+    };
+    Ok((handle, client, tx_hashes))
+}
+
+#[tokio::test]
+async fn test_get_states() -> Result<()> {
+    let tmpdir = moveos_config::temp_dir();
+    let (handle, mut client, _tx_hashes) = start_mock_service(&tmpdir, 0).await?;
+
+    // The module published by the genesis
+    let states = client
+        .get_states(GetStatesRequest {
+            access_path: "/module/0x1/string".to_owned(),
+            decode: false,
+        })
+        .await?
+        .into_inner()
+        .states;
+    assert_eq!(states.len(), 1);
+    assert!(states[0].state.is_some());
+
+    let access_path = format!(
+        "/resource/{}/0x3::account::Account",
+        AccountAddress::random().to_hex_literal()
+    );
+    let states = client
+        .get_states(GetStatesRequest {
+            access_path,
+            decode: true,
+        })
+        .await?
+        .into_inner()
+        .states;
+    assert_eq!(states.len(), 1);
+    assert!(states[0].state.is_none());
+
+    let status = client
+        .get_states(GetStatesRequest {
+            access_path: "/unknown".to_owned(),
+            decode: false,
+        })
+        .await
+        .unwrap_err();
+    assert_eq!(status.code(), Code::InvalidArgument);
+
+    handle.stop()?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_query_transactions_and_events() -> Result<()> {
+    let tmpdir = moveos_config::temp_dir();
+    let (handle, mut client, tx_hashes) = start_mock_service(&tmpdir, 3).await?;
+
+    let request = |cursor| QueryTransactionsRequest {
+        filter: Some(TransactionFilter {
+            filter: Some(transaction_filter::Filter::TxOrderRange(ALL_TX_ORDER_RANGE)),
+        }),
+        cursor,
+        limit: Some(2),
+        descending_order: false,
+    };
+    let page = client.query_transactions(request(None)).await?.into_inner();
+    let tx_orders: Vec<u64> = page
+        .data
+        .iter()
+        .map(|tx| tx.sequence_info.as_ref().unwrap().tx_order)
+        .collect();
+    assert_eq!(tx_orders, vec![1, 2]);
+    assert_eq!(
+        page.data[0].execution_info.as_ref().unwrap().tx_hash,
+        tx_hashes[0].as_bytes().to_vec()
+    );
+    assert_eq!(page.next_cursor, Some(2));
+    assert!(page.has_next_page);
+
+    let page = client
+        .query_transactions(request(page.next_cursor))
+        .await?
+        .into_inner();
+    assert_eq!(page.data.len(), 1);
+    assert_eq!(page.data[0].sequence_info.as_ref().unwrap().tx_order, 3);
+    assert_eq!(page.next_cursor, Some(3));
+    assert!(!page.has_next_page);
+
+    let page = client
+        .query_events(QueryEventsRequest {
+            filter: Some(EventFilter {
+                filter: Some(event_filter::Filter::TxHash(
+                    tx_hashes[1].as_bytes().to_vec(),
+                )),
+            }),
+            cursor: None,
+            limit: None,
+            descending_order: false,
+        })
+        .await?
+        .into_inner();
+    assert_eq!(page.data.len(), 1);
+    let event_id = page.data[0].indexer_event_id.clone().unwrap();
+    assert_eq!((event_id.tx_order, event_id.event_index), (2, 0));
+    assert_eq!(page.data[0].tx_hash, tx_hashes[1].as_bytes().to_vec());
+    assert!(!page.has_next_page);
+
+    // The filter is required, and the hash must be 32 bytes
+    let status = client
+        .query_events(QueryEventsRequest {
+            filter: None,
+            cursor: None,
+            limit: None,
+            descending_order: false,
+        })
+        .await
+        .unwrap_err();
+    assert_eq!(status.code(), Code::InvalidArgument);
+    let status = client
+        .query_events(QueryEventsRequest {
+            filter: Some(EventFilter {
+                filter: Some(event_filter::Filter::TxHash(vec![0u8; 4])),
+            }),
+            cursor: None,
+            limit: None,
+            descending_order: false,
+        })
+        .await
+        .unwrap_err();
+    assert_eq!(status.code(), Code::InvalidArgument);
+
+    handle.stop()?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_stream_transactions() -> Result<()> {
+    let tmpdir = moveos_config::temp_dir();
+    let (handle, mut client, tx_hashes) = start_mock_service(&tmpdir, 3).await?;
+
+    // Start after the first transaction, the stream keeps polling the indexer after the last one
+    let mut stream = client
+        .stream_transactions(StreamTransactionsRequest { cursor: Some(1) })
+        .await?
+        .into_inner();
+    let mut streamed = vec![];
+    for _ in 0..2 {
+        let tx = stream
+            .next()
+            .await
+            .ok_or(anyhow::anyhow!("The stream is closed"))??;
+        streamed.push(tx.execution_info.unwrap().tx_hash);
+    }
+    assert_eq!(
+        streamed,
+        vec![
+            tx_hashes[1].as_bytes().to_vec(),
+            tx_hashes[2].as_bytes().to_vec()
+        ]
+    );
+
+    handle.stop()?;
+    Ok(())
+}
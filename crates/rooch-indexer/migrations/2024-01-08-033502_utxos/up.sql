@@ -0,0 +1,18 @@
+CREATE TABLE utxos
+(
+    object_id          VARCHAR        NOT NULL       PRIMARY KEY,
+    owner              VARCHAR        NOT NULL,
+    flag               SMALLINT       NOT NULL,
+    txid               VARCHAR        NOT NULL,
+    vout               BIGINT         NOT NULL,
+    value              BIGINT         NOT NULL,
+    seals              VARCHAR        NOT NULL,
+    tx_order           BIGINT         NOT NULL,
+    state_index        BIGINT         NOT NULL,
+    created_at         BIGINT         NOT NULL,
+    updated_at         BIGINT         NOT NULL,
+    UNIQUE (tx_order, state_index)
+);
+
+CREATE INDEX idx_utxos_owner ON utxos (owner);
+CREATE UNIQUE INDEX idx_utxos_txid_and_vout ON utxos (txid, vout);
@@ -0,0 +1,2 @@
+DROP TABLE IF EXISTS transactions_fts;
+DROP TABLE IF EXISTS events_fts;
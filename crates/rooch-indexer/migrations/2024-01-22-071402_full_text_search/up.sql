@@ -0,0 +1,19 @@
+-- Full-text search index over the decoded event data and the transaction action,
+-- the rows are written by the indexer together with the events and the transactions,
+-- because the event data and the action arguments are stored in bcs.
+CREATE VIRTUAL TABLE events_fts USING fts5
+(
+    event_type,
+    event_data,
+    tx_order    UNINDEXED,
+    event_index UNINDEXED
+);
+
+CREATE VIRTUAL TABLE transactions_fts USING fts5
+(
+    action,
+    tx_order    UNINDEXED
+);
+
+-- Index the action name of the existing transactions
+INSERT INTO transactions_fts (action, tx_order) SELECT action, tx_order FROM transactions;
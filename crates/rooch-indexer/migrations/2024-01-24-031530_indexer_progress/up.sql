@@ -0,0 +1,6 @@
+CREATE TABLE indexer_progress
+(
+    table_name         VARCHAR        NOT NULL       PRIMARY KEY,
+    last_indexed_order BIGINT         NOT NULL,
+    updated_at         BIGINT         NOT NULL
+);
@@ -0,0 +1 @@
+DROP TABLE IF EXISTS coin_infos;
\ No newline at end of file
@@ -0,0 +1,10 @@
+CREATE TABLE coin_infos
+(
+    coin_type          VARCHAR        NOT NULL       PRIMARY KEY,
+    name               VARCHAR        NOT NULL,
+    symbol             VARCHAR        NOT NULL,
+    icon_url           VARCHAR,
+    decimals           SMALLINT       NOT NULL,
+    supply             VARCHAR        NOT NULL,
+    tx_order           BIGINT         NOT NULL
+);
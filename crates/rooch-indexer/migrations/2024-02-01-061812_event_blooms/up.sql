@@ -0,0 +1,10 @@
+-- The bloom filter of the event types and the event handle ids emitted by a transaction,
+-- the event type queries skip the transactions whose bloom does not contain the event type.
+-- The bloom is NULL for the transactions indexed before the blooms, they are always scanned.
+CREATE TABLE event_blooms
+(
+    tx_order           BIGINT         NOT NULL       PRIMARY KEY,
+    bloom              BLOB
+);
+
+INSERT INTO event_blooms (tx_order, bloom) SELECT DISTINCT tx_order, NULL FROM events;
@@ -0,0 +1,16 @@
+-- The transaction statistics of the accounts, updated incrementally when the transactions are indexed.
+-- The activity timestamps are the seconds when the transactions are indexed,
+-- they are NULL for the accounts whose transactions are indexed before the statistics.
+CREATE TABLE account_stats
+(
+    address            VARCHAR        NOT NULL       PRIMARY KEY,
+    tx_count           BIGINT         NOT NULL,
+    gas_used           BIGINT         NOT NULL,
+    first_tx_order     BIGINT         NOT NULL,
+    last_tx_order      BIGINT         NOT NULL,
+    first_active_at    BIGINT,
+    last_active_at     BIGINT
+);
+
+INSERT INTO account_stats (address, tx_count, gas_used, first_tx_order, last_tx_order, first_active_at, last_active_at)
+SELECT sender, COUNT(*), SUM(gas_used), MIN(tx_order), MAX(tx_order), NULL, NULL FROM transactions GROUP BY sender;
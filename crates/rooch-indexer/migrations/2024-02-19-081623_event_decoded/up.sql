@@ -0,0 +1,4 @@
+-- The decoded event data in json format, written by the indexer when the event is indexed,
+-- so the RPC does not need to decode the event data for each request.
+-- It is NULL for the events indexed before the column, or the event data can not be decoded.
+ALTER TABLE events ADD COLUMN event_decoded TEXT;
@@ -0,0 +1,18 @@
+-- The owner transitions of the objects, recorded when the global states are indexed.
+-- The old_owner is NULL when the object is created, the new_owner is NULL when the object is removed.
+-- The created_at is the seconds when the change is indexed, it is NULL for the changes recorded by this migration.
+CREATE TABLE object_ownership_changes
+(
+    object_id          VARCHAR        NOT NULL,
+    tx_order           BIGINT         NOT NULL,
+    old_owner          VARCHAR,
+    new_owner          VARCHAR,
+    created_at         BIGINT,
+    PRIMARY KEY (object_id, tx_order)
+);
+
+CREATE INDEX idx_object_ownership_changes_new_owner ON object_ownership_changes (new_owner);
+
+-- The objects indexed before the ownership history, their current owners are recorded as the initial owners.
+INSERT INTO object_ownership_changes (object_id, tx_order, old_owner, new_owner, created_at)
+SELECT object_id, tx_order, NULL, owner, NULL FROM global_states;
@@ -0,0 +1,15 @@
+-- The mappings from the multichain addresses to the Rooch addresses, indexed from the address mapping of the framework.
+-- The multichain address is stored as the multichain id and the hex of the raw address, so it does not depend on the Bitcoin network.
+-- The created_at is the seconds when the mapping is indexed.
+-- The mappings bound before this migration are indexed when the indexer is rebuilt.
+CREATE TABLE address_mappings
+(
+    multichain_id      BIGINT         NOT NULL,
+    raw_address        VARCHAR        NOT NULL,
+    rooch_address      VARCHAR        NOT NULL,
+    tx_order           BIGINT         NOT NULL,
+    created_at         BIGINT         NOT NULL,
+    PRIMARY KEY (multichain_id, raw_address)
+);
+
+CREATE INDEX idx_address_mappings_rooch_address ON address_mappings (rooch_address);
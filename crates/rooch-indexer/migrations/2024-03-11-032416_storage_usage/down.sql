@@ -0,0 +1,2 @@
+ALTER TABLE table_states DROP COLUMN state_size;
+ALTER TABLE global_states DROP COLUMN state_size;
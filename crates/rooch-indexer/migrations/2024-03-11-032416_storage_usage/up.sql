@@ -0,0 +1,5 @@
+-- The bytes of the state in the state tree, the length of the key plus the length of the value,
+-- it is used to account the storage usage of the accounts.
+-- It is 0 for the states indexed before the column, they are accounted when the indexer is rebuilt.
+ALTER TABLE global_states ADD COLUMN state_size BIGINT NOT NULL DEFAULT 0;
+ALTER TABLE table_states ADD COLUMN state_size BIGINT NOT NULL DEFAULT 0;
@@ -0,0 +1,20 @@
+-- The Move modules published on chain, indexed from the module tables of the accounts.
+-- The address of a module is also its publisher, a module can only be published under the address of the sender.
+-- The created_at is the seconds when the module is first indexed, the updated_at is the seconds when it is last upgraded.
+-- The source_digest is reserved for the digest of the package source, it is not recorded on chain yet.
+-- The modules published before this migration are indexed when the indexer is rebuilt.
+CREATE TABLE modules
+(
+    address            VARCHAR        NOT NULL,
+    name               VARCHAR        NOT NULL,
+    bytecode_hash      VARCHAR        NOT NULL,
+    source_digest      VARCHAR,
+    tx_order           BIGINT         NOT NULL,
+    state_index        BIGINT         NOT NULL,
+    created_at         BIGINT         NOT NULL,
+    updated_at         BIGINT         NOT NULL,
+    PRIMARY KEY (address, name)
+);
+
+CREATE INDEX idx_modules_tx_order_state_index ON modules (tx_order, state_index);
+CREATE INDEX idx_modules_created_at ON modules (created_at);
@@ -0,0 +1,19 @@
+-- The deposits and withdrawals of the coin stores, indexed from the DepositEvent and WithdrawEvent of the coin_store module.
+-- The coin store of an account is a named object of the account and the coin type, so the transfer history of an account
+-- is queried by the coin store id. The amount is the u256 in decimal string.
+-- The transfers of the events indexed before this migration are indexed when the indexer is rebuilt.
+CREATE TABLE coin_transfers
+(
+    tx_order           BIGINT         NOT NULL,
+    event_index        BIGINT         NOT NULL,
+    coin_store_id      VARCHAR        NOT NULL,
+    coin_type          VARCHAR        NOT NULL,
+    transfer_type      VARCHAR        NOT NULL,
+    amount             VARCHAR        NOT NULL,
+    tx_hash            VARCHAR        NOT NULL,
+    sender             VARCHAR        NOT NULL,
+    created_at         BIGINT         NOT NULL,
+    PRIMARY KEY (tx_order, event_index)
+);
+
+CREATE INDEX idx_coin_transfers_coin_store_id ON coin_transfers (coin_store_id, tx_order, event_index);
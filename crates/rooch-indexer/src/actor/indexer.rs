@@ -2,31 +2,55 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::actor::messages::{
-    IndexerEventsMessage, IndexerStatesMessage, IndexerTransactionMessage,
+    IndexerBatchMessage, IndexerEventsMessage, IndexerRevertMessage, IndexerStatesMessage,
+    IndexerTransactionMessage, IndexerWriteMessage, PruneIndexerTick,
 };
+use crate::metrics::IndexerMetrics;
+use crate::store::batch::IndexerBatch;
 use crate::store::traits::IndexerStoreTrait;
 use crate::types::{
-    IndexedEvent, IndexedGlobalState, IndexedTableChangeSet, IndexedTableState, IndexedTransaction,
+    state_size, IndexedAddressMapping, IndexedCoinInfo, IndexedCoinTransfer, IndexedEvent,
+    IndexedGlobalState, IndexedModule, IndexedTableChangeSet, IndexedTableState,
+    IndexedTransaction, IndexedUTXOState, IndexerProgressTable,
 };
 use crate::utils::format_struct_tag;
 use crate::IndexerStore;
+use crate::LOG_TARGET;
 use anyhow::Result;
 use async_trait::async_trait;
 use coerce::actor::{context::ActorContext, message::Handler, Actor};
 use move_core_types::effects::Op;
 use move_core_types::language_storage::TypeTag;
 use move_resource_viewer::MoveValueAnnotator;
+use moveos_store::state_store::statedb::StateDBStore;
 use moveos_store::MoveOSStore;
+use moveos_types::h256::H256;
 use moveos_types::moveos_std::context;
+use moveos_types::moveos_std::event::Event;
 use moveos_types::moveos_std::object::RawObject;
 use moveos_types::moveos_std::object_id::ObjectID;
-use moveos_types::state::{KeyState, SplitStateChangeSet, State};
-use moveos_types::state_resolver::MoveOSResolverProxy;
+use moveos_types::state::{
+    KeyState, MoveStructType, PlaceholderStruct, SplitStateChangeSet, State, StateChangeSet,
+};
+use moveos_types::state_resolver::{MoveOSResolverProxy, StateResolver};
+use moveos_types::transaction::{TransactionExecutionInfo, VerifiedMoveOSTransaction};
 use rooch_rpc_api::jsonrpc_types::{AnnotatedMoveStructView, AnnotatedMoveValueView};
+use rooch_types::bitcoin::utxo::UTXO;
+use rooch_types::framework::coin::CoinInfo;
+use rooch_types::framework::timestamp::Timestamp;
+use rooch_types::indexer::state::IndexerTableChangeSet;
+use rooch_types::transaction::{TransactionSequenceInfo, TypedTransaction};
+use std::collections::BTreeSet;
+
+/// The page size of listing the states of a removed table when it is restored
+const RESTORE_TABLE_PAGE_SIZE: usize = 1000;
 
 pub struct IndexerActor {
     indexer_store: IndexerStore,
     moveos_store: MoveOSResolverProxy<MoveOSStore>,
+    metrics: Option<IndexerMetrics>,
+    /// The tables the indexer writes, the messages of the other tables are ignored
+    tables: Vec<IndexerProgressTable>,
 }
 
 impl IndexerActor {
@@ -34,9 +58,44 @@ impl IndexerActor {
         Ok(Self {
             indexer_store,
             moveos_store: MoveOSResolverProxy(moveos_store),
+            metrics: None,
+            tables: IndexerProgressTable::ALL.to_vec(),
         })
     }
 
+    /// Only index the given tables, such as the light indexer without the states
+    pub fn with_tables(mut self, tables: Vec<IndexerProgressTable>) -> Self {
+        self.tables = tables;
+        self
+    }
+
+    fn is_indexed(&self, table: IndexerProgressTable) -> bool {
+        self.tables.contains(&table)
+    }
+
+    pub fn with_metrics(mut self, metrics: IndexerMetrics) -> Self {
+        self.metrics = Some(metrics);
+        self
+    }
+
+    fn observe_rows_written(&self, table: &str, op: &str, rows: usize) {
+        if let Some(metrics) = &self.metrics {
+            metrics.observe_rows_written(table, op, rows);
+        }
+    }
+
+    fn observe_executed(&self, tx_order: u64) {
+        if let Some(metrics) = &self.metrics {
+            metrics.observe_executed(tx_order);
+        }
+    }
+
+    fn observe_indexed(&self, progress: IndexerProgressTable, tx_order: u64) {
+        if let Some(metrics) = &self.metrics {
+            metrics.observe_indexed(progress, tx_order);
+        }
+    }
+
     pub fn resolve_raw_object_value_to_json(&self, raw_object: &RawObject) -> Result<String> {
         let obj_value = MoveValueAnnotator::new(&self.moveos_store)
             .view_resource(&raw_object.value.struct_tag, &raw_object.value.value)?;
@@ -58,6 +117,7 @@ impl IndexerActor {
         value: State,
         tx_order: u64,
         state_index: u64,
+        state_size: u64,
     ) -> Result<IndexedGlobalState> {
         let raw_object = value.as_raw_object()?;
         let obj_value_json = self.resolve_raw_object_value_to_json(&raw_object)?;
@@ -69,10 +129,58 @@ impl IndexerActor {
             object_type,
             tx_order,
             state_index,
+            state_size,
         );
         Ok(state)
     }
 
+    /// Build the UTXO state if the object is a Bitcoin UTXO, otherwise return `None`
+    pub fn new_utxo_state_from_raw_object(
+        &self,
+        value: &State,
+        tx_order: u64,
+        state_index: u64,
+        timestamp: u64,
+    ) -> Result<Option<IndexedUTXOState>> {
+        let raw_object = value.as_raw_object()?;
+        if raw_object.value.struct_tag != UTXO::struct_tag() {
+            return Ok(None);
+        }
+        let state =
+            IndexedUTXOState::new_from_raw_object(raw_object, tx_order, state_index, timestamp)?;
+        Ok(Some(state))
+    }
+
+    /// The on chain timestamp in milliseconds after the state changes of the transaction.
+    /// The L1 block transactions update the timestamp and create the UTXOs at once,
+    /// so the timestamp in the change set is preferred to the latest one in the state tree.
+    fn resolve_timestamp(&self, state_change_set: &StateChangeSet) -> Result<u64> {
+        let timestamp_id = Timestamp::object_id();
+        let changed = state_change_set
+            .changes
+            .get(&context::GLOBAL_OBJECT_STORAGE_HANDLE)
+            .and_then(|table_change| table_change.entries.get(&timestamp_id.to_key()));
+        let state = match changed {
+            Some(Op::New(value)) | Some(Op::Modify(value)) => Some(value.clone()),
+            _ => self.moveos_store.resolve_object_state(&timestamp_id)?,
+        };
+        timestamp_from_state(state)
+    }
+
+    /// Build the coin info if the object is a CoinInfo, otherwise return `None`
+    pub fn new_coin_info_from_raw_object(
+        &self,
+        value: &State,
+        tx_order: u64,
+    ) -> Result<Option<IndexedCoinInfo>> {
+        let raw_object = value.as_raw_object()?;
+        if !CoinInfo::<PlaceholderStruct>::is_coin_info(&raw_object.value.struct_tag) {
+            return Ok(None);
+        }
+        let coin_info = IndexedCoinInfo::new_from_raw_object(raw_object, tx_order)?;
+        Ok(Some(coin_info))
+    }
+
     pub fn new_table_state(
         &self,
         key: KeyState,
@@ -81,6 +189,7 @@ impl IndexerActor {
         tx_order: u64,
         state_index: u64,
     ) -> Result<IndexedTableState> {
+        let state_size = state_size(&key, &value);
         let key_hex = key.to_string();
         let key_state_json = self.resolve_state_to_json(&key.key_type, key.key.as_slice())?;
         let state_json = self.resolve_state_to_json(&value.value_type, value.value.as_slice())?;
@@ -93,27 +202,37 @@ impl IndexerActor {
             value.value_type,
             tx_order,
             state_index,
+            state_size,
         );
         Ok(state)
     }
-}
-
-impl Actor for IndexerActor {}
-
-#[async_trait]
-impl Handler<IndexerStatesMessage> for IndexerActor {
-    async fn handle(&mut self, msg: IndexerStatesMessage, _ctx: &mut ActorContext) -> Result<()> {
-        let IndexerStatesMessage {
-            tx_order,
-            state_change_set,
-        } = msg;
 
+    /// Index the state changes of the transaction at `tx_order` into the batch
+    pub fn index_states(
+        &self,
+        batch: &mut IndexerBatch,
+        tx_order: u64,
+        state_change_set: StateChangeSet,
+    ) -> Result<()> {
+        let timestamp = self.resolve_timestamp(&state_change_set)?;
         // indexer state index generator
         let mut state_index_generator = 0u64;
         let mut new_global_states = vec![];
         let mut update_global_states = vec![];
         let mut remove_global_states = vec![];
 
+        let mut new_utxos = vec![];
+        let mut remove_utxos = vec![];
+
+        let mut coin_infos = vec![];
+
+        // The multichain address mappings of the framework address mapping table
+        let mut address_mappings = vec![];
+        let mut remove_address_mappings = vec![];
+
+        // The published modules of the module tables of the accounts, the modules can not be removed
+        let mut modules = vec![];
+
         let mut new_table_states = vec![];
         let mut update_table_states = vec![];
         let mut remove_table_states = vec![];
@@ -129,14 +248,29 @@ impl Handler<IndexerStatesMessage> for IndexerActor {
                     match op {
                         Op::Modify(value) => {
                             if value.is_object() {
+                                if let Some(utxo) = self.new_utxo_state_from_raw_object(
+                                    &value,
+                                    tx_order,
+                                    state_index_generator,
+                                    timestamp,
+                                )? {
+                                    new_utxos.push(utxo);
+                                }
+                                if let Some(coin_info) =
+                                    self.new_coin_info_from_raw_object(&value, tx_order)?
+                                {
+                                    coin_infos.push(coin_info);
+                                }
+                                let state_size = state_size(&key, &value);
                                 let state = self.new_global_state_from_raw_object(
                                     value,
                                     tx_order,
                                     state_index_generator,
+                                    state_size,
                                 )?;
                                 update_global_states.push(state);
                             } else {
-                                log::warn!(
+                                tracing::warn!(target: LOG_TARGET,
                                     "Unexpected state type for op modify, table handle {:?}, value {:?}",
                                     table_handle,
                                     value
@@ -146,17 +280,33 @@ impl Handler<IndexerStatesMessage> for IndexerActor {
                         Op::Delete => {
                             let table_handle = ObjectID::from_bytes(key.key.as_slice())?;
                             remove_global_states.push(table_handle.to_string());
+                            remove_utxos.push(table_handle.to_string());
                         }
                         Op::New(value) => {
                             if value.is_object() {
+                                if let Some(utxo) = self.new_utxo_state_from_raw_object(
+                                    &value,
+                                    tx_order,
+                                    state_index_generator,
+                                    timestamp,
+                                )? {
+                                    new_utxos.push(utxo);
+                                }
+                                if let Some(coin_info) =
+                                    self.new_coin_info_from_raw_object(&value, tx_order)?
+                                {
+                                    coin_infos.push(coin_info);
+                                }
+                                let state_size = state_size(&key, &value);
                                 let state = self.new_global_state_from_raw_object(
                                     value,
                                     tx_order,
                                     state_index_generator,
+                                    state_size,
                                 )?;
                                 new_global_states.push(state);
                             } else {
-                                log::warn!(
+                                tracing::warn!(target: LOG_TARGET,
                                     "Unexpected state type for op new, table handle {:?}, value {:?}",
                                     table_handle,
                                     value
@@ -172,6 +322,18 @@ impl Handler<IndexerStatesMessage> for IndexerActor {
                 for (key, op) in table_change.entries.into_iter() {
                     match op {
                         Op::Modify(value) => {
+                            if let Some(mapping) =
+                                IndexedAddressMapping::new_from_table_item(&key, &value, tx_order)?
+                            {
+                                address_mappings.push(mapping);
+                            }
+                            if let Some(module) = IndexedModule::new_from_table_item(
+                                &value,
+                                tx_order,
+                                state_index_generator,
+                            )? {
+                                modules.push(module);
+                            }
                             let state = self.new_table_state(
                                 key,
                                 value,
@@ -182,9 +344,26 @@ impl Handler<IndexerStatesMessage> for IndexerActor {
                             update_table_states.push(state);
                         }
                         Op::Delete => {
+                            if let Some(multichain_address) =
+                                IndexedAddressMapping::multichain_address_from_key(&key)?
+                            {
+                                remove_address_mappings.push(multichain_address);
+                            }
                             remove_table_states.push((table_handle.to_string(), key.to_string()));
                         }
                         Op::New(value) => {
+                            if let Some(mapping) =
+                                IndexedAddressMapping::new_from_table_item(&key, &value, tx_order)?
+                            {
+                                address_mappings.push(mapping);
+                            }
+                            if let Some(module) = IndexedModule::new_from_table_item(
+                                &value,
+                                tx_order,
+                                state_index_generator,
+                            )? {
+                                modules.push(module);
+                            }
                             let state = self.new_table_state(
                                 key,
                                 value,
@@ -208,19 +387,53 @@ impl Handler<IndexerStatesMessage> for IndexerActor {
 
         //Merge new global states and update global states
         new_global_states.append(&mut update_global_states);
-        self.indexer_store
-            .persist_or_update_global_states(new_global_states)?;
-        self.indexer_store
-            .delete_global_states(remove_global_states)?;
+        // Record the ownership changes before the old owners are overwritten
+        self.index_object_ownership_changes(
+            batch,
+            tx_order,
+            &new_global_states,
+            &remove_global_states,
+        )?;
+        for state in new_global_states {
+            batch.upsert_global_state(state);
+        }
+        for object_id in remove_global_states {
+            batch.remove_global_state(object_id);
+        }
+
+        for utxo in new_utxos {
+            batch.upsert_utxo(utxo);
+        }
+        for object_id in remove_utxos {
+            batch.remove_utxo(object_id);
+        }
+
+        for coin_info in coin_infos {
+            batch.upsert_coin_info(coin_info);
+        }
+
+        for mapping in address_mappings {
+            batch.upsert_address_mapping(mapping);
+        }
+        for multichain_address in remove_address_mappings {
+            batch.remove_address_mapping(multichain_address);
+        }
+
+        for module in modules {
+            batch.upsert_module(module);
+        }
 
         //Merge new table states and update table states
         new_table_states.append(&mut update_table_states);
-        self.indexer_store
-            .persist_or_update_table_states(new_table_states)?;
-        self.indexer_store
-            .delete_table_states(remove_table_states)?;
-        self.indexer_store
-            .delete_table_states_by_table_handle(remove_table_states_by_table_handle)?;
+        for state in new_table_states {
+            batch.upsert_table_state(state);
+        }
+        for (table_handle, key_hex) in remove_table_states {
+            batch.remove_table_state(table_handle, key_hex);
+        }
+        for table_handle in remove_table_states_by_table_handle {
+            batch.remove_table(table_handle);
+        }
 
         // Store table change set for state sync
         let mut split_state_change_set = SplitStateChangeSet::default();
@@ -244,56 +457,461 @@ impl Handler<IndexerStatesMessage> for IndexerActor {
                 IndexedTableChangeSet::new(tx_order, index as u64, item.0, item.1)?;
             indexed_table_change_sets.push(table_change_set);
         }
-        self.indexer_store
-            .persist_table_change_sets(indexed_table_change_sets)?;
+        batch
+            .table_change_sets
+            .append(&mut indexed_table_change_sets);
+        batch.update_progress(IndexerProgressTable::States, tx_order);
         Ok(())
     }
-}
 
-#[async_trait]
-impl Handler<IndexerTransactionMessage> for IndexerActor {
-    async fn handle(
-        &mut self,
-        msg: IndexerTransactionMessage,
-        _ctx: &mut ActorContext,
+    /// Record the owner transitions of the upserted and removed global states of the transaction,
+    /// the old owners are the ones written by the batch if any, otherwise the ones in the store.
+    fn index_object_ownership_changes(
+        &self,
+        batch: &mut IndexerBatch,
+        tx_order: u64,
+        states: &[IndexedGlobalState],
+        removed_object_ids: &[String],
     ) -> Result<()> {
-        let IndexerTransactionMessage {
-            transaction,
-            sequence_info,
-            execution_info,
-            moveos_tx,
-        } = msg;
+        let new_owners = states
+            .iter()
+            .map(|state| {
+                (
+                    state.object_id.to_string(),
+                    Some(state.owner.to_hex_literal()),
+                )
+            })
+            .chain(
+                removed_object_ids
+                    .iter()
+                    .map(|object_id| (object_id.clone(), None)),
+            )
+            .collect::<Vec<_>>();
+        let unknown_object_ids = new_owners
+            .iter()
+            .filter(|(object_id, _)| batch.object_owner(object_id).is_none())
+            .map(|(object_id, _)| object_id.clone())
+            .collect::<Vec<_>>();
+        let stored_owners = self.indexer_store.get_object_owners(&unknown_object_ids)?;
+        for (object_id, new_owner) in new_owners {
+            let old_owner = batch
+                .object_owner(&object_id)
+                .unwrap_or_else(|| stored_owners.get(&object_id).cloned());
+            batch.add_object_ownership_change(object_id, tx_order, old_owner, new_owner);
+        }
+        Ok(())
+    }
 
+    pub fn index_transaction(
+        &self,
+        batch: &mut IndexerBatch,
+        transaction: TypedTransaction,
+        sequence_info: TransactionSequenceInfo,
+        execution_info: TransactionExecutionInfo,
+        moveos_tx: VerifiedMoveOSTransaction,
+    ) -> Result<()> {
+        let tx_order = sequence_info.tx_order;
         let indexed_transaction =
             IndexedTransaction::new(transaction, sequence_info, execution_info, moveos_tx)?;
-        let transactions = vec![indexed_transaction];
-        self.indexer_store.persist_transactions(transactions)?;
+        batch.transactions.push(indexed_transaction);
+        batch.update_progress(IndexerProgressTable::Transactions, tx_order);
         Ok(())
     }
-}
 
-#[async_trait]
-impl Handler<IndexerEventsMessage> for IndexerActor {
-    async fn handle(&mut self, msg: IndexerEventsMessage, _ctx: &mut ActorContext) -> Result<()> {
-        let IndexerEventsMessage {
-            events,
-            transaction,
-            sequence_info,
-            moveos_tx,
-        } = msg;
-
-        let events: Vec<_> = events
+    pub fn index_events(
+        &self,
+        batch: &mut IndexerBatch,
+        events: Vec<Event>,
+        transaction: TypedTransaction,
+        sequence_info: TransactionSequenceInfo,
+        moveos_tx: VerifiedMoveOSTransaction,
+    ) -> Result<()> {
+        let tx_order = sequence_info.tx_order;
+        let mut events: Vec<_> = events
             .into_iter()
             .map(|event| {
-                IndexedEvent::new(
+                let mut indexed_event = IndexedEvent::new(
                     event,
                     transaction.clone(),
                     sequence_info.clone(),
                     moveos_tx.clone(),
-                )
+                );
+                // The event is still indexed if the event data can not be decoded, but it can not be searched by the data.
+                match self.resolve_state_to_json(
+                    &TypeTag::Struct(Box::new(indexed_event.event_type.clone())),
+                    indexed_event.event_data.as_slice(),
+                ) {
+                    Ok(event_data_json) => indexed_event.event_data_json = event_data_json,
+                    Err(e) => tracing::warn!(target: LOG_TARGET,
+                        "Failed to decode event data of type {}: {:?}",
+                        indexed_event.event_type,
+                        e
+                    ),
+                }
+                indexed_event
             })
             .collect();
-        self.indexer_store.persist_events(events)?;
+        for event in events.iter() {
+            match IndexedCoinTransfer::new_from_event(event) {
+                Ok(Some(coin_transfer)) => batch.coin_transfers.push(coin_transfer),
+                Ok(None) => {}
+                Err(e) => tracing::warn!(
+                    target: LOG_TARGET,
+                    "Failed to decode coin transfer event of type {}: {:?}",
+                    event.event_type,
+                    e
+                ),
+            }
+        }
+        batch.events.append(&mut events);
+        batch.update_progress(IndexerProgressTable::Events, tx_order);
+        Ok(())
+    }
+
+    /// Commit the indexed data of the batch to the store
+    pub fn commit(&self, batch: IndexerBatch) -> Result<()> {
+        self.observe_batch_rows_written(&batch);
+        self.indexer_store.commit_batch(batch)?;
         Ok(())
     }
+
+    /// Revert the indexed data to the transaction at `tx_order`, such as after a reorg of the sequencer or DA.
+    /// The states touched by the change sets above `tx_order` are restored from the state tree at `state_root`,
+    /// which is the state root after the transaction at `tx_order`, then the transactions, events and change sets
+    /// above `tx_order` are deleted. The change sets are deleted last, so a failed revert can be retried.
+    pub fn revert(&self, tx_order: u64, state_root: H256) -> Result<()> {
+        if self.is_indexed(IndexerProgressTable::States) {
+            let table_change_sets = self
+                .indexer_store
+                .get_table_change_sets_from(tx_order + 1)?;
+            let mut batch = IndexerBatch::default();
+            self.restore_states(&mut batch, tx_order, state_root, table_change_sets)?;
+            batch.update_progress(IndexerProgressTable::States, tx_order);
+            self.commit(batch)?;
+        }
+        self.indexer_store.delete_from_tx_order(tx_order + 1)?;
+        for table in IndexerProgressTable::ALL {
+            self.observe_indexed(table, tx_order);
+        }
+        Ok(())
+    }
+
+    /// Restore the states touched by the change sets to the ones in the state tree at `state_root`
+    fn restore_states(
+        &self,
+        batch: &mut IndexerBatch,
+        tx_order: u64,
+        state_root: H256,
+        table_change_sets: Vec<IndexerTableChangeSet>,
+    ) -> Result<()> {
+        let statedb = StateDBStore::new_with_root(
+            self.moveos_store.0.statedb.node_store.clone(),
+            Some(state_root),
+        );
+        let mut object_ids = BTreeSet::new();
+        let mut table_items = BTreeSet::new();
+        let mut new_tables = BTreeSet::new();
+        let mut removed_tables = BTreeSet::new();
+        for table_change_set in table_change_sets {
+            let change_set = table_change_set.table_change_set;
+            new_tables.extend(change_set.new_tables);
+            removed_tables.extend(change_set.removed_tables);
+            for (table_handle, table_change) in change_set.changes {
+                for key in table_change.entries.into_keys() {
+                    if table_handle == context::GLOBAL_OBJECT_STORAGE_HANDLE {
+                        object_ids.insert(ObjectID::from_bytes(key.key.as_slice())?);
+                    } else {
+                        table_items.insert((table_handle, key));
+                    }
+                }
+            }
+        }
+        // The removed tables are objects, their global states are restored with the objects
+        object_ids.extend(removed_tables.iter().cloned());
+
+        let timestamp = timestamp_from_state(statedb.get(Timestamp::object_id())?)?;
+        let mut state_index_generator = 0u64;
+        for object_id in object_ids {
+            match statedb.get(object_id.clone())? {
+                Some(value) if value.is_object() => {
+                    if let Some(utxo) = self.new_utxo_state_from_raw_object(
+                        &value,
+                        tx_order,
+                        state_index_generator,
+                        timestamp,
+                    )? {
+                        batch.upsert_utxo(utxo);
+                    }
+                    if let Some(coin_info) = self.new_coin_info_from_raw_object(&value, tx_order)? {
+                        batch.upsert_coin_info(coin_info);
+                    }
+                    let state_size = state_size(&object_id.to_key(), &value);
+                    let state = self.new_global_state_from_raw_object(
+                        value,
+                        tx_order,
+                        state_index_generator,
+                        state_size,
+                    )?;
+                    batch.upsert_global_state(state);
+                }
+                _ => {
+                    batch.remove_global_state(object_id.to_string());
+                    batch.remove_utxo(object_id.to_string());
+                }
+            }
+            state_index_generator += 1;
+        }
+
+        // The tables created above `tx_order` do not exist at the state root
+        for table_handle in new_tables.iter() {
+            if !removed_tables.contains(table_handle) {
+                batch.remove_table(table_handle.to_string());
+            }
+        }
+        for (table_handle, key) in table_items {
+            if new_tables.contains(&table_handle) && !removed_tables.contains(&table_handle) {
+                continue;
+            }
+            match statedb.get_with_key(table_handle.clone(), key.clone())? {
+                Some(value) => {
+                    if let Some(mapping) =
+                        IndexedAddressMapping::new_from_table_item(&key, &value, tx_order)?
+                    {
+                        batch.upsert_address_mapping(mapping);
+                    }
+                    // The modules published above `tx_order` are deleted with the tx order
+                    if let Some(module) =
+                        IndexedModule::new_from_table_item(&value, tx_order, state_index_generator)?
+                    {
+                        batch.upsert_module(module);
+                    }
+                    let state = self.new_table_state(
+                        key,
+                        value,
+                        table_handle,
+                        tx_order,
+                        state_index_generator,
+                    )?;
+                    batch.upsert_table_state(state);
+                }
+                None => {
+                    if let Some(multichain_address) =
+                        IndexedAddressMapping::multichain_address_from_key(&key)?
+                    {
+                        batch.remove_address_mapping(multichain_address);
+                    }
+                    batch.remove_table_state(table_handle.to_string(), key.to_string());
+                }
+            }
+            state_index_generator += 1;
+        }
+
+        // The tables removed above `tx_order` are listed from the state root, they may not exist if also created above it
+        for table_handle in removed_tables {
+            if statedb.get(table_handle.clone())?.is_none() {
+                continue;
+            }
+            let mut cursor = None;
+            loop {
+                let items =
+                    statedb.list_with_key(table_handle.clone(), cursor, RESTORE_TABLE_PAGE_SIZE)?;
+                let has_next_page = items.len() == RESTORE_TABLE_PAGE_SIZE;
+                cursor = items.last().map(|(key, _)| key.clone());
+                for (key, value) in items {
+                    let state = self.new_table_state(
+                        key,
+                        value,
+                        table_handle.clone(),
+                        tx_order,
+                        state_index_generator,
+                    )?;
+                    batch.upsert_table_state(state);
+                    state_index_generator += 1;
+                }
+                if !has_next_page {
+                    break;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn observe_batch_rows_written(&self, batch: &IndexerBatch) {
+        if self.metrics.is_none() {
+            return;
+        }
+        let removed_global_states = batch
+            .global_states
+            .values()
+            .filter(|state| state.is_none())
+            .count();
+        let removed_utxos = batch.utxos.values().filter(|utxo| utxo.is_none()).count();
+        let removed_table_states = batch
+            .table_states
+            .values()
+            .filter(|state| state.is_none())
+            .count();
+        self.observe_rows_written(
+            "global_states",
+            "upsert",
+            batch.global_states.len() - removed_global_states,
+        );
+        self.observe_rows_written("global_states", "delete", removed_global_states);
+        self.observe_rows_written("utxos", "upsert", batch.utxos.len() - removed_utxos);
+        self.observe_rows_written("utxos", "delete", removed_utxos);
+        self.observe_rows_written("coin_infos", "upsert", batch.coin_infos.len());
+        self.observe_rows_written("modules", "upsert", batch.modules.len());
+        self.observe_rows_written(
+            "table_states",
+            "upsert",
+            batch.table_states.len() - removed_table_states,
+        );
+        self.observe_rows_written("table_states", "delete", removed_table_states);
+        self.observe_rows_written("table_change_sets", "insert", batch.table_change_sets.len());
+        self.observe_rows_written("transactions", "insert", batch.transactions.len());
+        self.observe_rows_written("events", "insert", batch.events.len());
+        self.observe_rows_written("coin_transfers", "insert", batch.coin_transfers.len());
+    }
+}
+
+impl IndexerActor {
+    /// Index the write message into the batch, the messages of the tables not indexed are ignored
+    fn index_message(&self, batch: &mut IndexerBatch, message: IndexerWriteMessage) -> Result<()> {
+        match message {
+            IndexerWriteMessage::States(IndexerStatesMessage {
+                tx_order,
+                state_change_set,
+            }) => {
+                if self.is_indexed(IndexerProgressTable::States) {
+                    self.observe_executed(tx_order);
+                    self.index_states(batch, tx_order, state_change_set)?;
+                }
+            }
+            IndexerWriteMessage::Transaction(IndexerTransactionMessage {
+                transaction,
+                sequence_info,
+                execution_info,
+                moveos_tx,
+            }) => {
+                if self.is_indexed(IndexerProgressTable::Transactions) {
+                    self.observe_executed(sequence_info.tx_order);
+                    self.index_transaction(
+                        batch,
+                        transaction,
+                        sequence_info,
+                        execution_info,
+                        moveos_tx,
+                    )?;
+                }
+            }
+            IndexerWriteMessage::Events(IndexerEventsMessage {
+                events,
+                transaction,
+                sequence_info,
+                moveos_tx,
+            }) => {
+                if self.is_indexed(IndexerProgressTable::Events) {
+                    self.observe_executed(sequence_info.tx_order);
+                    self.index_events(batch, events, transaction, sequence_info, moveos_tx)?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn commit_and_observe(&self, batch: IndexerBatch) -> Result<()> {
+        let progress = IndexerProgressTable::ALL
+            .into_iter()
+            .filter_map(|table| batch.progress(table).map(|tx_order| (table, tx_order)))
+            .collect::<Vec<_>>();
+        self.commit(batch)?;
+        for (table, tx_order) in progress {
+            self.observe_indexed(table, tx_order);
+        }
+        Ok(())
+    }
+}
+
+/// Read the milliseconds of the timestamp object, the timestamp is 0 before the genesis
+fn timestamp_from_state(state: Option<State>) -> Result<u64> {
+    match state {
+        Some(state) => Ok(state.as_object::<Timestamp>()?.value.microseconds),
+        None => Ok(0),
+    }
+}
+
+impl Actor for IndexerActor {}
+
+#[async_trait]
+impl Handler<IndexerStatesMessage> for IndexerActor {
+    async fn handle(&mut self, msg: IndexerStatesMessage, _ctx: &mut ActorContext) -> Result<()> {
+        let mut batch = IndexerBatch::default();
+        self.index_message(&mut batch, IndexerWriteMessage::States(msg))?;
+        self.commit_and_observe(batch)
+    }
+}
+
+#[async_trait]
+impl Handler<IndexerTransactionMessage> for IndexerActor {
+    async fn handle(
+        &mut self,
+        msg: IndexerTransactionMessage,
+        _ctx: &mut ActorContext,
+    ) -> Result<()> {
+        let mut batch = IndexerBatch::default();
+        self.index_message(&mut batch, IndexerWriteMessage::Transaction(msg))?;
+        self.commit_and_observe(batch)
+    }
+}
+
+#[async_trait]
+impl Handler<IndexerEventsMessage> for IndexerActor {
+    async fn handle(&mut self, msg: IndexerEventsMessage, _ctx: &mut ActorContext) -> Result<()> {
+        let mut batch = IndexerBatch::default();
+        self.index_message(&mut batch, IndexerWriteMessage::Events(msg))?;
+        self.commit_and_observe(batch)
+    }
+}
+
+#[async_trait]
+impl Handler<IndexerBatchMessage> for IndexerActor {
+    async fn handle(&mut self, msg: IndexerBatchMessage, _ctx: &mut ActorContext) -> Result<()> {
+        // The batch is aborted if any message fails to index, the progress is not moved past the failed message
+        let mut batch = IndexerBatch::default();
+        for message in msg.messages {
+            self.index_message(&mut batch, message)?;
+        }
+        self.commit_and_observe(batch)
+    }
+}
+
+#[async_trait]
+impl Handler<IndexerRevertMessage> for IndexerActor {
+    async fn handle(&mut self, msg: IndexerRevertMessage, _ctx: &mut ActorContext) -> Result<()> {
+        let IndexerRevertMessage {
+            tx_order,
+            state_root,
+        } = msg;
+        self.revert(tx_order, state_root)
+    }
+}
+
+#[async_trait]
+impl Handler<PruneIndexerTick> for IndexerActor {
+    async fn handle(&mut self, msg: PruneIndexerTick, _ctx: &mut ActorContext) {
+        match self.indexer_store.prune_history(&msg.retention) {
+            Ok(pruned) => tracing::info!(
+                target: LOG_TARGET,
+                "[PruneIndexer] retention: {}, pruned rows: {:?}",
+                msg.retention,
+                pruned
+            ),
+            Err(e) => tracing::error!(
+                target: LOG_TARGET,
+                "[PruneIndexer] retention: {}, error: {:?}",
+                msg.retention,
+                e
+            ),
+        }
+    }
 }
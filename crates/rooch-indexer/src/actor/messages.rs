@@ -3,20 +3,33 @@
 
 use anyhow::Result;
 use coerce::actor::message::Message;
+use coerce::actor::scheduler::timer::TimerTick;
+use move_core_types::account_address::AccountAddress;
+use move_core_types::language_storage::StructTag;
+use moveos_types::h256::H256;
 use moveos_types::moveos_std::event::Event;
+use moveos_types::moveos_std::object_id::ObjectID;
 use moveos_types::state::StateChangeSet;
 use moveos_types::transaction::{TransactionExecutionInfo, VerifiedMoveOSTransaction};
+use rooch_config::indexer_config::IndexerRetention;
+use rooch_types::address::MultiChainAddress;
+use rooch_types::indexer::account::{
+    IndexerAccountSummary, IndexerAddressMapping, IndexerStorageUsage,
+};
+use rooch_types::indexer::coin_transfer::IndexerCoinTransfer;
 use rooch_types::indexer::event_filter::{EventFilter, IndexerEvent, IndexerEventID};
+use rooch_types::indexer::module::{IndexerModule, ModuleFilter};
 use rooch_types::indexer::state::{
-    GlobalStateFilter, IndexerGlobalState, IndexerStateID, IndexerTableChangeSet,
-    IndexerTableState, StateSyncFilter, TableStateFilter,
+    GlobalStateFilter, IndexerCoinInfo, IndexerGlobalState, IndexerObjectOwnershipChange,
+    IndexerStateID, IndexerTableChangeSet, IndexerTableState, IndexerUTXOState, StateSyncFilter,
+    TableStateFilter, UTXOFilter,
 };
 use rooch_types::indexer::transaction_filter::TransactionFilter;
 use rooch_types::transaction::{TransactionSequenceInfo, TransactionWithInfo, TypedTransaction};
 use serde::{Deserialize, Serialize};
 
 /// Indexer Transaction write Message
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct IndexerTransactionMessage {
     pub transaction: TypedTransaction,
     pub sequence_info: TransactionSequenceInfo,
@@ -29,7 +42,7 @@ impl Message for IndexerTransactionMessage {
 }
 
 /// Indexer Event write Message
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct IndexerEventsMessage {
     pub events: Vec<Event>,
     pub transaction: TypedTransaction,
@@ -43,7 +56,7 @@ impl Message for IndexerEventsMessage {
 
 /// Indexer State write Message
 // #[derive(Debug, Serialize, Deserialize)]
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct IndexerStatesMessage {
     pub tx_order: u64,
     pub state_change_set: StateChangeSet,
@@ -53,6 +66,47 @@ impl Message for IndexerStatesMessage {
     type Result = Result<()>;
 }
 
+/// The indexer write messages, they are sent to the indexer through a bounded channel
+#[derive(Debug, Clone)]
+pub enum IndexerWriteMessage {
+    States(IndexerStatesMessage),
+    Transaction(IndexerTransactionMessage),
+    Events(IndexerEventsMessage),
+}
+
+/// The write messages drained from the channel at once, they are committed in one batch
+#[derive(Debug)]
+pub struct IndexerBatchMessage {
+    pub messages: Vec<IndexerWriteMessage>,
+}
+
+impl Message for IndexerBatchMessage {
+    type Result = Result<()>;
+}
+
+/// Revert the indexed data to the transaction at `tx_order`, the `state_root` is the state root after the transaction
+#[derive(Debug, Serialize, Deserialize)]
+pub struct IndexerRevertMessage {
+    pub tx_order: u64,
+    pub state_root: H256,
+}
+
+impl Message for IndexerRevertMessage {
+    type Result = Result<()>;
+}
+
+/// Prune the indexed history out of the retention periodically
+#[derive(Debug, Clone)]
+pub struct PruneIndexerTick {
+    pub retention: IndexerRetention,
+}
+
+impl Message for PruneIndexerTick {
+    type Result = ();
+}
+
+impl TimerTick for PruneIndexerTick {}
+
 /// Query Indexer Transactions Message
 #[derive(Debug, Serialize, Deserialize)]
 pub struct QueryIndexerTransactionsMessage {
@@ -81,6 +135,34 @@ impl Message for QueryIndexerEventsMessage {
     type Result = Result<Vec<IndexerEvent>>;
 }
 
+/// Search Indexer Transactions Message
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SearchIndexerTransactionsMessage {
+    pub query: String,
+    // exclusive cursor if `Some`, otherwise start from the beginning
+    pub cursor: Option<u64>,
+    pub limit: usize,
+    pub descending_order: bool,
+}
+
+impl Message for SearchIndexerTransactionsMessage {
+    type Result = Result<Vec<TransactionWithInfo>>;
+}
+
+/// Search Indexer Events Message
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SearchIndexerEventsMessage {
+    pub query: String,
+    // exclusive cursor if `Some`, otherwise start from the beginning
+    pub cursor: Option<IndexerEventID>,
+    pub limit: usize,
+    pub descending_order: bool,
+}
+
+impl Message for SearchIndexerEventsMessage {
+    type Result = Result<Vec<IndexerEvent>>;
+}
+
 /// Query Indexer Global States Message
 #[derive(Debug, Serialize, Deserialize)]
 pub struct QueryIndexerGlobalStatesMessage {
@@ -109,6 +191,112 @@ impl Message for QueryIndexerTableStatesMessage {
     type Result = Result<Vec<IndexerTableState>>;
 }
 
+/// Query Indexer UTXOs Message
+#[derive(Debug, Serialize, Deserialize)]
+pub struct QueryIndexerUTXOsMessage {
+    pub filter: UTXOFilter,
+    // exclusive cursor if `Some`, otherwise start from the beginning
+    pub cursor: Option<IndexerStateID>,
+    pub limit: usize,
+    pub descending_order: bool,
+}
+
+impl Message for QueryIndexerUTXOsMessage {
+    type Result = Result<Vec<IndexerUTXOState>>;
+}
+
+/// Get Indexer Coin Infos Message
+#[derive(Debug, Serialize, Deserialize)]
+pub struct GetIndexerCoinInfosMessage {
+    pub coin_types: Vec<StructTag>,
+}
+
+impl Message for GetIndexerCoinInfosMessage {
+    type Result = Result<Vec<Option<IndexerCoinInfo>>>;
+}
+
+/// Get Indexer Object Ownership History Message
+#[derive(Debug, Serialize, Deserialize)]
+pub struct GetIndexerObjectOwnershipHistoryMessage {
+    pub object_id: ObjectID,
+    // exclusive tx order cursor if `Some`, otherwise start from the beginning
+    pub cursor: Option<u64>,
+    pub limit: usize,
+    pub descending_order: bool,
+}
+
+impl Message for GetIndexerObjectOwnershipHistoryMessage {
+    type Result = Result<Vec<IndexerObjectOwnershipChange>>;
+}
+
+/// Get Indexer Account Summary Message
+#[derive(Debug, Serialize, Deserialize)]
+pub struct GetIndexerAccountSummaryMessage {
+    pub address: AccountAddress,
+}
+
+impl Message for GetIndexerAccountSummaryMessage {
+    type Result = Result<IndexerAccountSummary>;
+}
+
+/// Get Indexer Storage Usage of the account Message
+#[derive(Debug, Serialize, Deserialize)]
+pub struct GetIndexerStorageUsageMessage {
+    pub address: AccountAddress,
+}
+
+impl Message for GetIndexerStorageUsageMessage {
+    type Result = Result<IndexerStorageUsage>;
+}
+
+/// Get Indexer Address Mapping of the multichain address Message
+#[derive(Debug, Serialize, Deserialize)]
+pub struct GetIndexerAddressMappingMessage {
+    pub multichain_address: MultiChainAddress,
+}
+
+impl Message for GetIndexerAddressMappingMessage {
+    type Result = Result<Option<IndexerAddressMapping>>;
+}
+
+/// Get Indexer Address Mappings of the Rooch address Message
+#[derive(Debug, Serialize, Deserialize)]
+pub struct GetIndexerAddressMappingsByRoochAddressMessage {
+    pub rooch_address: AccountAddress,
+}
+
+impl Message for GetIndexerAddressMappingsByRoochAddressMessage {
+    type Result = Result<Vec<IndexerAddressMapping>>;
+}
+
+/// Query Indexer Modules Message
+#[derive(Debug, Serialize, Deserialize)]
+pub struct QueryIndexerModulesMessage {
+    pub filter: ModuleFilter,
+    // exclusive cursor if `Some`, otherwise start from the beginning
+    pub cursor: Option<IndexerStateID>,
+    pub limit: usize,
+    pub descending_order: bool,
+}
+
+impl Message for QueryIndexerModulesMessage {
+    type Result = Result<Vec<IndexerModule>>;
+}
+
+/// Query Indexer Coin Transfers Message
+#[derive(Debug, Serialize, Deserialize)]
+pub struct QueryIndexerCoinTransfersMessage {
+    pub coin_store_id: ObjectID,
+    // exclusive cursor if `Some`, otherwise start from the beginning
+    pub cursor: Option<IndexerEventID>,
+    pub limit: usize,
+    pub descending_order: bool,
+}
+
+impl Message for QueryIndexerCoinTransfersMessage {
+    type Result = Result<Vec<IndexerCoinTransfer>>;
+}
+
 /// Sync Indexer State change sets Message
 #[derive(Debug, Serialize, Deserialize)]
 pub struct SyncIndexerStatesMessage {
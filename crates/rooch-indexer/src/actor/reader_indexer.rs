@@ -2,24 +2,55 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::actor::messages::{
-    QueryIndexerEventsMessage, QueryIndexerGlobalStatesMessage, QueryIndexerTableStatesMessage,
-    QueryIndexerTransactionsMessage, SyncIndexerStatesMessage,
+    GetIndexerAccountSummaryMessage, GetIndexerAddressMappingMessage,
+    GetIndexerAddressMappingsByRoochAddressMessage, GetIndexerCoinInfosMessage,
+    GetIndexerObjectOwnershipHistoryMessage, GetIndexerStorageUsageMessage,
+    QueryIndexerCoinTransfersMessage, QueryIndexerEventsMessage, QueryIndexerGlobalStatesMessage,
+    QueryIndexerModulesMessage, QueryIndexerTableStatesMessage, QueryIndexerTransactionsMessage,
+    QueryIndexerUTXOsMessage, SearchIndexerEventsMessage, SearchIndexerTransactionsMessage,
+    SyncIndexerStatesMessage,
 };
 use crate::indexer_reader::IndexerReader;
+use crate::metrics::IndexerMetrics;
 use anyhow::{anyhow, Result};
 use async_trait::async_trait;
 use coerce::actor::{context::ActorContext, message::Handler, Actor};
+use metrics::HistogramTimer;
+use rooch_types::indexer::account::{
+    IndexerAccountSummary, IndexerAddressMapping, IndexerStorageUsage,
+};
+use rooch_types::indexer::coin_transfer::IndexerCoinTransfer;
 use rooch_types::indexer::event_filter::IndexerEvent;
-use rooch_types::indexer::state::{IndexerGlobalState, IndexerTableChangeSet, IndexerTableState};
+use rooch_types::indexer::module::IndexerModule;
+use rooch_types::indexer::state::{
+    IndexerCoinInfo, IndexerGlobalState, IndexerObjectOwnershipChange, IndexerTableChangeSet,
+    IndexerTableState, IndexerUTXOState,
+};
 use rooch_types::transaction::TransactionWithInfo;
 
 pub struct IndexerReaderActor {
     indexer_reader: IndexerReader,
+    metrics: Option<IndexerMetrics>,
 }
 
 impl IndexerReaderActor {
     pub fn new(indexer_reader: IndexerReader) -> Result<Self> {
-        Ok(Self { indexer_reader })
+        Ok(Self {
+            indexer_reader,
+            metrics: None,
+        })
+    }
+
+    pub fn with_metrics(mut self, metrics: IndexerMetrics) -> Self {
+        self.metrics = Some(metrics);
+        self
+    }
+
+    /// Start the latency timer of the query, the latency is observed when the timer is dropped
+    fn query_timer(&self, query: &str) -> Option<HistogramTimer> {
+        self.metrics
+            .as_ref()
+            .map(|metrics| metrics.query_timer(query))
     }
 }
 
@@ -38,6 +69,7 @@ impl Handler<QueryIndexerTransactionsMessage> for IndexerReaderActor {
             limit,
             descending_order,
         } = msg;
+        let _timer = self.query_timer("query_transactions_with_filter");
         self.indexer_reader
             .query_transactions_with_filter(filter, cursor, limit, descending_order)
             .map_err(|e| anyhow!(format!("Failed to query indexer transactions: {:?}", e)))
@@ -57,12 +89,53 @@ impl Handler<QueryIndexerEventsMessage> for IndexerReaderActor {
             limit,
             descending_order,
         } = msg;
+        let _timer = self.query_timer("query_events_with_filter");
         self.indexer_reader
             .query_events_with_filter(filter, cursor, limit, descending_order)
             .map_err(|e| anyhow!(format!("Failed to query indexer events: {:?}", e)))
     }
 }
 
+#[async_trait]
+impl Handler<SearchIndexerTransactionsMessage> for IndexerReaderActor {
+    async fn handle(
+        &mut self,
+        msg: SearchIndexerTransactionsMessage,
+        _ctx: &mut ActorContext,
+    ) -> Result<Vec<TransactionWithInfo>> {
+        let SearchIndexerTransactionsMessage {
+            query,
+            cursor,
+            limit,
+            descending_order,
+        } = msg;
+        let _timer = self.query_timer("search_transactions");
+        self.indexer_reader
+            .search_transactions(query, cursor, limit, descending_order)
+            .map_err(|e| anyhow!(format!("Failed to search indexer transactions: {:?}", e)))
+    }
+}
+
+#[async_trait]
+impl Handler<SearchIndexerEventsMessage> for IndexerReaderActor {
+    async fn handle(
+        &mut self,
+        msg: SearchIndexerEventsMessage,
+        _ctx: &mut ActorContext,
+    ) -> Result<Vec<IndexerEvent>> {
+        let SearchIndexerEventsMessage {
+            query,
+            cursor,
+            limit,
+            descending_order,
+        } = msg;
+        let _timer = self.query_timer("search_events");
+        self.indexer_reader
+            .search_events(query, cursor, limit, descending_order)
+            .map_err(|e| anyhow!(format!("Failed to search indexer events: {:?}", e)))
+    }
+}
+
 #[async_trait]
 impl Handler<QueryIndexerGlobalStatesMessage> for IndexerReaderActor {
     async fn handle(
@@ -76,6 +149,7 @@ impl Handler<QueryIndexerGlobalStatesMessage> for IndexerReaderActor {
             limit,
             descending_order,
         } = msg;
+        let _timer = self.query_timer("query_global_states_with_filter");
         self.indexer_reader
             .query_global_states_with_filter(filter, cursor, limit, descending_order)
             .map_err(|e| anyhow!(format!("Failed to query indexer global states: {:?}", e)))
@@ -95,12 +169,173 @@ impl Handler<QueryIndexerTableStatesMessage> for IndexerReaderActor {
             limit,
             descending_order,
         } = msg;
+        let _timer = self.query_timer("query_table_states_with_filter");
         self.indexer_reader
             .query_table_states_with_filter(filter, cursor, limit, descending_order)
             .map_err(|e| anyhow!(format!("Failed to query indexer table states: {:?}", e)))
     }
 }
 
+#[async_trait]
+impl Handler<QueryIndexerUTXOsMessage> for IndexerReaderActor {
+    async fn handle(
+        &mut self,
+        msg: QueryIndexerUTXOsMessage,
+        _ctx: &mut ActorContext,
+    ) -> Result<Vec<IndexerUTXOState>> {
+        let QueryIndexerUTXOsMessage {
+            filter,
+            cursor,
+            limit,
+            descending_order,
+        } = msg;
+        let _timer = self.query_timer("query_utxos_with_filter");
+        self.indexer_reader
+            .query_utxos_with_filter(filter, cursor, limit, descending_order)
+            .map_err(|e| anyhow!(format!("Failed to query indexer utxos: {:?}", e)))
+    }
+}
+
+#[async_trait]
+impl Handler<GetIndexerCoinInfosMessage> for IndexerReaderActor {
+    async fn handle(
+        &mut self,
+        msg: GetIndexerCoinInfosMessage,
+        _ctx: &mut ActorContext,
+    ) -> Result<Vec<Option<IndexerCoinInfo>>> {
+        let GetIndexerCoinInfosMessage { coin_types } = msg;
+        let _timer = self.query_timer("get_coin_infos");
+        self.indexer_reader
+            .get_coin_infos(coin_types)
+            .map_err(|e| anyhow!(format!("Failed to get indexer coin infos: {:?}", e)))
+    }
+}
+
+#[async_trait]
+impl Handler<GetIndexerAccountSummaryMessage> for IndexerReaderActor {
+    async fn handle(
+        &mut self,
+        msg: GetIndexerAccountSummaryMessage,
+        _ctx: &mut ActorContext,
+    ) -> Result<IndexerAccountSummary> {
+        let GetIndexerAccountSummaryMessage { address } = msg;
+        let _timer = self.query_timer("get_account_summary");
+        self.indexer_reader
+            .get_account_summary(address)
+            .map_err(|e| anyhow!(format!("Failed to get indexer account summary: {:?}", e)))
+    }
+}
+
+#[async_trait]
+impl Handler<GetIndexerStorageUsageMessage> for IndexerReaderActor {
+    async fn handle(
+        &mut self,
+        msg: GetIndexerStorageUsageMessage,
+        _ctx: &mut ActorContext,
+    ) -> Result<IndexerStorageUsage> {
+        let GetIndexerStorageUsageMessage { address } = msg;
+        let _timer = self.query_timer("get_storage_usage");
+        self.indexer_reader
+            .get_storage_usage(address)
+            .map_err(|e| anyhow!(format!("Failed to get indexer storage usage: {:?}", e)))
+    }
+}
+
+#[async_trait]
+impl Handler<GetIndexerAddressMappingMessage> for IndexerReaderActor {
+    async fn handle(
+        &mut self,
+        msg: GetIndexerAddressMappingMessage,
+        _ctx: &mut ActorContext,
+    ) -> Result<Option<IndexerAddressMapping>> {
+        let GetIndexerAddressMappingMessage { multichain_address } = msg;
+        let _timer = self.query_timer("get_address_mapping");
+        self.indexer_reader
+            .get_address_mapping(multichain_address)
+            .map_err(|e| anyhow!(format!("Failed to get indexer address mapping: {:?}", e)))
+    }
+}
+
+#[async_trait]
+impl Handler<GetIndexerAddressMappingsByRoochAddressMessage> for IndexerReaderActor {
+    async fn handle(
+        &mut self,
+        msg: GetIndexerAddressMappingsByRoochAddressMessage,
+        _ctx: &mut ActorContext,
+    ) -> Result<Vec<IndexerAddressMapping>> {
+        let GetIndexerAddressMappingsByRoochAddressMessage { rooch_address } = msg;
+        let _timer = self.query_timer("get_address_mappings_by_rooch_address");
+        self.indexer_reader
+            .get_address_mappings_by_rooch_address(rooch_address)
+            .map_err(|e| anyhow!(format!("Failed to get indexer address mappings: {:?}", e)))
+    }
+}
+
+#[async_trait]
+impl Handler<GetIndexerObjectOwnershipHistoryMessage> for IndexerReaderActor {
+    async fn handle(
+        &mut self,
+        msg: GetIndexerObjectOwnershipHistoryMessage,
+        _ctx: &mut ActorContext,
+    ) -> Result<Vec<IndexerObjectOwnershipChange>> {
+        let GetIndexerObjectOwnershipHistoryMessage {
+            object_id,
+            cursor,
+            limit,
+            descending_order,
+        } = msg;
+        let _timer = self.query_timer("get_object_ownership_history");
+        self.indexer_reader
+            .get_object_ownership_history(object_id, cursor, limit, descending_order)
+            .map_err(|e| {
+                anyhow!(format!(
+                    "Failed to get indexer object ownership history: {:?}",
+                    e
+                ))
+            })
+    }
+}
+
+#[async_trait]
+impl Handler<QueryIndexerModulesMessage> for IndexerReaderActor {
+    async fn handle(
+        &mut self,
+        msg: QueryIndexerModulesMessage,
+        _ctx: &mut ActorContext,
+    ) -> Result<Vec<IndexerModule>> {
+        let QueryIndexerModulesMessage {
+            filter,
+            cursor,
+            limit,
+            descending_order,
+        } = msg;
+        let _timer = self.query_timer("query_modules_with_filter");
+        self.indexer_reader
+            .query_modules_with_filter(filter, cursor, limit, descending_order)
+            .map_err(|e| anyhow!(format!("Failed to query indexer modules: {:?}", e)))
+    }
+}
+
+#[async_trait]
+impl Handler<QueryIndexerCoinTransfersMessage> for IndexerReaderActor {
+    async fn handle(
+        &mut self,
+        msg: QueryIndexerCoinTransfersMessage,
+        _ctx: &mut ActorContext,
+    ) -> Result<Vec<IndexerCoinTransfer>> {
+        let QueryIndexerCoinTransfersMessage {
+            coin_store_id,
+            cursor,
+            limit,
+            descending_order,
+        } = msg;
+        let _timer = self.query_timer("query_coin_transfers");
+        self.indexer_reader
+            .query_coin_transfers(coin_store_id, cursor, limit, descending_order)
+            .map_err(|e| anyhow!(format!("Failed to query indexer coin transfers: {:?}", e)))
+    }
+}
+
 #[async_trait]
 impl Handler<SyncIndexerStatesMessage> for IndexerReaderActor {
     async fn handle(
@@ -114,6 +349,7 @@ impl Handler<SyncIndexerStatesMessage> for IndexerReaderActor {
             limit,
             descending_order,
         } = msg;
+        let _timer = self.query_timer("sync_states");
         self.indexer_reader
             .sync_states(filter, cursor, limit, descending_order)
             .map_err(|e| {
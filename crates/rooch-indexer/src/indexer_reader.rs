@@ -2,25 +2,48 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::types::IndexerResult;
+use crate::LOG_TARGET;
 use crate::{
     errors::IndexerError, SqliteConnectionConfig, SqliteConnectionPoolConfig, SqlitePoolConnection,
 };
 use anyhow::{anyhow, Result};
 use diesel::{
-    r2d2::ConnectionManager, Connection, ExpressionMethods, QueryDsl, RunQueryDsl, SqliteConnection,
+    r2d2::ConnectionManager, Connection, ExpressionMethods, OptionalExtension, QueryDsl,
+    RunQueryDsl, SqliteConnection,
 };
+use move_core_types::account_address::AccountAddress;
+use move_core_types::language_storage::StructTag;
+use moveos_types::moveos_std::object_id::ObjectID;
+use std::collections::HashMap;
 use std::ops::DerefMut;
 
-use crate::models::events::StoredEvent;
+use crate::models::account_stats::{StoredAccountStats, StoredObjectTypeCount, StoredStateUsage};
+use crate::models::address_mappings::StoredAddressMapping;
+use crate::models::coin_infos::StoredCoinInfo;
+use crate::models::coin_transfers::StoredCoinTransfer;
+use crate::models::events::{StoredEvent, StoredEventBloom};
+use crate::models::modules::StoredModule;
+use crate::models::object_ownership::StoredObjectOwnershipChange;
 use crate::models::states::{StoredGlobalState, StoredTableChangeSet, StoredTableState};
 use crate::models::transactions::StoredTransaction;
+use crate::models::utxos::StoredUTXOState;
 use crate::schema::global_states;
-use crate::schema::{events, table_change_sets, table_states, transactions};
-use crate::utils::format_struct_tag;
+use crate::schema::{
+    account_stats, address_mappings, coin_infos, event_blooms, events, object_ownership_changes,
+    table_change_sets, table_states, transactions, utxos,
+};
+use crate::utils::{escape_sql_string, format_fts_query, format_struct_tag};
+use rooch_types::address::MultiChainAddress;
+use rooch_types::indexer::account::{
+    IndexerAccountSummary, IndexerAddressMapping, IndexerStorageUsage,
+};
+use rooch_types::indexer::coin_transfer::IndexerCoinTransfer;
 use rooch_types::indexer::event_filter::{EventFilter, IndexerEvent, IndexerEventID};
+use rooch_types::indexer::module::{IndexerModule, ModuleFilter};
 use rooch_types::indexer::state::{
-    GlobalStateFilter, IndexerGlobalState, IndexerStateID, IndexerTableChangeSet,
-    IndexerTableState, StateSyncFilter, TableStateFilter,
+    GlobalStateFilter, IndexerCoinInfo, IndexerGlobalState, IndexerObjectOwnershipChange,
+    IndexerStateID, IndexerTableChangeSet, IndexerTableState, IndexerUTXOState, StateSyncFilter,
+    TableStateFilter, UTXOFilter,
 };
 use rooch_types::indexer::transaction_filter::TransactionFilter;
 use rooch_types::transaction::TransactionWithInfo;
@@ -43,6 +66,16 @@ pub const STATE_INDEX_STR: &str = "state_index";
 pub const STATE_OBJECT_TYPE_STR: &str = "object_type";
 pub const STATE_OWNER_STR: &str = "owner";
 
+pub const MODULE_ADDRESS_STR: &str = "address";
+
+pub const COIN_STORE_ID_STR: &str = "coin_store_id";
+
+/// The number of the event blooms loaded in a batch when scanning the transactions of an event type
+pub const EVENT_BLOOM_BATCH_SIZE: i64 = 1000;
+
+pub const UTXO_TXID_STR: &str = "txid";
+pub const UTXO_VOUT_STR: &str = "vout";
+
 #[derive(Clone)]
 pub(crate) struct InnerIndexerReader {
     pool: crate::SqliteConnectionPool,
@@ -194,7 +227,7 @@ impl IndexerReader {
             main_where_clause, cursor_clause, order_clause, limit,
         );
 
-        tracing::debug!("query transactions: {}", query);
+        tracing::debug!(target: LOG_TARGET, "query transactions: {}", query);
         let stored_transactions = self
             .inner_indexer_reader
             .run_query(|conn| diesel::sql_query(query).load::<StoredTransaction>(conn))?;
@@ -236,7 +269,153 @@ impl IndexerReader {
             (-1, 0)
         };
 
-        let main_where_clause = match filter {
+        let bloom_event_type = Self::bloom_event_type(&filter);
+        let main_where_clause = Self::event_filter_where_clause(filter);
+
+        let stored_events = match bloom_event_type {
+            Some(event_type) => self.query_events_with_bloom(
+                &event_type,
+                &main_where_clause,
+                (tx_order, event_index),
+                limit,
+                descending_order,
+            )?,
+            None => self.query_stored_events(
+                &main_where_clause,
+                (tx_order, event_index),
+                limit,
+                descending_order,
+            )?,
+        };
+
+        let result = stored_events
+            .into_iter()
+            .map(|ev| ev.try_into_indexer_event())
+            .collect::<Result<Vec<_>>>()
+            .map_err(|e| {
+                IndexerError::SQLiteReadError(format!("Cast indexer events failed: {:?}", e))
+            })?;
+
+        Ok(result)
+    }
+
+    fn query_stored_events(
+        &self,
+        where_clause: &str,
+        (tx_order, event_index): (i64, i64),
+        limit: usize,
+        descending_order: bool,
+    ) -> IndexerResult<Vec<StoredEvent>> {
+        let cursor_clause = if descending_order {
+            format!(
+                "AND ({TX_ORDER_STR} < {} OR ({TX_ORDER_STR} = {} AND {EVENT_INDEX_STR} < {}))",
+                tx_order, tx_order, event_index
+            )
+        } else {
+            format!(
+                "AND ({TX_ORDER_STR} > {} OR ({TX_ORDER_STR} = {} AND {EVENT_INDEX_STR} > {}))",
+                tx_order, tx_order, event_index
+            )
+        };
+        let order_clause = if descending_order {
+            format!("{TX_ORDER_STR} DESC, {EVENT_INDEX_STR} DESC")
+        } else {
+            format!("{TX_ORDER_STR} ASC, {EVENT_INDEX_STR} ASC")
+        };
+
+        let query = format!(
+            "
+                SELECT * FROM events \
+                WHERE {} {} \
+                ORDER BY {} \
+                LIMIT {}
+            ",
+            where_clause, cursor_clause, order_clause, limit,
+        );
+
+        tracing::debug!(target: LOG_TARGET, "query events: {}", query);
+        self.inner_indexer_reader
+            .run_query(|conn| diesel::sql_query(query).load::<StoredEvent>(conn))
+    }
+
+    /// Query the events of the event type, the transactions are scanned by the event blooms in batches,
+    /// and only the events of the transactions whose bloom may contain the event type are loaded.
+    fn query_events_with_bloom(
+        &self,
+        event_type: &str,
+        where_clause: &str,
+        cursor: (i64, i64),
+        limit: usize,
+        descending_order: bool,
+    ) -> IndexerResult<Vec<StoredEvent>> {
+        let mut result = vec![];
+        // The events of the cursor transaction after the cursor event index are not returned yet
+        let mut bloom_cursor = cursor.0;
+        let mut include_cursor = true;
+        while result.len() < limit {
+            let blooms = self.inner_indexer_reader.run_query(|conn| {
+                let query = event_blooms::table.into_boxed();
+                let query = match (descending_order, include_cursor) {
+                    (true, true) => query.filter(event_blooms::tx_order.le(bloom_cursor)),
+                    (true, false) => query.filter(event_blooms::tx_order.lt(bloom_cursor)),
+                    (false, true) => query.filter(event_blooms::tx_order.ge(bloom_cursor)),
+                    (false, false) => query.filter(event_blooms::tx_order.gt(bloom_cursor)),
+                };
+                let query = if descending_order {
+                    query.order_by(event_blooms::tx_order.desc())
+                } else {
+                    query.order_by(event_blooms::tx_order.asc())
+                };
+                query
+                    .limit(EVENT_BLOOM_BATCH_SIZE)
+                    .load::<StoredEventBloom>(conn)
+            })?;
+            let Some(last_bloom) = blooms.last() else {
+                break;
+            };
+            bloom_cursor = last_bloom.tx_order;
+            include_cursor = false;
+            let exhausted = (blooms.len() as i64) < EVENT_BLOOM_BATCH_SIZE;
+
+            let candidates = blooms
+                .iter()
+                .filter(|bloom| bloom.may_contain(event_type))
+                .map(|bloom| bloom.tx_order.to_string())
+                .collect::<Vec<_>>();
+            if !candidates.is_empty() {
+                let candidates_clause = format!(
+                    "{} AND {TX_ORDER_STR} IN ({})",
+                    where_clause,
+                    candidates.join(",")
+                );
+                result.extend(self.query_stored_events(
+                    &candidates_clause,
+                    cursor,
+                    limit - result.len(),
+                    descending_order,
+                )?);
+            }
+            if exhausted {
+                break;
+            }
+        }
+        Ok(result)
+    }
+
+    /// The event type to filter the transactions by the event blooms, the event type filter
+    /// may be combined with the other filters.
+    fn bloom_event_type(filter: &EventFilter) -> Option<String> {
+        match filter {
+            EventFilter::EventType(struct_tag) => {
+                Some(format!("0x{}", struct_tag.to_canonical_string()))
+            }
+            EventFilter::All(filters) => filters.iter().find_map(Self::bloom_event_type),
+            _ => None,
+        }
+    }
+
+    fn event_filter_where_clause(filter: EventFilter) -> String {
+        match filter {
             EventFilter::EventType(struct_tag) => {
                 let event_type_str = format!("0x{}", struct_tag.to_canonical_string());
                 format!("{EVENT_TYPE_STR} = \"{}\"", event_type_str)
@@ -248,6 +427,17 @@ impl IndexerReader {
                 let tx_hash_str = format!("{:?}", tx_hash);
                 format!("{TX_HASH_STR} = \"{}\"", tx_hash_str)
             }
+            EventFilter::TxEventIndexRange {
+                tx_hash,
+                from_index,
+                to_index,
+            } => {
+                let tx_hash_str = format!("{:?}", tx_hash);
+                format!(
+                    "({TX_HASH_STR} = \"{}\" AND {EVENT_INDEX_STR} >= {} AND {EVENT_INDEX_STR} < {})",
+                    tx_hash_str, from_index, to_index
+                )
+            }
             EventFilter::TimeRange {
                 start_time,
                 end_time,
@@ -266,39 +456,130 @@ impl IndexerReader {
                     from_order, to_order
                 )
             }
-        };
+            EventFilter::All(filters) => {
+                if filters.is_empty() {
+                    // An empty combination matches all events
+                    "1 = 1".to_string()
+                } else {
+                    let clauses = filters
+                        .into_iter()
+                        .map(Self::event_filter_where_clause)
+                        .collect::<Vec<_>>();
+                    format!("({})", clauses.join(" AND "))
+                }
+            }
+        }
+    }
 
-        let cursor_clause = if descending_order {
-            format!(
-                "AND ({TX_ORDER_STR} < {} OR ({TX_ORDER_STR} = {} AND {EVENT_INDEX_STR} < {}))",
-                tx_order, tx_order, event_index
-            )
+    /// Full-text search the transactions by the action, the function id and the type arguments of the function call
+    pub fn search_transactions(
+        &self,
+        query: String,
+        cursor: Option<u64>,
+        limit: usize,
+        descending_order: bool,
+    ) -> IndexerResult<Vec<TransactionWithInfo>> {
+        let match_query = format_fts_query(&query);
+        if match_query.is_empty() {
+            return Err(IndexerError::GenericError(
+                "The search query should not be empty".to_string(),
+            ));
+        }
+
+        let cursor_clause = match cursor {
+            Some(cursor) if descending_order => {
+                format!("AND (transactions.{TX_ORDER_STR} < {})", cursor)
+            }
+            Some(cursor) => format!("AND (transactions.{TX_ORDER_STR} > {})", cursor),
+            None => "".to_string(),
+        };
+        let order_clause = if descending_order {
+            format!("transactions.{TX_ORDER_STR} DESC")
         } else {
-            format!(
-                "AND ({TX_ORDER_STR} > {} OR ({TX_ORDER_STR} = {} AND {EVENT_INDEX_STR} > {}))",
-                tx_order, tx_order, event_index
-            )
+            format!("transactions.{TX_ORDER_STR} ASC")
+        };
+
+        let query = format!(
+            "
+                SELECT transactions.* FROM transactions_fts \
+                JOIN transactions ON transactions.{TX_ORDER_STR} = transactions_fts.{TX_ORDER_STR} \
+                WHERE transactions_fts MATCH ? {} \
+                ORDER BY {} \
+                LIMIT {}
+            ",
+            cursor_clause, order_clause, limit,
+        );
+
+        tracing::debug!(target: LOG_TARGET, "search transactions: {}, match: {}", query, match_query);
+        let stored_transactions = self.inner_indexer_reader.run_query(|conn| {
+            diesel::sql_query(query)
+                .bind::<diesel::sql_types::Text, _>(match_query)
+                .load::<StoredTransaction>(conn)
+        })?;
+
+        let result = stored_transactions
+            .into_iter()
+            .map(|t| t.try_into_transaction_with_info())
+            .collect::<Result<Vec<_>>>()
+            .map_err(|e| {
+                IndexerError::SQLiteReadError(format!("Cast indexer transactions failed: {:?}", e))
+            })?;
+
+        Ok(result)
+    }
+
+    /// Full-text search the events by the event type and the decoded event data
+    pub fn search_events(
+        &self,
+        query: String,
+        cursor: Option<IndexerEventID>,
+        limit: usize,
+        descending_order: bool,
+    ) -> IndexerResult<Vec<IndexerEvent>> {
+        let match_query = format_fts_query(&query);
+        if match_query.is_empty() {
+            return Err(IndexerError::GenericError(
+                "The search query should not be empty".to_string(),
+            ));
+        }
+
+        let cursor_clause = match cursor {
+            Some(IndexerEventID {
+                tx_order,
+                event_index,
+            }) => {
+                let op = if descending_order { "<" } else { ">" };
+                format!(
+                    "AND (events.{TX_ORDER_STR} {op} {} OR (events.{TX_ORDER_STR} = {} AND events.{EVENT_INDEX_STR} {op} {}))",
+                    tx_order, tx_order, event_index
+                )
+            }
+            None => "".to_string(),
         };
         let order_clause = if descending_order {
-            format!("{TX_ORDER_STR} DESC, {EVENT_INDEX_STR} DESC")
+            format!("events.{TX_ORDER_STR} DESC, events.{EVENT_INDEX_STR} DESC")
         } else {
-            format!("{TX_ORDER_STR} ASC, {EVENT_INDEX_STR} ASC")
+            format!("events.{TX_ORDER_STR} ASC, events.{EVENT_INDEX_STR} ASC")
         };
 
         let query = format!(
             "
-                SELECT * FROM events \
-                WHERE {} {} \
+                SELECT events.* FROM events_fts \
+                JOIN events ON events.{TX_ORDER_STR} = events_fts.{TX_ORDER_STR} \
+                AND events.{EVENT_INDEX_STR} = events_fts.{EVENT_INDEX_STR} \
+                WHERE events_fts MATCH ? {} \
                 ORDER BY {} \
                 LIMIT {}
             ",
-            main_where_clause, cursor_clause, order_clause, limit,
+            cursor_clause, order_clause, limit,
         );
 
-        tracing::debug!("query events: {}", query);
-        let stored_events = self
-            .inner_indexer_reader
-            .run_query(|conn| diesel::sql_query(query).load::<StoredEvent>(conn))?;
+        tracing::debug!(target: LOG_TARGET, "search events: {}, match: {}", query, match_query);
+        let stored_events = self.inner_indexer_reader.run_query(|conn| {
+            diesel::sql_query(query)
+                .bind::<diesel::sql_types::Text, _>(match_query)
+                .load::<StoredEvent>(conn)
+        })?;
 
         let result = stored_events
             .into_iter()
@@ -318,28 +599,6 @@ impl IndexerReader {
         limit: usize,
         descending_order: bool,
     ) -> IndexerResult<Vec<IndexerGlobalState>> {
-        let (tx_order, state_index) = if let Some(cursor) = cursor {
-            let IndexerStateID {
-                tx_order,
-                state_index,
-            } = cursor;
-            (tx_order as i64, state_index as i64)
-        } else if descending_order {
-            let (max_tx_order, state_index): (i64, i64) =
-                self.inner_indexer_reader.run_query(|conn| {
-                    global_states::dsl::global_states
-                        .select((global_states::tx_order, global_states::state_index))
-                        .order_by((
-                            global_states::tx_order.desc(),
-                            global_states::state_index.desc(),
-                        ))
-                        .first::<(i64, i64)>(conn)
-                })?;
-            (max_tx_order + 1, state_index)
-        } else {
-            (-1, 0)
-        };
-
         let main_where_clause = match filter {
             GlobalStateFilter::ObjectTypeWithOwner { object_type, owner } => {
                 let object_type_str = format_struct_tag(object_type);
@@ -360,17 +619,335 @@ impl IndexerReader {
                 format!("{OBJECT_ID_STR} = \"{}\"", object_id)
             }
         };
+        let order_clause = if descending_order {
+            format!("{TX_ORDER_STR} DESC, {STATE_INDEX_STR} DESC")
+        } else {
+            format!("{TX_ORDER_STR} ASC, {STATE_INDEX_STR} ASC")
+        };
 
-        let cursor_clause = if descending_order {
-            format!(
-                "AND ({TX_ORDER_STR} < {} OR ({TX_ORDER_STR} = {} AND {STATE_INDEX_STR} < {}))",
-                tx_order, tx_order, state_index
+        // Resolve the start cursor and load the page in the same read transaction,
+        // so the page is deterministic even if new states are being written.
+        let stored_states = self.inner_indexer_reader.run_query(|conn| {
+            let (tx_order, state_index) = if let Some(cursor) = cursor {
+                let IndexerStateID {
+                    tx_order,
+                    state_index,
+                } = cursor;
+                (tx_order as i64, state_index as i64)
+            } else if descending_order {
+                let (max_tx_order, state_index): (i64, i64) = global_states::dsl::global_states
+                    .select((global_states::tx_order, global_states::state_index))
+                    .order_by((
+                        global_states::tx_order.desc(),
+                        global_states::state_index.desc(),
+                    ))
+                    .first::<(i64, i64)>(conn)?;
+                (max_tx_order + 1, state_index)
+            } else {
+                (-1, 0)
+            };
+
+            let cursor_clause = if descending_order {
+                format!(
+                    "AND ({TX_ORDER_STR} < {} OR ({TX_ORDER_STR} = {} AND {STATE_INDEX_STR} < {}))",
+                    tx_order, tx_order, state_index
+                )
+            } else {
+                format!(
+                    "AND ({TX_ORDER_STR} > {} OR ({TX_ORDER_STR} = {} AND {STATE_INDEX_STR} > {}))",
+                    tx_order, tx_order, state_index
+                )
+            };
+
+            let query = format!(
+                "
+                    SELECT * FROM global_states \
+                    WHERE {} {} \
+                    ORDER BY {} \
+                    LIMIT {}
+                ",
+                main_where_clause, cursor_clause, order_clause, limit,
+            );
+
+            tracing::debug!(target: LOG_TARGET, "query global states: {}", query);
+            diesel::sql_query(query).load::<StoredGlobalState>(conn)
+        })?;
+
+        let result = stored_states
+            .into_iter()
+            .map(|v| v.try_into_indexer_global_state())
+            .collect::<Result<Vec<_>>>()
+            .map_err(|e| {
+                IndexerError::SQLiteReadError(format!("Cast indexer global states failed: {:?}", e))
+            })?;
+
+        Ok(result)
+    }
+
+    /// Get the coin infos by the coin types, the result is in the same order as the coin types
+    pub fn get_coin_infos(
+        &self,
+        coin_types: Vec<StructTag>,
+    ) -> IndexerResult<Vec<Option<IndexerCoinInfo>>> {
+        let coin_type_strs = coin_types
+            .iter()
+            .map(|coin_type| coin_type.to_canonical_string())
+            .collect::<Vec<_>>();
+        let stored_coin_infos = self.inner_indexer_reader.run_query(|conn| {
+            coin_infos::dsl::coin_infos
+                .filter(coin_infos::coin_type.eq_any(coin_type_strs.clone()))
+                .load::<StoredCoinInfo>(conn)
+        })?;
+
+        let mut coin_info_map = stored_coin_infos
+            .into_iter()
+            .map(|v| Ok((v.coin_type.clone(), v.try_into_indexer_coin_info()?)))
+            .collect::<Result<HashMap<_, _>>>()
+            .map_err(|e| {
+                IndexerError::SQLiteReadError(format!("Cast indexer coin infos failed: {:?}", e))
+            })?;
+
+        Ok(coin_type_strs
+            .iter()
+            .map(|coin_type| coin_info_map.remove(coin_type))
+            .collect())
+    }
+
+    /// Get the account summary, the transaction statistics are read from the account stats
+    /// and the owned objects are counted by object type on the global states.
+    pub fn get_account_summary(
+        &self,
+        address: AccountAddress,
+    ) -> IndexerResult<IndexerAccountSummary> {
+        let address_str = address.to_hex_literal();
+        let stored_account_stats = self.inner_indexer_reader.run_query(|conn| {
+            account_stats::dsl::account_stats
+                .filter(account_stats::address.eq(address_str.clone()))
+                .first::<StoredAccountStats>(conn)
+                .optional()
+        })?;
+
+        let object_counts_query = format!(
+            "SELECT {}, COUNT(*) AS count FROM global_states WHERE {} = '{}' GROUP BY {} ORDER BY {}",
+            STATE_OBJECT_TYPE_STR,
+            STATE_OWNER_STR,
+            escape_sql_string(address_str),
+            STATE_OBJECT_TYPE_STR,
+            STATE_OBJECT_TYPE_STR,
+        );
+        tracing::debug!(target: LOG_TARGET, "get account summary object counts: {}", object_counts_query);
+        let object_counts = self
+            .inner_indexer_reader
+            .run_query(|conn| {
+                diesel::sql_query(object_counts_query).load::<StoredObjectTypeCount>(conn)
+            })?
+            .iter()
+            .map(|v| v.try_into_indexer_object_type_count())
+            .collect::<Result<Vec<_>>>()
+            .map_err(|e| {
+                IndexerError::SQLiteReadError(format!(
+                    "Cast indexer object type counts failed: {:?}",
+                    e
+                ))
+            })?;
+
+        Ok(IndexerAccountSummary {
+            address,
+            tx_count: stored_account_stats
+                .as_ref()
+                .map_or(0, |stats| stats.tx_count as u64),
+            gas_used: stored_account_stats
+                .as_ref()
+                .map_or(0, |stats| stats.gas_used as u64),
+            first_tx_order: stored_account_stats
+                .as_ref()
+                .map(|stats| stats.first_tx_order as u64),
+            last_tx_order: stored_account_stats
+                .as_ref()
+                .map(|stats| stats.last_tx_order as u64),
+            first_active_at: stored_account_stats
+                .as_ref()
+                .and_then(|stats| stats.first_active_at.map(|v| v as u64)),
+            last_active_at: stored_account_stats
+                .as_ref()
+                .and_then(|stats| stats.last_active_at.map(|v| v as u64)),
+            object_counts,
+        })
+    }
+
+    /// Get the storage usage of the account, the objects owned by the account
+    /// and the items of the tables of them are accounted by the state sizes.
+    pub fn get_storage_usage(&self, address: AccountAddress) -> IndexerResult<IndexerStorageUsage> {
+        let address_str = address.to_hex_literal();
+        let object_usage = self.inner_indexer_reader.run_query(|conn| {
+            diesel::sql_query(
+                "SELECT COUNT(*) AS count, COALESCE(SUM(state_size), 0) AS bytes FROM global_states WHERE owner = ?",
             )
-        } else {
-            format!(
-                "AND ({TX_ORDER_STR} > {} OR ({TX_ORDER_STR} = {} AND {STATE_INDEX_STR} > {}))",
-                tx_order, tx_order, state_index
+            .bind::<diesel::sql_types::Text, _>(address_str.clone())
+            .get_result::<StoredStateUsage>(conn)
+        })?;
+        let table_item_usage = self.inner_indexer_reader.run_query(|conn| {
+            diesel::sql_query(
+                "
+                    SELECT COUNT(*) AS count, COALESCE(SUM(table_states.state_size), 0) AS bytes \
+                    FROM table_states JOIN global_states ON table_states.table_handle = global_states.object_id \
+                    WHERE global_states.owner = ?
+                ",
             )
+            .bind::<diesel::sql_types::Text, _>(address_str.clone())
+            .get_result::<StoredStateUsage>(conn)
+        })?;
+
+        Ok(IndexerStorageUsage {
+            address,
+            object_count: object_usage.count as u64,
+            object_bytes: object_usage.bytes as u64,
+            table_item_count: table_item_usage.count as u64,
+            table_item_bytes: table_item_usage.bytes as u64,
+        })
+    }
+
+    /// Get the owner transitions of the object, `cursor` is the exclusive tx order
+    pub fn get_object_ownership_history(
+        &self,
+        object_id: ObjectID,
+        cursor: Option<u64>,
+        limit: usize,
+        descending_order: bool,
+    ) -> IndexerResult<Vec<IndexerObjectOwnershipChange>> {
+        let object_id_str = object_id.to_string();
+        let stored_changes = self.inner_indexer_reader.run_query(|conn| {
+            let mut query = object_ownership_changes::dsl::object_ownership_changes
+                .filter(object_ownership_changes::object_id.eq(object_id_str.clone()))
+                .into_boxed();
+            if let Some(cursor) = cursor {
+                query = if descending_order {
+                    query.filter(object_ownership_changes::tx_order.lt(cursor as i64))
+                } else {
+                    query.filter(object_ownership_changes::tx_order.gt(cursor as i64))
+                };
+            }
+            query = if descending_order {
+                query.order_by(object_ownership_changes::tx_order.desc())
+            } else {
+                query.order_by(object_ownership_changes::tx_order.asc())
+            };
+            query
+                .limit(limit as i64)
+                .load::<StoredObjectOwnershipChange>(conn)
+        })?;
+
+        let result = stored_changes
+            .iter()
+            .map(|v| v.try_into_indexer_object_ownership_change())
+            .collect::<Result<Vec<_>>>()
+            .map_err(|e| {
+                IndexerError::SQLiteReadError(format!(
+                    "Cast indexer object ownership changes failed: {:?}",
+                    e
+                ))
+            })?;
+
+        Ok(result)
+    }
+
+    /// Get the Rooch address mapping of the multichain address, `None` if the address is not bound
+    pub fn get_address_mapping(
+        &self,
+        multichain_address: MultiChainAddress,
+    ) -> IndexerResult<Option<IndexerAddressMapping>> {
+        let multichain_id = multichain_address.multichain_id.multichain_id().id() as i64;
+        let raw_address = hex::encode(&multichain_address.raw_address);
+        let stored_mapping = self.inner_indexer_reader.run_query(|conn| {
+            address_mappings::dsl::address_mappings
+                .filter(address_mappings::multichain_id.eq(multichain_id))
+                .filter(address_mappings::raw_address.eq(raw_address.clone()))
+                .first::<StoredAddressMapping>(conn)
+                .optional()
+        })?;
+
+        stored_mapping
+            .map(|v| v.try_into_indexer_address_mapping())
+            .transpose()
+            .map_err(|e| {
+                IndexerError::SQLiteReadError(format!(
+                    "Cast indexer address mapping failed: {:?}",
+                    e
+                ))
+            })
+    }
+
+    /// Get the multichain addresses mapped to the Rooch address, ordered by the tx order of the binding
+    pub fn get_address_mappings_by_rooch_address(
+        &self,
+        rooch_address: AccountAddress,
+    ) -> IndexerResult<Vec<IndexerAddressMapping>> {
+        let rooch_address_str = rooch_address.to_hex_literal();
+        let stored_mappings = self.inner_indexer_reader.run_query(|conn| {
+            address_mappings::dsl::address_mappings
+                .filter(address_mappings::rooch_address.eq(rooch_address_str.clone()))
+                .order_by((
+                    address_mappings::tx_order.asc(),
+                    address_mappings::multichain_id.asc(),
+                ))
+                .load::<StoredAddressMapping>(conn)
+        })?;
+
+        let result = stored_mappings
+            .iter()
+            .map(|v| v.try_into_indexer_address_mapping())
+            .collect::<Result<Vec<_>>>()
+            .map_err(|e| {
+                IndexerError::SQLiteReadError(format!(
+                    "Cast indexer address mappings failed: {:?}",
+                    e
+                ))
+            })?;
+
+        Ok(result)
+    }
+
+    /// Query the published modules, ordered by the tx order and the state index of the last publication,
+    /// so the recently published or upgraded modules come first in descending order.
+    pub fn query_modules_with_filter(
+        &self,
+        filter: ModuleFilter,
+        cursor: Option<IndexerStateID>,
+        limit: usize,
+        descending_order: bool,
+    ) -> IndexerResult<Vec<IndexerModule>> {
+        let main_where_clause = match filter {
+            ModuleFilter::Publisher(publisher) => {
+                format!("{MODULE_ADDRESS_STR} = \"{}\"", publisher.to_hex_literal())
+            }
+            ModuleFilter::TimeRange {
+                start_time,
+                end_time,
+            } => {
+                format!(
+                    "({CREATED_AT_STR} >= {} AND {CREATED_AT_STR} < {})",
+                    start_time, end_time
+                )
+            }
+        };
+        let cursor_clause = match cursor {
+            Some(IndexerStateID {
+                tx_order,
+                state_index,
+            }) => {
+                if descending_order {
+                    format!(
+                        "AND ({TX_ORDER_STR} < {} OR ({TX_ORDER_STR} = {} AND {STATE_INDEX_STR} < {}))",
+                        tx_order, tx_order, state_index
+                    )
+                } else {
+                    format!(
+                        "AND ({TX_ORDER_STR} > {} OR ({TX_ORDER_STR} = {} AND {STATE_INDEX_STR} > {}))",
+                        tx_order, tx_order, state_index
+                    )
+                }
+            }
+            None => "".to_string(),
         };
         let order_clause = if descending_order {
             format!("{TX_ORDER_STR} DESC, {STATE_INDEX_STR} DESC")
@@ -380,7 +957,7 @@ impl IndexerReader {
 
         let query = format!(
             "
-                SELECT * FROM global_states \
+                SELECT * FROM modules \
                 WHERE {} {} \
                 ORDER BY {} \
                 LIMIT {}
@@ -388,17 +965,167 @@ impl IndexerReader {
             main_where_clause, cursor_clause, order_clause, limit,
         );
 
-        tracing::debug!("query global states: {}", query);
-        let stored_states = self
+        tracing::debug!(target: LOG_TARGET, "query modules: {}", query);
+        let stored_modules = self
             .inner_indexer_reader
-            .run_query(|conn| diesel::sql_query(query).load::<StoredGlobalState>(conn))?;
+            .run_query(|conn| diesel::sql_query(query).load::<StoredModule>(conn))?;
 
-        let result = stored_states
+        let result = stored_modules
             .into_iter()
-            .map(|v| v.try_into_indexer_global_state())
+            .map(|v| v.try_into_indexer_module())
             .collect::<Result<Vec<_>>>()
             .map_err(|e| {
-                IndexerError::SQLiteReadError(format!("Cast indexer global states failed: {:?}", e))
+                IndexerError::SQLiteReadError(format!("Cast indexer modules failed: {:?}", e))
+            })?;
+
+        Ok(result)
+    }
+
+    /// Query the deposits and withdrawals of the coin store, ordered by the tx order and the event index
+    pub fn query_coin_transfers(
+        &self,
+        coin_store_id: ObjectID,
+        cursor: Option<IndexerEventID>,
+        limit: usize,
+        descending_order: bool,
+    ) -> IndexerResult<Vec<IndexerCoinTransfer>> {
+        let cursor_clause = match cursor {
+            Some(IndexerEventID {
+                tx_order,
+                event_index,
+            }) => {
+                if descending_order {
+                    format!(
+                        "AND ({TX_ORDER_STR} < {} OR ({TX_ORDER_STR} = {} AND {EVENT_INDEX_STR} < {}))",
+                        tx_order, tx_order, event_index
+                    )
+                } else {
+                    format!(
+                        "AND ({TX_ORDER_STR} > {} OR ({TX_ORDER_STR} = {} AND {EVENT_INDEX_STR} > {}))",
+                        tx_order, tx_order, event_index
+                    )
+                }
+            }
+            None => "".to_string(),
+        };
+        let order_clause = if descending_order {
+            format!("{TX_ORDER_STR} DESC, {EVENT_INDEX_STR} DESC")
+        } else {
+            format!("{TX_ORDER_STR} ASC, {EVENT_INDEX_STR} ASC")
+        };
+
+        let query = format!(
+            "
+                SELECT * FROM coin_transfers \
+                WHERE {COIN_STORE_ID_STR} = \"{}\" {} \
+                ORDER BY {} \
+                LIMIT {}
+            ",
+            coin_store_id, cursor_clause, order_clause, limit,
+        );
+
+        tracing::debug!(target: LOG_TARGET, "query coin transfers: {}", query);
+        let stored_transfers = self
+            .inner_indexer_reader
+            .run_query(|conn| diesel::sql_query(query).load::<StoredCoinTransfer>(conn))?;
+
+        let result = stored_transfers
+            .into_iter()
+            .map(|v| v.try_into_indexer_coin_transfer())
+            .collect::<Result<Vec<_>>>()
+            .map_err(|e| {
+                IndexerError::SQLiteReadError(format!(
+                    "Cast indexer coin transfers failed: {:?}",
+                    e
+                ))
+            })?;
+
+        Ok(result)
+    }
+
+    pub fn query_utxos_with_filter(
+        &self,
+        filter: UTXOFilter,
+        cursor: Option<IndexerStateID>,
+        limit: usize,
+        descending_order: bool,
+    ) -> IndexerResult<Vec<IndexerUTXOState>> {
+        let main_where_clause = match filter {
+            UTXOFilter::Owner(owner) => {
+                format!("{STATE_OWNER_STR} = \"{}\"", owner.to_hex_literal())
+            }
+            UTXOFilter::OutPoint { txid, vout } => {
+                format!(
+                    "{UTXO_TXID_STR} = \"{}\" AND {UTXO_VOUT_STR} = {}",
+                    txid.to_hex_literal(),
+                    vout
+                )
+            }
+            UTXOFilter::ObjectId(object_id) => {
+                format!("{OBJECT_ID_STR} = \"{}\"", object_id)
+            }
+            UTXOFilter::All => "1 = 1".to_string(),
+        };
+        let order_clause = if descending_order {
+            format!("{TX_ORDER_STR} DESC, {STATE_INDEX_STR} DESC")
+        } else {
+            format!("{TX_ORDER_STR} ASC, {STATE_INDEX_STR} ASC")
+        };
+
+        let stored_utxos = self.inner_indexer_reader.run_query(|conn| {
+            let (tx_order, state_index) = if let Some(cursor) = cursor {
+                let IndexerStateID {
+                    tx_order,
+                    state_index,
+                } = cursor;
+                (tx_order as i64, state_index as i64)
+            } else if descending_order {
+                // The utxos table is empty until the bitcoin relayer syncs a block
+                let max_state_id = utxos::dsl::utxos
+                    .select((utxos::tx_order, utxos::state_index))
+                    .order_by((utxos::tx_order.desc(), utxos::state_index.desc()))
+                    .first::<(i64, i64)>(conn)
+                    .optional()?;
+                match max_state_id {
+                    Some((max_tx_order, state_index)) => (max_tx_order + 1, state_index),
+                    None => (0, 0),
+                }
+            } else {
+                (-1, 0)
+            };
+
+            let cursor_clause = if descending_order {
+                format!(
+                    "AND ({TX_ORDER_STR} < {} OR ({TX_ORDER_STR} = {} AND {STATE_INDEX_STR} < {}))",
+                    tx_order, tx_order, state_index
+                )
+            } else {
+                format!(
+                    "AND ({TX_ORDER_STR} > {} OR ({TX_ORDER_STR} = {} AND {STATE_INDEX_STR} > {}))",
+                    tx_order, tx_order, state_index
+                )
+            };
+
+            let query = format!(
+                "
+                    SELECT * FROM utxos \
+                    WHERE {} {} \
+                    ORDER BY {} \
+                    LIMIT {}
+                ",
+                main_where_clause, cursor_clause, order_clause, limit,
+            );
+
+            tracing::debug!(target: LOG_TARGET, "query utxos: {}", query);
+            diesel::sql_query(query).load::<StoredUTXOState>(conn)
+        })?;
+
+        let result = stored_utxos
+            .into_iter()
+            .map(|v| v.try_into_indexer_utxo_state())
+            .collect::<Result<Vec<_>>>()
+            .map_err(|e| {
+                IndexerError::SQLiteReadError(format!("Cast indexer utxo states failed: {:?}", e))
             })?;
 
         Ok(result)
@@ -466,7 +1193,7 @@ impl IndexerReader {
             main_where_clause, cursor_clause, order_clause, limit,
         );
 
-        tracing::debug!("query table states: {}", query);
+        tracing::debug!(target: LOG_TARGET, "query table states: {}", query);
         let stored_states = self
             .inner_indexer_reader
             .run_query(|conn| diesel::sql_query(query).load::<StoredTableState>(conn))?;
@@ -549,7 +1276,7 @@ impl IndexerReader {
             where_clause, order_clause, limit,
         );
 
-        tracing::debug!("sync states: {}", query);
+        tracing::debug!(target: LOG_TARGET, "sync states: {}", query);
         let stored_table_change_sets = self
             .inner_indexer_reader
             .run_query(|conn| diesel::sql_query(query).load::<StoredTableChangeSet>(conn))?;
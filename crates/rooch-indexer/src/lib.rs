@@ -1,6 +1,7 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
 
+use std::collections::BTreeMap;
 use std::fmt::{Debug, Display, Formatter};
 use std::time::Duration;
 
@@ -8,18 +9,22 @@ use anyhow::Result;
 use diesel::r2d2::ConnectionManager;
 use diesel::sqlite::SqliteConnection;
 
+use crate::store::batch::IndexerBatch;
 use crate::store::sqlite_store::SqliteIndexerStore;
 use crate::store::traits::IndexerStoreTrait;
 use crate::types::{
-    IndexedEvent, IndexedGlobalState, IndexedTableChangeSet, IndexedTableState, IndexedTransaction,
+    IndexedCoinInfo, IndexedEvent, IndexedGlobalState, IndexedTableChangeSet, IndexedTableState,
+    IndexedTransaction, IndexedUTXOState, IndexerProgressTable,
 };
 use crate::utils::create_all_tables_if_not_exists;
 use errors::IndexerError;
-use rooch_config::indexer_config::ROOCH_INDEXER_DB_FILENAME;
+use rooch_config::indexer_config::{IndexerRetention, ROOCH_INDEXER_DB_FILENAME};
+use rooch_types::indexer::state::IndexerTableChangeSet;
 
 pub mod actor;
 pub mod errors;
 pub mod indexer_reader;
+pub mod metrics;
 pub mod models;
 pub mod proxy;
 pub mod schema;
@@ -29,6 +34,11 @@ mod tests;
 pub mod types;
 pub mod utils;
 
+/// The tracing target of the indexer logs
+pub const LOG_TARGET: &str = "rooch::indexer";
+/// The max number of rows deleted in one statement when pruning the indexed history
+pub const DEFAULT_PRUNE_BATCH_SIZE: u64 = 10000;
+
 pub type SqliteConnectionPool = diesel::r2d2::Pool<ConnectionManager<SqliteConnection>>;
 pub type SqlitePoolConnection = diesel::r2d2::PooledConnection<ConnectionManager<SqliteConnection>>;
 
@@ -99,6 +109,31 @@ impl IndexerStoreTrait for IndexerStore {
         self.sqlite_store.delete_global_states(state_pks)
     }
 
+    fn persist_object_ownership_changes(
+        &self,
+        tx_order: u64,
+        states: &[IndexedGlobalState],
+        removed_object_ids: &[String],
+    ) -> Result<(), IndexerError> {
+        self.sqlite_store
+            .persist_object_ownership_changes(tx_order, states, removed_object_ids)
+    }
+
+    fn persist_or_update_utxos(&self, states: Vec<IndexedUTXOState>) -> Result<(), IndexerError> {
+        self.sqlite_store.persist_or_update_utxos(states)
+    }
+
+    fn delete_utxos(&self, state_pks: Vec<String>) -> Result<(), IndexerError> {
+        self.sqlite_store.delete_utxos(state_pks)
+    }
+
+    fn persist_or_update_coin_infos(
+        &self,
+        coin_infos: Vec<IndexedCoinInfo>,
+    ) -> Result<(), IndexerError> {
+        self.sqlite_store.persist_or_update_coin_infos(coin_infos)
+    }
+
     fn persist_or_update_table_states(
         &self,
         states: Vec<IndexedTableState>,
@@ -136,6 +171,51 @@ impl IndexerStoreTrait for IndexerStore {
     fn persist_events(&self, events: Vec<IndexedEvent>) -> Result<(), IndexerError> {
         self.sqlite_store.persist_events(events)
     }
+
+    fn update_indexer_progress(
+        &self,
+        table: IndexerProgressTable,
+        tx_order: u64,
+    ) -> Result<(), IndexerError> {
+        self.sqlite_store.update_indexer_progress(table, tx_order)
+    }
+
+    fn get_indexer_progress(
+        &self,
+        table: IndexerProgressTable,
+    ) -> Result<Option<u64>, IndexerError> {
+        self.sqlite_store.get_indexer_progress(table)
+    }
+
+    fn delete_from_tx_order(&self, tx_order: u64) -> Result<(), IndexerError> {
+        self.sqlite_store.delete_from_tx_order(tx_order)
+    }
+
+    fn prune_history(
+        &self,
+        retention: &IndexerRetention,
+    ) -> Result<BTreeMap<String, u64>, IndexerError> {
+        self.sqlite_store
+            .prune_history(retention, DEFAULT_PRUNE_BATCH_SIZE)
+    }
+
+    fn get_object_owners(
+        &self,
+        object_ids: &[String],
+    ) -> Result<BTreeMap<String, String>, IndexerError> {
+        self.sqlite_store.get_object_owners(object_ids)
+    }
+
+    fn get_table_change_sets_from(
+        &self,
+        tx_order: u64,
+    ) -> Result<Vec<IndexerTableChangeSet>, IndexerError> {
+        self.sqlite_store.get_table_change_sets_from(tx_order)
+    }
+
+    fn commit_batch(&self, batch: IndexerBatch) -> Result<(), IndexerError> {
+        self.sqlite_store.commit_batch(batch)
+    }
 }
 
 pub fn new_sqlite_connection_pool_impl(
@@ -0,0 +1,123 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::types::IndexerProgressTable;
+use metrics::{
+    register, HistogramOpts, HistogramTimer, HistogramVec, Opts, PrometheusError, Registry,
+    UIntCounterVec, UIntGauge, UIntGaugeVec,
+};
+
+#[derive(Clone)]
+pub struct IndexerMetrics {
+    /// The tx order of the latest executed transaction sent to the indexer
+    pub executor_tx_order: UIntGauge,
+    /// The tx order of the latest indexed transaction, by the indexer progress table
+    pub indexer_tx_order: UIntGaugeVec,
+    /// How many transactions the indexer is behind the executor, by the indexer progress table
+    pub indexer_lag: UIntGaugeVec,
+    pub indexer_rows_written_total: UIntCounterVec,
+    pub indexer_query_latency_seconds: HistogramVec,
+}
+
+impl IndexerMetrics {
+    pub fn register(registry: &Registry) -> Result<Self, PrometheusError> {
+        let executor_tx_order = register(
+            UIntGauge::with_opts(Opts::new(
+                "executor_tx_order",
+                "The tx order of the latest executed transaction sent to the indexer",
+            ))?,
+            registry,
+        )?;
+        let indexer_tx_order = register(
+            UIntGaugeVec::new(
+                Opts::new(
+                    "indexer_tx_order",
+                    "The tx order of the latest indexed transaction",
+                ),
+                &["progress"],
+            )?,
+            registry,
+        )?;
+        let indexer_lag = register(
+            UIntGaugeVec::new(
+                Opts::new(
+                    "indexer_lag",
+                    "How many transactions the indexer is behind the executor",
+                ),
+                &["progress"],
+            )?,
+            registry,
+        )?;
+        let indexer_rows_written_total = register(
+            UIntCounterVec::new(
+                Opts::new(
+                    "indexer_rows_written_total",
+                    "Counters of how many rows the indexer writes",
+                ),
+                &["table", "op"],
+            )?,
+            registry,
+        )?;
+        let indexer_query_latency_seconds = register(
+            HistogramVec::new(
+                HistogramOpts::new(
+                    "indexer_query_latency_seconds",
+                    "Histogram of the indexer query latency in seconds",
+                ),
+                &["query"],
+            )?,
+            registry,
+        )?;
+
+        Ok(Self {
+            executor_tx_order,
+            indexer_tx_order,
+            indexer_lag,
+            indexer_rows_written_total,
+            indexer_query_latency_seconds,
+        })
+    }
+
+    /// Record the tx order of the executed transaction, it is received before the transaction is indexed
+    pub fn observe_executed(&self, tx_order: u64) {
+        if tx_order > self.executor_tx_order.get() {
+            self.executor_tx_order.set(tx_order);
+        }
+        self.update_lag();
+    }
+
+    /// Record the tx order of the transaction indexed into the progress table
+    pub fn observe_indexed(&self, progress: IndexerProgressTable, tx_order: u64) {
+        self.indexer_tx_order
+            .with_label_values(&[progress.name()])
+            .set(tx_order);
+        self.update_lag();
+    }
+
+    pub fn observe_rows_written(&self, table: &str, op: &str, rows: usize) {
+        if rows > 0 {
+            self.indexer_rows_written_total
+                .with_label_values(&[table, op])
+                .inc_by(rows as u64);
+        }
+    }
+
+    pub fn query_timer(&self, query: &str) -> HistogramTimer {
+        self.indexer_query_latency_seconds
+            .with_label_values(&[query])
+            .start_timer()
+    }
+
+    fn update_lag(&self) {
+        let executor_tx_order = self.executor_tx_order.get();
+        for progress in IndexerProgressTable::ALL {
+            let indexer_tx_order = self
+                .indexer_tx_order
+                .with_label_values(&[progress.name()])
+                .get();
+            self.indexer_lag
+                .with_label_values(&[progress.name()])
+                .set(executor_tx_order.saturating_sub(indexer_tx_order));
+        }
+    }
+}
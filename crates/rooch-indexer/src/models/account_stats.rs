@@ -0,0 +1,63 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::schema::account_stats;
+use diesel::prelude::*;
+use move_core_types::language_storage::StructTag;
+use rooch_types::indexer::account::IndexerObjectTypeCount;
+use std::str::FromStr;
+
+#[derive(Queryable, QueryableByName, Insertable, Debug, Clone)]
+#[diesel(table_name = account_stats)]
+pub struct StoredAccountStats {
+    /// The account address, the hex literal of the sender
+    #[diesel(sql_type = diesel::sql_types::Text)]
+    pub address: String,
+    /// The number of the transactions sent by the account
+    #[diesel(sql_type = diesel::sql_types::BigInt)]
+    pub tx_count: i64,
+    /// The total gas used by the transactions sent by the account
+    #[diesel(sql_type = diesel::sql_types::BigInt)]
+    pub gas_used: i64,
+    /// The tx order of the first transaction sent by the account
+    #[diesel(sql_type = diesel::sql_types::BigInt)]
+    pub first_tx_order: i64,
+    /// The tx order of the last transaction sent by the account
+    #[diesel(sql_type = diesel::sql_types::BigInt)]
+    pub last_tx_order: i64,
+    /// The seconds when the first transaction of the account is indexed
+    #[diesel(sql_type = diesel::sql_types::Nullable<diesel::sql_types::BigInt>)]
+    pub first_active_at: Option<i64>,
+    /// The seconds when the last transaction of the account is indexed
+    #[diesel(sql_type = diesel::sql_types::Nullable<diesel::sql_types::BigInt>)]
+    pub last_active_at: Option<i64>,
+}
+
+/// The number of the owned objects of an object type, the result of the aggregate query on global states
+#[derive(QueryableByName, Debug, Clone)]
+pub struct StoredObjectTypeCount {
+    #[diesel(sql_type = diesel::sql_types::Text)]
+    pub object_type: String,
+    #[diesel(sql_type = diesel::sql_types::BigInt)]
+    pub count: i64,
+}
+
+impl StoredObjectTypeCount {
+    pub fn try_into_indexer_object_type_count(
+        &self,
+    ) -> Result<IndexerObjectTypeCount, anyhow::Error> {
+        Ok(IndexerObjectTypeCount {
+            object_type: StructTag::from_str(self.object_type.as_str())?,
+            count: self.count as u64,
+        })
+    }
+}
+
+/// The number and the total bytes of the states, the result of the aggregate query of the storage usage
+#[derive(QueryableByName, Debug, Clone)]
+pub struct StoredStateUsage {
+    #[diesel(sql_type = diesel::sql_types::BigInt)]
+    pub count: i64,
+    #[diesel(sql_type = diesel::sql_types::BigInt)]
+    pub bytes: i64,
+}
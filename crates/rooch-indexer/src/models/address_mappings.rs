@@ -0,0 +1,56 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::schema::address_mappings;
+use crate::types::IndexedAddressMapping;
+use diesel::prelude::*;
+use move_core_types::account_address::AccountAddress;
+use rooch_types::address::MultiChainAddress;
+use rooch_types::indexer::account::IndexerAddressMapping;
+use rooch_types::multichain_id::RoochMultiChainID;
+
+#[derive(Queryable, QueryableByName, Insertable, Debug, Clone)]
+#[diesel(table_name = address_mappings)]
+pub struct StoredAddressMapping {
+    /// The multichain id of the multichain address
+    #[diesel(sql_type = diesel::sql_types::BigInt)]
+    pub multichain_id: i64,
+    /// The hex of the raw address of the multichain address
+    #[diesel(sql_type = diesel::sql_types::Text)]
+    pub raw_address: String,
+    /// The hex literal of the Rooch address
+    #[diesel(sql_type = diesel::sql_types::Text)]
+    pub rooch_address: String,
+    /// The tx order of the transaction which binds the multichain address
+    #[diesel(sql_type = diesel::sql_types::BigInt)]
+    pub tx_order: i64,
+    #[diesel(sql_type = diesel::sql_types::BigInt)]
+    pub created_at: i64,
+}
+
+impl StoredAddressMapping {
+    pub fn new(mapping: IndexedAddressMapping, created_at: u64) -> Self {
+        Self {
+            multichain_id: mapping
+                .multichain_address
+                .multichain_id
+                .multichain_id()
+                .id() as i64,
+            raw_address: hex::encode(&mapping.multichain_address.raw_address),
+            rooch_address: mapping.rooch_address.to_hex_literal(),
+            tx_order: mapping.tx_order as i64,
+            created_at: created_at as i64,
+        }
+    }
+
+    pub fn try_into_indexer_address_mapping(&self) -> Result<IndexerAddressMapping, anyhow::Error> {
+        let multichain_id = RoochMultiChainID::try_from(self.multichain_id as u64)?;
+        let raw_address = hex::decode(self.raw_address.as_str())?;
+        Ok(IndexerAddressMapping {
+            multichain_address: MultiChainAddress::new(multichain_id, raw_address),
+            rooch_address: AccountAddress::from_hex_literal(self.rooch_address.as_str())?,
+            tx_order: self.tx_order as u64,
+            created_at: self.created_at as u64,
+        })
+    }
+}
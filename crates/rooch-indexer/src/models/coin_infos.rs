@@ -0,0 +1,67 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::schema::coin_infos;
+use crate::types::IndexedCoinInfo;
+use diesel::prelude::*;
+use move_core_types::language_storage::StructTag;
+use move_core_types::u256::U256;
+use rooch_types::indexer::state::IndexerCoinInfo;
+use std::str::FromStr;
+
+#[derive(Queryable, QueryableByName, Insertable, Debug, Clone)]
+#[diesel(table_name = coin_infos)]
+pub struct StoredCoinInfo {
+    /// The coin type, the canonical string of the coin struct tag
+    #[diesel(sql_type = diesel::sql_types::Text)]
+    pub coin_type: String,
+    /// The name of the coin
+    #[diesel(sql_type = diesel::sql_types::Text)]
+    pub name: String,
+    /// The symbol of the coin
+    #[diesel(sql_type = diesel::sql_types::Text)]
+    pub symbol: String,
+    /// The url of the coin icon
+    #[diesel(sql_type = diesel::sql_types::Nullable<diesel::sql_types::Text>)]
+    pub icon_url: Option<String>,
+    /// The decimals of the coin
+    #[diesel(sql_type = diesel::sql_types::SmallInt)]
+    pub decimals: i16,
+    /// The total supply of the coin, u256 in decimal string
+    #[diesel(sql_type = diesel::sql_types::Text)]
+    pub supply: String,
+    /// The tx order of the last update of the CoinInfo object
+    #[diesel(sql_type = diesel::sql_types::BigInt)]
+    pub tx_order: i64,
+}
+
+impl From<IndexedCoinInfo> for StoredCoinInfo {
+    fn from(coin_info: IndexedCoinInfo) -> Self {
+        Self {
+            coin_type: coin_info.coin_type.to_canonical_string(),
+            name: coin_info.name,
+            symbol: coin_info.symbol,
+            icon_url: coin_info.icon_url,
+            decimals: coin_info.decimals as i16,
+            supply: coin_info.supply.to_string(),
+            tx_order: coin_info.tx_order as i64,
+        }
+    }
+}
+
+impl StoredCoinInfo {
+    pub fn try_into_indexer_coin_info(&self) -> Result<IndexerCoinInfo, anyhow::Error> {
+        let coin_type = StructTag::from_str(self.coin_type.as_str())?;
+        let supply = U256::from_str(self.supply.as_str())?;
+
+        Ok(IndexerCoinInfo {
+            coin_type,
+            name: self.name.clone(),
+            symbol: self.symbol.clone(),
+            icon_url: self.icon_url.clone(),
+            decimals: self.decimals as u8,
+            supply,
+            tx_order: self.tx_order as u64,
+        })
+    }
+}
@@ -0,0 +1,74 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::schema::coin_transfers;
+use crate::types::IndexedCoinTransfer;
+use diesel::prelude::*;
+use move_core_types::account_address::AccountAddress;
+use move_core_types::language_storage::StructTag;
+use move_core_types::u256::U256;
+use moveos_types::h256::H256;
+use moveos_types::moveos_std::object_id::ObjectID;
+use rooch_types::indexer::coin_transfer::{CoinTransferType, IndexerCoinTransfer};
+use rooch_types::indexer::event_filter::IndexerEventID;
+use std::str::FromStr;
+
+#[derive(Queryable, QueryableByName, Insertable, Debug, Clone)]
+#[diesel(table_name = coin_transfers)]
+pub struct StoredCoinTransfer {
+    /// The tx order of the transaction which emits the event
+    #[diesel(sql_type = diesel::sql_types::BigInt)]
+    pub tx_order: i64,
+    /// The index of the event in the transaction events
+    #[diesel(sql_type = diesel::sql_types::BigInt)]
+    pub event_index: i64,
+    /// The id of the coin store
+    #[diesel(sql_type = diesel::sql_types::Text)]
+    pub coin_store_id: String,
+    /// The coin type, the canonical string of the coin struct tag with the `0x` prefix
+    #[diesel(sql_type = diesel::sql_types::Text)]
+    pub coin_type: String,
+    /// `deposit` or `withdraw`
+    #[diesel(sql_type = diesel::sql_types::Text)]
+    pub transfer_type: String,
+    /// The amount of the coin, u256 in decimal string
+    #[diesel(sql_type = diesel::sql_types::Text)]
+    pub amount: String,
+    #[diesel(sql_type = diesel::sql_types::Text)]
+    pub tx_hash: String,
+    #[diesel(sql_type = diesel::sql_types::Text)]
+    pub sender: String,
+    #[diesel(sql_type = diesel::sql_types::BigInt)]
+    pub created_at: i64,
+}
+
+impl From<IndexedCoinTransfer> for StoredCoinTransfer {
+    fn from(transfer: IndexedCoinTransfer) -> Self {
+        Self {
+            tx_order: transfer.tx_order as i64,
+            event_index: transfer.event_index as i64,
+            coin_store_id: transfer.coin_store_id.to_string(),
+            coin_type: format!("0x{}", transfer.coin_type.to_canonical_string()),
+            transfer_type: transfer.transfer_type.to_string(),
+            amount: transfer.amount.to_string(),
+            tx_hash: format!("{:?}", transfer.tx_hash),
+            sender: transfer.sender.to_hex_literal(),
+            created_at: transfer.created_at as i64,
+        }
+    }
+}
+
+impl StoredCoinTransfer {
+    pub fn try_into_indexer_coin_transfer(&self) -> Result<IndexerCoinTransfer, anyhow::Error> {
+        Ok(IndexerCoinTransfer {
+            indexer_event_id: IndexerEventID::new(self.tx_order as u64, self.event_index as u64),
+            coin_store_id: ObjectID::from_str(self.coin_store_id.as_str())?,
+            coin_type: StructTag::from_str(self.coin_type.as_str())?,
+            transfer_type: CoinTransferType::from_str(self.transfer_type.as_str())?,
+            amount: U256::from_str(self.amount.as_str())?,
+            tx_hash: H256::from_str(self.tx_hash.as_str())?,
+            sender: AccountAddress::from_hex_literal(self.sender.as_str())?,
+            created_at: self.created_at as u64,
+        })
+    }
+}
@@ -1,9 +1,10 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::schema::events;
+use crate::schema::{event_blooms, events};
 use crate::types::IndexedEvent;
 use diesel::prelude::*;
+use ethers::types::{Bloom, BloomInput};
 use move_core_types::account_address::AccountAddress;
 use move_core_types::language_storage::StructTag;
 use moveos_types::h256::H256;
@@ -43,6 +44,9 @@ pub struct StoredEvent {
 
     #[diesel(sql_type = diesel::sql_types::BigInt)]
     pub created_at: i64,
+    /// the decoded event data in json format, None if the event data can not be decoded
+    #[diesel(sql_type = diesel::sql_types::Nullable<diesel::sql_types::Text>)]
+    pub event_decoded: Option<String>,
 }
 
 impl From<IndexedEvent> for StoredEvent {
@@ -58,6 +62,11 @@ impl From<IndexedEvent> for StoredEvent {
             tx_order: event.tx_order as i64,
             sender: event.sender.to_hex_literal(),
             created_at: event.created_at as i64,
+            event_decoded: if event.event_data_json.is_empty() {
+                None
+            } else {
+                Some(event.event_data_json)
+            },
         }
     }
 }
@@ -77,7 +86,46 @@ impl StoredEvent {
             tx_hash,
             sender,
             created_at: self.created_at as u64,
+            event_data_json: self.event_decoded.clone(),
         };
         Ok(indexer_event)
     }
 }
+
+/// The bloom filter of the event types and the event handle ids emitted by a transaction
+#[derive(Queryable, QueryableByName, Insertable, Debug, Clone)]
+#[diesel(table_name = event_blooms)]
+pub struct StoredEventBloom {
+    /// the tx order of the transaction
+    #[diesel(sql_type = diesel::sql_types::BigInt)]
+    pub tx_order: i64,
+    /// the bloom bytes, None if the transaction is indexed before the blooms
+    #[diesel(sql_type = diesel::sql_types::Nullable<diesel::sql_types::Blob>)]
+    pub bloom: Option<Vec<u8>>,
+}
+
+impl StoredEventBloom {
+    /// Build the bloom of the events emitted by the transaction of `tx_order`
+    pub fn new<'a>(tx_order: u64, events: impl IntoIterator<Item = &'a StoredEvent>) -> Self {
+        let mut bloom = Bloom::zero();
+        for event in events {
+            bloom.accrue(BloomInput::Raw(event.event_type.as_bytes()));
+            bloom.accrue(BloomInput::Raw(event.event_handle_id.as_bytes()));
+        }
+        Self {
+            tx_order: tx_order as i64,
+            bloom: Some(bloom.as_bytes().to_vec()),
+        }
+    }
+
+    /// Return false only if the transaction definitely does not emit the event type,
+    /// the `event_type` is in the same format as the `StoredEvent::event_type`.
+    pub fn may_contain(&self, event_type: &str) -> bool {
+        match &self.bloom {
+            Some(bloom) if bloom.len() == Bloom::len_bytes() => {
+                Bloom::from_slice(bloom).contains_input(BloomInput::Raw(event_type.as_bytes()))
+            }
+            _ => true,
+        }
+    }
+}
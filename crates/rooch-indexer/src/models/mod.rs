@@ -1,6 +1,14 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
 
+pub mod account_stats;
+pub mod address_mappings;
+pub mod coin_infos;
+pub mod coin_transfers;
 pub mod events;
+pub mod modules;
+pub mod object_ownership;
+pub mod progress;
 pub mod states;
 pub mod transactions;
+pub mod utxos;
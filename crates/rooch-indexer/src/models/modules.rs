@@ -0,0 +1,69 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::schema::modules;
+use crate::types::IndexedModule;
+use diesel::prelude::*;
+use move_core_types::account_address::AccountAddress;
+use moveos_types::h256::H256;
+use rooch_types::indexer::module::IndexerModule;
+use std::str::FromStr;
+
+#[derive(Queryable, QueryableByName, Insertable, Debug, Clone)]
+#[diesel(table_name = modules)]
+pub struct StoredModule {
+    /// The hex literal of the module address
+    #[diesel(sql_type = diesel::sql_types::Text)]
+    pub address: String,
+    #[diesel(sql_type = diesel::sql_types::Text)]
+    pub name: String,
+    /// The hex of the sha3-256 hash of the module bytecode
+    #[diesel(sql_type = diesel::sql_types::Text)]
+    pub bytecode_hash: String,
+    /// The hex of the digest of the package source, not recorded on chain yet
+    #[diesel(sql_type = diesel::sql_types::Nullable<diesel::sql_types::Text>)]
+    pub source_digest: Option<String>,
+    /// The tx order of the transaction which publishes or upgrades the module
+    #[diesel(sql_type = diesel::sql_types::BigInt)]
+    pub tx_order: i64,
+    #[diesel(sql_type = diesel::sql_types::BigInt)]
+    pub state_index: i64,
+    #[diesel(sql_type = diesel::sql_types::BigInt)]
+    pub created_at: i64,
+    #[diesel(sql_type = diesel::sql_types::BigInt)]
+    pub updated_at: i64,
+}
+
+impl StoredModule {
+    pub fn new(module: IndexedModule, updated_at: u64) -> Self {
+        Self {
+            address: module.address.to_hex_literal(),
+            name: module.name,
+            bytecode_hash: format!("{:?}", module.bytecode_hash),
+            source_digest: module
+                .source_digest
+                .map(|source_digest| format!("{:?}", source_digest)),
+            tx_order: module.tx_order as i64,
+            state_index: module.state_index as i64,
+            created_at: updated_at as i64,
+            updated_at: updated_at as i64,
+        }
+    }
+
+    pub fn try_into_indexer_module(&self) -> Result<IndexerModule, anyhow::Error> {
+        Ok(IndexerModule {
+            address: AccountAddress::from_hex_literal(self.address.as_str())?,
+            name: self.name.clone(),
+            bytecode_hash: H256::from_str(self.bytecode_hash.as_str())?,
+            source_digest: self
+                .source_digest
+                .as_deref()
+                .map(H256::from_str)
+                .transpose()?,
+            tx_order: self.tx_order as u64,
+            state_index: self.state_index as u64,
+            created_at: self.created_at as u64,
+            updated_at: self.updated_at as u64,
+        })
+    }
+}
@@ -0,0 +1,46 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::schema::object_ownership_changes;
+use diesel::prelude::*;
+use move_core_types::account_address::AccountAddress;
+use moveos_types::moveos_std::object_id::ObjectID;
+use rooch_types::indexer::state::IndexerObjectOwnershipChange;
+use std::str::FromStr;
+
+#[derive(Queryable, QueryableByName, Insertable, Debug, Clone)]
+#[diesel(table_name = object_ownership_changes)]
+pub struct StoredObjectOwnershipChange {
+    #[diesel(sql_type = diesel::sql_types::Text)]
+    pub object_id: String,
+    #[diesel(sql_type = diesel::sql_types::BigInt)]
+    pub tx_order: i64,
+    /// The hex literal of the owner before the transaction, NULL if the object is created
+    #[diesel(sql_type = diesel::sql_types::Nullable<diesel::sql_types::Text>)]
+    pub old_owner: Option<String>,
+    /// The hex literal of the owner after the transaction, NULL if the object is removed
+    #[diesel(sql_type = diesel::sql_types::Nullable<diesel::sql_types::Text>)]
+    pub new_owner: Option<String>,
+    #[diesel(sql_type = diesel::sql_types::Nullable<diesel::sql_types::BigInt>)]
+    pub created_at: Option<i64>,
+}
+
+impl StoredObjectOwnershipChange {
+    pub fn try_into_indexer_object_ownership_change(
+        &self,
+    ) -> Result<IndexerObjectOwnershipChange, anyhow::Error> {
+        let parse_owner = |owner: &Option<String>| {
+            owner
+                .as_deref()
+                .map(AccountAddress::from_hex_literal)
+                .transpose()
+        };
+        Ok(IndexerObjectOwnershipChange {
+            object_id: ObjectID::from_str(self.object_id.as_str())?,
+            tx_order: self.tx_order as u64,
+            old_owner: parse_owner(&self.old_owner)?,
+            new_owner: parse_owner(&self.new_owner)?,
+            created_at: self.created_at.map(|created_at| created_at as u64),
+        })
+    }
+}
@@ -0,0 +1,19 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::schema::indexer_progress;
+use diesel::prelude::*;
+
+#[derive(Queryable, QueryableByName, Insertable, Debug, Clone)]
+#[diesel(table_name = indexer_progress)]
+pub struct StoredIndexerProgress {
+    /// The name of the indexed data, see `IndexerProgressTable`
+    #[diesel(sql_type = diesel::sql_types::Text)]
+    pub table_name: String,
+    /// The tx order of the last indexed transaction
+    #[diesel(sql_type = diesel::sql_types::BigInt)]
+    pub last_indexed_order: i64,
+    /// The progress updated timestamp
+    #[diesel(sql_type = diesel::sql_types::BigInt)]
+    pub updated_at: i64,
+}
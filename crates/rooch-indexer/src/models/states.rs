@@ -49,6 +49,9 @@ pub struct StoredGlobalState {
     /// The object updated timestamp on chain
     #[diesel(sql_type = diesel::sql_types::BigInt)]
     pub updated_at: i64,
+    /// The bytes of the object state in the state tree
+    #[diesel(sql_type = diesel::sql_types::BigInt)]
+    pub state_size: i64,
 }
 
 impl From<IndexedGlobalState> for StoredGlobalState {
@@ -65,6 +68,7 @@ impl From<IndexedGlobalState> for StoredGlobalState {
             state_index: state.state_index as i64,
             created_at: state.created_at as i64,
             updated_at: state.updated_at as i64,
+            state_size: state.state_size as i64,
         }
     }
 }
@@ -126,6 +130,9 @@ pub struct StoredTableState {
     /// The table item updated timestamp on chain
     #[diesel(sql_type = diesel::sql_types::BigInt)]
     pub updated_at: i64,
+    /// The bytes of the table item state in the state tree
+    #[diesel(sql_type = diesel::sql_types::BigInt)]
+    pub state_size: i64,
 }
 
 impl From<IndexedTableState> for StoredTableState {
@@ -141,6 +148,7 @@ impl From<IndexedTableState> for StoredTableState {
             state_index: state.state_index as i64,
             created_at: state.created_at as i64,
             updated_at: state.updated_at as i64,
+            state_size: state.state_size as i64,
         }
     }
 }
@@ -0,0 +1,90 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::schema::utxos;
+use crate::types::IndexedUTXOState;
+use diesel::prelude::*;
+use move_core_types::account_address::AccountAddress;
+use moveos_types::moveos_std::object_id::ObjectID;
+use rooch_types::indexer::state::IndexerUTXOState;
+use std::str::FromStr;
+
+#[derive(Queryable, QueryableByName, Insertable, Debug, Clone)]
+#[diesel(table_name = utxos)]
+pub struct StoredUTXOState {
+    /// The object id of the UTXO
+    #[diesel(sql_type = diesel::sql_types::Text)]
+    pub object_id: String,
+    /// The owner of the UTXO
+    #[diesel(sql_type = diesel::sql_types::Text)]
+    pub owner: String,
+    /// A flag to indicate whether the object is shared or frozen
+    #[diesel(sql_type = diesel::sql_types::SmallInt)]
+    pub flag: i16,
+    /// The txid of the UTXO
+    #[diesel(sql_type = diesel::sql_types::Text)]
+    pub txid: String,
+    /// The vout of the UTXO
+    #[diesel(sql_type = diesel::sql_types::BigInt)]
+    pub vout: i64,
+    /// The value of the UTXO
+    #[diesel(sql_type = diesel::sql_types::BigInt)]
+    pub value: i64,
+    /// The protocol seals of the UTXO, json format
+    #[diesel(sql_type = diesel::sql_types::Text)]
+    pub seals: String,
+    /// The tx order of this transaction
+    #[diesel(sql_type = diesel::sql_types::BigInt)]
+    pub tx_order: i64,
+    /// The state index in the tx
+    #[diesel(sql_type = diesel::sql_types::BigInt)]
+    pub state_index: i64,
+    /// The object created timestamp on chain
+    #[diesel(sql_type = diesel::sql_types::BigInt)]
+    pub created_at: i64,
+    /// The object updated timestamp on chain
+    #[diesel(sql_type = diesel::sql_types::BigInt)]
+    pub updated_at: i64,
+}
+
+impl From<IndexedUTXOState> for StoredUTXOState {
+    fn from(state: IndexedUTXOState) -> Self {
+        Self {
+            object_id: state.object_id.to_string(),
+            owner: state.owner.to_hex_literal(),
+            flag: state.flag as i16,
+            txid: state.txid.to_hex_literal(),
+            vout: state.vout as i64,
+            value: state.value as i64,
+            seals: state.seals,
+            tx_order: state.tx_order as i64,
+            state_index: state.state_index as i64,
+            created_at: state.created_at as i64,
+            updated_at: state.updated_at as i64,
+        }
+    }
+}
+
+impl StoredUTXOState {
+    pub fn try_into_indexer_utxo_state(&self) -> Result<IndexerUTXOState, anyhow::Error> {
+        let object_id = ObjectID::from_str(self.object_id.as_str())?;
+        let owner = AccountAddress::from_hex_literal(self.owner.as_str())?;
+        let txid = AccountAddress::from_hex_literal(self.txid.as_str())?;
+        let seals = serde_json::from_str(self.seals.as_str())?;
+
+        let state = IndexerUTXOState {
+            object_id,
+            owner,
+            flag: self.flag as u8,
+            txid,
+            vout: self.vout as u32,
+            value: self.value as u64,
+            seals,
+            tx_order: self.tx_order as u64,
+            state_index: self.state_index as u64,
+            created_at: self.created_at as u64,
+            updated_at: self.updated_at as u64,
+        };
+        Ok(state)
+    }
+}
@@ -3,49 +3,136 @@
 
 use crate::actor::indexer::IndexerActor;
 use crate::actor::messages::{
-    IndexerEventsMessage, IndexerStatesMessage, IndexerTransactionMessage,
-    QueryIndexerEventsMessage, QueryIndexerGlobalStatesMessage, QueryIndexerTableStatesMessage,
-    QueryIndexerTransactionsMessage, SyncIndexerStatesMessage,
+    GetIndexerAccountSummaryMessage, GetIndexerAddressMappingMessage,
+    GetIndexerAddressMappingsByRoochAddressMessage, GetIndexerCoinInfosMessage,
+    GetIndexerObjectOwnershipHistoryMessage, GetIndexerStorageUsageMessage, IndexerBatchMessage,
+    IndexerEventsMessage, IndexerRevertMessage, IndexerStatesMessage, IndexerTransactionMessage,
+    IndexerWriteMessage, QueryIndexerCoinTransfersMessage, QueryIndexerEventsMessage,
+    QueryIndexerGlobalStatesMessage, QueryIndexerModulesMessage, QueryIndexerTableStatesMessage,
+    QueryIndexerTransactionsMessage, QueryIndexerUTXOsMessage, SearchIndexerEventsMessage,
+    SearchIndexerTransactionsMessage, SyncIndexerStatesMessage,
 };
 use crate::actor::reader_indexer::IndexerReaderActor;
+use crate::LOG_TARGET;
 use anyhow::Result;
 use coerce::actor::ActorRef;
+use move_core_types::account_address::AccountAddress;
+use move_core_types::language_storage::StructTag;
+use moveos_types::h256::H256;
 use moveos_types::moveos_std::event::Event;
+use moveos_types::moveos_std::object_id::ObjectID;
 use moveos_types::state::StateChangeSet;
 use moveos_types::transaction::{TransactionExecutionInfo, VerifiedMoveOSTransaction};
+use rooch_types::address::MultiChainAddress;
+use rooch_types::indexer::account::{
+    IndexerAccountSummary, IndexerAddressMapping, IndexerStorageUsage,
+};
+use rooch_types::indexer::coin_transfer::IndexerCoinTransfer;
 use rooch_types::indexer::event_filter::{EventFilter, IndexerEvent, IndexerEventID};
+use rooch_types::indexer::module::{IndexerModule, ModuleFilter};
 use rooch_types::indexer::state::{
-    GlobalStateFilter, IndexerGlobalState, IndexerStateID, IndexerTableChangeSet,
-    IndexerTableState, StateSyncFilter, TableStateFilter,
+    GlobalStateFilter, IndexerCoinInfo, IndexerGlobalState, IndexerObjectOwnershipChange,
+    IndexerStateID, IndexerTableChangeSet, IndexerTableState, IndexerUTXOState, StateSyncFilter,
+    TableStateFilter, UTXOFilter,
 };
 use rooch_types::indexer::transaction_filter::TransactionFilter;
 use rooch_types::transaction::{TransactionSequenceInfo, TransactionWithInfo, TypedTransaction};
+use std::future::Future;
+use std::sync::{Arc, OnceLock};
+use std::time::Duration;
+use tokio::sync::{mpsc, oneshot};
+
+/// The capacity of the channel between the executor and the indexer,
+/// the executor waits if the indexer falls behind so many write messages.
+pub const INDEXER_WRITE_CHANNEL_CAPACITY: usize = 1024;
+/// The max write messages committed in one batch
+pub const MAX_INDEXER_BATCH_MESSAGES: usize = 256;
+/// The max retries of a failed batch before the writer stops
+pub const MAX_INDEXER_BATCH_RETRIES: u32 = 5;
+/// The interval before the first retry of a failed batch, it is doubled on every retry
+const INDEXER_BATCH_RETRY_INTERVAL: Duration = Duration::from_millis(500);
 
 #[derive(Clone)]
 pub struct IndexerProxy {
     pub actor: ActorRef<IndexerActor>,
     pub reader_actor: ActorRef<IndexerReaderActor>,
+    writer: mpsc::Sender<IndexerWriterCommand>,
+    /// The error which stopped the writer, it is set once
+    writer_error: Arc<OnceLock<String>>,
+}
+
+/// The commands of the writer task, the revert is queued with the writes so it is ordered after the queued writes
+enum IndexerWriterCommand {
+    Write(IndexerWriteMessage),
+    Revert(IndexerRevertMessage, oneshot::Sender<Result<()>>),
 }
 
 impl IndexerProxy {
+    /// Create the proxy and spawn the writer task, which drains the write messages from the bounded channel
+    /// and sends them to the indexer actor in batches.
     pub fn new(actor: ActorRef<IndexerActor>, reader_actor: ActorRef<IndexerReaderActor>) -> Self {
+        let (writer, receiver) = mpsc::channel(INDEXER_WRITE_CHANNEL_CAPACITY);
+        let writer_error = Arc::new(OnceLock::new());
+        tokio::spawn(run_writer(actor.clone(), receiver, writer_error.clone()));
         Self {
             actor,
             reader_actor,
+            writer,
+            writer_error,
         }
     }
 
+    /// The error which stopped the writer, `None` if the writer is running.
+    /// The indexer progress stays at the last committed transaction once the writer is stopped.
+    pub fn writer_error(&self) -> Option<&str> {
+        self.writer_error.get().map(String::as_str)
+    }
+
+    fn writer_stopped(&self) -> anyhow::Error {
+        anyhow::anyhow!(
+            "The indexer writer is stopped: {}, please rebuild the indexer",
+            self.writer_error()
+                .unwrap_or("the indexer actor is stopped")
+        )
+    }
+
+    /// Queue the write message, it returns once the message is queued rather than indexed.
+    /// The indexing error is not returned to the caller of the queued message, but the writes after
+    /// the writer is stopped fail with the error, see `run_writer`.
+    async fn write(&self, message: IndexerWriteMessage) -> Result<()> {
+        self.writer
+            .send(IndexerWriterCommand::Write(message))
+            .await
+            .map_err(|_| self.writer_stopped())
+    }
+
+    /// Revert the indexed data to the transaction at `tx_order`, such as after a reorg of the sequencer or DA.
+    /// The writes queued before are committed first, it returns once the indexer is reverted.
+    pub async fn revert(&self, tx_order: u64, state_root: H256) -> Result<()> {
+        let (sender, receiver) = oneshot::channel();
+        self.writer
+            .send(IndexerWriterCommand::Revert(
+                IndexerRevertMessage {
+                    tx_order,
+                    state_root,
+                },
+                sender,
+            ))
+            .await
+            .map_err(|_| self.writer_stopped())?;
+        receiver.await.map_err(|_| self.writer_stopped())?
+    }
+
     pub async fn indexer_states(
         &self,
         tx_order: u64,
         state_change_set: StateChangeSet,
     ) -> Result<()> {
-        self.actor
-            .send(IndexerStatesMessage {
-                tx_order,
-                state_change_set,
-            })
-            .await?
+        self.write(IndexerWriteMessage::States(IndexerStatesMessage {
+            tx_order,
+            state_change_set,
+        }))
+        .await
     }
 
     pub async fn indexer_transaction(
@@ -55,14 +142,15 @@ impl IndexerProxy {
         execution_info: TransactionExecutionInfo,
         moveos_tx: VerifiedMoveOSTransaction,
     ) -> Result<()> {
-        self.actor
-            .send(IndexerTransactionMessage {
+        self.write(IndexerWriteMessage::Transaction(
+            IndexerTransactionMessage {
                 transaction,
                 sequence_info,
                 execution_info,
                 moveos_tx,
-            })
-            .await?
+            },
+        ))
+        .await
     }
 
     pub async fn indexer_events(
@@ -72,14 +160,13 @@ impl IndexerProxy {
         sequence_info: TransactionSequenceInfo,
         moveos_tx: VerifiedMoveOSTransaction,
     ) -> Result<()> {
-        self.actor
-            .send(IndexerEventsMessage {
-                events,
-                transaction,
-                sequence_info,
-                moveos_tx,
-            })
-            .await?
+        self.write(IndexerWriteMessage::Events(IndexerEventsMessage {
+            events,
+            transaction,
+            sequence_info,
+            moveos_tx,
+        }))
+        .await
     }
 
     pub async fn query_transactions(
@@ -118,6 +205,42 @@ impl IndexerProxy {
             .await?
     }
 
+    pub async fn search_transactions(
+        &self,
+        query: String,
+        // exclusive cursor if `Some`, otherwise start from the beginning
+        cursor: Option<u64>,
+        limit: usize,
+        descending_order: bool,
+    ) -> Result<Vec<TransactionWithInfo>> {
+        self.reader_actor
+            .send(SearchIndexerTransactionsMessage {
+                query,
+                cursor,
+                limit,
+                descending_order,
+            })
+            .await?
+    }
+
+    pub async fn search_events(
+        &self,
+        query: String,
+        // exclusive cursor if `Some`, otherwise start from the beginning
+        cursor: Option<IndexerEventID>,
+        limit: usize,
+        descending_order: bool,
+    ) -> Result<Vec<IndexerEvent>> {
+        self.reader_actor
+            .send(SearchIndexerEventsMessage {
+                query,
+                cursor,
+                limit,
+                descending_order,
+            })
+            .await?
+    }
+
     pub async fn query_global_states(
         &self,
         filter: GlobalStateFilter,
@@ -154,6 +277,120 @@ impl IndexerProxy {
             .await?
     }
 
+    pub async fn query_utxos(
+        &self,
+        filter: UTXOFilter,
+        // exclusive cursor if `Some`, otherwise start from the beginning
+        cursor: Option<IndexerStateID>,
+        limit: usize,
+        descending_order: bool,
+    ) -> Result<Vec<IndexerUTXOState>> {
+        self.reader_actor
+            .send(QueryIndexerUTXOsMessage {
+                filter,
+                cursor,
+                limit,
+                descending_order,
+            })
+            .await?
+    }
+
+    pub async fn get_coin_infos(
+        &self,
+        coin_types: Vec<StructTag>,
+    ) -> Result<Vec<Option<IndexerCoinInfo>>> {
+        self.reader_actor
+            .send(GetIndexerCoinInfosMessage { coin_types })
+            .await?
+    }
+
+    pub async fn get_account_summary(
+        &self,
+        address: AccountAddress,
+    ) -> Result<IndexerAccountSummary> {
+        self.reader_actor
+            .send(GetIndexerAccountSummaryMessage { address })
+            .await?
+    }
+
+    pub async fn get_storage_usage(&self, address: AccountAddress) -> Result<IndexerStorageUsage> {
+        self.reader_actor
+            .send(GetIndexerStorageUsageMessage { address })
+            .await?
+    }
+
+    pub async fn get_address_mapping(
+        &self,
+        multichain_address: MultiChainAddress,
+    ) -> Result<Option<IndexerAddressMapping>> {
+        self.reader_actor
+            .send(GetIndexerAddressMappingMessage { multichain_address })
+            .await?
+    }
+
+    pub async fn get_address_mappings_by_rooch_address(
+        &self,
+        rooch_address: AccountAddress,
+    ) -> Result<Vec<IndexerAddressMapping>> {
+        self.reader_actor
+            .send(GetIndexerAddressMappingsByRoochAddressMessage { rooch_address })
+            .await?
+    }
+
+    pub async fn query_modules(
+        &self,
+        filter: ModuleFilter,
+        // exclusive cursor if `Some`, otherwise start from the beginning
+        cursor: Option<IndexerStateID>,
+        limit: usize,
+        descending_order: bool,
+    ) -> Result<Vec<IndexerModule>> {
+        self.reader_actor
+            .send(QueryIndexerModulesMessage {
+                filter,
+                cursor,
+                limit,
+                descending_order,
+            })
+            .await?
+    }
+
+    pub async fn query_coin_transfers(
+        &self,
+        coin_store_id: ObjectID,
+        // exclusive cursor if `Some`, otherwise start from the beginning
+        cursor: Option<IndexerEventID>,
+        limit: usize,
+        descending_order: bool,
+    ) -> Result<Vec<IndexerCoinTransfer>> {
+        self.reader_actor
+            .send(QueryIndexerCoinTransfersMessage {
+                coin_store_id,
+                cursor,
+                limit,
+                descending_order,
+            })
+            .await?
+    }
+
+    pub async fn get_object_ownership_history(
+        &self,
+        object_id: ObjectID,
+        // exclusive tx order cursor if `Some`, otherwise start from the beginning
+        cursor: Option<u64>,
+        limit: usize,
+        descending_order: bool,
+    ) -> Result<Vec<IndexerObjectOwnershipChange>> {
+        self.reader_actor
+            .send(GetIndexerObjectOwnershipHistoryMessage {
+                object_id,
+                cursor,
+                limit,
+                descending_order,
+            })
+            .await?
+    }
+
     pub async fn sync_states(
         &self,
         filter: Option<StateSyncFilter>,
@@ -172,3 +409,120 @@ impl IndexerProxy {
             .await?
     }
 }
+
+/// Drain the queued write messages, up to `MAX_INDEXER_BATCH_MESSAGES`, and commit them in one batch.
+/// The messages are queued while the previous batch is committed, so the batch grows with the load.
+/// A queued revert ends the batch, it is sent to the indexer actor after the batch is committed.
+/// The failed batch is aborted without moving the indexer progress, so it is retried with backoff.
+/// The writer stops if the batch still fails after `MAX_INDEXER_BATCH_RETRIES`, the error is kept in `writer_error`
+/// and returned by the following writes, rather than skipping the failed transactions.
+/// The indexer is caught up by `rooch indexer rebuild`.
+async fn run_writer(
+    actor: ActorRef<IndexerActor>,
+    mut receiver: mpsc::Receiver<IndexerWriterCommand>,
+    writer_error: Arc<OnceLock<String>>,
+) {
+    let mut pending_command = None;
+    loop {
+        let command = match pending_command.take() {
+            Some(command) => command,
+            None => match receiver.recv().await {
+                Some(command) => command,
+                None => break,
+            },
+        };
+        let message = match command {
+            IndexerWriterCommand::Write(message) => message,
+            IndexerWriterCommand::Revert(message, sender) => {
+                match actor.send(message).await {
+                    Ok(result) => {
+                        let _ = sender.send(result);
+                    }
+                    Err(e) => {
+                        tracing::error!(target: LOG_TARGET, "Indexer actor is stopped: {:?}", e);
+                        let _ = writer_error.set("the indexer actor is stopped".to_owned());
+                        let _ = sender.send(Err(anyhow::anyhow!("The indexer actor is stopped")));
+                        break;
+                    }
+                }
+                continue;
+            }
+        };
+        let mut messages = vec![message];
+        while messages.len() < MAX_INDEXER_BATCH_MESSAGES {
+            match receiver.try_recv() {
+                Ok(IndexerWriterCommand::Write(message)) => messages.push(message),
+                Ok(command) => {
+                    pending_command = Some(command);
+                    break;
+                }
+                Err(_) => break,
+            }
+        }
+        let (actor, messages) = (&actor, &messages);
+        let result = retry_with_backoff(
+            MAX_INDEXER_BATCH_RETRIES,
+            INDEXER_BATCH_RETRY_INTERVAL,
+            || async move {
+                actor
+                    .send(IndexerBatchMessage {
+                        messages: messages.clone(),
+                    })
+                    .await
+                    .map_err(|e| RetryError::Permanent(anyhow::anyhow!("{:?}", e)))?
+                    .map_err(RetryError::Transient)
+            },
+        )
+        .await;
+        if let Err(e) = result {
+            tracing::error!(
+                target: LOG_TARGET,
+                "Indexer batch error, the indexer writer is stopped, please rebuild the indexer: {:?}",
+                e
+            );
+            let _ = writer_error.set(e.to_string());
+            break;
+        }
+    }
+}
+
+/// The error of a retried operation
+pub(crate) enum RetryError {
+    /// The operation may succeed if it is retried
+    Transient(anyhow::Error),
+    /// The operation can not succeed, it is not retried
+    Permanent(anyhow::Error),
+}
+
+/// Run the operation until it succeeds, fails with a permanent error or the transient error is retried `max_retries` times.
+/// The interval before the retry starts from `interval` and is doubled on every retry.
+pub(crate) async fn retry_with_backoff<T, F, Fut>(
+    max_retries: u32,
+    mut interval: Duration,
+    mut operation: F,
+) -> Result<T>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = std::result::Result<T, RetryError>>,
+{
+    let mut retries = 0;
+    loop {
+        match operation().await {
+            Ok(result) => return Ok(result),
+            Err(RetryError::Transient(e)) if retries < max_retries => {
+                retries += 1;
+                tracing::warn!(
+                    target: LOG_TARGET,
+                    "Indexer batch error, retry {}/{} in {:?}: {:?}",
+                    retries,
+                    max_retries,
+                    interval,
+                    e
+                );
+                tokio::time::sleep(interval).await;
+                interval *= 2;
+            }
+            Err(RetryError::Transient(e)) | Err(RetryError::Permanent(e)) => return Err(e),
+        }
+    }
+}
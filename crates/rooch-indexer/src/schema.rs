@@ -3,6 +3,61 @@
 
 // @generated automatically by Diesel CLI.
 
+diesel::table! {
+    account_stats (address) {
+        address -> Text,
+        tx_count -> BigInt,
+        gas_used -> BigInt,
+        first_tx_order -> BigInt,
+        last_tx_order -> BigInt,
+        first_active_at -> Nullable<BigInt>,
+        last_active_at -> Nullable<BigInt>,
+    }
+}
+
+diesel::table! {
+    address_mappings (multichain_id, raw_address) {
+        multichain_id -> BigInt,
+        raw_address -> Text,
+        rooch_address -> Text,
+        tx_order -> BigInt,
+        created_at -> BigInt,
+    }
+}
+
+diesel::table! {
+    coin_infos (coin_type) {
+        coin_type -> Text,
+        name -> Text,
+        symbol -> Text,
+        icon_url -> Nullable<Text>,
+        decimals -> SmallInt,
+        supply -> Text,
+        tx_order -> BigInt,
+    }
+}
+
+diesel::table! {
+    coin_transfers (tx_order, event_index) {
+        tx_order -> BigInt,
+        event_index -> BigInt,
+        coin_store_id -> Text,
+        coin_type -> Text,
+        transfer_type -> Text,
+        amount -> Text,
+        tx_hash -> Text,
+        sender -> Text,
+        created_at -> BigInt,
+    }
+}
+
+diesel::table! {
+    event_blooms (tx_order) {
+        tx_order -> BigInt,
+        bloom -> Nullable<Binary>,
+    }
+}
+
 diesel::table! {
     events (event_index, tx_order) {
         event_handle_id -> Text,
@@ -14,6 +69,7 @@ diesel::table! {
         tx_order -> BigInt,
         sender -> Text,
         created_at -> BigInt,
+        event_decoded -> Nullable<Text>,
     }
 }
 
@@ -30,6 +86,38 @@ diesel::table! {
         state_index -> BigInt,
         created_at -> BigInt,
         updated_at -> BigInt,
+        state_size -> BigInt,
+    }
+}
+
+diesel::table! {
+    indexer_progress (table_name) {
+        table_name -> Text,
+        last_indexed_order -> BigInt,
+        updated_at -> BigInt,
+    }
+}
+
+diesel::table! {
+    modules (address, name) {
+        address -> Text,
+        name -> Text,
+        bytecode_hash -> Text,
+        source_digest -> Nullable<Text>,
+        tx_order -> BigInt,
+        state_index -> BigInt,
+        created_at -> BigInt,
+        updated_at -> BigInt,
+    }
+}
+
+diesel::table! {
+    object_ownership_changes (object_id, tx_order) {
+        object_id -> Text,
+        tx_order -> BigInt,
+        old_owner -> Nullable<Text>,
+        new_owner -> Nullable<Text>,
+        created_at -> Nullable<BigInt>,
     }
 }
 
@@ -55,6 +143,7 @@ diesel::table! {
         state_index -> BigInt,
         created_at -> BigInt,
         updated_at -> BigInt,
+        state_size -> BigInt,
     }
 }
 
@@ -85,10 +174,35 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    utxos (object_id) {
+        object_id -> Text,
+        owner -> Text,
+        flag -> SmallInt,
+        txid -> Text,
+        vout -> BigInt,
+        value -> BigInt,
+        seals -> Text,
+        tx_order -> BigInt,
+        state_index -> BigInt,
+        created_at -> BigInt,
+        updated_at -> BigInt,
+    }
+}
+
 diesel::allow_tables_to_appear_in_same_query!(
+    account_stats,
+    address_mappings,
+    coin_infos,
+    coin_transfers,
+    event_blooms,
     events,
     global_states,
+    indexer_progress,
+    modules,
+    object_ownership_changes,
     table_change_sets,
     table_states,
     transactions,
+    utxos,
 );
@@ -0,0 +1,152 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::models::object_ownership::StoredObjectOwnershipChange;
+use crate::types::{
+    IndexedAddressMapping, IndexedCoinInfo, IndexedCoinTransfer, IndexedEvent, IndexedGlobalState,
+    IndexedModule, IndexedTableChangeSet, IndexedTableState, IndexedTransaction, IndexedUTXOState,
+    IndexerProgressTable,
+};
+use rooch_types::address::MultiChainAddress;
+use std::collections::{BTreeMap, BTreeSet};
+
+/// The indexed data of a block of transactions, it is committed in one transaction per indexer progress table.
+/// The states only keep the latest one of the block, so the writes of the same row are merged before commit.
+#[derive(Debug, Default)]
+pub struct IndexerBatch {
+    /// object id -> the latest global state, `None` if the object is removed
+    pub global_states: BTreeMap<String, Option<IndexedGlobalState>>,
+    pub object_ownership_changes: Vec<StoredObjectOwnershipChange>,
+    /// object id -> the latest UTXO, `None` if the UTXO is removed
+    pub utxos: BTreeMap<String, Option<IndexedUTXOState>>,
+    /// coin type -> the latest coin info
+    pub coin_infos: BTreeMap<String, IndexedCoinInfo>,
+    /// multichain address -> the latest address mapping, `None` if the mapping is removed
+    pub address_mappings: BTreeMap<MultiChainAddress, Option<IndexedAddressMapping>>,
+    /// (module address, module name) -> the latest published module
+    pub modules: BTreeMap<(String, String), IndexedModule>,
+    /// (table handle, key hex) -> the latest table state, `None` if the item is removed
+    pub table_states: BTreeMap<(String, String), Option<IndexedTableState>>,
+    /// The removed tables, all the states of them are deleted before the table states are written
+    pub removed_table_handles: BTreeSet<String>,
+    pub table_change_sets: Vec<IndexedTableChangeSet>,
+    pub transactions: Vec<IndexedTransaction>,
+    pub events: Vec<IndexedEvent>,
+    /// The coin transfers of the events, they are committed with the events
+    pub coin_transfers: Vec<IndexedCoinTransfer>,
+    states_progress: Option<u64>,
+    transactions_progress: Option<u64>,
+    events_progress: Option<u64>,
+}
+
+impl IndexerBatch {
+    pub fn is_empty(&self) -> bool {
+        IndexerProgressTable::ALL
+            .iter()
+            .all(|table| self.progress(*table).is_none())
+    }
+
+    /// The last indexed tx order of the table in the batch
+    pub fn progress(&self, table: IndexerProgressTable) -> Option<u64> {
+        match table {
+            IndexerProgressTable::Transactions => self.transactions_progress,
+            IndexerProgressTable::Events => self.events_progress,
+            IndexerProgressTable::States => self.states_progress,
+        }
+    }
+
+    pub fn update_progress(&mut self, table: IndexerProgressTable, tx_order: u64) {
+        let progress = match table {
+            IndexerProgressTable::Transactions => &mut self.transactions_progress,
+            IndexerProgressTable::Events => &mut self.events_progress,
+            IndexerProgressTable::States => &mut self.states_progress,
+        };
+        *progress = Some(progress.map_or(tx_order, |progress| progress.max(tx_order)));
+    }
+
+    /// The owner of the object written by the batch, `Some(None)` if the object is removed,
+    /// `None` if the object is not written by the batch, so the owner should be read from the store.
+    pub fn object_owner(&self, object_id: &str) -> Option<Option<String>> {
+        self.global_states
+            .get(object_id)
+            .map(|state| state.as_ref().map(|state| state.owner.to_hex_literal()))
+    }
+
+    /// Record the owner transition of the object, nothing is recorded if the owner is not changed.
+    /// The created time is set when the batch is committed.
+    pub fn add_object_ownership_change(
+        &mut self,
+        object_id: String,
+        tx_order: u64,
+        old_owner: Option<String>,
+        new_owner: Option<String>,
+    ) {
+        if old_owner == new_owner {
+            return;
+        }
+        self.object_ownership_changes
+            .push(StoredObjectOwnershipChange {
+                object_id,
+                tx_order: tx_order as i64,
+                old_owner,
+                new_owner,
+                created_at: None,
+            });
+    }
+
+    pub fn upsert_global_state(&mut self, state: IndexedGlobalState) {
+        self.global_states
+            .insert(state.object_id.to_string(), Some(state));
+    }
+
+    pub fn remove_global_state(&mut self, object_id: String) {
+        self.global_states.insert(object_id, None);
+    }
+
+    pub fn upsert_utxo(&mut self, utxo: IndexedUTXOState) {
+        self.utxos.insert(utxo.object_id.to_string(), Some(utxo));
+    }
+
+    pub fn remove_utxo(&mut self, object_id: String) {
+        self.utxos.insert(object_id, None);
+    }
+
+    pub fn upsert_coin_info(&mut self, coin_info: IndexedCoinInfo) {
+        self.coin_infos
+            .insert(coin_info.coin_type.to_canonical_string(), coin_info);
+    }
+
+    pub fn upsert_address_mapping(&mut self, mapping: IndexedAddressMapping) {
+        self.address_mappings
+            .insert(mapping.multichain_address.clone(), Some(mapping));
+    }
+
+    pub fn remove_address_mapping(&mut self, multichain_address: MultiChainAddress) {
+        self.address_mappings.insert(multichain_address, None);
+    }
+
+    pub fn upsert_module(&mut self, module: IndexedModule) {
+        self.modules.insert(
+            (module.address.to_hex_literal(), module.name.clone()),
+            module,
+        );
+    }
+
+    pub fn upsert_table_state(&mut self, state: IndexedTableState) {
+        self.table_states.insert(
+            (state.table_handle.to_string(), state.key_hex.clone()),
+            Some(state),
+        );
+    }
+
+    pub fn remove_table_state(&mut self, table_handle: String, key_hex: String) {
+        self.table_states.insert((table_handle, key_hex), None);
+    }
+
+    /// Remove the table and drop the pending writes of its states
+    pub fn remove_table(&mut self, table_handle: String) {
+        self.table_states
+            .retain(|(handle, _), _| handle != &table_handle);
+        self.removed_table_handles.insert(table_handle);
+    }
+}
@@ -1,18 +1,38 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
 
+use crate::LOG_TARGET;
 use anyhow::Result;
+use diesel::sql_types::{BigInt, Nullable, SmallInt, Text};
 use diesel::QueryDsl;
-use diesel::{ExpressionMethods, RunQueryDsl};
-use tracing::log;
+use diesel::{
+    Connection, ExpressionMethods, OptionalExtension, QueryResult, RunQueryDsl, SqliteConnection,
+};
+use moveos_common::utils::now_seconds;
+use rooch_config::indexer_config::IndexerRetention;
+use rooch_types::address::MultiChainAddress;
+use rooch_types::indexer::state::IndexerTableChangeSet;
+use std::collections::BTreeMap;
 
 use crate::errors::{Context, IndexerError};
-use crate::models::events::StoredEvent;
+use crate::models::address_mappings::StoredAddressMapping;
+use crate::models::coin_infos::StoredCoinInfo;
+use crate::models::coin_transfers::StoredCoinTransfer;
+use crate::models::events::{StoredEvent, StoredEventBloom};
+use crate::models::modules::StoredModule;
+use crate::models::progress::StoredIndexerProgress;
 use crate::models::states::{StoredGlobalState, StoredTableChangeSet, StoredTableState};
 use crate::models::transactions::StoredTransaction;
-use crate::schema::{events, global_states, table_change_sets, table_states, transactions};
+use crate::models::utxos::StoredUTXOState;
+use crate::schema::{
+    coin_transfers, event_blooms, events, global_states, indexer_progress,
+    object_ownership_changes, table_change_sets, transactions,
+};
+use crate::store::batch::IndexerBatch;
 use crate::types::{
-    IndexedEvent, IndexedGlobalState, IndexedTableChangeSet, IndexedTableState, IndexedTransaction,
+    IndexedCoinInfo, IndexedCoinTransfer, IndexedEvent, IndexedGlobalState, IndexedTableChangeSet,
+    IndexedTableState, IndexedTransaction, IndexedUTXOState, IndexerHistoryTable,
+    IndexerProgressTable,
 };
 use crate::utils::escape_sql_string;
 use crate::{get_sqlite_pool_connection, SqliteConnectionPool};
@@ -36,70 +56,15 @@ impl SqliteIndexerStore {
         }
 
         let mut connection = get_sqlite_pool_connection(&self.connection_pool)?;
-        let states = states
-            .into_iter()
-            .map(StoredGlobalState::from)
-            .collect::<Vec<_>>();
-
-        // Diesel for SQLite don't support batch update yet, so implements batch update directly via raw SQL
-        let values_clause = states
-            .into_iter()
-            .map(|state| {
-                format!(
-                    "('{}', '{}', {}, '{}', '{}', '{}', {}, {}, {}, {}, {})",
-                    escape_sql_string(state.object_id),
-                    escape_sql_string(state.owner),
-                    state.flag,
-                    escape_sql_string(state.value),
-                    escape_sql_string(state.object_type),
-                    escape_sql_string(state.state_root),
-                    state.size,
-                    state.tx_order,
-                    state.state_index,
-                    state.created_at,
-                    state.updated_at,
-                )
+        connection
+            .transaction::<_, diesel::result::Error, _>(|conn| {
+                for state in states {
+                    upsert_global_state(conn, &StoredGlobalState::from(state))?;
+                }
+                Ok(())
             })
-            .collect::<Vec<_>>()
-            .join(",");
-        let query = format!(
-            "
-                INSERT INTO global_states (object_id, owner, flag, value, object_type, state_root, size, tx_order, state_index, created_at, updated_at) \
-                VALUES {} \
-                ON CONFLICT (object_id) DO UPDATE SET \
-                owner = excluded.owner, \
-                flag = excluded.flag, \
-                value = excluded.value, \
-                state_root = excluded.state_root, \
-                size = excluded.size, \
-                tx_order = excluded.tx_order, \
-                state_index = excluded.state_index, \
-                updated_at = excluded.updated_at
-            ",
-            values_clause
-        );
-
-        // // Perform multi-insert with ON CONFLICT update
-        // diesel::insert_into(global_states::table)
-        //     .values(states.as_slice())
-        //     .on_conflict(global_states::object_id)
-        //     .do_update()
-        //     .set((
-        //         global_states::owner.eq(excluded(global_states::owner)),
-        //         global_states::flag.eq(excluded(global_states::flag)),
-        //         global_states::value.eq(excluded(global_states::value)),
-        //         global_states::size.eq(excluded(global_states::size)),
-        //         global_states::updated_at.eq(excluded(global_states::updated_at)),
-        //     ))
-        //     .execute(&mut connection)
-        //     .map_err(|e| IndexerError::SQLiteWriteError(e.to_string()))
-        //     .context("Failed to write or update global states to SQLiteDB");
-
-        // Execute the raw SQL query
-        diesel::sql_query(query.clone())
-            .execute(&mut connection)
             .map_err(|e| {
-                log::error!("Upsert global states Executing Query error: {}", query);
+                tracing::error!(target: LOG_TARGET, "Upsert global states error: {}", e);
                 IndexerError::SQLiteWriteError(e.to_string())
             })
             .context("Failed to write or update global states to SQLiteDB")?;
@@ -113,13 +78,157 @@ impl SqliteIndexerStore {
         }
 
         let mut connection = get_sqlite_pool_connection(&self.connection_pool)?;
+        connection
+            .transaction::<_, diesel::result::Error, _>(|conn| {
+                for object_id in state_pks.iter() {
+                    delete_global_state(conn, object_id)?;
+                }
+                Ok(())
+            })
+            .map_err(|e| IndexerError::SQLiteWriteError(e.to_string()))
+            .context("Failed to delete global states to SQLiteDB")?;
 
-        diesel::delete(
-            global_states::table.filter(global_states::object_id.eq_any(state_pks.as_slice())),
-        )
-        .execute(&mut connection)
-        .map_err(|e| IndexerError::SQLiteWriteError(e.to_string()))
-        .context("Failed to delete global states to SQLiteDB")?;
+        Ok(())
+    }
+
+    /// Record the owner transitions of the upserted and removed global states of the transaction at `tx_order`,
+    /// the old owners are read from the global states, so it must be called before the global states are updated.
+    pub fn persist_object_ownership_changes(
+        &self,
+        tx_order: u64,
+        states: &[IndexedGlobalState],
+        removed_object_ids: &[String],
+    ) -> Result<(), IndexerError> {
+        if states.is_empty() && removed_object_ids.is_empty() {
+            return Ok(());
+        }
+
+        let mut connection = get_sqlite_pool_connection(&self.connection_pool)?;
+        let created_at = now_seconds();
+        let mut queries = vec![];
+        if !states.is_empty() {
+            let values_clause = states
+                .iter()
+                .map(|state| {
+                    format!(
+                        "('{}', '{}')",
+                        escape_sql_string(state.object_id.to_string()),
+                        escape_sql_string(state.owner.to_hex_literal()),
+                    )
+                })
+                .collect::<Vec<_>>()
+                .join(",");
+            queries.push(format!(
+                "
+                    WITH new_owners (object_id, owner) AS (VALUES {}) \
+                    INSERT INTO object_ownership_changes (object_id, tx_order, old_owner, new_owner, created_at) \
+                    SELECT new_owners.object_id, {}, global_states.owner, new_owners.owner, {} \
+                    FROM new_owners LEFT JOIN global_states ON global_states.object_id = new_owners.object_id \
+                    WHERE global_states.owner IS NULL OR global_states.owner != new_owners.owner \
+                    ON CONFLICT (object_id, tx_order) DO UPDATE SET \
+                    new_owner = excluded.new_owner, \
+                    created_at = excluded.created_at
+                ",
+                values_clause, tx_order, created_at,
+            ));
+        }
+        if !removed_object_ids.is_empty() {
+            let object_ids = removed_object_ids
+                .iter()
+                .map(|object_id| format!("'{}'", escape_sql_string(object_id.clone())))
+                .collect::<Vec<_>>()
+                .join(",");
+            queries.push(format!(
+                "
+                    INSERT INTO object_ownership_changes (object_id, tx_order, old_owner, new_owner, created_at) \
+                    SELECT object_id, {}, owner, NULL, {} \
+                    FROM global_states WHERE object_id IN ({}) \
+                    ON CONFLICT (object_id, tx_order) DO UPDATE SET \
+                    new_owner = excluded.new_owner, \
+                    created_at = excluded.created_at
+                ",
+                tx_order, created_at, object_ids,
+            ));
+        }
+
+        connection
+            .transaction::<_, diesel::result::Error, _>(|conn| {
+                for query in queries {
+                    diesel::sql_query(query).execute(conn)?;
+                }
+                Ok(())
+            })
+            .map_err(|e| IndexerError::SQLiteWriteError(e.to_string()))
+            .context("Failed to write object ownership changes to SQLiteDB")?;
+
+        Ok(())
+    }
+
+    pub fn persist_or_update_utxos(
+        &self,
+        states: Vec<IndexedUTXOState>,
+    ) -> Result<(), IndexerError> {
+        if states.is_empty() {
+            return Ok(());
+        }
+
+        let mut connection = get_sqlite_pool_connection(&self.connection_pool)?;
+        connection
+            .transaction::<_, diesel::result::Error, _>(|conn| {
+                for state in states {
+                    upsert_utxo(conn, &StoredUTXOState::from(state))?;
+                }
+                Ok(())
+            })
+            .map_err(|e| {
+                tracing::error!(target: LOG_TARGET, "Upsert utxos error: {}", e);
+                IndexerError::SQLiteWriteError(e.to_string())
+            })
+            .context("Failed to write or update utxos to SQLiteDB")?;
+
+        Ok(())
+    }
+
+    pub fn persist_or_update_coin_infos(
+        &self,
+        coin_infos: Vec<IndexedCoinInfo>,
+    ) -> Result<(), IndexerError> {
+        if coin_infos.is_empty() {
+            return Ok(());
+        }
+
+        let mut connection = get_sqlite_pool_connection(&self.connection_pool)?;
+        connection
+            .transaction::<_, diesel::result::Error, _>(|conn| {
+                for coin_info in coin_infos {
+                    upsert_coin_info(conn, &StoredCoinInfo::from(coin_info))?;
+                }
+                Ok(())
+            })
+            .map_err(|e| {
+                tracing::error!(target: LOG_TARGET, "Upsert coin infos error: {}", e);
+                IndexerError::SQLiteWriteError(e.to_string())
+            })
+            .context("Failed to write or update coin infos to SQLiteDB")?;
+
+        Ok(())
+    }
+
+    pub fn delete_utxos(&self, state_pks: Vec<String>) -> Result<(), IndexerError> {
+        if state_pks.is_empty() {
+            return Ok(());
+        }
+
+        let mut connection = get_sqlite_pool_connection(&self.connection_pool)?;
+        connection
+            .transaction::<_, diesel::result::Error, _>(|conn| {
+                for object_id in state_pks.iter() {
+                    delete_utxo(conn, object_id)?;
+                }
+                Ok(())
+            })
+            .map_err(|e| IndexerError::SQLiteWriteError(e.to_string()))
+            .context("Failed to delete utxos to SQLiteDB")?;
 
         Ok(())
     }
@@ -133,50 +242,15 @@ impl SqliteIndexerStore {
         }
 
         let mut connection = get_sqlite_pool_connection(&self.connection_pool)?;
-        let states = states
-            .into_iter()
-            .map(StoredTableState::from)
-            .collect::<Vec<_>>();
-
-        // Diesel for SQLite don't support batch update yet, so implements batch update directly via raw SQL
-        let values_clause = states
-            .into_iter()
-            .map(|state| {
-                format!(
-                    "('{}', '{}', '{}', '{}', '{}', '{}', {}, {}, {}, {})",
-                    escape_sql_string(state.table_handle),
-                    escape_sql_string(state.key_hex),
-                    escape_sql_string(state.key_str),
-                    escape_sql_string(state.value),
-                    escape_sql_string(state.key_type),
-                    escape_sql_string(state.value_type),
-                    state.tx_order,
-                    state.state_index,
-                    state.created_at,
-                    state.updated_at,
-                )
+        connection
+            .transaction::<_, diesel::result::Error, _>(|conn| {
+                for state in states {
+                    upsert_table_state(conn, &StoredTableState::from(state))?;
+                }
+                Ok(())
             })
-            .collect::<Vec<_>>()
-            .join(",");
-        let query = format!(
-            "
-                INSERT INTO table_states (table_handle, key_hex, key_str, value, key_type, value_type, tx_order, state_index, created_at, updated_at) \
-                VALUES {} \
-                ON CONFLICT (table_handle, key_hex) DO UPDATE SET \
-                value = excluded.value, \
-                value_type = excluded.value_type, \
-                tx_order = excluded.tx_order, \
-                state_index = excluded.state_index, \
-                updated_at = excluded.updated_at
-            ",
-            values_clause
-        );
-
-        // Execute the raw SQL query
-        diesel::sql_query(query.clone())
-            .execute(&mut connection)
             .map_err(|e| {
-                log::error!("Upsert table states Executing Query error: {}", query);
+                tracing::error!(target: LOG_TARGET, "Upsert table states error: {}", e);
                 IndexerError::SQLiteWriteError(e.to_string())
             })
             .context("Failed to write or update table states to SQLiteDB")?;
@@ -193,32 +267,15 @@ impl SqliteIndexerStore {
         }
 
         let mut connection = get_sqlite_pool_connection(&self.connection_pool)?;
-        // Diesel for SQLite don't support batch delete on composite primary key yet, so implements batch delete directly via raw SQL
-        let values_clause = state_pks
-            .into_iter()
-            .map(|pk| {
-                format!(
-                    "('{}', '{}')",
-                    escape_sql_string(pk.0),
-                    escape_sql_string(pk.1),
-                )
+        connection
+            .transaction::<_, diesel::result::Error, _>(|conn| {
+                for (table_handle, key_hex) in state_pks.iter() {
+                    delete_table_state(conn, table_handle, key_hex)?;
+                }
+                Ok(())
             })
-            .collect::<Vec<_>>()
-            .join(",");
-
-        let query = format!(
-            "
-                DELETE FROM table_states \
-                WHERE (table_handle, key_hex) IN ({})
-            ",
-            values_clause
-        );
-
-        // Execute the raw SQL query
-        diesel::sql_query(query.clone())
-            .execute(&mut connection)
             .map_err(|e| {
-                log::error!("Delete table states Executing Query error: {}", query);
+                tracing::error!(target: LOG_TARGET, "Delete table states error: {}", e);
                 IndexerError::SQLiteWriteError(e.to_string())
             })
             .context("Failed to delete table states to SQLiteDB")?;
@@ -235,12 +292,15 @@ impl SqliteIndexerStore {
         }
 
         let mut connection = get_sqlite_pool_connection(&self.connection_pool)?;
-        diesel::delete(
-            table_states::table.filter(table_states::table_handle.eq_any(table_handles.as_slice())),
-        )
-        .execute(&mut connection)
-        .map_err(|e| IndexerError::SQLiteWriteError(e.to_string()))
-        .context("Failed to delete table states by table handles to SQLiteDB")?;
+        connection
+            .transaction::<_, diesel::result::Error, _>(|conn| {
+                for table_handle in table_handles.iter() {
+                    delete_table_states_of_table(conn, table_handle)?;
+                }
+                Ok(())
+            })
+            .map_err(|e| IndexerError::SQLiteWriteError(e.to_string()))
+            .context("Failed to delete table states by table handles to SQLiteDB")?;
 
         Ok(())
     }
@@ -254,14 +314,10 @@ impl SqliteIndexerStore {
         }
 
         let mut connection = get_sqlite_pool_connection(&self.connection_pool)?;
-        let table_change_sets = table_change_sets
-            .into_iter()
-            .map(StoredTableChangeSet::from)
-            .collect::<Vec<_>>();
-
-        diesel::insert_into(table_change_sets::table)
-            .values(table_change_sets.as_slice())
-            .execute(&mut connection)
+        connection
+            .transaction::<_, diesel::result::Error, _>(|conn| {
+                insert_table_change_sets(conn, table_change_sets)
+            })
             .map_err(|e| IndexerError::SQLiteWriteError(e.to_string()))
             .context("Failed to write table change sets to SQLiteDB")?;
 
@@ -277,14 +333,10 @@ impl SqliteIndexerStore {
         }
 
         let mut connection = get_sqlite_pool_connection(&self.connection_pool)?;
-        let transactions = transactions
-            .into_iter()
-            .map(StoredTransaction::from)
-            .collect::<Vec<_>>();
-
-        diesel::insert_into(transactions::table)
-            .values(transactions.as_slice())
-            .execute(&mut connection)
+        connection
+            .transaction::<_, diesel::result::Error, _>(|conn| {
+                insert_transactions(conn, transactions)
+            })
             .map_err(|e| IndexerError::SQLiteWriteError(e.to_string()))
             .context("Failed to write transactions to SQLiteDB")?;
 
@@ -297,17 +349,662 @@ impl SqliteIndexerStore {
         }
 
         let mut connection = get_sqlite_pool_connection(&self.connection_pool)?;
-        let events = events
-            .into_iter()
-            .map(StoredEvent::from)
-            .collect::<Vec<_>>();
-
-        diesel::insert_into(events::table)
-            .values(events.as_slice())
-            .execute(&mut connection)
+        connection
+            .transaction::<_, diesel::result::Error, _>(|conn| insert_events(conn, events))
             .map_err(|e| IndexerError::SQLiteWriteError(e.to_string()))
             .context("Failed to write events to SQLiteDB")?;
 
         Ok(())
     }
+
+    pub fn update_indexer_progress(
+        &self,
+        table: IndexerProgressTable,
+        tx_order: u64,
+    ) -> Result<(), IndexerError> {
+        let mut connection = get_sqlite_pool_connection(&self.connection_pool)?;
+        upsert_indexer_progress(&mut connection, table, tx_order, now_seconds())
+            .map_err(|e| IndexerError::SQLiteWriteError(e.to_string()))
+            .context("Failed to update indexer progress to SQLiteDB")?;
+
+        Ok(())
+    }
+
+    /// Get the owners of the objects in the global states, the objects not found are omitted
+    pub fn get_object_owners(
+        &self,
+        object_ids: &[String],
+    ) -> Result<BTreeMap<String, String>, IndexerError> {
+        if object_ids.is_empty() {
+            return Ok(BTreeMap::new());
+        }
+
+        let mut connection = get_sqlite_pool_connection(&self.connection_pool)?;
+        let owners = global_states::table
+            .select((global_states::object_id, global_states::owner))
+            .filter(global_states::object_id.eq_any(object_ids))
+            .load::<(String, String)>(&mut connection)
+            .map_err(|e| IndexerError::SQLiteReadError(e.to_string()))?;
+
+        Ok(owners.into_iter().collect())
+    }
+
+    /// Get the table change sets from the tx order (inclusive), ordered by the tx order and the state index
+    pub fn get_table_change_sets_from(
+        &self,
+        tx_order: u64,
+    ) -> Result<Vec<IndexerTableChangeSet>, IndexerError> {
+        let mut connection = get_sqlite_pool_connection(&self.connection_pool)?;
+        let stored_table_change_sets = table_change_sets::table
+            .filter(table_change_sets::tx_order.ge(tx_order as i64))
+            .order((
+                table_change_sets::tx_order.asc(),
+                table_change_sets::state_index.asc(),
+            ))
+            .load::<StoredTableChangeSet>(&mut connection)
+            .map_err(|e| IndexerError::SQLiteReadError(e.to_string()))?;
+
+        stored_table_change_sets
+            .iter()
+            .map(|table_change_set| {
+                table_change_set
+                    .try_into_indexer_state_change_set()
+                    .map_err(|e| IndexerError::SQLiteReadError(e.to_string()))
+            })
+            .collect()
+    }
+
+    /// Commit the batch in one SQLite transaction per indexer progress table, the progress is updated in
+    /// the same transaction as the indexed data of the table, so it never runs ahead of the data.
+    /// The rows are written one by one with the same SQL, so the prepared statements are reused by the connection.
+    pub fn commit_batch(&self, batch: IndexerBatch) -> Result<(), IndexerError> {
+        if batch.is_empty() {
+            return Ok(());
+        }
+
+        let mut connection = get_sqlite_pool_connection(&self.connection_pool)?;
+        let updated_at = now_seconds();
+        let states_progress = batch.progress(IndexerProgressTable::States);
+        let transactions_progress = batch.progress(IndexerProgressTable::Transactions);
+        let events_progress = batch.progress(IndexerProgressTable::Events);
+
+        if let Some(tx_order) = states_progress {
+            connection
+                .transaction::<_, diesel::result::Error, _>(|conn| {
+                    for mut change in batch.object_ownership_changes {
+                        change.created_at = Some(updated_at as i64);
+                        diesel::replace_into(object_ownership_changes::table)
+                            .values(&change)
+                            .execute(conn)?;
+                    }
+                    for (object_id, state) in batch.global_states {
+                        match state {
+                            Some(state) => {
+                                upsert_global_state(conn, &StoredGlobalState::from(state))?
+                            }
+                            None => delete_global_state(conn, &object_id)?,
+                        };
+                    }
+                    for (object_id, utxo) in batch.utxos {
+                        match utxo {
+                            Some(utxo) => upsert_utxo(conn, &StoredUTXOState::from(utxo))?,
+                            None => delete_utxo(conn, &object_id)?,
+                        };
+                    }
+                    for coin_info in batch.coin_infos.into_values() {
+                        upsert_coin_info(conn, &StoredCoinInfo::from(coin_info))?;
+                    }
+                    for (multichain_address, mapping) in batch.address_mappings {
+                        match mapping {
+                            Some(mapping) => upsert_address_mapping(
+                                conn,
+                                &StoredAddressMapping::new(mapping, updated_at),
+                            )?,
+                            None => delete_address_mapping(conn, &multichain_address)?,
+                        };
+                    }
+                    for module in batch.modules.into_values() {
+                        upsert_module(conn, &StoredModule::new(module, updated_at))?;
+                    }
+                    for table_handle in batch.removed_table_handles.iter() {
+                        delete_table_states_of_table(conn, table_handle)?;
+                    }
+                    for ((table_handle, key_hex), state) in batch.table_states {
+                        match state {
+                            Some(state) => {
+                                upsert_table_state(conn, &StoredTableState::from(state))?
+                            }
+                            None => delete_table_state(conn, &table_handle, &key_hex)?,
+                        };
+                    }
+                    insert_table_change_sets(conn, batch.table_change_sets)?;
+                    upsert_indexer_progress(
+                        conn,
+                        IndexerProgressTable::States,
+                        tx_order,
+                        updated_at,
+                    )
+                })
+                .map_err(|e| IndexerError::SQLiteWriteError(e.to_string()))
+                .context("Failed to commit the states batch to SQLiteDB")?;
+        }
+
+        if let Some(tx_order) = transactions_progress {
+            connection
+                .transaction::<_, diesel::result::Error, _>(|conn| {
+                    insert_transactions(conn, batch.transactions)?;
+                    upsert_indexer_progress(
+                        conn,
+                        IndexerProgressTable::Transactions,
+                        tx_order,
+                        updated_at,
+                    )
+                })
+                .map_err(|e| IndexerError::SQLiteWriteError(e.to_string()))
+                .context("Failed to commit the transactions batch to SQLiteDB")?;
+        }
+
+        if let Some(tx_order) = events_progress {
+            connection
+                .transaction::<_, diesel::result::Error, _>(|conn| {
+                    insert_events(conn, batch.events)?;
+                    insert_coin_transfers(conn, batch.coin_transfers)?;
+                    upsert_indexer_progress(
+                        conn,
+                        IndexerProgressTable::Events,
+                        tx_order,
+                        updated_at,
+                    )
+                })
+                .map_err(|e| IndexerError::SQLiteWriteError(e.to_string()))
+                .context("Failed to commit the events batch to SQLiteDB")?;
+        }
+
+        Ok(())
+    }
+
+    pub fn get_indexer_progress(
+        &self,
+        table: IndexerProgressTable,
+    ) -> Result<Option<u64>, IndexerError> {
+        let mut connection = get_sqlite_pool_connection(&self.connection_pool)?;
+        let progress = indexer_progress::table
+            .filter(indexer_progress::table_name.eq(table.name()))
+            .first::<StoredIndexerProgress>(&mut connection)
+            .optional()
+            .map_err(|e| IndexerError::SQLiteReadError(e.to_string()))?;
+
+        Ok(progress.map(|progress| progress.last_indexed_order as u64))
+    }
+
+    /// Delete the indexed data of the transactions from `tx_order`, and rewind the indexer progress to `tx_order - 1`.
+    /// The states tables only keep the latest states, so they are cleared only if rewinding to the first transaction,
+    /// otherwise the states are overwritten when the transactions are indexed again.
+    pub fn delete_from_tx_order(&self, tx_order: u64) -> Result<(), IndexerError> {
+        let mut connection = get_sqlite_pool_connection(&self.connection_pool)?;
+        let tx_order = tx_order.max(1);
+        let mut queries = [
+            "transactions",
+            "transactions_fts",
+            "events",
+            "events_fts",
+            "event_blooms",
+            "coin_transfers",
+            "table_change_sets",
+            "object_ownership_changes",
+            "modules",
+        ]
+        .iter()
+        .map(|table| format!("DELETE FROM {} WHERE tx_order >= {}", table, tx_order))
+        .collect::<Vec<_>>();
+        // Rebuild the account statistics from the remaining transactions
+        queries.push("DELETE FROM account_stats".to_string());
+        queries.push(
+            "
+                INSERT INTO account_stats (address, tx_count, gas_used, first_tx_order, last_tx_order, first_active_at, last_active_at) \
+                SELECT sender, COUNT(*), SUM(gas_used), MIN(tx_order), MAX(tx_order), NULL, NULL \
+                FROM transactions GROUP BY sender
+            "
+            .to_string(),
+        );
+        if tx_order == 1 {
+            queries.extend(
+                [
+                    "global_states",
+                    "table_states",
+                    "utxos",
+                    "coin_infos",
+                    "address_mappings",
+                    "modules",
+                ]
+                .iter()
+                .map(|table| format!("DELETE FROM {}", table)),
+            );
+        }
+        let updated_at = now_seconds();
+
+        connection
+            .transaction::<_, diesel::result::Error, _>(|conn| {
+                for query in queries {
+                    diesel::sql_query(query).execute(conn)?;
+                }
+                for table in IndexerProgressTable::ALL {
+                    upsert_indexer_progress(conn, table, tx_order - 1, updated_at)?;
+                }
+                Ok(())
+            })
+            .map_err(|e| IndexerError::SQLiteWriteError(e.to_string()))
+            .context("Failed to delete indexed data from SQLiteDB")?;
+
+        Ok(())
+    }
+
+    /// Prune the rows of the history tables which are out of the retention, the latest `n` transactions are retained.
+    /// The rows are deleted in batches of `batch_size`, so the writer lock is not held for a long time.
+    /// Returns the number of the pruned rows of every history table with retention.
+    pub fn prune_history(
+        &self,
+        retention: &IndexerRetention,
+        batch_size: u64,
+    ) -> Result<BTreeMap<String, u64>, IndexerError> {
+        let mut pruned = BTreeMap::new();
+        let mut last_tx_order = None;
+        for table in IndexerProgressTable::ALL {
+            last_tx_order = last_tx_order.max(self.get_indexer_progress(table)?);
+        }
+        let Some(last_tx_order) = last_tx_order else {
+            return Ok(pruned);
+        };
+
+        let mut connection = get_sqlite_pool_connection(&self.connection_pool)?;
+        for table in IndexerHistoryTable::ALL {
+            let Some(retained) = table.retention(retention) else {
+                continue;
+            };
+            let before_tx_order = (last_tx_order + 1).saturating_sub(retained);
+            let mut total = 0u64;
+            for sqlite_table in table.sqlite_tables() {
+                loop {
+                    let deleted = diesel::sql_query(format!(
+                        "DELETE FROM {sqlite_table} WHERE rowid IN (SELECT rowid FROM {sqlite_table} WHERE tx_order < {before_tx_order} LIMIT {batch_size})"
+                    ))
+                    .execute(&mut connection)
+                    .map_err(|e| IndexerError::SQLiteWriteError(e.to_string()))
+                    .context("Failed to prune indexed history from SQLiteDB")?;
+                    // The full-text search and the bloom filter rows are not counted
+                    if *sqlite_table == table.name() {
+                        total += deleted as u64;
+                    }
+                    if (deleted as u64) < batch_size {
+                        break;
+                    }
+                }
+            }
+            if total > 0 {
+                tracing::info!(
+                    target: LOG_TARGET,
+                    "Pruned {} rows of {} before tx order {}",
+                    total,
+                    table,
+                    before_tx_order
+                );
+            }
+            pruned.insert(table.to_string(), total);
+        }
+
+        Ok(pruned)
+    }
+}
+
+fn upsert_global_state(conn: &mut SqliteConnection, state: &StoredGlobalState) -> QueryResult<()> {
+    diesel::sql_query(
+        "
+            INSERT INTO global_states (object_id, owner, flag, value, object_type, state_root, size, tx_order, state_index, created_at, updated_at, state_size) \
+            VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?) \
+            ON CONFLICT (object_id) DO UPDATE SET \
+            owner = excluded.owner, \
+            flag = excluded.flag, \
+            value = excluded.value, \
+            state_root = excluded.state_root, \
+            size = excluded.size, \
+            tx_order = excluded.tx_order, \
+            state_index = excluded.state_index, \
+            updated_at = excluded.updated_at, \
+            state_size = excluded.state_size
+        ",
+    )
+    .bind::<Text, _>(&state.object_id)
+    .bind::<Text, _>(&state.owner)
+    .bind::<SmallInt, _>(state.flag)
+    .bind::<Text, _>(&state.value)
+    .bind::<Text, _>(&state.object_type)
+    .bind::<Text, _>(&state.state_root)
+    .bind::<BigInt, _>(state.size)
+    .bind::<BigInt, _>(state.tx_order)
+    .bind::<BigInt, _>(state.state_index)
+    .bind::<BigInt, _>(state.created_at)
+    .bind::<BigInt, _>(state.updated_at)
+    .bind::<BigInt, _>(state.state_size)
+    .execute(conn)?;
+    Ok(())
+}
+
+fn delete_global_state(conn: &mut SqliteConnection, object_id: &str) -> QueryResult<()> {
+    diesel::sql_query("DELETE FROM global_states WHERE object_id = ?")
+        .bind::<Text, _>(object_id)
+        .execute(conn)?;
+    Ok(())
+}
+
+fn upsert_utxo(conn: &mut SqliteConnection, state: &StoredUTXOState) -> QueryResult<()> {
+    diesel::sql_query(
+        "
+            INSERT INTO utxos (object_id, owner, flag, txid, vout, value, seals, tx_order, state_index, created_at, updated_at) \
+            VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?) \
+            ON CONFLICT (object_id) DO UPDATE SET \
+            owner = excluded.owner, \
+            flag = excluded.flag, \
+            seals = excluded.seals, \
+            tx_order = excluded.tx_order, \
+            state_index = excluded.state_index, \
+            updated_at = excluded.updated_at
+        ",
+    )
+    .bind::<Text, _>(&state.object_id)
+    .bind::<Text, _>(&state.owner)
+    .bind::<SmallInt, _>(state.flag)
+    .bind::<Text, _>(&state.txid)
+    .bind::<BigInt, _>(state.vout)
+    .bind::<BigInt, _>(state.value)
+    .bind::<Text, _>(&state.seals)
+    .bind::<BigInt, _>(state.tx_order)
+    .bind::<BigInt, _>(state.state_index)
+    .bind::<BigInt, _>(state.created_at)
+    .bind::<BigInt, _>(state.updated_at)
+    .execute(conn)?;
+    Ok(())
+}
+
+fn delete_utxo(conn: &mut SqliteConnection, object_id: &str) -> QueryResult<()> {
+    diesel::sql_query("DELETE FROM utxos WHERE object_id = ?")
+        .bind::<Text, _>(object_id)
+        .execute(conn)?;
+    Ok(())
+}
+
+fn upsert_coin_info(conn: &mut SqliteConnection, coin_info: &StoredCoinInfo) -> QueryResult<()> {
+    diesel::sql_query(
+        "
+            INSERT INTO coin_infos (coin_type, name, symbol, icon_url, decimals, supply, tx_order) \
+            VALUES (?, ?, ?, ?, ?, ?, ?) \
+            ON CONFLICT (coin_type) DO UPDATE SET \
+            name = excluded.name, \
+            symbol = excluded.symbol, \
+            icon_url = excluded.icon_url, \
+            decimals = excluded.decimals, \
+            supply = excluded.supply, \
+            tx_order = excluded.tx_order
+        ",
+    )
+    .bind::<Text, _>(&coin_info.coin_type)
+    .bind::<Text, _>(&coin_info.name)
+    .bind::<Text, _>(&coin_info.symbol)
+    .bind::<Nullable<Text>, _>(&coin_info.icon_url)
+    .bind::<SmallInt, _>(coin_info.decimals)
+    .bind::<Text, _>(&coin_info.supply)
+    .bind::<BigInt, _>(coin_info.tx_order)
+    .execute(conn)?;
+    Ok(())
+}
+
+/// The mapping is created when the multichain address is bound, so the created time is kept on update
+fn upsert_address_mapping(
+    conn: &mut SqliteConnection,
+    mapping: &StoredAddressMapping,
+) -> QueryResult<()> {
+    diesel::sql_query(
+        "
+            INSERT INTO address_mappings (multichain_id, raw_address, rooch_address, tx_order, created_at) \
+            VALUES (?, ?, ?, ?, ?) \
+            ON CONFLICT (multichain_id, raw_address) DO UPDATE SET \
+            rooch_address = excluded.rooch_address, \
+            tx_order = excluded.tx_order
+        ",
+    )
+    .bind::<BigInt, _>(mapping.multichain_id)
+    .bind::<Text, _>(&mapping.raw_address)
+    .bind::<Text, _>(&mapping.rooch_address)
+    .bind::<BigInt, _>(mapping.tx_order)
+    .bind::<BigInt, _>(mapping.created_at)
+    .execute(conn)?;
+    Ok(())
+}
+
+fn delete_address_mapping(
+    conn: &mut SqliteConnection,
+    multichain_address: &MultiChainAddress,
+) -> QueryResult<()> {
+    diesel::sql_query("DELETE FROM address_mappings WHERE multichain_id = ? AND raw_address = ?")
+        .bind::<BigInt, _>(multichain_address.multichain_id.multichain_id().id() as i64)
+        .bind::<Text, _>(hex::encode(&multichain_address.raw_address))
+        .execute(conn)?;
+    Ok(())
+}
+
+/// The module is created when it is first published, so the created time is kept on upgrade
+fn upsert_module(conn: &mut SqliteConnection, module: &StoredModule) -> QueryResult<()> {
+    diesel::sql_query(
+        "
+            INSERT INTO modules (address, name, bytecode_hash, source_digest, tx_order, state_index, created_at, updated_at) \
+            VALUES (?, ?, ?, ?, ?, ?, ?, ?) \
+            ON CONFLICT (address, name) DO UPDATE SET \
+            bytecode_hash = excluded.bytecode_hash, \
+            source_digest = excluded.source_digest, \
+            tx_order = excluded.tx_order, \
+            state_index = excluded.state_index, \
+            updated_at = excluded.updated_at
+        ",
+    )
+    .bind::<Text, _>(&module.address)
+    .bind::<Text, _>(&module.name)
+    .bind::<Text, _>(&module.bytecode_hash)
+    .bind::<Nullable<Text>, _>(&module.source_digest)
+    .bind::<BigInt, _>(module.tx_order)
+    .bind::<BigInt, _>(module.state_index)
+    .bind::<BigInt, _>(module.created_at)
+    .bind::<BigInt, _>(module.updated_at)
+    .execute(conn)?;
+    Ok(())
+}
+
+fn upsert_table_state(conn: &mut SqliteConnection, state: &StoredTableState) -> QueryResult<()> {
+    diesel::sql_query(
+        "
+            INSERT INTO table_states (table_handle, key_hex, key_str, value, key_type, value_type, tx_order, state_index, created_at, updated_at, state_size) \
+            VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?) \
+            ON CONFLICT (table_handle, key_hex) DO UPDATE SET \
+            value = excluded.value, \
+            value_type = excluded.value_type, \
+            tx_order = excluded.tx_order, \
+            state_index = excluded.state_index, \
+            updated_at = excluded.updated_at, \
+            state_size = excluded.state_size
+        ",
+    )
+    .bind::<Text, _>(&state.table_handle)
+    .bind::<Text, _>(&state.key_hex)
+    .bind::<Text, _>(&state.key_str)
+    .bind::<Text, _>(&state.value)
+    .bind::<Text, _>(&state.key_type)
+    .bind::<Text, _>(&state.value_type)
+    .bind::<BigInt, _>(state.tx_order)
+    .bind::<BigInt, _>(state.state_index)
+    .bind::<BigInt, _>(state.created_at)
+    .bind::<BigInt, _>(state.updated_at)
+    .bind::<BigInt, _>(state.state_size)
+    .execute(conn)?;
+    Ok(())
+}
+
+fn delete_table_state(
+    conn: &mut SqliteConnection,
+    table_handle: &str,
+    key_hex: &str,
+) -> QueryResult<()> {
+    diesel::sql_query("DELETE FROM table_states WHERE table_handle = ? AND key_hex = ?")
+        .bind::<Text, _>(table_handle)
+        .bind::<Text, _>(key_hex)
+        .execute(conn)?;
+    Ok(())
+}
+
+fn delete_table_states_of_table(
+    conn: &mut SqliteConnection,
+    table_handle: &str,
+) -> QueryResult<()> {
+    diesel::sql_query("DELETE FROM table_states WHERE table_handle = ?")
+        .bind::<Text, _>(table_handle)
+        .execute(conn)?;
+    Ok(())
+}
+
+fn insert_table_change_sets(
+    conn: &mut SqliteConnection,
+    table_change_sets: Vec<IndexedTableChangeSet>,
+) -> QueryResult<()> {
+    for table_change_set in table_change_sets {
+        diesel::insert_into(table_change_sets::table)
+            .values(StoredTableChangeSet::from(table_change_set))
+            .execute(conn)?;
+    }
+    Ok(())
+}
+
+/// Insert the transactions with the full-text search index of the transaction action,
+/// and accumulate the account statistics of the senders.
+fn insert_transactions(
+    conn: &mut SqliteConnection,
+    transactions: Vec<IndexedTransaction>,
+) -> QueryResult<()> {
+    let mut stored_transactions = Vec::with_capacity(transactions.len());
+    for transaction in transactions {
+        diesel::sql_query("INSERT INTO transactions_fts (action, tx_order) VALUES (?, ?)")
+            .bind::<Text, _>(transaction.action_search_text())
+            .bind::<BigInt, _>(transaction.tx_order as i64)
+            .execute(conn)?;
+        let transaction = StoredTransaction::from(transaction);
+        diesel::insert_into(transactions::table)
+            .values(&transaction)
+            .execute(conn)?;
+        stored_transactions.push(transaction);
+    }
+    upsert_account_stats(conn, stored_transactions.as_slice())
+}
+
+/// Insert the events with the full-text search index of the event data, and the bloom of the events of each transaction
+fn insert_events(conn: &mut SqliteConnection, events: Vec<IndexedEvent>) -> QueryResult<()> {
+    let mut stored_events = Vec::with_capacity(events.len());
+    for event in events {
+        diesel::sql_query(
+            "INSERT INTO events_fts (event_type, event_data, tx_order, event_index) VALUES (?, ?, ?, ?)",
+        )
+        .bind::<Text, _>(format!("0x{}", event.event_type.to_canonical_string()))
+        .bind::<Text, _>(&event.event_data_json)
+        .bind::<BigInt, _>(event.tx_order as i64)
+        .bind::<BigInt, _>(event.event_index as i64)
+        .execute(conn)?;
+        let event = StoredEvent::from(event);
+        diesel::insert_into(events::table)
+            .values(&event)
+            .execute(conn)?;
+        stored_events.push(event);
+    }
+
+    let mut tx_events = BTreeMap::<i64, Vec<&StoredEvent>>::new();
+    for event in stored_events.iter() {
+        tx_events.entry(event.tx_order).or_default().push(event);
+    }
+    for (tx_order, events) in tx_events {
+        diesel::replace_into(event_blooms::table)
+            .values(StoredEventBloom::new(tx_order as u64, events))
+            .execute(conn)?;
+    }
+    Ok(())
+}
+
+fn insert_coin_transfers(
+    conn: &mut SqliteConnection,
+    coin_transfers: Vec<IndexedCoinTransfer>,
+) -> QueryResult<()> {
+    for coin_transfer in coin_transfers {
+        diesel::insert_into(coin_transfers::table)
+            .values(&StoredCoinTransfer::from(coin_transfer))
+            .execute(conn)?;
+    }
+    Ok(())
+}
+
+/// Upsert the account statistics of the transactions' senders,
+/// the statistics are accumulated to the existing ones.
+fn upsert_account_stats(
+    conn: &mut SqliteConnection,
+    transactions: &[StoredTransaction],
+) -> QueryResult<()> {
+    let mut stats: BTreeMap<&str, (i64, i64, i64, i64)> = BTreeMap::new();
+    for transaction in transactions {
+        let (tx_count, gas_used, first_tx_order, last_tx_order) = stats
+            .entry(transaction.sender.as_str())
+            .or_insert((0, 0, transaction.tx_order, transaction.tx_order));
+        *tx_count += 1;
+        *gas_used = gas_used.saturating_add(transaction.gas_used);
+        *first_tx_order = (*first_tx_order).min(transaction.tx_order);
+        *last_tx_order = (*last_tx_order).max(transaction.tx_order);
+    }
+    let active_at = now_seconds() as i64;
+    for (sender, (tx_count, gas_used, first_tx_order, last_tx_order)) in stats {
+        diesel::sql_query(
+            "
+                INSERT INTO account_stats (address, tx_count, gas_used, first_tx_order, last_tx_order, first_active_at, last_active_at) \
+                VALUES (?, ?, ?, ?, ?, ?, ?) \
+                ON CONFLICT (address) DO UPDATE SET \
+                tx_count = tx_count + excluded.tx_count, \
+                gas_used = gas_used + excluded.gas_used, \
+                first_tx_order = MIN(first_tx_order, excluded.first_tx_order), \
+                last_tx_order = MAX(last_tx_order, excluded.last_tx_order), \
+                first_active_at = COALESCE(first_active_at, excluded.first_active_at), \
+                last_active_at = excluded.last_active_at
+            ",
+        )
+        .bind::<Text, _>(sender)
+        .bind::<BigInt, _>(tx_count)
+        .bind::<BigInt, _>(gas_used)
+        .bind::<BigInt, _>(first_tx_order)
+        .bind::<BigInt, _>(last_tx_order)
+        .bind::<BigInt, _>(active_at)
+        .bind::<BigInt, _>(active_at)
+        .execute(conn)?;
+    }
+    Ok(())
+}
+
+fn upsert_indexer_progress(
+    conn: &mut SqliteConnection,
+    table: IndexerProgressTable,
+    tx_order: u64,
+    updated_at: u64,
+) -> QueryResult<()> {
+    diesel::sql_query(
+        "
+            INSERT INTO indexer_progress (table_name, last_indexed_order, updated_at) \
+            VALUES (?, ?, ?) \
+            ON CONFLICT (table_name) DO UPDATE SET \
+            last_indexed_order = excluded.last_indexed_order, \
+            updated_at = excluded.updated_at
+        ",
+    )
+    .bind::<Text, _>(table.name())
+    .bind::<BigInt, _>(tx_order as i64)
+    .bind::<BigInt, _>(updated_at as i64)
+    .execute(conn)?;
+    Ok(())
 }
@@ -2,9 +2,14 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::errors::IndexerError;
+use crate::store::batch::IndexerBatch;
 use crate::types::{
-    IndexedEvent, IndexedGlobalState, IndexedTableChangeSet, IndexedTableState, IndexedTransaction,
+    IndexedCoinInfo, IndexedEvent, IndexedGlobalState, IndexedTableChangeSet, IndexedTableState,
+    IndexedTransaction, IndexedUTXOState, IndexerProgressTable,
 };
+use rooch_config::indexer_config::IndexerRetention;
+use rooch_types::indexer::state::IndexerTableChangeSet;
+use std::collections::BTreeMap;
 
 pub trait IndexerStoreTrait: Send + Sync {
     fn persist_or_update_global_states(
@@ -14,6 +19,22 @@ pub trait IndexerStoreTrait: Send + Sync {
 
     fn delete_global_states(&self, state_pks: Vec<String>) -> Result<(), IndexerError>;
 
+    fn persist_object_ownership_changes(
+        &self,
+        tx_order: u64,
+        states: &[IndexedGlobalState],
+        removed_object_ids: &[String],
+    ) -> Result<(), IndexerError>;
+
+    fn persist_or_update_utxos(&self, states: Vec<IndexedUTXOState>) -> Result<(), IndexerError>;
+
+    fn delete_utxos(&self, state_pks: Vec<String>) -> Result<(), IndexerError>;
+
+    fn persist_or_update_coin_infos(
+        &self,
+        coin_infos: Vec<IndexedCoinInfo>,
+    ) -> Result<(), IndexerError>;
+
     fn persist_or_update_table_states(
         &self,
         states: Vec<IndexedTableState>,
@@ -37,4 +58,34 @@ pub trait IndexerStoreTrait: Send + Sync {
     ) -> Result<(), IndexerError>;
 
     fn persist_events(&self, events: Vec<IndexedEvent>) -> Result<(), IndexerError>;
+
+    fn update_indexer_progress(
+        &self,
+        table: IndexerProgressTable,
+        tx_order: u64,
+    ) -> Result<(), IndexerError>;
+
+    fn get_indexer_progress(
+        &self,
+        table: IndexerProgressTable,
+    ) -> Result<Option<u64>, IndexerError>;
+
+    fn delete_from_tx_order(&self, tx_order: u64) -> Result<(), IndexerError>;
+
+    fn prune_history(
+        &self,
+        retention: &IndexerRetention,
+    ) -> Result<BTreeMap<String, u64>, IndexerError>;
+
+    fn get_object_owners(
+        &self,
+        object_ids: &[String],
+    ) -> Result<BTreeMap<String, String>, IndexerError>;
+
+    fn get_table_change_sets_from(
+        &self,
+        tx_order: u64,
+    ) -> Result<Vec<IndexerTableChangeSet>, IndexerError>;
+
+    fn commit_batch(&self, batch: IndexerBatch) -> Result<(), IndexerError>;
 }
@@ -2,28 +2,50 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::indexer_reader::IndexerReader;
+use crate::metrics::IndexerMetrics;
+use crate::proxy::{retry_with_backoff, RetryError};
+use crate::store::batch::IndexerBatch;
 use crate::store::traits::IndexerStoreTrait;
 use crate::types::{
-    IndexedEvent, IndexedGlobalState, IndexedTableChangeSet, IndexedTableState, IndexedTransaction,
+    IndexedAddressMapping, IndexedCoinInfo, IndexedCoinTransfer, IndexedEvent, IndexedGlobalState,
+    IndexedModule, IndexedTableChangeSet, IndexedTableState, IndexedTransaction, IndexedUTXOState,
+    IndexerProgressTable,
 };
 use crate::utils::format_struct_tag;
 use crate::IndexerStore;
 use anyhow::Result;
+use move_binary_format::file_format::empty_module;
 use move_core_types::account_address::AccountAddress;
-use move_core_types::language_storage::StructTag;
+use move_core_types::identifier::Identifier;
+use move_core_types::language_storage::{StructTag, TypeTag};
+use move_core_types::metadata::Metadata;
+use move_core_types::u256::U256;
 use move_core_types::vm_status::KeptVMStatus;
 use moveos_types::h256::H256;
+use moveos_types::move_std::string::MoveString;
 use moveos_types::move_types::{random_struct_tag, random_type_tag};
+use moveos_types::moveos_std::move_module::MoveModule;
 use moveos_types::moveos_std::object_id::ObjectID;
+use moveos_types::moveos_std::simple_multimap::SimpleMultiMap;
 use moveos_types::moveos_std::tx_context::TxContext;
-use moveos_types::state::{MoveStructType, SplitStateChangeSet};
-use moveos_types::transaction::{TransactionExecutionInfo, VerifiedMoveOSTransaction};
+use moveos_types::state::{
+    KeyState, MoveStructState, MoveStructType, MoveType, SplitStateChangeSet, State,
+};
+use moveos_types::transaction::{MoveAction, TransactionExecutionInfo, VerifiedMoveOSTransaction};
 use rand::{thread_rng, Rng};
-use rooch_config::indexer_config::ROOCH_INDEXER_DB_FILENAME;
+use rooch_config::indexer_config::{IndexerRetention, ROOCH_INDEXER_DB_FILENAME};
+use rooch_types::address::{
+    BitcoinAddress, EthereumAddress, MultiChainAddress, RoochSupportedAddress,
+};
 use rooch_types::framework::coin::CoinInfo;
+use rooch_types::framework::coin_store::{DepositEvent, WithdrawEvent};
 use rooch_types::framework::gas_coin::GasCoin;
-use rooch_types::indexer::event_filter::EventFilter;
-use rooch_types::indexer::state::{GlobalStateFilter, TableStateFilter};
+use rooch_types::indexer::coin_transfer::CoinTransferType;
+use rooch_types::indexer::event_filter::{EventFilter, IndexerEvent, IndexerEventID};
+use rooch_types::indexer::module::ModuleFilter;
+use rooch_types::indexer::state::{
+    GlobalStateFilter, IndexerStateID, TableStateFilter, UTXOFilter,
+};
 use rooch_types::indexer::transaction_filter::TransactionFilter;
 use rooch_types::test_utils::{
     random_bytes, random_event, random_function_calls, random_state_change_set, random_string,
@@ -48,6 +70,7 @@ fn random_update_global_states(states: Vec<IndexedGlobalState>) -> Vec<IndexedGl
             state_index: item.state_index,
             created_at: item.created_at,
             updated_at: item.updated_at + 1,
+            state_size: item.state_size + 1,
         })
         .collect()
 }
@@ -64,6 +87,7 @@ fn random_new_global_states() -> Result<Vec<IndexedGlobalState>> {
             random_struct_tag().to_canonical_string(),
             n as u64,
             state_index,
+            rng.gen_range(1..=1000),
         );
 
         new_global_states.push(state);
@@ -100,6 +124,7 @@ fn random_new_table_states() -> Vec<IndexedTableState> {
             random_type_tag(),
             n as u64,
             state_index,
+            rng.gen_range(1..=1000),
         );
         table_states.push(state);
         state_index = state_index + 1;
@@ -122,6 +147,7 @@ fn random_update_table_states(states: Vec<IndexedTableState>) -> Vec<IndexedTabl
             state_index: item.state_index,
             created_at: item.created_at,
             updated_at: item.updated_at + 1,
+            state_size: item.state_size + 1,
         })
         .collect()
 }
@@ -189,6 +215,10 @@ fn test_transaction_store() -> Result<()> {
     let query_transactions =
         indexer_reader.query_transactions_with_filter(filter, None, 1, true)?;
     assert_eq!(query_transactions.len(), 1);
+
+    let action_name = MoveAction::from(random_moveos_tx.action).action_name();
+    let search_transactions = indexer_reader.search_transactions(action_name, None, 1, true)?;
+    assert_eq!(search_transactions.len(), 1);
     Ok(())
 }
 
@@ -223,18 +253,203 @@ fn test_event_store() -> Result<()> {
         post_execute_functions: random_function_calls(),
     };
 
-    let indexed_event = IndexedEvent::new(
+    let mut indexed_event = IndexedEvent::new(
         random_event,
         random_transaction,
         random_sequence_info,
         random_moveos_tx.clone(),
     );
+    indexed_event.event_data_json = r#"{"name":"rooch_indexer"}"#.to_string();
+    let event_type = indexed_event.event_type.clone();
+    // Only the `DepositEvent` and `WithdrawEvent` of the coin store are transfers
+    let mut other_event = coin_store_event(withdraw.clone(), 1, 0);
+    other_event.event_type = random_struct_tag();
+    assert!(IndexedCoinTransfer::new_from_event(&other_event)?.is_none());
+
     let events = vec![indexed_event];
     let _ = indexer_store.persist_events(events)?;
 
     let filter = EventFilter::Sender(random_moveos_tx.ctx.sender);
     let query_events = indexer_reader.query_events_with_filter(filter, None, 1, true)?;
     assert_eq!(query_events.len(), 1);
+    assert_eq!(
+        query_events[0].event_data_json.as_deref(),
+        Some(r#"{"name":"rooch_indexer"}"#)
+    );
+
+    let filter = EventFilter::All(vec![
+        EventFilter::Sender(random_moveos_tx.ctx.sender),
+        EventFilter::EventType(event_type.clone()),
+        EventFilter::TimeRange {
+            start_time: 0,
+            end_time: 1,
+        },
+    ]);
+    let query_events = indexer_reader.query_events_with_filter(filter, None, 1, true)?;
+    assert_eq!(query_events.len(), 1);
+
+    let filter = EventFilter::All(vec![
+        EventFilter::Sender(AccountAddress::random()),
+        EventFilter::EventType(event_type),
+    ]);
+    let query_events = indexer_reader.query_events_with_filter(filter, None, 1, true)?;
+    assert_eq!(query_events.len(), 0);
+
+    let search_events = indexer_reader.search_events("rooch_indexer".to_string(), None, 1, true)?;
+    assert_eq!(search_events.len(), 1);
+    let search_events = indexer_reader.search_events("\"unknown".to_string(), None, 1, true)?;
+    assert_eq!(search_events.len(), 0);
+    Ok(())
+}
+
+#[test]
+fn test_event_blooms() -> Result<()> {
+    let tmpdir = moveos_config::temp_dir();
+    let indexer_db = tmpdir.path().join(ROOCH_INDEXER_DB_FILENAME);
+    if !indexer_db.exists() {
+        std::fs::File::create(indexer_db.clone())?;
+    }
+    let indexer_db_url = indexer_db
+        .as_path()
+        .to_str()
+        .ok_or(anyhow::anyhow!("Invalid mock indexer db dir"))?;
+    let indexer_store = IndexerStore::new(indexer_db_url)?;
+    indexer_store.create_all_tables_if_not_exists()?;
+    let indexer_reader = IndexerReader::new(indexer_db_url)?;
+
+    let event_type = random_struct_tag();
+    let other_event_type = random_struct_tag();
+    let new_event = |tx_order: u64, event_type: &StructTag| {
+        let tx_order_signature = Authenticator::new(rand::random(), random_bytes());
+        let random_sequence_info =
+            TransactionSequenceInfo::new(tx_order, tx_order_signature, H256::random());
+        let random_moveos_tx = VerifiedMoveOSTransaction {
+            ctx: TxContext::new_readonly_ctx(AccountAddress::random()),
+            action: random_verified_move_action(),
+            pre_execute_functions: random_function_calls(),
+            post_execute_functions: random_function_calls(),
+        };
+        let mut indexed_event = IndexedEvent::new(
+            random_event(),
+            random_typed_transaction(),
+            random_sequence_info,
+            random_moveos_tx,
+        );
+        indexed_event.tx_order = tx_order;
+        indexed_event.event_index = 0;
+        indexed_event.event_type = event_type.clone();
+        indexed_event
+    };
+    let events = vec![
+        new_event(1, &event_type),
+        new_event(2, &other_event_type),
+        new_event(3, &event_type),
+    ];
+    indexer_store.persist_events(events)?;
+
+    let tx_orders = |events: Vec<IndexerEvent>| {
+        events
+            .into_iter()
+            .map(|event| event.indexer_event_id.tx_order)
+            .collect::<Vec<_>>()
+    };
+    let filter = EventFilter::EventType(event_type.clone());
+    let query_events = indexer_reader.query_events_with_filter(filter.clone(), None, 10, false)?;
+    assert_eq!(tx_orders(query_events), vec![1, 3]);
+    let query_events = indexer_reader.query_events_with_filter(filter.clone(), None, 10, true)?;
+    assert_eq!(tx_orders(query_events), vec![3, 1]);
+    let query_events = indexer_reader.query_events_with_filter(filter.clone(), None, 1, false)?;
+    assert_eq!(tx_orders(query_events), vec![1]);
+    let cursor = IndexerEventID::new(1, 0);
+    let query_events = indexer_reader.query_events_with_filter(filter, Some(cursor), 10, false)?;
+    assert_eq!(tx_orders(query_events), vec![3]);
+
+    let filter = EventFilter::All(vec![
+        EventFilter::EventType(other_event_type),
+        EventFilter::TxOrderRange {
+            from_order: 0,
+            to_order: 10,
+        },
+    ]);
+    let query_events = indexer_reader.query_events_with_filter(filter, None, 10, false)?;
+    assert_eq!(tx_orders(query_events), vec![2]);
+
+    let filter = EventFilter::EventType(random_struct_tag());
+    let query_events = indexer_reader.query_events_with_filter(filter, None, 10, false)?;
+    assert!(query_events.is_empty());
+    Ok(())
+}
+
+#[test]
+fn test_query_events_by_tx_event_index_range() -> Result<()> {
+    let tmpdir = moveos_config::temp_dir();
+    let indexer_db = tmpdir.path().join(ROOCH_INDEXER_DB_FILENAME);
+    if !indexer_db.exists() {
+        std::fs::File::create(indexer_db.clone())?;
+    }
+    let indexer_db_url = indexer_db
+        .as_path()
+        .to_str()
+        .ok_or(anyhow::anyhow!("Invalid mock indexer db dir"))?;
+    let indexer_store = IndexerStore::new(indexer_db_url)?;
+    indexer_store.create_all_tables_if_not_exists()?;
+    let indexer_reader = IndexerReader::new(indexer_db_url)?;
+
+    let tx_hash = H256::random();
+    let other_tx_hash = H256::random();
+    let new_event = |tx_order: u64, tx_hash: H256, event_index: u64| {
+        let tx_order_signature = Authenticator::new(rand::random(), random_bytes());
+        let random_sequence_info =
+            TransactionSequenceInfo::new(tx_order, tx_order_signature, H256::random());
+        let random_moveos_tx = VerifiedMoveOSTransaction {
+            ctx: TxContext::new_readonly_ctx(AccountAddress::random()),
+            action: random_verified_move_action(),
+            pre_execute_functions: random_function_calls(),
+            post_execute_functions: random_function_calls(),
+        };
+        let mut indexed_event = IndexedEvent::new(
+            random_event(),
+            random_typed_transaction(),
+            random_sequence_info,
+            random_moveos_tx,
+        );
+        indexed_event.tx_order = tx_order;
+        indexed_event.tx_hash = tx_hash;
+        indexed_event.event_index = event_index;
+        indexed_event
+    };
+    let events = vec![
+        new_event(1, tx_hash, 0),
+        new_event(1, tx_hash, 1),
+        new_event(1, tx_hash, 2),
+        new_event(1, tx_hash, 3),
+        new_event(2, other_tx_hash, 1),
+    ];
+    indexer_store.persist_events(events)?;
+
+    let event_indexes = |events: Vec<IndexerEvent>| {
+        events
+            .into_iter()
+            .map(|event| event.indexer_event_id.event_index)
+            .collect::<Vec<_>>()
+    };
+    let filter = EventFilter::TxEventIndexRange {
+        tx_hash,
+        from_index: 1,
+        to_index: 3,
+    };
+    let query_events = indexer_reader.query_events_with_filter(filter.clone(), None, 10, false)?;
+    assert_eq!(event_indexes(query_events), vec![1, 2]);
+    let query_events = indexer_reader.query_events_with_filter(filter, None, 10, true)?;
+    assert_eq!(event_indexes(query_events), vec![2, 1]);
+
+    let filter = EventFilter::TxEventIndexRange {
+        tx_hash: other_tx_hash,
+        from_index: 0,
+        to_index: 1,
+    };
+    let query_events = indexer_reader.query_events_with_filter(filter, None, 10, false)?;
+    assert!(query_events.is_empty());
     Ok(())
 }
 
@@ -313,3 +528,1004 @@ fn test_state_store() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_table_change_sets_from() -> Result<()> {
+    let tmpdir = moveos_config::temp_dir();
+    let indexer_db = tmpdir.path().join(ROOCH_INDEXER_DB_FILENAME);
+    if !indexer_db.exists() {
+        std::fs::File::create(indexer_db.clone())?;
+    }
+    let indexer_db_url = indexer_db
+        .as_path()
+        .to_str()
+        .ok_or(anyhow::anyhow!("Invalid mock indexer db dir"))?;
+    let indexer_store = IndexerStore::new(indexer_db_url)?;
+    indexer_store.create_all_tables_if_not_exists()?;
+
+    let mut indexed_table_change_sets = vec![];
+    for tx_order in 1..=3u64 {
+        let mut split_state_change_set = SplitStateChangeSet::default();
+        for (table_handle, table_change) in random_state_change_set().changes {
+            split_state_change_set.add_table_change(table_handle, table_change);
+        }
+        for (index, item) in split_state_change_set
+            .table_change_sets
+            .into_iter()
+            .enumerate()
+        {
+            indexed_table_change_sets.push(IndexedTableChangeSet::new(
+                tx_order,
+                index as u64,
+                item.0,
+                item.1,
+            )?);
+        }
+    }
+    let expected = indexed_table_change_sets
+        .iter()
+        .filter(|table_change_set| table_change_set.tx_order >= 2)
+        .count();
+    indexer_store.persist_table_change_sets(indexed_table_change_sets)?;
+
+    let table_change_sets = indexer_store.get_table_change_sets_from(2)?;
+    assert_eq!(table_change_sets.len(), expected);
+    assert!(table_change_sets
+        .windows(2)
+        .all(|pair| (pair[0].tx_order, pair[0].state_index)
+            < (pair[1].tx_order, pair[1].state_index)));
+    assert!(table_change_sets
+        .iter()
+        .all(|table_change_set| table_change_set.tx_order >= 2));
+
+    indexer_store.delete_from_tx_order(2)?;
+    assert!(indexer_store.get_table_change_sets_from(2)?.is_empty());
+    Ok(())
+}
+
+#[test]
+fn test_global_states_cursor_pagination() -> Result<()> {
+    let tmpdir = moveos_config::temp_dir();
+    let indexer_db = tmpdir.path().join(ROOCH_INDEXER_DB_FILENAME);
+    if !indexer_db.exists() {
+        std::fs::File::create(indexer_db.clone())?;
+    }
+    let indexer_db_url = indexer_db
+        .as_path()
+        .to_str()
+        .ok_or(anyhow::anyhow!("Invalid mock indexer db dir"))?;
+    let indexer_store = IndexerStore::new(indexer_db_url)?;
+    indexer_store.create_all_tables_if_not_exists()?;
+    let indexer_reader = IndexerReader::new(indexer_db_url)?;
+
+    let owner = AccountAddress::random();
+    let new_global_states = random_new_global_states()?
+        .into_iter()
+        .map(|state| IndexedGlobalState { owner, ..state })
+        .collect::<Vec<_>>();
+    let total = new_global_states.len();
+    indexer_store.persist_or_update_global_states(new_global_states)?;
+
+    let mut cursor = None;
+    let mut paged_state_ids = vec![];
+    loop {
+        let page = indexer_reader.query_global_states_with_filter(
+            GlobalStateFilter::Owner(owner),
+            cursor,
+            2,
+            false,
+        )?;
+        if page.is_empty() {
+            break;
+        }
+        let last = page.last().unwrap();
+        // The cursor should survive the round trip through its opaque string form
+        let next_cursor = IndexerStateID::new(last.tx_order, last.state_index).to_cursor();
+        cursor = Some(IndexerStateID::from_cursor(next_cursor.as_str())?);
+        paged_state_ids.extend(
+            page.iter()
+                .map(|state| IndexerStateID::new(state.tx_order, state.state_index)),
+        );
+    }
+
+    assert_eq!(paged_state_ids.len(), total);
+    assert!(paged_state_ids.windows(2).all(|w| w[0] < w[1]));
+    Ok(())
+}
+
+#[test]
+fn test_object_ownership_history() -> Result<()> {
+    let tmpdir = moveos_config::temp_dir();
+    let indexer_db = tmpdir.path().join(ROOCH_INDEXER_DB_FILENAME);
+    if !indexer_db.exists() {
+        std::fs::File::create(indexer_db.clone())?;
+    }
+    let indexer_db_url = indexer_db
+        .as_path()
+        .to_str()
+        .ok_or(anyhow::anyhow!("Invalid mock indexer db dir"))?;
+    let indexer_store = IndexerStore::new(indexer_db_url)?;
+    indexer_store.create_all_tables_if_not_exists()?;
+    let indexer_reader = IndexerReader::new(indexer_db_url)?;
+
+    let state = random_new_global_states()?.remove(0);
+    let object_id = state.object_id.clone();
+    let first_owner = state.owner;
+    indexer_store.persist_object_ownership_changes(1, &[state.clone()], &[])?;
+    indexer_store.persist_or_update_global_states(vec![state.clone()])?;
+
+    // Updating the object without changing the owner is not recorded
+    indexer_store.persist_object_ownership_changes(2, &[state.clone()], &[])?;
+    indexer_store.persist_or_update_global_states(vec![state.clone()])?;
+
+    let second_owner = AccountAddress::random();
+    let transferred_state = IndexedGlobalState {
+        owner: second_owner,
+        tx_order: 3,
+        ..state
+    };
+    indexer_store.persist_object_ownership_changes(3, &[transferred_state.clone()], &[])?;
+    indexer_store.persist_or_update_global_states(vec![transferred_state])?;
+
+    indexer_store.persist_object_ownership_changes(4, &[], &[object_id.to_string()])?;
+    indexer_store.delete_global_states(vec![object_id.to_string()])?;
+
+    let history =
+        indexer_reader.get_object_ownership_history(object_id.clone(), None, 10, false)?;
+    assert_eq!(
+        history
+            .iter()
+            .map(|change| change.tx_order)
+            .collect::<Vec<_>>(),
+        vec![1, 3, 4]
+    );
+    assert_eq!(history[0].old_owner, None);
+    assert_eq!(history[0].new_owner, Some(first_owner));
+    assert_eq!(history[1].old_owner, Some(first_owner));
+    assert_eq!(history[1].new_owner, Some(second_owner));
+    assert_eq!(history[2].old_owner, Some(second_owner));
+    assert_eq!(history[2].new_owner, None);
+
+    let history = indexer_reader.get_object_ownership_history(object_id, Some(4), 1, true)?;
+    assert_eq!(history.len(), 1);
+    assert_eq!(history[0].tx_order, 3);
+    Ok(())
+}
+
+#[test]
+fn test_commit_batch() -> Result<()> {
+    let tmpdir = moveos_config::temp_dir();
+    let indexer_db = tmpdir.path().join(ROOCH_INDEXER_DB_FILENAME);
+    if !indexer_db.exists() {
+        std::fs::File::create(indexer_db.clone())?;
+    }
+    let indexer_db_url = indexer_db
+        .as_path()
+        .to_str()
+        .ok_or(anyhow::anyhow!("Invalid mock indexer db dir"))?;
+    let indexer_store = IndexerStore::new(indexer_db_url)?;
+    indexer_store.create_all_tables_if_not_exists()?;
+    let indexer_reader = IndexerReader::new(indexer_db_url)?;
+
+    let state = random_new_global_states()?.remove(0);
+    let object_id = state.object_id.clone();
+    let first_owner = state.owner;
+    let second_owner = AccountAddress::random();
+
+    // The object is created at tx 1 and transferred at tx 2 in the same batch
+    let mut batch = IndexerBatch::default();
+    batch.add_object_ownership_change(
+        object_id.to_string(),
+        1,
+        None,
+        Some(first_owner.to_hex_literal()),
+    );
+    batch.upsert_global_state(state.clone());
+    let old_owner = batch.object_owner(&object_id.to_string()).unwrap();
+    batch.add_object_ownership_change(
+        object_id.to_string(),
+        2,
+        old_owner,
+        Some(second_owner.to_hex_literal()),
+    );
+    batch.upsert_global_state(IndexedGlobalState {
+        owner: second_owner,
+        tx_order: 2,
+        ..state
+    });
+    // The table states written before the table is removed are dropped
+    let table_state = random_new_table_states().remove(0);
+    let table_handle = table_state.table_handle.clone();
+    batch.upsert_table_state(table_state);
+    batch.remove_table(table_handle.to_string());
+    batch.update_progress(IndexerProgressTable::States, 1);
+    batch.update_progress(IndexerProgressTable::States, 2);
+    indexer_store.commit_batch(batch)?;
+
+    assert_eq!(
+        indexer_store.get_indexer_progress(IndexerProgressTable::States)?,
+        Some(2)
+    );
+    assert_eq!(
+        indexer_store.get_indexer_progress(IndexerProgressTable::Transactions)?,
+        None
+    );
+    let filter = GlobalStateFilter::ObjectId(object_id.clone());
+    let query_global_states =
+        indexer_reader.query_global_states_with_filter(filter, None, 1, true)?;
+    assert_eq!(query_global_states.len(), 1);
+    assert_eq!(query_global_states[0].owner, second_owner);
+    let history = indexer_reader.get_object_ownership_history(object_id, None, 10, false)?;
+    assert_eq!(history.len(), 2);
+    assert_eq!(history[1].old_owner, Some(first_owner));
+    assert_eq!(history[1].new_owner, Some(second_owner));
+    let filter = TableStateFilter::TableHandle(table_handle);
+    let query_table_states =
+        indexer_reader.query_table_states_with_filter(filter, None, 1, true)?;
+    assert_eq!(query_table_states.len(), 0);
+    Ok(())
+}
+
+#[test]
+fn test_address_mapping() -> Result<()> {
+    let tmpdir = moveos_config::temp_dir();
+    let indexer_db = tmpdir.path().join(ROOCH_INDEXER_DB_FILENAME);
+    if !indexer_db.exists() {
+        std::fs::File::create(indexer_db.clone())?;
+    }
+    let indexer_db_url = indexer_db
+        .as_path()
+        .to_str()
+        .ok_or(anyhow::anyhow!("Invalid mock indexer db dir"))?;
+    let indexer_store = IndexerStore::new(indexer_db_url)?;
+    indexer_store.create_all_tables_if_not_exists()?;
+    let indexer_reader = IndexerReader::new(indexer_db_url)?;
+
+    let rooch_address = AccountAddress::random();
+    let btc_address = MultiChainAddress::from(BitcoinAddress::random());
+    let eth_address = MultiChainAddress::from(EthereumAddress::random());
+    let new_mapping = |multichain_address: &MultiChainAddress, tx_order: u64| {
+        let key = KeyState::new(multichain_address.to_bytes(), MultiChainAddress::type_tag());
+        let value = State::new(bcs::to_bytes(&rooch_address)?, TypeTag::Address);
+        IndexedAddressMapping::new_from_table_item(&key, &value, tx_order)
+    };
+    // Only the `MultiChainAddress -> address` table items are address mappings
+    let other_value = State::new(bcs::to_bytes(&1u64)?, TypeTag::U64);
+    let btc_key = KeyState::new(btc_address.to_bytes(), MultiChainAddress::type_tag());
+    assert!(IndexedAddressMapping::new_from_table_item(&btc_key, &other_value, 1)?.is_none());
+
+    let mut batch = IndexerBatch::default();
+    batch.upsert_address_mapping(new_mapping(&btc_address, 1)?.unwrap());
+    batch.upsert_address_mapping(new_mapping(&eth_address, 2)?.unwrap());
+    batch.update_progress(IndexerProgressTable::States, 2);
+    indexer_store.commit_batch(batch)?;
+
+    let mapping = indexer_reader
+        .get_address_mapping(btc_address.clone())?
+        .unwrap();
+    assert_eq!(mapping.multichain_address, btc_address);
+    assert_eq!(mapping.rooch_address, rooch_address);
+    assert_eq!(mapping.tx_order, 1);
+    let mappings = indexer_reader.get_address_mappings_by_rooch_address(rooch_address)?;
+    assert_eq!(mappings.len(), 2);
+    assert_eq!(mappings[1].multichain_address, eth_address);
+
+    let mut batch = IndexerBatch::default();
+    batch.remove_address_mapping(
+        IndexedAddressMapping::multichain_address_from_key(&btc_key)?.unwrap(),
+    );
+    batch.update_progress(IndexerProgressTable::States, 3);
+    indexer_store.commit_batch(batch)?;
+    assert!(indexer_reader.get_address_mapping(btc_address)?.is_none());
+    let mappings = indexer_reader.get_address_mappings_by_rooch_address(rooch_address)?;
+    assert_eq!(mappings.len(), 1);
+    Ok(())
+}
+
+fn module_state(address: AccountAddress, name: &str, version: u64) -> Result<State> {
+    let mut module = empty_module();
+    module.address_identifiers[0] = address;
+    module.identifiers[0] = Identifier::new(name)?;
+    module.metadata.push(Metadata {
+        key: b"version".to_vec(),
+        value: version.to_le_bytes().to_vec(),
+    });
+    let mut byte_codes = vec![];
+    module.serialize(&mut byte_codes)?;
+    Ok(State::new(
+        bcs::to_bytes(&MoveModule::new(byte_codes))?,
+        MoveModule::type_tag(),
+    ))
+}
+
+#[test]
+fn test_modules() -> Result<()> {
+    let tmpdir = moveos_config::temp_dir();
+    let indexer_db = tmpdir.path().join(ROOCH_INDEXER_DB_FILENAME);
+    if !indexer_db.exists() {
+        std::fs::File::create(indexer_db.clone())?;
+    }
+    let indexer_db_url = indexer_db
+        .as_path()
+        .to_str()
+        .ok_or(anyhow::anyhow!("Invalid mock indexer db dir"))?;
+    let indexer_store = IndexerStore::new(indexer_db_url)?;
+    indexer_store.create_all_tables_if_not_exists()?;
+    let indexer_reader = IndexerReader::new(indexer_db_url)?;
+
+    // Only the `MoveModule` table items are modules
+    let other_value = State::new(bcs::to_bytes(&1u64)?, TypeTag::U64);
+    assert!(IndexedModule::new_from_table_item(&other_value, 1, 0)?.is_none());
+
+    let publisher = AccountAddress::random();
+    let other_publisher = AccountAddress::random();
+    let counter = module_state(publisher, "counter", 1)?;
+    let module = IndexedModule::new_from_table_item(&counter, 1, 0)?.unwrap();
+    assert_eq!(module.address, publisher);
+    assert_eq!(module.name, "counter");
+    assert!(module.source_digest.is_none());
+
+    let mut batch = IndexerBatch::default();
+    batch.upsert_module(module);
+    batch.upsert_module(
+        IndexedModule::new_from_table_item(&module_state(publisher, "ticket", 1)?, 1, 1)?.unwrap(),
+    );
+    batch.upsert_module(
+        IndexedModule::new_from_table_item(&module_state(other_publisher, "counter", 1)?, 2, 0)?
+            .unwrap(),
+    );
+    batch.update_progress(IndexerProgressTable::States, 2);
+    indexer_store.commit_batch(batch)?;
+
+    let modules = indexer_reader.query_modules_with_filter(
+        ModuleFilter::Publisher(publisher),
+        None,
+        10,
+        true,
+    )?;
+    assert_eq!(modules.len(), 2);
+    assert_eq!(modules[0].name, "ticket");
+    assert_eq!(modules[1].name, "counter");
+    let counter_hash = modules[1].bytecode_hash;
+    let created_at = modules[1].created_at;
+
+    // Paginate with the cursor of the last module of the previous page
+    let time_range = ModuleFilter::TimeRange {
+        start_time: created_at,
+        end_time: created_at + 1,
+    };
+    let first_page = indexer_reader.query_modules_with_filter(time_range.clone(), None, 2, true)?;
+    assert_eq!(first_page.len(), 2);
+    assert_eq!(first_page[0].address, other_publisher);
+    let cursor = IndexerStateID::new(first_page[1].tx_order, first_page[1].state_index);
+    let second_page =
+        indexer_reader.query_modules_with_filter(time_range.clone(), Some(cursor), 2, true)?;
+    assert_eq!(second_page.len(), 1);
+    assert_eq!(second_page[0].name, "counter");
+    assert_eq!(second_page[0].address, publisher);
+    let modules = indexer_reader.query_modules_with_filter(
+        ModuleFilter::TimeRange {
+            start_time: 0,
+            end_time: created_at,
+        },
+        None,
+        10,
+        true,
+    )?;
+    assert!(modules.is_empty());
+
+    // The upgraded module is moved to the latest tx order, the created time is kept
+    let mut batch = IndexerBatch::default();
+    batch.upsert_module(
+        IndexedModule::new_from_table_item(&module_state(publisher, "counter", 2)?, 3, 0)?.unwrap(),
+    );
+    batch.update_progress(IndexerProgressTable::States, 3);
+    indexer_store.commit_batch(batch)?;
+    let modules = indexer_reader.query_modules_with_filter(time_range, None, 10, true)?;
+    assert_eq!(modules.len(), 3);
+    assert_eq!(modules[0].name, "counter");
+    assert_eq!(modules[0].address, publisher);
+    assert_eq!(modules[0].tx_order, 3);
+    assert_eq!(modules[0].created_at, created_at);
+    assert_ne!(modules[0].bytecode_hash, counter_hash);
+
+    // The modules published or upgraded above the tx order are deleted on revert,
+    // the indexer restores the upgraded ones from the state tree before deleting
+    indexer_store.delete_from_tx_order(2)?;
+    let modules = indexer_reader.query_modules_with_filter(
+        ModuleFilter::Publisher(publisher),
+        None,
+        10,
+        false,
+    )?;
+    assert_eq!(modules.len(), 1);
+    assert_eq!(modules[0].name, "ticket");
+    Ok(())
+}
+
+#[test]
+fn test_utxo_store() -> Result<()> {
+    let tmpdir = moveos_config::temp_dir();
+    let indexer_db = tmpdir.path().join(ROOCH_INDEXER_DB_FILENAME);
+    if !indexer_db.exists() {
+        std::fs::File::create(indexer_db.clone())?;
+    }
+    let indexer_db_url = indexer_db
+        .as_path()
+        .to_str()
+        .ok_or(anyhow::anyhow!("Invalid mock indexer db dir"))?;
+    let indexer_store = IndexerStore::new(indexer_db_url)?;
+    indexer_store.create_all_tables_if_not_exists()?;
+    let indexer_reader = IndexerReader::new(indexer_db_url)?;
+
+    // The utxos table is empty before any UTXO is indexed
+    let query_utxos = indexer_reader.query_utxos_with_filter(UTXOFilter::All, None, 10, true)?;
+    assert_eq!(query_utxos.len(), 0);
+
+    let owner = AccountAddress::random();
+    let txid = AccountAddress::random();
+    let utxos = (0..3u32)
+        .map(|vout| IndexedUTXOState {
+            object_id: ObjectID::from(AccountAddress::random()),
+            owner,
+            flag: 0,
+            txid,
+            vout,
+            value: 1000 + vout as u64,
+            seals: serde_json::to_string(&SimpleMultiMap::<MoveString, ObjectID>::create())
+                .unwrap(),
+            tx_order: 1,
+            state_index: vout as u64,
+            created_at: 0,
+            updated_at: 0,
+        })
+        .collect::<Vec<_>>();
+    let removed_object_id = utxos[0].object_id.to_string();
+    indexer_store.persist_or_update_utxos(utxos)?;
+
+    let query_utxos =
+        indexer_reader.query_utxos_with_filter(UTXOFilter::Owner(owner), None, 10, true)?;
+    assert_eq!(query_utxos.len(), 3);
+
+    let query_utxos = indexer_reader.query_utxos_with_filter(
+        UTXOFilter::OutPoint { txid, vout: 1 },
+        None,
+        10,
+        true,
+    )?;
+    assert_eq!(query_utxos.len(), 1);
+    assert_eq!(query_utxos[0].value, 1001);
+
+    indexer_store.delete_utxos(vec![removed_object_id])?;
+    let query_utxos =
+        indexer_reader.query_utxos_with_filter(UTXOFilter::Owner(owner), None, 10, false)?;
+    assert_eq!(query_utxos.len(), 2);
+    Ok(())
+}
+
+#[test]
+fn test_indexer_progress() -> Result<()> {
+    let tmpdir = moveos_config::temp_dir();
+    let indexer_db = tmpdir.path().join(ROOCH_INDEXER_DB_FILENAME);
+    if !indexer_db.exists() {
+        std::fs::File::create(indexer_db.clone())?;
+    }
+    let indexer_db_url = indexer_db
+        .as_path()
+        .to_str()
+        .ok_or(anyhow::anyhow!("Invalid mock indexer db dir"))?;
+    let indexer_store = IndexerStore::new(indexer_db_url)?;
+    indexer_store.create_all_tables_if_not_exists()?;
+    let indexer_reader = IndexerReader::new(indexer_db_url)?;
+
+    assert_eq!(
+        indexer_store.get_indexer_progress(IndexerProgressTable::Transactions)?,
+        None
+    );
+
+    let sender = AccountAddress::random();
+    let mut transactions = vec![];
+    for tx_order in 1..=3u64 {
+        let tx_order_signature = Authenticator::new(rand::random(), random_bytes());
+        let sequence_info =
+            TransactionSequenceInfo::new(tx_order, tx_order_signature, H256::random());
+        let execution_info = TransactionExecutionInfo::new(
+            H256::random(),
+            H256::random(),
+            H256::random(),
+            rand::random(),
+            KeptVMStatus::Executed,
+        );
+        let moveos_tx = VerifiedMoveOSTransaction {
+            ctx: TxContext::new_readonly_ctx(sender),
+            action: random_verified_move_action(),
+            pre_execute_functions: random_function_calls(),
+            post_execute_functions: random_function_calls(),
+        };
+        transactions.push(IndexedTransaction::new(
+            random_typed_transaction(),
+            sequence_info,
+            execution_info,
+            moveos_tx,
+        )?);
+    }
+    indexer_store.persist_transactions(transactions)?;
+    indexer_store.update_indexer_progress(IndexerProgressTable::Transactions, 3)?;
+    indexer_store.update_indexer_progress(IndexerProgressTable::Events, 2)?;
+    assert_eq!(
+        indexer_store.get_indexer_progress(IndexerProgressTable::Transactions)?,
+        Some(3)
+    );
+    assert_eq!(
+        indexer_store.get_indexer_progress(IndexerProgressTable::Events)?,
+        Some(2)
+    );
+
+    indexer_store.delete_from_tx_order(2)?;
+    let filter = TransactionFilter::Sender(sender);
+    let query_transactions =
+        indexer_reader.query_transactions_with_filter(filter, None, 10, true)?;
+    assert_eq!(query_transactions.len(), 1);
+    for table in IndexerProgressTable::ALL {
+        assert_eq!(indexer_store.get_indexer_progress(table)?, Some(1));
+    }
+    Ok(())
+}
+
+#[test]
+fn test_account_stats() -> Result<()> {
+    let tmpdir = moveos_config::temp_dir();
+    let indexer_db = tmpdir.path().join(ROOCH_INDEXER_DB_FILENAME);
+    if !indexer_db.exists() {
+        std::fs::File::create(indexer_db.clone())?;
+    }
+    let indexer_db_url = indexer_db
+        .as_path()
+        .to_str()
+        .ok_or(anyhow::anyhow!("Invalid mock indexer db dir"))?;
+    let indexer_store = IndexerStore::new(indexer_db_url)?;
+    indexer_store.create_all_tables_if_not_exists()?;
+    let indexer_reader = IndexerReader::new(indexer_db_url)?;
+
+    let sender = AccountAddress::random();
+    let summary = indexer_reader.get_account_summary(sender)?;
+    assert_eq!(summary.tx_count, 0);
+    assert!(summary.object_counts.is_empty());
+
+    let mut transactions = vec![];
+    for tx_order in 1..=3u64 {
+        let tx_order_signature = Authenticator::new(rand::random(), random_bytes());
+        let sequence_info =
+            TransactionSequenceInfo::new(tx_order, tx_order_signature, H256::random());
+        let execution_info = TransactionExecutionInfo::new(
+            H256::random(),
+            H256::random(),
+            H256::random(),
+            100,
+            KeptVMStatus::Executed,
+        );
+        let moveos_tx = VerifiedMoveOSTransaction {
+            ctx: TxContext::new_readonly_ctx(sender),
+            action: random_verified_move_action(),
+            pre_execute_functions: random_function_calls(),
+            post_execute_functions: random_function_calls(),
+        };
+        transactions.push(IndexedTransaction::new(
+            random_typed_transaction(),
+            sequence_info,
+            execution_info,
+            moveos_tx,
+        )?);
+    }
+    indexer_store.persist_transactions(transactions)?;
+
+    let summary = indexer_reader.get_account_summary(sender)?;
+    assert_eq!(summary.tx_count, 3);
+    assert_eq!(summary.gas_used, 300);
+    assert_eq!(summary.first_tx_order, Some(1));
+    assert_eq!(summary.last_tx_order, Some(3));
+    assert!(summary.first_active_at.is_some());
+
+    // The account stats are rebuilt when the transactions are reverted
+    indexer_store.delete_from_tx_order(2)?;
+    let summary = indexer_reader.get_account_summary(sender)?;
+    assert_eq!(summary.tx_count, 1);
+    assert_eq!(summary.gas_used, 100);
+    assert_eq!(summary.last_tx_order, Some(1));
+    Ok(())
+}
+
+#[test]
+fn test_storage_usage() -> Result<()> {
+    let tmpdir = moveos_config::temp_dir();
+    let indexer_db = tmpdir.path().join(ROOCH_INDEXER_DB_FILENAME);
+    if !indexer_db.exists() {
+        std::fs::File::create(indexer_db.clone())?;
+    }
+    let indexer_db_url = indexer_db
+        .as_path()
+        .to_str()
+        .ok_or(anyhow::anyhow!("Invalid mock indexer db dir"))?;
+    let indexer_store = IndexerStore::new(indexer_db_url)?;
+    indexer_store.create_all_tables_if_not_exists()?;
+    let indexer_reader = IndexerReader::new(indexer_db_url)?;
+
+    let owner = AccountAddress::random();
+    let usage = indexer_reader.get_storage_usage(owner)?;
+    assert_eq!(usage.object_count, 0);
+    assert_eq!(usage.total_bytes(), 0);
+
+    let global_state = |owner: AccountAddress, state_size: u64| -> Result<IndexedGlobalState> {
+        let mut raw_object = random_table_object()?.to_raw();
+        raw_object.owner = owner;
+        Ok(IndexedGlobalState::new_from_raw_object(
+            raw_object,
+            random_string(),
+            random_struct_tag().to_canonical_string(),
+            1,
+            0,
+            state_size,
+        ))
+    };
+    let table_state = |table_handle: ObjectID, state_size: u64| {
+        IndexedTableState::new(
+            table_handle,
+            H256::random().to_string(),
+            random_string(),
+            random_string(),
+            random_type_tag(),
+            random_type_tag(),
+            1,
+            0,
+            state_size,
+        )
+    };
+
+    let owned_object = global_state(owner, 100)?;
+    let other_object = global_state(AccountAddress::random(), 1000)?;
+    indexer_store.persist_or_update_global_states(vec![
+        owned_object.clone(),
+        global_state(owner, 50)?,
+        other_object.clone(),
+    ])?;
+    let owned_item = table_state(owned_object.object_id, 10);
+    indexer_store.persist_or_update_table_states(vec![
+        owned_item.clone(),
+        table_state(owned_object.object_id, 20),
+        table_state(other_object.object_id, 2000),
+    ])?;
+
+    let usage = indexer_reader.get_storage_usage(owner)?;
+    assert_eq!(usage.object_count, 2);
+    assert_eq!(usage.object_bytes, 150);
+    assert_eq!(usage.table_item_count, 2);
+    assert_eq!(usage.table_item_bytes, 30);
+    assert_eq!(usage.total_bytes(), 180);
+
+    // The sizes are replaced when the states are updated, and released when the states are removed
+    indexer_store.persist_or_update_global_states(vec![IndexedGlobalState {
+        state_size: 200,
+        ..owned_object.clone()
+    }])?;
+    indexer_store.delete_table_states(vec![(
+        owned_item.table_handle.to_string(),
+        owned_item.key_hex.clone(),
+    )])?;
+    let usage = indexer_reader.get_storage_usage(owner)?;
+    assert_eq!(usage.object_bytes, 250);
+    assert_eq!(usage.table_item_count, 1);
+    assert_eq!(usage.table_item_bytes, 20);
+
+    // The table items are accounted to the new owner of the object
+    indexer_store.persist_or_update_global_states(vec![IndexedGlobalState {
+        owner: AccountAddress::random(),
+        ..owned_object
+    }])?;
+    let usage = indexer_reader.get_storage_usage(owner)?;
+    assert_eq!(usage.object_count, 1);
+    assert_eq!(usage.object_bytes, 50);
+    assert_eq!(usage.table_item_count, 0);
+    Ok(())
+}
+
+#[test]
+fn test_coin_info_store() -> Result<()> {
+    let tmpdir = moveos_config::temp_dir();
+    let indexer_db = tmpdir.path().join(ROOCH_INDEXER_DB_FILENAME);
+    if !indexer_db.exists() {
+        std::fs::File::create(indexer_db.clone())?;
+    }
+    let indexer_db_url = indexer_db
+        .as_path()
+        .to_str()
+        .ok_or(anyhow::anyhow!("Invalid mock indexer db dir"))?;
+    let indexer_store = IndexerStore::new(indexer_db_url)?;
+    indexer_store.create_all_tables_if_not_exists()?;
+    let indexer_reader = IndexerReader::new(indexer_db_url)?;
+
+    let coin_type = GasCoin::struct_tag();
+    let query_coin_infos = indexer_reader.get_coin_infos(vec![coin_type.clone()])?;
+    assert!(query_coin_infos[0].is_none());
+
+    let coin_info = IndexedCoinInfo {
+        coin_type: coin_type.clone(),
+        name: "Rooch Gas Coin".to_owned(),
+        symbol: "RGC".to_owned(),
+        icon_url: None,
+        decimals: 18,
+        supply: U256::from(1000u64),
+        tx_order: 1,
+    };
+    indexer_store.persist_or_update_coin_infos(vec![coin_info.clone()])?;
+
+    // The CoinInfo object is modified, the cached coin info is updated
+    let updated_coin_info = IndexedCoinInfo {
+        icon_url: Some("https://rooch.network/logo.svg".to_owned()),
+        supply: U256::from(2000u64),
+        tx_order: 2,
+        ..coin_info
+    };
+    indexer_store.persist_or_update_coin_infos(vec![updated_coin_info])?;
+
+    let query_coin_infos =
+        indexer_reader.get_coin_infos(vec![coin_type.clone(), random_struct_tag()])?;
+    assert_eq!(query_coin_infos.len(), 2);
+    let query_coin_info = query_coin_infos[0].clone().unwrap();
+    assert_eq!(query_coin_info.coin_type, coin_type);
+    assert_eq!(query_coin_info.symbol, "RGC");
+    assert_eq!(
+        query_coin_info.icon_url,
+        Some("https://rooch.network/logo.svg".to_owned())
+    );
+    assert_eq!(query_coin_info.supply, U256::from(2000u64));
+    assert_eq!(query_coin_info.tx_order, 2);
+    assert!(query_coin_infos[1].is_none());
+    Ok(())
+}
+
+#[test]
+fn test_indexer_metrics_lag() -> Result<()> {
+    let registry = metrics::Registry::new();
+    let indexer_metrics = IndexerMetrics::register(&registry)?;
+
+    indexer_metrics.observe_executed(5);
+    indexer_metrics.observe_indexed(IndexerProgressTable::Transactions, 3);
+    let lag = |progress: IndexerProgressTable| {
+        indexer_metrics
+            .indexer_lag
+            .with_label_values(&[progress.name()])
+            .get()
+    };
+    assert_eq!(lag(IndexerProgressTable::Transactions), 2);
+    assert_eq!(lag(IndexerProgressTable::Events), 5);
+
+    // The executed tx order never goes back
+    indexer_metrics.observe_executed(4);
+    indexer_metrics.observe_indexed(IndexerProgressTable::Transactions, 5);
+    assert_eq!(indexer_metrics.executor_tx_order.get(), 5);
+    assert_eq!(lag(IndexerProgressTable::Transactions), 0);
+
+    indexer_metrics.observe_rows_written("transactions", "insert", 2);
+    indexer_metrics.observe_rows_written("transactions", "insert", 0);
+    assert_eq!(
+        indexer_metrics
+            .indexer_rows_written_total
+            .with_label_values(&["transactions", "insert"])
+            .get(),
+        2
+    );
+    Ok(())
+}
+
+fn coin_store_event<T: MoveStructState>(event: T, tx_order: u64, event_index: u64) -> IndexedEvent {
+    IndexedEvent {
+        event_handle_id: ObjectID::from(AccountAddress::random()),
+        event_seq: tx_order,
+        event_type: T::struct_tag(),
+        event_data: bcs::to_bytes(&event).unwrap(),
+        event_data_json: String::new(),
+        event_index,
+        tx_hash: H256::random(),
+        tx_order,
+        sender: AccountAddress::random(),
+        created_at: tx_order,
+    }
+}
+
+#[test]
+fn test_coin_transfers() -> Result<()> {
+    let tmpdir = moveos_config::temp_dir();
+    let indexer_db = tmpdir.path().join(ROOCH_INDEXER_DB_FILENAME);
+    if !indexer_db.exists() {
+        std::fs::File::create(indexer_db.clone())?;
+    }
+    let indexer_db_url = indexer_db
+        .as_path()
+        .to_str()
+        .ok_or(anyhow::anyhow!("Invalid mock indexer db dir"))?;
+    let indexer_store = IndexerStore::new(indexer_db_url)?;
+    indexer_store.create_all_tables_if_not_exists()?;
+    let indexer_reader = IndexerReader::new(indexer_db_url)?;
+
+    let coin_type = GasCoin::struct_tag();
+    let coin_store_id = ObjectID::from(AccountAddress::random());
+    let other_coin_store_id = ObjectID::from(AccountAddress::random());
+    let deposit = |coin_store_id: ObjectID, amount: u64| DepositEvent {
+        coin_store_id,
+        coin_type: MoveString::from(coin_type.to_canonical_string()),
+        amount: U256::from(amount),
+    };
+    let withdraw = WithdrawEvent {
+        coin_store_id: coin_store_id.clone(),
+        coin_type: MoveString::from(coin_type.to_canonical_string()),
+        amount: U256::from(30u64),
+    };
+    let events = vec![
+        coin_store_event(deposit(coin_store_id.clone(), 100), 1, 0),
+        coin_store_event(deposit(other_coin_store_id.clone(), 50), 1, 1),
+        coin_store_event(withdraw, 2, 0),
+        coin_store_event(deposit(coin_store_id.clone(), 20), 3, 0),
+    ];
+
+    let mut batch = IndexerBatch::default();
+    for event in events.iter() {
+        let transfer = IndexedCoinTransfer::new_from_event(event)?.unwrap();
+        assert_eq!(transfer.coin_type, coin_type);
+        batch.coin_transfers.push(transfer);
+    }
+    batch.update_progress(IndexerProgressTable::Events, 3);
+    indexer_store.commit_batch(batch)?;
+
+    let transfers = indexer_reader.query_coin_transfers(coin_store_id.clone(), None, 10, true)?;
+    assert_eq!(transfers.len(), 3);
+    assert_eq!(transfers[0].indexer_event_id, IndexerEventID::new(3, 0));
+    assert_eq!(transfers[1].transfer_type, CoinTransferType::Withdraw);
+    assert_eq!(transfers[1].amount, U256::from(30u64));
+    assert_eq!(transfers[1].sender, events[2].sender);
+    assert_eq!(transfers[2].transfer_type, CoinTransferType::Deposit);
+    assert_eq!(transfers[2].amount, U256::from(100u64));
+
+    // Paginate with the cursor of the last transfer of the previous page
+    let first_page = indexer_reader.query_coin_transfers(coin_store_id.clone(), None, 2, false)?;
+    assert_eq!(first_page.len(), 2);
+    assert_eq!(first_page[0].indexer_event_id, IndexerEventID::new(1, 0));
+    let second_page = indexer_reader.query_coin_transfers(
+        coin_store_id,
+        Some(first_page[1].indexer_event_id),
+        2,
+        false,
+    )?;
+    assert_eq!(second_page.len(), 1);
+    assert_eq!(second_page[0].indexer_event_id, IndexerEventID::new(3, 0));
+
+    let transfers = indexer_reader.query_coin_transfers(other_coin_store_id, None, 10, true)?;
+    assert_eq!(transfers.len(), 1);
+    assert_eq!(transfers[0].amount, U256::from(50u64));
+    Ok(())
+}
+
+#[test]
+fn test_prune_history() -> Result<()> {
+    let tmpdir = moveos_config::temp_dir();
+    let indexer_db = tmpdir.path().join(ROOCH_INDEXER_DB_FILENAME);
+    if !indexer_db.exists() {
+        std::fs::File::create(indexer_db.clone())?;
+    }
+    let indexer_db_url = indexer_db
+        .as_path()
+        .to_str()
+        .ok_or(anyhow::anyhow!("Invalid mock indexer db dir"))?;
+    let indexer_store = IndexerStore::new(indexer_db_url)?;
+    indexer_store.create_all_tables_if_not_exists()?;
+    let indexer_reader = IndexerReader::new(indexer_db_url)?;
+
+    // Nothing is pruned before any transaction is indexed
+    let retention = IndexerRetention {
+        events: Some(2),
+        ..Default::default()
+    };
+    assert!(indexer_store.prune_history(&retention)?.is_empty());
+
+    let mut events = vec![];
+    let mut indexed_table_change_sets = vec![];
+    for tx_order in 1..=5u64 {
+        let tx_order_signature = Authenticator::new(rand::random(), random_bytes());
+        let random_sequence_info =
+            TransactionSequenceInfo::new(tx_order, tx_order_signature, H256::random());
+        let random_moveos_tx = VerifiedMoveOSTransaction {
+            ctx: TxContext::new_readonly_ctx(AccountAddress::random()),
+            action: random_verified_move_action(),
+            pre_execute_functions: random_function_calls(),
+            post_execute_functions: random_function_calls(),
+        };
+        let mut indexed_event = IndexedEvent::new(
+            random_event(),
+            random_typed_transaction(),
+            random_sequence_info,
+            random_moveos_tx,
+        );
+        indexed_event.tx_order = tx_order;
+        indexed_event.event_index = 0;
+        events.push(indexed_event);
+
+        let mut split_state_change_set = SplitStateChangeSet::default();
+        for (table_handle, table_change) in random_state_change_set().changes {
+            split_state_change_set.add_table_change(table_handle, table_change);
+        }
+        for (index, item) in split_state_change_set
+            .table_change_sets
+            .into_iter()
+            .enumerate()
+        {
+            indexed_table_change_sets.push(IndexedTableChangeSet::new(
+                tx_order,
+                index as u64,
+                item.0,
+                item.1,
+            )?);
+        }
+    }
+    let table_change_sets_count = indexed_table_change_sets.len();
+    indexer_store.persist_events(events)?;
+    indexer_store.persist_table_change_sets(indexed_table_change_sets)?;
+    indexer_store.update_indexer_progress(IndexerProgressTable::Events, 5)?;
+
+    // The events of the latest 2 transactions are retained, the table change sets without retention are kept
+    let pruned = indexer_store.prune_history(&retention)?;
+    assert_eq!(pruned.get("events"), Some(&3));
+    assert!(!pruned.contains_key("table_change_sets"));
+    let query_events =
+        indexer_reader.query_events_with_filter(EventFilter::All(vec![]), None, 10, false)?;
+    let tx_orders = query_events
+        .into_iter()
+        .map(|event| event.indexer_event_id.tx_order)
+        .collect::<Vec<_>>();
+    assert_eq!(tx_orders, vec![4, 5]);
+    assert_eq!(
+        indexer_store.get_table_change_sets_from(1)?.len(),
+        table_change_sets_count
+    );
+
+    // Pruning again is a no-op
+    let pruned = indexer_store.prune_history(&retention)?;
+    assert_eq!(pruned.get("events"), Some(&0));
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_retry_with_backoff() {
+    let interval = std::time::Duration::from_millis(1);
+
+    // The transient error is retried until the operation succeeds
+    let attempts = &std::cell::Cell::new(0);
+    let result = retry_with_backoff(3, interval, || async move {
+        attempts.set(attempts.get() + 1);
+        if attempts.get() < 3 {
+            Err(RetryError::Transient(anyhow::anyhow!("database is locked")))
+        } else {
+            Ok(attempts.get())
+        }
+    })
+    .await;
+    assert_eq!(result.unwrap(), 3);
+
+    // The error is returned after the max retries
+    attempts.set(0);
+    let result: Result<()> = retry_with_backoff(3, interval, || async move {
+        attempts.set(attempts.get() + 1);
+        Err(RetryError::Transient(anyhow::anyhow!("database is locked")))
+    })
+    .await;
+    assert!(result.is_err());
+    assert_eq!(attempts.get(), 4);
+
+    // The permanent error is not retried
+    attempts.set(0);
+    let result: Result<()> = retry_with_backoff(3, interval, || async move {
+        attempts.set(attempts.get() + 1);
+        Err(RetryError::Permanent(anyhow::anyhow!("actor is stopped")))
+    })
+    .await;
+    assert!(result.is_err());
+    assert_eq!(attempts.get(), 1);
+}
@@ -2,22 +2,34 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use anyhow::Result;
+use move_binary_format::CompiledModule;
 use move_core_types::account_address::AccountAddress;
 use move_core_types::language_storage::{StructTag, TypeTag};
+use move_core_types::u256::U256;
 
-use moveos_types::h256::H256;
+use moveos_types::h256::{sha3_256_of, H256};
 use moveos_types::moveos_std::event::Event;
+use moveos_types::moveos_std::move_module::MoveModule;
 use moveos_types::moveos_std::object::RawObject;
 use moveos_types::moveos_std::object_id::ObjectID;
-use moveos_types::state::TableChangeSet;
+use moveos_types::state::{
+    KeyState, MoveState, MoveStructType, MoveType, PlaceholderStruct, State, TableChangeSet,
+};
 use moveos_types::transaction::{MoveAction, TransactionExecutionInfo, VerifiedMoveOSTransaction};
 use rooch_rpc_api::jsonrpc_types::TableChangeSetView;
+use rooch_types::address::MultiChainAddress;
+use rooch_types::bitcoin::utxo::UTXO;
+use rooch_types::framework::coin::CoinInfo;
+use rooch_types::framework::coin_store::{DepositEvent, WithdrawEvent};
+use rooch_types::indexer::coin_transfer::CoinTransferType;
 use rooch_types::multichain_id::MultiChainID;
 use rooch_types::transaction::{
     AbstractTransaction, TransactionSequenceInfo, TransactionType, TypedTransaction,
 };
 
 use crate::errors::IndexerError;
+use rooch_config::indexer_config::IndexerRetention;
+use std::str::FromStr;
 
 pub type IndexerResult<T> = Result<T, IndexerError>;
 
@@ -107,6 +119,18 @@ impl IndexedTransaction {
         };
         Ok(indexed_transaction)
     }
+
+    /// The text of the action for the full-text search index, the action name with the function id and the type arguments of the function call
+    pub fn action_search_text(&self) -> String {
+        match &self.action {
+            MoveAction::Function(call) => {
+                let mut terms = vec![self.action.action_name(), call.function_id.to_string()];
+                terms.extend(call.ty_args.iter().map(|ty| ty.to_canonical_string()));
+                terms.join(" ")
+            }
+            _ => self.action.action_name(),
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -119,6 +143,8 @@ pub struct IndexedEvent {
     pub event_type: StructTag,
     // the data payload of the event
     pub event_data: Vec<u8>,
+    // the decoded event data in json format, stored with the event and used for the full-text search index
+    pub event_data_json: String,
     // event index in the transaction events
     pub event_index: u64,
 
@@ -144,6 +170,7 @@ impl IndexedEvent {
             event_seq: event.event_id.event_seq,
             event_type: event.event_type,
             event_data: event.event_data,
+            event_data_json: String::new(),
             event_index: event.event_index,
 
             tx_hash: transaction.tx_hash(),
@@ -156,6 +183,68 @@ impl IndexedEvent {
     }
 }
 
+#[derive(Debug, Clone)]
+pub struct IndexedCoinTransfer {
+    // The tx order and the event index of the DepositEvent or WithdrawEvent
+    pub tx_order: u64,
+    pub event_index: u64,
+    // The id of the coin store the coin is deposited into or withdrawn from
+    pub coin_store_id: ObjectID,
+    pub coin_type: StructTag,
+    pub transfer_type: CoinTransferType,
+    pub amount: U256,
+
+    pub tx_hash: H256,
+    pub sender: AccountAddress,
+    pub created_at: u64,
+}
+
+impl IndexedCoinTransfer {
+    /// Build the coin transfer if the event is a `DepositEvent` or `WithdrawEvent` of the coin store,
+    /// otherwise return `None`
+    pub fn new_from_event(event: &IndexedEvent) -> Result<Option<Self>> {
+        let (coin_store_id, coin_type, transfer_type, amount) =
+            if DepositEvent::struct_tag_match(&event.event_type) {
+                let deposit = DepositEvent::from_bytes(&event.event_data)?;
+                (
+                    deposit.coin_store_id,
+                    deposit.coin_type,
+                    CoinTransferType::Deposit,
+                    deposit.amount,
+                )
+            } else if WithdrawEvent::struct_tag_match(&event.event_type) {
+                let withdraw = WithdrawEvent::from_bytes(&event.event_data)?;
+                (
+                    withdraw.coin_store_id,
+                    withdraw.coin_type,
+                    CoinTransferType::Withdraw,
+                    withdraw.amount,
+                )
+            } else {
+                return Ok(None);
+            };
+        // The coin type in the event is the type name without the `0x` prefix
+        let coin_type = StructTag::from_str(format!("0x{}", coin_type).as_str())?;
+        Ok(Some(IndexedCoinTransfer {
+            tx_order: event.tx_order,
+            event_index: event.event_index,
+            coin_store_id,
+            coin_type,
+            transfer_type,
+            amount,
+            tx_hash: event.tx_hash,
+            sender: event.sender,
+            created_at: event.created_at,
+        }))
+    }
+}
+
+/// The bytes of the state in the state tree, the length of the key plus the length of the value,
+/// the storage usage of the accounts is accounted by it.
+pub fn state_size(key: &KeyState, value: &State) -> u64 {
+    (key.key.len() + value.value.len()) as u64
+}
+
 #[derive(Debug, Clone)]
 pub struct IndexedGlobalState {
     // The global state key
@@ -180,6 +269,8 @@ pub struct IndexedGlobalState {
     pub created_at: u64,
     // The object updated timestamp on chain
     pub updated_at: u64,
+    // The bytes of the object state in the state tree
+    pub state_size: u64,
 }
 
 impl IndexedGlobalState {
@@ -189,6 +280,7 @@ impl IndexedGlobalState {
         object_type: String,
         tx_order: u64,
         state_index: u64,
+        state_size: u64,
     ) -> Self {
         IndexedGlobalState {
             object_id: raw_object.id,
@@ -204,7 +296,173 @@ impl IndexedGlobalState {
             //TODO record transaction timestamp
             created_at: 0,
             updated_at: 0,
+            state_size,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct IndexedUTXOState {
+    // The object id of the UTXO
+    pub object_id: ObjectID,
+    // The owner of the UTXO
+    pub owner: AccountAddress,
+    // A flag to indicate whether the object is shared or frozen
+    pub flag: u8,
+    // The txid of the UTXO
+    pub txid: AccountAddress,
+    // The vout of the UTXO
+    pub vout: u32,
+    // The value of the UTXO
+    pub value: u64,
+    // The protocol seals of the UTXO, json format
+    pub seals: String,
+    // The tx order of this transaction
+    pub tx_order: u64,
+    // The state index in the tx
+    pub state_index: u64,
+    // The object created timestamp on chain
+    pub created_at: u64,
+    // The object updated timestamp on chain
+    pub updated_at: u64,
+}
+
+impl IndexedUTXOState {
+    pub fn new_from_raw_object(
+        raw_object: RawObject,
+        tx_order: u64,
+        state_index: u64,
+        timestamp: u64,
+    ) -> Result<Self> {
+        let utxo = bcs::from_bytes::<UTXO>(&raw_object.value.value)?;
+        let seals = serde_json::to_string(&utxo.seals)?;
+        Ok(IndexedUTXOState {
+            object_id: raw_object.id,
+            owner: raw_object.owner,
+            flag: raw_object.flag,
+            txid: utxo.txid,
+            vout: utxo.vout,
+            value: utxo.value,
+            seals,
+            tx_order,
+            state_index,
+            created_at: timestamp,
+            updated_at: timestamp,
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct IndexedCoinInfo {
+    // The coin type
+    pub coin_type: StructTag,
+    // The name of the coin
+    pub name: String,
+    // The symbol of the coin
+    pub symbol: String,
+    // The url of the coin icon
+    pub icon_url: Option<String>,
+    // The decimals of the coin
+    pub decimals: u8,
+    // The total supply of the coin
+    pub supply: U256,
+    // The tx order of the last update of the CoinInfo object
+    pub tx_order: u64,
+}
+
+impl IndexedCoinInfo {
+    pub fn new_from_raw_object(raw_object: RawObject, tx_order: u64) -> Result<Self> {
+        let coin_info = bcs::from_bytes::<CoinInfo<PlaceholderStruct>>(&raw_object.value.value)?;
+        Ok(IndexedCoinInfo {
+            coin_type: coin_info.coin_type_tag(),
+            name: coin_info.name(),
+            symbol: coin_info.symbol(),
+            icon_url: coin_info.icon_url(),
+            decimals: coin_info.decimals(),
+            supply: coin_info.supply(),
+            tx_order,
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct IndexedAddressMapping {
+    // The multichain address, such as the Bitcoin or Ethereum address
+    pub multichain_address: MultiChainAddress,
+    // The Rooch address which the multichain address is mapped to
+    pub rooch_address: AccountAddress,
+    // The tx order of the transaction which binds the multichain address
+    pub tx_order: u64,
+}
+
+impl IndexedAddressMapping {
+    /// The multichain address of the table key if the table is the `MultiChainAddress -> address` mapping
+    /// of the framework address mapping, otherwise return `None`
+    pub fn multichain_address_from_key(key: &KeyState) -> Result<Option<MultiChainAddress>> {
+        if key.key_type != MultiChainAddress::type_tag() {
+            return Ok(None);
+        }
+        Ok(Some(bcs::from_bytes::<MultiChainAddress>(&key.key)?))
+    }
+
+    /// Build the address mapping if the table item is an entry of the framework address mapping,
+    /// otherwise return `None`
+    pub fn new_from_table_item(
+        key: &KeyState,
+        value: &State,
+        tx_order: u64,
+    ) -> Result<Option<Self>> {
+        if value.value_type != TypeTag::Address {
+            return Ok(None);
         }
+        let Some(multichain_address) = Self::multichain_address_from_key(key)? else {
+            return Ok(None);
+        };
+        let rooch_address = bcs::from_bytes::<AccountAddress>(&value.value)?;
+        Ok(Some(IndexedAddressMapping {
+            multichain_address,
+            rooch_address,
+            tx_order,
+        }))
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct IndexedModule {
+    // The address of the module, it is also the publisher of the module
+    pub address: AccountAddress,
+    pub name: String,
+    // The sha3-256 hash of the module bytecode
+    pub bytecode_hash: H256,
+    // The digest of the package source, it is not recorded on chain yet
+    pub source_digest: Option<H256>,
+    // The tx order of the transaction which publishes or upgrades the module
+    pub tx_order: u64,
+    // The state index of the module table item in the transaction
+    pub state_index: u64,
+}
+
+impl IndexedModule {
+    /// Build the module if the table item is a module of the module table of an account,
+    /// otherwise return `None`
+    pub fn new_from_table_item(
+        value: &State,
+        tx_order: u64,
+        state_index: u64,
+    ) -> Result<Option<Self>> {
+        if value.value_type != MoveModule::type_tag() {
+            return Ok(None);
+        }
+        let module = value.cast::<MoveModule>()?;
+        let module_id = CompiledModule::deserialize(&module.byte_codes)?.self_id();
+        Ok(Some(IndexedModule {
+            address: *module_id.address(),
+            name: module_id.name().to_string(),
+            bytecode_hash: sha3_256_of(&module.byte_codes),
+            source_digest: None,
+            tx_order,
+            state_index,
+        }))
     }
 }
 
@@ -231,6 +489,8 @@ pub struct IndexedTableState {
     pub created_at: u64,
     // The table item updated timestamp on chain
     pub updated_at: u64,
+    // The bytes of the table item state in the state tree
+    pub state_size: u64,
 }
 
 impl IndexedTableState {
@@ -243,6 +503,7 @@ impl IndexedTableState {
         value_type: TypeTag,
         tx_order: u64,
         state_index: u64,
+        state_size: u64,
     ) -> Self {
         IndexedTableState {
             table_handle,
@@ -257,6 +518,7 @@ impl IndexedTableState {
             //TODO record transaction timestamp
             created_at: 0,
             updated_at: 0,
+            state_size,
         }
     }
 }
@@ -296,3 +558,86 @@ impl IndexedTableChangeSet {
         })
     }
 }
+
+/// The indexed data tracked by the indexer progress, every one is indexed by a separate message
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IndexerProgressTable {
+    Transactions,
+    Events,
+    States,
+}
+
+impl IndexerProgressTable {
+    pub const ALL: [IndexerProgressTable; 3] = [
+        IndexerProgressTable::Transactions,
+        IndexerProgressTable::Events,
+        IndexerProgressTable::States,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            IndexerProgressTable::Transactions => "transactions",
+            IndexerProgressTable::Events => "events",
+            IndexerProgressTable::States => "states",
+        }
+    }
+}
+
+impl std::fmt::Display for IndexerProgressTable {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// The indexed history tables pruned by the indexer retention, the rows are keyed by the tx order
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum IndexerHistoryTable {
+    TableChangeSets,
+    Events,
+    CoinTransfers,
+    ObjectOwnershipChanges,
+}
+
+impl IndexerHistoryTable {
+    pub const ALL: [IndexerHistoryTable; 4] = [
+        IndexerHistoryTable::TableChangeSets,
+        IndexerHistoryTable::Events,
+        IndexerHistoryTable::CoinTransfers,
+        IndexerHistoryTable::ObjectOwnershipChanges,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            IndexerHistoryTable::TableChangeSets => "table_change_sets",
+            IndexerHistoryTable::Events => "events",
+            IndexerHistoryTable::CoinTransfers => "coin_transfers",
+            IndexerHistoryTable::ObjectOwnershipChanges => "object_ownership_changes",
+        }
+    }
+
+    /// The SQLite tables of the history, including the full-text search and the bloom filter tables of the events
+    pub fn sqlite_tables(&self) -> &'static [&'static str] {
+        match self {
+            IndexerHistoryTable::TableChangeSets => &["table_change_sets"],
+            IndexerHistoryTable::Events => &["events", "events_fts", "event_blooms"],
+            IndexerHistoryTable::CoinTransfers => &["coin_transfers"],
+            IndexerHistoryTable::ObjectOwnershipChanges => &["object_ownership_changes"],
+        }
+    }
+
+    /// The number of the latest transactions whose rows are retained
+    pub fn retention(&self, retention: &IndexerRetention) -> Option<u64> {
+        match self {
+            IndexerHistoryTable::TableChangeSets => retention.table_change_sets,
+            IndexerHistoryTable::Events => retention.events,
+            IndexerHistoryTable::CoinTransfers => retention.coin_transfers,
+            IndexerHistoryTable::ObjectOwnershipChanges => retention.object_ownership_changes,
+        }
+    }
+}
+
+impl std::fmt::Display for IndexerHistoryTable {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
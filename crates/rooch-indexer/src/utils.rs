@@ -2,6 +2,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::SqlitePoolConnection;
+use crate::LOG_TARGET;
 use anyhow::anyhow;
 use diesel::{RunQueryDsl, SqliteConnection};
 use diesel_migrations::{embed_migrations, EmbeddedMigrations, MigrationHarness};
@@ -14,7 +15,7 @@ const MIGRATIONS: EmbeddedMigrations = embed_migrations!("migrations");
 pub fn create_all_tables_if_not_exists(
     conn: &mut SqlitePoolConnection,
 ) -> Result<(), anyhow::Error> {
-    info!("Indexer creates all tables in the db ...");
+    info!(target: LOG_TARGET, "Indexer creates all tables in the db ...");
     let migration = MIGRATIONS;
 
     // Create the __diesel_schema_migrations table if not exist
@@ -30,7 +31,7 @@ pub fn create_all_tables_if_not_exists(
 
     conn.run_pending_migrations(migration)
         .map_err(|e| anyhow!("Failed to run migrations {e}"))?;
-    info!("Indexer creates all tables complete.");
+    info!(target: LOG_TARGET, "Indexer creates all tables complete.");
     Ok(())
 }
 
@@ -40,7 +41,7 @@ pub fn create_all_tables_if_not_exists(
 /// resetting the migrations. This option is destructive and will result in the loss of all
 /// data in the tables. Use with caution, especially in production environments.
 pub fn reset_db(conn: &mut SqlitePoolConnection, drop_all: bool) -> Result<(), anyhow::Error> {
-    info!("Resetting db ...");
+    info!(target: LOG_TARGET, "Resetting db ...");
     let migration = MIGRATIONS;
     if drop_all {
         drop_all_tables(conn)
@@ -52,12 +53,12 @@ pub fn reset_db(conn: &mut SqlitePoolConnection, drop_all: bool) -> Result<(), a
     let migration = MIGRATIONS;
     conn.run_pending_migrations(migration)
         .map_err(|e| anyhow!("Failed to run migrations {e}"))?;
-    info!("Reset db complete.");
+    info!(target: LOG_TARGET, "Reset db complete.");
     Ok(())
 }
 
 pub fn drop_all_tables(conn: &mut SqliteConnection) -> Result<(), diesel::result::Error> {
-    info!("Dropping all tables in the db ...");
+    info!(target: LOG_TARGET, "Dropping all tables in the db ...");
     let table_names: Vec<String> = diesel::dsl::sql::<diesel::sql_types::Text>(
         "
         SELECT name FROM sqlite_schema WHERE type = 'table'
@@ -80,7 +81,7 @@ pub fn drop_all_tables(conn: &mut SqliteConnection) -> Result<(), diesel::result
     ",
     )
     .execute(conn)?;
-    info!("Dropped all tables complete.");
+    info!(target: LOG_TARGET, "Dropped all tables complete.");
     Ok(())
 }
 
@@ -89,6 +90,17 @@ pub fn escape_sql_string(value: String) -> String {
     value.replace(['\''], "''")
 }
 
+/// Convert the user input to a FTS5 match query, every whitespace separated term is quoted as a phrase,
+/// so the special characters of the FTS5 query syntax in the input are matched literally.
+/// All the terms must be matched.
+pub fn format_fts_query(query: &str) -> String {
+    query
+        .split_whitespace()
+        .map(|term| format!("\"{}\"", term.replace('"', "\"\"")))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
 // For better generate sql index for indexer query
 pub fn format_struct_tag(sturct_tag: StructTag) -> String {
     let address = format!("0x{}", sturct_tag.address.to_canonical_string());
@@ -15,8 +15,10 @@ use rand::rngs::OsRng;
 use rooch_types::address::RoochAddress;
 use rooch_types::crypto::RoochKeyPair;
 use rooch_types::error::RoochError;
+use rooch_types::framework::auth_validator::BuiltinAuthValidator;
 use rooch_types::key_struct::{EncryptionData, GenerateNewKeyPair, GeneratedKeyPair};
 use rooch_types::multichain_id::RoochMultiChainID;
+use rooch_types::schnorr::{SchnorrKeyPair, SCHNORR_PRIVATE_KEY_LENGTH};
 use slip10_ed25519::derive_ed25519_private_key;
 use std::str::FromStr;
 use std::string::String;
@@ -140,6 +142,16 @@ pub fn derive_address_from_private_key(private_key: Vec<u8>) -> Result<RoochAddr
     Ok(address)
 }
 
+/// The Schnorr private key is encrypted as `flag || private key`, to distinguish it from the Ed25519 private key.
+pub fn encrypt_schnorr_key(
+    kp: &SchnorrKeyPair,
+    password: Option<String>,
+) -> Result<EncryptionData, RoochError> {
+    let mut key = vec![BuiltinAuthValidator::Schnorr.flag()];
+    key.extend_from_slice(&kp.private_key_bytes());
+    encrypt_key(&key, password)
+}
+
 pub fn retrieve_key_pair(
     encryption: &EncryptionData,
     password: Option<String>,
@@ -153,6 +165,14 @@ pub fn retrieve_key_pair(
 
     let private_key = decrypt_key(&nonce, &ciphertext, &tag, password)?;
 
+    if private_key.len() == SCHNORR_PRIVATE_KEY_LENGTH + 1
+        && private_key[0] == BuiltinAuthValidator::Schnorr.flag()
+    {
+        return Ok(RoochKeyPair::Schnorr(
+            SchnorrKeyPair::from_private_key_bytes(&private_key[1..])?,
+        ));
+    }
+
     let kp = Ed25519KeyPair::from(
         Ed25519PrivateKey::from_bytes(&private_key)
             .map_err(|e| RoochError::SignatureKeyGenError(e.to_string()))?,
@@ -209,6 +229,22 @@ pub fn generate_derivation_path(account_index: u32) -> Result<DerivationPath, Ro
     .map_err(|_| RoochError::SignatureKeyGenError("Cannot parse derivation path".to_owned()))
 }
 
+/// Derivation path of the session keys, the change level is 1 to separate them from the account keys
+/// m / purpose' / coin_type' / 0' / 1' / session_key_index'
+pub fn generate_session_key_derivation_path(
+    session_key_index: u32,
+) -> Result<DerivationPath, RoochError> {
+    let (purpose, coin_type) = (
+        DERIVATION_PATH_PURPOSE_ED25519,
+        RoochMultiChainID::Rooch as u32,
+    );
+
+    DerivationPath::from_str(
+        format!("m/{}'/{}'/0'/1'/{}'", purpose, coin_type, session_key_index).as_str(),
+    )
+    .map_err(|_| RoochError::SignatureKeyGenError("Cannot parse derivation path".to_owned()))
+}
+
 pub fn generate_new_key_pair(
     mnemonic_phrase: Option<String>,
     derivation_path: Option<DerivationPath>,
@@ -4,7 +4,8 @@
 use super::types::LocalAccount;
 use crate::key_derive::{
     derive_address_from_private_key, derive_private_key_from_path, encrypt_key,
-    generate_derivation_path, generate_new_key_pair, hash_password,
+    encrypt_schnorr_key, generate_derivation_path, generate_new_key_pair, hash_password,
+    validate_derivation_path,
 };
 use crate::keystore::ImportedMnemonic;
 use bip32::DerivationPath;
@@ -12,6 +13,7 @@ use bip39::{Language, Mnemonic, Seed};
 use fastcrypto::encoding::{Base64, Encoding};
 use rooch_types::framework::session_key::SessionKey;
 use rooch_types::key_struct::{MnemonicData, MnemonicResult};
+use rooch_types::schnorr::SchnorrKeyPair;
 use rooch_types::{
     address::RoochAddress,
     authentication_key::AuthenticationKey,
@@ -64,6 +66,22 @@ pub trait AccountKeystore {
     ) -> Result<(), anyhow::Error>;
     fn nullify(&mut self, address: &RoochAddress) -> Result<(), anyhow::Error>;
 
+    /// Replace the key of the address with the new key, the old key is kept under the `rotated` tag
+    /// until the rotation is confirmed by `confirm_rotated_key` or reverted by `restore_rotated_key`.
+    fn rotate_address_encryption_data(
+        &mut self,
+        address: &RoochAddress,
+        encryption: EncryptionData,
+    ) -> Result<(), anyhow::Error>;
+
+    fn has_rotated_key(&self, address: &RoochAddress) -> bool;
+
+    /// Drop the rotated key of the address after the rotation transaction is confirmed on-chain
+    fn confirm_rotated_key(&mut self, address: &RoochAddress) -> Result<(), anyhow::Error>;
+
+    /// Restore the rotated key as the key of the address, the new key is dropped
+    fn restore_rotated_key(&mut self, address: &RoochAddress) -> Result<(), anyhow::Error>;
+
     fn sign_hashed(
         &self,
         address: &RoochAddress,
@@ -89,6 +107,30 @@ pub trait AccountKeystore {
 
     fn addresses(&self) -> Vec<RoochAddress>;
 
+    /// Record the derivation path of the key derived from a mnemonic
+    fn set_derivation_path(
+        &mut self,
+        address: RoochAddress,
+        derivation_path: &DerivationPath,
+    ) -> Result<(), anyhow::Error>;
+
+    fn get_derivation_path(&self, address: &RoochAddress) -> Option<DerivationPath>;
+
+    /// The next account index of the mnemonic, after the indexes of all the derived addresses
+    fn next_account_index(&self, mnemonic_data: &MnemonicData) -> u32 {
+        mnemonic_data
+            .addresses
+            .iter()
+            .filter_map(|address| self.get_derivation_path(address))
+            .filter_map(|derivation_path| {
+                derivation_path
+                    .iter()
+                    .last()
+                    .map(|child_number| child_number.index() + 1)
+            })
+            .fold(mnemonic_data.addresses.len() as u32, u32::max)
+    }
+
     fn generate_and_add_new_key(
         &mut self,
         mnemonic_phrase: Option<String>,
@@ -105,23 +147,39 @@ pub trait AccountKeystore {
                 .clone()
                 .map(|mnemonic| mnemonic.mnemonic_phrase)
         };
-        let derivation_path = if derivation_path.is_none() && one_mnemonic.is_some() {
-            let account_index = one_mnemonic.clone().unwrap().mnemonic_data.addresses.len() as u32;
-            Some(generate_derivation_path(account_index)?)
-        } else {
-            None
+        // derive the next account of the mnemonic if the derivation path is not specified
+        let derivation_path = match derivation_path {
+            Some(derivation_path) => derivation_path,
+            None => {
+                let account_index = one_mnemonic
+                    .as_ref()
+                    .map(|mnemonic| self.next_account_index(&mnemonic.mnemonic_data))
+                    .unwrap_or_default();
+                generate_derivation_path(account_index)?
+            }
         };
 
-        let result =
-            generate_new_key_pair(mnemonic_phrase, derivation_path, word_length, password)?;
+        let result = generate_new_key_pair(
+            mnemonic_phrase,
+            Some(derivation_path.clone()),
+            word_length,
+            password,
+        )?;
         let new_address = result.address;
         self.add_address_encryption_data(
             new_address,
             result.key_pair_data.private_key_encryption.clone(),
         )?;
+        self.set_derivation_path(new_address, &derivation_path)?;
         // reuse mnemonic if mnemonic already generate
         if let Some(mut update_mnemonic) = one_mnemonic {
-            update_mnemonic.mnemonic_data.addresses.push(new_address);
+            if !update_mnemonic
+                .mnemonic_data
+                .addresses
+                .contains(&new_address)
+            {
+                update_mnemonic.mnemonic_data.addresses.push(new_address);
+            }
             self.update_mnemonic_data(
                 update_mnemonic.mnemonic_phrase_key,
                 update_mnemonic.mnemonic_data,
@@ -143,6 +201,22 @@ pub trait AccountKeystore {
         Ok(result)
     }
 
+    /// Derive the key at the account index from the mnemonic of the keystore,
+    /// the derivation path is `m/44'/784'/0'/0'/{account_index}'`.
+    fn derive_new_key(
+        &mut self,
+        account_index: u32,
+        password: Option<String>,
+    ) -> Result<GeneratedKeyPair, anyhow::Error> {
+        if self.get_mnemonics(password.clone())?.is_empty() {
+            return Err(anyhow::anyhow!(
+                "Cannot find mnemonic in the keystore, create an account first"
+            ));
+        }
+        let derivation_path = generate_derivation_path(account_index)?;
+        self.generate_and_add_new_key(None, Some(derivation_path), None, password)
+    }
+
     fn import_from_mnemonic(
         &mut self,
         phrase: &str,
@@ -152,7 +226,8 @@ pub trait AccountKeystore {
         let mnemonic = Mnemonic::from_phrase(phrase, Language::English)?;
         let seed = Seed::new(&mnemonic, "");
 
-        let sk = derive_private_key_from_path(seed.as_bytes(), derivation_path)?;
+        let derivation_path = validate_derivation_path(derivation_path)?;
+        let sk = derive_private_key_from_path(seed.as_bytes(), Some(derivation_path.clone()))?;
 
         let encryption = encrypt_key(&sk, password).expect("Encryption failed for private key");
 
@@ -164,10 +239,25 @@ pub trait AccountKeystore {
         };
 
         self.add_address_encryption_data(result.address, encryption)?;
+        self.set_derivation_path(result.address, &derivation_path)?;
 
         Ok(result)
     }
 
+    /// Import a secp256k1 private key as the BIP-340 Schnorr key, such as the untweaked internal key of a BIP-86 taproot wallet.
+    /// The address is derived from the x-only public key.
+    fn import_schnorr_private_key(
+        &mut self,
+        private_key: &[u8],
+        password: Option<String>,
+    ) -> Result<RoochAddress, anyhow::Error> {
+        let kp = SchnorrKeyPair::from_private_key_bytes(private_key)?;
+        let address = PublicKey::Schnorr(kp.public()).address();
+        let encryption = encrypt_schnorr_key(&kp, password)?;
+        self.add_address_encryption_data(address, encryption)?;
+        Ok(address)
+    }
+
     fn update_address_with_encryption_data(
         &mut self,
         _address: &RoochAddress,
@@ -208,6 +298,18 @@ pub trait AccountKeystore {
         session_key: SessionKey,
     ) -> Result<(), anyhow::Error>;
 
+    /// Get the local session keys of the address, with the on-chain SessionKey if it has been bound
+    fn get_session_keys(
+        &self,
+        address: &RoochAddress,
+    ) -> Result<Vec<(AuthenticationKey, Option<SessionKey>)>, anyhow::Error>;
+
+    fn remove_session_key(
+        &mut self,
+        address: &RoochAddress,
+        authentication_key: &AuthenticationKey,
+    ) -> Result<(), anyhow::Error>;
+
     fn sign_transaction_via_session_key(
         &self,
         address: &RoochAddress,
@@ -4,9 +4,12 @@
 use std::collections::BTreeMap;
 
 use super::types::{AddressMapping, LocalAccount, LocalSessionKey};
-use crate::key_derive::{decrypt_key, generate_new_key_pair, retrieve_key_pair};
+use crate::key_derive::{
+    decrypt_key, generate_new_key_pair, generate_session_key_derivation_path, retrieve_key_pair,
+};
 use crate::keystore::account_keystore::AccountKeystore;
 use anyhow::anyhow;
+use bip32::DerivationPath;
 use fastcrypto::encoding::{Base64, Encoding};
 use rooch_types::framework::session_key::SessionKey;
 use rooch_types::key_struct::{MnemonicData, MnemonicResult};
@@ -40,6 +43,12 @@ pub(crate) struct BaseKeyStore {
     pub(crate) is_password_empty: bool,
     #[serde(default)]
     pub(crate) address_mapping: AddressMapping,
+    /// The old keys replaced by the key rotation, kept until the rotation transaction is confirmed
+    #[serde(default)]
+    pub(crate) rotated: BTreeMap<RoochAddress, EncryptionData>,
+    /// The derivation paths of the keys derived from the mnemonics
+    #[serde(default)]
+    pub(crate) derivation_paths: BTreeMap<RoochAddress, String>,
 }
 
 impl BaseKeyStore {
@@ -51,10 +60,26 @@ impl BaseKeyStore {
             password_hash: None,
             is_password_empty: true,
             address_mapping: AddressMapping::default(),
+            rotated: BTreeMap::new(),
+            derivation_paths: BTreeMap::new(),
         }
     }
 }
 
+impl BaseKeyStore {
+    /// The index of the next session key derived from the mnemonic
+    fn next_session_key_index(&self) -> u32 {
+        self.session_keys
+            .values()
+            .flat_map(|inner_map| inner_map.values())
+            .filter_map(|local_session_key| local_session_key.derivation_path.as_ref())
+            .filter_map(|path| path.parse::<DerivationPath>().ok())
+            .filter_map(|path| path.as_ref().last().map(|child| child.index() + 1))
+            .max()
+            .unwrap_or_default()
+    }
+}
+
 impl AccountKeystore for BaseKeyStore {
     fn get_accounts(&self, password: Option<String>) -> Result<Vec<LocalAccount>, anyhow::Error> {
         let mut accounts = BTreeMap::new();
@@ -72,6 +97,7 @@ impl AccountKeystore for BaseKeyStore {
                 multichain_address,
                 public_key: Some(public_key),
                 has_session_key,
+                derivation_path: self.derivation_paths.get(address).cloned(),
             };
             accounts.insert(*address, local_account);
         }
@@ -90,6 +116,7 @@ impl AccountKeystore for BaseKeyStore {
                 multichain_address,
                 public_key: None,
                 has_session_key,
+                derivation_path: None,
             };
             accounts.insert(*address, local_account);
         }
@@ -216,6 +243,48 @@ impl AccountKeystore for BaseKeyStore {
 
     fn nullify(&mut self, address: &RoochAddress) -> Result<(), anyhow::Error> {
         self.keys.remove(address);
+        self.rotated.remove(address);
+        self.derivation_paths.remove(address);
+        Ok(())
+    }
+
+    fn rotate_address_encryption_data(
+        &mut self,
+        address: &RoochAddress,
+        encryption: EncryptionData,
+    ) -> Result<(), anyhow::Error> {
+        if self.rotated.contains_key(address) {
+            return Err(anyhow!(
+                "The key rotation of address [{address}] is not confirmed yet"
+            ));
+        }
+        let old_encryption = self
+            .keys
+            .get(address)
+            .cloned()
+            .ok_or_else(|| anyhow!("Cannot find key for address: [{address}]"))?;
+        self.keys.insert(*address, encryption);
+        self.rotated.insert(*address, old_encryption);
+        Ok(())
+    }
+
+    fn has_rotated_key(&self, address: &RoochAddress) -> bool {
+        self.rotated.contains_key(address)
+    }
+
+    fn confirm_rotated_key(&mut self, address: &RoochAddress) -> Result<(), anyhow::Error> {
+        self.rotated
+            .remove(address)
+            .ok_or_else(|| anyhow!("Cannot find rotated key for address: [{address}]"))?;
+        Ok(())
+    }
+
+    fn restore_rotated_key(&mut self, address: &RoochAddress) -> Result<(), anyhow::Error> {
+        let old_encryption = self
+            .rotated
+            .remove(address)
+            .ok_or_else(|| anyhow!("Cannot find rotated key for address: [{address}]"))?;
+        self.keys.insert(*address, old_encryption);
         Ok(())
     }
 
@@ -224,8 +293,24 @@ impl AccountKeystore for BaseKeyStore {
         address: &RoochAddress,
         password: Option<String>,
     ) -> Result<AuthenticationKey, anyhow::Error> {
-        //TODO define derivation_path for session key
-        let result = generate_new_key_pair(None, None, None, password.clone())?;
+        // Derive the session key from the mnemonic of the keystore if there is one,
+        // so the session keys are deterministic and could be recovered from the mnemonic
+        let mnemonic_phrase = self
+            .get_mnemonics(password.clone())?
+            .pop()
+            .map(|mnemonic| mnemonic.mnemonic_phrase);
+        let derivation_path = match mnemonic_phrase {
+            Some(_) => Some(generate_session_key_derivation_path(
+                self.next_session_key_index(),
+            )?),
+            None => None,
+        };
+        let result = generate_new_key_pair(
+            mnemonic_phrase,
+            derivation_path.clone(),
+            None,
+            password.clone(),
+        )?;
         let kp: RoochKeyPair =
             retrieve_key_pair(&result.key_pair_data.private_key_encryption, password)?;
         let authentication_key = kp.public().authentication_key();
@@ -233,6 +318,7 @@ impl AccountKeystore for BaseKeyStore {
         let local_session_key = LocalSessionKey {
             session_key: None,
             private_key: result.key_pair_data.private_key_encryption,
+            derivation_path: derivation_path.map(|path| path.to_string()),
         };
         inner_map.insert(authentication_key.clone(), local_session_key);
         Ok(authentication_key)
@@ -253,6 +339,46 @@ impl AccountKeystore for BaseKeyStore {
         Ok(())
     }
 
+    fn get_session_keys(
+        &self,
+        address: &RoochAddress,
+    ) -> Result<Vec<(AuthenticationKey, Option<SessionKey>)>, anyhow::Error> {
+        Ok(self
+            .session_keys
+            .get(address)
+            .map(|inner_map| {
+                inner_map
+                    .iter()
+                    .map(|(authentication_key, local_session_key)| {
+                        (
+                            authentication_key.clone(),
+                            local_session_key.session_key.clone(),
+                        )
+                    })
+                    .collect()
+            })
+            .unwrap_or_default())
+    }
+
+    fn remove_session_key(
+        &mut self,
+        address: &RoochAddress,
+        authentication_key: &AuthenticationKey,
+    ) -> Result<(), anyhow::Error> {
+        let inner_map = self.session_keys.get_mut(address).ok_or_else(|| {
+            anyhow::Error::new(RoochError::KeyConversionError(format!(
+                "Cannot find session key for address:[{address}]"
+            )))
+        })?;
+        inner_map.remove(authentication_key).ok_or_else(|| {
+            anyhow::Error::new(RoochError::KeyConversionError(format!("Cannot find session key for address:[{address}] and authentication_key:[{authentication_key}]")))
+        })?;
+        if inner_map.is_empty() {
+            self.session_keys.remove(address);
+        }
+        Ok(())
+    }
+
     fn sign_transaction_via_session_key(
         &self,
         address: &RoochAddress,
@@ -292,6 +418,22 @@ impl AccountKeystore for BaseKeyStore {
         Ok(RoochTransaction::new(msg, auth))
     }
 
+    fn set_derivation_path(
+        &mut self,
+        address: RoochAddress,
+        derivation_path: &DerivationPath,
+    ) -> Result<(), anyhow::Error> {
+        self.derivation_paths
+            .insert(address, derivation_path.to_string());
+        Ok(())
+    }
+
+    fn get_derivation_path(&self, address: &RoochAddress) -> Option<DerivationPath> {
+        self.derivation_paths
+            .get(address)
+            .and_then(|derivation_path| derivation_path.parse().ok())
+    }
+
     fn addresses(&self) -> Vec<RoochAddress> {
         // Create an empty Vec to store the addresses.
         let mut addresses = Vec::new();
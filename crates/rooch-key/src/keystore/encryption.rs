@@ -0,0 +1,149 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::key_derive::{decrypt_key, encrypt_key};
+use fastcrypto::encoding::{Base64, Encoding};
+use rooch_types::error::RoochError;
+use rooch_types::key_struct::EncryptionData;
+use serde::{Deserialize, Serialize};
+
+/// The environment variable of the keystore password, used when the password prompt is not available.
+pub const ROOCH_KEYSTORE_PASSWORD_ENV: &str = "ROOCH_KEYSTORE_PASSWORD";
+
+pub const ENCRYPTED_KEYSTORE_VERSION: u32 = 1;
+
+const NONCE_LENGTH: usize = 12;
+
+/// The on-disk format of the encrypted keystore, the whole keystore is encrypted with the same scheme
+/// as the keys in the keystore, see `encrypt_key`.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct EncryptedKeystore {
+    pub version: u32,
+    #[serde(flatten)]
+    pub encryption: EncryptionData,
+}
+
+impl EncryptedKeystore {
+    pub fn encrypt(plaintext: &[u8], password: &str) -> Result<Self, RoochError> {
+        Ok(Self {
+            version: ENCRYPTED_KEYSTORE_VERSION,
+            encryption: encrypt_key(plaintext, Some(password.to_owned()))?,
+        })
+    }
+
+    pub fn decrypt(&self, password: &str) -> Result<Vec<u8>, RoochError> {
+        if self.version != ENCRYPTED_KEYSTORE_VERSION {
+            return Err(RoochError::KeyConversionError(format!(
+                "Unsupported encrypted keystore version: {}",
+                self.version
+            )));
+        }
+        let decode = |data: &str| {
+            Base64::decode(data).map_err(|e| RoochError::KeyConversionError(e.to_string()))
+        };
+        let nonce = decode(&self.encryption.nonce)?;
+        if nonce.len() != NONCE_LENGTH {
+            return Err(RoochError::KeyConversionError(format!(
+                "Invalid keystore nonce length: {}",
+                nonce.len()
+            )));
+        }
+        decrypt_key(
+            &nonce,
+            &decode(&self.encryption.ciphertext)?,
+            &decode(&self.encryption.tag)?,
+            Some(password.to_owned()),
+        )
+        .map_err(|_| {
+            RoochError::InvalidPasswordError(
+                "Keystore decryption failed, the password is invalid".to_owned(),
+            )
+        })
+    }
+}
+
+/// Read the keystore password from the `ROOCH_KEYSTORE_PASSWORD` environment variable,
+/// or prompt the user if it is not set.
+pub fn read_keystore_password(prompt: &str) -> Result<String, RoochError> {
+    if let Ok(password) = std::env::var(ROOCH_KEYSTORE_PASSWORD_ENV) {
+        return Ok(password);
+    }
+    rpassword::prompt_password(prompt).map_err(|e| {
+        RoochError::InvalidPasswordError(format!("Read keystore password failed: {}", e))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encrypt_decrypt() {
+        let plaintext = b"{\"keys\":{}}";
+        let encrypted = EncryptedKeystore::encrypt(plaintext, "password").unwrap();
+        assert_eq!(encrypted.version, ENCRYPTED_KEYSTORE_VERSION);
+        assert_eq!(encrypted.decrypt("password").unwrap(), plaintext);
+
+        // The nonce is random
+        let other = EncryptedKeystore::encrypt(plaintext, "password").unwrap();
+        assert_ne!(encrypted.encryption.nonce, other.encryption.nonce);
+
+        // The keystore file format
+        let json = serde_json::to_value(&encrypted).unwrap();
+        assert!(json.get("ciphertext").is_some());
+        let decoded: EncryptedKeystore = serde_json::from_value(json).unwrap();
+        assert_eq!(decoded.decrypt("password").unwrap(), plaintext);
+    }
+
+    #[test]
+    fn test_decrypt_with_wrong_password() {
+        let encrypted = EncryptedKeystore::encrypt(b"keystore", "password").unwrap();
+        assert!(matches!(
+            encrypted.decrypt("wrong password"),
+            Err(RoochError::InvalidPasswordError(_))
+        ));
+        assert!(encrypted.decrypt("").is_err());
+    }
+
+    fn tamper(
+        encrypted: &EncryptedKeystore,
+        field: impl Fn(&mut EncryptionData) -> &mut String,
+        tamper_bytes: impl FnOnce(&mut Vec<u8>),
+    ) -> EncryptedKeystore {
+        let mut tampered = encrypted.clone();
+        let data = field(&mut tampered.encryption);
+        let mut bytes = Base64::decode(data).unwrap();
+        tamper_bytes(&mut bytes);
+        *data = Base64::encode(bytes);
+        tampered
+    }
+
+    #[test]
+    fn test_decrypt_tampered_keystore() {
+        let encrypted = EncryptedKeystore::encrypt(b"keystore", "password").unwrap();
+
+        let tampered = tamper(&encrypted, |data| &mut data.nonce, |bytes| bytes[0] ^= 1);
+        assert!(tampered.decrypt("password").is_err());
+        let tampered = tamper(
+            &encrypted,
+            |data| &mut data.ciphertext,
+            |bytes| bytes[0] ^= 1,
+        );
+        assert!(tampered.decrypt("password").is_err());
+        let tampered = tamper(&encrypted, |data| &mut data.tag, |bytes| bytes[0] ^= 1);
+        assert!(tampered.decrypt("password").is_err());
+        // The invalid nonce length is rejected instead of panicking
+        let tampered = tamper(
+            &encrypted,
+            |data| &mut data.nonce,
+            |bytes| bytes.truncate(8),
+        );
+        assert!(tampered.decrypt("password").is_err());
+
+        let tampered = EncryptedKeystore {
+            version: ENCRYPTED_KEYSTORE_VERSION + 1,
+            ..encrypted
+        };
+        assert!(tampered.decrypt("password").is_err());
+    }
+}
@@ -5,12 +5,18 @@ use super::types::LocalAccount;
 use crate::key_derive::retrieve_key_pair;
 use crate::keystore::account_keystore::AccountKeystore;
 use crate::keystore::base_keystore::BaseKeyStore;
+use crate::keystore::encryption::{
+    read_keystore_password, EncryptedKeystore, ROOCH_KEYSTORE_PASSWORD_ENV,
+};
 use anyhow::anyhow;
+use bip32::DerivationPath;
+use rooch_types::framework::session_key::SessionKey;
 use rooch_types::key_struct::{MnemonicData, MnemonicResult};
 use rooch_types::{
     address::RoochAddress,
     authentication_key::AuthenticationKey,
     crypto::{PublicKey, RoochKeyPair, Signature},
+    error::RoochError,
     key_struct::EncryptionData,
     transaction::rooch::{RoochTransaction, RoochTransactionData},
 };
@@ -21,10 +27,30 @@ use std::fs::File;
 use std::io::BufReader;
 use std::path::{Path, PathBuf};
 
+/// The at-rest status of the keystore file
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum KeystoreFileStatus {
+    Encrypted,
+    /// The keystore file is stored in plaintext, `rooch account lock` encrypts it
+    Plaintext,
+}
+
+impl std::fmt::Display for KeystoreFileStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            KeystoreFileStatus::Encrypted => write!(f, "Encrypted"),
+            KeystoreFileStatus::Plaintext => write!(f, "Plaintext"),
+        }
+    }
+}
+
 #[derive(Default, Serialize, Deserialize, Debug)]
 pub struct FileBasedKeystore {
     pub(crate) keystore: BaseKeyStore,
     pub(crate) path: Option<PathBuf>,
+    /// The password to encrypt the keystore file, the keystore file is stored in plaintext if `None`
+    #[serde(skip)]
+    pub(crate) encryption_password: Option<String>,
 }
 
 impl AccountKeystore for FileBasedKeystore {
@@ -87,6 +113,48 @@ impl AccountKeystore for FileBasedKeystore {
         Ok(())
     }
 
+    fn set_derivation_path(
+        &mut self,
+        address: RoochAddress,
+        derivation_path: &DerivationPath,
+    ) -> Result<(), anyhow::Error> {
+        self.keystore
+            .set_derivation_path(address, derivation_path)?;
+        self.save()?;
+        Ok(())
+    }
+
+    fn get_derivation_path(&self, address: &RoochAddress) -> Option<DerivationPath> {
+        self.keystore.get_derivation_path(address)
+    }
+
+    fn rotate_address_encryption_data(
+        &mut self,
+        address: &RoochAddress,
+        encryption: EncryptionData,
+    ) -> Result<(), anyhow::Error> {
+        self.keystore
+            .rotate_address_encryption_data(address, encryption)?;
+        self.save()?;
+        Ok(())
+    }
+
+    fn has_rotated_key(&self, address: &RoochAddress) -> bool {
+        self.keystore.has_rotated_key(address)
+    }
+
+    fn confirm_rotated_key(&mut self, address: &RoochAddress) -> Result<(), anyhow::Error> {
+        self.keystore.confirm_rotated_key(address)?;
+        self.save()?;
+        Ok(())
+    }
+
+    fn restore_rotated_key(&mut self, address: &RoochAddress) -> Result<(), anyhow::Error> {
+        self.keystore.restore_rotated_key(address)?;
+        self.save()?;
+        Ok(())
+    }
+
     fn sign_hashed(
         &self,
         address: &RoochAddress,
@@ -146,13 +214,31 @@ impl AccountKeystore for FileBasedKeystore {
     fn binding_session_key(
         &mut self,
         address: RoochAddress,
-        session_key: rooch_types::framework::session_key::SessionKey,
+        session_key: SessionKey,
     ) -> Result<(), anyhow::Error> {
         self.keystore.binding_session_key(address, session_key)?;
         self.save()?;
         Ok(())
     }
 
+    fn get_session_keys(
+        &self,
+        address: &RoochAddress,
+    ) -> Result<Vec<(AuthenticationKey, Option<SessionKey>)>, anyhow::Error> {
+        self.keystore.get_session_keys(address)
+    }
+
+    fn remove_session_key(
+        &mut self,
+        address: &RoochAddress,
+        authentication_key: &AuthenticationKey,
+    ) -> Result<(), anyhow::Error> {
+        self.keystore
+            .remove_session_key(address, authentication_key)?;
+        self.save()?;
+        Ok(())
+    }
+
     fn sign_transaction_via_session_key(
         &self,
         address: &RoochAddress,
@@ -215,63 +301,111 @@ impl AccountKeystore for FileBasedKeystore {
 
 impl FileBasedKeystore {
     pub fn new(path: &PathBuf) -> Result<Self, anyhow::Error> {
-        let keystore = if path.exists() {
-            let reader = BufReader::new(File::open(path).map_err(|e| {
-                anyhow!(
-                    "Can't open FileBasedKeystore from Rooch path {:?}: {}",
-                    path,
-                    e
-                )
-            })?);
-            serde_json::from_reader(reader).map_err(|e| {
-                anyhow!(
-                    "Can't deserialize FileBasedKeystore from Rooch path {:?}: {}",
-                    path,
-                    e
-                )
-            })?
+        if path.exists() {
+            Self::load(path)
         } else {
-            BaseKeyStore::new(BTreeMap::new())
-        };
+            Ok(Self {
+                keystore: BaseKeyStore::new(BTreeMap::new()),
+                path: Some(path.to_path_buf()),
+                encryption_password: std::env::var(ROOCH_KEYSTORE_PASSWORD_ENV).ok(),
+            })
+        }
+    }
 
-        Ok(Self {
-            keystore,
-            path: Some(path.to_path_buf()),
+    /// Load the keystore from the path, the password of the encrypted keystore is read from the
+    /// `ROOCH_KEYSTORE_PASSWORD` environment variable or the prompt.
+    /// The plaintext keystore is loaded as it is, the caller can check `status` and suggest `rooch account lock` to encrypt it.
+    pub fn load(path: &PathBuf) -> Result<Self, anyhow::Error> {
+        Self::load_with_password(path, || {
+            read_keystore_password("Enter the keystore password:")
         })
     }
 
-    pub fn load(path: &PathBuf) -> Result<Self, anyhow::Error> {
-        if path.exists() {
-            let reader = BufReader::new(File::open(path).map_err(|e| {
-                anyhow!(
-                    "Can't open FileBasedKeystore from Rooch path {:?}: {}",
-                    path,
-                    e
-                )
-            })?);
-            let keystore = serde_json::from_reader(reader).map_err(|e| {
-                anyhow!(
-                    "Can't deserialize FileBasedKeystore from Rooch path {:?}: {}",
-                    path,
-                    e
-                )
-            })?;
+    fn load_with_password(
+        path: &PathBuf,
+        password: impl FnOnce() -> Result<String, RoochError>,
+    ) -> Result<Self, anyhow::Error> {
+        if !path.exists() {
+            return Err(anyhow!("Key store path {:?} does not exist", path));
+        }
+        let reader = BufReader::new(File::open(path).map_err(|e| {
+            anyhow!(
+                "Can't open FileBasedKeystore from Rooch path {:?}: {}",
+                path,
+                e
+            )
+        })?);
+        let deserialize_error = |e: serde_json::Error| {
+            anyhow!(
+                "Can't deserialize FileBasedKeystore from Rooch path {:?}: {}",
+                path,
+                e
+            )
+        };
+        let value: serde_json::Value =
+            serde_json::from_reader(reader).map_err(deserialize_error)?;
+
+        // The keystore file is either the encrypted format or the plaintext format
+        if value.get("ciphertext").is_some() {
+            let encrypted: EncryptedKeystore =
+                serde_json::from_value(value).map_err(deserialize_error)?;
+            let password = password()?;
+            let plaintext = encrypted.decrypt(&password)?;
+            let keystore = serde_json::from_slice(&plaintext).map_err(deserialize_error)?;
             Ok(Self {
                 keystore,
                 path: Some(path.to_path_buf()),
+                encryption_password: Some(password),
             })
         } else {
-            Err(anyhow!("Key store path {:?} does not exist", path))
+            let keystore = serde_json::from_value(value).map_err(deserialize_error)?;
+            Ok(Self {
+                keystore,
+                path: Some(path.to_path_buf()),
+                encryption_password: None,
+            })
+        }
+    }
+
+    pub fn status(&self) -> KeystoreFileStatus {
+        if self.is_encrypted() {
+            KeystoreFileStatus::Encrypted
+        } else {
+            KeystoreFileStatus::Plaintext
         }
     }
 
+    pub fn is_encrypted(&self) -> bool {
+        self.encryption_password.is_some()
+    }
+
+    /// Encrypt the keystore file with the password
+    pub fn lock(&mut self, password: String) -> Result<(), anyhow::Error> {
+        self.encryption_password = Some(password);
+        self.save()
+    }
+
+    /// Decrypt the keystore file and store it in plaintext
+    pub fn unlock(&mut self) -> Result<(), anyhow::Error> {
+        self.encryption_password = None;
+        self.save()
+    }
+
     pub fn set_path(&mut self, path: &Path) {
         self.path = Some(path.to_path_buf());
     }
 
     pub fn save(&self) -> Result<(), anyhow::Error> {
         if let Some(path) = &self.path {
-            let store = serde_json::to_string_pretty(&self.keystore)?;
+            let store = match &self.encryption_password {
+                Some(password) => {
+                    let plaintext = serde_json::to_vec(&self.keystore)?;
+                    serde_json::to_string_pretty(&EncryptedKeystore::encrypt(
+                        &plaintext, password,
+                    )?)?
+                }
+                None => serde_json::to_string_pretty(&self.keystore)?,
+            };
             fs::write(path, store)?;
         }
         Ok(())
@@ -296,3 +430,81 @@ impl FileBasedKeystore {
         Ok(key_pairs)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn plaintext_keystore(path: &Path) -> RoochAddress {
+        let mut keystore = FileBasedKeystore {
+            path: Some(path.to_path_buf()),
+            ..Default::default()
+        };
+        let result = keystore
+            .generate_and_add_new_key(None, None, None, None)
+            .unwrap();
+        result.address
+    }
+
+    fn is_encrypted_file(path: &Path) -> bool {
+        fs::read_to_string(path).unwrap().contains("ciphertext")
+    }
+
+    #[test]
+    fn test_lock_plaintext_keystore() {
+        let temp_dir = moveos_config::temp_dir();
+        let path = temp_dir.path().join("rooch.keystore");
+        let address = plaintext_keystore(&path);
+        assert!(!is_encrypted_file(&path));
+
+        // The plaintext keystore is not migrated implicitly on load
+        let mut keystore = FileBasedKeystore::load_with_password(&path, || {
+            panic!("The plaintext keystore has no password")
+        })
+        .unwrap();
+        assert_eq!(keystore.status(), KeystoreFileStatus::Plaintext);
+        assert!(!is_encrypted_file(&path));
+
+        keystore.lock("password".to_owned()).unwrap();
+        assert_eq!(keystore.status(), KeystoreFileStatus::Encrypted);
+        assert!(is_encrypted_file(&path));
+        assert!(!fs::read_to_string(&path)
+            .unwrap()
+            .contains(&address.to_string()));
+
+        let keystore =
+            FileBasedKeystore::load_with_password(&path, || Ok("password".to_owned())).unwrap();
+        assert_eq!(keystore.status(), KeystoreFileStatus::Encrypted);
+        assert_eq!(keystore.addresses(), vec![address]);
+
+        assert!(
+            FileBasedKeystore::load_with_password(&path, || Ok("wrong password".to_owned()))
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_unlock_keystore() {
+        let temp_dir = moveos_config::temp_dir();
+        let path = temp_dir.path().join("rooch.keystore");
+        let address = plaintext_keystore(&path);
+
+        let mut keystore = FileBasedKeystore::load_with_password(&path, || {
+            panic!("The plaintext keystore has no password")
+        })
+        .unwrap();
+        keystore.lock("password".to_owned()).unwrap();
+        assert!(is_encrypted_file(&path));
+
+        let mut keystore =
+            FileBasedKeystore::load_with_password(&path, || Ok("password".to_owned())).unwrap();
+        keystore.unlock().unwrap();
+        assert!(!is_encrypted_file(&path));
+        let keystore = FileBasedKeystore::load_with_password(&path, || {
+            panic!("The unlocked keystore has no password")
+        })
+        .unwrap();
+        assert_eq!(keystore.status(), KeystoreFileStatus::Plaintext);
+        assert_eq!(keystore.addresses(), vec![address]);
+    }
+}
@@ -5,6 +5,8 @@ use super::types::LocalAccount;
 use crate::key_derive::get_key_pair_from_red;
 use crate::keystore::account_keystore::AccountKeystore;
 use crate::keystore::base_keystore::BaseKeyStore;
+use bip32::DerivationPath;
+use rooch_types::framework::session_key::SessionKey;
 use rooch_types::key_struct::{MnemonicData, MnemonicResult};
 use rooch_types::{
     address::RoochAddress,
@@ -75,6 +77,39 @@ impl AccountKeystore for InMemKeystore {
         self.keystore.nullify(address)
     }
 
+    fn set_derivation_path(
+        &mut self,
+        address: RoochAddress,
+        derivation_path: &DerivationPath,
+    ) -> Result<(), anyhow::Error> {
+        self.keystore.set_derivation_path(address, derivation_path)
+    }
+
+    fn get_derivation_path(&self, address: &RoochAddress) -> Option<DerivationPath> {
+        self.keystore.get_derivation_path(address)
+    }
+
+    fn rotate_address_encryption_data(
+        &mut self,
+        address: &RoochAddress,
+        encryption: EncryptionData,
+    ) -> Result<(), anyhow::Error> {
+        self.keystore
+            .rotate_address_encryption_data(address, encryption)
+    }
+
+    fn has_rotated_key(&self, address: &RoochAddress) -> bool {
+        self.keystore.has_rotated_key(address)
+    }
+
+    fn confirm_rotated_key(&mut self, address: &RoochAddress) -> Result<(), anyhow::Error> {
+        self.keystore.confirm_rotated_key(address)
+    }
+
+    fn restore_rotated_key(&mut self, address: &RoochAddress) -> Result<(), anyhow::Error> {
+        self.keystore.restore_rotated_key(address)
+    }
+
     fn sign_hashed(
         &self,
         address: &RoochAddress,
@@ -132,11 +167,27 @@ impl AccountKeystore for InMemKeystore {
     fn binding_session_key(
         &mut self,
         address: RoochAddress,
-        session_key: rooch_types::framework::session_key::SessionKey,
+        session_key: SessionKey,
     ) -> Result<(), anyhow::Error> {
         self.keystore.binding_session_key(address, session_key)
     }
 
+    fn get_session_keys(
+        &self,
+        address: &RoochAddress,
+    ) -> Result<Vec<(AuthenticationKey, Option<SessionKey>)>, anyhow::Error> {
+        self.keystore.get_session_keys(address)
+    }
+
+    fn remove_session_key(
+        &mut self,
+        address: &RoochAddress,
+        authentication_key: &AuthenticationKey,
+    ) -> Result<(), anyhow::Error> {
+        self.keystore
+            .remove_session_key(address, authentication_key)
+    }
+
     fn sign_transaction_via_session_key(
         &self,
         address: &RoochAddress,
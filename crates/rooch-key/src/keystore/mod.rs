@@ -3,8 +3,10 @@
 
 use crate::keystore::account_keystore::AccountKeystore;
 use crate::keystore::file_keystore::FileBasedKeystore;
+use bip32::DerivationPath;
 use enum_dispatch::enum_dispatch;
 use memory_keystore::InMemKeystore;
+use rooch_types::framework::session_key::SessionKey;
 use rooch_types::key_struct::{MnemonicData, MnemonicResult};
 use rooch_types::{
     address::RoochAddress,
@@ -19,6 +21,7 @@ use std::fmt::Write;
 
 pub mod account_keystore;
 pub mod base_keystore;
+pub mod encryption;
 pub mod file_keystore;
 pub mod memory_keystore;
 pub mod types;
@@ -157,6 +160,64 @@ impl AccountKeystore for Keystore {
         }
     }
 
+    fn set_derivation_path(
+        &mut self,
+        address: RoochAddress,
+        derivation_path: &DerivationPath,
+    ) -> Result<(), anyhow::Error> {
+        match self {
+            Keystore::File(file_keystore) => {
+                file_keystore.set_derivation_path(address, derivation_path)
+            }
+            Keystore::InMem(inmem_keystore) => {
+                inmem_keystore.set_derivation_path(address, derivation_path)
+            }
+        }
+    }
+
+    fn get_derivation_path(&self, address: &RoochAddress) -> Option<DerivationPath> {
+        match self {
+            Keystore::File(file_keystore) => file_keystore.get_derivation_path(address),
+            Keystore::InMem(inmem_keystore) => inmem_keystore.get_derivation_path(address),
+        }
+    }
+
+    fn rotate_address_encryption_data(
+        &mut self,
+        address: &RoochAddress,
+        encryption: EncryptionData,
+    ) -> Result<(), anyhow::Error> {
+        match self {
+            Keystore::File(file_keystore) => {
+                file_keystore.rotate_address_encryption_data(address, encryption)
+            }
+            Keystore::InMem(inmem_keystore) => {
+                inmem_keystore.rotate_address_encryption_data(address, encryption)
+            }
+        }
+    }
+
+    fn has_rotated_key(&self, address: &RoochAddress) -> bool {
+        match self {
+            Keystore::File(file_keystore) => file_keystore.has_rotated_key(address),
+            Keystore::InMem(inmem_keystore) => inmem_keystore.has_rotated_key(address),
+        }
+    }
+
+    fn confirm_rotated_key(&mut self, address: &RoochAddress) -> Result<(), anyhow::Error> {
+        match self {
+            Keystore::File(file_keystore) => file_keystore.confirm_rotated_key(address),
+            Keystore::InMem(inmem_keystore) => inmem_keystore.confirm_rotated_key(address),
+        }
+    }
+
+    fn restore_rotated_key(&mut self, address: &RoochAddress) -> Result<(), anyhow::Error> {
+        match self {
+            Keystore::File(file_keystore) => file_keystore.restore_rotated_key(address),
+            Keystore::InMem(inmem_keystore) => inmem_keystore.restore_rotated_key(address),
+        }
+    }
+
     fn sign_hashed(
         &self,
         address: &RoochAddress,
@@ -218,7 +279,7 @@ impl AccountKeystore for Keystore {
     fn binding_session_key(
         &mut self,
         address: RoochAddress,
-        session_key: rooch_types::framework::session_key::SessionKey,
+        session_key: SessionKey,
     ) -> Result<(), anyhow::Error> {
         match self {
             Keystore::File(file_keystore) => {
@@ -230,6 +291,31 @@ impl AccountKeystore for Keystore {
         }
     }
 
+    fn get_session_keys(
+        &self,
+        address: &RoochAddress,
+    ) -> Result<Vec<(AuthenticationKey, Option<SessionKey>)>, anyhow::Error> {
+        match self {
+            Keystore::File(file_keystore) => file_keystore.get_session_keys(address),
+            Keystore::InMem(inmem_keystore) => inmem_keystore.get_session_keys(address),
+        }
+    }
+
+    fn remove_session_key(
+        &mut self,
+        address: &RoochAddress,
+        authentication_key: &AuthenticationKey,
+    ) -> Result<(), anyhow::Error> {
+        match self {
+            Keystore::File(file_keystore) => {
+                file_keystore.remove_session_key(address, authentication_key)
+            }
+            Keystore::InMem(inmem_keystore) => {
+                inmem_keystore.remove_session_key(address, authentication_key)
+            }
+        }
+    }
+
     fn addresses(&self) -> Vec<RoochAddress> {
         match self {
             Keystore::File(file_keystore) => file_keystore.addresses(),
@@ -313,7 +399,8 @@ impl Display for Keystore {
         match self {
             Keystore::File(file) => {
                 writeln!(writer, "Keystore Type : Rooch File")?;
-                write!(writer, "Keystore Path : {:?}", file.path)?;
+                writeln!(writer, "Keystore Path : {:?}", file.path)?;
+                write!(writer, "Keystore Status : {}", file.status())?;
             }
             Keystore::InMem(_) => {
                 writeln!(writer, "Keystore Type : Rooch InMem")?;
@@ -14,6 +14,9 @@ use std::collections::BTreeMap;
 pub struct LocalSessionKey {
     pub session_key: Option<SessionKey>,
     pub private_key: EncryptionData,
+    /// The derivation path if the session key is derived from the mnemonic of the keystore
+    #[serde(default)]
+    pub derivation_path: Option<String>,
 }
 
 #[derive(Clone, Serialize, Deserialize, Debug)]
@@ -22,6 +25,8 @@ pub struct LocalAccount {
     pub multichain_address: Option<MultiChainAddress>,
     pub public_key: Option<PublicKey>,
     pub has_session_key: bool,
+    /// The derivation path if the key is derived from a mnemonic
+    pub derivation_path: Option<String>,
 }
 
 #[derive(Serialize, Deserialize, Default, Debug)]
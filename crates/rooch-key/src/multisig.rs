@@ -0,0 +1,140 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use fastcrypto::encoding::Base64;
+use rooch_types::{
+    address::RoochAddress,
+    crypto::{RoochKeyPair, RoochSignature, Signature},
+    error::RoochError,
+    multisig::{MultisigMemberPublicKey, MultisigPublicKey},
+    transaction::{
+        authenticator::MultisigAuthenticator,
+        rooch::{RoochTransaction, RoochTransactionData},
+    },
+};
+use serde::{Deserialize, Serialize};
+use serde_with::serde_as;
+use std::collections::BTreeMap;
+
+/// The multisig transaction collects the approvals of the multisig members,
+/// it is exchanged between the members and can be combined into a signed transaction once the approvals reach the threshold.
+#[serde_as]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MultisigTransaction {
+    pub public_key: MultisigPublicKey,
+    pub tx_data: RoochTransactionData,
+    /// The raw signatures of the tx hash, keyed by the signer index in the multisig public key
+    #[serde_as(as = "BTreeMap<_, Base64>")]
+    pub approvals: BTreeMap<u8, Vec<u8>>,
+}
+
+impl MultisigTransaction {
+    pub fn new(public_key: MultisigPublicKey, tx_data: RoochTransactionData) -> Self {
+        Self {
+            public_key,
+            tx_data,
+            approvals: BTreeMap::new(),
+        }
+    }
+
+    /// Add the approval of the member at `signer_index`, the signature is verified against the tx hash.
+    pub fn add_approval(&mut self, signer_index: u8, signature: Vec<u8>) -> Result<(), RoochError> {
+        self.public_key
+            .member(signer_index)?
+            .verify(self.tx_data.hash().as_bytes(), &signature)?;
+        self.approvals.insert(signer_index, signature);
+        Ok(())
+    }
+
+    /// Merge the approvals of another copy of the same multisig transaction.
+    pub fn combine(&mut self, other: MultisigTransaction) -> Result<(), RoochError> {
+        if self.public_key != other.public_key || self.tx_data != other.tx_data {
+            return Err(RoochError::CommandArgumentError(
+                "Can not combine different multisig transactions".to_owned(),
+            ));
+        }
+        for (signer_index, signature) in other.approvals {
+            self.add_approval(signer_index, signature)?;
+        }
+        Ok(())
+    }
+
+    pub fn is_ready(&self) -> bool {
+        self.approvals.len() >= self.public_key.threshold() as usize
+    }
+
+    /// Build the signed transaction with the multisig authenticator.
+    pub fn into_transaction(self) -> Result<RoochTransaction, RoochError> {
+        let authenticator = MultisigAuthenticator::new(
+            self.public_key,
+            self.tx_data.hash().as_bytes(),
+            self.approvals,
+        )?;
+        Ok(RoochTransaction::new(self.tx_data, authenticator.into()))
+    }
+}
+
+/// The multisig keypair is the multisig public key with the keypairs of the members held locally,
+/// usually a member only holds its own keypair, and approves the transaction with it.
+pub struct MultisigKeyPair {
+    public_key: MultisigPublicKey,
+    keypairs: BTreeMap<u8, RoochKeyPair>,
+}
+
+impl MultisigKeyPair {
+    pub fn new(
+        public_key: MultisigPublicKey,
+        keypairs: Vec<RoochKeyPair>,
+    ) -> Result<Self, RoochError> {
+        let keypairs = keypairs
+            .into_iter()
+            .map(|kp| {
+                let member = MultisigMemberPublicKey::try_from(&kp.public())?;
+                let signer_index = public_key.index_of(&member).ok_or_else(|| {
+                    RoochError::KeyConversionError(format!(
+                        "The public key {} is not a member of the multisig account",
+                        member
+                    ))
+                })?;
+                Ok((signer_index, kp))
+            })
+            .collect::<Result<BTreeMap<_, _>, RoochError>>()?;
+        Ok(Self {
+            public_key,
+            keypairs,
+        })
+    }
+
+    pub fn public_key(&self) -> &MultisigPublicKey {
+        &self.public_key
+    }
+
+    pub fn address(&self) -> RoochAddress {
+        self.public_key.address()
+    }
+
+    /// Approve the multisig transaction with all the local member keypairs.
+    pub fn approve(&self, tx: &mut MultisigTransaction) -> Result<(), RoochError> {
+        if tx.public_key != self.public_key {
+            return Err(RoochError::CommandArgumentError(
+                "The transaction does not belong to the multisig account".to_owned(),
+            ));
+        }
+        let tx_hash = tx.tx_data.hash();
+        for (signer_index, kp) in self.keypairs.iter() {
+            let signature = Signature::new_hashed(tx_hash.as_bytes(), kp);
+            tx.add_approval(*signer_index, signature.signature_bytes().to_vec())?;
+        }
+        Ok(())
+    }
+
+    /// Sign the transaction directly, the local member keypairs must reach the threshold.
+    pub fn sign_transaction(
+        &self,
+        tx_data: RoochTransactionData,
+    ) -> Result<RoochTransaction, RoochError> {
+        let mut tx = MultisigTransaction::new(self.public_key.clone(), tx_data);
+        self.approve(&mut tx)?;
+        tx.into_transaction()
+    }
+}
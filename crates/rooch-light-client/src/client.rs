@@ -0,0 +1,192 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::header::SequencedHeader;
+use anyhow::{ensure, format_err, Result};
+use moveos_types::access_path::AccessPath;
+use rooch_rpc_api::jsonrpc_types::transaction_view::TransactionFilterView;
+use rooch_rpc_api::jsonrpc_types::{QueryOptions, StateView};
+use rooch_rpc_client::{Client, ClientBuilder};
+use rooch_types::address::RoochAddress;
+use std::collections::BTreeMap;
+
+const SYNC_PAGE_SIZE: u64 = 100;
+/// The number of the latest sequenced headers kept for the reads at a previous tx order.
+pub const DEFAULT_MAX_TRACKED_HEADERS: usize = 1000;
+
+/// Follow the transactions ordered by the trusted sequencer and read the states with proofs.
+/// The tx orders are verified with the sequencer signatures, but the state roots are reported by the node,
+/// so the states are only proved to be consistent with the state root the node claims.
+/// It is not a trust-minimized light client, it trusts the node to execute the sequenced transactions honestly.
+///
+/// The client downloads every transaction after its latest header to check the tx orders are continuous,
+/// so a sync costs the transactions sequenced since the last sync. The first sync starts at the latest
+/// transaction reported by the node, unless a trusted header is given by `with_trusted_header`.
+#[derive(Debug, Clone)]
+pub struct LightClient {
+    client: Client,
+    sequencer: RoochAddress,
+    headers: BTreeMap<u64, SequencedHeader>,
+    max_tracked_headers: usize,
+}
+
+impl LightClient {
+    pub fn new(client: Client, sequencer: RoochAddress) -> Self {
+        Self {
+            client,
+            sequencer,
+            headers: BTreeMap::new(),
+            max_tracked_headers: DEFAULT_MAX_TRACKED_HEADERS,
+        }
+    }
+
+    /// Connect to the RPC server with the default client options
+    pub async fn connect(url: impl AsRef<str>, sequencer: RoochAddress) -> Result<Self> {
+        Ok(Self::new(
+            ClientBuilder::default().build(url).await?,
+            sequencer,
+        ))
+    }
+
+    /// Start syncing after a header from a trusted source instead of the latest transaction
+    pub fn with_trusted_header(mut self, header: SequencedHeader) -> Self {
+        self.headers.clear();
+        self.headers.insert(header.tx_order, header);
+        self
+    }
+
+    pub fn with_max_tracked_headers(mut self, max_tracked_headers: usize) -> Self {
+        self.max_tracked_headers = max_tracked_headers.max(1);
+        self
+    }
+
+    pub fn sequencer(&self) -> RoochAddress {
+        self.sequencer
+    }
+
+    pub fn latest_header(&self) -> Option<&SequencedHeader> {
+        self.headers.values().next_back()
+    }
+
+    /// The sequenced header of the tx order, None if it is not synced or is evicted
+    pub fn header(&self, tx_order: u64) -> Option<&SequencedHeader> {
+        self.headers.get(&tx_order)
+    }
+
+    /// Fetch the transactions after the latest sequenced header, and verify their tx orders are signed by the sequencer
+    /// and continuous, return the latest sequenced header.
+    /// If the client has no header, it starts at the latest transaction instead of downloading the whole history.
+    pub async fn sync(&mut self) -> Result<Option<SequencedHeader>> {
+        if self.latest_header().is_none() {
+            match self.fetch_latest_header().await? {
+                Some(header) => self.track_header(header),
+                None => return Ok(None),
+            }
+        }
+        let query_options = QueryOptions::default().decode(false).show_raw(true);
+        loop {
+            let cursor = self.latest_header().map(|header| header.tx_order);
+            let page = self
+                .client
+                .rooch
+                .get_transactions_by_order(
+                    cursor,
+                    Some(SYNC_PAGE_SIZE),
+                    Some(query_options.clone()),
+                )
+                .await?;
+            let mut expected_tx_order = cursor.map_or(0, |tx_order| tx_order + 1);
+            for tx in page.data.iter() {
+                let header = SequencedHeader::verify_order(tx, self.sequencer)?;
+                ensure!(
+                    header.tx_order == expected_tx_order,
+                    "The tx order {} is not continuous, expect {}",
+                    header.tx_order,
+                    expected_tx_order
+                );
+                self.track_header(header);
+                expected_tx_order += 1;
+            }
+            if !page.has_next_page || page.data.is_empty() {
+                break;
+            }
+        }
+        let latest_header = self.latest_header().copied();
+        if let Some(header) = &latest_header {
+            log::debug!(
+                "Light client synced to tx order {}, state root {:?}",
+                header.tx_order,
+                header.state_root
+            );
+        }
+        Ok(latest_header)
+    }
+
+    /// Read the states at the latest sequenced header, the proofs are verified with the state root reported by the node
+    pub async fn get_states(&self, access_path: AccessPath) -> Result<Vec<Option<StateView>>> {
+        let header = self
+            .latest_header()
+            .ok_or_else(|| format_err!("The light client is not synced"))?;
+        self.get_states_at(access_path, header.tx_order).await
+    }
+
+    /// Read the states after the transaction of the tx order is executed, the header of the tx order must be tracked.
+    /// The proofs are verified with the state root of the header, which is not signed by the sequencer.
+    pub async fn get_states_at(
+        &self,
+        access_path: AccessPath,
+        tx_order: u64,
+    ) -> Result<Vec<Option<StateView>>> {
+        let state_root = self
+            .header(tx_order)
+            .ok_or_else(|| format_err!("The header of tx order {} is not tracked", tx_order))?
+            .state_root;
+        let proofs = self
+            .client
+            .rooch
+            .get_states_with_proof(access_path, Some(state_root))
+            .await?;
+        proofs
+            .into_iter()
+            .map(|proof| {
+                ensure!(
+                    proof.state_root.0 == state_root,
+                    "The state root of the proof {:?} mismatches the state root of the header {:?}",
+                    proof.state_root.0,
+                    state_root
+                );
+                proof.verify(state_root)?;
+                Ok(proof.state)
+            })
+            .collect()
+    }
+
+    /// The header of the latest transaction reported by the node, its tx order is signed by the sequencer,
+    /// but the node may hide the transactions after it, they are found by the next sync.
+    async fn fetch_latest_header(&self) -> Result<Option<SequencedHeader>> {
+        let page = self
+            .client
+            .rooch
+            .query_transactions(
+                TransactionFilterView::TxOrderRange {
+                    from_order: 0,
+                    to_order: u64::MAX,
+                },
+                None,
+                Some(1),
+                Some(true),
+            )
+            .await?;
+        page.data
+            .first()
+            .map(|tx| SequencedHeader::verify_order(tx, self.sequencer))
+            .transpose()
+    }
+
+    fn track_header(&mut self, header: SequencedHeader) {
+        self.headers.insert(header.tx_order, header);
+        while self.headers.len() > self.max_tracked_headers {
+            self.headers.pop_first();
+        }
+    }
+}
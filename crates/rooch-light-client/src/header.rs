@@ -0,0 +1,136 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use anyhow::{ensure, Result};
+use moveos_types::h256::H256;
+use rooch_rpc_api::jsonrpc_types::{TransactionTypeView, TransactionWithInfoView};
+use rooch_types::address::RoochAddress;
+use rooch_types::transaction::ethereum::EthereumTransaction;
+use rooch_types::transaction::rooch::RoochTransaction;
+use rooch_types::transaction::{AbstractTransaction, TransactionSequenceInfo};
+use serde::{Deserialize, Serialize};
+
+/// The header of a transaction sequenced by the sequencer.
+/// Only the tx order and the tx hash are signed by the sequencer, the state root is reported by the node.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SequencedHeader {
+    pub tx_order: u64,
+    pub tx_hash: H256,
+    /// The global state root after the transaction is executed, as reported by the node, it is not signed by the sequencer
+    pub state_root: H256,
+}
+
+impl SequencedHeader {
+    /// Verify the tx order of the transaction is signed by the `sequencer`.
+    /// If the raw transaction is returned, its hash is checked against the hash signed by the sequencer.
+    /// The tx accumulator root and the state root are not checked, they are not covered by the signature.
+    pub fn verify_order(tx: &TransactionWithInfoView, sequencer: RoochAddress) -> Result<Self> {
+        let tx_hash = tx.execution_info.tx_hash.0;
+        if let Some(raw) = &tx.transaction.raw {
+            let raw_tx_hash = match tx.transaction.transaction_type {
+                TransactionTypeView::Rooch => RoochTransaction::decode(&raw.0)?.tx_hash(),
+                TransactionTypeView::Ethereum => EthereumTransaction::decode(&raw.0)?.tx_hash(),
+            };
+            ensure!(
+                raw_tx_hash == tx_hash,
+                "The hash of the raw transaction {:?} mismatches the tx hash {:?}",
+                raw_tx_hash,
+                tx_hash
+            );
+        }
+        let sequence_info = TransactionSequenceInfo::from(tx.sequence_info.clone());
+        sequence_info.verify_order_signature(tx_hash, sequencer)?;
+        Ok(Self {
+            tx_order: sequence_info.tx_order,
+            tx_hash,
+            state_root: tx.execution_info.state_root.0,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fastcrypto::ed25519::Ed25519KeyPair;
+    use fastcrypto::traits::KeyPair;
+    use move_core_types::account_address::AccountAddress;
+    use move_core_types::identifier::Identifier;
+    use move_core_types::language_storage::ModuleId;
+    use move_core_types::vm_status::KeptVMStatus;
+    use moveos_types::move_types::FunctionId;
+    use moveos_types::transaction::{MoveAction, TransactionExecutionInfo};
+    use rooch_types::address::RoochSupportedAddress;
+    use rooch_types::crypto::{RoochKeyPair, Signature};
+    use rooch_types::transaction::rooch::RoochTransactionData;
+    use rooch_types::transaction::{TransactionWithInfo, TypedTransaction};
+
+    fn test_keypair() -> RoochKeyPair {
+        RoochKeyPair::Ed25519(Ed25519KeyPair::generate(&mut rand::thread_rng()))
+    }
+
+    fn test_tx(sequence_number: u64) -> RoochTransaction {
+        let kp = test_keypair();
+        let action = MoveAction::new_function_call(
+            FunctionId::new(
+                ModuleId::new(AccountAddress::ONE, Identifier::new("test").unwrap()),
+                Identifier::new("test").unwrap(),
+            ),
+            vec![],
+            vec![],
+        );
+        RoochTransactionData::new_for_test((&kp.public()).into(), sequence_number, action).sign(&kp)
+    }
+
+    fn sequenced_tx(kp: &RoochKeyPair, tx_order: u64) -> TransactionWithInfoView {
+        let tx = test_tx(tx_order);
+        let tx_hash = tx.tx_hash();
+        let witness_hash = TransactionSequenceInfo::witness_hash(tx_hash, tx_order);
+        TransactionWithInfo {
+            transaction: TypedTransaction::Rooch(tx),
+            sequence_info: TransactionSequenceInfo::new(
+                tx_order,
+                Signature::new_hashed(&witness_hash.0, kp).into(),
+                H256::random(),
+            ),
+            execution_info: TransactionExecutionInfo::new(
+                tx_hash,
+                H256::random(),
+                H256::random(),
+                0,
+                KeptVMStatus::Executed,
+            ),
+        }
+        .into()
+    }
+
+    #[test]
+    fn test_verify_order() {
+        let kp = test_keypair();
+        let sequencer: RoochAddress = (&kp.public()).into();
+        let tx = sequenced_tx(&kp, 1);
+
+        let header = SequencedHeader::verify_order(&tx, sequencer).unwrap();
+        assert_eq!(header.tx_order, 1);
+        assert_eq!(header.tx_hash, tx.execution_info.tx_hash.0);
+        assert_eq!(header.state_root, tx.execution_info.state_root.0);
+
+        // Signed by the other sequencer
+        assert!(SequencedHeader::verify_order(&tx, RoochAddress::random()).is_err());
+
+        // The tx order is moved by the node
+        let mut reordered = tx.clone();
+        reordered.sequence_info.tx_order = 2.into();
+        assert!(SequencedHeader::verify_order(&reordered, sequencer).is_err());
+
+        // The raw transaction is replaced by the node
+        let mut replaced = tx.clone();
+        replaced.transaction.raw = Some(test_tx(2).encode().into());
+        assert!(SequencedHeader::verify_order(&replaced, sequencer).is_err());
+
+        // Without the raw transaction, the tx hash is still signed
+        let mut without_raw = tx;
+        without_raw.transaction.raw = None;
+        without_raw.execution_info.tx_hash = H256::random().into();
+        assert!(SequencedHeader::verify_order(&without_raw, sequencer).is_err());
+    }
+}
@@ -0,0 +1,28 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+//! The light client of Rooch, follows the transactions ordered by a trusted sequencer and reads the states
+//! with proofs against the state roots reported by the RPC node.
+//!
+//! ```ignore
+//! let mut light_client = LightClient::connect("http://127.0.0.1:50051", sequencer).await?;
+//! let header = light_client.sync().await?;
+//! let states = light_client
+//!     .get_states(AccessPath::resource(account, GasCoin::struct_tag()))
+//!     .await?;
+//! ```
+//!
+//! The sequencer signs the tx order of the transaction hash only, the state root after the transaction
+//! is reported by the node and is not covered by the signature, neither is the tx accumulator root.
+//! So the light client detects the reordered or replaced transactions, but it trusts the node to execute
+//! the sequenced transactions honestly, a dishonest node can prove the states of a forged state root.
+//! There are no signed headers or accumulator proofs to verify the state roots yet.
+//!
+//! The client checks the tx orders by downloading the transactions, so the cost of a sync grows with
+//! the transactions sequenced since the last sync, the first sync starts at the latest transaction.
+
+pub mod client;
+pub mod header;
+
+pub use client::LightClient;
+pub use header::SequencedHeader;
@@ -10,6 +10,7 @@ use clap::Parser;
 use rooch_open_rpc::Project;
 use rooch_rpc_api::api::btc_api::BtcAPIOpenRpc;
 use rooch_rpc_api::api::rooch_api::RoochAPIOpenRpc;
+use rooch_rpc_api::api::subscription_api::SubscriptionAPIOpenRpc;
 use std::fs::File;
 use std::io::Write;
 use std::path::Path;
@@ -36,12 +37,16 @@ enum Action {
     Test,
     Record,
 }
+/// The OpenRPC service discovery method, it returns the OpenRPC document of the server
+pub const RPC_DISCOVER_METHOD: &str = "rpc.discover";
+
 // TODO: This currently always use workspace version, which is not ideal.
 const VERSION: &str = env!("CARGO_PKG_VERSION");
 
 pub fn build_rooch_rpc_spec() -> Project {
     let mut open_rpc = rooch_rpc_doc(VERSION);
     open_rpc.add_module(RoochAPIOpenRpc::module_doc());
+    open_rpc.add_module(SubscriptionAPIOpenRpc::module_doc());
     //FIXME if add the EthAPIOpenRpc, the pnpm sdk gen raies error
     open_rpc.add_module(BtcAPIOpenRpc::module_doc());
     //open_rpc.add_examples(RpcExampleProvider::new().examples());
@@ -1,6 +1,8 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
 
+use crate::fee::GasUsageStats;
+use crate::scc::PendingTransaction;
 use anyhow::Result;
 use coerce::actor::{message::Message, scheduler::timer::TimerTick};
 use moveos_types::transaction::TransactionExecutionInfo;
@@ -37,3 +39,23 @@ impl Message for ProposeBlock {
 }
 
 impl TimerTick for ProposeBlock {}
+
+/// Get the gas usage stats of the recent proposed transactions
+#[derive(Debug)]
+pub struct GetGasUsageStatsMessage {}
+
+impl Message for GetGasUsageStatsMessage {
+    type Result = Result<GasUsageStats>;
+}
+
+/// Get the pending transactions which are not proposed in a block yet
+#[derive(Debug)]
+pub struct GetPendingTransactionsMessage {
+    /// The tx order of the last transaction of the previous page
+    pub cursor: Option<u64>,
+    pub limit: usize,
+}
+
+impl Message for GetPendingTransactionsMessage {
+    type Result = Result<Vec<PendingTransaction>>;
+}
@@ -8,13 +8,18 @@ use coerce::actor::{context::ActorContext, message::Handler, Actor};
 use rooch_da::proxy::DAProxy;
 use rooch_types::crypto::RoochKeyPair;
 
-use crate::scc::StateCommitmentChain;
+use crate::fee::{FeeEstimator, GasUsageStats};
+use crate::scc::{PendingTransaction, StateCommitmentChain};
 
-use super::messages::{ProposeBlock, TransactionProposeMessage, TransactionProposeResult};
+use super::messages::{
+    GetGasUsageStatsMessage, GetPendingTransactionsMessage, ProposeBlock,
+    TransactionProposeMessage, TransactionProposeResult,
+};
 
 pub struct ProposerActor {
     proposer_key: RoochKeyPair,
     scc: StateCommitmentChain,
+    fee_estimator: FeeEstimator,
 }
 
 impl ProposerActor {
@@ -22,6 +27,7 @@ impl ProposerActor {
         Self {
             proposer_key,
             scc: StateCommitmentChain::new(da_proxy),
+            fee_estimator: FeeEstimator::default(),
         }
     }
 }
@@ -35,11 +41,34 @@ impl Handler<TransactionProposeMessage> for ProposerActor {
         msg: TransactionProposeMessage,
         _ctx: &mut ActorContext,
     ) -> Result<TransactionProposeResult> {
+        self.fee_estimator.record(msg.tx_execution_info.gas_used);
         self.scc.append_transaction(msg);
         Ok(TransactionProposeResult {})
     }
 }
 
+#[async_trait]
+impl Handler<GetGasUsageStatsMessage> for ProposerActor {
+    async fn handle(
+        &mut self,
+        _msg: GetGasUsageStatsMessage,
+        _ctx: &mut ActorContext,
+    ) -> Result<GasUsageStats> {
+        Ok(self.fee_estimator.stats())
+    }
+}
+
+#[async_trait]
+impl Handler<GetPendingTransactionsMessage> for ProposerActor {
+    async fn handle(
+        &mut self,
+        msg: GetPendingTransactionsMessage,
+        _ctx: &mut ActorContext,
+    ) -> Result<Vec<PendingTransaction>> {
+        Ok(self.scc.pending_transactions(msg.cursor, msg.limit))
+    }
+}
+
 #[async_trait]
 impl Handler<ProposeBlock> for ProposerActor {
     async fn handle(&mut self, _message: ProposeBlock, _ctx: &mut ActorContext) {
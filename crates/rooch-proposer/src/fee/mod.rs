@@ -0,0 +1,86 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use moveos_types::gas_config::GasConfig;
+use std::collections::VecDeque;
+
+/// The default number of the recent transactions tracked by the fee estimator
+pub const DEFAULT_FEE_ESTIMATOR_WINDOW_SIZE: usize = 1000;
+
+/// The extra gas percentage added to the estimated gas, the gas used may change between the estimation and the execution
+pub const GAS_ESTIMATE_MARGIN_PERCENT: u64 = 20;
+
+/// The gas usage percentiles of the recent executed transactions
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct GasUsageStats {
+    /// The number of the transactions in the window
+    pub sample_count: u64,
+    pub min: u64,
+    pub p50: u64,
+    pub p75: u64,
+    pub p90: u64,
+    pub p99: u64,
+    pub max: u64,
+}
+
+/// FeeEstimator tracks the gas used by the recent executed transactions in a sliding window,
+/// and provides the gas usage percentiles for estimating the `max_gas_amount` of new transactions.
+pub struct FeeEstimator {
+    window_size: usize,
+    gas_used: VecDeque<u64>,
+}
+
+impl FeeEstimator {
+    pub fn new(window_size: usize) -> Self {
+        Self {
+            window_size,
+            gas_used: VecDeque::with_capacity(window_size),
+        }
+    }
+
+    /// Record the gas used by an executed transaction, the oldest record is evicted if the window is full
+    pub fn record(&mut self, gas_used: u64) {
+        if self.window_size == 0 {
+            return;
+        }
+        if self.gas_used.len() == self.window_size {
+            self.gas_used.pop_front();
+        }
+        self.gas_used.push_back(gas_used);
+    }
+
+    pub fn stats(&self) -> GasUsageStats {
+        if self.gas_used.is_empty() {
+            return GasUsageStats::default();
+        }
+        let mut sorted: Vec<u64> = self.gas_used.iter().copied().collect();
+        sorted.sort_unstable();
+        GasUsageStats {
+            sample_count: sorted.len() as u64,
+            min: sorted[0],
+            p50: percentile(&sorted, 50),
+            p75: percentile(&sorted, 75),
+            p90: percentile(&sorted, 90),
+            p99: percentile(&sorted, 99),
+            max: sorted[sorted.len() - 1],
+        }
+    }
+}
+
+impl Default for FeeEstimator {
+    fn default() -> Self {
+        Self::new(DEFAULT_FEE_ESTIMATOR_WINDOW_SIZE)
+    }
+}
+
+/// Add the safety margin to the estimated gas, the result is capped by the default max gas amount
+pub fn max_gas_amount_with_margin(gas_used: u64) -> u64 {
+    let gas = gas_used as u128 * (100 + GAS_ESTIMATE_MARGIN_PERCENT) as u128 / 100;
+    gas.min(GasConfig::DEFAULT_MAX_GAS_AMOUNT as u128) as u64
+}
+
+/// Nearest-rank percentile of the sorted values
+fn percentile(sorted: &[u64], percent: usize) -> u64 {
+    let rank = (sorted.len() * percent + 99) / 100;
+    sorted[rank.saturating_sub(1)]
+}
@@ -8,9 +8,14 @@ use moveos_types::transaction::TransactionExecutionInfo;
 use rooch_types::transaction::{TransactionSequenceInfo, TypedTransaction};
 
 use crate::actor::{
-    messages::{TransactionProposeMessage, TransactionProposeResult},
+    messages::{
+        GetGasUsageStatsMessage, GetPendingTransactionsMessage, TransactionProposeMessage,
+        TransactionProposeResult,
+    },
     proposer::ProposerActor,
 };
+use crate::fee::GasUsageStats;
+use crate::scc::PendingTransaction;
 
 #[derive(Clone)]
 pub struct ProposerProxy {
@@ -36,4 +41,18 @@ impl ProposerProxy {
             })
             .await?
     }
+
+    pub async fn get_gas_usage_stats(&self) -> Result<GasUsageStats> {
+        self.actor.send(GetGasUsageStatsMessage {}).await?
+    }
+
+    pub async fn get_pending_transactions(
+        &self,
+        cursor: Option<u64>,
+        limit: usize,
+    ) -> Result<Vec<PendingTransaction>> {
+        self.actor
+            .send(GetPendingTransactionsMessage { cursor, limit })
+            .await?
+    }
 }
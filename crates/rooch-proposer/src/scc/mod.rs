@@ -2,22 +2,34 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use std::collections::BTreeMap;
+use std::time::{SystemTime, UNIX_EPOCH};
 
 use moveos_types::h256;
 use moveos_types::h256::H256;
+use moveos_types::transaction::TransactionExecutionInfo;
 use rooch_da::messages::{Batch, BatchMeta};
 use rooch_da::proxy::DAProxy;
 use rooch_types::block::Block;
-use rooch_types::transaction::AbstractTransaction;
+use rooch_types::transaction::{AbstractTransaction, TransactionSequenceInfo, TypedTransaction};
 
 use crate::actor::messages::TransactionProposeMessage;
 
+/// A transaction waiting in the SCC buffer to be proposed in the next block
+#[derive(Debug, Clone)]
+pub struct PendingTransaction {
+    pub tx: TypedTransaction,
+    pub tx_execution_info: TransactionExecutionInfo,
+    pub tx_sequence_info: TransactionSequenceInfo,
+    /// The milliseconds when the transaction is appended to the buffer
+    pub inserted_at: u64,
+}
+
 /// State Commitment Chain(SCC) is a chain of transaction state root
 /// This SCC is a mirror of the on-chain SCC
 pub struct StateCommitmentChain {
     //TODO save to the storage
     blocks: BTreeMap<u128, Block>,
-    buffer: Vec<TransactionProposeMessage>,
+    buffer: Vec<PendingTransaction>,
     da: DAProxy,
 }
 
@@ -32,7 +44,31 @@ impl StateCommitmentChain {
     }
 
     pub fn append_transaction(&mut self, tx: TransactionProposeMessage) {
-        self.buffer.push(tx);
+        let inserted_at = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_millis() as u64)
+            .unwrap_or_default();
+        self.buffer.push(PendingTransaction {
+            tx: tx.tx,
+            tx_execution_info: tx.tx_execution_info,
+            tx_sequence_info: tx.tx_sequence_info,
+            inserted_at,
+        });
+    }
+
+    /// Get the pending transactions in the buffer ordered by tx order,
+    /// start from the next transaction after the `cursor` tx order if it is `Some`.
+    pub fn pending_transactions(
+        &self,
+        cursor: Option<u64>,
+        limit: usize,
+    ) -> Vec<PendingTransaction> {
+        self.buffer
+            .iter()
+            .filter(|tx| cursor.map_or(true, |cursor| tx.tx_sequence_info.tx_order > cursor))
+            .take(limit)
+            .cloned()
+            .collect()
     }
 
     /// Append a new block to the SCC
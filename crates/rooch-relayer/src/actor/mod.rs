@@ -5,3 +5,4 @@ pub mod bitcoin_relayer;
 pub mod ethereum_relayer;
 pub mod messages;
 pub mod relayer;
+pub mod scheduler_relayer;
@@ -4,6 +4,7 @@
 use super::bitcoin_relayer::BitcoinRelayer;
 use super::ethereum_relayer::EthereumRelayer;
 use super::messages::RelayTick;
+use super::scheduler_relayer::SchedulerRelayer;
 use crate::{Relayer, TxSubmiter};
 use anyhow::Result;
 use async_trait::async_trait;
@@ -36,6 +37,7 @@ impl RelayerActor {
         relayer_key: RoochKeyPair,
         ethereum_config: Option<EthereumRelayerConfig>,
         bitcoin_config: Option<BitcoinRelayerConfig>,
+        scheduler_keeper: bool,
         rooch_rpc_url: &str,
     ) -> Result<Self> {
         let rooch_rpc_client = ClientBuilder::default().build(rooch_rpc_url).await?;
@@ -44,6 +46,7 @@ impl RelayerActor {
             relayer_key,
             ethereum_config,
             bitcoin_config,
+            scheduler_keeper,
             rooch_rpc_client,
         )
         .await
@@ -54,6 +57,7 @@ impl RelayerActor {
         relayer_key: RoochKeyPair,
         ethereum_config: Option<EthereumRelayerConfig>,
         bitcoin_config: Option<BitcoinRelayerConfig>,
+        scheduler_keeper: bool,
         tx_submiter: T,
     ) -> Result<Self> {
         let chain_id = tx_submiter.get_chain_id().await?;
@@ -65,10 +69,15 @@ impl RelayerActor {
         }
 
         if let Some(bitcoin_config) = bitcoin_config {
-            let bitcoin_relayer = BitcoinRelayer::new(bitcoin_config, executor)?;
+            let bitcoin_relayer = BitcoinRelayer::new(bitcoin_config, executor.clone())?;
             relayers.push(Box::new(bitcoin_relayer));
         }
 
+        if scheduler_keeper {
+            let scheduler_relayer = SchedulerRelayer::new(executor);
+            relayers.push(Box::new(scheduler_relayer));
+        }
+
         Ok(Self {
             chain_id,
             relayer_address,
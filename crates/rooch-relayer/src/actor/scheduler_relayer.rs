@@ -0,0 +1,64 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::Relayer;
+use anyhow::Result;
+use async_trait::async_trait;
+use moveos_types::{module_binding::MoveFunctionCaller, transaction::FunctionCall};
+use rooch_executor::proxy::ExecutorProxy;
+use rooch_types::framework::scheduler::SchedulerModule;
+use std::collections::HashMap;
+use tracing::info;
+
+/// The keeper of the on-chain scheduler, it triggers the callbacks of the due tasks,
+/// and the relayer account receives the fee of the runs from the prefunded gas of the tasks.
+pub struct SchedulerRelayer {
+    //TODO if we want make the relayer to an independent process, we need to replace the executor proxy with a rooch rpc client
+    move_caller: ExecutorProxy,
+    /// task_id -> (next_run_seconds, submit timestamp) of the submitted callbacks,
+    /// to avoid resubmitting a failed callback on every tick
+    submitted: HashMap<u64, (u64, u64)>,
+    batch_size: u64,
+    retry_interval: u64,
+}
+
+impl SchedulerRelayer {
+    pub fn new(executor: ExecutorProxy) -> Self {
+        Self {
+            move_caller: executor,
+            submitted: HashMap::new(),
+            batch_size: 100u64,
+            retry_interval: 60u64,
+        }
+    }
+}
+
+#[async_trait]
+impl Relayer for SchedulerRelayer {
+    async fn relay(&mut self) -> Result<Option<FunctionCall>> {
+        let scheduler = self.move_caller.as_module_binding::<SchedulerModule>();
+        let due_tasks = scheduler.due_tasks(self.batch_size)?;
+        let now = chrono::Utc::now().timestamp() as u64;
+        self.submitted.retain(|task_id, (next_run_seconds, _)| {
+            due_tasks
+                .iter()
+                .any(|task| task.task_id == *task_id && task.next_run_seconds == *next_run_seconds)
+        });
+        for task in due_tasks {
+            if let Some((_, submitted_at)) = self.submitted.get(&task.task_id) {
+                if submitted_at + self.retry_interval > now {
+                    continue;
+                }
+            }
+            info!(
+                "SchedulerRelayer run task: {}, callback: {}::{}::{}",
+                task.task_id, task.callback_address, task.callback_module, task.function_name
+            );
+            let call = task.callback_call()?;
+            self.submitted
+                .insert(task.task_id, (task.next_run_seconds, now));
+            return Ok(Some(call));
+        }
+        Ok(None)
+    }
+}
@@ -0,0 +1,20 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use jsonrpsee::core::RpcResult;
+use jsonrpsee::proc_macros::rpc;
+use rooch_open_rpc_macros::open_rpc;
+
+/// The admin API manages the running server, the `admin_*` methods are only allowed from the localhost.
+#[open_rpc(namespace = "admin")]
+#[rpc(server, client, namespace = "admin")]
+#[async_trait]
+pub trait AdminAPI {
+    /// Get the log filter of the server, such as `info,rooch::indexer=debug`
+    #[method(name = "getLogFilter")]
+    async fn get_log_filter(&self) -> RpcResult<String>;
+
+    /// Reload the log filter of the server without restarting, the filter is in the `RUST_LOG` syntax
+    #[method(name = "setLogFilter")]
+    async fn set_log_filter(&self, filter: String) -> RpcResult<()>;
+}
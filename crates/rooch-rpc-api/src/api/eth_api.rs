@@ -3,9 +3,12 @@
 
 use crate::jsonrpc_types::{
     eth::{
-        ethereum_types::block::{Block, BlockNumber},
+        ethereum_types::{
+            block::{Block, BlockNumber},
+            log::Log,
+        },
         transaction::{Transaction, TransactionReceipt, TransactionRequest},
-        CallRequest, EthFeeHistory,
+        CallRequest, EthFeeHistory, LogFilter,
     },
     BytesView, H160View, H256View, StrView,
 };
@@ -117,6 +120,19 @@ pub trait EthAPI {
         include_txs: bool,
     ) -> RpcResult<Block<TransactionType>>;
 
+    /// Executes a view function without creating a transaction, the `data` is the BCS bytes of the function call `MoveAction`,
+    /// returns the BCS bytes of the function return values.
+    #[method(name = "call")]
+    async fn call(
+        &self,
+        request: CallRequest,
+        num: Option<StrView<BlockNumber>>,
+    ) -> RpcResult<BytesView>;
+
+    /// Returns the logs matching the filter, the logs are the events emitted by the transactions.
+    #[method(name = "getLogs")]
+    async fn get_logs(&self, filter: LogFilter) -> RpcResult<Vec<Log>>;
+
     /// Returns the code at the given contract address.
     #[method(name = "getCode")]
     async fn get_code(
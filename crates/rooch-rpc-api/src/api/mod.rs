@@ -3,9 +3,11 @@
 
 use jsonrpsee::RpcModule;
 
+pub mod admin_api;
 pub mod btc_api;
 pub mod eth_api;
 pub mod rooch_api;
+pub mod subscription_api;
 
 pub const DEFAULT_RESULT_LIMIT: u64 = 50;
 pub const DEFAULT_RESULT_LIMIT_USIZE: usize = DEFAULT_RESULT_LIMIT as usize;
@@ -13,6 +15,8 @@ pub const DEFAULT_RESULT_LIMIT_USIZE: usize = DEFAULT_RESULT_LIMIT as usize;
 pub const MAX_RESULT_LIMIT: u64 = 200;
 pub const MAX_RESULT_LIMIT_USIZE: usize = MAX_RESULT_LIMIT as usize;
 
+pub const DEFAULT_MAX_TX_BATCH_SIZE: usize = 100;
+
 // pub fn validate_limit(limit: Option<usize>, max: usize) -> Result<usize, anyhow::Error> {
 //     match limit {
 //         Some(l) if l > max => Err(anyhow!("Page size limit {l} exceeds max limit {max}")),
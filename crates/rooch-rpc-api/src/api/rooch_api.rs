@@ -1,19 +1,28 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::jsonrpc_types::account_view::BalanceInfoView;
+use crate::jsonrpc_types::account_view::{
+    AccountSummaryView, AddressMappingView, BalanceInfoView, StorageUsageView,
+};
 use crate::jsonrpc_types::event_view::EventFilterView;
-use crate::jsonrpc_types::transaction_view::{TransactionFilterView, TransactionWithInfoView};
+use crate::jsonrpc_types::module_view::ModuleFilterView;
+use crate::jsonrpc_types::transaction_view::{
+    PendingTransactionPageView, TransactionFilterView, TransactionWithInfoView,
+};
 use crate::jsonrpc_types::{
     AccessPathView, AccountAddressView, AnnotatedFunctionResultView, BalanceInfoPageView,
-    BytesView, EventOptions, EventPageView, ExecuteTransactionResponseView, FunctionCallView,
-    GlobalStateFilterView, H256View, IndexerEventPageView, IndexerGlobalStatePageView,
-    IndexerTableChangeSetPageView, IndexerTableStatePageView, StateOptions, StatePageView,
-    StateSyncFilterView, StateView, StrView, StructTagView, TableStateFilterView,
-    TransactionWithInfoPageView,
+    BytesView, CoinInfoView, DryRunTransactionResponseView, EventOptions, EventPageView,
+    ExecuteTransactionResponseView, FunctionCallView, GasEstimateView, GasPriceEstimateView,
+    GlobalStateFilterView, H256View, IndexerCoinTransferPageView, IndexerEventPageView,
+    IndexerGlobalStatePageView, IndexerModulePageView, IndexerTableChangeSetPageView,
+    IndexerTableStatePageView, ModuleIdView, MoveModuleABIView, ObjectOwnershipChangePageView,
+    QueryOptions, ScheduledTaskPageView, SendTransactionResultView, SessionKeyPageView,
+    StateOptions, StatePageView, StateProofView, StateSyncFilterView, StateView, StrView,
+    StructTagView, TableStateFilterView, TransactionWithInfoPageView,
 };
 use jsonrpsee::core::RpcResult;
 use jsonrpsee::proc_macros::rpc;
+use moveos_types::moveos_std::object_id::ObjectID;
 use rooch_open_rpc_macros::open_rpc;
 use rooch_types::indexer::event_filter::IndexerEventID;
 use rooch_types::indexer::state::IndexerStateID;
@@ -25,11 +34,24 @@ pub trait RoochAPI {
     #[method(name = "getChainID")]
     async fn get_chain_id(&self) -> RpcResult<StrView<u64>>;
 
+    /// Get the sequence number of the next transaction of the account, 0 if the account does not exist
+    #[method(name = "getSequenceNumber")]
+    async fn get_sequence_number(&self, address: AccountAddressView) -> RpcResult<StrView<u64>>;
+
     /// Send the signed transaction in bcs hex format
     /// This method does not block waiting for the transaction to be executed.
     #[method(name = "sendRawTransaction")]
     async fn send_raw_transaction(&self, tx_bcs_hex: BytesView) -> RpcResult<H256View>;
 
+    /// Send a batch of signed transactions in bcs hex format
+    /// The transactions are submitted in order and independently, and the result of each transaction is returned.
+    /// The batch size is limited by the server's `max_tx_batch_size` option.
+    #[method(name = "sendRawTransactionBatch")]
+    async fn send_raw_transaction_batch(
+        &self,
+        tx_bcs_hex_batch: Vec<BytesView>,
+    ) -> RpcResult<Vec<SendTransactionResultView>>;
+
     /// Send the signed transaction in bcs hex format
     /// This method blocks waiting for the transaction to be executed.
     #[method(name = "executeRawTransaction")]
@@ -38,6 +60,35 @@ pub trait RoochAPI {
         tx_bcs_hex: BytesView,
     ) -> RpcResult<ExecuteTransactionResponseView>;
 
+    /// Dry run the signed or unsigned transaction in bcs hex format against the latest state
+    /// The transaction is executed but the state changes are not committed.
+    /// The unsigned transaction is the bcs of `RoochTransactionData`, the authenticator is not validated.
+    /// If `gas_profile` is true, the gas is broken down by instruction, native function and storage operation.
+    #[method(name = "dryRunTransaction")]
+    async fn dry_run_transaction(
+        &self,
+        tx_bcs_hex: BytesView,
+        gas_profile: Option<bool>,
+    ) -> RpcResult<DryRunTransactionResponseView>;
+
+    /// Estimate the gas price and the gas usage based on the recent executed transactions
+    #[method(name = "estimateGasPrice")]
+    async fn estimate_gas_price(&self) -> RpcResult<GasPriceEstimateView>;
+
+    /// Estimate the gas of the signed or unsigned transaction in bcs hex format by dry running it,
+    /// the returned `max_gas_amount` includes a safety margin.
+    #[method(name = "estimateGas")]
+    async fn estimate_gas(&self, tx_bcs_hex: BytesView) -> RpcResult<GasEstimateView>;
+
+    /// Get the pending transactions which are executed but not proposed in a block yet, ordered by tx order
+    /// The cursor is the tx order of the last transaction of the previous page.
+    #[method(name = "getPendingTransactions")]
+    async fn get_pending_transactions(
+        &self,
+        cursor: Option<StrView<u64>>,
+        limit: Option<StrView<usize>>,
+    ) -> RpcResult<PendingTransactionPageView>;
+
     /// Execute a read-only function call
     /// The function do not change the state of Application
     #[method(name = "executeViewFunction")]
@@ -46,8 +97,24 @@ pub trait RoochAPI {
         function_call: FunctionCallView,
     ) -> RpcResult<AnnotatedFunctionResultView>;
 
+    /// Execute a read-only function call signed by the caller in bcs hex format, the signed call is a `SignedViewFunctionCall`.
+    /// The function is executed with the verified caller as the sender, and the call does not change the state of Application
+    #[method(name = "executeSignedViewFunction")]
+    async fn execute_signed_view_function(
+        &self,
+        signed_call_bcs_hex: BytesView,
+    ) -> RpcResult<AnnotatedFunctionResultView>;
+
+    /// Get the ABI of the published module, the exposed functions with the parameter types and the structs with the fields,
+    /// return None if the module does not exist
+    #[method(name = "getModuleABI")]
+    async fn get_module_abi(&self, module_id: ModuleIdView)
+        -> RpcResult<Option<MoveModuleABIView>>;
+
     /// Get the states by access_path
     /// If the StateOptions.decode is true, the state is decoded and the decoded value is returned in the response.
+    /// If the StateOptions.state_root or StateOptions.tx_order is set, the historical states are returned,
+    /// the query fails if the states have been pruned.
     #[method(name = "getStates")]
     async fn get_states(
         &self,
@@ -55,8 +122,20 @@ pub trait RoochAPI {
         state_option: Option<StateOptions>,
     ) -> RpcResult<Vec<Option<StateView>>>;
 
+    /// Get the states by access_path with the Merkle proofs against the global state root
+    /// If the state_root is not provided, use the latest state root.
+    /// The proofs can be verified by `StateProofView::verify` with a trusted state root.
+    #[method(name = "getStateWithProof")]
+    async fn get_state_with_proof(
+        &self,
+        access_path: AccessPathView,
+        state_root: Option<H256View>,
+    ) -> RpcResult<Vec<StateProofView>>;
+
     /// List the states by access_path
     /// If the StateOptions.decode is true, the state is decoded and the decoded value is returned in the response.
+    /// If the StateOptions.state_root or StateOptions.tx_order is set, the historical states are returned,
+    /// the query fails if the states have been pruned.
     #[method(name = "listStates")]
     async fn list_states(
         &self,
@@ -67,6 +146,9 @@ pub trait RoochAPI {
     ) -> RpcResult<StatePageView>;
 
     /// Get the events by event handle id
+    /// The events are in ascending order of the event seq by default, `descending_order` returns the latest events first.
+    /// The `from_seq` and `to_seq` are the inclusive bounds of the event seqs, the cursor is the event seq of
+    /// the last event of the previous page, and the page size is bounded by the max result limit.
     #[method(name = "getEventsByEventHandle")]
     async fn get_events_by_event_handle(
         &self,
@@ -74,22 +156,38 @@ pub trait RoochAPI {
         cursor: Option<StrView<u64>>,
         limit: Option<StrView<u64>>,
         event_options: Option<EventOptions>,
+        descending_order: Option<bool>,
+        from_seq: Option<StrView<u64>>,
+        to_seq: Option<StrView<u64>>,
     ) -> RpcResult<EventPageView>;
 
+    /// Get the transactions with the execution info by the tx hashes, in the order of the hashes,
+    /// None is returned for the hash of the transaction not found.
+    /// The QueryOptions selects the returned fields the same as `rooch_getTransactionsByOrder`
     #[method(name = "getTransactionsByHash")]
     async fn get_transactions_by_hash(
         &self,
         tx_hashes: Vec<H256View>,
+        query_options: Option<QueryOptions>,
     ) -> RpcResult<Vec<Option<TransactionWithInfoView>>>;
 
+    /// Get the transactions by tx order
+    /// The QueryOptions selects the returned fields, the decoded actions and the raw bytes are returned by default,
+    /// and the events are returned if the QueryOptions.show_events is true
     #[method(name = "getTransactionsByOrder")]
     async fn get_transactions_by_order(
         &self,
         cursor: Option<StrView<u64>>,
         limit: Option<StrView<u64>>,
+        query_options: Option<QueryOptions>,
     ) -> RpcResult<TransactionWithInfoPageView>;
 
-    /// get account balance by AccountAddress and CoinType
+    /// get the coin info by CoinType, return None if the coin is not registered
+    #[method(name = "getCoinInfo")]
+    async fn get_coin_info(&self, coin_type: StructTagView) -> RpcResult<Option<CoinInfoView>>;
+
+    /// get account balance by AccountAddress and CoinType, the balance is the sum of the account coin store
+    /// and the other CoinStore objects of the coin type owned by the account
     #[method(name = "getBalance")]
     async fn get_balance(
         &self,
@@ -97,7 +195,8 @@ pub trait RoochAPI {
         coin_type: StructTagView,
     ) -> RpcResult<BalanceInfoView>;
 
-    /// get account balances by AccountAddress
+    /// get account balances by AccountAddress, one balance per coin type of the account coin stores,
+    /// paginated by the cursor of the account coin stores table
     #[method(name = "getBalances")]
     async fn get_balances(
         &self,
@@ -106,6 +205,49 @@ pub trait RoochAPI {
         limit: Option<StrView<usize>>,
     ) -> RpcResult<BalanceInfoPageView>;
 
+    /// get the on-chain session keys of the account by AccountAddress, with their scopes and gas budgets
+    #[method(name = "getSessionKeys")]
+    async fn get_session_keys(
+        &self,
+        account_addr: AccountAddressView,
+        cursor: Option<String>,
+        limit: Option<StrView<usize>>,
+    ) -> RpcResult<SessionKeyPageView>;
+
+    /// get the account summary by AccountAddress, include the transaction statistics
+    /// and the number of the owned objects by object type, aggregated by the indexer
+    #[method(name = "getAccountSummary")]
+    async fn get_account_summary(
+        &self,
+        account_addr: AccountAddressView,
+    ) -> RpcResult<AccountSummaryView>;
+
+    /// get the storage usage by AccountAddress, the bytes of the owned objects and the items of their tables
+    /// in the state tree, accounted by the indexer
+    #[method(name = "getStorageUsage")]
+    async fn get_storage_usage(
+        &self,
+        account_addr: AccountAddressView,
+    ) -> RpcResult<StorageUsageView>;
+
+    /// Resolve the multichain address to the Rooch address by the address mappings indexed by the indexer,
+    /// return `None` if the address is not bound. The Rooch address is resolved to itself.
+    /// The Bitcoin address must be valid for the Bitcoin network of the node.
+    #[method(name = "resolveAddress")]
+    async fn resolve_address(
+        &self,
+        multichain_id: u64,
+        address: String,
+    ) -> RpcResult<Option<AccountAddressView>>;
+
+    /// Reverse resolve the Rooch address to all the multichain addresses bound to it,
+    /// ordered by the tx order of the binding
+    #[method(name = "reverseResolveAddress")]
+    async fn reverse_resolve_address(
+        &self,
+        rooch_address: AccountAddressView,
+    ) -> RpcResult<Vec<AddressMappingView>>;
+
     /// Query the transactions indexer by transaction filter
     #[method(name = "queryTransactions")]
     async fn query_transactions(
@@ -128,17 +270,70 @@ pub trait RoochAPI {
         descending_order: Option<bool>,
     ) -> RpcResult<IndexerEventPageView>;
 
+    /// Full-text search the transactions by the transaction action, e.g. the function id of the function call
+    /// All the whitespace separated terms of the query must be matched.
+    #[method(name = "searchTransactions")]
+    async fn search_transactions(
+        &self,
+        query: String,
+        // exclusive cursor if `Some`, otherwise start from the beginning
+        cursor: Option<StrView<u64>>,
+        limit: Option<StrView<usize>>,
+        descending_order: Option<bool>,
+    ) -> RpcResult<TransactionWithInfoPageView>;
+
+    /// Full-text search the events by the event type and the decoded event data
+    /// All the whitespace separated terms of the query must be matched.
+    #[method(name = "searchEvents")]
+    async fn search_events(
+        &self,
+        query: String,
+        // exclusive cursor if `Some`, otherwise start from the beginning
+        cursor: Option<IndexerEventID>,
+        limit: Option<StrView<usize>>,
+        descending_order: Option<bool>,
+    ) -> RpcResult<IndexerEventPageView>;
+
     /// Query the global states indexer by state filter
+    /// The cursor is the opaque `next_cursor` returned by the previous page
+    /// The decoded values are omitted if the QueryOptions.decode is false
     #[method(name = "queryGlobalStates")]
     async fn query_global_states(
         &self,
         filter: GlobalStateFilterView,
         // exclusive cursor if `Some`, otherwise start from the beginning
-        cursor: Option<IndexerStateID>,
+        cursor: Option<String>,
         limit: Option<StrView<usize>>,
         descending_order: Option<bool>,
+        query_options: Option<QueryOptions>,
     ) -> RpcResult<IndexerGlobalStatePageView>;
 
+    /// Query the published modules indexer by module filter, ordered by the tx order of the last publication,
+    /// the recently published or upgraded modules come first by default.
+    /// The cursor is the opaque `next_cursor` returned by the previous page
+    #[method(name = "queryModules")]
+    async fn query_modules(
+        &self,
+        filter: ModuleFilterView,
+        // exclusive cursor if `Some`, otherwise start from the beginning
+        cursor: Option<String>,
+        limit: Option<StrView<usize>>,
+        descending_order: Option<bool>,
+    ) -> RpcResult<IndexerModulePageView>;
+
+    /// Get the coin transfer history of the account coin store of the coin type,
+    /// the deposits and withdrawals are indexed from the coin store events, the latest transfers come first by default.
+    #[method(name = "getTransferHistory")]
+    async fn get_transfer_history(
+        &self,
+        account_addr: AccountAddressView,
+        coin_type: StructTagView,
+        // exclusive cursor if `Some`, otherwise start from the beginning
+        cursor: Option<IndexerEventID>,
+        limit: Option<StrView<usize>>,
+        descending_order: Option<bool>,
+    ) -> RpcResult<IndexerCoinTransferPageView>;
+
     /// Query the table states indexer by state filter
     #[method(name = "queryTableStates")]
     async fn query_table_states(
@@ -160,4 +355,24 @@ pub trait RoochAPI {
         limit: Option<StrView<usize>>,
         descending_order: Option<bool>,
     ) -> RpcResult<IndexerTableChangeSetPageView>;
+
+    /// Get the owner transitions of the object recorded by the indexer, the provenance of the object
+    #[method(name = "getObjectOwnershipHistory")]
+    async fn get_object_ownership_history(
+        &self,
+        object_id: ObjectID,
+        // exclusive tx order cursor if `Some`, otherwise start from the beginning
+        cursor: Option<StrView<u64>>,
+        limit: Option<StrView<usize>>,
+        descending_order: Option<bool>,
+    ) -> RpcResult<ObjectOwnershipChangePageView>;
+
+    /// List the tasks registered to the on-chain scheduler, in the order of the task id
+    #[method(name = "listScheduledTasks")]
+    async fn list_scheduled_tasks(
+        &self,
+        // exclusive task id cursor if `Some`, otherwise start from the beginning
+        cursor: Option<StrView<u64>>,
+        limit: Option<StrView<usize>>,
+    ) -> RpcResult<ScheduledTaskPageView>;
 }
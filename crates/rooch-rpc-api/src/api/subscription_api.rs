@@ -0,0 +1,22 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::jsonrpc_types::event_view::{EventFilterView, IndexerEventView};
+use crate::jsonrpc_types::{IndexerObjectStateChangeView, ObjectStateFilterView};
+use jsonrpsee::proc_macros::rpc;
+use rooch_open_rpc_macros::open_rpc;
+
+/// The subscription API is only available over the WebSocket transport,
+/// so it is separated from the RoochAPI which is also served over HTTP.
+#[open_rpc(namespace = "rooch")]
+#[rpc(server, client, namespace = "rooch")]
+pub trait SubscriptionAPI {
+    /// Subscribe to a stream of the newly emitted events which match the event filter
+    #[subscription(name = "subscribeEvents", item = IndexerEventView)]
+    fn subscribe_events(&self, filter: EventFilterView);
+
+    /// Subscribe to a stream of the object changes which match the object filter,
+    /// every change of the matched objects in a transaction is emitted.
+    #[subscription(name = "subscribeObject", item = IndexerObjectStateChangeView)]
+    fn subscribe_object(&self, filter: ObjectStateFilterView);
+}
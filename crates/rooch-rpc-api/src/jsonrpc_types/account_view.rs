@@ -2,11 +2,14 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use super::CoinInfoView;
-use crate::jsonrpc_types::StrView;
+use crate::jsonrpc_types::{AccountAddressView, BytesView, StrView, StructTagView};
 use move_core_types::u256::U256;
+use rooch_types::framework::session_key::SessionKey;
+use rooch_types::indexer::account::{
+    IndexerAccountSummary, IndexerAddressMapping, IndexerObjectTypeCount, IndexerStorageUsage,
+};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
-use std::ops::Div;
 
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct BalanceInfoView {
@@ -23,12 +26,173 @@ impl BalanceInfoView {
         }
     }
 
-    //TODO implements big decimal calculation for Decimal point display
+    /// Display the balance with the decimal point, e.g. `123.45` for the raw balance `12345000` with decimals `5`.
+    /// The trailing zeros of the fractional part are trimmed.
     pub fn get_balance_show(&self) -> String {
-        let balance = U256::div(
-            self.balance.0,
-            U256::from(10u32.pow(self.coin_info.decimals as u32)),
-        );
-        balance.to_string()
+        let raw = self.balance.0.to_string();
+        let decimals = self.coin_info.decimals as usize;
+        if decimals == 0 {
+            return raw;
+        }
+        let raw = format!("{:0>width$}", raw, width = decimals + 1);
+        let (integer, fraction) = raw.split_at(raw.len() - decimals);
+        let fraction = fraction.trim_end_matches('0');
+        if fraction.is_empty() {
+            integer.to_string()
+        } else {
+            format!("{}.{}", integer, fraction)
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ObjectTypeCountView {
+    pub object_type: StructTagView,
+    pub count: StrView<u64>,
+}
+
+impl From<IndexerObjectTypeCount> for ObjectTypeCountView {
+    fn from(object_type_count: IndexerObjectTypeCount) -> Self {
+        Self {
+            object_type: object_type_count.object_type.into(),
+            count: object_type_count.count.into(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct AccountSummaryView {
+    pub address: AccountAddressView,
+    /// The number of the transactions sent by the account
+    pub tx_count: StrView<u64>,
+    /// The total gas used by the transactions sent by the account
+    pub gas_used: StrView<u64>,
+    /// The tx order of the first transaction sent by the account
+    pub first_tx_order: Option<StrView<u64>>,
+    /// The tx order of the last transaction sent by the account
+    pub last_tx_order: Option<StrView<u64>>,
+    /// The seconds when the first transaction of the account is indexed
+    pub first_active_at: Option<StrView<u64>>,
+    /// The seconds when the last transaction of the account is indexed
+    pub last_active_at: Option<StrView<u64>>,
+    /// The number of the owned objects by object type
+    pub object_counts: Vec<ObjectTypeCountView>,
+}
+
+impl From<IndexerAccountSummary> for AccountSummaryView {
+    fn from(summary: IndexerAccountSummary) -> Self {
+        Self {
+            address: summary.address.into(),
+            tx_count: summary.tx_count.into(),
+            gas_used: summary.gas_used.into(),
+            first_tx_order: summary.first_tx_order.map(Into::into),
+            last_tx_order: summary.last_tx_order.map(Into::into),
+            first_active_at: summary.first_active_at.map(Into::into),
+            last_active_at: summary.last_active_at.map(Into::into),
+            object_counts: summary
+                .object_counts
+                .into_iter()
+                .map(ObjectTypeCountView::from)
+                .collect(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct StorageUsageView {
+    pub address: AccountAddressView,
+    /// The number of the objects owned by the account
+    pub object_count: StrView<u64>,
+    /// The bytes of the objects owned by the account
+    pub object_bytes: StrView<u64>,
+    /// The number of the table items of the objects owned by the account
+    pub table_item_count: StrView<u64>,
+    /// The bytes of the table items of the objects owned by the account
+    pub table_item_bytes: StrView<u64>,
+    /// The total bytes of the states accounted to the account
+    pub total_bytes: StrView<u64>,
+}
+
+impl From<IndexerStorageUsage> for StorageUsageView {
+    fn from(usage: IndexerStorageUsage) -> Self {
+        Self {
+            address: usage.address.into(),
+            object_count: usage.object_count.into(),
+            object_bytes: usage.object_bytes.into(),
+            table_item_count: usage.table_item_count.into(),
+            table_item_bytes: usage.table_item_bytes.into(),
+            total_bytes: usage.total_bytes().into(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct AddressMappingView {
+    /// The multichain id of the address, defined in slip-0044
+    pub multichain_id: u64,
+    /// The chain original address, such as the Bitcoin or Ethereum address
+    pub multichain_address: String,
+    /// The Rooch address which the multichain address is mapped to
+    pub rooch_address: AccountAddressView,
+    /// The tx order of the transaction which binds the multichain address
+    pub tx_order: StrView<u64>,
+    /// The seconds when the mapping is indexed
+    pub created_at: StrView<u64>,
+}
+
+impl AddressMappingView {
+    /// The Bitcoin address is formatted for the Bitcoin network of the node
+    pub fn try_new(
+        mapping: IndexerAddressMapping,
+        bitcoin_network: u8,
+    ) -> Result<Self, anyhow::Error> {
+        Ok(Self {
+            multichain_id: mapping
+                .multichain_address
+                .multichain_id
+                .multichain_id()
+                .id(),
+            multichain_address: mapping
+                .multichain_address
+                .to_original_string_with_bitcoin_network(bitcoin_network)?,
+            rooch_address: mapping.rooch_address.into(),
+            tx_order: mapping.tx_order.into(),
+            created_at: mapping.created_at.into(),
+        })
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct SessionKeyView {
+    pub authentication_key: BytesView,
+    /// The scopes of the session key, format: address::module_name::function_name
+    pub scopes: Vec<String>,
+    pub create_time: StrView<u64>,
+    pub last_active_time: StrView<u64>,
+    /// The max inactive interval of the session key in seconds, 0 means the session key never expires
+    pub max_inactive_interval: StrView<u64>,
+    /// The max gas the session key can spend in total, 0 means no limit
+    pub max_gas_budget: StrView<u64>,
+    pub gas_used: StrView<u64>,
+    /// The gas the session key can still spend, `None` if the gas of the session key is not limited
+    pub remaining_gas_budget: Option<StrView<u64>>,
+}
+
+impl From<SessionKey> for SessionKeyView {
+    fn from(session_key: SessionKey) -> Self {
+        Self {
+            remaining_gas_budget: session_key.remaining_gas_budget().map(Into::into),
+            scopes: session_key
+                .scopes
+                .iter()
+                .map(|scope| scope.to_string())
+                .collect(),
+            authentication_key: session_key.authentication_key.into(),
+            create_time: session_key.create_time.into(),
+            last_active_time: session_key.last_active_time.into(),
+            max_inactive_interval: session_key.max_inactive_interval.into(),
+            max_gas_budget: session_key.max_gas_budget.into(),
+            gas_used: session_key.gas_used.into(),
+        }
     }
 }
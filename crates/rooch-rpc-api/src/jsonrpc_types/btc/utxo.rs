@@ -8,9 +8,9 @@ use bitcoin::hashes::Hash;
 use bitcoin::Txid;
 use move_core_types::account_address::AccountAddress;
 use moveos_types::moveos_std::object_id::ObjectID;
-use moveos_types::state::MoveStructType;
 use rooch_types::bitcoin::utxo::{UTXOState, UTXO};
-use rooch_types::indexer::state::GlobalStateFilter;
+use rooch_types::indexer::state::UTXOFilter;
+use rooch_types::into_address::IntoAddress;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
@@ -19,28 +19,27 @@ use serde::{Deserialize, Serialize};
 pub enum UTXOFilterView {
     /// Query by owner, represent by bitcoin address
     Owner(BitcoinAddressView),
-
-    // TODO Txid needs to be pre-indexed, or can only be scan the whole table, and database index cannot be used
-    /// Query by txid.
-    // Txid(TxidView),
+    /// Query by the outpoint of the UTXO, the bitcoin txid and the output index.
+    OutPoint { txid: TxidView, vout: u32 },
     /// Query by object id.
     ObjectId(ObjectID),
 }
 
 impl UTXOFilterView {
-    pub fn into_global_state_filter(
+    pub fn into_utxo_filter(
         filter_opt: Option<UTXOFilterView>,
         resolve_address: AccountAddress,
-    ) -> GlobalStateFilter {
+    ) -> UTXOFilter {
         match filter_opt {
             Some(filter) => match filter {
-                UTXOFilterView::Owner(_owner) => GlobalStateFilter::ObjectTypeWithOwner {
-                    object_type: UTXO::struct_tag(),
-                    owner: resolve_address,
+                UTXOFilterView::Owner(_owner) => UTXOFilter::Owner(resolve_address),
+                UTXOFilterView::OutPoint { txid, vout } => UTXOFilter::OutPoint {
+                    txid: Txid::from(txid).into_address(),
+                    vout,
                 },
-                UTXOFilterView::ObjectId(object_id) => GlobalStateFilter::ObjectId(object_id),
+                UTXOFilterView::ObjectId(object_id) => UTXOFilter::ObjectId(object_id),
             },
-            None => GlobalStateFilter::ObjectType(UTXO::struct_tag()),
+            None => UTXOFilter::All,
         }
     }
 }
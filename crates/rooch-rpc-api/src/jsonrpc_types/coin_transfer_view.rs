@@ -0,0 +1,55 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::jsonrpc_types::{AccountAddressView, H256View, StrView, StructTagView};
+use move_core_types::u256::U256;
+use moveos_types::moveos_std::object_id::ObjectID;
+use rooch_types::indexer::coin_transfer::{CoinTransferType, IndexerCoinTransfer};
+use rooch_types::indexer::event_filter::IndexerEventID;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, Eq, PartialEq, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum CoinTransferTypeView {
+    Deposit,
+    Withdraw,
+}
+
+impl From<CoinTransferType> for CoinTransferTypeView {
+    fn from(transfer_type: CoinTransferType) -> Self {
+        match transfer_type {
+            CoinTransferType::Deposit => Self::Deposit,
+            CoinTransferType::Withdraw => Self::Withdraw,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct IndexerCoinTransferView {
+    /// The id of the `DepositEvent` or `WithdrawEvent` which records the transfer
+    pub indexer_event_id: IndexerEventID,
+    pub coin_store_id: ObjectID,
+    pub coin_type: StructTagView,
+    pub transfer_type: CoinTransferTypeView,
+    pub amount: StrView<U256>,
+    pub tx_hash: H256View,
+    /// The sender of the transaction, it is not the owner of the coin store if the coin is sent to others
+    pub sender: AccountAddressView,
+    pub created_at: StrView<u64>,
+}
+
+impl From<IndexerCoinTransfer> for IndexerCoinTransferView {
+    fn from(transfer: IndexerCoinTransfer) -> Self {
+        Self {
+            indexer_event_id: transfer.indexer_event_id,
+            coin_store_id: transfer.coin_store_id,
+            coin_type: transfer.coin_type.into(),
+            transfer_type: transfer.transfer_type.into(),
+            amount: transfer.amount.into(),
+            tx_hash: transfer.tx_hash.into(),
+            sender: transfer.sender.into(),
+            created_at: transfer.created_at.into(),
+        }
+    }
+}
@@ -0,0 +1,45 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::jsonrpc_types::eth::ethereum_types::block::BlockNumber;
+use crate::jsonrpc_types::{H160View, H256View, StrView};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// A single value or a list of values, any of the values matches.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(untagged)]
+pub enum ValueOrArray<T> {
+    Value(T),
+    Array(Vec<T>),
+}
+
+impl<T: PartialEq> ValueOrArray<T> {
+    pub fn contains(&self, value: &T) -> bool {
+        match self {
+            ValueOrArray::Value(v) => v == value,
+            ValueOrArray::Array(values) => values.contains(value),
+        }
+    }
+}
+
+/// The filter of `eth_getLogs`, the block is the Rooch transaction, so the block number is the tx order.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct LogFilter {
+    /// The tx order to query from (inclusive), default to `latest`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub from_block: Option<StrView<BlockNumber>>,
+    /// The tx order to query to (inclusive), default to `latest`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub to_block: Option<StrView<BlockNumber>>,
+    /// Only query the logs of the transaction with the hash, `fromBlock` and `toBlock` are ignored if it is set
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub block_hash: Option<H256View>,
+    /// The addresses of the modules that emitted the logs
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address: Option<ValueOrArray<H160View>>,
+    /// The topics by position, `null` matches any topic
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub topics: Option<Vec<Option<ValueOrArray<H256View>>>>,
+}
@@ -4,12 +4,14 @@
 pub mod call_request;
 pub mod ethereum_types;
 pub mod fee_history;
+pub mod log_filter;
 pub mod transaction;
 pub mod transaction_access_list;
 
 pub use self::{
     call_request::CallRequest,
     fee_history::EthFeeHistory,
+    log_filter::{LogFilter, ValueOrArray},
     transaction::{Transaction, TransactionReceipt, TransactionRequest},
     transaction_access_list::{AccessList, AccessListItem},
 };
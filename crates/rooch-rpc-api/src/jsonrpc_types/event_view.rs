@@ -96,7 +96,10 @@ impl From<IndexerEvent> for IndexerEventView {
             sender: event.sender.into(),
             created_at: event.created_at,
 
-            decoded_event_data: None,
+            // The event data is decoded by the indexer when the event is indexed
+            decoded_event_data: event
+                .event_data_json
+                .and_then(|json| serde_json::from_str(json.as_str()).ok()),
         }
     }
 }
@@ -110,6 +113,15 @@ pub enum EventFilterView {
     Sender(AccountAddressView),
     /// Return events emitted by the given transaction hash.
     TxHash(H256View),
+    /// Return events emitted by the given transaction in [from_index, to_index) interval of the event index in the transaction,
+    /// the events are in the emission order.
+    TxEventIndexRange {
+        tx_hash: H256View,
+        /// left endpoint of the event index, inclusive
+        from_index: u64,
+        /// right endpoint of the event index, exclusive
+        to_index: u64,
+    },
     /// Return events emitted in [start_time, end_time) interval
     TimeRange {
         /// left endpoint of time interval, milliseconds since block, inclusive
@@ -125,6 +137,20 @@ pub enum EventFilterView {
         /// right endpoint of transaction order, exclusive
         to_order: u64,
     },
+    /// Return events that match all of the given filters, e.g. sender + event type + time range
+    All(Vec<EventFilterView>),
+}
+
+impl EventFilterView {
+    /// The complexity of the filter for the query limits, it is the number of the sub filters including the nested ones
+    pub fn complexity(&self) -> usize {
+        match self {
+            EventFilterView::All(filters) => {
+                filters.iter().map(Self::complexity).sum::<usize>() + 1
+            }
+            _ => 1,
+        }
+    }
 }
 
 impl From<EventFilterView> for EventFilter {
@@ -133,6 +159,15 @@ impl From<EventFilterView> for EventFilter {
             EventFilterView::EventType(event_type) => Self::EventType(event_type.into()),
             EventFilterView::Sender(address) => Self::Sender(address.into()),
             EventFilterView::TxHash(tx_hash) => Self::TxHash(tx_hash.into()),
+            EventFilterView::TxEventIndexRange {
+                tx_hash,
+                from_index,
+                to_index,
+            } => Self::TxEventIndexRange {
+                tx_hash: tx_hash.into(),
+                from_index,
+                to_index,
+            },
             EventFilterView::TimeRange {
                 start_time,
                 end_time,
@@ -147,6 +182,9 @@ impl From<EventFilterView> for EventFilter {
                 from_order,
                 to_order,
             },
+            EventFilterView::All(filters) => {
+                Self::All(filters.into_iter().map(Into::into).collect())
+            }
         }
     }
 }
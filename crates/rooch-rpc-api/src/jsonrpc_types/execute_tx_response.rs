@@ -3,14 +3,16 @@
 
 use super::BytesView;
 use super::{ModuleIdView, StateChangeSetView, StrView};
-use crate::jsonrpc_types::event_view::EventView;
-use crate::jsonrpc_types::H256View;
+use crate::jsonrpc_types::event_view::{EventView, TransactionEventView};
+use crate::jsonrpc_types::{H256View, RpcError, RpcErrorData};
 use move_core_types::vm_status::{AbortLocation, KeptVMStatus};
 use moveos_types::transaction::TransactionExecutionInfo;
 use moveos_types::transaction::TransactionOutput;
+use moveos_types::transaction::{GasProfile, RawTransactionOutput};
 use rooch_types::transaction::{authenticator::Authenticator, TransactionSequenceInfo};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::str::FromStr;
 
 pub type AbortLocationView = StrView<AbortLocation>;
@@ -94,6 +96,15 @@ impl From<Authenticator> for AuthenticatorView {
         }
     }
 }
+
+impl From<AuthenticatorView> for Authenticator {
+    fn from(view: AuthenticatorView) -> Self {
+        Self {
+            auth_validator_id: view.auth_validator_id.0,
+            payload: view.payload.0,
+        }
+    }
+}
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct TransactionSequenceInfoView {
     pub tx_order: StrView<u64>,
@@ -113,6 +124,16 @@ impl From<TransactionSequenceInfo> for TransactionSequenceInfoView {
     }
 }
 
+impl From<TransactionSequenceInfoView> for TransactionSequenceInfo {
+    fn from(view: TransactionSequenceInfoView) -> Self {
+        Self {
+            tx_order: view.tx_order.0,
+            tx_order_signature: view.tx_order_signature.into(),
+            tx_accumulator_root: view.tx_accumulator_root.0,
+        }
+    }
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
 pub struct TransactionExecutionInfoView {
     pub tx_hash: H256View,
@@ -161,6 +182,88 @@ impl From<TransactionOutput> for TransactionOutputView {
     }
 }
 
+/// The output of a dry run transaction, the state changes are not committed
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+pub struct DryRunTransactionResponseView {
+    pub status: KeptVMStatusView,
+    pub gas_used: u64,
+    pub events: Vec<TransactionEventView>,
+    /// The state changes would be applied if the transaction is submitted
+    pub table_changeset: StateChangeSetView,
+    pub is_upgrade: bool,
+    /// The gas breakdown, only returned if the gas profile is requested
+    pub gas_profile: Option<GasProfileView>,
+}
+
+impl From<RawTransactionOutput> for DryRunTransactionResponseView {
+    fn from(tx_output: RawTransactionOutput) -> Self {
+        Self {
+            status: tx_output.status.into(),
+            gas_used: tx_output.gas_used,
+            events: tx_output
+                .events
+                .into_iter()
+                .map(|event| event.into())
+                .collect(),
+            table_changeset: tx_output.state_changeset.into(),
+            is_upgrade: tx_output.is_upgrade,
+            gas_profile: tx_output.gas_profile.map(Into::into),
+        }
+    }
+}
+
+/// The gas breakdown of a dry run transaction.
+/// The storage gas of the state changes is included, so the total may be larger than the `gas_used`.
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+pub struct GasProfileView {
+    /// The instruction name -> the gas charged by the instructions
+    pub instructions: BTreeMap<String, u64>,
+    /// The native function `address::module::function` -> the gas charged by the native function
+    pub natives: BTreeMap<String, u64>,
+    /// The storage operation (`new`, `modify`, `delete`, `event`, `io_write`) -> the gas charged by the operations
+    pub storage: BTreeMap<String, u64>,
+}
+
+impl From<GasProfile> for GasProfileView {
+    fn from(profile: GasProfile) -> Self {
+        Self {
+            instructions: profile.instructions,
+            natives: profile.natives,
+            storage: profile.storage,
+        }
+    }
+}
+
+/// The result of a transaction in a batch submission.
+/// The transactions in a batch are submitted independently, a failed transaction does not affect the others.
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+pub struct SendTransactionResultView {
+    /// The transaction hash, `None` if the transaction can not be decoded
+    pub tx_hash: Option<H256View>,
+    /// The error message if the transaction submission failed
+    pub error: Option<String>,
+    /// The error data if the transaction submission failed
+    pub error_data: Option<RpcErrorData>,
+}
+
+impl SendTransactionResultView {
+    pub fn success(tx_hash: H256View) -> Self {
+        Self {
+            tx_hash: Some(tx_hash),
+            error: None,
+            error_data: None,
+        }
+    }
+
+    pub fn failure(tx_hash: Option<H256View>, error: RpcError) -> Self {
+        Self {
+            tx_hash,
+            error: Some(error.message),
+            error_data: Some(error.data),
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct ExecuteTransactionResponse {
     pub sequence_info: TransactionSequenceInfo,
@@ -0,0 +1,39 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use super::KeptVMStatusView;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// The gas usage percentiles of the recent executed transactions
+#[derive(Clone, Debug, Default, Serialize, Deserialize, JsonSchema)]
+pub struct GasUsageStatsView {
+    /// The number of the recent transactions the stats based on
+    pub sample_count: u64,
+    pub min: u64,
+    pub p50: u64,
+    pub p75: u64,
+    pub p90: u64,
+    pub p99: u64,
+    pub max: u64,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+pub struct GasPriceEstimateView {
+    /// The price of one gas unit, the gas fee is `gas_used * gas_price`
+    pub gas_price: u64,
+    pub gas_usage: GasUsageStatsView,
+    /// The suggested `max_gas_amount` for a typical transaction, based on the p99 gas usage with a safety margin
+    pub recommended_max_gas_amount: u64,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+pub struct GasEstimateView {
+    /// The status of the dry run, the estimation is only reliable if the transaction is executed successfully
+    pub status: KeptVMStatusView,
+    /// The gas used by the dry run
+    pub gas_used: u64,
+    /// The suggested `max_gas_amount` of the transaction, the gas used with a safety margin
+    pub max_gas_amount: u64,
+    pub gas_price: u64,
+}
@@ -6,8 +6,11 @@
 mod str_view;
 mod execute_tx_response;
 mod function_return_value_view;
+mod gas_estimate_view;
+mod module_abi_view;
 mod move_types;
 mod rooch_types;
+mod rpc_error;
 mod rpc_options;
 mod state_view;
 #[cfg(test)]
@@ -15,8 +18,10 @@ mod tests;
 mod transaction_argument_view;
 
 pub mod account_view;
+pub mod coin_transfer_view;
 pub mod eth;
 pub mod event_view;
+pub mod module_view;
 pub mod transaction_view;
 
 pub mod address;
@@ -25,7 +30,10 @@ pub mod btc;
 pub use self::rooch_types::*;
 pub use execute_tx_response::*;
 pub use function_return_value_view::*;
+pub use gas_estimate_view::*;
+pub use module_abi_view::*;
 pub use move_types::*;
+pub use rpc_error::*;
 pub use rpc_options::*;
 pub use state_view::*;
 pub use str_view::*;
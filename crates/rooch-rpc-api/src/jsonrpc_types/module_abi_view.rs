@@ -0,0 +1,195 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use super::{AccountAddressView, ModuleIdView, StrView};
+use anyhow::Result;
+use move_binary_format::{
+    file_format::{Ability, AbilitySet, Visibility},
+    normalized, CompiledModule,
+};
+use move_core_types::identifier::Identifier;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// The string representation of a Move type in the ABI, the generic type parameters are represented as `T0`, `T1`, ...
+/// e.g. `u64`, `vector<u8>`, `&mut 0x2::context::Context`, `0x3::coin::Coin<T0>`
+pub fn format_move_type(ty: &normalized::Type) -> String {
+    match ty {
+        normalized::Type::Bool => "bool".to_owned(),
+        normalized::Type::U8 => "u8".to_owned(),
+        normalized::Type::U16 => "u16".to_owned(),
+        normalized::Type::U32 => "u32".to_owned(),
+        normalized::Type::U64 => "u64".to_owned(),
+        normalized::Type::U128 => "u128".to_owned(),
+        normalized::Type::U256 => "u256".to_owned(),
+        normalized::Type::Address => "address".to_owned(),
+        normalized::Type::Signer => "signer".to_owned(),
+        normalized::Type::Struct {
+            address,
+            module,
+            name,
+            type_arguments,
+        } => {
+            let struct_name = format!("{}::{}::{}", address.to_hex_literal(), module, name);
+            if type_arguments.is_empty() {
+                struct_name
+            } else {
+                format!(
+                    "{}<{}>",
+                    struct_name,
+                    type_arguments
+                        .iter()
+                        .map(format_move_type)
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                )
+            }
+        }
+        normalized::Type::Vector(ty) => format!("vector<{}>", format_move_type(ty)),
+        normalized::Type::TypeParameter(idx) => format!("T{}", idx),
+        normalized::Type::Reference(ty) => format!("&{}", format_move_type(ty)),
+        normalized::Type::MutableReference(ty) => format!("&mut {}", format_move_type(ty)),
+    }
+}
+
+fn format_abilities(abilities: AbilitySet) -> Vec<String> {
+    abilities
+        .into_iter()
+        .map(|ability| {
+            match ability {
+                Ability::Copy => "copy",
+                Ability::Drop => "drop",
+                Ability::Store => "store",
+                Ability::Key => "key",
+            }
+            .to_owned()
+        })
+        .collect()
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+pub struct MoveFunctionTypeParameterABIView {
+    /// The abilities the type argument must have
+    pub constraints: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+pub struct MoveFunctionABIView {
+    pub name: StrView<Identifier>,
+    /// `public`, `friend` or `private`, the private functions are exposed only if they are entry functions
+    pub visibility: String,
+    pub is_entry: bool,
+    pub type_parameters: Vec<MoveFunctionTypeParameterABIView>,
+    /// The parameter types, including the `&mut Context` and `&signer` parameters injected by the VM
+    pub parameters: Vec<String>,
+    pub returns: Vec<String>,
+}
+
+impl MoveFunctionABIView {
+    fn new(name: Identifier, function: &normalized::Function) -> Self {
+        let visibility = match function.visibility {
+            Visibility::Public => "public",
+            Visibility::Friend => "friend",
+            Visibility::Private => "private",
+        };
+        Self {
+            name: StrView(name),
+            visibility: visibility.to_owned(),
+            is_entry: function.is_entry,
+            type_parameters: function
+                .type_parameters
+                .iter()
+                .map(|constraints| MoveFunctionTypeParameterABIView {
+                    constraints: format_abilities(*constraints),
+                })
+                .collect(),
+            parameters: function.parameters.iter().map(format_move_type).collect(),
+            returns: function.return_.iter().map(format_move_type).collect(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+pub struct MoveStructTypeParameterABIView {
+    /// The abilities the type argument must have
+    pub constraints: Vec<String>,
+    pub is_phantom: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+pub struct MoveStructFieldABIView {
+    pub name: StrView<Identifier>,
+    #[serde(rename = "type")]
+    pub type_: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+pub struct MoveStructABIView {
+    pub name: StrView<Identifier>,
+    pub abilities: Vec<String>,
+    pub type_parameters: Vec<MoveStructTypeParameterABIView>,
+    pub fields: Vec<MoveStructFieldABIView>,
+}
+
+impl MoveStructABIView {
+    fn new(name: Identifier, move_struct: &normalized::Struct) -> Self {
+        Self {
+            name: StrView(name),
+            abilities: format_abilities(move_struct.abilities),
+            type_parameters: move_struct
+                .type_parameters
+                .iter()
+                .map(|type_parameter| MoveStructTypeParameterABIView {
+                    constraints: format_abilities(type_parameter.constraints),
+                    is_phantom: type_parameter.is_phantom,
+                })
+                .collect(),
+            fields: move_struct
+                .fields
+                .iter()
+                .map(|field| MoveStructFieldABIView {
+                    name: StrView(field.name.clone()),
+                    type_: format_move_type(&field.type_),
+                })
+                .collect(),
+        }
+    }
+}
+
+/// The ABI of a published module, the exposed(public, friend and entry) functions and all the structs
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+pub struct MoveModuleABIView {
+    pub address: AccountAddressView,
+    pub name: StrView<Identifier>,
+    pub friends: Vec<ModuleIdView>,
+    pub functions: Vec<MoveFunctionABIView>,
+    pub structs: Vec<MoveStructABIView>,
+}
+
+impl MoveModuleABIView {
+    pub fn try_from_bytecode(byte_codes: &[u8]) -> Result<Self> {
+        let module = CompiledModule::deserialize(byte_codes)?;
+        Ok(Self::from(&module))
+    }
+}
+
+impl From<&CompiledModule> for MoveModuleABIView {
+    fn from(module: &CompiledModule) -> Self {
+        let module = normalized::Module::new(module);
+        Self {
+            address: StrView(module.address),
+            name: StrView(module.name),
+            friends: module.friends.into_iter().map(StrView).collect(),
+            functions: module
+                .exposed_functions
+                .into_iter()
+                .map(|(name, function)| MoveFunctionABIView::new(name, &function))
+                .collect(),
+            structs: module
+                .structs
+                .into_iter()
+                .map(|(name, move_struct)| MoveStructABIView::new(name, &move_struct))
+                .collect(),
+        }
+    }
+}
@@ -0,0 +1,69 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::jsonrpc_types::{AccountAddressView, H256View, StrView};
+use rooch_types::indexer::module::{IndexerModule, ModuleFilter};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct IndexerModuleView {
+    /// The address of the module, it is also the publisher of the module
+    pub address: AccountAddressView,
+    pub name: String,
+    /// The sha3-256 hash of the module bytecode
+    pub bytecode_hash: H256View,
+    /// The digest of the package source, it is not recorded on chain yet
+    pub source_digest: Option<H256View>,
+    /// The tx order of the transaction which publishes or upgrades the module
+    pub tx_order: StrView<u64>,
+    pub state_index: StrView<u64>,
+    /// The seconds when the module is first indexed
+    pub created_at: StrView<u64>,
+    /// The seconds when the module is last upgraded
+    pub updated_at: StrView<u64>,
+}
+
+impl From<IndexerModule> for IndexerModuleView {
+    fn from(module: IndexerModule) -> Self {
+        Self {
+            address: module.address.into(),
+            name: module.name,
+            bytecode_hash: module.bytecode_hash.into(),
+            source_digest: module.source_digest.map(Into::into),
+            tx_order: module.tx_order.into(),
+            state_index: module.state_index.into(),
+            created_at: module.created_at.into(),
+            updated_at: module.updated_at.into(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub enum ModuleFilterView {
+    /// Query by the publisher address.
+    Publisher(AccountAddressView),
+    /// Return the modules first indexed in [start_time, end_time) interval
+    TimeRange {
+        /// left endpoint of time interval, seconds since epoch, inclusive
+        start_time: StrView<u64>,
+        /// right endpoint of time interval, seconds since epoch, exclusive
+        end_time: StrView<u64>,
+    },
+}
+
+impl From<ModuleFilterView> for ModuleFilter {
+    fn from(filter: ModuleFilterView) -> Self {
+        match filter {
+            ModuleFilterView::Publisher(publisher) => Self::Publisher(publisher.into()),
+            ModuleFilterView::TimeRange {
+                start_time,
+                end_time,
+            } => Self::TimeRange {
+                start_time: start_time.0,
+                end_time: end_time.0,
+            },
+        }
+    }
+}
@@ -1,19 +1,23 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::jsonrpc_types::account_view::BalanceInfoView;
+use crate::jsonrpc_types::account_view::{BalanceInfoView, SessionKeyView};
 use crate::jsonrpc_types::btc::ord::InscriptionStateView;
 use crate::jsonrpc_types::btc::utxo::UTXOStateView;
+use crate::jsonrpc_types::coin_transfer_view::IndexerCoinTransferView;
 use crate::jsonrpc_types::event_view::{EventView, IndexerEventView};
+use crate::jsonrpc_types::module_view::IndexerModuleView;
 use crate::jsonrpc_types::transaction_view::TransactionWithInfoView;
 use crate::jsonrpc_types::{
-    move_types::{MoveActionTypeView, MoveActionView},
-    BytesView, IndexerGlobalStateView, IndexerTableChangeSetView, IndexerTableStateView,
-    StateKVView, StrView, StructTagView,
+    move_types::{AccountAddressView, FunctionIdView, MoveActionTypeView, MoveActionView},
+    BytesView, IndexerGlobalStateView, IndexerObjectOwnershipChangeView, IndexerTableChangeSetView,
+    IndexerTableStateView, StateKVView, StrView, StructTagView,
 };
 use move_core_types::u256::U256;
 use rooch_types::framework::coin::CoinInfo;
+use rooch_types::framework::scheduler::ScheduledTask;
 use rooch_types::indexer::event_filter::IndexerEventID;
+use rooch_types::indexer::state::IndexerCoinInfo;
 use rooch_types::indexer::state::IndexerStateID;
 use rooch_types::transaction::{AbstractTransaction, TransactionType, TypedTransaction};
 use schemars::JsonSchema;
@@ -24,15 +28,27 @@ pub type EventPageView = PageView<EventView, u64>;
 pub type TransactionWithInfoPageView = PageView<TransactionWithInfoView, u64>;
 pub type StatePageView = PageView<StateKVView, String>;
 pub type BalanceInfoPageView = PageView<BalanceInfoView, String>;
+pub type SessionKeyPageView = PageView<SessionKeyView, String>;
 pub type IndexerEventPageView = PageView<IndexerEventView, IndexerEventID>;
+pub type IndexerCoinTransferPageView = PageView<IndexerCoinTransferView, IndexerEventID>;
 pub type IndexerTableChangeSetPageView = PageView<IndexerTableChangeSetView, IndexerStateID>;
 
-pub type IndexerGlobalStatePageView = PageView<IndexerGlobalStateView, IndexerStateID>;
+/// The cursor of global states page is an opaque string encoded from the IndexerStateID
+pub type IndexerGlobalStatePageView = PageView<IndexerGlobalStateView, String>;
 pub type IndexerTableStatePageView = PageView<IndexerTableStateView, IndexerStateID>;
 
+/// The cursor of modules page is an opaque string encoded from the IndexerStateID
+pub type IndexerModulePageView = PageView<IndexerModuleView, String>;
+
 pub type UTXOPageView = PageView<UTXOStateView, IndexerStateID>;
 pub type InscriptionPageView = PageView<InscriptionStateView, IndexerStateID>;
 
+/// The cursor of object ownership history page is the tx order
+pub type ObjectOwnershipChangePageView = PageView<IndexerObjectOwnershipChangeView, u64>;
+
+/// The cursor of scheduled tasks page is the task id
+pub type ScheduledTaskPageView = PageView<ScheduledTaskView, u64>;
+
 /// `next_cursor` points to the last item in the page;
 /// Reading with `next_cursor` will start from the next item after `next_cursor` if
 /// `next_cursor` is `Some`, otherwise it will start from the first item.
@@ -66,8 +82,12 @@ pub struct TransactionView {
     // TBD: how to represent the sender.
     pub sender: String,
     pub action_type: MoveActionTypeView,
-    pub action: MoveActionView,
-    pub raw: BytesView,
+    /// The decoded action, omitted if the `QueryOptions.decode` is false
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action: Option<MoveActionView>,
+    /// The raw BCS bytes of the transaction, omitted if the `QueryOptions.show_raw` is false
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub raw: Option<BytesView>,
 }
 
 impl From<TypedTransaction> for TransactionView {
@@ -78,17 +98,17 @@ impl From<TypedTransaction> for TransactionView {
                 transaction_type: transaction_type.into(),
                 sequence_number: rooch.sequence_number(),
                 sender: rooch.sender().to_string(),
-                action: rooch.action().clone().into(),
+                action: Some(rooch.action().clone().into()),
                 action_type: rooch.action().clone().into(),
-                raw: rooch.encode().into(),
+                raw: Some(rooch.encode().into()),
             },
             TypedTransaction::Ethereum(eth) => Self {
                 transaction_type: transaction_type.into(),
                 sequence_number: eth.0.nonce.as_u64(),
                 sender: eth.0.from.to_string(),
-                action: eth.decode_calldata_to_action().unwrap().into(),
+                action: Some(eth.decode_calldata_to_action().unwrap().into()),
                 action_type: eth.decode_calldata_to_action().unwrap().into(),
-                raw: eth.encode().into(),
+                raw: Some(eth.encode().into()),
             },
         }
     }
@@ -99,6 +119,7 @@ pub struct CoinInfoView {
     pub coin_type: StructTagView,
     pub name: String,
     pub symbol: String,
+    pub icon_url: Option<String>,
     pub decimals: u8,
     pub supply: StrView<U256>,
 }
@@ -112,8 +133,53 @@ impl<CoinType> From<CoinInfo<CoinType>> for CoinInfoView {
             coin_type: coin_info.coin_type_tag().into(),
             name: coin_info.name(),
             symbol: coin_info.symbol(),
+            icon_url: coin_info.icon_url(),
             decimals: coin_info.decimals(),
             supply: StrView(coin_info.supply()),
         }
     }
 }
+
+impl From<IndexerCoinInfo> for CoinInfoView {
+    fn from(coin_info: IndexerCoinInfo) -> Self {
+        Self {
+            coin_type: coin_info.coin_type.into(),
+            name: coin_info.name,
+            symbol: coin_info.symbol,
+            icon_url: coin_info.icon_url,
+            decimals: coin_info.decimals,
+            supply: StrView(coin_info.supply),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ScheduledTaskView {
+    pub task_id: u64,
+    pub owner: AccountAddressView,
+    /// The callback entry function, it is called with the task id
+    pub callback: FunctionIdView,
+    pub interval_seconds: u64,
+    pub next_run_seconds: u64,
+    pub fee_per_run: StrView<U256>,
+    pub balance: StrView<U256>,
+    pub run_count: u64,
+}
+
+impl TryFrom<ScheduledTask> for ScheduledTaskView {
+    type Error = anyhow::Error;
+
+    fn try_from(task: ScheduledTask) -> Result<Self, Self::Error> {
+        let callback = task.callback_call()?.function_id;
+        Ok(Self {
+            task_id: task.task_id,
+            owner: task.owner.into(),
+            callback: callback.into(),
+            interval_seconds: task.interval_seconds,
+            next_run_seconds: task.next_run_seconds,
+            fee_per_run: StrView(task.fee_per_run),
+            balance: StrView(task.balance),
+            run_count: task.run_count,
+        })
+    }
+}
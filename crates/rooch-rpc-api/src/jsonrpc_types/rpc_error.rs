@@ -0,0 +1,220 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::jsonrpc_types::VMStatusView;
+use jsonrpsee::core::Error as JsonRpcError;
+use jsonrpsee::types::error::{
+    CallError, ErrorObject, CALL_EXECUTION_FAILED_CODE, INVALID_PARAMS_CODE,
+};
+use move_binary_format::errors::VMError;
+use move_core_types::vm_status::{AbortLocation, StatusCode, VMStatus};
+use moveos_types::module_binding::ModuleBinding;
+use rooch_types::addresses::ROOCH_FRAMEWORK_ADDRESS;
+use rooch_types::framework::transaction_validator::TransactionValidator;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+// The abort codes of the transaction validator, keep the same as the `transaction_validator` module.
+const ERROR_VALIDATE_SEQUENCE_NUMBER_TOO_OLD: u64 = 1001;
+const ERROR_VALIDATE_SEQUENCE_NUMBER_TOO_NEW: u64 = 1002;
+const ERROR_VALIDATE_ACCOUNT_DOES_NOT_EXIST: u64 = 1003;
+const ERROR_VALIDATE_CANT_PAY_GAS_DEPOSIT: u64 = 1004;
+const ERROR_VALIDATE_TRANSACTION_EXPIRED: u64 = 1005;
+const ERROR_VALIDATE_BAD_CHAIN_ID: u64 = 1006;
+const ERROR_VALIDATE_SEQUENCE_NUMBER_TOO_BIG: u64 = 1007;
+const ERROR_VALIDATE_NOT_INSTALLED_AUTH_VALIDATOR: u64 = 1010;
+
+// The abort codes of the auth validators, keep the same as the `auth_validator` module.
+const ERROR_VALIDATE_INVALID_ACCOUNT_AUTH_KEY: u64 = 1001;
+const ERROR_VALIDATE_INVALID_AUTHENTICATOR: u64 = 1002;
+
+/// The stable error code of the RPC errors, it is returned in the `data` field of the JSON-RPC error,
+/// so the clients can react to the errors without parsing the error message.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, JsonSchema, Eq, PartialEq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum RpcErrorCode {
+    /// The request parameters are invalid.
+    InvalidParams,
+    /// The query exceeds the query limits of the server, such as the max page size or the max filter complexity.
+    QueryLimitExceeded,
+    /// The gas is not enough to execute the transaction, or the sender can not pay the gas.
+    InsufficientGas,
+    SequenceNumberTooOld,
+    SequenceNumberTooNew,
+    SequenceNumberTooBig,
+    AccountNotFound,
+    TransactionExpired,
+    BadChainId,
+    /// The authenticator of the transaction is invalid, include the invalid signature.
+    InvalidAuthenticator,
+    /// The auth key in the authenticator does not match the sender's auth key.
+    InvalidAuthKey,
+    /// The auth validator of the authenticator is not installed to the sender's account.
+    AuthValidatorNotInstalled,
+    /// The Move code aborted, the abort location and code are returned in the `vm_status`.
+    MoveAbort,
+    /// The VM returned an error status, the status code is returned in the `vm_status`.
+    VmError,
+    InternalError,
+}
+
+impl RpcErrorCode {
+    /// The JSON-RPC error code of the error.
+    pub fn json_rpc_code(&self) -> i32 {
+        match self {
+            RpcErrorCode::InvalidParams | RpcErrorCode::QueryLimitExceeded => INVALID_PARAMS_CODE,
+            _ => CALL_EXECUTION_FAILED_CODE,
+        }
+    }
+
+    fn from_abort(location: &AbortLocation, abort_code: u64) -> Self {
+        let module_id = match location {
+            AbortLocation::Module(module_id) => module_id,
+            AbortLocation::Script => return RpcErrorCode::MoveAbort,
+        };
+        if module_id == &TransactionValidator::module_id() {
+            match abort_code {
+                ERROR_VALIDATE_SEQUENCE_NUMBER_TOO_OLD => RpcErrorCode::SequenceNumberTooOld,
+                ERROR_VALIDATE_SEQUENCE_NUMBER_TOO_NEW => RpcErrorCode::SequenceNumberTooNew,
+                ERROR_VALIDATE_ACCOUNT_DOES_NOT_EXIST => RpcErrorCode::AccountNotFound,
+                ERROR_VALIDATE_CANT_PAY_GAS_DEPOSIT => RpcErrorCode::InsufficientGas,
+                ERROR_VALIDATE_TRANSACTION_EXPIRED => RpcErrorCode::TransactionExpired,
+                ERROR_VALIDATE_BAD_CHAIN_ID => RpcErrorCode::BadChainId,
+                ERROR_VALIDATE_SEQUENCE_NUMBER_TOO_BIG => RpcErrorCode::SequenceNumberTooBig,
+                ERROR_VALIDATE_NOT_INSTALLED_AUTH_VALIDATOR => {
+                    RpcErrorCode::AuthValidatorNotInstalled
+                }
+                _ => RpcErrorCode::MoveAbort,
+            }
+        } else if module_id.address() == &ROOCH_FRAMEWORK_ADDRESS
+            && module_id.name().as_str().ends_with("_validator")
+        {
+            match abort_code {
+                ERROR_VALIDATE_INVALID_ACCOUNT_AUTH_KEY => RpcErrorCode::InvalidAuthKey,
+                ERROR_VALIDATE_INVALID_AUTHENTICATOR => RpcErrorCode::InvalidAuthenticator,
+                _ => RpcErrorCode::MoveAbort,
+            }
+        } else {
+            RpcErrorCode::MoveAbort
+        }
+    }
+
+    fn from_status_code(status_code: StatusCode) -> Self {
+        match status_code {
+            StatusCode::OUT_OF_GAS | StatusCode::INSUFFICIENT_BALANCE_FOR_TRANSACTION_FEE => {
+                RpcErrorCode::InsufficientGas
+            }
+            StatusCode::SEQUENCE_NUMBER_TOO_OLD => RpcErrorCode::SequenceNumberTooOld,
+            StatusCode::SEQUENCE_NUMBER_TOO_NEW => RpcErrorCode::SequenceNumberTooNew,
+            StatusCode::SEQUENCE_NUMBER_TOO_BIG => RpcErrorCode::SequenceNumberTooBig,
+            StatusCode::SENDING_ACCOUNT_DOES_NOT_EXIST => RpcErrorCode::AccountNotFound,
+            StatusCode::TRANSACTION_EXPIRED => RpcErrorCode::TransactionExpired,
+            StatusCode::BAD_CHAIN_ID => RpcErrorCode::BadChainId,
+            StatusCode::INVALID_SIGNATURE => RpcErrorCode::InvalidAuthenticator,
+            StatusCode::INVALID_AUTH_KEY => RpcErrorCode::InvalidAuthKey,
+            _ => RpcErrorCode::VmError,
+        }
+    }
+}
+
+impl From<&VMStatus> for RpcErrorCode {
+    fn from(vm_status: &VMStatus) -> Self {
+        match vm_status {
+            VMStatus::Executed => RpcErrorCode::InternalError,
+            VMStatus::MoveAbort(location, abort_code) => {
+                RpcErrorCode::from_abort(location, *abort_code)
+            }
+            VMStatus::ExecutionFailure { status_code, .. }
+            | VMStatus::Error { status_code, .. } => RpcErrorCode::from_status_code(*status_code),
+        }
+    }
+}
+
+/// The machine-readable data of the RPC errors, returned in the `data` field of the JSON-RPC error.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct RpcErrorData {
+    pub code: RpcErrorCode,
+    /// The VM status if the error is returned by the VM, such as the transaction validation error.
+    pub vm_status: Option<VMStatusView>,
+}
+
+impl RpcErrorData {
+    /// Extract the error data from the JSON-RPC error returned by the server.
+    pub fn from_json_rpc_error(error: &JsonRpcError) -> Option<Self> {
+        match error {
+            JsonRpcError::Call(CallError::Custom(error_object)) => error_object
+                .data()
+                .and_then(|data| serde_json::from_str(data.get()).ok()),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct RpcError {
+    pub message: String,
+    pub data: RpcErrorData,
+}
+
+impl RpcError {
+    pub fn new(code: RpcErrorCode, message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+            data: RpcErrorData {
+                code,
+                vm_status: None,
+            },
+        }
+    }
+
+    pub fn invalid_params(message: impl Into<String>) -> Self {
+        Self::new(RpcErrorCode::InvalidParams, message)
+    }
+
+    /// The `value` of the `item` exceeds the `max` of the query limits
+    pub fn query_limit_exceeded(item: &str, value: usize, max: usize) -> Self {
+        Self::new(
+            RpcErrorCode::QueryLimitExceeded,
+            format!("The {} {} exceeds the max {}", item, value, max),
+        )
+    }
+
+    pub fn with_vm_status(vm_status: VMStatus, message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+            data: RpcErrorData {
+                code: RpcErrorCode::from(&vm_status),
+                vm_status: Some(vm_status.into()),
+            },
+        }
+    }
+
+    pub fn code(&self) -> RpcErrorCode {
+        self.data.code
+    }
+}
+
+impl From<anyhow::Error> for RpcError {
+    fn from(error: anyhow::Error) -> Self {
+        let vm_status = match error.downcast_ref::<VMStatus>() {
+            Some(vm_status) => Some(vm_status.clone()),
+            None => error
+                .downcast_ref::<VMError>()
+                .map(|vm_error| vm_error.clone().into_vm_status()),
+        };
+        match vm_status {
+            Some(vm_status) => Self::with_vm_status(vm_status, error.to_string()),
+            None => Self::new(RpcErrorCode::InternalError, error.to_string()),
+        }
+    }
+}
+
+impl From<RpcError> for JsonRpcError {
+    fn from(error: RpcError) -> Self {
+        JsonRpcError::Call(CallError::Custom(ErrorObject::owned(
+            error.code().json_rpc_code(),
+            error.message,
+            Some(error.data),
+        )))
+    }
+}
@@ -1,6 +1,7 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
 
+use crate::jsonrpc_types::{H256View, StrView};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
@@ -9,6 +10,11 @@ use serde::{Deserialize, Serialize};
 pub struct StateOptions {
     /// If true, the state is decoded and the decoded value is returned in the response.
     pub decode: bool,
+    /// If set, the states are read at the given state root instead of the latest one.
+    pub state_root: Option<H256View>,
+    /// If set, the states are read as of the execution of the transaction with the given tx order.
+    /// It can not be used together with the `state_root`.
+    pub tx_order: Option<StrView<u64>>,
 }
 
 impl StateOptions {
@@ -20,6 +26,16 @@ impl StateOptions {
         self.decode = decode;
         self
     }
+
+    pub fn state_root(mut self, state_root: H256View) -> Self {
+        self.state_root = Some(state_root);
+        self
+    }
+
+    pub fn tx_order(mut self, tx_order: u64) -> Self {
+        self.tx_order = Some(tx_order.into());
+        self
+    }
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize, JsonSchema, Eq, PartialEq, Default)]
@@ -39,3 +55,47 @@ impl EventOptions {
         self
     }
 }
+
+/// Select the fields of the heavy query results, the fields not needed by the client are omitted from the response.
+/// The default options keep the response the same as the query without options.
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema, Eq, PartialEq)]
+#[serde(rename_all = "camelCase", default)]
+pub struct QueryOptions {
+    /// If true, the decoded values of the states and the decoded actions of the transactions are returned.
+    pub decode: bool,
+    /// If true, the raw BCS bytes of the transactions are returned.
+    pub show_raw: bool,
+    /// If true, the events emitted by the transactions are returned.
+    pub show_events: bool,
+}
+
+impl Default for QueryOptions {
+    fn default() -> Self {
+        Self {
+            decode: true,
+            show_raw: true,
+            show_events: false,
+        }
+    }
+}
+
+impl QueryOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn decode(mut self, decode: bool) -> Self {
+        self.decode = decode;
+        self
+    }
+
+    pub fn show_raw(mut self, show_raw: bool) -> Self {
+        self.show_raw = show_raw;
+        self
+    }
+
+    pub fn show_events(mut self, show_events: bool) -> Self {
+        self.show_events = show_events;
+        self
+    }
+}
@@ -2,21 +2,24 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use super::{
-    AccountAddressView, AnnotatedMoveStructView, AnnotatedMoveValueView, BytesView, StrView,
-    StructTagView, TypeTagView,
+    AccountAddressView, AnnotatedMoveStructView, AnnotatedMoveValueView, BytesView, H256View,
+    QueryOptions, StrView, StructTagView, TypeTagView,
 };
 use anyhow::Result;
 use move_core_types::account_address::AccountAddress;
 use move_core_types::effects::Op;
+use moveos_types::h256::H256;
 use moveos_types::state::{AnnotatedKeyState, KeyState, TableChangeSet};
+use moveos_types::state_proof::{SparseMerkleProof, StateProof, TableObjectProof};
 use moveos_types::state_resolver::StateKV;
 use moveos_types::{
     moveos_std::object_id::ObjectID,
     state::{AnnotatedState, State, StateChangeSet, TableChange, TableTypeInfo},
 };
 use rooch_types::indexer::state::{
-    GlobalStateFilter, IndexerGlobalState, IndexerStateChangeSet, IndexerTableChangeSet,
-    IndexerTableState, StateSyncFilter, TableStateFilter,
+    GlobalStateFilter, IndexerGlobalState, IndexerObjectOwnershipChange, IndexerObjectStateChange,
+    IndexerStateChangeSet, IndexerTableChangeSet, IndexerTableState, ObjectStateFilter,
+    StateSyncFilter, TableStateFilter,
 };
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
@@ -311,6 +314,75 @@ impl From<IndexerStateChangeSet> for IndexerStateChangeSetView {
     }
 }
 
+/// An owner transition of an object
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+pub struct IndexerObjectOwnershipChangeView {
+    pub object_id: ObjectID,
+    pub tx_order: u64,
+    /// `None` if the object is created by the transaction
+    pub old_owner: Option<AccountAddressView>,
+    /// `None` if the object is removed by the transaction
+    pub new_owner: Option<AccountAddressView>,
+    /// The seconds when the change is indexed, `None` if the change is recorded before the ownership history is indexed
+    pub created_at: Option<u64>,
+}
+
+impl From<IndexerObjectOwnershipChange> for IndexerObjectOwnershipChangeView {
+    fn from(change: IndexerObjectOwnershipChange) -> Self {
+        IndexerObjectOwnershipChangeView {
+            object_id: change.object_id,
+            tx_order: change.tx_order,
+            old_owner: change.old_owner.map(Into::into),
+            new_owner: change.new_owner.map(Into::into),
+            created_at: change.created_at,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+pub struct IndexerObjectStateChangeView {
+    pub tx_order: u64,
+    pub tx_hash: H256View,
+    pub object_id: ObjectID,
+    /// The T struct tag of the object value, `None` if the object is deleted
+    pub object_type: Option<StructTagView>,
+    pub change: OpView<StateView>,
+    pub created_at: u64,
+}
+
+impl From<IndexerObjectStateChange> for IndexerObjectStateChangeView {
+    fn from(change: IndexerObjectStateChange) -> Self {
+        IndexerObjectStateChangeView {
+            tx_order: change.tx_order,
+            tx_hash: change.tx_hash.into(),
+            object_id: change.object_id,
+            object_type: change.object_type.map(Into::into),
+            change: change.op.into(),
+            created_at: change.created_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ObjectStateFilterView {
+    /// Subscribe to the changes of the object.
+    ObjectId(ObjectID),
+    /// Subscribe to the changes of the objects with the value type, the deleted objects are not included.
+    ObjectType(StructTagView),
+}
+
+impl From<ObjectStateFilterView> for ObjectStateFilter {
+    fn from(filter: ObjectStateFilterView) -> Self {
+        match filter {
+            ObjectStateFilterView::ObjectId(object_id) => ObjectStateFilter::ObjectId(object_id),
+            ObjectStateFilterView::ObjectType(object_type) => {
+                ObjectStateFilter::ObjectType(object_type.into())
+            }
+        }
+    }
+}
+
 #[derive(Default, Clone, Debug, Serialize, Deserialize, JsonSchema)]
 pub struct TableChangeSetView {
     pub new_tables: BTreeSet<ObjectID>,
@@ -390,7 +462,9 @@ pub struct IndexerGlobalStateView {
     pub object_id: ObjectID,
     pub owner: AccountAddressView,
     pub flag: u8,
-    pub value: AnnotatedMoveStructView,
+    /// The decoded value of the object, omitted if the `QueryOptions.decode` is false
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<AnnotatedMoveStructView>,
     pub object_type: StructTagView,
     pub state_root: AccountAddressView,
     pub size: u64,
@@ -404,7 +478,21 @@ impl IndexerGlobalStateView {
     pub fn try_new_from_global_state(
         state: IndexerGlobalState,
     ) -> Result<IndexerGlobalStateView, anyhow::Error> {
-        let value: AnnotatedMoveStructView = serde_json::from_str(state.value.as_str())?;
+        Self::try_new_from_global_state_with_options(state, &QueryOptions::default())
+    }
+
+    /// The decoded value is only parsed from the indexed json if the `QueryOptions.decode` is true
+    pub fn try_new_from_global_state_with_options(
+        state: IndexerGlobalState,
+        options: &QueryOptions,
+    ) -> Result<IndexerGlobalStateView, anyhow::Error> {
+        let value = if options.decode {
+            Some(serde_json::from_str::<AnnotatedMoveStructView>(
+                state.value.as_str(),
+            )?)
+        } else {
+            None
+        };
         let global_state_view = IndexerGlobalStateView {
             object_id: state.object_id,
             owner: state.owner.into(),
@@ -437,6 +525,8 @@ pub enum GlobalStateFilterView {
     /// Query by object id.
     ObjectId(ObjectID),
     /// Query by multi chain address
+    /// The Bitcoin address can be a legacy, segwit (bech32) or taproot (bech32m) address,
+    /// and must be valid for the Bitcoin network of the node.
     MultiChainAddress { multichain_id: u64, address: String },
 }
 
@@ -515,3 +605,109 @@ impl From<TableStateFilterView> for TableStateFilter {
         }
     }
 }
+
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
+pub struct SparseMerkleProofView {
+    /// The leaf `(key hash, value hash)` of the proof, `None` if the subtree is empty
+    pub leaf: Option<(H256View, H256View)>,
+    /// The siblings from the bottom level to the root level
+    pub siblings: Vec<H256View>,
+}
+
+impl From<SparseMerkleProof> for SparseMerkleProofView {
+    fn from(proof: SparseMerkleProof) -> Self {
+        Self {
+            leaf: proof.leaf.map(|(key, value)| (key.into(), value.into())),
+            siblings: proof.siblings.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl From<SparseMerkleProofView> for SparseMerkleProof {
+    fn from(proof: SparseMerkleProofView) -> Self {
+        Self::new(
+            proof.leaf.map(|(key, value)| (key.0, value.0)),
+            proof
+                .siblings
+                .into_iter()
+                .map(|sibling| sibling.0)
+                .collect(),
+        )
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
+pub struct TableObjectProofView {
+    /// The table object state, `None` if the table object does not exist
+    pub object: Option<StateView>,
+    /// The proof of the table object in the global state tree
+    pub proof: SparseMerkleProofView,
+}
+
+impl From<TableObjectProof> for TableObjectProofView {
+    fn from(table_object: TableObjectProof) -> Self {
+        Self {
+            object: table_object.object.map(Into::into),
+            proof: table_object.proof.into(),
+        }
+    }
+}
+
+impl From<TableObjectProofView> for TableObjectProof {
+    fn from(table_object: TableObjectProofView) -> Self {
+        Self {
+            object: table_object.object.map(Into::into),
+            proof: table_object.proof.into(),
+        }
+    }
+}
+
+/// The state of a key with the Merkle proof against the global state root
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
+pub struct StateProofView {
+    /// The global state root of the proof
+    pub state_root: H256View,
+    /// The table handle of the key
+    pub handle: ObjectID,
+    pub key: SimpleKeyStateView,
+    /// The state of the key, `None` if the key does not exist
+    pub state: Option<StateView>,
+    /// The proof of the table object, `None` if the key is in the global object storage
+    pub table_object: Option<TableObjectProofView>,
+    /// The proof of the key in the table state tree
+    pub proof: SparseMerkleProofView,
+}
+
+impl StateProofView {
+    pub fn new(
+        state_root: H256,
+        handle: ObjectID,
+        key: KeyState,
+        state: Option<State>,
+        proof: StateProof,
+    ) -> Self {
+        Self {
+            state_root: state_root.into(),
+            handle,
+            key: key.into(),
+            state: state.map(Into::into),
+            table_object: proof.table_object.map(Into::into),
+            proof: proof.proof.into(),
+        }
+    }
+
+    /// Verify the state and proof with a trusted global state root,
+    /// so light clients can check the state without trusting the node.
+    pub fn verify(&self, trusted_state_root: H256) -> Result<()> {
+        let proof = StateProof {
+            table_object: self.table_object.clone().map(Into::into),
+            proof: self.proof.clone().into(),
+        };
+        proof.verify(
+            trusted_state_root,
+            self.handle,
+            self.key.clone().into(),
+            self.state.clone().map(Into::into),
+        )
+    }
+}
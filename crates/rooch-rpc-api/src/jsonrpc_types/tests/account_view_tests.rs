@@ -0,0 +1,112 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::jsonrpc_types::account_view::{
+    AddressMappingView, BalanceInfoView, SessionKeyView, StorageUsageView,
+};
+use crate::jsonrpc_types::{CoinInfoView, StrView};
+use move_core_types::account_address::AccountAddress;
+use move_core_types::language_storage::StructTag;
+use move_core_types::u256::U256;
+use rooch_types::address::{
+    BitcoinAddress, EthereumAddress, MultiChainAddress, RoochSupportedAddress,
+};
+use rooch_types::bitcoin::network::Network;
+use rooch_types::framework::session_key::{SessionKey, SessionScope};
+use rooch_types::indexer::account::{IndexerAddressMapping, IndexerStorageUsage};
+use rooch_types::multichain_id::RoochMultiChainID;
+use std::str::FromStr;
+
+fn balance_info(decimals: u8, balance: u64) -> BalanceInfoView {
+    let coin_info = CoinInfoView {
+        coin_type: StrView(StructTag::from_str("0x3::gas_coin::GasCoin").unwrap()),
+        name: "Rooch Gas Coin".to_string(),
+        symbol: "RGC".to_string(),
+        icon_url: None,
+        decimals,
+        supply: StrView(U256::zero()),
+    };
+    BalanceInfoView::new(coin_info, U256::from(balance))
+}
+
+#[test]
+fn test_balance_show() {
+    assert_eq!(balance_info(0, 12345).get_balance_show(), "12345");
+    assert_eq!(balance_info(5, 12345000).get_balance_show(), "123.45");
+    assert_eq!(balance_info(8, 100000000).get_balance_show(), "1");
+    assert_eq!(balance_info(8, 1).get_balance_show(), "0.00000001");
+    assert_eq!(balance_info(8, 0).get_balance_show(), "0");
+}
+
+#[test]
+fn test_address_mapping_view() {
+    let rooch_address = AccountAddress::random();
+    let bitcoin_network = Network::NetworkRegtest.to_num();
+    let mapping = |multichain_address: MultiChainAddress| IndexerAddressMapping {
+        multichain_address,
+        rooch_address,
+        tx_order: 1,
+        created_at: 0,
+    };
+
+    let btc_address = MultiChainAddress::from(BitcoinAddress::random());
+    let view = AddressMappingView::try_new(mapping(btc_address.clone()), bitcoin_network).unwrap();
+    assert_eq!(
+        view.multichain_id,
+        RoochMultiChainID::Bitcoin.multichain_id().id()
+    );
+    assert_eq!(view.rooch_address.0, rooch_address);
+    // The Bitcoin address is formatted for the network, so it can be resolved again
+    assert_eq!(
+        MultiChainAddress::try_from_str_with_bitcoin_network(
+            RoochMultiChainID::Bitcoin,
+            view.multichain_address.as_str(),
+            bitcoin_network,
+        )
+        .unwrap(),
+        btc_address
+    );
+
+    let eth_address = MultiChainAddress::from(EthereumAddress::random());
+    let view = AddressMappingView::try_new(mapping(eth_address.clone()), bitcoin_network).unwrap();
+    assert_eq!(view.multichain_address, eth_address.to_original_string());
+}
+
+#[test]
+fn test_storage_usage_view() {
+    let usage = IndexerStorageUsage {
+        address: AccountAddress::random(),
+        object_count: 2,
+        object_bytes: 150,
+        table_item_count: 3,
+        table_item_bytes: 30,
+    };
+    let view = StorageUsageView::from(usage.clone());
+    assert_eq!(view.address.0, usage.address);
+    assert_eq!(view.object_count.0, 2);
+    assert_eq!(view.table_item_bytes.0, 30);
+    assert_eq!(view.total_bytes.0, 180);
+}
+
+#[test]
+fn test_session_key_view() {
+    let session_key = SessionKey {
+        authentication_key: vec![1, 2, 3],
+        scopes: vec![SessionScope::new(AccountAddress::ONE, "*", "*")],
+        create_time: 10,
+        last_active_time: 20,
+        max_inactive_interval: 3600,
+        max_gas_budget: 1000,
+        gas_used: 300,
+    };
+    let view = SessionKeyView::from(session_key.clone());
+    assert_eq!(view.authentication_key.to_string(), "0x010203");
+    assert_eq!(view.scopes, vec![session_key.scopes[0].to_string()]);
+    assert_eq!(view.remaining_gas_budget, Some(StrView(700)));
+
+    let unlimited = SessionKeyView::from(SessionKey {
+        max_gas_budget: 0,
+        ..session_key
+    });
+    assert_eq!(unlimited.remaining_gas_budget, None);
+}
@@ -0,0 +1,31 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::jsonrpc_types::event_view::EventFilterView;
+use crate::jsonrpc_types::transaction_view::TransactionFilterView;
+use crate::jsonrpc_types::StrView;
+use move_core_types::account_address::AccountAddress;
+use moveos_types::h256::H256;
+
+#[test]
+fn test_filter_complexity() {
+    let sender = EventFilterView::Sender(StrView(AccountAddress::random()));
+    assert_eq!(sender.complexity(), 1);
+    let time_range = EventFilterView::TimeRange {
+        start_time: 0,
+        end_time: 1,
+    };
+    let nested = EventFilterView::All(vec![
+        sender.clone(),
+        EventFilterView::All(vec![sender, time_range]),
+    ]);
+    assert_eq!(nested.complexity(), 5);
+
+    let tx_hashes = TransactionFilterView::TxHashes(vec![StrView(H256::random()); 3]);
+    assert_eq!(tx_hashes.complexity(), 3);
+    let tx_order_range = TransactionFilterView::TxOrderRange {
+        from_order: 0,
+        to_order: 10,
+    };
+    assert_eq!(tx_order_range.complexity(), 1);
+}
@@ -1,4 +1,10 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
 
+mod account_view_tests;
+mod event_view_tests;
+mod module_abi_view_tests;
+mod module_view_tests;
+mod rpc_error_tests;
+mod rpc_options_tests;
 mod str_view_tests;
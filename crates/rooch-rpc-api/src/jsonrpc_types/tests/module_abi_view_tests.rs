@@ -0,0 +1,32 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::jsonrpc_types::format_move_type;
+use move_binary_format::normalized::Type;
+use move_core_types::{account_address::AccountAddress, identifier::Identifier};
+
+#[test]
+fn test_format_move_type() {
+    let context = Type::MutableReference(Box::new(Type::Struct {
+        address: AccountAddress::TWO,
+        module: Identifier::new("context").unwrap(),
+        name: Identifier::new("Context").unwrap(),
+        type_arguments: vec![],
+    }));
+    assert_eq!(
+        format_move_type(&context),
+        "&mut 0x2::context::Context".to_owned()
+    );
+
+    let coins = Type::Vector(Box::new(Type::Struct {
+        address: AccountAddress::from_hex_literal("0x3").unwrap(),
+        module: Identifier::new("coin").unwrap(),
+        name: Identifier::new("Coin").unwrap(),
+        type_arguments: vec![Type::TypeParameter(0)],
+    }));
+    assert_eq!(format_move_type(&coins), "vector<0x3::coin::Coin<T0>>");
+    assert_eq!(
+        format_move_type(&Type::Reference(Box::new(Type::Signer))),
+        "&signer"
+    );
+}
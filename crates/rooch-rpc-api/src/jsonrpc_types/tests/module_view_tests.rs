@@ -0,0 +1,56 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::jsonrpc_types::module_view::{IndexerModuleView, ModuleFilterView};
+use move_core_types::account_address::AccountAddress;
+use moveos_types::h256::H256;
+use rooch_types::indexer::module::{IndexerModule, ModuleFilter};
+
+#[test]
+fn test_module_filter_view() {
+    let filter: ModuleFilterView = serde_json::from_str(r#"{"publisher":"0x42"}"#).unwrap();
+    match ModuleFilter::from(filter) {
+        ModuleFilter::Publisher(publisher) => {
+            assert_eq!(publisher, AccountAddress::from_hex_literal("0x42").unwrap())
+        }
+        filter => panic!("Unexpected filter {:?}", filter),
+    }
+
+    let filter: ModuleFilterView =
+        serde_json::from_str(r#"{"timeRange":{"start_time":"100","end_time":"200"}}"#).unwrap();
+    match ModuleFilter::from(filter) {
+        ModuleFilter::TimeRange {
+            start_time,
+            end_time,
+        } => {
+            assert_eq!(start_time, 100);
+            assert_eq!(end_time, 200);
+        }
+        filter => panic!("Unexpected filter {:?}", filter),
+    }
+}
+
+#[test]
+fn test_indexer_module_view() {
+    let module = IndexerModule {
+        address: AccountAddress::from_hex_literal("0x42").unwrap(),
+        name: "counter".to_string(),
+        bytecode_hash: H256::random(),
+        source_digest: None,
+        tx_order: 10,
+        state_index: 2,
+        created_at: 1710000000,
+        updated_at: 1710000100,
+    };
+    let view = IndexerModuleView::from(module.clone());
+    let json = serde_json::to_value(&view).unwrap();
+    assert_eq!(json["name"], "counter");
+    assert_eq!(json["tx_order"], "10");
+    assert_eq!(json["state_index"], "2");
+    assert_eq!(json["created_at"], "1710000000");
+    assert!(json["source_digest"].is_null());
+    assert_eq!(
+        json["bytecode_hash"],
+        format!("{:?}", module.bytecode_hash).as_str()
+    );
+}
@@ -0,0 +1,56 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::jsonrpc_types::{RpcError, RpcErrorCode, RpcErrorData};
+use jsonrpsee::core::Error as JsonRpcError;
+use move_core_types::vm_status::{AbortLocation, StatusCode, VMStatus};
+use moveos_types::module_binding::ModuleBinding;
+use rooch_types::framework::transaction_validator::TransactionValidator;
+
+#[test]
+fn test_rpc_error_code_from_vm_status() {
+    let location = AbortLocation::Module(TransactionValidator::module_id());
+    let error: RpcError = anyhow::Error::from(VMStatus::MoveAbort(location.clone(), 1001)).into();
+    assert_eq!(error.code(), RpcErrorCode::SequenceNumberTooOld);
+    let error: RpcError = anyhow::Error::from(VMStatus::MoveAbort(location, 1004)).into();
+    assert_eq!(error.code(), RpcErrorCode::InsufficientGas);
+
+    let vm_status = VMStatus::Error {
+        status_code: StatusCode::OUT_OF_GAS,
+        sub_status: None,
+        message: None,
+    };
+    let error: RpcError = anyhow::Error::from(vm_status).into();
+    assert_eq!(error.code(), RpcErrorCode::InsufficientGas);
+
+    let error: RpcError = anyhow::anyhow!("unexpected").into();
+    assert_eq!(error.code(), RpcErrorCode::InternalError);
+    assert!(error.data.vm_status.is_none());
+}
+
+#[test]
+fn test_rpc_error_data_roundtrip() {
+    let location = AbortLocation::Module(TransactionValidator::module_id());
+    let error: RpcError = anyhow::Error::from(VMStatus::MoveAbort(location, 10)).into();
+    let json_rpc_error: JsonRpcError = error.into();
+    let data = RpcErrorData::from_json_rpc_error(&json_rpc_error).unwrap();
+    assert_eq!(data.code, RpcErrorCode::MoveAbort);
+    assert!(data.vm_status.is_some());
+
+    let json = serde_json::to_value(RpcErrorCode::SequenceNumberTooOld).unwrap();
+    assert_eq!(json, serde_json::json!("SEQUENCE_NUMBER_TOO_OLD"));
+}
+
+#[test]
+fn test_query_limit_exceeded_error() {
+    let error = RpcError::query_limit_exceeded("page size", 500, 200);
+    assert_eq!(error.code(), RpcErrorCode::QueryLimitExceeded);
+    assert_eq!(error.message, "The page size 500 exceeds the max 200");
+    let json_rpc_error: JsonRpcError = error.into();
+    let data = RpcErrorData::from_json_rpc_error(&json_rpc_error).unwrap();
+    assert_eq!(data.code, RpcErrorCode::QueryLimitExceeded);
+    assert_eq!(
+        serde_json::to_value(data.code).unwrap(),
+        serde_json::json!("QUERY_LIMIT_EXCEEDED")
+    );
+}
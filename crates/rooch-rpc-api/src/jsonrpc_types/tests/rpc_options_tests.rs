@@ -0,0 +1,31 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::jsonrpc_types::{QueryOptions, StateOptions};
+
+#[test]
+fn test_state_options_serde() {
+    let options: StateOptions = serde_json::from_str(r#"{"decode":true}"#).unwrap();
+    assert_eq!(options, StateOptions::new().decode(true));
+
+    let options: StateOptions = serde_json::from_str(r#"{"txOrder":"10"}"#).unwrap();
+    assert_eq!(options, StateOptions::new().tx_order(10));
+    assert!(options.state_root.is_none());
+
+    let json = serde_json::to_string(&StateOptions::new().tx_order(10)).unwrap();
+    let decoded: StateOptions = serde_json::from_str(&json).unwrap();
+    assert_eq!(decoded.tx_order.map(|tx_order| tx_order.0), Some(10));
+}
+
+#[test]
+fn test_query_options_serde() {
+    // The omitted fields keep the default, so an empty options is the same as no options
+    let options: QueryOptions = serde_json::from_str("{}").unwrap();
+    assert_eq!(options, QueryOptions::default());
+    assert!(options.decode && options.show_raw && !options.show_events);
+
+    let options: QueryOptions =
+        serde_json::from_str(r#"{"decode":false,"showEvents":true}"#).unwrap();
+    assert_eq!(options, QueryOptions::new().decode(false).show_events(true));
+    assert!(options.show_raw);
+}
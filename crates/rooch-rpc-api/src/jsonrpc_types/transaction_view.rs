@@ -2,8 +2,8 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::jsonrpc_types::{
-    AccountAddressView, H256View, TransactionExecutionInfoView, TransactionSequenceInfoView,
-    TransactionView,
+    AccountAddressView, H256View, IndexerEventView, PageView, QueryOptions, StrView,
+    TransactionExecutionInfoView, TransactionSequenceInfoView, TransactionView,
 };
 use rooch_types::indexer::transaction_filter::TransactionFilter;
 use rooch_types::transaction::TransactionWithInfo;
@@ -15,6 +15,9 @@ pub struct TransactionWithInfoView {
     pub transaction: TransactionView,
     pub sequence_info: TransactionSequenceInfoView,
     pub execution_info: TransactionExecutionInfoView,
+    /// The events emitted by the transaction, only returned if the `QueryOptions.show_events` is true
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub events: Option<Vec<IndexerEventView>>,
 }
 
 impl From<TransactionWithInfo> for TransactionWithInfoView {
@@ -23,10 +26,45 @@ impl From<TransactionWithInfo> for TransactionWithInfoView {
             transaction: tx.transaction.into(),
             sequence_info: tx.sequence_info.into(),
             execution_info: tx.execution_info.into(),
+            events: None,
         }
     }
 }
 
+impl TransactionWithInfoView {
+    /// Omit the fields not selected by the options, the events are set by the caller if `show_events` is true
+    pub fn with_options(mut self, options: &QueryOptions) -> Self {
+        if !options.decode {
+            self.transaction.action = None;
+        }
+        if !options.show_raw {
+            self.transaction.raw = None;
+        }
+        self
+    }
+}
+
+/// A transaction which is executed but not proposed in a block yet
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct PendingTransactionView {
+    pub tx_hash: H256View,
+    pub tx_order: StrView<u64>,
+    /// The multi chain address of the sender, such as `rooch:0x...`
+    pub sender: String,
+    /// The price of one gas unit when the transaction is queried
+    pub gas_price: u64,
+    pub gas_used: u64,
+    /// The milliseconds when the transaction is inserted into the pending queue
+    pub inserted_at: u64,
+    /// The seconds since the unix epoch after which the transaction is rejected, `None` if it never expires
+    pub expiration_timestamp_secs: Option<u64>,
+    /// The seconds left before the transaction expires when it is queried, 0 if it is expired
+    pub time_to_expiry_secs: Option<u64>,
+}
+
+/// The cursor of the pending transactions page is the tx order
+pub type PendingTransactionPageView = PageView<PendingTransactionView, StrView<u64>>;
+
 #[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum TransactionFilterView {
@@ -53,6 +91,16 @@ pub enum TransactionFilterView {
     },
 }
 
+impl TransactionFilterView {
+    /// The complexity of the filter for the query limits, it is the number of the tx hashes of `TxHashes`
+    pub fn complexity(&self) -> usize {
+        match self {
+            TransactionFilterView::TxHashes(tx_hashes) => tx_hashes.len(),
+            _ => 1,
+        }
+    }
+}
+
 impl From<TransactionFilterView> for TransactionFilter {
     fn from(event_filter: TransactionFilterView) -> Self {
         match event_filter {
@@ -9,6 +9,7 @@ use rooch_types::address::RoochAddress;
 use rooch_types::chain_id::RoochChainID;
 use serde::Deserialize;
 use serde::Serialize;
+use std::collections::BTreeMap;
 use std::fmt::{Display, Formatter, Write};
 use std::path::PathBuf;
 
@@ -22,6 +23,13 @@ pub struct ClientConfig {
     pub active_address: Option<RoochAddress>,
     pub envs: Vec<Env>,
     pub active_env: Option<String>,
+    /// The auth validator id used for signing the transactions of the account,
+    /// the native validator is used if the account is absent
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub auth_validators: BTreeMap<RoochAddress, u64>,
+    /// The named addresses of the address book, the names are accepted by the commands anywhere an address is expected
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub address_book: BTreeMap<String, RoochAddress>,
 }
 
 impl ClientConfig {
@@ -31,6 +39,8 @@ impl ClientConfig {
             active_address: None,
             envs: vec![],
             active_env: None,
+            auth_validators: BTreeMap::new(),
+            address_book: BTreeMap::new(),
         }
     }
 
@@ -42,6 +52,14 @@ impl ClientConfig {
         }
     }
 
+    pub fn get_env_mut(&mut self, alias: &Option<String>) -> Option<&mut Env> {
+        if let Some(alias) = alias {
+            self.envs.iter_mut().find(|env| &env.alias == alias)
+        } else {
+            self.envs.first_mut()
+        }
+    }
+
     pub fn get_active_env(&self) -> Result<&Env, anyhow::Error> {
         self.get_env(&self.active_env).ok_or_else(|| {
             anyhow!(
@@ -59,17 +77,28 @@ impl ClientConfig {
         if let Some(update_env) = find_env {
             update_env.rpc = env.rpc;
             update_env.ws = env.ws;
+            update_env.active_address = env.active_address;
+            update_env.max_gas_amount = env.max_gas_amount;
         } else {
             self.envs.push(env)
         }
     }
 }
 
+/// The environment profile, the commands connect to the RPC endpoint of the active environment,
+/// or the environment selected by `--profile`.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Env {
     pub alias: String,
     pub rpc: String,
     pub ws: Option<String>,
+    /// The active account of the environment, overrides the global active address if present
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub active_address: Option<RoochAddress>,
+    /// The max gas amount of the transactions sent to the environment,
+    /// the gas amount is estimated by the dry run if absent
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_gas_amount: Option<u64>,
 }
 
 impl Env {
@@ -96,6 +125,8 @@ impl Env {
             alias: RoochChainID::DEV.chain_name().to_lowercase(),
             rpc: ROOCH_DEV_NET_URL.into(),
             ws: None,
+            active_address: None,
+            max_gas_amount: None,
         }
     }
 
@@ -104,6 +135,8 @@ impl Env {
             alias: RoochChainID::TEST.chain_name().to_lowercase(),
             rpc: ROOCH_TEST_NET_URL.into(),
             ws: None,
+            active_address: None,
+            max_gas_amount: None,
         }
     }
 }
@@ -114,6 +147,8 @@ impl Default for Env {
             alias: RoochChainID::LOCAL.chain_name().to_lowercase(),
             rpc: ServerConfig::default().url(false),
             ws: None,
+            active_address: None,
+            max_gas_amount: None,
         }
     }
 }
@@ -127,6 +162,14 @@ impl Display for Env {
             writeln!(writer)?;
             write!(writer, "Websocket URL: {ws}")?;
         }
+        if let Some(active_address) = &self.active_address {
+            writeln!(writer)?;
+            write!(writer, "Active address: {active_address}")?;
+        }
+        if let Some(max_gas_amount) = &self.max_gas_amount {
+            writeln!(writer)?;
+            write!(writer, "Max gas amount: {max_gas_amount}")?;
+        }
         write!(f, "{}", writer)
     }
 }
@@ -4,7 +4,9 @@
 use anyhow::Result;
 use eth_client::EthRpcClient;
 use jsonrpsee::core::client::ClientT;
+use jsonrpsee::core::client::Subscription;
 use jsonrpsee::http_client::{HttpClient, HttpClientBuilder};
+use jsonrpsee::ws_client::{WsClient, WsClientBuilder};
 use move_core_types::language_storage::ModuleId;
 use move_core_types::metadata::Metadata;
 use move_core_types::resolver::ModuleResolver;
@@ -16,11 +18,16 @@ use moveos_types::{
     moveos_std::tx_context::TxContext, transaction::FunctionCall,
 };
 use rooch_client::RoochRpcClient;
+use rooch_rpc_api::api::subscription_api::SubscriptionAPIClient;
+use rooch_rpc_api::jsonrpc_types::{IndexerObjectStateChangeView, ObjectStateFilterView};
 use std::sync::Arc;
 use std::time::Duration;
+use tokio::sync::OnceCell;
 
 pub mod client_config;
 pub mod eth_client;
+pub mod nonce_manager;
+pub mod remote_state_resolver;
 pub mod rooch_client;
 pub mod wallet_context;
 
@@ -59,6 +66,9 @@ impl ClientBuilder {
 
         Ok(Client {
             http: http_client.clone(),
+            ws_url: self.ws_url,
+            ws: Arc::new(OnceCell::new()),
+            request_timeout: self.request_timeout,
             rooch: RoochRpcClient::new(http_client.clone()),
             eth: EthRpcClient::new(http_client),
         })
@@ -78,6 +88,10 @@ impl Default for ClientBuilder {
 #[derive(Clone)]
 pub struct Client {
     http: Arc<HttpClient>,
+    ws_url: Option<String>,
+    /// The WebSocket client is connected on the first subscription, it is shared by the clones
+    ws: Arc<OnceCell<WsClient>>,
+    request_timeout: Duration,
     pub rooch: RoochRpcClient,
     pub eth: EthRpcClient,
 }
@@ -96,6 +110,35 @@ impl Client {
     ) -> Result<serde_json::Value> {
         Ok(self.http.request(method, params).await?)
     }
+
+    /// Whether the WebSocket URL is configured, the subscriptions are only available over the WebSocket
+    pub fn has_ws(&self) -> bool {
+        self.ws_url.is_some()
+    }
+
+    async fn ws(&self) -> Result<&WsClient> {
+        let ws_url = self
+            .ws_url
+            .as_ref()
+            .ok_or_else(|| anyhow::anyhow!("The WebSocket URL of the env is not configured"))?;
+        self.ws
+            .get_or_try_init(|| async {
+                WsClientBuilder::default()
+                    .request_timeout(self.request_timeout)
+                    .build(ws_url)
+                    .await
+                    .map_err(|e| anyhow::anyhow!(e))
+            })
+            .await
+    }
+
+    /// Subscribe to the changes of the objects which match the filter
+    pub async fn subscribe_object(
+        &self,
+        filter: ObjectStateFilterView,
+    ) -> Result<Subscription<IndexerObjectStateChangeView>> {
+        Ok(self.ws().await?.subscribe_object(filter).await?)
+    }
 }
 
 impl MoveFunctionCaller for Client {
@@ -0,0 +1,105 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use moveos_types::h256::H256;
+use rooch_config::config::Config;
+use rooch_types::address::RoochAddress;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// A transaction sent by the wallet whose result is not received yet, such as the submission is timeout
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct InFlightTransaction {
+    pub tx_hash: H256,
+    pub max_gas_amount: u64,
+}
+
+/// The local nonce manager of the wallet, it tracks the in-flight transactions of the accounts,
+/// so the next transaction does not reuse the sequence number of an in-flight transaction.
+/// The in-flight transactions are pruned once the on-chain sequence number passes them.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct NonceManager {
+    /// address -> sequence number -> in-flight transaction
+    #[serde(default)]
+    pub in_flight: BTreeMap<RoochAddress, BTreeMap<u64, InFlightTransaction>>,
+}
+
+impl Config for NonceManager {}
+
+impl NonceManager {
+    /// Remove the in-flight transactions of the account which are confirmed by the on-chain sequence number
+    pub fn prune(&mut self, address: &RoochAddress, onchain_sequence_number: u64) {
+        if let Some(transactions) = self.in_flight.get_mut(address) {
+            *transactions = transactions.split_off(&onchain_sequence_number);
+            if transactions.is_empty() {
+                self.in_flight.remove(address);
+            }
+        }
+    }
+
+    /// The sequence number of the next transaction, after the on-chain sequence number and the in-flight transactions
+    pub fn next_sequence_number(
+        &mut self,
+        address: &RoochAddress,
+        onchain_sequence_number: u64,
+    ) -> u64 {
+        self.prune(address, onchain_sequence_number);
+        self.in_flight
+            .get(address)
+            .and_then(|transactions| transactions.keys().next_back())
+            .map_or(onchain_sequence_number, |sequence_number| {
+                sequence_number + 1
+            })
+    }
+
+    pub fn track(
+        &mut self,
+        address: RoochAddress,
+        sequence_number: u64,
+        transaction: InFlightTransaction,
+    ) {
+        self.in_flight
+            .entry(address)
+            .or_default()
+            .insert(sequence_number, transaction);
+    }
+
+    pub fn untrack(&mut self, address: &RoochAddress, sequence_number: u64) {
+        if let Some(transactions) = self.in_flight.get_mut(address) {
+            transactions.remove(&sequence_number);
+            if transactions.is_empty() {
+                self.in_flight.remove(address);
+            }
+        }
+    }
+
+    pub fn get(
+        &self,
+        address: &RoochAddress,
+        sequence_number: u64,
+    ) -> Option<&InFlightTransaction> {
+        self.in_flight
+            .get(address)
+            .and_then(|transactions| transactions.get(&sequence_number))
+    }
+
+    pub fn in_flight_transactions(
+        &self,
+        address: &RoochAddress,
+    ) -> Vec<(u64, InFlightTransaction)> {
+        self.in_flight
+            .get(address)
+            .map(|transactions| {
+                transactions
+                    .iter()
+                    .map(|(sequence_number, tx)| (*sequence_number, tx.clone()))
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Drop all the in-flight transactions of the account
+    pub fn reset(&mut self, address: &RoochAddress) {
+        self.in_flight.remove(address);
+    }
+}
@@ -0,0 +1,69 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::Client;
+use anyhow::Result;
+use moveos_types::access_path::AccessPath;
+use moveos_types::moveos_std::object_id::ObjectID;
+use moveos_types::state::{KeyState, State};
+use moveos_types::state_resolver::{StateKV, StateResolver};
+use std::collections::HashMap;
+use std::sync::RwLock;
+
+/// A StateResolver forked from the remote state, the states are lazily fetched over RPC and cached,
+/// so a transaction can be simulated locally against the latest state of the remote chain.
+pub struct RemoteStateResolver {
+    client: Client,
+    cache: RwLock<HashMap<(ObjectID, KeyState), Option<State>>>,
+}
+
+impl RemoteStateResolver {
+    pub fn new(client: Client) -> Self {
+        Self {
+            client,
+            cache: RwLock::new(HashMap::new()),
+        }
+    }
+}
+
+impl StateResolver for RemoteStateResolver {
+    fn resolve_table_item(
+        &self,
+        handle: &ObjectID,
+        key: &KeyState,
+    ) -> Result<Option<State>, anyhow::Error> {
+        let cache_key = (*handle, key.clone());
+        if let Some(state) = self.cache.read().unwrap().get(&cache_key) {
+            return Ok(state.clone());
+        }
+
+        let state = futures::executor::block_on(
+            self.client
+                .rooch
+                .get_states(AccessPath::table(*handle, vec![key.clone()])),
+        )?
+        .pop()
+        .flatten()
+        .map(State::from);
+        self.cache.write().unwrap().insert(cache_key, state.clone());
+        Ok(state)
+    }
+
+    fn list_table_items(
+        &self,
+        handle: &ObjectID,
+        cursor: Option<KeyState>,
+        limit: usize,
+    ) -> Result<Vec<StateKV>, anyhow::Error> {
+        let page = futures::executor::block_on(self.client.rooch.list_states(
+            AccessPath::table_without_keys(*handle).into(),
+            cursor.map(|cursor| cursor.to_string()),
+            Some(limit),
+        ))?;
+        Ok(page
+            .data
+            .into_iter()
+            .map(|state_kv| (state_kv.key_state.into(), state_kv.state.into()))
+            .collect())
+    }
+}
@@ -3,23 +3,39 @@
 
 use anyhow::Result;
 use jsonrpsee::http_client::HttpClient;
+use move_core_types::account_address::AccountAddress;
+use move_core_types::language_storage::ModuleId;
 use moveos_types::h256::H256;
 use moveos_types::{
-    access_path::AccessPath,
-    state::{MoveStructType, State},
-    transaction::FunctionCall,
+    access_path::AccessPath, moveos_std::object_id::ObjectID, transaction::FunctionCall,
 };
 use rooch_rpc_api::api::rooch_api::RoochAPIClient;
 use rooch_rpc_api::jsonrpc_types::TransactionWithInfoPageView;
 use rooch_rpc_api::jsonrpc_types::{
-    account_view::BalanceInfoView, transaction_view::TransactionWithInfoView,
+    account_view::{AccountSummaryView, AddressMappingView, BalanceInfoView, StorageUsageView},
+    module_view::ModuleFilterView,
+    transaction_view::{
+        PendingTransactionPageView, TransactionFilterView, TransactionWithInfoView,
+    },
 };
 use rooch_rpc_api::jsonrpc_types::{
     AccessPathView, AccountAddressView, AnnotatedFunctionResultView, BalanceInfoPageView,
-    EventOptions, EventPageView, StateOptions, StatePageView, StructTagView,
+    CoinInfoView, EventOptions, EventPageView, GlobalStateFilterView, IndexerCoinTransferPageView,
+    IndexerGlobalStatePageView, IndexerModulePageView, MoveModuleABIView,
+    ObjectOwnershipChangePageView, QueryOptions, ScheduledTaskPageView, SessionKeyPageView,
+    StateOptions, StatePageView, StructTagView,
+};
+use rooch_rpc_api::jsonrpc_types::{
+    DryRunTransactionResponseView, ExecuteTransactionResponseView, GasEstimateView,
+    GasPriceEstimateView, SendTransactionResultView, StateProofView, StateView,
+};
+use rooch_types::indexer::event_filter::IndexerEventID;
+use rooch_types::transaction::view::SignedViewFunctionCall;
+use rooch_types::{
+    address::RoochAddress,
+    transaction::rooch::{RoochTransaction, RoochTransactionData},
+    transaction::AbstractTransaction,
 };
-use rooch_rpc_api::jsonrpc_types::{ExecuteTransactionResponseView, StateView};
-use rooch_types::{account::Account, address::RoochAddress, transaction::rooch::RoochTransaction};
 use std::sync::Arc;
 
 #[derive(Clone, Debug)]
@@ -40,13 +56,70 @@ impl RoochRpcClient {
     }
 
     pub async fn execute_tx(&self, tx: RoochTransaction) -> Result<ExecuteTransactionResponseView> {
-        let tx_payload = bcs::to_bytes(&tx)?;
+        let tx_payload = tx.encode();
         self.http
             .execute_raw_transaction(tx_payload.into())
             .await
             .map_err(|e| anyhow::anyhow!(e))
     }
 
+    /// Send the transaction without waiting for it to be executed, return the transaction hash
+    pub async fn send_tx(&self, tx: RoochTransaction) -> Result<H256> {
+        let tx_payload = tx.encode();
+        self.http
+            .send_raw_transaction(tx_payload.into())
+            .await
+            .map(Into::into)
+            .map_err(|e| anyhow::anyhow!(e))
+    }
+
+    pub async fn send_tx_batch(
+        &self,
+        txs: Vec<RoochTransaction>,
+    ) -> Result<Vec<SendTransactionResultView>> {
+        let tx_payloads = txs.iter().map(|tx| tx.encode().into()).collect();
+        self.http
+            .send_raw_transaction_batch(tx_payloads)
+            .await
+            .map_err(|e| anyhow::anyhow!(e))
+    }
+
+    pub async fn dry_run_tx(
+        &self,
+        tx: RoochTransaction,
+        gas_profile: bool,
+    ) -> Result<DryRunTransactionResponseView> {
+        let tx_payload = tx.encode();
+        self.http
+            .dry_run_transaction(tx_payload.into(), Some(gas_profile))
+            .await
+            .map_err(|e| anyhow::anyhow!(e))
+    }
+
+    pub async fn estimate_gas_price(&self) -> Result<GasPriceEstimateView> {
+        Ok(self.http.estimate_gas_price().await?)
+    }
+
+    /// Estimate the gas of the unsigned transaction data by dry running it
+    pub async fn estimate_gas(&self, tx_data: &RoochTransactionData) -> Result<GasEstimateView> {
+        let tx_payload = bcs::to_bytes(tx_data)?;
+        self.http
+            .estimate_gas(tx_payload.into())
+            .await
+            .map_err(|e| anyhow::anyhow!(e))
+    }
+
+    pub async fn get_pending_transactions(
+        &self,
+        cursor: Option<u64>,
+        limit: Option<usize>,
+    ) -> Result<PendingTransactionPageView> {
+        Ok(self
+            .http
+            .get_pending_transactions(cursor.map(Into::into), limit.map(Into::into))
+            .await?)
+    }
+
     pub async fn execute_view_function(
         &self,
         function_call: FunctionCall,
@@ -57,10 +130,32 @@ impl RoochRpcClient {
             .map_err(|e| anyhow::anyhow!(e))
     }
 
+    /// Execute the view function with the signer of the call as the sender
+    pub async fn execute_signed_view_function(
+        &self,
+        signed_call: SignedViewFunctionCall,
+    ) -> Result<AnnotatedFunctionResultView> {
+        Ok(self
+            .http
+            .execute_signed_view_function(signed_call.encode().into())
+            .await?)
+    }
+
     pub async fn get_states(&self, access_path: AccessPath) -> Result<Vec<Option<StateView>>> {
         Ok(self.http.get_states(access_path.into(), None).await?)
     }
 
+    pub async fn get_states_with_proof(
+        &self,
+        access_path: AccessPath,
+        state_root: Option<H256>,
+    ) -> Result<Vec<StateProofView>> {
+        Ok(self
+            .http
+            .get_state_with_proof(access_path.into(), state_root.map(Into::into))
+            .await?)
+    }
+
     pub async fn get_decoded_states(
         &self,
         access_path: AccessPath,
@@ -78,35 +173,112 @@ impl RoochRpcClient {
         &self,
         cursor: Option<u64>,
         limit: Option<u64>,
+        query_options: Option<QueryOptions>,
     ) -> Result<TransactionWithInfoPageView> {
         Ok(self
             .http
-            .get_transactions_by_order(cursor.map(Into::into), limit.map(Into::into))
+            .get_transactions_by_order(cursor.map(Into::into), limit.map(Into::into), query_options)
             .await?)
     }
 
     pub async fn get_transactions_by_hash(
         &self,
         tx_hashes: Vec<H256>,
+        query_options: Option<QueryOptions>,
     ) -> Result<Vec<Option<TransactionWithInfoView>>> {
         Ok(self
             .http
-            .get_transactions_by_hash(tx_hashes.iter().map(|hash| (*hash).into()).collect())
+            .get_transactions_by_hash(
+                tx_hashes.iter().map(|hash| (*hash).into()).collect(),
+                query_options,
+            )
+            .await?)
+    }
+
+    pub async fn query_transactions(
+        &self,
+        filter: TransactionFilterView,
+        cursor: Option<u64>,
+        limit: Option<usize>,
+        descending_order: Option<bool>,
+    ) -> Result<TransactionWithInfoPageView> {
+        Ok(self
+            .http
+            .query_transactions(
+                filter,
+                cursor.map(Into::into),
+                limit.map(Into::into),
+                descending_order,
+            )
+            .await?)
+    }
+
+    pub async fn query_global_states(
+        &self,
+        filter: GlobalStateFilterView,
+        cursor: Option<String>,
+        limit: Option<usize>,
+        descending_order: Option<bool>,
+        query_options: Option<QueryOptions>,
+    ) -> Result<IndexerGlobalStatePageView> {
+        Ok(self
+            .http
+            .query_global_states(
+                filter,
+                cursor,
+                limit.map(Into::into),
+                descending_order,
+                query_options,
+            )
+            .await?)
+    }
+
+    pub async fn query_modules(
+        &self,
+        filter: ModuleFilterView,
+        cursor: Option<String>,
+        limit: Option<usize>,
+        descending_order: Option<bool>,
+    ) -> Result<IndexerModulePageView> {
+        Ok(self
+            .http
+            .query_modules(filter, cursor, limit.map(Into::into), descending_order)
             .await?)
     }
 
+    /// Get the deposit and withdraw history of the account coin store of the coin type
+    pub async fn get_transfer_history(
+        &self,
+        account_addr: AccountAddressView,
+        coin_type: StructTagView,
+        cursor: Option<IndexerEventID>,
+        limit: Option<usize>,
+        descending_order: Option<bool>,
+    ) -> Result<IndexerCoinTransferPageView> {
+        Ok(self
+            .http
+            .get_transfer_history(
+                account_addr,
+                coin_type,
+                cursor,
+                limit.map(Into::into),
+                descending_order,
+            )
+            .await?)
+    }
+
+    /// Get the sequence number of the next transaction of the account
     pub async fn get_sequence_number(&self, sender: RoochAddress) -> Result<u64> {
         Ok(self
-            .get_states(AccessPath::resource(sender.into(), Account::struct_tag()))
+            .http
+            .get_sequence_number(AccountAddress::from(sender).into())
             .await?
-            .pop()
-            .flatten()
-            .map(|state_view| {
-                let state = State::from(state_view);
-                state.cast::<Account>()
-            })
-            .transpose()?
-            .map_or(0, |account| account.sequence_number))
+            .0)
+    }
+
+    /// Get the ABI of the published module, None if the module does not exist
+    pub async fn get_module_abi(&self, module_id: ModuleId) -> Result<Option<MoveModuleABIView>> {
+        Ok(self.http.get_module_abi(module_id.into()).await?)
     }
 
     pub async fn get_events_by_event_handle(
@@ -115,6 +287,9 @@ impl RoochRpcClient {
         cursor: Option<u64>,
         limit: Option<u64>,
         event_options: Option<EventOptions>,
+        descending_order: Option<bool>,
+        from_seq: Option<u64>,
+        to_seq: Option<u64>,
     ) -> Result<EventPageView> {
         let s = self
             .http
@@ -123,6 +298,9 @@ impl RoochRpcClient {
                 cursor.map(Into::into),
                 limit.map(Into::into),
                 event_options,
+                descending_order,
+                from_seq.map(Into::into),
+                to_seq.map(Into::into),
             )
             .await?;
         Ok(s)
@@ -157,6 +335,10 @@ impl RoochRpcClient {
             .await?)
     }
 
+    pub async fn get_coin_info(&self, coin_type: StructTagView) -> Result<Option<CoinInfoView>> {
+        Ok(self.http.get_coin_info(coin_type).await?)
+    }
+
     pub async fn get_balance(
         &self,
         account_addr: AccountAddressView,
@@ -176,4 +358,74 @@ impl RoochRpcClient {
             .get_balances(account_addr, cursor, limit.map(Into::into))
             .await?)
     }
+
+    pub async fn get_session_keys(
+        &self,
+        account_addr: AccountAddressView,
+        cursor: Option<String>,
+        limit: Option<usize>,
+    ) -> Result<SessionKeyPageView> {
+        Ok(self
+            .http
+            .get_session_keys(account_addr, cursor, limit.map(Into::into))
+            .await?)
+    }
+
+    pub async fn get_account_summary(
+        &self,
+        account_addr: AccountAddressView,
+    ) -> Result<AccountSummaryView> {
+        Ok(self.http.get_account_summary(account_addr).await?)
+    }
+
+    pub async fn get_storage_usage(
+        &self,
+        account_addr: AccountAddressView,
+    ) -> Result<StorageUsageView> {
+        Ok(self.http.get_storage_usage(account_addr).await?)
+    }
+
+    pub async fn resolve_address(
+        &self,
+        multichain_id: u64,
+        address: String,
+    ) -> Result<Option<AccountAddressView>> {
+        Ok(self.http.resolve_address(multichain_id, address).await?)
+    }
+
+    pub async fn reverse_resolve_address(
+        &self,
+        rooch_address: AccountAddressView,
+    ) -> Result<Vec<AddressMappingView>> {
+        Ok(self.http.reverse_resolve_address(rooch_address).await?)
+    }
+
+    pub async fn get_object_ownership_history(
+        &self,
+        object_id: ObjectID,
+        cursor: Option<u64>,
+        limit: Option<usize>,
+        descending_order: Option<bool>,
+    ) -> Result<ObjectOwnershipChangePageView> {
+        Ok(self
+            .http
+            .get_object_ownership_history(
+                object_id,
+                cursor.map(Into::into),
+                limit.map(Into::into),
+                descending_order,
+            )
+            .await?)
+    }
+
+    pub async fn list_scheduled_tasks(
+        &self,
+        cursor: Option<u64>,
+        limit: Option<usize>,
+    ) -> Result<ScheduledTaskPageView> {
+        Ok(self
+            .http
+            .list_scheduled_tasks(cursor.map(Into::into), limit.map(Into::into))
+            .await?)
+    }
 }
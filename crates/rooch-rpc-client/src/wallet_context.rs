@@ -1,40 +1,55 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::client_config::{ClientConfig, DEFAULT_EXPIRATION_SECS};
+use crate::client_config::{ClientConfig, Env, DEFAULT_EXPIRATION_SECS};
+use crate::nonce_manager::{InFlightTransaction, NonceManager};
 use crate::Client;
 use anyhow::{anyhow, Result};
 use move_command_line_common::address::ParsedAddress;
 use move_core_types::account_address::AccountAddress;
 use moveos_types::gas_config::GasConfig;
+use moveos_types::h256::H256;
 use moveos_types::transaction::MoveAction;
+use once_cell::sync::Lazy;
+use parking_lot::{Mutex, MutexGuard};
 use rooch_config::config::{Config, PersistedConfig};
 use rooch_config::server_config::ServerConfig;
-use rooch_config::{rooch_config_dir, ROOCH_CLIENT_CONFIG, ROOCH_SERVER_CONFIG};
+use rooch_config::{
+    rooch_config_dir, ROOCH_CLIENT_CONFIG, ROOCH_NONCE_FILENAME, ROOCH_SERVER_CONFIG,
+};
 use rooch_key::keystore::account_keystore::AccountKeystore;
 use rooch_key::keystore::file_keystore::FileBasedKeystore;
 use rooch_key::keystore::Keystore;
 use rooch_rpc_api::jsonrpc_types::{ExecuteTransactionResponseView, KeptVMStatusView};
 use rooch_types::address::RoochAddress;
 use rooch_types::addresses;
-use rooch_types::crypto::Signature;
 use rooch_types::error::{RoochError, RoochResult};
-use rooch_types::transaction::{
-    authenticator::Authenticator,
-    rooch::{RoochTransaction, RoochTransactionData},
-};
+use rooch_types::transaction::rooch::{RoochTransaction, RoochTransactionData};
+use rooch_types::transaction::AbstractTransaction;
 use std::collections::BTreeMap;
 use std::path::PathBuf;
 use std::sync::Arc;
 use std::time::Duration;
 use tokio::sync::RwLock;
 
+/// The RPC clients shared by the wallet contexts of the process, keyed by the rpc and ws url of the env,
+/// so the commands run in one process, such as in the console, reuse the connections.
+static SHARED_CLIENTS: Lazy<Mutex<BTreeMap<(String, Option<String>), Client>>> =
+    Lazy::new(Default::default);
+
 pub struct WalletContext {
     client: Arc<RwLock<Option<Client>>>,
     pub client_config: PersistedConfig<ClientConfig>,
     pub server_config: PersistedConfig<ServerConfig>,
     pub keystore: Keystore,
     pub address_mapping: BTreeMap<String, AccountAddress>,
+    /// The sequence number of the built transactions, overrides the one from the nonce manager if `Some`
+    pub sequence_number: Option<u64>,
+    /// The built transactions expire after the seconds from now if `Some`, otherwise they never expire
+    pub expiration_secs: Option<u64>,
+    /// The environment profile used by the context, overrides the active environment of the client config if `Some`
+    profile: Option<String>,
+    nonce_manager: Arc<Mutex<PersistedConfig<NonceManager>>>,
 }
 
 pub type AddressMappingFn = Box<dyn Fn(&str) -> Option<AccountAddress> + Send + Sync>;
@@ -60,6 +75,14 @@ impl WalletContext {
         let client_config = client_config.persisted(&client_config_path);
         let server_config = server_config.persisted(&server_config_path);
 
+        let nonce_manager_path = config_dir.join(ROOCH_NONCE_FILENAME);
+        let nonce_manager = if nonce_manager_path.exists() {
+            PersistedConfig::<NonceManager>::read(&nonce_manager_path)?
+        } else {
+            NonceManager::default()
+        }
+        .persisted(&nonce_manager_path);
+
         let keystore_result = FileBasedKeystore::load(&client_config.keystore_path);
         let keystore = match keystore_result {
             Ok(file_keystore) => Keystore::File(file_keystore),
@@ -69,18 +92,87 @@ impl WalletContext {
         let mut address_mapping = BTreeMap::new();
         address_mapping.extend(addresses::rooch_framework_named_addresses());
 
-        //TODO support account name alias name.
-        if let Some(active_address) = client_config.active_address {
-            address_mapping.insert("default".to_string(), AccountAddress::from(active_address));
+        // The framework names and `default` take precedence over the address book
+        for (name, address) in client_config.address_book.iter() {
+            address_mapping
+                .entry(name.clone())
+                .or_insert_with(|| AccountAddress::from(*address));
         }
 
-        Ok(Self {
+        let mut context = Self {
             client: Default::default(),
             client_config,
             server_config,
             keystore,
             address_mapping,
-        })
+            sequence_number: None,
+            expiration_secs: None,
+            profile: None,
+            nonce_manager: Arc::new(Mutex::new(nonce_manager)),
+        };
+        context.update_default_address();
+        Ok(context)
+    }
+
+    /// Use the environment profile instead of the active environment, the profile is not persisted
+    pub fn use_profile(&mut self, profile: String) -> Result<()> {
+        let alias = Some(profile.clone());
+        if self.client_config.get_env(&alias).is_none() {
+            return Err(anyhow!(
+                "Environment configuration not found for profile [{}]",
+                profile
+            ));
+        }
+        self.profile = alias;
+        self.client = Default::default();
+        self.update_default_address();
+        Ok(())
+    }
+
+    /// The environment of the profile if it is used, otherwise the active environment
+    pub fn active_env(&self) -> Result<&Env> {
+        match &self.profile {
+            Some(profile) => self.client_config.get_env(&self.profile).ok_or_else(|| {
+                anyhow!(
+                    "Environment configuration not found for profile [{}]",
+                    profile
+                )
+            }),
+            None => self.client_config.get_active_env(),
+        }
+    }
+
+    /// The active address of the environment, fallback to the global active address
+    pub fn active_address(&self) -> Option<RoochAddress> {
+        self.active_env()
+            .ok()
+            .and_then(|env| env.active_address)
+            .or(self.client_config.active_address)
+    }
+
+    /// Set the active address of the environment if the environment has its own active address,
+    /// otherwise set the global active address. The client config is not saved.
+    pub fn set_active_address(&mut self, address: RoochAddress) {
+        let alias = match &self.profile {
+            Some(profile) => Some(profile.clone()),
+            None => self.client_config.active_env.clone(),
+        };
+        match self
+            .client_config
+            .get_env_mut(&alias)
+            .filter(|env| env.active_address.is_some())
+        {
+            Some(env) => env.active_address = Some(address),
+            None => self.client_config.active_address = Some(address),
+        }
+        self.update_default_address();
+    }
+
+    fn update_default_address(&mut self) {
+        if let Some(active_address) = self.active_address() {
+            self.address_mapping
+                .insert("default".to_string(), AccountAddress::from(active_address));
+        }
     }
 
     pub fn add_address_mapping(&mut self, name: String, address: AccountAddress) {
@@ -95,11 +187,13 @@ impl WalletContext {
     pub fn resolve_address(&self, parsed_address: ParsedAddress) -> RoochResult<AccountAddress> {
         match parsed_address {
             ParsedAddress::Numerical(address) => Ok(address.into_inner()),
-            ParsedAddress::Named(name) => {
-                self.address_mapping.get(&name).cloned().ok_or_else(|| {
+            ParsedAddress::Named(name) => match self.address_mapping.get(&name) {
+                Some(address) => Ok(*address),
+                // The hex address without the `0x` prefix is parsed as a name
+                None => AccountAddress::from_hex(&name).map_err(|_| {
                     RoochError::CommandArgumentError(format!("Unknown named address: {}", name))
-                })
-            }
+                }),
+            },
         }
     }
 
@@ -128,11 +222,19 @@ impl WalletContext {
             client.clone()
         } else {
             drop(read);
-            let client = self
-                .client_config
-                .get_active_env()?
-                .create_rpc_client(Duration::from_secs(DEFAULT_EXPIRATION_SECS), None)
-                .await?;
+            let env = self.active_env()?;
+            let key = (env.rpc.clone(), env.ws.clone());
+            let shared_client = SHARED_CLIENTS.lock().get(&key).cloned();
+            let client = match shared_client {
+                Some(client) => client,
+                None => {
+                    let client = env
+                        .create_rpc_client(Duration::from_secs(DEFAULT_EXPIRATION_SECS), None)
+                        .await?;
+                    SHARED_CLIENTS.lock().insert(key, client.clone());
+                    client
+                }
+            };
 
             self.client.write().await.insert(client).clone()
         })
@@ -145,20 +247,55 @@ impl WalletContext {
     ) -> RoochResult<RoochTransactionData> {
         let client = self.get_client().await?;
         let chain_id = client.rooch.get_chain_id().await?;
-        let sequence_number = client
-            .rooch
-            .get_sequence_number(sender)
-            .await
-            .map_err(RoochError::from)?;
+        let sequence_number = match self.sequence_number {
+            Some(sequence_number) => sequence_number,
+            None => {
+                let onchain_sequence_number = client
+                    .rooch
+                    .get_sequence_number(sender)
+                    .await
+                    .map_err(RoochError::from)?;
+                // Skip the sequence numbers of the in-flight transactions
+                self.nonce_manager
+                    .lock()
+                    .next_sequence_number(&sender, onchain_sequence_number)
+            }
+        };
         log::debug!("use sequence_number: {}", sequence_number);
-        //TODO max gas amount from cli option or dry run estimate
-        let tx_data = RoochTransactionData::new(
+        let env_max_gas_amount = self.active_env()?.max_gas_amount;
+        let mut tx_data = RoochTransactionData::new(
             sender,
             sequence_number,
             chain_id,
-            GasConfig::DEFAULT_MAX_GAS_AMOUNT,
+            env_max_gas_amount.unwrap_or(GasConfig::DEFAULT_MAX_GAS_AMOUNT),
             action,
         );
+        if let Some(expiration_secs) = self.expiration_secs {
+            let now_secs = std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .map_err(|e| RoochError::UnexpectedError(e.to_string()))?
+                .as_secs();
+            tx_data =
+                tx_data.with_expiration_timestamp_secs(now_secs.saturating_add(expiration_secs));
+        }
+        if env_max_gas_amount.is_some() {
+            log::debug!("use env max_gas_amount: {}", tx_data.max_gas_amount);
+            return Ok(tx_data);
+        }
+        // Use the dry run estimate as the max gas amount, fallback to the default if the dry run failed,
+        // so the error is reported by the execution.
+        match client.rooch.estimate_gas(&tx_data).await {
+            Ok(estimate) if estimate.status == KeptVMStatusView::Executed => {
+                log::debug!("use estimated max_gas_amount: {}", estimate.max_gas_amount);
+                tx_data.max_gas_amount = estimate.max_gas_amount;
+            }
+            Ok(estimate) => {
+                log::debug!("estimate gas failed with status: {:?}", estimate.status);
+            }
+            Err(e) => {
+                log::debug!("estimate gas failed: {}", e);
+            }
+        }
         Ok(tx_data)
     }
 
@@ -168,34 +305,107 @@ impl WalletContext {
         action: MoveAction,
         password: Option<String>,
     ) -> RoochResult<RoochTransaction> {
-        let kp = self
+        let tx_data = self.build_tx_data(sender, action).await?;
+        self.sign_tx_data(tx_data, password)
+    }
+
+    /// Sign the transaction data with the key of the sender
+    pub fn sign_tx_data(
+        &self,
+        tx_data: RoochTransactionData,
+        password: Option<String>,
+    ) -> RoochResult<RoochTransaction> {
+        let sender = tx_data.sender;
+        let tx = self
             .keystore
-            .get_key_pair_with_password(&sender, password)
-            .ok()
-            .ok_or_else(|| {
+            .sign_transaction(&sender, tx_data, password)
+            .map_err(|e| {
                 RoochError::SignMessageError(format!(
-                    "Cannot find encryption data for address: [{sender}]"
+                    "Failed to sign transaction for address: [{sender}], {e}"
                 ))
             })?;
-
-        let tx_data = self.build_tx_data(sender, action).await?;
-        let signature = Signature::new_hashed(tx_data.hash().as_bytes(), &kp);
-        Ok(RoochTransaction::new(
-            tx_data,
-            Authenticator::rooch(signature),
-        ))
+        // Use the auth validator configured for the account, it validates the same signature as the native validator
+        Ok(match self.client_config.auth_validators.get(&sender) {
+            Some(auth_validator_id) => tx.with_auth_validator_id(*auth_validator_id),
+            None => tx,
+        })
     }
 
+    /// Execute the transaction, the transaction is tracked as in-flight by the nonce manager until the result is received
     pub async fn execute(
         &self,
         tx: RoochTransaction,
     ) -> RoochResult<ExecuteTransactionResponseView> {
         let client = self.get_client().await?;
-        client
-            .rooch
-            .execute_tx(tx)
-            .await
-            .map_err(|e| RoochError::TransactionError(e.to_string()))
+        let sender = tx.sender();
+        let sequence_number = tx.sequence_number();
+        self.update_nonce_manager(|nonce_manager| {
+            nonce_manager.track(
+                sender,
+                sequence_number,
+                InFlightTransaction {
+                    tx_hash: tx.tx_hash(),
+                    max_gas_amount: tx.max_gas_amount(),
+                },
+            )
+        })?;
+        let result = client.rooch.execute_tx(tx).await;
+        // The transaction is rejected if the server returns an error response,
+        // it is kept in-flight if the result is unknown, such as the request is timeout.
+        let finished = match &result {
+            Ok(_) => true,
+            Err(e) => matches!(
+                e.downcast_ref::<jsonrpsee::core::Error>(),
+                Some(jsonrpsee::core::Error::Call(_))
+            ),
+        };
+        if finished {
+            self.update_nonce_manager(|nonce_manager| {
+                nonce_manager.untrack(&sender, sequence_number)
+            })?;
+        }
+        result.map_err(|e| RoochError::TransactionError(e.to_string()))
+    }
+
+    /// Send the transaction without waiting for it to be executed.
+    /// The transaction is kept in-flight until the on-chain sequence number catches up with it.
+    pub async fn send(&self, tx: RoochTransaction) -> RoochResult<H256> {
+        let client = self.get_client().await?;
+        let sender = tx.sender();
+        let sequence_number = tx.sequence_number();
+        self.update_nonce_manager(|nonce_manager| {
+            nonce_manager.track(
+                sender,
+                sequence_number,
+                InFlightTransaction {
+                    tx_hash: tx.tx_hash(),
+                    max_gas_amount: tx.max_gas_amount(),
+                },
+            )
+        })?;
+        let result = client.rooch.send_tx(tx).await;
+        if let Err(e) = &result {
+            if matches!(
+                e.downcast_ref::<jsonrpsee::core::Error>(),
+                Some(jsonrpsee::core::Error::Call(_))
+            ) {
+                self.update_nonce_manager(|nonce_manager| {
+                    nonce_manager.untrack(&sender, sequence_number)
+                })?;
+            }
+        }
+        result.map_err(|e| RoochError::TransactionError(e.to_string()))
+    }
+
+    pub fn nonce_manager(&self) -> MutexGuard<'_, PersistedConfig<NonceManager>> {
+        self.nonce_manager.lock()
+    }
+
+    /// Update the nonce manager and save it
+    pub fn update_nonce_manager<F: FnOnce(&mut NonceManager)>(&self, f: F) -> RoochResult<()> {
+        let mut nonce_manager = self.nonce_manager.lock();
+        f(&mut nonce_manager);
+        nonce_manager.save().map_err(RoochError::from)
     }
 
     pub async fn sign_and_execute(
@@ -4,63 +4,86 @@
 use std::env;
 use std::fmt::Debug;
 use std::net::SocketAddr;
+use std::str::FromStr;
 use std::sync::Arc;
 use std::time::Duration;
 
 use anyhow::{Error, Result};
 use coerce::actor::scheduler::timer::Timer;
 use coerce::actor::{system::ActorSystem, IntoActor};
-use hyper::header::HeaderValue;
+use hyper::header::{HeaderName, HeaderValue};
 use hyper::Method;
+use jsonrpsee::core::server::rpc_module::Methods;
 use jsonrpsee::server::ServerBuilder;
 use jsonrpsee::RpcModule;
+use metrics::Registry;
 use serde_json::json;
-use tower_http::cors::{AllowOrigin, CorsLayer};
+use tower_http::cors::{AllowHeaders, AllowMethods, AllowOrigin, CorsLayer};
 use tower_http::trace::TraceLayer;
 use tracing::info;
 
 use moveos_store::{MoveOSDB, MoveOSStore};
 use raw_store::errors::RawStoreError;
+use raw_store::metrics::{RocksDBCollector, StoreMetrics};
 use raw_store::rocks::RocksDB;
 use raw_store::StoreInstance;
 use rooch_config::da_config::DAConfig;
 use rooch_config::indexer_config::IndexerConfig;
-use rooch_config::server_config::ServerConfig;
+use rooch_config::node_role::NodeRole;
+use rooch_config::server_config::{CorsConfig, MethodAccessConfig, ServerConfig};
 use rooch_config::store_config::StoreConfig;
 use rooch_config::{BaseConfig, RoochOpt, ServerOpt};
 use rooch_da::actor::da::DAActor;
 use rooch_da::proxy::DAProxy;
 use rooch_executor::actor::executor::ExecutorActor;
+use rooch_executor::actor::messages::{CatchUpPrimaryTick, PruneStatesTick};
 use rooch_executor::actor::reader_executor::ReaderExecutorActor;
 use rooch_executor::proxy::ExecutorProxy;
 use rooch_framework::natives::default_gas_schedule;
+use rooch_graphql::{start_graphql_server, GraphQLServerHandle};
+use rooch_grpc_server::{start_grpc_server, GrpcServerHandle};
 use rooch_indexer::actor::indexer::IndexerActor;
+use rooch_indexer::actor::messages::PruneIndexerTick;
 use rooch_indexer::actor::reader_indexer::IndexerReaderActor;
 use rooch_indexer::indexer_reader::IndexerReader;
+use rooch_indexer::metrics::IndexerMetrics;
 use rooch_indexer::proxy::IndexerProxy;
+use rooch_indexer::types::IndexerProgressTable;
 use rooch_indexer::IndexerStore;
 use rooch_key::key_derive::{generate_new_key_pair, retrieve_key_pair};
+use rooch_open_rpc_spec_builder::{build_rooch_rpc_spec, RPC_DISCOVER_METHOD};
 use rooch_proposer::actor::messages::ProposeBlock;
 use rooch_proposer::actor::proposer::ProposerActor;
 use rooch_proposer::proxy::ProposerProxy;
 use rooch_relayer::actor::messages::RelayTick;
 use rooch_relayer::actor::relayer::RelayerActor;
-use rooch_rpc_api::api::RoochRpcModule;
+use rooch_rpc_api::api::{RoochRpcModule, DEFAULT_MAX_TX_BATCH_SIZE};
 use rooch_sequencer::actor::sequencer::SequencerActor;
 use rooch_sequencer::proxy::SequencerProxy;
 use rooch_store::RoochStore;
+use rooch_sync::transport::SYNC_CERT_DIR;
+use rooch_sync::{start_sync_server, SyncServerHandle};
 use rooch_types::address::RoochAddress;
 use rooch_types::bitcoin::genesis::BitcoinGenesisContext;
 use rooch_types::bitcoin::network::Network;
 use rooch_types::crypto::RoochKeyPair;
 use rooch_types::error::{GenesisError, RoochError};
 
+use crate::server::admin_server::AdminServer;
 use crate::server::btc_server::BtcServer;
 use crate::server::eth_server::{EthNetServer, EthServer};
 use crate::server::rooch_server::RoochServer;
+use crate::server::subscription_server::SubscriptionServer;
 use crate::service::aggregate_service::AggregateService;
+use crate::service::method_access::{filter_methods, MethodAccessLayer};
+use crate::service::query_limits::QueryLimits;
+use crate::service::rate_limiter::{limit_calls, RateLimitLayer};
 use crate::service::rpc_logger::RpcLogger;
+use crate::service::rpc_metrics::RpcMetrics;
 use crate::service::rpc_service::RpcService;
+use crate::service::subscription_service::SubscriptionService;
+use crate::service::telemetry::{init_tracing, make_request_span, shutdown_tracing};
+use crate::service::tls::{load_tls_acceptor, start_tls_server, ForwardedPeers, TlsServerHandle};
 
 pub mod server;
 pub mod service;
@@ -68,19 +91,46 @@ pub mod service;
 /// This exit code means is that the server failed to start and required human intervention.
 static R_EXIT_CODE_NEED_HELP: i32 = 120;
 
+/// The tracing target of the JSON-RPC server logs
+pub const LOG_TARGET: &str = "rooch::rpc";
+
 pub struct ServerHandle {
     handle: jsonrpsee::server::ServerHandle,
+    grpc_handle: Option<GrpcServerHandle>,
+    graphql_handle: Option<GraphQLServerHandle>,
+    sync_handle: Option<SyncServerHandle>,
+    tls_handle: Option<TlsServerHandle>,
+    metrics_handle: Option<tokio::task::JoinHandle<()>>,
     timers: Vec<Timer>,
+    otlp_tracing: bool,
     _store_config: StoreConfig,
     _index_config: IndexerConfig,
 }
 
 impl ServerHandle {
-    fn stop(self) -> Result<()> {
+    pub fn stop(self) -> Result<()> {
         self.handle.stop()?;
+        if let Some(grpc_handle) = self.grpc_handle {
+            grpc_handle.stop()?;
+        }
+        if let Some(graphql_handle) = self.graphql_handle {
+            graphql_handle.stop()?;
+        }
+        if let Some(sync_handle) = self.sync_handle {
+            sync_handle.stop()?;
+        }
+        if let Some(tls_handle) = self.tls_handle {
+            tls_handle.stop()?;
+        }
+        if let Some(metrics_handle) = self.metrics_handle {
+            metrics_handle.abort();
+        }
         for timer in self.timers {
             timer.stop();
         }
+        if self.otlp_tracing {
+            shutdown_tracing();
+        }
         Ok(())
     }
 }
@@ -119,6 +169,10 @@ impl Service {
 
 pub struct RpcModuleBuilder {
     module: RpcModule<()>,
+    method_access: MethodAccessConfig,
+    loopback_server: bool,
+    limit_calls: bool,
+    denied_methods: Vec<&'static str>,
 }
 
 impl Default for RpcModuleBuilder {
@@ -129,13 +183,50 @@ impl Default for RpcModuleBuilder {
 
 impl RpcModuleBuilder {
     pub fn new() -> Self {
+        Self::with_method_access(MethodAccessConfig::default(), true)
+    }
+
+    /// The methods are filtered by the method access rules when the modules are registered,
+    /// the localhost only methods are not registered unless the server listens on the loopback interface.
+    pub fn with_method_access(method_access: MethodAccessConfig, loopback_server: bool) -> Self {
         Self {
             module: RpcModule::new(()),
+            method_access,
+            loopback_server,
+            limit_calls: false,
+            denied_methods: vec![],
         }
     }
 
+    /// Charge every WebSocket call of the registered modules to the rate limit, see `limit_calls`
+    pub fn with_call_rate_limit(mut self, limit_calls: bool) -> Self {
+        self.limit_calls = limit_calls;
+        self
+    }
+
     pub fn register_module<M: RoochRpcModule>(&mut self, module: M) -> Result<()> {
-        Ok(self.module.merge(module.rpc())?)
+        let (methods, denied_methods) = filter_methods(module.rpc().into(), |method| {
+            self.method_access.is_allowed(method, self.loopback_server)
+        })?;
+        self.denied_methods.extend(denied_methods);
+        self.merge(methods)
+    }
+
+    /// Register the OpenRPC service discovery method `rpc.discover`, it returns the OpenRPC document of the APIs
+    pub fn register_discover_method(&mut self) -> Result<()> {
+        let open_rpc = serde_json::to_value(build_rooch_rpc_spec())?;
+        let mut module = RpcModule::new(());
+        module.register_method(RPC_DISCOVER_METHOD, move |_, _| Ok(open_rpc.clone()))?;
+        self.merge(module.into())
+    }
+
+    fn merge(&mut self, methods: Methods) -> Result<()> {
+        let methods = if self.limit_calls {
+            limit_calls(methods)?
+        } else {
+            methods
+        };
+        Ok(self.module.merge(methods)?)
     }
 }
 
@@ -146,7 +237,7 @@ pub async fn start_server(opt: &RoochOpt, server_opt: ServerOpt) -> Result<Serve
         Ok(server_handle) => Ok(server_handle),
         Err(e) => match e.downcast::<GenesisError>() {
             Ok(e) => {
-                log::error!(
+                tracing::error!(target: LOG_TARGET,
                     "{:?}, please clean your data dir. `rooch server clean -n {}` ",
                     e,
                     active_env
@@ -155,7 +246,7 @@ pub async fn start_server(opt: &RoochOpt, server_opt: ServerOpt) -> Result<Serve
             }
             Err(e) => match e.downcast::<RawStoreError>() {
                 Ok(e) => {
-                    log::error!(
+                    tracing::error!(target: LOG_TARGET,
                         "{:?}, please clean your data dir. `rooch server clean -n {}` ",
                         e,
                         active_env
@@ -163,7 +254,7 @@ pub async fn start_server(opt: &RoochOpt, server_opt: ServerOpt) -> Result<Serve
                     std::process::exit(R_EXIT_CODE_NEED_HELP);
                 }
                 Err(e) => {
-                    log::error!("{:?}, server start fail. ", e);
+                    tracing::error!(target: LOG_TARGET, "{:?}, server start fail. ", e);
                     std::process::exit(R_EXIT_CODE_NEED_HELP);
                 }
             },
@@ -172,10 +263,61 @@ pub async fn start_server(opt: &RoochOpt, server_opt: ServerOpt) -> Result<Serve
 }
 
 // run json-rpc server
+/// Build the CORS layer from the config, fallback to the `ACCESS_CONTROL_ALLOW_ORIGIN` env if the config is absent.
+fn cors_layer(config: Option<&CorsConfig>) -> Result<CorsLayer> {
+    let config = match config {
+        Some(config) => config.clone(),
+        None => CorsConfig {
+            allow_origins: env::var("ACCESS_CONTROL_ALLOW_ORIGIN")
+                .map(|value| value.split(',').map(ToOwned::to_owned).collect())
+                .unwrap_or_default(),
+            ..Default::default()
+        },
+    };
+    let allow_origin = if config.allow_any_origin() {
+        AllowOrigin::any()
+    } else {
+        AllowOrigin::list(
+            config
+                .allow_origins
+                .iter()
+                .map(|origin| HeaderValue::from_str(origin.trim()))
+                .collect::<Result<Vec<_>, _>>()?,
+        )
+    };
+    let allow_methods = config
+        .allow_methods
+        .iter()
+        .map(|method| Method::from_bytes(method.trim().to_uppercase().as_bytes()))
+        .collect::<Result<Vec<_>, _>>()?;
+    let allow_headers = config
+        .allow_headers
+        .iter()
+        .map(|header| HeaderName::from_str(header.trim()))
+        .collect::<Result<Vec<_>, _>>()?;
+    info!(target: LOG_TARGET, "RPC Server CORS: {}", config);
+
+    let cors = CorsLayer::new()
+        .allow_methods(AllowMethods::list(allow_methods))
+        .allow_origin(allow_origin)
+        .allow_headers(AllowHeaders::list(allow_headers));
+    Ok(match config.max_age_seconds {
+        Some(max_age) => cors.max_age(Duration::from_secs(max_age)),
+        None => cors,
+    })
+}
+
 pub async fn run_start_server(opt: &RoochOpt, mut server_opt: ServerOpt) -> Result<ServerHandle> {
     // We may call `start_server` multiple times in testing scenarios
     // tracing_subscriber can only be inited once.
-    let _ = tracing_subscriber::fmt::try_init();
+    let log_config = server_opt.log.clone().unwrap_or_default();
+    init_tracing(server_opt.tracing.as_ref(), &log_config)?;
+    info!(target: LOG_TARGET, "RPC Server log: {}", log_config);
+    if let Some(tracing) = &server_opt.tracing {
+        info!(target: LOG_TARGET, "RPC Server tracing: {}", tracing);
+    }
+    #[cfg(feature = "failpoints")]
+    init_failpoints()?;
 
     let config = opt.port.map_or(ServerConfig::default(), |port| {
         ServerConfig::new_with_port(port)
@@ -187,22 +329,48 @@ pub async fn run_start_server(opt: &RoochOpt, mut server_opt: ServerOpt) -> Resu
 
     //Init store
     let base_config = BaseConfig::load_with_opt(opt)?;
+    let sync_cert_dir = base_config.data_dir().join(SYNC_CERT_DIR);
     let mut store_config = StoreConfig::default();
     store_config.merge_with_opt_with_init(opt, Arc::new(base_config.clone()), true)?;
-    let (moveos_store, rooch_store) = init_storage(&store_config)?;
+    // Init metrics, the metrics are only collected if the metrics server is enabled
+    let metrics_registry = opt.metrics_port.map(|_| Registry::new());
+    // The read replica opens the stores of the primary node as the secondary instances, and serves the queries only
+    let read_replica = store_config.is_read_replica();
+    let (moveos_store, rooch_store, replica_dbs) = if read_replica {
+        info!(
+            target: LOG_TARGET,
+            "RPC Server runs as a read replica, the secondary instances are at {:?}",
+            store_config.read_replica_dir()
+        );
+        init_replica_storage_with_metrics(&store_config, metrics_registry.as_ref())?
+    } else {
+        let (moveos_store, rooch_store) =
+            init_storage_with_metrics(&store_config, metrics_registry.as_ref())?;
+        (moveos_store, rooch_store, vec![])
+    };
 
     //Init indexer store
     let mut indexer_config = IndexerConfig::default();
     indexer_config.merge_with_opt_with_init(opt, Arc::new(base_config), true)?;
     let (indexer_store, indexer_reader) = init_indexer(&indexer_config)?;
 
+    let indexer_metrics = metrics_registry
+        .as_ref()
+        .map(IndexerMetrics::register)
+        .transpose()?;
+    let rpc_metrics = metrics_registry
+        .as_ref()
+        .map(RpcMetrics::register)
+        .transpose()?;
+
     // Check for key pairs
     if server_opt.sequencer_keypair.is_none()
         || server_opt.proposer_keypair.is_none()
         || server_opt.relayer_keypair.is_none()
     {
-        // only for integration test, generate test key pairs
-        if chain_id_opt.is_test_or_dev_or_local() {
+        // only for integration test, generate test key pairs,
+        // the read replica does not sign anything, so the key pairs are not required either
+        if chain_id_opt.is_test_or_dev_or_local() || read_replica {
             let result = generate_new_key_pair(None, None, None, None)?;
             let kp: RoochKeyPair =
                 retrieve_key_pair(&result.key_pair_data.private_key_encryption, None)?;
@@ -221,6 +389,11 @@ pub async fn run_start_server(opt: &RoochOpt, mut server_opt: ServerOpt) -> Resu
 
     // Init executor
     let is_genesis = moveos_store.statedb.is_genesis();
+    if is_genesis && read_replica {
+        return Err(Error::msg(
+            "The primary node of the read replica is not initialized, start the primary node first",
+        ));
+    }
 
     // #TODO: If not launched in the Genesis way, the latest onchain GasSchedule needs to be obtained.
     let gas_schedule_blob =
@@ -233,23 +406,57 @@ pub async fn run_start_server(opt: &RoochOpt, mut server_opt: ServerOpt) -> Resu
         moveos_store.clone(),
         rooch_store.clone(),
     )?;
-    let reader_executor = ReaderExecutorActor::new(
+    let reader_executor = ReaderExecutorActor::new_with_state_cache_size(
         executor_actor.genesis().clone(),
         moveos_store.clone(),
         rooch_store.clone(),
+        store_config.state_cache_size(),
     )?
     .into_actor(Some("ReaderExecutor"), &actor_system)
     .await?;
     let executor = executor_actor
         .into_actor(Some("Executor"), &actor_system)
         .await?;
-    let executor_proxy = ExecutorProxy::new(executor.into(), reader_executor.into());
+    let executor_proxy =
+        ExecutorProxy::new(executor.clone().into(), reader_executor.clone().into());
+    let mut timers = vec![];
+    if read_replica {
+        //TODO load from config
+        let catch_up_duration_in_millis: u64 = 1000;
+        let catch_up_timer = Timer::start(
+            reader_executor,
+            Duration::from_millis(catch_up_duration_in_millis),
+            CatchUpPrimaryTick { dbs: replica_dbs },
+        );
+        timers.push(catch_up_timer);
+    }
+
+    // Init state sync server, it serves the transactions and the states to the syncing peers
+    let sync_handle = match opt.sync_port {
+        Some(sync_port) => {
+            let sync_addr: SocketAddr = format!("{}:{}", config.host, sync_port).parse()?;
+            Some(
+                start_sync_server(
+                    sync_addr,
+                    &sync_cert_dir,
+                    moveos_store.clone(),
+                    rooch_store.clone(),
+                )
+                .await?,
+            )
+        }
+        None => None,
+    };
 
     // Init sequencer
-    info!("RPC Server sequencer address: {:?}", sequencer_account);
-    let sequencer = SequencerActor::new(sequencer_keypair, rooch_store, is_genesis)?
-        .into_actor(Some("Sequencer"), &actor_system)
-        .await?;
+    info!(target: LOG_TARGET, "RPC Server sequencer address: {:?}", sequencer_account);
+    let sequencer = if read_replica {
+        SequencerActor::new_read_only(sequencer_keypair, rooch_store)?
+    } else {
+        SequencerActor::new(sequencer_keypair, rooch_store, is_genesis)?
+    }
+    .into_actor(Some("Sequencer"), &actor_system)
+    .await?;
     let sequencer_proxy = SequencerProxy::new(sequencer.into());
 
     // Init DA
@@ -267,51 +474,118 @@ pub async fn run_start_server(opt: &RoochOpt, mut server_opt: ServerOpt) -> Resu
     // Init proposer
     let proposer_keypair = server_opt.proposer_keypair.unwrap();
     let proposer_account: RoochAddress = (&proposer_keypair.public()).into();
-    info!("RPC Server proposer address: {:?}", proposer_account);
+    info!(target: LOG_TARGET, "RPC Server proposer address: {:?}", proposer_account);
     let proposer = ProposerActor::new(proposer_keypair, da_proxy)
         .into_actor(Some("Proposer"), &actor_system)
         .await?;
     let proposer_proxy = ProposerProxy::new(proposer.clone().into());
     //TODO load from config
     let block_propose_duration_in_seconds: u64 = 5;
-    let mut timers = vec![];
-    let proposer_timer = Timer::start(
-        proposer,
-        Duration::from_secs(block_propose_duration_in_seconds),
-        ProposeBlock {},
-    );
-    timers.push(proposer_timer);
+    // The blocks are proposed and the stores are pruned by the primary node only
+    if !read_replica {
+        let proposer_timer = Timer::start(
+            proposer,
+            Duration::from_secs(block_propose_duration_in_seconds),
+            ProposeBlock {},
+        );
+        timers.push(proposer_timer);
+    }
+
+    // Init state pruner
+    let pruning_policy = store_config.pruning_policy();
+    if !pruning_policy.is_archive() && !read_replica {
+        info!(target: LOG_TARGET, "RPC Server state pruning policy: {}", pruning_policy);
+        //TODO load from config
+        let state_prune_duration_in_seconds: u64 = 600;
+        let pruner_timer = Timer::start(
+            executor,
+            Duration::from_secs(state_prune_duration_in_seconds),
+            PruneStatesTick {
+                policy: pruning_policy,
+            },
+        );
+        timers.push(pruner_timer);
+    }
 
     // Init indexer
-    let indexer_executor = IndexerActor::new(indexer_store, moveos_store)?
+    let node_role = indexer_config.node_role();
+    info!(target: LOG_TARGET, "RPC Server node role: {}", node_role);
+    let mut indexer_actor =
+        IndexerActor::new(indexer_store, moveos_store)?.with_tables(indexer_tables(node_role));
+    let mut indexer_reader_actor = IndexerReaderActor::new(indexer_reader)?;
+    if let Some(indexer_metrics) = indexer_metrics {
+        indexer_actor = indexer_actor.with_metrics(indexer_metrics.clone());
+        indexer_reader_actor = indexer_reader_actor.with_metrics(indexer_metrics);
+    }
+    let indexer_executor = indexer_actor
         .into_actor(Some("Indexer"), &actor_system)
         .await?;
-    let indexer_reader_executor = IndexerReaderActor::new(indexer_reader)?
+    let indexer_reader_executor = indexer_reader_actor
         .into_actor(Some("IndexerReader"), &actor_system)
         .await?;
+
+    // Init indexer pruner
+    let indexer_retention = indexer_config.retention();
+    if !indexer_retention.is_unlimited() && !read_replica {
+        info!(target: LOG_TARGET, "RPC Server indexer retention: {}", indexer_retention);
+        let indexer_pruner_timer = Timer::start(
+            indexer_executor.clone(),
+            Duration::from_secs(indexer_config.prune_interval_secs()),
+            PruneIndexerTick {
+                retention: indexer_retention,
+            },
+        );
+        timers.push(indexer_pruner_timer);
+    }
     let indexer_proxy = IndexerProxy::new(indexer_executor.into(), indexer_reader_executor.into());
 
+    // Init gRPC server
+    let grpc_handle = match opt.grpc_port {
+        Some(grpc_port) => {
+            let grpc_addr: SocketAddr = format!("{}:{}", config.host, grpc_port).parse()?;
+            Some(start_grpc_server(grpc_addr, executor_proxy.clone(), indexer_proxy.clone()).await?)
+        }
+        None => None,
+    };
+
+    // Init GraphQL server of the indexer
+    let graphql_handle = match opt.graphql_port {
+        Some(graphql_port) => {
+            let graphql_addr: SocketAddr = format!("{}:{}", config.host, graphql_port).parse()?;
+            Some(start_graphql_server(graphql_addr, indexer_proxy.clone()).await?)
+        }
+        None => None,
+    };
+
     let rpc_service = RpcService::new(
         chain_id_opt.chain_id().id(),
         executor_proxy.clone(),
         sequencer_proxy,
         proposer_proxy,
         indexer_proxy,
-    );
+        SubscriptionService::default(),
+    )
+    .with_read_only(read_replica);
     let aggregate_service = AggregateService::new(rpc_service.clone());
 
     let ethereum_relayer_config = opt.ethereum_relayer_config();
     let bitcoin_relayer_config = opt.bitcoin_relayer_config();
+    let scheduler_keeper = opt.scheduler_keeper;
 
-    if ethereum_relayer_config.is_some() || bitcoin_relayer_config.is_some() {
+    let relayer_enabled =
+        ethereum_relayer_config.is_some() || bitcoin_relayer_config.is_some() || scheduler_keeper;
+    if relayer_enabled && read_replica {
+        info!(target: LOG_TARGET, "RPC Server relayer is disabled on the read replica");
+    } else if relayer_enabled {
         let relayer_keypair = server_opt.relayer_keypair.unwrap();
         let relayer_account: RoochAddress = (&relayer_keypair.public()).into();
-        info!("RPC Server relayer address: {:?}", relayer_account);
+        info!(target: LOG_TARGET, "RPC Server relayer address: {:?}", relayer_account);
         let relayer = RelayerActor::new(
             executor_proxy,
             relayer_keypair,
             ethereum_relayer_config,
             bitcoin_relayer_config,
+            scheduler_keeper,
             rpc_service.clone(),
         )
         .await?
@@ -326,40 +600,59 @@ pub async fn run_start_server(opt: &RoochOpt, mut server_opt: ServerOpt) -> Resu
         timers.push(relayer_timer);
     }
 
-    let acl = match env::var("ACCESS_CONTROL_ALLOW_ORIGIN") {
-        Ok(value) => {
-            let allow_hosts = value
-                .split(',')
-                .map(HeaderValue::from_str)
-                .collect::<Result<Vec<_>, _>>()?;
-            AllowOrigin::list(allow_hosts)
-        }
-        _ => AllowOrigin::any(),
-    };
-    info!(?acl);
-
-    let cors: CorsLayer = CorsLayer::new()
-        // Allow `POST` when accessing the resource
-        .allow_methods([Method::POST])
-        // Allow requests from any origin
-        .allow_origin(acl)
-        .allow_headers([hyper::header::CONTENT_TYPE]);
+    let cors = cors_layer(server_opt.cors.as_ref())?;
 
+    if let Some(rate_limit) = &server_opt.rate_limit {
+        info!(target: LOG_TARGET, "RPC Server rate limit: {}", rate_limit);
+    }
+    if let Some(method_access) = &server_opt.method_access {
+        info!(target: LOG_TARGET, "RPC Server method access: {}", method_access);
+    }
+    let query_limits = QueryLimits::new(server_opt.query_limits.clone().unwrap_or_default());
+    info!(target: LOG_TARGET, "RPC Server query limits: {}", query_limits.config());
+    let otlp_tracing = server_opt.tracing.is_some();
+    let loopback_server = addr.ip().is_loopback();
+    let method_access = server_opt.method_access.unwrap_or_default();
+    let forwarded_peers = ForwardedPeers::default();
+    let rate_limit_layer = server_opt
+        .rate_limit
+        .map(|rate_limit| RateLimitLayer::new(rate_limit, forwarded_peers.clone()));
+    let rate_limiter = rate_limit_layer.as_ref().map(RateLimitLayer::limiter);
     let middleware = tower::ServiceBuilder::new()
-        .layer(TraceLayer::new_for_http())
-        .layer(cors);
-
-    // Build server
+        .layer(TraceLayer::new_for_http().make_span_with(make_request_span))
+        .layer(cors)
+        .option_layer(rate_limit_layer)
+        .layer(MethodAccessLayer::new(&method_access));
+
+    // Build server, the JSON-RPC server listens on the loopback interface and the TLS server forwards to it if TLS is enabled
+    let tls_acceptor = server_opt.tls.as_ref().map(load_tls_acceptor).transpose()?;
+    let rpc_addr: SocketAddr = if tls_acceptor.is_some() {
+        "127.0.0.1:0".parse()?
+    } else {
+        addr
+    };
     let server = ServerBuilder::default()
-        .set_logger(RpcLogger)
+        .set_logger(RpcLogger::new(rpc_metrics, rate_limiter.clone()))
         .set_middleware(middleware)
-        .build(&addr)
+        .build(&rpc_addr)
         .await?;
+    let tls_handle = match tls_acceptor {
+        Some(acceptor) => {
+            Some(start_tls_server(addr, server.local_addr()?, acceptor, forwarded_peers).await?)
+        }
+        None => None,
+    };
 
-    let mut rpc_module_builder = RpcModuleBuilder::new();
+    // The HTTP calls are charged by the rate limit layer, and the WebSocket calls are charged by the method wrappers
+    let mut rpc_module_builder =
+        RpcModuleBuilder::with_method_access(method_access, loopback_server)
+            .with_call_rate_limit(rate_limiter.is_some());
     rpc_module_builder.register_module(RoochServer::new(
         rpc_service.clone(),
         aggregate_service.clone(),
+        opt.max_tx_batch_size.unwrap_or(DEFAULT_MAX_TX_BATCH_SIZE),
+        btc_network,
+        query_limits.clone(),
     ))?;
     rpc_module_builder.register_module(EthNetServer::new(chain_id_opt.chain_id()))?;
     rpc_module_builder.register_module(EthServer::new(
@@ -371,18 +664,51 @@ pub async fn run_start_server(opt: &RoochOpt, mut server_opt: ServerOpt) -> Resu
         rpc_service.clone(),
         aggregate_service.clone(),
         btc_network,
+        query_limits,
     ))?;
+    rpc_module_builder.register_module(SubscriptionServer::new(rpc_service.clone()))?;
+    // The admin methods are localhost only, they are not served unless the server listens on the loopback interface
+    if loopback_server {
+        rpc_module_builder.register_module(AdminServer)?;
+    }
+    rpc_module_builder.register_discover_method()?;
+    if !rpc_module_builder.denied_methods.is_empty() {
+        info!(target: LOG_TARGET, "Denied JSON-RPC methods : {:?}", rpc_module_builder.denied_methods);
+    }
 
     // let rpc_api = build_rpc_api(rpc_api);
     let methods_names = rpc_module_builder.module.method_names().collect::<Vec<_>>();
     let handle = server.start(rpc_module_builder.module)?;
 
-    info!("JSON-RPC HTTP Server start listening {:?}", addr);
-    info!("Available JSON-RPC methods : {:?}", methods_names);
+    info!(target: LOG_TARGET,
+        "JSON-RPC HTTP and WebSocket Server start listening {}",
+        config.url(tls_handle.is_some())
+    );
+    info!(target: LOG_TARGET, "Available JSON-RPC methods : {:?}", methods_names);
+
+    // Start the metrics server, Prometheus scrapes the metrics from `/metrics`
+    let metrics_handle = match (opt.metrics_port, metrics_registry) {
+        (Some(metrics_port), Some(registry)) => {
+            let metrics_addr: SocketAddr = format!("{}:{}", config.host, metrics_port).parse()?;
+            info!(target: LOG_TARGET, "Metrics server start listening {}", metrics_addr);
+            Some(tokio::spawn(async move {
+                if let Err(e) = metrics::metric_server::start_server(metrics_addr, registry).await {
+                    tracing::error!(target: LOG_TARGET, "Metrics server error: {:?}", e);
+                }
+            }))
+        }
+        _ => None,
+    };
 
     Ok(ServerHandle {
         handle,
+        grpc_handle,
+        graphql_handle,
+        sync_handle,
+        tls_handle,
+        metrics_handle,
         timers,
+        otlp_tracing,
         _store_config: store_config,
         _index_config: indexer_config,
     })
@@ -403,39 +729,146 @@ fn _build_rpc_api<M: Send + Sync + 'static>(mut rpc_module: RpcModule<M>) -> Rpc
     rpc_module
 }
 
-fn init_storage(store_config: &StoreConfig) -> Result<(MoveOSStore, RoochStore)> {
+/// Configure the fail points from the `FAILPOINTS` env, the format is `<NAME>=<ACTIONS>` separated by `;`,
+/// for example, `FAILPOINTS="rocksdb_write=1%return;executor_after_apply=1%panic"`.
+#[cfg(feature = "failpoints")]
+fn init_failpoints() -> Result<()> {
+    let Ok(failpoints) = env::var("FAILPOINTS") else {
+        return Ok(());
+    };
+    for failpoint in failpoints.split(';').filter(|cfg| !cfg.trim().is_empty()) {
+        let (name, actions) = failpoint
+            .split_once('=')
+            .ok_or_else(|| anyhow::anyhow!("Invalid fail point: {}", failpoint))?;
+        fail::cfg(name.trim(), actions.trim()).map_err(|e| anyhow::anyhow!(e))?;
+        tracing::warn!(target: LOG_TARGET, "RPC Server fail point {} is enabled: {}", name.trim(), actions.trim());
+    }
+    Ok(())
+}
+
+pub fn init_storage(store_config: &StoreConfig) -> Result<(MoveOSStore, RoochStore)> {
+    init_storage_with_metrics(store_config, None)
+}
+
+/// Init the stores, the store metrics and the RocksDB properties are registered to the `metrics_registry` if present
+pub fn init_storage_with_metrics(
+    store_config: &StoreConfig,
+    metrics_registry: Option<&Registry>,
+) -> Result<(MoveOSStore, RoochStore)> {
     let (rooch_db_path, moveos_db_path) = (
         store_config.get_rooch_store_dir(),
         store_config.get_moveos_store_dir(),
     );
+    let store_metrics = metrics_registry.map(StoreMetrics::register).transpose()?;
 
     //Init store
-    let moveosdb = MoveOSDB::new(StoreInstance::new_db_instance(RocksDB::new(
+    let moveos_instance = StoreInstance::new_db_instance(RocksDB::new(
         moveos_db_path,
         moveos_store::StoreMeta::get_column_family_names().to_vec(),
         store_config.rocksdb_config(),
-        None,
-    )?))?;
+        store_metrics.clone(),
+    )?);
+    let rooch_instance = StoreInstance::new_db_instance(RocksDB::new(
+        rooch_db_path,
+        rooch_store::StoreMeta::get_column_family_names().to_vec(),
+        store_config.rocksdb_config(),
+        store_metrics,
+    )?);
+    load_storage(
+        store_config,
+        moveos_instance,
+        rooch_instance,
+        metrics_registry,
+    )
+}
+
+/// Init the stores of the read replica as the RocksDB secondary instances of the stores of the primary node,
+/// the secondary dbs are returned to catch up with the primary node periodically.
+pub fn init_replica_storage_with_metrics(
+    store_config: &StoreConfig,
+    metrics_registry: Option<&Registry>,
+) -> Result<(MoveOSStore, RoochStore, Vec<Arc<RocksDB>>)> {
+    let (Some(replica_rooch_db_path), Some(replica_moveos_db_path)) = (
+        store_config.get_replica_rooch_store_dir(),
+        store_config.get_replica_moveos_store_dir(),
+    ) else {
+        anyhow::bail!("The read replica dir is not configured");
+    };
+    let store_metrics = metrics_registry.map(StoreMetrics::register).transpose()?;
+
+    let moveos_instance = StoreInstance::new_db_instance(RocksDB::open_secondary(
+        store_config.get_moveos_store_dir(),
+        replica_moveos_db_path,
+        moveos_store::StoreMeta::get_column_family_names().to_vec(),
+        store_config.rocksdb_config(),
+        store_metrics.clone(),
+    )?);
+    let rooch_instance = StoreInstance::new_db_instance(RocksDB::open_secondary(
+        store_config.get_rooch_store_dir(),
+        replica_rooch_db_path,
+        rooch_store::StoreMeta::get_column_family_names().to_vec(),
+        store_config.rocksdb_config(),
+        store_metrics,
+    )?);
+    let dbs = [&moveos_instance, &rooch_instance]
+        .into_iter()
+        .filter_map(|instance| instance.shared_db())
+        .collect();
+    let (moveos_store, rooch_store) = load_storage(
+        store_config,
+        moveos_instance,
+        rooch_instance,
+        metrics_registry,
+    )?;
+    Ok((moveos_store, rooch_store, dbs))
+}
+
+fn load_storage(
+    store_config: &StoreConfig,
+    moveos_instance: StoreInstance,
+    rooch_instance: StoreInstance,
+    metrics_registry: Option<&Registry>,
+) -> Result<(MoveOSStore, RoochStore)> {
+    if let Some(registry) = metrics_registry {
+        let dbs = [&moveos_instance, &rooch_instance]
+            .into_iter()
+            .filter_map(|instance| instance.shared_db())
+            .collect();
+        RocksDBCollector::register(registry, dbs)?;
+    }
+
+    let moveosdb =
+        MoveOSDB::new_with_node_cache_size(moveos_instance, store_config.state_node_cache_size())?;
     let lastest_state_root = moveosdb
         .config_store
         .get_startup_info()?
         .map(|info| info.state_root_hash);
 
     if let Some(latest_state_root) = lastest_state_root {
-        info!("Load latest state root {:?}", latest_state_root);
+        info!(target: LOG_TARGET, "Load latest state root {:?}", latest_state_root);
     }
     let moveos_store = MoveOSStore::new_with_root(moveosdb, lastest_state_root)?;
 
-    let rooch_store = RoochStore::new(StoreInstance::new_db_instance(RocksDB::new(
-        rooch_db_path,
-        rooch_store::StoreMeta::get_column_family_names().to_vec(),
-        store_config.rocksdb_config(),
-        None,
-    )?))?;
+    let rooch_store = RoochStore::new(rooch_instance)?;
     Ok((moveos_store, rooch_store))
 }
 
-fn init_indexer(indexer_config: &IndexerConfig) -> Result<(IndexerStore, IndexerReader)> {
+/// The indexer tables written by the node role
+fn indexer_tables(node_role: NodeRole) -> Vec<IndexerProgressTable> {
+    let mut tables = vec![];
+    if node_role.index_transactions() {
+        tables.push(IndexerProgressTable::Transactions);
+    }
+    if node_role.index_events() {
+        tables.push(IndexerProgressTable::Events);
+    }
+    if node_role.index_states() {
+        tables.push(IndexerProgressTable::States);
+    }
+    tables
+}
+
+pub fn init_indexer(indexer_config: &IndexerConfig) -> Result<(IndexerStore, IndexerReader)> {
     let indexer_db_path = indexer_config.get_indexer_db();
     let indexer_db_url = indexer_db_path
         .to_str()
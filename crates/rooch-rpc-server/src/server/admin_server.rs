@@ -0,0 +1,36 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::service::telemetry::{log_filter, set_log_filter};
+use crate::LOG_TARGET;
+use jsonrpsee::core::{async_trait, RpcResult};
+use jsonrpsee::RpcModule;
+use rooch_rpc_api::api::admin_api::AdminAPIServer;
+use rooch_rpc_api::api::RoochRpcModule;
+use rooch_rpc_api::jsonrpc_types::RpcError;
+use tracing::info;
+use tracing_subscriber::EnvFilter;
+
+pub struct AdminServer;
+
+#[async_trait]
+impl AdminAPIServer for AdminServer {
+    async fn get_log_filter(&self) -> RpcResult<String> {
+        Ok(log_filter()?)
+    }
+
+    async fn set_log_filter(&self, filter: String) -> RpcResult<()> {
+        let env_filter = EnvFilter::try_new(&filter).map_err(|e| {
+            RpcError::invalid_params(format!("Invalid log filter {}: {}", filter, e))
+        })?;
+        set_log_filter(env_filter)?;
+        info!(target: LOG_TARGET, "Reload the log filter: {}", filter);
+        Ok(())
+    }
+}
+
+impl RoochRpcModule for AdminServer {
+    fn rpc(self) -> RpcModule<Self> {
+        self.into_rpc()
+    }
+}
@@ -1,26 +1,28 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::service::{aggregate_service::AggregateService, rpc_service::RpcService};
+use crate::service::{
+    aggregate_service::AggregateService, query_limits::QueryLimits, rpc_service::RpcService,
+};
 use jsonrpsee::{
     core::{async_trait, RpcResult},
     RpcModule,
 };
 use move_core_types::account_address::AccountAddress;
 use rooch_rpc_api::api::btc_api::BtcAPIServer;
-use rooch_rpc_api::api::{RoochRpcModule, DEFAULT_RESULT_LIMIT_USIZE, MAX_RESULT_LIMIT_USIZE};
+use rooch_rpc_api::api::RoochRpcModule;
 use rooch_rpc_api::jsonrpc_types::btc::ord::{InscriptionFilterView, InscriptionStateView};
 use rooch_rpc_api::jsonrpc_types::btc::utxo::{UTXOFilterView, UTXOStateView};
 use rooch_rpc_api::jsonrpc_types::{InscriptionPageView, StrView, UTXOPageView};
 use rooch_types::address::MultiChainAddress;
 use rooch_types::indexer::state::IndexerStateID;
 use rooch_types::multichain_id::RoochMultiChainID;
-use std::cmp::min;
 
 pub struct BtcServer {
     rpc_service: RpcService,
     aggregate_service: AggregateService,
     btc_network: u8,
+    query_limits: QueryLimits,
 }
 
 impl BtcServer {
@@ -28,11 +30,13 @@ impl BtcServer {
         rpc_service: RpcService,
         aggregate_service: AggregateService,
         btc_network: u8,
+        query_limits: QueryLimits,
     ) -> Self {
         Self {
             rpc_service,
             aggregate_service,
             btc_network,
+            query_limits,
         }
     }
 }
@@ -47,10 +51,9 @@ impl BtcAPIServer for BtcServer {
         limit: Option<StrView<usize>>,
         descending_order: Option<bool>,
     ) -> RpcResult<UTXOPageView> {
-        let limit_of = min(
-            limit.map(Into::into).unwrap_or(DEFAULT_RESULT_LIMIT_USIZE),
-            MAX_RESULT_LIMIT_USIZE,
-        );
+        let limit_of = self
+            .query_limits
+            .page_size("btc_queryUTXOs", limit.map(Into::into))?;
         let descending_order = descending_order.unwrap_or(true);
 
         let resolve_address = match filter.clone() {
@@ -66,10 +69,10 @@ impl BtcAPIServer for BtcServer {
             _ => AccountAddress::ZERO,
         };
 
-        let global_state_filter = UTXOFilterView::into_global_state_filter(filter, resolve_address);
+        let utxo_filter = UTXOFilterView::into_utxo_filter(filter, resolve_address);
         let states = self
             .rpc_service
-            .query_global_states(global_state_filter, cursor, limit_of + 1, descending_order)
+            .query_utxos(utxo_filter, cursor, limit_of + 1, descending_order)
             .await?;
 
         let mut data = self
@@ -101,10 +104,9 @@ impl BtcAPIServer for BtcServer {
         limit: Option<StrView<usize>>,
         descending_order: Option<bool>,
     ) -> RpcResult<InscriptionPageView> {
-        let limit_of = min(
-            limit.map(Into::into).unwrap_or(DEFAULT_RESULT_LIMIT_USIZE),
-            MAX_RESULT_LIMIT_USIZE,
-        );
+        let limit_of = self
+            .query_limits
+            .page_size("btc_queryInscriptions", limit.map(Into::into))?;
         let descending_order = descending_order.unwrap_or(true);
 
         let resolve_address = match filter.clone() {
@@ -2,17 +2,23 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::service::{aggregate_service::AggregateService, rpc_service::RpcService};
+use crate::LOG_TARGET;
 use ethers::types::{H160, U256, U64};
 use jsonrpsee::{
-    core::{async_trait, Error as JsonRpcError, RpcResult},
+    core::{async_trait, RpcResult},
     RpcModule,
 };
+use move_core_types::vm_status::VMStatus;
 use moveos_types::{
-    access_path::AccessPath, gas_config::GasConfig, h256::H256, state::MoveStructType,
+    access_path::AccessPath, function_return_value::FunctionReturnValue, gas_config::GasConfig,
+    h256::H256, state::MoveStructType, transaction::MoveAction,
 };
 use rooch_rpc_api::{
-    api::eth_api::EthNetAPIServer,
-    jsonrpc_types::{eth::ethereum_types::bloom::Bloom, H160View},
+    api::{eth_api::EthNetAPIServer, MAX_RESULT_LIMIT_USIZE},
+    jsonrpc_types::{
+        eth::{ethereum_types::bloom::Bloom, ethereum_types::log::Log, LogFilter},
+        H160View,
+    },
 };
 use rooch_rpc_api::{
     api::{
@@ -28,13 +34,14 @@ use rooch_rpc_api::{
             },
             AccessList, CallRequest, EthFeeHistory, Transaction, TransactionReceipt,
         },
-        BytesView, H256View, StrView,
+        BytesView, H256View, RpcError, RpcErrorCode, StrView,
     },
 };
 use rooch_types::{
     account::Account,
     address::{EthereumAddress, MultiChainAddress},
     framework::gas_coin::GasCoin,
+    indexer::event_filter::{EventFilter, IndexerEvent, IndexerEventID},
     transaction::{AbstractTransaction, TypedTransaction},
 };
 use rooch_types::{chain_id::ChainID, transaction::ethereum::EthereumTransaction};
@@ -43,6 +50,9 @@ use std::str::FromStr;
 use std::time::SystemTime;
 use tracing::info;
 
+/// The max number of the logs returned by `eth_getLogs`, the client should narrow the block range if it is exceeded
+pub const MAX_LOGS_LIMIT: usize = 10000;
+
 pub struct EthServer {
     chain_id: ChainID,
     rpc_service: RpcService,
@@ -61,6 +71,98 @@ impl EthServer {
             aggregate_service,
         }
     }
+
+    /// The block is the Rooch transaction, so the block number is the tx order
+    async fn resolve_block_number(&self, num: Option<StrView<BlockNumber>>) -> RpcResult<u64> {
+        match num.map(|num| num.0) {
+            Some(BlockNumber::Number(number)) => Ok(number.as_u64()),
+            Some(BlockNumber::Earliest) => Ok(0),
+            _ => Ok(self
+                .rpc_service
+                .get_sequencer_order()
+                .await?
+                .map(|order| order.last_order)
+                .unwrap_or(0)),
+        }
+    }
+
+    async fn query_logs(
+        &self,
+        event_filter: EventFilter,
+        log_filter: &LogFilter,
+    ) -> RpcResult<Vec<Log>> {
+        let mut logs = vec![];
+        let mut cursor: Option<IndexerEventID> = None;
+        loop {
+            let events = self
+                .rpc_service
+                .query_events(event_filter.clone(), cursor, MAX_RESULT_LIMIT_USIZE, false)
+                .await?;
+            let has_next_page = events.len() == MAX_RESULT_LIMIT_USIZE;
+            cursor = events.last().map(|event| event.indexer_event_id);
+            logs.extend(
+                events
+                    .into_iter()
+                    .map(event_to_log)
+                    .filter(|log| log_matches(log_filter, log)),
+            );
+            if logs.len() > MAX_LOGS_LIMIT {
+                return Err(RpcError::invalid_params(format!(
+                    "query returned more than {} results",
+                    MAX_LOGS_LIMIT
+                ))
+                .into());
+            }
+            if !has_next_page {
+                break;
+            }
+        }
+        Ok(logs)
+    }
+}
+
+/// The log address is the last 20 bytes of the module address of the event type,
+/// and the only topic is the keccak256 hash of the event type, like the event signature hash of Solidity.
+fn event_to_log(event: IndexerEvent) -> Log {
+    let module_address = event.event_type.address.to_vec();
+    let address = H160::from_slice(&module_address[module_address.len() - H160::len_bytes()..]);
+    let topic = H256::from(ethers::utils::keccak256(
+        event.event_type.to_canonical_string(),
+    ));
+    let event_index = U256::from(event.indexer_event_id.event_index);
+    Log {
+        address: address.into(),
+        topics: vec![topic.into()],
+        data: BytesView::from(event.event_data),
+        block_hash: Some(event.tx_hash.into()),
+        block_number: Some(StrView(event.indexer_event_id.tx_order)),
+        transaction_hash: Some(event.tx_hash.into()),
+        transaction_index: Some(StrView(0)),
+        log_index: Some(StrView(event_index)),
+        transaction_log_index: Some(StrView(event_index)),
+        log_type: None,
+        removed: Some(false),
+    }
+}
+
+fn log_matches(filter: &LogFilter, log: &Log) -> bool {
+    if let Some(address) = &filter.address {
+        if !address.contains(&log.address) {
+            return false;
+        }
+    }
+    if let Some(topics) = &filter.topics {
+        for (position, topic) in topics.iter().enumerate() {
+            let Some(topic) = topic else {
+                continue;
+            };
+            match log.topics.get(position) {
+                Some(log_topic) if topic.contains(log_topic) => {}
+                _ => return false,
+            }
+        }
+    }
+    true
 }
 
 #[async_trait]
@@ -86,7 +188,7 @@ impl EthAPIServer for EthServer {
         let block_number = num
             .0
             .as_number()
-            .ok_or_else(|| JsonRpcError::Custom("block number should be a number".to_string()))?;
+            .ok_or_else(|| RpcError::invalid_params("block number should be a number"))?;
         let parent_hash =
             H256::from_str("0xe5ece23ec875db0657f964cbc74fa34439eef3ab3dc8664e7f4ae8b5c5c963e1")
                 .unwrap();
@@ -307,9 +409,7 @@ impl EthAPIServer for EthServer {
                 })
             }
             None => {
-                return Err(JsonRpcError::Custom(String::from(
-                    "newest_block not a number",
-                )));
+                return Err(RpcError::invalid_params("newest_block not a number").into());
             }
         }
     }
@@ -329,14 +429,17 @@ impl EthAPIServer for EthServer {
             .resolve_address(MultiChainAddress::from(EthereumAddress(address.into())))
             .await?;
 
-        info!(
+        info!(target: LOG_TARGET,
             "transaction_count source address: {:?}, rooch address: {:?}",
             address, account_address
         );
 
         let seq_number = self
             .rpc_service
-            .get_states(AccessPath::resource(account_address, Account::struct_tag()))
+            .get_states(
+                None,
+                AccessPath::resource(account_address, Account::struct_tag()),
+            )
             .await?
             .pop()
             .flatten()
@@ -346,65 +449,84 @@ impl EthAPIServer for EthServer {
                 StrView(<u64 as Into<U256>>::into(account.sequence_number))
             });
 
-        info!("transaction_count seq_number: {:?}", seq_number);
+        info!(target: LOG_TARGET, "transaction_count seq_number: {:?}", seq_number);
 
         Ok(seq_number)
     }
 
     async fn send_raw_transaction(&self, bytes: BytesView) -> RpcResult<H256View> {
-        info!("send_raw_transaction: {:?}", bytes);
+        info!(target: LOG_TARGET, "send_raw_transaction: {:?}", bytes);
         let eth_tx = EthereumTransaction::decode(&bytes.0)?;
-        info!("send_raw_transaction input: {:?}", eth_tx.0.input);
+        info!(target: LOG_TARGET, "send_raw_transaction input: {:?}", eth_tx.0.input);
         let action = eth_tx.decode_calldata_to_action()?;
-        info!(
+        info!(target: LOG_TARGET,
             "send_raw_transaction decode_calldata_to_action: {:?}",
             action
         );
-        info!(
+        info!(target: LOG_TARGET,
             "send_raw_transaction from: {:?}, nonce: {:?}",
             eth_tx.0.from, eth_tx.0.nonce
         );
 
         let tx = TypedTransaction::Ethereum(eth_tx);
-        info!(
+        info!(target: LOG_TARGET,
             "send_raw_transaction authenticator_info: {:?}",
             tx.authenticator_info().unwrap()
         );
 
         let hash = H256View::from(tx.tx_hash());
-        let _output = self.rpc_service.execute_tx(tx).await?;
+        let _output = self
+            .rpc_service
+            .execute_tx(tx)
+            .await
+            .map_err(RpcError::from)?;
         Ok(hash)
     }
 
     async fn transaction_receipt(&self, hash: H256View) -> RpcResult<Option<TransactionReceipt>> {
-        let result = self
+        let tx_hash: H256 = hash.into();
+        let Some(info) = self
             .rpc_service
-            .get_transaction_execution_infos_by_hash(vec![hash.into()])
+            .get_transaction_execution_infos_by_hash(vec![tx_hash])
             .await?
-            .into_iter()
-            .last()
-            .and_then(|trans| {
-                trans.map(|info| TransactionReceipt {
-                    transaction_hash: info.tx_hash.into(),
-                    block_hash: Some(info.state_root.into()),
-                    block_number: Some(StrView(U64::from(10u16))),
-                    gas_used: Some(StrView(<u64 as Into<U256>>::into(info.gas_used))),
-                    status: Some(StrView(U64::from(info.status.is_success() as u8))),
-                    cumulative_gas_used: StrView(<u64 as Into<U256>>::into(info.gas_used)),
-                    contract_address: None,
-                    logs: Vec::new(),
-                    logs_bloom: Bloom::default(),
-                    transaction_index: StrView(U64::from(0u8)),
-                    from: StrView(H160::default()),
-                    to: Some(StrView(H160::default())),
-                    root: Some(H256View::from(H256::default())),
-                    transaction_type: None,
-                    effective_gas_price: None,
-                    other: OtherFields::default(),
-                })
-            });
+            .pop()
+            .flatten()
+        else {
+            return Ok(None);
+        };
+        let tx_order = self
+            .rpc_service
+            .get_tx_sequence_info_mapping_by_hash(vec![tx_hash])
+            .await?
+            .pop()
+            .flatten()
+            .map(|mapping| mapping.tx_order);
+        let (from, to) = match self.rpc_service.get_transaction_by_hash(tx_hash).await? {
+            Some(TypedTransaction::Ethereum(eth_tx)) => (eth_tx.0.from, eth_tx.0.to),
+            _ => (H160::default(), None),
+        };
+        let logs = self
+            .query_logs(EventFilter::TxHash(tx_hash), &LogFilter::default())
+            .await?;
 
-        Ok(result)
+        Ok(Some(TransactionReceipt {
+            transaction_hash: info.tx_hash.into(),
+            block_hash: Some(info.tx_hash.into()),
+            block_number: tx_order.map(|tx_order| StrView(U64::from(tx_order))),
+            gas_used: Some(StrView(<u64 as Into<U256>>::into(info.gas_used))),
+            status: Some(StrView(U64::from(info.status.is_success() as u8))),
+            cumulative_gas_used: StrView(<u64 as Into<U256>>::into(info.gas_used)),
+            contract_address: None,
+            logs,
+            logs_bloom: Bloom::default(),
+            transaction_index: StrView(U64::from(0u8)),
+            from: StrView(from),
+            to: to.map(StrView),
+            root: Some(info.state_root.into()),
+            transaction_type: None,
+            effective_gas_price: None,
+            other: OtherFields::default(),
+        }))
     }
 
     async fn transaction_by_hash(&self, hash: H256View) -> RpcResult<Option<Transaction>> {
@@ -565,6 +687,75 @@ impl EthAPIServer for EthServer {
         Ok(block)
     }
 
+    async fn call(
+        &self,
+        request: CallRequest,
+        _num: Option<StrView<BlockNumber>>,
+    ) -> RpcResult<BytesView> {
+        let data = request
+            .data
+            .ok_or_else(|| RpcError::invalid_params("The call data is empty"))?;
+        let function_call = match bcs::from_bytes::<MoveAction>(&data.0) {
+            Ok(MoveAction::Function(function_call)) => function_call,
+            Ok(_) => {
+                return Err(RpcError::invalid_params(
+                    "Only the function call can be executed by eth_call",
+                )
+                .into())
+            }
+            Err(e) => {
+                return Err(RpcError::invalid_params(format!(
+                    "Decode call data to action failed: {}",
+                    e
+                ))
+                .into())
+            }
+        };
+        let result = self
+            .rpc_service
+            .execute_view_function(function_call)
+            .await?;
+        if result.vm_status != VMStatus::Executed {
+            let message = format!("Execute view function failed: {:?}", result.vm_status);
+            return Err(RpcError::with_vm_status(result.vm_status, message).into());
+        }
+        let return_values: Vec<FunctionReturnValue> = result
+            .return_values
+            .unwrap_or_default()
+            .into_iter()
+            .map(|return_value| return_value.value)
+            .collect();
+        let bytes = bcs::to_bytes(&return_values).map_err(|e| {
+            RpcError::new(
+                RpcErrorCode::InternalError,
+                format!("Encode return values failed: {}", e),
+            )
+        })?;
+        Ok(BytesView::from(bytes))
+    }
+
+    async fn get_logs(&self, filter: LogFilter) -> RpcResult<Vec<Log>> {
+        let event_filter = match &filter.block_hash {
+            Some(block_hash) => EventFilter::TxHash(block_hash.clone().into()),
+            None => {
+                let from_order = self.resolve_block_number(filter.from_block.clone()).await?;
+                let to_order = self.resolve_block_number(filter.to_block.clone()).await?;
+                if from_order > to_order {
+                    return Err(RpcError::invalid_params(format!(
+                        "Invalid block range [{}, {}]",
+                        from_order, to_order
+                    ))
+                    .into());
+                }
+                EventFilter::TxOrderRange {
+                    from_order,
+                    to_order: to_order + 1,
+                }
+            }
+        };
+        self.query_logs(event_filter, &filter).await
+    }
+
     async fn get_code(
         &self,
         _address: H160View,
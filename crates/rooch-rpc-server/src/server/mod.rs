@@ -1,6 +1,8 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
 
+pub mod admin_server;
 pub mod btc_server;
 pub mod eth_server;
 pub mod rooch_server;
+pub mod subscription_server;
@@ -2,43 +2,61 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::service::aggregate_service::AggregateService;
+use crate::service::query_limits::QueryLimits;
 use crate::service::rpc_service::RpcService;
+use crate::LOG_TARGET;
 use anyhow::Result;
 use jsonrpsee::{
-    core::{async_trait, Error as JsonRpcError, RpcResult},
+    core::{async_trait, RpcResult},
     RpcModule,
 };
 use move_core_types::account_address::AccountAddress;
+use moveos_common::utils::now_seconds;
+use moveos_types::access_path::AccessPath;
+use moveos_types::gas_config::GasConfig;
 use moveos_types::h256::H256;
+use moveos_types::moveos_std::object_id::ObjectID;
 use moveos_types::state::KeyState;
+use moveos_types::transaction::RawTransactionOutput;
+use rooch_proposer::fee::max_gas_amount_with_margin;
+use rooch_rpc_api::api::rooch_api::RoochAPIServer;
+use rooch_rpc_api::jsonrpc_types::coin_transfer_view::IndexerCoinTransferView;
 use rooch_rpc_api::jsonrpc_types::event_view::{EventFilterView, EventView, IndexerEventView};
-use rooch_rpc_api::jsonrpc_types::transaction_view::TransactionFilterView;
+use rooch_rpc_api::jsonrpc_types::module_view::{IndexerModuleView, ModuleFilterView};
+use rooch_rpc_api::jsonrpc_types::transaction_view::{
+    PendingTransactionPageView, PendingTransactionView, TransactionFilterView,
+};
 use rooch_rpc_api::jsonrpc_types::{
-    account_view::BalanceInfoView, GlobalStateFilterView, IndexerEventPageView,
-    IndexerGlobalStatePageView, IndexerGlobalStateView, IndexerTableChangeSetPageView,
-    IndexerTableChangeSetView, IndexerTableStatePageView, IndexerTableStateView, KeyStateView,
-    StateKVView, StateOptions, StateSyncFilterView, TableStateFilterView,
+    account_view::{AccountSummaryView, AddressMappingView, BalanceInfoView, StorageUsageView},
+    CoinInfoView, GlobalStateFilterView, IndexerCoinTransferPageView, IndexerEventPageView,
+    IndexerGlobalStatePageView, IndexerGlobalStateView, IndexerModulePageView,
+    IndexerObjectOwnershipChangeView, IndexerTableChangeSetPageView, IndexerTableChangeSetView,
+    IndexerTableStatePageView, IndexerTableStateView, KeyStateView, ModuleIdView,
+    MoveModuleABIView, ObjectOwnershipChangePageView, QueryOptions, ScheduledTaskPageView,
+    ScheduledTaskView, SendTransactionResultView, StateKVView, StateOptions, StateProofView,
+    StateSyncFilterView, TableStateFilterView,
 };
 use rooch_rpc_api::jsonrpc_types::{transaction_view::TransactionWithInfoView, EventOptions};
 use rooch_rpc_api::jsonrpc_types::{
-    AccessPathView, AccountAddressView, BalanceInfoPageView, EventPageView,
-    ExecuteTransactionResponseView, FunctionCallView, H256View, StatePageView, StateView, StrView,
-    StructTagView, TransactionWithInfoPageView,
-};
-use rooch_rpc_api::{api::rooch_api::RoochAPIServer, api::DEFAULT_RESULT_LIMIT};
-use rooch_rpc_api::{
-    api::{RoochRpcModule, DEFAULT_RESULT_LIMIT_USIZE},
-    jsonrpc_types::AnnotatedFunctionResultView,
+    AccessPathView, AccountAddressView, BalanceInfoPageView, DryRunTransactionResponseView,
+    EventPageView, ExecuteTransactionResponseView, FunctionCallView, GasEstimateView,
+    GasPriceEstimateView, GasUsageStatsView, H256View, RpcError, RpcErrorCode, SessionKeyPageView,
+    StatePageView, StateView, StrView, StructTagView, TransactionWithInfoPageView,
 };
 use rooch_rpc_api::{
-    api::{MAX_RESULT_LIMIT, MAX_RESULT_LIMIT_USIZE},
-    jsonrpc_types::BytesView,
+    api::{RoochRpcModule, MAX_RESULT_LIMIT_USIZE},
+    jsonrpc_types::{AnnotatedFunctionResultView, BytesView},
 };
-use rooch_types::indexer::event_filter::IndexerEventID;
+use rooch_types::framework::account_coin_store::AccountCoinStoreModule;
+use rooch_types::indexer::event_filter::{EventFilter, IndexerEventID};
 use rooch_types::indexer::state::IndexerStateID;
-use rooch_types::transaction::rooch::RoochTransaction;
+use rooch_types::transaction::rooch::{RoochTransaction, RoochTransactionData};
+use rooch_types::transaction::view::SignedViewFunctionCall;
 use rooch_types::transaction::{AbstractTransaction, TypedTransaction};
-use rooch_types::{address::MultiChainAddress, multichain_id::RoochMultiChainID};
+use rooch_types::{
+    address::{MultiChainAddress, RoochAddress},
+    multichain_id::RoochMultiChainID,
+};
 use std::cmp::min;
 use std::str::FromStr;
 use tracing::info;
@@ -46,13 +64,107 @@ use tracing::info;
 pub struct RoochServer {
     rpc_service: RpcService,
     aggregate_service: AggregateService,
+    max_tx_batch_size: usize,
+    btc_network: u8,
+    query_limits: QueryLimits,
 }
 
 impl RoochServer {
-    pub fn new(rpc_service: RpcService, aggregate_service: AggregateService) -> Self {
+    pub fn new(
+        rpc_service: RpcService,
+        aggregate_service: AggregateService,
+        max_tx_batch_size: usize,
+        btc_network: u8,
+        query_limits: QueryLimits,
+    ) -> Self {
         Self {
             rpc_service,
             aggregate_service,
+            max_tx_batch_size,
+            btc_network,
+            query_limits,
+        }
+    }
+
+    /// Dry run the signed transaction or the unsigned transaction data.
+    /// The signed transaction and the unsigned transaction data are unambiguous in bcs,
+    /// because bcs requires all the bytes to be consumed.
+    async fn dry_run(&self, payload: BytesView, gas_profile: bool) -> Result<RawTransactionOutput> {
+        match RoochTransaction::decode(&payload.0) {
+            Ok(tx) => {
+                self.rpc_service
+                    .dry_run_tx(TypedTransaction::Rooch(tx), gas_profile)
+                    .await
+            }
+            Err(_) => {
+                let tx_data = bcs::from_bytes::<RoochTransactionData>(&payload.0)?;
+                self.rpc_service.dry_run_tx_data(tx_data, gas_profile).await
+            }
+        }
+    }
+
+    /// Fill the events emitted by the transactions from the indexer
+    async fn fill_transaction_events<'a>(
+        &self,
+        txs: impl Iterator<Item = &'a mut TransactionWithInfoView>,
+    ) -> Result<()> {
+        for tx in txs {
+            let events = self
+                .rpc_service
+                .query_events(
+                    EventFilter::TxHash(tx.execution_info.tx_hash.0),
+                    None,
+                    MAX_RESULT_LIMIT_USIZE,
+                    false,
+                )
+                .await?
+                .into_iter()
+                .map(IndexerEventView::from)
+                .collect();
+            tx.events = Some(events);
+        }
+        Ok(())
+    }
+
+    /// Resolve the state root of the historical state query, None means the latest state.
+    /// The state root of a tx order is the one after the transaction is executed.
+    async fn historical_state_root(&self, state_option: &StateOptions) -> RpcResult<Option<H256>> {
+        match (&state_option.state_root, &state_option.tx_order) {
+            (Some(_), Some(_)) => Err(RpcError::invalid_params(
+                "The state_root and tx_order can not be specified at the same time",
+            )
+            .into()),
+            (Some(state_root), None) => Ok(Some((*state_root).into())),
+            (None, Some(tx_order)) => {
+                let tx_order = tx_order.0;
+                let tx_hash = self
+                    .rpc_service
+                    .get_tx_sequence_info_mapping_by_order(vec![tx_order])
+                    .await?
+                    .pop()
+                    .flatten()
+                    .ok_or_else(|| {
+                        RpcError::invalid_params(format!(
+                            "The tx order {} does not exist",
+                            tx_order
+                        ))
+                    })?
+                    .tx_hash;
+                let execution_info = self
+                    .rpc_service
+                    .get_transaction_execution_infos_by_hash(vec![tx_hash])
+                    .await?
+                    .pop()
+                    .flatten()
+                    .ok_or_else(|| {
+                        RpcError::new(
+                            RpcErrorCode::InternalError,
+                            format!("The execution info of tx order {} does not exist", tx_order),
+                        )
+                    })?;
+                Ok(Some(execution_info.state_root))
+            }
+            (None, None) => Ok(None),
         }
     }
 }
@@ -64,30 +176,167 @@ impl RoochAPIServer for RoochServer {
         Ok(StrView(chain_id))
     }
 
+    async fn get_sequence_number(&self, address: AccountAddressView) -> RpcResult<StrView<u64>> {
+        let sequence_number = self.rpc_service.get_sequence_number(address.into())?;
+        Ok(StrView(sequence_number))
+    }
+
     async fn send_raw_transaction(&self, payload: BytesView) -> RpcResult<H256View> {
-        info!("send_raw_transaction payload: {:?}", payload);
-        let tx = bcs::from_bytes::<RoochTransaction>(&payload.0).map_err(anyhow::Error::from)?;
-        info!("send_raw_transaction tx: {:?}", tx);
+        info!(target: LOG_TARGET, "send_raw_transaction payload: {:?}", payload);
+        let tx = RoochTransaction::decode(&payload.0)
+            .map_err(|e| RpcError::invalid_params(format!("Decode transaction failed: {}", e)))?;
+        info!(target: LOG_TARGET, "send_raw_transaction tx: {:?}", tx);
 
         let hash = tx.tx_hash();
         self.rpc_service
             .quene_tx(TypedTransaction::Rooch(tx))
-            .await?;
+            .await
+            .map_err(RpcError::from)?;
         Ok(hash.into())
     }
 
+    async fn send_raw_transaction_batch(
+        &self,
+        payloads: Vec<BytesView>,
+    ) -> RpcResult<Vec<SendTransactionResultView>> {
+        if payloads.len() > self.max_tx_batch_size {
+            return Err(RpcError::invalid_params(format!(
+                "Transaction batch size {} exceeds the max batch size {}",
+                payloads.len(),
+                self.max_tx_batch_size
+            ))
+            .into());
+        }
+
+        let mut results = Vec::with_capacity(payloads.len());
+        for payload in payloads {
+            let tx = match RoochTransaction::decode(&payload.0) {
+                Ok(tx) => tx,
+                Err(e) => {
+                    results.push(SendTransactionResultView::failure(
+                        None,
+                        RpcError::invalid_params(format!("Decode transaction failed: {}", e)),
+                    ));
+                    continue;
+                }
+            };
+            let hash = tx.tx_hash();
+            let result = match self.rpc_service.quene_tx(TypedTransaction::Rooch(tx)).await {
+                Ok(_) => SendTransactionResultView::success(hash.into()),
+                Err(e) => SendTransactionResultView::failure(Some(hash.into()), e.into()),
+            };
+            results.push(result);
+        }
+        Ok(results)
+    }
+
     async fn execute_raw_transaction(
         &self,
         payload: BytesView,
     ) -> RpcResult<ExecuteTransactionResponseView> {
-        let tx = bcs::from_bytes::<RoochTransaction>(&payload.0).map_err(anyhow::Error::from)?;
+        let tx = RoochTransaction::decode(&payload.0)
+            .map_err(|e| RpcError::invalid_params(format!("Decode transaction failed: {}", e)))?;
         Ok(self
             .rpc_service
             .execute_tx(TypedTransaction::Rooch(tx))
-            .await?
+            .await
+            .map_err(RpcError::from)?
             .into())
     }
 
+    async fn dry_run_transaction(
+        &self,
+        payload: BytesView,
+        gas_profile: Option<bool>,
+    ) -> RpcResult<DryRunTransactionResponseView> {
+        let output = self
+            .dry_run(payload, gas_profile.unwrap_or(false))
+            .await
+            .map_err(RpcError::from)?;
+        Ok(output.into())
+    }
+
+    async fn estimate_gas_price(&self) -> RpcResult<GasPriceEstimateView> {
+        let gas_price = self.rpc_service.get_gas_price()?;
+        let stats = self.rpc_service.get_gas_usage_stats().await?;
+        let recommended_max_gas_amount = if stats.sample_count == 0 {
+            GasConfig::DEFAULT_MAX_GAS_AMOUNT
+        } else {
+            max_gas_amount_with_margin(stats.p99)
+        };
+        Ok(GasPriceEstimateView {
+            gas_price,
+            gas_usage: GasUsageStatsView {
+                sample_count: stats.sample_count,
+                min: stats.min,
+                p50: stats.p50,
+                p75: stats.p75,
+                p90: stats.p90,
+                p99: stats.p99,
+                max: stats.max,
+            },
+            recommended_max_gas_amount,
+        })
+    }
+
+    async fn estimate_gas(&self, payload: BytesView) -> RpcResult<GasEstimateView> {
+        let output = self.dry_run(payload, false).await.map_err(RpcError::from)?;
+        let gas_price = self.rpc_service.get_gas_price()?;
+        Ok(GasEstimateView {
+            status: output.status.into(),
+            gas_used: output.gas_used,
+            max_gas_amount: max_gas_amount_with_margin(output.gas_used),
+            gas_price,
+        })
+    }
+
+    async fn get_pending_transactions(
+        &self,
+        cursor: Option<StrView<u64>>,
+        limit: Option<StrView<usize>>,
+    ) -> RpcResult<PendingTransactionPageView> {
+        let limit_of = self
+            .query_limits
+            .page_size("rooch_getPendingTransactions", limit.map(Into::into))?;
+        let cursor = cursor.map(|v| v.0);
+        let gas_price = self.rpc_service.get_gas_price()?;
+        let mut pending_txs = self
+            .rpc_service
+            .get_pending_transactions(cursor, limit_of + 1)
+            .await?;
+
+        let has_next_page = pending_txs.len() > limit_of;
+        pending_txs.truncate(limit_of);
+        let next_cursor = pending_txs
+            .last()
+            .map_or(cursor, |tx| Some(tx.tx_sequence_info.tx_order));
+
+        let now_secs = now_seconds();
+        let data = pending_txs
+            .into_iter()
+            .map(|pending_tx| {
+                let expiration_timestamp_secs = pending_tx.tx.expiration_timestamp_secs();
+                PendingTransactionView {
+                    tx_hash: pending_tx.tx_execution_info.tx_hash.into(),
+                    tx_order: StrView(pending_tx.tx_sequence_info.tx_order),
+                    sender: pending_tx.tx.sender().to_string(),
+                    gas_price,
+                    gas_used: pending_tx.tx_execution_info.gas_used,
+                    inserted_at: pending_tx.inserted_at,
+                    expiration_timestamp_secs,
+                    time_to_expiry_secs: expiration_timestamp_secs
+                        .map(|expiration| expiration.saturating_sub(now_secs)),
+                }
+            })
+            .collect();
+
+        Ok(PendingTransactionPageView {
+            data,
+            next_cursor: next_cursor.map(StrView),
+            has_next_page,
+        })
+    }
+
     async fn execute_view_function(
         &self,
         function_call: FunctionCallView,
@@ -99,16 +348,46 @@ impl RoochAPIServer for RoochServer {
             .into())
     }
 
+    async fn execute_signed_view_function(
+        &self,
+        payload: BytesView,
+    ) -> RpcResult<AnnotatedFunctionResultView> {
+        let signed_call = SignedViewFunctionCall::decode(&payload.0).map_err(|e| {
+            RpcError::invalid_params(format!("Decode signed view function call failed: {}", e))
+        })?;
+        Ok(self
+            .rpc_service
+            .execute_signed_view_function(signed_call)
+            .await?
+            .into())
+    }
+
+    async fn get_module_abi(
+        &self,
+        module_id: ModuleIdView,
+    ) -> RpcResult<Option<MoveModuleABIView>> {
+        let abi = self
+            .rpc_service
+            .get_module(&module_id.0)
+            .await?
+            .map(|byte_codes| MoveModuleABIView::try_from_bytecode(&byte_codes))
+            .transpose()?;
+        Ok(abi)
+    }
+
     async fn get_states(
         &self,
         access_path: AccessPathView,
         state_option: Option<StateOptions>,
     ) -> RpcResult<Vec<Option<StateView>>> {
+        let access_path: AccessPath = access_path.into();
+        self.query_limits.check_access_path(&access_path)?;
         let state_option = state_option.unwrap_or_default();
+        let state_root = self.historical_state_root(&state_option).await?;
         if state_option.decode {
             Ok(self
                 .rpc_service
-                .get_annotated_states(access_path.into())
+                .get_annotated_states(state_root, access_path)
                 .await?
                 .into_iter()
                 .map(|s| s.map(StateView::from))
@@ -116,7 +395,7 @@ impl RoochAPIServer for RoochServer {
         } else {
             Ok(self
                 .rpc_service
-                .get_states(access_path.into())
+                .get_states(state_root, access_path)
                 .await?
                 .into_iter()
                 .map(|s| s.map(StateView::from))
@@ -124,6 +403,26 @@ impl RoochAPIServer for RoochServer {
         }
     }
 
+    async fn get_state_with_proof(
+        &self,
+        access_path: AccessPathView,
+        state_root: Option<H256View>,
+    ) -> RpcResult<Vec<StateProofView>> {
+        let access_path: AccessPath = access_path.into();
+        self.query_limits.check_access_path(&access_path)?;
+        let (handle, keys) = access_path.clone().into_table_query();
+        let keys = keys.ok_or_else(|| RpcError::invalid_params("AccessPath invalid path"))?;
+        let (state_root, states) = self
+            .rpc_service
+            .get_states_with_proof(state_root.map(Into::into), access_path)
+            .await?;
+        Ok(keys
+            .into_iter()
+            .zip(states)
+            .map(|(key, (state, proof))| StateProofView::new(state_root, handle, key, state, proof))
+            .collect())
+    }
+
     async fn list_states(
         &self,
         access_path: AccessPathView,
@@ -132,17 +431,17 @@ impl RoochAPIServer for RoochServer {
         state_option: Option<StateOptions>,
     ) -> RpcResult<StatePageView> {
         let state_option = state_option.unwrap_or_default();
-        let limit_of = min(
-            limit.map(Into::into).unwrap_or(DEFAULT_RESULT_LIMIT_USIZE),
-            MAX_RESULT_LIMIT_USIZE,
-        );
+        let state_root = self.historical_state_root(&state_option).await?;
+        let limit_of = self
+            .query_limits
+            .page_size("rooch_listStates", limit.map(Into::into))?;
         let cursor_of = match cursor.clone() {
             Some(key_state_str) => Some(KeyState::from_str(key_state_str.as_str())?),
             None => None,
         };
         let mut data: Vec<StateKVView> = if state_option.decode {
             self.rpc_service
-                .list_annotated_states(access_path.into(), cursor_of, limit_of + 1)
+                .list_annotated_states(state_root, access_path.into(), cursor_of, limit_of + 1)
                 .await?
                 .into_iter()
                 .map(|(key_state, state)| {
@@ -151,7 +450,7 @@ impl RoochAPIServer for RoochServer {
                 .collect::<Vec<_>>()
         } else {
             self.rpc_service
-                .list_states(access_path.into(), cursor_of, limit_of + 1)
+                .list_states(state_root, access_path.into(), cursor_of, limit_of + 1)
                 .await?
                 .into_iter()
                 .map(|(key_state, state)| {
@@ -179,24 +478,55 @@ impl RoochAPIServer for RoochServer {
         cursor: Option<StrView<u64>>,
         limit: Option<StrView<u64>>,
         event_options: Option<EventOptions>,
+        descending_order: Option<bool>,
+        from_seq: Option<StrView<u64>>,
+        to_seq: Option<StrView<u64>>,
     ) -> RpcResult<EventPageView> {
         let event_options = event_options.unwrap_or_default();
         let cursor = cursor.map(|v| v.0);
         let limit = limit.map(|v| v.0);
+        let descending_order = descending_order.unwrap_or(false);
+        let from_seq = from_seq.map(|v| v.0);
+        let to_seq = to_seq.map(|v| v.0);
+        if let (Some(from_seq), Some(to_seq)) = (from_seq, to_seq) {
+            if from_seq > to_seq {
+                return Err(RpcError::invalid_params(format!(
+                    "from_seq {} is greater than to_seq {}",
+                    from_seq, to_seq
+                )));
+            }
+        }
 
         // NOTE: fetch one more object to check if there is next page
-        let limit_of = min(limit.unwrap_or(DEFAULT_RESULT_LIMIT), MAX_RESULT_LIMIT);
+        let limit_of = self
+            .query_limits
+            .page_size("rooch_getEventsByEventHandle", limit.map(|v| v as usize))?
+            as u64;
         let limit = limit_of + 1;
         let mut data = if event_options.decode {
             self.rpc_service
-                .get_annotated_events_by_event_handle(event_handle_type.into(), cursor, limit)
+                .get_annotated_events_by_event_handle(
+                    event_handle_type.into(),
+                    cursor,
+                    limit,
+                    descending_order,
+                    from_seq,
+                    to_seq,
+                )
                 .await?
                 .into_iter()
                 .map(EventView::from)
                 .collect::<Vec<_>>()
         } else {
             self.rpc_service
-                .get_events_by_event_handle(event_handle_type.into(), cursor, limit)
+                .get_events_by_event_handle(
+                    event_handle_type.into(),
+                    cursor,
+                    limit,
+                    descending_order,
+                    from_seq,
+                    to_seq,
+                )
                 .await?
                 .into_iter()
                 .map(EventView::from)
@@ -220,7 +550,11 @@ impl RoochAPIServer for RoochServer {
     async fn get_transactions_by_hash(
         &self,
         tx_hashes: Vec<H256View>,
+        query_options: Option<QueryOptions>,
     ) -> RpcResult<Vec<Option<TransactionWithInfoView>>> {
+        self.query_limits
+            .check_batch_size("rooch_getTransactionsByHash", tx_hashes.len())?;
+        let query_options = query_options.unwrap_or_default();
         let tx_hashes: Vec<H256> = tx_hashes.iter().map(|m| (*m).into()).collect::<Vec<_>>();
 
         let tx_sequence_info_mapping = self
@@ -228,13 +562,19 @@ impl RoochAPIServer for RoochServer {
             .get_tx_sequence_info_mapping_by_hash(tx_hashes.clone())
             .await?;
 
-        let data = self
+        let mut data = self
             .aggregate_service
             .get_transaction_with_info(tx_hashes, tx_sequence_info_mapping)
             .await?
             .into_iter()
-            .map(|item| item.map(TransactionWithInfoView::from))
+            .map(|item| {
+                item.map(|tx| TransactionWithInfoView::from(tx).with_options(&query_options))
+            })
             .collect::<Vec<_>>();
+        if query_options.show_events {
+            self.fill_transaction_events(data.iter_mut().flatten())
+                .await?;
+        }
 
         Ok(data)
     }
@@ -243,14 +583,19 @@ impl RoochAPIServer for RoochServer {
         &self,
         cursor: Option<StrView<u64>>,
         limit: Option<StrView<u64>>,
+        query_options: Option<QueryOptions>,
     ) -> RpcResult<TransactionWithInfoPageView> {
+        let query_options = query_options.unwrap_or_default();
         let last_sequencer_order = self
             .rpc_service
             .get_sequencer_order()
             .await?
             .map_or(0, |v| v.last_order);
 
-        let limit_of = limit.map(Into::into).unwrap_or(DEFAULT_RESULT_LIMIT);
+        let limit_of = self
+            .query_limits
+            .page_size("rooch_getTransactionsByOrder", limit.map(|v| v.0 as usize))?
+            as u64;
         let cursor = cursor.map(|v| v.0);
         let start = cursor.unwrap_or(0);
         let end = min(start + (limit_of + 1), last_sequencer_order + 1);
@@ -277,22 +622,27 @@ impl RoochAPIServer for RoochServer {
         let mut tx_hashes = vec![];
         for item in tx_sequence_info_mapping.clone() {
             if item.is_none() {
-                return Err(JsonRpcError::Custom(String::from(
+                return Err(RpcError::new(
+                    RpcErrorCode::InternalError,
                     "The tx hash corresponding to tx order does not exist",
-                )));
+                )
+                .into());
             }
             tx_hashes.push(item.unwrap().tx_hash);
         }
         assert_eq!(tx_hashes.len(), tx_orders.len());
 
-        let data = self
+        let mut data = self
             .aggregate_service
             .get_transaction_with_info(tx_hashes, tx_sequence_info_mapping)
             .await?
             .into_iter()
             .flatten()
-            .map(TransactionWithInfoView::from)
+            .map(|tx| TransactionWithInfoView::from(tx).with_options(&query_options))
             .collect::<Vec<_>>();
+        if query_options.show_events {
+            self.fill_transaction_events(data.iter_mut()).await?;
+        }
 
         Ok(TransactionWithInfoPageView {
             data,
@@ -301,6 +651,13 @@ impl RoochAPIServer for RoochServer {
         })
     }
 
+    async fn get_coin_info(&self, coin_type: StructTagView) -> RpcResult<Option<CoinInfoView>> {
+        Ok(self
+            .aggregate_service
+            .get_coin_info(coin_type.into())
+            .await?)
+    }
+
     async fn get_balance(
         &self,
         account_addr: AccountAddressView,
@@ -320,10 +677,9 @@ impl RoochAPIServer for RoochServer {
         cursor: Option<String>,
         limit: Option<StrView<usize>>,
     ) -> RpcResult<BalanceInfoPageView> {
-        let limit_of = min(
-            limit.map(Into::into).unwrap_or(DEFAULT_RESULT_LIMIT_USIZE),
-            MAX_RESULT_LIMIT_USIZE,
-        );
+        let limit_of = self
+            .query_limits
+            .page_size("rooch_getBalances", limit.map(Into::into))?;
         let cursor_of = match cursor.clone() {
             Some(key_state_str) => Some(KeyState::from_str(key_state_str.as_str())?),
             None => None,
@@ -352,6 +708,102 @@ impl RoochAPIServer for RoochServer {
         })
     }
 
+    async fn get_session_keys(
+        &self,
+        account_addr: AccountAddressView,
+        cursor: Option<String>,
+        limit: Option<StrView<usize>>,
+    ) -> RpcResult<SessionKeyPageView> {
+        let limit_of = self
+            .query_limits
+            .page_size("rooch_getSessionKeys", limit.map(Into::into))?;
+        let cursor_of = match cursor.clone() {
+            Some(key_state_str) => Some(KeyState::from_str(key_state_str.as_str())?),
+            None => None,
+        };
+
+        let mut data = self
+            .aggregate_service
+            .get_session_keys(account_addr.into(), cursor_of, limit_of + 1)
+            .await?;
+
+        let has_next_page = data.len() > limit_of;
+        data.truncate(limit_of);
+
+        let next_cursor = data
+            .last()
+            .map_or(cursor, |(key, _session_key)| Some(key.to_string()));
+
+        Ok(SessionKeyPageView {
+            data: data
+                .into_iter()
+                .map(|(_, session_key)| session_key.into())
+                .collect(),
+            next_cursor,
+            has_next_page,
+        })
+    }
+
+    async fn get_account_summary(
+        &self,
+        account_addr: AccountAddressView,
+    ) -> RpcResult<AccountSummaryView> {
+        Ok(self
+            .rpc_service
+            .get_indexer_account_summary(account_addr.into())
+            .await?
+            .into())
+    }
+
+    async fn get_storage_usage(
+        &self,
+        account_addr: AccountAddressView,
+    ) -> RpcResult<StorageUsageView> {
+        Ok(self
+            .rpc_service
+            .get_indexer_storage_usage(account_addr.into())
+            .await?
+            .into())
+    }
+
+    async fn resolve_address(
+        &self,
+        multichain_id: u64,
+        address: String,
+    ) -> RpcResult<Option<AccountAddressView>> {
+        let multichain_id = RoochMultiChainID::try_from(multichain_id)
+            .map_err(|e| RpcError::invalid_params(e.to_string()))?;
+        let multichain_address = MultiChainAddress::try_from_str_with_bitcoin_network(
+            multichain_id,
+            address.as_str(),
+            self.btc_network,
+        )
+        .map_err(|e| RpcError::invalid_params(e.to_string()))?;
+        if multichain_address.is_rooch_address() {
+            let rooch_address = RoochAddress::try_from(multichain_address)?;
+            return Ok(Some(AccountAddress::from(rooch_address).into()));
+        }
+        Ok(self
+            .rpc_service
+            .get_indexer_address_mapping(multichain_address)
+            .await?
+            .map(|mapping| mapping.rooch_address.into()))
+    }
+
+    async fn reverse_resolve_address(
+        &self,
+        rooch_address: AccountAddressView,
+    ) -> RpcResult<Vec<AddressMappingView>> {
+        let mappings = self
+            .rpc_service
+            .get_indexer_address_mappings_by_rooch_address(rooch_address.into())
+            .await?
+            .into_iter()
+            .map(|mapping| AddressMappingView::try_new(mapping, self.btc_network))
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(mappings)
+    }
+
     async fn query_transactions(
         &self,
         filter: TransactionFilterView,
@@ -360,10 +812,11 @@ impl RoochAPIServer for RoochServer {
         limit: Option<StrView<usize>>,
         descending_order: Option<bool>,
     ) -> RpcResult<TransactionWithInfoPageView> {
-        let limit_of = min(
-            limit.map(Into::into).unwrap_or(DEFAULT_RESULT_LIMIT_USIZE),
-            MAX_RESULT_LIMIT_USIZE,
-        );
+        let limit_of = self
+            .query_limits
+            .page_size("rooch_queryTransactions", limit.map(Into::into))?;
+        self.query_limits
+            .check_filter_complexity(filter.complexity())?;
         let cursor = cursor.map(|v| v.0);
         let descending_order = descending_order.unwrap_or(true);
 
@@ -397,10 +850,11 @@ impl RoochAPIServer for RoochServer {
         limit: Option<StrView<usize>>,
         descending_order: Option<bool>,
     ) -> RpcResult<IndexerEventPageView> {
-        let limit_of = min(
-            limit.map(Into::into).unwrap_or(DEFAULT_RESULT_LIMIT_USIZE),
-            MAX_RESULT_LIMIT_USIZE,
-        );
+        let limit_of = self
+            .query_limits
+            .page_size("rooch_queryEvents", limit.map(Into::into))?;
+        self.query_limits
+            .check_filter_complexity(filter.complexity())?;
         let descending_order = descending_order.unwrap_or(true);
 
         let mut data = self
@@ -425,19 +879,99 @@ impl RoochAPIServer for RoochServer {
         })
     }
 
+    async fn search_transactions(
+        &self,
+        query: String,
+        // exclusive cursor if `Some`, otherwise start from the beginning
+        cursor: Option<StrView<u64>>,
+        limit: Option<StrView<usize>>,
+        descending_order: Option<bool>,
+    ) -> RpcResult<TransactionWithInfoPageView> {
+        let limit_of = self
+            .query_limits
+            .page_size("rooch_searchTransactions", limit.map(Into::into))?;
+        self.query_limits
+            .check_filter_complexity(query.split_whitespace().count())?;
+        let cursor = cursor.map(|v| v.0);
+        let descending_order = descending_order.unwrap_or(true);
+
+        let mut data = self
+            .rpc_service
+            .search_transactions(query, cursor, limit_of + 1, descending_order)
+            .await?;
+
+        let has_next_page = data.len() > limit_of;
+        data.truncate(limit_of);
+        let next_cursor = data
+            .last()
+            .cloned()
+            .map_or(cursor, |t| Some(t.sequence_info.tx_order));
+
+        Ok(TransactionWithInfoPageView {
+            data: data
+                .into_iter()
+                .map(TransactionWithInfoView::from)
+                .collect::<Vec<_>>(),
+            next_cursor,
+            has_next_page,
+        })
+    }
+
+    async fn search_events(
+        &self,
+        query: String,
+        // exclusive cursor if `Some`, otherwise start from the beginning
+        cursor: Option<IndexerEventID>,
+        limit: Option<StrView<usize>>,
+        descending_order: Option<bool>,
+    ) -> RpcResult<IndexerEventPageView> {
+        let limit_of = self
+            .query_limits
+            .page_size("rooch_searchEvents", limit.map(Into::into))?;
+        self.query_limits
+            .check_filter_complexity(query.split_whitespace().count())?;
+        let descending_order = descending_order.unwrap_or(true);
+
+        let mut data = self
+            .rpc_service
+            .search_events(query, cursor, limit_of + 1, descending_order)
+            .await?
+            .into_iter()
+            .map(IndexerEventView::from)
+            .collect::<Vec<_>>();
+
+        let has_next_page = data.len() > limit_of;
+        data.truncate(limit_of);
+        let next_cursor = data
+            .last()
+            .cloned()
+            .map_or(cursor, |e| Some(e.indexer_event_id));
+
+        Ok(IndexerEventPageView {
+            data,
+            next_cursor,
+            has_next_page,
+        })
+    }
+
     async fn query_global_states(
         &self,
         filter: GlobalStateFilterView,
         // exclusive cursor if `Some`, otherwise start from the beginning
-        cursor: Option<IndexerStateID>,
+        cursor: Option<String>,
         limit: Option<StrView<usize>>,
         descending_order: Option<bool>,
+        query_options: Option<QueryOptions>,
     ) -> RpcResult<IndexerGlobalStatePageView> {
-        let limit_of = min(
-            limit.map(Into::into).unwrap_or(DEFAULT_RESULT_LIMIT_USIZE),
-            MAX_RESULT_LIMIT_USIZE,
-        );
+        let query_options = query_options.unwrap_or_default();
+        let limit_of = self
+            .query_limits
+            .page_size("rooch_queryGlobalStates", limit.map(Into::into))?;
         let descending_order = descending_order.unwrap_or(true);
+        let cursor_of = match cursor.clone() {
+            Some(cursor_str) => Some(IndexerStateID::from_cursor(cursor_str.as_str())?),
+            None => None,
+        };
 
         // resolve multichain address
         let resolve_address = match filter.clone() {
@@ -445,10 +979,12 @@ impl RoochAPIServer for RoochServer {
                 multichain_id,
                 address,
             } => {
-                let multi_chain_address = MultiChainAddress::try_from_str_with_multichain_id(
+                let multi_chain_address = MultiChainAddress::try_from_str_with_bitcoin_network(
                     RoochMultiChainID::try_from(multichain_id)?,
                     address.as_str(),
-                )?;
+                    self.btc_network,
+                )
+                .map_err(|e| RpcError::invalid_params(e.to_string()))?;
                 self.rpc_service
                     .resolve_address(multi_chain_address)
                     .await?
@@ -459,16 +995,26 @@ impl RoochAPIServer for RoochServer {
             GlobalStateFilterView::into_global_state_filter(filter, resolve_address);
         let mut data = self
             .rpc_service
-            .query_global_states(global_state_filter, cursor, limit_of + 1, descending_order)
+            .query_global_states(
+                global_state_filter,
+                cursor_of,
+                limit_of + 1,
+                descending_order,
+            )
             .await?
             .into_iter()
-            .map(IndexerGlobalStateView::try_new_from_global_state)
+            .map(|state| {
+                IndexerGlobalStateView::try_new_from_global_state_with_options(
+                    state,
+                    &query_options,
+                )
+            })
             .collect::<Result<Vec<_>>>()?;
 
         let has_next_page = data.len() > limit_of;
         data.truncate(limit_of);
-        let next_cursor = data.last().cloned().map_or(cursor, |t| {
-            Some(IndexerStateID::new(t.tx_order, t.state_index))
+        let next_cursor = data.last().map_or(cursor, |t| {
+            Some(IndexerStateID::new(t.tx_order, t.state_index).to_cursor())
         });
 
         Ok(IndexerGlobalStatePageView {
@@ -478,6 +1024,81 @@ impl RoochAPIServer for RoochServer {
         })
     }
 
+    async fn query_modules(
+        &self,
+        filter: ModuleFilterView,
+        // exclusive cursor if `Some`, otherwise start from the beginning
+        cursor: Option<String>,
+        limit: Option<StrView<usize>>,
+        descending_order: Option<bool>,
+    ) -> RpcResult<IndexerModulePageView> {
+        let limit_of = self
+            .query_limits
+            .page_size("rooch_queryModules", limit.map(Into::into))?;
+        let descending_order = descending_order.unwrap_or(true);
+        let cursor_of = match cursor.clone() {
+            Some(cursor_str) => Some(IndexerStateID::from_cursor(cursor_str.as_str())?),
+            None => None,
+        };
+
+        let mut data = self
+            .rpc_service
+            .query_modules(filter.into(), cursor_of, limit_of + 1, descending_order)
+            .await?
+            .into_iter()
+            .map(IndexerModuleView::from)
+            .collect::<Vec<_>>();
+
+        let has_next_page = data.len() > limit_of;
+        data.truncate(limit_of);
+        let next_cursor = data.last().map_or(cursor, |module| {
+            Some(IndexerStateID::new(module.tx_order.0, module.state_index.0).to_cursor())
+        });
+
+        Ok(IndexerModulePageView {
+            data,
+            next_cursor,
+            has_next_page,
+        })
+    }
+
+    async fn get_transfer_history(
+        &self,
+        account_addr: AccountAddressView,
+        coin_type: StructTagView,
+        // exclusive cursor if `Some`, otherwise start from the beginning
+        cursor: Option<IndexerEventID>,
+        limit: Option<StrView<usize>>,
+        descending_order: Option<bool>,
+    ) -> RpcResult<IndexerCoinTransferPageView> {
+        let limit_of = self
+            .query_limits
+            .page_size("rooch_getTransferHistory", limit.map(Into::into))?;
+        let descending_order = descending_order.unwrap_or(true);
+        let coin_store_id =
+            AccountCoinStoreModule::account_coin_store_id(account_addr.into(), coin_type.into());
+
+        let mut data = self
+            .rpc_service
+            .query_coin_transfers(coin_store_id, cursor, limit_of + 1, descending_order)
+            .await?
+            .into_iter()
+            .map(IndexerCoinTransferView::from)
+            .collect::<Vec<_>>();
+
+        let has_next_page = data.len() > limit_of;
+        data.truncate(limit_of);
+        let next_cursor = data
+            .last()
+            .map_or(cursor, |transfer| Some(transfer.indexer_event_id));
+
+        Ok(IndexerCoinTransferPageView {
+            data,
+            next_cursor,
+            has_next_page,
+        })
+    }
+
     async fn query_table_states(
         &self,
         filter: TableStateFilterView,
@@ -486,10 +1107,9 @@ impl RoochAPIServer for RoochServer {
         limit: Option<StrView<usize>>,
         descending_order: Option<bool>,
     ) -> RpcResult<IndexerTableStatePageView> {
-        let limit_of = min(
-            limit.map(Into::into).unwrap_or(DEFAULT_RESULT_LIMIT_USIZE),
-            MAX_RESULT_LIMIT_USIZE,
-        );
+        let limit_of = self
+            .query_limits
+            .page_size("rooch_queryTableStates", limit.map(Into::into))?;
         let descending_order = descending_order.unwrap_or(true);
 
         let mut data = self
@@ -521,10 +1141,9 @@ impl RoochAPIServer for RoochServer {
         limit: Option<StrView<usize>>,
         descending_order: Option<bool>,
     ) -> RpcResult<IndexerTableChangeSetPageView> {
-        let limit_of = min(
-            limit.map(Into::into).unwrap_or(DEFAULT_RESULT_LIMIT_USIZE),
-            MAX_RESULT_LIMIT_USIZE,
-        );
+        let limit_of = self
+            .query_limits
+            .page_size("rooch_syncStates", limit.map(Into::into))?;
         // Sync from asc by default
         let descending_order = descending_order.unwrap_or(false);
 
@@ -553,6 +1172,67 @@ impl RoochAPIServer for RoochServer {
             has_next_page,
         })
     }
+
+    async fn get_object_ownership_history(
+        &self,
+        object_id: ObjectID,
+        cursor: Option<StrView<u64>>,
+        limit: Option<StrView<usize>>,
+        descending_order: Option<bool>,
+    ) -> RpcResult<ObjectOwnershipChangePageView> {
+        let limit_of = self
+            .query_limits
+            .page_size("rooch_getObjectOwnershipHistory", limit.map(Into::into))?;
+        let cursor: Option<u64> = cursor.map(Into::into);
+        let descending_order = descending_order.unwrap_or(false);
+
+        let mut data = self
+            .rpc_service
+            .get_object_ownership_history(object_id, cursor, limit_of + 1, descending_order)
+            .await?
+            .into_iter()
+            .map(IndexerObjectOwnershipChangeView::from)
+            .collect::<Vec<_>>();
+
+        let has_next_page = data.len() > limit_of;
+        data.truncate(limit_of);
+        let next_cursor = data.last().map_or(cursor, |change| Some(change.tx_order));
+
+        Ok(ObjectOwnershipChangePageView {
+            data,
+            next_cursor,
+            has_next_page,
+        })
+    }
+
+    async fn list_scheduled_tasks(
+        &self,
+        cursor: Option<StrView<u64>>,
+        limit: Option<StrView<usize>>,
+    ) -> RpcResult<ScheduledTaskPageView> {
+        let limit_of = self
+            .query_limits
+            .page_size("rooch_listScheduledTasks", limit.map(Into::into))?;
+        let cursor: Option<u64> = cursor.map(Into::into);
+        let start_task_id = cursor.map(|task_id| task_id + 1).unwrap_or(0);
+
+        let mut data = self
+            .rpc_service
+            .list_scheduled_tasks(start_task_id, (limit_of + 1) as u64)?
+            .into_iter()
+            .map(ScheduledTaskView::try_from)
+            .collect::<Result<Vec<_>>>()?;
+
+        let has_next_page = data.len() > limit_of;
+        data.truncate(limit_of);
+        let next_cursor = data.last().map_or(cursor, |task| Some(task.task_id));
+
+        Ok(ScheduledTaskPageView {
+            data,
+            next_cursor,
+            has_next_page,
+        })
+    }
 }
 
 impl RoochRpcModule for RoochServer {
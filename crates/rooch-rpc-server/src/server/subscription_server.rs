@@ -0,0 +1,83 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::service::rpc_service::RpcService;
+use crate::LOG_TARGET;
+use futures::{Stream, StreamExt};
+use jsonrpsee::core::error::SubscriptionClosed;
+use jsonrpsee::types::SubscriptionResult;
+use jsonrpsee::{RpcModule, SubscriptionSink};
+use rooch_rpc_api::api::subscription_api::SubscriptionAPIServer;
+use rooch_rpc_api::api::RoochRpcModule;
+use rooch_rpc_api::jsonrpc_types::event_view::{EventFilterView, IndexerEventView};
+use rooch_rpc_api::jsonrpc_types::{IndexerObjectStateChangeView, ObjectStateFilterView};
+use serde::Serialize;
+use tracing::debug;
+
+pub struct SubscriptionServer {
+    rpc_service: RpcService,
+}
+
+impl SubscriptionServer {
+    pub fn new(rpc_service: RpcService) -> Self {
+        Self { rpc_service }
+    }
+}
+
+impl SubscriptionAPIServer for SubscriptionServer {
+    fn subscribe_events(
+        &self,
+        sink: SubscriptionSink,
+        filter: EventFilterView,
+    ) -> SubscriptionResult {
+        let stream = self
+            .rpc_service
+            .subscribe_events(filter.into())
+            .map(IndexerEventView::from);
+        spawn_subscription(sink, Box::pin(stream));
+        Ok(())
+    }
+
+    fn subscribe_object(
+        &self,
+        sink: SubscriptionSink,
+        filter: ObjectStateFilterView,
+    ) -> SubscriptionResult {
+        let stream = self
+            .rpc_service
+            .subscribe_object_states(filter.into())
+            .map(IndexerObjectStateChangeView::from);
+        spawn_subscription(sink, Box::pin(stream));
+        Ok(())
+    }
+}
+
+impl RoochRpcModule for SubscriptionServer {
+    fn rpc(self) -> RpcModule<Self> {
+        self.into_rpc()
+    }
+}
+
+/// Pipe the stream to the subscription sink until the stream ends or the client unsubscribes.
+fn spawn_subscription<S, T>(mut sink: SubscriptionSink, stream: S)
+where
+    S: Stream<Item = T> + Unpin + Send + 'static,
+    T: Serialize,
+{
+    tokio::spawn(async move {
+        match sink.pipe_from_stream(stream).await {
+            SubscriptionClosed::Success => {
+                debug!(target: LOG_TARGET, "Subscription completed.");
+                sink.close(SubscriptionClosed::Success);
+            }
+            SubscriptionClosed::RemotePeerAborted => {
+                debug!(target: LOG_TARGET, "Subscription aborted by remote peer.");
+                sink.close(SubscriptionClosed::RemotePeerAborted);
+            }
+            SubscriptionClosed::Failed(err) => {
+                debug!(target: LOG_TARGET, "Subscription failed: {:?}", err);
+                sink.close(err);
+            }
+        };
+    });
+}
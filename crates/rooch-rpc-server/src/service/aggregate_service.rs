@@ -5,22 +5,27 @@ use crate::service::rpc_service::RpcService;
 use anyhow::Result;
 use move_core_types::account_address::AccountAddress;
 use move_core_types::language_storage::{StructTag, TypeTag};
+use move_core_types::u256::U256;
 use moveos_types::access_path::AccessPath;
 use moveos_types::h256::H256;
 use moveos_types::module_binding::MoveFunctionCaller;
 use moveos_types::moveos_std::object::RawObject;
 use moveos_types::moveos_std::object_id::ObjectID;
 use moveos_types::state::{KeyState, PlaceholderStruct};
+use rooch_rpc_api::api::MAX_RESULT_LIMIT_USIZE;
 use rooch_rpc_api::jsonrpc_types::account_view::BalanceInfoView;
 use rooch_rpc_api::jsonrpc_types::CoinInfoView;
 use rooch_types::address::{BitcoinAddress, MultiChainAddress};
 use rooch_types::bitcoin::ord::{Inscription, InscriptionState};
-use rooch_types::bitcoin::utxo::{UTXOState, UTXO};
+use rooch_types::bitcoin::utxo::UTXOState;
 use rooch_types::framework::account_coin_store::AccountCoinStoreModule;
 use rooch_types::framework::address_mapping::AddressMapping;
 use rooch_types::framework::coin::{CoinInfo, CoinModule};
 use rooch_types::framework::coin_store::CoinStore;
-use rooch_types::indexer::state::IndexerGlobalState;
+use rooch_types::framework::session_key::{SessionKey, SessionKeyModule};
+use rooch_types::indexer::state::{
+    GlobalStateFilter, IndexerGlobalState, IndexerStateID, IndexerUTXOState,
+};
 use rooch_types::multichain_id::RoochMultiChainID;
 use rooch_types::transaction::{TransactionSequenceInfoMapping, TransactionWithInfo};
 use std::collections::HashMap;
@@ -50,7 +55,7 @@ impl AggregateService {
                 .collect(),
         );
         self.rpc_service
-            .get_states(access_path)
+            .get_states(None, access_path)
             .await?
             .into_iter()
             .zip(coin_types)
@@ -71,13 +76,32 @@ impl AggregateService {
             .collect::<Result<HashMap<_, _>>>()
     }
 
+    /// Get the coin info from the indexer cache, fallback to the state if the coin info is not indexed yet
+    pub async fn get_coin_info(&self, coin_type: StructTag) -> Result<Option<CoinInfoView>> {
+        let coin_info = self
+            .rpc_service
+            .get_indexer_coin_infos(vec![coin_type.clone()])
+            .await?
+            .pop()
+            .flatten();
+        match coin_info {
+            Some(coin_info) => Ok(Some(coin_info.into())),
+            None => Ok(self
+                .get_coin_infos(vec![coin_type])
+                .await?
+                .into_values()
+                .flatten()
+                .next()),
+        }
+    }
+
     pub async fn get_coin_stores(
         &self,
         coin_store_ids: Vec<ObjectID>,
     ) -> Result<Vec<Option<CoinStore<PlaceholderStruct>>>> {
         let access_path = AccessPath::objects(coin_store_ids);
         self.rpc_service
-            .get_states(access_path)
+            .get_states(None, access_path)
             .await?
             .into_iter()
             .map(|state_opt| {
@@ -107,7 +131,8 @@ impl AggregateService {
                 anyhow::anyhow!("Can not find CoinInfo with coin_type: {}", coin_type)
             })?;
 
-        let coin_store_id = AccountCoinStoreModule::account_coin_store_id(account_addr, coin_type);
+        let coin_store_id =
+            AccountCoinStoreModule::account_coin_store_id(account_addr, coin_type.clone());
         let balance = self
             .get_coin_stores(vec![coin_store_id])
             .await?
@@ -115,10 +140,62 @@ impl AggregateService {
             .flatten()
             .map(|coin_store| coin_store.balance())
             .unwrap_or_default();
+        let balance = self
+            .sum_owned_coin_stores_balance(account_addr, coin_type, coin_store_id, balance)
+            .await?;
 
         Ok(BalanceInfoView::new(coin_info, balance))
     }
 
+    /// Add the balances of the other CoinStore objects of the coin type owned by the account to the account coin store balance,
+    /// such as the coin stores created by the modules and transferred to the account.
+    /// The owned objects are resolved by the indexer, so only the account coin store is counted if the states are not indexed.
+    pub async fn sum_owned_coin_stores_balance(
+        &self,
+        account_addr: AccountAddress,
+        coin_type: StructTag,
+        account_coin_store_id: ObjectID,
+        account_coin_store_balance: U256,
+    ) -> Result<U256> {
+        let filter = GlobalStateFilter::ObjectTypeWithOwner {
+            object_type: CoinStore::<PlaceholderStruct>::struct_tag_with_coin_type(coin_type),
+            owner: account_addr,
+        };
+        let mut balance = account_coin_store_balance;
+        let mut cursor = None;
+        loop {
+            let states = self
+                .rpc_service
+                .query_global_states(filter.clone(), cursor, MAX_RESULT_LIMIT_USIZE, false)
+                .await?;
+            let has_next_page = states.len() == MAX_RESULT_LIMIT_USIZE;
+            cursor = states
+                .last()
+                .map(|state| IndexerStateID::new(state.tx_order, state.state_index));
+            let coin_store_ids = states
+                .into_iter()
+                .map(|state| state.object_id)
+                .filter(|object_id| object_id != &account_coin_store_id)
+                .collect::<Vec<_>>();
+            if !coin_store_ids.is_empty() {
+                for coin_store in self
+                    .get_coin_stores(coin_store_ids)
+                    .await?
+                    .into_iter()
+                    .flatten()
+                {
+                    balance = balance.checked_add(coin_store.balance()).ok_or_else(|| {
+                        anyhow::anyhow!("Balance overflow of {}", coin_store.coin_type())
+                    })?;
+                }
+            }
+            if !has_next_page {
+                break;
+            }
+        }
+        Ok(balance)
+    }
+
     pub async fn get_balances(
         &self,
         account_addr: AccountAddress,
@@ -136,6 +213,7 @@ impl AggregateService {
                 let coin_store_ids = self
                     .rpc_service
                     .list_states(
+                        None,
                         AccessPath::table_without_keys(coin_stores_handle),
                         cursor,
                         limit,
@@ -172,9 +250,15 @@ impl AggregateService {
                         .ok_or_else(|| {
                             anyhow::anyhow!("Can not find CoinInfo for {}", coin_store.coin_type())
                         })?;
-                    let balance_info =
-                        BalanceInfoView::new(coin_info.clone(), coin_store.balance());
-                    result.push((Some(key), balance_info))
+                    let balance = self
+                        .sum_owned_coin_stores_balance(
+                            account_addr,
+                            coin_store.coin_type_tag(),
+                            object_id,
+                            coin_store.balance(),
+                        )
+                        .await?;
+                    result.push((Some(key), BalanceInfoView::new(coin_info, balance)))
                 }
 
                 Ok(result)
@@ -183,6 +267,34 @@ impl AggregateService {
         }
     }
 
+    /// List the on-chain session keys of the account from the session keys table
+    pub async fn get_session_keys(
+        &self,
+        account_addr: AccountAddress,
+        cursor: Option<KeyState>,
+        limit: usize,
+    ) -> Result<Vec<(KeyState, SessionKey)>> {
+        let session_key_module = self
+            .rpc_service
+            .executor
+            .as_module_binding::<SessionKeyModule>();
+        match session_key_module.session_keys_handle(account_addr)? {
+            Some(session_keys_handle) => self
+                .rpc_service
+                .list_states(
+                    None,
+                    AccessPath::table_without_keys(session_keys_handle),
+                    cursor,
+                    limit,
+                )
+                .await?
+                .into_iter()
+                .map(|(k, v)| Ok((k, v.cast::<SessionKey>()?)))
+                .collect::<Result<Vec<_>>>(),
+            None => Ok(vec![]),
+        }
+    }
+
     pub async fn get_transaction_with_info(
         &self,
         tx_hashes: Vec<H256>,
@@ -258,7 +370,7 @@ impl AggregateService {
         // Global table 0x0 table's key type is always ObjectID.
         let access_path = AccessPath::objects(table_handles.clone());
         self.rpc_service
-            .get_states(access_path)
+            .get_states(None, access_path)
             .await?
             .into_iter()
             .zip(table_handles)
@@ -274,34 +386,13 @@ impl AggregateService {
             .collect::<Result<HashMap<_, _>>>()
     }
 
-    pub async fn pack_uxtos(&self, states: Vec<IndexerGlobalState>) -> Result<Vec<UTXOState>> {
-        let table_handles = states.iter().map(|m| m.object_id).collect::<Vec<_>>();
+    pub async fn pack_uxtos(&self, states: Vec<IndexerUTXOState>) -> Result<Vec<UTXOState>> {
         let owners = states.iter().map(|m| m.owner).collect::<Vec<_>>();
         let owner_keys = states
             .iter()
             .map(|m| KeyState::new(m.owner.to_vec(), TypeTag::Address))
             .collect::<Vec<_>>();
 
-        // Global table 0x0 table's key type is always ObjectID.
-        let access_path = AccessPath::objects(table_handles.clone());
-        let objects = self
-            .rpc_service
-            .get_states(access_path)
-            .await?
-            .into_iter()
-            .zip(table_handles)
-            .map(|(state_opt, table_handle)| {
-                Ok((
-                    table_handle,
-                    state_opt
-                        .map(|state| {
-                            Ok::<UTXO, anyhow::Error>(state.as_object_uncheck::<UTXO>()?.value)
-                        })
-                        .transpose()?,
-                ))
-            })
-            .collect::<Result<HashMap<_, _>>>()?;
-
         let address_mapping_module = self
             .rpc_service
             .executor
@@ -312,7 +403,7 @@ impl AggregateService {
         let access_path = AccessPath::table(reverse_mapping_handle, owner_keys);
         let reverse_address_mapping = self
             .rpc_service
-            .get_states(access_path)
+            .get_states(None, access_path)
             .await?
             .into_iter()
             .zip(owners)
@@ -326,11 +417,10 @@ impl AggregateService {
             })
             .collect::<Result<HashMap<_, _>>>()?;
 
+        // The UTXO value is indexed in the utxos table, so there is no need to load the object state
         let data = states
             .into_iter()
-            // .enumerate()
             .map(|state| {
-                let utxo = objects.get(&state.object_id).cloned().flatten();
                 let reverse_mapping_opt =
                     reverse_address_mapping.get(&state.owner).cloned().flatten();
                 let reverse_address = reverse_mapping_opt.and_then(|m| {
@@ -339,9 +429,8 @@ impl AggregateService {
                         .map(|p| BitcoinAddress::new(p.raw_address.clone()))
                 });
 
-                Ok(UTXOState::new_from_global_state(
+                Ok(UTXOState::new_from_indexer_utxo_state(
                     state,
-                    utxo,
                     reverse_address,
                 ))
             })
@@ -364,7 +453,7 @@ impl AggregateService {
         let access_path = AccessPath::objects(table_handles.clone());
         let objects = self
             .rpc_service
-            .get_states(access_path)
+            .get_states(None, access_path)
             .await?
             .into_iter()
             .zip(table_handles)
@@ -392,7 +481,7 @@ impl AggregateService {
         let access_path = AccessPath::table(reverse_mapping_handle, owner_keys);
         let reverse_address_mapping = self
             .rpc_service
-            .get_states(access_path)
+            .get_states(None, access_path)
             .await?
             .into_iter()
             .zip(owners)
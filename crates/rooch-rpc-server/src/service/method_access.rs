@@ -0,0 +1,295 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use anyhow::Result;
+use hyper::{header, Body, Request, Response, StatusCode};
+use jsonrpsee::core::server::rpc_module::{MethodCallback, MethodKind, Methods};
+use jsonrpsee::core::traits::ToRpcParams;
+use jsonrpsee::core::{JsonRawValue, RpcResult};
+use jsonrpsee::types::Params;
+use jsonrpsee::RpcModule;
+use rooch_config::server_config::MethodAccessConfig;
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use tower::{BoxError, Layer, Service};
+
+/// The JSON-RPC error code of the rejected WebSocket connections
+const METHOD_NOT_ALLOWED_ERROR_CODE: i32 = -32601;
+
+/// Filter the methods by `is_allowed`, returns the allowed methods and the names of the denied methods.
+/// The `Methods` of jsonrpsee can not remove a method, so if some of the methods are denied,
+/// the allowed method calls are registered on a new module and forwarded to the source methods.
+/// The subscriptions can not be forwarded, they are only served if all the methods of the source are allowed.
+pub fn filter_methods(
+    methods: Methods,
+    is_allowed: impl Fn(&str) -> bool,
+) -> Result<(Methods, Vec<&'static str>)> {
+    let (allowed, denied): (Vec<_>, Vec<_>) = methods
+        .method_names()
+        .partition(|method| is_allowed(method));
+    if denied.is_empty() {
+        return Ok((methods, denied));
+    }
+    let mut module = RpcModule::new(());
+    for method in allowed {
+        match methods.method(method).map(MethodCallback::inner) {
+            Some(MethodKind::Sync(_)) | Some(MethodKind::Async(_)) => {
+                let methods = methods.clone();
+                module.register_async_method(method, move |params, _| {
+                    let methods = methods.clone();
+                    async move { forward_call(&methods, method, params).await }
+                })?;
+            }
+            _ => anyhow::bail!(
+                "The subscription method {} can not be served if the other methods of its module are denied",
+                method
+            ),
+        }
+    }
+    Ok((module.into(), denied))
+}
+
+/// The params of the forwarded call, they are passed to the source method as they are.
+struct RawParams(Option<Box<JsonRawValue>>);
+
+impl ToRpcParams for RawParams {
+    fn to_rpc_params(self) -> Result<Option<Box<JsonRawValue>>, jsonrpsee::core::Error> {
+        Ok(self.0)
+    }
+}
+
+pub(crate) async fn forward_call(
+    methods: &Methods,
+    method: &str,
+    params: Params<'static>,
+) -> RpcResult<Box<JsonRawValue>> {
+    let params = RawParams(params.parse()?);
+    methods.call(method, params).await
+}
+
+/// The tower layer rejects the WebSocket connections with `403 Forbidden` if the WebSocket is disabled.
+/// The method rules are not checked here, see `filter_methods`.
+#[derive(Clone)]
+pub struct MethodAccessLayer {
+    disable_websocket: bool,
+}
+
+impl MethodAccessLayer {
+    pub fn new(config: &MethodAccessConfig) -> Self {
+        Self {
+            disable_websocket: config.disable_websocket,
+        }
+    }
+}
+
+impl<S> Layer<S> for MethodAccessLayer {
+    type Service = MethodAccessService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        MethodAccessService {
+            inner,
+            disable_websocket: self.disable_websocket,
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct MethodAccessService<S> {
+    inner: S,
+    disable_websocket: bool,
+}
+
+impl<S> Service<Request<Body>> for MethodAccessService<S>
+where
+    S: Service<Request<Body>, Response = Response<Body>> + Clone + Send + 'static,
+    S::Error: Into<BoxError> + 'static,
+    S::Future: Send + 'static,
+{
+    type Response = Response<Body>;
+    type Error = BoxError;
+    type Future = Pin<Box<dyn Future<Output = Result<Self::Response, Self::Error>> + Send>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx).map_err(Into::into)
+    }
+
+    fn call(&mut self, request: Request<Body>) -> Self::Future {
+        if self.disable_websocket && request.headers().contains_key(header::UPGRADE) {
+            return Box::pin(async { Ok(error_response("WebSocket is disabled")) });
+        }
+        let future = self.inner.call(request);
+        Box::pin(async move { future.await.map_err(Into::into) })
+    }
+}
+
+fn error_response(message: &str) -> Response<Body> {
+    let body = serde_json::json!({
+        "jsonrpc": "2.0",
+        "error": {
+            "code": METHOD_NOT_ALLOWED_ERROR_CODE,
+            "message": message,
+        },
+        "id": null,
+    });
+    Response::builder()
+        .status(StatusCode::FORBIDDEN)
+        .header(header::CONTENT_TYPE, "application/json")
+        .body(Body::from(body.to_string()))
+        .expect("Build error response should success")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::server::admin_server::AdminServer;
+    use crate::RpcModuleBuilder;
+    use jsonrpsee::core::client::ClientT;
+    use jsonrpsee::http_client::HttpClientBuilder;
+    use jsonrpsee::rpc_params;
+    use jsonrpsee::server::ServerBuilder;
+    use jsonrpsee::types::error::METHOD_NOT_FOUND_CODE;
+    use std::net::SocketAddr;
+
+    fn test_module() -> RpcModule<()> {
+        let mut module = RpcModule::new(());
+        for method in [
+            "rooch_sendRawTransaction",
+            "rooch_executeRawTransaction",
+            "admin_setLogFilter",
+        ] {
+            module
+                .register_method(method, move |_, _| Ok(method))
+                .unwrap();
+        }
+        module
+            .register_async_method("rooch_getStates", |params, _| async move {
+                params.one::<String>().map_err(Into::into)
+            })
+            .unwrap();
+        module
+    }
+
+    fn test_config() -> MethodAccessConfig {
+        MethodAccessConfig {
+            deny: vec!["rooch_send*".to_owned()],
+            localhost_only: vec!["rooch_executeRawTransaction".to_owned()],
+            ..Default::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_filter_methods() {
+        let config = test_config();
+
+        let (methods, mut denied) = filter_methods(test_module().into(), |method| {
+            config.is_allowed(method, false)
+        })
+        .unwrap();
+        denied.sort();
+        assert_eq!(
+            denied,
+            vec![
+                "admin_setLogFilter",
+                "rooch_executeRawTransaction",
+                "rooch_sendRawTransaction"
+            ]
+        );
+        assert_eq!(
+            methods.method_names().collect::<Vec<_>>(),
+            vec!["rooch_getStates"]
+        );
+        // The allowed calls are forwarded to the source methods with the params
+        let state: String = methods
+            .call("rooch_getStates", rpc_params!["/object/0x1"])
+            .await
+            .unwrap();
+        assert_eq!(state, "/object/0x1");
+        assert!(methods
+            .call::<_, String>("rooch_getStates", rpc_params![])
+            .await
+            .is_err());
+
+        let (methods, denied) = filter_methods(test_module().into(), |method| {
+            config.is_allowed(method, true)
+        })
+        .unwrap();
+        assert_eq!(denied, vec!["rooch_sendRawTransaction"]);
+        let method: String = methods
+            .call("rooch_executeRawTransaction", rpc_params![])
+            .await
+            .unwrap();
+        assert_eq!(method, "rooch_executeRawTransaction");
+        assert!(methods.method("admin_setLogFilter").is_some());
+        assert!(methods.method("rooch_sendRawTransaction").is_none());
+    }
+
+    #[test]
+    fn test_filter_subscription_methods() {
+        let mut module = test_module();
+        module
+            .register_subscription(
+                "rooch_subscribeTest",
+                "rooch_subscribeTest",
+                "rooch_unsubscribeTest",
+                |_, mut sink, _| {
+                    sink.accept()?;
+                    Ok(())
+                },
+            )
+            .unwrap();
+        // The subscriptions are kept if all the methods are allowed
+        let (methods, denied) = filter_methods(module.into(), |_| true).unwrap();
+        assert!(denied.is_empty());
+        assert!(methods.method("rooch_subscribeTest").is_some());
+        // The subscriptions can not be forwarded
+        assert!(filter_methods(methods, |method| !method.starts_with("admin_")).is_err());
+    }
+
+    async fn admin_call(server_addr: SocketAddr, method_access: MethodAccessConfig) -> i32 {
+        let server = ServerBuilder::default().build(server_addr).await.unwrap();
+        let addr = server.local_addr().unwrap();
+        let mut builder =
+            RpcModuleBuilder::with_method_access(method_access, server_addr.ip().is_loopback());
+        builder.register_module(AdminServer).unwrap();
+        let handle = server.start(builder.module).unwrap();
+
+        let client = HttpClientBuilder::default()
+            .build(format!("http://127.0.0.1:{}", addr.port()))
+            .unwrap();
+        let result = client
+            .request::<String, _>("admin_getLogFilter", rpc_params![])
+            .await;
+        handle.stop().unwrap();
+        match result {
+            Ok(_) => 0,
+            Err(jsonrpsee::core::Error::Call(jsonrpsee::types::error::CallError::Custom(err))) => {
+                err.code()
+            }
+            Err(e) => panic!("Unexpected error: {:?}", e),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_non_loopback_server_rejects_admin_methods() {
+        // The server listens on all the interfaces, the admin methods are not served even from the localhost
+        let code = admin_call("0.0.0.0:0".parse().unwrap(), MethodAccessConfig::default()).await;
+        assert_eq!(code, METHOD_NOT_FOUND_CODE);
+
+        // The admin methods are served on the loopback server, the log filter is not initialized in the test
+        let code = admin_call(
+            "127.0.0.1:0".parse().unwrap(),
+            MethodAccessConfig::default(),
+        )
+        .await;
+        assert_ne!(code, METHOD_NOT_FOUND_CODE);
+
+        // Unless they are denied
+        let method_access = MethodAccessConfig {
+            deny: vec!["admin_*".to_owned()],
+            ..Default::default()
+        };
+        let code = admin_call("127.0.0.1:0".parse().unwrap(), method_access).await;
+        assert_eq!(code, METHOD_NOT_FOUND_CODE);
+    }
+}
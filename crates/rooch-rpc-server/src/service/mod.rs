@@ -2,5 +2,12 @@
 // SPDX-License-Identifier: Apache-2.0
 
 pub mod aggregate_service;
+pub mod method_access;
+pub mod query_limits;
+pub mod rate_limiter;
 pub mod rpc_logger;
+pub mod rpc_metrics;
 pub mod rpc_service;
+pub mod subscription_service;
+pub mod telemetry;
+pub mod tls;
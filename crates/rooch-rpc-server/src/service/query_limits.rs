@@ -0,0 +1,79 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use moveos_types::access_path::{AccessPath, Path};
+use rooch_config::server_config::QueryLimitsConfig;
+use rooch_rpc_api::jsonrpc_types::RpcError;
+
+/// Enforce the query limits on the JSON-RPC requests, the methods are the full JSON-RPC method names such as `rooch_queryEvents`.
+#[derive(Clone, Debug, Default)]
+pub struct QueryLimits {
+    config: QueryLimitsConfig,
+}
+
+impl QueryLimits {
+    pub fn new(config: QueryLimitsConfig) -> Self {
+        Self { config }
+    }
+
+    pub fn config(&self) -> &QueryLimitsConfig {
+        &self.config
+    }
+
+    /// The page size of the paginated query of the method, the default page size is used if the `limit` is absent.
+    pub fn page_size(&self, method: &str, limit: Option<usize>) -> Result<usize, RpcError> {
+        let max_page_size = self.config.max_page_size_of(method);
+        match limit {
+            Some(limit) if limit > max_page_size => Err(RpcError::query_limit_exceeded(
+                &format!("page size of {}", method),
+                limit,
+                max_page_size,
+            )),
+            Some(limit) => Ok(limit),
+            None => Ok(self.config.default_page_size_of(method)),
+        }
+    }
+
+    /// Check the number of the items requested at once by the method, such as the tx hashes of `rooch_getTransactionsByHash`,
+    /// they are limited by the max page size of the method.
+    pub fn check_batch_size(&self, method: &str, size: usize) -> Result<(), RpcError> {
+        let max_page_size = self.config.max_page_size_of(method);
+        if size > max_page_size {
+            return Err(RpcError::query_limit_exceeded(
+                &format!("batch size of {}", method),
+                size,
+                max_page_size,
+            ));
+        }
+        Ok(())
+    }
+
+    /// Check the number of the keys in the access path, the access path without keys lists the states by page.
+    pub fn check_access_path(&self, access_path: &AccessPath) -> Result<(), RpcError> {
+        let keys = match &access_path.0 {
+            Path::Object { object_ids } => object_ids.len(),
+            Path::Resource { resource_types, .. } => resource_types.as_ref().map_or(0, Vec::len),
+            Path::Module { module_names, .. } => module_names.as_ref().map_or(0, Vec::len),
+            Path::Table { keys, .. } => keys.as_ref().map_or(0, Vec::len),
+        };
+        if keys > self.config.max_access_path_keys {
+            return Err(RpcError::query_limit_exceeded(
+                "number of the access path keys",
+                keys,
+                self.config.max_access_path_keys,
+            ));
+        }
+        Ok(())
+    }
+
+    pub fn check_filter_complexity(&self, complexity: usize) -> Result<(), RpcError> {
+        if complexity > self.config.max_filter_complexity {
+            return Err(RpcError::query_limit_exceeded(
+                "filter complexity",
+                complexity,
+                self.config.max_filter_complexity,
+            ));
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,895 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::service::method_access::forward_call;
+use crate::service::tls::ForwardedPeers;
+use anyhow::Result;
+use hyper::body::HttpBody;
+use hyper::{header, Body, HeaderMap, Method, Request, Response, StatusCode};
+use jsonrpsee::core::server::rpc_module::{MethodCallback, MethodKind, Methods};
+use jsonrpsee::types::error::{CallError, ErrorObject};
+use jsonrpsee::RpcModule;
+use rooch_config::server_config::{RateLimitConfig, TokenBucketConfig};
+use std::cell::RefCell;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::future::Future;
+use std::net::{IpAddr, SocketAddr};
+use std::pin::Pin;
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::task::{Context, Poll};
+use std::time::Instant;
+use tower::{BoxError, Layer, Service};
+
+/// The key of the shared quota if the client can not be identified
+const UNKNOWN_CLIENT: &str = "unknown";
+
+/// Clean up the refilled buckets when the number of the buckets exceeds it
+const MAX_IDLE_BUCKETS: usize = 10000;
+
+/// The JSON-RPC error code of the requests rejected by the rate limiter
+const REQUEST_REJECTED_ERROR_CODE: i32 = -32005;
+
+struct TokenBucket {
+    capacity: f64,
+    refill_per_second: f64,
+    tokens: f64,
+    last_refill: Instant,
+}
+
+impl TokenBucket {
+    fn new(config: &TokenBucketConfig) -> Self {
+        let capacity = config.burst_size.max(1) as f64;
+        Self {
+            capacity,
+            refill_per_second: config.requests_per_second as f64,
+            tokens: capacity,
+            last_refill: Instant::now(),
+        }
+    }
+
+    fn refill(&mut self, now: Instant) {
+        let elapsed = now.duration_since(self.last_refill).as_secs_f64();
+        self.tokens = (self.tokens + elapsed * self.refill_per_second).min(self.capacity);
+        self.last_refill = now;
+    }
+
+    fn can_acquire(&mut self, now: Instant, cost: u32) -> bool {
+        self.refill(now);
+        self.tokens >= cost as f64
+    }
+
+    fn acquire(&mut self, cost: u32) {
+        self.tokens -= cost as f64;
+    }
+
+    fn is_full(&mut self, now: Instant) -> bool {
+        self.refill(now);
+        self.tokens >= self.capacity
+    }
+}
+
+/// The token buckets keyed by the client, or by the client and the method.
+struct TokenBuckets {
+    config: TokenBucketConfig,
+    buckets: Mutex<HashMap<String, TokenBucket>>,
+}
+
+impl TokenBuckets {
+    fn new(config: TokenBucketConfig) -> Self {
+        Self {
+            config,
+            buckets: Mutex::new(HashMap::new()),
+        }
+    }
+
+    fn lock(&self, now: Instant) -> MutexGuard<'_, HashMap<String, TokenBucket>> {
+        let mut buckets = self
+            .buckets
+            .lock()
+            .expect("Lock token buckets should success");
+        if buckets.len() > MAX_IDLE_BUCKETS {
+            buckets.retain(|_, bucket| !bucket.is_full(now));
+        }
+        buckets
+    }
+
+    fn bucket<'a>(
+        &self,
+        buckets: &'a mut HashMap<String, TokenBucket>,
+        key: &str,
+    ) -> &'a mut TokenBucket {
+        buckets
+            .entry(key.to_owned())
+            .or_insert_with(|| TokenBucket::new(&self.config))
+    }
+}
+
+/// The slot of the peer address of the request, it is inserted into the request extensions by the rate limiter
+/// and filled by `RpcLogger::on_connect`, as the peer address is not exposed to the HTTP middleware.
+#[derive(Clone, Default)]
+pub struct PeerAddrSlot(Arc<Mutex<Option<SocketAddr>>>);
+
+impl PeerAddrSlot {
+    pub fn set(&self, addr: SocketAddr) {
+        *self.0.lock().expect("Lock peer addr slot should success") = Some(addr);
+    }
+
+    pub fn get(&self) -> Option<SocketAddr> {
+        *self.0.lock().expect("Lock peer addr slot should success")
+    }
+}
+
+pub struct RateLimiter {
+    per_client: TokenBuckets,
+    per_method: HashMap<String, TokenBuckets>,
+    max_request_body_size: u32,
+    trust_proxy_headers: bool,
+    trusted_proxies: HashSet<IpAddr>,
+    forwarded_peers: ForwardedPeers,
+}
+
+impl RateLimiter {
+    pub fn new(config: RateLimitConfig, forwarded_peers: ForwardedPeers) -> Self {
+        Self {
+            per_client: TokenBuckets::new(config.per_client),
+            per_method: config
+                .per_method
+                .into_iter()
+                .map(|(method, config)| (method, TokenBuckets::new(config)))
+                .collect(),
+            max_request_body_size: config.max_request_body_size,
+            trust_proxy_headers: config.trust_proxy_headers,
+            trusted_proxies: config.trusted_proxies.into_iter().collect(),
+            forwarded_peers,
+        }
+    }
+
+    /// The client is identified by the proxy headers if they are trusted, otherwise by the peer ip.
+    /// The connections forwarded by the TLS server are identified by the peer ip of the TLS connection.
+    fn client(&self, headers: &HeaderMap, peer_addr: Option<SocketAddr>) -> String {
+        if self.trust_proxy_headers {
+            if let Some(ip) = proxy_client_ip(headers, &self.trusted_proxies) {
+                return ip;
+            }
+        }
+        match peer_addr {
+            Some(peer_addr) => self
+                .forwarded_peers
+                .get(&peer_addr)
+                .unwrap_or(peer_addr)
+                .ip()
+                .to_string(),
+            None => UNKNOWN_CLIENT.to_owned(),
+        }
+    }
+
+    /// Every call of a batch request costs a token of the client quota and a token of the method quota.
+    /// All the quotas are checked before any of them is charged, so the rejected request costs no token.
+    fn try_acquire(&self, client: &str, methods: &[String]) -> bool {
+        self.try_acquire_at(client, methods, Instant::now())
+    }
+
+    fn try_acquire_at(&self, client: &str, methods: &[String], now: Instant) -> bool {
+        let mut method_costs = BTreeMap::<&str, u32>::new();
+        for method in methods {
+            if self.per_method.contains_key(method) {
+                *method_costs.entry(method.as_str()).or_default() += 1;
+            }
+        }
+        let quotas = std::iter::once((&self.per_client, methods.len().max(1) as u32)).chain(
+            method_costs
+                .into_iter()
+                .map(|(method, cost)| (&self.per_method[method], cost)),
+        );
+        // The buckets are always locked in the same order, the client quota first and then the method quotas by name
+        let mut locked = quotas
+            .map(|(buckets, cost)| (buckets, buckets.lock(now), cost))
+            .collect::<Vec<_>>();
+        let acquirable = locked
+            .iter_mut()
+            .all(|(buckets, guard, cost)| buckets.bucket(guard, client).can_acquire(now, *cost));
+        if acquirable {
+            for (buckets, guard, cost) in locked.iter_mut() {
+                buckets.bucket(guard, client).acquire(*cost);
+            }
+        }
+        acquirable
+    }
+
+    /// The quota of the calls of a WebSocket connection
+    pub(crate) fn connection_quota(
+        self: &Arc<Self>,
+        headers: &HeaderMap,
+        peer_addr: SocketAddr,
+    ) -> CallQuota {
+        CallQuota {
+            limiter: self.clone(),
+            client: self.client(headers, Some(peer_addr)),
+        }
+    }
+}
+
+/// The tower layer applies the rate limits and the request body size limit to the JSON-RPC requests.
+/// The rate limited requests are rejected with `429 Too Many Requests`,
+/// and the oversized requests are rejected with `413 Payload Too Large`.
+#[derive(Clone)]
+pub struct RateLimitLayer {
+    limiter: Arc<RateLimiter>,
+}
+
+impl RateLimitLayer {
+    pub fn new(config: RateLimitConfig, forwarded_peers: ForwardedPeers) -> Self {
+        Self {
+            limiter: Arc::new(RateLimiter::new(config, forwarded_peers)),
+        }
+    }
+
+    /// The limiter shared with `RpcLogger`, which limits the calls of the WebSocket connections
+    pub fn limiter(&self) -> Arc<RateLimiter> {
+        self.limiter.clone()
+    }
+}
+
+impl<S> Layer<S> for RateLimitLayer {
+    type Service = RateLimitService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        RateLimitService {
+            inner,
+            limiter: self.limiter.clone(),
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct RateLimitService<S> {
+    inner: S,
+    limiter: Arc<RateLimiter>,
+}
+
+impl<S> Service<Request<Body>> for RateLimitService<S>
+where
+    S: Service<Request<Body>, Response = Response<Body>> + Clone + Send + 'static,
+    S::Error: Into<BoxError> + 'static,
+    S::Future: Send + 'static,
+{
+    type Response = Response<Body>;
+    type Error = BoxError;
+    type Future = Pin<Box<dyn Future<Output = Result<Self::Response, Self::Error>> + Send>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx).map_err(Into::into)
+    }
+
+    fn call(&mut self, request: Request<Body>) -> Self::Future {
+        // The inner service is ready, take it and leave a clone in place
+        let clone = self.inner.clone();
+        let mut inner = std::mem::replace(&mut self.inner, clone);
+        let limiter = self.limiter.clone();
+
+        Box::pin(async move {
+            let (mut parts, body) = request.into_parts();
+            // The WebSocket handshake and the other requests without JSON-RPC body
+            let (body, methods) = if parts.method == Method::POST {
+                let Some(bytes) = read_body(body, limiter.max_request_body_size).await? else {
+                    return Ok(error_response(
+                        StatusCode::PAYLOAD_TOO_LARGE,
+                        "Request body too large",
+                    ));
+                };
+                let methods = request_methods(&bytes);
+                (Body::from(bytes), methods)
+            } else {
+                (body, vec![])
+            };
+
+            // The JSON-RPC server fills the peer address when the request is passed to it.
+            // The HTTP request is not handled until the returned future is polled,
+            // and the WebSocket upgrade is not completed if the response is dropped.
+            let peer_addr = PeerAddrSlot::default();
+            parts.extensions.insert(peer_addr.clone());
+            let headers = parts.headers.clone();
+            let future = inner.call(Request::from_parts(parts, body));
+
+            let client = limiter.client(&headers, peer_addr.get());
+            if !limiter.try_acquire(&client, &methods) {
+                return Ok(error_response(
+                    StatusCode::TOO_MANY_REQUESTS,
+                    "Too many requests",
+                ));
+            }
+            future.await.map_err(Into::into)
+        })
+    }
+}
+
+/// The quota of the client of a WebSocket connection. The WebSocket messages are not seen by the tower layer,
+/// so every call of the connection is charged by the method wrapper of `limit_calls`.
+#[derive(Clone)]
+pub struct CallQuota {
+    limiter: Arc<RateLimiter>,
+    client: String,
+}
+
+impl CallQuota {
+    fn try_acquire(&self, method: &str) -> bool {
+        self.limiter.try_acquire(&self.client, &[method.to_owned()])
+    }
+}
+
+thread_local! {
+    /// The quota of the current WebSocket call. It is set by `RpcLogger::on_call`, and jsonrpsee calls the method
+    /// right after `on_call` on the same thread without yielding, so the method wrapper takes the quota of its call.
+    static CALL_QUOTA: RefCell<Option<CallQuota>> = RefCell::new(None);
+}
+
+/// Set the quota of the current call, it is `None` for the HTTP calls which are charged by the tower layer.
+pub(crate) fn set_call_quota(quota: Option<CallQuota>) {
+    CALL_QUOTA.with(|call_quota| *call_quota.borrow_mut() = quota);
+}
+
+fn take_call_quota() -> Option<CallQuota> {
+    CALL_QUOTA.with(|call_quota| call_quota.borrow_mut().take())
+}
+
+/// Wrap the method calls to charge the WebSocket calls, the wrapped calls are forwarded to the source methods.
+/// The subscriptions can not be forwarded, so the methods with subscriptions are returned as they are,
+/// the subscriptions of a connection are bounded by the max subscriptions per connection instead.
+pub fn limit_calls(methods: Methods) -> Result<Methods> {
+    let has_subscription = methods.method_names().any(|method| {
+        !matches!(
+            methods.method(method).map(MethodCallback::inner),
+            Some(MethodKind::Sync(_)) | Some(MethodKind::Async(_))
+        )
+    });
+    if has_subscription {
+        return Ok(methods);
+    }
+    let mut module = RpcModule::new(());
+    for method in methods.method_names() {
+        let methods = methods.clone();
+        module.register_async_method(method, move |params, _| {
+            // Take the quota when the call starts, before anything is awaited, see `CALL_QUOTA`
+            let quota = take_call_quota();
+            let methods = methods.clone();
+            async move {
+                if let Some(quota) = quota {
+                    if !quota.try_acquire(method) {
+                        return Err(jsonrpsee::core::Error::Call(CallError::Custom(
+                            ErrorObject::owned(
+                                REQUEST_REJECTED_ERROR_CODE,
+                                "Too many requests",
+                                None::<()>,
+                            ),
+                        )));
+                    }
+                }
+                forward_call(&methods, method, params).await
+            }
+        })?;
+    }
+    Ok(module.into())
+}
+
+/// The client ip set by the proxies in the `X-Forwarded-For` or `X-Real-IP` header.
+/// Every proxy appends the address of its peer to `X-Forwarded-For` and the entries on the left are set by the client,
+/// so the client is the rightmost entry which is not a trusted proxy.
+pub(crate) fn proxy_client_ip(
+    headers: &HeaderMap,
+    trusted_proxies: &HashSet<IpAddr>,
+) -> Option<String> {
+    let hops = headers
+        .get_all("x-forwarded-for")
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(','))
+        .map(str::trim)
+        .filter(|hop| !hop.is_empty())
+        .collect::<Vec<_>>();
+    let is_trusted = |hop: &&&str| {
+        hop.parse::<IpAddr>()
+            .map_or(false, |ip| trusted_proxies.contains(&ip))
+    };
+    hops.iter()
+        .rev()
+        .find(|hop| !is_trusted(hop))
+        // All the hops are the trusted proxies, the leftmost one is the nearest to the client
+        .or_else(|| hops.first())
+        .copied()
+        .or_else(|| {
+            headers
+                .get("x-real-ip")
+                .and_then(|value| value.to_str().ok())
+                .map(str::trim)
+                .filter(|ip| !ip.is_empty())
+        })
+        .map(ToOwned::to_owned)
+}
+
+/// Read the request body, return None if the body exceeds the `max_size`.
+pub(crate) async fn read_body(mut body: Body, max_size: u32) -> Result<Option<Vec<u8>>, BoxError> {
+    let max_size = max_size as usize;
+    if body.size_hint().lower() as usize > max_size {
+        return Ok(None);
+    }
+    let mut bytes = Vec::new();
+    while let Some(chunk) = body.data().await {
+        let chunk = chunk?;
+        if bytes.len() + chunk.len() > max_size {
+            return Ok(None);
+        }
+        bytes.extend_from_slice(&chunk);
+    }
+    Ok(Some(bytes))
+}
+
+/// The method names of the single or batch JSON-RPC request, the invalid request is left to the JSON-RPC server.
+pub(crate) fn request_methods(body: &[u8]) -> Vec<String> {
+    let method = |call: &serde_json::Value| {
+        call.get("method")
+            .and_then(|method| method.as_str())
+            .map(ToOwned::to_owned)
+    };
+    match serde_json::from_slice::<serde_json::Value>(body) {
+        Ok(serde_json::Value::Array(calls)) => calls.iter().filter_map(method).collect(),
+        Ok(call) => method(&call).into_iter().collect(),
+        Err(_) => vec![],
+    }
+}
+
+fn error_response(status: StatusCode, message: &str) -> Response<Body> {
+    let body = serde_json::json!({
+        "jsonrpc": "2.0",
+        "error": {
+            "code": REQUEST_REJECTED_ERROR_CODE,
+            "message": message,
+        },
+        "id": null,
+    });
+    Response::builder()
+        .status(status)
+        .header(header::CONTENT_TYPE, "application/json")
+        .body(Body::from(body.to_string()))
+        .expect("Build error response should success")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::service::rpc_logger::RpcLogger;
+    use jsonrpsee::core::client::ClientT;
+    use jsonrpsee::http_client::HttpClientBuilder;
+    use jsonrpsee::rpc_params;
+    use jsonrpsee::server::ServerBuilder;
+    use jsonrpsee::ws_client::WsClientBuilder;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::time::Duration;
+    use tower::ServiceExt;
+
+    const TEST_PEER_HEADER: &str = "x-test-peer";
+
+    fn bucket_config(burst_size: u32) -> TokenBucketConfig {
+        // The bucket is not refilled during the test
+        TokenBucketConfig {
+            requests_per_second: 0,
+            burst_size,
+        }
+    }
+
+    fn rate_limit_config(burst_size: u32) -> RateLimitConfig {
+        RateLimitConfig {
+            per_client: bucket_config(burst_size),
+            per_method: BTreeMap::new(),
+            max_request_body_size: RateLimitConfig::DEFAULT_MAX_REQUEST_BODY_SIZE,
+            trust_proxy_headers: false,
+            trusted_proxies: vec![],
+        }
+    }
+
+    /// The mock of the JSON-RPC server, it fills the peer address like `RpcLogger::on_connect`
+    /// and counts the handled requests.
+    fn mock_server(
+        config: RateLimitConfig,
+        forwarded_peers: ForwardedPeers,
+        handled: Arc<AtomicUsize>,
+    ) -> impl Service<Request<Body>, Response = Response<Body>, Error = BoxError> {
+        let server = tower::service_fn(move |request: Request<Body>| {
+            let peer_addr = request
+                .headers()
+                .get(TEST_PEER_HEADER)
+                .and_then(|value| value.to_str().ok())
+                .and_then(|value| value.parse::<SocketAddr>().ok());
+            if let (Some(slot), Some(peer_addr)) =
+                (request.extensions().get::<PeerAddrSlot>(), peer_addr)
+            {
+                slot.set(peer_addr);
+            }
+            let handled = handled.clone();
+            async move {
+                handled.fetch_add(1, Ordering::SeqCst);
+                Ok::<_, BoxError>(Response::new(Body::empty()))
+            }
+        });
+        RateLimitLayer::new(config, forwarded_peers).layer(server)
+    }
+
+    fn request(peer: &str, body: &str) -> Request<Body> {
+        Request::builder()
+            .method(Method::POST)
+            .header(TEST_PEER_HEADER, peer)
+            .body(Body::from(body.to_owned()))
+            .unwrap()
+    }
+
+    fn call(method: &str) -> String {
+        format!(
+            r#"{{"jsonrpc":"2.0","id":1,"method":"{}","params":[]}}"#,
+            method
+        )
+    }
+
+    fn batch(method: &str, calls: usize) -> String {
+        format!("[{}]", vec![call(method); calls].join(","))
+    }
+
+    async fn status<S>(service: &mut S, request: Request<Body>) -> StatusCode
+    where
+        S: Service<Request<Body>, Response = Response<Body>, Error = BoxError>,
+    {
+        service
+            .ready()
+            .await
+            .unwrap()
+            .call(request)
+            .await
+            .unwrap()
+            .status()
+    }
+
+    #[test]
+    fn test_token_bucket() {
+        let mut bucket = TokenBucket::new(&TokenBucketConfig {
+            requests_per_second: 1,
+            burst_size: 2,
+        });
+        let now = bucket.last_refill;
+        assert!(bucket.can_acquire(now, 2));
+        bucket.acquire(2);
+        assert!(!bucket.can_acquire(now, 1));
+        let now = now + Duration::from_secs(1);
+        assert!(bucket.can_acquire(now, 1));
+        assert!(!bucket.can_acquire(now, 2));
+
+        // The bucket is refilled up to the burst size
+        let now = now + Duration::from_secs(10);
+        assert!(bucket.is_full(now));
+        assert!(!bucket.can_acquire(now, 3));
+        assert!(bucket.can_acquire(now, 2));
+    }
+
+    #[test]
+    fn test_rate_limit_per_key() {
+        let limiter = RateLimiter::new(rate_limit_config(1), ForwardedPeers::default());
+        let now = Instant::now();
+        assert!(limiter.try_acquire_at("a", &[], now));
+        assert!(!limiter.try_acquire_at("a", &[], now));
+        assert!(limiter.try_acquire_at("b", &[], now));
+    }
+
+    #[test]
+    fn test_rejected_request_costs_no_token() {
+        let mut config = rate_limit_config(2);
+        config
+            .per_method
+            .insert("rooch_sendRawTransaction".to_owned(), bucket_config(1));
+        let limiter = RateLimiter::new(config, ForwardedPeers::default());
+        let now = Instant::now();
+        let send = "rooch_sendRawTransaction".to_owned();
+        let get = "rooch_getStates".to_owned();
+
+        // The batch exceeds the method quota, the client quota is not charged
+        assert!(!limiter.try_acquire_at("a", &[send.clone(), send.clone()], now));
+        assert!(limiter.try_acquire_at("a", &[get.clone(), get.clone()], now));
+        // The batch exceeds the client quota, the method quota is not charged
+        assert!(!limiter.try_acquire_at("b", &[get.clone(), get.clone(), send.clone()], now));
+        assert!(limiter.try_acquire_at("b", &[send.clone()], now));
+        assert!(!limiter.try_acquire_at("b", &[send], now));
+    }
+
+    #[test]
+    fn test_request_methods() {
+        assert_eq!(
+            request_methods(call("rooch_getStates").as_bytes()),
+            vec!["rooch_getStates".to_owned()]
+        );
+        assert_eq!(
+            request_methods(batch("rooch_getStates", 3).as_bytes()),
+            vec!["rooch_getStates".to_owned(); 3]
+        );
+        assert!(request_methods(b"invalid").is_empty());
+    }
+
+    #[test]
+    fn test_proxy_client_ip() {
+        let no_trusted_proxies = HashSet::new();
+        let mut headers = HeaderMap::new();
+        assert_eq!(proxy_client_ip(&headers, &no_trusted_proxies), None);
+        headers.insert("x-real-ip", "10.0.0.2".parse().unwrap());
+        assert_eq!(
+            proxy_client_ip(&headers, &no_trusted_proxies),
+            Some("10.0.0.2".to_owned())
+        );
+        // The leftmost entry is set by the client, the rightmost entry is set by the nearest proxy
+        headers.insert("x-forwarded-for", " 10.0.0.1, 10.0.0.3".parse().unwrap());
+        assert_eq!(
+            proxy_client_ip(&headers, &no_trusted_proxies),
+            Some("10.0.0.3".to_owned())
+        );
+        headers.append("x-forwarded-for", "10.0.0.4".parse().unwrap());
+        assert_eq!(
+            proxy_client_ip(&headers, &no_trusted_proxies),
+            Some("10.0.0.4".to_owned())
+        );
+
+        // The trusted proxies are skipped
+        let trusted_proxies = ["10.0.0.3", "10.0.0.4"]
+            .iter()
+            .map(|ip| ip.parse().unwrap())
+            .collect::<HashSet<IpAddr>>();
+        assert_eq!(
+            proxy_client_ip(&headers, &trusted_proxies),
+            Some("10.0.0.1".to_owned())
+        );
+        headers.insert("x-forwarded-for", "10.0.0.3, 10.0.0.4".parse().unwrap());
+        assert_eq!(
+            proxy_client_ip(&headers, &trusted_proxies),
+            Some("10.0.0.3".to_owned())
+        );
+    }
+
+    #[tokio::test]
+    async fn test_rate_limit_per_peer() {
+        let handled = Arc::new(AtomicUsize::new(0));
+        let mut service = mock_server(
+            rate_limit_config(2),
+            ForwardedPeers::default(),
+            handled.clone(),
+        );
+        let body = call("rooch_getStates");
+
+        assert_eq!(
+            status(&mut service, request("10.0.0.1:1000", &body)).await,
+            StatusCode::OK
+        );
+        // The other connection of the same peer shares the quota
+        assert_eq!(
+            status(&mut service, request("10.0.0.1:1001", &body)).await,
+            StatusCode::OK
+        );
+        assert_eq!(
+            status(&mut service, request("10.0.0.1:1000", &body)).await,
+            StatusCode::TOO_MANY_REQUESTS
+        );
+        // The other peer has its own quota
+        assert_eq!(
+            status(&mut service, request("10.0.0.2:1000", &body)).await,
+            StatusCode::OK
+        );
+        // The rejected request is not handled by the JSON-RPC server
+        assert_eq!(handled.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn test_rate_limit_batch() {
+        let handled = Arc::new(AtomicUsize::new(0));
+        let mut service = mock_server(
+            rate_limit_config(3),
+            ForwardedPeers::default(),
+            handled.clone(),
+        );
+        let peer = "10.0.0.1:1000";
+
+        // Every call of the batch costs a token
+        assert_eq!(
+            status(&mut service, request(peer, &batch("rooch_getStates", 4))).await,
+            StatusCode::TOO_MANY_REQUESTS
+        );
+        assert_eq!(
+            status(&mut service, request(peer, &batch("rooch_getStates", 2))).await,
+            StatusCode::OK
+        );
+        assert_eq!(
+            status(&mut service, request(peer, &batch("rooch_getStates", 2))).await,
+            StatusCode::TOO_MANY_REQUESTS
+        );
+        assert_eq!(
+            status(&mut service, request(peer, &call("rooch_getStates"))).await,
+            StatusCode::OK
+        );
+        assert_eq!(handled.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_rate_limit_per_method() {
+        let mut config = rate_limit_config(10);
+        config
+            .per_method
+            .insert("rooch_sendRawTransaction".to_owned(), bucket_config(1));
+        let mut service = mock_server(
+            config,
+            ForwardedPeers::default(),
+            Arc::new(AtomicUsize::new(0)),
+        );
+        let peer = "10.0.0.1:1000";
+
+        let send = call("rooch_sendRawTransaction");
+        assert_eq!(
+            status(&mut service, request(peer, &send)).await,
+            StatusCode::OK
+        );
+        assert_eq!(
+            status(&mut service, request(peer, &send)).await,
+            StatusCode::TOO_MANY_REQUESTS
+        );
+        assert_eq!(
+            status(&mut service, request(peer, &call("rooch_getStates"))).await,
+            StatusCode::OK
+        );
+        assert_eq!(
+            status(&mut service, request("10.0.0.2:1000", &send)).await,
+            StatusCode::OK
+        );
+    }
+
+    #[tokio::test]
+    async fn test_request_body_too_large() {
+        let mut config = rate_limit_config(10);
+        config.max_request_body_size = 16;
+        let handled = Arc::new(AtomicUsize::new(0));
+        let mut service = mock_server(config, ForwardedPeers::default(), handled.clone());
+
+        assert_eq!(
+            status(
+                &mut service,
+                request("10.0.0.1:1000", &call("rooch_getStates"))
+            )
+            .await,
+            StatusCode::PAYLOAD_TOO_LARGE
+        );
+        assert_eq!(handled.load(Ordering::SeqCst), 0);
+    }
+
+    #[tokio::test]
+    async fn test_rate_limit_proxy_headers() {
+        let body = call("rooch_getStates");
+        let proxied = |client: &str| {
+            let mut request = request("127.0.0.1:1000", &body);
+            request
+                .headers_mut()
+                .insert("x-forwarded-for", client.parse().unwrap());
+            request
+        };
+
+        // The proxy headers are ignored by default
+        let mut service = mock_server(
+            rate_limit_config(1),
+            ForwardedPeers::default(),
+            Arc::new(AtomicUsize::new(0)),
+        );
+        assert_eq!(
+            status(&mut service, proxied("10.0.0.1")).await,
+            StatusCode::OK
+        );
+        assert_eq!(
+            status(&mut service, proxied("10.0.0.2")).await,
+            StatusCode::TOO_MANY_REQUESTS
+        );
+
+        let mut config = rate_limit_config(1);
+        config.trust_proxy_headers = true;
+        let mut service = mock_server(
+            config,
+            ForwardedPeers::default(),
+            Arc::new(AtomicUsize::new(0)),
+        );
+        assert_eq!(
+            status(&mut service, proxied("10.0.0.1")).await,
+            StatusCode::OK
+        );
+        assert_eq!(
+            status(&mut service, proxied("10.0.0.2")).await,
+            StatusCode::OK
+        );
+        assert_eq!(
+            status(&mut service, proxied("10.0.0.1")).await,
+            StatusCode::TOO_MANY_REQUESTS
+        );
+        // The client can not escape the limit by prepending a forged address
+        assert_eq!(
+            status(&mut service, proxied("10.0.0.3, 10.0.0.1")).await,
+            StatusCode::TOO_MANY_REQUESTS
+        );
+    }
+
+    #[tokio::test]
+    async fn test_rate_limit_forwarded_peers() {
+        let forwarded_peers = ForwardedPeers::default();
+        forwarded_peers.insert(
+            "127.0.0.1:1000".parse().unwrap(),
+            "10.0.0.1:2000".parse().unwrap(),
+        );
+        forwarded_peers.insert(
+            "127.0.0.1:1001".parse().unwrap(),
+            "10.0.0.2:2000".parse().unwrap(),
+        );
+        let mut service = mock_server(
+            rate_limit_config(1),
+            forwarded_peers,
+            Arc::new(AtomicUsize::new(0)),
+        );
+        let body = call("rooch_getStates");
+
+        // The TLS connections are identified by the peer of the TLS connection
+        assert_eq!(
+            status(&mut service, request("127.0.0.1:1000", &body)).await,
+            StatusCode::OK
+        );
+        assert_eq!(
+            status(&mut service, request("127.0.0.1:1001", &body)).await,
+            StatusCode::OK
+        );
+        assert_eq!(
+            status(&mut service, request("127.0.0.1:1000", &body)).await,
+            StatusCode::TOO_MANY_REQUESTS
+        );
+    }
+
+    #[tokio::test]
+    async fn test_rate_limit_websocket_calls() {
+        let layer = RateLimitLayer::new(rate_limit_config(4), ForwardedPeers::default());
+        let server = ServerBuilder::default()
+            .set_logger(RpcLogger::new(None, Some(layer.limiter())))
+            .set_middleware(tower::ServiceBuilder::new().layer(layer))
+            .build("127.0.0.1:0")
+            .await
+            .unwrap();
+        let addr = server.local_addr().unwrap();
+        let mut module = RpcModule::new(());
+        module
+            .register_method("rooch_getChainID", |_, _| Ok(1u64))
+            .unwrap();
+        let handle = server.start(limit_calls(module.into()).unwrap()).unwrap();
+
+        let ws_client = WsClientBuilder::default()
+            .build(format!("ws://{}", addr))
+            .await
+            .unwrap();
+        let http_client = HttpClientBuilder::default()
+            .build(format!("http://{}", addr))
+            .unwrap();
+        // The WebSocket upgrade and every call of the connection cost a token
+        for _ in 0..2 {
+            let chain_id: u64 = ws_client
+                .request("rooch_getChainID", rpc_params![])
+                .await
+                .unwrap();
+            assert_eq!(chain_id, 1);
+        }
+        // The HTTP call is only charged by the layer
+        let chain_id: u64 = http_client
+            .request("rooch_getChainID", rpc_params![])
+            .await
+            .unwrap();
+        assert_eq!(chain_id, 1);
+        let result = ws_client
+            .request::<u64, _>("rooch_getChainID", rpc_params![])
+            .await;
+        handle.stop().unwrap();
+        match result {
+            Err(jsonrpsee::core::Error::Call(CallError::Custom(err))) => {
+                assert_eq!(err.code(), REQUEST_REJECTED_ERROR_CODE)
+            }
+            other => panic!("Unexpected result: {:?}", other),
+        }
+    }
+}
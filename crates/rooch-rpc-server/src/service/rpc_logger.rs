@@ -1,21 +1,66 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
 
-use jsonrpsee::server::logger::Logger;
+use crate::service::rate_limiter::{set_call_quota, CallQuota, PeerAddrSlot, RateLimiter};
+use crate::service::rpc_metrics::RpcMetrics;
+use crate::LOG_TARGET;
+use jsonrpsee::server::logger::{Logger, MethodKind, TransportProtocol};
+use std::sync::{Arc, OnceLock};
 use tracing::Level;
 
-#[derive(Debug, Clone)]
-pub struct RpcLogger;
+#[derive(Default)]
+pub struct RpcLogger {
+    metrics: Option<RpcMetrics>,
+    rate_limiter: Option<Arc<RateLimiter>>,
+    /// The quota of the WebSocket connection of the logger, it is set by `on_connect`
+    connection_quota: Arc<OnceLock<CallQuota>>,
+}
+
+impl RpcLogger {
+    pub fn new(metrics: Option<RpcMetrics>, rate_limiter: Option<Arc<RateLimiter>>) -> Self {
+        Self {
+            metrics,
+            rate_limiter,
+            connection_quota: Default::default(),
+        }
+    }
+}
+
+/// jsonrpsee clones the logger for every connection before `on_connect`,
+/// and clones it again for the WebSocket connection after `on_connect`.
+/// So the unset quota is not shared by the clones, and the set quota is shared by the clones of its connection.
+impl Clone for RpcLogger {
+    fn clone(&self) -> Self {
+        Self {
+            metrics: self.metrics.clone(),
+            rate_limiter: self.rate_limiter.clone(),
+            connection_quota: match self.connection_quota.get() {
+                Some(_) => self.connection_quota.clone(),
+                None => Default::default(),
+            },
+        }
+    }
+}
 
 impl Logger for RpcLogger {
     type Instant = std::time::Instant;
 
     fn on_connect(
         &self,
-        _remote_addr: std::net::SocketAddr,
-        _request: &jsonrpsee::server::logger::HttpRequest,
-        _t: jsonrpsee::server::logger::TransportProtocol,
+        remote_addr: std::net::SocketAddr,
+        request: &jsonrpsee::server::logger::HttpRequest,
+        transport: TransportProtocol,
     ) {
+        // Expose the peer address to the rate limiter
+        if let Some(peer_addr) = request.extensions().get::<PeerAddrSlot>() {
+            peer_addr.set(remote_addr);
+        }
+        if let (TransportProtocol::WebSocket, Some(rate_limiter)) = (transport, &self.rate_limiter)
+        {
+            let _ = self
+                .connection_quota
+                .set(rate_limiter.connection_quota(request.headers(), remote_addr));
+        }
     }
 
     fn on_request(
@@ -29,15 +74,23 @@ impl Logger for RpcLogger {
         &self,
         method_name: &str,
         params: jsonrpsee::types::Params,
-        _kind: jsonrpsee::server::logger::MethodKind,
-        transport: jsonrpsee::server::logger::TransportProtocol,
+        kind: MethodKind,
+        transport: TransportProtocol,
     ) {
+        // Hand the quota of the WebSocket call to the method, the HTTP calls are charged by the rate limit layer
+        set_call_quota(match (transport, kind) {
+            (TransportProtocol::WebSocket, MethodKind::MethodCall) => {
+                self.connection_quota.get().cloned()
+            }
+            _ => None,
+        });
         //TODO remove param parse when server stable.
         let params_str = match params.parse::<serde_json::Value>() {
             Ok(json) => json.to_string(),
             Err(e) => e.to_string(),
         };
         tracing::event!(
+            target: LOG_TARGET,
             Level::INFO,
             event = "on_call",
             transport = transport.to_string(),
@@ -53,8 +106,13 @@ impl Logger for RpcLogger {
         started_at: Self::Instant,
         _transport: jsonrpsee::server::logger::TransportProtocol,
     ) {
-        let elapsed_millis = started_at.elapsed().as_millis();
+        let elapsed = started_at.elapsed();
+        if let Some(metrics) = &self.metrics {
+            metrics.observe_call(method_name, success, elapsed);
+        }
+        let elapsed_millis = elapsed.as_millis();
         tracing::event!(
+            target: LOG_TARGET,
             Level::INFO,
             event = "on_result",
             method_name = method_name,
@@ -0,0 +1,53 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use metrics::{
+    register, HistogramOpts, HistogramVec, Opts, PrometheusError, Registry, UIntCounterVec,
+};
+use std::time::Duration;
+
+#[derive(Clone)]
+pub struct RpcMetrics {
+    pub rpc_requests_total: UIntCounterVec,
+    pub rpc_request_latency_seconds: HistogramVec,
+}
+
+impl RpcMetrics {
+    pub fn register(registry: &Registry) -> Result<Self, PrometheusError> {
+        let rpc_requests_total = register(
+            UIntCounterVec::new(
+                Opts::new(
+                    "rpc_requests_total",
+                    "Counters of how many JSON-RPC calls by method",
+                ),
+                &["method", "result"],
+            )?,
+            registry,
+        )?;
+        let rpc_request_latency_seconds = register(
+            HistogramVec::new(
+                HistogramOpts::new(
+                    "rpc_request_latency_seconds",
+                    "Histogram of the JSON-RPC call latency in seconds",
+                ),
+                &["method"],
+            )?,
+            registry,
+        )?;
+
+        Ok(Self {
+            rpc_requests_total,
+            rpc_request_latency_seconds,
+        })
+    }
+
+    pub fn observe_call(&self, method_name: &str, success: bool, elapsed: Duration) {
+        let result = if success { "ok" } else { "error" };
+        self.rpc_requests_total
+            .with_label_values(&[method_name, result])
+            .inc();
+        self.rpc_request_latency_seconds
+            .with_label_values(&[method_name])
+            .observe(elapsed.as_secs_f64());
+    }
+}
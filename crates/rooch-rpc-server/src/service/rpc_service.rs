@@ -1,35 +1,57 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
 
+use crate::service::subscription_service::SubscriptionService;
+use crate::LOG_TARGET;
 use anyhow::Result;
+use futures::Stream;
 use move_core_types::account_address::AccountAddress;
-use move_core_types::language_storage::StructTag;
+use move_core_types::language_storage::{ModuleId, StructTag};
 
+use moveos_common::utils::{now_milliseconds, now_seconds};
 use moveos_types::access_path::AccessPath;
 use moveos_types::function_return_value::AnnotatedFunctionResult;
 use moveos_types::h256::H256;
+use moveos_types::module_binding::MoveFunctionCaller;
 use moveos_types::moveos_std::event::{AnnotatedEvent, Event, EventID};
+use moveos_types::moveos_std::move_module::MoveModule;
+use moveos_types::moveos_std::object_id::ObjectID;
 use moveos_types::state::{AnnotatedState, KeyState, MoveStructType, State};
+use moveos_types::state_proof::StateProof;
 use moveos_types::state_resolver::{AnnotatedStateKV, StateKV};
-use moveos_types::transaction::{FunctionCall, TransactionExecutionInfo};
+use moveos_types::transaction::{FunctionCall, RawTransactionOutput, TransactionExecutionInfo};
 use rooch_executor::proxy::ExecutorProxy;
 use rooch_indexer::proxy::IndexerProxy;
+use rooch_proposer::fee::GasUsageStats;
 use rooch_proposer::proxy::ProposerProxy;
+use rooch_proposer::scc::PendingTransaction;
 use rooch_relayer::TxSubmiter;
 use rooch_rpc_api::jsonrpc_types::{ExecuteTransactionResponse, ExecuteTransactionResponseView};
 use rooch_sequencer::proxy::SequencerProxy;
 use rooch_types::account::Account;
+use rooch_types::account::AccountModule;
 use rooch_types::address::{MultiChainAddress, RoochAddress};
+use rooch_types::framework::scheduler::{ScheduledTask, SchedulerModule};
+use rooch_types::framework::transaction_fee::TransactionFeeModule;
+use rooch_types::indexer::account::{
+    IndexerAccountSummary, IndexerAddressMapping, IndexerStorageUsage,
+};
+use rooch_types::indexer::coin_transfer::IndexerCoinTransfer;
 use rooch_types::indexer::event_filter::{EventFilter, IndexerEvent, IndexerEventID};
+use rooch_types::indexer::module::{IndexerModule, ModuleFilter};
 use rooch_types::indexer::state::{
-    GlobalStateFilter, IndexerGlobalState, IndexerStateID, IndexerTableChangeSet,
-    IndexerTableState, StateSyncFilter, TableStateFilter,
+    GlobalStateFilter, IndexerCoinInfo, IndexerGlobalState, IndexerObjectOwnershipChange,
+    IndexerObjectStateChange, IndexerStateChangeSet, IndexerStateID, IndexerTableChangeSet,
+    IndexerTableState, IndexerUTXOState, ObjectStateFilter, StateSyncFilter, TableStateFilter,
+    UTXOFilter,
 };
 use rooch_types::indexer::transaction_filter::TransactionFilter;
 use rooch_types::sequencer::SequencerOrder;
-use rooch_types::transaction::rooch::RoochTransaction;
+use rooch_types::transaction::rooch::{RoochTransaction, RoochTransactionData};
+use rooch_types::transaction::view::SignedViewFunctionCall;
+use rooch_types::transaction::{AbstractTransaction, TransactionWithInfo, TypedTransaction};
 use rooch_types::transaction::{TransactionSequenceInfo, TransactionSequenceInfoMapping};
-use rooch_types::transaction::{TransactionWithInfo, TypedTransaction};
+use tracing::{info_span, Instrument, Span};
 
 /// RpcService is the implementation of the RPC service.
 /// It is the glue between the RPC server(EthAPIServer,RoochApiServer) and the rooch's actors.
@@ -41,6 +63,9 @@ pub struct RpcService {
     pub(crate) sequencer: SequencerProxy,
     pub(crate) proposer: ProposerProxy,
     pub(crate) indexer: IndexerProxy,
+    pub(crate) subscription: SubscriptionService,
+    /// The read replica serves the queries only, the transactions should be sent to the primary node
+    pub(crate) read_only: bool,
 }
 
 impl RpcService {
@@ -50,6 +75,7 @@ impl RpcService {
         sequencer: SequencerProxy,
         proposer: ProposerProxy,
         indexer: IndexerProxy,
+        subscription: SubscriptionService,
     ) -> Self {
         Self {
             chain_id,
@@ -57,8 +83,15 @@ impl RpcService {
             sequencer,
             proposer,
             indexer,
+            subscription,
+            read_only: false,
         }
     }
+
+    pub fn with_read_only(mut self, read_only: bool) -> Self {
+        self.read_only = read_only;
+        self
+    }
 }
 
 impl RpcService {
@@ -72,30 +105,72 @@ impl RpcService {
         Ok(())
     }
 
+    /// Execute the transaction through the pipeline, every stage is traced by a span of the transaction span,
+    /// the stage span covers the time waiting in the mailbox of the actor and handling the message.
+    #[tracing::instrument(name = "rooch.execute_tx", skip_all, fields(tx_hash = ?tx.tx_hash(), tx_order = tracing::field::Empty))]
     pub async fn execute_tx(&self, tx: TypedTransaction) -> Result<ExecuteTransactionResponse> {
+        if self.read_only {
+            anyhow::bail!(
+                "The node is a read replica, the transaction should be sent to the primary node"
+            );
+        }
+        // The expiration is checked at admission only, the executor validation is also used to replay the history
+        tx.check_expiration(now_seconds())?;
         // First, validate the transactin
-        let moveos_tx = self.executor.validate_transaction(tx.clone()).await?;
-        let sequence_info = self.sequencer.sequence_transaction(tx.clone()).await?;
+        let moveos_tx = self
+            .executor
+            .validate_transaction(tx.clone())
+            .instrument(info_span!("executor.validate"))
+            .await?;
+        let sequence_info = self
+            .sequencer
+            .sequence_transaction(tx.clone())
+            .instrument(info_span!("sequencer.sequence"))
+            .await?;
+        Span::current().record("tx_order", sequence_info.tx_order);
         // Then execute
-        let (output, execution_info) = self.executor.execute_transaction(moveos_tx.clone()).await?;
+        let (output, execution_info) = self
+            .executor
+            .execute_transaction(moveos_tx.clone())
+            .instrument(info_span!("executor.execute"))
+            .await?;
+        // The time the transaction is executed by the node, pushed to the subscribers with the outputs
+        let created_at = now_milliseconds();
         self.proposer
             .propose_transaction(tx.clone(), execution_info.clone(), sequence_info.clone())
+            .instrument(info_span!("proposer.propose"))
             .await?;
 
         // Sync lastest state root from writer executor to reader executor
         self.executor
             .refresh_state(execution_info.state_root, output.is_upgrade)
+            .instrument(info_span!("executor.refresh_state"))
             .await?;
 
-        // Last save indexer
+        // Last queue the indexer writes, they are committed in batches by the indexer
         let result = self
             .indexer
             .indexer_states(sequence_info.tx_order, output.state_changeset.clone())
+            .instrument(info_span!("indexer.commit", table = "states"))
             .await;
         match result {
             Ok(_) => {}
-            Err(error) => log::error!("Indexer states error: {}", error),
+            Err(error) => tracing::error!(target: LOG_TARGET, "Indexer states error: {}", error),
         };
+        // Push the object changes to subscribers
+        if self.subscription.has_object_state_subscribers() {
+            let state_change_set = IndexerStateChangeSet {
+                tx_order: sequence_info.tx_order,
+                state_change_set: output.state_changeset.clone(),
+                created_at,
+            };
+            match IndexerObjectStateChange::from_state_change_set(&state_change_set, tx.tx_hash()) {
+                Ok(changes) => self.subscription.publish_object_state_changes(changes),
+                Err(error) => {
+                    tracing::error!(target: LOG_TARGET, "Split object state changes error: {}", error)
+                }
+            }
+        }
         let result = self
             .indexer
             .indexer_transaction(
@@ -104,18 +179,41 @@ impl RpcService {
                 execution_info.clone(),
                 moveos_tx.clone(),
             )
+            .instrument(info_span!("indexer.commit", table = "transactions"))
             .await;
         match result {
             Ok(_) => {}
-            Err(error) => log::error!("Indexer transactions error: {}", error),
+            Err(error) => {
+                tracing::error!(target: LOG_TARGET, "Indexer transactions error: {}", error)
+            }
         };
+        // Push the events to subscribers before moving the transaction into the indexer
+        if self.subscription.has_event_subscribers() {
+            let tx_hash = tx.tx_hash();
+            let events = output
+                .events
+                .iter()
+                .cloned()
+                .map(|event| {
+                    IndexerEvent::new(
+                        event,
+                        tx_hash,
+                        sequence_info.tx_order,
+                        moveos_tx.ctx.sender,
+                        created_at,
+                    )
+                })
+                .collect();
+            self.subscription.publish_events(events);
+        }
         let result = self
             .indexer
             .indexer_events(output.events.clone(), tx, sequence_info.clone(), moveos_tx)
+            .instrument(info_span!("indexer.commit", table = "events"))
             .await;
         match result {
             Ok(_) => {}
-            Err(error) => log::error!("Indexer events error: {}", error),
+            Err(error) => tracing::error!(target: LOG_TARGET, "Indexer events error: {}", error),
         };
 
         Ok(ExecuteTransactionResponse {
@@ -125,6 +223,66 @@ impl RpcService {
         })
     }
 
+    /// Dry run the signed transaction, the authenticator is validated but the output is not committed
+    pub async fn dry_run_tx(
+        &self,
+        tx: TypedTransaction,
+        gas_profile: bool,
+    ) -> Result<RawTransactionOutput> {
+        tx.check_expiration(now_seconds())?;
+        let moveos_tx = self.executor.validate_transaction(tx).await?;
+        self.executor
+            .dry_run_transaction(moveos_tx, gas_profile)
+            .await
+    }
+
+    /// Dry run the unsigned transaction, the authenticator is not validated
+    pub async fn dry_run_tx_data(
+        &self,
+        tx_data: RoochTransactionData,
+        gas_profile: bool,
+    ) -> Result<RawTransactionOutput> {
+        let moveos_tx = self.executor.validate_unsigned_transaction(tx_data).await?;
+        self.executor
+            .dry_run_transaction(moveos_tx, gas_profile)
+            .await
+    }
+
+    /// Get the gas usage stats of the recent executed transactions
+    pub async fn get_gas_usage_stats(&self) -> Result<GasUsageStats> {
+        self.proposer.get_gas_usage_stats().await
+    }
+
+    pub async fn get_pending_transactions(
+        &self,
+        cursor: Option<u64>,
+        limit: usize,
+    ) -> Result<Vec<PendingTransaction>> {
+        self.proposer.get_pending_transactions(cursor, limit).await
+    }
+
+    pub fn get_gas_price(&self) -> Result<u64> {
+        self.executor
+            .as_module_binding::<TransactionFeeModule>()
+            .get_gas_factor()
+    }
+
+    pub fn get_sequence_number(&self, address: AccountAddress) -> Result<u64> {
+        self.executor
+            .as_module_binding::<AccountModule>()
+            .sequence_number(address)
+    }
+
+    pub fn list_scheduled_tasks(
+        &self,
+        start_task_id: u64,
+        limit: u64,
+    ) -> Result<Vec<ScheduledTask>> {
+        self.executor
+            .as_module_binding::<SchedulerModule>()
+            .tasks(start_task_id, limit)
+    }
+
     pub async fn execute_view_function(
         &self,
         function_call: FunctionCall,
@@ -133,45 +291,88 @@ impl RpcService {
         Ok(resp)
     }
 
+    pub async fn execute_signed_view_function(
+        &self,
+        signed_call: SignedViewFunctionCall,
+    ) -> Result<AnnotatedFunctionResult> {
+        let resp = self
+            .executor
+            .execute_signed_view_function(signed_call)
+            .await?;
+        Ok(resp)
+    }
+
     pub async fn resolve_address(&self, mca: MultiChainAddress) -> Result<AccountAddress> {
         self.executor.resolve_address(mca).await
     }
 
-    pub async fn get_states(&self, access_path: AccessPath) -> Result<Vec<Option<State>>> {
-        self.executor.get_states(access_path).await
+    pub async fn get_states(
+        &self,
+        state_root: Option<H256>,
+        access_path: AccessPath,
+    ) -> Result<Vec<Option<State>>> {
+        self.executor.get_states(state_root, access_path).await
+    }
+
+    /// Get the bytecode of the published module, return None if the module does not exist
+    pub async fn get_module(&self, module_id: &ModuleId) -> Result<Option<Vec<u8>>> {
+        let access_path = AccessPath::module(*module_id.address(), module_id.name().to_owned());
+        self.get_states(None, access_path)
+            .await?
+            .pop()
+            .flatten()
+            .map(|state| Ok(state.cast::<MoveModule>()?.byte_codes))
+            .transpose()
+    }
+
+    pub async fn get_states_with_proof(
+        &self,
+        state_root: Option<H256>,
+        access_path: AccessPath,
+    ) -> Result<(H256, Vec<(Option<State>, StateProof)>)> {
+        self.executor
+            .get_states_with_proof(state_root, access_path)
+            .await
     }
 
     pub async fn exists_account(&self, address: AccountAddress) -> Result<bool> {
         let mut resp = self
-            .get_states(AccessPath::resource(address, Account::struct_tag()))
+            .get_states(None, AccessPath::resource(address, Account::struct_tag()))
             .await?;
         Ok(resp.pop().flatten().is_some())
     }
 
     pub async fn get_annotated_states(
         &self,
+        state_root: Option<H256>,
         access_path: AccessPath,
     ) -> Result<Vec<Option<AnnotatedState>>> {
-        self.executor.get_annotated_states(access_path).await
+        self.executor
+            .get_annotated_states(state_root, access_path)
+            .await
     }
 
     pub async fn list_states(
         &self,
+        state_root: Option<H256>,
         access_path: AccessPath,
         cursor: Option<KeyState>,
         limit: usize,
     ) -> Result<Vec<StateKV>> {
-        self.executor.list_states(access_path, cursor, limit).await
+        self.executor
+            .list_states(state_root, access_path, cursor, limit)
+            .await
     }
 
     pub async fn list_annotated_states(
         &self,
+        state_root: Option<H256>,
         access_path: AccessPath,
         cursor: Option<KeyState>,
         limit: usize,
     ) -> Result<Vec<AnnotatedStateKV>> {
         self.executor
-            .list_annotated_states(access_path, cursor, limit)
+            .list_annotated_states(state_root, access_path, cursor, limit)
             .await
     }
 
@@ -180,10 +381,20 @@ impl RpcService {
         event_handle_type: StructTag,
         cursor: Option<u64>,
         limit: u64,
+        descending_order: bool,
+        from_seq: Option<u64>,
+        to_seq: Option<u64>,
     ) -> Result<Vec<AnnotatedEvent>> {
         let resp = self
             .executor
-            .get_annotated_events_by_event_handle(event_handle_type, cursor, limit)
+            .get_annotated_events_by_event_handle(
+                event_handle_type,
+                cursor,
+                limit,
+                descending_order,
+                from_seq,
+                to_seq,
+            )
             .await?;
         Ok(resp)
     }
@@ -193,10 +404,20 @@ impl RpcService {
         event_handle_type: StructTag,
         cursor: Option<u64>,
         limit: u64,
+        descending_order: bool,
+        from_seq: Option<u64>,
+        to_seq: Option<u64>,
     ) -> Result<Vec<Event>> {
         let resp = self
             .executor
-            .get_events_by_event_handle(event_handle_type, cursor, limit)
+            .get_events_by_event_handle(
+                event_handle_type,
+                cursor,
+                limit,
+                descending_order,
+                from_seq,
+                to_seq,
+            )
             .await?;
         Ok(resp)
     }
@@ -209,6 +430,20 @@ impl RpcService {
         Ok(resp)
     }
 
+    pub fn subscribe_events(
+        &self,
+        filter: EventFilter,
+    ) -> impl Stream<Item = IndexerEvent> + Send + 'static {
+        self.subscription.subscribe_events(filter)
+    }
+
+    pub fn subscribe_object_states(
+        &self,
+        filter: ObjectStateFilter,
+    ) -> impl Stream<Item = IndexerObjectStateChange> + Send + 'static {
+        self.subscription.subscribe_object_states(filter)
+    }
+
     pub async fn get_transaction_by_hash(&self, hash: H256) -> Result<Option<TypedTransaction>> {
         let resp = self.sequencer.get_transaction_by_hash(hash).await?;
         Ok(resp)
@@ -311,6 +546,32 @@ impl RpcService {
         Ok(resp)
     }
 
+    pub async fn search_transactions(
+        &self,
+        query: String,
+        // exclusive cursor if `Some`, otherwise start from the beginning
+        cursor: Option<u64>,
+        limit: usize,
+        descending_order: bool,
+    ) -> Result<Vec<TransactionWithInfo>> {
+        self.indexer
+            .search_transactions(query, cursor, limit, descending_order)
+            .await
+    }
+
+    pub async fn search_events(
+        &self,
+        query: String,
+        // exclusive cursor if `Some`, otherwise start from the beginning
+        cursor: Option<IndexerEventID>,
+        limit: usize,
+        descending_order: bool,
+    ) -> Result<Vec<IndexerEvent>> {
+        self.indexer
+            .search_events(query, cursor, limit, descending_order)
+            .await
+    }
+
     pub async fn query_global_states(
         &self,
         filter: GlobalStateFilter,
@@ -326,6 +587,36 @@ impl RpcService {
         Ok(resp)
     }
 
+    pub async fn query_modules(
+        &self,
+        filter: ModuleFilter,
+        // exclusive cursor if `Some`, otherwise start from the beginning
+        cursor: Option<IndexerStateID>,
+        limit: usize,
+        descending_order: bool,
+    ) -> Result<Vec<IndexerModule>> {
+        let resp = self
+            .indexer
+            .query_modules(filter, cursor, limit, descending_order)
+            .await?;
+        Ok(resp)
+    }
+
+    pub async fn query_coin_transfers(
+        &self,
+        coin_store_id: ObjectID,
+        // exclusive cursor if `Some`, otherwise start from the beginning
+        cursor: Option<IndexerEventID>,
+        limit: usize,
+        descending_order: bool,
+    ) -> Result<Vec<IndexerCoinTransfer>> {
+        let resp = self
+            .indexer
+            .query_coin_transfers(coin_store_id, cursor, limit, descending_order)
+            .await?;
+        Ok(resp)
+    }
+
     pub async fn query_table_states(
         &self,
         filter: TableStateFilter,
@@ -341,6 +632,79 @@ impl RpcService {
         Ok(resp)
     }
 
+    pub async fn query_utxos(
+        &self,
+        filter: UTXOFilter,
+        // exclusive cursor if `Some`, otherwise start from the beginning
+        cursor: Option<IndexerStateID>,
+        limit: usize,
+        descending_order: bool,
+    ) -> Result<Vec<IndexerUTXOState>> {
+        let resp = self
+            .indexer
+            .query_utxos(filter, cursor, limit, descending_order)
+            .await?;
+        Ok(resp)
+    }
+
+    pub async fn get_indexer_coin_infos(
+        &self,
+        coin_types: Vec<StructTag>,
+    ) -> Result<Vec<Option<IndexerCoinInfo>>> {
+        let resp = self.indexer.get_coin_infos(coin_types).await?;
+        Ok(resp)
+    }
+
+    pub async fn get_indexer_account_summary(
+        &self,
+        address: AccountAddress,
+    ) -> Result<IndexerAccountSummary> {
+        let resp = self.indexer.get_account_summary(address).await?;
+        Ok(resp)
+    }
+
+    pub async fn get_indexer_storage_usage(
+        &self,
+        address: AccountAddress,
+    ) -> Result<IndexerStorageUsage> {
+        let resp = self.indexer.get_storage_usage(address).await?;
+        Ok(resp)
+    }
+
+    pub async fn get_indexer_address_mapping(
+        &self,
+        multichain_address: MultiChainAddress,
+    ) -> Result<Option<IndexerAddressMapping>> {
+        let resp = self.indexer.get_address_mapping(multichain_address).await?;
+        Ok(resp)
+    }
+
+    pub async fn get_indexer_address_mappings_by_rooch_address(
+        &self,
+        rooch_address: AccountAddress,
+    ) -> Result<Vec<IndexerAddressMapping>> {
+        let resp = self
+            .indexer
+            .get_address_mappings_by_rooch_address(rooch_address)
+            .await?;
+        Ok(resp)
+    }
+
+    pub async fn get_object_ownership_history(
+        &self,
+        object_id: ObjectID,
+        // exclusive tx order cursor if `Some`, otherwise start from the beginning
+        cursor: Option<u64>,
+        limit: usize,
+        descending_order: bool,
+    ) -> Result<Vec<IndexerObjectOwnershipChange>> {
+        let resp = self
+            .indexer
+            .get_object_ownership_history(object_id, cursor, limit, descending_order)
+            .await?;
+        Ok(resp)
+    }
+
     pub async fn sync_states(
         &self,
         filter: Option<StateSyncFilter>,
@@ -366,7 +730,10 @@ impl TxSubmiter for RpcService {
     //TODO provide a trait to abstract the async state reader, elemiate the duplicated code bwteen RpcService and Client
     async fn get_sequence_number(&self, address: RoochAddress) -> Result<u64> {
         Ok(self
-            .get_states(AccessPath::resource(address.into(), Account::struct_tag()))
+            .get_states(
+                None,
+                AccessPath::resource(address.into(), Account::struct_tag()),
+            )
             .await?
             .pop()
             .flatten()
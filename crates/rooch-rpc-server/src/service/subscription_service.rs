@@ -0,0 +1,200 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::LOG_TARGET;
+use futures::{Stream, StreamExt};
+use rooch_types::indexer::event_filter::{EventFilter, IndexerEvent};
+use rooch_types::indexer::state::{IndexerObjectStateChange, ObjectStateFilter};
+use rooch_types::indexer::Filter;
+use tokio::sync::broadcast;
+use tokio::sync::broadcast::error::RecvError;
+
+/// The max number of items buffered for the slowest subscriber,
+/// the lagged subscriber will skip the oldest items.
+pub const DEFAULT_SUBSCRIPTION_CHANNEL_CAPACITY: usize = 1024;
+
+/// SubscriptionService broadcasts the data produced by the executed transactions to the RPC subscribers.
+#[derive(Clone)]
+pub struct SubscriptionService {
+    event_sender: broadcast::Sender<IndexerEvent>,
+    object_state_sender: broadcast::Sender<IndexerObjectStateChange>,
+}
+
+impl SubscriptionService {
+    pub fn new(capacity: usize) -> Self {
+        let (event_sender, _) = broadcast::channel(capacity);
+        let (object_state_sender, _) = broadcast::channel(capacity);
+        Self {
+            event_sender,
+            object_state_sender,
+        }
+    }
+
+    pub fn has_event_subscribers(&self) -> bool {
+        self.event_sender.receiver_count() > 0
+    }
+
+    pub fn publish_events(&self, events: Vec<IndexerEvent>) {
+        for event in events {
+            // An error only means there is no active subscriber now.
+            let _ = self.event_sender.send(event);
+        }
+    }
+
+    pub fn subscribe_events(
+        &self,
+        filter: EventFilter,
+    ) -> impl Stream<Item = IndexerEvent> + Send + 'static {
+        into_stream(self.event_sender.subscribe())
+            .filter(move |event| futures::future::ready(filter.matches(event)))
+    }
+
+    pub fn has_object_state_subscribers(&self) -> bool {
+        self.object_state_sender.receiver_count() > 0
+    }
+
+    pub fn publish_object_state_changes(&self, changes: Vec<IndexerObjectStateChange>) {
+        for change in changes {
+            // An error only means there is no active subscriber now.
+            let _ = self.object_state_sender.send(change);
+        }
+    }
+
+    pub fn subscribe_object_states(
+        &self,
+        filter: ObjectStateFilter,
+    ) -> impl Stream<Item = IndexerObjectStateChange> + Send + 'static {
+        into_stream(self.object_state_sender.subscribe())
+            .filter(move |change| futures::future::ready(filter.matches(change)))
+    }
+}
+
+impl Default for SubscriptionService {
+    fn default() -> Self {
+        Self::new(DEFAULT_SUBSCRIPTION_CHANNEL_CAPACITY)
+    }
+}
+
+fn into_stream<T: Clone + Send + 'static>(
+    receiver: broadcast::Receiver<T>,
+) -> impl Stream<Item = T> + Send + 'static {
+    futures::stream::unfold(receiver, |mut receiver| async move {
+        loop {
+            match receiver.recv().await {
+                Ok(item) => return Some((item, receiver)),
+                Err(RecvError::Lagged(skipped)) => {
+                    tracing::warn!(target: LOG_TARGET, "Subscriber lagged behind, skipped {} items", skipped);
+                }
+                Err(RecvError::Closed) => return None,
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::FutureExt;
+    use move_core_types::account_address::AccountAddress;
+    use move_core_types::effects::Op;
+    use move_core_types::language_storage::StructTag;
+    use moveos_types::h256::H256;
+    use moveos_types::move_types::random_struct_tag;
+    use moveos_types::moveos_std::context::GLOBAL_OBJECT_STORAGE_HANDLE;
+    use moveos_types::moveos_std::object_id::ObjectID;
+    use moveos_types::state::StateChangeSet;
+    use rooch_types::indexer::state::IndexerStateChangeSet;
+    use rooch_types::test_utils::{random_event, random_raw_object};
+
+    fn indexer_event(tx_order: u64, event_type: StructTag, created_at: u64) -> IndexerEvent {
+        let mut event = random_event();
+        event.event_type = event_type;
+        IndexerEvent::new(
+            event,
+            H256::random(),
+            tx_order,
+            AccountAddress::random(),
+            created_at,
+        )
+    }
+
+    #[tokio::test]
+    async fn test_subscribe_events() {
+        let service = SubscriptionService::new(16);
+        assert!(!service.has_event_subscribers());
+
+        let event_type = random_struct_tag();
+        let filter = EventFilter::All(vec![
+            EventFilter::EventType(event_type.clone()),
+            EventFilter::TimeRange {
+                start_time: 1000,
+                end_time: 2000,
+            },
+        ]);
+        let mut stream = Box::pin(service.subscribe_events(filter));
+        assert!(service.has_event_subscribers());
+
+        service.publish_events(vec![
+            indexer_event(1, event_type.clone(), 500),
+            indexer_event(2, random_struct_tag(), 1500),
+            indexer_event(3, event_type, 1500),
+        ]);
+        let event = stream.next().await.unwrap();
+        assert_eq!(event.indexer_event_id.tx_order, 3);
+        assert_eq!(event.created_at, 1500);
+        // The other events are filtered out
+        assert!(stream.next().now_or_never().is_none());
+
+        drop(stream);
+        assert!(!service.has_event_subscribers());
+    }
+
+    #[tokio::test]
+    async fn test_subscribe_object_states() {
+        let service = SubscriptionService::new(16);
+        assert!(!service.has_object_state_subscribers());
+
+        let object = random_raw_object();
+        let object_type = object.value.struct_tag.clone();
+        let deleted_object_id = ObjectID::from(AccountAddress::random());
+        let mut state_change_set = StateChangeSet::default();
+        let table_change =
+            state_change_set.get_or_insert_table_change(GLOBAL_OBJECT_STORAGE_HANDLE);
+        table_change
+            .entries
+            .insert(object.id.to_key(), Op::New(object.into_state().unwrap()));
+        table_change
+            .entries
+            .insert(deleted_object_id.to_key(), Op::Delete);
+        let changes = IndexerObjectStateChange::from_state_change_set(
+            &IndexerStateChangeSet {
+                tx_order: 1,
+                state_change_set,
+                created_at: 1000,
+            },
+            H256::random(),
+        )
+        .unwrap();
+        assert_eq!(changes.len(), 2);
+        assert!(changes.iter().all(|change| change.created_at == 1000));
+
+        let mut type_stream =
+            Box::pin(service.subscribe_object_states(ObjectStateFilter::ObjectType(object_type)));
+        let mut deleted_stream = Box::pin(
+            service.subscribe_object_states(ObjectStateFilter::ObjectId(deleted_object_id)),
+        );
+        assert!(service.has_object_state_subscribers());
+        service.publish_object_state_changes(changes);
+
+        let change = type_stream.next().await.unwrap();
+        assert_eq!(change.object_id, object.id);
+        assert!(matches!(change.op, Op::New(_)));
+        // The deleted object does not carry the value type
+        assert!(type_stream.next().now_or_never().is_none());
+
+        let change = deleted_stream.next().await.unwrap();
+        assert_eq!(change.object_id, deleted_object_id);
+        assert_eq!(change.object_type, None);
+        assert!(deleted_stream.next().now_or_never().is_none());
+    }
+}
@@ -0,0 +1,114 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use anyhow::{anyhow, Result};
+use hyper::header::HeaderMap;
+use hyper::Request;
+use once_cell::sync::OnceCell;
+use opentelemetry::propagation::Extractor;
+use opentelemetry::sdk::propagation::TraceContextPropagator;
+use opentelemetry::sdk::{trace, Resource};
+use opentelemetry::KeyValue;
+use opentelemetry_otlp::WithExportConfig;
+use rooch_config::server_config::{LogConfig, LogFormat, TracingConfig};
+use tracing::{info_span, Span};
+use tracing_opentelemetry::OpenTelemetrySpanExt;
+use tracing_subscriber::layer::SubscriberExt;
+use tracing_subscriber::util::SubscriberInitExt;
+use tracing_subscriber::{reload, EnvFilter, Registry};
+
+/// The handle to reload the log filter of the subscriber inited by `init_tracing`
+static LOG_FILTER_HANDLE: OnceCell<reload::Handle<EnvFilter, Registry>> = OnceCell::new();
+
+/// Init the tracing subscriber, the spans are exported to the OpenTelemetry collector if the tracing config is present.
+/// The subscriber can only be inited once, the later calls are ignored, such as starting the server multiple times in testing.
+pub fn init_tracing(config: Option<&TracingConfig>, log_config: &LogConfig) -> Result<()> {
+    let filter = EnvFilter::try_new(&log_config.filter)
+        .map_err(|e| anyhow!("Invalid log filter {}: {}", log_config.filter, e))?;
+    let (filter_layer, filter_handle) = reload::Layer::new(filter);
+
+    opentelemetry::global::set_text_map_propagator(TraceContextPropagator::new());
+    let otel_layer = match config {
+        Some(config) => {
+            let tracer = opentelemetry_otlp::new_pipeline()
+                .tracing()
+                .with_exporter(
+                    opentelemetry_otlp::new_exporter()
+                        .tonic()
+                        .with_endpoint(config.otlp_endpoint.clone()),
+                )
+                .with_trace_config(trace::config().with_resource(Resource::new(vec![
+                    KeyValue::new("service.name", config.service_name.clone()),
+                ])))
+                .install_batch(opentelemetry::runtime::Tokio)?;
+            Some(tracing_opentelemetry::layer().with_tracer(tracer))
+        }
+        None => None,
+    };
+    let (text_layer, json_layer) = match log_config.format {
+        LogFormat::Text => (Some(tracing_subscriber::fmt::layer()), None),
+        LogFormat::Json => (None, Some(tracing_subscriber::fmt::layer().json())),
+    };
+    if tracing_subscriber::registry()
+        .with(filter_layer)
+        .with(otel_layer)
+        .with(text_layer)
+        .with(json_layer)
+        .try_init()
+        .is_ok()
+    {
+        let _ = LOG_FILTER_HANDLE.set(filter_handle);
+    }
+    Ok(())
+}
+
+fn log_filter_handle() -> Result<&'static reload::Handle<EnvFilter, Registry>> {
+    LOG_FILTER_HANDLE.get().ok_or_else(|| {
+        anyhow!(
+            "The log filter is not reloadable, the tracing subscriber is not inited by the server"
+        )
+    })
+}
+
+/// The current log filter of the tracing subscriber
+pub fn log_filter() -> Result<String> {
+    Ok(log_filter_handle()?.with_current(|filter| filter.to_string())?)
+}
+
+/// Reload the log filter of the tracing subscriber
+pub fn set_log_filter(filter: EnvFilter) -> Result<()> {
+    log_filter_handle()?.reload(filter)?;
+    Ok(())
+}
+
+/// Flush the pending spans to the collector and stop the exporter
+pub fn shutdown_tracing() {
+    opentelemetry::global::shutdown_tracer_provider();
+}
+
+/// Make the span of the JSON-RPC HTTP request, the parent is the trace context in the `traceparent` header of the request,
+/// so the spans of the transaction flow (sequencer, executor, indexer) are attached to the trace of the client.
+pub fn make_request_span<B>(request: &Request<B>) -> Span {
+    let span = info_span!(
+        "rpc.request",
+        method = %request.method(),
+        uri = %request.uri(),
+    );
+    let parent_context = opentelemetry::global::get_text_map_propagator(|propagator| {
+        propagator.extract(&HeaderExtractor(request.headers()))
+    });
+    span.set_parent(parent_context);
+    span
+}
+
+struct HeaderExtractor<'a>(&'a HeaderMap);
+
+impl<'a> Extractor for HeaderExtractor<'a> {
+    fn get(&self, key: &str) -> Option<&str> {
+        self.0.get(key).and_then(|value| value.to_str().ok())
+    }
+
+    fn keys(&self) -> Vec<&str> {
+        self.0.keys().map(|key| key.as_str()).collect()
+    }
+}
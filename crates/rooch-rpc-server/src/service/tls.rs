@@ -0,0 +1,252 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::LOG_TARGET;
+use anyhow::{anyhow, Result};
+use rooch_config::server_config::TlsConfig;
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::BufReader;
+use std::net::SocketAddr;
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::net::{TcpListener, TcpStream};
+use tokio::sync::{oneshot, Semaphore};
+use tokio::task::JoinHandle;
+use tokio_rustls::rustls::{Certificate, PrivateKey, ServerConfig};
+use tokio_rustls::TlsAcceptor;
+use tracing::{debug, error, info};
+
+/// The TLS handshake must complete in the timeout, so the idle connections can not hold the connection slots
+const TLS_HANDSHAKE_TIMEOUT: Duration = Duration::from_secs(10);
+/// The max concurrent TLS connections, the same as the default max connections of the JSON-RPC server.
+/// The new connections wait in the listen backlog until a connection is closed.
+const MAX_TLS_CONNECTIONS: usize = 100;
+
+#[derive(Clone, Copy, Debug)]
+struct TlsConnectionLimits {
+    handshake_timeout: Duration,
+    max_connections: usize,
+}
+
+impl Default for TlsConnectionLimits {
+    fn default() -> Self {
+        Self {
+            handshake_timeout: TLS_HANDSHAKE_TIMEOUT,
+            max_connections: MAX_TLS_CONNECTIONS,
+        }
+    }
+}
+
+/// The peer addresses of the TLS connections, keyed by the local address of the forwarded connections,
+/// so the JSON-RPC server can identify the clients of the forwarded connections.
+#[derive(Clone, Default)]
+pub struct ForwardedPeers(Arc<Mutex<HashMap<SocketAddr, SocketAddr>>>);
+
+impl ForwardedPeers {
+    pub fn get(&self, forwarded_addr: &SocketAddr) -> Option<SocketAddr> {
+        self.lock().get(forwarded_addr).copied()
+    }
+
+    pub(crate) fn insert(&self, forwarded_addr: SocketAddr, peer: SocketAddr) {
+        self.lock().insert(forwarded_addr, peer);
+    }
+
+    fn remove(&self, forwarded_addr: &SocketAddr) {
+        self.lock().remove(forwarded_addr);
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, HashMap<SocketAddr, SocketAddr>> {
+        self.0.lock().expect("Lock forwarded peers should success")
+    }
+}
+
+pub struct TlsServerHandle {
+    shutdown_tx: oneshot::Sender<()>,
+    join_handle: JoinHandle<()>,
+}
+
+impl TlsServerHandle {
+    pub fn stop(self) -> Result<()> {
+        // The server may have exited already, ignore the error.
+        let _ = self.shutdown_tx.send(());
+        self.join_handle.abort();
+        Ok(())
+    }
+}
+
+fn load_certs(path: &Path) -> Result<Vec<Certificate>> {
+    let mut reader = BufReader::new(File::open(path)?);
+    let certs = rustls_pemfile::certs(&mut reader)?;
+    if certs.is_empty() {
+        return Err(anyhow!("No certificate found in {}", path.display()));
+    }
+    Ok(certs.into_iter().map(Certificate).collect())
+}
+
+fn load_private_key(path: &Path) -> Result<PrivateKey> {
+    let mut reader = BufReader::new(File::open(path)?);
+    rustls_pemfile::read_all(&mut reader)?
+        .into_iter()
+        .find_map(|item| match item {
+            rustls_pemfile::Item::PKCS8Key(key)
+            | rustls_pemfile::Item::RSAKey(key)
+            | rustls_pemfile::Item::ECKey(key) => Some(PrivateKey(key)),
+            _ => None,
+        })
+        .ok_or_else(|| anyhow!("No private key found in {}", path.display()))
+}
+
+pub fn load_tls_acceptor(config: &TlsConfig) -> Result<TlsAcceptor> {
+    let certs = load_certs(&config.cert_path)?;
+    let key = load_private_key(&config.key_path)?;
+    let mut server_config = ServerConfig::builder()
+        .with_safe_defaults()
+        .with_no_client_auth()
+        .with_single_cert(certs, key)?;
+    // The JSON-RPC server only speaks HTTP/1.1, the WebSocket upgrade is not supported over HTTP/2
+    server_config.alpn_protocols = vec![b"http/1.1".to_vec()];
+    Ok(TlsAcceptor::from(Arc::new(server_config)))
+}
+
+/// Terminate the TLS connections on `addr`, and forward the decrypted streams to the JSON-RPC server listening on `upstream`.
+pub async fn start_tls_server(
+    addr: SocketAddr,
+    upstream: SocketAddr,
+    acceptor: TlsAcceptor,
+    forwarded_peers: ForwardedPeers,
+) -> Result<TlsServerHandle> {
+    let listener = TcpListener::bind(addr).await?;
+    let handle = serve(
+        listener,
+        upstream,
+        acceptor,
+        forwarded_peers,
+        TlsConnectionLimits::default(),
+    );
+    info!(target: LOG_TARGET, "TLS Server start listening {:?}", addr);
+    Ok(handle)
+}
+
+fn serve(
+    listener: TcpListener,
+    upstream: SocketAddr,
+    acceptor: TlsAcceptor,
+    forwarded_peers: ForwardedPeers,
+    limits: TlsConnectionLimits,
+) -> TlsServerHandle {
+    let (shutdown_tx, mut shutdown_rx) = oneshot::channel::<()>();
+    let connections = Arc::new(Semaphore::new(limits.max_connections));
+    let join_handle = tokio::spawn(async move {
+        loop {
+            // Wait for a connection slot before accepting the connection
+            let permit = tokio::select! {
+                _ = &mut shutdown_rx => break,
+                permit = connections.clone().acquire_owned() => {
+                    permit.expect("The connection semaphore is never closed")
+                }
+            };
+            let (stream, peer) = tokio::select! {
+                _ = &mut shutdown_rx => break,
+                accepted = listener.accept() => match accepted {
+                    Ok(accepted) => accepted,
+                    Err(e) => {
+                        error!(target: LOG_TARGET, "TLS server accept connection error: {:?}", e);
+                        continue;
+                    }
+                },
+            };
+            let acceptor = acceptor.clone();
+            let forwarded_peers = forwarded_peers.clone();
+            tokio::spawn(async move {
+                let _permit = permit;
+                if let Err(e) = forward(
+                    stream,
+                    peer,
+                    upstream,
+                    acceptor,
+                    forwarded_peers,
+                    limits.handshake_timeout,
+                )
+                .await
+                {
+                    debug!(target: LOG_TARGET, "TLS connection from {:?} closed with error: {:?}", peer, e);
+                }
+            });
+        }
+    });
+    TlsServerHandle {
+        shutdown_tx,
+        join_handle,
+    }
+}
+
+async fn forward(
+    stream: TcpStream,
+    peer: SocketAddr,
+    upstream: SocketAddr,
+    acceptor: TlsAcceptor,
+    forwarded_peers: ForwardedPeers,
+    handshake_timeout: Duration,
+) -> Result<()> {
+    let mut tls_stream = tokio::time::timeout(handshake_timeout, acceptor.accept(stream))
+        .await
+        .map_err(|_| anyhow!("TLS handshake timeout"))??;
+    let mut upstream_stream = TcpStream::connect(upstream).await?;
+    let forwarded_addr = upstream_stream.local_addr()?;
+    forwarded_peers.insert(forwarded_addr, peer);
+    let result = tokio::io::copy_bidirectional(&mut tls_stream, &mut upstream_stream).await;
+    forwarded_peers.remove(&forwarded_addr);
+    result?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Instant;
+    use tokio::io::AsyncReadExt;
+    use tokio_rustls::rustls::server::ResolvesServerCertUsingSni;
+
+    /// Wait until the server closes the connection
+    async fn wait_closed(mut stream: TcpStream) {
+        let mut buf = [0u8; 1];
+        let read = tokio::time::timeout(Duration::from_secs(5), stream.read(&mut buf))
+            .await
+            .expect("The connection should be closed by the server");
+        assert!(matches!(read, Ok(0) | Err(_)));
+    }
+
+    #[tokio::test]
+    async fn test_tls_handshake_timeout_and_connection_limit() {
+        // The handshake never completes, the client sends nothing
+        let server_config = ServerConfig::builder()
+            .with_safe_defaults()
+            .with_no_client_auth()
+            .with_cert_resolver(Arc::new(ResolvesServerCertUsingSni::new()));
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let handshake_timeout = Duration::from_millis(100);
+        let handle = serve(
+            listener,
+            addr,
+            TlsAcceptor::from(Arc::new(server_config)),
+            ForwardedPeers::default(),
+            TlsConnectionLimits {
+                handshake_timeout,
+                max_connections: 1,
+            },
+        );
+
+        let started_at = Instant::now();
+        let first = TcpStream::connect(addr).await.unwrap();
+        let second = TcpStream::connect(addr).await.unwrap();
+        wait_closed(first).await;
+        assert!(started_at.elapsed() >= handshake_timeout);
+        // The second connection is accepted after the first one is closed
+        wait_closed(second).await;
+        assert!(started_at.elapsed() >= handshake_timeout * 2);
+        handle.stop().unwrap();
+    }
+}
@@ -6,14 +6,17 @@ use crate::messages::{
     GetTxSequenceInfoMappingByHashMessage, GetTxSequenceInfoMappingByOrderMessage,
     GetTxSequenceInfosMessage, TransactionSequenceMessage,
 };
+use crate::LOG_TARGET;
 use anyhow::Result;
 use async_trait::async_trait;
 use coerce::actor::{context::ActorContext, message::Handler, Actor};
-use moveos_types::h256::{self, H256};
+use moveos_common::utils::now_seconds;
+use moveos_types::h256::H256;
 use rooch_store::meta_store::MetaStore;
 use rooch_store::transaction_store::TransactionStore;
 use rooch_store::RoochStore;
-use rooch_types::sequencer::SequencerOrder;
+use rooch_types::address::RoochAddress;
+use rooch_types::sequencer::{SequencerOrder, SequencerWALEntry};
 use rooch_types::transaction::{
     TransactionSequenceInfo, TransactionSequenceInfoMapping, TypedTransaction,
 };
@@ -40,13 +43,81 @@ impl SequencerActor {
             .get_sequencer_order()?
             .map(|order| order.last_order);
         let last_order = last_order_opt.unwrap_or(0u64);
-        info!("Load latest sequencer order {:?}", last_order);
+        info!(target: LOG_TARGET, "Load latest sequencer order {:?}", last_order);
+        let mut sequencer = Self {
+            last_order,
+            sequencer_key,
+            rooch_store,
+        };
+        sequencer.replay_wal(last_order_opt)?;
+        Ok(sequencer)
+    }
+
+    /// Create the sequencer of the read replica, the store is read only, so the WAL is left to the primary node,
+    /// and the sequencer only serves the queries of the sequenced transactions.
+    pub fn new_read_only(sequencer_key: RoochKeyPair, rooch_store: RoochStore) -> Result<Self> {
+        let last_order = rooch_store
+            .get_meta_store()
+            .get_sequencer_order()?
+            .map(|order| order.last_order)
+            .unwrap_or(0u64);
+        info!(target: LOG_TARGET, "Load latest sequencer order {:?} of the read replica", last_order);
         Ok(Self {
             last_order,
             sequencer_key,
             rooch_store,
         })
     }
+
+    /// Replay the WAL entries left by a crash, the entries already committed are dropped,
+    /// and the others are committed in order. The entries must continue from the last committed order.
+    fn replay_wal(&mut self, last_order_opt: Option<u64>) -> Result<()> {
+        let sequencer = RoochAddress::from(&self.sequencer_key.public());
+        let mut last_order_opt = last_order_opt;
+        for entry in self.rooch_store.get_sequencer_wal_entries()? {
+            let tx_order = entry.tx_order();
+            if last_order_opt.is_some_and(|last_order| tx_order <= last_order) {
+                self.rooch_store.remove_sequencer_wal_entry(tx_order)?;
+                continue;
+            }
+            let expected_order = last_order_opt.map_or(0, |last_order| last_order + 1);
+            if tx_order != expected_order {
+                anyhow::bail!(
+                    "Sequencer WAL is not continuous, expect tx order {}, actual: {}",
+                    expected_order,
+                    tx_order
+                );
+            }
+            entry.check_integrity(sequencer)?;
+            info!(target: LOG_TARGET, "Replay sequencer WAL entry of tx order {}", tx_order);
+            self.commit(entry)?;
+            last_order_opt = Some(tx_order);
+        }
+        Ok(())
+    }
+
+    /// Commit the sequence result, the sequencer order is saved after the sequence info,
+    /// so an order is committed only if all of its data is saved, then the WAL entry is removed.
+    fn commit(&mut self, entry: SequencerWALEntry) -> Result<()> {
+        let SequencerWALEntry {
+            tx_hash,
+            tx,
+            tx_sequence_info,
+        } = entry;
+        let tx_order = tx_sequence_info.tx_order;
+
+        self.rooch_store.save_transaction(tx)?;
+        self.rooch_store
+            .save_tx_sequence_info_mapping(tx_order, tx_hash)?;
+        self.rooch_store
+            .save_tx_sequence_info_reverse_mapping(tx_hash, tx_order)?;
+        self.rooch_store.save_tx_sequence_info(tx_sequence_info)?;
+        self.rooch_store
+            .save_sequencer_order(SequencerOrder::new(tx_order))?;
+        self.last_order = tx_order;
+
+        self.rooch_store.remove_sequencer_wal_entry(tx_order)
+    }
 }
 
 impl Actor for SequencerActor {}
@@ -59,6 +130,8 @@ impl Handler<TransactionSequenceMessage> for SequencerActor {
         _ctx: &mut ActorContext,
     ) -> Result<TransactionSequenceInfo> {
         let tx = msg.tx;
+        // The transaction may wait in the queue after it is validated, so check the expiration again before ordering it
+        tx.check_expiration(now_seconds())?;
         let tx_order = if self.last_order == 0 {
             let last_order_opt = self
                 .rooch_store
@@ -72,21 +145,8 @@ impl Handler<TransactionSequenceMessage> for SequencerActor {
         } else {
             self.last_order + 1
         };
-        let hash = tx.tx_hash();
-        let mut witness_data = hash.as_ref().to_vec();
-        witness_data.extend(tx_order.to_le_bytes().iter());
-        let witness_hash = h256::sha3_256_of(&witness_data);
+        let witness_hash = TransactionSequenceInfo::witness_hash(tx.tx_hash(), tx_order);
         let tx_order_signature = Signature::new_hashed(&witness_hash.0, &self.sequencer_key).into();
-        self.last_order = tx_order;
-
-        self.rooch_store.save_transaction(tx)?;
-        self.rooch_store
-            .save_tx_sequence_info_mapping(tx_order, hash)?;
-        self.rooch_store
-            .save_tx_sequence_info_reverse_mapping(hash, tx_order)?;
-
-        self.rooch_store
-            .save_sequencer_order(SequencerOrder::new(self.last_order))?;
 
         let tx_accumulator_root = H256::random();
         let tx_sequence_info = TransactionSequenceInfo {
@@ -94,8 +154,10 @@ impl Handler<TransactionSequenceMessage> for SequencerActor {
             tx_order_signature,
             tx_accumulator_root,
         };
-        self.rooch_store
-            .save_tx_sequence_info(tx_sequence_info.clone())?;
+        // Persist the ordering decision before committing it, so it is replayed if the sequencer crashes
+        let entry = SequencerWALEntry::new(tx, tx_sequence_info.clone());
+        self.rooch_store.save_sequencer_wal_entry(entry.clone())?;
+        self.commit(entry)?;
         Ok(tx_sequence_info)
     }
 }
@@ -175,3 +237,93 @@ impl Handler<GetSequencerOrderMessage> for SequencerActor {
         self.rooch_store.get_meta_store().get_sequencer_order()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fastcrypto::ed25519::Ed25519KeyPair;
+    use fastcrypto::traits::KeyPair;
+    use rooch_types::transaction::rooch::RoochTransaction;
+
+    fn random_key_pair() -> RoochKeyPair {
+        RoochKeyPair::Ed25519(Ed25519KeyPair::generate(&mut rand::thread_rng()))
+    }
+
+    /// The WAL entry persisted by the sequencer before it crashes, the order is not committed
+    fn wal_entry(tx_order: u64, sequencer_key: &RoochKeyPair) -> SequencerWALEntry {
+        let tx = TypedTransaction::Rooch(RoochTransaction::mock());
+        let witness_hash = TransactionSequenceInfo::witness_hash(tx.tx_hash(), tx_order);
+        let tx_order_signature = Signature::new_hashed(&witness_hash.0, sequencer_key).into();
+        SequencerWALEntry::new(
+            tx,
+            TransactionSequenceInfo::new(tx_order, tx_order_signature, H256::random()),
+        )
+    }
+
+    #[test]
+    fn test_replay_wal_after_crash() -> Result<()> {
+        let sequencer_key = random_key_pair();
+        let mut rooch_store = RoochStore::mock_rooch_store()?;
+        let sequencer = SequencerActor::new(sequencer_key.copy(), rooch_store.clone(), true)?;
+        assert_eq!(sequencer.last_order, 0);
+
+        // The sequencer crashes after the WAL entries are persisted
+        let entries = vec![wal_entry(0, &sequencer_key), wal_entry(1, &sequencer_key)];
+        for entry in &entries {
+            rooch_store.save_sequencer_wal_entry(entry.clone())?;
+        }
+        // The first entry is committed, but its WAL entry is not removed before the crash
+        rooch_store.save_transaction(entries[0].tx.clone())?;
+        rooch_store.save_sequencer_order(SequencerOrder::new(0))?;
+
+        let sequencer = SequencerActor::new(sequencer_key.copy(), rooch_store.clone(), false)?;
+        assert_eq!(sequencer.last_order, 1);
+        assert_eq!(
+            rooch_store.get_sequencer_order()?,
+            Some(SequencerOrder::new(1))
+        );
+        assert!(rooch_store.get_sequencer_wal_entries()?.is_empty());
+        for entry in entries {
+            assert_eq!(
+                rooch_store.get_transaction_by_hash(entry.tx_hash)?,
+                Some(entry.tx)
+            );
+        }
+        let mappings = rooch_store
+            .get_transaction_store()
+            .get_tx_sequence_info_mapping_by_order(vec![1])?;
+        assert_eq!(
+            mappings[0].as_ref().map(|mapping| mapping.tx_order),
+            Some(1)
+        );
+
+        // Restart again, there is nothing to replay
+        let sequencer = SequencerActor::new(sequencer_key, rooch_store, false)?;
+        assert_eq!(sequencer.last_order, 1);
+        Ok(())
+    }
+
+    #[test]
+    fn test_replay_forged_wal_entry() -> Result<()> {
+        let sequencer_key = random_key_pair();
+        let rooch_store = RoochStore::mock_rooch_store()?;
+
+        // The WAL entry is not signed by the sequencer, it is not committed
+        let entry = wal_entry(0, &random_key_pair());
+        rooch_store.save_sequencer_wal_entry(entry.clone())?;
+        assert!(SequencerActor::new(sequencer_key.copy(), rooch_store.clone(), false).is_err());
+        assert_eq!(rooch_store.get_sequencer_order()?, None);
+        assert_eq!(rooch_store.get_transaction_by_hash(entry.tx_hash)?, None);
+
+        // The WAL entry of a tampered transaction
+        rooch_store.remove_sequencer_wal_entry(0)?;
+        let mut entry = wal_entry(0, &sequencer_key);
+        let forged = wal_entry(0, &random_key_pair());
+        entry.tx_hash = forged.tx_hash;
+        entry.tx = forged.tx;
+        rooch_store.save_sequencer_wal_entry(entry)?;
+        assert!(SequencerActor::new(sequencer_key, rooch_store.clone(), false).is_err());
+        assert_eq!(rooch_store.get_sequencer_order()?, None);
+        Ok(())
+    }
+}
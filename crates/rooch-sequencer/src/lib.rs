@@ -4,3 +4,6 @@
 pub mod actor;
 pub mod messages;
 pub mod proxy;
+
+/// The tracing target of the sequencer logs
+pub const LOG_TARGET: &str = "rooch::sequencer";
@@ -10,7 +10,7 @@ use moveos_types::h256::H256;
 use once_cell::sync::Lazy;
 use raw_store::rocks::RocksDB;
 use raw_store::{ColumnFamilyName, StoreInstance};
-use rooch_types::sequencer::SequencerOrder;
+use rooch_types::sequencer::{SequencerOrder, SequencerWALEntry};
 use rooch_types::transaction::{
     TransactionSequenceInfo, TransactionSequenceInfoMapping, TypedTransaction,
 };
@@ -27,6 +27,7 @@ pub const TX_SEQUENCE_INFO_REVERSE_MAPPING_PREFIX_NAME: ColumnFamilyName =
     "tx_sequence_info_reverse_mapping";
 
 pub const META_SEQUENCER_ORDER_PREFIX_NAME: ColumnFamilyName = "meta_sequencer_order";
+pub const META_SEQUENCER_WAL_PREFIX_NAME: ColumnFamilyName = "meta_sequencer_wal";
 
 ///db store use prefix_name vec to init
 /// Please note that adding a prefix needs to be added in vec simultaneously, remember！！
@@ -37,6 +38,7 @@ static VEC_PREFIX_NAME: Lazy<Vec<ColumnFamilyName>> = Lazy::new(|| {
         TX_SEQUENCE_INFO_MAPPING_PREFIX_NAME,
         META_SEQUENCER_ORDER_PREFIX_NAME,
         TX_SEQUENCE_INFO_REVERSE_MAPPING_PREFIX_NAME,
+        META_SEQUENCER_WAL_PREFIX_NAME,
     ]
 });
 
@@ -158,4 +160,16 @@ impl MetaStore for RoochStore {
     fn save_sequencer_order(&self, sequencer_order: SequencerOrder) -> Result<()> {
         self.get_meta_store().save_sequencer_order(sequencer_order)
     }
+
+    fn get_sequencer_wal_entries(&self) -> Result<Vec<SequencerWALEntry>> {
+        self.get_meta_store().get_sequencer_wal_entries()
+    }
+
+    fn save_sequencer_wal_entry(&self, entry: SequencerWALEntry) -> Result<()> {
+        self.get_meta_store().save_sequencer_wal_entry(entry)
+    }
+
+    fn remove_sequencer_wal_entry(&self, tx_order: u64) -> Result<()> {
+        self.get_meta_store().remove_sequencer_wal_entry(tx_order)
+    }
 }
@@ -1,10 +1,10 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::META_SEQUENCER_ORDER_PREFIX_NAME;
+use crate::{META_SEQUENCER_ORDER_PREFIX_NAME, META_SEQUENCER_WAL_PREFIX_NAME};
 use anyhow::Result;
 use raw_store::{derive_store, CodecKVStore, StoreInstance};
-use rooch_types::sequencer::SequencerOrder;
+use rooch_types::sequencer::{SequencerOrder, SequencerWALEntry};
 use std::string::ToString;
 
 pub const SEQUENCER_ORDER_KEY: &str = "sequencer_order";
@@ -14,22 +14,37 @@ derive_store!(
     SequencerOrder,
     META_SEQUENCER_ORDER_PREFIX_NAME
 );
+derive_store!(
+    SequencerWALStore,
+    u64,
+    SequencerWALEntry,
+    META_SEQUENCER_WAL_PREFIX_NAME
+);
 
 pub trait MetaStore {
     fn get_sequencer_order(&self) -> Result<Option<SequencerOrder>>;
 
     fn save_sequencer_order(&self, sequencer_order: SequencerOrder) -> Result<()>;
+
+    /// The WAL entries of the tx orders not yet confirmed as committed, sorted by tx order
+    fn get_sequencer_wal_entries(&self) -> Result<Vec<SequencerWALEntry>>;
+
+    fn save_sequencer_wal_entry(&self, entry: SequencerWALEntry) -> Result<()>;
+
+    fn remove_sequencer_wal_entry(&self, tx_order: u64) -> Result<()>;
 }
 
 #[derive(Clone)]
 pub struct MetaDBStore {
     sequencer_order_store: SequencerOrderStore,
+    sequencer_wal_store: SequencerWALStore,
 }
 
 impl MetaDBStore {
     pub fn new(instance: StoreInstance) -> Self {
         MetaDBStore {
-            sequencer_order_store: SequencerOrderStore::new(instance),
+            sequencer_order_store: SequencerOrderStore::new(instance.clone()),
+            sequencer_wal_store: SequencerWALStore::new(instance),
         }
     }
 
@@ -42,4 +57,23 @@ impl MetaDBStore {
         self.sequencer_order_store
             .put_sync(SEQUENCER_ORDER_KEY.to_string(), sequencer_order)
     }
+
+    pub fn get_sequencer_wal_entries(&self) -> Result<Vec<SequencerWALEntry>> {
+        let mut tx_orders = self.sequencer_wal_store.keys()?;
+        tx_orders.sort();
+        Ok(self
+            .sequencer_wal_store
+            .multiple_get(tx_orders)?
+            .into_iter()
+            .flatten()
+            .collect())
+    }
+
+    pub fn save_sequencer_wal_entry(&self, entry: SequencerWALEntry) -> Result<()> {
+        self.sequencer_wal_store.put_sync(entry.tx_order(), entry)
+    }
+
+    pub fn remove_sequencer_wal_entry(&self, tx_order: u64) -> Result<()> {
+        self.sequencer_wal_store.remove(tx_order)
+    }
 }
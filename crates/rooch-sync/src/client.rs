@@ -0,0 +1,108 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::protocol::{SyncInfo, SyncRequest, SyncResponse, SyncTransaction};
+use crate::transport::{client_config, read_message, write_message, SYNC_SERVER_NAME};
+use anyhow::{bail, format_err, Result};
+use moveos_types::h256::H256;
+use quinn::{Connection, Endpoint};
+use std::fmt;
+use std::net::SocketAddr;
+use std::str::FromStr;
+
+/// A state sync peer as `<certificate fingerprint>@<address>`,
+/// the fingerprint is logged by the sync server when it starts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SyncPeer {
+    pub addr: SocketAddr,
+    pub cert_fingerprint: H256,
+}
+
+impl FromStr for SyncPeer {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let (cert_fingerprint, addr) = s.split_once('@').ok_or_else(|| {
+            format_err!(
+                "Invalid sync peer {}, expect `<certificate fingerprint>@<address>`",
+                s
+            )
+        })?;
+        Ok(Self {
+            addr: addr.parse()?,
+            cert_fingerprint: H256::from_str(cert_fingerprint)
+                .map_err(|e| format_err!("Invalid certificate fingerprint: {}", e))?,
+        })
+    }
+}
+
+impl fmt::Display for SyncPeer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}@{}", self.cert_fingerprint, self.addr)
+    }
+}
+
+/// The client of a state sync peer, the requests are sent in the streams of one QUIC connection.
+#[derive(Clone)]
+pub struct SyncClient {
+    peer: SocketAddr,
+    connection: Connection,
+}
+
+impl SyncClient {
+    /// Connect to the peer, the certificate of the peer must have the pinned fingerprint
+    pub async fn connect(endpoint: &Endpoint, peer: SyncPeer) -> Result<Self> {
+        let connection = endpoint
+            .connect_with(
+                client_config(peer.cert_fingerprint),
+                peer.addr,
+                SYNC_SERVER_NAME,
+            )?
+            .await?;
+        Ok(Self {
+            peer: peer.addr,
+            connection,
+        })
+    }
+
+    pub fn peer(&self) -> SocketAddr {
+        self.peer
+    }
+
+    async fn request(&self, request: SyncRequest) -> Result<SyncResponse> {
+        let (mut send, recv) = self.connection.open_bi().await?;
+        write_message(&mut send, &request).await?;
+        match read_message(recv).await? {
+            SyncResponse::Error(e) => bail!("The peer {} returns error: {}", self.peer, e),
+            response => Ok(response),
+        }
+    }
+
+    pub async fn get_sync_info(&self, tx_order: Option<u64>) -> Result<SyncInfo> {
+        match self.request(SyncRequest::GetSyncInfo { tx_order }).await? {
+            SyncResponse::SyncInfo(sync_info) => Ok(sync_info),
+            response => bail!("Unexpected response of GetSyncInfo: {:?}", response),
+        }
+    }
+
+    pub async fn get_transactions(
+        &self,
+        start_order: u64,
+        limit: u64,
+    ) -> Result<Vec<SyncTransaction>> {
+        match self
+            .request(SyncRequest::GetTransactions { start_order, limit })
+            .await?
+        {
+            SyncResponse::Transactions(transactions) => Ok(transactions),
+            response => bail!("Unexpected response of GetTransactions: {:?}", response),
+        }
+    }
+
+    pub async fn get_state_nodes(&self, hashes: Vec<H256>) -> Result<Vec<(H256, Vec<u8>)>> {
+        match self.request(SyncRequest::GetStateNodes { hashes }).await? {
+            SyncResponse::StateNodes(nodes) => Ok(nodes),
+            response => bail!("Unexpected response of GetStateNodes: {:?}", response),
+        }
+    }
+}
@@ -0,0 +1,12 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+pub mod client;
+pub mod protocol;
+pub mod server;
+pub mod syncer;
+pub mod transport;
+
+pub use client::SyncPeer;
+pub use server::{start_sync_server, SyncServerHandle};
+pub use syncer::{StateSyncer, SyncResult};
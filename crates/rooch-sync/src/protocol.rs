@@ -0,0 +1,61 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use moveos_types::genesis_info::GenesisInfo;
+use moveos_types::h256::H256;
+use moveos_types::transaction::TransactionExecutionInfo;
+use rooch_types::transaction::{TransactionSequenceInfo, TypedTransaction};
+use serde::{Deserialize, Serialize};
+
+/// The ALPN protocol id of the state sync protocol, bump the version when the messages are changed.
+pub const SYNC_PROTOCOL: &[u8] = b"rooch-sync/1";
+
+/// The max number of the transactions in a `GetTransactions` response
+pub const MAX_TX_BATCH_SIZE: u64 = 1000;
+
+/// The max number of the state nodes in a `GetStateNodes` request
+pub const MAX_STATE_CHUNK_SIZE: usize = 1000;
+
+/// The max size of an encoded message, the larger message is rejected
+pub const MAX_MESSAGE_SIZE: usize = 64 * 1024 * 1024;
+
+/// The request of the state sync protocol, every request is sent in a new bidirectional stream,
+/// and the peer answers with one `SyncResponse` in the same stream.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum SyncRequest {
+    /// Get the sync info at the tx order, `None` for the latest tx order of the peer
+    GetSyncInfo { tx_order: Option<u64> },
+    /// Get the transactions of the tx orders in `[start_order, start_order + limit)`,
+    /// the limit is capped by `MAX_TX_BATCH_SIZE`.
+    GetTransactions { start_order: u64, limit: u64 },
+    /// Get the encoded state tree nodes by the node hashes
+    GetStateNodes { hashes: Vec<H256> },
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum SyncResponse {
+    SyncInfo(SyncInfo),
+    /// The tx orders without transaction are skipped
+    Transactions(Vec<SyncTransaction>),
+    /// The node hash -> the encoded node, the nodes missing in the peer are skipped
+    StateNodes(Vec<(H256, Vec<u8>)>),
+    Error(String),
+}
+
+/// The state of a peer at a tx order, the syncing node requires all the peers to agree on it.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct SyncInfo {
+    pub tx_order: u64,
+    /// The hash of the transaction of the tx order, `None` if there is no transaction
+    pub tx_hash: Option<H256>,
+    /// The state root after executing the transaction of the tx order
+    pub state_root: H256,
+    pub genesis: GenesisInfo,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SyncTransaction {
+    pub tx: TypedTransaction,
+    pub sequence_info: TransactionSequenceInfo,
+    pub execution_info: TransactionExecutionInfo,
+}
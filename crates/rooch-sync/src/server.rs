@@ -0,0 +1,232 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::protocol::{
+    SyncInfo, SyncRequest, SyncResponse, SyncTransaction, MAX_STATE_CHUNK_SIZE, MAX_TX_BATCH_SIZE,
+};
+use crate::transport::{read_message, server_endpoint, write_message};
+use anyhow::{anyhow, ensure, Result};
+use moveos_store::transaction_store::TransactionStore;
+use moveos_store::MoveOSStore;
+use moveos_types::h256::H256;
+use quinn::{Connecting, ConnectionError, Endpoint, RecvStream, SendStream};
+use rooch_store::meta_store::MetaStore;
+use rooch_store::transaction_store::TransactionStore as SequencerTransactionStore;
+use rooch_store::RoochStore;
+use smt::NodeStore;
+use std::net::SocketAddr;
+use std::path::Path;
+use tokio::task::JoinHandle;
+use tracing::{info, warn};
+
+pub struct SyncServerHandle {
+    endpoint: Endpoint,
+    join_handle: JoinHandle<()>,
+}
+
+impl SyncServerHandle {
+    pub fn stop(self) -> Result<()> {
+        self.endpoint.close(0u32.into(), b"shutdown");
+        self.join_handle.abort();
+        Ok(())
+    }
+}
+
+// Start the state sync server, the peers download the transactions and the state nodes from it.
+// The peers pin the fingerprint of the certificate in `cert_dir`, it is logged when the server starts.
+pub async fn start_sync_server(
+    addr: SocketAddr,
+    cert_dir: &Path,
+    moveos_store: MoveOSStore,
+    rooch_store: RoochStore,
+) -> Result<SyncServerHandle> {
+    let (endpoint, cert_fingerprint) = server_endpoint(addr, cert_dir)?;
+    let service = SyncService::new(moveos_store, rooch_store);
+    let accept_endpoint = endpoint.clone();
+    let join_handle = tokio::spawn(async move {
+        while let Some(connecting) = accept_endpoint.accept().await {
+            let service = service.clone();
+            tokio::spawn(async move {
+                if let Err(e) = serve_connection(connecting, service).await {
+                    warn!("State sync connection error: {:?}", e);
+                }
+            });
+        }
+    });
+    info!(
+        "State sync server start listening {:?}, the peer is {:?}@{}",
+        addr, cert_fingerprint, addr
+    );
+    Ok(SyncServerHandle {
+        endpoint,
+        join_handle,
+    })
+}
+
+async fn serve_connection(connecting: Connecting, service: SyncService) -> Result<()> {
+    let connection = connecting.await?;
+    loop {
+        let (send, recv) = match connection.accept_bi().await {
+            Ok(stream) => stream,
+            Err(ConnectionError::ApplicationClosed(_)) => return Ok(()),
+            Err(e) => return Err(e.into()),
+        };
+        let service = service.clone();
+        tokio::spawn(async move {
+            if let Err(e) = service.serve_stream(send, recv).await {
+                warn!("State sync stream error: {:?}", e);
+            }
+        });
+    }
+}
+
+/// Serve the state sync requests from the stores
+#[derive(Clone)]
+pub struct SyncService {
+    moveos_store: MoveOSStore,
+    rooch_store: RoochStore,
+}
+
+impl SyncService {
+    pub fn new(moveos_store: MoveOSStore, rooch_store: RoochStore) -> Self {
+        Self {
+            moveos_store,
+            rooch_store,
+        }
+    }
+
+    async fn serve_stream(self, mut send: SendStream, recv: RecvStream) -> Result<()> {
+        let request: SyncRequest = read_message(recv).await?;
+        // The store reads are blocking
+        let response = tokio::task::spawn_blocking(move || self.handle(request)).await?;
+        write_message(&mut send, &response).await
+    }
+
+    pub fn handle(&self, request: SyncRequest) -> SyncResponse {
+        let result = match request {
+            SyncRequest::GetSyncInfo { tx_order } => {
+                self.get_sync_info(tx_order).map(SyncResponse::SyncInfo)
+            }
+            SyncRequest::GetTransactions { start_order, limit } => self
+                .get_transactions(start_order, limit.min(MAX_TX_BATCH_SIZE))
+                .map(SyncResponse::Transactions),
+            SyncRequest::GetStateNodes { hashes } => {
+                self.get_state_nodes(hashes).map(SyncResponse::StateNodes)
+            }
+        };
+        result.unwrap_or_else(|e| SyncResponse::Error(e.to_string()))
+    }
+
+    fn last_order(&self) -> Result<u64> {
+        Ok(self
+            .rooch_store
+            .get_sequencer_order()?
+            .map(|order| order.last_order)
+            .unwrap_or(0))
+    }
+
+    fn get_tx_hash(&self, tx_order: u64) -> Result<Option<H256>> {
+        Ok(self
+            .rooch_store
+            .get_tx_sequence_info_mapping_by_order(vec![tx_order])?
+            .pop()
+            .flatten()
+            .map(|mapping| mapping.tx_hash))
+    }
+
+    fn get_sync_info(&self, tx_order: Option<u64>) -> Result<SyncInfo> {
+        let last_order = self.last_order()?;
+        let tx_order = tx_order.unwrap_or(last_order);
+        ensure!(
+            tx_order <= last_order,
+            "The tx order {} is greater than the latest tx order {}",
+            tx_order,
+            last_order
+        );
+        let tx_hash = self.get_tx_hash(tx_order)?;
+        // The state root of the tx order, or the startup state root if there is no transaction
+        let state_root = match tx_hash {
+            Some(tx_hash) => self
+                .moveos_store
+                .get_transaction_store()
+                .get_tx_execution_info(tx_hash)?
+                .map(|info| info.state_root),
+            None => self
+                .moveos_store
+                .get_config_store()
+                .get_startup_info()?
+                .map(|info| info.state_root_hash),
+        }
+        .ok_or_else(|| anyhow!("Can not find the state root of the tx order {}", tx_order))?;
+        let genesis = self
+            .moveos_store
+            .get_config_store()
+            .get_genesis()?
+            .ok_or_else(|| anyhow!("The genesis is not initialized"))?;
+        Ok(SyncInfo {
+            tx_order,
+            tx_hash,
+            state_root,
+            genesis,
+        })
+    }
+
+    fn get_transactions(&self, start_order: u64, limit: u64) -> Result<Vec<SyncTransaction>> {
+        let last_order = self.last_order()?;
+        if limit == 0 || start_order > last_order {
+            return Ok(vec![]);
+        }
+        let end_order = std::cmp::min(start_order.saturating_add(limit - 1), last_order);
+        let tx_orders = (start_order..=end_order).collect::<Vec<_>>();
+        let mappings = self
+            .rooch_store
+            .get_tx_sequence_info_mapping_by_order(tx_orders)?;
+        let cursor = start_order.checked_sub(1);
+        let sequence_infos = self
+            .rooch_store
+            .get_tx_sequence_infos_by_order(cursor, end_order - start_order + 1)?;
+        let mut transactions = vec![];
+        for (mapping, sequence_info) in mappings.into_iter().zip(sequence_infos) {
+            let (Some(mapping), Some(sequence_info)) = (mapping, sequence_info) else {
+                continue;
+            };
+            let tx = self
+                .rooch_store
+                .get_transaction_by_hash(mapping.tx_hash)?
+                .ok_or_else(|| anyhow!("The transaction {:?} does not exist", mapping.tx_hash))?;
+            let execution_info = self
+                .moveos_store
+                .get_transaction_store()
+                .get_tx_execution_info(mapping.tx_hash)?
+                .ok_or_else(|| {
+                    anyhow!(
+                        "The execution info of transaction {:?} does not exist",
+                        mapping.tx_hash
+                    )
+                })?;
+            transactions.push(SyncTransaction {
+                tx,
+                sequence_info,
+                execution_info,
+            });
+        }
+        Ok(transactions)
+    }
+
+    fn get_state_nodes(&self, hashes: Vec<H256>) -> Result<Vec<(H256, Vec<u8>)>> {
+        ensure!(
+            hashes.len() <= MAX_STATE_CHUNK_SIZE,
+            "Too many state nodes {} in a request, the max is {}",
+            hashes.len(),
+            MAX_STATE_CHUNK_SIZE
+        );
+        let node_store = &self.moveos_store.statedb.node_store;
+        let mut nodes = vec![];
+        for hash in hashes {
+            if let Some(node) = node_store.get(&hash)? {
+                nodes.push((hash, node));
+            }
+        }
+        Ok(nodes)
+    }
+}
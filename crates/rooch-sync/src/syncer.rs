@@ -0,0 +1,486 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::client::{SyncClient, SyncPeer};
+use crate::protocol::{SyncInfo, SyncTransaction, MAX_STATE_CHUNK_SIZE, MAX_TX_BATCH_SIZE};
+use crate::transport::client_endpoint;
+use anyhow::{bail, ensure, Result};
+use moveos_store::transaction_store::TransactionStore;
+use moveos_store::MoveOSStore;
+use moveos_types::genesis_info::GenesisInfo;
+use moveos_types::h256::H256;
+use moveos_types::startup_info::StartupInfo;
+use moveos_types::state::{KeyState, State};
+use quinn::Endpoint;
+use rooch_store::meta_store::MetaStore;
+use rooch_store::transaction_store::TransactionStore as SequencerTransactionStore;
+use rooch_store::RoochStore;
+use rooch_types::address::RoochAddress;
+use rooch_types::sequencer::SequencerOrder;
+use rooch_types::transaction::AbstractTransaction;
+use serde::{Deserialize, Serialize};
+use smt::{NodeStore, SPARSE_MERKLE_PLACEHOLDER_HASH};
+use std::collections::{BTreeMap, HashSet};
+use tracing::{info, warn};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SyncResult {
+    /// The sync info agreed by the peers, the node is synced to it
+    pub target: SyncInfo,
+    /// The number of the state nodes downloaded from the peers, the nodes in the local store are not downloaded
+    pub state_nodes: u64,
+    pub transactions: u64,
+}
+
+/// The StateSyncer downloads the state tree and the transactions at a tx order from the peers,
+/// so a new node catches up without replaying the history.
+///
+/// What is checked and what is trusted:
+/// - The genesis of the peers must be the configured trusted genesis.
+/// - Every tx order in the range is required, and its order signature must be signed by the configured sequencer
+///   for the hash of the downloaded transaction, so the transactions and their order are the ones of the sequencer.
+/// - The state root of the target tx order is not signed, the syncer requires all the peers to agree on it,
+///   and every state node is checked by its hash from that root.
+/// - The execution infos of the transactions are taken from the peers as is, they are not re-executed,
+///   only the state root of the target transaction is compared with the agreed one.
+///
+/// The peers are authenticated by the pinned fingerprints of their certificates.
+/// The syncer must not run with the server, the store can not be opened by two processes.
+pub struct StateSyncer {
+    moveos_store: MoveOSStore,
+    rooch_store: RoochStore,
+    genesis: GenesisInfo,
+    sequencer: RoochAddress,
+    // Keep the endpoint alive with the connections
+    _endpoint: Endpoint,
+    peers: Vec<SyncClient>,
+}
+
+impl StateSyncer {
+    /// Connect to the peers, the unreachable peers are skipped.
+    /// The `genesis` and the `sequencer` are trusted, they are not taken from the peers.
+    pub async fn connect(
+        moveos_store: MoveOSStore,
+        rooch_store: RoochStore,
+        peers: &[SyncPeer],
+        genesis: GenesisInfo,
+        sequencer: RoochAddress,
+    ) -> Result<Self> {
+        ensure!(!peers.is_empty(), "No state sync peer");
+        let endpoint = client_endpoint()?;
+        let mut clients = vec![];
+        for peer in peers {
+            match SyncClient::connect(&endpoint, *peer).await {
+                Ok(client) => clients.push(client),
+                Err(e) => warn!("Failed to connect to the state sync peer {}: {:?}", peer, e),
+            }
+        }
+        ensure!(
+            !clients.is_empty(),
+            "Failed to connect to any state sync peer"
+        );
+        Ok(Self {
+            moveos_store,
+            rooch_store,
+            genesis,
+            sequencer,
+            _endpoint: endpoint,
+            peers: clients,
+        })
+    }
+
+    /// Sync to the tx order, default to the latest tx order served by all the peers.
+    /// The sync is resumable, the state nodes and the transactions in the local store are not downloaded again.
+    pub async fn sync(&mut self, tx_order: Option<u64>) -> Result<SyncResult> {
+        let target = self.agreed_sync_info(tx_order).await?;
+        info!(
+            "State sync target tx order: {}, state root: {:?}",
+            target.tx_order, target.state_root
+        );
+        ensure!(
+            self.genesis == target.genesis,
+            "The genesis {} of the peers mismatches the trusted genesis {}",
+            target.genesis,
+            self.genesis
+        );
+        if let Some(genesis) = self.moveos_store.get_config_store().get_genesis()? {
+            ensure!(
+                genesis == self.genesis,
+                "The local genesis {} mismatches the trusted genesis {}",
+                genesis,
+                self.genesis
+            );
+        }
+        let local_order = self
+            .rooch_store
+            .get_sequencer_order()?
+            .map(|order| order.last_order);
+        if let Some(local_order) = local_order {
+            ensure!(
+                local_order <= target.tx_order,
+                "The local tx order {} is greater than the target tx order {}",
+                local_order,
+                target.tx_order
+            );
+        }
+
+        let state_nodes = self.sync_state(target.state_root).await?;
+        // There is no transaction to sync if the chain has no transaction, the tx orders start from 0
+        let transactions = if target.tx_hash.is_some() {
+            let start_order = local_order.map_or(0, |order| order + 1);
+            self.sync_transactions(start_order, &target).await?
+        } else {
+            0
+        };
+
+        // The startup info is saved at last, so the node starts from the synced state only if the sync is completed
+        if target.tx_hash.is_some() {
+            self.rooch_store
+                .save_sequencer_order(SequencerOrder::new(target.tx_order))?;
+        }
+        let config_store = self.moveos_store.get_config_store();
+        config_store.save_genesis(target.genesis.clone())?;
+        config_store.save_startup_info(StartupInfo::new(target.state_root))?;
+        Ok(SyncResult {
+            target,
+            state_nodes,
+            transactions,
+        })
+    }
+
+    /// Get the sync info of the tx order from all the peers, the peers must agree on it.
+    async fn agreed_sync_info(&self, tx_order: Option<u64>) -> Result<SyncInfo> {
+        let tx_order = match tx_order {
+            Some(tx_order) => tx_order,
+            None => {
+                let mut min_order = u64::MAX;
+                for peer in &self.peers {
+                    min_order = min_order.min(peer.get_sync_info(None).await?.tx_order);
+                }
+                min_order
+            }
+        };
+        let mut agreed: Option<SyncInfo> = None;
+        for peer in &self.peers {
+            let sync_info = peer.get_sync_info(Some(tx_order)).await?;
+            match &agreed {
+                Some(agreed) if agreed != &sync_info => bail!(
+                    "The peers disagree on the state at tx order {}: {:?} from {}, {:?} from {}",
+                    tx_order,
+                    agreed,
+                    self.peers[0].peer(),
+                    sync_info,
+                    peer.peer()
+                ),
+                Some(_) => {}
+                None => agreed = Some(sync_info),
+            }
+        }
+        Ok(agreed.expect("There is at least one peer"))
+    }
+
+    /// Download the state tree from the state root, and the state trees of the objects in it.
+    /// The nodes in the local store are read locally, only the missing nodes are downloaded.
+    async fn sync_state(&self, state_root: H256) -> Result<u64> {
+        let node_store = &self.moveos_store.statedb.node_store;
+        let mut downloaded = 0;
+        let mut round = 0;
+        let mut visited = HashSet::new();
+        let mut pending = vec![state_root];
+        while !pending.is_empty() {
+            let mut missing = vec![];
+            while let Some(hash) = pending.pop() {
+                if hash == *SPARSE_MERKLE_PLACEHOLDER_HASH || !visited.insert(hash) {
+                    continue;
+                }
+                match node_store.get(&hash)? {
+                    Some(node) => pending.extend(follow_node(hash, &node)?),
+                    None => missing.push(hash),
+                }
+                if missing.len() >= MAX_STATE_CHUNK_SIZE {
+                    break;
+                }
+            }
+            if missing.is_empty() {
+                continue;
+            }
+            let (nodes, children) = self.download_state_nodes(&missing, round).await?;
+            round += 1;
+            downloaded += nodes.len() as u64;
+            node_store.write_nodes(nodes)?;
+            pending.extend(children);
+            if round % 100 == 0 {
+                info!("State sync downloaded {} state nodes", downloaded);
+            }
+        }
+        Ok(downloaded)
+    }
+
+    /// Download the state nodes from the peers in turn, fallback to the next peer if the peer fails.
+    async fn download_state_nodes(
+        &self,
+        hashes: &[H256],
+        round: usize,
+    ) -> Result<(BTreeMap<H256, Vec<u8>>, Vec<H256>)> {
+        let mut last_error = None;
+        for i in 0..self.peers.len() {
+            let peer = &self.peers[(round + i) % self.peers.len()];
+            match peer
+                .get_state_nodes(hashes.to_vec())
+                .await
+                .and_then(|nodes| verify_state_nodes(hashes, nodes))
+            {
+                Ok(result) => return Ok(result),
+                Err(e) => {
+                    warn!(
+                        "Failed to download the state nodes from {}: {:?}",
+                        peer.peer(),
+                        e
+                    );
+                    last_error = Some(e);
+                }
+            }
+        }
+        Err(last_error.expect("There is at least one peer"))
+    }
+
+    /// Download the transactions in `[start_order, target.tx_order]` and save them to the stores,
+    /// every tx order must be present and signed by the sequencer.
+    async fn sync_transactions(&mut self, start_order: u64, target: &SyncInfo) -> Result<u64> {
+        let mut synced = 0;
+        let mut last_tx_hash = None;
+        let mut start = start_order;
+        let mut round = 0;
+        while start <= target.tx_order {
+            let limit = std::cmp::min(MAX_TX_BATCH_SIZE, target.tx_order - start + 1);
+            let transactions = self.download_transactions(start, limit, round).await?;
+            round += 1;
+            for transaction in transactions {
+                let SyncTransaction {
+                    tx,
+                    sequence_info,
+                    execution_info,
+                } = transaction;
+                let tx_hash = execution_info.tx_hash;
+                let tx_order = sequence_info.tx_order;
+                self.rooch_store.save_transaction(tx)?;
+                self.rooch_store
+                    .save_tx_sequence_info_mapping(tx_order, tx_hash)?;
+                self.rooch_store
+                    .save_tx_sequence_info_reverse_mapping(tx_hash, tx_order)?;
+                self.rooch_store.save_tx_sequence_info(sequence_info)?;
+                if tx_order == target.tx_order {
+                    ensure!(
+                        execution_info.state_root == target.state_root,
+                        "The state root {:?} of the target transaction mismatches the agreed state root {:?}",
+                        execution_info.state_root,
+                        target.state_root
+                    );
+                }
+                self.moveos_store
+                    .get_transaction_store()
+                    .save_tx_execution_info(execution_info)?;
+                last_tx_hash = Some(tx_hash);
+                synced += 1;
+            }
+            start += limit;
+        }
+        // Nothing is downloaded if the local store is already at the target
+        if start_order <= target.tx_order {
+            ensure!(
+                last_tx_hash == target.tx_hash,
+                "The last synced transaction {:?} mismatches the agreed transaction {:?}",
+                last_tx_hash,
+                target.tx_hash
+            );
+        }
+        Ok(synced)
+    }
+
+    async fn download_transactions(
+        &self,
+        start_order: u64,
+        limit: u64,
+        round: usize,
+    ) -> Result<Vec<SyncTransaction>> {
+        let mut last_error = None;
+        for i in 0..self.peers.len() {
+            let peer = &self.peers[(round + i) % self.peers.len()];
+            match peer
+                .get_transactions(start_order, limit)
+                .await
+                .and_then(|transactions| {
+                    verify_transactions(
+                        start_order,
+                        start_order + limit - 1,
+                        &transactions,
+                        self.sequencer,
+                    )
+                    .map(|_| transactions)
+                }) {
+                Ok(transactions) => return Ok(transactions),
+                Err(e) => {
+                    warn!(
+                        "Failed to download the transactions from {}: {:?}",
+                        peer.peer(),
+                        e
+                    );
+                    last_error = Some(e);
+                }
+            }
+        }
+        Err(last_error.expect("There is at least one peer"))
+    }
+}
+
+/// Verify the node by its hash, return the child nodes and the state tree root of the object in the leaf.
+fn follow_node(hash: H256, node: &[u8]) -> Result<Vec<H256>> {
+    let (mut children, state) = smt::verify_node::<KeyState, State>(hash, node)?;
+    if let Some(state) = state {
+        if state.is_object() {
+            children.push(H256(state.as_raw_object()?.state_root.into()));
+        }
+    }
+    Ok(children)
+}
+
+/// Verify the downloaded nodes are the requested ones, return the nodes and their children.
+fn verify_state_nodes(
+    hashes: &[H256],
+    nodes: Vec<(H256, Vec<u8>)>,
+) -> Result<(BTreeMap<H256, Vec<u8>>, Vec<H256>)> {
+    let mut verified = BTreeMap::new();
+    let mut children = vec![];
+    for (hash, node) in nodes {
+        ensure!(
+            hashes.contains(&hash),
+            "The state node {:?} is not requested",
+            hash
+        );
+        children.extend(follow_node(hash, &node)?);
+        verified.insert(hash, node);
+    }
+    ensure!(
+        verified.len() == hashes.len(),
+        "The peer misses {} of the {} requested state nodes",
+        hashes.len() - verified.len(),
+        hashes.len()
+    );
+    Ok((verified, children))
+}
+
+/// Verify the transactions are exactly the tx orders in `[start_order, end_order]` without gap,
+/// the order of every transaction is signed by the `sequencer`, and the transaction hashes match the execution infos.
+fn verify_transactions(
+    start_order: u64,
+    end_order: u64,
+    transactions: &[SyncTransaction],
+    sequencer: RoochAddress,
+) -> Result<()> {
+    ensure!(
+        transactions.len() as u64 == end_order - start_order + 1,
+        "The peer returns {} transactions for the tx orders [{}, {}]",
+        transactions.len(),
+        start_order,
+        end_order
+    );
+    for (expected_order, transaction) in (start_order..=end_order).zip(transactions) {
+        let tx_order = transaction.sequence_info.tx_order;
+        ensure!(
+            tx_order == expected_order,
+            "Expect the transaction of tx order {}, but got {}",
+            expected_order,
+            tx_order
+        );
+        let tx_hash = transaction.tx.tx_hash();
+        transaction
+            .sequence_info
+            .verify_order_signature(tx_hash, sequencer)
+            .map_err(|e| {
+                anyhow::anyhow!(
+                    "The order signature of the transaction {} is invalid: {}",
+                    tx_order,
+                    e
+                )
+            })?;
+        ensure!(
+            tx_hash == transaction.execution_info.tx_hash,
+            "The hash {:?} of the transaction {} mismatches the execution info {:?}",
+            tx_hash,
+            tx_order,
+            transaction.execution_info.tx_hash
+        );
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fastcrypto::ed25519::Ed25519KeyPair;
+    use fastcrypto::traits::KeyPair;
+    use move_core_types::vm_status::KeptVMStatus;
+    use moveos_types::transaction::{MoveAction, TransactionExecutionInfo};
+    use rooch_types::address::RoochSupportedAddress;
+    use rooch_types::crypto::{RoochKeyPair, Signature};
+    use rooch_types::transaction::authenticator::Authenticator;
+    use rooch_types::transaction::rooch::{RoochTransaction, RoochTransactionData};
+    use rooch_types::transaction::{TransactionSequenceInfo, TypedTransaction};
+
+    fn mock_transaction(tx_order: u64, sequencer_key: &RoochKeyPair) -> SyncTransaction {
+        let tx = TypedTransaction::Rooch(RoochTransaction::new(
+            RoochTransactionData::new_for_test(
+                RoochAddress::random(),
+                tx_order,
+                MoveAction::new_module_bundle(vec![]),
+            ),
+            Authenticator::new(0, vec![]),
+        ));
+        let execution_info = TransactionExecutionInfo::new(
+            tx.tx_hash(),
+            H256::random(),
+            H256::random(),
+            0,
+            KeptVMStatus::Executed,
+        );
+        let witness_hash = TransactionSequenceInfo::witness_hash(tx.tx_hash(), tx_order);
+        SyncTransaction {
+            tx,
+            sequence_info: TransactionSequenceInfo::new(
+                tx_order,
+                Signature::new_hashed(&witness_hash.0, sequencer_key).into(),
+                H256::random(),
+            ),
+            execution_info,
+        }
+    }
+
+    #[test]
+    fn test_verify_transactions() {
+        let sequencer_key =
+            RoochKeyPair::Ed25519(Ed25519KeyPair::generate(&mut rand::thread_rng()));
+        let sequencer: RoochAddress = (&sequencer_key.public()).into();
+        let transactions: Vec<_> = (1..=3)
+            .map(|tx_order| mock_transaction(tx_order, &sequencer_key))
+            .collect();
+        assert!(verify_transactions(1, 3, &transactions, sequencer).is_ok());
+        // Out of the range
+        assert!(verify_transactions(2, 4, &transactions, sequencer).is_err());
+        // Not signed by the sequencer
+        assert!(verify_transactions(1, 3, &transactions, RoochAddress::random()).is_err());
+        // A gap in the tx orders
+        let with_gap = vec![transactions[0].clone(), transactions[2].clone()];
+        assert!(verify_transactions(1, 3, &with_gap, sequencer).is_err());
+        // Not increasing
+        let reversed: Vec<_> = transactions.iter().rev().cloned().collect();
+        assert!(verify_transactions(1, 3, &reversed, sequencer).is_err());
+        // The order signature of another transaction
+        let mut transaction = mock_transaction(1, &sequencer_key);
+        transaction.sequence_info = transactions[0].sequence_info.clone();
+        assert!(verify_transactions(1, 1, &[transaction], sequencer).is_err());
+        // The hash mismatches the execution info
+        let mut transaction = mock_transaction(1, &sequencer_key);
+        transaction.execution_info.tx_hash = H256::random();
+        assert!(verify_transactions(1, 1, &[transaction], sequencer).is_err());
+    }
+}
@@ -0,0 +1,134 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::protocol::{MAX_MESSAGE_SIZE, SYNC_PROTOCOL};
+use anyhow::Result;
+use moveos_types::h256::{self, H256};
+use quinn::{Endpoint, RecvStream, SendStream};
+use rustls::client::{ServerCertVerified, ServerCertVerifier};
+use rustls::{Certificate, PrivateKey, ServerName};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::net::SocketAddr;
+use std::path::Path;
+use std::sync::Arc;
+use std::time::SystemTime;
+
+/// The server name of the self-signed certificate of the sync server
+pub const SYNC_SERVER_NAME: &str = "rooch-sync";
+/// The dir of the certificate of the sync server in the data dir
+pub const SYNC_CERT_DIR: &str = "sync";
+const SYNC_CERT_FILE: &str = "cert.der";
+const SYNC_KEY_FILE: &str = "key.der";
+
+/// The fingerprint of the certificate pinned by the peers, the sha3-256 hash of the DER encoded certificate
+pub fn certificate_fingerprint(cert: &Certificate) -> H256 {
+    h256::sha3_256_of(&cert.0)
+}
+
+/// Load the self-signed certificate of the sync server from the dir, generate it at the first start.
+/// The certificate is kept across the restarts, so the fingerprint pinned by the peers does not change.
+pub fn load_or_generate_certificate(cert_dir: &Path) -> Result<(Certificate, PrivateKey)> {
+    let cert_path = cert_dir.join(SYNC_CERT_FILE);
+    let key_path = cert_dir.join(SYNC_KEY_FILE);
+    if cert_path.exists() && key_path.exists() {
+        return Ok((
+            Certificate(std::fs::read(cert_path)?),
+            PrivateKey(std::fs::read(key_path)?),
+        ));
+    }
+    let cert = rcgen::generate_simple_self_signed(vec![SYNC_SERVER_NAME.to_owned()])?;
+    let cert_der = cert.serialize_der()?;
+    let key_der = cert.serialize_private_key_der();
+    std::fs::create_dir_all(cert_dir)?;
+    write_private_file(&key_path, &key_der)?;
+    std::fs::write(cert_path, &cert_der)?;
+    Ok((Certificate(cert_der), PrivateKey(key_der)))
+}
+
+#[cfg(unix)]
+fn write_private_file(path: &Path, contents: &[u8]) -> Result<()> {
+    use std::io::Write;
+    use std::os::unix::fs::OpenOptionsExt;
+    let mut file = std::fs::OpenOptions::new()
+        .write(true)
+        .create(true)
+        .truncate(true)
+        .mode(0o600)
+        .open(path)?;
+    file.write_all(contents)?;
+    Ok(())
+}
+
+#[cfg(not(unix))]
+fn write_private_file(path: &Path, contents: &[u8]) -> Result<()> {
+    Ok(std::fs::write(path, contents)?)
+}
+
+/// Create the QUIC endpoint of the sync server with the self-signed certificate in the dir,
+/// return the endpoint and the fingerprint of the certificate.
+pub fn server_endpoint(addr: SocketAddr, cert_dir: &Path) -> Result<(Endpoint, H256)> {
+    let (cert, key) = load_or_generate_certificate(cert_dir)?;
+    let fingerprint = certificate_fingerprint(&cert);
+    let mut crypto = rustls::ServerConfig::builder()
+        .with_safe_defaults()
+        .with_no_client_auth()
+        .with_single_cert(vec![cert], key)?;
+    crypto.alpn_protocols = vec![SYNC_PROTOCOL.to_vec()];
+    let config = quinn::ServerConfig::with_crypto(Arc::new(crypto));
+    Ok((Endpoint::server(config, addr)?, fingerprint))
+}
+
+/// Create the QUIC endpoint to connect to the sync servers, the client config is set per peer by `client_config`.
+pub fn client_endpoint() -> Result<Endpoint> {
+    Ok(Endpoint::client("0.0.0.0:0".parse()?)?)
+}
+
+/// The client config of a peer, the certificate of the peer must have the pinned fingerprint.
+pub fn client_config(cert_fingerprint: H256) -> quinn::ClientConfig {
+    let mut crypto = rustls::ClientConfig::builder()
+        .with_safe_defaults()
+        .with_custom_certificate_verifier(Arc::new(PinnedServerVerification { cert_fingerprint }))
+        .with_no_client_auth();
+    crypto.alpn_protocols = vec![SYNC_PROTOCOL.to_vec()];
+    quinn::ClientConfig::new(Arc::new(crypto))
+}
+
+/// Write the BCS encoded message and finish the stream, so the receiver reads the message to the end.
+pub async fn write_message<T: Serialize>(send: &mut SendStream, message: &T) -> Result<()> {
+    let bytes = bcs::to_bytes(message)?;
+    send.write_all(&bytes).await?;
+    send.finish().await?;
+    Ok(())
+}
+
+pub async fn read_message<T: DeserializeOwned>(recv: RecvStream) -> Result<T> {
+    let bytes = recv.read_to_end(MAX_MESSAGE_SIZE).await?;
+    Ok(bcs::from_bytes(&bytes)?)
+}
+
+/// The certificate of the sync server is self-signed, it is verified by the pinned fingerprint instead of a CA.
+struct PinnedServerVerification {
+    cert_fingerprint: H256,
+}
+
+impl ServerCertVerifier for PinnedServerVerification {
+    fn verify_server_cert(
+        &self,
+        end_entity: &Certificate,
+        _intermediates: &[Certificate],
+        _server_name: &ServerName,
+        _scts: &mut dyn Iterator<Item = &[u8]>,
+        _ocsp_response: &[u8],
+        _now: SystemTime,
+    ) -> Result<ServerCertVerified, rustls::Error> {
+        let fingerprint = certificate_fingerprint(end_entity);
+        if fingerprint != self.cert_fingerprint {
+            return Err(rustls::Error::General(format!(
+                "The certificate fingerprint {:?} mismatches the pinned fingerprint {:?}",
+                fingerprint, self.cert_fingerprint
+            )));
+        }
+        Ok(ServerCertVerified::assertion())
+    }
+}
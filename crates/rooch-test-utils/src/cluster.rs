@@ -0,0 +1,177 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use anyhow::{bail, Result};
+use fastcrypto::ed25519::Ed25519KeyPair;
+use fastcrypto::traits::KeyPair;
+use moveos_types::gas_config::GasConfig;
+use moveos_types::transaction::MoveAction;
+use rooch_config::{RoochOpt, ServerOpt};
+use rooch_rpc_api::jsonrpc_types::{ExecuteTransactionResponseView, KeptVMStatusView};
+use rooch_rpc_client::{Client, ClientBuilder};
+use rooch_rpc_server::{run_start_server, ServerHandle};
+use rooch_types::address::RoochAddress;
+use rooch_types::crypto::RoochKeyPair;
+use rooch_types::framework::gas_coin::GasCoin;
+use rooch_types::transaction::rooch::RoochTransactionData;
+use std::net::TcpListener;
+
+/// The config of the test cluster
+#[derive(Clone, Debug)]
+pub struct TestClusterConfig {
+    /// The number of the test accounts generated for the cluster
+    pub num_accounts: usize,
+    /// Fund every test account with 100 RGC from the gas coin faucet after the cluster is started
+    pub fund_accounts: bool,
+    /// The port of the JSON-RPC server, an unused port is picked if absent
+    pub port: Option<u16>,
+}
+
+impl Default for TestClusterConfig {
+    fn default() -> Self {
+        Self {
+            num_accounts: 3,
+            fund_accounts: true,
+            port: None,
+        }
+    }
+}
+
+/// A test account of the cluster, the key pair is kept in memory and never written to a keystore
+#[derive(Debug)]
+pub struct TestAccount {
+    pub address: RoochAddress,
+    pub key_pair: RoochKeyPair,
+}
+
+impl TestAccount {
+    pub fn generate() -> Self {
+        let key_pair = RoochKeyPair::Ed25519(Ed25519KeyPair::generate(&mut rand::thread_rng()));
+        let address = (&key_pair.public()).into();
+        Self { address, key_pair }
+    }
+}
+
+/// A Rooch node running in the current process for the integration tests.
+/// The sequencer, the executor, the indexer and the JSON-RPC server are started on the local chain with temporary stores,
+/// the stores are removed after the cluster is stopped.
+///
+/// The actors are registered in the global actor system, so only one cluster should be running in a process at a time.
+pub struct RoochTestCluster {
+    handle: ServerHandle,
+    rpc_url: String,
+    client: Client,
+    accounts: Vec<TestAccount>,
+}
+
+impl RoochTestCluster {
+    /// Start a cluster with the default config
+    pub async fn start() -> Result<Self> {
+        Self::start_with_config(TestClusterConfig::default()).await
+    }
+
+    pub async fn start_with_config(config: TestClusterConfig) -> Result<Self> {
+        let port = match config.port {
+            Some(port) => port,
+            None => pick_unused_port()?,
+        };
+        let mut opt = RoochOpt::new_with_temp_store();
+        opt.port = Some(port);
+        let handle = run_start_server(&opt, ServerOpt::new()).await?;
+
+        let rpc_url = format!("http://127.0.0.1:{}", port);
+        let client = ClientBuilder::default()
+            .ws_url(format!("ws://127.0.0.1:{}", port))
+            .build(rpc_url.as_str())
+            .await?;
+        let accounts = (0..config.num_accounts)
+            .map(|_| TestAccount::generate())
+            .collect();
+        let cluster = Self {
+            handle,
+            rpc_url,
+            client,
+            accounts,
+        };
+        if config.fund_accounts {
+            for account in cluster.accounts.iter() {
+                cluster.fund_account(account).await?;
+            }
+        }
+        tracing::info!(
+            "Rooch test cluster started at {} with {} accounts",
+            cluster.rpc_url,
+            cluster.accounts.len()
+        );
+        Ok(cluster)
+    }
+
+    /// The url of the JSON-RPC server, the WebSocket subscriptions are served on the same port
+    pub fn rpc_url(&self) -> &str {
+        self.rpc_url.as_str()
+    }
+
+    pub fn client(&self) -> &Client {
+        &self.client
+    }
+
+    pub fn accounts(&self) -> &[TestAccount] {
+        &self.accounts
+    }
+
+    /// The test account at the index, panics if the index is out of range
+    pub fn account(&self, index: usize) -> &TestAccount {
+        &self.accounts[index]
+    }
+
+    /// Mint 100 RGC to the account from the gas coin faucet
+    pub async fn fund_account(
+        &self,
+        account: &TestAccount,
+    ) -> Result<ExecuteTransactionResponseView> {
+        self.sign_and_execute(account, GasCoin::create_faucet_action())
+            .await
+    }
+
+    /// Sign the action with the account and execute it, fails if the transaction is not executed successfully
+    pub async fn sign_and_execute(
+        &self,
+        account: &TestAccount,
+        action: MoveAction,
+    ) -> Result<ExecuteTransactionResponseView> {
+        let chain_id = self.client.rooch.get_chain_id().await?;
+        let sequence_number = self
+            .client
+            .rooch
+            .get_sequence_number(account.address)
+            .await?;
+        let tx = RoochTransactionData::new(
+            account.address,
+            sequence_number,
+            chain_id,
+            GasConfig::DEFAULT_MAX_GAS_AMOUNT,
+            action,
+        )
+        .sign(&account.key_pair);
+        let result = self.client.rooch.execute_tx(tx).await?;
+        if result.execution_info.status != KeptVMStatusView::Executed {
+            bail!(
+                "Transaction {} of {} execution failed: {:?}",
+                result.execution_info.tx_hash,
+                account.address,
+                result.execution_info.status
+            );
+        }
+        Ok(result)
+    }
+
+    pub fn stop(self) -> Result<()> {
+        self.handle.stop()
+    }
+}
+
+/// Pick an unused local port by binding to the port 0, the port is released before it is returned
+fn pick_unused_port() -> Result<u16> {
+    let listener = TcpListener::bind("127.0.0.1:0")?;
+    Ok(listener.local_addr()?.port())
+}
@@ -0,0 +1,51 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use anyhow::Result;
+use move_core_types::account_address::AccountAddress;
+use move_core_types::u256::U256;
+use moveos_types::state::MoveStructType;
+use rooch_test_utils::{RoochTestCluster, TestClusterConfig};
+use rooch_types::framework::gas_coin::GasCoin;
+use rooch_types::framework::transfer::TransferModule;
+
+#[tokio::test]
+async fn test_cluster_funds_accounts() -> Result<()> {
+    let cluster = RoochTestCluster::start_with_config(TestClusterConfig {
+        num_accounts: 2,
+        ..Default::default()
+    })
+    .await?;
+    let sender = cluster.account(0);
+    let recipient = cluster.account(1);
+    let client = cluster.client();
+    let balance_of = |address: AccountAddress| async move {
+        client
+            .rooch
+            .get_balance(address.into(), GasCoin::struct_tag().into())
+            .await
+            .map(|balance| balance.balance.0)
+    };
+
+    let sender_balance = balance_of(sender.address.into()).await?;
+    assert!(sender_balance > U256::zero());
+    let recipient_balance = balance_of(recipient.address.into()).await?;
+
+    let amount = GasCoin::scaling(1u64);
+    cluster
+        .sign_and_execute(
+            sender,
+            TransferModule::create_transfer_coin_action(
+                GasCoin::struct_tag(),
+                recipient.address.into(),
+                amount,
+            ),
+        )
+        .await?;
+    assert_eq!(
+        balance_of(recipient.address.into()).await?,
+        recipient_balance + amount
+    );
+
+    cluster.stop()
+}
@@ -2,11 +2,13 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::addresses::ROOCH_FRAMEWORK_ADDRESS;
+use anyhow::Result;
 use move_core_types::{
     account_address::AccountAddress, ident_str, identifier::IdentStr, value::MoveValue,
 };
 use moveos_types::{
     module_binding::{ModuleBinding, MoveFunctionCaller},
+    moveos_std::tx_context::TxContext,
     state::{MoveStructState, MoveStructType},
     transaction::MoveAction,
 };
@@ -39,7 +41,6 @@ impl MoveStructState for Account {
 }
 
 /// Rust bindings for RoochFramework account module
-#[allow(dead_code)]
 pub struct AccountModule<'a> {
     caller: &'a dyn MoveFunctionCaller,
 }
@@ -47,6 +48,26 @@ pub struct AccountModule<'a> {
 impl<'a> AccountModule<'a> {
     const CREATE_ACCOUNT_ENTRY_FUNCTION_NAME: &'static IdentStr =
         ident_str!("create_account_entry");
+    const SEQUENCE_NUMBER_FUNCTION_NAME: &'static IdentStr = ident_str!("sequence_number");
+
+    /// The sequence number of the next transaction of the account, 0 if the account does not exist
+    pub fn sequence_number(&self, address: AccountAddress) -> Result<u64> {
+        let call = Self::create_function_call(
+            Self::SEQUENCE_NUMBER_FUNCTION_NAME,
+            vec![],
+            vec![MoveValue::Address(address)],
+        );
+        let ctx = TxContext::new_readonly_ctx(address);
+        let sequence_number =
+            self.caller
+                .call_function(&ctx, call)?
+                .into_result()
+                .map(|mut values| {
+                    let value = values.pop().expect("should have one return value");
+                    bcs::from_bytes::<u64>(&value.value).expect("should be a valid u64")
+                })?;
+        Ok(sequence_number)
+    }
 
     pub fn create_account_action(address: AccountAddress) -> MoveAction {
         Self::create_move_action(
@@ -8,6 +8,7 @@ use crate::{
 };
 use anyhow::{bail, Result};
 use bitcoin::bech32::segwit::encode_to_fmt_unchecked;
+use bitcoin::bech32::{Bech32m, Hrp};
 use bitcoin::script::PushBytesBuf;
 use bitcoin::{
     address::Address, secp256k1::Secp256k1, Network, PrivateKey, Script, WitnessProgram,
@@ -37,7 +38,7 @@ use rand::{seq::SliceRandom, thread_rng};
 use serde::ser::Error;
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use serde_with::serde_as;
-use sha3::{Digest, Sha3_256};
+use sha3::{Digest, Keccak256};
 use std::fmt;
 use std::str::FromStr;
 
@@ -57,7 +58,7 @@ pub struct MultiChainAddress {
 }
 
 impl MultiChainAddress {
-    pub(crate) fn new(multichain_id: RoochMultiChainID, raw_address: Vec<u8>) -> Self {
+    pub fn new(multichain_id: RoochMultiChainID, raw_address: Vec<u8>) -> Self {
         Self {
             multichain_id,
             raw_address,
@@ -89,6 +90,35 @@ impl MultiChainAddress {
         }
     }
 
+    /// Same as `try_from_str_with_multichain_id`, but the Bitcoin address must be valid for the given Bitcoin network,
+    /// such as the bech32 `bc1`/`tb1`/`bcrt1` prefix of the segwit and taproot addresses.
+    pub fn try_from_str_with_bitcoin_network(
+        multichain_id: RoochMultiChainID,
+        str: &str,
+        bitcoin_network: u8,
+    ) -> Result<Self, anyhow::Error> {
+        match multichain_id {
+            RoochMultiChainID::Bitcoin => {
+                let address = BitcoinAddress::from_str_with_network(str, bitcoin_network)?;
+                Ok(address.into())
+            }
+            _ => Self::try_from_str_with_multichain_id(multichain_id, str),
+        }
+    }
+
+    /// The chain original address, the Bitcoin address is formatted for the given Bitcoin network
+    pub fn to_original_string_with_bitcoin_network(
+        &self,
+        bitcoin_network: u8,
+    ) -> Result<String, anyhow::Error> {
+        match self.multichain_id {
+            RoochMultiChainID::Bitcoin => {
+                BitcoinAddress::try_from(self.clone())?.format(bitcoin_network)
+            }
+            _ => Ok(self.to_original_string()),
+        }
+    }
+
     pub fn is_rooch_address(&self) -> bool {
         self.multichain_id.is_rooch()
     }
@@ -239,6 +269,28 @@ impl TryFrom<MultiChainAddress> for RoochAddress {
     }
 }
 
+/// The human-readable part of the bech32 encoded Rooch address
+pub const ROOCH_HRP: Hrp = Hrp::parse_unchecked("rooch");
+
+impl RoochAddress {
+    /// The bech32m encoding of the address, such as `rooch1...`
+    pub fn to_bech32(&self) -> String {
+        bitcoin::bech32::encode::<Bech32m>(&ROOCH_HRP, self.0.as_bytes())
+            .expect("The bech32 encoding of the Rooch address should succeed")
+    }
+
+    pub fn from_bech32(s: &str) -> Result<Self> {
+        let (hrp, data) = bitcoin::bech32::decode(s)?;
+        if hrp != ROOCH_HRP {
+            bail!("Invalid Rooch address hrp: {}", hrp);
+        }
+        if data.len() != H256::len_bytes() {
+            bail!("Invalid Rooch address length: {}", data.len());
+        }
+        Ok(Self(H256::from_slice(&data)))
+    }
+}
+
 // ==== Display and FromStr, Deserialize and Serialize ====
 
 impl fmt::Display for RoochAddress {
@@ -354,8 +406,8 @@ impl From<Secp256k1PublicKey> for EthereumAddress {
         let uncompressed_public_key_bytes = value.pubkey.serialize_uncompressed();
         // Ignore the first byte and take the last 64-bytes of the uncompressed pubkey
         let uncompressed_64 = uncompressed_public_key_bytes[1..].to_vec();
-        // create a SHA3-256 object
-        let mut hasher = Sha3_256::new();
+        // create a Keccak-256 object, the same hash as Ethereum
+        let mut hasher = Keccak256::new();
         // write input message
         hasher.update(&uncompressed_64);
         // read hash digest
@@ -516,6 +568,43 @@ impl BitcoinAddress {
         self.bytes.is_empty()
     }
 
+    /// Parse the address string and check the address is valid for the given Bitcoin network
+    pub fn from_str_with_network(s: &str, network: u8) -> Result<Self, anyhow::Error> {
+        let network = network::Network::try_from(network)?;
+        let address = Address::from_str(s)?
+            .require_network(network.into())
+            .map_err(|_| {
+                anyhow::anyhow!("Bitcoin address {} is invalid for network {}", s, network)
+            })?;
+        Self::try_from_supported(address)
+    }
+
+    fn try_from_supported(address: bitcoin::Address) -> Result<Self, anyhow::Error> {
+        let bitcoin_address = Self::from(address.clone());
+        if bitcoin_address.address_type().is_none() {
+            bail!("Unsupported bitcoin address {}", address);
+        }
+        Ok(bitcoin_address)
+    }
+
+    /// The standard address type of the address, `None` if the address is empty or a witness program of an unknown version
+    pub fn address_type(&self) -> Option<bitcoin::AddressType> {
+        let payload_type = BitcoinAddressPayloadType::try_from(*self.bytes.first()?).ok()?;
+        match payload_type {
+            BitcoinAddressPayloadType::PubkeyHash => Some(bitcoin::AddressType::P2pkh),
+            BitcoinAddressPayloadType::ScriptHash => Some(bitcoin::AddressType::P2sh),
+            BitcoinAddressPayloadType::WitnessProgram => {
+                let version = *self.bytes.get(1)?;
+                match (version, self.bytes.len() - 2) {
+                    (0, 20) => Some(bitcoin::AddressType::P2wpkh),
+                    (0, 32) => Some(bitcoin::AddressType::P2wsh),
+                    (1, 32) => Some(bitcoin::AddressType::P2tr),
+                    _ => None,
+                }
+            }
+        }
+    }
+
     ///  Format the base58 as a hexadecimal string
     pub fn format(&self, network: u8) -> Result<String, anyhow::Error> {
         let payload_type = BitcoinAddressPayloadType::try_from(self.bytes[0])?;
@@ -592,9 +681,10 @@ impl RoochSupportedAddress for BitcoinAddress {
 impl FromStr for BitcoinAddress {
     type Err = anyhow::Error;
 
+    /// Parse the address of any Bitcoin network, use `from_str_with_network` to check the network prefix
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let address = Address::from_str(s)?.assume_checked();
-        Ok(Self::from(address))
+        Self::try_from_supported(address)
     }
 }
 
@@ -809,6 +899,13 @@ mod test {
         assert!(bytes.len() == 32);
         let rooch_address_from_bytes = bcs::from_bytes(&bytes).unwrap();
         assert_eq!(rooch_address, rooch_address_from_bytes);
+
+        let bech32_str = rooch_address.to_bech32();
+        assert!(bech32_str.starts_with("rooch1"));
+        assert_eq!(
+            RoochAddress::from_bech32(&bech32_str).unwrap(),
+            rooch_address
+        );
     }
 
     #[test]
@@ -832,6 +929,13 @@ mod test {
 
     }
 
+    #[test]
+    fn test_rooch_address_from_invalid_bech32() {
+        let bitcoin_address = "bc1pq5ttgyqu5pmfn9aqt09d978mky2fndxr3ed3ntszta75g9q6xrlqlwyl0r";
+        assert!(RoochAddress::from_bech32(bitcoin_address).is_err());
+        assert!(RoochAddress::from_bech32("0x1").is_err());
+    }
+
     #[test]
     pub fn test_from_script() {
         let bytes = hex::decode("001497cdff4fd3ed6f885d54a52b79d7a2141072ae3f").unwrap();
@@ -873,6 +977,53 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    pub fn test_bitcoin_address_with_network() -> Result<()> {
+        let mainnet = network::Network::NetworkBitcoin.to_num();
+        let testnet = network::Network::NetworkTestnet.to_num();
+
+        // native segwit p2wpkh, bech32
+        let p2wpkh_mainnet = "bc1qjlxl7n7na4hcsh25554hn4azzsg89t3lcty7gp";
+        let p2wpkh_testnet = "tb1qjlxl7n7na4hcsh25554hn4azzsg89t3ljdldnj";
+        let address = BitcoinAddress::from_str_with_network(p2wpkh_mainnet, mainnet)?;
+        assert_eq!(address.address_type(), Some(bitcoin::AddressType::P2wpkh));
+        assert_eq!(address.format(mainnet)?, p2wpkh_mainnet);
+        assert_eq!(address.format(testnet)?, p2wpkh_testnet);
+        assert_eq!(
+            BitcoinAddress::from_str_with_network(p2wpkh_testnet, testnet)?,
+            address
+        );
+        assert!(BitcoinAddress::from_str_with_network(p2wpkh_mainnet, testnet).is_err());
+        assert!(BitcoinAddress::from_str_with_network(p2wpkh_testnet, mainnet).is_err());
+
+        // taproot p2tr, bech32m
+        let p2tr_mainnet = "bc1pq5ttgyqu5pmfn9aqt09d978mky2fndxr3ed3ntszta75g9q6xrlqlwyl0r";
+        let address = BitcoinAddress::from_str_with_network(p2tr_mainnet, mainnet)?;
+        assert_eq!(address.address_type(), Some(bitcoin::AddressType::P2tr));
+        assert_eq!(address.format(mainnet)?, p2tr_mainnet);
+        let p2tr_testnet = address.format(testnet)?;
+        assert!(p2tr_testnet.starts_with("tb1p"));
+        assert_eq!(
+            BitcoinAddress::from_str_with_network(&p2tr_testnet, testnet)?,
+            address
+        );
+        assert!(BitcoinAddress::from_str_with_network(p2tr_mainnet, testnet).is_err());
+
+        let multi_chain_address = MultiChainAddress::try_from_str_with_bitcoin_network(
+            RoochMultiChainID::Bitcoin,
+            p2tr_mainnet,
+            mainnet,
+        )?;
+        assert_eq!(multi_chain_address, MultiChainAddress::from(address));
+        assert!(MultiChainAddress::try_from_str_with_bitcoin_network(
+            RoochMultiChainID::Bitcoin,
+            p2tr_mainnet,
+            testnet,
+        )
+        .is_err());
+        Ok(())
+    }
+
     #[test]
     pub fn test_convert_bitcoin_address() -> Result<()> {
         // bitcoin regtest address
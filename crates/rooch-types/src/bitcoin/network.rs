@@ -33,7 +33,7 @@ impl TryFrom<&str> for Network {
     type Error = anyhow::Error;
 
     fn try_from(value: &str) -> Result<Self, Self::Error> {
-        let value = value.to_uppercase();
+        let value = value.to_lowercase();
         match value.as_str() {
             "bitcoin" => Ok(Network::NetworkBitcoin),
             "testnet" => Ok(Network::NetworkTestnet),
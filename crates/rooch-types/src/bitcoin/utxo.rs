@@ -2,7 +2,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::address::BitcoinAddress;
-use crate::indexer::state::IndexerGlobalState;
+use crate::indexer::state::{IndexerGlobalState, IndexerUTXOState};
 use move_core_types::language_storage::StructTag;
 
 use anyhow::Result;
@@ -130,6 +130,24 @@ impl UTXOState {
             updated_at: state.updated_at,
         }
     }
+
+    pub fn new_from_indexer_utxo_state(
+        state: IndexerUTXOState,
+        owner_bitcoin_address: Option<BitcoinAddress>,
+    ) -> Self {
+        Self {
+            object_id: state.object_id,
+            owner: state.owner,
+            owner_bitcoin_address,
+            flag: state.flag,
+            value: Some(state.utxo()),
+            object_type: UTXO::struct_tag(),
+            tx_order: state.tx_order,
+            state_index: state.state_index,
+            created_at: state.created_at,
+            updated_at: state.updated_at,
+        }
+    }
 }
 
 /// Rust bindings for BitcoinMove utxo module
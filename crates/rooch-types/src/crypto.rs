@@ -6,6 +6,7 @@ use crate::{
     authentication_key::AuthenticationKey,
     error::{RoochError, RoochResult},
     framework::auth_validator::BuiltinAuthValidator,
+    schnorr::{SchnorrKeyPair, SchnorrPublicKey, SchnorrRoochSignature},
 };
 use derive_more::{AsMut, AsRef, From};
 pub use enum_dispatch::enum_dispatch;
@@ -38,12 +39,14 @@ pub type DefaultHash = Blake2b256;
 #[derive(Debug, From, PartialEq, Eq)]
 pub enum RoochKeyPair {
     Ed25519(Ed25519KeyPair),
+    Schnorr(SchnorrKeyPair),
 }
 
 impl RoochKeyPair {
     pub fn public(&self) -> PublicKey {
         match self {
             RoochKeyPair::Ed25519(kp) => PublicKey::Ed25519(kp.public().into()),
+            RoochKeyPair::Schnorr(kp) => PublicKey::Schnorr(kp.public()),
         }
     }
 
@@ -54,6 +57,7 @@ impl RoochKeyPair {
     pub fn copy(&self) -> Self {
         match self {
             RoochKeyPair::Ed25519(kp) => RoochKeyPair::Ed25519(kp.copy()),
+            RoochKeyPair::Schnorr(kp) => RoochKeyPair::Schnorr(kp.copy()),
         }
     }
 }
@@ -62,6 +66,7 @@ impl Signer<Signature> for RoochKeyPair {
     fn sign(&self, msg: &[u8]) -> Signature {
         match self {
             RoochKeyPair::Ed25519(kp) => kp.sign(msg),
+            RoochKeyPair::Schnorr(kp) => kp.sign(msg),
         }
     }
 }
@@ -85,6 +90,9 @@ impl EncodeDecodeBase64 for RoochKeyPair {
             RoochKeyPair::Ed25519(kp) => {
                 bytes.extend_from_slice(kp.as_bytes());
             }
+            RoochKeyPair::Schnorr(kp) => {
+                bytes.extend_from_slice(&kp.private_key_bytes());
+            }
         }
         Base64::encode(&bytes[..])
     }
@@ -92,13 +100,18 @@ impl EncodeDecodeBase64 for RoochKeyPair {
     /// Decode a RoochKeyPair from `flag || privkey` in Base64. The public key is computed directly from the private key bytes.
     fn decode_base64(value: &str) -> Result<Self, eyre::Report> {
         let bytes = Base64::decode(value).map_err(|e| eyre!("{}", e.to_string()))?;
+        let key_bytes = bytes.get(1..).ok_or_else(|| eyre!("Invalid length"))?;
         match BuiltinAuthValidator::from_flag_byte(
             *bytes.first().ok_or_else(|| eyre!("Invalid length"))?,
         ) {
-            // Process Rooch key pair by default
-            Ok(_x) => Ok(RoochKeyPair::Ed25519(Ed25519KeyPair::from_bytes(
-                bytes.get(1..).ok_or_else(|| eyre!("Invalid length"))?,
-            )?)),
+            Ok(BuiltinAuthValidator::Rooch) => Ok(RoochKeyPair::Ed25519(
+                Ed25519KeyPair::from_bytes(key_bytes)?,
+            )),
+            Ok(BuiltinAuthValidator::Schnorr) => Ok(RoochKeyPair::Schnorr(
+                SchnorrKeyPair::from_private_key_bytes(key_bytes)?,
+            )),
+            // The other validators have no key pair of their own flag
+            Ok(validator) => Err(eyre!("Unsupported key pair of {} validator", validator)),
             _ => Err(eyre!("Invalid bytes")),
         }
     }
@@ -129,12 +142,14 @@ impl<'de> Deserialize<'de> for RoochKeyPair {
 #[derive(Debug, Clone, PartialEq, Eq, JsonSchema)]
 pub enum PublicKey {
     Ed25519(Ed25519PublicKeyAsBytes),
+    Schnorr(SchnorrPublicKey),
 }
 
 impl AsRef<[u8]> for PublicKey {
     fn as_ref(&self) -> &[u8] {
         match self {
             PublicKey::Ed25519(pk) => &pk.0,
+            PublicKey::Schnorr(pk) => pk.as_ref(),
         }
     }
 }
@@ -156,6 +171,10 @@ impl EncodeDecodeBase64 for PublicKey {
                         bytes.get(1..).ok_or_else(|| eyre!("Invalid length"))?,
                     )?;
                     Ok(PublicKey::Ed25519((&pk).into()))
+                } else if x == &BuiltinAuthValidator::Schnorr.flag() {
+                    Ok(PublicKey::Schnorr(SchnorrPublicKey::from_bytes(
+                        bytes.get(1..).ok_or_else(|| eyre!("Invalid length"))?,
+                    )?))
                 } else {
                     Err(eyre!("Invalid flag byte"))
                 }
@@ -191,20 +210,27 @@ impl PublicKey {
     pub fn flag(&self) -> u8 {
         match self {
             PublicKey::Ed25519(_) => Ed25519RoochSignature::SCHEME.flag(),
+            PublicKey::Schnorr(_) => BuiltinAuthValidator::Schnorr.flag(),
         }
     }
     pub fn try_from_bytes(
-        _auth_validator: BuiltinAuthValidator,
+        auth_validator: BuiltinAuthValidator,
         key_bytes: &[u8],
     ) -> Result<PublicKey, eyre::Report> {
-        // Process Rooch public key by default
-        Ok(PublicKey::Ed25519(
-            (&Ed25519PublicKey::from_bytes(key_bytes)?).into(),
-        ))
+        match auth_validator {
+            BuiltinAuthValidator::Schnorr => {
+                Ok(PublicKey::Schnorr(SchnorrPublicKey::from_bytes(key_bytes)?))
+            }
+            // Process Rooch public key by default
+            _ => Ok(PublicKey::Ed25519(
+                (&Ed25519PublicKey::from_bytes(key_bytes)?).into(),
+            )),
+        }
     }
     pub fn auth_validator(&self) -> BuiltinAuthValidator {
         match self {
             PublicKey::Ed25519(_) => Ed25519RoochSignature::SCHEME,
+            PublicKey::Schnorr(_) => BuiltinAuthValidator::Schnorr,
         }
     }
 
@@ -316,6 +342,7 @@ pub trait RoochSignatureInner: Sized + ToFromBytes + PartialEq + Eq + Hash {
 #[derive(Clone, JsonSchema, Debug, PartialEq, Eq, Hash)]
 pub enum Signature {
     Ed25519RoochSignature,
+    SchnorrRoochSignature,
 }
 
 impl Serialize for Signature {
@@ -371,6 +398,11 @@ impl Signature {
     /// Parse [enum CompressedSignature] from trait Signature `flag || sig || pk`.
     /// This is useful for the MultiSig to combine partial signature into a MultiSig public key.
     pub fn to_compressed(&self) -> Result<CompressedSignature, RoochError> {
+        if let Signature::SchnorrRoochSignature(_) = self {
+            return Err(RoochError::InvalidSignature {
+                error: "Schnorr signature can not be compressed".to_owned(),
+            });
+        }
         let bytes = self.signature_bytes();
         // Process Rooch signature by default
         Ok(CompressedSignature::Ed25519(
@@ -385,6 +417,9 @@ impl Signature {
     /// This is useful for the MultiSig to construct the bitmap in [struct MultiPublicKey].
     pub fn to_public_key(&self) -> Result<PublicKey, RoochError> {
         let bytes = self.public_key_bytes();
+        if let Signature::SchnorrRoochSignature(sig) = self {
+            return Ok(PublicKey::Schnorr(sig.public_key()?));
+        }
         // Process Rooch signature by default
         Ok(PublicKey::Ed25519(
             (&Ed25519PublicKey::from_bytes(bytes)
@@ -398,6 +433,7 @@ impl AsRef<[u8]> for Signature {
     fn as_ref(&self) -> &[u8] {
         match self {
             Signature::Ed25519RoochSignature(sig) => sig.as_ref(),
+            Signature::SchnorrRoochSignature(sig) => sig.as_ref(),
         }
     }
 }
@@ -405,6 +441,7 @@ impl AsMut<[u8]> for Signature {
     fn as_mut(&mut self) -> &mut [u8] {
         match self {
             Signature::Ed25519RoochSignature(sig) => sig.as_mut(),
+            Signature::SchnorrRoochSignature(sig) => sig.as_mut(),
         }
     }
 }
@@ -415,6 +452,8 @@ impl ToFromBytes for Signature {
             Some(x) => {
                 if x == &Ed25519RoochSignature::SCHEME.flag() {
                     Ok(<Ed25519RoochSignature as ToFromBytes>::from_bytes(bytes)?.into())
+                } else if x == &BuiltinAuthValidator::Schnorr.flag() {
+                    Ok(<SchnorrRoochSignature as ToFromBytes>::from_bytes(bytes)?.into())
                 } else {
                     Err(FastCryptoError::InvalidInput)
                 }
@@ -447,6 +486,9 @@ pub trait RoochSignature: Sized + ToFromBytes {
     fn verify_secure<T>(&self, value: &T, author: RoochAddress) -> RoochResult<()>
     where
         T: Serialize;
+
+    /// Verify the signature of the message in the hashed form, it is signed by `Signature::new_hashed`
+    fn verify_hashed(&self, hashed_msg: &[u8], author: RoochAddress) -> RoochResult<()>;
 }
 
 impl<S: RoochSignatureInner + Sized> RoochSignature for S {
@@ -480,6 +522,14 @@ impl<S: RoochSignatureInner + Sized> RoochSignature for S {
                 error: format!("Fail to verify user sig {}", e),
             })
     }
+
+    fn verify_hashed(&self, hashed_msg: &[u8], author: RoochAddress) -> Result<(), RoochError> {
+        let (sig, pk) = &self.get_verification_inputs(author)?;
+        pk.verify(hashed_msg, sig)
+            .map_err(|e| RoochError::InvalidSignature {
+                error: format!("Fail to verify hashed sig {}", e),
+            })
+    }
 }
 
 //
@@ -528,10 +578,13 @@ impl RoochSignatureInner for Ed25519RoochSignature {
 
 #[cfg(test)]
 mod tests {
+    use super::{EncodeDecodeBase64, RoochKeyPair};
     use crate::address::RoochAddress;
+    use crate::framework::auth_validator::BuiltinAuthValidator;
     use ethers::utils::keccak256;
     use fastcrypto::{
         ed25519::{Ed25519KeyPair, Ed25519PrivateKey},
+        encoding::{Base64, Encoding},
         secp256k1::{Secp256k1KeyPair, Secp256k1PrivateKey},
         traits::{KeyPair, ToFromBytes},
     };
@@ -563,4 +616,22 @@ mod tests {
         let expected_address = "0x1a642f0e3c3af545e7acbd38b07251b3990914f1";
         assert_eq!(address_str, expected_address);
     }
+
+    #[test]
+    fn test_key_pair_flag() {
+        let kp = RoochKeyPair::Ed25519(Ed25519KeyPair::generate(&mut rand::thread_rng()));
+        let decoded = RoochKeyPair::decode_base64(&kp.encode_base64()).unwrap();
+        assert_eq!(decoded.public(), kp.public());
+
+        // The key pair of the validator without its own key is rejected
+        for validator in [
+            BuiltinAuthValidator::Ethereum,
+            BuiltinAuthValidator::Multisig,
+            BuiltinAuthValidator::Webauthn,
+        ] {
+            let mut bytes = vec![validator.flag()];
+            bytes.extend_from_slice(&[1u8; 32]);
+            assert!(RoochKeyPair::decode_base64(&Base64::encode(&bytes)).is_err());
+        }
+    }
 }
@@ -54,6 +54,8 @@ pub enum RoochError {
     ViewFunctionError(String),
     #[error("Import account error: {0}")]
     ImportAccountError(String),
+    #[error("Export account error: {0}")]
+    ExportAccountError(String),
     #[error("Switch account error: {0}")]
     SwitchAccountError(String),
     #[error("Update account error: {0}")]
@@ -100,6 +102,21 @@ pub enum RoochError {
     #[error("Clean server error: {0}")]
     CleanServerError(String),
 
+    #[error("Replay error: {0}")]
+    ReplayError(String),
+
+    #[error("Snapshot error: {0}")]
+    SnapshotError(String),
+
+    #[error("State sync error: {0}")]
+    StateSyncError(String),
+
+    #[error("Rebuild indexer error: {0}")]
+    RebuildIndexerError(String),
+
+    #[error("Module verification error: {0}")]
+    ModuleVerificationError(String),
+
     #[error("Use of disabled feature: {:?}", error)]
     UnsupportedFeatureError { error: String },
 
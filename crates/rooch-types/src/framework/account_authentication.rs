@@ -4,18 +4,22 @@
 use crate::addresses::ROOCH_FRAMEWORK_ADDRESS;
 use anyhow::Result;
 use move_core_types::{
-    account_address::AccountAddress, ident_str, identifier::IdentStr, value::MoveValue,
+    account_address::AccountAddress,
+    ident_str,
+    identifier::IdentStr,
+    language_storage::{StructTag, TypeTag},
+    value::MoveValue,
 };
 use moveos_types::{
     module_binding::{ModuleBinding, MoveFunctionCaller},
     move_std::option::MoveOption,
     moveos_std::tx_context::TxContext,
     state::{MoveState, MoveStructState, MoveStructType},
-    transaction::FunctionCall,
+    transaction::{FunctionCall, MoveAction},
 };
 use serde::{Deserialize, Serialize};
 
-/// Rust bindings for RoochFramework account_authencation::AuthenticationKey
+/// Rust bindings for RoochFramework account_authentication::AuthenticationKey
 #[derive(Clone, Ord, PartialOrd, PartialEq, Eq, Hash, Serialize, Deserialize, Debug)]
 pub struct AuthenticationKey<V> {
     pub authencation_key: Vec<u8>,
@@ -53,7 +57,7 @@ where
     }
 }
 
-/// Rust bindings for RoochFramework account_authencation module
+/// Rust bindings for RoochFramework account_authentication module
 pub struct AuthenticationKeyModule<'a> {
     caller: &'a dyn MoveFunctionCaller,
 }
@@ -61,6 +65,12 @@ pub struct AuthenticationKeyModule<'a> {
 impl<'a> AuthenticationKeyModule<'a> {
     const GET_AUTHENTICATION_KEY_FUNCTION_NAME: &'static IdentStr =
         ident_str!("get_authentication_key");
+    const INSTALLED_AUTH_VALIDATORS_FUNCTION_NAME: &'static IdentStr =
+        ident_str!("installed_auth_validators");
+    const INSTALL_AUTH_VALIDATOR_ENTRY_FUNCTION_NAME: &'static IdentStr =
+        ident_str!("install_auth_validator_entry");
+    const UNINSTALL_AUTH_VALIDATOR_ENTRY_FUNCTION_NAME: &'static IdentStr =
+        ident_str!("uninstall_auth_validator_entry");
 
     pub fn get_authentication_key<V: MoveStructType>(
         &self,
@@ -86,10 +96,45 @@ impl<'a> AuthenticationKeyModule<'a> {
             })?;
         Ok(auth_key)
     }
+
+    /// The ids of the auth validators installed for the account
+    pub fn installed_auth_validators(&self, address: AccountAddress) -> Result<Vec<u64>> {
+        let call = FunctionCall::new(
+            Self::function_id(Self::INSTALLED_AUTH_VALIDATORS_FUNCTION_NAME),
+            vec![],
+            vec![MoveValue::Address(address)
+                .simple_serialize()
+                .expect("address should serialize")],
+        );
+        let ctx = TxContext::new_readonly_ctx(address);
+        self.caller
+            .call_function(&ctx, call)?
+            .into_result()
+            .map(|values| {
+                let value = values.get(0).expect("Expected return value");
+                bcs::from_bytes::<Vec<u64>>(&value.value).expect("Expected vector<u64>")
+            })
+    }
+
+    pub fn install_auth_validator_action(validator_type: StructTag) -> MoveAction {
+        Self::create_move_action(
+            Self::INSTALL_AUTH_VALIDATOR_ENTRY_FUNCTION_NAME,
+            vec![TypeTag::Struct(Box::new(validator_type))],
+            vec![],
+        )
+    }
+
+    pub fn uninstall_auth_validator_action(validator_type: StructTag) -> MoveAction {
+        Self::create_move_action(
+            Self::UNINSTALL_AUTH_VALIDATOR_ENTRY_FUNCTION_NAME,
+            vec![TypeTag::Struct(Box::new(validator_type))],
+            vec![],
+        )
+    }
 }
 
 impl<'a> ModuleBinding<'a> for AuthenticationKeyModule<'a> {
-    const MODULE_NAME: &'static IdentStr = ident_str!("account_authencation");
+    const MODULE_NAME: &'static IdentStr = ident_str!("account_authentication");
     const MODULE_ADDRESS: AccountAddress = ROOCH_FRAMEWORK_ADDRESS;
 
     fn new(caller: &'a impl MoveFunctionCaller) -> Self
@@ -2,8 +2,11 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use super::ethereum_validator::EthereumValidatorModule;
+use super::multisig_validator::MultisigValidatorModule;
 use super::native_validator::NativeValidatorModule;
+use super::schnorr_validator::SchnorrValidatorModule;
 use super::transaction_validator::TransactionValidator;
+use super::webauthn_validator::WebauthnValidatorModule;
 use crate::address::MultiChainAddress;
 use crate::addresses::ROOCH_FRAMEWORK_ADDRESS;
 use crate::error::RoochError;
@@ -17,11 +20,11 @@ use moveos_types::function_return_value::DecodedFunctionResult;
 use moveos_types::move_std::option::MoveOption;
 use moveos_types::transaction::MoveAction;
 use moveos_types::{
-    module_binding::MoveFunctionCaller,
+    module_binding::{ModuleBinding, MoveFunctionCaller},
     move_std::ascii::MoveAsciiString,
     move_types::FunctionId,
     moveos_std::tx_context::TxContext,
-    state::{MoveStructState, MoveStructType},
+    state::{MoveState, MoveStructState, MoveStructType},
     transaction::FunctionCall,
 };
 use serde::{Deserialize, Serialize};
@@ -46,16 +49,25 @@ use strum_macros::{Display, EnumString};
 pub enum BuiltinAuthValidator {
     Rooch,
     Ethereum,
+    Multisig,
+    Schnorr,
+    Webauthn,
 }
 
 impl BuiltinAuthValidator {
     const ROOCH_FLAG: u8 = 0x00;
     const ETHEREUM_FLAG: u8 = 0x01;
+    const MULTISIG_FLAG: u8 = 0x03;
+    const SCHNORR_FLAG: u8 = 0x04;
+    const WEBAUTHN_FLAG: u8 = 0x05;
 
     pub fn flag(&self) -> u8 {
         match self {
             BuiltinAuthValidator::Rooch => Self::ROOCH_FLAG,
             BuiltinAuthValidator::Ethereum => Self::ETHEREUM_FLAG,
+            BuiltinAuthValidator::Multisig => Self::MULTISIG_FLAG,
+            BuiltinAuthValidator::Schnorr => Self::SCHNORR_FLAG,
+            BuiltinAuthValidator::Webauthn => Self::WEBAUTHN_FLAG,
         }
     }
 
@@ -69,6 +81,10 @@ impl BuiltinAuthValidator {
     pub fn from_flag_byte(byte_int: u8) -> Result<BuiltinAuthValidator, RoochError> {
         match byte_int {
             Self::ROOCH_FLAG => Ok(BuiltinAuthValidator::Rooch),
+            Self::ETHEREUM_FLAG => Ok(BuiltinAuthValidator::Ethereum),
+            Self::MULTISIG_FLAG => Ok(BuiltinAuthValidator::Multisig),
+            Self::SCHNORR_FLAG => Ok(BuiltinAuthValidator::Schnorr),
+            Self::WEBAUTHN_FLAG => Ok(BuiltinAuthValidator::Webauthn),
             _ => Err(RoochError::KeyConversionError(
                 "Invalid key auth validator".to_owned(),
             )),
@@ -86,6 +102,22 @@ impl BuiltinAuthValidator {
             BuiltinAuthValidator::Ethereum => {
                 EthereumValidatorModule::rotate_authentication_key_action(public_key)
             }
+            BuiltinAuthValidator::Multisig => {
+                // The public key of the multisig account is the BCS bytes of the MultisigPublicKey
+                let multisig_public_key = bcs::from_bytes(&public_key).map_err(|e| {
+                    RoochError::KeyConversionError(format!("Invalid multisig public key: {}", e))
+                })?;
+                MultisigValidatorModule::rotate_authentication_key_action(&multisig_public_key)
+            }
+            BuiltinAuthValidator::Schnorr => {
+                SchnorrValidatorModule::rotate_authentication_key_action(public_key)
+            }
+            BuiltinAuthValidator::Webauthn => {
+                // The passkey is registered with its relying party, see `WebauthnValidatorModule::rotate_authentication_key_action`
+                return Err(RoochError::KeyConversionError(
+                    "The passkey should be registered with the rp id and the origin".to_owned(),
+                ));
+            }
         };
         Ok(action)
     }
@@ -98,6 +130,15 @@ impl BuiltinAuthValidator {
             BuiltinAuthValidator::Ethereum => {
                 EthereumValidatorModule::remove_authentication_key_action()
             }
+            BuiltinAuthValidator::Multisig => {
+                MultisigValidatorModule::remove_authentication_key_action()
+            }
+            BuiltinAuthValidator::Schnorr => {
+                SchnorrValidatorModule::remove_authentication_key_action()
+            }
+            BuiltinAuthValidator::Webauthn => {
+                WebauthnValidatorModule::remove_authentication_key_action()
+            }
         };
         Ok(action)
     }
@@ -190,6 +231,48 @@ impl TxValidateResult {
     }
 }
 
+/// Rust bindings for RoochFramework auth_validator_registry module
+pub struct AuthValidatorRegistryModule<'a> {
+    caller: &'a dyn MoveFunctionCaller,
+}
+
+impl<'a> AuthValidatorRegistryModule<'a> {
+    const GET_VALIDATOR_FUNCTION_NAME: &'static IdentStr = ident_str!("get_validator");
+
+    /// Get the registered auth validator by id, return None if the validator is not registered
+    pub fn get_validator(&self, id: u64) -> Result<Option<AuthValidator>> {
+        let call = FunctionCall::new(
+            Self::function_id(Self::GET_VALIDATOR_FUNCTION_NAME),
+            vec![],
+            vec![MoveValue::U64(id)
+                .simple_serialize()
+                .expect("u64 should serialize")],
+        );
+        let ctx = TxContext::new_readonly_ctx(ROOCH_FRAMEWORK_ADDRESS);
+        self.caller
+            .call_function(&ctx, call)?
+            .into_result()
+            .map(|values| {
+                let value = values.get(0).expect("Expected return value");
+                let result = MoveOption::<AuthValidator>::from_bytes(&value.value)
+                    .expect("Expected Option<AuthValidator>");
+                result.into()
+            })
+    }
+}
+
+impl<'a> ModuleBinding<'a> for AuthValidatorRegistryModule<'a> {
+    const MODULE_NAME: &'static IdentStr = ident_str!("auth_validator_registry");
+    const MODULE_ADDRESS: AccountAddress = ROOCH_FRAMEWORK_ADDRESS;
+
+    fn new(caller: &'a impl MoveFunctionCaller) -> Self
+    where
+        Self: Sized,
+    {
+        Self { caller }
+    }
+}
+
 /// Rust bindings for developer custom auth validator module
 /// Because the module is not in RoochFramework, we need to dynamically determine the module id base on the AuthValidator struct
 pub struct AuthValidatorCaller<'a> {
@@ -252,3 +335,25 @@ impl<'a> AuthValidatorCaller<'a> {
         FunctionCall::new(self.post_execute_function_id(), vec![], vec![])
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::BuiltinAuthValidator;
+    use clap::ValueEnum;
+
+    #[test]
+    fn test_from_flag_byte() {
+        for validator in BuiltinAuthValidator::value_variants() {
+            assert_eq!(
+                BuiltinAuthValidator::from_flag_byte(validator.flag()).unwrap(),
+                *validator
+            );
+            assert_eq!(
+                BuiltinAuthValidator::from_flag(&validator.flag().to_string()).unwrap(),
+                *validator
+            );
+        }
+        assert!(BuiltinAuthValidator::from_flag_byte(0x02).is_err());
+        assert!(BuiltinAuthValidator::from_flag("rooch").is_err());
+    }
+}
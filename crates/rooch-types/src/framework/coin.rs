@@ -6,6 +6,7 @@ use move_core_types::language_storage::StructTag;
 use move_core_types::u256::U256;
 use move_core_types::{account_address::AccountAddress, ident_str, identifier::IdentStr};
 use moveos_types::module_binding::{ModuleBinding, MoveFunctionCaller};
+use moveos_types::move_std::option::MoveOption;
 use moveos_types::move_std::string::MoveString;
 use moveos_types::moveos_std::object_id::{self, ObjectID};
 use moveos_types::state::{MoveState, MoveStructState, MoveStructType, PlaceholderStruct};
@@ -91,6 +92,7 @@ pub struct CoinInfo<CoinType> {
     coin_type: MoveString,
     name: MoveString,
     symbol: MoveString,
+    icon_url: MoveOption<MoveString>,
     decimals: u8,
     supply: U256,
     phantom: std::marker::PhantomData<CoinType>,
@@ -123,6 +125,7 @@ where
             MoveString::type_layout(),
             MoveString::type_layout(),
             MoveString::type_layout(),
+            MoveOption::<MoveString>::type_layout(),
             move_core_types::value::MoveTypeLayout::U8,
             move_core_types::value::MoveTypeLayout::U256,
         ])
@@ -141,6 +144,13 @@ where
             type_params: vec![coin_type.into()],
         }
     }
+
+    /// Return true if the struct tag is the CoinInfo of any coin type
+    pub fn is_coin_info(struct_tag: &StructTag) -> bool {
+        struct_tag.address == Self::ADDRESS
+            && struct_tag.module.as_ident_str() == Self::MODULE_NAME
+            && struct_tag.name.as_ident_str() == Self::STRUCT_NAME
+    }
 }
 impl<CoinType> CoinInfo<CoinType> {
     pub fn coin_type(&self) -> String {
@@ -158,6 +168,9 @@ impl<CoinType> CoinInfo<CoinType> {
     pub fn symbol(&self) -> String {
         self.symbol.to_string()
     }
+    pub fn icon_url(&self) -> Option<String> {
+        Option::<MoveString>::from(self.icon_url.clone()).map(|icon_url| icon_url.to_string())
+    }
     pub fn decimals(&self) -> u8 {
         self.decimals
     }
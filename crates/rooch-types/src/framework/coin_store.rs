@@ -6,6 +6,7 @@ use move_core_types::language_storage::StructTag;
 use move_core_types::u256::U256;
 use move_core_types::{account_address::AccountAddress, ident_str, identifier::IdentStr};
 use moveos_types::move_std::string::MoveString;
+use moveos_types::moveos_std::object_id::ObjectID;
 use moveos_types::state::{MoveState, MoveStructState, MoveStructType};
 use serde::{Deserialize, Serialize};
 
@@ -109,3 +110,51 @@ impl<CoinType> CoinStore<CoinType> {
         self.frozen
     }
 }
+
+/// The event emitted when some amount of a coin is deposited into a coin store
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DepositEvent {
+    pub coin_store_id: ObjectID,
+    pub coin_type: MoveString,
+    pub amount: U256,
+}
+
+impl MoveStructType for DepositEvent {
+    const ADDRESS: AccountAddress = ROOCH_FRAMEWORK_ADDRESS;
+    const MODULE_NAME: &'static IdentStr = MODULE_NAME;
+    const STRUCT_NAME: &'static IdentStr = ident_str!("DepositEvent");
+}
+
+impl MoveStructState for DepositEvent {
+    fn struct_layout() -> move_core_types::value::MoveStructLayout {
+        move_core_types::value::MoveStructLayout::new(vec![
+            ObjectID::type_layout(),
+            MoveString::type_layout(),
+            move_core_types::value::MoveTypeLayout::U256,
+        ])
+    }
+}
+
+/// The event emitted when some amount of a coin is withdrawn from a coin store
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WithdrawEvent {
+    pub coin_store_id: ObjectID,
+    pub coin_type: MoveString,
+    pub amount: U256,
+}
+
+impl MoveStructType for WithdrawEvent {
+    const ADDRESS: AccountAddress = ROOCH_FRAMEWORK_ADDRESS;
+    const MODULE_NAME: &'static IdentStr = MODULE_NAME;
+    const STRUCT_NAME: &'static IdentStr = ident_str!("WithdrawEvent");
+}
+
+impl MoveStructState for WithdrawEvent {
+    fn struct_layout() -> move_core_types::value::MoveStructLayout {
+        move_core_types::value::MoveStructLayout::new(vec![
+            ObjectID::type_layout(),
+            MoveString::type_layout(),
+            move_core_types::value::MoveTypeLayout::U256,
+        ])
+    }
+}
@@ -3,9 +3,10 @@
 
 use crate::addresses::ROOCH_FRAMEWORK_ADDRESS;
 use move_core_types::{
-    account_address::AccountAddress, ident_str, identifier::IdentStr, u256::U256,
+    account_address::AccountAddress, ident_str, identifier::IdentStr, language_storage::ModuleId,
+    u256::U256,
 };
-use moveos_types::state::MoveStructType;
+use moveos_types::{move_types::FunctionId, state::MoveStructType, transaction::MoveAction};
 
 pub const MODULE_NAME: &IdentStr = ident_str!("gas_coin");
 pub const DECIMALS: u8 = 18;
@@ -20,7 +21,21 @@ impl MoveStructType for GasCoin {
 }
 
 impl GasCoin {
+    pub const FAUCET_ENTRY_FUNCTION_NAME: &'static IdentStr = ident_str!("faucet_entry");
+
     pub fn scaling<I: Into<U256>>(value: I) -> U256 {
         U256::from(10u64.pow(DECIMALS as u32)) * value.into()
     }
+
+    /// The action to mint 100 RGC to the sender via `gas_coin::faucet_entry`
+    pub fn create_faucet_action() -> MoveAction {
+        MoveAction::new_function_call(
+            FunctionId::new(
+                ModuleId::new(ROOCH_FRAMEWORK_ADDRESS, MODULE_NAME.to_owned()),
+                Self::FAUCET_ENTRY_FUNCTION_NAME.to_owned(),
+            ),
+            vec![],
+            vec![],
+        )
+    }
 }
@@ -2,8 +2,14 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::addresses::ROOCH_FRAMEWORK_ADDRESS;
-use move_core_types::{account_address::AccountAddress, ident_str, identifier::IdentStr};
-use moveos_types::state::{MoveStructState, MoveStructType};
+use move_core_types::{
+    account_address::AccountAddress, ident_str, identifier::IdentStr, u256::U256, value::MoveValue,
+};
+use moveos_types::{
+    module_binding::{ModuleBinding, MoveFunctionCaller},
+    state::{MoveStructState, MoveStructType},
+    transaction::{FunctionCall, MoveAction},
+};
 use serde::{Deserialize, Serialize};
 
 pub const MODULE_NAME: &IdentStr = ident_str!("genesis");
@@ -53,3 +59,42 @@ impl GenesisContext {
         }
     }
 }
+
+/// Rust bindings for RoochFramework genesis module
+pub struct GenesisModule<'a> {
+    #[allow(dead_code)]
+    caller: &'a dyn MoveFunctionCaller,
+}
+
+impl<'a> GenesisModule<'a> {
+    pub const ALLOCATE_FUNCTION_NAME: &'static IdentStr = ident_str!("allocate");
+
+    /// The action of the genesis transaction allocating the initial gas coins, only the genesis account can call it
+    pub fn create_allocate_action(allocations: Vec<(AccountAddress, U256)>) -> MoveAction {
+        let (addresses, amounts): (Vec<_>, Vec<_>) = allocations.into_iter().unzip();
+        MoveAction::Function(FunctionCall::new(
+            Self::function_id(Self::ALLOCATE_FUNCTION_NAME),
+            vec![],
+            vec![
+                MoveValue::Vector(addresses.into_iter().map(MoveValue::Address).collect())
+                    .simple_serialize()
+                    .unwrap(),
+                MoveValue::Vector(amounts.into_iter().map(MoveValue::U256).collect())
+                    .simple_serialize()
+                    .unwrap(),
+            ],
+        ))
+    }
+}
+
+impl<'a> ModuleBinding<'a> for GenesisModule<'a> {
+    const MODULE_NAME: &'static IdentStr = MODULE_NAME;
+    const MODULE_ADDRESS: AccountAddress = ROOCH_FRAMEWORK_ADDRESS;
+
+    fn new(caller: &'a impl MoveFunctionCaller) -> Self
+    where
+        Self: Sized,
+    {
+        Self { caller }
+    }
+}
@@ -19,11 +19,17 @@ pub mod ethereum_light_client;
 pub mod ethereum_validator;
 pub mod gas_coin;
 pub mod genesis;
+pub mod multisig_validator;
 pub mod native_validator;
+pub mod onchain_config;
+pub mod scheduler;
+pub mod schnorr_validator;
 pub mod session_key;
 pub mod timestamp;
+pub mod transaction_fee;
 pub mod transaction_validator;
 pub mod transfer;
+pub mod webauthn_validator;
 
 /// MoveOS system pre_execute functions registry.
 /// The registry is used to filter out system pre_execute functions.
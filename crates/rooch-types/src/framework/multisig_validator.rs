@@ -0,0 +1,95 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use super::auth_validator::BuiltinAuthValidator;
+use crate::addresses::ROOCH_FRAMEWORK_ADDRESS;
+use crate::multisig::MultisigPublicKey;
+use anyhow::Result;
+use move_core_types::{
+    account_address::AccountAddress, ident_str, identifier::IdentStr, value::MoveValue,
+};
+use moveos_types::{
+    module_binding::{ModuleBinding, MoveFunctionCaller},
+    moveos_std::tx_context::TxContext,
+    state::MoveStructType,
+    transaction::{FunctionCall, MoveAction},
+};
+
+pub struct MultisigValidator {}
+
+impl MultisigValidator {
+    pub fn auth_validator_id() -> u64 {
+        BuiltinAuthValidator::Multisig.flag().into()
+    }
+}
+
+impl MoveStructType for MultisigValidator {
+    const ADDRESS: AccountAddress = ROOCH_FRAMEWORK_ADDRESS;
+    const MODULE_NAME: &'static IdentStr = MultisigValidatorModule::MODULE_NAME;
+    const STRUCT_NAME: &'static IdentStr = ident_str!("MultisigValidator");
+}
+
+/// Rust bindings for RoochFramework multisig_validator module
+pub struct MultisigValidatorModule<'a> {
+    caller: &'a dyn MoveFunctionCaller,
+}
+
+impl<'a> MultisigValidatorModule<'a> {
+    const VALIDATE_FUNCTION_NAME: &'static IdentStr = ident_str!("validate");
+    const ROTATE_AUTHENTICATION_KEY_ENTRY_FUNCTION_NAME: &'static IdentStr =
+        ident_str!("rotate_authentication_key_entry");
+    const REMOVE_AUTHENTICATION_KEY_ENTRY_FUNCTION_NAME: &'static IdentStr =
+        ident_str!("remove_authentication_key_entry");
+
+    pub fn validate(&self, ctx: &TxContext, payload: Vec<u8>) -> Result<()> {
+        let auth_validator_call = FunctionCall::new(
+            Self::function_id(Self::VALIDATE_FUNCTION_NAME),
+            vec![],
+            vec![MoveValue::vector_u8(payload).simple_serialize().unwrap()],
+        );
+        self.caller
+            .call_function(ctx, auth_validator_call)?
+            .into_result()
+            .map(|values| {
+                debug_assert!(values.is_empty(), "should not have return values");
+            })?;
+        Ok(())
+    }
+
+    pub fn rotate_authentication_key_action(multisig_public_key: &MultisigPublicKey) -> MoveAction {
+        Self::create_move_action(
+            Self::ROTATE_AUTHENTICATION_KEY_ENTRY_FUNCTION_NAME,
+            vec![],
+            vec![
+                MoveValue::U8(multisig_public_key.threshold()),
+                MoveValue::Vector(
+                    multisig_public_key
+                        .public_keys()
+                        .iter()
+                        .map(|public_key| MoveValue::vector_u8(public_key.clone()))
+                        .collect(),
+                ),
+            ],
+        )
+    }
+
+    pub fn remove_authentication_key_action() -> MoveAction {
+        Self::create_move_action(
+            Self::REMOVE_AUTHENTICATION_KEY_ENTRY_FUNCTION_NAME,
+            vec![],
+            vec![],
+        )
+    }
+}
+
+impl<'a> ModuleBinding<'a> for MultisigValidatorModule<'a> {
+    const MODULE_NAME: &'static IdentStr = ident_str!("multisig_validator");
+    const MODULE_ADDRESS: AccountAddress = ROOCH_FRAMEWORK_ADDRESS;
+
+    fn new(caller: &'a impl MoveFunctionCaller) -> Self
+    where
+        Self: Sized,
+    {
+        Self { caller }
+    }
+}
@@ -0,0 +1,88 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::addresses::ROOCH_FRAMEWORK_ADDRESS;
+use anyhow::Result;
+use move_core_types::{
+    account_address::AccountAddress, ident_str, identifier::IdentStr, value::MoveValue,
+};
+use moveos_types::{
+    module_binding::{ModuleBinding, MoveFunctionCaller},
+    moveos_std::tx_context::TxContext,
+    transaction::{FunctionCall, MoveAction},
+};
+
+pub const MODULE_NAME: &IdentStr = ident_str!("onchain_config");
+
+/// Rust bindings for RoochFramework onchain_config module
+pub struct OnchainConfigModule<'a> {
+    caller: &'a dyn MoveFunctionCaller,
+}
+
+impl<'a> OnchainConfigModule<'a> {
+    pub const SEQUENCER_FUNCTION_NAME: &'static IdentStr = ident_str!("sequencer");
+    pub const GAS_SCHEDULE_VERSION_FUNCTION_NAME: &'static IdentStr =
+        ident_str!("gas_schedule_version");
+    pub const UPDATE_ONCHAIN_GAS_SCHEDULE_FUNCTION_NAME: &'static IdentStr =
+        ident_str!("update_onchain_gas_schedule");
+
+    pub fn sequencer(&self) -> Result<AccountAddress> {
+        let call = FunctionCall::new(
+            Self::function_id(Self::SEQUENCER_FUNCTION_NAME),
+            vec![],
+            vec![],
+        );
+        let ctx = TxContext::zero();
+        let sequencer =
+            self.caller
+                .call_function(&ctx, call)?
+                .into_result()
+                .map(|mut values| {
+                    let value = values.pop().expect("should have one return value");
+                    bcs::from_bytes::<AccountAddress>(&value.value)
+                        .expect("should be a valid address")
+                })?;
+        Ok(sequencer)
+    }
+
+    pub fn gas_schedule_version(&self) -> Result<u64> {
+        let call = FunctionCall::new(
+            Self::function_id(Self::GAS_SCHEDULE_VERSION_FUNCTION_NAME),
+            vec![],
+            vec![],
+        );
+        let ctx = TxContext::zero();
+        let version = self
+            .caller
+            .call_function(&ctx, call)?
+            .into_result()
+            .map(|mut values| {
+                let value = values.pop().expect("should have one return value");
+                bcs::from_bytes::<u64>(&value.value).expect("should be a valid u64")
+            })?;
+        Ok(version)
+    }
+
+    /// The gas schedule blob is the BCS bytes of the `GasSchedule`, only the sequencer can update the gas schedule
+    pub fn create_update_onchain_gas_schedule_action(gas_schedule_blob: Vec<u8>) -> MoveAction {
+        MoveAction::Function(FunctionCall::new(
+            Self::function_id(Self::UPDATE_ONCHAIN_GAS_SCHEDULE_FUNCTION_NAME),
+            vec![],
+            vec![MoveValue::vector_u8(gas_schedule_blob)
+                .simple_serialize()
+                .unwrap()],
+        ))
+    }
+}
+
+impl<'a> ModuleBinding<'a> for OnchainConfigModule<'a> {
+    const MODULE_NAME: &'static IdentStr = MODULE_NAME;
+    const MODULE_ADDRESS: AccountAddress = ROOCH_FRAMEWORK_ADDRESS;
+
+    fn new(caller: &'a impl MoveFunctionCaller) -> Self
+    where
+        Self: Sized,
+    {
+        Self { caller }
+    }
+}
@@ -0,0 +1,137 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::addresses::ROOCH_FRAMEWORK_ADDRESS;
+use anyhow::Result;
+use move_core_types::{
+    account_address::AccountAddress,
+    ident_str,
+    identifier::{IdentStr, Identifier},
+    language_storage::ModuleId,
+    u256::U256,
+    value::MoveValue,
+};
+use moveos_types::{
+    module_binding::{ModuleBinding, MoveFunctionCaller},
+    move_std::string::MoveString,
+    move_types::FunctionId,
+    moveos_std::tx_context::TxContext,
+    state::{MoveState, MoveStructState, MoveStructType},
+    transaction::FunctionCall,
+};
+use serde::{Deserialize, Serialize};
+
+pub const MODULE_NAME: &IdentStr = ident_str!("scheduler");
+
+/// The task registered to the scheduler, the callback is `callback_address::callback_module::function_name(ctx, task_id)`
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ScheduledTask {
+    pub task_id: u64,
+    pub owner: AccountAddress,
+    pub callback_address: AccountAddress,
+    pub callback_module: MoveString,
+    pub function_name: MoveString,
+    pub interval_seconds: u64,
+    /// The timestamp in seconds of the next run
+    pub next_run_seconds: u64,
+    /// The gas coins paid to the sender of the callback transaction on every run
+    pub fee_per_run: U256,
+    /// The remaining prefunded gas coins of the task
+    pub balance: U256,
+    pub run_count: u64,
+}
+
+impl MoveStructType for ScheduledTask {
+    const ADDRESS: AccountAddress = ROOCH_FRAMEWORK_ADDRESS;
+    const MODULE_NAME: &'static IdentStr = MODULE_NAME;
+    const STRUCT_NAME: &'static IdentStr = ident_str!("ScheduledTask");
+}
+
+impl MoveStructState for ScheduledTask {
+    fn struct_layout() -> move_core_types::value::MoveStructLayout {
+        move_core_types::value::MoveStructLayout::new(vec![
+            move_core_types::value::MoveTypeLayout::U64,
+            move_core_types::value::MoveTypeLayout::Address,
+            move_core_types::value::MoveTypeLayout::Address,
+            MoveString::type_layout(),
+            MoveString::type_layout(),
+            move_core_types::value::MoveTypeLayout::U64,
+            move_core_types::value::MoveTypeLayout::U64,
+            move_core_types::value::MoveTypeLayout::U256,
+            move_core_types::value::MoveTypeLayout::U256,
+            move_core_types::value::MoveTypeLayout::U64,
+        ])
+    }
+}
+
+impl ScheduledTask {
+    /// The call of the callback entry function of the task
+    pub fn callback_call(&self) -> Result<FunctionCall> {
+        let module_id = ModuleId::new(
+            self.callback_address,
+            Identifier::try_from(self.callback_module.clone())?,
+        );
+        let function_name = Identifier::try_from(self.function_name.clone())?;
+        Ok(FunctionCall::new(
+            FunctionId::new(module_id, function_name),
+            vec![],
+            vec![MoveValue::U64(self.task_id).simple_serialize().unwrap()],
+        ))
+    }
+}
+
+/// Rust bindings for RoochFramework scheduler module
+pub struct SchedulerModule<'a> {
+    caller: &'a dyn MoveFunctionCaller,
+}
+
+impl<'a> SchedulerModule<'a> {
+    pub const TASKS_FUNCTION_NAME: &'static IdentStr = ident_str!("tasks");
+    pub const DUE_TASKS_FUNCTION_NAME: &'static IdentStr = ident_str!("due_tasks");
+
+    /// Returns the registered tasks whose id is not less than `start_task_id`, in the order of the task id
+    pub fn tasks(&self, start_task_id: u64, limit: u64) -> Result<Vec<ScheduledTask>> {
+        let call = Self::create_function_call(
+            Self::TASKS_FUNCTION_NAME,
+            vec![],
+            vec![MoveValue::U64(start_task_id), MoveValue::U64(limit)],
+        );
+        self.call_tasks_function(call)
+    }
+
+    /// Returns the tasks which are due and have enough prefund to pay the next run
+    pub fn due_tasks(&self, limit: u64) -> Result<Vec<ScheduledTask>> {
+        let call = Self::create_function_call(
+            Self::DUE_TASKS_FUNCTION_NAME,
+            vec![],
+            vec![MoveValue::U64(limit)],
+        );
+        self.call_tasks_function(call)
+    }
+
+    fn call_tasks_function(&self, call: FunctionCall) -> Result<Vec<ScheduledTask>> {
+        let ctx = TxContext::new_readonly_ctx(AccountAddress::ZERO);
+        let tasks = self
+            .caller
+            .call_function(&ctx, call)?
+            .into_result()
+            .map(|mut values| {
+                let value = values.pop().expect("should have one return value");
+                bcs::from_bytes::<Vec<ScheduledTask>>(&value.value)
+                    .expect("should be a valid Vec<ScheduledTask>")
+            })?;
+        Ok(tasks)
+    }
+}
+
+impl<'a> ModuleBinding<'a> for SchedulerModule<'a> {
+    const MODULE_NAME: &'static IdentStr = MODULE_NAME;
+    const MODULE_ADDRESS: AccountAddress = ROOCH_FRAMEWORK_ADDRESS;
+
+    fn new(caller: &'a impl MoveFunctionCaller) -> Self
+    where
+        Self: Sized,
+    {
+        Self { caller }
+    }
+}
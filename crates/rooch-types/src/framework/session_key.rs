@@ -10,6 +10,7 @@ use moveos_types::{
     module_binding::{ModuleBinding, MoveFunctionCaller},
     move_std::ascii::MoveAsciiString,
     move_std::option::MoveOption,
+    moveos_std::object_id::ObjectID,
     moveos_std::tx_context::TxContext,
     serde::Readable,
     state::{MoveState, MoveStructState, MoveStructType},
@@ -118,6 +119,9 @@ pub struct SessionKey {
     pub create_time: u64,
     pub last_active_time: u64,
     pub max_inactive_interval: u64,
+    /// The max gas the session key can spend in total, 0 means no limit
+    pub max_gas_budget: u64,
+    pub gas_used: u64,
 }
 
 impl SessionKey {
@@ -135,6 +139,14 @@ impl SessionKey {
         now - self.last_active_time > self.max_inactive_interval
     }
 
+    /// The remaining gas budget of the session key, `None` if the gas of the session key is not limited
+    pub fn remaining_gas_budget(&self) -> Option<u64> {
+        if self.max_gas_budget == 0 {
+            return None;
+        }
+        Some(self.max_gas_budget.saturating_sub(self.gas_used))
+    }
+
     pub fn is_scope_match(&self, function: &FunctionCall) -> bool {
         self.scopes
             .iter()
@@ -166,6 +178,8 @@ impl MoveStructState for SessionKey {
             move_core_types::value::MoveTypeLayout::U64,
             move_core_types::value::MoveTypeLayout::U64,
             move_core_types::value::MoveTypeLayout::U64,
+            move_core_types::value::MoveTypeLayout::U64,
+            move_core_types::value::MoveTypeLayout::U64,
         ])
     }
 }
@@ -177,8 +191,14 @@ pub struct SessionKeyModule<'a> {
 
 impl<'a> SessionKeyModule<'a> {
     pub const GET_SESSION_KEY_FUNCTION_NAME: &'static IdentStr = ident_str!("get_session_key");
+    pub const SESSION_KEYS_HANDLE_FUNCTION_NAME: &'static IdentStr =
+        ident_str!("session_keys_handle");
     pub const CREATE_SESSION_KEY_ENTRY_FUNCTION_NAME: &'static IdentStr =
         ident_str!("create_session_key_entry");
+    pub const CREATE_SESSION_KEY_WITH_BUDGET_ENTRY_FUNCTION_NAME: &'static IdentStr =
+        ident_str!("create_session_key_with_budget_entry");
+    pub const REMOVE_SESSION_KEY_ENTRY_FUNCTION_NAME: &'static IdentStr =
+        ident_str!("remove_session_key_entry");
 
     pub fn get_session_key(
         &self,
@@ -211,6 +231,29 @@ impl<'a> SessionKeyModule<'a> {
         Ok(session_key)
     }
 
+    /// The handle of the session keys table of the account, `None` if the account has no session key
+    pub fn session_keys_handle(&self, account_address: AccountAddress) -> Result<Option<ObjectID>> {
+        let call = FunctionCall::new(
+            Self::function_id(Self::SESSION_KEYS_HANDLE_FUNCTION_NAME),
+            vec![],
+            vec![MoveValue::Address(account_address)
+                .simple_serialize()
+                .unwrap()],
+        );
+        let ctx = TxContext::new_readonly_ctx(account_address);
+        let handle = self
+            .caller
+            .call_function(&ctx, call)?
+            .into_result()
+            .map(|mut values| {
+                let value = values.pop().expect("should have one return value");
+                bcs::from_bytes::<MoveOption<ObjectID>>(&value.value)
+                    .expect("should be a valid MoveOption<ObjectID>")
+                    .into()
+            })?;
+        Ok(handle)
+    }
+
     pub fn create_session_key_action(
         authentication_key: Vec<u8>,
         scope: SessionScope,
@@ -228,6 +271,50 @@ impl<'a> SessionKeyModule<'a> {
             ],
         )
     }
+
+    /// Create a session key with multiple scopes, which can spend at most `max_gas_budget` gas in total, 0 means no limit
+    pub fn create_session_key_with_budget_action(
+        authentication_key: Vec<u8>,
+        scopes: Vec<SessionScope>,
+        max_inactive_interval: u64,
+        max_gas_budget: u64,
+    ) -> MoveAction {
+        Self::create_move_action(
+            Self::CREATE_SESSION_KEY_WITH_BUDGET_ENTRY_FUNCTION_NAME,
+            vec![],
+            vec![
+                MoveValue::vector_u8(authentication_key),
+                MoveValue::Vector(
+                    scopes
+                        .iter()
+                        .map(|scope| scope.module_address.to_move_value())
+                        .collect(),
+                ),
+                MoveValue::Vector(
+                    scopes
+                        .iter()
+                        .map(|scope| scope.module_name.to_move_value())
+                        .collect(),
+                ),
+                MoveValue::Vector(
+                    scopes
+                        .iter()
+                        .map(|scope| scope.function_name.to_move_value())
+                        .collect(),
+                ),
+                MoveValue::U64(max_inactive_interval),
+                MoveValue::U64(max_gas_budget),
+            ],
+        )
+    }
+
+    pub fn remove_session_key_action(authentication_key: Vec<u8>) -> MoveAction {
+        Self::create_move_action(
+            Self::REMOVE_SESSION_KEY_ENTRY_FUNCTION_NAME,
+            vec![],
+            vec![MoveValue::vector_u8(authentication_key)],
+        )
+    }
 }
 
 impl<'a> ModuleBinding<'a> for SessionKeyModule<'a> {
@@ -246,7 +333,7 @@ impl<'a> ModuleBinding<'a> for SessionKeyModule<'a> {
 mod tests {
     use std::str::FromStr;
 
-    use super::SessionScope;
+    use super::{SessionKey, SessionScope};
     use move_core_types::{account_address::AccountAddress, ident_str, language_storage::ModuleId};
     use moveos_types::{
         move_std::ascii::MoveAsciiString, move_types::FunctionId, transaction::FunctionCall,
@@ -377,4 +464,22 @@ mod tests {
             .into_iter()
             .for_each(|c| do_test_scope_match(&session_scope, &c.0, c.1));
     }
+
+    #[test]
+    fn test_remaining_gas_budget() {
+        let mut session_key = SessionKey {
+            authentication_key: vec![],
+            scopes: vec![],
+            create_time: 0,
+            last_active_time: 0,
+            max_inactive_interval: 0,
+            max_gas_budget: 0,
+            gas_used: 100,
+        };
+        assert_eq!(session_key.remaining_gas_budget(), None);
+        session_key.max_gas_budget = 150;
+        assert_eq!(session_key.remaining_gas_budget(), Some(50));
+        session_key.gas_used = 200;
+        assert_eq!(session_key.remaining_gas_budget(), Some(0));
+    }
 }
@@ -8,7 +8,10 @@ use move_core_types::{
 };
 use moveos_types::{
     module_binding::{ModuleBinding, MoveFunctionCaller},
-    moveos_std::tx_context::TxContext,
+    moveos_std::{
+        object_id::{self, ObjectID},
+        tx_context::TxContext,
+    },
     state::{MoveStructState, MoveStructType},
     transaction::{FunctionCall, MoveAction},
 };
@@ -21,6 +24,12 @@ pub struct Timestamp {
     pub microseconds: u64,
 }
 
+impl Timestamp {
+    pub fn object_id() -> ObjectID {
+        object_id::named_object_id(&Self::struct_tag())
+    }
+}
+
 impl MoveStructType for Timestamp {
     const ADDRESS: AccountAddress = ROOCH_FRAMEWORK_ADDRESS;
     const MODULE_NAME: &'static IdentStr = MODULE_NAME;
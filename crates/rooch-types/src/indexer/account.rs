@@ -0,0 +1,68 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::address::MultiChainAddress;
+use move_core_types::account_address::AccountAddress;
+use move_core_types::language_storage::StructTag;
+use serde::{Deserialize, Serialize};
+
+/// The number of the objects of a type owned by an account
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct IndexerObjectTypeCount {
+    pub object_type: StructTag,
+    pub count: u64,
+}
+
+/// The account statistics aggregated by the indexer
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct IndexerAccountSummary {
+    pub address: AccountAddress,
+    /// The number of the transactions sent by the account
+    pub tx_count: u64,
+    /// The total gas used by the transactions sent by the account
+    pub gas_used: u64,
+    /// The tx order of the first transaction sent by the account
+    pub first_tx_order: Option<u64>,
+    /// The tx order of the last transaction sent by the account
+    pub last_tx_order: Option<u64>,
+    /// The seconds when the first transaction of the account is indexed
+    pub first_active_at: Option<u64>,
+    /// The seconds when the last transaction of the account is indexed
+    pub last_active_at: Option<u64>,
+    /// The number of the owned objects by object type
+    pub object_counts: Vec<IndexerObjectTypeCount>,
+}
+
+/// The mapping from a multichain address to the Rooch address, indexed from the address mapping of the framework
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct IndexerAddressMapping {
+    pub multichain_address: MultiChainAddress,
+    pub rooch_address: AccountAddress,
+    /// The tx order of the transaction which binds the multichain address
+    pub tx_order: u64,
+    /// The seconds when the mapping is indexed
+    pub created_at: u64,
+}
+
+/// The storage usage of an account accounted by the indexer, the bytes are the sizes of the states in the state tree.
+/// The owned objects and the items of the tables of the owned objects are accounted to the account,
+/// it is the base of the storage rent.
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct IndexerStorageUsage {
+    pub address: AccountAddress,
+    /// The number of the objects owned by the account
+    pub object_count: u64,
+    /// The bytes of the objects owned by the account
+    pub object_bytes: u64,
+    /// The number of the table items of the objects owned by the account
+    pub table_item_count: u64,
+    /// The bytes of the table items of the objects owned by the account
+    pub table_item_bytes: u64,
+}
+
+impl IndexerStorageUsage {
+    /// The total bytes of the states accounted to the account
+    pub fn total_bytes(&self) -> u64 {
+        self.object_bytes.saturating_add(self.table_item_bytes)
+    }
+}
@@ -0,0 +1,63 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::indexer::event_filter::IndexerEventID;
+use anyhow::{bail, Result};
+use move_core_types::account_address::AccountAddress;
+use move_core_types::language_storage::StructTag;
+use move_core_types::u256::U256;
+use moveos_types::h256::H256;
+use moveos_types::moveos_std::object_id::ObjectID;
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+/// Whether the coin is deposited into or withdrawn from the coin store
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, Eq, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum CoinTransferType {
+    Deposit,
+    Withdraw,
+}
+
+impl CoinTransferType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CoinTransferType::Deposit => "deposit",
+            CoinTransferType::Withdraw => "withdraw",
+        }
+    }
+}
+
+impl Display for CoinTransferType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl FromStr for CoinTransferType {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "deposit" => Ok(CoinTransferType::Deposit),
+            "withdraw" => Ok(CoinTransferType::Withdraw),
+            _ => bail!("Invalid coin transfer type: {}", s),
+        }
+    }
+}
+
+/// A deposit or withdrawal of a coin store, indexed from the `DepositEvent` and `WithdrawEvent` of the `coin_store` module
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct IndexerCoinTransfer {
+    /// The id of the event which records the transfer
+    pub indexer_event_id: IndexerEventID,
+    pub coin_store_id: ObjectID,
+    pub coin_type: StructTag,
+    pub transfer_type: CoinTransferType,
+    pub amount: U256,
+    pub tx_hash: H256,
+    /// The sender of the transaction, it is not the owner of the coin store if the coin is sent to others
+    pub sender: AccountAddress,
+    pub created_at: u64,
+}
@@ -7,7 +7,7 @@ use move_core_types::account_address::AccountAddress;
 use move_core_types::language_storage::StructTag;
 use moveos_types::h256::H256;
 use moveos_types::move_types::struct_tag_match;
-use moveos_types::moveos_std::event::EventID;
+use moveos_types::moveos_std::event::{Event, EventID};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
@@ -56,6 +56,29 @@ pub struct IndexerEvent {
 
     /// the event created timestamp on chain
     pub created_at: u64,
+    /// the decoded event data in json format, None if the event is not decoded by the indexer
+    pub event_data_json: Option<String>,
+}
+
+impl IndexerEvent {
+    pub fn new(
+        event: Event,
+        tx_hash: H256,
+        tx_order: u64,
+        sender: AccountAddress,
+        created_at: u64,
+    ) -> Self {
+        IndexerEvent {
+            indexer_event_id: IndexerEventID::new(tx_order, event.event_index),
+            event_id: event.event_id,
+            event_type: event.event_type,
+            event_data: event.event_data,
+            tx_hash,
+            sender,
+            created_at,
+            event_data_json: None,
+        }
+    }
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -67,6 +90,15 @@ pub enum EventFilter {
     Sender(AccountAddress),
     /// Return events emitted by the given transaction hash.
     TxHash(H256),
+    /// Return events emitted by the given transaction in [from_index, to_index) interval of the event index in the transaction,
+    /// the events are in the emission order.
+    TxEventIndexRange {
+        tx_hash: H256,
+        /// left endpoint of the event index, inclusive
+        from_index: u64,
+        /// right endpoint of the event index, exclusive
+        to_index: u64,
+    },
     /// Return events emitted in [start_time, end_time) interval
     TimeRange {
         /// left endpoint of time interval, milliseconds since epoch, inclusive
@@ -81,6 +113,8 @@ pub enum EventFilter {
         /// right endpoint of transaction order, exclusive
         to_order: u64,
     },
+    /// Return events that match all of the given filters, e.g. sender + event type + time range
+    All(Vec<EventFilter>),
 }
 
 impl EventFilter {
@@ -89,6 +123,15 @@ impl EventFilter {
             EventFilter::EventType(event_type) => struct_tag_match(&item.event_type, event_type),
             EventFilter::Sender(sender) => sender == &item.sender,
             EventFilter::TxHash(tx_hash) => tx_hash == &item.tx_hash,
+            EventFilter::TxEventIndexRange {
+                tx_hash,
+                from_index,
+                to_index,
+            } => {
+                tx_hash == &item.tx_hash
+                    && *from_index <= item.indexer_event_id.event_index
+                    && *to_index > item.indexer_event_id.event_index
+            }
             EventFilter::TimeRange {
                 start_time,
                 end_time,
@@ -100,6 +143,7 @@ impl EventFilter {
                 *from_order <= item.indexer_event_id.tx_order
                     && *to_order > item.indexer_event_id.tx_order
             }
+            EventFilter::All(filters) => filters.iter().all(|filter| filter.matches(item)),
         })
     }
 }
@@ -1,6 +1,9 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
+pub mod account;
+pub mod coin_transfer;
 pub mod event_filter;
+pub mod module;
 pub mod state;
 pub mod transaction_filter;
 
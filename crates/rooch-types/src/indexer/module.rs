@@ -0,0 +1,39 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use move_core_types::account_address::AccountAddress;
+use moveos_types::h256::H256;
+use serde::{Deserialize, Serialize};
+
+/// A Move module published on chain, indexed from the module tables of the accounts
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct IndexerModule {
+    /// The address of the module, it is also the publisher of the module
+    pub address: AccountAddress,
+    pub name: String,
+    /// The sha3-256 hash of the module bytecode
+    pub bytecode_hash: H256,
+    /// The digest of the package source, it is not recorded on chain yet, so it is always `None` now
+    pub source_digest: Option<H256>,
+    /// The tx order of the transaction which publishes or upgrades the module
+    pub tx_order: u64,
+    pub state_index: u64,
+    /// The seconds when the module is first indexed
+    pub created_at: u64,
+    /// The seconds when the module is last upgraded
+    pub updated_at: u64,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ModuleFilter {
+    /// Query by the publisher address.
+    Publisher(AccountAddress),
+    /// Return the modules first indexed in [start_time, end_time) interval
+    TimeRange {
+        /// left endpoint of time interval, seconds since epoch, inclusive
+        start_time: u64,
+        /// right endpoint of time interval, seconds since epoch, exclusive
+        end_time: u64,
+    },
+}
@@ -1,12 +1,20 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
 
+use crate::bitcoin::utxo::UTXO;
 use crate::indexer::Filter;
 use anyhow::Result;
 use move_core_types::account_address::AccountAddress;
+use move_core_types::effects::Op;
 use move_core_types::language_storage::{StructTag, TypeTag};
+use move_core_types::u256::U256;
+use moveos_types::h256::H256;
+use moveos_types::move_std::string::MoveString;
+use moveos_types::move_types::struct_tag_match;
+use moveos_types::moveos_std::context::GLOBAL_OBJECT_STORAGE_HANDLE;
 use moveos_types::moveos_std::object_id::ObjectID;
-use moveos_types::state::{StateChangeSet, TableChangeSet};
+use moveos_types::moveos_std::simple_multimap::SimpleMultiMap;
+use moveos_types::state::{State, StateChangeSet, TableChangeSet};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
@@ -17,6 +25,56 @@ pub struct IndexerStateChangeSet {
     pub created_at: u64,
 }
 
+/// The change of a global object in a transaction
+#[derive(Clone, Debug)]
+pub struct IndexerObjectStateChange {
+    pub tx_order: u64,
+    pub tx_hash: H256,
+    pub object_id: ObjectID,
+    /// The T struct tag of the object value, `None` if the object is deleted
+    pub object_type: Option<StructTag>,
+    pub op: Op<State>,
+    pub created_at: u64,
+}
+
+impl IndexerObjectStateChange {
+    /// Split the global object changes from the state change set of a transaction
+    pub fn from_state_change_set(
+        state_change_set: &IndexerStateChangeSet,
+        tx_hash: H256,
+    ) -> Result<Vec<Self>> {
+        let table_change = match state_change_set
+            .state_change_set
+            .changes
+            .get(&GLOBAL_OBJECT_STORAGE_HANDLE)
+        {
+            Some(table_change) => table_change,
+            None => return Ok(vec![]),
+        };
+        table_change
+            .entries
+            .iter()
+            .map(|(key, op)| {
+                let object_id = ObjectID::from_bytes(key.key.as_slice())?;
+                let object_type = match op {
+                    Op::New(value) | Op::Modify(value) => {
+                        Some(value.as_raw_object()?.value.struct_tag)
+                    }
+                    Op::Delete => None,
+                };
+                Ok(Self {
+                    tx_order: state_change_set.tx_order,
+                    tx_hash,
+                    object_id,
+                    object_type,
+                    op: op.clone(),
+                    created_at: state_change_set.created_at,
+                })
+            })
+            .collect()
+    }
+}
+
 #[derive(Clone, Debug)]
 pub struct IndexerTableChangeSet {
     pub tx_order: u64,
@@ -51,6 +109,17 @@ impl IndexerStateID {
             state_index,
         }
     }
+
+    /// Encode the state id to an opaque cursor string, clients should pass it back as is.
+    pub fn to_cursor(&self) -> String {
+        hex::encode(bcs::to_bytes(self).expect("Serialize IndexerStateID should success"))
+    }
+
+    /// Decode the state id from an opaque cursor string which is generated by `to_cursor`.
+    pub fn from_cursor(cursor: &str) -> Result<Self> {
+        let bytes = hex::decode(cursor.strip_prefix("0x").unwrap_or(cursor))?;
+        Ok(bcs::from_bytes(&bytes)?)
+    }
 }
 
 #[derive(Clone, Debug)]
@@ -117,6 +186,35 @@ impl Filter<IndexerGlobalState> for GlobalStateFilter {
     }
 }
 
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ObjectStateFilter {
+    /// Match the changes of the object.
+    ObjectId(ObjectID),
+    /// Match the changes of the objects with the value type.
+    /// The deleted objects do not carry the value type, so they are not matched by this filter.
+    ObjectType(StructTag),
+}
+
+impl ObjectStateFilter {
+    fn try_matches(&self, item: &IndexerObjectStateChange) -> Result<bool> {
+        Ok(match self {
+            ObjectStateFilter::ObjectId(object_id) => object_id == &item.object_id,
+            ObjectStateFilter::ObjectType(object_type) => item
+                .object_type
+                .as_ref()
+                .map(|item_type| struct_tag_match(object_type, item_type))
+                .unwrap_or_default(),
+        })
+    }
+}
+
+impl Filter<IndexerObjectStateChange> for ObjectStateFilter {
+    fn matches(&self, item: &IndexerObjectStateChange) -> bool {
+        self.try_matches(item).unwrap_or_default()
+    }
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub enum TableStateFilter {
@@ -158,3 +256,80 @@ impl Filter<IndexerTableChangeSet> for StateSyncFilter {
         self.try_matches(item).unwrap_or_default()
     }
 }
+
+#[derive(Clone, Debug)]
+pub struct IndexerUTXOState {
+    pub object_id: ObjectID,
+    pub owner: AccountAddress,
+    pub flag: u8,
+    pub txid: AccountAddress,
+    pub vout: u32,
+    pub value: u64,
+    pub seals: SimpleMultiMap<MoveString, ObjectID>,
+    pub tx_order: u64,
+    pub state_index: u64,
+    pub created_at: u64,
+    pub updated_at: u64,
+}
+
+impl IndexerUTXOState {
+    pub fn utxo(&self) -> UTXO {
+        UTXO::new(self.txid, self.vout, self.value, self.seals.clone())
+    }
+}
+
+/// An owner transition of an object, recorded by the indexer
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct IndexerObjectOwnershipChange {
+    pub object_id: ObjectID,
+    pub tx_order: u64,
+    /// `None` if the object is created by the transaction
+    pub old_owner: Option<AccountAddress>,
+    /// `None` if the object is removed by the transaction
+    pub new_owner: Option<AccountAddress>,
+    /// The seconds when the change is indexed, `None` if the change is recorded before the ownership history is indexed
+    pub created_at: Option<u64>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum UTXOFilter {
+    /// Query by owner.
+    Owner(AccountAddress),
+    /// Query by the outpoint of the UTXO.
+    OutPoint { txid: AccountAddress, vout: u32 },
+    /// Query by object id.
+    ObjectId(ObjectID),
+    /// Query all UTXOs.
+    All,
+}
+
+impl UTXOFilter {
+    fn try_matches(&self, item: &IndexerUTXOState) -> Result<bool> {
+        Ok(match self {
+            UTXOFilter::Owner(owner) => owner == &item.owner,
+            UTXOFilter::OutPoint { txid, vout } => txid == &item.txid && vout == &item.vout,
+            UTXOFilter::ObjectId(object_id) => object_id == &item.object_id,
+            UTXOFilter::All => true,
+        })
+    }
+}
+
+impl Filter<IndexerUTXOState> for UTXOFilter {
+    fn matches(&self, item: &IndexerUTXOState) -> bool {
+        self.try_matches(item).unwrap_or_default()
+    }
+}
+
+/// The coin metadata cached by the indexer, it is updated when the CoinInfo object changes
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct IndexerCoinInfo {
+    pub coin_type: StructTag,
+    pub name: String,
+    pub symbol: String,
+    pub icon_url: Option<String>,
+    pub decimals: u8,
+    pub supply: U256,
+    /// The tx order of the last update of the CoinInfo object
+    pub tx_order: u64,
+}
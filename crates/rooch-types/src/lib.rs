@@ -17,7 +17,10 @@ pub mod indexer;
 pub mod into_address;
 pub mod key_struct;
 pub mod multichain_id;
+pub mod multisig;
+pub mod schnorr;
 pub mod sequencer;
 pub mod stdlib_version;
 pub mod test_utils;
 pub mod transaction;
+pub mod webauthn;
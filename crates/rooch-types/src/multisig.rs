@@ -0,0 +1,305 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    address::RoochAddress,
+    authentication_key::AuthenticationKey,
+    crypto::{DefaultHash, PublicKey},
+    error::RoochError,
+    framework::auth_validator::BuiltinAuthValidator,
+};
+use fastcrypto::{
+    ed25519::{Ed25519PublicKey, Ed25519Signature},
+    encoding::{Base64, Encoding},
+    hash::HashFunction,
+    secp256k1::{Secp256k1PublicKey, Secp256k1Signature},
+    traits::{ToFromBytes, VerifyingKey},
+};
+use moveos_types::{h256::H256, serde::Readable};
+use serde::{Deserialize, Serialize};
+use serde_with::{serde_as, Bytes};
+use std::{fmt, str::FromStr};
+
+/// The max number of the public keys in a multisig account, keep the same as the multisig_validator module.
+pub const MAX_MULTISIG_PUBLIC_KEYS: usize = 16;
+
+/// A member public key of the multisig account.
+/// It is encoded as `flag || public key`, the flags are defined by the multisig_validator module.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum MultisigMemberPublicKey {
+    Ed25519(Ed25519PublicKey),
+    Secp256k1(Secp256k1PublicKey),
+}
+
+impl MultisigMemberPublicKey {
+    const ED25519_FLAG: u8 = 0x00;
+    const SECP256K1_FLAG: u8 = 0x01;
+
+    pub fn flag(&self) -> u8 {
+        match self {
+            MultisigMemberPublicKey::Ed25519(_) => Self::ED25519_FLAG,
+            MultisigMemberPublicKey::Secp256k1(_) => Self::SECP256K1_FLAG,
+        }
+    }
+
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = vec![self.flag()];
+        match self {
+            MultisigMemberPublicKey::Ed25519(pk) => bytes.extend_from_slice(pk.as_ref()),
+            MultisigMemberPublicKey::Secp256k1(pk) => bytes.extend_from_slice(pk.as_ref()),
+        }
+        bytes
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, RoochError> {
+        let (flag, key_bytes) = bytes.split_first().ok_or_else(|| {
+            RoochError::KeyConversionError("Empty multisig member public key".to_owned())
+        })?;
+        let invalid_key = |e| RoochError::KeyConversionError(format!("Invalid public key: {}", e));
+        match *flag {
+            Self::ED25519_FLAG => Ok(MultisigMemberPublicKey::Ed25519(
+                Ed25519PublicKey::from_bytes(key_bytes).map_err(invalid_key)?,
+            )),
+            Self::SECP256K1_FLAG => Ok(MultisigMemberPublicKey::Secp256k1(
+                Secp256k1PublicKey::from_bytes(key_bytes).map_err(invalid_key)?,
+            )),
+            _ => Err(RoochError::KeyConversionError(format!(
+                "Invalid multisig member public key flag: {}",
+                flag
+            ))),
+        }
+    }
+
+    /// Verify the raw signature(without flag and public key) of the message.
+    /// The Secp256k1 signature is signed on the SHA256 hash of the message.
+    pub fn verify(&self, msg: &[u8], signature: &[u8]) -> Result<(), RoochError> {
+        let result = match self {
+            MultisigMemberPublicKey::Ed25519(pk) => Ed25519Signature::from_bytes(signature)
+                .and_then(|signature| pk.verify(msg, &signature)),
+            MultisigMemberPublicKey::Secp256k1(pk) => Secp256k1Signature::from_bytes(signature)
+                .and_then(|signature| pk.verify(msg, &signature)),
+        };
+        result.map_err(|e| RoochError::InvalidSignature {
+            error: format!("Fail to verify multisig member signature {}", e),
+        })
+    }
+}
+
+impl TryFrom<&PublicKey> for MultisigMemberPublicKey {
+    type Error = RoochError;
+
+    fn try_from(public_key: &PublicKey) -> Result<Self, Self::Error> {
+        match public_key {
+            PublicKey::Ed25519(pk) => Ok(MultisigMemberPublicKey::Ed25519(
+                Ed25519PublicKey::from_bytes(pk.as_ref()).map_err(|e| {
+                    RoochError::KeyConversionError(format!("Invalid public key: {}", e))
+                })?,
+            )),
+            PublicKey::Schnorr(_) => Err(RoochError::KeyConversionError(
+                "Schnorr public key is not supported by the multisig account".to_owned(),
+            )),
+        }
+    }
+}
+
+impl fmt::Display for MultisigMemberPublicKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", Base64::encode(self.to_bytes()))
+    }
+}
+
+/// Decode from `flag || public key` in Base64, the Ed25519 member public key has the same encoding as the Rooch public key.
+impl FromStr for MultisigMemberPublicKey {
+    type Err = RoochError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let bytes = Base64::decode(s).map_err(|e| {
+            RoochError::KeyConversionError(format!("Invalid base64 public key: {}", e))
+        })?;
+        Self::from_bytes(&bytes)
+    }
+}
+
+/// The public key of the k-of-n multisig account.
+/// The account is controlled by the `public_keys`, and a transaction is valid if it is signed by at least `threshold` of them.
+/// The BCS layout is the same as the first two fields of the `MultisigPayload` in the multisig_validator module.
+#[serde_as]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct MultisigPublicKey {
+    threshold: u8,
+    #[serde_as(as = "Vec<Readable<Base64, Bytes>>")]
+    public_keys: Vec<Vec<u8>>,
+}
+
+impl MultisigPublicKey {
+    pub fn new(
+        public_keys: Vec<MultisigMemberPublicKey>,
+        threshold: u8,
+    ) -> Result<Self, RoochError> {
+        let multisig_public_key = Self {
+            threshold,
+            public_keys: public_keys
+                .iter()
+                .map(MultisigMemberPublicKey::to_bytes)
+                .collect(),
+        };
+        multisig_public_key.check()?;
+        Ok(multisig_public_key)
+    }
+
+    /// Check the threshold and the public keys, keep the same as the `check_public_keys` in the multisig_validator module.
+    pub fn check(&self) -> Result<(), RoochError> {
+        if self.public_keys.len() > MAX_MULTISIG_PUBLIC_KEYS {
+            return Err(RoochError::KeyConversionError(format!(
+                "Too many multisig public keys: {}, the max is {}",
+                self.public_keys.len(),
+                MAX_MULTISIG_PUBLIC_KEYS
+            )));
+        }
+        if self.threshold == 0 || self.threshold as usize > self.public_keys.len() {
+            return Err(RoochError::KeyConversionError(format!(
+                "Invalid multisig threshold: {}, it should be in [1, {}]",
+                self.threshold,
+                self.public_keys.len()
+            )));
+        }
+        for (i, public_key) in self.public_keys.iter().enumerate() {
+            MultisigMemberPublicKey::from_bytes(public_key)?;
+            if self.public_keys[i + 1..].contains(public_key) {
+                return Err(RoochError::KeyConversionError(format!(
+                    "Duplicate multisig public key: {}",
+                    Base64::encode(public_key)
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    pub fn threshold(&self) -> u8 {
+        self.threshold
+    }
+
+    /// The encoded member public keys, `flag || public key`.
+    pub fn public_keys(&self) -> &[Vec<u8>] {
+        &self.public_keys
+    }
+
+    pub fn member(&self, index: u8) -> Result<MultisigMemberPublicKey, RoochError> {
+        let public_key = self.public_keys.get(index as usize).ok_or_else(|| {
+            RoochError::KeyConversionError(format!("Invalid multisig signer index: {}", index))
+        })?;
+        MultisigMemberPublicKey::from_bytes(public_key)
+    }
+
+    /// Return the index of the member public key in the multisig public key.
+    pub fn index_of(&self, public_key: &MultisigMemberPublicKey) -> Option<u8> {
+        let public_key = public_key.to_bytes();
+        self.public_keys
+            .iter()
+            .position(|pk| pk == &public_key)
+            .map(|index| index as u8)
+    }
+
+    /// The authentication key is the hash of the validator flag, the threshold and the BCS bytes of the public keys.
+    pub fn authentication_key(&self) -> AuthenticationKey {
+        AuthenticationKey::new(self.hash().to_vec())
+    }
+
+    /// The address of the multisig account is the same as its authentication key.
+    pub fn address(&self) -> RoochAddress {
+        RoochAddress(H256(self.hash()))
+    }
+
+    fn hash(&self) -> [u8; 32] {
+        let mut hasher = DefaultHash::default();
+        hasher.update([BuiltinAuthValidator::Multisig.flag(), self.threshold]);
+        hasher.update(
+            bcs::to_bytes(&self.public_keys).expect("Serialize public keys should success"),
+        );
+        hasher.finalize().digest
+    }
+}
+
+impl fmt::Display for MultisigPublicKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            Base64::encode(
+                bcs::to_bytes(self).expect("Serialize multisig public key should success")
+            )
+        )
+    }
+}
+
+/// Decode from the BCS bytes in Base64.
+impl FromStr for MultisigPublicKey {
+    type Err = RoochError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let bytes = Base64::decode(s).map_err(|e| {
+            RoochError::KeyConversionError(format!("Invalid base64 multisig public key: {}", e))
+        })?;
+        let multisig_public_key: Self = bcs::from_bytes(&bytes).map_err(|e| {
+            RoochError::KeyConversionError(format!("Invalid multisig public key: {}", e))
+        })?;
+        multisig_public_key.check()?;
+        Ok(multisig_public_key)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fastcrypto::{
+        ed25519::Ed25519KeyPair,
+        secp256k1::Secp256k1KeyPair,
+        traits::{KeyPair, Signer},
+    };
+    use rand::{rngs::StdRng, SeedableRng};
+
+    fn ed25519_members(rng: &mut StdRng, n: usize) -> Vec<Ed25519KeyPair> {
+        (0..n).map(|_| Ed25519KeyPair::generate(rng)).collect()
+    }
+
+    #[test]
+    fn test_multisig_public_key_check() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let public_keys = ed25519_members(&mut rng, 3)
+            .iter()
+            .map(|kp| MultisigMemberPublicKey::Ed25519(kp.public().clone()))
+            .collect::<Vec<_>>();
+
+        assert!(MultisigPublicKey::new(public_keys.clone(), 0).is_err());
+        assert!(MultisigPublicKey::new(public_keys.clone(), 4).is_err());
+        let mut duplicated = public_keys.clone();
+        duplicated.push(public_keys[0].clone());
+        assert!(MultisigPublicKey::new(duplicated, 2).is_err());
+
+        let multisig_public_key = MultisigPublicKey::new(public_keys.clone(), 2).unwrap();
+        assert_eq!(multisig_public_key.index_of(&public_keys[2]), Some(2));
+        let decoded = MultisigPublicKey::from_str(&multisig_public_key.to_string()).unwrap();
+        assert_eq!(decoded, multisig_public_key);
+        assert_eq!(decoded.address(), multisig_public_key.address());
+    }
+
+    #[test]
+    fn test_multisig_member_verify() {
+        let mut rng = StdRng::seed_from_u64(1);
+        let msg = b"multisig";
+        let ed25519_kp = Ed25519KeyPair::generate(&mut rng);
+        let secp256k1_kp = Secp256k1KeyPair::generate(&mut rng);
+        let ed25519_pk = MultisigMemberPublicKey::Ed25519(ed25519_kp.public().clone());
+        let secp256k1_pk = MultisigMemberPublicKey::Secp256k1(secp256k1_kp.public().clone());
+
+        let ed25519_sig: Ed25519Signature = ed25519_kp.sign(msg);
+        let secp256k1_sig: Secp256k1Signature = secp256k1_kp.sign(msg);
+        ed25519_pk.verify(msg, ed25519_sig.as_ref()).unwrap();
+        secp256k1_pk.verify(msg, secp256k1_sig.as_ref()).unwrap();
+        assert!(ed25519_pk.verify(b"other", ed25519_sig.as_ref()).is_err());
+        assert!(secp256k1_pk.verify(msg, ed25519_sig.as_ref()).is_err());
+
+        let decoded = MultisigMemberPublicKey::from_str(&secp256k1_pk.to_string()).unwrap();
+        assert_eq!(decoded, secp256k1_pk);
+    }
+}
@@ -0,0 +1,282 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    address::{BitcoinAddress, EthereumAddress, RoochAddress},
+    crypto::{DefaultHash, PublicKey, RoochSignature, Signature},
+    error::{RoochError, RoochResult},
+    framework::auth_validator::BuiltinAuthValidator,
+};
+use bitcoin::secp256k1::{
+    rand::thread_rng, schnorr, Keypair, Message, Parity, Secp256k1, XOnlyPublicKey,
+};
+use derive_more::{AsMut, AsRef};
+use fastcrypto::{
+    encoding::Base64,
+    error::FastCryptoError,
+    hash::{HashFunction, Sha256},
+    secp256k1::Secp256k1PublicKey,
+    traits::{Signer, ToFromBytes},
+};
+use moveos_types::serde::Readable;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use serde_with::{serde_as, Bytes};
+
+pub const SCHNORR_PRIVATE_KEY_LENGTH: usize = 32;
+pub const SCHNORR_PUBLIC_KEY_LENGTH: usize = 32;
+pub const SCHNORR_SIGNATURE_LENGTH: usize = 64;
+
+/// The BIP-340 signature is signed on a 32 bytes digest,
+/// the message of other length is hashed with SHA256 first.
+fn to_message(msg: &[u8]) -> Message {
+    let digest = if msg.len() == 32 {
+        msg.to_vec()
+    } else {
+        Sha256::digest(msg).digest.to_vec()
+    };
+    Message::from_digest_slice(&digest).expect("The digest should be 32 bytes")
+}
+
+/// The BIP-340 Schnorr key pair over the secp256k1 curve, the same key as the Bitcoin taproot key.
+#[derive(Debug, PartialEq, Eq)]
+pub struct SchnorrKeyPair {
+    keypair: Keypair,
+}
+
+impl SchnorrKeyPair {
+    pub fn generate() -> Self {
+        Self {
+            keypair: Keypair::new(&Secp256k1::new(), &mut thread_rng()),
+        }
+    }
+
+    pub fn from_private_key_bytes(bytes: &[u8]) -> Result<Self, RoochError> {
+        let keypair = Keypair::from_seckey_slice(&Secp256k1::new(), bytes).map_err(|e| {
+            RoochError::KeyConversionError(format!("Invalid Schnorr private key: {}", e))
+        })?;
+        Ok(Self { keypair })
+    }
+
+    pub fn private_key_bytes(&self) -> [u8; SCHNORR_PRIVATE_KEY_LENGTH] {
+        self.keypair.secret_bytes()
+    }
+
+    pub fn public(&self) -> SchnorrPublicKey {
+        SchnorrPublicKey(self.keypair.x_only_public_key().0.serialize())
+    }
+
+    pub fn copy(&self) -> Self {
+        Self {
+            keypair: self.keypair,
+        }
+    }
+
+    pub fn sign_raw(&self, msg: &[u8]) -> schnorr::Signature {
+        Secp256k1::new().sign_schnorr(&to_message(msg), &self.keypair)
+    }
+}
+
+impl Signer<Signature> for SchnorrKeyPair {
+    fn sign(&self, msg: &[u8]) -> Signature {
+        SchnorrRoochSignature::new(self, msg).into()
+    }
+}
+
+/// The x-only public key of BIP-340
+#[serde_as]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema)]
+pub struct SchnorrPublicKey(
+    #[schemars(with = "Base64")]
+    #[serde_as(as = "Readable<Base64, Bytes>")]
+    pub [u8; SCHNORR_PUBLIC_KEY_LENGTH],
+);
+
+impl SchnorrPublicKey {
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, RoochError> {
+        let public_key = XOnlyPublicKey::from_slice(bytes).map_err(|e| {
+            RoochError::KeyConversionError(format!("Invalid Schnorr public key: {}", e))
+        })?;
+        Ok(Self(public_key.serialize()))
+    }
+
+    /// Verify the raw 64 bytes signature of the message.
+    pub fn verify(&self, msg: &[u8], signature: &[u8]) -> Result<(), RoochError> {
+        let invalid_signature = |e: bitcoin::secp256k1::Error| RoochError::InvalidSignature {
+            error: format!("Fail to verify Schnorr signature {}", e),
+        };
+        let public_key = XOnlyPublicKey::from_slice(&self.0).map_err(invalid_signature)?;
+        let signature = schnorr::Signature::from_slice(signature).map_err(invalid_signature)?;
+        Secp256k1::verification_only()
+            .verify_schnorr(&signature, &to_message(msg), &public_key)
+            .map_err(invalid_signature)
+    }
+
+    /// The BIP-86 taproot address of the key, the key is tweaked without the script tree
+    pub fn taproot_address(&self) -> Result<BitcoinAddress, RoochError> {
+        let internal_key = self.x_only_public_key()?;
+        let address = bitcoin::Address::p2tr(
+            &Secp256k1::verification_only(),
+            internal_key,
+            None,
+            bitcoin::Network::Bitcoin,
+        );
+        Ok(BitcoinAddress::from(address))
+    }
+
+    /// The Ethereum address of the key, the full public key is the one with the even y coordinate as BIP-340
+    pub fn ethereum_address(&self) -> Result<EthereumAddress, RoochError> {
+        let public_key = self.x_only_public_key()?.public_key(Parity::Even);
+        let public_key = Secp256k1PublicKey::from_bytes(&public_key.serialize())
+            .map_err(|e| RoochError::KeyConversionError(e.to_string()))?;
+        Ok(EthereumAddress::from(public_key))
+    }
+
+    fn x_only_public_key(&self) -> Result<XOnlyPublicKey, RoochError> {
+        XOnlyPublicKey::from_slice(&self.0).map_err(|e| {
+            RoochError::KeyConversionError(format!("Invalid Schnorr public key: {}", e))
+        })
+    }
+}
+
+impl AsRef<[u8]> for SchnorrPublicKey {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+/// The Schnorr signature in form of `flag || signature || x-only public key`,
+/// the same layout as the authenticator payload of the schnorr_validator module.
+#[serde_as]
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, PartialEq, Eq, Hash, AsRef, AsMut)]
+#[as_ref(forward)]
+#[as_mut(forward)]
+pub struct SchnorrRoochSignature(
+    #[schemars(with = "Base64")]
+    #[serde_as(as = "Readable<Base64, Bytes>")]
+    [u8; SCHNORR_SIGNATURE_LENGTH + SCHNORR_PUBLIC_KEY_LENGTH + 1],
+);
+
+impl SchnorrRoochSignature {
+    pub const LENGTH: usize = SCHNORR_SIGNATURE_LENGTH + SCHNORR_PUBLIC_KEY_LENGTH + 1;
+
+    pub fn new(kp: &SchnorrKeyPair, message: &[u8]) -> Self {
+        let sig = kp.sign_raw(message);
+        let mut bytes = [0; Self::LENGTH];
+        bytes[0] = BuiltinAuthValidator::Schnorr.flag();
+        bytes[1..1 + SCHNORR_SIGNATURE_LENGTH].copy_from_slice(&sig[..]);
+        bytes[1 + SCHNORR_SIGNATURE_LENGTH..].copy_from_slice(kp.public().as_ref());
+        Self(bytes)
+    }
+
+    pub fn public_key(&self) -> Result<SchnorrPublicKey, RoochError> {
+        SchnorrPublicKey::from_bytes(self.public_key_bytes())
+    }
+}
+
+impl ToFromBytes for SchnorrRoochSignature {
+    fn from_bytes(bytes: &[u8]) -> Result<Self, FastCryptoError> {
+        if bytes.len() != Self::LENGTH {
+            return Err(FastCryptoError::InputLengthWrong(Self::LENGTH));
+        }
+        let mut sig_bytes = [0; Self::LENGTH];
+        sig_bytes.copy_from_slice(bytes);
+        Ok(Self(sig_bytes))
+    }
+}
+
+impl RoochSignature for SchnorrRoochSignature {
+    fn signature_bytes(&self) -> &[u8] {
+        &self.0[1..1 + SCHNORR_SIGNATURE_LENGTH]
+    }
+
+    fn public_key_bytes(&self) -> &[u8] {
+        &self.0[1 + SCHNORR_SIGNATURE_LENGTH..]
+    }
+
+    fn auth_validator(&self) -> BuiltinAuthValidator {
+        BuiltinAuthValidator::Schnorr
+    }
+
+    fn verify_secure<T>(&self, value: &T, author: RoochAddress) -> RoochResult<()>
+    where
+        T: Serialize,
+    {
+        let mut hasher = DefaultHash::default();
+        hasher.update(&bcs::to_bytes(&value).expect("Message serialization should not fail"));
+        let digest = hasher.finalize().digest;
+
+        let public_key = self.public_key()?;
+        let received_addr = RoochAddress::from(&PublicKey::Schnorr(public_key.clone()));
+        if received_addr != author {
+            return Err(RoochError::IncorrectSigner {
+                error: format!("Signature verify_secure() failure. Author is {author}, received address is {received_addr}")
+            });
+        }
+        public_key.verify(&digest, self.signature_bytes())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // BIP-340 test vector 1
+    #[test]
+    fn test_verify_bip340_vector() {
+        let public_key = SchnorrPublicKey::from_bytes(
+            &hex::decode("dff1d77f2a671c5f36183726db2341be58feae1da2deced843240f7b502ba659")
+                .unwrap(),
+        )
+        .unwrap();
+        let msg = hex::decode("243f6a8885a308d313198a2e03707344a4093822299f31d0082efa98ec4e6c89")
+            .unwrap();
+        let signature = hex::decode("6896bd60eeae296db48a229ff71dfe071bde413e6d43f917dc8dcf8c78de33418906d11ac976abccb20b091292bff4ea897efcb639ea871cfa95f6de339e4b0a").unwrap();
+        assert!(public_key.verify(&msg, &signature).is_ok());
+        assert!(public_key.verify(&[0u8; 32], &signature).is_err());
+    }
+
+    #[test]
+    fn test_sign_and_verify() {
+        let kp = SchnorrKeyPair::from_private_key_bytes(&[1u8; 32]).unwrap();
+        let msg = [2u8; 32];
+        let signature = SchnorrRoochSignature::new(&kp, &msg);
+        assert_eq!(signature.public_key().unwrap(), kp.public());
+        assert!(kp
+            .public()
+            .verify(&msg, signature.signature_bytes())
+            .is_ok());
+
+        let restored = SchnorrKeyPair::from_private_key_bytes(&kp.private_key_bytes()).unwrap();
+        assert_eq!(restored, kp);
+    }
+
+    // The first receiving address of the BIP-86 test vectors
+    #[test]
+    fn test_taproot_address() {
+        let public_key = SchnorrPublicKey::from_bytes(
+            &hex::decode("cc8a4bc64d897bddc5fbc2f670f7a8ba0b386779106cf1223c6fc5d7cd6fc115")
+                .unwrap(),
+        )
+        .unwrap();
+        let address = public_key.taproot_address().unwrap();
+        assert_eq!(
+            address
+                .format(crate::bitcoin::network::Network::NetworkBitcoin.to_num())
+                .unwrap(),
+            "bc1p5cyxnuxmeuwuvkwfem96lqzszd02n6xdcjrs20cac6yqjjwudpxqkedrcr"
+        );
+    }
+
+    // The public key of the private key 1 is the generator point, its y coordinate is even
+    #[test]
+    fn test_ethereum_address() {
+        let mut private_key = [0u8; 32];
+        private_key[31] = 1;
+        let kp = SchnorrKeyPair::from_private_key_bytes(&private_key).unwrap();
+        assert_eq!(
+            kp.public().ethereum_address().unwrap().to_string(),
+            "0x7e5f4552091a69125d5dfcb7b8c2659029395bdf"
+        );
+    }
+}
@@ -1,6 +1,9 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
 
+use crate::address::RoochAddress;
+use crate::transaction::{AbstractTransaction, TransactionSequenceInfo, TypedTransaction};
+use moveos_types::h256::H256;
 use serde::{Deserialize, Serialize};
 use std::fmt;
 use std::fmt::Debug;
@@ -21,3 +24,103 @@ impl SequencerOrder {
         SequencerOrder { last_order }
     }
 }
+
+/// The write-ahead log entry of a tx order assigned by the sequencer, it is persisted before the
+/// sequence result is committed, so the ordering decision could be replayed after a crash.
+#[derive(Eq, PartialEq, Deserialize, Serialize, Clone, Debug)]
+pub struct SequencerWALEntry {
+    pub tx_hash: H256,
+    pub tx: TypedTransaction,
+    pub tx_sequence_info: TransactionSequenceInfo,
+}
+
+impl SequencerWALEntry {
+    pub fn new(tx: TypedTransaction, tx_sequence_info: TransactionSequenceInfo) -> Self {
+        Self {
+            tx_hash: tx.tx_hash(),
+            tx,
+            tx_sequence_info,
+        }
+    }
+
+    pub fn tx_order(&self) -> u64 {
+        self.tx_sequence_info.tx_order
+    }
+
+    /// Check the entry is not corrupted or forged, the recorded hash must be the hash of the transaction,
+    /// and the tx order must be signed by the `sequencer`.
+    pub fn check_integrity(&self, sequencer: RoochAddress) -> anyhow::Result<()> {
+        let tx_hash = self.tx.tx_hash();
+        if tx_hash != self.tx_hash {
+            anyhow::bail!(
+                "Sequencer WAL entry of tx order {} is corrupted, tx hash mismatch, expect: {:?}, actual: {:?}",
+                self.tx_order(),
+                self.tx_hash,
+                tx_hash
+            );
+        }
+        self.tx_sequence_info
+            .verify_order_signature(self.tx_hash, sequencer)
+            .map_err(|e| {
+                anyhow::anyhow!(
+                    "Sequencer WAL entry of tx order {} is not signed by the sequencer {}: {}",
+                    self.tx_order(),
+                    sequencer,
+                    e
+                )
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::crypto::{RoochKeyPair, Signature};
+    use crate::transaction::rooch::RoochTransaction;
+    use fastcrypto::ed25519::Ed25519KeyPair;
+    use fastcrypto::traits::KeyPair;
+
+    fn signed_entry(tx_order: u64, kp: &RoochKeyPair) -> SequencerWALEntry {
+        let tx = TypedTransaction::Rooch(RoochTransaction::mock());
+        let witness_hash = TransactionSequenceInfo::witness_hash(tx.tx_hash(), tx_order);
+        let tx_sequence_info = TransactionSequenceInfo::new(
+            tx_order,
+            Signature::new_hashed(&witness_hash.0, kp).into(),
+            H256::random(),
+        );
+        SequencerWALEntry::new(tx, tx_sequence_info)
+    }
+
+    #[test]
+    fn test_sequencer_wal_entry_integrity() {
+        let kp = RoochKeyPair::Ed25519(Ed25519KeyPair::generate(&mut rand::thread_rng()));
+        let sequencer: RoochAddress = (&kp.public()).into();
+        let entry = signed_entry(1, &kp);
+        assert_eq!(entry.tx_order(), 1);
+        entry.check_integrity(sequencer).unwrap();
+
+        let entry_bytes = bcs::to_bytes(&entry).unwrap();
+        let decoded: SequencerWALEntry = bcs::from_bytes(&entry_bytes).unwrap();
+        assert_eq!(decoded, entry);
+
+        // The entry is signed by another key
+        assert!(entry.check_integrity(RoochAddress::random()).is_err());
+
+        // The tx order is changed
+        let mut reordered = entry.clone();
+        reordered.tx_sequence_info.tx_order = 2;
+        assert!(reordered.check_integrity(sequencer).is_err());
+
+        // The transaction is replaced, the recorded hash mismatches
+        let mut corrupted = entry.clone();
+        corrupted.tx_hash = H256::random();
+        assert!(corrupted.check_integrity(sequencer).is_err());
+
+        // The transaction and its hash are replaced, the signature mismatches
+        let mut forged = entry;
+        let other = signed_entry(1, &kp);
+        forged.tx_hash = other.tx_hash;
+        forged.tx = other.tx;
+        assert!(forged.check_integrity(sequencer).is_err());
+    }
+}
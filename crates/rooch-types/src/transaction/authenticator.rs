@@ -17,9 +17,15 @@ use proptest::{collection::vec, prelude::*};
 #[cfg(any(test, feature = "fuzzing"))]
 use rand::{rngs::StdRng, SeedableRng};
 use serde::{Deserialize, Serialize};
-use std::{fmt, str::FromStr};
+use std::{collections::BTreeMap, fmt, str::FromStr};
 
-use crate::{crypto::Signature, framework::auth_validator::BuiltinAuthValidator};
+use crate::{
+    crypto::{RoochSignature, Signature},
+    error::RoochError,
+    framework::auth_validator::BuiltinAuthValidator,
+    multisig::MultisigPublicKey,
+    webauthn,
+};
 
 /// A `Authenticator` is an an abstraction of a account authenticator.
 /// It is a part of `AccountAbstraction`
@@ -35,8 +41,9 @@ pub struct RoochAuthenticator {
 }
 
 impl BuiltinAuthenticator for RoochAuthenticator {
+    /// The Ed25519 signature is validated by the native validator, and the Schnorr signature by the schnorr validator
     fn auth_validator_id(&self) -> u64 {
-        BuiltinAuthValidator::Rooch.flag().into()
+        self.signature.auth_validator().flag().into()
     }
     fn payload(&self) -> Vec<u8> {
         self.signature.as_ref().to_vec()
@@ -65,6 +72,106 @@ prop_compose! {
     }
 }
 
+/// The k-of-n multisig authenticator, the BCS layout is the same as the `MultisigPayload` in the multisig_validator module.
+/// The `signatures` are the raw signatures of the tx hash, signed by the members at the `signer_indexes`.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MultisigAuthenticator {
+    pub public_key: MultisigPublicKey,
+    pub signer_indexes: Vec<u8>,
+    pub signatures: Vec<Vec<u8>>,
+}
+
+impl MultisigAuthenticator {
+    /// Create a multisig authenticator from the signatures keyed by the signer index,
+    /// the signatures are verified against the `tx_hash`, and must reach the threshold.
+    pub fn new(
+        public_key: MultisigPublicKey,
+        tx_hash: &[u8],
+        signatures: BTreeMap<u8, Vec<u8>>,
+    ) -> Result<Self, RoochError> {
+        if signatures.len() < public_key.threshold() as usize {
+            return Err(RoochError::InvalidSignature {
+                error: format!(
+                    "Not enough multisig signatures, got {}, the threshold is {}",
+                    signatures.len(),
+                    public_key.threshold()
+                ),
+            });
+        }
+        for (signer_index, signature) in signatures.iter() {
+            public_key
+                .member(*signer_index)?
+                .verify(tx_hash, signature)?;
+        }
+        // The BTreeMap keeps the signer indexes in ascending order, which is required by the validator.
+        let (signer_indexes, signatures) = signatures.into_iter().unzip();
+        Ok(Self {
+            public_key,
+            signer_indexes,
+            signatures,
+        })
+    }
+}
+
+impl BuiltinAuthenticator for MultisigAuthenticator {
+    fn auth_validator_id(&self) -> u64 {
+        BuiltinAuthValidator::Multisig.flag().into()
+    }
+    fn payload(&self) -> Vec<u8> {
+        bcs::to_bytes(self).expect("Serialize multisig authenticator should success")
+    }
+}
+
+/// The WebAuthn(passkey) authenticator, the BCS layout is the same as the `WebauthnPayload` in the webauthn_validator module.
+/// The `signature` is the `r || s` signature of the assertion, and the challenge in the `client_data_json` is the tx hash.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WebauthnAuthenticator {
+    pub signature: Vec<u8>,
+    pub public_key: Vec<u8>,
+    pub authenticator_data: Vec<u8>,
+    pub client_data_json: Vec<u8>,
+}
+
+impl WebauthnAuthenticator {
+    /// Create a WebAuthn authenticator from the assertion response of the passkey, the DER signature is converted
+    /// and the assertion is verified against the `tx_hash` and the `rp_id` and `origin` of the registered passkey.
+    pub fn new(
+        public_key: Vec<u8>,
+        tx_hash: &[u8],
+        authenticator_data: Vec<u8>,
+        client_data_json: Vec<u8>,
+        der_signature: &[u8],
+        rp_id: &str,
+        origin: &str,
+    ) -> Result<Self, RoochError> {
+        let signature = webauthn::signature_from_der(der_signature)?;
+        webauthn::verify_assertion(
+            &signature,
+            &public_key,
+            &authenticator_data,
+            &client_data_json,
+            tx_hash,
+            &webauthn::rp_id_hash(rp_id),
+            origin,
+        )?;
+        Ok(Self {
+            signature,
+            public_key,
+            authenticator_data,
+            client_data_json,
+        })
+    }
+}
+
+impl BuiltinAuthenticator for WebauthnAuthenticator {
+    fn auth_validator_id(&self) -> u64 {
+        BuiltinAuthValidator::Webauthn.flag().into()
+    }
+    fn payload(&self) -> Vec<u8> {
+        bcs::to_bytes(self).expect("Serialize webauthn authenticator should success")
+    }
+}
+
 impl<T> From<T> for Authenticator
 where
     T: BuiltinAuthenticator,
@@ -349,6 +349,10 @@ impl AbstractTransaction for EthereumTransaction {
         self.encode().len() as u64
     }
 
+    fn expiration_timestamp_secs(&self) -> Option<u64> {
+        None
+    }
+
     fn construct_moveos_transaction(
         self,
         resolved_sender: AccountAddress,
@@ -1,14 +1,18 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
 
-use self::{authenticator::Authenticator, ethereum::EthereumTransaction, rooch::RoochTransaction};
-use crate::address::MultiChainAddress;
+use self::rooch::{RoochTransaction, RoochTransactionExtension};
+use self::{authenticator::Authenticator, ethereum::EthereumTransaction};
+use crate::address::{MultiChainAddress, RoochAddress};
+use crate::crypto::{RoochSignature, Signature};
 use crate::multichain_id::{MultiChainID, ETHER, ROOCH};
 use anyhow::{format_err, Result};
+use fastcrypto::traits::ToFromBytes;
 use move_core_types::account_address::AccountAddress;
+use moveos_types::h256::{self, H256};
+use moveos_types::transaction::MoveOSTransaction;
 use moveos_types::transaction::TransactionExecutionInfo;
-use moveos_types::{h256::H256, transaction::MoveOSTransaction};
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use std::fmt;
 use std::fmt::{Display, Formatter};
 use std::str::FromStr;
@@ -16,6 +20,7 @@ use std::str::FromStr;
 pub mod authenticator;
 pub mod ethereum;
 pub mod rooch;
+pub mod view;
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
 pub enum TransactionType {
@@ -104,14 +109,81 @@ pub trait AbstractTransaction {
 
     fn multi_chain_id(&self) -> MultiChainID;
     fn tx_size(&self) -> u64;
+
+    /// The seconds since the unix epoch after which the transaction is rejected, never expires if `None`
+    fn expiration_timestamp_secs(&self) -> Option<u64>;
+
+    /// Check the transaction is not expired at `now_secs`, the expired transaction is rejected at admission by the RPC service and the sequencer
+    fn check_expiration(&self, now_secs: u64) -> Result<()> {
+        match self.expiration_timestamp_secs() {
+            Some(expiration_timestamp_secs) if expiration_timestamp_secs <= now_secs => {
+                Err(format_err!(
+                    "Transaction {} is expired at {}, now is {}",
+                    self.tx_hash(),
+                    expiration_timestamp_secs,
+                    now_secs
+                ))
+            }
+            _ => Ok(()),
+        }
+    }
 }
 
-#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub enum TypedTransaction {
     Rooch(RoochTransaction),
     Ethereum(EthereumTransaction),
 }
 
+/// The encoding of `TypedTransaction`, the Rooch transaction with extension is encoded as the appended variant,
+/// so the encoding of the transactions saved before the extension is introduced is unchanged.
+#[derive(Serialize)]
+#[serde(rename = "TypedTransaction")]
+enum TypedTransactionRef<'a> {
+    Rooch(&'a RoochTransaction),
+    Ethereum(&'a EthereumTransaction),
+    RoochWithExtension(&'a RoochTransaction, &'a RoochTransactionExtension),
+}
+
+#[derive(Deserialize)]
+#[serde(rename = "TypedTransaction")]
+enum TypedTransactionRepr {
+    Rooch(RoochTransaction),
+    Ethereum(EthereumTransaction),
+    RoochWithExtension(RoochTransaction, RoochTransactionExtension),
+}
+
+impl Serialize for TypedTransaction {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            TypedTransaction::Rooch(tx) => match tx.extension() {
+                Some(extension) => TypedTransactionRef::RoochWithExtension(tx, extension),
+                None => TypedTransactionRef::Rooch(tx),
+            },
+            TypedTransaction::Ethereum(tx) => TypedTransactionRef::Ethereum(tx),
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for TypedTransaction {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Ok(match TypedTransactionRepr::deserialize(deserializer)? {
+            TypedTransactionRepr::Rooch(tx) => TypedTransaction::Rooch(tx),
+            TypedTransactionRepr::Ethereum(tx) => TypedTransaction::Ethereum(tx),
+            TypedTransactionRepr::RoochWithExtension(tx, extension) => {
+                TypedTransaction::Rooch(tx.with_extension(extension))
+            }
+        })
+    }
+}
+
 impl TryFrom<RawTransaction> for TypedTransaction {
     type Error = anyhow::Error;
 
@@ -200,6 +272,13 @@ impl AbstractTransaction for TypedTransaction {
     fn tx_size(&self) -> u64 {
         self.encode().len() as u64
     }
+
+    fn expiration_timestamp_secs(&self) -> Option<u64> {
+        match self {
+            TypedTransaction::Rooch(tx) => tx.expiration_timestamp_secs(),
+            TypedTransaction::Ethereum(tx) => tx.expiration_timestamp_secs(),
+        }
+    }
 }
 
 ///`TransactionSequenceInfo` represents the result of sequence a transaction.
@@ -225,6 +304,21 @@ impl TransactionSequenceInfo {
             tx_accumulator_root,
         }
     }
+
+    /// The witness of the tx order signed by the sequencer, it commits the transaction to the tx order
+    pub fn witness_hash(tx_hash: H256, tx_order: u64) -> H256 {
+        let mut witness_data = tx_hash.as_ref().to_vec();
+        witness_data.extend(tx_order.to_le_bytes().iter());
+        h256::sha3_256_of(&witness_data)
+    }
+
+    /// Verify the tx order of the transaction is signed by the `sequencer`
+    pub fn verify_order_signature(&self, tx_hash: H256, sequencer: RoochAddress) -> Result<()> {
+        let signature = Signature::from_bytes(&self.tx_order_signature.payload)
+            .map_err(|e| format_err!("Invalid tx order signature: {}", e))?;
+        signature.verify_hashed(&Self::witness_hash(tx_hash, self.tx_order).0, sequencer)?;
+        Ok(())
+    }
 }
 
 /// Transaction with sequence info and execution info.
@@ -251,7 +345,8 @@ impl TransactionSequenceInfoMapping {
 
 #[cfg(test)]
 mod tests {
-    use super::rooch::RoochTransaction;
+    use super::rooch::{RoochTransaction, RoochTransactionData};
+    use super::{h256, AbstractTransaction, RawTransaction, TransactionType, TypedTransaction};
 
     fn test_serialize_deserialize_roundtrip<T>(tx: T)
     where
@@ -267,4 +362,147 @@ mod tests {
         let tx = RoochTransaction::mock();
         test_serialize_deserialize_roundtrip(tx)
     }
+
+    #[test]
+    fn test_check_expiration() {
+        let tx = RoochTransaction::mock();
+        assert!(tx.check_expiration(u64::MAX).is_ok());
+
+        let data = RoochTransactionData::new_for_test(
+            tx.sender(),
+            tx.sequence_number(),
+            tx.action().clone(),
+        )
+        .with_expiration_timestamp_secs(100);
+        let tx = RoochTransaction::new(data, tx.authenticator_info().unwrap().authenticator);
+        assert!(tx.check_expiration(99).is_ok());
+        assert!(tx.check_expiration(100).is_err());
+        test_serialize_deserialize_roundtrip(tx)
+    }
+
+    #[test]
+    fn test_verify_order_signature() {
+        use super::{TransactionSequenceInfo, H256};
+        use crate::address::RoochAddress;
+        use crate::crypto::{RoochKeyPair, Signature};
+        use fastcrypto::ed25519::Ed25519KeyPair;
+        use fastcrypto::traits::KeyPair;
+
+        let kp = RoochKeyPair::Ed25519(Ed25519KeyPair::generate(&mut rand::thread_rng()));
+        let sequencer: RoochAddress = (&kp.public()).into();
+        let tx_hash = H256::random();
+        let witness_hash = TransactionSequenceInfo::witness_hash(tx_hash, 1);
+        let sequence_info = TransactionSequenceInfo::new(
+            1,
+            Signature::new_hashed(&witness_hash.0, &kp).into(),
+            H256::random(),
+        );
+        assert!(sequence_info
+            .verify_order_signature(tx_hash, sequencer)
+            .is_ok());
+        assert!(sequence_info
+            .verify_order_signature(H256::random(), sequencer)
+            .is_err());
+        assert!(sequence_info
+            .verify_order_signature(tx_hash, RoochAddress::random())
+            .is_err());
+
+        let mut reordered = sequence_info;
+        reordered.tx_order = 2;
+        assert!(reordered
+            .verify_order_signature(tx_hash, sequencer)
+            .is_err());
+    }
+
+    /// The Rooch transaction encoded before the extension is introduced,
+    /// it calls `0x1::m::f` without arguments.
+    const LEGACY_ROOCH_TRANSACTION: &str = concat!(
+        // sender, sequence number, chain id, max gas amount
+        "1111111111111111111111111111111111111111111111111111111111111111",
+        "0100000000000000",
+        "0300000000000000",
+        "e803000000000000",
+        // function call action
+        "01",
+        "0000000000000000000000000000000000000000000000000000000000000001",
+        "016d",
+        "0166",
+        "00",
+        "00",
+        // authenticator
+        "0000000000000000",
+        "02aabb",
+    );
+
+    #[test]
+    fn test_decode_legacy_transaction() {
+        let bytes = hex::decode(LEGACY_ROOCH_TRANSACTION).unwrap();
+        let tx = RoochTransaction::decode(&bytes).unwrap();
+        assert_eq!(tx.sequence_number(), 1);
+        assert_eq!(tx.chain_id(), 3);
+        assert_eq!(tx.max_gas_amount(), 1000);
+        assert_eq!(tx.expiration_timestamp_secs(), None);
+        assert_eq!(tx.encode(), bytes);
+        // The hash of the transaction without extension is the hash of the encoded data
+        let authenticator_len = 11;
+        assert_eq!(
+            tx.tx_hash(),
+            h256::sha3_256_of(&bytes[..bytes.len() - authenticator_len])
+        );
+
+        // The raw transaction sent by the clients
+        let raw = bcs::to_bytes(&RawTransaction {
+            transaction_type: TransactionType::Rooch,
+            raw: bytes.clone(),
+        })
+        .unwrap();
+        assert_eq!(
+            TypedTransaction::decode(&raw).unwrap(),
+            TypedTransaction::Rooch(tx.clone())
+        );
+        // The typed transaction saved in the store
+        let stored = [vec![0u8], bytes].concat();
+        assert_eq!(
+            bcs::from_bytes::<TypedTransaction>(&stored).unwrap(),
+            TypedTransaction::Rooch(tx.clone())
+        );
+        assert_eq!(bcs::to_bytes(&TypedTransaction::Rooch(tx)).unwrap(), stored);
+    }
+
+    #[test]
+    fn test_transaction_extension() {
+        let tx = RoochTransaction::mock();
+        let data = RoochTransactionData::new_for_test(
+            tx.sender(),
+            tx.sequence_number(),
+            tx.action().clone(),
+        );
+        let expiring_data = data.clone().with_expiration_timestamp_secs(100);
+        // The extension is not encoded with the data, it is signed after the data
+        assert_eq!(expiring_data.encode(), data.encode());
+        assert_eq!(
+            expiring_data.hash(),
+            h256::sha3_256_of(&[data.encode(), bcs::to_bytes(&100u64).unwrap()].concat())
+        );
+        assert_ne!(expiring_data.hash(), data.hash());
+
+        let expiring_tx = RoochTransaction::new(
+            expiring_data,
+            tx.authenticator_info().unwrap().authenticator,
+        );
+        let bytes = expiring_tx.encode();
+        assert_eq!(
+            bytes,
+            [tx.encode(), bcs::to_bytes(&100u64).unwrap()].concat()
+        );
+        assert_eq!(RoochTransaction::decode(&bytes).unwrap(), expiring_tx);
+
+        let typed_tx = TypedTransaction::Rooch(expiring_tx);
+        let stored = bcs::to_bytes(&typed_tx).unwrap();
+        assert_eq!(stored[0], 2);
+        assert_eq!(
+            bcs::from_bytes::<TypedTransaction>(&stored).unwrap(),
+            typed_tx
+        );
+    }
 }
@@ -30,6 +30,17 @@ pub struct RoochTransactionData {
     pub max_gas_amount: u64,
     // The MoveAction to execute.
     pub action: MoveAction,
+    // The optional fields of the transaction, they are not encoded with the data but after the transaction,
+    // so the encoding of the transaction without extension is unchanged, see `RoochTransaction::encode`.
+    #[serde(skip)]
+    pub extension: Option<RoochTransactionExtension>,
+}
+
+/// The optional fields of the Rooch transaction, they are signed after the transaction data.
+#[derive(Clone, Debug, Hash, Eq, PartialEq, Serialize, Deserialize)]
+pub struct RoochTransactionExtension {
+    /// The seconds since the unix epoch after which the transaction is rejected
+    pub expiration_timestamp_secs: u64,
 }
 
 impl RoochTransactionData {
@@ -46,9 +57,18 @@ impl RoochTransactionData {
             chain_id,
             max_gas_amount,
             action,
+            extension: None,
         }
     }
 
+    /// The transaction is rejected at admission after the expiration timestamp, it is not checked when the history is replayed
+    pub fn with_expiration_timestamp_secs(mut self, expiration_timestamp_secs: u64) -> Self {
+        self.extension = Some(RoochTransactionExtension {
+            expiration_timestamp_secs,
+        });
+        self
+    }
+
     pub fn new_for_test(sender: RoochAddress, sequence_number: u64, action: MoveAction) -> Self {
         Self {
             sender,
@@ -56,15 +76,35 @@ impl RoochTransactionData {
             chain_id: RoochChainID::LOCAL.chain_id().id(),
             max_gas_amount: GasConfig::DEFAULT_MAX_GAS_AMOUNT * 100,
             action,
+            extension: None,
         }
     }
 
+    pub fn expiration_timestamp_secs(&self) -> Option<u64> {
+        self.extension
+            .as_ref()
+            .map(|extension| extension.expiration_timestamp_secs)
+    }
+
     pub fn encode(&self) -> Vec<u8> {
         bcs::to_bytes(self).expect("encode transaction should success")
     }
 
+    /// The bytes signed by the sender, the encoding of the data followed by the encoding of the extension if present.
+    /// The signing bytes of the transactions with and without extension do not collide,
+    /// because a bcs value can not be a prefix of another value of the same type.
+    pub fn signing_bytes(&self) -> Vec<u8> {
+        let mut signing_bytes = self.encode();
+        if let Some(extension) = &self.extension {
+            signing_bytes.extend(
+                bcs::to_bytes(extension).expect("encode transaction extension should success"),
+            );
+        }
+        signing_bytes
+    }
+
     pub fn hash(&self) -> H256 {
-        moveos_types::h256::sha3_256_of(self.encode().as_slice())
+        moveos_types::h256::sha3_256_of(self.signing_bytes().as_slice())
     }
 
     pub fn sign(self, kp: &RoochKeyPair) -> RoochTransaction {
@@ -122,6 +162,26 @@ impl RoochTransaction {
         &self.data.action
     }
 
+    /// The extension is not encoded by the serde of the transaction, see `encode` and `TypedTransaction`
+    pub fn extension(&self) -> Option<&RoochTransactionExtension> {
+        self.data.extension.as_ref()
+    }
+
+    pub fn with_extension(mut self, extension: RoochTransactionExtension) -> Self {
+        self.data.extension = Some(extension);
+        self
+    }
+
+    pub fn auth_validator_id(&self) -> u64 {
+        self.authenticator.auth_validator_id
+    }
+
+    /// Validate the transaction with another auth validator, the authenticator payload is kept unchanged.
+    pub fn with_auth_validator_id(mut self, auth_validator_id: u64) -> Self {
+        self.authenticator.auth_validator_id = auth_validator_id;
+        self
+    }
+
     //TODO use protest Arbitrary to generate mock data
     #[cfg(test)]
     pub fn mock() -> RoochTransaction {
@@ -151,6 +211,22 @@ impl RoochTransaction {
     }
 }
 
+/// Convert the unsigned transaction data to MoveOSTransaction, only used by dry run.
+impl From<RoochTransactionData> for MoveOSTransaction {
+    fn from(tx_data: RoochTransactionData) -> Self {
+        let tx_hash = tx_data.hash();
+        let tx_size = tx_data.encode().len() as u64;
+        let tx_ctx = TxContext::new(
+            tx_data.sender.into(),
+            tx_data.sequence_number,
+            tx_data.max_gas_amount,
+            tx_hash,
+            tx_size,
+        );
+        MoveOSTransaction::new(tx_ctx, tx_data.action)
+    }
+}
+
 impl From<RoochTransaction> for MoveOSTransaction {
     fn from(tx: RoochTransaction) -> Self {
         let tx_hash = tx.tx_hash();
@@ -171,15 +247,28 @@ impl AbstractTransaction for RoochTransaction {
         TransactionType::Rooch
     }
 
+    /// The transaction without extension is tried first, bcs requires all the bytes to be consumed,
+    /// so the transaction with extension is not decoded as the transaction without it.
     fn decode(bytes: &[u8]) -> Result<Self>
     where
         Self: std::marker::Sized,
     {
-        bcs::from_bytes::<Self>(bytes).map_err(Into::into)
+        match bcs::from_bytes::<Self>(bytes) {
+            Ok(tx) => Ok(tx),
+            Err(_) => {
+                let (tx, extension) = bcs::from_bytes::<(Self, RoochTransactionExtension)>(bytes)?;
+                Ok(tx.with_extension(extension))
+            }
+        }
     }
 
+    /// The extension is encoded after the transaction, the transaction without extension is encoded as it was.
     fn encode(&self) -> Vec<u8> {
-        bcs::to_bytes(self).expect("encode transaction should success")
+        match self.extension() {
+            Some(extension) => bcs::to_bytes(&(self, extension)),
+            None => bcs::to_bytes(self),
+        }
+        .expect("encode transaction should success")
     }
 
     //TODO unify the hash function
@@ -218,4 +307,8 @@ impl AbstractTransaction for RoochTransaction {
     fn tx_size(&self) -> u64 {
         self.encode().len() as u64
     }
+
+    fn expiration_timestamp_secs(&self) -> Option<u64> {
+        self.data.expiration_timestamp_secs()
+    }
 }
@@ -0,0 +1,176 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use super::{authenticator::Authenticator, AuthenticatorInfo};
+use crate::address::RoochAddress;
+use crate::crypto::{RoochKeyPair, Signature};
+use anyhow::{format_err, Result};
+use moveos_types::h256::H256;
+use moveos_types::moveos_std::tx_context::TxContext;
+use moveos_types::transaction::FunctionCall;
+use serde::{Deserialize, Serialize};
+
+/// The prefix of the signed bytes of the view function call,
+/// so the signature of a view function call can not be replayed as the signature of a transaction.
+pub const VIEW_FUNCTION_CALL_SIGNING_PREFIX: &[u8] = b"ROOCH::ViewFunctionCall::";
+
+/// The view function call signed by the caller, the signature proves the identity of the caller without sending a transaction,
+/// so the view function can read `context::sender` as the verified caller.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct ViewFunctionCallData {
+    pub sender: RoochAddress,
+    pub chain_id: u64,
+    // The seconds since the unix epoch after which the signed call is rejected, it limits the replay of the signed call.
+    pub expiration_timestamp_secs: u64,
+    pub call: FunctionCall,
+}
+
+impl ViewFunctionCallData {
+    pub fn new(
+        sender: RoochAddress,
+        chain_id: u64,
+        expiration_timestamp_secs: u64,
+        call: FunctionCall,
+    ) -> Self {
+        Self {
+            sender,
+            chain_id,
+            expiration_timestamp_secs,
+            call,
+        }
+    }
+
+    pub fn encode(&self) -> Vec<u8> {
+        bcs::to_bytes(self).expect("encode view function call should success")
+    }
+
+    /// The hash of the signing prefix and the encoded call, it is the message signed by the caller
+    pub fn hash(&self) -> H256 {
+        let mut bytes = VIEW_FUNCTION_CALL_SIGNING_PREFIX.to_vec();
+        bytes.extend(self.encode());
+        moveos_types::h256::sha3_256_of(bytes.as_slice())
+    }
+
+    pub fn sign(self, kp: &RoochKeyPair) -> SignedViewFunctionCall {
+        let signature = Signature::new_hashed(self.hash().as_bytes(), kp);
+        SignedViewFunctionCall::new(self, Authenticator::rooch(signature))
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct SignedViewFunctionCall {
+    pub data: ViewFunctionCallData,
+    pub authenticator: Authenticator,
+}
+
+impl SignedViewFunctionCall {
+    pub fn new(data: ViewFunctionCallData, authenticator: Authenticator) -> Self {
+        Self {
+            data,
+            authenticator,
+        }
+    }
+
+    pub fn decode(bytes: &[u8]) -> Result<Self> {
+        bcs::from_bytes::<Self>(bytes).map_err(Into::into)
+    }
+
+    pub fn encode(&self) -> Vec<u8> {
+        bcs::to_bytes(self).expect("encode signed view function call should success")
+    }
+
+    pub fn sender(&self) -> RoochAddress {
+        self.data.sender
+    }
+
+    pub fn call(&self) -> &FunctionCall {
+        &self.data.call
+    }
+
+    pub fn authenticator_info(&self) -> AuthenticatorInfo {
+        AuthenticatorInfo::new(self.data.chain_id, self.authenticator.clone())
+    }
+
+    pub fn check_expiration(&self, now_secs: u64) -> Result<()> {
+        if self.data.expiration_timestamp_secs <= now_secs {
+            return Err(format_err!(
+                "View function call {} is expired at {}, now is {}",
+                self.data.hash(),
+                self.data.expiration_timestamp_secs,
+                now_secs
+            ));
+        }
+        Ok(())
+    }
+
+    /// The read-only context of the call, the tx hash is the signed hash of the call, so the auth validator can verify the signature.
+    /// The sequence number is the current sequence number of the sender, the signed call does not increase it.
+    pub fn tx_context(&self, sequence_number: u64, max_gas_amount: u64) -> TxContext {
+        TxContext::new(
+            self.data.sender.into(),
+            sequence_number,
+            max_gas_amount,
+            self.data.hash(),
+            self.data.encode().len() as u64,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::address::RoochSupportedAddress;
+    use crate::transaction::rooch::RoochTransactionData;
+    use fastcrypto::ed25519::Ed25519KeyPair;
+    use fastcrypto::traits::KeyPair;
+    use move_core_types::account_address::AccountAddress;
+    use move_core_types::{identifier::Identifier, language_storage::ModuleId};
+    use moveos_types::move_types::FunctionId;
+    use moveos_types::transaction::MoveAction;
+
+    fn mock_call() -> FunctionCall {
+        FunctionCall::new(
+            FunctionId::new(
+                ModuleId::new(AccountAddress::random(), Identifier::new("test").unwrap()),
+                Identifier::new("test").unwrap(),
+            ),
+            vec![],
+            vec![],
+        )
+    }
+
+    #[test]
+    fn test_signed_view_function_call() {
+        let kp = RoochKeyPair::Ed25519(Ed25519KeyPair::generate(&mut rand::thread_rng()));
+        let sender: RoochAddress = (&kp.public()).into();
+        let data = ViewFunctionCallData::new(sender, 4, 100, mock_call());
+        let signed = data.clone().sign(&kp);
+        assert_eq!(signed.sender(), sender);
+        assert_eq!(
+            SignedViewFunctionCall::decode(&signed.encode()).unwrap(),
+            signed
+        );
+        assert!(signed.check_expiration(99).is_ok());
+        assert!(signed.check_expiration(100).is_err());
+
+        let ctx = signed.tx_context(1, 0);
+        assert_eq!(ctx.sender, AccountAddress::from(sender));
+        assert_eq!(ctx.tx_hash, data.hash().0.to_vec());
+    }
+
+    #[test]
+    fn test_signing_prefix() {
+        // The signed hash of the view function call is not the hash of the plain encoded bytes
+        let data = ViewFunctionCallData::new(RoochAddress::random(), 4, 100, mock_call());
+        assert_ne!(
+            data.hash(),
+            moveos_types::h256::sha3_256_of(data.encode().as_slice())
+        );
+        let tx_data = RoochTransactionData::new_for_test(
+            data.sender,
+            0,
+            MoveAction::Function(data.call.clone()),
+        );
+        assert_ne!(data.hash(), tx_data.hash());
+    }
+}
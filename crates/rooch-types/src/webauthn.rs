@@ -0,0 +1,317 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+//! The WebAuthn(passkey) credentials and assertions, shared by the webauthn natives and the clients.
+//!
+//! The credential public key is a COSE_Key encoded in CBOR, only the ES256 algorithm over the P-256 curve is supported.
+//! The assertion signs `authenticator data || SHA256(client data JSON)`, and the challenge in the client data is
+//! the base64url encoded tx hash. The assertion is bound to the relying party of the registered credential,
+//! the authenticator data starts with `SHA256(rp id)` and the client data has the origin of the web page.
+
+use crate::{
+    authentication_key::AuthenticationKey, crypto::DefaultHash, error::RoochError,
+    framework::auth_validator::BuiltinAuthValidator,
+};
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+use ciborium::value::Value;
+use fastcrypto::{
+    hash::{HashFunction, Sha256},
+    secp256r1::{Secp256r1PublicKey, Secp256r1Signature},
+    traits::{ToFromBytes, VerifyingKey},
+};
+use p256::elliptic_curve::sec1::ToEncodedPoint;
+use serde::Deserialize;
+
+/// The `type` of the client data of an assertion.
+pub const WEBAUTHN_GET_TYPE: &str = "webauthn.get";
+/// The length of the compressed P-256 public key.
+pub const PUBLIC_KEY_LENGTH: usize = 33;
+/// The length of the `r || s` signature.
+pub const SIGNATURE_LENGTH: usize = 64;
+
+/// The length of the SHA256 hash of the rp id.
+pub const RP_ID_HASH_LENGTH: usize = 32;
+
+/// The authenticator data is `rpIdHash(32) || flags(1) || signCount(4) || ...`.
+const AUTHENTICATOR_DATA_MIN_LENGTH: usize = 37;
+const AUTHENTICATOR_DATA_FLAGS_INDEX: usize = 32;
+/// The user present flag of the authenticator data.
+const FLAG_USER_PRESENT: u8 = 0x01;
+
+const COSE_KEY_TYPE: i128 = 1;
+const COSE_KEY_ALG: i128 = 3;
+const COSE_KEY_CRV: i128 = -1;
+const COSE_KEY_X: i128 = -2;
+const COSE_KEY_Y: i128 = -3;
+const COSE_KEY_TYPE_EC2: i128 = 2;
+const COSE_ALG_ES256: i128 = -7;
+const COSE_CRV_P256: i128 = 1;
+
+#[derive(Deserialize)]
+struct ClientData {
+    #[serde(rename = "type")]
+    ty: String,
+    challenge: String,
+    origin: String,
+    #[serde(default, rename = "crossOrigin")]
+    cross_origin: bool,
+}
+
+/// Decode the credential public key of the attestation, return the compressed P-256 public key.
+pub fn cose_key_to_public_key(cose_key: &[u8]) -> Result<Vec<u8>, RoochError> {
+    let invalid_key =
+        |msg: &str| RoochError::KeyConversionError(format!("Invalid COSE key: {}", msg));
+    let value: Value =
+        ciborium::de::from_reader(cose_key).map_err(|e| invalid_key(&e.to_string()))?;
+    let entries = value.into_map().map_err(|_| invalid_key("not a map"))?;
+    let get = |label: i128| {
+        entries
+            .iter()
+            .find(|(key, _)| key.as_integer().map(i128::from) == Some(label))
+            .map(|(_, value)| value)
+    };
+    let get_int = |label: i128| get(label).and_then(Value::as_integer).map(i128::from);
+    if get_int(COSE_KEY_TYPE) != Some(COSE_KEY_TYPE_EC2)
+        || get_int(COSE_KEY_ALG) != Some(COSE_ALG_ES256)
+        || get_int(COSE_KEY_CRV) != Some(COSE_CRV_P256)
+    {
+        return Err(invalid_key(
+            "only the ES256 key over the P-256 curve is supported",
+        ));
+    }
+    let (Some(x), Some(y)) = (
+        get(COSE_KEY_X).and_then(Value::as_bytes),
+        get(COSE_KEY_Y).and_then(Value::as_bytes),
+    ) else {
+        return Err(invalid_key("the coordinates are missing"));
+    };
+    if x.len() != 32 || y.len() != 32 {
+        return Err(invalid_key("invalid coordinate length"));
+    }
+    let mut uncompressed = vec![0x04];
+    uncompressed.extend_from_slice(x);
+    uncompressed.extend_from_slice(y);
+    let public_key = p256::PublicKey::from_sec1_bytes(&uncompressed)
+        .map_err(|_| invalid_key("the point is not on the curve"))?;
+    Ok(public_key.to_encoded_point(true).as_bytes().to_vec())
+}
+
+/// The authentication key of the passkey registered for an account, it is the hash of the validator flag and the compressed public key.
+pub fn public_key_to_authentication_key(public_key: &[u8]) -> AuthenticationKey {
+    let mut hasher = DefaultHash::default();
+    hasher.update([BuiltinAuthValidator::Webauthn.flag()]);
+    hasher.update(public_key);
+    AuthenticationKey::new(hasher.finalize().digest.to_vec())
+}
+
+/// The hash of the relying party id, such as `rooch.network`, it is the first 32 bytes of the authenticator data.
+pub fn rp_id_hash(rp_id: &str) -> Vec<u8> {
+    Sha256::digest(rp_id.as_bytes()).to_vec()
+}
+
+/// The challenge of the assertion, the base64url encoded tx hash without padding.
+pub fn challenge(tx_hash: &[u8]) -> String {
+    URL_SAFE_NO_PAD.encode(tx_hash)
+}
+
+/// Check the client data is of an assertion from the `origin` not embedded cross-origin, and its challenge is the `challenge` bytes.
+pub fn check_client_data(
+    client_data_json: &[u8],
+    challenge: &[u8],
+    origin: &str,
+) -> Result<(), RoochError> {
+    let client_data: ClientData =
+        serde_json::from_slice(client_data_json).map_err(|e| RoochError::InvalidSignature {
+            error: format!("Invalid WebAuthn client data: {}", e),
+        })?;
+    if client_data.ty != WEBAUTHN_GET_TYPE {
+        return Err(RoochError::InvalidSignature {
+            error: format!("Invalid WebAuthn client data type: {}", client_data.ty),
+        });
+    }
+    if client_data.challenge != self::challenge(challenge) {
+        return Err(RoochError::InvalidSignature {
+            error: "The WebAuthn challenge mismatches".to_owned(),
+        });
+    }
+    if client_data.origin != origin || client_data.cross_origin {
+        return Err(RoochError::InvalidSignature {
+            error: format!("Invalid WebAuthn origin: {}", client_data.origin),
+        });
+    }
+    Ok(())
+}
+
+/// Check the authenticator data is well-formed, of the relying party with the `rp_id_hash`, and the user is present.
+pub fn check_authenticator_data(
+    authenticator_data: &[u8],
+    rp_id_hash: &[u8],
+) -> Result<(), RoochError> {
+    if authenticator_data.len() < AUTHENTICATOR_DATA_MIN_LENGTH {
+        return Err(RoochError::InvalidSignature {
+            error: "The WebAuthn authenticator data is too short".to_owned(),
+        });
+    }
+    if authenticator_data[..RP_ID_HASH_LENGTH] != *rp_id_hash {
+        return Err(RoochError::InvalidSignature {
+            error: "The WebAuthn rp id hash mismatches".to_owned(),
+        });
+    }
+    if authenticator_data[AUTHENTICATOR_DATA_FLAGS_INDEX] & FLAG_USER_PRESENT == 0 {
+        return Err(RoochError::InvalidSignature {
+            error: "The WebAuthn user is not present".to_owned(),
+        });
+    }
+    Ok(())
+}
+
+/// The message signed by the authenticator, it is hashed with SHA256 again by the ES256 algorithm.
+pub fn signed_message(authenticator_data: &[u8], client_data_json: &[u8]) -> Vec<u8> {
+    let mut message = authenticator_data.to_vec();
+    message.extend_from_slice(Sha256::digest(client_data_json).as_ref());
+    message
+}
+
+/// Verify the assertion of the `challenge` signed by the compressed P-256 `public_key`,
+/// for the relying party with the `rp_id_hash` on the `origin`.
+pub fn verify_assertion(
+    signature: &[u8],
+    public_key: &[u8],
+    authenticator_data: &[u8],
+    client_data_json: &[u8],
+    challenge: &[u8],
+    rp_id_hash: &[u8],
+    origin: &str,
+) -> Result<(), RoochError> {
+    let signature =
+        Secp256r1Signature::from_bytes(signature).map_err(|e| RoochError::InvalidSignature {
+            error: format!("Invalid WebAuthn signature: {}", e),
+        })?;
+    let public_key = Secp256r1PublicKey::from_bytes(public_key)
+        .map_err(|e| RoochError::KeyConversionError(format!("Invalid public key: {}", e)))?;
+    check_client_data(client_data_json, challenge, origin)?;
+    check_authenticator_data(authenticator_data, rp_id_hash)?;
+    public_key
+        .verify(
+            &signed_message(authenticator_data, client_data_json),
+            &signature,
+        )
+        .map_err(|e| RoochError::InvalidSignature {
+            error: format!("Invalid WebAuthn signature: {}", e),
+        })
+}
+
+/// Convert the DER encoded signature returned by the authenticator to the `r || s` form with the low `s`.
+pub fn signature_from_der(der_signature: &[u8]) -> Result<Vec<u8>, RoochError> {
+    let signature = p256::ecdsa::Signature::from_der(der_signature).map_err(|e| {
+        RoochError::InvalidSignature {
+            error: format!("Invalid DER signature: {}", e),
+        }
+    })?;
+    let signature = signature.normalize_s().unwrap_or(signature);
+    Ok(signature.to_bytes().to_vec())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const COSE_KEY: &str = "a5010203262001215820c23939956bb7b263b549f1cfc608fcbad4d235a2bc3e30ceb89d2340b2e18263225820ab2c55b2756103dae7f47ca6363391ffd211385c415ce294dd901f6e8c944c6c";
+    const PUBLIC_KEY: &str = "02c23939956bb7b263b549f1cfc608fcbad4d235a2bc3e30ceb89d2340b2e18263";
+    const CHALLENGE: &str = "e5167b1b653978a5d91c9b47ca24ef7125a7a494fd7d2e07a5b35f43edc4d24f";
+    const CLIENT_DATA_JSON: &str = r#"{"type":"webauthn.get","challenge":"5RZ7G2U5eKXZHJtHyiTvcSWnpJT9fS4HpbNfQ-3E0k8","origin":"https://rooch.network","crossOrigin":false}"#;
+    const AUTHENTICATOR_DATA: &str =
+        "701e5ca13e155a366732dbcbbbc694edc8c7fc06b03cfebc4c7361057a4f148d0500000001";
+    const RP_ID: &str = "rooch.network";
+    const ORIGIN: &str = "https://rooch.network";
+    const SIGNATURE: &str = "81c6d5292a48c690b62f4eda9bee5da02bdb9833923a8bc01a0ffe63f141996c638e3e7bbd1db146ef4b8e8e27043ee5faa72c94cb28b8869974b0674053a319";
+    const HIGH_S_DER_SIGNATURE: &str = "304602210081c6d5292a48c690b62f4eda9bee5da02bdb9833923a8bc01a0ffe63f141996c0221009c71c18342e24eba10b47171d8fbc119c23fce18dbeee5fe5a451a5bbc0f8238";
+
+    #[test]
+    fn test_cose_key_to_public_key() {
+        let public_key = cose_key_to_public_key(&hex::decode(COSE_KEY).unwrap()).unwrap();
+        assert_eq!(hex::encode(public_key), PUBLIC_KEY);
+        // The Ed25519 COSE key {1: 1, 3: -8}
+        assert!(cose_key_to_public_key(&hex::decode("a201010327").unwrap()).is_err());
+        assert!(cose_key_to_public_key(&[0xff]).is_err());
+    }
+
+    #[test]
+    fn test_verify_assertion() {
+        let public_key = hex::decode(PUBLIC_KEY).unwrap();
+        let challenge = hex::decode(CHALLENGE).unwrap();
+        let authenticator_data = hex::decode(AUTHENTICATOR_DATA).unwrap();
+        let signature = hex::decode(SIGNATURE).unwrap();
+        verify_assertion(
+            &signature,
+            &public_key,
+            &authenticator_data,
+            CLIENT_DATA_JSON.as_bytes(),
+            &challenge,
+            &rp_id_hash(RP_ID),
+            ORIGIN,
+        )
+        .unwrap();
+        assert_eq!(
+            signature_from_der(&hex::decode(HIGH_S_DER_SIGNATURE).unwrap()).unwrap(),
+            signature
+        );
+
+        let mut wrong_challenge = challenge.clone();
+        wrong_challenge[0] ^= 1;
+        assert!(verify_assertion(
+            &signature,
+            &public_key,
+            &authenticator_data,
+            CLIENT_DATA_JSON.as_bytes(),
+            &wrong_challenge,
+            &rp_id_hash(RP_ID),
+            ORIGIN,
+        )
+        .is_err());
+
+        let mut user_absent = authenticator_data.clone();
+        user_absent[AUTHENTICATOR_DATA_FLAGS_INDEX] &= !FLAG_USER_PRESENT;
+        assert!(verify_assertion(
+            &signature,
+            &public_key,
+            &user_absent,
+            CLIENT_DATA_JSON.as_bytes(),
+            &challenge,
+            &rp_id_hash(RP_ID),
+            ORIGIN,
+        )
+        .is_err());
+
+        // The assertion of the other relying party
+        assert!(verify_assertion(
+            &signature,
+            &public_key,
+            &authenticator_data,
+            CLIENT_DATA_JSON.as_bytes(),
+            &challenge,
+            &rp_id_hash("evil.network"),
+            ORIGIN,
+        )
+        .is_err());
+        assert!(verify_assertion(
+            &signature,
+            &public_key,
+            &authenticator_data,
+            CLIENT_DATA_JSON.as_bytes(),
+            &challenge,
+            &rp_id_hash(RP_ID),
+            "https://evil.network",
+        )
+        .is_err());
+
+        let create_client_data = CLIENT_DATA_JSON.replace(WEBAUTHN_GET_TYPE, "webauthn.create");
+        assert!(check_client_data(create_client_data.as_bytes(), &challenge, ORIGIN).is_err());
+        let cross_origin_client_data =
+            CLIENT_DATA_JSON.replace(r#""crossOrigin":false"#, r#""crossOrigin":true"#);
+        assert!(
+            check_client_data(cross_origin_client_data.as_bytes(), &challenge, ORIGIN).is_err()
+        );
+        check_client_data(CLIENT_DATA_JSON.as_bytes(), &challenge, ORIGIN).unwrap();
+    }
+}
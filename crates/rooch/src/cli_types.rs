@@ -4,10 +4,16 @@
 use async_trait::async_trait;
 use clap::Parser;
 use move_command_line_common::address::ParsedAddress;
+use moveos_types::transaction::MoveAction;
+use rooch_key::key_derive::verify_password;
+use rooch_key::keystore::account_keystore::AccountKeystore;
 use rooch_rpc_client::wallet_context::WalletContext;
+use rooch_types::address::RoochAddress;
 use rooch_types::authentication_key::AuthenticationKey;
 use rooch_types::error::{RoochError, RoochResult};
 use rooch_types::transaction::authenticator::Authenticator;
+use rooch_types::transaction::rooch::RoochTransaction;
+use rpassword::prompt_password;
 use serde::Serialize;
 use std::path::PathBuf;
 use std::str::FromStr;
@@ -83,6 +89,63 @@ pub struct TransactionOptions {
     /// This option conflicts with `authenticator`
     #[clap(long, conflicts_with = "authenticator")]
     pub(crate) session_key: Option<AuthenticationKey>,
+
+    /// Custom the sequence number of the transaction, such as to replace an in-flight transaction.
+    /// Default is the next sequence number after the on-chain sequence number and the local in-flight transactions.
+    #[clap(long)]
+    pub(crate) sequence_number: Option<u64>,
+
+    /// The transaction expires after the seconds from now, the expired transaction is rejected by the sequencer.
+    /// Default is the transaction never expires.
+    #[clap(long)]
+    pub(crate) expiration_secs: Option<u64>,
+}
+
+impl TransactionOptions {
+    /// Sign the action via the custom authenticator, the session key or the key of the sender in the keystore.
+    pub(crate) async fn sign(
+        &self,
+        context: &WalletContext,
+        sender: RoochAddress,
+        action: MoveAction,
+    ) -> RoochResult<RoochTransaction> {
+        match (&self.authenticator, &self.session_key) {
+            (Some(authenticator), _) => {
+                let tx_data = context.build_tx_data(sender, action).await?;
+                //TODO the authenticator usually is associalted with the RoochTransactinData
+                //So we need to find a way to let user generate the authenticator based on the tx_data.
+                Ok(RoochTransaction::new(tx_data, authenticator.clone().into()))
+            }
+            (_, Some(session_key)) => {
+                let tx_data = context.build_tx_data(sender, action).await?;
+                let password = prompt_sign_password(context)?;
+                context
+                    .keystore
+                    .sign_transaction_via_session_key(&sender, tx_data, session_key, password)
+                    .map_err(|e| RoochError::SignMessageError(e.to_string()))
+            }
+            (None, None) => {
+                let password = prompt_sign_password(context)?;
+                context.sign(sender, action, password).await
+            }
+        }
+    }
+}
+
+/// Prompt the password of the keystore if it is set, and verify it.
+fn prompt_sign_password(context: &WalletContext) -> RoochResult<Option<String>> {
+    if context.keystore.get_if_password_is_empty() {
+        return Ok(None);
+    }
+    let password = prompt_password("Enter the password to run functions:").unwrap_or_default();
+    let is_verified =
+        verify_password(Some(password.clone()), context.keystore.get_password_hash())?;
+    if !is_verified {
+        return Err(RoochError::InvalidPasswordError(
+            "Password is invalid".to_owned(),
+        ));
+    }
+    Ok(Some(password))
 }
 
 #[derive(Debug, Parser)]
@@ -90,10 +153,19 @@ pub struct WalletContextOptions {
     /// rooch config path
     #[clap(long)]
     pub config_dir: Option<PathBuf>,
+
+    /// Use the environment profile instead of the active environment, such as `--profile test`.
+    /// The RPC endpoint, the active address and the gas defaults of the profile are used.
+    #[clap(long)]
+    pub profile: Option<String>,
 }
 
 impl WalletContextOptions {
     pub fn build(&self) -> RoochResult<WalletContext> {
-        WalletContext::new(self.config_dir.clone()).map_err(RoochError::from)
+        let mut context = WalletContext::new(self.config_dir.clone()).map_err(RoochError::from)?;
+        if let Some(profile) = &self.profile {
+            context.use_profile(profile.clone())?;
+        }
+        Ok(context)
     }
 }
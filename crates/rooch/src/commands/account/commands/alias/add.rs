@@ -0,0 +1,56 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::cli_types::{CommandAction, WalletContextOptions};
+use async_trait::async_trait;
+use clap::Parser;
+use move_command_line_common::address::ParsedAddress;
+use move_core_types::identifier::Identifier;
+use rooch_types::address::RoochAddress;
+use rooch_types::addresses;
+use rooch_types::error::{RoochError, RoochResult};
+use std::collections::BTreeMap;
+
+/// Add a named address to the address book, the existing address of the name is replaced
+#[derive(Debug, Parser)]
+pub struct AddCommand {
+    /// The name of the address, it must be a valid Move identifier, such as `alice`
+    #[clap(short = 'n', long = "name")]
+    name: String,
+
+    /// The address of the name, it can be another name of the address book
+    #[clap(short = 'a', long = "address", value_parser=ParsedAddress::parse)]
+    address: ParsedAddress,
+
+    #[clap(flatten)]
+    pub context_options: WalletContextOptions,
+}
+
+#[async_trait]
+impl CommandAction<BTreeMap<String, RoochAddress>> for AddCommand {
+    async fn execute(self) -> RoochResult<BTreeMap<String, RoochAddress>> {
+        let mut context = self.context_options.build()?;
+        if !Identifier::is_valid(&self.name) {
+            return Err(RoochError::CommandArgumentError(format!(
+                "Invalid name `{}`, the name must be a valid Move identifier",
+                self.name
+            )));
+        }
+        if self.name == "default"
+            || addresses::rooch_framework_named_addresses().contains_key(&self.name)
+        {
+            return Err(RoochError::CommandArgumentError(format!(
+                "The name `{}` is reserved",
+                self.name
+            )));
+        }
+
+        let address: RoochAddress = context.resolve_address(self.address)?.into();
+        context
+            .client_config
+            .address_book
+            .insert(self.name.clone(), address);
+        context.client_config.save()?;
+        Ok(BTreeMap::from([(self.name, address)]))
+    }
+}
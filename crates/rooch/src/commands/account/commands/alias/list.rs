@@ -0,0 +1,24 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::cli_types::{CommandAction, WalletContextOptions};
+use async_trait::async_trait;
+use clap::Parser;
+use rooch_types::address::RoochAddress;
+use rooch_types::error::RoochResult;
+use std::collections::BTreeMap;
+
+/// List the named addresses of the address book
+#[derive(Debug, Parser)]
+pub struct ListCommand {
+    #[clap(flatten)]
+    pub context_options: WalletContextOptions,
+}
+
+#[async_trait]
+impl CommandAction<BTreeMap<String, RoochAddress>> for ListCommand {
+    async fn execute(self) -> RoochResult<BTreeMap<String, RoochAddress>> {
+        let context = self.context_options.build()?;
+        Ok(context.client_config.address_book.clone())
+    }
+}
@@ -0,0 +1,42 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::cli_types::CommandAction;
+use add::AddCommand;
+use async_trait::async_trait;
+use list::ListCommand;
+use remove::RemoveCommand;
+use rooch_types::error::RoochResult;
+
+pub mod add;
+pub mod list;
+pub mod remove;
+
+/// Manage the address book of the wallet.
+///
+/// The address book is stored in the client config, the names are accepted by the commands anywhere an address is expected,
+/// such as `rooch account balance -a alice` or the `@alice` address argument of `rooch move run`.
+#[derive(Debug, clap::Parser)]
+pub struct Alias {
+    #[clap(subcommand)]
+    cmd: AliasCommand,
+}
+
+#[async_trait]
+impl CommandAction<String> for Alias {
+    async fn execute(self) -> RoochResult<String> {
+        match self.cmd {
+            AliasCommand::Add(add) => add.execute_serialized().await,
+            AliasCommand::List(list) => list.execute_serialized().await,
+            AliasCommand::Remove(remove) => remove.execute_serialized().await,
+        }
+    }
+}
+
+#[derive(Debug, clap::Subcommand)]
+#[clap(name = "alias")]
+pub enum AliasCommand {
+    Add(AddCommand),
+    List(ListCommand),
+    Remove(RemoveCommand),
+}
@@ -0,0 +1,39 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::cli_types::{CommandAction, WalletContextOptions};
+use async_trait::async_trait;
+use clap::Parser;
+use rooch_types::address::RoochAddress;
+use rooch_types::error::{RoochError, RoochResult};
+use std::collections::BTreeMap;
+
+/// Remove a named address from the address book
+#[derive(Debug, Parser)]
+pub struct RemoveCommand {
+    /// The name of the address to remove
+    #[clap(short = 'n', long = "name")]
+    name: String,
+
+    #[clap(flatten)]
+    pub context_options: WalletContextOptions,
+}
+
+#[async_trait]
+impl CommandAction<BTreeMap<String, RoochAddress>> for RemoveCommand {
+    async fn execute(self) -> RoochResult<BTreeMap<String, RoochAddress>> {
+        let mut context = self.context_options.build()?;
+        let address = context
+            .client_config
+            .address_book
+            .remove(&self.name)
+            .ok_or_else(|| {
+                RoochError::CommandArgumentError(format!(
+                    "The name `{}` is not in the address book",
+                    self.name
+                ))
+            })?;
+        context.client_config.save()?;
+        Ok(BTreeMap::from([(self.name, address)]))
+    }
+}
@@ -0,0 +1,65 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::cli_types::{CommandAction, WalletContextOptions};
+use async_trait::async_trait;
+use clap::Parser;
+use move_command_line_common::address::ParsedAddress;
+use moveos_types::module_binding::MoveFunctionCaller;
+use rooch_types::{
+    address::RoochAddress,
+    error::RoochResult,
+    framework::{
+        account_authentication::AuthenticationKeyModule,
+        auth_validator::AuthValidatorRegistryModule,
+    },
+};
+use serde::{Deserialize, Serialize};
+
+/// List the auth validators installed for the account
+#[derive(Debug, Parser)]
+pub struct ListCommand {
+    /// The account to list the auth validators.
+    #[clap(short = 'a', long = "address", value_parser=ParsedAddress::parse, default_value = "default")]
+    address: ParsedAddress,
+
+    #[clap(flatten)]
+    pub context_options: WalletContextOptions,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AuthValidatorView {
+    pub id: u64,
+    /// The module which defines the auth validator, `None` if the validator is not registered
+    pub module: Option<String>,
+    /// Whether the CLI signs the transactions of the account with the auth validator
+    pub used_for_signing: bool,
+}
+
+#[async_trait]
+impl CommandAction<Vec<AuthValidatorView>> for ListCommand {
+    async fn execute(self) -> RoochResult<Vec<AuthValidatorView>> {
+        let context = self.context_options.build()?;
+        let address: RoochAddress = context.resolve_address(self.address)?.into();
+        let signing_auth_validator = context.client_config.auth_validators.get(&address);
+
+        let client = context.get_client().await?;
+        let auth_validator_ids = client
+            .as_module_binding::<AuthenticationKeyModule>()
+            .installed_auth_validators(address.into())?;
+        let registry_module = client.as_module_binding::<AuthValidatorRegistryModule>();
+        auth_validator_ids
+            .into_iter()
+            .map(|id| {
+                let module = registry_module
+                    .get_validator(id)?
+                    .map(|validator| validator.validator_module_id().short_str_lossless());
+                Ok(AuthValidatorView {
+                    id,
+                    module,
+                    used_for_signing: signing_auth_validator == Some(&id),
+                })
+            })
+            .collect()
+    }
+}
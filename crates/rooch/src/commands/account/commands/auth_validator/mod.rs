@@ -0,0 +1,42 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::cli_types::CommandAction;
+use async_trait::async_trait;
+use list::ListCommand;
+use register::RegisterCommand;
+use remove::RemoveCommand;
+use rooch_types::error::RoochResult;
+
+pub mod list;
+pub mod register;
+pub mod remove;
+
+/// Manage the custom auth validators of an account.
+///
+/// A custom auth validator is registered in the auth validator registry by the module which defines it,
+/// and the account installs it with `register`, then the transactions of the account can be validated by it.
+#[derive(Debug, clap::Parser)]
+pub struct AuthValidator {
+    #[clap(subcommand)]
+    cmd: AuthValidatorCommand,
+}
+
+#[async_trait]
+impl CommandAction<String> for AuthValidator {
+    async fn execute(self) -> RoochResult<String> {
+        match self.cmd {
+            AuthValidatorCommand::Register(register) => register.execute_serialized().await,
+            AuthValidatorCommand::List(list) => list.execute_serialized().await,
+            AuthValidatorCommand::Remove(remove) => remove.execute_serialized().await,
+        }
+    }
+}
+
+#[derive(Debug, clap::Subcommand)]
+#[clap(name = "auth-validator")]
+pub enum AuthValidatorCommand {
+    Register(RegisterCommand),
+    List(ListCommand),
+    Remove(RemoveCommand),
+}
@@ -0,0 +1,100 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::cli_types::{CommandAction, WalletContextOptions};
+use async_trait::async_trait;
+use clap::Parser;
+use move_command_line_common::address::ParsedAddress;
+use move_command_line_common::types::ParsedStructType;
+use moveos_types::module_binding::MoveFunctionCaller;
+use rooch_key::key_derive::verify_password;
+use rooch_key::keystore::account_keystore::AccountKeystore;
+use rooch_rpc_api::jsonrpc_types::ExecuteTransactionResponseView;
+use rooch_types::{
+    address::RoochAddress,
+    error::{RoochError, RoochResult},
+    framework::{
+        account_authentication::AuthenticationKeyModule,
+        auth_validator::AuthValidatorRegistryModule,
+    },
+};
+use rpassword::prompt_password;
+
+/// Install a registered auth validator for the account
+#[derive(Debug, Parser)]
+pub struct RegisterCommand {
+    /// The account to install the auth validator.
+    #[clap(short = 'a', long = "address", value_parser=ParsedAddress::parse, default_value = "default")]
+    address: ParsedAddress,
+
+    /// The validator type, as `<ADDRESS>::<MODULE_ID>::<STRUCT_NAME>`
+    /// Example: `0x42::webauthn_validator::WebauthnValidator`
+    #[clap(long, value_parser=ParsedStructType::parse)]
+    validator_type: ParsedStructType,
+
+    /// Sign the following transactions of the account with the installed auth validator
+    #[clap(long)]
+    use_for_signing: bool,
+
+    #[clap(flatten)]
+    pub context_options: WalletContextOptions,
+}
+
+#[async_trait]
+impl CommandAction<ExecuteTransactionResponseView> for RegisterCommand {
+    async fn execute(self) -> RoochResult<ExecuteTransactionResponseView> {
+        let mut context = self.context_options.build()?;
+        let mapping = context.address_mapping();
+        let sender: RoochAddress = self.address.into_account_address(&mapping)?.into();
+        let validator_type = self.validator_type.into_struct_tag(&mapping)?;
+
+        let action = AuthenticationKeyModule::install_auth_validator_action(validator_type);
+        let result = if context.keystore.get_if_password_is_empty() {
+            context.sign_and_execute(sender, action, None).await?
+        } else {
+            let password = prompt_password("Enter the password to install the auth validator:")
+                .unwrap_or_default();
+            let is_verified =
+                verify_password(Some(password.clone()), context.keystore.get_password_hash())?;
+
+            if !is_verified {
+                return Err(RoochError::InvalidPasswordError(
+                    "Password is invalid".to_owned(),
+                ));
+            }
+
+            context
+                .sign_and_execute(sender, action, Some(password))
+                .await?
+        };
+        let result = context.assert_execute_success(result)?;
+
+        if self.use_for_signing {
+            // The installed auth validator is the last one of the account
+            let client = context.get_client().await?;
+            let auth_validator_id = client
+                .as_module_binding::<AuthenticationKeyModule>()
+                .installed_auth_validators(sender.into())?
+                .pop()
+                .ok_or_else(|| {
+                    RoochError::ViewFunctionError(format!(
+                        "Failed to get the installed auth validator of {}",
+                        sender
+                    ))
+                })?;
+            let auth_validator = client
+                .as_module_binding::<AuthValidatorRegistryModule>()
+                .get_validator(auth_validator_id)?;
+            context
+                .client_config
+                .auth_validators
+                .insert(sender, auth_validator_id);
+            context.client_config.save()?;
+            println!(
+                "Sign the transactions of {} with the auth validator {}: {:?}",
+                sender, auth_validator_id, auth_validator
+            );
+        }
+        Ok(result)
+    }
+}
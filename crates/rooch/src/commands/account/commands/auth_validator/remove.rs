@@ -0,0 +1,73 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::cli_types::{CommandAction, WalletContextOptions};
+use async_trait::async_trait;
+use clap::Parser;
+use move_command_line_common::address::ParsedAddress;
+use move_command_line_common::types::ParsedStructType;
+use rooch_key::key_derive::verify_password;
+use rooch_key::keystore::account_keystore::AccountKeystore;
+use rooch_rpc_api::jsonrpc_types::ExecuteTransactionResponseView;
+use rooch_types::{
+    address::RoochAddress,
+    error::{RoochError, RoochResult},
+    framework::account_authentication::AuthenticationKeyModule,
+};
+use rpassword::prompt_password;
+
+/// Uninstall the auth validator of the account, the account signs with the native validator again
+#[derive(Debug, Parser)]
+pub struct RemoveCommand {
+    /// The account to uninstall the auth validator.
+    #[clap(short = 'a', long = "address", value_parser=ParsedAddress::parse, default_value = "default")]
+    address: ParsedAddress,
+
+    /// The validator type, as `<ADDRESS>::<MODULE_ID>::<STRUCT_NAME>`
+    /// Example: `0x42::webauthn_validator::WebauthnValidator`
+    #[clap(long, value_parser=ParsedStructType::parse)]
+    validator_type: ParsedStructType,
+
+    #[clap(flatten)]
+    pub context_options: WalletContextOptions,
+}
+
+#[async_trait]
+impl CommandAction<ExecuteTransactionResponseView> for RemoveCommand {
+    async fn execute(self) -> RoochResult<ExecuteTransactionResponseView> {
+        let mut context = self.context_options.build()?;
+        let mapping = context.address_mapping();
+        let sender: RoochAddress = self.address.into_account_address(&mapping)?.into();
+        let validator_type = self.validator_type.into_struct_tag(&mapping)?;
+
+        // The transaction can not be validated by the validator being uninstalled, sign it with the native validator
+        let signing_auth_validator = context.client_config.auth_validators.remove(&sender);
+
+        let action = AuthenticationKeyModule::uninstall_auth_validator_action(validator_type);
+        let result = if context.keystore.get_if_password_is_empty() {
+            context.sign_and_execute(sender, action, None).await
+        } else {
+            let password = prompt_password("Enter the password to uninstall the auth validator:")
+                .unwrap_or_default();
+            let is_verified =
+                verify_password(Some(password.clone()), context.keystore.get_password_hash())?;
+
+            if !is_verified {
+                return Err(RoochError::InvalidPasswordError(
+                    "Password is invalid".to_owned(),
+                ));
+            }
+
+            context
+                .sign_and_execute(sender, action, Some(password))
+                .await
+        }
+        .and_then(|result| context.assert_execute_success(result));
+
+        let result = result?;
+        if signing_auth_validator.is_some() {
+            context.client_config.save()?;
+        }
+        Ok(result)
+    }
+}
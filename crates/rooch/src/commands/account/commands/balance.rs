@@ -12,7 +12,7 @@ use move_command_line_common::types::ParsedStructType;
 use rooch_rpc_api::api::MAX_RESULT_LIMIT_USIZE;
 use rooch_types::error::RoochResult;
 
-/// Show account balance, only the accounts managed by the current node are supported
+/// Show the balances of all the coins of the account, or the balance of the given coin type
 #[derive(Debug, Parser)]
 pub struct BalanceCommand {
     #[clap(short = 'a', long = "address", value_parser=ParsedAddress::parse, default_value = "default")]
@@ -24,6 +24,10 @@ pub struct BalanceCommand {
     #[clap(long, value_parser=ParsedStructType::parse)]
     coin_type: Option<ParsedStructType>,
 
+    /// Return command outputs in json format
+    #[clap(long, default_value = "false")]
+    json: bool,
+
     #[clap(flatten)]
     pub context_options: WalletContextOptions,
 }
@@ -50,27 +54,42 @@ impl CommandAction<()> for BalanceCommand {
                 ]
             }
             None => {
-                client
-                    .rooch
-                    .get_balances(address_addr.into(), None, Some(MAX_RESULT_LIMIT_USIZE))
-                    .await?
-                    .data
+                // Load all the pages of the coin stores of the account
+                let mut balances = vec![];
+                let mut cursor = None;
+                loop {
+                    let page = client
+                        .rooch
+                        .get_balances(address_addr.into(), cursor, Some(MAX_RESULT_LIMIT_USIZE))
+                        .await?;
+                    balances.extend(page.data);
+                    if !page.has_next_page {
+                        break;
+                    }
+                    cursor = page.next_cursor;
+                }
+                balances
             }
         };
 
+        if self.json {
+            println!("{}", serde_json::to_string_pretty(&balances).unwrap());
+            return Ok(());
+        }
+
         println!(
-            "{0: ^102} | {1: ^16} | {2: ^6} |  {3: ^32} ",
-            "Coin Type", "Symbol", "Decimals", "Balance"
+            "{0: ^16} | {1: ^32} | {2: ^8} | {3: ^66}",
+            "Symbol", "Balance", "Decimals", "Coin Type"
         );
-        println!("{}", ["-"; 68].join(""));
+        println!("{}", ["-"; 132].join(""));
 
         for balance_info in balances {
             println!(
-                "{0: ^102} | {1: ^16} | {2: ^6} | {3: ^32} ",
-                balance_info.coin_info.coin_type,
+                "{0: ^16} | {1: ^32} | {2: ^8} | {3: ^66}",
                 balance_info.coin_info.symbol,
+                balance_info.get_balance_show(),
                 balance_info.coin_info.decimals,
-                balance_info.balance,
+                balance_info.coin_info.coin_type,
             );
         }
 
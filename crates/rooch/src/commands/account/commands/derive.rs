@@ -0,0 +1,82 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::cli_types::{CommandAction, WalletContextOptions};
+use async_trait::async_trait;
+use clap::Parser;
+use move_core_types::account_address::AccountAddress;
+use rooch_key::key_derive::verify_password;
+use rooch_key::keystore::account_keystore::AccountKeystore;
+use rooch_types::error::{RoochError, RoochResult};
+use rpassword::prompt_password;
+use serde::{Deserialize, Serialize};
+
+/// Derive an account from the mnemonic of the keystore.
+///
+/// The key is derived with the BIP-44 derivation path `m/44'/784'/0'/0'/{index}'`,
+/// so the same mnemonic always derives the same address for the same index.
+#[derive(Debug, Parser)]
+pub struct DeriveCommand {
+    /// The account index in the derivation path, the next unused index of the mnemonic if not set.
+    #[clap(long)]
+    index: Option<u32>,
+
+    #[clap(flatten)]
+    pub context_options: WalletContextOptions,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DerivedAccountView {
+    pub address: String,
+    pub derivation_path: String,
+}
+
+#[async_trait]
+impl CommandAction<DerivedAccountView> for DeriveCommand {
+    async fn execute(self) -> RoochResult<DerivedAccountView> {
+        let mut context = self.context_options.build()?;
+        let password = if context.keystore.get_if_password_is_empty() {
+            None
+        } else {
+            let password =
+                prompt_password("Enter the password to derive a new key pair:").unwrap_or_default();
+            let is_verified =
+                verify_password(Some(password.clone()), context.keystore.get_password_hash())?;
+
+            if !is_verified {
+                return Err(RoochError::InvalidPasswordError(
+                    "Password is invalid".to_owned(),
+                ));
+            }
+            Some(password)
+        };
+
+        let result = match self.index {
+            Some(index) => context.keystore.derive_new_key(index, password)?,
+            None => {
+                if context.keystore.get_mnemonics(password.clone())?.is_empty() {
+                    return Err(RoochError::CommandArgumentError(
+                        "Cannot find mnemonic in the keystore, create an account first".to_owned(),
+                    ));
+                }
+                context
+                    .keystore
+                    .generate_and_add_new_key(None, None, None, password)?
+            }
+        };
+        let derivation_path = context
+            .keystore
+            .get_derivation_path(&result.address)
+            .map(|derivation_path| derivation_path.to_string())
+            .unwrap_or_default();
+        println!(
+            "Derived keypair for address [{}] with derivation path [{}]",
+            result.address, derivation_path
+        );
+
+        Ok(DerivedAccountView {
+            address: AccountAddress::from(result.address).to_hex_literal(),
+            derivation_path,
+        })
+    }
+}
@@ -0,0 +1,186 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::cli_types::WalletContextOptions;
+use clap::Parser;
+use fastcrypto::traits::EncodeDecodeBase64;
+use move_command_line_common::address::ParsedAddress;
+use rooch_key::key_derive::{encrypt_key, verify_password};
+use rooch_key::keystore::account_keystore::AccountKeystore;
+use rooch_types::address::RoochAddress;
+use rooch_types::error::{RoochError, RoochResult};
+use rpassword::prompt_password;
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+/// Export the private key or the mnemonic phrase of an account in rooch.keystore.
+///
+/// The exported secret is never printed to the terminal in plaintext:
+/// use `--encrypt` to print a password-protected form, or `--output` to write it to a new file only readable by the owner.
+#[derive(Debug, Parser)]
+pub struct ExportCommand {
+    /// Rooch address in string format, the active address is used by default.
+    #[clap(short = 'a', long = "address", value_parser=ParsedAddress::parse)]
+    address: Option<ParsedAddress>,
+    /// Export the mnemonic phrase which the address is derived from instead of the private key.
+    #[clap(long)]
+    mnemonic: bool,
+    /// Encrypt the exported secret with a new password (argon2 + chacha20poly1305).
+    #[clap(long)]
+    encrypt: bool,
+    /// Write the exported secret to the file instead of printing it, the file should not exist.
+    #[clap(short = 'o', long = "output")]
+    output: Option<PathBuf>,
+    #[clap(flatten)]
+    pub context_options: WalletContextOptions,
+}
+
+impl ExportCommand {
+    pub async fn execute(self) -> RoochResult<String> {
+        if !self.encrypt && self.output.is_none() {
+            return Err(RoochError::CommandArgumentError(
+                "Refusing to print the secret in plaintext, please use --encrypt or --output"
+                    .to_owned(),
+            ));
+        }
+
+        let context = self.context_options.build()?;
+        let address: RoochAddress = match self.address {
+            Some(address) => context.resolve_address(address)?.into(),
+            None => context
+                .active_address()
+                .ok_or(RoochError::ActiveAddressDoesNotExistError)?,
+        };
+        if !context.keystore.addresses().contains(&address) {
+            return Err(RoochError::ExportAccountError(format!(
+                "Address `{}` does not in the Rooch keystore",
+                address
+            )));
+        }
+
+        let password = if context.keystore.get_if_password_is_empty() {
+            None
+        } else {
+            let password = prompt_password("Enter the password to export the account:")?;
+            let is_verified =
+                verify_password(Some(password.clone()), context.keystore.get_password_hash())?;
+
+            if !is_verified {
+                return Err(RoochError::InvalidPasswordError(
+                    "Password is invalid".to_owned(),
+                ));
+            }
+            Some(password)
+        };
+
+        let secret = if self.mnemonic {
+            context
+                .keystore
+                .get_mnemonics(password.clone())?
+                .into_iter()
+                .find(|mnemonic| mnemonic.mnemonic_data.addresses.contains(&address))
+                .map(|mnemonic| mnemonic.mnemonic_phrase)
+                .ok_or_else(|| {
+                    RoochError::ExportAccountError(format!(
+                        "Address `{}` is not derived from a mnemonic phrase in the Rooch keystore",
+                        address
+                    ))
+                })?
+        } else {
+            context
+                .keystore
+                .get_key_pair_with_password(&address, password)?
+                .encode_base64()
+        };
+
+        let content = if self.encrypt {
+            let export_password = check_export_password(
+                prompt_password("Enter a password to encrypt the export:")?,
+                prompt_password("Re-enter the password:")?,
+            )?;
+            let encryption = encrypt_key(secret.as_bytes(), Some(export_password))?;
+            serde_json::to_string_pretty(&encryption).map_err(|e| {
+                RoochError::ExportAccountError(format!("Serialize encryption data failed: {}", e))
+            })?
+        } else {
+            secret
+        };
+
+        match self.output {
+            Some(output) => {
+                write_secret_file(&output, &content)?;
+                Ok(format!(
+                    "Exported address `{}` to file {:?}",
+                    address,
+                    output.as_path()
+                ))
+            }
+            None => Ok(content),
+        }
+    }
+}
+
+fn check_export_password(password: String, confirm_password: String) -> RoochResult<String> {
+    if password.is_empty() {
+        return Err(RoochError::InvalidPasswordError(
+            "The password to encrypt the export can not be empty".to_owned(),
+        ));
+    }
+    if password != confirm_password {
+        return Err(RoochError::InvalidPasswordError(
+            "Passwords do not match".to_owned(),
+        ));
+    }
+    Ok(password)
+}
+
+/// Write the secret to a new file which is only readable and writable by the owner,
+/// an existing file is not overwritten as its permissions may be wider.
+fn write_secret_file(path: &Path, content: &str) -> RoochResult<()> {
+    let mut options = OpenOptions::new();
+    options.write(true).create_new(true);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::OpenOptionsExt;
+        options.mode(0o600);
+    }
+    let mut file = options.open(path).map_err(|e| {
+        RoochError::ExportAccountError(format!("Create file {:?} failed: {}", path, e))
+    })?;
+    file.write_all(content.as_bytes())?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check_export_password() {
+        assert_eq!(
+            check_export_password("password".to_owned(), "password".to_owned()).unwrap(),
+            "password"
+        );
+        assert!(check_export_password("password".to_owned(), "other".to_owned()).is_err());
+        assert!(check_export_password(String::new(), String::new()).is_err());
+    }
+
+    #[test]
+    fn test_write_secret_file() {
+        let temp_dir = moveos_config::temp_dir();
+        let path = temp_dir.path().join("secret");
+        write_secret_file(&path, "secret").unwrap();
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "secret");
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            let mode = std::fs::metadata(&path).unwrap().permissions().mode();
+            assert_eq!(mode & 0o777, 0o600);
+        }
+
+        // The existing file is not overwritten
+        assert!(write_secret_file(&path, "other").is_err());
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "secret");
+    }
+}
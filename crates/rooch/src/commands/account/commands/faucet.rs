@@ -0,0 +1,71 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::cli_types::{CommandAction, WalletContextOptions};
+use async_trait::async_trait;
+use clap::Parser;
+use move_command_line_common::address::ParsedAddress;
+use rooch_faucet::types::{FaucetErrorResponse, FaucetRequest, FaucetResponse};
+use rooch_faucet::FAUCET_PATH;
+use rooch_types::address::RoochAddress;
+use rooch_types::error::{RoochError, RoochResult};
+
+pub const DEFAULT_FAUCET_URL: &str = "http://127.0.0.1:6868";
+
+/// Request the gas coins from the faucet server, only available on the devnet and the local network
+#[derive(Debug, Parser)]
+pub struct FaucetCommand {
+    #[clap(short = 'a', long = "address", value_parser=ParsedAddress::parse, default_value = "default")]
+    /// The account's address to receive the gas coins, if absent, use the default active account.
+    address: ParsedAddress,
+
+    /// The url of the faucet server
+    #[clap(long, env = "ROOCH_FAUCET_URL", default_value = DEFAULT_FAUCET_URL)]
+    faucet_url: String,
+
+    /// The captcha response token, required if the faucet server enables the captcha verification
+    #[clap(long)]
+    captcha: Option<String>,
+
+    #[clap(flatten)]
+    pub context_options: WalletContextOptions,
+}
+
+#[async_trait]
+impl CommandAction<FaucetResponse> for FaucetCommand {
+    async fn execute(self) -> RoochResult<FaucetResponse> {
+        let context = self.context_options.build()?;
+        let mapping = context.address_mapping();
+        let recipient: RoochAddress = self.address.into_account_address(&mapping)?.into();
+
+        let url = format!("{}{}", self.faucet_url.trim_end_matches('/'), FAUCET_PATH);
+        let response = reqwest::Client::new()
+            .post(url.as_str())
+            .json(&FaucetRequest {
+                recipient: recipient.to_string(),
+                captcha: self.captcha,
+            })
+            .send()
+            .await
+            .map_err(|e| {
+                RoochError::UnexpectedError(format!("Request faucet {url} failed: {e}"))
+            })?;
+
+        if response.status().is_success() {
+            response
+                .json::<FaucetResponse>()
+                .await
+                .map_err(|e| RoochError::UnexpectedError(format!("Invalid faucet response: {e}")))
+        } else {
+            let status = response.status();
+            let error = response
+                .json::<FaucetErrorResponse>()
+                .await
+                .map(|e| e.error)
+                .unwrap_or_else(|_| status.to_string());
+            Err(RoochError::UnexpectedError(format!(
+                "Faucet request failed: {error}"
+            )))
+        }
+    }
+}
@@ -0,0 +1,99 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::cli_types::{CommandAction, WalletContextOptions};
+use async_trait::async_trait;
+use clap::Parser;
+use move_core_types::account_address::AccountAddress;
+use rooch_key::key_derive::verify_password;
+use rooch_key::keystore::account_keystore::AccountKeystore;
+use rooch_types::error::{RoochError, RoochResult};
+use rpassword::prompt_password;
+use serde::{Deserialize, Serialize};
+use std::io::BufRead;
+use std::path::{Path, PathBuf};
+
+/// Import a secp256k1 private key as a BIP-340 Schnorr key.
+///
+/// The key is the untweaked internal key of a BIP-86 taproot wallet, such as the key of the
+/// `m/86'/0'/0'/0/0` derivation path, not the tweaked output key of the taproot address.
+/// The Rooch address is derived from the x-only public key, and `rooch account show` derives the same
+/// taproot address as the wallet from it.
+///
+/// The private key is read from the hidden prompt by default, it is never passed as a command line argument.
+#[derive(Debug, Parser)]
+pub struct ImportCommand {
+    /// Read the secp256k1 private key in WIF or hex format from the file, or from the stdin if it is `-`.
+    #[clap(long)]
+    private_key_file: Option<PathBuf>,
+
+    #[clap(flatten)]
+    pub context_options: WalletContextOptions,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ImportedAccountView {
+    pub address: String,
+    pub public_key: String,
+}
+
+fn read_private_key(private_key_file: Option<&Path>) -> RoochResult<String> {
+    let private_key = match private_key_file {
+        Some(path) if path == Path::new("-") => {
+            let mut line = String::new();
+            std::io::stdin().lock().read_line(&mut line)?;
+            line
+        }
+        Some(path) => std::fs::read_to_string(path)?,
+        None => prompt_password("Enter the private key in WIF or hex format:")?,
+    };
+    Ok(private_key.trim().to_owned())
+}
+
+fn parse_private_key(private_key: &str) -> RoochResult<Vec<u8>> {
+    if let Ok(wif) = bitcoin::PrivateKey::from_wif(private_key) {
+        return Ok(wif.to_bytes());
+    }
+    hex::decode(private_key.strip_prefix("0x").unwrap_or(private_key)).map_err(|_| {
+        RoochError::CommandArgumentError(
+            "The private key should be in WIF or hex format".to_owned(),
+        )
+    })
+}
+
+#[async_trait]
+impl CommandAction<ImportedAccountView> for ImportCommand {
+    async fn execute(self) -> RoochResult<ImportedAccountView> {
+        let mut context = self.context_options.build()?;
+        let password = if context.keystore.get_if_password_is_empty() {
+            None
+        } else {
+            let password =
+                prompt_password("Enter the password to import the key pair:").unwrap_or_default();
+            let is_verified =
+                verify_password(Some(password.clone()), context.keystore.get_password_hash())?;
+
+            if !is_verified {
+                return Err(RoochError::InvalidPasswordError(
+                    "Password is invalid".to_owned(),
+                ));
+            }
+            Some(password)
+        };
+
+        let private_key = parse_private_key(&read_private_key(self.private_key_file.as_deref())?)?;
+        let address = context
+            .keystore
+            .import_schnorr_private_key(&private_key, password.clone())?;
+        let public_key = context
+            .keystore
+            .get_key_pair_with_password(&address, password)?
+            .public();
+        println!("Imported Schnorr keypair for address [{}]", address);
+
+        Ok(ImportedAccountView {
+            address: AccountAddress::from(address).to_hex_literal(),
+            public_key: hex::encode(public_key.as_ref()),
+        })
+    }
+}
@@ -5,7 +5,9 @@ use crate::cli_types::{CommandAction, WalletContextOptions};
 use async_trait::async_trait;
 use clap::Parser;
 use rooch_key::keystore::account_keystore::AccountKeystore;
+use rooch_key::keystore::file_keystore::KeystoreFileStatus;
 use rooch_key::keystore::types::LocalAccount;
+use rooch_key::keystore::Keystore;
 use rooch_types::{crypto::EncodeDecodeBase64, error::RoochResult};
 use rpassword::prompt_password;
 use serde::{Deserialize, Serialize};
@@ -32,7 +34,7 @@ pub struct AccountView {
 impl CommandAction<()> for ListCommand {
     async fn execute(self) -> RoochResult<()> {
         let context = self.context_options.build()?;
-        let active_address = context.client_config.active_address;
+        let active_address = context.active_address();
 
         let password = if context.keystore.get_if_password_is_empty() {
             None
@@ -91,6 +93,14 @@ impl CommandAction<()> for ListCommand {
             );
         }
 
+        if let Keystore::File(file_keystore) = &context.keystore {
+            if file_keystore.status() == KeystoreFileStatus::Plaintext {
+                println!(
+                    "\nThe keystore is stored in plaintext, run `rooch account lock` to encrypt it"
+                );
+            }
+        }
+
         Ok(())
     }
 }
@@ -0,0 +1,63 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::cli_types::{CommandAction, WalletContextOptions};
+use async_trait::async_trait;
+use clap::Parser;
+use rooch_key::keystore::encryption::ROOCH_KEYSTORE_PASSWORD_ENV;
+use rooch_key::keystore::Keystore;
+use rooch_types::error::{RoochError, RoochResult};
+use rpassword::prompt_password;
+use std::fmt::Debug;
+
+/// Encrypt the plaintext keystore file with a password, the keystore is never encrypted implicitly.
+/// The password is read from the `ROOCH_KEYSTORE_PASSWORD` environment variable or the prompt.
+#[derive(Debug, Parser)]
+pub struct LockCommand {
+    #[clap(flatten)]
+    pub context_options: WalletContextOptions,
+}
+
+#[async_trait]
+impl CommandAction<()> for LockCommand {
+    async fn execute(self) -> RoochResult<()> {
+        let mut context = self.context_options.build()?;
+        let file_keystore = match &mut context.keystore {
+            Keystore::File(file_keystore) => file_keystore,
+            _ => {
+                return Err(RoochError::CommandArgumentError(
+                    "Only the file based keystore can be locked".to_owned(),
+                ))
+            }
+        };
+        if file_keystore.is_encrypted() {
+            println!("The keystore is already locked");
+            return Ok(());
+        }
+
+        let password = match std::env::var(ROOCH_KEYSTORE_PASSWORD_ENV) {
+            Ok(password) => password,
+            Err(_) => {
+                let password = prompt_password("Enter a new keystore password:")?;
+                let confirmed = prompt_password("Confirm the keystore password:")?;
+                if password != confirmed {
+                    return Err(RoochError::InvalidPasswordError(
+                        "The passwords do not match".to_owned(),
+                    ));
+                }
+                password
+            }
+        };
+        if password.is_empty() {
+            return Err(RoochError::InvalidPasswordError(
+                "The keystore password can not be empty".to_owned(),
+            ));
+        }
+
+        file_keystore
+            .lock(password)
+            .map_err(|e| RoochError::CommandArgumentError(e.to_string()))?;
+        println!("The keystore was successfully locked");
+        Ok(())
+    }
+}
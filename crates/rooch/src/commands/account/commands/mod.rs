@@ -1,8 +1,22 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
 
+pub mod alias;
+pub mod auth_validator;
 pub mod balance;
 pub mod create;
+pub mod derive;
+pub mod export;
+pub mod faucet;
+pub mod import;
 pub mod list;
+pub mod lock;
+pub mod multisig;
+pub mod nonce;
 pub mod nullify;
+pub mod passkey;
+pub mod rotate_key;
+pub mod show;
 pub mod switch;
+pub mod transfer;
+pub mod unlock;
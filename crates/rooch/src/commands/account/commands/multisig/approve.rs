@@ -0,0 +1,173 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::cli_types::{CommandAction, FunctionArg, WalletContextOptions};
+use anyhow::Result;
+use async_trait::async_trait;
+use clap::Parser;
+use move_command_line_common::address::ParsedAddress;
+use move_command_line_common::types::ParsedStructType;
+use move_core_types::language_storage::TypeTag;
+use moveos_types::transaction::MoveAction;
+use rooch_key::key_derive::verify_password;
+use rooch_key::keystore::account_keystore::AccountKeystore;
+use rooch_key::multisig::{MultisigKeyPair, MultisigTransaction};
+use rooch_types::{
+    address::RoochAddress,
+    error::{RoochError, RoochResult},
+    function_arg::{parse_function_arg, ParsedFunctionId},
+    multisig::MultisigPublicKey,
+};
+use rpassword::prompt_password;
+use std::path::PathBuf;
+
+/// Approve a multisig transaction with the key of a member in rooch.keystore.
+///
+/// Approve an existing multisig transaction file with `--input`,
+/// or start a new multisig transaction of the Move function call with `--multisig-public-key` and `--function`.
+#[derive(Debug, Parser)]
+pub struct ApproveCommand {
+    /// The multisig transaction file to approve.
+    #[clap(long, conflicts_with_all = ["multisig_public_key", "function"])]
+    input: Option<PathBuf>,
+
+    /// The Base64 encoded multisig public key, required to start a new multisig transaction.
+    #[clap(long, requires = "function")]
+    multisig_public_key: Option<MultisigPublicKey>,
+
+    /// Function name as `<ADDRESS>::<MODULE_ID>::<FUNCTION_NAME>`
+    /// Example: `0x42::message::set_message`, `rooch_framework::empty::empty`
+    #[clap(long, requires = "multisig_public_key")]
+    function: Option<ParsedFunctionId>,
+
+    /// TypeTag arguments separated by spaces.
+    ///
+    /// Example: `0x1::M::T1 0x1::M::T2 rooch_framework::empty::Empty`
+    #[clap(
+        long = "type-args",
+        value_parser=ParsedStructType::parse,
+    )]
+    type_args: Vec<ParsedStructType>,
+
+    /// Arguments combined with their type separated by spaces.
+    ///
+    /// Supported types [u8, u16, u32, u64, u128, u256, bool, object_id, string, address, vector<inner_type>]
+    ///
+    /// Example: `address:0x1 bool:true u8:0 u256:1234 'vector<u32>:a,b,c,d'`
+    ///     address and uint can be written in short form like `@0x1 1u8 4123u256`.
+    #[clap(long = "args", value_parser=parse_function_arg)]
+    args: Vec<FunctionArg>,
+
+    /// The address of the member key in rooch.keystore, the active address is used by default.
+    #[clap(short = 'a', long = "address", value_parser=ParsedAddress::parse)]
+    address: Option<ParsedAddress>,
+
+    /// Write the approved multisig transaction to the file, the input file is overwritten by default.
+    #[clap(short = 'o', long = "output")]
+    output: Option<PathBuf>,
+
+    #[clap(flatten)]
+    pub context_options: WalletContextOptions,
+}
+
+#[async_trait]
+impl CommandAction<String> for ApproveCommand {
+    async fn execute(self) -> RoochResult<String> {
+        let context = self.context_options.build()?;
+        let address: RoochAddress = match self.address {
+            Some(address) => context.resolve_address(address)?.into(),
+            None => context
+                .active_address()
+                .ok_or(RoochError::ActiveAddressDoesNotExistError)?,
+        };
+
+        let (mut multisig_tx, output) = match (self.input, self.multisig_public_key, self.function)
+        {
+            (Some(input), _, _) => {
+                let content = std::fs::read_to_string(&input)?;
+                let multisig_tx: MultisigTransaction =
+                    serde_json::from_str(&content).map_err(|e| {
+                        RoochError::CommandArgumentError(format!(
+                            "Invalid multisig transaction file {:?}: {}",
+                            input, e
+                        ))
+                    })?;
+                (multisig_tx, self.output.unwrap_or(input))
+            }
+            (None, Some(multisig_public_key), Some(function)) => {
+                let output = self.output.ok_or_else(|| {
+                    RoochError::CommandArgumentError(
+                        "The --output is required to start a new multisig transaction".to_owned(),
+                    )
+                })?;
+                let address_mapping = context.address_mapping();
+                let function_id = function.into_function_id(&address_mapping)?;
+                let args = self
+                    .args
+                    .into_iter()
+                    .map(|arg| arg.into_bytes(&address_mapping))
+                    .collect::<Result<Vec<_>>>()?;
+                let type_args = self
+                    .type_args
+                    .into_iter()
+                    .map(|tag| {
+                        Ok(TypeTag::Struct(Box::new(
+                            tag.into_struct_tag(&address_mapping)?,
+                        )))
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                let action = MoveAction::new_function_call(function_id, type_args, args);
+                let tx_data = context
+                    .build_tx_data(multisig_public_key.address(), action)
+                    .await?;
+                (
+                    MultisigTransaction::new(multisig_public_key, tx_data),
+                    output,
+                )
+            }
+            _ => {
+                return Err(RoochError::CommandArgumentError(
+                    "Either --input or --multisig-public-key with --function is required"
+                        .to_owned(),
+                ))
+            }
+        };
+
+        let password = if context.keystore.get_if_password_is_empty() {
+            None
+        } else {
+            let password = prompt_password("Enter the password to approve the transaction:")
+                .unwrap_or_default();
+            let is_verified =
+                verify_password(Some(password.clone()), context.keystore.get_password_hash())?;
+
+            if !is_verified {
+                return Err(RoochError::InvalidPasswordError(
+                    "Password is invalid".to_owned(),
+                ));
+            }
+            Some(password)
+        };
+
+        let kp = context
+            .keystore
+            .get_key_pair_with_password(&address, password)?;
+        let multisig_kp = MultisigKeyPair::new(multisig_tx.public_key.clone(), vec![kp])?;
+        multisig_kp.approve(&mut multisig_tx)?;
+
+        let content = serde_json::to_string_pretty(&multisig_tx).map_err(|e| {
+            RoochError::CommandArgumentError(format!(
+                "Serialize multisig transaction failed: {}",
+                e
+            ))
+        })?;
+        std::fs::write(&output, content)?;
+        Ok(format!(
+            "Approved the multisig transaction with address `{}`, {}/{} approvals, saved to file {:?}",
+            address,
+            multisig_tx.approvals.len(),
+            multisig_tx.public_key.threshold(),
+            output.as_path()
+        ))
+    }
+}
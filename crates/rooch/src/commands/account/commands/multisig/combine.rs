@@ -0,0 +1,67 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::cli_types::{CommandAction, WalletContextOptions};
+use async_trait::async_trait;
+use clap::Parser;
+use rooch_key::multisig::MultisigTransaction;
+use rooch_rpc_api::jsonrpc_types::ExecuteTransactionResponseView;
+use rooch_types::error::{RoochError, RoochResult};
+use std::path::PathBuf;
+
+/// Combine the approvals of a multisig transaction and submit it.
+///
+/// The files are the copies of the same multisig transaction approved by different members,
+/// the combined approvals must reach the threshold of the multisig account.
+#[derive(Debug, Parser)]
+pub struct CombineCommand {
+    /// The approved multisig transaction files, separated by spaces.
+    #[clap(long = "files", num_args = 1.., required = true)]
+    files: Vec<PathBuf>,
+
+    #[clap(flatten)]
+    pub context_options: WalletContextOptions,
+}
+
+#[async_trait]
+impl CommandAction<ExecuteTransactionResponseView> for CombineCommand {
+    async fn execute(self) -> RoochResult<ExecuteTransactionResponseView> {
+        let context = self.context_options.build()?;
+
+        let mut combined: Option<MultisigTransaction> = None;
+        for file in self.files {
+            let content = std::fs::read_to_string(&file)?;
+            let multisig_tx: MultisigTransaction = serde_json::from_str(&content).map_err(|e| {
+                RoochError::CommandArgumentError(format!(
+                    "Invalid multisig transaction file {:?}: {}",
+                    file, e
+                ))
+            })?;
+            match combined.as_mut() {
+                Some(combined) => combined.combine(multisig_tx).map_err(|e| {
+                    RoochError::CommandArgumentError(format!(
+                        "Failed to combine the multisig transaction file {:?}: {}",
+                        file, e
+                    ))
+                })?,
+                None => combined = Some(multisig_tx),
+            }
+        }
+        let multisig_tx = combined.ok_or_else(|| {
+            RoochError::CommandArgumentError(
+                "At least one multisig transaction file is required".to_owned(),
+            )
+        })?;
+        if !multisig_tx.is_ready() {
+            return Err(RoochError::CommandArgumentError(format!(
+                "Not enough approvals, got {}, the threshold is {}",
+                multisig_tx.approvals.len(),
+                multisig_tx.public_key.threshold()
+            )));
+        }
+
+        let tx = multisig_tx.into_transaction()?;
+        let result = context.execute(tx).await?;
+        context.assert_execute_success(result)
+    }
+}
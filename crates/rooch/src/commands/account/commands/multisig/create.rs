@@ -0,0 +1,46 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::cli_types::CommandAction;
+use async_trait::async_trait;
+use clap::Parser;
+use rooch_types::{
+    address::RoochAddress,
+    error::RoochResult,
+    multisig::{MultisigMemberPublicKey, MultisigPublicKey},
+};
+use serde::{Deserialize, Serialize};
+
+/// Create a k-of-n multisig account from the public keys of the members.
+///
+/// The account address is derived from the public keys and the threshold, no transaction is required.
+#[derive(Debug, Parser)]
+pub struct CreateCommand {
+    /// The Base64 encoded public keys of the members, separated by spaces.
+    /// The Rooch(Ed25519) public key is accepted as it is, the Secp256k1 public key is prefixed with the flag `0x01`.
+    #[clap(long = "public-keys", num_args = 1.., required = true)]
+    public_keys: Vec<MultisigMemberPublicKey>,
+    /// The number of the members required to approve a transaction.
+    #[clap(long)]
+    threshold: u8,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct MultisigAccountView {
+    pub address: RoochAddress,
+    /// The Base64 encoded multisig public key, used to approve the transactions of the account
+    pub multisig_public_key: String,
+    pub threshold: u8,
+}
+
+#[async_trait]
+impl CommandAction<MultisigAccountView> for CreateCommand {
+    async fn execute(self) -> RoochResult<MultisigAccountView> {
+        let multisig_public_key = MultisigPublicKey::new(self.public_keys, self.threshold)?;
+        Ok(MultisigAccountView {
+            address: multisig_public_key.address(),
+            multisig_public_key: multisig_public_key.to_string(),
+            threshold: multisig_public_key.threshold(),
+        })
+    }
+}
@@ -0,0 +1,42 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::cli_types::CommandAction;
+use approve::ApproveCommand;
+use async_trait::async_trait;
+use combine::CombineCommand;
+use create::CreateCommand;
+use rooch_types::error::RoochResult;
+
+pub mod approve;
+pub mod combine;
+pub mod create;
+
+/// Manage the k-of-n multisig accounts, which are controlled by the shared keys of a team.
+///
+/// A multisig transaction is approved by the members one by one with `approve`,
+/// the approval files are exchanged between the members, and submitted with `combine` once they reach the threshold.
+#[derive(Debug, clap::Parser)]
+pub struct Multisig {
+    #[clap(subcommand)]
+    cmd: MultisigCommand,
+}
+
+#[async_trait]
+impl CommandAction<String> for Multisig {
+    async fn execute(self) -> RoochResult<String> {
+        match self.cmd {
+            MultisigCommand::Create(create) => create.execute_serialized().await,
+            MultisigCommand::Approve(approve) => approve.execute_serialized().await,
+            MultisigCommand::Combine(combine) => combine.execute_serialized().await,
+        }
+    }
+}
+
+#[derive(Debug, clap::Subcommand)]
+#[clap(name = "multisig")]
+pub enum MultisigCommand {
+    Create(CreateCommand),
+    Approve(ApproveCommand),
+    Combine(CombineCommand),
+}
@@ -0,0 +1,84 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::cli_types::{CommandAction, WalletContextOptions};
+use async_trait::async_trait;
+use clap::Parser;
+use move_command_line_common::address::ParsedAddress;
+use moveos_types::h256::H256;
+use rooch_types::address::RoochAddress;
+use rooch_types::error::{RoochError, RoochResult};
+use serde::{Deserialize, Serialize};
+
+/// Show the sequence numbers of the account, and the in-flight transactions tracked by the local nonce manager
+#[derive(Debug, Parser)]
+pub struct NonceCommand {
+    /// The account to show the sequence numbers, if absent, show the default active account.
+    #[clap(short = 'a', long = "address", value_parser=ParsedAddress::parse, default_value = "default")]
+    address: ParsedAddress,
+
+    /// Drop the in-flight transactions of the account from the local nonce manager,
+    /// such as the in-flight transactions are never executed.
+    #[clap(long)]
+    reset: bool,
+
+    #[clap(flatten)]
+    pub context_options: WalletContextOptions,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct InFlightTransactionView {
+    pub sequence_number: u64,
+    pub tx_hash: H256,
+    pub max_gas_amount: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct NonceView {
+    pub address: String,
+    /// The sequence number of the next transaction on-chain
+    pub onchain_sequence_number: u64,
+    /// The sequence number used by the next transaction of the wallet
+    pub next_sequence_number: u64,
+    pub in_flight: Vec<InFlightTransactionView>,
+}
+
+#[async_trait]
+impl CommandAction<NonceView> for NonceCommand {
+    async fn execute(self) -> RoochResult<NonceView> {
+        let context = self.context_options.build()?;
+        let address: RoochAddress = context.resolve_address(self.address)?.into();
+        let client = context.get_client().await?;
+        let onchain_sequence_number = client
+            .rooch
+            .get_sequence_number(address)
+            .await
+            .map_err(RoochError::from)?;
+
+        let mut next_sequence_number = onchain_sequence_number;
+        context.update_nonce_manager(|nonce_manager| {
+            if self.reset {
+                nonce_manager.reset(&address);
+            }
+            next_sequence_number =
+                nonce_manager.next_sequence_number(&address, onchain_sequence_number);
+        })?;
+        let in_flight = context
+            .nonce_manager()
+            .in_flight_transactions(&address)
+            .into_iter()
+            .map(|(sequence_number, tx)| InFlightTransactionView {
+                sequence_number,
+                tx_hash: tx.tx_hash,
+                max_gas_amount: tx.max_gas_amount,
+            })
+            .collect();
+
+        Ok(NonceView {
+            address: address.to_string(),
+            onchain_sequence_number,
+            next_sequence_number,
+            in_flight,
+        })
+    }
+}
@@ -2,6 +2,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use clap::Parser;
+use move_command_line_common::address::ParsedAddress;
 use move_core_types::account_address::AccountAddress;
 use rooch_key::key_derive::verify_password;
 use rooch_rpc_api::jsonrpc_types::ExecuteTransactionResponseView;
@@ -17,14 +18,13 @@ use rooch_types::{
 
 use crate::cli_types::{CommandAction, WalletContextOptions};
 use rooch_key::keystore::account_keystore::AccountKeystore;
-use std::str::FromStr;
 
 /// Nullify a keypair from a selected coin id with a Rooch address in rooch.keystore
 #[derive(Debug, Parser)]
 pub struct NullifyCommand {
-    /// Rooch address in string format.
-    #[clap(short = 'a', long = "address")]
-    address: String,
+    /// Rooch address in string format, or the name in the address book.
+    #[clap(short = 'a', long = "address", value_parser=ParsedAddress::parse)]
+    address: ParsedAddress,
     #[clap(flatten)]
     pub context_options: WalletContextOptions,
 }
@@ -34,9 +34,7 @@ impl CommandAction<ExecuteTransactionResponseView> for NullifyCommand {
     async fn execute(self) -> RoochResult<ExecuteTransactionResponseView> {
         let mut context = self.context_options.build()?;
 
-        let existing_address = RoochAddress::from_str(self.address.as_str()).map_err(|e| {
-            RoochError::CommandArgumentError(format!("Invalid Rooch address String: {}", e))
-        })?;
+        let existing_address: RoochAddress = context.resolve_address(self.address)?.into();
 
         println!(
             "{}",
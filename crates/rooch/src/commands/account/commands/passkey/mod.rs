@@ -0,0 +1,39 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::cli_types::CommandAction;
+use async_trait::async_trait;
+use register::RegisterCommand;
+use remove::RemoveCommand;
+use rooch_types::error::RoochResult;
+
+pub mod register;
+pub mod remove;
+
+/// Manage the WebAuthn passkey of an account.
+///
+/// The passkey credential is registered with the COSE_Key public key returned by the attestation and its relying party,
+/// then the transactions of the account can be signed by the authenticator on the registered origin,
+/// with the base64url encoded tx hash as the challenge.
+#[derive(Debug, clap::Parser)]
+pub struct Passkey {
+    #[clap(subcommand)]
+    cmd: PasskeyCommand,
+}
+
+#[async_trait]
+impl CommandAction<String> for Passkey {
+    async fn execute(self) -> RoochResult<String> {
+        match self.cmd {
+            PasskeyCommand::Register(register) => register.execute_serialized().await,
+            PasskeyCommand::Remove(remove) => remove.execute_serialized().await,
+        }
+    }
+}
+
+#[derive(Debug, clap::Subcommand)]
+#[clap(name = "passkey")]
+pub enum PasskeyCommand {
+    Register(RegisterCommand),
+    Remove(RemoveCommand),
+}
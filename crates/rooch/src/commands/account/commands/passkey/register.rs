@@ -0,0 +1,105 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::cli_types::{CommandAction, WalletContextOptions};
+use async_trait::async_trait;
+use clap::Parser;
+use move_command_line_common::address::ParsedAddress;
+use rooch_key::key_derive::verify_password;
+use rooch_key::keystore::account_keystore::AccountKeystore;
+use rooch_rpc_api::jsonrpc_types::TransactionExecutionInfoView;
+use rooch_types::{
+    address::RoochAddress,
+    error::{RoochError, RoochResult},
+    framework::webauthn_validator::WebauthnValidatorModule,
+    webauthn,
+};
+use rpassword::prompt_password;
+use serde::{Deserialize, Serialize};
+
+/// Register a passkey credential for the account, the account key signs the registration.
+#[derive(Debug, Parser)]
+pub struct RegisterCommand {
+    /// The account to register the passkey.
+    #[clap(short = 'a', long = "address", value_parser=ParsedAddress::parse, default_value = "default")]
+    address: ParsedAddress,
+
+    /// The hex encoded COSE_Key of the credential, as the `credentialPublicKey` in the attested credential data.
+    /// Only the ES256 key over the P-256 curve is supported.
+    #[clap(long, value_parser=parse_cose_key)]
+    cose_key: Vec<u8>,
+
+    /// The relying party id the credential is created for, such as `rooch.network`.
+    #[clap(long)]
+    rp_id: String,
+
+    /// The origin of the web page the passkey signs on, default to `https://<rp-id>`.
+    #[clap(long)]
+    origin: Option<String>,
+
+    #[clap(flatten)]
+    pub context_options: WalletContextOptions,
+}
+
+fn parse_cose_key(s: &str) -> Result<Vec<u8>, anyhow::Error> {
+    Ok(hex::decode(s.strip_prefix("0x").unwrap_or(s))?)
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RegisterPasskeyView {
+    pub address: String,
+    /// The hex encoded compressed P-256 public key of the passkey
+    pub public_key: String,
+    pub authentication_key: String,
+    pub rp_id: String,
+    pub origin: String,
+    pub execution_info: TransactionExecutionInfoView,
+}
+
+#[async_trait]
+impl CommandAction<RegisterPasskeyView> for RegisterCommand {
+    async fn execute(self) -> RoochResult<RegisterPasskeyView> {
+        let context = self.context_options.build()?;
+        let sender: RoochAddress = context.resolve_address(self.address)?.into();
+        // Check the COSE key before submitting, the validator aborts on the invalid key
+        let public_key = webauthn::cose_key_to_public_key(&self.cose_key)?;
+
+        let origin = self
+            .origin
+            .unwrap_or_else(|| format!("https://{}", self.rp_id));
+
+        let action = WebauthnValidatorModule::rotate_authentication_key_action(
+            self.cose_key,
+            &self.rp_id,
+            &origin,
+        );
+        let result = if context.keystore.get_if_password_is_empty() {
+            context.sign_and_execute(sender, action, None).await?
+        } else {
+            let password =
+                prompt_password("Enter the password to register the passkey:").unwrap_or_default();
+            let is_verified =
+                verify_password(Some(password.clone()), context.keystore.get_password_hash())?;
+
+            if !is_verified {
+                return Err(RoochError::InvalidPasswordError(
+                    "Password is invalid".to_owned(),
+                ));
+            }
+
+            context
+                .sign_and_execute(sender, action, Some(password))
+                .await?
+        };
+        let result = context.assert_execute_success(result)?;
+
+        Ok(RegisterPasskeyView {
+            address: sender.to_string(),
+            authentication_key: webauthn::public_key_to_authentication_key(&public_key).to_string(),
+            public_key: hex::encode(public_key),
+            rp_id: self.rp_id,
+            origin,
+            execution_info: result.execution_info,
+        })
+    }
+}
@@ -0,0 +1,56 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::cli_types::{CommandAction, WalletContextOptions};
+use async_trait::async_trait;
+use clap::Parser;
+use move_command_line_common::address::ParsedAddress;
+use rooch_key::key_derive::verify_password;
+use rooch_key::keystore::account_keystore::AccountKeystore;
+use rooch_rpc_api::jsonrpc_types::ExecuteTransactionResponseView;
+use rooch_types::{
+    address::RoochAddress,
+    error::{RoochError, RoochResult},
+    framework::auth_validator::BuiltinAuthValidator,
+};
+use rpassword::prompt_password;
+
+/// Remove the passkey of the account, the transactions signed by the passkey are rejected after it.
+#[derive(Debug, Parser)]
+pub struct RemoveCommand {
+    /// The account to remove the passkey.
+    #[clap(short = 'a', long = "address", value_parser=ParsedAddress::parse, default_value = "default")]
+    address: ParsedAddress,
+
+    #[clap(flatten)]
+    pub context_options: WalletContextOptions,
+}
+
+#[async_trait]
+impl CommandAction<ExecuteTransactionResponseView> for RemoveCommand {
+    async fn execute(self) -> RoochResult<ExecuteTransactionResponseView> {
+        let context = self.context_options.build()?;
+        let sender: RoochAddress = context.resolve_address(self.address)?.into();
+
+        let action = BuiltinAuthValidator::Webauthn.create_remove_authentication_key_action()?;
+        let result = if context.keystore.get_if_password_is_empty() {
+            context.sign_and_execute(sender, action, None).await?
+        } else {
+            let password =
+                prompt_password("Enter the password to remove the passkey:").unwrap_or_default();
+            let is_verified =
+                verify_password(Some(password.clone()), context.keystore.get_password_hash())?;
+
+            if !is_verified {
+                return Err(RoochError::InvalidPasswordError(
+                    "Password is invalid".to_owned(),
+                ));
+            }
+
+            context
+                .sign_and_execute(sender, action, Some(password))
+                .await?
+        };
+        context.assert_execute_success(result)
+    }
+}
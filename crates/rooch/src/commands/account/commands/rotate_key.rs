@@ -0,0 +1,180 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::cli_types::{CommandAction, WalletContextOptions};
+use async_trait::async_trait;
+use clap::Parser;
+use move_command_line_common::address::ParsedAddress;
+use moveos_types::module_binding::MoveFunctionCaller;
+use rooch_key::key_derive::{generate_new_key_pair, retrieve_key_pair, verify_password};
+use rooch_key::keystore::account_keystore::AccountKeystore;
+use rooch_rpc_api::jsonrpc_types::TransactionExecutionInfoView;
+use rooch_rpc_client::wallet_context::WalletContext;
+use rooch_types::{
+    address::RoochAddress,
+    authentication_key::AuthenticationKey,
+    crypto::PublicKey,
+    error::{RoochError, RoochResult},
+    framework::{
+        account_authentication::AuthenticationKeyModule, auth_validator::BuiltinAuthValidator,
+        native_validator::NativeValidator,
+    },
+};
+use rpassword::prompt_password;
+use serde::{Deserialize, Serialize};
+
+/// Rotate the key of an account.
+///
+/// A new key pair is generated and the authentication key of the account is rotated to it on-chain.
+/// The old key is kept in the keystore under the `rotated` tag until the rotation transaction is confirmed,
+/// use `--restore` to switch back to the old key if the rotation is not applied.
+#[derive(Debug, Parser)]
+pub struct RotateKeyCommand {
+    /// The account to rotate the key.
+    #[clap(short = 'a', long = "address", value_parser=ParsedAddress::parse, default_value = "default")]
+    address: ParsedAddress,
+
+    /// Restore the rotated old key of the account, the key generated by the unconfirmed rotation is dropped.
+    #[clap(long)]
+    restore: bool,
+
+    #[clap(flatten)]
+    pub context_options: WalletContextOptions,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RotateKeyView {
+    pub address: String,
+    /// The public key the account signs with after the command, `None` if the old key is restored
+    pub public_key: Option<PublicKey>,
+    /// The execution info of the rotation transaction, `None` if the old key is restored
+    pub execution_info: Option<TransactionExecutionInfoView>,
+}
+
+#[async_trait]
+impl CommandAction<RotateKeyView> for RotateKeyCommand {
+    async fn execute(self) -> RoochResult<RotateKeyView> {
+        let mut context = self.context_options.build()?;
+        let sender: RoochAddress = context.resolve_address(self.address)?.into();
+
+        if self.restore {
+            context.keystore.restore_rotated_key(&sender)?;
+            println!("Restored the rotated key of address [{}]", sender);
+            return Ok(RotateKeyView {
+                address: sender.to_string(),
+                public_key: None,
+                execution_info: None,
+            });
+        }
+
+        let password = if context.keystore.get_if_password_is_empty() {
+            None
+        } else {
+            let password =
+                prompt_password("Enter the password to rotate the key:").unwrap_or_default();
+            let is_verified =
+                verify_password(Some(password.clone()), context.keystore.get_password_hash())?;
+
+            if !is_verified {
+                return Err(RoochError::InvalidPasswordError(
+                    "Password is invalid".to_owned(),
+                ));
+            }
+            Some(password)
+        };
+
+        if context.keystore.has_rotated_key(&sender) {
+            // The previous rotation is interrupted, the current key is the one generated by it
+            let current_key = context
+                .keystore
+                .get_key_pair_with_password(&sender, password.clone())?;
+            if is_rotation_applied(&context, sender, current_key.public().authentication_key())
+                .await?
+            {
+                context.keystore.confirm_rotated_key(&sender)?;
+                println!(
+                    "Confirmed the previous key rotation of address [{}]",
+                    sender
+                );
+            } else {
+                return Err(RoochError::CommandArgumentError(format!(
+                    "The previous key rotation of address [{}] is not applied on-chain, run `rooch account rotate-key --restore` to restore the old key",
+                    sender
+                )));
+            }
+        }
+
+        let result = generate_new_key_pair(None, None, None, password.clone())?;
+        let new_key = retrieve_key_pair(
+            &result.key_pair_data.private_key_encryption,
+            password.clone(),
+        )?;
+        let new_public_key = new_key.public();
+        let action = BuiltinAuthValidator::Rooch
+            .create_rotate_authentication_key_action(new_public_key.as_ref().to_vec())?;
+
+        // Sign the rotation with the old key before it is replaced in the keystore
+        let tx = context.sign(sender, action, password).await?;
+        context
+            .keystore
+            .rotate_address_encryption_data(&sender, result.key_pair_data.private_key_encryption)?;
+        println!(
+            "Generated new keypair for address [{}], the old key is kept until the rotation is confirmed",
+            sender
+        );
+        println!(
+            "Secret Recovery Phrase : [{}]",
+            result.key_pair_data.mnemonic_phrase
+        );
+
+        let execute_result = context
+            .execute(tx)
+            .await
+            .and_then(|result| context.assert_execute_success(result));
+        match execute_result {
+            Ok(result) => {
+                context.keystore.confirm_rotated_key(&sender)?;
+                Ok(RotateKeyView {
+                    address: sender.to_string(),
+                    public_key: Some(new_public_key),
+                    execution_info: Some(result.execution_info),
+                })
+            }
+            Err(e) => {
+                // The execution may fail after the transaction is applied, such as the network error,
+                // so only restore the old key if the authentication key is not rotated on-chain.
+                match is_rotation_applied(&context, sender, new_public_key.authentication_key())
+                    .await
+                {
+                    Ok(false) => {
+                        context.keystore.restore_rotated_key(&sender)?;
+                        println!("Restored the old key of address [{}]", sender);
+                    }
+                    Ok(true) => {
+                        context.keystore.confirm_rotated_key(&sender)?;
+                    }
+                    Err(_) => {
+                        println!(
+                            "Failed to check the key rotation of address [{}], the old key is kept under the `rotated` tag",
+                            sender
+                        );
+                    }
+                }
+                Err(e)
+            }
+        }
+    }
+}
+
+/// Check whether the native authentication key of the account on-chain is the given key
+async fn is_rotation_applied(
+    context: &WalletContext,
+    address: RoochAddress,
+    authentication_key: AuthenticationKey,
+) -> RoochResult<bool> {
+    let client = context.get_client().await?;
+    let onchain_key = client
+        .as_module_binding::<AuthenticationKeyModule>()
+        .get_authentication_key::<NativeValidator>(address.into())?;
+    Ok(onchain_key.as_deref() == Some(authentication_key.as_ref()))
+}
@@ -0,0 +1,106 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::cli_types::{CommandAction, WalletContextOptions};
+use async_trait::async_trait;
+use clap::Parser;
+use move_command_line_common::address::ParsedAddress;
+use rooch_key::keystore::account_keystore::AccountKeystore;
+use rooch_types::address::RoochAddress;
+use rooch_types::bitcoin::network::Network;
+use rooch_types::crypto::{EncodeDecodeBase64, PublicKey};
+use rooch_types::error::{RoochError, RoochResult};
+use rpassword::prompt_password;
+use serde::{Deserialize, Serialize};
+
+/// Show the addresses of an account on all the supported chains, so the cross-chain identity mapping can be confirmed.
+///
+/// The Bitcoin taproot and Ethereum addresses are derived from the same key only if it is a Schnorr key in the local keystore,
+/// the Ed25519 key of a Rooch account has no address on other chains.
+#[derive(Debug, Parser)]
+pub struct ShowCommand {
+    /// The account to show, in hex, bech32 `rooch1...` or a named address, if absent, show the default active account.
+    #[clap(default_value = "default")]
+    address: String,
+
+    /// The Bitcoin network of the taproot address, one of bitcoin, testnet, signet and regtest
+    #[clap(long, default_value = "bitcoin", value_parser = parse_bitcoin_network)]
+    bitcoin_network: Network,
+
+    #[clap(flatten)]
+    pub context_options: WalletContextOptions,
+}
+
+fn parse_bitcoin_network(s: &str) -> Result<Network, anyhow::Error> {
+    Network::try_from(s)
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AccountShowView {
+    pub address: String,
+    pub bech32_address: String,
+    /// The BIP-86 taproot address derived from the Schnorr key
+    pub bitcoin_address: Option<String>,
+    /// The Ethereum address derived from the Schnorr key
+    pub ethereum_address: Option<String>,
+    /// The multichain address mapped to the account in the local keystore
+    pub multichain_address: Option<String>,
+    /// Base64 encoded public key, None if the account is not in the local keystore
+    pub public_key: Option<String>,
+}
+
+#[async_trait]
+impl CommandAction<AccountShowView> for ShowCommand {
+    async fn execute(self) -> RoochResult<AccountShowView> {
+        let context = self.context_options.build()?;
+        let address = match RoochAddress::from_bech32(&self.address) {
+            Ok(address) => address,
+            Err(_) => {
+                let parsed_address = ParsedAddress::parse(&self.address)
+                    .map_err(|e| RoochError::CommandArgumentError(e.to_string()))?;
+                context.resolve_address(parsed_address)?.into()
+            }
+        };
+
+        let account = if context.keystore.addresses().contains(&address) {
+            let password = if context.keystore.get_if_password_is_empty() {
+                None
+            } else {
+                Some(prompt_password("Enter the password to show the account:").unwrap_or_default())
+            };
+            context
+                .keystore
+                .get_accounts(password)?
+                .into_iter()
+                .find(|account| account.address == address)
+        } else {
+            None
+        };
+        let public_key = account
+            .as_ref()
+            .and_then(|account| account.public_key.clone());
+
+        let (bitcoin_address, ethereum_address) = match &public_key {
+            Some(PublicKey::Schnorr(public_key)) => (
+                Some(
+                    public_key
+                        .taproot_address()?
+                        .format(self.bitcoin_network.to_num())?,
+                ),
+                Some(public_key.ethereum_address()?.to_string()),
+            ),
+            _ => (None, None),
+        };
+
+        Ok(AccountShowView {
+            address: address.to_string(),
+            bech32_address: address.to_bech32(),
+            bitcoin_address,
+            ethereum_address,
+            multichain_address: account
+                .and_then(|account| account.multichain_address)
+                .map(|multichain_address| multichain_address.to_string()),
+            public_key: public_key.map(|public_key| public_key.encode_base64()),
+        })
+    }
+}
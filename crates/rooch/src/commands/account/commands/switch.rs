@@ -4,12 +4,13 @@
 use crate::cli_types::{CommandAction, WalletContextOptions};
 use async_trait::async_trait;
 use clap::Parser;
+use move_command_line_common::address::ParsedAddress;
 use rooch_key::keystore::account_keystore::AccountKeystore;
 use rooch_types::{
     address::RoochAddress,
     error::{RoochError, RoochResult},
 };
-use std::{fmt::Debug, str::FromStr};
+use std::fmt::Debug;
 
 /// Switch the active Rooch account
 #[derive(Debug, Parser)]
@@ -17,31 +18,29 @@ pub struct SwitchCommand {
     #[clap(flatten)]
     pub context_options: WalletContextOptions,
     /// The address of the Rooch account to be set as active
-    #[clap(short = 'a', long = "address")]
-    address: String,
+    #[clap(short = 'a', long = "address", value_parser=ParsedAddress::parse)]
+    address: ParsedAddress,
 }
 
 #[async_trait]
 impl CommandAction<()> for SwitchCommand {
     async fn execute(self) -> RoochResult<()> {
         let mut context = self.context_options.build()?;
-        let rooch_address = RoochAddress::from_str(self.address.as_str()).map_err(|e| {
-            RoochError::CommandArgumentError(format!("Invalid Rooch address String: {}", e))
-        })?;
+        let rooch_address: RoochAddress = context.resolve_address(self.address)?.into();
 
         if !context.keystore.addresses().contains(&rooch_address) {
             return Err(RoochError::SwitchAccountError(format!(
                 "Address `{}` does not in the Rooch keystore",
-                self.address
+                rooch_address
             )));
         }
 
-        context.client_config.active_address = Some(rooch_address);
+        context.set_active_address(rooch_address);
         context.client_config.save()?;
 
         println!(
             "The active account was successfully switched to `{}`",
-            self.address
+            rooch_address
         );
 
         Ok(())
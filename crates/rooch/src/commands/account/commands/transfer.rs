@@ -0,0 +1,130 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::cli_types::{CommandAction, TransactionOptions, WalletContextOptions};
+use async_trait::async_trait;
+use clap::Parser;
+use move_command_line_common::address::ParsedAddress;
+use move_command_line_common::types::ParsedStructType;
+use move_core_types::u256::U256;
+use moveos_types::h256::H256;
+use rooch_rpc_api::jsonrpc_types::{DryRunTransactionResponseView, ExecuteTransactionResponseView};
+use rooch_types::{
+    address::RoochAddress,
+    error::{RoochError, RoochResult},
+    framework::transfer::TransferModule,
+};
+use serde::{Deserialize, Serialize};
+
+/// Transfer coins to an address, the coin transfer entry function is called on behalf of the sender.
+///
+/// The transaction is submitted without waiting for it by default, use `--wait` to wait for the execution result.
+#[derive(Debug, Parser)]
+pub struct TransferCommand {
+    /// The recipient address.
+    #[clap(long, value_parser=ParsedAddress::parse)]
+    to: ParsedAddress,
+
+    /// The amount of the coin in the smallest unit.
+    #[clap(long)]
+    amount: U256,
+
+    /// Struct name as `<ADDRESS>::<MODULE_ID>::<STRUCT_NAME><TypeParam>`
+    /// Example: `0x3::gas_coin::GasCoin`, `0x123::Coin::Box<0x123::coin_box::FCoin>`
+    #[clap(long, value_parser=ParsedStructType::parse, default_value = "0x3::gas_coin::GasCoin")]
+    coin_type: ParsedStructType,
+
+    /// Wait for the transaction to be executed, and show the execution result.
+    #[clap(long, conflicts_with = "dry_run")]
+    wait: bool,
+
+    /// Dry run the transaction, the state changes are printed but not applied
+    #[clap(long)]
+    dry_run: bool,
+
+    /// Return command outputs in json format
+    #[clap(long, default_value = "false")]
+    json: bool,
+
+    #[clap(flatten)]
+    tx_options: TransactionOptions,
+
+    #[clap(flatten)]
+    pub context_options: WalletContextOptions,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum TransferView {
+    Submitted { tx_hash: H256 },
+    Executed(ExecuteTransactionResponseView),
+    DryRun(DryRunTransactionResponseView),
+}
+
+#[async_trait]
+impl CommandAction<()> for TransferCommand {
+    async fn execute(self) -> RoochResult<()> {
+        let mut context = self.context_options.build()?;
+        context.sequence_number = self.tx_options.sequence_number;
+        context.expiration_secs = self.tx_options.expiration_secs;
+        let mapping = context.address_mapping();
+        let sender: RoochAddress = context
+            .resolve_address(self.tx_options.sender.clone())?
+            .into();
+        let to = self.to.into_account_address(&mapping)?;
+        let coin_type = self.coin_type.into_struct_tag(&mapping)?;
+
+        let action =
+            TransferModule::create_transfer_coin_action(coin_type.clone(), to, self.amount);
+        let tx = self.tx_options.sign(&context, sender, action).await?;
+
+        let view = if self.dry_run {
+            let client = context.get_client().await?;
+            let resp = client
+                .rooch
+                .dry_run_tx(tx, false)
+                .await
+                .map_err(|e| RoochError::TransactionError(e.to_string()))?;
+            TransferView::DryRun(resp)
+        } else if self.wait {
+            TransferView::Executed(context.execute(tx).await?)
+        } else {
+            TransferView::Submitted {
+                tx_hash: context.send(tx).await?,
+            }
+        };
+
+        if self.json {
+            println!("{}", serde_json::to_string_pretty(&view).unwrap());
+            return Ok(());
+        }
+
+        println!(
+            "Transfer {} {} from {} to {}",
+            self.amount,
+            coin_type,
+            sender,
+            to.to_hex_literal()
+        );
+        match view {
+            TransferView::Submitted { tx_hash } => {
+                println!("Submitted transaction {:?}", tx_hash);
+            }
+            TransferView::Executed(resp) => {
+                println!(
+                    "Executed transaction {}, status: {:?}, gas used: {}",
+                    resp.execution_info.tx_hash,
+                    resp.execution_info.status,
+                    resp.execution_info.gas_used
+                );
+            }
+            TransferView::DryRun(resp) => {
+                println!(
+                    "Dry run status: {:?}, gas used: {}",
+                    resp.status, resp.gas_used
+                );
+            }
+        }
+        Ok(())
+    }
+}
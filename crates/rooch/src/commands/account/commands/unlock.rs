@@ -0,0 +1,43 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::cli_types::{CommandAction, WalletContextOptions};
+use async_trait::async_trait;
+use clap::Parser;
+use rooch_key::keystore::Keystore;
+use rooch_types::error::{RoochError, RoochResult};
+use std::fmt::Debug;
+
+/// Decrypt the keystore file and store it in plaintext until it is locked again.
+/// The password is read from the `ROOCH_KEYSTORE_PASSWORD` environment variable or the prompt.
+#[derive(Debug, Parser)]
+pub struct UnlockCommand {
+    #[clap(flatten)]
+    pub context_options: WalletContextOptions,
+}
+
+#[async_trait]
+impl CommandAction<()> for UnlockCommand {
+    async fn execute(self) -> RoochResult<()> {
+        // The encrypted keystore is decrypted with the password when building the context
+        let mut context = self.context_options.build()?;
+        let file_keystore = match &mut context.keystore {
+            Keystore::File(file_keystore) => file_keystore,
+            _ => {
+                return Err(RoochError::CommandArgumentError(
+                    "Only the file based keystore can be unlocked".to_owned(),
+                ))
+            }
+        };
+        if !file_keystore.is_encrypted() {
+            println!("The keystore is not locked");
+            return Ok(());
+        }
+
+        file_keystore
+            .unlock()
+            .map_err(|e| RoochError::CommandArgumentError(e.to_string()))?;
+        println!("The keystore was successfully unlocked");
+        Ok(())
+    }
+}
@@ -5,7 +5,11 @@ use crate::cli_types::CommandAction;
 use crate::commands::account::commands::balance::BalanceCommand;
 use async_trait::async_trait;
 use commands::{
-    create::CreateCommand, list::ListCommand, nullify::NullifyCommand, switch::SwitchCommand,
+    alias::Alias, auth_validator::AuthValidator, create::CreateCommand, derive::DeriveCommand,
+    export::ExportCommand, faucet::FaucetCommand, import::ImportCommand, list::ListCommand,
+    lock::LockCommand, multisig::Multisig, nonce::NonceCommand, nullify::NullifyCommand,
+    passkey::Passkey, rotate_key::RotateKeyCommand, show::ShowCommand, switch::SwitchCommand,
+    transfer::TransferCommand, unlock::UnlockCommand,
 };
 use rooch_types::error::{RoochError, RoochResult};
 use std::path::PathBuf;
@@ -33,6 +37,22 @@ impl CommandAction<String> for Account {
             AccountCommand::Switch(switch) => switch.execute().await.map(|_| "".to_owned()),
             AccountCommand::Nullify(nullify) => nullify.execute().await.map(|_| "".to_owned()),
             AccountCommand::Balance(balance) => balance.execute().await.map(|_| "".to_owned()),
+            AccountCommand::Export(export) => export.execute().await,
+            AccountCommand::Lock(lock) => lock.execute().await.map(|_| "".to_owned()),
+            AccountCommand::Unlock(unlock) => unlock.execute().await.map(|_| "".to_owned()),
+            AccountCommand::Multisig(multisig) => multisig.execute().await,
+            AccountCommand::AuthValidator(auth_validator) => auth_validator.execute().await,
+            AccountCommand::Passkey(passkey) => passkey.execute().await,
+            AccountCommand::Alias(alias) => alias.execute().await,
+            AccountCommand::Derive(derive) => derive.execute_serialized().await,
+            AccountCommand::RotateKey(rotate_key) => rotate_key.execute_serialized().await,
+            AccountCommand::Nonce(nonce) => nonce.execute_serialized().await,
+            AccountCommand::Show(show) => show.execute_serialized().await,
+            AccountCommand::Import(import) => import.execute_serialized().await,
+            AccountCommand::Transfer(transfer) => transfer.execute().await.map(|_| "".to_owned()),
+            AccountCommand::Faucet(faucet) => faucet.execute().await.map(|resp| {
+                serde_json::to_string_pretty(&resp).expect("Failed to serialize response")
+            }),
         }
         .map_err(RoochError::from)
     }
@@ -46,4 +66,18 @@ pub enum AccountCommand {
     Switch(SwitchCommand),
     Nullify(NullifyCommand),
     Balance(BalanceCommand),
+    Export(ExportCommand),
+    Lock(LockCommand),
+    Unlock(UnlockCommand),
+    Multisig(Multisig),
+    AuthValidator(AuthValidator),
+    Passkey(Passkey),
+    Alias(Alias),
+    Faucet(FaucetCommand),
+    RotateKey(RotateKeyCommand),
+    Derive(DeriveCommand),
+    Nonce(NonceCommand),
+    Show(ShowCommand),
+    Import(ImportCommand),
+    Transfer(TransferCommand),
 }
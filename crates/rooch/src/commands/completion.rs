@@ -0,0 +1,28 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::cli_types::CommandAction;
+use crate::RoochCli;
+use async_trait::async_trait;
+use clap::{CommandFactory, Parser};
+use clap_complete::Shell;
+use rooch_types::error::{RoochError, RoochResult};
+
+/// Generate the shell completion script of rooch
+/// Example: `rooch completion bash > ~/.local/share/bash-completion/completions/rooch`
+#[derive(Debug, Parser)]
+pub struct CompletionCommand {
+    /// The shell to generate the completion script for
+    #[clap(value_enum)]
+    shell: Shell,
+}
+
+#[async_trait]
+impl CommandAction<String> for CompletionCommand {
+    async fn execute(self) -> RoochResult<String> {
+        let mut command = RoochCli::command();
+        let mut script = vec![];
+        clap_complete::generate(self.shell, &mut command, "rooch", &mut script);
+        String::from_utf8(script).map_err(|e| RoochError::UnexpectedError(e.to_string()))
+    }
+}
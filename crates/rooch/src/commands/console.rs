@@ -0,0 +1,74 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::cli_types::CommandAction;
+use crate::{run_cli, Command, RoochCli};
+use async_trait::async_trait;
+use clap::Parser;
+use rooch_config::{rooch_config_dir, ROOCH_CONSOLE_HISTORY_FILENAME};
+use rooch_types::error::{RoochError, RoochResult};
+use rustyline::error::ReadlineError;
+use rustyline::DefaultEditor;
+
+/// Start an interactive console to run the rooch commands without the `rooch` prefix.
+/// The commands run in one process, so the RPC connections are reused by the commands,
+/// and the command history is kept in the rooch config dir.
+#[derive(Debug, Parser)]
+pub struct ConsoleCommand {}
+
+#[async_trait]
+impl CommandAction<String> for ConsoleCommand {
+    async fn execute(self) -> RoochResult<String> {
+        let history_path = rooch_config_dir()?.join(ROOCH_CONSOLE_HISTORY_FILENAME);
+        let mut editor =
+            DefaultEditor::new().map_err(|e| RoochError::UnexpectedError(e.to_string()))?;
+        // The history file does not exist on the first run
+        let _ = editor.load_history(&history_path);
+
+        println!("Welcome to the rooch console, type `help` to list the commands, `exit` to quit.");
+        loop {
+            let line = match editor.readline("rooch> ") {
+                Ok(line) => line,
+                // Ctrl-C discards the current line
+                Err(ReadlineError::Interrupted) => continue,
+                Err(ReadlineError::Eof) => break,
+                Err(e) => return Err(RoochError::UnexpectedError(e.to_string())),
+            };
+            let line = line.trim();
+            if line.is_empty() {
+                continue;
+            }
+            let _ = editor.add_history_entry(line);
+            if line == "exit" || line == "quit" {
+                break;
+            }
+
+            let Some(args) = shlex::split(line) else {
+                println!("Invalid command, unclosed quotation: {}", line);
+                continue;
+            };
+            let opt =
+                match RoochCli::try_parse_from(std::iter::once("rooch".to_owned()).chain(args)) {
+                    Ok(opt) => opt,
+                    Err(e) => {
+                        // Print the help and the parse error
+                        let _ = e.print();
+                        continue;
+                    }
+                };
+            if matches!(opt.cmd, Command::Console(_)) {
+                println!("Already in the console");
+                continue;
+            }
+            match run_cli(opt).await {
+                Ok(output) => println!("{}", output),
+                Err(e) => println!("{}", e),
+            }
+        }
+
+        editor
+            .save_history(&history_path)
+            .map_err(|e| RoochError::UnexpectedError(e.to_string()))?;
+        Ok(String::new())
+    }
+}
@@ -3,11 +3,12 @@
 
 use crate::cli_types::WalletContextOptions;
 use clap::{Parser, ValueHint};
+use move_command_line_common::address::ParsedAddress;
 use rooch_rpc_client::client_config::Env;
 use rooch_types::error::RoochResult;
 use std::time::Duration;
 
-/// Add a new Rooch environment
+/// Add a new Rooch environment, or update the environment of the alias
 #[derive(Debug, Parser)]
 pub struct AddCommand {
     #[clap(flatten)]
@@ -18,16 +19,34 @@ pub struct AddCommand {
     pub rpc: String,
     #[clap(long, value_hint = ValueHint::Url)]
     pub ws: Option<String>,
+    /// The active account of the environment, the global active account is used if absent
+    #[clap(long, value_parser=ParsedAddress::parse)]
+    pub active_address: Option<ParsedAddress>,
+    /// The max gas amount of the transactions sent to the environment, the gas is estimated if absent
+    #[clap(long)]
+    pub max_gas_amount: Option<u64>,
 }
 
 impl AddCommand {
     pub async fn execute(self) -> RoochResult<()> {
         let mut context = self.context_options.build()?;
-        let AddCommand { alias, rpc, ws, .. } = self;
+        let AddCommand {
+            alias,
+            rpc,
+            ws,
+            active_address,
+            max_gas_amount,
+            ..
+        } = self;
+        let active_address = active_address
+            .map(|address| context.resolve_address(address).map(Into::into))
+            .transpose()?;
         let env = Env {
             ws,
             rpc,
             alias: alias.clone(),
+            active_address,
+            max_gas_amount,
         };
 
         // TODO: is this request timeout okay?
@@ -17,10 +17,15 @@ impl ListCommand {
         let context = self.context_options.build()?;
 
         println!(
-            "{:^24} | {:^48} | {:^48} | {:^12}",
-            "Env Alias", "RPC URL", "Websocket URL", "Active Env"
+            "{:^24} | {:^48} | {:^48} | {:^66} | {:^14} | {:^12}",
+            "Env Alias",
+            "RPC URL",
+            "Websocket URL",
+            "Active Address",
+            "Max Gas Amount",
+            "Active Env"
         );
-        println!("{}", ["-"; 153].join(""));
+        println!("{}", ["-"; 227].join(""));
 
         for env in context.client_config.envs.iter() {
             let mut active = "";
@@ -29,9 +34,17 @@ impl ListCommand {
             }
 
             let ws = env.ws.clone().unwrap_or("Null".to_owned());
+            let active_address = env
+                .active_address
+                .map(|address| address.to_string())
+                .unwrap_or("Null".to_owned());
+            let max_gas_amount = env
+                .max_gas_amount
+                .map(|amount| amount.to_string())
+                .unwrap_or("Null".to_owned());
             println!(
-                "{:^24} | {:^48} | {:^48} | {:^12}",
-                env.alias, env.rpc, ws, active
+                "{:^24} | {:^48} | {:^48} | {:^66} | {:^14} | {:^12}",
+                env.alias, env.rpc, ws, active_address, max_gas_amount, active
             )
         }
 
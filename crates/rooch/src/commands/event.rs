@@ -41,6 +41,15 @@ pub struct GetEventsByEventHandle {
     /// Max number of items returned per page
     #[clap(long)]
     limit: Option<u64>,
+    /// Return the latest events first
+    #[clap(long)]
+    descending_order: bool,
+    /// The min event seq of the returned events, inclusive
+    #[clap(long)]
+    from_seq: Option<u64>,
+    /// The max event seq of the returned events, inclusive
+    #[clap(long)]
+    to_seq: Option<u64>,
 
     #[clap(flatten)]
     pub(crate) context_options: WalletContextOptions,
@@ -60,6 +69,9 @@ impl CommandAction<EventPageView> for GetEventsByEventHandle {
                 self.cursor,
                 self.limit,
                 Some(EventOptions::default().decode(true)),
+                Some(self.descending_order),
+                self.from_seq,
+                self.to_seq,
             )
             .await
             .map_err(RoochError::from)?;
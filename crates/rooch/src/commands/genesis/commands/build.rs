@@ -0,0 +1,60 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::cli_types::CommandAction;
+use async_trait::async_trait;
+use clap::Parser;
+use moveos_types::h256::H256;
+use rooch_genesis::{GenesisConfig, RoochGenesis};
+use rooch_types::error::{RoochError, RoochResult};
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+/// Build the genesis package from a genesis config TOML file.
+///
+/// The genesis is built only from the config, so the validators building the same config get the same genesis,
+/// compare the printed genesis package hash and state root to verify it.
+#[derive(Debug, Parser)]
+pub struct BuildCommand {
+    /// The genesis config TOML file, with the chain parameters, the framework packages override and the allocations
+    #[clap(short = 'c', long = "config")]
+    pub config: PathBuf,
+
+    /// The output file of the genesis package
+    #[clap(short = 'o', long = "output", default_value = "genesis.blob")]
+    pub output: PathBuf,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct GenesisBuildView {
+    pub chain_id: u64,
+    pub genesis_package_hash: H256,
+    pub state_root: H256,
+    pub output: PathBuf,
+}
+
+#[async_trait]
+impl CommandAction<GenesisBuildView> for BuildCommand {
+    async fn execute(self) -> RoochResult<GenesisBuildView> {
+        let genesis_config = GenesisConfig::load(&self.config).map_err(|e| {
+            RoochError::CommandArgumentError(format!(
+                "Load genesis config {:?} failed: {}",
+                self.config, e
+            ))
+        })?;
+        let genesis = RoochGenesis::build_with_config(&genesis_config)?;
+        genesis.genesis_package.save_to(&self.output)?;
+
+        let genesis_info = genesis.genesis_info();
+        println!(
+            "Genesis package hash: {}, state root: {}",
+            genesis_info.genesis_package_hash, genesis_info.state_root_hash
+        );
+        Ok(GenesisBuildView {
+            chain_id: genesis_config.chain_id,
+            genesis_package_hash: genesis_info.genesis_package_hash,
+            state_root: genesis_info.state_root_hash,
+            output: self.output,
+        })
+    }
+}
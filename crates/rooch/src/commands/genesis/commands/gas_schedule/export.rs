@@ -0,0 +1,47 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use super::get_onchain_gas_schedule;
+use crate::cli_types::{CommandAction, WalletContextOptions};
+use async_trait::async_trait;
+use clap::Parser;
+use rooch_framework::natives::{default_gas_schedule, GasSchedule};
+use rooch_types::error::{RoochError, RoochResult};
+use std::path::PathBuf;
+
+/// Export the gas schedule to a json file, the default gas schedule of this binary is exported if `--onchain` is absent
+#[derive(Debug, Parser)]
+pub struct ExportCommand {
+    /// Export the current onchain gas schedule instead of the default gas schedule
+    #[clap(long)]
+    pub onchain: bool,
+
+    /// Write the gas schedule to the file instead of printing it
+    #[clap(short = 'o', long = "output")]
+    pub output: Option<PathBuf>,
+
+    #[clap(flatten)]
+    pub(crate) context_options: WalletContextOptions,
+}
+
+#[async_trait]
+impl CommandAction<GasSchedule> for ExportCommand {
+    async fn execute(self) -> RoochResult<GasSchedule> {
+        let gas_schedule = if self.onchain {
+            let context = self.context_options.build()?;
+            let client = context.get_client().await?;
+            get_onchain_gas_schedule(&client).await?
+        } else {
+            default_gas_schedule()
+        };
+
+        if let Some(output) = self.output {
+            let content = serde_json::to_string_pretty(&gas_schedule).map_err(|e| {
+                RoochError::CommandArgumentError(format!("Serialize gas schedule failed: {}", e))
+            })?;
+            std::fs::write(&output, content)?;
+            println!("Exported gas schedule to file {:?}", output);
+        }
+        Ok(gas_schedule)
+    }
+}
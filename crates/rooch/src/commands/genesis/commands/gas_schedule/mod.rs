@@ -0,0 +1,58 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::cli_types::CommandAction;
+use anyhow::Result;
+use async_trait::async_trait;
+use export::ExportCommand;
+use moveos::gas::table::gas_schedule_struct;
+use moveos_types::{access_path::AccessPath, moveos_std::object_id::named_object_id, state::State};
+use rooch_framework::natives::GasSchedule as GasScheduleConfig;
+use rooch_rpc_client::Client;
+use rooch_types::error::RoochResult;
+use update::UpdateCommand;
+
+pub mod export;
+pub mod update;
+
+/// Manage the onchain gas schedule, the gas parameters of the instructions, the storage and the natives.
+///
+/// Export the gas schedule to a json file with `export`, edit the entries and bump the `feature_version`,
+/// then submit it with `update`, the new gas schedule takes effect from the next transaction.
+#[derive(Debug, clap::Parser)]
+pub struct GasSchedule {
+    #[clap(subcommand)]
+    cmd: GasScheduleCommand,
+}
+
+#[async_trait]
+impl CommandAction<String> for GasSchedule {
+    async fn execute(self) -> RoochResult<String> {
+        match self.cmd {
+            GasScheduleCommand::Export(export) => export.execute_serialized().await,
+            GasScheduleCommand::Update(update) => update.execute_serialized().await,
+        }
+    }
+}
+
+#[derive(Debug, clap::Subcommand)]
+#[clap(name = "gas-schedule")]
+pub enum GasScheduleCommand {
+    Export(ExportCommand),
+    Update(UpdateCommand),
+}
+
+/// Get the current onchain gas schedule
+pub(crate) async fn get_onchain_gas_schedule(client: &Client) -> Result<GasScheduleConfig> {
+    let object_id = named_object_id(&gas_schedule_struct());
+    let state = client
+        .rooch
+        .get_states(AccessPath::object(object_id))
+        .await?
+        .pop()
+        .flatten()
+        .ok_or_else(|| anyhow::anyhow!("The onchain gas schedule does not exist"))?;
+    Ok(State::from(state)
+        .as_object_uncheck::<GasScheduleConfig>()?
+        .value)
+}
@@ -0,0 +1,81 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use super::get_onchain_gas_schedule;
+use crate::cli_types::{CommandAction, WalletContextOptions};
+use async_trait::async_trait;
+use clap::Parser;
+use move_command_line_common::address::ParsedAddress;
+use rooch_framework::natives::GasSchedule;
+use rooch_key::key_derive::verify_password;
+use rooch_key::keystore::account_keystore::AccountKeystore;
+use rooch_rpc_api::jsonrpc_types::ExecuteTransactionResponseView;
+use rooch_types::{
+    address::RoochAddress,
+    error::{RoochError, RoochResult},
+    framework::onchain_config::OnchainConfigModule,
+};
+use rpassword::prompt_password;
+use std::path::PathBuf;
+
+/// Update the onchain gas schedule with the gas schedule json file, the transaction must be sent by the sequencer
+#[derive(Debug, Parser)]
+pub struct UpdateCommand {
+    /// The gas schedule json file, as the output of `rooch genesis gas-schedule export`
+    #[clap(short = 'f', long = "file")]
+    pub file: PathBuf,
+
+    /// The sequencer account to send the transaction, if absent, use the default active account.
+    #[clap(long, value_parser=ParsedAddress::parse, default_value = "default")]
+    pub sender: ParsedAddress,
+
+    #[clap(flatten)]
+    pub(crate) context_options: WalletContextOptions,
+}
+
+#[async_trait]
+impl CommandAction<ExecuteTransactionResponseView> for UpdateCommand {
+    async fn execute(self) -> RoochResult<ExecuteTransactionResponseView> {
+        let context = self.context_options.build()?;
+        let sender: RoochAddress = context.resolve_address(self.sender)?.into();
+
+        let content = std::fs::read_to_string(&self.file)?;
+        let gas_schedule: GasSchedule = serde_json::from_str(&content).map_err(|e| {
+            RoochError::CommandArgumentError(format!(
+                "Invalid gas schedule file {:?}: {}",
+                self.file, e
+            ))
+        })?;
+
+        let client = context.get_client().await?;
+        let onchain_gas_schedule = get_onchain_gas_schedule(&client).await?;
+        if gas_schedule.feature_version <= onchain_gas_schedule.feature_version {
+            return Err(RoochError::CommandArgumentError(format!(
+                "The feature_version of the gas schedule must be greater than the onchain feature_version {}",
+                onchain_gas_schedule.feature_version
+            )));
+        }
+
+        let password = if context.keystore.get_if_password_is_empty() {
+            None
+        } else {
+            let password = prompt_password("Enter the password to update the gas schedule:")
+                .unwrap_or_default();
+            let is_verified =
+                verify_password(Some(password.clone()), context.keystore.get_password_hash())?;
+
+            if !is_verified {
+                return Err(RoochError::InvalidPasswordError(
+                    "Password is invalid".to_owned(),
+                ));
+            }
+            Some(password)
+        };
+
+        let gas_schedule_blob = bcs::to_bytes(&gas_schedule)?;
+        let action =
+            OnchainConfigModule::create_update_onchain_gas_schedule_action(gas_schedule_blob);
+        let result = context.sign_and_execute(sender, action, password).await?;
+        context.assert_execute_success(result)
+    }
+}
@@ -0,0 +1,34 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::cli_types::CommandAction;
+use crate::commands::genesis::commands::build::BuildCommand;
+use crate::commands::genesis::commands::gas_schedule::GasSchedule;
+use async_trait::async_trait;
+use clap::{Parser, Subcommand};
+use rooch_types::error::RoochResult;
+
+pub mod commands;
+
+/// Tool for managing the genesis configurations
+#[derive(Parser)]
+pub struct Genesis {
+    #[clap(subcommand)]
+    cmd: GenesisCommand,
+}
+
+#[async_trait]
+impl CommandAction<String> for Genesis {
+    async fn execute(self) -> RoochResult<String> {
+        match self.cmd {
+            GenesisCommand::Build(build) => build.execute_serialized().await,
+            GenesisCommand::GasSchedule(gas_schedule) => gas_schedule.execute().await,
+        }
+    }
+}
+
+#[derive(Subcommand)]
+pub enum GenesisCommand {
+    Build(BuildCommand),
+    GasSchedule(GasSchedule),
+}
@@ -0,0 +1,6 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+pub mod prune;
+pub mod rebuild;
+pub mod revert;
@@ -0,0 +1,51 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::cli_types::CommandAction;
+use async_trait::async_trait;
+use clap::Parser;
+use rooch_config::indexer_config::IndexerConfig;
+use rooch_config::{BaseConfig, RoochOpt};
+use rooch_indexer::store::traits::IndexerStoreTrait;
+use rooch_rpc_server::init_indexer;
+use rooch_types::error::{RoochError, RoochResult};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+/// Prune the indexed history out of the retention once, such as the events, coin transfers and table change sets.
+/// The retention is set by the `--indexer-*-retention` options, the latest N transactions are retained.
+/// The indexer can not be reverted beyond the pruned table change sets.
+/// The server must be stopped before pruning, the store can not be opened by two processes.
+#[derive(Debug, Parser)]
+pub struct PruneCommand {
+    #[clap(flatten)]
+    opt: RoochOpt,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PruneOutput {
+    /// The number of the pruned rows of every history table
+    pub pruned: BTreeMap<String, u64>,
+}
+
+#[async_trait]
+impl CommandAction<PruneOutput> for PruneCommand {
+    async fn execute(self) -> RoochResult<PruneOutput> {
+        let base_config = BaseConfig::load_with_opt(&self.opt)?;
+        let mut indexer_config = IndexerConfig::default();
+        indexer_config.merge_with_opt_with_init(&self.opt, Arc::new(base_config), false)?;
+        let retention = indexer_config.retention();
+        if retention.is_unlimited() {
+            return Err(RoochError::RebuildIndexerError(
+                "No indexer retention is set, use the `--indexer-*-retention` options".to_owned(),
+            ));
+        }
+
+        let (indexer_store, _) = init_indexer(&indexer_config)?;
+        let pruned = indexer_store
+            .prune_history(&retention)
+            .map_err(|e| RoochError::RebuildIndexerError(e.to_string()))?;
+        Ok(PruneOutput { pruned })
+    }
+}
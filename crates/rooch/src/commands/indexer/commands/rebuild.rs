@@ -0,0 +1,157 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::cli_types::{CommandAction, WalletContextOptions};
+use crate::commands::server::commands::replay::{
+    load_transaction, open_server_storage, sequencer_account, ReplayExecutor,
+};
+use async_trait::async_trait;
+use clap::Parser;
+use rooch_config::indexer_config::IndexerConfig;
+use rooch_config::{BaseConfig, RoochOpt};
+use rooch_indexer::actor::indexer::IndexerActor;
+use rooch_indexer::store::batch::IndexerBatch;
+use rooch_indexer::store::traits::IndexerStoreTrait;
+use rooch_indexer::types::IndexerProgressTable;
+use rooch_indexer::IndexerStore;
+use rooch_rpc_server::init_indexer;
+use rooch_types::error::{RoochError, RoochResult};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+
+/// The transactions re-indexed are committed every so many transactions
+const REBUILD_BATCH_SIZE: u64 = 1000;
+
+/// Rebuild the indexer by re-executing the transactions in the rooch store from genesis.
+/// The indexed data from the `from` tx order is deleted and indexed again, default to resume from the last indexed tx order.
+/// The server must be stopped before rebuilding, the store can not be opened by two processes.
+#[derive(Debug, Parser)]
+pub struct RebuildCommand {
+    /// The tx order to re-index from, default to the tx order after the last indexed one
+    #[clap(long)]
+    from: Option<u64>,
+
+    /// The tx order to re-index to (inclusive), default to the latest tx order in the store
+    #[clap(long)]
+    to: Option<u64>,
+
+    #[clap(flatten)]
+    opt: RoochOpt,
+
+    #[clap(flatten)]
+    pub context_options: WalletContextOptions,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RebuildOutput {
+    pub from: u64,
+    pub to: u64,
+    /// The number of the re-indexed transactions
+    pub indexed: u64,
+}
+
+impl RebuildCommand {
+    fn open_indexer_store(&self) -> RoochResult<IndexerStore> {
+        let base_config = BaseConfig::load_with_opt(&self.opt)?;
+        let mut indexer_config = IndexerConfig::default();
+        indexer_config.merge_with_opt_with_init(&self.opt, Arc::new(base_config), false)?;
+        let (indexer_store, _) = init_indexer(&indexer_config)?;
+        Ok(indexer_store)
+    }
+
+    /// The tx order after the last indexed one of all the tables, the tables are indexed one by one,
+    /// so the indexer may crash with the tables indexed to different tx orders.
+    fn resume_order(indexer_store: &IndexerStore) -> RoochResult<u64> {
+        let mut last_indexed_order = u64::MAX;
+        for table in IndexerProgressTable::ALL {
+            let progress = indexer_store
+                .get_indexer_progress(table)
+                .map_err(|e| RoochError::RebuildIndexerError(e.to_string()))?;
+            last_indexed_order = last_indexed_order.min(progress.unwrap_or(0));
+        }
+        Ok(last_indexed_order + 1)
+    }
+}
+
+#[async_trait]
+impl CommandAction<RebuildOutput> for RebuildCommand {
+    async fn execute(self) -> RoochResult<RebuildOutput> {
+        let sequencer_account = sequencer_account(&self.opt, &self.context_options)?;
+        let (moveos_store, rooch_store, last_order) = open_server_storage(&self.opt)?;
+        let indexer_store = self.open_indexer_store()?;
+
+        let from = match self.from {
+            Some(from) => from,
+            None => Self::resume_order(&indexer_store)?,
+        };
+        let to = self.to.unwrap_or(last_order);
+        if to > last_order {
+            return Err(RoochError::RebuildIndexerError(format!(
+                "The tx order {} is greater than the latest tx order {}",
+                to, last_order
+            )));
+        }
+        if from == 0 {
+            return Err(RoochError::RebuildIndexerError(
+                "The tx order to re-index from should be greater than 0".to_owned(),
+            ));
+        }
+        if from > to {
+            return Ok(RebuildOutput {
+                from,
+                to,
+                indexed: 0,
+            });
+        }
+
+        indexer_store
+            .delete_from_tx_order(from)
+            .map_err(|e| RoochError::RebuildIndexerError(e.to_string()))?;
+
+        // The indexer resolves the states with the replayed store, it has the states at the indexing tx order
+        let mut replay = ReplayExecutor::new(&self.opt, sequencer_account, &moveos_store)?;
+        let indexer = IndexerActor::new(indexer_store, replay.moveos_store.clone())?;
+
+        let mut indexed = 0;
+        let mut batch = IndexerBatch::default();
+        for tx_order in 1..=to {
+            let (tx, sequence_info, expected) =
+                load_transaction(&rooch_store, &moveos_store, tx_order)?;
+
+            let moveos_tx = replay.executor.validate(tx.clone())?;
+            let result = replay.executor.execute(moveos_tx.clone())?;
+            if result.transaction_info.state_root != expected.state_root {
+                return Err(RoochError::RebuildIndexerError(format!(
+                    "The state root of tx order {} diverges from the stored one, expected {:?}, actual {:?}",
+                    tx_order, expected.state_root, result.transaction_info.state_root
+                )));
+            }
+            if tx_order < from {
+                continue;
+            }
+
+            indexer.index_states(&mut batch, tx_order, result.output.state_changeset)?;
+            indexer.index_transaction(
+                &mut batch,
+                tx.clone(),
+                sequence_info.clone(),
+                result.transaction_info,
+                moveos_tx.clone(),
+            )?;
+            indexer.index_events(
+                &mut batch,
+                result.output.events,
+                tx,
+                sequence_info,
+                moveos_tx,
+            )?;
+            indexed += 1;
+            if indexed % REBUILD_BATCH_SIZE == 0 {
+                indexer.commit(std::mem::take(&mut batch))?;
+            }
+        }
+        indexer.commit(batch)?;
+
+        Ok(RebuildOutput { from, to, indexed })
+    }
+}
@@ -0,0 +1,63 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::cli_types::CommandAction;
+use crate::commands::server::commands::replay::{load_transaction, open_server_storage};
+use async_trait::async_trait;
+use clap::Parser;
+use rooch_config::indexer_config::IndexerConfig;
+use rooch_config::{BaseConfig, RoochOpt};
+use rooch_indexer::actor::indexer::IndexerActor;
+use rooch_rpc_server::init_indexer;
+use rooch_types::error::{RoochError, RoochResult};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+
+/// Revert the indexer to the given tx order, such as after a reorg of the sequencer or DA.
+/// The indexed data above the tx order is deleted, and the states changed above it are restored from the state tree.
+/// The server must be stopped before reverting, the store can not be opened by two processes.
+#[derive(Debug, Parser)]
+pub struct RevertCommand {
+    /// The tx order to revert to (inclusive), the indexed data above it is reverted
+    #[clap(long)]
+    to: u64,
+
+    #[clap(flatten)]
+    opt: RoochOpt,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RevertOutput {
+    pub to: u64,
+}
+
+#[async_trait]
+impl CommandAction<RevertOutput> for RevertCommand {
+    async fn execute(self) -> RoochResult<RevertOutput> {
+        let (moveos_store, rooch_store, last_order) = open_server_storage(&self.opt)?;
+        if self.to > last_order {
+            return Err(RoochError::RebuildIndexerError(format!(
+                "The tx order {} is greater than the latest tx order {}",
+                self.to, last_order
+            )));
+        }
+        if self.to == 0 {
+            return Err(RoochError::RebuildIndexerError(
+                "The tx order to revert to should be greater than 0, rebuild the indexer from 1 instead"
+                    .to_owned(),
+            ));
+        }
+        let (_, _, execution_info) = load_transaction(&rooch_store, &moveos_store, self.to)?;
+
+        let base_config = BaseConfig::load_with_opt(&self.opt)?;
+        let mut indexer_config = IndexerConfig::default();
+        indexer_config.merge_with_opt_with_init(&self.opt, Arc::new(base_config), false)?;
+        let (indexer_store, _) = init_indexer(&indexer_config)?;
+
+        let indexer = IndexerActor::new(indexer_store, moveos_store)?;
+        indexer
+            .revert(self.to, execution_info.state_root)
+            .map_err(|e| RoochError::RebuildIndexerError(e.to_string()))?;
+        Ok(RevertOutput { to: self.to })
+    }
+}
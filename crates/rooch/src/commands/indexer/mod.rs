@@ -0,0 +1,39 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::cli_types::CommandAction;
+use async_trait::async_trait;
+use clap::Parser;
+use rooch_types::error::RoochResult;
+
+use self::commands::prune::PruneCommand;
+use self::commands::rebuild::RebuildCommand;
+use self::commands::revert::RevertCommand;
+
+pub mod commands;
+
+/// Manage the Rooch indexer
+#[derive(Parser)]
+pub struct Indexer {
+    #[clap(subcommand)]
+    cmd: IndexerCommand,
+}
+
+#[async_trait]
+impl CommandAction<String> for Indexer {
+    async fn execute(self) -> RoochResult<String> {
+        match self.cmd {
+            IndexerCommand::Rebuild(rebuild) => rebuild.execute_serialized().await,
+            IndexerCommand::Revert(revert) => revert.execute_serialized().await,
+            IndexerCommand::Prune(prune) => prune.execute_serialized().await,
+        }
+    }
+}
+
+#[derive(clap::Subcommand)]
+#[clap(name = "indexer")]
+pub enum IndexerCommand {
+    Rebuild(RebuildCommand),
+    Revert(RevertCommand),
+    Prune(PruneCommand),
+}
@@ -20,6 +20,7 @@ use rooch_rpc_client::client_config::{ClientConfig, Env};
 use rooch_types::error::RoochError;
 use rooch_types::error::RoochResult;
 use rpassword::prompt_password;
+use std::collections::BTreeMap;
 use std::fs;
 
 /// Tool for init with rooch
@@ -100,6 +101,8 @@ impl CommandAction<()> for Init {
                         alias: "custom".to_string(),
                         rpc: chain_url[1].to_owned(),
                         ws: None,
+                        active_address: None,
+                        max_gas_amount: None,
                     })
                 }
 
@@ -138,6 +141,8 @@ impl CommandAction<()> for Init {
                             alias,
                             rpc: url,
                             ws: None,
+                            active_address: None,
+                            max_gas_amount: None,
                         }
                     })
                 }
@@ -182,6 +187,8 @@ impl CommandAction<()> for Init {
                     active_address: Some(result.address),
                     // make dev env as default env
                     active_env: Some(active_env_alias),
+                    auth_validators: BTreeMap::new(),
+                    address_book: BTreeMap::new(),
                 };
 
                 client_config
@@ -3,8 +3,12 @@
 
 pub mod abi;
 pub mod account;
+pub mod completion;
+pub mod console;
 pub mod env;
 pub mod event;
+pub mod genesis;
+pub mod indexer;
 pub mod init;
 pub mod move_cli;
 pub mod object;
@@ -40,7 +40,7 @@ impl CommandAction<ExecuteTransactionResponseView> for FrameworkUpgrade {
             .package_path
             .unwrap_or_else(|| std::env::current_dir().unwrap());
 
-        let context = self
+        let mut context = self
             .context_options
             .build()
             .expect("Building context failed.");
@@ -70,7 +70,8 @@ impl CommandAction<ExecuteTransactionResponseView> for FrameworkUpgrade {
         );
 
         // Build context and handle errors
-
+        context.sequence_number = self.tx_options.sequence_number;
+        context.expiration_secs = self.tx_options.expiration_secs;
         let sender = context.resolve_address(self.tx_options.sender)?.into();
         // Handle transaction with or without authenticator
         match self.tx_options.authenticator {
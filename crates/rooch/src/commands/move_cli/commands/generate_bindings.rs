@@ -0,0 +1,637 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::cli_types::WalletContextOptions;
+use clap::*;
+use move_binary_format::access::ModuleAccess;
+use move_binary_format::file_format::FunctionDefinitionIndex;
+use move_binary_format::normalized;
+use move_binary_format::CompiledModule;
+use move_cli::base::reroot_path;
+use move_compiler::compiled_unit::CompiledUnit;
+use move_core_types::account_address::AccountAddress;
+use move_package::BuildConfig;
+use moveos_types::addresses::{MOVEOS_STD_ADDRESS, MOVE_STD_ADDRESS};
+use moveos_verifier::build::run_verifier;
+use rooch_rpc_api::jsonrpc_types::format_move_type;
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::Write;
+use std::path::PathBuf;
+
+/// The languages of the generated bindings
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum BindingsLanguage {
+    Rust,
+}
+
+/// Generate the typed bindings of the modules of the package at `path`.
+/// The Rust bindings have a module for each Move module, with the structs implementing `MoveStructState`
+/// and the builders of the `MoveAction` of the entry functions, the signer and `Context` parameters are injected by the VM,
+/// and the `&Object<T>` parameters are passed by `ObjectID`.
+/// The structs or entry functions with the types not supported in the bindings are skipped with a comment.
+#[derive(Parser)]
+#[clap(name = "generate-bindings")]
+pub struct GenerateBindings {
+    /// The language of the generated bindings
+    #[clap(long, value_enum, default_value = "rust")]
+    lang: BindingsLanguage,
+
+    /// The file to write the bindings to, the bindings are printed if not set
+    #[clap(long)]
+    output: Option<PathBuf>,
+
+    /// Named addresses for the move binary
+    ///
+    /// Example: alice=0x1234, bob=default, alice2=alice
+    ///
+    /// Note: This will fail if there are duplicates in the Move.toml file remove those first.
+    #[clap(long, value_parser = crate::utils::parse_map::<String, String>, default_value = "")]
+    pub(crate) named_addresses: BTreeMap<String, String>,
+
+    #[clap(flatten)]
+    config_options: WalletContextOptions,
+}
+
+impl GenerateBindings {
+    pub async fn execute(
+        self,
+        path: Option<PathBuf>,
+        config: BuildConfig,
+    ) -> anyhow::Result<String> {
+        let context = self.config_options.build()?;
+
+        let mut config = config;
+        config
+            .additional_named_addresses
+            .extend(context.parse_and_resolve_addresses(self.named_addresses)?);
+
+        let rerooted_path = reroot_path(path)?;
+        let config_cloned = config.clone();
+        let mut package = config.compile_package_no_exit(&rerooted_path, &mut std::io::stderr())?;
+        run_verifier(rerooted_path, config_cloned, &mut package)?;
+
+        let mut modules = vec![];
+        for unit_with_source in package.root_compiled_units.iter() {
+            if let CompiledUnit::Module(named_module) = &unit_with_source.unit {
+                let module = &named_module.module;
+                // The parameter names are read from the source map, they are not kept in the bytecode
+                let mut parameter_names = BTreeMap::new();
+                for (index, function_def) in module.function_defs().iter().enumerate() {
+                    let name = module
+                        .identifier_at(module.function_handle_at(function_def.function).name)
+                        .to_string();
+                    if let Ok(function_source_map) = named_module
+                        .source_map
+                        .get_function_source_map(FunctionDefinitionIndex::new(index as u16))
+                    {
+                        let names = function_source_map
+                            .parameters
+                            .iter()
+                            .map(|(name, _)| name.clone())
+                            .collect::<Vec<_>>();
+                        parameter_names.insert(name, names);
+                    }
+                }
+                modules.push((module.clone(), parameter_names));
+            }
+        }
+
+        let bindings = match self.lang {
+            BindingsLanguage::Rust => RustBindingsGenerator::new(&modules).generate()?,
+        };
+        match self.output {
+            Some(output) => {
+                std::fs::write(&output, bindings)?;
+                Ok(format!("Generated the bindings to {}", output.display()))
+            }
+            None => Ok(bindings),
+        }
+    }
+}
+
+const RUST_KEYWORDS: &[&str] = &[
+    "as", "async", "await", "break", "const", "continue", "crate", "dyn", "else", "enum", "extern",
+    "false", "fn", "for", "if", "impl", "in", "let", "loop", "match", "mod", "move", "mut", "pub",
+    "ref", "return", "static", "struct", "super", "trait", "true", "type", "unsafe", "use",
+    "where", "while", "abstract", "become", "box", "do", "final", "macro", "override", "priv",
+    "try", "typeof", "unsized", "virtual", "yield",
+];
+
+/// Escape the Move identifier which is a Rust keyword with the raw identifier prefix
+fn rust_ident(name: &str) -> String {
+    if RUST_KEYWORDS.contains(&name) {
+        format!("r#{}", name)
+    } else {
+        name.to_owned()
+    }
+}
+
+/// `my_module` -> `MyModule`
+fn camel_case(name: &str) -> String {
+    name.split('_')
+        .map(|part| {
+            let mut chars = part.chars();
+            match chars.next() {
+                Some(first) => first.to_ascii_uppercase().to_string() + chars.as_str(),
+                None => String::new(),
+            }
+        })
+        .collect()
+}
+
+fn is_struct(ty: &normalized::Type, address: AccountAddress, module: &str, name: &str) -> bool {
+    matches!(ty, normalized::Type::Struct { address: struct_address, module: struct_module, name: struct_name, .. }
+        if *struct_address == address && struct_module.as_str() == module && struct_name.as_str() == name)
+}
+
+/// Generate the Rust bindings of the modules, the types of the generated code are referred with the full paths,
+/// so the names of the Move structs do not conflict with the imported types.
+struct RustBindingsGenerator<'a> {
+    modules: &'a [(CompiledModule, BTreeMap<String, Vec<String>>)],
+    /// The (address, module) of the modules in the package, their structs are referred by the generated structs
+    package_modules: BTreeSet<(AccountAddress, String)>,
+}
+
+impl<'a> RustBindingsGenerator<'a> {
+    fn new(modules: &'a [(CompiledModule, BTreeMap<String, Vec<String>>)]) -> Self {
+        let package_modules = modules
+            .iter()
+            .map(|(module, _)| {
+                (
+                    *module.self_id().address(),
+                    module.self_id().name().to_string(),
+                )
+            })
+            .collect();
+        Self {
+            modules,
+            package_modules,
+        }
+    }
+
+    fn generate(&self) -> anyhow::Result<String> {
+        let mut code = String::new();
+        writeln!(
+            code,
+            "// Generated by `rooch move generate-bindings --lang rust`, do not edit."
+        )?;
+        for (module, parameter_names) in self.modules {
+            writeln!(code)?;
+            self.generate_module(&mut code, module, parameter_names)?;
+        }
+        Ok(code)
+    }
+
+    fn generate_module(
+        &self,
+        code: &mut String,
+        module: &CompiledModule,
+        parameter_names: &BTreeMap<String, Vec<String>>,
+    ) -> anyhow::Result<()> {
+        let normalized_module = normalized::Module::new(module);
+        let module_name = normalized_module.name.to_string();
+        writeln!(code, "#[allow(dead_code, clippy::all)]")?;
+        writeln!(code, "pub mod {} {{", rust_ident(&module_name))?;
+        // The trait is imported anonymously, so it does not conflict with the names of the Move structs
+        writeln!(
+            code,
+            "    use moveos_types::module_binding::ModuleBinding as _;"
+        )?;
+        writeln!(code)?;
+        writeln!(
+            code,
+            "    pub const MODULE_NAME: &move_core_types::identifier::IdentStr = move_core_types::ident_str!(\"{}\");",
+            module_name
+        )?;
+        writeln!(
+            code,
+            "    pub const MODULE_ADDRESS: move_core_types::account_address::AccountAddress = move_core_types::account_address::AccountAddress::new({:?});",
+            normalized_module.address.into_bytes()
+        )?;
+
+        for (name, move_struct) in normalized_module.structs.iter() {
+            writeln!(code)?;
+            self.generate_struct(code, &module_name, name.as_str(), move_struct)?;
+        }
+
+        let module_binding = format!("{}Module", camel_case(&module_name));
+        writeln!(code)?;
+        writeln!(code, "    pub struct {};", module_binding)?;
+        writeln!(code)?;
+        writeln!(code, "    impl {} {{", module_binding)?;
+        for (name, function) in normalized_module.exposed_functions.iter() {
+            if !function.is_entry {
+                continue;
+            }
+            let names = parameter_names.get(name.as_str());
+            self.generate_entry_function(code, name.as_str(), function, names)?;
+        }
+        writeln!(code, "    }}")?;
+        writeln!(code)?;
+        writeln!(
+            code,
+            "    impl<'a> moveos_types::module_binding::ModuleBinding<'a> for {} {{",
+            module_binding
+        )?;
+        writeln!(
+            code,
+            "        const MODULE_NAME: &'static move_core_types::identifier::IdentStr = MODULE_NAME;"
+        )?;
+        writeln!(
+            code,
+            "        const MODULE_ADDRESS: move_core_types::account_address::AccountAddress = MODULE_ADDRESS;"
+        )?;
+        writeln!(code)?;
+        writeln!(
+            code,
+            "        fn new(_caller: &'a impl moveos_types::module_binding::MoveFunctionCaller) -> Self {{"
+        )?;
+        writeln!(code, "            Self")?;
+        writeln!(code, "        }}")?;
+        writeln!(code, "    }}")?;
+        writeln!(code, "}}")?;
+        Ok(())
+    }
+
+    fn generate_struct(
+        &self,
+        code: &mut String,
+        module_name: &str,
+        name: &str,
+        move_struct: &normalized::Struct,
+    ) -> anyhow::Result<()> {
+        let mut fields = vec![];
+        for field in move_struct.fields.iter() {
+            match self.rust_type(module_name, &field.type_) {
+                Some(rust_type) => fields.push((rust_ident(field.name.as_str()), rust_type)),
+                None => {
+                    writeln!(
+                        code,
+                        "    // Skipped struct {}: unsupported field type {}",
+                        name,
+                        format_move_type(&field.type_)
+                    )?;
+                    return Ok(());
+                }
+            }
+        }
+
+        let type_params = (0..move_struct.type_parameters.len())
+            .map(|index| format!("T{}", index))
+            .collect::<Vec<_>>();
+        let phantom_type_params = move_struct
+            .type_parameters
+            .iter()
+            .enumerate()
+            .filter(|(_, type_parameter)| type_parameter.is_phantom)
+            .map(|(index, _)| format!("T{}", index))
+            .collect::<Vec<_>>();
+        // The phantom type params are only required to be struct types, the others are the types of the fields
+        let bounds = move_struct
+            .type_parameters
+            .iter()
+            .enumerate()
+            .map(|(index, type_parameter)| {
+                if type_parameter.is_phantom {
+                    format!("T{}: moveos_types::state::MoveStructType", index)
+                } else {
+                    format!("T{}: moveos_types::state::MoveState", index)
+                }
+            })
+            .collect::<Vec<_>>();
+        let generics = if type_params.is_empty() {
+            String::new()
+        } else {
+            format!("<{}>", type_params.join(", "))
+        };
+        let where_clause = if bounds.is_empty() {
+            String::new()
+        } else {
+            format!(" where {}", bounds.join(", "))
+        };
+
+        writeln!(
+            code,
+            "    #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]"
+        )?;
+        if !type_params.is_empty() {
+            // The phantom type params are not serialized
+            let serialize_bounds = move_struct
+                .type_parameters
+                .iter()
+                .enumerate()
+                .filter(|(_, type_parameter)| !type_parameter.is_phantom)
+                .map(|(index, _)| format!("T{}: serde::Serialize", index))
+                .collect::<Vec<_>>()
+                .join(", ");
+            let deserialize_bounds = move_struct
+                .type_parameters
+                .iter()
+                .enumerate()
+                .filter(|(_, type_parameter)| !type_parameter.is_phantom)
+                .map(|(index, _)| format!("T{}: serde::Deserialize<'de>", index))
+                .collect::<Vec<_>>()
+                .join(", ");
+            writeln!(
+                code,
+                "    #[serde(bound(serialize = \"{}\", deserialize = \"{}\"))]",
+                serialize_bounds, deserialize_bounds
+            )?;
+        }
+        writeln!(code, "    pub struct {}{} {{", name, generics)?;
+        for (field_name, rust_type) in fields.iter() {
+            writeln!(code, "        pub {}: {},", field_name, rust_type)?;
+        }
+        if !phantom_type_params.is_empty() {
+            writeln!(
+                code,
+                "        pub phantom: std::marker::PhantomData<({},)>,",
+                phantom_type_params.join(", ")
+            )?;
+        }
+        writeln!(code, "    }}")?;
+        writeln!(code)?;
+
+        writeln!(
+            code,
+            "    impl{} moveos_types::state::MoveStructType for {}{}{} {{",
+            generics, name, generics, where_clause
+        )?;
+        writeln!(
+            code,
+            "        const ADDRESS: move_core_types::account_address::AccountAddress = MODULE_ADDRESS;"
+        )?;
+        writeln!(
+            code,
+            "        const MODULE_NAME: &'static move_core_types::identifier::IdentStr = MODULE_NAME;"
+        )?;
+        writeln!(
+            code,
+            "        const STRUCT_NAME: &'static move_core_types::identifier::IdentStr = move_core_types::ident_str!(\"{}\");",
+            name
+        )?;
+        if !type_params.is_empty() {
+            writeln!(code)?;
+            writeln!(
+                code,
+                "        fn type_params() -> Vec<move_core_types::language_storage::TypeTag> {{"
+            )?;
+            writeln!(
+                code,
+                "            vec![{}]",
+                type_params
+                    .iter()
+                    .map(|type_param| format!(
+                        "<{} as moveos_types::state::MoveType>::type_tag()",
+                        type_param
+                    ))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            )?;
+            writeln!(code, "        }}")?;
+        }
+        writeln!(code, "    }}")?;
+        writeln!(code)?;
+
+        writeln!(
+            code,
+            "    impl{} moveos_types::state::MoveStructState for {}{}{} {{",
+            generics, name, generics, where_clause
+        )?;
+        writeln!(
+            code,
+            "        fn struct_layout() -> move_core_types::value::MoveStructLayout {{"
+        )?;
+        writeln!(
+            code,
+            "            move_core_types::value::MoveStructLayout::new(vec![{}])",
+            fields
+                .iter()
+                .map(|(_, rust_type)| format!(
+                    "<{} as moveos_types::state::MoveState>::type_layout()",
+                    rust_type
+                ))
+                .collect::<Vec<_>>()
+                .join(", ")
+        )?;
+        writeln!(code, "        }}")?;
+        writeln!(code, "    }}")?;
+        Ok(())
+    }
+
+    /// The Rust type of the Move type of a struct field, `None` if the type is not supported
+    fn rust_type(&self, module_name: &str, ty: &normalized::Type) -> Option<String> {
+        let rust_type = match ty {
+            normalized::Type::Bool => "bool".to_owned(),
+            normalized::Type::U8 => "u8".to_owned(),
+            normalized::Type::U16 => "u16".to_owned(),
+            normalized::Type::U32 => "u32".to_owned(),
+            normalized::Type::U64 => "u64".to_owned(),
+            normalized::Type::U128 => "u128".to_owned(),
+            normalized::Type::U256 => "move_core_types::u256::U256".to_owned(),
+            normalized::Type::Address => {
+                "move_core_types::account_address::AccountAddress".to_owned()
+            }
+            normalized::Type::Vector(ty) => format!("Vec<{}>", self.rust_type(module_name, ty)?),
+            normalized::Type::TypeParameter(index) => format!("T{}", index),
+            normalized::Type::Struct {
+                address,
+                module,
+                name,
+                type_arguments,
+            } => {
+                let type_arguments = type_arguments
+                    .iter()
+                    .map(|ty| self.rust_type(module_name, ty))
+                    .collect::<Option<Vec<_>>>()?;
+                let generics = if type_arguments.is_empty() {
+                    String::new()
+                } else {
+                    format!("<{}>", type_arguments.join(", "))
+                };
+                match (*address, module.as_str(), name.as_str()) {
+                    (MOVE_STD_ADDRESS, "string", "String") => {
+                        "moveos_types::move_std::string::MoveString".to_owned()
+                    }
+                    (MOVE_STD_ADDRESS, "ascii", "String") => {
+                        "moveos_types::move_std::ascii::MoveAsciiString".to_owned()
+                    }
+                    (MOVE_STD_ADDRESS, "option", "Option") => {
+                        format!("moveos_types::move_std::option::MoveOption{}", generics)
+                    }
+                    (MOVEOS_STD_ADDRESS, "object_id", "ObjectID") => {
+                        "moveos_types::moveos_std::object_id::ObjectID".to_owned()
+                    }
+                    (MOVEOS_STD_ADDRESS, "object", "Object") => {
+                        format!("moveos_types::moveos_std::object::Object{}", generics)
+                    }
+                    _ if self
+                        .package_modules
+                        .contains(&(*address, module.to_string())) =>
+                    {
+                        if module.as_str() == module_name {
+                            format!("{}{}", name, generics)
+                        } else {
+                            format!(
+                                "super::{}::{}{}",
+                                rust_ident(module.as_str()),
+                                name,
+                                generics
+                            )
+                        }
+                    }
+                    _ => return None,
+                }
+            }
+            normalized::Type::Signer
+            | normalized::Type::Reference(_)
+            | normalized::Type::MutableReference(_) => return None,
+        };
+        Some(rust_type)
+    }
+
+    fn generate_entry_function(
+        &self,
+        code: &mut String,
+        name: &str,
+        function: &normalized::Function,
+        parameter_names: Option<&Vec<String>>,
+    ) -> anyhow::Result<()> {
+        let mut parameters = vec![];
+        let mut args = vec![];
+        for (index, ty) in function.parameters.iter().enumerate() {
+            if Self::is_injected_parameter(ty) {
+                continue;
+            }
+            let parameter_name = parameter_names
+                .and_then(|names| names.get(index))
+                .map(|name| rust_ident(name))
+                .unwrap_or_else(|| format!("arg{}", index));
+            match Self::move_value(ty, &parameter_name, 0) {
+                Some((rust_type, arg)) => {
+                    parameters.push(format!("{}: {}", parameter_name, rust_type));
+                    args.push(arg);
+                }
+                None => {
+                    writeln!(
+                        code,
+                        "        // Skipped entry function {}: unsupported parameter type {}",
+                        name,
+                        format_move_type(ty)
+                    )?;
+                    return Ok(());
+                }
+            }
+        }
+
+        let type_params = (0..function.type_parameters.len())
+            .map(|index| format!("T{}: moveos_types::state::MoveType", index))
+            .collect::<Vec<_>>();
+        let generics = if type_params.is_empty() {
+            String::new()
+        } else {
+            format!("<{}>", type_params.join(", "))
+        };
+        let ty_args = (0..function.type_parameters.len())
+            .map(|index| format!("<T{} as moveos_types::state::MoveType>::type_tag()", index))
+            .collect::<Vec<_>>();
+        let function_name_const = format!("{}_FUNCTION_NAME", name.to_uppercase());
+        writeln!(
+            code,
+            "        pub const {}: &'static move_core_types::identifier::IdentStr = move_core_types::ident_str!(\"{}\");",
+            function_name_const, name
+        )?;
+        writeln!(code)?;
+        writeln!(
+            code,
+            "        pub fn create_{}_action{}({}) -> moveos_types::transaction::MoveAction {{",
+            name,
+            generics,
+            parameters.join(", ")
+        )?;
+        writeln!(
+            code,
+            "            Self::create_move_action(Self::{}, vec![{}], vec![{}])",
+            function_name_const,
+            ty_args.join(", "),
+            args.join(", ")
+        )?;
+        writeln!(code, "        }}")?;
+        writeln!(code)?;
+        Ok(())
+    }
+
+    /// The signer and `Context` parameters are filled by the VM, they are not passed by the transaction
+    fn is_injected_parameter(ty: &normalized::Type) -> bool {
+        match ty {
+            normalized::Type::Signer => true,
+            normalized::Type::Reference(ty) | normalized::Type::MutableReference(ty) => {
+                matches!(ty.as_ref(), normalized::Type::Signer)
+                    || is_struct(ty, MOVEOS_STD_ADDRESS, "context", "Context")
+            }
+            _ => false,
+        }
+    }
+
+    /// The Rust type of the parameter and the expression converting it to the `MoveValue` of the argument,
+    /// `None` if the type can not be passed by the transaction
+    fn move_value(ty: &normalized::Type, var: &str, depth: usize) -> Option<(String, String)> {
+        let move_value = "move_core_types::value::MoveValue";
+        let value = match ty {
+            normalized::Type::Bool => ("bool".to_owned(), format!("{}::Bool({})", move_value, var)),
+            normalized::Type::U8 => ("u8".to_owned(), format!("{}::U8({})", move_value, var)),
+            normalized::Type::U16 => ("u16".to_owned(), format!("{}::U16({})", move_value, var)),
+            normalized::Type::U32 => ("u32".to_owned(), format!("{}::U32({})", move_value, var)),
+            normalized::Type::U64 => ("u64".to_owned(), format!("{}::U64({})", move_value, var)),
+            normalized::Type::U128 => ("u128".to_owned(), format!("{}::U128({})", move_value, var)),
+            normalized::Type::U256 => (
+                "move_core_types::u256::U256".to_owned(),
+                format!("{}::U256({})", move_value, var),
+            ),
+            normalized::Type::Address => (
+                "move_core_types::account_address::AccountAddress".to_owned(),
+                format!("{}::Address({})", move_value, var),
+            ),
+            normalized::Type::Vector(ty) if matches!(ty.as_ref(), normalized::Type::U8) => (
+                "Vec<u8>".to_owned(),
+                format!("{}::vector_u8({})", move_value, var),
+            ),
+            normalized::Type::Vector(ty) => {
+                let item = format!("v{}", depth);
+                let (rust_type, item_value) = Self::move_value(ty, &item, depth + 1)?;
+                (
+                    format!("Vec<{}>", rust_type),
+                    format!(
+                        "{}::Vector({}.into_iter().map(|{}| {}).collect())",
+                        move_value, var, item, item_value
+                    ),
+                )
+            }
+            // The strings have the same BCS bytes as the `vector<u8>` of the string bytes
+            ty if is_struct(ty, MOVE_STD_ADDRESS, "string", "String")
+                || is_struct(ty, MOVE_STD_ADDRESS, "ascii", "String") =>
+            {
+                (
+                    "String".to_owned(),
+                    format!("{}::vector_u8({}.into_bytes())", move_value, var),
+                )
+            }
+            ty if is_struct(ty, MOVEOS_STD_ADDRESS, "object_id", "ObjectID") => (
+                "moveos_types::moveos_std::object_id::ObjectID".to_owned(),
+                format!("{}::Address({}.into())", move_value, var),
+            ),
+            // The `&Object<T>` and `&mut Object<T>` parameters are resolved by the VM from the object id
+            normalized::Type::Reference(ty) | normalized::Type::MutableReference(ty)
+                if matches!(ty.as_ref(), normalized::Type::Struct { address, module, name, .. }
+                    if *address == MOVEOS_STD_ADDRESS && module.as_str() == "object" && name.as_str() == "Object") =>
+            {
+                (
+                    "moveos_types::moveos_std::object_id::ObjectID".to_owned(),
+                    format!("{}::Address({}.into())", move_value, var),
+                )
+            }
+            _ => return None,
+        };
+        Some(value)
+    }
+}
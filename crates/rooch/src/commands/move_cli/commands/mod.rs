@@ -4,6 +4,7 @@
 pub mod build;
 pub mod explain;
 pub mod framework_upgrade;
+pub mod generate_bindings;
 pub mod integration_test;
 pub mod new;
 pub mod publish;
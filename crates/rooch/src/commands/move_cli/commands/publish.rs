@@ -8,7 +8,10 @@ use move_binary_format::file_format::CompiledModule;
 use move_bytecode_utils::dependency_graph::DependencyGraph;
 use move_bytecode_utils::Modules;
 use move_cli::Move;
+use move_compiler::compiled_unit::CompiledUnit;
+use move_core_types::resolver::ModuleResolver;
 use move_core_types::{identifier::Identifier, language_storage::ModuleId};
+use move_package::compilation::compiled_package::CompiledPackage;
 use moveos_verifier::verifier;
 use rooch_key::key_derive::verify_password;
 use rooch_rpc_api::jsonrpc_types::ExecuteTransactionResponseView;
@@ -16,14 +19,19 @@ use rooch_types::transaction::rooch::RoochTransaction;
 use rpassword::prompt_password;
 
 use crate::cli_types::{CommandAction, TransactionOptions, WalletContextOptions};
+use crate::commands::move_cli::package_hooks::OnChainPackage;
 use moveos::vm::dependency_order::sort_by_dependency_order;
 use moveos_types::{
-    addresses::MOVEOS_STD_ADDRESS, move_types::FunctionId, transaction::MoveAction,
+    addresses::MOVEOS_STD_ADDRESS,
+    h256::{sha3_256_of, H256},
+    move_types::FunctionId,
+    transaction::MoveAction,
 };
 use moveos_verifier::build::run_verifier;
 use rooch_key::keystore::account_keystore::AccountKeystore;
 use rooch_types::address::RoochAddress;
 use rooch_types::error::{RoochError, RoochResult};
+use serde::Serialize;
 use std::collections::BTreeMap;
 use std::io::stderr;
 
@@ -51,6 +59,55 @@ pub struct Publish {
     /// `moveos_std::context::publish_modules_entry`
     #[clap(long)]
     pub by_move_action: bool,
+
+    /// Skip verifying the dependency modules against the published ones.
+    /// By default, the dependency modules built from the packages pinned by the local `Move.lock`
+    /// must be the same as the modules published on chain, otherwise the publish is rejected.
+    #[clap(long, conflicts_with = "verify_only")]
+    pub skip_dependency_verification: bool,
+
+    /// Only compare the local build of the package with the modules already published on chain,
+    /// without sending the publish transaction. It is used for verifying the source of published modules.
+    #[clap(long)]
+    pub verify_only: bool,
+}
+
+/// The verification result of a module, the hash is the SHA3-256 of the module bytecode without metadata
+#[derive(Debug, Clone, Serialize)]
+pub struct ModuleVerification {
+    pub module_id: String,
+    pub local_hash: H256,
+    /// None if the module is not published on chain
+    pub onchain_hash: Option<H256>,
+}
+
+impl ModuleVerification {
+    pub fn is_matched(&self) -> bool {
+        self.onchain_hash == Some(self.local_hash)
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ModuleVerificationReport {
+    pub matched: bool,
+    pub modules: Vec<ModuleVerification>,
+}
+
+impl ModuleVerificationReport {
+    fn mismatched_modules(&self) -> Vec<String> {
+        self.modules
+            .iter()
+            .filter(|module| !module.is_matched())
+            .map(|module| module.module_id.clone())
+            .collect()
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(untagged)]
+pub enum PublishOutput {
+    Executed(ExecuteTransactionResponseView),
+    Verified(ModuleVerificationReport),
 }
 
 impl Publish {
@@ -62,13 +119,68 @@ impl Publish {
         let order_modules = graph.compute_topological_order()?;
         Ok(order_modules.cloned().collect())
     }
+
+    /// The hash of the module bytecode, the metadata is excluded because the runtime metadata
+    /// is injected by the Rooch verifier and the framework modules are published with it.
+    pub fn module_hash(module: &CompiledModule) -> anyhow::Result<H256> {
+        let mut module = module.clone();
+        module.metadata.clear();
+        let mut binary: Vec<u8> = vec![];
+        module.serialize(&mut binary)?;
+        Ok(sha3_256_of(&binary))
+    }
+
+    /// Compare the local modules with the ones published on chain.
+    pub fn verify_modules<'a, R: ModuleResolver>(
+        modules: impl Iterator<Item = &'a CompiledModule>,
+        resolver: &R,
+    ) -> anyhow::Result<ModuleVerificationReport> {
+        let modules = modules
+            .map(|module| {
+                let module_id = module.self_id();
+                let onchain_hash = resolver
+                    .get_module(&module_id)?
+                    .map(|bytes| {
+                        let onchain_module = CompiledModule::deserialize(&bytes)?;
+                        Self::module_hash(&onchain_module)
+                    })
+                    .transpose()?;
+                Ok(ModuleVerification {
+                    module_id: module_id.short_str_lossless(),
+                    local_hash: Self::module_hash(module)?,
+                    onchain_hash,
+                })
+            })
+            .collect::<anyhow::Result<Vec<_>>>()?;
+        Ok(ModuleVerificationReport {
+            matched: modules.iter().all(ModuleVerification::is_matched),
+            modules,
+        })
+    }
+
+    /// The dependency modules built from the sources, the on-chain dependencies are skipped
+    /// because they are built from the interface files generated from the published modules.
+    fn dependency_modules(package: &CompiledPackage) -> impl Iterator<Item = &CompiledModule> {
+        package
+            .deps_compiled_units
+            .iter()
+            .filter(|(_, unit_with_source)| {
+                !OnChainPackage::is_onchain_source(&unit_with_source.source_path)
+            })
+            .filter_map(|(_, unit_with_source)| match &unit_with_source.unit {
+                CompiledUnit::Module(named_module) => Some(&named_module.module),
+                CompiledUnit::Script(_) => None,
+            })
+    }
 }
 
 #[async_trait]
-impl CommandAction<ExecuteTransactionResponseView> for Publish {
-    async fn execute(self) -> RoochResult<ExecuteTransactionResponseView> {
+impl CommandAction<PublishOutput> for Publish {
+    async fn execute(self) -> RoochResult<PublishOutput> {
         // Build context and handle errors
-        let context = self.context_options.build()?;
+        let mut context = self.context_options.build()?;
+        context.sequence_number = self.tx_options.sequence_number;
+        context.expiration_secs = self.tx_options.expiration_secs;
 
         // Clone variables for later use
         let package_path = self
@@ -100,10 +212,25 @@ impl CommandAction<ExecuteTransactionResponseView> for Publish {
             )));
         };
 
+        let resolver = context.get_client().await?;
+        if self.verify_only {
+            let report = Self::verify_modules(modules.iter_modules().into_iter(), &&resolver)?;
+            return Ok(PublishOutput::Verified(report));
+        }
+        if !self.skip_dependency_verification {
+            let report = Self::verify_modules(Self::dependency_modules(&package), &&resolver)?;
+            if !report.matched {
+                return Err(RoochError::ModuleVerificationError(format!(
+                    "The dependency modules {:?} are not the same as the published ones, \
+                    please update the dependencies in Move.lock or use --skip-dependency-verification",
+                    report.mismatched_modules()
+                )));
+            }
+        }
+
         // Initialize bundles vector and sort modules by dependency order
         let mut bundles: Vec<Vec<u8>> = vec![];
         let sorted_modules = sort_by_dependency_order(modules.iter_modules())?;
-        let resolver = context.get_client().await?;
         // Serialize and collect module binaries into bundles
         for module in sorted_modules {
             let module_address = module.self_id().address().to_owned();
@@ -202,6 +329,6 @@ impl CommandAction<ExecuteTransactionResponseView> for Publish {
         };
         //Directly return the result, the publish transaction may be failed.
         //Caller need to check the `execution_info.status` field.
-        Ok(tx_result)
+        Ok(PublishOutput::Executed(tx_result))
     }
 }
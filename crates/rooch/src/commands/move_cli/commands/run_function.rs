@@ -7,18 +7,29 @@ use async_trait::async_trait;
 use clap::Parser;
 use move_command_line_common::types::ParsedStructType;
 use move_core_types::language_storage::TypeTag;
-use moveos_types::transaction::MoveAction;
-use rooch_key::key_derive::verify_password;
-use rooch_key::keystore::account_keystore::AccountKeystore;
-use rooch_rpc_api::jsonrpc_types::ExecuteTransactionResponseView;
+use moveos::gas::table::get_gas_schedule_entries;
+use moveos::vm::moveos_vm::MoveOSVM;
+use moveos_types::gas_config::GasConfig;
+use moveos_types::h256::H256;
+use moveos_types::moveos_std::tx_context::TxContext;
+use moveos_types::state_resolver::MoveOSResolverProxy;
+use moveos_types::transaction::{MoveAction, RawTransactionOutput};
+use rooch_framework::natives::gas_parameter::gas_member::FromOnChainGasSchedule;
+use rooch_framework::natives::{default_gas_schedule, NativeGasParameters};
+use rooch_genesis::RoochGenesis;
+use rooch_rpc_api::jsonrpc_types::{DryRunTransactionResponseView, ExecuteTransactionResponseView};
+use rooch_rpc_client::remote_state_resolver::RemoteStateResolver;
+use rooch_rpc_client::Client;
+use rooch_types::bitcoin::genesis::BitcoinGenesisContext;
+use rooch_types::bitcoin::network::Network;
+use rooch_types::chain_id::RoochChainID;
 use rooch_types::function_arg::parse_function_arg;
 use rooch_types::{
     address::RoochAddress,
     error::{RoochError, RoochResult},
     function_arg::ParsedFunctionId,
-    transaction::rooch::RoochTransaction,
 };
-use rpassword::prompt_password;
+use serde::{Deserialize, Serialize};
 
 /// Run a Move function
 #[derive(Parser)]
@@ -52,14 +63,38 @@ pub struct RunFunction {
 
     #[clap(flatten)]
     tx_options: TransactionOptions,
+
+    /// Dry run the transaction, the state changes are printed but not applied
+    #[clap(long)]
+    pub dry_run: bool,
+
+    /// Simulate the transaction in a local MoveOS against the state forked from the RPC server,
+    /// the states are fetched on demand, so nothing is signed or sent to the server
+    #[clap(long, requires = "dry_run")]
+    pub local: bool,
+
+    /// Break down the gas of the dry run by instruction, native function and storage operation
+    #[clap(long, requires = "dry_run")]
+    pub gas_profile: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum RunFunctionResponseView {
+    Execute(ExecuteTransactionResponseView),
+    DryRun(DryRunTransactionResponseView),
 }
 
 #[async_trait]
-impl CommandAction<ExecuteTransactionResponseView> for RunFunction {
-    async fn execute(self) -> RoochResult<ExecuteTransactionResponseView> {
-        let context = self.context.build()?;
+impl CommandAction<RunFunctionResponseView> for RunFunction {
+    async fn execute(self) -> RoochResult<RunFunctionResponseView> {
+        let mut context = self.context.build()?;
+        context.sequence_number = self.tx_options.sequence_number;
+        context.expiration_secs = self.tx_options.expiration_secs;
         let address_mapping = context.address_mapping();
-        let sender: RoochAddress = context.resolve_address(self.tx_options.sender)?.into();
+        let sender: RoochAddress = context
+            .resolve_address(self.tx_options.sender.clone())?
+            .into();
         let function_id = self.function.into_function_id(&address_mapping)?;
         let args = self
             .args
@@ -76,69 +111,66 @@ impl CommandAction<ExecuteTransactionResponseView> for RunFunction {
             })
             .collect::<Result<Vec<_>>>()?;
         let action = MoveAction::new_function_call(function_id, type_args, args);
-        match (self.tx_options.authenticator, self.tx_options.session_key) {
-            (Some(authenticator), _) => {
-                let tx_data = context.build_tx_data(sender, action).await?;
-                //TODO the authenticator usually is associalted with the RoochTransactinData
-                //So we need to find a way to let user generate the authenticator based on the tx_data.
-                let tx = RoochTransaction::new(tx_data, authenticator.into());
-                context.execute(tx).await
-            }
-            (_, Some(session_key)) => {
-                let tx_data = context.build_tx_data(sender, action).await?;
-                let tx = if context.keystore.get_if_password_is_empty() {
-                    context
-                        .keystore
-                        .sign_transaction_via_session_key(&sender, tx_data, &session_key, None)
-                        .map_err(|e| RoochError::SignMessageError(e.to_string()))?
-                } else {
-                    let password =
-                        prompt_password("Enter the password to run functions:").unwrap_or_default();
-                    let is_verified = verify_password(
-                        Some(password.clone()),
-                        context.keystore.get_password_hash(),
-                    )?;
-
-                    if !is_verified {
-                        return Err(RoochError::InvalidPasswordError(
-                            "Password is invalid".to_owned(),
-                        ));
-                    }
-
-                    context
-                        .keystore
-                        .sign_transaction_via_session_key(
-                            &sender,
-                            tx_data,
-                            &session_key,
-                            Some(password),
-                        )
-                        .map_err(|e| RoochError::SignMessageError(e.to_string()))?
-                };
-                context.execute(tx).await
-            }
-            (None, None) => {
-                if context.keystore.get_if_password_is_empty() {
-                    context.sign_and_execute(sender, action, None).await
-                } else {
-                    let password =
-                        prompt_password("Enter the password to run functions:").unwrap_or_default();
-                    let is_verified = verify_password(
-                        Some(password.clone()),
-                        context.keystore.get_password_hash(),
-                    )?;
+        if self.local {
+            let client = context.get_client().await?;
+            return simulate_locally(client, sender, action, self.gas_profile)
+                .await
+                .map(RunFunctionResponseView::DryRun);
+        }
 
-                    if !is_verified {
-                        return Err(RoochError::InvalidPasswordError(
-                            "Password is invalid".to_owned(),
-                        ));
-                    }
+        let tx = self.tx_options.sign(&context, sender, action).await?;
 
-                    context
-                        .sign_and_execute(sender, action, Some(password))
-                        .await
-                }
-            }
+        if self.dry_run {
+            let client = context.get_client().await?;
+            let resp = client
+                .rooch
+                .dry_run_tx(tx, self.gas_profile)
+                .await
+                .map_err(|e| RoochError::TransactionError(e.to_string()))?;
+            Ok(RunFunctionResponseView::DryRun(resp))
+        } else {
+            context
+                .execute(tx)
+                .await
+                .map(RunFunctionResponseView::Execute)
         }
     }
 }
+
+/// Simulate the action in a local MoveOS, the states are lazily fetched from the RPC server
+async fn simulate_locally(
+    client: Client,
+    sender: RoochAddress,
+    action: MoveAction,
+    gas_profile: bool,
+) -> RoochResult<DryRunTransactionResponseView> {
+    let sequence_number = client.rooch.get_sequence_number(sender).await?;
+    // The remote state resolver blocks on the RPC requests, so run it out of the async runtime
+    let output = tokio::task::spawn_blocking(move || -> Result<RawTransactionOutput> {
+        let resolver = MoveOSResolverProxy(RemoteStateResolver::new(client));
+        let gas_schedule_blob = bcs::to_bytes(&default_gas_schedule())?;
+        let mut genesis = RoochGenesis::build(
+            RoochChainID::LOCAL.genesis_ctx(sender, gas_schedule_blob),
+            BitcoinGenesisContext::new(Network::default().to_num()),
+        )?;
+        // Use the native gas parameters of the forked chain, the same as the executor does
+        if let Some(gas_parameters) = get_gas_schedule_entries(&resolver)
+            .and_then(|entries| NativeGasParameters::from_on_chain_gas_schedule(&entries))
+        {
+            genesis.rooch_framework_gas_params = gas_parameters;
+        }
+
+        let vm = MoveOSVM::new(genesis.all_natives(), genesis.config.clone().vm_config)?;
+        let ctx = TxContext::new(
+            sender.into(),
+            sequence_number,
+            GasConfig::DEFAULT_MAX_GAS_AMOUNT,
+            H256::random(),
+            0,
+        );
+        Ok(vm.simulate(&resolver, ctx, action, gas_profile)?)
+    })
+    .await
+    .map_err(|e| RoochError::UnexpectedError(e.to_string()))??;
+    Ok(output.into())
+}
@@ -14,7 +14,7 @@ use rooch_types::{
     function_arg::{FunctionArg, ParsedFunctionId},
 };
 
-/// Run a Move function
+/// Call a Move view function and print its decoded return values
 #[derive(Parser)]
 pub struct RunViewFunction {
     /// Function name as `<ADDRESS>::<MODULE_ID>::<FUNCTION_NAME>`
@@ -40,11 +40,43 @@ pub struct RunViewFunction {
     #[clap(long = "args")]
     pub args: Vec<FunctionArg>,
 
+    /// Print the decoded return values as a table instead of json
+    #[clap(long, default_value = "false")]
+    table: bool,
+
     /// RPC client options.
     #[clap(flatten)]
     context: WalletContextOptions,
 }
 
+impl RunViewFunction {
+    fn format_table(result: &AnnotatedFunctionResultView) -> String {
+        let mut output = format!(
+            "VM status: {}\n",
+            serde_json::to_string(&result.vm_status).expect("Failed to serialize vm status")
+        );
+        let return_values = match &result.return_values {
+            Some(return_values) => return_values,
+            None => return output,
+        };
+        output.push_str(&format!(
+            "{:^6} | {:^48} | {}\n",
+            "Index", "Type", "Decoded Value"
+        ));
+        output.push_str(&["-"; 100].join(""));
+        for (index, return_value) in return_values.iter().enumerate() {
+            output.push_str(&format!(
+                "\n{:^6} | {:^48} | {}",
+                index,
+                return_value.value.type_tag.to_string(),
+                serde_json::to_string(&return_value.decoded_value)
+                    .expect("Failed to serialize decoded value")
+            ));
+        }
+        output
+    }
+}
+
 #[async_trait]
 impl CommandAction<AnnotatedFunctionResultView> for RunViewFunction {
     async fn execute(self) -> RoochResult<AnnotatedFunctionResultView> {
@@ -75,4 +107,14 @@ impl CommandAction<AnnotatedFunctionResultView> for RunViewFunction {
             .await
             .map_err(|e| RoochError::ViewFunctionError(e.to_string()))
     }
+
+    async fn execute_serialized(self) -> RoochResult<String> {
+        let table = self.table;
+        let result = self.execute().await?;
+        if table {
+            Ok(Self::format_table(&result))
+        } else {
+            Ok(serde_json::to_string_pretty(&result).unwrap())
+        }
+    }
 }
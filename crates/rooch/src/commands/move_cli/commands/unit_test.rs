@@ -1,13 +1,17 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
 
-use std::{collections::BTreeMap, path::PathBuf, sync::Arc};
+use std::io::Write;
+use std::{collections::BTreeMap, path::Path, path::PathBuf, sync::Arc};
 
 use clap::Parser;
 use codespan_reporting::diagnostic::Severity;
 use move_cli::base::test;
 use move_command_line_common::address::NumericalAddress;
 use move_command_line_common::parser::NumberFormat;
+use move_coverage::coverage_map::CoverageMap;
+use move_coverage::summary::{summarize_inst_cov, ModuleSummary};
+use move_package::source_package::layout::SourcePackageLayout;
 use move_package::BuildConfig;
 use move_unit_test::extensions::set_extension_hook;
 use move_vm_runtime::native_extensions::NativeContextExtensions;
@@ -42,6 +46,19 @@ pub struct Test {
     #[clap(long, value_parser = crate::utils::parse_map::< String, String >, default_value = "")]
     pub(crate) named_addresses: BTreeMap<String, String>,
 
+    /// Print the coverage of the functions besides the modules.
+    /// The coverage is computed by `--coverage`, which is only supported by the debug build of rooch.
+    #[clap(long, requires = "coverage")]
+    pub coverage_functions: bool,
+
+    /// Write the HTML coverage report of the modules and the functions to the directory
+    #[clap(long, value_name = "DIR", requires = "coverage")]
+    pub coverage_html: Option<PathBuf>,
+
+    /// Fail if the coverage percentage of the package is lower than the value, e.g. `80`
+    #[clap(long, value_name = "PERCENT", requires = "coverage")]
+    pub min_coverage: Option<f64>,
+
     #[clap(flatten)]
     config_options: WalletContextOptions,
 }
@@ -60,6 +77,8 @@ impl Test {
             .extend(context.parse_and_resolve_addresses(self.named_addresses)?);
 
         let root_path = path.clone().unwrap_or_else(|| PathBuf::from("."));
+        // The test runner changes the current dir to the package root, so resolve it before the tests
+        let package_root = SourcePackageLayout::try_find_root(&root_path.canonicalize()?)?;
 
         build_config.dev_mode = true;
 
@@ -97,11 +116,118 @@ impl Test {
         let cost_table = move_vm_test_utils::gas_schedule::INITIAL_COST_SCHEDULE.clone();
         let natives = all_natives(NativeGasParameters::zeros());
         set_extension_hook(Box::new(new_moveos_natives_runtime));
+        let compute_coverage = self.test.compute_coverage;
+        // The test runner exits the process if any test fails
         self.test
-            .execute(path, build_config, natives, Some(cost_table))
+            .execute(path, build_config.clone(), natives, Some(cost_table))?;
+
+        if compute_coverage {
+            let summaries = coverage_summaries(&package_root, build_config)?;
+            let mut stdout = std::io::stdout();
+            let mut total = 0u64;
+            let mut covered = 0u64;
+            for summary in summaries.iter() {
+                let (module_total, module_covered) =
+                    summary.summarize_human(&mut stdout, self.coverage_functions)?;
+                total += module_total;
+                covered += module_covered;
+            }
+            let percentage = coverage_percentage(covered, total);
+            writeln!(stdout, "+-------------------------+")?;
+            writeln!(stdout, "| Move Coverage: {:>7.2}% |", percentage)?;
+            writeln!(stdout, "+-------------------------+")?;
+
+            if let Some(html_dir) = self.coverage_html {
+                let report_path = write_html_report(&html_dir, &summaries, percentage)?;
+                println!(
+                    "The HTML coverage report is written to {}",
+                    report_path.display()
+                );
+            }
+            if let Some(min_coverage) = self.min_coverage {
+                if percentage < min_coverage {
+                    return Err(anyhow::anyhow!(
+                        "The Move coverage {:.2}% is lower than the minimum coverage {:.2}%",
+                        percentage,
+                        min_coverage
+                    ));
+                }
+            }
+        }
+        Ok(())
     }
 }
 
+/// Summarize the instruction coverage of the root modules of the package by the coverage map of the tests
+fn coverage_summaries(
+    package_root: &Path,
+    build_config: BuildConfig,
+) -> anyhow::Result<Vec<ModuleSummary>> {
+    let coverage_map = CoverageMap::from_binary_file(package_root.join(".coverage_map.mvcov"))?
+        .to_unified_exec_map();
+    let package = build_config.compile_package_no_exit(package_root, &mut std::io::stderr())?;
+    Ok(package
+        .root_modules_map()
+        .iter_modules()
+        .into_iter()
+        .map(|module| summarize_inst_cov(module, &coverage_map))
+        .collect())
+}
+
+fn coverage_percentage(covered: u64, total: u64) -> f64 {
+    if total == 0 {
+        100.0
+    } else {
+        covered as f64 / total as f64 * 100.0
+    }
+}
+
+fn write_html_report(
+    html_dir: &Path,
+    summaries: &[ModuleSummary],
+    percentage: f64,
+) -> anyhow::Result<PathBuf> {
+    let mut html = String::new();
+    html.push_str(
+        "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<title>Move Coverage</title>\n",
+    );
+    html.push_str("<style>table{border-collapse:collapse}td,th{border:1px solid #ccc;padding:2px 8px;text-align:left}.module{font-weight:bold;background:#f0f0f0}</style>\n");
+    html.push_str("</head>\n<body>\n");
+    html.push_str(&format!("<h1>Move Coverage: {:.2}%</h1>\n", percentage));
+    html.push_str("<table>\n<tr><th>Module / Function</th><th>Covered</th><th>Total</th><th>Coverage</th></tr>\n");
+    for summary in summaries {
+        let functions = summary
+            .function_summaries
+            .iter()
+            .filter(|(_, function)| !function.fn_is_native)
+            .collect::<Vec<_>>();
+        let total = functions.iter().map(|(_, function)| function.total).sum();
+        let covered = functions.iter().map(|(_, function)| function.covered).sum();
+        html.push_str(&format!(
+            "<tr class=\"module\"><td>{}</td><td>{}</td><td>{}</td><td>{:.2}%</td></tr>\n",
+            summary.module_name,
+            covered,
+            total,
+            coverage_percentage(covered, total)
+        ));
+        for (name, function) in functions {
+            html.push_str(&format!(
+                "<tr><td>&nbsp;&nbsp;{}</td><td>{}</td><td>{}</td><td>{:.2}%</td></tr>\n",
+                name,
+                function.covered,
+                function.total,
+                coverage_percentage(function.covered, function.total)
+            ));
+        }
+    }
+    html.push_str("</table>\n</body>\n</html>\n");
+
+    std::fs::create_dir_all(html_dir)?;
+    let report_path = html_dir.join("index.html");
+    std::fs::write(&report_path, html)?;
+    Ok(report_path)
+}
+
 static MOVEOSSTORE: Lazy<Box<MoveOSResolverProxy<MoveOSStore>>> = Lazy::new(|| {
     Box::new(MoveOSResolverProxy(
         MoveOSStore::mock_moveos_store().unwrap(),
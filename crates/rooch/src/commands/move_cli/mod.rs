@@ -4,9 +4,9 @@
 use async_trait::async_trait;
 use clap::{Parser, Subcommand};
 use commands::{
-    build::Build, framework_upgrade::FrameworkUpgrade, integration_test::IntegrationTest, new::New,
-    publish::Publish, run_function::RunFunction, run_view_function::RunViewFunction,
-    unit_test::Test,
+    build::Build, framework_upgrade::FrameworkUpgrade, generate_bindings::GenerateBindings,
+    integration_test::IntegrationTest, new::New, publish::Publish, run_function::RunFunction,
+    run_view_function::RunViewFunction, unit_test::Test,
 };
 use move_cli::{
     base::{
@@ -18,9 +18,11 @@ use move_cli::{
 use rooch_types::error::{RoochError, RoochResult};
 
 use crate::commands::move_cli::commands::explain::Explain;
+use crate::commands::move_cli::package_hooks::RoochPackageHooks;
 use crate::CommandAction;
 
 pub mod commands;
+pub mod package_hooks;
 
 #[derive(Parser)]
 pub struct MoveCli {
@@ -48,12 +50,14 @@ pub enum MoveCommand {
     IntegrationTest(IntegrationTest),
     Explain(Explain),
     FrameworkUpgrade(FrameworkUpgrade),
+    GenerateBindings(GenerateBindings),
 }
 
 #[async_trait]
 impl CommandAction<String> for MoveCli {
     async fn execute(self) -> RoochResult<String> {
         let move_args = self.move_args;
+        RoochPackageHooks::register();
         match self.cmd {
             MoveCommand::Build(c) => c
                 .execute(move_args.package_path, move_args.build_config)
@@ -128,6 +132,10 @@ impl CommandAction<String> for MoveCli {
                 .map(|_| "Success".to_owned())
                 .map_err(RoochError::from),
             MoveCommand::FrameworkUpgrade(c) => c.execute_serialized().await,
+            MoveCommand::GenerateBindings(c) => c
+                .execute(move_args.package_path, move_args.build_config)
+                .await
+                .map_err(RoochError::from),
         }
     }
 }
@@ -0,0 +1,173 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use anyhow::{bail, Result};
+use move_binary_format::file_format::CompiledModule;
+use move_core_types::account_address::AccountAddress;
+use move_package::package_hooks::{register_package_hooks, PackageHooks};
+use move_package::source_package::parsed_manifest::CustomDepInfo;
+use move_symbol_pool::Symbol;
+use moveos_types::access_path::AccessPath;
+use moveos_types::moveos_std::move_module::MoveModule;
+use moveos_types::state::State;
+use rooch_rpc_client::ClientBuilder;
+use rooch_types::address::RoochAddress;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::Path;
+use std::str::FromStr;
+
+/// The key of the on-chain dependency in the Move.toml, the value is the RPC url of the node, for example:
+/// `Foo = { rooch = "https://dev-seed.rooch.network", address = "0x42" }`
+pub const ROOCH_DEPENDENCY_KEY: &str = "rooch";
+/// The file records the origin of the package generated from the on-chain modules
+pub const ONCHAIN_PACKAGE_FILE: &str = "OnChain.json";
+
+const LIST_MODULES_PAGE_SIZE: usize = 100;
+
+/// The origin of the package generated from the on-chain modules
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OnChainPackage {
+    pub node_url: String,
+    pub address: AccountAddress,
+    pub modules: Vec<String>,
+}
+
+impl OnChainPackage {
+    /// Whether the source file belongs to a package generated from the on-chain modules,
+    /// the source files are at `<package>/sources/<module>.move`.
+    pub fn is_onchain_source(source_path: &Path) -> bool {
+        source_path
+            .parent()
+            .and_then(Path::parent)
+            .map(|package_path| package_path.join(ONCHAIN_PACKAGE_FILE).exists())
+            .unwrap_or(false)
+    }
+}
+
+/// Resolve the dependencies on the packages published on chain.
+/// The module bytecode is downloaded from the node, and the interface files generated from it
+/// are used as the sources of the dependency, so the package can depend on the deployed modules directly.
+pub struct RoochPackageHooks {}
+
+impl RoochPackageHooks {
+    pub fn register() {
+        register_package_hooks(Box::new(RoochPackageHooks {}));
+    }
+}
+
+impl PackageHooks for RoochPackageHooks {
+    fn custom_package_info_fields(&self) -> Vec<String> {
+        vec![]
+    }
+
+    fn custom_dependency_key(&self) -> Option<String> {
+        Some(ROOCH_DEPENDENCY_KEY.to_owned())
+    }
+
+    fn resolve_custom_dependency(&self, dep_name: Symbol, info: &CustomDepInfo) -> Result<()> {
+        // The downloaded package is reused, remove the directory to download it again after an upgrade
+        if info.download_to.join(ONCHAIN_PACKAGE_FILE).exists() {
+            return Ok(());
+        }
+        let address = parse_package_address(info.package_address.as_str())?;
+        let modules =
+            futures::executor::block_on(download_modules(info.node_url.as_str(), address))?;
+        if modules.is_empty() {
+            bail!(
+                "No module is published at {} on {}, failed to resolve the dependency {}",
+                address.to_hex_literal(),
+                info.node_url,
+                dep_name
+            );
+        }
+        save_package(
+            info.download_to.as_path(),
+            info.package_name.as_str(),
+            info.node_url.as_str(),
+            address,
+            modules,
+        )
+    }
+}
+
+fn parse_package_address(address: &str) -> Result<AccountAddress> {
+    AccountAddress::from_hex_literal(address)
+        .or_else(|_| RoochAddress::from_str(address).map(AccountAddress::from))
+        .map_err(|_| anyhow::anyhow!("Invalid package address: {}", address))
+}
+
+/// Download all the modules published at the address
+async fn download_modules(node_url: &str, address: AccountAddress) -> Result<Vec<CompiledModule>> {
+    let client = ClientBuilder::default().build(node_url).await?;
+    let access_path = AccessPath::modules_of(address);
+    let mut modules = vec![];
+    let mut cursor = None;
+    loop {
+        let page = client
+            .rooch
+            .list_states(
+                access_path.clone().into(),
+                cursor,
+                Some(LIST_MODULES_PAGE_SIZE),
+            )
+            .await?;
+        for state_kv in page.data {
+            let module = State::from(state_kv.state).cast::<MoveModule>()?;
+            modules.push(CompiledModule::deserialize(&module.byte_codes)?);
+        }
+        if !page.has_next_page {
+            break;
+        }
+        cursor = page.next_cursor;
+    }
+    Ok(modules)
+}
+
+/// Save the modules as a package with the interface files as sources, and the bytecode for reference
+fn save_package(
+    package_path: &Path,
+    package_name: &str,
+    node_url: &str,
+    address: AccountAddress,
+    modules: Vec<CompiledModule>,
+) -> Result<()> {
+    let sources_path = package_path.join("sources");
+    let bytecode_path = package_path.join("bytecode_modules");
+    std::fs::create_dir_all(&sources_path)?;
+    std::fs::create_dir_all(&bytecode_path)?;
+
+    let mut module_names = vec![];
+    for module in modules {
+        let (module_id, interface) =
+            move_compiler::interface_generator::write_module_to_string(&BTreeMap::new(), &module)?;
+        let module_name = module_id.name().to_string();
+        std::fs::write(
+            sources_path.join(format!("{}.move", module_name)),
+            interface,
+        )?;
+        let mut binary = vec![];
+        module.serialize(&mut binary)?;
+        std::fs::write(bytecode_path.join(format!("{}.mv", module_name)), binary)?;
+        module_names.push(module_name);
+    }
+
+    std::fs::write(
+        package_path.join("Move.toml"),
+        format!(
+            "[package]\nname = \"{}\"\nversion = \"0.0.0\"\n",
+            package_name
+        ),
+    )?;
+    // The origin file is written at last, it marks the package is downloaded completely
+    let onchain_package = OnChainPackage {
+        node_url: node_url.to_owned(),
+        address,
+        modules: module_names,
+    };
+    std::fs::write(
+        package_path.join(ONCHAIN_PACKAGE_FILE),
+        serde_json::to_string_pretty(&onchain_package)?,
+    )?;
+    Ok(())
+}
@@ -0,0 +1,54 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::cli_types::{CommandAction, WalletContextOptions};
+use async_trait::async_trait;
+use clap::Parser;
+use moveos_types::access_path::AccessPath;
+use rooch_rpc_api::jsonrpc_types::StatePageView;
+use rooch_types::{error::RoochResult, function_arg::ParsedObjectID};
+
+/// List the dynamic fields of the object, the fields are stored in the table with the object id as the handle
+#[derive(Debug, Parser)]
+pub struct DynamicFieldsCommand {
+    /// Object id.
+    pub id: ParsedObjectID,
+
+    /// The exclusive cursor, the `next_cursor` returned by the previous page
+    #[clap(long)]
+    pub cursor: Option<String>,
+
+    #[clap(long)]
+    pub limit: Option<usize>,
+
+    /// Only return the BCS bytes of the fields, without the decoded values
+    #[clap(long)]
+    pub raw: bool,
+
+    #[clap(flatten)]
+    pub(crate) context_options: WalletContextOptions,
+}
+
+#[async_trait]
+impl CommandAction<StatePageView> for DynamicFieldsCommand {
+    async fn execute(self) -> RoochResult<StatePageView> {
+        let context = self.context_options.build()?;
+        let mapping = context.address_mapping();
+        let id = self.id.into_object_id(&mapping)?;
+        let client = context.get_client().await?;
+        let access_path = AccessPath::table_without_keys(id).into();
+        let resp = if self.raw {
+            client
+                .rooch
+                .list_states(access_path, self.cursor, self.limit)
+                .await?
+        } else {
+            client
+                .rooch
+                .list_decoded_states(access_path, self.cursor, self.limit)
+                .await?
+        };
+
+        Ok(resp)
+    }
+}
@@ -10,29 +10,32 @@ use rooch_types::{error::RoochResult, function_arg::ParsedObjectID};
 
 /// Get object by object id
 #[derive(Debug, Parser)]
-pub struct ObjectCommand {
+pub struct GetCommand {
     /// Object id.
-    #[clap(long)]
     pub id: ParsedObjectID,
 
+    /// Only return the BCS bytes of the object, without the decoded value
+    #[clap(long)]
+    pub raw: bool,
+
     #[clap(flatten)]
     pub(crate) context_options: WalletContextOptions,
 }
 
 #[async_trait]
-impl CommandAction<Option<StateView>> for ObjectCommand {
+impl CommandAction<Option<StateView>> for GetCommand {
     async fn execute(self) -> RoochResult<Option<StateView>> {
         let context = self.context_options.build()?;
         let mapping = context.address_mapping();
         let id = self.id.into_object_id(&mapping)?;
         let client = context.get_client().await?;
-        let resp = client
-            .rooch
-            .get_decoded_states(AccessPath::object(id))
-            .await?
-            .pop()
-            .flatten();
+        let access_path = AccessPath::object(id);
+        let resp = if self.raw {
+            client.rooch.get_states(access_path).await?
+        } else {
+            client.rooch.get_decoded_states(access_path).await?
+        };
 
-        Ok(resp)
+        Ok(resp.into_iter().next().flatten())
     }
 }
@@ -0,0 +1,113 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::cli_types::{CommandAction, WalletContextOptions};
+use async_trait::async_trait;
+use clap::Parser;
+use move_command_line_common::{address::ParsedAddress, types::ParsedStructType};
+use moveos_types::{access_path::AccessPath, moveos_std::object_id::ObjectID};
+use rooch_rpc_api::jsonrpc_types::{
+    AccountAddressView, GlobalStateFilterView, IndexerGlobalStatePageView, PageView, StateView,
+    StructTagView,
+};
+use rooch_types::error::RoochResult;
+use serde::{Deserialize, Serialize};
+
+/// List the objects owned by the account, the objects are queried from the indexer
+#[derive(Debug, Parser)]
+pub struct ListCommand {
+    /// The owner address of the objects, if absent, use the default active account.
+    #[clap(long, value_parser=ParsedAddress::parse, default_value = "default")]
+    pub owner: ParsedAddress,
+
+    /// Only list the objects of the struct type, as `<ADDRESS>::<MODULE_ID>::<STRUCT_NAME><TypeParam1?, TypeParam2?>`
+    /// Example: `0x3::coin_store::CoinStore<0x3::gas_coin::GasCoin>`
+    #[clap(long = "type", value_parser=ParsedStructType::parse)]
+    pub object_type: Option<ParsedStructType>,
+
+    /// The exclusive cursor, the `next_cursor` returned by the previous page
+    #[clap(long)]
+    pub cursor: Option<String>,
+
+    #[clap(long)]
+    pub limit: Option<usize>,
+
+    /// List the latest updated objects first
+    #[clap(long)]
+    pub descending_order: bool,
+
+    /// Only return the BCS bytes of the objects, without the decoded values
+    #[clap(long)]
+    pub raw: bool,
+
+    #[clap(flatten)]
+    pub(crate) context_options: WalletContextOptions,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RawObjectView {
+    pub object_id: ObjectID,
+    pub owner: AccountAddressView,
+    pub object_type: StructTagView,
+    pub state: Option<StateView>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ObjectPageView {
+    Decoded(IndexerGlobalStatePageView),
+    Raw(PageView<RawObjectView, String>),
+}
+
+#[async_trait]
+impl CommandAction<ObjectPageView> for ListCommand {
+    async fn execute(self) -> RoochResult<ObjectPageView> {
+        let context = self.context_options.build()?;
+        let mapping = context.address_mapping();
+        let owner = self.owner.into_account_address(&mapping)?;
+        let filter = match self.object_type {
+            Some(object_type) => GlobalStateFilterView::ObjectTypeWithOwner {
+                object_type: object_type.into_struct_tag(&mapping)?.into(),
+                owner: owner.into(),
+            },
+            None => GlobalStateFilterView::Owner(owner.into()),
+        };
+        let client = context.get_client().await?;
+
+        let page = client
+            .rooch
+            .query_global_states(
+                filter,
+                self.cursor,
+                self.limit,
+                Some(self.descending_order),
+                None,
+            )
+            .await?;
+        if !self.raw || page.data.is_empty() {
+            return Ok(ObjectPageView::Decoded(page));
+        }
+
+        let object_ids = page.data.iter().map(|state| state.object_id).collect();
+        let states = client
+            .rooch
+            .get_states(AccessPath::objects(object_ids))
+            .await?;
+        let data = page
+            .data
+            .into_iter()
+            .zip(states)
+            .map(|(global_state, state)| RawObjectView {
+                object_id: global_state.object_id,
+                owner: global_state.owner,
+                object_type: global_state.object_type,
+                state,
+            })
+            .collect();
+        Ok(ObjectPageView::Raw(PageView {
+            data,
+            next_cursor: page.next_cursor,
+            has_next_page: page.has_next_page,
+        }))
+    }
+}
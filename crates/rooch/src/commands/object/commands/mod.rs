@@ -0,0 +1,6 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+pub mod dynamic_fields;
+pub mod get;
+pub mod list;
@@ -0,0 +1,37 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::cli_types::CommandAction;
+use crate::commands::object::commands::{
+    dynamic_fields::DynamicFieldsCommand, get::GetCommand, list::ListCommand,
+};
+use async_trait::async_trait;
+use clap::{Parser, Subcommand};
+use rooch_types::error::RoochResult;
+
+pub mod commands;
+
+/// Tool for inspecting objects
+#[derive(Parser)]
+pub struct Object {
+    #[clap(subcommand)]
+    cmd: ObjectCommand,
+}
+
+#[async_trait]
+impl CommandAction<String> for Object {
+    async fn execute(self) -> RoochResult<String> {
+        match self.cmd {
+            ObjectCommand::Get(cmd) => cmd.execute_serialized().await,
+            ObjectCommand::List(cmd) => cmd.execute_serialized().await,
+            ObjectCommand::DynamicFields(cmd) => cmd.execute_serialized().await,
+        }
+    }
+}
+
+#[derive(Subcommand)]
+pub enum ObjectCommand {
+    Get(GetCommand),
+    List(ListCommand),
+    DynamicFields(DynamicFieldsCommand),
+}
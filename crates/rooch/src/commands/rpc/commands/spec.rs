@@ -0,0 +1,50 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::cli_types::{CommandAction, WalletContextOptions};
+use async_trait::async_trait;
+use clap::Parser;
+use rooch_open_rpc_spec_builder::{build_rooch_rpc_spec, RPC_DISCOVER_METHOD};
+use rooch_types::error::{RoochError, RoochResult};
+use std::path::PathBuf;
+
+/// Dump the OpenRPC document of the JSON-RPC APIs, it could be used to generate the clients and diff the APIs across releases.
+/// The document is built from the APIs of this binary by default, or fetched from the server by `rpc.discover` with `--remote`.
+#[derive(Debug, Parser)]
+pub struct SpecCommand {
+    /// Fetch the document from the server of the active env instead of building it locally
+    #[clap(long)]
+    pub remote: bool,
+
+    /// Write the document to the file instead of printing it
+    #[clap(long, short = 'o')]
+    pub output: Option<PathBuf>,
+
+    #[clap(flatten)]
+    pub(crate) context_options: WalletContextOptions,
+}
+
+#[async_trait]
+impl CommandAction<String> for SpecCommand {
+    async fn execute(self) -> RoochResult<String> {
+        let spec = if self.remote {
+            let client = self.context_options.build()?.get_client().await?;
+            client.request(RPC_DISCOVER_METHOD, vec![]).await?
+        } else {
+            serde_json::to_value(build_rooch_rpc_spec())
+                .map_err(|e| RoochError::UnexpectedError(e.to_string()))?
+        };
+        let content = serde_json::to_string_pretty(&spec)
+            .map_err(|e| RoochError::UnexpectedError(e.to_string()))?;
+        match self.output {
+            Some(output) => {
+                std::fs::write(&output, content + "\n")?;
+                Ok(format!(
+                    "OpenRPC document is written to {}",
+                    output.display()
+                ))
+            }
+            None => Ok(content),
+        }
+    }
+}
@@ -5,6 +5,7 @@ use crate::cli_types::CommandAction;
 use async_trait::async_trait;
 use clap::Parser;
 use commands::request::RequestCommand;
+use commands::spec::SpecCommand;
 use rooch_types::error::RoochResult;
 
 pub mod commands;
@@ -20,6 +21,7 @@ impl CommandAction<String> for Rpc {
     async fn execute(self) -> RoochResult<String> {
         match self.cmd {
             RpcCommand::Request(request) => request.execute_serialized().await,
+            RpcCommand::Spec(spec) => spec.execute().await,
         }
     }
 }
@@ -28,4 +30,5 @@ impl CommandAction<String> for Rpc {
 #[clap(name = "server")]
 pub enum RpcCommand {
     Request(RequestCommand),
+    Spec(SpecCommand),
 }
@@ -2,4 +2,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 pub mod clean;
+pub mod replay;
+pub mod snapshot;
 pub mod start;
+pub mod sync;
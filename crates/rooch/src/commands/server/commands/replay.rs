@@ -0,0 +1,264 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::cli_types::{CommandAction, WalletContextOptions};
+use async_trait::async_trait;
+use clap::Parser;
+use moveos_config::{temp_dir, DataDirPath};
+use moveos_store::transaction_store::TransactionStore;
+use moveos_store::MoveOSStore;
+use moveos_types::h256::H256;
+use moveos_types::transaction::TransactionExecutionInfo;
+use rooch_config::store_config::StoreConfig;
+use rooch_config::{BaseConfig, RoochOpt};
+use rooch_executor::actor::executor::ExecutorActor;
+use rooch_framework::natives::default_gas_schedule;
+use rooch_rpc_server::init_storage;
+use rooch_store::meta_store::MetaStore;
+use rooch_store::transaction_store::TransactionStore as SequencerTransactionStore;
+use rooch_store::RoochStore;
+use rooch_types::address::RoochAddress;
+use rooch_types::bitcoin::genesis::BitcoinGenesisContext;
+use rooch_types::bitcoin::network::Network;
+use rooch_types::error::{RoochError, RoochResult};
+use rooch_types::transaction::{TransactionSequenceInfo, TypedTransaction};
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+use std::sync::Arc;
+
+/// Replay the transactions in the rooch store against a state rebuilt from genesis,
+/// and verify the execution results with the ones saved by the server.
+/// The server must be stopped before replaying, the store can not be opened by two processes.
+#[derive(Debug, Parser)]
+pub struct ReplayCommand {
+    /// The tx order to start verifying from, the transactions before it are replayed but not verified
+    #[clap(long, default_value = "1")]
+    from: u64,
+
+    /// The tx order to replay to (inclusive), default to the latest tx order in the store
+    #[clap(long)]
+    to: Option<u64>,
+
+    #[clap(flatten)]
+    opt: RoochOpt,
+
+    #[clap(flatten)]
+    pub context_options: WalletContextOptions,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReplayDivergence {
+    pub tx_order: u64,
+    pub tx_hash: H256,
+    pub expected_state_root: H256,
+    pub actual_state_root: H256,
+    pub expected_event_root: H256,
+    pub actual_event_root: H256,
+    pub expected_gas_used: u64,
+    pub actual_gas_used: u64,
+    pub expected_status: String,
+    pub actual_status: String,
+}
+
+impl ReplayDivergence {
+    fn new(
+        tx_order: u64,
+        expected: &TransactionExecutionInfo,
+        actual: &TransactionExecutionInfo,
+    ) -> Self {
+        Self {
+            tx_order,
+            tx_hash: expected.tx_hash,
+            expected_state_root: expected.state_root,
+            actual_state_root: actual.state_root,
+            expected_event_root: expected.event_root,
+            actual_event_root: actual.event_root,
+            expected_gas_used: expected.gas_used,
+            actual_gas_used: actual.gas_used,
+            expected_status: format!("{:?}", expected.status),
+            actual_status: format!("{:?}", actual.status),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReplayOutput {
+    pub from: u64,
+    pub to: u64,
+    /// The number of the replayed transactions, include the ones before `from`
+    pub replayed: u64,
+    /// The number of the verified transactions
+    pub verified: u64,
+    /// The first divergent transaction, the replay stops at it because the following states are all divergent
+    pub divergence: Option<ReplayDivergence>,
+}
+
+/// Resolve the sequencer account of the genesis, default to the active address.
+pub(crate) fn sequencer_account(
+    opt: &RoochOpt,
+    context_options: &WalletContextOptions,
+) -> RoochResult<RoochAddress> {
+    match &opt.sequencer_account {
+        Some(sequencer_account) => RoochAddress::from_str(sequencer_account).map_err(|e| {
+            RoochError::CommandArgumentError(format!("Invalid sequencer account address: {}", e))
+        }),
+        None => {
+            let context = context_options.build()?;
+            context
+                .client_config
+                .active_address
+                .ok_or(RoochError::ActiveAddressDoesNotExistError)
+        }
+    }
+}
+
+/// Open the stores of the server, and return the latest tx order.
+pub(crate) fn open_server_storage(opt: &RoochOpt) -> RoochResult<(MoveOSStore, RoochStore, u64)> {
+    let base_config = BaseConfig::load_with_opt(opt)?;
+    let mut store_config = StoreConfig::default();
+    store_config.merge_with_opt_with_init(opt, Arc::new(base_config), false)?;
+    let (moveos_store, rooch_store) = init_storage(&store_config)?;
+    let last_order = rooch_store
+        .get_sequencer_order()?
+        .map(|order| order.last_order)
+        .unwrap_or(0);
+    Ok((moveos_store, rooch_store, last_order))
+}
+
+pub(crate) fn load_transaction(
+    rooch_store: &RoochStore,
+    moveos_store: &MoveOSStore,
+    tx_order: u64,
+) -> RoochResult<(
+    TypedTransaction,
+    TransactionSequenceInfo,
+    TransactionExecutionInfo,
+)> {
+    let not_exist = || RoochError::ReplayError(format!("The tx order {} does not exist", tx_order));
+    let tx_hash = rooch_store
+        .get_tx_sequence_info_mapping_by_order(vec![tx_order])?
+        .pop()
+        .flatten()
+        .ok_or_else(not_exist)?
+        .tx_hash;
+    let sequence_info = rooch_store
+        .get_tx_sequence_infos_by_order(Some(tx_order - 1), 1)?
+        .pop()
+        .flatten()
+        .ok_or_else(not_exist)?;
+    let tx = rooch_store
+        .get_transaction_by_hash(tx_hash)?
+        .ok_or_else(|| {
+            RoochError::ReplayError(format!("The transaction {:?} does not exist", tx_hash))
+        })?;
+    let execution_info = moveos_store
+        .get_transaction_store()
+        .get_tx_execution_info(tx_hash)?
+        .ok_or_else(|| {
+            RoochError::ReplayError(format!(
+                "The execution info of transaction {:?} does not exist",
+                tx_hash
+            ))
+        })?;
+    Ok((tx, sequence_info, execution_info))
+}
+
+/// The executor re-executes the transactions against a state rebuilt from genesis in a temporary store.
+pub(crate) struct ReplayExecutor {
+    pub executor: ExecutorActor,
+    pub moveos_store: MoveOSStore,
+    // Keep the temporary store alive, it is removed when dropped
+    _data_dir: DataDirPath,
+}
+
+impl ReplayExecutor {
+    pub fn new(
+        opt: &RoochOpt,
+        sequencer_account: RoochAddress,
+        moveos_store: &MoveOSStore,
+    ) -> RoochResult<Self> {
+        let data_dir = temp_dir();
+        let mut replay_opt = opt.clone();
+        replay_opt.base_data_dir = Some(data_dir.path().to_path_buf());
+        let replay_base_config = BaseConfig::load_with_opt(&replay_opt)?;
+        let mut replay_store_config = StoreConfig::default();
+        replay_store_config.merge_with_opt_with_init(
+            &replay_opt,
+            Arc::new(replay_base_config),
+            true,
+        )?;
+        let (replay_moveos_store, replay_rooch_store) = init_storage(&replay_store_config)?;
+
+        let chain_id = opt.chain_id.clone().unwrap_or_default();
+        let gas_schedule_blob = bcs::to_bytes(&default_gas_schedule())
+            .expect("Failure serializing genesis gas schedule");
+        let btc_network = opt.btc_network.unwrap_or(Network::default().to_num());
+        let executor = ExecutorActor::new(
+            chain_id.genesis_ctx(sequencer_account, gas_schedule_blob),
+            BitcoinGenesisContext::new(btc_network),
+            replay_moveos_store.clone(),
+            replay_rooch_store,
+        )?;
+        executor
+            .genesis()
+            .check_genesis(moveos_store.get_config_store())?;
+
+        Ok(Self {
+            executor,
+            moveos_store: replay_moveos_store,
+            _data_dir: data_dir,
+        })
+    }
+}
+
+#[async_trait]
+impl CommandAction<ReplayOutput> for ReplayCommand {
+    async fn execute(self) -> RoochResult<ReplayOutput> {
+        let sequencer_account = sequencer_account(&self.opt, &self.context_options)?;
+        let (moveos_store, rooch_store, last_order) = open_server_storage(&self.opt)?;
+
+        let to = self.to.unwrap_or(last_order);
+        if to > last_order {
+            return Err(RoochError::ReplayError(format!(
+                "The tx order {} is greater than the latest tx order {}",
+                to, last_order
+            )));
+        }
+        if self.from == 0 || self.from > to {
+            return Err(RoochError::ReplayError(format!(
+                "Invalid replay range [{}, {}]",
+                self.from, to
+            )));
+        }
+
+        // Rebuild the state from genesis in a temporary store, it is removed after the replay
+        let mut replay = ReplayExecutor::new(&self.opt, sequencer_account, &moveos_store)?;
+
+        let mut replayed = 0;
+        let mut verified = 0;
+        let mut divergence = None;
+        for tx_order in 1..=to {
+            let (tx, _, expected) = load_transaction(&rooch_store, &moveos_store, tx_order)?;
+
+            let verified_tx = replay.executor.validate(tx)?;
+            let actual = replay.executor.execute(verified_tx)?.transaction_info;
+            replayed += 1;
+
+            if tx_order >= self.from {
+                verified += 1;
+                if actual != expected {
+                    divergence = Some(ReplayDivergence::new(tx_order, &expected, &actual));
+                    break;
+                }
+            }
+        }
+
+        Ok(ReplayOutput {
+            from: self.from,
+            to,
+            replayed,
+            verified,
+            divergence,
+        })
+    }
+}
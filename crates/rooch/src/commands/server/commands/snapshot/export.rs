@@ -0,0 +1,243 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use super::{SnapshotManifest, SnapshotRecord, SnapshotWriter, SNAPSHOT_VERSION};
+use crate::cli_types::CommandAction;
+use crate::commands::server::commands::replay::open_server_storage;
+use async_trait::async_trait;
+use clap::Parser;
+use moveos_config::temp_dir;
+use moveos_store::transaction_store::TransactionStore;
+use moveos_store::{
+    MoveOSStore, CONFIG_GENESIS_PREFIX_NAME, EVENT_HANDLE_PREFIX_NAME, EVENT_PREFIX_NAME,
+    STATE_NODE_PREFIX_NAME,
+};
+use moveos_types::h256::H256;
+use raw_store::SchemaStore;
+use rooch_config::indexer_config::{IndexerConfig, ROOCH_INDEXER_DB_FILENAME};
+use rooch_config::{BaseConfig, RoochOpt};
+use rooch_indexer::store::traits::IndexerStoreTrait;
+use rooch_indexer::IndexerStore;
+use rooch_store::meta_store::MetaStore;
+use rooch_store::transaction_store::TransactionStore as SequencerTransactionStore;
+use rooch_store::RoochStore;
+use rooch_types::error::{RoochError, RoochResult};
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+use std::sync::Arc;
+
+/// The number of the transactions loaded from the store in a batch
+const EXPORT_TX_BATCH_SIZE: u64 = 1000;
+
+/// Export the server storage at a tx order to a snapshot archive.
+#[derive(Debug, Parser)]
+pub struct ExportCommand {
+    /// The path of the snapshot archive to create
+    #[clap(long, short = 'o')]
+    output: PathBuf,
+
+    /// The tx order to take the snapshot at, default to the latest tx order in the store
+    #[clap(long)]
+    tx_order: Option<u64>,
+
+    /// Do not export the indexer db, the indexer of the new node needs to be rebuilt
+    #[clap(long)]
+    skip_indexer: bool,
+
+    #[clap(flatten)]
+    opt: RoochOpt,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExportOutput {
+    pub output: PathBuf,
+    pub manifest: SnapshotManifest,
+    /// The number of the raw key values of the MoveOS store
+    pub raw_entries: u64,
+    pub transactions: u64,
+}
+
+#[async_trait]
+impl CommandAction<ExportOutput> for ExportCommand {
+    async fn execute(self) -> RoochResult<ExportOutput> {
+        let (moveos_store, rooch_store, last_order) = open_server_storage(&self.opt)?;
+        let has_transactions = rooch_store.get_sequencer_order()?.is_some();
+
+        let tx_order = self.tx_order.unwrap_or(last_order);
+        if tx_order > last_order {
+            return Err(RoochError::SnapshotError(format!(
+                "The tx order {} is greater than the latest tx order {}",
+                tx_order, last_order
+            )));
+        }
+        let state_root = snapshot_state_root(&moveos_store, &rooch_store, tx_order)?;
+
+        let indexer_db = if self.skip_indexer {
+            None
+        } else {
+            export_indexer_db(&self.opt, tx_order, last_order)?
+        };
+
+        let manifest = SnapshotManifest {
+            version: SNAPSHOT_VERSION,
+            tx_order,
+            state_root,
+            include_events: tx_order == last_order,
+            include_indexer: indexer_db.is_some(),
+        };
+        let mut writer = SnapshotWriter::create(&self.output)?;
+        writer.write(&SnapshotRecord::Manifest(manifest.clone()))?;
+
+        // The state nodes are content addressed, so the nodes of all the state roots are exported,
+        // the imported node starts from the state root of the snapshot.
+        let mut column_families = vec![STATE_NODE_PREFIX_NAME, CONFIG_GENESIS_PREFIX_NAME];
+        if manifest.include_events {
+            column_families.extend([EVENT_PREFIX_NAME, EVENT_HANDLE_PREFIX_NAME]);
+        }
+        let db = moveos_store
+            .moveosdb
+            .node_store
+            .get_store()
+            .store()
+            .db()
+            .ok_or_else(|| RoochError::SnapshotError("Only support db store".to_owned()))?;
+        let mut raw_entries = 0;
+        for column_family in column_families {
+            for item in db.raw_iter(column_family) {
+                let (key, value) = item?;
+                writer.write(&SnapshotRecord::MoveOSRaw {
+                    column_family: column_family.to_owned(),
+                    key,
+                    value,
+                })?;
+                raw_entries += 1;
+            }
+        }
+
+        let mut transactions = 0;
+        if has_transactions {
+            let mut start = 0;
+            while start <= tx_order {
+                let end = std::cmp::min(start + EXPORT_TX_BATCH_SIZE - 1, tx_order);
+                for record in load_transactions(&moveos_store, &rooch_store, start, end)? {
+                    writer.write(&record)?;
+                    transactions += 1;
+                }
+                start = end + 1;
+            }
+        }
+
+        if let Some(indexer_db) = indexer_db {
+            writer.write(&SnapshotRecord::IndexerDB(indexer_db))?;
+        }
+        writer.finish()?;
+
+        Ok(ExportOutput {
+            output: self.output,
+            manifest,
+            raw_entries,
+            transactions,
+        })
+    }
+}
+
+/// The state root after executing the transaction of the tx order,
+/// or the startup state root if there is no transaction.
+fn snapshot_state_root(
+    moveos_store: &MoveOSStore,
+    rooch_store: &RoochStore,
+    tx_order: u64,
+) -> RoochResult<H256> {
+    let tx_hash = rooch_store
+        .get_tx_sequence_info_mapping_by_order(vec![tx_order])?
+        .pop()
+        .flatten()
+        .map(|mapping| mapping.tx_hash);
+    let state_root = match tx_hash {
+        Some(tx_hash) => moveos_store
+            .get_transaction_store()
+            .get_tx_execution_info(tx_hash)?
+            .map(|info| info.state_root),
+        None => moveos_store
+            .get_config_store()
+            .get_startup_info()?
+            .map(|info| info.state_root_hash),
+    };
+    state_root.ok_or_else(|| {
+        RoochError::SnapshotError(format!(
+            "Can not find the state root of the tx order {}",
+            tx_order
+        ))
+    })
+}
+
+/// Load the transactions of the tx orders in `[start, end]`, skip the tx orders without transaction.
+fn load_transactions(
+    moveos_store: &MoveOSStore,
+    rooch_store: &RoochStore,
+    start: u64,
+    end: u64,
+) -> RoochResult<Vec<SnapshotRecord>> {
+    let tx_orders = (start..=end).collect::<Vec<_>>();
+    let mappings = rooch_store.get_tx_sequence_info_mapping_by_order(tx_orders)?;
+    let cursor = if start == 0 { None } else { Some(start - 1) };
+    let sequence_infos = rooch_store.get_tx_sequence_infos_by_order(cursor, end - start + 1)?;
+    let mut records = vec![];
+    for (mapping, sequence_info) in mappings.into_iter().zip(sequence_infos) {
+        let (Some(mapping), Some(sequence_info)) = (mapping, sequence_info) else {
+            continue;
+        };
+        let tx = rooch_store
+            .get_transaction_by_hash(mapping.tx_hash)?
+            .ok_or_else(|| {
+                RoochError::SnapshotError(format!(
+                    "The transaction {:?} does not exist",
+                    mapping.tx_hash
+                ))
+            })?;
+        let execution_info = moveos_store
+            .get_transaction_store()
+            .get_tx_execution_info(mapping.tx_hash)?
+            .ok_or_else(|| {
+                RoochError::SnapshotError(format!(
+                    "The execution info of transaction {:?} does not exist",
+                    mapping.tx_hash
+                ))
+            })?;
+        records.push(SnapshotRecord::Transaction {
+            tx: Box::new(tx),
+            sequence_info,
+            execution_info,
+        });
+    }
+    Ok(records)
+}
+
+/// Read the indexer db, the data after the tx order is removed from a copy of the db.
+fn export_indexer_db(
+    opt: &RoochOpt,
+    tx_order: u64,
+    last_order: u64,
+) -> RoochResult<Option<Vec<u8>>> {
+    let base_config = BaseConfig::load_with_opt(opt)?;
+    let mut indexer_config = IndexerConfig::default();
+    indexer_config.merge_with_opt_with_init(opt, Arc::new(base_config), false)?;
+    let indexer_db = indexer_config.get_indexer_db();
+    if !indexer_db.exists() {
+        return Ok(None);
+    }
+    if tx_order == last_order {
+        return Ok(Some(std::fs::read(&indexer_db)?));
+    }
+
+    let tmp_dir = temp_dir();
+    let tmp_indexer_db = tmp_dir.path().join(ROOCH_INDEXER_DB_FILENAME);
+    std::fs::copy(&indexer_db, &tmp_indexer_db)?;
+    let tmp_indexer_db_url = tmp_indexer_db
+        .to_str()
+        .ok_or_else(|| RoochError::SnapshotError("Invalid indexer db path".to_owned()))?;
+    IndexerStore::new(tmp_indexer_db_url)?
+        .delete_from_tx_order(tx_order + 1)
+        .map_err(|e| RoochError::SnapshotError(e.to_string()))?;
+    Ok(Some(std::fs::read(&tmp_indexer_db)?))
+}
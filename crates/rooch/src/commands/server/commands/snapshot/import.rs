@@ -0,0 +1,155 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use super::{SnapshotManifest, SnapshotReader, SnapshotRecord};
+use crate::cli_types::CommandAction;
+use crate::commands::server::commands::replay::open_server_storage;
+use async_trait::async_trait;
+use clap::Parser;
+use moveos_store::transaction_store::TransactionStore;
+use moveos_types::startup_info::StartupInfo;
+use raw_store::rocks::batch::WriteBatch;
+use raw_store::traits::DBStore;
+use raw_store::SchemaStore;
+use rooch_config::indexer_config::IndexerConfig;
+use rooch_config::{BaseConfig, RoochOpt};
+use rooch_store::meta_store::MetaStore;
+use rooch_store::transaction_store::TransactionStore as SequencerTransactionStore;
+use rooch_types::error::{RoochError, RoochResult};
+use rooch_types::sequencer::SequencerOrder;
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+use std::sync::Arc;
+
+/// The number of the raw key values written to the store in a batch
+const IMPORT_BATCH_SIZE: usize = 1000;
+
+/// Import a snapshot archive to the empty server storage, the server starts from the state of the snapshot.
+#[derive(Debug, Parser)]
+pub struct ImportCommand {
+    /// The path of the snapshot archive to import
+    #[clap(long, short = 'i')]
+    input: PathBuf,
+
+    #[clap(flatten)]
+    opt: RoochOpt,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImportOutput {
+    pub manifest: SnapshotManifest,
+    /// The number of the raw key values of the MoveOS store
+    pub raw_entries: u64,
+    pub transactions: u64,
+}
+
+#[async_trait]
+impl CommandAction<ImportOutput> for ImportCommand {
+    async fn execute(self) -> RoochResult<ImportOutput> {
+        let (moveos_store, mut rooch_store, _) = open_server_storage(&self.opt)?;
+        if moveos_store
+            .get_config_store()
+            .get_startup_info()?
+            .is_some()
+            || rooch_store.get_sequencer_order()?.is_some()
+        {
+            return Err(RoochError::SnapshotError(
+                "The server storage is not empty, clean it with `rooch server clean` before importing".to_owned(),
+            ));
+        }
+        let base_config = BaseConfig::load_with_opt(&self.opt)?;
+        let mut indexer_config = IndexerConfig::default();
+        indexer_config.merge_with_opt_with_init(&self.opt, Arc::new(base_config), false)?;
+        let indexer_db = indexer_config.get_indexer_db();
+
+        let mut reader = SnapshotReader::open(&self.input)?;
+        let manifest = reader.read_manifest()?;
+
+        let db = moveos_store
+            .moveosdb
+            .node_store
+            .get_store()
+            .store()
+            .db()
+            .ok_or_else(|| RoochError::SnapshotError("Only support db store".to_owned()))?;
+        // The raw key values of a column family are continuous in the archive
+        let mut pending: Option<(String, WriteBatch)> = None;
+        let mut raw_entries = 0;
+        let mut transactions = 0;
+        let mut last_execution_info = None;
+        loop {
+            let record = reader.read()?;
+            if let Some((column_family, batch)) = pending.take() {
+                let same_column_family = matches!(
+                    &record,
+                    SnapshotRecord::MoveOSRaw { column_family: cf, .. } if cf == &column_family
+                );
+                if same_column_family && batch.rows.len() < IMPORT_BATCH_SIZE {
+                    pending = Some((column_family, batch));
+                } else {
+                    db.write_batch(column_family.as_str(), batch)?;
+                }
+            }
+            match record {
+                SnapshotRecord::Manifest(_) => {
+                    return Err(RoochError::SnapshotError(
+                        "Unexpected manifest in the snapshot".to_owned(),
+                    ));
+                }
+                SnapshotRecord::MoveOSRaw {
+                    column_family,
+                    key,
+                    value,
+                } => {
+                    let (_, batch) =
+                        pending.get_or_insert_with(|| (column_family, WriteBatch::new()));
+                    batch.put(key, value)?;
+                    raw_entries += 1;
+                }
+                SnapshotRecord::Transaction {
+                    tx,
+                    sequence_info,
+                    execution_info,
+                } => {
+                    let tx_hash = execution_info.tx_hash;
+                    let tx_order = sequence_info.tx_order;
+                    rooch_store.save_transaction(*tx)?;
+                    rooch_store.save_tx_sequence_info_mapping(tx_order, tx_hash)?;
+                    rooch_store.save_tx_sequence_info_reverse_mapping(tx_hash, tx_order)?;
+                    rooch_store.save_tx_sequence_info(sequence_info)?;
+                    moveos_store
+                        .get_transaction_store()
+                        .save_tx_execution_info(execution_info.clone())?;
+                    last_execution_info = Some(execution_info);
+                    transactions += 1;
+                }
+                SnapshotRecord::IndexerDB(bytes) => {
+                    if let Some(parent) = indexer_db.parent() {
+                        std::fs::create_dir_all(parent)?;
+                    }
+                    std::fs::write(&indexer_db, bytes)?;
+                }
+                SnapshotRecord::End => break,
+            }
+        }
+
+        if let Some(execution_info) = last_execution_info {
+            if execution_info.state_root != manifest.state_root {
+                return Err(RoochError::SnapshotError(format!(
+                    "The state root {:?} of the last transaction does not match the snapshot state root {:?}",
+                    execution_info.state_root, manifest.state_root
+                )));
+            }
+            rooch_store.save_sequencer_order(SequencerOrder::new(manifest.tx_order))?;
+        }
+        moveos_store
+            .get_config_store()
+            .save_startup_info(StartupInfo::new(manifest.state_root))?;
+
+        Ok(ImportOutput {
+            manifest,
+            raw_entries,
+            transactions,
+        })
+    }
+}
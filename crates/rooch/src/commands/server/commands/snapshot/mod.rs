@@ -0,0 +1,156 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::cli_types::CommandAction;
+use async_trait::async_trait;
+use export::ExportCommand;
+use flate2::read::GzDecoder;
+use flate2::write::GzEncoder;
+use flate2::Compression;
+use import::ImportCommand;
+use moveos_types::h256::H256;
+use moveos_types::transaction::TransactionExecutionInfo;
+use rooch_types::error::{RoochError, RoochResult};
+use rooch_types::transaction::{TransactionSequenceInfo, TypedTransaction};
+use serde::{Deserialize, Serialize};
+use std::fs::File;
+use std::io::{BufReader, BufWriter, Read, Write};
+use std::path::Path;
+
+pub mod export;
+pub mod import;
+
+/// The version of the snapshot archive format, bump it when the records are changed.
+pub const SNAPSHOT_VERSION: u32 = 1;
+
+/// Export the server storage at a tx order to a snapshot archive, or import a snapshot archive
+/// to bootstrap a new node without replaying the history.
+/// The server must be stopped, the store can not be opened by two processes.
+#[derive(Debug, clap::Parser)]
+pub struct Snapshot {
+    #[clap(subcommand)]
+    cmd: SnapshotCommand,
+}
+
+#[async_trait]
+impl CommandAction<String> for Snapshot {
+    async fn execute(self) -> RoochResult<String> {
+        match self.cmd {
+            SnapshotCommand::Export(export) => export.execute_serialized().await,
+            SnapshotCommand::Import(import) => import.execute_serialized().await,
+        }
+    }
+}
+
+#[derive(Debug, clap::Subcommand)]
+#[clap(name = "snapshot")]
+pub enum SnapshotCommand {
+    Export(ExportCommand),
+    Import(ImportCommand),
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SnapshotManifest {
+    pub version: u32,
+    /// The tx order of the last transaction in the snapshot
+    pub tx_order: u64,
+    /// The state root after executing the last transaction
+    pub state_root: H256,
+    /// The events are only exported when the snapshot is taken at the latest tx order,
+    /// because the events are not indexed by the tx order in the store.
+    pub include_events: bool,
+    pub include_indexer: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum SnapshotRecord {
+    Manifest(SnapshotManifest),
+    /// A raw key value of a column family of the MoveOS store, such as the state nodes
+    MoveOSRaw {
+        column_family: String,
+        key: Vec<u8>,
+        value: Vec<u8>,
+    },
+    Transaction {
+        tx: Box<TypedTransaction>,
+        sequence_info: TransactionSequenceInfo,
+        execution_info: TransactionExecutionInfo,
+    },
+    /// The indexer SQLite db file, trimmed to the tx order of the snapshot
+    IndexerDB(Vec<u8>),
+    End,
+}
+
+/// The snapshot archive is a gzip stream of the length prefixed BCS encoded records,
+/// starts with the manifest and ends with the `End` record.
+pub struct SnapshotWriter {
+    encoder: GzEncoder<BufWriter<File>>,
+}
+
+impl SnapshotWriter {
+    pub fn create(path: &Path) -> RoochResult<Self> {
+        let file = File::create(path).map_err(|e| {
+            RoochError::SnapshotError(format!("Failed to create {:?}: {}", path, e))
+        })?;
+        Ok(Self {
+            encoder: GzEncoder::new(BufWriter::new(file), Compression::default()),
+        })
+    }
+
+    pub fn write(&mut self, record: &SnapshotRecord) -> RoochResult<()> {
+        let bytes = bcs::to_bytes(record)?;
+        self.encoder
+            .write_all(&(bytes.len() as u64).to_le_bytes())
+            .and_then(|_| self.encoder.write_all(&bytes))
+            .map_err(|e| RoochError::SnapshotError(e.to_string()))
+    }
+
+    pub fn finish(mut self) -> RoochResult<()> {
+        self.write(&SnapshotRecord::End)?;
+        self.encoder
+            .finish()
+            .and_then(|mut writer| writer.flush())
+            .map_err(|e| RoochError::SnapshotError(e.to_string()))
+    }
+}
+
+pub struct SnapshotReader {
+    decoder: GzDecoder<BufReader<File>>,
+}
+
+impl SnapshotReader {
+    pub fn open(path: &Path) -> RoochResult<Self> {
+        let file = File::open(path)
+            .map_err(|e| RoochError::SnapshotError(format!("Failed to open {:?}: {}", path, e)))?;
+        Ok(Self {
+            decoder: GzDecoder::new(BufReader::new(file)),
+        })
+    }
+
+    pub fn read(&mut self) -> RoochResult<SnapshotRecord> {
+        let mut len_bytes = [0u8; 8];
+        self.decoder
+            .read_exact(&mut len_bytes)
+            .map_err(|e| RoochError::SnapshotError(format!("Truncated snapshot: {}", e)))?;
+        let mut bytes = vec![0u8; u64::from_le_bytes(len_bytes) as usize];
+        self.decoder
+            .read_exact(&mut bytes)
+            .map_err(|e| RoochError::SnapshotError(format!("Truncated snapshot: {}", e)))?;
+        Ok(bcs::from_bytes(&bytes)?)
+    }
+
+    pub fn read_manifest(&mut self) -> RoochResult<SnapshotManifest> {
+        match self.read()? {
+            SnapshotRecord::Manifest(manifest) if manifest.version == SNAPSHOT_VERSION => {
+                Ok(manifest)
+            }
+            SnapshotRecord::Manifest(manifest) => Err(RoochError::SnapshotError(format!(
+                "Unsupported snapshot version {}, expect {}",
+                manifest.version, SNAPSHOT_VERSION
+            ))),
+            _ => Err(RoochError::SnapshotError(
+                "The snapshot does not start with the manifest".to_owned(),
+            )),
+        }
+    }
+}
@@ -4,6 +4,7 @@
 use crate::cli_types::{CommandAction, WalletContextOptions};
 use async_trait::async_trait;
 use clap::Parser;
+use rooch_config::server_config::{CorsConfig, LogConfig, LogFormat, TlsConfig, TracingConfig};
 use rooch_config::{RoochOpt, ServerOpt};
 use rooch_key::key_derive::verify_password;
 use rooch_key::keystore::account_keystore::AccountKeystore;
@@ -12,6 +13,7 @@ use rooch_types::address::RoochAddress;
 use rooch_types::chain_id::RoochChainID;
 use rooch_types::error::{RoochError, RoochResult};
 use rpassword::prompt_password;
+use std::path::PathBuf;
 use std::str::FromStr;
 use tokio::signal::ctrl_c;
 #[cfg(unix)]
@@ -24,6 +26,37 @@ pub struct StartCommand {
     #[clap(flatten)]
     opt: RoochOpt,
 
+    /// The PEM certificate chain file to serve the JSON-RPC over HTTPS, overrides the `tls` in the server config file
+    #[clap(long, requires = "tls_key")]
+    tls_cert: Option<PathBuf>,
+
+    /// The PEM private key file of the TLS certificate
+    #[clap(long, requires = "tls_cert")]
+    tls_key: Option<PathBuf>,
+
+    /// The allowed CORS origins, separated by comma, `*` allows any origin.
+    /// Overrides the `cors` in the server config file
+    #[clap(long, value_delimiter = ',')]
+    cors_allow_origins: Option<Vec<String>>,
+
+    /// The allowed CORS methods, separated by comma, default is `POST`
+    #[clap(long, value_delimiter = ',')]
+    cors_allow_methods: Option<Vec<String>>,
+
+    /// The OTLP gRPC endpoint to export the transaction flow spans, such as `http://localhost:4317`.
+    /// Overrides the `tracing` in the server config file
+    #[clap(long, env = "OTEL_EXPORTER_OTLP_ENDPOINT")]
+    otlp_endpoint: Option<String>,
+
+    /// The format of the server logs, `text` or `json`. Overrides the `log.format` in the server config file
+    #[clap(long, value_enum)]
+    log_format: Option<LogFormat>,
+
+    /// The log filter in the `RUST_LOG` syntax, such as `info,rooch::indexer=debug`.
+    /// Overrides the `log.filter` in the server config file
+    #[clap(long, env = "RUST_LOG")]
+    log_filter: Option<String>,
+
     #[clap(flatten)]
     pub context_options: WalletContextOptions,
 }
@@ -134,6 +167,41 @@ impl CommandAction<()> for StartCommand {
         server_opt.sequencer_keypair = Some(sequencer_keypair.copy());
         server_opt.proposer_keypair = Some(proposer_keypair.copy());
         server_opt.relayer_keypair = Some(relayer_keypair.copy());
+        server_opt.rate_limit = context.server_config.rate_limit.clone();
+        server_opt.method_access = context.server_config.method_access.clone();
+        server_opt.query_limits = context.server_config.query_limits.clone();
+        server_opt.tls = match (self.tls_cert.take(), self.tls_key.take()) {
+            (Some(cert_path), Some(key_path)) => Some(TlsConfig {
+                cert_path,
+                key_path,
+            }),
+            _ => context.server_config.tls.clone(),
+        };
+        server_opt.cors = context.server_config.cors.clone();
+        if self.cors_allow_origins.is_some() || self.cors_allow_methods.is_some() {
+            let cors = server_opt.cors.get_or_insert_with(CorsConfig::default);
+            if let Some(allow_origins) = self.cors_allow_origins.take() {
+                cors.allow_origins = allow_origins;
+            }
+            if let Some(allow_methods) = self.cors_allow_methods.take() {
+                cors.allow_methods = allow_methods;
+            }
+        }
+
+        server_opt.tracing = match self.otlp_endpoint.take() {
+            Some(otlp_endpoint) => Some(TracingConfig::new(otlp_endpoint)),
+            None => context.server_config.tracing.clone(),
+        };
+        server_opt.log = context.server_config.log.clone();
+        if self.log_format.is_some() || self.log_filter.is_some() {
+            let log = server_opt.log.get_or_insert_with(LogConfig::default);
+            if let Some(log_format) = self.log_format.take() {
+                log.format = log_format;
+            }
+            if let Some(log_filter) = self.log_filter.take() {
+                log.filter = log_filter;
+            }
+        }
 
         let active_env = context.client_config.get_active_env()?;
         server_opt.active_env = Some(active_env.clone().alias);
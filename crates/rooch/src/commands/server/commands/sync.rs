@@ -0,0 +1,67 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::cli_types::CommandAction;
+use crate::commands::server::commands::replay::open_server_storage;
+use async_trait::async_trait;
+use clap::Parser;
+use moveos_types::genesis_info::GenesisInfo;
+use moveos_types::h256::H256;
+use rooch_config::RoochOpt;
+use rooch_sync::{StateSyncer, SyncPeer, SyncResult};
+use rooch_types::address::RoochAddress;
+use rooch_types::error::{RoochError, RoochResult};
+
+/// Sync the transactions and the states from the peers over QUIC, the peers are the nodes started with `--sync-port`.
+/// The genesis and the sequencer are trusted from the options, the order of every transaction must be signed by the sequencer,
+/// the state root of the target tx order must be agreed by all the peers, and the sync is resumable.
+/// The execution results of the transactions are taken from the peers, they are not re-executed.
+/// The server must be stopped, the store can not be opened by two processes.
+/// The indexer is not synced, rebuild it with `rooch indexer rebuild` after syncing.
+#[derive(Debug, Parser)]
+pub struct SyncCommand {
+    /// The state sync peer as `<certificate fingerprint>@<address>`, such as `0x3a..@127.0.0.1:6770`,
+    /// the peer logs it when the sync server starts, it can be specified multiple times
+    #[clap(long = "peer", required = true)]
+    peers: Vec<SyncPeer>,
+
+    /// The trusted genesis package hash of the chain, printed by `rooch genesis build`
+    #[clap(long)]
+    genesis_package_hash: H256,
+
+    /// The trusted genesis state root of the chain, printed by `rooch genesis build`
+    #[clap(long)]
+    genesis_state_root: H256,
+
+    /// The address of the sequencer, the order of every synced transaction must be signed by it
+    #[clap(long)]
+    sequencer: RoochAddress,
+
+    /// The tx order to sync to, default to the latest tx order served by all the peers
+    #[clap(long)]
+    tx_order: Option<u64>,
+
+    #[clap(flatten)]
+    opt: RoochOpt,
+}
+
+#[async_trait]
+impl CommandAction<SyncResult> for SyncCommand {
+    async fn execute(self) -> RoochResult<SyncResult> {
+        let (moveos_store, rooch_store, _) = open_server_storage(&self.opt)?;
+        let genesis = GenesisInfo::new(self.genesis_package_hash, self.genesis_state_root);
+        let mut syncer = StateSyncer::connect(
+            moveos_store,
+            rooch_store,
+            &self.peers,
+            genesis,
+            self.sequencer,
+        )
+        .await
+        .map_err(|e| RoochError::StateSyncError(e.to_string()))?;
+        syncer
+            .sync(self.tx_order)
+            .await
+            .map_err(|e| RoochError::StateSyncError(e.to_string()))
+    }
+}
@@ -8,6 +8,9 @@ use commands::start::StartCommand;
 use rooch_types::error::RoochResult;
 
 use self::commands::clean::CleanCommand;
+use self::commands::replay::ReplayCommand;
+use self::commands::snapshot::Snapshot;
+use self::commands::sync::SyncCommand;
 
 pub mod commands;
 
@@ -18,12 +21,22 @@ pub struct Server {
     cmd: ServerCommand,
 }
 
+impl Server {
+    /// The start command inits the tracing subscriber with the log config of the server
+    pub fn is_start(&self) -> bool {
+        matches!(self.cmd, ServerCommand::Start(_))
+    }
+}
+
 #[async_trait]
 impl CommandAction<String> for Server {
     async fn execute(self) -> RoochResult<String> {
         match self.cmd {
             ServerCommand::Start(start) => start.execute_serialized().await,
             ServerCommand::Clean(clean) => clean.execute().map(|_| "".to_owned()),
+            ServerCommand::Replay(replay) => replay.execute_serialized().await,
+            ServerCommand::Snapshot(snapshot) => snapshot.execute().await,
+            ServerCommand::Sync(sync) => sync.execute_serialized().await,
         }
     }
 }
@@ -33,4 +46,7 @@ impl CommandAction<String> for Server {
 pub enum ServerCommand {
     Start(StartCommand),
     Clean(CleanCommand),
+    Replay(ReplayCommand),
+    Snapshot(Snapshot),
+    Sync(SyncCommand),
 }
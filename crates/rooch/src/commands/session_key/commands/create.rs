@@ -19,14 +19,20 @@ pub struct CreateCommand {
     /// The scope of the session key, format: address::module_name::function_name.
     /// The module_name and function_name must be valid Move identifiers or '*'. `*` means any module or function.
     /// For example: 0x3::empty::empty
-    #[clap(long)]
-    pub scope: SessionScope,
+    /// The option could be repeated to grant multiple scopes.
+    #[clap(long = "scope", required = true)]
+    pub scopes: Vec<SessionScope>,
 
     /// The max inactive interval of the session key, in seconds.
     /// If the max_inactive_interval is 0, the session key will never expire.
     #[clap(long, default_value = "3600")]
     pub max_inactive_interval: u64,
 
+    /// The max gas the session key can spend in total.
+    /// If the max_gas_budget is 0, the gas of the session key is not limited.
+    #[clap(long, default_value = "0")]
+    pub max_gas_budget: u64,
+
     #[clap(flatten)]
     pub tx_options: TransactionOptions,
 
@@ -37,6 +43,8 @@ pub struct CreateCommand {
 impl CreateCommand {
     pub async fn execute(self) -> RoochResult<SessionKey> {
         let mut context = self.context_options.build()?;
+        context.sequence_number = self.tx_options.sequence_number;
+        context.expiration_secs = self.tx_options.expiration_secs;
 
         let sender: RoochAddress = context.resolve_address(self.tx_options.sender)?.into();
 
@@ -58,14 +66,12 @@ impl CreateCommand {
                 .keystore
                 .generate_session_key(&sender, Some(password))?
         };
-        let session_scope = self.scope;
-
-        let action =
-            rooch_types::framework::session_key::SessionKeyModule::create_session_key_action(
-                session_auth_key.as_ref().to_vec(),
-                session_scope.clone(),
-                self.max_inactive_interval,
-            );
+        let action = SessionKeyModule::create_session_key_with_budget_action(
+            session_auth_key.as_ref().to_vec(),
+            self.scopes,
+            self.max_inactive_interval,
+            self.max_gas_budget,
+        );
 
         println!("Generated new session key {session_auth_key} for address [{sender}]",);
 
@@ -0,0 +1,84 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::cli_types::WalletContextOptions;
+use clap::Parser;
+use move_command_line_common::address::ParsedAddress;
+use moveos_types::module_binding::MoveFunctionCaller;
+use rooch_key::keystore::account_keystore::AccountKeystore;
+use rooch_types::{
+    address::RoochAddress,
+    authentication_key::AuthenticationKey,
+    error::RoochResult,
+    framework::session_key::{SessionKey, SessionKeyModule},
+};
+use serde::{Deserialize, Serialize};
+
+/// List the local session keys of an account, with their on-chain scopes and expiry
+#[derive(Debug, Parser)]
+pub struct ListCommand {
+    /// The account address that owns the session keys.
+    #[clap(long, value_parser=ParsedAddress::parse, default_value = "default")]
+    pub address: ParsedAddress,
+
+    #[clap(flatten)]
+    pub context_options: WalletContextOptions,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct SessionKeyView {
+    pub authentication_key: String,
+    /// The on-chain SessionKey, `None` if the session key is not bound or has been removed on-chain
+    pub session_key: Option<SessionKey>,
+    /// The unix timestamp in seconds after which the session key expires if it stays inactive,
+    /// `None` if the session key never expires
+    pub expiration_time: Option<u64>,
+    pub expired: bool,
+    /// The gas the session key can still spend, `None` if the gas of the session key is not limited
+    pub remaining_gas_budget: Option<u64>,
+}
+
+impl SessionKeyView {
+    fn new(authentication_key: AuthenticationKey, session_key: Option<SessionKey>) -> Self {
+        let expiration_time = session_key.as_ref().and_then(|session_key| {
+            if session_key.max_inactive_interval == 0 {
+                None
+            } else {
+                Some(session_key.last_active_time + session_key.max_inactive_interval)
+            }
+        });
+        let expired = session_key
+            .as_ref()
+            .map(|session_key| session_key.is_expired())
+            .unwrap_or(false);
+        let remaining_gas_budget = session_key
+            .as_ref()
+            .and_then(|session_key| session_key.remaining_gas_budget());
+        Self {
+            authentication_key: authentication_key.to_string(),
+            session_key,
+            expiration_time,
+            expired,
+            remaining_gas_budget,
+        }
+    }
+}
+
+impl ListCommand {
+    pub async fn execute(self) -> RoochResult<Vec<SessionKeyView>> {
+        let context = self.context_options.build()?;
+        let address: RoochAddress = context.resolve_address(self.address)?.into();
+        let local_session_keys = context.keystore.get_session_keys(&address)?;
+
+        let client = context.get_client().await?;
+        let session_key_module = client.as_module_binding::<SessionKeyModule>();
+        let mut views = Vec::with_capacity(local_session_keys.len());
+        for (authentication_key, _) in local_session_keys {
+            // The last active time is updated on-chain, so always read the latest SessionKey
+            let session_key =
+                session_key_module.get_session_key(address.into(), &authentication_key)?;
+            views.push(SessionKeyView::new(authentication_key, session_key));
+        }
+        Ok(views)
+    }
+}
@@ -2,3 +2,5 @@
 // SPDX-License-Identifier: Apache-2.0
 
 pub mod create;
+pub mod list;
+pub mod revoke;
@@ -0,0 +1,66 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::cli_types::{TransactionOptions, WalletContextOptions};
+use clap::Parser;
+use rooch_key::key_derive::verify_password;
+use rooch_key::keystore::account_keystore::AccountKeystore;
+use rooch_rpc_api::jsonrpc_types::ExecuteTransactionResponseView;
+use rooch_types::{
+    address::RoochAddress,
+    authentication_key::AuthenticationKey,
+    error::{RoochError, RoochResult},
+    framework::session_key::SessionKeyModule,
+};
+use rpassword::prompt_password;
+
+/// Revoke a session key on-chain and remove it from the local keystore
+#[derive(Debug, Parser)]
+pub struct RevokeCommand {
+    /// The authentication key of the session key to revoke
+    #[clap(long)]
+    pub authentication_key: AuthenticationKey,
+
+    #[clap(flatten)]
+    pub tx_options: TransactionOptions,
+
+    #[clap(flatten)]
+    pub context_options: WalletContextOptions,
+}
+
+impl RevokeCommand {
+    pub async fn execute(self) -> RoochResult<ExecuteTransactionResponseView> {
+        let mut context = self.context_options.build()?;
+        context.sequence_number = self.tx_options.sequence_number;
+        context.expiration_secs = self.tx_options.expiration_secs;
+
+        let sender: RoochAddress = context.resolve_address(self.tx_options.sender)?.into();
+
+        let action =
+            SessionKeyModule::remove_session_key_action(self.authentication_key.as_ref().to_vec());
+
+        let result = if context.keystore.get_if_password_is_empty() {
+            context.sign_and_execute(sender, action, None).await?
+        } else {
+            let password = prompt_password("Enter the password to revoke the session key:")
+                .unwrap_or_default();
+            let is_verified =
+                verify_password(Some(password.clone()), context.keystore.get_password_hash())?;
+
+            if !is_verified {
+                return Err(RoochError::InvalidPasswordError(
+                    "Password is invalid".to_owned(),
+                ));
+            }
+
+            context
+                .sign_and_execute(sender, action, Some(password))
+                .await?
+        };
+        let result = context.assert_execute_success(result)?;
+        context
+            .keystore
+            .remove_session_key(&sender, &self.authentication_key)?;
+        Ok(result)
+    }
+}
@@ -4,7 +4,7 @@
 use crate::cli_types::CommandAction;
 use async_trait::async_trait;
 use clap::Parser;
-use commands::create::CreateCommand;
+use commands::{create::CreateCommand, list::ListCommand, revoke::RevokeCommand};
 use rooch_types::error::RoochResult;
 
 pub mod commands;
@@ -23,6 +23,12 @@ impl CommandAction<String> for SessionKey {
             SessionKeyCommand::Create(create) => create.execute().await.map(|resp| {
                 serde_json::to_string_pretty(&resp).expect("Failed to serialize response")
             }),
+            SessionKeyCommand::List(list) => list.execute().await.map(|resp| {
+                serde_json::to_string_pretty(&resp).expect("Failed to serialize response")
+            }),
+            SessionKeyCommand::Revoke(revoke) => revoke.execute().await.map(|resp| {
+                serde_json::to_string_pretty(&resp).expect("Failed to serialize response")
+            }),
         }
     }
 }
@@ -31,4 +37,6 @@ impl CommandAction<String> for SessionKey {
 #[clap(name = "session_key")]
 pub enum SessionKeyCommand {
     Create(CreateCommand),
+    List(ListCommand),
+    Revoke(RevokeCommand),
 }
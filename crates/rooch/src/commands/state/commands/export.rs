@@ -0,0 +1,194 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::cli_types::{CommandAction, WalletContextOptions};
+use async_trait::async_trait;
+use clap::{Parser, ValueEnum};
+use moveos_types::access_path::AccessPath;
+use rooch_rpc_api::jsonrpc_types::{AnnotatedMoveValueView, StateKVView};
+use rooch_types::error::{RoochError, RoochResult};
+use rooch_types::function_arg::ParsedObjectID;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::fs::{File, OpenOptions};
+use std::io::{BufWriter, Write};
+use std::path::{Path, PathBuf};
+
+const CSV_HEADER: &str = "key,key_type,decoded_key,value,value_type,decoded_value";
+
+/// The formats of the exported table entries
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum ExportFormat {
+    /// A row of the raw and decoded key and value per entry
+    Csv,
+    /// A JSON object of the raw and decoded key and value per line
+    Jsonl,
+}
+
+/// Export all the key/value entries of a table to a file, the keys and values are decoded.
+///
+/// The cursor of the last exported page is saved to `<output>.cursor` after the page is written,
+/// an interrupted export continues from it with `--resume`, and the entries are appended to the output.
+#[derive(Parser)]
+pub struct ExportCommand {
+    /// The handle of the table to export
+    #[clap(long = "table-handle")]
+    pub table_handle: ParsedObjectID,
+
+    /// The format of the output file
+    #[clap(long, value_enum, default_value = "jsonl")]
+    pub format: ExportFormat,
+
+    /// The file to write the entries to, it is overwritten unless `--resume` is set
+    #[clap(long, short = 'o')]
+    pub output: PathBuf,
+
+    /// Continue the export from the cursor saved by the previous export to the same output
+    #[clap(long)]
+    pub resume: bool,
+
+    /// The number of the entries fetched per request
+    #[clap(long, default_value = "100")]
+    pub page_size: usize,
+
+    #[clap(flatten)]
+    pub(crate) context_options: WalletContextOptions,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ExportView {
+    pub table_handle: String,
+    pub output: PathBuf,
+    /// The number of the entries exported by this run
+    pub entries: u64,
+    /// The cursor after the last exported entry
+    pub cursor: Option<String>,
+}
+
+#[async_trait]
+impl CommandAction<String> for ExportCommand {
+    async fn execute(self) -> RoochResult<String> {
+        let context = self.context_options.build()?;
+        let table_handle = self
+            .table_handle
+            .into_object_id(&context.address_mapping())?;
+        let client = context.get_client().await?;
+
+        let cursor_path = cursor_path(&self.output);
+        let mut cursor = if self.resume && cursor_path.exists() {
+            Some(std::fs::read_to_string(&cursor_path)?.trim().to_owned())
+                .filter(|cursor| !cursor.is_empty())
+        } else {
+            None
+        };
+        let file = if self.resume {
+            OpenOptions::new()
+                .create(true)
+                .append(true)
+                .open(&self.output)?
+        } else {
+            File::create(&self.output)?
+        };
+        let write_header = matches!(self.format, ExportFormat::Csv) && file.metadata()?.len() == 0;
+        let mut writer = BufWriter::new(file);
+        if write_header {
+            writeln!(writer, "{}", CSV_HEADER)?;
+        }
+
+        let access_path = AccessPath::table_without_keys(table_handle);
+        let mut entries = 0u64;
+        loop {
+            let page = client
+                .rooch
+                .list_decoded_states(
+                    access_path.clone().into(),
+                    cursor.clone(),
+                    Some(self.page_size),
+                )
+                .await
+                .map_err(RoochError::from)?;
+            for state_kv in page.data.iter() {
+                match self.format {
+                    ExportFormat::Csv => writeln!(writer, "{}", to_csv_row(state_kv))?,
+                    ExportFormat::Jsonl => writeln!(writer, "{}", to_json(state_kv))?,
+                }
+            }
+            entries += page.data.len() as u64;
+            // The entries are flushed before the cursor is saved, so the resumed export does not skip entries
+            writer.flush()?;
+            if page.next_cursor.is_some() {
+                cursor = page.next_cursor;
+                if let Some(cursor) = &cursor {
+                    std::fs::write(&cursor_path, cursor)?;
+                }
+            }
+            if !page.has_next_page || page.data.is_empty() {
+                break;
+            }
+        }
+
+        let view = ExportView {
+            table_handle: table_handle.to_string(),
+            output: self.output,
+            entries,
+            cursor,
+        };
+        Ok(serde_json::to_string_pretty(&view).unwrap())
+    }
+}
+
+fn cursor_path(output: &Path) -> PathBuf {
+    let mut file_name = output.file_name().unwrap_or_default().to_os_string();
+    file_name.push(".cursor");
+    output.with_file_name(file_name)
+}
+
+fn decoded_json(decoded: &Option<AnnotatedMoveValueView>) -> Value {
+    decoded
+        .as_ref()
+        .map(|decoded| serde_json::to_value(decoded).unwrap_or(Value::Null))
+        .unwrap_or(Value::Null)
+}
+
+fn to_json(state_kv: &StateKVView) -> Value {
+    serde_json::json!({
+        "key": state_kv.key_state.key.to_string(),
+        "key_type": state_kv.key_state.key_type.to_string(),
+        "decoded_key": decoded_json(&state_kv.key_state.decoded_key),
+        "value": state_kv.state.value.to_string(),
+        "value_type": state_kv.state.value_type.to_string(),
+        "decoded_value": decoded_json(&state_kv.state.decoded_value),
+    })
+}
+
+fn to_csv_row(state_kv: &StateKVView) -> String {
+    [
+        state_kv.key_state.key.to_string(),
+        state_kv.key_state.key_type.to_string(),
+        csv_value(decoded_json(&state_kv.key_state.decoded_key)),
+        state_kv.state.value.to_string(),
+        state_kv.state.value_type.to_string(),
+        csv_value(decoded_json(&state_kv.state.decoded_value)),
+    ]
+    .iter()
+    .map(|field| csv_escape(field))
+    .collect::<Vec<_>>()
+    .join(",")
+}
+
+/// The strings are written without the JSON quotes, the other values are written as JSON
+fn csv_value(value: Value) -> String {
+    match value {
+        Value::String(s) => s,
+        Value::Null => String::new(),
+        value => value.to_string(),
+    }
+}
+
+fn csv_escape(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_owned()
+    }
+}
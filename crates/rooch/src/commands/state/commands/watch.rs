@@ -0,0 +1,249 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::cli_types::{CommandAction, WalletContextOptions};
+use async_trait::async_trait;
+use clap::Parser;
+use futures::StreamExt;
+use moveos_types::access_path::{AccessPath, Path};
+use rooch_rpc_api::jsonrpc_types::{
+    IndexerObjectStateChangeView, ObjectStateFilterView, OpView, StateView,
+};
+use rooch_rpc_client::Client;
+use rooch_types::error::{RoochError, RoochResult};
+use rooch_types::function_arg::ParsedObjectID;
+use serde_json::Value;
+use std::time::Duration;
+use tokio::signal::ctrl_c;
+
+/// Watch the changes of the states and print the decoded diffs as they occur.
+///
+/// The changes of the objects are pushed by the WebSocket subscription if the `ws` of the active env is configured,
+/// the other access paths, or the env without `ws`, fallback to polling the states.
+#[derive(Parser)]
+pub struct WatchCommand {
+    /// The access path of the states to watch, such as /object/$object_id or /resource/$account_address/$resource_type
+    #[clap(
+        long = "access-path",
+        short = 'a',
+        conflicts_with = "object-id",
+        required_unless_present = "object-id"
+    )]
+    pub access_path: Option<AccessPath>,
+
+    /// The object to watch, the same as `--access-path /object/$object_id`
+    #[clap(long = "object-id", id = "object-id")]
+    pub object_id: Option<ParsedObjectID>,
+
+    /// The interval in milliseconds of polling the states
+    #[clap(long, default_value = "1000")]
+    pub interval: u64,
+
+    /// Poll the states even if the WebSocket is configured
+    #[clap(long)]
+    pub poll: bool,
+
+    #[clap(flatten)]
+    pub(crate) context_options: WalletContextOptions,
+}
+
+#[async_trait]
+impl CommandAction<String> for WatchCommand {
+    async fn execute(self) -> RoochResult<String> {
+        let context = self.context_options.build()?;
+        let access_path = match (self.access_path, self.object_id) {
+            (Some(access_path), _) => access_path,
+            (None, Some(object_id)) => {
+                AccessPath::object(object_id.into_object_id(&context.address_mapping())?)
+            }
+            (None, None) => {
+                return Err(RoochError::CommandArgumentError(
+                    "The --access-path or --object-id is required".to_owned(),
+                ))
+            }
+        };
+        let client = context.get_client().await?;
+        let mut watcher = StateWatcher::new(client.clone(), access_path.clone()).await?;
+
+        let subscribe_object_ids = match &access_path.0 {
+            Path::Object { object_ids } if client.has_ws() && !self.poll => {
+                Some(object_ids.clone())
+            }
+            _ => None,
+        };
+        match subscribe_object_ids {
+            Some(object_ids) => {
+                println!("Watching {} over the WebSocket", access_path);
+                let mut subscriptions = vec![];
+                for object_id in object_ids {
+                    subscriptions.push(
+                        client
+                            .subscribe_object(ObjectStateFilterView::ObjectId(object_id))
+                            .await?,
+                    );
+                }
+                let mut changes = futures::stream::select_all(subscriptions);
+                loop {
+                    tokio::select! {
+                        change = changes.next() => match change {
+                            Some(change) => {
+                                let change = change
+                                    .map_err(|e| RoochError::UnexpectedError(e.to_string()))?;
+                                print_object_change(&change);
+                                watcher.refresh().await?;
+                            }
+                            None => break,
+                        },
+                        _ = ctrl_c() => break,
+                    }
+                }
+            }
+            None => {
+                println!(
+                    "Watching {} by polling every {}ms",
+                    access_path, self.interval
+                );
+                let interval = Duration::from_millis(self.interval);
+                loop {
+                    tokio::select! {
+                        _ = tokio::time::sleep(interval) => watcher.refresh().await?,
+                        _ = ctrl_c() => break,
+                    }
+                }
+            }
+        }
+        Ok(format!(
+            "Watched {} changes of {}",
+            watcher.changes, access_path
+        ))
+    }
+}
+
+/// Keep the latest decoded states of the access path, and print the diffs when they are refreshed
+struct StateWatcher {
+    client: Client,
+    access_path: AccessPath,
+    states: Vec<Option<StateView>>,
+    changes: u64,
+}
+
+impl StateWatcher {
+    async fn new(client: Client, access_path: AccessPath) -> RoochResult<Self> {
+        let states = client
+            .rooch
+            .get_decoded_states(access_path.clone())
+            .await
+            .map_err(RoochError::from)?;
+        Ok(Self {
+            client,
+            access_path,
+            states,
+            changes: 0,
+        })
+    }
+
+    async fn refresh(&mut self) -> RoochResult<()> {
+        let states = self
+            .client
+            .rooch
+            .get_decoded_states(self.access_path.clone())
+            .await
+            .map_err(RoochError::from)?;
+        for (i, (old, new)) in self.states.iter().zip(states.iter()).enumerate() {
+            let label = state_label(&self.access_path, i);
+            match (old, new) {
+                (None, None) => {}
+                (None, Some(new)) => {
+                    println!("{} created: {}", label, decoded_value(new));
+                    self.changes += 1;
+                }
+                (Some(_), None) => {
+                    println!("{} deleted", label);
+                    self.changes += 1;
+                }
+                (Some(old), Some(new)) => {
+                    let mut diffs = vec![];
+                    diff_json("", &decoded_value(old), &decoded_value(new), &mut diffs);
+                    if !diffs.is_empty() {
+                        println!("{} modified:", label);
+                        for diff in diffs {
+                            println!("  {}", diff);
+                        }
+                        self.changes += 1;
+                    }
+                }
+            }
+        }
+        self.states = states;
+        Ok(())
+    }
+}
+
+fn print_object_change(change: &IndexerObjectStateChangeView) {
+    let op = match &change.change {
+        OpView::New(_) => "new",
+        OpView::Modify(_) => "modify",
+        OpView::Delete => "delete",
+    };
+    println!(
+        "tx_order: {}, tx_hash: {}, object: {}, op: {}",
+        change.tx_order, change.tx_hash, change.object_id, op
+    );
+}
+
+fn state_label(access_path: &AccessPath, index: usize) -> String {
+    match &access_path.0 {
+        Path::Object { object_ids } => object_ids.get(index).map(|object_id| object_id.to_string()),
+        Path::Resource {
+            resource_types: Some(resource_types),
+            ..
+        } => resource_types.get(index).map(|t| t.to_string()),
+        Path::Module {
+            module_names: Some(module_names),
+            ..
+        } => module_names.get(index).map(|name| name.to_string()),
+        _ => None,
+    }
+    .unwrap_or_else(|| format!("#{}", index))
+}
+
+fn decoded_value(state: &StateView) -> Value {
+    match &state.decoded_value {
+        Some(decoded_value) => serde_json::to_value(decoded_value).unwrap_or(Value::Null),
+        None => Value::String(state.value.to_string()),
+    }
+}
+
+/// Collect the changed fields between the two json values as `path: old -> new`
+fn diff_json(path: &str, old: &Value, new: &Value, diffs: &mut Vec<String>) {
+    match (old, new) {
+        (Value::Object(old_fields), Value::Object(new_fields)) => {
+            let mut keys = old_fields
+                .keys()
+                .chain(new_fields.keys())
+                .collect::<Vec<_>>();
+            keys.sort();
+            keys.dedup();
+            for key in keys {
+                diff_json(
+                    &format!("{}.{}", path, key),
+                    old_fields.get(key).unwrap_or(&Value::Null),
+                    new_fields.get(key).unwrap_or(&Value::Null),
+                    diffs,
+                );
+            }
+        }
+        (Value::Array(old_items), Value::Array(new_items))
+            if old_items.len() == new_items.len() =>
+        {
+            for (i, (old_item, new_item)) in old_items.iter().zip(new_items.iter()).enumerate() {
+                diff_json(&format!("{}[{}]", path, i), old_item, new_item, diffs);
+            }
+        }
+        _ if old != new => {
+            let path = if path.is_empty() { "." } else { path };
+            diffs.push(format!("{}: {} -> {}", path, old, new));
+        }
+        _ => {}
+    }
+}
@@ -0,0 +1,61 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::cli_types::{CommandAction, WalletContextOptions};
+use crate::commands::state::commands::export::ExportCommand;
+use crate::commands::state::commands::watch::WatchCommand;
+use async_trait::async_trait;
+use clap::{Parser, Subcommand};
+use moveos_types::access_path::AccessPath;
+use rooch_types::error::{RoochError, RoochResult};
+
+pub mod commands;
+
+/// Get states by accessPath, watch the changes of the states, or export the entries of a table
+#[derive(Parser)]
+#[clap(args_conflicts_with_subcommands = true)]
+pub struct StateCommand {
+    #[clap(subcommand)]
+    cmd: Option<StateSubCommand>,
+
+    //TODO access path should support named address?
+    /// /object/$object_id1[,$object_id2]
+    /// /resource/$account_address/$resource_type1[,$resource_type2]
+    /// /module/$account_address/$module_name1[,$module_name2]
+    /// /table/$table_handle/$key1[,$key2]
+    #[clap(long = "access-path", short = 'a')]
+    pub access_path: Option<AccessPath>,
+
+    /// RPC client options.
+    #[clap(flatten)]
+    context_options: WalletContextOptions,
+}
+
+#[async_trait]
+impl CommandAction<String> for StateCommand {
+    async fn execute(self) -> RoochResult<String> {
+        if let Some(cmd) = self.cmd {
+            return match cmd {
+                StateSubCommand::Watch(cmd) => cmd.execute().await,
+                StateSubCommand::Export(cmd) => cmd.execute().await,
+            };
+        }
+        let access_path = self.access_path.ok_or_else(|| {
+            RoochError::CommandArgumentError("The --access-path is required".to_owned())
+        })?;
+        let client = self.context_options.build()?.get_client().await?;
+
+        let resp = client
+            .rooch
+            .get_decoded_states(access_path)
+            .await
+            .map_err(RoochError::from)?;
+        Ok(serde_json::to_string_pretty(&resp).unwrap())
+    }
+}
+
+#[derive(Subcommand)]
+pub enum StateSubCommand {
+    Watch(WatchCommand),
+    Export(ExportCommand),
+}
@@ -0,0 +1,95 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::cli_types::{CommandAction, WalletContextOptions};
+use async_trait::async_trait;
+use clap::Parser;
+use move_command_line_common::address::ParsedAddress;
+use moveos_types::gas_config::GasConfig;
+use moveos_types::transaction::{FunctionCall, MoveAction};
+use rooch_key::key_derive::verify_password;
+use rooch_key::keystore::account_keystore::AccountKeystore;
+use rooch_rpc_api::jsonrpc_types::ExecuteTransactionResponseView;
+use rooch_types::address::RoochAddress;
+use rooch_types::error::{RoochError, RoochResult};
+use rooch_types::framework::empty::Empty;
+use rpassword::prompt_password;
+
+/// Cancel an in-flight transaction by replacing it with an empty transaction of the same sequence number.
+///
+/// The replacement bumps the max gas amount of the in-flight transaction, it only takes effect if the
+/// in-flight transaction is not executed yet, otherwise the replacement is rejected for the used sequence number.
+#[derive(Debug, Parser)]
+pub struct CancelCommand {
+    /// Sender account address.
+    #[clap(long, alias = "sender-account", value_parser=ParsedAddress::parse, default_value = "default")]
+    sender: ParsedAddress,
+
+    /// The sequence number of the transaction to cancel, default is the on-chain sequence number of the sender,
+    /// that is the first transaction not executed yet.
+    #[clap(long)]
+    sequence_number: Option<u64>,
+
+    /// The percentage to bump the max gas amount of the in-flight transaction
+    #[clap(long, default_value = "20")]
+    gas_bump_percent: u64,
+
+    #[clap(flatten)]
+    pub(crate) context_options: WalletContextOptions,
+}
+
+#[async_trait]
+impl CommandAction<ExecuteTransactionResponseView> for CancelCommand {
+    async fn execute(self) -> RoochResult<ExecuteTransactionResponseView> {
+        let mut context = self.context_options.build()?;
+        let sender: RoochAddress = context.resolve_address(self.sender)?.into();
+        let client = context.get_client().await?;
+        let onchain_sequence_number = client
+            .rooch
+            .get_sequence_number(sender)
+            .await
+            .map_err(RoochError::from)?;
+        let sequence_number = self.sequence_number.unwrap_or(onchain_sequence_number);
+        if sequence_number < onchain_sequence_number {
+            return Err(RoochError::CommandArgumentError(format!(
+                "The transaction of sequence number {} is already executed, the on-chain sequence number of [{}] is {}",
+                sequence_number, sender, onchain_sequence_number
+            )));
+        }
+
+        let action = MoveAction::Function(FunctionCall::new(
+            Empty::empty_function_id(),
+            vec![],
+            vec![],
+        ));
+        context.sequence_number = Some(sequence_number);
+        let mut tx_data = context.build_tx_data(sender, action).await?;
+        // Bump the max gas amount of the in-flight transaction, or the estimate if it is not tracked locally
+        let in_flight_max_gas_amount = context
+            .nonce_manager()
+            .get(&sender, sequence_number)
+            .map(|tx| tx.max_gas_amount)
+            .unwrap_or(tx_data.max_gas_amount);
+        tx_data.max_gas_amount =
+            (in_flight_max_gas_amount.saturating_mul(100 + self.gas_bump_percent) / 100)
+                .min(GasConfig::DEFAULT_MAX_GAS_AMOUNT);
+
+        let password = if context.keystore.get_if_password_is_empty() {
+            None
+        } else {
+            let password = prompt_password("Enter the password to cancel the transaction:")
+                .unwrap_or_default();
+            let is_verified =
+                verify_password(Some(password.clone()), context.keystore.get_password_hash())?;
+            if !is_verified {
+                return Err(RoochError::InvalidPasswordError(
+                    "Password is invalid".to_owned(),
+                ));
+            }
+            Some(password)
+        };
+        let tx = context.sign_tx_data(tx_data, password)?;
+        let result = context.execute(tx).await?;
+        context.assert_execute_success(result)
+    }
+}
@@ -0,0 +1,44 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::cli_types::CommandAction;
+use async_trait::async_trait;
+use clap::Parser;
+use rooch_rpc_api::jsonrpc_types::{BytesView, H256View, StrView, TransactionView};
+use rooch_types::error::{RoochError, RoochResult};
+use rooch_types::transaction::rooch::RoochTransaction;
+use rooch_types::transaction::{AbstractTransaction, TypedTransaction};
+use serde::{Deserialize, Serialize};
+
+/// Decode the BCS bytes of a Rooch transaction locally, such as the `raw` of the transaction view
+/// or the payload of `rooch_sendRawTransaction`, no RPC request is sent.
+#[derive(Debug, Parser)]
+pub struct DecodeCommand {
+    /// The hex encoded BCS bytes of the transaction, with or without the `0x` prefix
+    #[clap(long)]
+    pub raw: BytesView,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DecodedTransactionView {
+    pub tx_hash: H256View,
+    pub chain_id: StrView<u64>,
+    pub max_gas_amount: StrView<u64>,
+    pub auth_validator_id: StrView<u64>,
+    pub transaction: TransactionView,
+}
+
+#[async_trait]
+impl CommandAction<DecodedTransactionView> for DecodeCommand {
+    async fn execute(self) -> RoochResult<DecodedTransactionView> {
+        let tx = RoochTransaction::decode(&self.raw.0)
+            .map_err(|e| RoochError::BcsError(format!("Invalid transaction bytes: {}", e)))?;
+        Ok(DecodedTransactionView {
+            tx_hash: tx.tx_hash().into(),
+            chain_id: tx.chain_id().into(),
+            max_gas_amount: tx.max_gas_amount().into(),
+            auth_validator_id: tx.auth_validator_id().into(),
+            transaction: TypedTransaction::Rooch(tx).into(),
+        })
+    }
+}
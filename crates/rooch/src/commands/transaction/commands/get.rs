@@ -0,0 +1,54 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::cli_types::{CommandAction, WalletContextOptions};
+use async_trait::async_trait;
+use clap::Parser;
+use moveos_types::h256::H256;
+use rooch_rpc_api::jsonrpc_types::transaction_view::TransactionWithInfoView;
+use rooch_types::error::{RoochError, RoochResult};
+
+/// Get a transaction with its sequence info and execution info by hash or by tx order
+#[derive(Debug, Parser)]
+#[clap(group(clap::ArgGroup::new("tx").required(true).args(&["hash", "order"])))]
+pub struct GetCommand {
+    /// The transaction hash
+    #[clap(long)]
+    pub hash: Option<H256>,
+
+    /// The transaction order
+    #[clap(long)]
+    pub order: Option<u64>,
+
+    #[clap(flatten)]
+    pub(crate) context_options: WalletContextOptions,
+}
+
+#[async_trait]
+impl CommandAction<TransactionWithInfoView> for GetCommand {
+    async fn execute(self) -> RoochResult<TransactionWithInfoView> {
+        let client = self.context_options.build()?.get_client().await?;
+
+        let tx = match (self.hash, self.order) {
+            (Some(hash), _) => client
+                .rooch
+                .get_transactions_by_hash(vec![hash], None)
+                .await?
+                .pop()
+                .flatten(),
+            (None, Some(order)) => {
+                // The cursor is exclusive, the tx order 0 is the first page without cursor
+                let cursor = order.checked_sub(1);
+                client
+                    .rooch
+                    .get_transactions_by_order(cursor, Some(1), None)
+                    .await?
+                    .data
+                    .pop()
+                    .filter(|tx| tx.sequence_info.tx_order.0 == order)
+            }
+            (None, None) => None,
+        };
+        tx.ok_or_else(|| RoochError::TransactionError("Transaction not found".to_owned()))
+    }
+}
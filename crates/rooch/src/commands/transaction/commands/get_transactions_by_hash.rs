@@ -5,6 +5,7 @@ use crate::cli_types::{CommandAction, WalletContextOptions};
 use async_trait::async_trait;
 use moveos_types::h256::H256;
 use rooch_rpc_api::jsonrpc_types::transaction_view::TransactionWithInfoView;
+use rooch_rpc_api::jsonrpc_types::QueryOptions;
 use rooch_types::error::RoochResult;
 
 /// Get transactions by hashes
@@ -14,6 +15,18 @@ pub struct GetTransactionsByHashCommand {
     #[clap(long, value_delimiter = ',')]
     pub hashes: Vec<H256>,
 
+    /// Omit the decoded actions of the transactions
+    #[clap(long)]
+    pub no_decode: bool,
+
+    /// Omit the raw BCS bytes of the transactions
+    #[clap(long)]
+    pub no_raw: bool,
+
+    /// Return the events emitted by the transactions
+    #[clap(long)]
+    pub show_events: bool,
+
     #[clap(flatten)]
     pub(crate) context_options: WalletContextOptions,
 }
@@ -23,7 +36,14 @@ impl CommandAction<Vec<Option<TransactionWithInfoView>>> for GetTransactionsByHa
     async fn execute(self) -> RoochResult<Vec<Option<TransactionWithInfoView>>> {
         let client = self.context_options.build()?.get_client().await?;
 
-        let resp = client.rooch.get_transactions_by_hash(self.hashes).await?;
+        let query_options = QueryOptions::default()
+            .decode(!self.no_decode)
+            .show_raw(!self.no_raw)
+            .show_events(self.show_events);
+        let resp = client
+            .rooch
+            .get_transactions_by_hash(self.hashes, Some(query_options))
+            .await?;
 
         Ok(resp)
     }
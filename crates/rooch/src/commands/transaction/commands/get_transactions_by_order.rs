@@ -3,7 +3,7 @@
 
 use crate::cli_types::{CommandAction, WalletContextOptions};
 use async_trait::async_trait;
-use rooch_rpc_api::jsonrpc_types::TransactionWithInfoPageView;
+use rooch_rpc_api::jsonrpc_types::{QueryOptions, TransactionWithInfoPageView};
 use rooch_types::error::RoochResult;
 
 /// Get transactions by order
@@ -16,6 +16,18 @@ pub struct GetTransactionsByOrderCommand {
     #[clap(long)]
     pub limit: Option<u64>,
 
+    /// Omit the decoded actions of the transactions
+    #[clap(long)]
+    pub no_decode: bool,
+
+    /// Omit the raw BCS bytes of the transactions
+    #[clap(long)]
+    pub no_raw: bool,
+
+    /// Return the events emitted by the transactions
+    #[clap(long)]
+    pub show_events: bool,
+
     #[clap(flatten)]
     pub(crate) context_options: WalletContextOptions,
 }
@@ -25,9 +37,13 @@ impl CommandAction<TransactionWithInfoPageView> for GetTransactionsByOrderComman
     async fn execute(self) -> RoochResult<TransactionWithInfoPageView> {
         let client = self.context_options.build()?.get_client().await?;
 
+        let query_options = QueryOptions::default()
+            .decode(!self.no_decode)
+            .show_raw(!self.no_raw)
+            .show_events(self.show_events);
         let resp = client
             .rooch
-            .get_transactions_by_order(self.cursor, self.limit)
+            .get_transactions_by_order(self.cursor, self.limit, Some(query_options))
             .await?;
 
         Ok(resp)
@@ -0,0 +1,54 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::cli_types::{CommandAction, WalletContextOptions};
+use async_trait::async_trait;
+use clap::Parser;
+use move_command_line_common::address::ParsedAddress;
+use rooch_rpc_api::jsonrpc_types::transaction_view::TransactionFilterView;
+use rooch_rpc_api::jsonrpc_types::TransactionWithInfoPageView;
+use rooch_types::error::RoochResult;
+
+/// List the transactions sent by the account, the transactions are queried from the indexer
+#[derive(Debug, Parser)]
+pub struct ListCommand {
+    /// The sender address of the transactions, if absent, use the default active account.
+    #[clap(long, value_parser=ParsedAddress::parse, default_value = "default")]
+    pub sender: ParsedAddress,
+
+    /// The exclusive tx order cursor, start from the beginning if absent
+    #[clap(long)]
+    pub cursor: Option<u64>,
+
+    #[clap(long)]
+    pub limit: Option<usize>,
+
+    /// List the latest transactions first
+    #[clap(long)]
+    pub descending_order: bool,
+
+    #[clap(flatten)]
+    pub(crate) context_options: WalletContextOptions,
+}
+
+#[async_trait]
+impl CommandAction<TransactionWithInfoPageView> for ListCommand {
+    async fn execute(self) -> RoochResult<TransactionWithInfoPageView> {
+        let context = self.context_options.build()?;
+        let mapping = context.address_mapping();
+        let sender = self.sender.into_account_address(&mapping)?;
+        let client = context.get_client().await?;
+
+        let resp = client
+            .rooch
+            .query_transactions(
+                TransactionFilterView::Sender(sender.into()),
+                self.cursor,
+                self.limit,
+                Some(self.descending_order),
+            )
+            .await?;
+
+        Ok(resp)
+    }
+}
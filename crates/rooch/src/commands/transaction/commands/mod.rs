@@ -1,5 +1,10 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
 
+pub mod cancel;
+pub mod decode;
+pub mod get;
 pub mod get_transactions_by_hash;
 pub mod get_transactions_by_order;
+pub mod list;
+pub mod wait;
@@ -0,0 +1,56 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::cli_types::{CommandAction, WalletContextOptions};
+use async_trait::async_trait;
+use clap::Parser;
+use moveos_types::h256::H256;
+use rooch_rpc_api::jsonrpc_types::transaction_view::TransactionWithInfoView;
+use rooch_types::error::{RoochError, RoochResult};
+use std::time::{Duration, Instant};
+
+/// Wait for the transaction to be executed, and return it with the execution info
+#[derive(Debug, Parser)]
+pub struct WaitCommand {
+    /// The transaction hash
+    #[clap(long)]
+    pub hash: H256,
+
+    /// The max seconds to wait for the transaction
+    #[clap(long, default_value = "60")]
+    pub timeout: u64,
+
+    /// The interval milliseconds of polling the transaction
+    #[clap(long, default_value = "500")]
+    pub interval: u64,
+
+    #[clap(flatten)]
+    pub(crate) context_options: WalletContextOptions,
+}
+
+#[async_trait]
+impl CommandAction<TransactionWithInfoView> for WaitCommand {
+    async fn execute(self) -> RoochResult<TransactionWithInfoView> {
+        let client = self.context_options.build()?.get_client().await?;
+
+        let deadline = Instant::now() + Duration::from_secs(self.timeout);
+        loop {
+            if let Some(tx) = client
+                .rooch
+                .get_transactions_by_hash(vec![self.hash], None)
+                .await?
+                .pop()
+                .flatten()
+            {
+                return Ok(tx);
+            }
+            if Instant::now() >= deadline {
+                return Err(RoochError::TransactionError(format!(
+                    "Wait for transaction {:?} timeout after {} seconds",
+                    self.hash, self.timeout
+                )));
+            }
+            tokio::time::sleep(Duration::from_millis(self.interval)).await;
+        }
+    }
+}
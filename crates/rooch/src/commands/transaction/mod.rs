@@ -3,8 +3,9 @@
 
 use crate::cli_types::CommandAction;
 use crate::commands::transaction::commands::{
+    cancel::CancelCommand, decode::DecodeCommand, get::GetCommand,
     get_transactions_by_hash::GetTransactionsByHashCommand,
-    get_transactions_by_order::GetTransactionsByOrderCommand,
+    get_transactions_by_order::GetTransactionsByOrderCommand, list::ListCommand, wait::WaitCommand,
 };
 use async_trait::async_trait;
 use clap::{Parser, Subcommand};
@@ -23,14 +24,24 @@ pub struct Transaction {
 impl CommandAction<String> for Transaction {
     async fn execute(self) -> RoochResult<String> {
         match self.cmd {
+            TransactionCommand::Get(cmd) => cmd.execute_serialized().await,
+            TransactionCommand::List(cmd) => cmd.execute_serialized().await,
+            TransactionCommand::Wait(cmd) => cmd.execute_serialized().await,
+            TransactionCommand::Decode(cmd) => cmd.execute_serialized().await,
             TransactionCommand::GetTransactionsByOrder(cmd) => cmd.execute_serialized().await,
             TransactionCommand::GetTransactionsByHash(cmd) => cmd.execute_serialized().await,
+            TransactionCommand::Cancel(cmd) => cmd.execute_serialized().await,
         }
     }
 }
 
 #[derive(Subcommand)]
 pub enum TransactionCommand {
+    Get(GetCommand),
+    List(ListCommand),
+    Wait(WaitCommand),
+    Decode(DecodeCommand),
     GetTransactionsByOrder(GetTransactionsByOrderCommand),
     GetTransactionsByHash(GetTransactionsByHashCommand),
+    Cancel(CancelCommand),
 }
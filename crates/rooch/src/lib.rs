@@ -4,7 +4,8 @@
 use crate::commands::event::EventCommand;
 use cli_types::CommandAction;
 use commands::{
-    abi::ABI, account::Account, env::Env, init::Init, move_cli::MoveCli, object::ObjectCommand,
+    abi::ABI, account::Account, completion::CompletionCommand, console::ConsoleCommand, env::Env,
+    genesis::Genesis, indexer::Indexer, init::Init, move_cli::MoveCli, object::Object,
     resource::ResourceCommand, rpc::Rpc, server::Server, session_key::SessionKey,
     state::StateCommand, transaction::Transaction,
 };
@@ -28,8 +29,9 @@ pub enum Command {
     Init(Init),
     Move(MoveCli),
     Server(Server),
+    Indexer(Indexer),
     State(StateCommand),
-    Object(ObjectCommand),
+    Object(Object),
     Resource(ResourceCommand),
     Transaction(Transaction),
     Event(EventCommand),
@@ -37,6 +39,9 @@ pub enum Command {
     Env(Env),
     SessionKey(SessionKey),
     Rpc(Rpc),
+    Genesis(Genesis),
+    Completion(CompletionCommand),
+    Console(ConsoleCommand),
 }
 
 pub async fn run_cli(opt: RoochCli) -> RoochResult<String> {
@@ -44,9 +49,10 @@ pub async fn run_cli(opt: RoochCli) -> RoochResult<String> {
         Command::Account(account) => account.execute().await,
         Command::Move(move_cli) => move_cli.execute().await,
         Command::Server(server) => server.execute().await,
+        Command::Indexer(indexer) => indexer.execute().await,
         Command::Init(init) => init.execute_serialized().await,
-        Command::State(state) => state.execute_serialized().await,
-        Command::Object(object) => object.execute_serialized().await,
+        Command::State(state) => state.execute().await,
+        Command::Object(object) => object.execute().await,
         Command::Resource(resource) => resource.execute_serialized().await,
         Command::Transaction(transation) => transation.execute().await,
         Command::Event(event) => event.execute().await,
@@ -54,5 +60,8 @@ pub async fn run_cli(opt: RoochCli) -> RoochResult<String> {
         Command::Env(env) => env.execute().await,
         Command::SessionKey(session_key) => session_key.execute().await,
         Command::Rpc(rpc) => rpc.execute().await,
+        Command::Genesis(genesis) => genesis.execute().await,
+        Command::Completion(completion) => completion.execute().await,
+        Command::Console(console) => console.execute().await,
     }
 }
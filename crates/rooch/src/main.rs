@@ -2,15 +2,17 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use clap::Parser;
-use rooch::RoochCli;
+use rooch::{Command, RoochCli};
 use std::process::exit;
 
 /// rooch is a command line tools for Rooch Network
 #[tokio::main]
 async fn main() {
-    let _ = tracing_subscriber::fmt::try_init();
-
     let opt = RoochCli::parse();
+    if !matches!(&opt.cmd, Command::Server(server) if server.is_start()) {
+        let _ = tracing_subscriber::fmt::try_init();
+    }
+
     let result = rooch::run_cli(opt).await;
 
     match result {
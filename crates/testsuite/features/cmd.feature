@@ -37,6 +37,10 @@ Feature: Rooch CLI integration tests
 
       Then cmd: "account create"
       Then cmd: "account list"
+      Then cmd: "account show"
+      Then assert: "'{{$.account[-1].address}}' == '{{$.address_mapping.default}}'"
+      Then cmd: "account show {{$.account[-1].bech32_address}}"
+      Then assert: "'{{$.account[-1].address}}' == '{{$.address_mapping.default}}'"
       #Then cmd: "account nullify --address 0xebf29d2aed4da3d2e13a32d71266a302fbfd5ceb3ff1f465c006fa207f1789ce"
 
       Then cmd: "rpc request --method rooch_getBalance --params '["{{$.address_mapping.default}}", "0x3::gas_coin::GasCoin"]'"
@@ -51,10 +55,16 @@ Feature: Rooch CLI integration tests
       Then cmd: "session-key create  --scope 0x3::empty::empty"
       Then cmd: "move run --function 0x3::empty::empty  --session-key {{$.session-key[-1].authentication_key}}"
       Then assert: "{{$.move[-1].execution_info.status.type}} == executed"
+      Then cmd: "session-key list"
+      Then assert: "'{{$.session-key[-1][0].expired}}' == 'false'"
+      Then cmd: "session-key revoke --authentication-key {{$.session-key[-2].authentication_key}}"
+      Then assert: "{{$.session-key[-1].execution_info.status.type}} == executed"
 
       # transaction
       Then cmd: "transaction get-transactions-by-order --cursor 0 --limit 1"
       Then cmd: "transaction get-transactions-by-hash --hashes {{$.transaction[-1].data[0].execution_info.tx_hash}}"
+      Then cmd: "transaction get-transactions-by-hash --hashes {{$.transaction[-1][0].execution_info.tx_hash}},0x0000000000000000000000000000000000000000000000000000000000000000 --show-events"
+      Then assert: "'{{$.transaction[-1][0].execution_info.tx_hash}}' == '{{$.transaction[-2][0].execution_info.tx_hash}}'"
 
       # account balance
       Then cmd: "account balance"
@@ -62,13 +72,23 @@ Feature: Rooch CLI integration tests
       Then cmd: "rpc request --method rooch_getBalance --params '["{{$.address_mapping.default}}", "0x3::gas_coin::GasCoin"]'"
       Then assert: "'{{$.rpc[-1].balance}}' != '0'"
 
+      # account transfer
+      Then cmd: "account transfer --to 0x42 --amount 100 --wait --json"
+      Then assert: "{{$.account[-1].execution_info.status.type}} == executed"
+
+      # environment profile
+      Then cmd: "env add --alias local-profile --rpc http://127.0.0.1:50051 --max-gas-amount 100000000"
+      Then cmd: "move run --function 0x3::empty::empty --profile local-profile"
+      Then assert: "{{$.move[-1].execution_info.status.type}} == executed"
+      Then cmd: "env remove --env local-profile"
+
       Then stop the server
 
     @serial
     Scenario: state
       Given a server for state
-      Then cmd: "object --id 0x3" 
-      Then cmd: "object --id 0x3::timestamp::Timestamp"
+      Then cmd: "object get 0x3" 
+      Then cmd: "object get 0x3::timestamp::Timestamp"
       Then cmd: "state --access-path /object/0x3::timestamp::Timestamp"
       Then assert: "{{$.state[-1][0].value_type}} == '0x2::object::ObjectEntity<0x3::timestamp::Timestamp>'"
       Then cmd: "state --access-path /object/0x3::chain_id::ChainID"
@@ -128,6 +148,9 @@ Feature: Rooch CLI integration tests
     Then assert: "{{$.rpc[-1].data[0].indexer_event_id.tx_order}} == 1"
     Then assert: "{{$.rpc[-1].next_cursor.tx_order}} == 0"
     Then assert: "{{$.rpc[-1].has_next_page}} == false"
+    Then cmd: "rpc request --method rooch_queryEvents --params '[{"tx_event_index_range":{"tx_hash":"{{$.rpc[-1].data[0].tx_hash}}", "from_index":0, "to_index":1}}, null, "10", false]'"
+    Then assert: "{{$.rpc[-1].data[0].indexer_event_id.event_index}} == 0"
+    Then assert: "{{$.rpc[-1].has_next_page}} == false"
 
     # Sync states
     Then cmd: "rpc request --method rooch_queryGlobalStates --params '[{"object_type":"0x3::coin::CoinInfo"}, null, "10", true]'"
@@ -159,6 +182,8 @@ Feature: Rooch CLI integration tests
       Then cmd: "state --access-path /resource/{{$.address_mapping.default}}/{{$.address_mapping.default}}::kv_store::KVStore
       Then cmd: "state --access-path /table/{{$.state[-1][0].decoded_value.value.table.value.handle}}/key1"
       Then assert: "{{$.state[-1][0].decoded_value}} == "value1""
+      Then cmd: "state export --table-handle {{$.state[-2][0].decoded_value.value.table.value.handle}} --format jsonl --output /tmp/kv_store_table.jsonl"
+      Then assert: "{{$.state[-1].entries}} == 1"
 
 
       Then stop the server
@@ -3,15 +3,13 @@
 
 module bitcoin_move::genesis{
     use std::option;
-    use std::option::Option;
     use moveos_std::context;
     use moveos_std::context::Context;
     use moveos_std::signer;
     use bitcoin_move::light_client;
     use bitcoin_move::ord;
     use bitcoin_move::brc20;
-
-    friend bitcoin_move::network;
+    use bitcoin_move::network;
 
      /// BitcoinGenesisContext is a genesis init config in the TxContext.
     struct BitcoinGenesisContext has copy,store,drop{
@@ -21,19 +19,20 @@ module bitcoin_move::genesis{
     fun init(ctx: &mut Context){
         //let genesis_account = &account::create_account(ctx, @bitcoin_move);
         let genesis_account = signer::module_signer<BitcoinGenesisContext>();
+        let bitcoin_network = bitcoin_network(ctx);
+        network::genesis_init(ctx, bitcoin_network);
         brc20::genesis_init(ctx, &genesis_account);
         ord::genesis_init(ctx, &genesis_account);
         light_client::genesis_init(ctx, &genesis_account);
         
     }
 
-    public(friend) fun network(ctx: &Context) : Option<u8> {
+    fun bitcoin_network(ctx: &Context) : u8 {
         let genesis_context_option = context::get<BitcoinGenesisContext>(ctx);
         if(option::is_some(&genesis_context_option)){
-            let network = option::borrow(&genesis_context_option).network;
-            option::some(network)
+            option::borrow(&genesis_context_option).network
         } else {
-            option::none<u8>()
+            network::default_network()
         }
     }
 }
\ No newline at end of file
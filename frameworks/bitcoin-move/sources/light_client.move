@@ -19,12 +19,18 @@ module bitcoin_move::light_client{
     use bitcoin_move::types::{Self, Block, Header, Transaction, OutPoint};    
     use bitcoin_move::ord::{Self, Inscription, bind_multichain_address};
     use bitcoin_move::utxo::{Self, UTXOSeal};
+    use bitcoin_move::pow;
+    use bitcoin_move::network;
     
 
     friend bitcoin_move::genesis;
 
     const ErrorBlockNotFound:u64 = 1;
     const ErrorBlockAlreadyProcessed:u64 = 2;
+    const ErrorInvalidBlockHash:u64 = 3;
+    const ErrorInvalidProofOfWork:u64 = 4;
+    const ErrorInvalidPrevBlockHash:u64 = 5;
+    const ErrorInvalidBits:u64 = 6;
 
     struct TxProgressErrorLogEvent has copy, drop{
         txid: address,
@@ -73,14 +79,14 @@ module bitcoin_move::light_client{
         object::to_shared(obj);
     }
 
-    fun process_block(btc_block_store_obj: &mut Object<BitcoinBlockStore>, block_height: u64, block_hash: address, block_bytes: vector<u8>):u32{
+    fun process_block(btc_block_store_obj: &mut Object<BitcoinBlockStore>, network: u8, block_height: u64, block_hash: address, block_bytes: vector<u8>):u32{
         
         let btc_block_store = object::borrow_mut(btc_block_store_obj);
         //already processed
         assert!(!table::contains(&btc_block_store.hash_to_height, block_hash), ErrorBlockAlreadyProcessed);
 
         let block = bcs::from_bytes<Block>(block_bytes);
-        validate_block(btc_block_store, block_height, block_hash, &block);
+        validate_block(btc_block_store, network, block_height, block_hash, &block);
         process_txs(btc_block_store, &block); 
         let block_header = types::header(&block);
 
@@ -95,14 +101,44 @@ module bitcoin_move::light_client{
         time 
     }
 
-    fun validate_block(_btc_block_store: &BitcoinBlockStore, _block_height: u64, _block_hash: address, _block: &Block){
-        //TODO validate the block via bitcoin consensus
-        // validate prev block hash
-        // validate block hash
-        // validate block nonce
+    fun validate_block(btc_block_store: &BitcoinBlockStore, network: u8, block_height: u64, block_hash: address, block: &Block){
+        let block_header = types::header(block);
+        assert!(pow::header_hash(block_header) == block_hash, ErrorInvalidBlockHash);
+        // The pow limit is checked on every block, the first block and the blocks without the prev block can not have trivial bits
+        assert!(pow::check_pow(block_header, pow::pow_limit_bits(network)), ErrorInvalidProofOfWork);
+        // The relayer may start from any height, so the prev block hash and the bits are validated only if the prev block has been processed
+        if(block_height > 0 && table::contains(&btc_block_store.height_to_hash, block_height - 1)){
+            let prev_block_hash = *table::borrow(&btc_block_store.height_to_hash, block_height - 1);
+            assert!(types::prev_blockhash(block_header) == prev_block_hash, ErrorInvalidPrevBlockHash);
+            let prev_block_header = table::borrow(&btc_block_store.blocks, prev_block_hash);
+            validate_bits(btc_block_store, network, block_height, block_header, prev_block_header);
+        };
         //TODO validate txid
     }
 
+    /// Validate the bits of the block follow the difficulty adjustment of the network.
+    /// The bits are the same as the prev block except at the retarget heights, and the regtest never retargets.
+    /// The testnet allows the min difficulty blocks, so its bits are only checked by the proof of work.
+    fun validate_bits(btc_block_store: &BitcoinBlockStore, network: u8, block_height: u64, block_header: &Header, prev_block_header: &Header){
+        if(network == network::network_testnet()){
+            return
+        };
+        let bits = types::bits(block_header);
+        let prev_bits = types::bits(prev_block_header);
+        if(network == network::network_regtest() || !pow::is_retarget_height(block_height)){
+            assert!(bits == prev_bits, ErrorInvalidBits);
+            return
+        };
+        // The first block of the period is not processed if the relayer starts in the middle of the period
+        let first_block_height = block_height - pow::difficulty_adjustment_interval();
+        if(table::contains(&btc_block_store.height_to_hash, first_block_height)){
+            let first_block_hash = *table::borrow(&btc_block_store.height_to_hash, first_block_height);
+            let first_block_header = table::borrow(&btc_block_store.blocks, first_block_hash);
+            let next_bits = pow::next_bits(prev_bits, types::time(first_block_header), types::time(prev_block_header), pow::pow_limit_bits(network));
+            assert!(bits == next_bits, ErrorInvalidBits);
+        };
+    }
+
     fun process_txs(btc_block_store: &mut BitcoinBlockStore, block:&Block){
         let txdata = types::txdata(block);
         let idx = 0;
@@ -203,7 +239,8 @@ module bitcoin_move::light_client{
 
     /// The relay server submit a new Bitcoin block to the light client.
     entry fun submit_new_block(ctx: &mut Context, btc_block_store_obj: &mut Object<BitcoinBlockStore>, block_height: u64, block_hash: address, block_bytes: vector<u8>){
-        let time = process_block(btc_block_store_obj, block_height, block_hash, block_bytes);
+        let network = network::network(ctx);
+        let time = process_block(btc_block_store_obj, network, block_height, block_hash, block_bytes);
 
         let timestamp_seconds = (time as u64);
         let module_signer = signer::module_signer<BitcoinBlockStore>();
@@ -307,5 +344,73 @@ module bitcoin_move::light_client{
             option::none()
         }
     }
-    
+
+    #[test_only]
+    fun submit_header_for_test(ctx: &mut Context, network: u8, block_height: u64, block_hash: address, header_bytes: vector<u8>){
+        // The block without transactions
+        vector::push_back(&mut header_bytes, 0);
+        let btc_block_store_obj = context::borrow_mut_object_shared<BitcoinBlockStore>(ctx, moveos_std::object_id::named_object_id<BitcoinBlockStore>());
+        process_block(btc_block_store_obj, network, block_height, block_hash, header_bytes);
+    }
+
+    // The headers are mined with the regtest pow limit 0x207fffff, except the retarget header mined with the signet pow limit 0x1e0377ae
+
+    #[test_only]
+    fun submit_first_header_for_test(ctx: &mut Context, network: u8, block_height: u64){
+        submit_header_for_test(ctx, network, block_height, @0xa399809d860592ceeb42badcc606a60a7652351b7ed1bb92292e2fd8a9cad75a, x"000000200000000000000000000000000000000000000000000000000000000000000000111111111111111111111111111111111111111111111111111111111111111100f15365ffff7f2000000000");
+    }
+
+    #[test(genesis_account=@0x4)]
+    fun test_validate_bits(genesis_account: &signer){
+        let ctx = context::new_test_context(@bitcoin_move);
+        genesis_init(&mut ctx, genesis_account);
+        submit_first_header_for_test(&mut ctx, network::network_bitcoin(), 100);
+        submit_header_for_test(&mut ctx, network::network_bitcoin(), 101, @0xd663a9e2540143d865a3a61b68cce83ed7217701a7b1d4e5dcf6858297f93378, x"00000020a399809d860592ceeb42badcc606a60a7652351b7ed1bb92292e2fd8a9cad75a111111111111111111111111111111111111111111111111111111111111111158f35365ffff7f2000000000");
+        context::drop_test_context(ctx);
+    }
+
+    #[test(genesis_account=@0x4)]
+    #[expected_failure(abort_code = ErrorInvalidBits, location = Self)]
+    fun test_validate_bits_changed_off_retarget_height(genesis_account: &signer){
+        let ctx = context::new_test_context(@bitcoin_move);
+        genesis_init(&mut ctx, genesis_account);
+        submit_first_header_for_test(&mut ctx, network::network_bitcoin(), 100);
+        submit_header_for_test(&mut ctx, network::network_bitcoin(), 101, @0x3f251c43a76c99c4cb2f27257bfb99e86a6ee8a6d8524345eb0e0a32a818b248, x"00000020a399809d860592ceeb42badcc606a60a7652351b7ed1bb92292e2fd8a9cad75a111111111111111111111111111111111111111111111111111111111111111158f35365feff7f2000000000");
+        context::drop_test_context(ctx);
+    }
+
+    #[test_only]
+    fun submit_retarget_period_for_test(ctx: &mut Context, network: u8){
+        submit_first_header_for_test(ctx, network, 2016);
+        submit_header_for_test(ctx, network, 4031, @0x4b8d1989b49b39f47a732e54da5ed95939d30ea68813e70fa1ede9e771880a71, x"0000002022222222222222222222222222222222222222222222222222222222222222221111111111111111111111111111111111111111111111111111111111111111b0f55365ffff7f2003000000");
+    }
+
+    #[test(genesis_account=@0x4)]
+    fun test_validate_bits_retarget(genesis_account: &signer){
+        let ctx = context::new_test_context(@bitcoin_move);
+        genesis_init(&mut ctx, genesis_account);
+        submit_retarget_period_for_test(&mut ctx, network::network_signet());
+        // The period is shorter than a quarter of the target timespan, the target is capped by the signet pow limit
+        submit_header_for_test(&mut ctx, network::network_signet(), 4032, @0x5986894b8242470a44a6bee1db6a6456675aa236d901069393f6979e2a010000, x"000000204b8d1989b49b39f47a732e54da5ed95939d30ea68813e70fa1ede9e771880a71111111111111111111111111111111111111111111111111111111111111111108f85365ae77031ef8694100");
+        context::drop_test_context(ctx);
+    }
+
+    #[test(genesis_account=@0x4)]
+    #[expected_failure(abort_code = ErrorInvalidBits, location = Self)]
+    fun test_validate_bits_not_retargeted(genesis_account: &signer){
+        let ctx = context::new_test_context(@bitcoin_move);
+        genesis_init(&mut ctx, genesis_account);
+        submit_retarget_period_for_test(&mut ctx, network::network_signet());
+        submit_header_for_test(&mut ctx, network::network_signet(), 4032, @0xd46cb0964408beeff7c8a7d36453452e37f6f253f99c7cee744d2a70e136b232, x"000000204b8d1989b49b39f47a732e54da5ed95939d30ea68813e70fa1ede9e771880a71111111111111111111111111111111111111111111111111111111111111111108f85365ffff7f2000000000");
+        context::drop_test_context(ctx);
+    }
+
+    #[test(genesis_account=@0x4)]
+    fun test_validate_bits_regtest_no_retarget(genesis_account: &signer){
+        let ctx = context::new_test_context(@bitcoin_move);
+        genesis_init(&mut ctx, genesis_account);
+        submit_retarget_period_for_test(&mut ctx, network::network_regtest());
+        submit_header_for_test(&mut ctx, network::network_regtest(), 4032, @0xd46cb0964408beeff7c8a7d36453452e37f6f253f99c7cee744d2a70e136b232, x"000000204b8d1989b49b39f47a732e54da5ed95939d30ea68813e70fa1ede9e771880a71111111111111111111111111111111111111111111111111111111111111111108f85365ffff7f2000000000");
+        context::drop_test_context(ctx);
+    }
 }
\ No newline at end of file
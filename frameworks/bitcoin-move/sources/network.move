@@ -2,13 +2,20 @@
 // SPDX-License-Identifier: Apache-2.0
 
 module bitcoin_move::network{
-    use std::option;
     use std::string::{Self, String};
-    use bitcoin_move::genesis;
-    use moveos_std::context::Context;
+    use moveos_std::context::{Self, Context};
+    use moveos_std::object;
+    use moveos_std::object_id;
+
+    friend bitcoin_move::genesis;
 
     const ErrorUnknownNetwork: u64 = 1;
 
+    /// The Bitcoin network of the chain, it is initialized by the genesis with the network of the BitcoinGenesisContext.
+    struct BitcoinNetwork has key{
+        network: u8,
+    }
+
     ///Currently, Move does not support enum types, so we use constants to represent the network type.    
     /// Mainnet Bitcoin.
     const NETWORK_BITCOIN: u8 = 1;
@@ -42,10 +49,16 @@ module bitcoin_move::network{
         Self::network_bitcoin()
     }
 
+    public(friend) fun genesis_init(ctx: &mut Context, network: u8){
+        let obj = context::new_named_object(ctx, BitcoinNetwork{network});
+        object::to_shared(obj);
+    }
+
     public fun network(ctx: &Context) : u8 {
-        let network_opt = genesis::network(ctx);
-        if(option::is_some(&network_opt)){
-            *option::borrow(&network_opt)
+        let object_id = object_id::named_object_id<BitcoinNetwork>();
+        if(context::exists_object<BitcoinNetwork>(ctx, object_id)){
+            let obj = context::borrow_object<BitcoinNetwork>(ctx, object_id);
+            object::borrow(obj).network
         } else {
             Self::default_network()
         }
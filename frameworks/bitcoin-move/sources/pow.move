@@ -0,0 +1,148 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+/// Verify the proof of work of the Bitcoin block headers via natives,
+/// so the light client can check the relayed headers without hashing and 256-bit arithmetic in Move.
+module bitcoin_move::pow{
+    use moveos_std::bcs;
+    use bitcoin_move::types::{Self, Header};
+    use bitcoin_move::network;
+
+    /// The header bytes can not be decoded as a Bitcoin block header.
+    const ErrorInvalidHeader: u64 = 1;
+    /// The compact bits is negative, overflows or encodes a zero target.
+    const ErrorInvalidBits: u64 = 2;
+
+    /// The number of blocks of a difficulty adjustment period.
+    const DIFFICULTY_ADJUSTMENT_INTERVAL: u64 = 2016;
+    /// The compact bits of the max target of the Bitcoin mainnet.
+    const MAINNET_POW_LIMIT_BITS: u32 = 0x1d00ffff;
+    /// The compact bits of the max target of the Bitcoin signet.
+    const SIGNET_POW_LIMIT_BITS: u32 = 0x1e0377ae;
+    /// The compact bits of the max target of the Bitcoin regtest.
+    const REGTEST_POW_LIMIT_BITS: u32 = 0x207fffff;
+
+    public fun difficulty_adjustment_interval(): u64 {
+        DIFFICULTY_ADJUSTMENT_INTERVAL
+    }
+
+    public fun mainnet_pow_limit_bits(): u32 {
+        MAINNET_POW_LIMIT_BITS
+    }
+
+    /// The compact bits of the max target of the network, the testnet has the same max target as the mainnet.
+    public fun pow_limit_bits(network: u8): u32 {
+        if (network == network::network_signet()) {
+            SIGNET_POW_LIMIT_BITS
+        } else if (network == network::network_regtest()) {
+            REGTEST_POW_LIMIT_BITS
+        } else {
+            MAINNET_POW_LIMIT_BITS
+        }
+    }
+
+    /// The block hash of the header, the double SHA256 of the consensus encoded header.
+    /// The BCS bytes of the `Header` are the same as the consensus encoding.
+    public fun header_hash(header: &Header): address {
+        header_hash_bytes(&bcs::to_bytes(header))
+    }
+
+    /// Check the target of the header bits does not exceed the pow limit of the network,
+    /// and the block hash of the header meets the target.
+    public fun check_pow(header: &Header, pow_limit_bits: u32): bool {
+        check_pow_bytes(&bcs::to_bytes(header), pow_limit_bits)
+    }
+
+    /// The expected number of hashes to mine a block with the bits.
+    public fun work(bits: u32): u256 {
+        bits_to_work(bits)
+    }
+
+    /// Accumulate the work of the header to the chainwork of its previous block.
+    public fun chainwork(prev_chainwork: u256, header: &Header): u256 {
+        prev_chainwork + bits_to_work(types::bits(header))
+    }
+
+    /// The bits of the block at the height must be recalculated, otherwise it is the same as the previous block on mainnet.
+    public fun is_retarget_height(block_height: u64): bool {
+        block_height % DIFFICULTY_ADJUSTMENT_INTERVAL == 0
+    }
+
+    /// Calculate the bits of the next difficulty adjustment period.
+    /// `first_block_time` is the time of the first block of the current period,
+    /// `last_block_time` is the time of the last block of the current period, and `prev_bits` is its bits.
+    public fun next_bits(prev_bits: u32, first_block_time: u32, last_block_time: u32, pow_limit_bits: u32): u32 {
+        calculate_next_bits(prev_bits, first_block_time, last_block_time, pow_limit_bits)
+    }
+
+    native fun header_hash_bytes(header_bytes: &vector<u8>): address;
+
+    native fun check_pow_bytes(header_bytes: &vector<u8>, pow_limit_bits: u32): bool;
+
+    native fun bits_to_work(bits: u32): u256;
+
+    native fun calculate_next_bits(prev_bits: u32, first_block_time: u32, last_block_time: u32, pow_limit_bits: u32): u32;
+
+    #[test_only]
+    fun genesis_header(): Header {
+        bcs::from_bytes<Header>(x"0100000000000000000000000000000000000000000000000000000000000000000000003ba3edfd7a7b12b27ac72c3e67768f617fc81bc3888a51323a9fb8aa4b1e5e4a29ab5f49ffff001d1dac2b7c")
+    }
+
+    #[test]
+    fun test_header_hash() {
+        let header = genesis_header();
+        // The genesis block hash 000000000019d6689c085ae165831e934ff763ae46a2a6c172b3f1b60a8ce26f in the internal byte order
+        assert!(header_hash(&header) == @0x6fe28c0ab6f1b372c1a6a246ae63f74f931e8365e15a089c68d6190000000000, 1000);
+        assert!(check_pow(&header, MAINNET_POW_LIMIT_BITS), 1001);
+    }
+
+    #[test]
+    fun test_check_pow_failed() {
+        // The genesis header with a different nonce
+        let header = bcs::from_bytes<Header>(x"0100000000000000000000000000000000000000000000000000000000000000000000003ba3edfd7a7b12b27ac72c3e67768f617fc81bc3888a51323a9fb8aa4b1e5e4a29ab5f49ffff001d1dac2b7d");
+        assert!(!check_pow(&header, MAINNET_POW_LIMIT_BITS), 1000);
+    }
+
+    #[test]
+    fun test_check_pow_exceeds_pow_limit() {
+        // The regtest genesis header, its block hash meets the target of the regtest bits 0x207fffff
+        let header = bcs::from_bytes<Header>(x"0100000000000000000000000000000000000000000000000000000000000000000000003ba3edfd7a7b12b27ac72c3e67768f617fc81bc3888a51323a9fb8aa4b1e5e4adae5494dffff7f2002000000");
+        assert!(check_pow(&header, REGTEST_POW_LIMIT_BITS), 1000);
+        // The bits exceed the pow limit of the mainnet
+        assert!(!check_pow(&header, MAINNET_POW_LIMIT_BITS), 1001);
+    }
+
+    #[test]
+    fun test_work() {
+        assert!(work(MAINNET_POW_LIMIT_BITS) == 0x100010001, 1000);
+        let header = genesis_header();
+        assert!(chainwork(0x100010001, &header) == 0x200020002, 1001);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = ErrorInvalidBits, location = Self)]
+    fun test_work_invalid_bits() {
+        // The negative bits
+        work(0x01800001);
+    }
+
+    #[test]
+    fun test_pow_limit_bits() {
+        assert!(pow_limit_bits(network::network_bitcoin()) == MAINNET_POW_LIMIT_BITS, 1000);
+        assert!(pow_limit_bits(network::network_testnet()) == MAINNET_POW_LIMIT_BITS, 1001);
+        assert!(pow_limit_bits(network::network_signet()) == SIGNET_POW_LIMIT_BITS, 1002);
+        assert!(pow_limit_bits(network::network_regtest()) == REGTEST_POW_LIMIT_BITS, 1003);
+    }
+
+    #[test]
+    fun test_next_bits() {
+        assert!(is_retarget_height(32256), 1000);
+        assert!(!is_retarget_height(32255), 1001);
+        // The retarget of the mainnet block 32256
+        assert!(next_bits(0x1d00ffff, 1261130161, 1262152739, MAINNET_POW_LIMIT_BITS) == 0x1d00d86a, 1002);
+        // The new target exceeds the pow limit
+        assert!(next_bits(0x1d00ffff, 1231006505, 1233061996, MAINNET_POW_LIMIT_BITS) == 0x1d00ffff, 1003);
+        // The actual timespan is less than a quarter of the target timespan
+        assert!(next_bits(0x1c05a3f4, 1279008237, 1279297671, MAINNET_POW_LIMIT_BITS) == 0x1c0168fd, 1004);
+    }
+}
@@ -0,0 +1,204 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+/// Parse and classify the Bitcoin scripts via natives,
+/// so the contracts inspecting the relayed Bitcoin transactions do not need to parse the scripts in Move.
+module bitcoin_move::script{
+    use std::vector;
+    use std::option::{Self, Option};
+    use bitcoin_move::script_buf::{Self, ScriptBuf};
+    use bitcoin_move::opcode;
+
+    /// The script is invalid, such as a push opcode without enough data.
+    const ErrorInvalidScript: u64 = 1;
+
+    // The script types, keep the same as the constants in the natives
+    const SCRIPT_TYPE_NON_STANDARD: u8 = 0;
+    const SCRIPT_TYPE_P2PK: u8 = 1;
+    const SCRIPT_TYPE_P2PKH: u8 = 2;
+    const SCRIPT_TYPE_P2SH: u8 = 3;
+    const SCRIPT_TYPE_P2WPKH: u8 = 4;
+    const SCRIPT_TYPE_P2WSH: u8 = 5;
+    const SCRIPT_TYPE_P2TR: u8 = 6;
+    const SCRIPT_TYPE_WITNESS_UNKNOWN: u8 = 7;
+    const SCRIPT_TYPE_MULTISIG: u8 = 8;
+    const SCRIPT_TYPE_OP_RETURN: u8 = 9;
+
+    #[data_struct]
+    struct Instruction has store, copy, drop {
+        /// The opcode of the instruction, it is the push opcode for the push instructions.
+        opcode: u8,
+        /// The pushed data, empty for the non-push instructions.
+        data: vector<u8>,
+    }
+
+    public fun script_type_non_standard(): u8 {
+        SCRIPT_TYPE_NON_STANDARD
+    }
+
+    public fun script_type_p2pk(): u8 {
+        SCRIPT_TYPE_P2PK
+    }
+
+    public fun script_type_p2pkh(): u8 {
+        SCRIPT_TYPE_P2PKH
+    }
+
+    public fun script_type_p2sh(): u8 {
+        SCRIPT_TYPE_P2SH
+    }
+
+    public fun script_type_p2wpkh(): u8 {
+        SCRIPT_TYPE_P2WPKH
+    }
+
+    public fun script_type_p2wsh(): u8 {
+        SCRIPT_TYPE_P2WSH
+    }
+
+    public fun script_type_p2tr(): u8 {
+        SCRIPT_TYPE_P2TR
+    }
+
+    public fun script_type_witness_unknown(): u8 {
+        SCRIPT_TYPE_WITNESS_UNKNOWN
+    }
+
+    public fun script_type_multisig(): u8 {
+        SCRIPT_TYPE_MULTISIG
+    }
+
+    public fun script_type_op_return(): u8 {
+        SCRIPT_TYPE_OP_RETURN
+    }
+
+    public fun opcode(self: &Instruction): u8 {
+        self.opcode
+    }
+
+    public fun data(self: &Instruction): &vector<u8> {
+        &self.data
+    }
+
+    /// Checks if the instruction pushes data, `OP_0` is a push instruction with empty data.
+    public fun is_push(self: &Instruction): bool {
+        self.opcode <= opcode::op_pushdata4()
+    }
+
+    /// Parse the script into instructions, abort with `ErrorInvalidScript` if the script is invalid.
+    public fun instructions(script: &ScriptBuf): vector<Instruction> {
+        let (opcodes, datas) = parse_instructions(script_buf::bytes(script));
+        vector::reverse(&mut opcodes);
+        vector::reverse(&mut datas);
+        let instructions = vector::empty();
+        while (!vector::is_empty(&opcodes)) {
+            let opcode = vector::pop_back(&mut opcodes);
+            let data = vector::pop_back(&mut datas);
+            vector::push_back(&mut instructions, Instruction{ opcode, data });
+        };
+        instructions
+    }
+
+    /// Get the type of the script, see the `script_type_*` functions.
+    public fun script_type(script: &ScriptBuf): u8 {
+        classify(script_buf::bytes(script))
+    }
+
+    public fun is_p2pk(script: &ScriptBuf): bool {
+        script_type(script) == SCRIPT_TYPE_P2PK
+    }
+
+    public fun is_p2tr(script: &ScriptBuf): bool {
+        script_type(script) == SCRIPT_TYPE_P2TR
+    }
+
+    public fun is_multisig(script: &ScriptBuf): bool {
+        script_type(script) == SCRIPT_TYPE_MULTISIG
+    }
+
+    public fun is_op_return(script: &ScriptBuf): bool {
+        script_type(script) == SCRIPT_TYPE_OP_RETURN
+    }
+
+    /// Get the public keys of the P2PK script or the bare multisig script,
+    /// return empty vector for the other scripts.
+    public fun public_keys(script: &ScriptBuf): vector<vector<u8>> {
+        extract_public_keys(script_buf::bytes(script))
+    }
+
+    /// Get the x-only taproot output key of the P2TR script.
+    public fun taproot_output_key(script: &ScriptBuf): Option<vector<u8>> {
+        let output_key = extract_taproot_output_key(script_buf::bytes(script));
+        if (vector::is_empty(&output_key)) {
+            option::none()
+        } else {
+            option::some(output_key)
+        }
+    }
+
+    native fun parse_instructions(bytes: &vector<u8>): (vector<u8>, vector<vector<u8>>);
+
+    native fun classify(bytes: &vector<u8>): u8;
+
+    native fun extract_public_keys(bytes: &vector<u8>): vector<vector<u8>>;
+
+    native fun extract_taproot_output_key(bytes: &vector<u8>): vector<u8>;
+
+    #[test]
+    fun test_p2pkh() {
+        let script = script_buf::new(x"76a914010966776006953d5567439e5e39f86a0d273bee88ac");
+        assert!(script_type(&script) == SCRIPT_TYPE_P2PKH, 1000);
+        let instructions = instructions(&script);
+        assert!(vector::length(&instructions) == 5, 1001);
+        let push = vector::borrow(&instructions, 2);
+        assert!(is_push(push), 1002);
+        assert!(*data(push) == x"010966776006953d5567439e5e39f86a0d273bee", 1003);
+        assert!(opcode(vector::borrow(&instructions, 4)) == opcode::op_checksig(), 1004);
+        assert!(vector::is_empty(&public_keys(&script)), 1005);
+    }
+
+    #[test]
+    fun test_p2tr() {
+        let script = script_buf::new(x"5120a60869f0dbcf1dc659c9cecbaf8050135ea9e8cdc487053f1dc6880949dc684c");
+        assert!(is_p2tr(&script), 1000);
+        let output_key = taproot_output_key(&script);
+        assert!(option::destroy_some(output_key) == x"a60869f0dbcf1dc659c9cecbaf8050135ea9e8cdc487053f1dc6880949dc684c", 1001);
+        let p2wpkh = script_buf::new(x"001497cdff4fd3ed6f885d54a52b79d7a2141072ae3f");
+        assert!(script_type(&p2wpkh) == SCRIPT_TYPE_P2WPKH, 1002);
+        assert!(option::is_none(&taproot_output_key(&p2wpkh)), 1003);
+    }
+
+    #[test]
+    fun test_p2pk() {
+        let script = script_buf::new(x"2102c6047f9441ed7d6d3045406e95c07cd85c778e4b8cef3ca7abac09b95c709ee5ac");
+        assert!(is_p2pk(&script), 1000);
+        let keys = public_keys(&script);
+        assert!(vector::length(&keys) == 1, 1001);
+        assert!(*vector::borrow(&keys, 0) == x"02c6047f9441ed7d6d3045406e95c07cd85c778e4b8cef3ca7abac09b95c709ee5", 1002);
+    }
+
+    #[test]
+    fun test_multisig() {
+        let script = script_buf::new(x"51210279be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f817982102c6047f9441ed7d6d3045406e95c07cd85c778e4b8cef3ca7abac09b95c709ee552ae");
+        assert!(is_multisig(&script), 1000);
+        let keys = public_keys(&script);
+        assert!(vector::length(&keys) == 2, 1001);
+        assert!(*vector::borrow(&keys, 1) == x"02c6047f9441ed7d6d3045406e95c07cd85c778e4b8cef3ca7abac09b95c709ee5", 1002);
+    }
+
+    #[test]
+    fun test_op_return() {
+        let script = script_buf::new(x"6a0b68656c6c6f20776f726c64");
+        assert!(is_op_return(&script), 1000);
+        let instructions = instructions(&script);
+        assert!(*data(vector::borrow(&instructions, 1)) == b"hello world", 1001);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = ErrorInvalidScript, location = Self)]
+    fun test_invalid_script() {
+        // OP_PUSHBYTES_20 with only 2 bytes
+        let script = script_buf::new(x"140102");
+        instructions(&script);
+    }
+}
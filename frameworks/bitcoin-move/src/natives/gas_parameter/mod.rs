@@ -2,3 +2,5 @@
 // SPDX-License-Identifier: Apache-2.0
 
 mod ord;
+mod pow;
+mod script;
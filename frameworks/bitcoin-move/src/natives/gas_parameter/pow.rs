@@ -0,0 +1,14 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::natives::pow::GasParameters;
+use rooch_framework::natives::gas_parameter::native::MUL;
+
+rooch_framework::natives::gas_parameter::native::define_gas_parameters_for_natives!(GasParameters, "pow", [
+    [.header_hash.base, "header_hash.base", 1000 * MUL],
+    [.header_hash.per_byte, "header_hash.per_byte", 30 * MUL],
+    [.check_pow.base, "check_pow.base", 1500 * MUL],
+    [.check_pow.per_byte, "check_pow.per_byte", 30 * MUL],
+    [.bits_to_work.base, "bits_to_work.base", 500 * MUL],
+    [.calculate_next_bits.base, "calculate_next_bits.base", 1000 * MUL]
+]);
@@ -0,0 +1,16 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::natives::script::GasParameters;
+use rooch_framework::natives::gas_parameter::native::MUL;
+
+rooch_framework::natives::gas_parameter::native::define_gas_parameters_for_natives!(GasParameters, "script", [
+    [.parse_instructions.base, "parse_instructions.base", 1000 * MUL],
+    [.parse_instructions.per_byte, "parse_instructions.per_byte", 30 * MUL],
+    [.classify.base, "classify.base", 500 * MUL],
+    [.classify.per_byte, "classify.per_byte", 10 * MUL],
+    [.extract_public_keys.base, "extract_public_keys.base", 1000 * MUL],
+    [.extract_public_keys.per_byte, "extract_public_keys.per_byte", 30 * MUL],
+    [.extract_taproot_output_key.base, "extract_taproot_output_key.base", 500 * MUL],
+    [.extract_taproot_output_key.per_byte, "extract_taproot_output_key.per_byte", 10 * MUL]
+]);
@@ -12,23 +12,32 @@ use std::collections::BTreeMap;
 
 mod gas_parameter;
 pub mod ord;
+pub mod pow;
+pub mod script;
 
 #[derive(Debug, Clone)]
 pub struct GasParameters {
     ord: ord::GasParameters,
+    pow: pow::GasParameters,
+    script: script::GasParameters,
 }
 
 impl FromOnChainGasSchedule for GasParameters {
     fn from_on_chain_gas_schedule(gas_schedule: &BTreeMap<String, u64>) -> Option<Self> {
         Some(Self {
             ord: FromOnChainGasSchedule::from_on_chain_gas_schedule(gas_schedule).unwrap(),
+            pow: FromOnChainGasSchedule::from_on_chain_gas_schedule(gas_schedule).unwrap(),
+            script: FromOnChainGasSchedule::from_on_chain_gas_schedule(gas_schedule).unwrap(),
         })
     }
 }
 
 impl ToOnChainGasSchedule for GasParameters {
     fn to_on_chain_gas_schedule(&self) -> Vec<(String, u64)> {
-        self.ord.to_on_chain_gas_schedule()
+        let mut entires = self.ord.to_on_chain_gas_schedule();
+        entires.extend(self.pow.to_on_chain_gas_schedule());
+        entires.extend(self.script.to_on_chain_gas_schedule());
+        entires
     }
 }
 
@@ -36,6 +45,8 @@ impl InitialGasSchedule for GasParameters {
     fn initial() -> Self {
         Self {
             ord: InitialGasSchedule::initial(),
+            pow: InitialGasSchedule::initial(),
+            script: InitialGasSchedule::initial(),
         }
     }
 }
@@ -49,6 +60,8 @@ impl GasParameters {
     pub fn zeros() -> Self {
         Self {
             ord: ord::GasParameters::zeros(),
+            pow: pow::GasParameters::zeros(),
+            script: script::GasParameters::zeros(),
         }
     }
 }
@@ -65,6 +78,8 @@ pub fn all_natives(gas_params: GasParameters) -> NativeFunctionTable {
     }
 
     add_natives!("ord", ord::make_all(gas_params.ord));
+    add_natives!("pow", pow::make_all(gas_params.pow));
+    add_natives!("script", script::make_all(gas_params.script));
 
     make_table_from_iter(BITCOIN_MOVE_ADDRESS, natives)
 }
@@ -0,0 +1,266 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use bitcoin::block::Header;
+use bitcoin::consensus::deserialize;
+use bitcoin::hashes::Hash;
+use bitcoin::{CompactTarget, Target};
+use move_binary_format::errors::PartialVMResult;
+use move_core_types::account_address::AccountAddress;
+use move_core_types::gas_algebra::{InternalGas, InternalGasPerByte, NumBytes};
+use move_core_types::u256::U256;
+use move_vm_runtime::native_functions::{NativeContext, NativeFunction};
+use move_vm_types::{
+    loaded_data::runtime_types::Type,
+    natives::function::NativeResult,
+    pop_arg,
+    values::{Value, VectorRef},
+};
+use moveos_stdlib::natives::helpers::{make_module_natives, make_native};
+use smallvec::smallvec;
+use std::collections::VecDeque;
+
+pub const E_INVALID_HEADER: u64 = 1;
+pub const E_INVALID_BITS: u64 = 2;
+
+/// The expected timespan of a difficulty adjustment period, two weeks in seconds.
+pub const TARGET_TIMESPAN: u64 = 14 * 24 * 60 * 60;
+
+#[derive(Debug, Clone)]
+pub struct FromBytesGasParameters {
+    pub base: InternalGas,
+    pub per_byte: InternalGasPerByte,
+}
+
+impl FromBytesGasParameters {
+    pub fn zeros() -> Self {
+        Self {
+            base: 0.into(),
+            per_byte: 0.into(),
+        }
+    }
+
+    fn cost(&self, bytes: &[u8]) -> InternalGas {
+        self.base + self.per_byte * NumBytes::new(bytes.len() as u64)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct FromBitsGasParameters {
+    pub base: InternalGas,
+}
+
+impl FromBitsGasParameters {
+    pub fn zeros() -> Self {
+        Self { base: 0.into() }
+    }
+}
+
+/// Decode the compact `bits` into the target, the same as `SetCompact` of Bitcoin Core,
+/// return None if the bits is negative, overflows or encodes a zero target.
+pub fn bits_to_target(bits: u32) -> Option<Target> {
+    let size = bits >> 24;
+    let word = bits & 0x007f_ffff;
+    let negative = word != 0 && (bits & 0x0080_0000) != 0;
+    let overflow =
+        word != 0 && (size > 34 || (word > 0xff && size > 33) || (word > 0xffff && size > 32));
+    if negative || overflow {
+        return None;
+    }
+    let target = Target::from_compact(CompactTarget::from_consensus(bits));
+    if target == Target::ZERO {
+        None
+    } else {
+        Some(target)
+    }
+}
+
+/// The expected number of hashes to mine a block with the `bits`.
+pub fn bits_to_work(bits: u32) -> Option<U256> {
+    let target = bits_to_target(bits)?;
+    Some(U256::from_le_bytes(&target.to_work().to_le_bytes()))
+}
+
+/// Calculate the bits of the next difficulty adjustment period, the same as `CalculateNextWorkRequired` of Bitcoin Core.
+/// The actual timespan of the period is clamped to [TARGET_TIMESPAN / 4, TARGET_TIMESPAN * 4],
+/// and the new target does not exceed the pow limit.
+pub fn calculate_next_bits(
+    prev_bits: u32,
+    first_block_time: u32,
+    last_block_time: u32,
+    pow_limit_bits: u32,
+) -> Option<u32> {
+    let prev_target = bits_to_target(prev_bits)?;
+    let pow_limit = bits_to_target(pow_limit_bits)?;
+    let actual_timespan = (last_block_time as u64)
+        .saturating_sub(first_block_time as u64)
+        .clamp(TARGET_TIMESPAN / 4, TARGET_TIMESPAN * 4);
+    let prev_target = U256::from_le_bytes(&prev_target.to_le_bytes());
+    // The target overflows only if it is close to the max target, the pow limit is used in this case
+    let next_target = match prev_target.checked_mul(U256::from(actual_timespan)) {
+        Some(target) => Target::from_le_bytes(
+            target
+                .checked_div(U256::from(TARGET_TIMESPAN))?
+                .to_le_bytes(),
+        ),
+        None => pow_limit,
+    };
+    Some(next_target.min(pow_limit).to_compact_lossy().to_consensus())
+}
+
+/***************************************************************************************************
+ * native fun header_hash_bytes
+ * Implementation of the Move native function `pow::header_hash_bytes(header_bytes: &vector<u8>): address`
+ *   gas cost: base + per_byte * header_bytes.len()
+ **************************************************************************************************/
+pub fn native_header_hash_bytes(
+    gas_params: &FromBytesGasParameters,
+    _context: &mut NativeContext,
+    ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(ty_args.is_empty());
+    debug_assert!(args.len() == 1);
+
+    let bytes = pop_arg!(args, VectorRef);
+    let bytes = bytes.as_bytes_ref();
+    let cost = gas_params.cost(&bytes);
+
+    let Ok(header) = deserialize::<Header>(&bytes) else {
+        return Ok(NativeResult::err(cost, E_INVALID_HEADER));
+    };
+
+    let block_hash = AccountAddress::new(header.block_hash().to_byte_array());
+    Ok(NativeResult::ok(
+        cost,
+        smallvec![Value::address(block_hash)],
+    ))
+}
+
+/***************************************************************************************************
+ * native fun check_pow_bytes
+ * Implementation of the Move native function `pow::check_pow_bytes(header_bytes: &vector<u8>, pow_limit_bits: u32): bool`
+ *   gas cost: base + per_byte * header_bytes.len()
+ **************************************************************************************************/
+pub fn native_check_pow_bytes(
+    gas_params: &FromBytesGasParameters,
+    _context: &mut NativeContext,
+    ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(ty_args.is_empty());
+    debug_assert!(args.len() == 2);
+
+    let pow_limit_bits = pop_arg!(args, u32);
+    let bytes = pop_arg!(args, VectorRef);
+    let bytes = bytes.as_bytes_ref();
+    let cost = gas_params.cost(&bytes);
+
+    let Ok(header) = deserialize::<Header>(&bytes) else {
+        return Ok(NativeResult::err(cost, E_INVALID_HEADER));
+    };
+    let (Some(target), Some(pow_limit)) = (
+        bits_to_target(header.bits.to_consensus()),
+        bits_to_target(pow_limit_bits),
+    ) else {
+        return Ok(NativeResult::err(cost, E_INVALID_BITS));
+    };
+
+    // The target easier than the pow limit is invalid even if the block hash meets it
+    let valid = target <= pow_limit && target.is_met_by(header.block_hash());
+    Ok(NativeResult::ok(cost, smallvec![Value::bool(valid)]))
+}
+
+/***************************************************************************************************
+ * native fun bits_to_work
+ * Implementation of the Move native function `pow::bits_to_work(bits: u32): u256`
+ *   gas cost: base
+ **************************************************************************************************/
+pub fn native_bits_to_work(
+    gas_params: &FromBitsGasParameters,
+    _context: &mut NativeContext,
+    ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(ty_args.is_empty());
+    debug_assert!(args.len() == 1);
+
+    let bits = pop_arg!(args, u32);
+    let cost = gas_params.base;
+
+    let Some(work) = bits_to_work(bits) else {
+        return Ok(NativeResult::err(cost, E_INVALID_BITS));
+    };
+    Ok(NativeResult::ok(cost, smallvec![Value::u256(work)]))
+}
+
+/***************************************************************************************************
+ * native fun calculate_next_bits
+ * Implementation of the Move native function
+ * `pow::calculate_next_bits(prev_bits: u32, first_block_time: u32, last_block_time: u32, pow_limit_bits: u32): u32`
+ *   gas cost: base
+ **************************************************************************************************/
+pub fn native_calculate_next_bits(
+    gas_params: &FromBitsGasParameters,
+    _context: &mut NativeContext,
+    ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(ty_args.is_empty());
+    debug_assert!(args.len() == 4);
+
+    let pow_limit_bits = pop_arg!(args, u32);
+    let last_block_time = pop_arg!(args, u32);
+    let first_block_time = pop_arg!(args, u32);
+    let prev_bits = pop_arg!(args, u32);
+    let cost = gas_params.base;
+
+    let Some(next_bits) =
+        calculate_next_bits(prev_bits, first_block_time, last_block_time, pow_limit_bits)
+    else {
+        return Ok(NativeResult::err(cost, E_INVALID_BITS));
+    };
+    Ok(NativeResult::ok(cost, smallvec![Value::u32(next_bits)]))
+}
+
+#[derive(Debug, Clone)]
+pub struct GasParameters {
+    pub header_hash: FromBytesGasParameters,
+    pub check_pow: FromBytesGasParameters,
+    pub bits_to_work: FromBitsGasParameters,
+    pub calculate_next_bits: FromBitsGasParameters,
+}
+
+impl GasParameters {
+    pub fn zeros() -> Self {
+        Self {
+            header_hash: FromBytesGasParameters::zeros(),
+            check_pow: FromBytesGasParameters::zeros(),
+            bits_to_work: FromBitsGasParameters::zeros(),
+            calculate_next_bits: FromBitsGasParameters::zeros(),
+        }
+    }
+}
+
+pub fn make_all(gas_params: GasParameters) -> impl Iterator<Item = (String, NativeFunction)> {
+    let natives = [
+        (
+            "header_hash_bytes",
+            make_native(gas_params.header_hash, native_header_hash_bytes),
+        ),
+        (
+            "check_pow_bytes",
+            make_native(gas_params.check_pow, native_check_pow_bytes),
+        ),
+        (
+            "bits_to_work",
+            make_native(gas_params.bits_to_work, native_bits_to_work),
+        ),
+        (
+            "calculate_next_bits",
+            make_native(gas_params.calculate_next_bits, native_calculate_next_bits),
+        ),
+    ];
+
+    make_module_natives(natives)
+}
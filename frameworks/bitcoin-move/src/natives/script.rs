@@ -0,0 +1,308 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use bitcoin::blockdata::opcodes::all::{
+    OP_CHECKMULTISIG, OP_CHECKSIG, OP_PUSHNUM_1, OP_PUSHNUM_16,
+};
+use bitcoin::blockdata::script::Instruction;
+use bitcoin::Script;
+use move_binary_format::errors::PartialVMResult;
+use move_core_types::gas_algebra::{InternalGas, InternalGasPerByte, NumBytes};
+use move_vm_runtime::native_functions::{NativeContext, NativeFunction};
+use move_vm_types::{
+    loaded_data::runtime_types::Type,
+    natives::function::NativeResult,
+    pop_arg,
+    values::{Value, Vector, VectorRef},
+};
+use moveos_stdlib::natives::helpers::{make_module_natives, make_native};
+use smallvec::smallvec;
+use std::collections::VecDeque;
+
+pub const E_INVALID_SCRIPT: u64 = 1;
+
+// The script types, keep the same as the constants in `bitcoin_move::script`
+pub const SCRIPT_TYPE_NON_STANDARD: u8 = 0;
+pub const SCRIPT_TYPE_P2PK: u8 = 1;
+pub const SCRIPT_TYPE_P2PKH: u8 = 2;
+pub const SCRIPT_TYPE_P2SH: u8 = 3;
+pub const SCRIPT_TYPE_P2WPKH: u8 = 4;
+pub const SCRIPT_TYPE_P2WSH: u8 = 5;
+pub const SCRIPT_TYPE_P2TR: u8 = 6;
+pub const SCRIPT_TYPE_WITNESS_UNKNOWN: u8 = 7;
+pub const SCRIPT_TYPE_MULTISIG: u8 = 8;
+pub const SCRIPT_TYPE_OP_RETURN: u8 = 9;
+
+const COMPRESSED_PUBLIC_KEY_SIZE: usize = 33;
+const UNCOMPRESSED_PUBLIC_KEY_SIZE: usize = 65;
+
+#[derive(Debug, Clone)]
+pub struct FromBytesGasParameters {
+    pub base: InternalGas,
+    pub per_byte: InternalGasPerByte,
+}
+
+impl FromBytesGasParameters {
+    pub fn zeros() -> Self {
+        Self {
+            base: 0.into(),
+            per_byte: 0.into(),
+        }
+    }
+
+    fn cost(&self, bytes: &[u8]) -> InternalGas {
+        self.base + self.per_byte * NumBytes::new(bytes.len() as u64)
+    }
+}
+
+/// Parse the script into the opcodes and the pushed data of the instructions,
+/// return None if the script is invalid, such as a push opcode without enough data.
+pub fn parse_instructions(script: &Script) -> Option<(Vec<u8>, Vec<Vec<u8>>)> {
+    let bytes = script.as_bytes();
+    let mut opcodes = vec![];
+    let mut datas = vec![];
+    for instruction in script.instruction_indices() {
+        let (index, instruction) = instruction.ok()?;
+        opcodes.push(bytes[index]);
+        datas.push(match instruction {
+            Instruction::PushBytes(push_bytes) => push_bytes.as_bytes().to_vec(),
+            Instruction::Op(_) => vec![],
+        });
+    }
+    Some((opcodes, datas))
+}
+
+pub fn classify(script: &Script) -> u8 {
+    if script.is_p2pkh() {
+        SCRIPT_TYPE_P2PKH
+    } else if script.is_p2sh() {
+        SCRIPT_TYPE_P2SH
+    } else if script.is_p2wpkh() {
+        SCRIPT_TYPE_P2WPKH
+    } else if script.is_p2wsh() {
+        SCRIPT_TYPE_P2WSH
+    } else if script.is_p2tr() {
+        SCRIPT_TYPE_P2TR
+    } else if script.is_witness_program() {
+        SCRIPT_TYPE_WITNESS_UNKNOWN
+    } else if script.is_op_return() {
+        SCRIPT_TYPE_OP_RETURN
+    } else if p2pk_public_key(script).is_some() {
+        SCRIPT_TYPE_P2PK
+    } else if multisig_public_keys(script).is_some() {
+        SCRIPT_TYPE_MULTISIG
+    } else {
+        SCRIPT_TYPE_NON_STANDARD
+    }
+}
+
+fn is_public_key(bytes: &[u8]) -> bool {
+    bytes.len() == COMPRESSED_PUBLIC_KEY_SIZE || bytes.len() == UNCOMPRESSED_PUBLIC_KEY_SIZE
+}
+
+/// The public key of the P2PK script: `<pubkey> OP_CHECKSIG`.
+fn p2pk_public_key(script: &Script) -> Option<Vec<u8>> {
+    let (opcodes, mut datas) = parse_instructions(script)?;
+    match opcodes.as_slice() {
+        [_, op] if *op == OP_CHECKSIG.to_u8() && is_public_key(&datas[0]) => {
+            Some(datas.swap_remove(0))
+        }
+        _ => None,
+    }
+}
+
+fn decode_pushnum(opcode: u8) -> Option<usize> {
+    if (OP_PUSHNUM_1.to_u8()..=OP_PUSHNUM_16.to_u8()).contains(&opcode) {
+        Some((opcode - OP_PUSHNUM_1.to_u8() + 1) as usize)
+    } else {
+        None
+    }
+}
+
+/// The public keys of the bare multisig script: `OP_m <pubkey>... OP_n OP_CHECKMULTISIG`.
+fn multisig_public_keys(script: &Script) -> Option<Vec<Vec<u8>>> {
+    let (opcodes, datas) = parse_instructions(script)?;
+    let len = opcodes.len();
+    if len < 4 || opcodes[len - 1] != OP_CHECKMULTISIG.to_u8() {
+        return None;
+    }
+    let required = decode_pushnum(opcodes[0])?;
+    let total = decode_pushnum(opcodes[len - 2])?;
+    if required > total || total != len - 3 {
+        return None;
+    }
+    let public_keys = datas[1..len - 2].to_vec();
+    if public_keys.iter().all(|key| is_public_key(key)) {
+        Some(public_keys)
+    } else {
+        None
+    }
+}
+
+pub fn public_keys(script: &Script) -> Vec<Vec<u8>> {
+    match p2pk_public_key(script) {
+        Some(public_key) => vec![public_key],
+        None => multisig_public_keys(script).unwrap_or_default(),
+    }
+}
+
+/// The x-only output key of the P2TR script, empty if the script is not a P2TR script.
+pub fn taproot_output_key(script: &Script) -> Vec<u8> {
+    if script.is_p2tr() {
+        script.as_bytes()[2..].to_vec()
+    } else {
+        vec![]
+    }
+}
+
+fn vector_of_bytes(values: Vec<Vec<u8>>) -> PartialVMResult<Value> {
+    Vector::pack(
+        &Type::Vector(Box::new(Type::U8)),
+        values.into_iter().map(Value::vector_u8).collect(),
+    )
+}
+
+/***************************************************************************************************
+ * native fun parse_instructions
+ * Implementation of the Move native function `script::parse_instructions(bytes: &vector<u8>): (vector<u8>, vector<vector<u8>>)`
+ *   gas cost: base + per_byte * bytes.len()
+ **************************************************************************************************/
+pub fn native_parse_instructions(
+    gas_params: &FromBytesGasParameters,
+    _context: &mut NativeContext,
+    ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(ty_args.is_empty());
+    debug_assert!(args.len() == 1);
+
+    let bytes = pop_arg!(args, VectorRef);
+    let bytes = bytes.as_bytes_ref();
+    let cost = gas_params.cost(&bytes);
+
+    let Some((opcodes, datas)) = parse_instructions(Script::from_bytes(&bytes)) else {
+        return Ok(NativeResult::err(cost, E_INVALID_SCRIPT));
+    };
+
+    Ok(NativeResult::ok(
+        cost,
+        smallvec![Value::vector_u8(opcodes), vector_of_bytes(datas)?],
+    ))
+}
+
+/***************************************************************************************************
+ * native fun classify
+ * Implementation of the Move native function `script::classify(bytes: &vector<u8>): u8`
+ *   gas cost: base + per_byte * bytes.len()
+ **************************************************************************************************/
+pub fn native_classify(
+    gas_params: &FromBytesGasParameters,
+    _context: &mut NativeContext,
+    ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(ty_args.is_empty());
+    debug_assert!(args.len() == 1);
+
+    let bytes = pop_arg!(args, VectorRef);
+    let bytes = bytes.as_bytes_ref();
+    let cost = gas_params.cost(&bytes);
+
+    let script_type = classify(Script::from_bytes(&bytes));
+    Ok(NativeResult::ok(cost, smallvec![Value::u8(script_type)]))
+}
+
+/***************************************************************************************************
+ * native fun extract_public_keys
+ * Implementation of the Move native function `script::extract_public_keys(bytes: &vector<u8>): vector<vector<u8>>`
+ *   gas cost: base + per_byte * bytes.len()
+ **************************************************************************************************/
+pub fn native_extract_public_keys(
+    gas_params: &FromBytesGasParameters,
+    _context: &mut NativeContext,
+    ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(ty_args.is_empty());
+    debug_assert!(args.len() == 1);
+
+    let bytes = pop_arg!(args, VectorRef);
+    let bytes = bytes.as_bytes_ref();
+    let cost = gas_params.cost(&bytes);
+
+    let public_keys = public_keys(Script::from_bytes(&bytes));
+    Ok(NativeResult::ok(
+        cost,
+        smallvec![vector_of_bytes(public_keys)?],
+    ))
+}
+
+/***************************************************************************************************
+ * native fun extract_taproot_output_key
+ * Implementation of the Move native function `script::extract_taproot_output_key(bytes: &vector<u8>): vector<u8>`
+ *   gas cost: base + per_byte * bytes.len()
+ **************************************************************************************************/
+pub fn native_extract_taproot_output_key(
+    gas_params: &FromBytesGasParameters,
+    _context: &mut NativeContext,
+    ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(ty_args.is_empty());
+    debug_assert!(args.len() == 1);
+
+    let bytes = pop_arg!(args, VectorRef);
+    let bytes = bytes.as_bytes_ref();
+    let cost = gas_params.cost(&bytes);
+
+    let output_key = taproot_output_key(Script::from_bytes(&bytes));
+    Ok(NativeResult::ok(
+        cost,
+        smallvec![Value::vector_u8(output_key)],
+    ))
+}
+
+#[derive(Debug, Clone)]
+pub struct GasParameters {
+    pub parse_instructions: FromBytesGasParameters,
+    pub classify: FromBytesGasParameters,
+    pub extract_public_keys: FromBytesGasParameters,
+    pub extract_taproot_output_key: FromBytesGasParameters,
+}
+
+impl GasParameters {
+    pub fn zeros() -> Self {
+        Self {
+            parse_instructions: FromBytesGasParameters::zeros(),
+            classify: FromBytesGasParameters::zeros(),
+            extract_public_keys: FromBytesGasParameters::zeros(),
+            extract_taproot_output_key: FromBytesGasParameters::zeros(),
+        }
+    }
+}
+
+pub fn make_all(gas_params: GasParameters) -> impl Iterator<Item = (String, NativeFunction)> {
+    let natives = [
+        (
+            "parse_instructions",
+            make_native(gas_params.parse_instructions, native_parse_instructions),
+        ),
+        (
+            "classify",
+            make_native(gas_params.classify, native_classify),
+        ),
+        (
+            "extract_public_keys",
+            make_native(gas_params.extract_public_keys, native_extract_public_keys),
+        ),
+        (
+            "extract_taproot_output_key",
+            make_native(
+                gas_params.extract_taproot_output_key,
+                native_extract_taproot_output_key,
+            ),
+        ),
+    ];
+
+    make_module_natives(natives)
+}
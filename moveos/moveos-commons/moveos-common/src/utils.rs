@@ -4,6 +4,7 @@
 use anyhow::{Error, Result};
 use itertools::Itertools;
 use serde::{Deserialize, Serialize};
+use std::time::{SystemTime, UNIX_EPOCH};
 use std::{collections::BTreeMap, str::FromStr};
 
 /// Error message for parsing a map
@@ -40,6 +41,22 @@ where
     Ok(map)
 }
 
+/// The seconds since the unix epoch of the local clock, 0 if the clock is before the epoch
+pub fn now_seconds() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_secs())
+        .unwrap_or_default()
+}
+
+/// The milliseconds since the unix epoch of the local clock, 0 if the clock is before the epoch
+pub fn now_milliseconds() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_millis() as u64)
+        .unwrap_or_default()
+}
+
 pub fn to_bytes<T>(value: &T) -> Result<Vec<u8>>
 where
     T: ?Sized + Serialize,
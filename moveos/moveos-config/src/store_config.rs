@@ -6,6 +6,10 @@
 
 use clap::Parser;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// The default number of the state tree nodes cached in memory
+pub const DEFAULT_STATE_NODE_CACHE_SIZE: usize = 100_000;
 
 /// Port selected RocksDB options for tuning underlying rocksdb instance of DiemDB.
 /// see https://github.com/facebook/rocksdb/blob/master/include/rocksdb/options.h
@@ -13,7 +17,7 @@ use serde::{Deserialize, Serialize};
 /// https://github.com/facebook/rocksdb/wiki/WAL-Performance
 /// wal_bytes_per_sync, bytes_per_sync see https://github.com/facebook/rocksdb/wiki/IO#range-sync
 /// for detailed explanations.
-#[derive(Copy, Clone, Debug, Deserialize, PartialEq, Eq, Serialize, Parser)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq, Serialize, Parser)]
 #[serde(default, deny_unknown_fields)]
 pub struct RocksdbConfig {
     #[clap(name = "rocksdb-max-open-files", long, help = "rocksdb max open files")]
@@ -32,6 +36,12 @@ pub struct RocksdbConfig {
     pub wal_bytes_per_sync: u64,
     #[clap(name = "rocksdb-bytes-per-sync", long, help = "rocksdb bytes per sync")]
     pub bytes_per_sync: u64,
+    /// The options applied to all the column families
+    #[clap(skip)]
+    pub column_family: ColumnFamilyConfig,
+    /// The options of the column families keyed by the column family name, override `column_family`
+    #[clap(skip)]
+    pub column_families: BTreeMap<String, ColumnFamilyConfig>,
 }
 
 impl RocksdbConfig {
@@ -44,6 +54,14 @@ impl RocksdbConfig {
     fn default_max_open_files() -> i32 {
         256
     }
+
+    /// The options of the column family, the options not set for the column family fallback to the common options
+    pub fn column_family_config(&self, cf_name: &str) -> ColumnFamilyConfig {
+        match self.column_families.get(cf_name) {
+            Some(cf_config) => self.column_family.merge(cf_config),
+            None => self.column_family,
+        }
+    }
 }
 
 impl Default for RocksdbConfig {
@@ -59,6 +77,217 @@ impl Default for RocksdbConfig {
             bytes_per_sync: 1u64 << 20,
             // For wal sync every size to be 1MB
             wal_bytes_per_sync: 1u64 << 20,
+            column_family: ColumnFamilyConfig::default(),
+            column_families: BTreeMap::new(),
+        }
+    }
+}
+
+/// The compression algorithm of the column family data blocks.
+#[derive(Copy, Clone, Debug, Default, Deserialize, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CompressionType {
+    None,
+    Snappy,
+    #[default]
+    Lz4,
+    Zstd,
+}
+
+impl std::fmt::Display for CompressionType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CompressionType::None => write!(f, "none"),
+            CompressionType::Snappy => write!(f, "snappy"),
+            CompressionType::Lz4 => write!(f, "lz4"),
+            CompressionType::Zstd => write!(f, "zstd"),
+        }
+    }
+}
+
+impl std::str::FromStr for CompressionType {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "none" => Ok(CompressionType::None),
+            "snappy" => Ok(CompressionType::Snappy),
+            "lz4" => Ok(CompressionType::Lz4),
+            "zstd" => Ok(CompressionType::Zstd),
+            _ => Err(anyhow::anyhow!(
+                "Invalid compression type {}, expect none, snappy, lz4 or zstd",
+                s
+            )),
+        }
+    }
+}
+
+/// The options of a RocksDB column family, the RocksDB default is used if an option is not set.
+#[derive(Copy, Clone, Debug, Default, Deserialize, PartialEq, Eq, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ColumnFamilyConfig {
+    /// The size in bytes of the LRU block cache of the column family
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub block_cache_size: Option<u64>,
+    /// The bits per key of the bloom filter, the bloom filter is disabled if not set
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bloom_filter_bits_per_key: Option<u32>,
+    /// The compression of the data blocks, lz4 if not set
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub compression: Option<CompressionType>,
+    /// The size in bytes of a memtable
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub write_buffer_size: Option<u64>,
+}
+
+impl ColumnFamilyConfig {
+    /// Override the options with the options set in `other`
+    pub fn merge(&self, other: &ColumnFamilyConfig) -> ColumnFamilyConfig {
+        ColumnFamilyConfig {
+            block_cache_size: other.block_cache_size.or(self.block_cache_size),
+            bloom_filter_bits_per_key: other
+                .bloom_filter_bits_per_key
+                .or(self.bloom_filter_bits_per_key),
+            compression: other.compression.or(self.compression),
+            write_buffer_size: other.write_buffer_size.or(self.write_buffer_size),
+        }
+    }
+
+    pub fn compression(&self) -> CompressionType {
+        self.compression.unwrap_or_default()
+    }
+}
+
+impl std::str::FromStr for ColumnFamilyConfig {
+    type Err = anyhow::Error;
+
+    /// Parse the options from `<KEY>=<VALUE>` pairs separated by `,`,
+    /// e.g. `block_cache_size=1073741824,bloom_filter_bits_per_key=10,compression=zstd`
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut config = ColumnFamilyConfig::default();
+        for option in s.split(',').map(str::trim).filter(|o| !o.is_empty()) {
+            let (key, value) = option.split_once('=').ok_or_else(|| {
+                anyhow::anyhow!(
+                    "Invalid column family option {}, expect <KEY>=<VALUE>",
+                    option
+                )
+            })?;
+            match key.trim() {
+                "block_cache_size" => config.block_cache_size = Some(value.trim().parse()?),
+                "bloom_filter_bits_per_key" => {
+                    config.bloom_filter_bits_per_key = Some(value.trim().parse()?)
+                }
+                "compression" => config.compression = Some(value.trim().parse()?),
+                "write_buffer_size" => config.write_buffer_size = Some(value.trim().parse()?),
+                _ => anyhow::bail!(
+                    "Invalid column family option {}, expect block_cache_size, bloom_filter_bits_per_key, compression or write_buffer_size",
+                    key
+                ),
+            }
+        }
+        Ok(config)
+    }
+}
+
+/// The options of the named column family, parsed from `<CF_NAME>:<OPTIONS>`
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct NamedColumnFamilyConfig {
+    pub name: String,
+    pub config: ColumnFamilyConfig,
+}
+
+impl std::str::FromStr for NamedColumnFamilyConfig {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (name, options) = s.split_once(':').ok_or_else(|| {
+            anyhow::anyhow!(
+                "Invalid column family options {}, expect <CF_NAME>:<OPTIONS>",
+                s
+            )
+        })?;
+        anyhow::ensure!(
+            !name.trim().is_empty(),
+            "Missing column family name in {}",
+            s
+        );
+        Ok(NamedColumnFamilyConfig {
+            name: name.trim().to_string(),
+            config: options.parse()?,
+        })
+    }
+}
+
+/// The policy of the state pruner, decides which historical state roots are retained.
+#[derive(Copy, Clone, Debug, Default, Deserialize, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PruningPolicy {
+    /// Keep all the historical states, never prune.
+    #[default]
+    Archive,
+    /// Keep the latest N state roots.
+    RetainLatest(u64),
+    /// Keep the latest state root and the state root at the end of every epoch,
+    /// the epoch is the given number of transactions.
+    RetainByEpoch(u64),
+}
+
+impl PruningPolicy {
+    pub fn is_archive(&self) -> bool {
+        matches!(self, PruningPolicy::Archive)
+    }
+
+    /// Returns whether the state root of `tx_order` should be retained, `latest_tx_order` is the latest executed tx order.
+    pub fn should_retain(&self, tx_order: u64, latest_tx_order: u64) -> bool {
+        match self {
+            PruningPolicy::Archive => true,
+            PruningPolicy::RetainLatest(n) => latest_tx_order.saturating_sub(tx_order) < *n,
+            PruningPolicy::RetainByEpoch(epoch_size) => {
+                tx_order == latest_tx_order || (tx_order + 1) % (*epoch_size).max(1) == 0
+            }
+        }
+    }
+}
+
+impl std::fmt::Display for PruningPolicy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PruningPolicy::Archive => write!(f, "archive"),
+            PruningPolicy::RetainLatest(n) => write!(f, "retain-latest:{}", n),
+            PruningPolicy::RetainByEpoch(epoch_size) => write!(f, "retain-by-epoch:{}", epoch_size),
+        }
+    }
+}
+
+impl std::str::FromStr for PruningPolicy {
+    type Err = anyhow::Error;
+
+    /// Parse the policy from `archive`, `retain-latest:<N>` or `retain-by-epoch:<EPOCH_SIZE>`
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (name, arg) = match s.split_once(':') {
+            Some((name, arg)) => (name, Some(arg)),
+            None => (s, None),
+        };
+        let parse_arg = |arg: Option<&str>| -> anyhow::Result<u64> {
+            let value = arg
+                .ok_or_else(|| anyhow::anyhow!("Missing argument for pruning policy {}", name))?
+                .parse::<u64>()?;
+            anyhow::ensure!(
+                value > 0,
+                "The argument of pruning policy {} must be positive",
+                name
+            );
+            Ok(value)
+        };
+        match name {
+            "archive" => Ok(PruningPolicy::Archive),
+            "retain-latest" => Ok(PruningPolicy::RetainLatest(parse_arg(arg)?)),
+            "retain-by-epoch" => Ok(PruningPolicy::RetainByEpoch(parse_arg(arg)?)),
+            _ => Err(anyhow::anyhow!(
+                "Invalid pruning policy {}, expect archive, retain-latest:<N> or retain-by-epoch:<EPOCH_SIZE>",
+                s
+            )),
         }
     }
 }
@@ -7,7 +7,7 @@ use move_core_types::language_storage::StructTag;
 use moveos_types::moveos_std::event::{Event, EventHandle, EventID, TransactionEvent};
 use moveos_types::moveos_std::object_id::ObjectID;
 use raw_store::{derive_store, CodecKVStore, StoreInstance};
-use std::cmp::min;
+use std::cmp::{max, min};
 use std::collections::{HashMap, HashSet};
 
 derive_store!(EventDBBaseStore, (ObjectID, u64), Event, EVENT_PREFIX_NAME);
@@ -31,6 +31,9 @@ pub trait EventStore {
         event_handle_id: &ObjectID,
         cursor: Option<u64>,
         limit: u64,
+        descending_order: bool,
+        from_seq: Option<u64>,
+        to_seq: Option<u64>,
     ) -> Result<Vec<Event>>;
 
     fn get_events_by_event_handle_type(
@@ -38,6 +41,9 @@ pub trait EventStore {
         event_handle_type: &StructTag,
         cursor: Option<u64>,
         limit: u64,
+        descending_order: bool,
+        from_seq: Option<u64>,
+        to_seq: Option<u64>,
     ) -> Result<Vec<Event>>;
 }
 
@@ -129,14 +135,17 @@ impl EventDBStore {
         self.event_store.multiple_get(keys)
     }
 
-    /// Get events by event handle id
-    /// The cursor is the previous last event seq
-    /// So, do not include the result
+    /// Get events by event handle id, ordered by the event seq.
+    /// The cursor is the event seq of the last event of the previous page, it is excluded from the result.
+    /// The `from_seq` and `to_seq` are the inclusive bounds of the event seqs.
     pub fn get_events_by_event_handle_id(
         &self,
         event_handle_id: &ObjectID,
         cursor: Option<u64>,
         limit: u64,
+        descending_order: bool,
+        from_seq: Option<u64>,
+        to_seq: Option<u64>,
     ) -> Result<Vec<Event>> {
         let event_handle = self.get_event_handle(*event_handle_id)?.ok_or_else(|| {
             anyhow!(
@@ -144,16 +153,42 @@ impl EventDBStore {
                 event_handle_id.to_string()
             )
         })?;
-        let last_seq = event_handle.count;
-        let start = match cursor {
-            //The cursor do not include the result
-            Some(cursor) => cursor + 1,
-            //None means start from -1
-            None => 0,
+        let Some(last_seq) = event_handle.count.checked_sub(1) else {
+            return Ok(vec![]);
         };
-        let end = min(start + limit, last_seq);
-        let event_ids = (start..end)
-            .map(|v| (EventID::new(*event_handle_id, v)))
+        if limit == 0 {
+            return Ok(vec![]);
+        }
+        let from_seq = from_seq.unwrap_or(0);
+        let to_seq = to_seq.map_or(last_seq, |to_seq| min(to_seq, last_seq));
+
+        let event_seqs = if descending_order {
+            let end = match cursor {
+                Some(cursor) => match cursor.checked_sub(1) {
+                    Some(end) => min(end, to_seq),
+                    None => return Ok(vec![]),
+                },
+                None => to_seq,
+            };
+            if end < from_seq {
+                return Ok(vec![]);
+            }
+            let start = max(from_seq, end.saturating_sub(limit - 1));
+            (start..=end).rev().collect::<Vec<_>>()
+        } else {
+            let start = match cursor {
+                Some(cursor) => max(cursor.saturating_add(1), from_seq),
+                None => from_seq,
+            };
+            if start > to_seq {
+                return Ok(vec![]);
+            }
+            let end = min(to_seq, start.saturating_add(limit - 1));
+            (start..=end).collect::<Vec<_>>()
+        };
+        let event_ids = event_seqs
+            .into_iter()
+            .map(|v| EventID::new(*event_handle_id, v))
             .collect::<Vec<_>>();
         Ok(self
             .multi_get_events(event_ids)?
@@ -167,8 +202,18 @@ impl EventDBStore {
         event_handle_type: &StructTag,
         cursor: Option<u64>,
         limit: u64,
+        descending_order: bool,
+        from_seq: Option<u64>,
+        to_seq: Option<u64>,
     ) -> Result<Vec<Event>> {
         let event_handle_id = EventHandle::derive_event_handle_id(event_handle_type);
-        self.get_events_by_event_handle_id(&event_handle_id, cursor, limit)
+        self.get_events_by_event_handle_id(
+            &event_handle_id,
+            cursor,
+            limit,
+            descending_order,
+            from_seq,
+            to_seq,
+        )
     }
 }
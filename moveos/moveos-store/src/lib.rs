@@ -18,7 +18,7 @@ use crate::state_store::statedb::StateDBStore;
 use crate::state_store::NodeDBStore;
 use crate::transaction_store::{TransactionDBStore, TransactionStore};
 use move_core_types::language_storage::StructTag;
-use moveos_config::store_config::RocksdbConfig;
+use moveos_config::store_config::{RocksdbConfig, DEFAULT_STATE_NODE_CACHE_SIZE};
 use moveos_types::h256::H256;
 use moveos_types::moveos_std::event::{Event, EventID, TransactionEvent};
 use moveos_types::moveos_std::object_id::ObjectID;
@@ -94,8 +94,16 @@ impl MoveOSDB {
     }
 
     pub fn new(instance: StoreInstance) -> Result<Self> {
+        Self::new_with_node_cache_size(instance, DEFAULT_STATE_NODE_CACHE_SIZE)
+    }
+
+    /// Create the db with a state node cache of `node_cache_size` nodes, the cache is disabled if the size is 0
+    pub fn new_with_node_cache_size(
+        instance: StoreInstance,
+        node_cache_size: usize,
+    ) -> Result<Self> {
         let store = Self {
-            node_store: NodeDBStore::new(instance.clone()),
+            node_store: NodeDBStore::new_with_cache_size(instance.clone(), node_cache_size),
             event_store: EventDBStore::new(instance.clone()),
             transaction_store: TransactionDBStore::new(instance.clone()),
             config_store: ConfigDBStore::new(instance),
@@ -201,9 +209,18 @@ impl EventStore for MoveOSStore {
         event_handle_id: &ObjectID,
         cursor: Option<u64>,
         limit: u64,
+        descending_order: bool,
+        from_seq: Option<u64>,
+        to_seq: Option<u64>,
     ) -> Result<Vec<Event>> {
-        self.get_event_store()
-            .get_events_by_event_handle_id(event_handle_id, cursor, limit)
+        self.get_event_store().get_events_by_event_handle_id(
+            event_handle_id,
+            cursor,
+            limit,
+            descending_order,
+            from_seq,
+            to_seq,
+        )
     }
 
     fn get_events_by_event_handle_type(
@@ -211,9 +228,18 @@ impl EventStore for MoveOSStore {
         event_handle_type: &StructTag,
         cursor: Option<u64>,
         limit: u64,
+        descending_order: bool,
+        from_seq: Option<u64>,
+        to_seq: Option<u64>,
     ) -> Result<Vec<Event>> {
-        self.get_event_store()
-            .get_events_by_event_handle_type(event_handle_type, cursor, limit)
+        self.get_event_store().get_events_by_event_handle_type(
+            event_handle_type,
+            cursor,
+            limit,
+            descending_order,
+            from_seq,
+            to_seq,
+        )
     }
 }
 
@@ -1,30 +1,166 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
 
+pub mod pruner;
 pub mod statedb;
 
 use anyhow::Result;
+use lru::LruCache;
+use moveos_config::store_config::DEFAULT_STATE_NODE_CACHE_SIZE;
 use moveos_types::h256::H256;
-use raw_store::{CodecKVStore, CodecWriteBatch};
+use parking_lot::{Mutex, RwLock};
+use raw_store::{
+    CodecKVStore, CodecWriteBatch, ColumnFamily, ColumnFamilyName, InnerStore, SchemaStore,
+    StoreInstance,
+};
 use smt::NodeStore;
 use std::collections::BTreeMap;
+use std::num::NonZeroUsize;
+use std::sync::Arc;
 
 use crate::STATE_NODE_PREFIX_NAME;
-use raw_store::derive_store;
 
-derive_store!(NodeDBStore, H256, Vec<u8>, STATE_NODE_PREFIX_NAME);
+/// The store of the state tree nodes, with a LRU cache of the encoded nodes shared by all the clones of the store.
+/// The nodes are content-addressed, so a cached node is never stale, it only needs to be evicted when the node is deleted.
+#[derive(Clone)]
+pub struct NodeDBStore {
+    store: InnerStore<Self>,
+    cache: Option<Arc<Mutex<LruCache<H256, Vec<u8>>>>>,
+}
+
+impl NodeDBStore {
+    pub fn new(instance: StoreInstance) -> Self {
+        Self::new_with_cache_size(instance, DEFAULT_STATE_NODE_CACHE_SIZE)
+    }
+
+    /// Create the store with a node cache of `cache_size` nodes, the cache is disabled if the `cache_size` is 0
+    pub fn new_with_cache_size(instance: StoreInstance, cache_size: usize) -> Self {
+        Self {
+            store: InnerStore::new(instance),
+            cache: NonZeroUsize::new(cache_size)
+                .map(|size| Arc::new(Mutex::new(LruCache::new(size)))),
+        }
+    }
+
+    /// Delete the nodes from the db and the cache
+    pub fn delete_nodes(&self, hashes: Vec<H256>) -> Result<()> {
+        if let Some(cache) = &self.cache {
+            let mut cache = cache.lock();
+            for hash in hashes.iter() {
+                cache.pop(hash);
+            }
+        }
+        self.write_batch(CodecWriteBatch::new_deletes(hashes))
+    }
+
+    fn cache_nodes<'a, I: IntoIterator<Item = (&'a H256, &'a Vec<u8>)>>(&self, nodes: I) {
+        if let Some(cache) = &self.cache {
+            let mut cache = cache.lock();
+            for (hash, node) in nodes {
+                cache.put(*hash, node.clone());
+            }
+        }
+    }
+}
+
+impl ColumnFamily for NodeDBStore {
+    type Key = H256;
+    type Value = Vec<u8>;
+
+    fn name() -> ColumnFamilyName {
+        STATE_NODE_PREFIX_NAME
+    }
+}
+
+impl SchemaStore for NodeDBStore {
+    fn get_store(&self) -> &InnerStore<Self> {
+        &self.store
+    }
+}
 
 impl NodeStore for NodeDBStore {
     fn get(&self, hash: &H256) -> Result<Option<Vec<u8>>> {
-        self.kv_get(*hash)
+        if let Some(node) = self
+            .cache
+            .as_ref()
+            .and_then(|cache| cache.lock().get(hash).cloned())
+        {
+            return Ok(Some(node));
+        }
+        let node = self.kv_get(*hash)?;
+        if let Some(node) = &node {
+            self.cache_nodes([(hash, node)]);
+        }
+        Ok(node)
+    }
+
+    fn put(&self, key: H256, node: Vec<u8>) -> Result<()> {
+        self.kv_put(key, node.clone())?;
+        self.cache_nodes([(&key, &node)]);
+        Ok(())
+    }
+
+    fn write_nodes(&self, nodes: BTreeMap<H256, Vec<u8>>) -> Result<()> {
+        let batch = CodecWriteBatch::new_puts(
+            nodes
+                .iter()
+                .map(|(hash, node)| (*hash, node.clone()))
+                .collect(),
+        );
+        self.write_batch(batch)?;
+        self.cache_nodes(nodes.iter());
+        Ok(())
+    }
+}
+
+/// A NodeStore buffers the written nodes in memory, the buffered nodes are readable from the store,
+/// and are written to the underlying store in one batch by `commit`.
+/// It is used to apply all the tree updates of a transaction with one write batch.
+#[derive(Clone)]
+pub struct BatchNodeStore<NS> {
+    store: NS,
+    pending: Arc<RwLock<BTreeMap<H256, Vec<u8>>>>,
+}
+
+impl<NS> BatchNodeStore<NS>
+where
+    NS: NodeStore,
+{
+    pub fn new(store: NS) -> Self {
+        Self {
+            store,
+            pending: Arc::new(RwLock::new(BTreeMap::new())),
+        }
+    }
+
+    /// Write the buffered nodes to the underlying store
+    pub fn commit(&self) -> Result<()> {
+        let nodes = std::mem::take(&mut *self.pending.write());
+        if nodes.is_empty() {
+            return Ok(());
+        }
+        self.store.write_nodes(nodes)
+    }
+}
+
+impl<NS> NodeStore for BatchNodeStore<NS>
+where
+    NS: NodeStore,
+{
+    fn get(&self, hash: &H256) -> Result<Option<Vec<u8>>> {
+        match self.pending.read().get(hash) {
+            Some(node) => Ok(Some(node.clone())),
+            None => self.store.get(hash),
+        }
     }
 
     fn put(&self, key: H256, node: Vec<u8>) -> Result<()> {
-        self.kv_put(key, node)
+        self.pending.write().insert(key, node);
+        Ok(())
     }
 
     fn write_nodes(&self, nodes: BTreeMap<H256, Vec<u8>>) -> Result<()> {
-        let batch = CodecWriteBatch::new_puts(nodes.into_iter().collect());
-        self.write_batch(batch)
+        self.pending.write().extend(nodes);
+        Ok(())
     }
 }
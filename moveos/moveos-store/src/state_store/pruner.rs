@@ -0,0 +1,73 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::state_store::NodeDBStore;
+use anyhow::Result;
+use moveos_types::h256::H256;
+use moveos_types::state::{KeyState, State};
+use raw_store::CodecKVStore;
+use smt::{SMTree, SPARSE_MERKLE_PLACEHOLDER_HASH};
+use std::collections::HashSet;
+
+const DELETE_BATCH_SIZE: usize = 10000;
+
+/// The StatePruner removes the state tree nodes which are not reachable from the retained state roots.
+/// The nodes are content-addressed and shared between the state roots, so the pruner marks all the nodes
+/// reachable from the retained state roots, including the nested object trees, and sweeps the others.
+/// The pruner must not run concurrently with the state writer.
+pub struct StatePruner {
+    node_store: NodeDBStore,
+}
+
+impl StatePruner {
+    pub fn new(node_store: NodeDBStore) -> Self {
+        Self { node_store }
+    }
+
+    /// Prune the nodes which are not reachable from the `retained_roots`, return the number of deleted nodes.
+    pub fn prune(&self, retained_roots: Vec<H256>) -> Result<usize> {
+        // Snapshot the stored node keys before marking, so the nodes written after the snapshot are kept.
+        let stored_nodes = self.stored_nodes()?;
+
+        let mut reachable = HashSet::new();
+        for state_root in retained_roots {
+            self.mark(state_root, &mut reachable)?;
+        }
+
+        let mut deleted = 0;
+        let unreachable = stored_nodes
+            .into_iter()
+            .filter(|hash| !reachable.contains(hash))
+            .collect::<Vec<_>>();
+        for chunk in unreachable.chunks(DELETE_BATCH_SIZE) {
+            self.node_store.delete_nodes(chunk.to_vec())?;
+            deleted += chunk.len();
+        }
+        Ok(deleted)
+    }
+
+    fn stored_nodes(&self) -> Result<Vec<H256>> {
+        let mut iter = self.node_store.iter()?;
+        iter.seek_to_first();
+        iter.map(|item| item.map(|(hash, _)| hash)).collect()
+    }
+
+    /// Mark all the nodes reachable from the `state_root`, and the state trees of the objects in it.
+    fn mark(&self, state_root: H256, reachable: &mut HashSet<H256>) -> Result<()> {
+        let mut pending = vec![state_root];
+        while let Some(state_root) = pending.pop() {
+            let tree: SMTree<KeyState, State, NodeDBStore> =
+                SMTree::new(self.node_store.clone(), Some(state_root));
+            for state in tree.walk_new_nodes(reachable)? {
+                if !state.is_object() {
+                    continue;
+                }
+                let object_state_root = H256(state.as_raw_object()?.state_root.into());
+                if object_state_root != *SPARSE_MERKLE_PLACEHOLDER_HASH {
+                    pending.push(object_state_root);
+                }
+            }
+        }
+        Ok(())
+    }
+}
@@ -12,6 +12,7 @@ use moveos_types::move_types::as_struct_tag;
 use moveos_types::moveos_std::object_id::ObjectID;
 use moveos_types::state::MoveStructType;
 use moveos_types::state::{KeyState, TableState, TableStateSet};
+use moveos_types::state_proof::{StateProof, TableObjectProof};
 use moveos_types::state_resolver::StateKV;
 use moveos_types::{
     h256::H256,
@@ -28,10 +29,11 @@ use moveos_types::{
     state::StateChangeSet,
     state_resolver::{self, module_name_to_key, resource_tag_to_key, StateResolver},
 };
-use smt::{NodeStore, SMTIterator, SMTree, UpdateSet};
+use smt::{NodeStore, SMTIterator, SMTree, SparseMerkleProof, UpdateSet};
 use std::collections::BTreeMap;
 
-use crate::state_store::NodeDBStore;
+use crate::state_store::{BatchNodeStore, NodeDBStore};
+use rayon::prelude::*;
 
 #[derive(Clone)]
 pub struct TreeTable<NS> {
@@ -56,6 +58,10 @@ where
         self.smt.get(key)
     }
 
+    pub fn get_with_proof(&self, key: KeyState) -> Result<(Option<State>, SparseMerkleProof)> {
+        self.smt.get_with_proof(key)
+    }
+
     pub fn list(&self, cursor: Option<KeyState>, limit: usize) -> Result<Vec<StateKV>> {
         self.smt.list(cursor, limit)
     }
@@ -126,66 +132,30 @@ where
     }
 }
 
-/// StateDB provide state storage and state proof
-#[derive(Clone)]
-pub struct StateDBStore {
-    pub node_store: NodeDBStore,
-    global_table: TreeTable<NodeDBStore>,
-}
-
-impl StateDBStore {
-    pub fn new(node_store: NodeDBStore) -> Self {
-        Self {
-            node_store: node_store.clone(),
-            global_table: TreeTable::new(node_store),
-        }
-    }
-
-    pub fn new_with_root(node_store: NodeDBStore, state_root: Option<H256>) -> Self {
-        Self {
-            node_store: node_store.clone(),
-            global_table: TreeTable::new_with_root(node_store, state_root),
-        }
-    }
-
-    pub fn get(&self, id: ObjectID) -> Result<Option<State>> {
-        self.global_table.get(id.to_key())
-    }
-
-    pub fn list(&self, cursor: Option<KeyState>, limit: usize) -> Result<Vec<StateKV>> {
-        self.global_table.list(cursor, limit)
-    }
-
-    pub fn get_as_object<T: MoveStructState>(
-        &self,
-        id: ObjectID,
-    ) -> Result<Option<ObjectEntity<T>>> {
-        self.get(id)?
+impl<NS> TreeTable<NS>
+where
+    NS: NodeStore + Clone,
+{
+    fn get_as_object<T: MoveStructState>(&self, id: ObjectID) -> Result<Option<ObjectEntity<T>>> {
+        self.get(id.to_key())?
             .map(|state| state.as_object::<T>())
             .transpose()
             .map_err(Into::into)
     }
 
-    pub fn get_as_raw_object(&self, id: ObjectID) -> Result<Option<RawObject>> {
-        self.get(id)?
+    fn get_as_raw_object(&self, id: ObjectID) -> Result<Option<RawObject>> {
+        self.get(id.to_key())?
             .map(|state| state.as_raw_object())
             .transpose()
             .map_err(Into::into)
     }
 
-    fn get_as_account_storage(
-        &self,
-        account: AccountAddress,
-    ) -> Result<Option<ObjectEntity<AccountStorage>>> {
-        self.get_as_object::<AccountStorage>(account.into())
-    }
-
     fn get_as_account_storage_or_create(
         &self,
         account: AccountAddress,
     ) -> Result<ObjectEntity<AccountStorage>> {
         let account_storage = self
-            .get_as_account_storage(account)?
+            .get_as_object::<AccountStorage>(account.into())?
             .unwrap_or_else(|| ObjectEntity::new_account_storage_object(account));
 
         // Resource table key type tag: std::ascii::String
@@ -197,7 +167,8 @@ impl StateDBStore {
         Ok(account_storage)
     }
 
-    fn get_as_table(&self, id: ObjectID) -> Result<Option<(RawObject, TreeTable<NodeDBStore>)>> {
+    /// Get the object `id` in this table and the state tree of the object, the tree shares the node store with this table
+    fn get_as_table(&self, id: ObjectID) -> Result<Option<(RawObject, TreeTable<NS>)>> {
         let object = self.get_as_raw_object(id)?;
         match object {
             Some(object) => {
@@ -205,7 +176,7 @@ impl StateDBStore {
                 Ok(Some((
                     object,
                     TreeTable::new_with_root(
-                        self.node_store.clone(),
+                        self.smt.node_store().clone(),
                         Some(H256(state_root.into())),
                     ),
                 )))
@@ -214,25 +185,106 @@ impl StateDBStore {
         }
     }
 
-    fn get_as_table_or_create(&self, id: ObjectID) -> Result<(RawObject, TreeTable<NodeDBStore>)> {
-        Ok(self.get_as_table(id)?.unwrap_or_else(|| {
+    fn get_as_table_or_create(&self, id: ObjectID) -> Result<(RawObject, TreeTable<NS>)> {
+        Ok(self.global_table.get_as_table(id)?.unwrap_or_else(|| {
             self.create_table(id)
                 .expect("create_table should succ when get_as_table_or_create")
         }))
     }
 
-    fn create_table(&self, id: ObjectID) -> Result<(RawObject, TreeTable<NodeDBStore>)> {
-        let table = TreeTable::new(self.node_store.clone());
+    fn create_table(&self, id: ObjectID) -> Result<(RawObject, TreeTable<NS>)> {
+        let table = TreeTable::new(self.smt.node_store().clone());
         let table_info = TableInfo::new(AccountAddress::new(table.state_root().into()))?;
         let object = ObjectEntity::new_table_object(id, table_info).to_raw();
         Ok((object, table))
     }
+}
+
+/// StateDB provide state storage and state proof
+#[derive(Clone)]
+pub struct StateDBStore {
+    pub node_store: NodeDBStore,
+    global_table: TreeTable<NodeDBStore>,
+}
+
+impl StateDBStore {
+    pub fn new(node_store: NodeDBStore) -> Self {
+        Self {
+            node_store: node_store.clone(),
+            global_table: TreeTable::new(node_store),
+        }
+    }
+
+    pub fn new_with_root(node_store: NodeDBStore, state_root: Option<H256>) -> Self {
+        Self {
+            node_store: node_store.clone(),
+            global_table: TreeTable::new_with_root(node_store, state_root),
+        }
+    }
+
+    pub fn get(&self, id: ObjectID) -> Result<Option<State>> {
+        self.global_table.get(id.to_key())
+    }
+
+    pub fn list(&self, cursor: Option<KeyState>, limit: usize) -> Result<Vec<StateKV>> {
+        self.global_table.list(cursor, limit)
+    }
+
+    pub fn get_as_object<T: MoveStructState>(
+        &self,
+        id: ObjectID,
+    ) -> Result<Option<ObjectEntity<T>>> {
+        self.global_table.get_as_object(id)
+    }
+
+    pub fn get_as_raw_object(&self, id: ObjectID) -> Result<Option<RawObject>> {
+        self.global_table.get_as_raw_object(id)
+    }
 
     pub fn get_with_key(&self, id: ObjectID, key: KeyState) -> Result<Option<State>> {
-        self.get_as_table(id)
+        self.global_table
+            .get_as_table(id)
             .and_then(|res| res.map(|(_, table)| table.get(key)).unwrap_or(Ok(None)))
     }
 
+    /// Get the state of the key in the table `handle` and the proof against the global state root
+    pub fn get_with_proof(
+        &self,
+        handle: ObjectID,
+        key: KeyState,
+    ) -> Result<(Option<State>, StateProof)> {
+        if handle == state_resolver::GLOBAL_OBJECT_STORAGE_HANDLE {
+            let (state, proof) = self.global_table.get_with_proof(key)?;
+            return Ok((
+                state,
+                StateProof {
+                    table_object: None,
+                    proof,
+                },
+            ));
+        }
+
+        let (object, object_proof) = self.global_table.get_with_proof(handle.to_key())?;
+        let (state, proof) = match &object {
+            Some(object) => {
+                let state_root = object.as_raw_object()?.state_root;
+                TreeTable::new_with_root(self.node_store.clone(), Some(H256(state_root.into())))
+                    .get_with_proof(key)?
+            }
+            None => (None, SparseMerkleProof::default()),
+        };
+        Ok((
+            state,
+            StateProof {
+                table_object: Some(TableObjectProof {
+                    object,
+                    proof: object_proof,
+                }),
+                proof,
+            },
+        ))
+    }
+
     pub fn list_with_key(
         &self,
         id: ObjectID,
@@ -245,18 +297,23 @@ impl StateDBStore {
         table.list(cursor, limit)
     }
 
+    /// Apply the change set to the state trees and return the new global state root.
+    /// The changed tables are updated concurrently, and all the new tree nodes are written in one batch.
     pub fn apply_change_set(
         &self,
         change_set: ChangeSet,
         state_change_set: StateChangeSet,
     ) -> Result<H256> {
+        let node_store = BatchNodeStore::new(self.node_store.clone());
+        let global_table = TreeTable::new_with_root(node_store.clone(), Some(self.state_root()));
+
         let mut changed_objects = UpdateSet::new();
         //TODO
         //We want deprecate the global storage instructions https://github.com/rooch-network/rooch/issues/248
         //So the ChangeSet should be empty, but we need the mutated accounts to init the account storage
         ////We need to figure out a way to init a fresh account.
         for (account, account_change_set) in change_set.into_inner() {
-            let account_storage = self.get_as_account_storage_or_create(account)?;
+            let account_storage = global_table.get_as_account_storage_or_create(account)?;
 
             let (modules, resources) = account_change_set.into_inner();
             debug_assert!(modules.is_empty() && resources.is_empty());
@@ -264,29 +321,46 @@ impl StateDBStore {
             changed_objects.put(ObjectID::from(account).to_key(), account_storage.into())
         }
 
-        for (table_handle, table_change) in state_change_set.changes {
-            // handle global object
-            if table_handle == context::GLOBAL_OBJECT_STORAGE_HANDLE {
-                self.global_table
-                    .put_changes(table_change.entries.into_iter())?;
-                // TODO: do we need to update the size of global table?
-            } else {
-                let (mut raw_object, table) = self.get_as_table_or_create(table_handle)?;
-                let new_state_root = table.put_changes(table_change.entries.into_iter())?;
-                raw_object.state_root = AccountAddress::new(new_state_root.into());
-                let curr_table_size: i64 = raw_object.size as i64;
-                let updated_table_size = curr_table_size + table_change.size_increment;
-                debug_assert!(updated_table_size >= 0);
-                raw_object.size = updated_table_size as u64;
-                changed_objects.put(table_handle.to_key(), raw_object.into_state()?);
-            }
+        let (global_changes, table_changes): (Vec<_>, Vec<_>) = state_change_set
+            .changes
+            .into_iter()
+            .partition(|(table_handle, _)| *table_handle == context::GLOBAL_OBJECT_STORAGE_HANDLE);
+
+        // handle global object
+        for (_, table_change) in global_changes {
+            global_table.put_changes(table_change.entries.into_iter())?;
+            // TODO: do we need to update the size of global table?
+        }
+
+        // The tables are independent trees, so they are updated concurrently
+        let changed_tables = table_changes
+            .into_par_iter()
+            .map(
+                |(table_handle, table_change)| -> Result<(KeyState, State)> {
+                    let (mut raw_object, table) =
+                        global_table.get_as_table_or_create(table_handle)?;
+                    let new_state_root = table.put_changes(table_change.entries.into_iter())?;
+                    raw_object.state_root = AccountAddress::new(new_state_root.into());
+                    let curr_table_size: i64 = raw_object.size as i64;
+                    let updated_table_size = curr_table_size + table_change.size_increment;
+                    debug_assert!(updated_table_size >= 0);
+                    raw_object.size = updated_table_size as u64;
+                    Ok((table_handle.to_key(), raw_object.into_state()?))
+                },
+            )
+            .collect::<Result<Vec<_>>>()?;
+        for (key, state) in changed_tables {
+            changed_objects.put(key, state);
         }
 
         for table_handle in state_change_set.removed_tables {
             changed_objects.remove(table_handle.to_key());
         }
 
-        self.global_table.puts(changed_objects)
+        let new_state_root = global_table.puts(changed_objects)?;
+        node_store.commit()?;
+        self.global_table.update_state_root(new_state_root)?;
+        Ok(new_state_root)
     }
 
     pub fn is_genesis(&self) -> bool {
@@ -332,7 +406,7 @@ impl StateDBStore {
                 state_root = self.global_table.puts(v.entries)?
             } else {
                 // must force create table
-                let (_table_object, table_store) = self.create_table(k)?;
+                let (_table_object, table_store) = self.global_table.create_table(k)?;
                 state_root = table_store.puts(v.entries)?
             }
         }
@@ -346,7 +420,7 @@ impl StateDBStore {
     //     if handle == &state_resolver::GLOBAL_OBJECT_STORAGE_HANDLE {
     //         self.global_table.iter().map(|v| Some(v))
     //     } else {
-    //         self.get_as_table(*handle)
+    //         self.global_table.get_as_table(*handle)
     //             .and_then(|res| res.map_or(Ok(None), |(_, table)| table.iter().map(|v| Some(v))))
     //     }
     // }
@@ -363,7 +437,7 @@ impl StateDBStore {
             if ObjectID::struct_tag_match(&as_struct_tag(key.key_type.clone())?) {
                 let mut table_state = TableState::default();
                 let table_handle = ObjectID::from_bytes(key.key.clone())?;
-                let result = self.get_as_table(table_handle)?;
+                let result = self.global_table.get_as_table(table_handle)?;
                 if result.is_none() {
                     continue;
                 };
@@ -386,6 +460,10 @@ impl StateDBStore {
         Ok(table_state_set)
     }
 
+    pub fn state_root(&self) -> H256 {
+        self.global_table.state_root()
+    }
+
     // update global table state root
     pub fn update_state_root(&self, new_state_root: H256) -> Result<()> {
         self.global_table.update_state_root(new_state_root)?;
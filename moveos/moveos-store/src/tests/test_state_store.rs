@@ -1,6 +1,8 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
 
+use crate::state_store::pruner::StatePruner;
+use crate::state_store::statedb::StateDBStore;
 use crate::MoveOSStore;
 use anyhow::Result;
 use move_core_types::account_address::AccountAddress;
@@ -198,3 +200,145 @@ fn test_state_db_dump_and_apply() -> Result<()> {
     assert_eq!(global_state_set, global_state_set2);
     Ok(())
 }
+
+#[test]
+fn test_statedb_get_with_proof() -> Result<()> {
+    let moveos_store = MoveOSStore::mock_moveos_store().expect("moveos store mock should succ");
+
+    let mut table_change_set = StateChangeSet::default();
+    let table_handle = ObjectID::ONE;
+    let mut table_change = TableChange::default();
+    let key = KeyState::new(
+        MoveString::from_str("test_key").unwrap().to_bytes(),
+        MoveString::type_tag(),
+    );
+    let value: State = MoveString::from_str("test_value").unwrap().into();
+    table_change
+        .entries
+        .insert(key.clone(), Op::New(value.clone()));
+    table_change_set.changes.insert(table_handle, table_change);
+    let state_root = moveos_store
+        .get_state_store()
+        .apply_change_set(ChangeSet::new(), table_change_set)?;
+
+    let (state, proof) = moveos_store
+        .get_state_store()
+        .get_with_proof(table_handle, key.clone())?;
+    assert_eq!(state, Some(value.clone()));
+    proof.verify(state_root, table_handle, key.clone(), state)?;
+
+    let fake_value: State = MoveString::from_str("fake_value").unwrap().into();
+    assert!(proof
+        .verify(state_root, table_handle, key.clone(), Some(fake_value))
+        .is_err());
+    assert!(proof
+        .verify(H256::random(), table_handle, key, Some(value))
+        .is_err());
+
+    let not_exist_key = KeyState::new(
+        MoveString::from_str("not_exist_key").unwrap().to_bytes(),
+        MoveString::type_tag(),
+    );
+    let (state, proof) = moveos_store
+        .get_state_store()
+        .get_with_proof(table_handle, not_exist_key.clone())?;
+    assert!(state.is_none());
+    proof.verify(state_root, table_handle, not_exist_key, None)?;
+    Ok(())
+}
+
+#[test]
+fn test_state_pruner() -> Result<()> {
+    let moveos_store = MoveOSStore::mock_moveos_store().expect("moveos store mock should succ");
+    let table_handle = ObjectID::ONE;
+    let key = KeyState::new(
+        MoveString::from_str("test_key").unwrap().to_bytes(),
+        MoveString::type_tag(),
+    );
+
+    let mut state_roots = vec![];
+    for i in 0..3 {
+        let mut table_change_set = StateChangeSet::default();
+        let mut table_change = TableChange::default();
+        let value: State = MoveString::from_str(format!("test_value{}", i).as_str())
+            .unwrap()
+            .into();
+        table_change.entries.insert(key.clone(), Op::New(value));
+        table_change_set.changes.insert(table_handle, table_change);
+        state_roots.push(
+            moveos_store
+                .get_state_store()
+                .apply_change_set(ChangeSet::new(), table_change_set)?,
+        );
+    }
+
+    let pruner = StatePruner::new(moveos_store.get_state_node_store().clone());
+    let retained_roots = state_roots[1..].to_vec();
+    let deleted = pruner.prune(retained_roots.clone())?;
+    assert!(deleted > 0);
+    // Prune again with the same roots, nothing to delete
+    assert_eq!(pruner.prune(retained_roots)?, 0);
+
+    for (i, state_root) in state_roots.into_iter().enumerate() {
+        let statedb = StateDBStore::new_with_root(
+            moveos_store.get_state_node_store().clone(),
+            Some(state_root),
+        );
+        let state = statedb.get_with_key(table_handle, key.clone());
+        if i == 0 {
+            assert!(state.is_err());
+        } else {
+            let expect: State = MoveString::from_str(format!("test_value{}", i).as_str())
+                .unwrap()
+                .into();
+            assert_eq!(state?, Some(expect));
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn test_statedb_apply_multi_tables() -> Result<()> {
+    let moveos_store = MoveOSStore::mock_moveos_store().expect("moveos store mock should succ");
+
+    let mut state_change_set = StateChangeSet::default();
+    let mut expected = vec![];
+    for _n in 0..10 {
+        let table_handle = ObjectID::from(AccountAddress::random());
+        let table_change = random_table_change();
+        for (key, op) in table_change.entries.iter() {
+            if let Op::New(state) = op {
+                expected.push((table_handle, key.clone(), state.clone()));
+            }
+        }
+        state_change_set.changes.insert(table_handle, table_change);
+    }
+    let state_root = moveos_store
+        .get_state_store()
+        .apply_change_set(ChangeSet::new(), state_change_set)?;
+    assert_eq!(moveos_store.get_state_store().state_root(), state_root);
+
+    // Read the states from a new statedb, the nodes must be committed to the node store
+    let statedb = StateDBStore::new_with_root(
+        moveos_store.get_state_node_store().clone(),
+        Some(state_root),
+    );
+    for (table_handle, key, state) in expected {
+        assert_eq!(statedb.get_with_key(table_handle, key)?, Some(state));
+    }
+    Ok(())
+}
+
+#[test]
+fn test_node_cache_delete() -> Result<()> {
+    let moveos_store = MoveOSStore::mock_moveos_store().expect("moveos store mock should succ");
+    let node_store = moveos_store.get_state_node_store();
+
+    let key = H256::random();
+    let node = b"testnode".to_vec();
+    node_store.put(key, node.clone())?;
+    assert_eq!(node_store.get(&key)?, Some(node));
+    node_store.delete_nodes(vec![key])?;
+    assert_eq!(node_store.get(&key)?, None);
+    Ok(())
+}
@@ -9,7 +9,7 @@ use move_core_types::account_address::AccountAddress;
 use move_core_types::identifier::Identifier;
 use move_core_types::language_storage::{StructTag, TypeTag};
 use move_core_types::vm_status::KeptVMStatus;
-use moveos_config::store_config::RocksdbConfig;
+use moveos_config::store_config::{ColumnFamilyConfig, CompressionType, RocksdbConfig};
 use moveos_types::h256::H256;
 use moveos_types::moveos_std::event::TransactionEvent;
 use moveos_types::transaction::TransactionExecutionInfo;
@@ -74,6 +74,53 @@ fn test_open_read_only() {
     assert_eq!(result, Some(bcs::to_bytes(&value).unwrap()));
 }
 
+#[test]
+fn test_column_family_options() {
+    let tmpdir = moveos_config::temp_dir();
+    let cfs = vec![DEFAULT_PREFIX_NAME];
+    let mut rocksdb_config = RocksdbConfig {
+        column_family: "block_cache_size=1048576,bloom_filter_bits_per_key=10"
+            .parse()
+            .unwrap(),
+        ..Default::default()
+    };
+    rocksdb_config.column_families.insert(
+        DEFAULT_PREFIX_NAME.to_string(),
+        "compression=zstd,write_buffer_size=8388608"
+            .parse()
+            .unwrap(),
+    );
+    assert_eq!(
+        rocksdb_config.column_family_config(DEFAULT_PREFIX_NAME),
+        ColumnFamilyConfig {
+            block_cache_size: Some(1048576),
+            bloom_filter_bits_per_key: Some(10),
+            compression: Some(CompressionType::Zstd),
+            write_buffer_size: Some(8388608),
+        }
+    );
+    assert!("compression=gzip".parse::<ColumnFamilyConfig>().is_err());
+
+    let db = RocksDB::new(tmpdir.path(), cfs, rocksdb_config, None).unwrap();
+    let key = H256::random();
+    let value = H256::zero();
+    db.put(
+        DEFAULT_PREFIX_NAME,
+        bcs::to_bytes(&key).unwrap(),
+        bcs::to_bytes(&value).unwrap(),
+    )
+    .unwrap();
+    assert_eq!(
+        db.get(DEFAULT_PREFIX_NAME, bcs::to_bytes(&key).unwrap())
+            .unwrap(),
+        Some(bcs::to_bytes(&value).unwrap())
+    );
+    let num_keys = db.cf_property_values("rocksdb.estimate-num-keys");
+    assert_eq!(num_keys, vec![(DEFAULT_PREFIX_NAME, 1)]);
+    let stopped = db.cf_property_values("rocksdb.is-write-stopped");
+    assert_eq!(stopped, vec![(DEFAULT_PREFIX_NAME, 0)]);
+}
+
 #[test]
 fn test_store() {
     let store = MoveOSStore::mock_moveos_store().unwrap();
@@ -125,6 +172,50 @@ fn test_event_store() {
     assert_eq!(event1.event_id.event_seq, 1);
 }
 
+#[test]
+fn test_get_events_by_event_handle() {
+    let store = MoveOSStore::mock_moveos_store().unwrap();
+
+    let test_struct_tag = StructTag {
+        address: AccountAddress::random(),
+        module: Identifier::new("Module").unwrap(),
+        name: Identifier::new("Name").unwrap(),
+        type_params: vec![],
+    };
+    let tx_events = (0..5)
+        .map(|i| TransactionEvent::new(test_struct_tag.clone(), vec![i], i as u64))
+        .collect::<Vec<_>>();
+    store.save_events(tx_events).unwrap();
+
+    let event_seqs = |cursor, limit, descending_order, from_seq, to_seq| {
+        store
+            .get_events_by_event_handle_type(
+                &test_struct_tag,
+                cursor,
+                limit,
+                descending_order,
+                from_seq,
+                to_seq,
+            )
+            .unwrap()
+            .into_iter()
+            .map(|event| event.event_id.event_seq)
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(event_seqs(None, 10, false, None, None), vec![0, 1, 2, 3, 4]);
+    assert_eq!(event_seqs(Some(1), 2, false, None, None), vec![2, 3]);
+    assert_eq!(event_seqs(None, 2, true, None, None), vec![4, 3]);
+    assert_eq!(event_seqs(Some(3), 10, true, None, None), vec![2, 1, 0]);
+    assert_eq!(event_seqs(Some(0), 10, true, None, None), Vec::<u64>::new());
+    assert_eq!(event_seqs(None, 10, false, Some(1), Some(3)), vec![1, 2, 3]);
+    assert_eq!(event_seqs(None, 10, true, Some(1), Some(3)), vec![3, 2, 1]);
+    assert_eq!(event_seqs(Some(2), 10, true, Some(1), Some(100)), vec![1]);
+    assert_eq!(
+        event_seqs(None, 10, false, Some(5), None),
+        Vec::<u64>::new()
+    );
+}
+
 #[test]
 fn test_iter() {
     let store = MoveOSStore::mock_moveos_store().unwrap();
@@ -233,6 +233,14 @@ impl AccessPath {
         })
     }
 
+    /// All the modules published at the account, it is used to list the modules
+    pub fn modules_of(account: AccountAddress) -> Self {
+        AccessPath(Path::Module {
+            account,
+            module_names: None,
+        })
+    }
+
     pub fn table(table_handle: ObjectID, keys: Vec<KeyState>) -> Self {
         AccessPath(Path::Table {
             table_handle,
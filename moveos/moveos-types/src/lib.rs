@@ -14,5 +14,6 @@ pub mod moveos_std;
 pub mod serde;
 pub mod startup_info;
 pub mod state;
+pub mod state_proof;
 pub mod state_resolver;
 pub mod transaction;
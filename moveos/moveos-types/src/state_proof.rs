@@ -0,0 +1,72 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::h256::H256;
+use crate::moveos_std::object_id::ObjectID;
+use crate::state::{KeyState, State};
+use crate::state_resolver::GLOBAL_OBJECT_STORAGE_HANDLE;
+use anyhow::{ensure, Result};
+use serde::{Deserialize, Serialize};
+pub use smt::SparseMerkleProof;
+
+/// The table object and its proof in the global state tree.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TableObjectProof {
+    /// The table object state, `None` if the table object does not exist
+    pub object: Option<State>,
+    /// The proof of the table object in the global state tree
+    pub proof: SparseMerkleProof,
+}
+
+/// The proof of a state in the state tree.
+/// The global objects are proved directly by the global state root,
+/// the table items are proved by the table state root, and the table object is proved by the global state root.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StateProof {
+    /// The proof of the table object, `None` if the handle is the global object storage handle
+    pub table_object: Option<TableObjectProof>,
+    /// The proof of the key in the table state tree
+    pub proof: SparseMerkleProof,
+}
+
+impl StateProof {
+    /// Verify the `state` of `key` in the table `handle` with the trusted global `state_root`.
+    /// If `state` is `None`, verify the key does not exist.
+    pub fn verify(
+        &self,
+        state_root: H256,
+        handle: ObjectID,
+        key: KeyState,
+        state: Option<State>,
+    ) -> Result<()> {
+        if handle == GLOBAL_OBJECT_STORAGE_HANDLE {
+            ensure!(
+                self.table_object.is_none(),
+                "Unexpected table object proof for the global object storage"
+            );
+            return self.proof.verify(state_root, key, state);
+        }
+
+        let table_object = self
+            .table_object
+            .as_ref()
+            .ok_or_else(|| anyhow::anyhow!("Missing table object proof for table {}", handle))?;
+        table_object
+            .proof
+            .verify(state_root, handle.to_key(), table_object.object.clone())?;
+        match &table_object.object {
+            Some(object) => {
+                let table_state_root = H256(object.as_raw_object()?.state_root.into());
+                self.proof.verify(table_state_root, key, state)
+            }
+            None => {
+                ensure!(
+                    state.is_none(),
+                    "The table {} does not exist, but the state is provided",
+                    handle
+                );
+                Ok(())
+            }
+        }
+    }
+}
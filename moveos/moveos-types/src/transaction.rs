@@ -14,6 +14,7 @@ use move_core_types::{
     vm_status::KeptVMStatus,
 };
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::fmt::Display;
 
 #[cfg(any(test, feature = "fuzzing"))]
@@ -284,6 +285,45 @@ pub struct RawTransactionOutput {
     pub events: Vec<TransactionEvent>,
     pub gas_used: u64,
     pub is_upgrade: bool,
+    /// The gas breakdown of the execution, only collected if the gas profiling is enabled
+    pub gas_profile: Option<GasProfile>,
+}
+
+/// The breakdown of the gas charged by the gas meter in a transaction execution
+#[derive(Debug, Clone, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct GasProfile {
+    /// The instruction name -> the gas charged by the instructions, the native functions are not included
+    pub instructions: BTreeMap<String, u64>,
+    /// The native function `address::module::function` -> the gas charged by the native function
+    pub natives: BTreeMap<String, u64>,
+    /// The storage operation (`new`, `modify`, `delete`, `event`, `io_write`) -> the gas charged by the operations
+    pub storage: BTreeMap<String, u64>,
+}
+
+impl GasProfile {
+    pub fn add_instruction(&mut self, instruction: &str, gas: u64) {
+        Self::add(&mut self.instructions, instruction, gas);
+    }
+
+    pub fn add_native(&mut self, function: &str, gas: u64) {
+        Self::add(&mut self.natives, function, gas);
+    }
+
+    pub fn add_storage(&mut self, op: &str, gas: u64) {
+        Self::add(&mut self.storage, op, gas);
+    }
+
+    fn add(entries: &mut BTreeMap<String, u64>, key: &str, gas: u64) {
+        if gas == 0 {
+            return;
+        }
+        match entries.get_mut(key) {
+            Some(total) => *total = total.saturating_add(gas),
+            None => {
+                entries.insert(key.to_owned(), gas);
+            }
+        }
+    }
 }
 
 /// TransactionOutput is the execution result of a MoveOS transaction, and pack TransactionEvent to Event
@@ -370,9 +410,24 @@ impl TransactionExecutionInfo {
 
 #[cfg(test)]
 mod tests {
-    use super::MoveAction;
+    use super::{GasProfile, MoveAction};
     use proptest::prelude::*;
 
+    #[test]
+    fn test_gas_profile_add() {
+        let mut profile = GasProfile::default();
+        profile.add_instruction("Add", 3);
+        profile.add_instruction("Add", 4);
+        profile.add_instruction("Nop", 0);
+        profile.add_native("0x1::hash::sha3_256", 10);
+        profile.add_storage("new", u64::MAX);
+        profile.add_storage("new", 1);
+        assert_eq!(profile.instructions.get("Add"), Some(&7));
+        assert!(!profile.instructions.contains_key("Nop"));
+        assert_eq!(profile.natives.get("0x1::hash::sha3_256"), Some(&10));
+        assert_eq!(profile.storage.get("new"), Some(&u64::MAX));
+    }
+
     proptest! {
         #[test]
         fn test_move_action_bcs_serde(input in any::<MoveAction>()) {
@@ -25,7 +25,7 @@ use moveos_types::moveos_std::event::TransactionEvent;
 use moveos_types::moveos_std::object_id;
 use moveos_types::state::{MoveStructState, MoveStructType, StateChangeSet};
 use moveos_types::state_resolver::{AnnotatedStateReader, MoveOSResolver};
-use moveos_types::transaction::GasStatement;
+use moveos_types::transaction::{GasProfile, GasStatement};
 use once_cell::sync::Lazy;
 use serde::{Deserialize, Serialize};
 use std::cell::RefCell;
@@ -553,6 +553,11 @@ pub struct MoveOSGasMeter {
     instructions_executed: u64,
     instructions_next_tier_start: Option<u64>,
     instructions_current_tier_mult: u64,
+
+    // The gas breakdown of the execution, `None` if the profiling is disabled.
+    profile: Option<GasProfile>,
+    // The last called function, the gas of a native function is charged after it is called.
+    last_called_function: Option<String>,
 }
 
 impl MoveOSGasMeter {
@@ -587,6 +592,8 @@ impl MoveOSGasMeter {
             stack_height_next_tier_start,
             stack_size_next_tier_start,
             instructions_next_tier_start,
+            profile: None,
+            last_called_function: None,
         }
     }
 
@@ -612,9 +619,18 @@ impl MoveOSGasMeter {
             instructions_executed: 0,
             instructions_next_tier_start: None,
             instructions_current_tier_mult: 0,
+            profile: None,
+            last_called_function: None,
         }
     }
 
+    /// Collect the gas breakdown by the instructions, native functions and storage operations,
+    /// it is returned in the transaction output. The profiling slows down the execution,
+    /// so it is only enabled for the dry run.
+    pub fn enable_profiling(&mut self) {
+        self.profile = Some(GasProfile::default());
+    }
+
     pub fn push_stack(&mut self, pushes: u64) -> PartialVMResult<()> {
         match self.stack_height_current.checked_add(pushes) {
             // We should never hit this.
@@ -710,6 +726,28 @@ impl MoveOSGasMeter {
     pub fn set_metering(&mut self, enabled: bool) {
         self.charge = enabled;
     }
+
+    fn charge_instruction(&mut self, instruction: &str, cost: InternalGas) -> PartialVMResult<()> {
+        if self.charge {
+            if let Some(profile) = self.profile.as_mut() {
+                profile.add_instruction(instruction, cost.into());
+            }
+        }
+        self.charge_v1(cost)
+    }
+
+    fn record_call(&mut self, module_id: &ModuleId, func_name: &str) {
+        if self.profile.is_some() {
+            self.last_called_function =
+                Some(format!("{}::{}", module_id.short_str_lossless(), func_name));
+        }
+    }
+
+    fn record_storage(&mut self, op: &str, fee: u64) {
+        if let Some(profile) = self.profile.as_mut() {
+            profile.add_storage(op, fee);
+        }
+    }
 }
 
 pub trait ClassifiedGasMeter {
@@ -720,6 +758,7 @@ pub trait ClassifiedGasMeter {
     fn charge_change_set(&mut self, change_set: &StateChangeSet) -> PartialVMResult<()>;
     fn check_constrains(&self, max_gas_amount: u64) -> PartialVMResult<()>;
     fn gas_statement(&self) -> GasStatement;
+    fn gas_profile(&self) -> Option<GasProfile>;
 }
 
 impl ClassifiedGasMeter for MoveOSGasMeter {
@@ -750,6 +789,7 @@ impl ClassifiedGasMeter for MoveOSGasMeter {
             * data_size;
         let new_value = self.storage_gas_used.borrow().add(InternalGas::from(fee));
         *self.storage_gas_used.borrow_mut() = new_value;
+        self.record_storage("io_write", fee);
         self.deduct_gas(InternalGas::from(fee))
     }
 
@@ -769,6 +809,7 @@ impl ClassifiedGasMeter for MoveOSGasMeter {
             *self.storage_gas_used.borrow_mut() = new_value;
             total_event_fee += fee;
         }
+        self.record_storage("event", total_event_fee);
         self.deduct_gas(InternalGas::from(total_event_fee))
     }
 
@@ -780,31 +821,35 @@ impl ClassifiedGasMeter for MoveOSGasMeter {
         let mut total_change_set_fee = 0;
         for (_, table_change) in change_set.changes.iter() {
             for (key, op) in table_change.entries.iter() {
-                let fee = {
+                let (op_name, fee) = {
                     match op {
-                        Op::Modify(value) => {
+                        Op::Modify(value) => (
+                            "modify",
                             (key.key.len() + value.value.len()) as u64
                                 * self
                                     .cost_table
                                     .storage_gas_parameter
-                                    .storage_fee_per_op_modify_byte
-                        }
-                        Op::Delete => {
+                                    .storage_fee_per_op_modify_byte,
+                        ),
+                        Op::Delete => (
+                            "delete",
                             self.cost_table
                                 .storage_gas_parameter
-                                .storage_fee_per_op_delete
-                        }
-                        Op::New(value) => {
+                                .storage_fee_per_op_delete,
+                        ),
+                        Op::New(value) => (
+                            "new",
                             (key.key.len() + value.value.len()) as u64
                                 * self
                                     .cost_table
                                     .storage_gas_parameter
-                                    .storage_fee_per_op_new_byte
-                        }
+                                    .storage_fee_per_op_new_byte,
+                        ),
                     }
                 };
                 let new_value = self.storage_gas_used.borrow().add(InternalGas::from(fee));
                 *self.storage_gas_used.borrow_mut() = new_value;
+                self.record_storage(op_name, fee);
                 total_change_set_fee += fee;
             }
         }
@@ -836,6 +881,10 @@ impl ClassifiedGasMeter for MoveOSGasMeter {
             storage_gas_used: *self.storage_gas_used.borrow(),
         }
     }
+
+    fn gas_profile(&self) -> Option<GasProfile> {
+        self.profile.clone()
+    }
 }
 
 impl GasMeter for MoveOSGasMeter {
@@ -847,7 +896,7 @@ impl GasMeter for MoveOSGasMeter {
         macro_rules! dispatch {
             ($($name: ident => $cost: expr),* $(,)?) => {
                 match instr {
-                    $(SimpleInstruction::$name => self.deduct_gas($cost)),*
+                    $(SimpleInstruction::$name => self.charge_instruction(stringify!($name), $cost)),*
                 }
             };
         }
@@ -908,43 +957,48 @@ impl GasMeter for MoveOSGasMeter {
     }
 
     fn charge_br_true(&mut self, _target_offset: Option<CodeOffset>) -> PartialVMResult<()> {
-        self.charge_v1(self.cost_table.instruction_gas_parameter.br_true)
+        self.charge_instruction("BrTrue", self.cost_table.instruction_gas_parameter.br_true)
     }
 
     fn charge_br_false(&mut self, _target_offset: Option<CodeOffset>) -> PartialVMResult<()> {
-        self.charge_v1(self.cost_table.instruction_gas_parameter.br_false)
+        self.charge_instruction(
+            "BrFalse",
+            self.cost_table.instruction_gas_parameter.br_false,
+        )
     }
 
     fn charge_branch(&mut self, _target_offset: CodeOffset) -> PartialVMResult<()> {
-        self.charge_v1(self.cost_table.instruction_gas_parameter.branch)
+        self.charge_instruction("Branch", self.cost_table.instruction_gas_parameter.branch)
     }
 
     fn charge_pop(&mut self, _popped_val: impl ValueView) -> PartialVMResult<()> {
-        self.charge_v1(self.cost_table.instruction_gas_parameter.pop)
+        self.charge_instruction("Pop", self.cost_table.instruction_gas_parameter.pop)
     }
 
     fn charge_call(
         &mut self,
-        _module_id: &ModuleId,
-        _func_name: &str,
+        module_id: &ModuleId,
+        func_name: &str,
         args: impl ExactSizeIterator<Item = impl ValueView>,
         num_locals: NumArgs,
     ) -> PartialVMResult<()> {
+        self.record_call(module_id, func_name);
         let call_base = self.cost_table.instruction_gas_parameter.call_base;
         let call_per_arg = self.cost_table.instruction_gas_parameter.call_per_arg;
         let cost = call_base + call_per_arg * NumArgs::new(args.len() as u64);
         let call_per_local = self.cost_table.instruction_gas_parameter.call_per_local;
-        self.charge_v1(cost + call_per_local * num_locals)
+        self.charge_instruction("Call", cost + call_per_local * num_locals)
     }
 
     fn charge_call_generic(
         &mut self,
-        _module_id: &ModuleId,
-        _func_name: &str,
+        module_id: &ModuleId,
+        func_name: &str,
         ty_args: impl ExactSizeIterator<Item = impl TypeView>,
         args: impl ExactSizeIterator<Item = impl ValueView>,
         num_locals: NumArgs,
     ) -> PartialVMResult<()> {
+        self.record_call(module_id, func_name);
         let call_generic_base = self.cost_table.instruction_gas_parameter.call_generic_base;
         let call_generic_per_type_arg = self
             .cost_table
@@ -964,13 +1018,13 @@ impl GasMeter for MoveOSGasMeter {
             .instruction_gas_parameter
             .call_generic_per_local;
 
-        self.charge_v1(cost + call_generic_per_local * num_locals)
+        self.charge_instruction("CallGeneric", cost + call_generic_per_local * num_locals)
     }
 
     fn charge_ld_const(&mut self, size: NumBytes) -> PartialVMResult<()> {
         let ld_const_base = self.cost_table.instruction_gas_parameter.ld_const_base;
         let ld_const_per_byte = self.cost_table.instruction_gas_parameter.ld_const_per_byte;
-        self.charge_v1(ld_const_base + ld_const_per_byte * size)
+        self.charge_instruction("LdConst", ld_const_base + ld_const_per_byte * size)
     }
 
     fn charge_ld_const_after_deserialization(
@@ -993,17 +1047,20 @@ impl GasMeter for MoveOSGasMeter {
             .instruction_gas_parameter
             .copy_loc_per_abs_val_unit;
 
-        self.charge_v1(copy_loc_base + copy_loc_per_abs_val_unit * (stack_size + heap_size))
+        self.charge_instruction(
+            "CopyLoc",
+            copy_loc_base + copy_loc_per_abs_val_unit * (stack_size + heap_size),
+        )
     }
 
     fn charge_move_loc(&mut self, _val: impl ValueView) -> PartialVMResult<()> {
         let move_local_base = self.cost_table.instruction_gas_parameter.move_loc_base;
-        self.charge_v1(move_local_base)
+        self.charge_instruction("MoveLoc", move_local_base)
     }
 
     fn charge_store_loc(&mut self, _val: impl ValueView) -> PartialVMResult<()> {
         let store_local_base = self.cost_table.instruction_gas_parameter.st_loc_base;
-        self.charge_v1(store_local_base)
+        self.charge_instruction("StoreLoc", store_local_base)
     }
 
     fn charge_pack(
@@ -1017,7 +1074,7 @@ impl GasMeter for MoveOSGasMeter {
             false => {
                 let pack_base = self.cost_table.instruction_gas_parameter.pack_base;
                 let pack_per_field = self.cost_table.instruction_gas_parameter.pack_per_field;
-                self.charge_v1(pack_base + pack_per_field * num_args)
+                self.charge_instruction("Pack", pack_base + pack_per_field * num_args)
             }
             true => {
                 let pack_generic_base = self.cost_table.instruction_gas_parameter.pack_generic_base;
@@ -1025,7 +1082,10 @@ impl GasMeter for MoveOSGasMeter {
                     .cost_table
                     .instruction_gas_parameter
                     .pack_generic_per_field;
-                self.charge_v1(pack_generic_base + pack_generic_per_field * num_args)
+                self.charge_instruction(
+                    "Pack",
+                    pack_generic_base + pack_generic_per_field * num_args,
+                )
             }
         }
     }
@@ -1041,7 +1101,7 @@ impl GasMeter for MoveOSGasMeter {
             false => {
                 let unpack_base = self.cost_table.instruction_gas_parameter.unpack_base;
                 let unpack_per_field = self.cost_table.instruction_gas_parameter.unpack_per_field;
-                self.charge_v1(unpack_base + unpack_per_field * num_args)
+                self.charge_instruction("Unpack", unpack_base + unpack_per_field * num_args)
             }
             true => {
                 let unpack_generic_base = self
@@ -1052,7 +1112,10 @@ impl GasMeter for MoveOSGasMeter {
                     .cost_table
                     .instruction_gas_parameter
                     .unpack_generic_per_field;
-                self.charge_v1(unpack_generic_base + unpack_generic_per_field * num_args)
+                self.charge_instruction(
+                    "Unpack",
+                    unpack_generic_base + unpack_generic_per_field * num_args,
+                )
             }
         }
     }
@@ -1069,7 +1132,10 @@ impl GasMeter for MoveOSGasMeter {
             .instruction_gas_parameter
             .read_ref_per_abs_val_unit;
 
-        self.charge_v1(read_ref_base + read_ref_per_abs_val_unit * (stack_size + heap_size))
+        self.charge_instruction(
+            "ReadRef",
+            read_ref_base + read_ref_per_abs_val_unit * (stack_size + heap_size),
+        )
     }
 
     fn charge_write_ref(
@@ -1079,7 +1145,7 @@ impl GasMeter for MoveOSGasMeter {
     ) -> PartialVMResult<()> {
         let write_ref_base = self.cost_table.instruction_gas_parameter.write_ref_base;
 
-        self.charge_v1(write_ref_base)
+        self.charge_instruction("WriteRef", write_ref_base)
     }
 
     fn charge_eq(&mut self, lhs: impl ValueView, rhs: impl ValueView) -> PartialVMResult<()> {
@@ -1100,7 +1166,7 @@ impl GasMeter for MoveOSGasMeter {
 
         let cost = eq_base + eq_per_abs_val_unit * (lhs_abs_val_size + rhs_abs_val_size);
 
-        self.charge_v1(cost)
+        self.charge_instruction("Eq", cost)
     }
 
     fn charge_neq(&mut self, lhs: impl ValueView, rhs: impl ValueView) -> PartialVMResult<()> {
@@ -1121,7 +1187,7 @@ impl GasMeter for MoveOSGasMeter {
 
         let cost = neq_base + neq_per_abs_val_unit * (lhs_abs_val_size + rhs_abs_val_size);
 
-        self.charge_v1(cost)
+        self.charge_instruction("Neq", cost)
     }
 
     fn charge_borrow_global(
@@ -1148,10 +1214,12 @@ impl GasMeter for MoveOSGasMeter {
             .instruction_gas_parameter
             .mut_borrow_global_generic_base;
         match (is_mut, is_generic) {
-            (false, false) => self.charge_v1(imm_borrow_global_base),
-            (false, true) => self.charge_v1(imm_borrow_global_generic_base),
-            (true, false) => self.charge_v1(mut_borrow_global_base),
-            (true, true) => self.charge_v1(mut_borrow_global_generic_base),
+            (false, false) => self.charge_instruction("BorrowGlobal", imm_borrow_global_base),
+            (false, true) => {
+                self.charge_instruction("BorrowGlobal", imm_borrow_global_generic_base)
+            }
+            (true, false) => self.charge_instruction("BorrowGlobal", mut_borrow_global_base),
+            (true, true) => self.charge_instruction("BorrowGlobal", mut_borrow_global_generic_base),
         }
     }
 
@@ -1168,8 +1236,8 @@ impl GasMeter for MoveOSGasMeter {
             .exists_generic_base;
 
         match is_generic {
-            false => self.charge_v1(exists_base),
-            true => self.charge_v1(exists_generic_base),
+            false => self.charge_instruction("Exists", exists_base),
+            true => self.charge_instruction("Exists", exists_generic_base),
         }
     }
 
@@ -1186,8 +1254,8 @@ impl GasMeter for MoveOSGasMeter {
             .move_from_generic_base;
 
         match is_generic {
-            false => self.charge_v1(move_from_base),
-            true => self.charge_v1(move_from_generic_base),
+            false => self.charge_instruction("MoveFrom", move_from_base),
+            true => self.charge_instruction("MoveFrom", move_from_generic_base),
         }
     }
 
@@ -1205,8 +1273,8 @@ impl GasMeter for MoveOSGasMeter {
             .move_from_generic_base;
 
         match is_generic {
-            false => self.charge_v1(move_to_base),
-            true => self.charge_v1(move_to_generic_base),
+            false => self.charge_instruction("MoveTo", move_to_base),
+            true => self.charge_instruction("MoveTo", move_to_generic_base),
         }
     }
 
@@ -1220,11 +1288,14 @@ impl GasMeter for MoveOSGasMeter {
         let vec_pack_base = self.cost_table.instruction_gas_parameter.vec_pack_base;
         let vec_pack_per_elem = self.cost_table.instruction_gas_parameter.vec_pack_per_elem;
 
-        self.charge_v1(vec_pack_base + vec_pack_per_elem * num_args)
+        self.charge_instruction("VecPack", vec_pack_base + vec_pack_per_elem * num_args)
     }
 
     fn charge_vec_len(&mut self, _ty: impl TypeView) -> PartialVMResult<()> {
-        self.charge_v1(self.cost_table.instruction_gas_parameter.vec_len_base)
+        self.charge_instruction(
+            "VecLen",
+            self.cost_table.instruction_gas_parameter.vec_len_base,
+        )
     }
 
     fn charge_vec_borrow(
@@ -1243,8 +1314,8 @@ impl GasMeter for MoveOSGasMeter {
             .vec_mut_borrow_base;
 
         match is_mut {
-            false => self.charge_v1(vec_imm_borrow_base),
-            true => self.charge_v1(vec_mut_borrow_base),
+            false => self.charge_instruction("VecBorrow", vec_imm_borrow_base),
+            true => self.charge_instruction("VecBorrow", vec_mut_borrow_base),
         }
     }
 
@@ -1253,7 +1324,10 @@ impl GasMeter for MoveOSGasMeter {
         _ty: impl TypeView,
         _val: impl ValueView,
     ) -> PartialVMResult<()> {
-        self.charge_v1(self.cost_table.instruction_gas_parameter.vec_push_back_base)
+        self.charge_instruction(
+            "VecPushBack",
+            self.cost_table.instruction_gas_parameter.vec_push_back_base,
+        )
     }
 
     fn charge_vec_pop_back(
@@ -1261,7 +1335,10 @@ impl GasMeter for MoveOSGasMeter {
         _ty: impl TypeView,
         _val: Option<impl ValueView>,
     ) -> PartialVMResult<()> {
-        self.charge_v1(self.cost_table.instruction_gas_parameter.vec_pop_back_base)
+        self.charge_instruction(
+            "VecPopBack",
+            self.cost_table.instruction_gas_parameter.vec_pop_back_base,
+        )
     }
 
     fn charge_vec_unpack(
@@ -1276,11 +1353,17 @@ impl GasMeter for MoveOSGasMeter {
             .instruction_gas_parameter
             .vec_unpack_per_expected_elem;
 
-        self.charge_v1(vec_unpack_base + vec_unpack_per_expected_elem * expect_num_elements)
+        self.charge_instruction(
+            "VecUnpack",
+            vec_unpack_base + vec_unpack_per_expected_elem * expect_num_elements,
+        )
     }
 
     fn charge_vec_swap(&mut self, _ty: impl TypeView) -> PartialVMResult<()> {
-        self.charge_v1(self.cost_table.instruction_gas_parameter.vec_swap_base)
+        self.charge_instruction(
+            "VecSwap",
+            self.cost_table.instruction_gas_parameter.vec_swap_base,
+        )
     }
 
     fn charge_load_resource(
@@ -1299,6 +1382,12 @@ impl GasMeter for MoveOSGasMeter {
         amount: InternalGas,
         _ret_vals: Option<impl ExactSizeIterator<Item = impl ValueView>>,
     ) -> PartialVMResult<()> {
+        if self.charge {
+            if let Some(profile) = self.profile.as_mut() {
+                let function = self.last_called_function.as_deref().unwrap_or("unknown");
+                profile.add_native(function, amount.into());
+            }
+        }
         self.charge_v1(amount)
     }
 
@@ -230,6 +230,22 @@ impl MoveOS {
     }
 
     pub fn execute(&self, tx: VerifiedMoveOSTransaction) -> Result<RawTransactionOutput> {
+        self.execute_inner(tx, false)
+    }
+
+    /// Execute the transaction and collect the gas breakdown in the output, it is used by the dry run.
+    pub fn execute_with_gas_profile(
+        &self,
+        tx: VerifiedMoveOSTransaction,
+    ) -> Result<RawTransactionOutput> {
+        self.execute_inner(tx, true)
+    }
+
+    fn execute_inner(
+        &self,
+        tx: VerifiedMoveOSTransaction,
+        gas_profile: bool,
+    ) -> Result<RawTransactionOutput> {
         let VerifiedMoveOSTransaction {
             ctx,
             action,
@@ -253,7 +269,10 @@ impl MoveOS {
 
         let gas_entries = get_gas_schedule_entries(&self.db);
         let cost_table = initial_cost_schedule(gas_entries);
-        let gas_meter = MoveOSGasMeter::new(cost_table, ctx.max_gas_amount);
+        let mut gas_meter = MoveOSGasMeter::new(cost_table, ctx.max_gas_amount);
+        if gas_profile {
+            gas_meter.enable_profiling();
+        }
 
         // Temporary behavior, will enable this in the future.
         // gas_meter.charge_io_write(ctx.tx_size)?;
@@ -422,6 +441,7 @@ impl MoveOS {
             events,
             gas_used: _,
             is_upgrade: _,
+            gas_profile: _,
         } = output;
         let new_state_root = self
             .db
@@ -455,9 +475,9 @@ impl MoveOS {
         Ok((new_state_root, event_ids))
     }
 
-    /// Execute readonly view function
+    /// Execute readonly view function, the sender of the view function is `0x0`.
+    /// Use `execute_readonly_function` with the context of a verified sender to execute the view function on behalf of the sender.
     pub fn execute_view_function(&self, function_call: FunctionCall) -> FunctionResult {
-        //TODO allow user to specify the sender
         let tx_context = TxContext::new_readonly_ctx(AccountAddress::ZERO);
         //TODO verify the view function
         self.execute_readonly_function(&tx_context, function_call)
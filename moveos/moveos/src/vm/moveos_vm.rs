@@ -5,7 +5,7 @@ use std::{borrow::Borrow, sync::Arc};
 
 use move_binary_format::{
     compatibility::Compatibility,
-    errors::{Location, PartialVMError, VMError, VMResult},
+    errors::{vm_status_of_result, Location, PartialVMError, VMError, VMResult},
     file_format::AbilitySet,
     CompiledModule,
 };
@@ -108,6 +108,41 @@ impl MoveOSVM {
         MoveOSSession::new(&self.inner, remote, ctx, gas_meter, true)
     }
 
+    /// Simulate the move action against the state resolver, such as a resolver forked from the remote state.
+    /// The system pre_execute and post_execute functions are not executed,
+    /// so the transaction is not validated and the gas is not charged.
+    /// If `gas_profile` is true, the gas breakdown is returned in the output.
+    pub fn simulate<S: MoveOSResolver>(
+        &self,
+        remote: &S,
+        ctx: TxContext,
+        action: MoveAction,
+        gas_profile: bool,
+    ) -> VMResult<RawTransactionOutput> {
+        let gas_entries = get_gas_schedule_entries(remote);
+        let cost_table = initial_cost_schedule(gas_entries);
+        let mut gas_meter = MoveOSGasMeter::new(cost_table, ctx.max_gas_amount);
+        if gas_profile {
+            gas_meter.enable_profiling();
+        }
+        let system_env = ctx.map.clone();
+
+        let mut session = self.new_session(remote, ctx, gas_meter);
+        let action = session.verify_move_action(action)?;
+        let execute_result = session.execute_move_action(action);
+        let kept_status = vm_status_of_result(execute_result)
+            .keep_or_discard()
+            .map_err(|status| PartialVMError::new(status).finish(Location::Undefined))?;
+        // The changes of the failed action are discarded, only the status and the gas used are returned.
+        let session = if kept_status == KeptVMStatus::Executed {
+            session
+        } else {
+            session.respawn(system_env)
+        };
+        let (_ctx, output) = session.finish_with_extensions(kept_status)?;
+        Ok(output)
+    }
+
     pub fn mark_loader_cache_as_invalid(&self) {
         self.inner.mark_loader_cache_as_invalid()
     }
@@ -524,6 +559,8 @@ where
                     .finish(Location::Undefined));
             }
         }
+        // The profile includes the storage gas of the change set, which is not counted in the gas used yet.
+        let gas_profile = gas_meter.gas_profile();
 
         // Temporary behavior, will enable this in the future.
         /*
@@ -559,6 +596,7 @@ where
                 events,
                 gas_used,
                 is_upgrade,
+                gas_profile,
             },
         ))
     }
@@ -0,0 +1,11 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+//! The fail points to inject the faults into the store, they are no-op unless the `failpoints` feature is enabled.
+//! The actions of a fail point are configured by `fail::cfg`, for example, `fail::cfg(ROCKSDB_WRITE, "10%return")`
+//! fails 10% of the writes, and `fail::cfg(ROCKSDB_READ, "5%sleep(100)")` delays 5% of the reads by 100 milliseconds.
+
+/// Fail the RocksDB writes with the `return` action, or panic with the `panic` action
+pub const ROCKSDB_WRITE: &str = "rocksdb_write";
+/// Delay the RocksDB reads with the `sleep(<MILLIS>)` action, or panic with the `panic` action
+pub const ROCKSDB_READ: &str = "rocksdb_read";
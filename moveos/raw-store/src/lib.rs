@@ -5,6 +5,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 pub mod errors;
+pub mod failpoints;
 pub mod metrics;
 pub mod rocks;
 pub mod store_macros;
@@ -43,6 +44,13 @@ impl StoreInstance {
         }
     }
 
+    /// The shared RocksDB of the instance, e.g. to export the RocksDB properties as metrics
+    pub fn shared_db(&self) -> Option<Arc<RocksDB>> {
+        match self {
+            StoreInstance::DB { db } => Some(db.clone()),
+        }
+    }
+
     pub fn db_mut(&mut self) -> Option<&mut RocksDB> {
         match self {
             StoreInstance::DB { db } => Arc::get_mut(db),
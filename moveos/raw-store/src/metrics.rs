@@ -4,12 +4,16 @@
 // Copyright (c) The Starcoin Core Contributors
 // SPDX-License-Identifier: Apache-2
 
+use crate::rocks::{RocksDB, ROCKSDB_CF_PROPERTIES};
 use anyhow::Result;
 use coarsetime::Instant;
 use metrics::{
     self, register, HistogramOpts, HistogramVec, Opts, PrometheusError, Registry, UIntCounterVec,
-    UIntGauge,
+    UIntGauge, UIntGaugeVec,
 };
+use prometheus::core::{Collector, Desc};
+use prometheus::proto;
+use std::sync::Arc;
 
 #[derive(Clone)]
 pub struct StoreMetrics {
@@ -61,6 +65,49 @@ impl StoreMetrics {
     }
 }
 
+/// Export the compaction, flush and write stall properties of the RocksDB column families,
+/// the properties are read from RocksDB when the metrics are scraped.
+pub struct RocksDBCollector {
+    dbs: Vec<Arc<RocksDB>>,
+    cf_properties: UIntGaugeVec,
+}
+
+impl RocksDBCollector {
+    pub fn new(dbs: Vec<Arc<RocksDB>>) -> Result<Self, PrometheusError> {
+        let cf_properties = UIntGaugeVec::new(
+            Opts::new(
+                "rocksdb_cf_property",
+                "The integer properties of the RocksDB column families",
+            ),
+            &["db", "cf", "property"],
+        )?;
+        Ok(Self { dbs, cf_properties })
+    }
+
+    pub fn register(registry: &Registry, dbs: Vec<Arc<RocksDB>>) -> Result<(), PrometheusError> {
+        registry.register(Box::new(Self::new(dbs)?))
+    }
+}
+
+impl Collector for RocksDBCollector {
+    fn desc(&self) -> Vec<&Desc> {
+        self.cf_properties.desc()
+    }
+
+    fn collect(&self) -> Vec<proto::MetricFamily> {
+        for db in &self.dbs {
+            for property in ROCKSDB_CF_PROPERTIES {
+                for (cf_name, value) in db.cf_property_values(property) {
+                    self.cf_properties
+                        .with_label_values(&[db.name(), cf_name, property])
+                        .set(value);
+                }
+            }
+        }
+        self.cf_properties.collect()
+    }
+}
+
 #[allow(clippy::upper_case_acronyms)]
 pub enum ResultType {
     NONE,
@@ -13,9 +13,10 @@ use crate::traits::DBStore;
 use crate::{ColumnFamilyName, WriteOp};
 use anyhow::{ensure, format_err, Error, Result};
 use moveos_common::utils::{check_open_fds_limit, from_bytes};
-use moveos_config::store_config::RocksdbConfig;
+use moveos_config::store_config::{ColumnFamilyConfig, CompressionType, RocksdbConfig};
 use rocksdb::{
-    BoundColumnFamily, Options, ReadOptions, WriteBatch as DBWriteBatch, WriteOptions, DB,
+    BlockBasedOptions, BoundColumnFamily, Cache, DBCompressionType, IteratorMode, Options,
+    ReadOptions, WriteBatch as DBWriteBatch, WriteOptions, DB,
 };
 use serde::de::DeserializeOwned;
 use serde::Serialize;
@@ -28,9 +29,27 @@ use std::sync::Arc;
 pub const DEFAULT_PREFIX_NAME: ColumnFamilyName = "default";
 pub const RES_FDS: u64 = 4096;
 
+/// The integer properties of the column families exported as metrics,
+/// see https://github.com/facebook/rocksdb/blob/main/include/rocksdb/db.h for the details.
+pub const ROCKSDB_CF_PROPERTIES: &[&str] = &[
+    "rocksdb.num-running-compactions",
+    "rocksdb.compaction-pending",
+    "rocksdb.estimate-pending-compaction-bytes",
+    "rocksdb.num-running-flushes",
+    "rocksdb.mem-table-flush-pending",
+    "rocksdb.num-immutable-mem-table",
+    "rocksdb.cur-size-all-mem-tables",
+    "rocksdb.actual-delayed-write-rate",
+    "rocksdb.is-write-stopped",
+    "rocksdb.total-sst-files-size",
+    "rocksdb.block-cache-usage",
+    "rocksdb.estimate-num-keys",
+];
+
 #[allow(clippy::upper_case_acronyms)]
 pub struct RocksDB {
     db: DB,
+    name: String,
     cfs: Vec<ColumnFamilyName>,
     metrics: Option<StoreMetrics>,
 }
@@ -54,6 +73,82 @@ impl RocksDB {
     ) -> Result<Self> {
         let path = root_path.as_ref();
 
+        Self::check_cfs(path, &column_families)?;
+
+        let mut rocksdb_opts = Self::gen_rocksdb_options(&rocksdb_config);
+
+        let db = if readonly {
+            Self::open_readonly(&rocksdb_opts, path, column_families.clone())?
+        } else {
+            rocksdb_opts.create_if_missing(true);
+            rocksdb_opts.create_missing_column_families(true);
+            Self::open_inner(
+                &rocksdb_opts,
+                path,
+                column_families.clone(),
+                &rocksdb_config,
+            )?
+        };
+        check_open_fds_limit(rocksdb_config.max_open_files as u64 + RES_FDS)?;
+        let name = path
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string())
+            .unwrap_or_default();
+        Ok(RocksDB {
+            db,
+            name,
+            cfs: column_families,
+            metrics,
+        })
+    }
+
+    /// Open the db as a secondary instance of the primary db at `primary_path`, the secondary instance
+    /// keeps its own info logs at `secondary_path`, and follows the writes of the primary via `try_catch_up_with_primary`.
+    pub fn open_secondary(
+        primary_path: impl AsRef<Path>,
+        secondary_path: impl AsRef<Path>,
+        column_families: Vec<ColumnFamilyName>,
+        rocksdb_config: RocksdbConfig,
+        metrics: Option<StoreMetrics>,
+    ) -> Result<Self> {
+        let path = primary_path.as_ref();
+        ensure!(
+            Self::db_exists(path),
+            RawStoreError::StoreCheckError(format_err!(
+                "The primary db {:?} does not exist.",
+                path
+            ))
+        );
+        Self::check_cfs(path, &column_families)?;
+
+        let mut rocksdb_opts = Self::gen_rocksdb_options(&rocksdb_config);
+        // The secondary instance should keep all the files open, otherwise the files deleted by the primary may not be readable
+        rocksdb_opts.set_max_open_files(-1);
+        let db = rocksdb::DB::open_cf_as_secondary(
+            &rocksdb_opts,
+            path,
+            secondary_path.as_ref(),
+            column_families.clone(),
+        )?;
+        let name = path
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string())
+            .unwrap_or_default();
+        Ok(RocksDB {
+            db,
+            name,
+            cfs: column_families,
+            metrics,
+        })
+    }
+
+    /// Catch up with the writes of the primary db, only for the secondary instance
+    pub fn try_catch_up_with_primary(&self) -> Result<()> {
+        self.db.try_catch_up_with_primary()?;
+        Ok(())
+    }
+
+    fn check_cfs(path: &Path, column_families: &[ColumnFamilyName]) -> Result<()> {
         let cfs_set: HashSet<_> = column_families.iter().collect();
         {
             ensure!(
@@ -88,41 +183,50 @@ impl RocksDB {
                 ))
             );
         }
-
-        let mut rocksdb_opts = Self::gen_rocksdb_options(&rocksdb_config);
-
-        let db = if readonly {
-            Self::open_readonly(&rocksdb_opts, path, column_families.clone())?
-        } else {
-            rocksdb_opts.create_if_missing(true);
-            rocksdb_opts.create_missing_column_families(true);
-            Self::open_inner(&rocksdb_opts, path, column_families.clone())?
-        };
-        check_open_fds_limit(rocksdb_config.max_open_files as u64 + RES_FDS)?;
-        Ok(RocksDB {
-            db,
-            cfs: column_families,
-            metrics,
-        })
+        Ok(())
     }
 
     fn open_inner(
         opts: &Options,
         path: impl AsRef<Path>,
         column_families: Vec<ColumnFamilyName>,
+        rocksdb_config: &RocksdbConfig,
     ) -> Result<DB> {
         let inner = rocksdb::DB::open_cf_descriptors(
             opts,
             path,
             column_families.iter().map(|cf_name| {
-                let mut cf_opts = rocksdb::Options::default();
-                cf_opts.set_compression_type(rocksdb::DBCompressionType::Lz4);
+                let cf_opts = Self::gen_cf_options(&rocksdb_config.column_family_config(cf_name));
                 rocksdb::ColumnFamilyDescriptor::new((*cf_name).to_string(), cf_opts)
             }),
         )?;
         Ok(inner)
     }
 
+    fn gen_cf_options(config: &ColumnFamilyConfig) -> Options {
+        let mut cf_opts = Options::default();
+        cf_opts.set_compression_type(match config.compression() {
+            CompressionType::None => DBCompressionType::None,
+            CompressionType::Snappy => DBCompressionType::Snappy,
+            CompressionType::Lz4 => DBCompressionType::Lz4,
+            CompressionType::Zstd => DBCompressionType::Zstd,
+        });
+        if let Some(write_buffer_size) = config.write_buffer_size {
+            cf_opts.set_write_buffer_size(write_buffer_size as usize);
+        }
+        if config.block_cache_size.is_some() || config.bloom_filter_bits_per_key.is_some() {
+            let mut table_opts = BlockBasedOptions::default();
+            if let Some(block_cache_size) = config.block_cache_size {
+                table_opts.set_block_cache(&Cache::new_lru_cache(block_cache_size as usize));
+            }
+            if let Some(bits_per_key) = config.bloom_filter_bits_per_key {
+                table_opts.set_bloom_filter(bits_per_key as f64, false);
+            }
+            cf_opts.set_block_based_table_factory(&table_opts);
+        }
+        cf_opts
+    }
+
     fn open_readonly(
         db_opts: &Options,
         path: impl AsRef<Path>,
@@ -170,6 +274,25 @@ impl RocksDB {
         Ok(())
     }
 
+    /// The name of the DB, the last component of the DB path
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Read the integer `property` of every column family, the column families not supporting the property are skipped
+    pub fn cf_property_values(&self, property: &str) -> Vec<(ColumnFamilyName, u64)> {
+        self.cfs
+            .iter()
+            .filter_map(|cf_name| {
+                let cf_handle = self.get_cf_handle(cf_name);
+                match self.db.property_int_value_cf(&cf_handle, property) {
+                    Ok(Some(value)) => Some((*cf_name, value)),
+                    _ => None,
+                }
+            })
+            .collect()
+    }
+
     /// List cf
     pub fn list_cf(path: impl AsRef<Path>) -> Result<Vec<String>, Error> {
         Ok(rocksdb::DB::list_cf(&rocksdb::Options::default(), path)?)
@@ -189,6 +312,14 @@ impl RocksDB {
         })
     }
 
+    /// Fail the write if the fault is injected by the fail point
+    fn check_write_failpoint() -> Result<()> {
+        fail::fail_point!(crate::failpoints::ROCKSDB_WRITE, |_| Err(format_err!(
+            "Injected RocksDB write failure"
+        )));
+        Ok(())
+    }
+
     fn default_write_options() -> WriteOptions {
         let mut opts = WriteOptions::new();
         // opts.set_sync(false);
@@ -246,6 +377,20 @@ impl RocksDB {
         self.iter_with_direction(prefix_name, ScanDirection::Backward)
     }
 
+    /// Returns a forward iterator on the raw keys and values of a certain schema, without decoding.
+    pub fn raw_iter(
+        &self,
+        prefix_name: &str,
+    ) -> impl Iterator<Item = Result<(Vec<u8>, Vec<u8>)>> + '_ {
+        let cf_handle = self.get_cf_handle(prefix_name);
+        self.db
+            .iterator_cf(&cf_handle, IteratorMode::Start)
+            .map(|item| {
+                item.map(|(key, value)| (key.into_vec(), value.into_vec()))
+                    .map_err(Error::from)
+            })
+    }
+
     fn sync_write_options() -> WriteOptions {
         let mut opts = WriteOptions::new();
         opts.set_sync(true);
@@ -336,6 +481,7 @@ where
 
 impl DBStore for RocksDB {
     fn get(&self, prefix_name: &str, key: Vec<u8>) -> Result<Option<Vec<u8>>> {
+        fail::fail_point!(crate::failpoints::ROCKSDB_READ);
         record_metrics("db", prefix_name, "get", self.metrics.as_ref()).call(|| {
             let cf_handle = self.get_cf_handle(prefix_name);
             let result = self.db.get_cf(&cf_handle, key.as_slice())?;
@@ -344,6 +490,7 @@ impl DBStore for RocksDB {
     }
 
     fn put(&self, prefix_name: &str, key: Vec<u8>, value: Vec<u8>) -> Result<()> {
+        Self::check_write_failpoint()?;
         if let Some(metrics) = self.metrics.as_ref() {
             metrics
                 .store_item_bytes
@@ -368,6 +515,7 @@ impl DBStore for RocksDB {
         })
     }
     fn remove(&self, prefix_name: &str, key: Vec<u8>) -> Result<()> {
+        Self::check_write_failpoint()?;
         record_metrics("db", prefix_name, "remove", self.metrics.as_ref()).call(|| {
             let cf_handle = self.get_cf_handle(prefix_name);
             self.db.delete_cf(&cf_handle, &key)?;
@@ -377,6 +525,7 @@ impl DBStore for RocksDB {
 
     /// Writes a group of records wrapped in a WriteBatch.
     fn write_batch(&self, prefix_name: &str, batch: WriteBatch) -> Result<()> {
+        Self::check_write_failpoint()?;
         record_metrics("db", prefix_name, "write_batch", self.metrics.as_ref()).call(|| {
             let mut db_batch = DBWriteBatch::default();
             let cf_handle = self.get_cf_handle(prefix_name);
@@ -401,6 +550,7 @@ impl DBStore for RocksDB {
     }
 
     fn put_sync(&self, prefix_name: &str, key: Vec<u8>, value: Vec<u8>) -> Result<()> {
+        Self::check_write_failpoint()?;
         if let Some(metrics) = self.metrics.as_ref() {
             metrics
                 .store_item_bytes
@@ -417,6 +567,7 @@ impl DBStore for RocksDB {
     }
 
     fn write_batch_sync(&self, prefix_name: &str, batch: WriteBatch) -> Result<()> {
+        Self::check_write_failpoint()?;
         record_metrics("db", prefix_name, "write_batch_sync", self.metrics.as_ref()).call(|| {
             let mut db_batch = DBWriteBatch::default();
             let cf_handle = self.get_cf_handle(prefix_name);
@@ -432,6 +583,7 @@ impl DBStore for RocksDB {
     }
 
     fn multi_get(&self, prefix_name: &str, keys: Vec<Vec<u8>>) -> Result<Vec<Option<Vec<u8>>>> {
+        fail::fail_point!(crate::failpoints::ROCKSDB_READ);
         record_metrics("db", prefix_name, "multi_get", self.metrics.as_ref()).call(|| {
             let cf_handle = self.get_cf_handle(prefix_name);
             let cf_handles = iter::repeat(&cf_handle)
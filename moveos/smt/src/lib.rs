@@ -2,7 +2,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use std::{
-    collections::{BTreeMap, HashMap},
+    collections::{BTreeMap, HashMap, HashSet},
     marker::PhantomData,
     sync::Arc,
 };
@@ -11,7 +11,7 @@ use anyhow::Result;
 use parking_lot::RwLock;
 use primitive_types::H256;
 
-use jellyfish_merkle::hash::SPARSE_MERKLE_PLACEHOLDER_HASH_VALUE;
+use jellyfish_merkle::hash::{SMTHash, SPARSE_MERKLE_PLACEHOLDER_HASH_VALUE};
 pub use jellyfish_merkle::{hash::SPARSE_MERKLE_PLACEHOLDER_HASH, proof::SparseMerkleProof};
 use jellyfish_merkle::{
     iterator::JellyfishMerkleIterator,
@@ -51,6 +51,36 @@ where
     }
 }
 
+/// Decode the encoded node and verify its merkle hash is the `hash`, it is used to verify the nodes from the untrusted sources.
+/// Returns the child node hashes of the internal node and the value of the leaf node,
+/// so the caller can download the tree from the root hash.
+pub fn verify_node<K, V>(hash: H256, encoded_node: &[u8]) -> Result<(Vec<H256>, Option<V>)>
+where
+    K: Key,
+    V: Value,
+{
+    let node = Node::<K, V>::decode(encoded_node)?;
+    let node_hash: H256 = node.merkle_hash().into();
+    anyhow::ensure!(
+        node_hash == hash,
+        "The node hash {:?} mismatches the expected hash {:?}",
+        node_hash,
+        hash
+    );
+    Ok(match node {
+        Node::Internal(internal_node) => (
+            internal_node
+                .all_child()
+                .into_iter()
+                .map(H256::from)
+                .collect(),
+            None,
+        ),
+        Node::Leaf(leaf_node) => (vec![], Some(leaf_node.value().origin.clone())),
+        Node::Null => (vec![], None),
+    })
+}
+
 #[derive(Default, Clone)]
 pub struct InMemoryNodeStore {
     inner: Arc<RwLock<HashMap<H256, Vec<u8>>>>,
@@ -106,6 +136,10 @@ where
         }
     }
 
+    pub fn node_store(&self) -> &NS {
+        &self.node_store
+    }
+
     /// get current root hash
     pub fn root_hash(&self) -> H256 {
         *self.root_hash.read()
@@ -214,6 +248,33 @@ where
         self.root_hash() == *SPARSE_MERKLE_PLACEHOLDER_HASH
     }
 
+    /// Walk the nodes reachable from the current root, skip the subtrees whose root is already in `visited`.
+    /// The newly visited node hashes are inserted into `visited`, and the values of the newly visited leaves are returned,
+    /// so the caller can follow the nested trees referenced by the values.
+    pub fn walk_new_nodes(&self, visited: &mut HashSet<H256>) -> Result<Vec<V>> {
+        let mut values = Vec::new();
+        let mut pending = vec![self.root_hash()];
+        while let Some(hash) = pending.pop() {
+            if hash == *SPARSE_MERKLE_PLACEHOLDER_HASH || visited.contains(&hash) {
+                continue;
+            }
+            let node_key: NodeKey = hash.into();
+            let node = TreeReader::<K, V>::get_node_option(&self.node_store, &node_key)?
+                .ok_or_else(|| anyhow::format_err!("Missing node at {:?}", hash))?;
+            match node {
+                Node::Internal(internal_node) => {
+                    pending.extend(internal_node.all_child().into_iter().map(H256::from));
+                }
+                Node::Leaf(leaf_node) => {
+                    values.push(leaf_node.value().origin.clone());
+                }
+                Node::Null => {}
+            }
+            visited.insert(hash);
+        }
+        Ok(values)
+    }
+
     /// Dump all (key, value) from the tree
     pub fn dump(&self) -> Result<Vec<(K, V)>> {
         let iter = self.iter(None)?;
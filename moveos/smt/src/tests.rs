@@ -53,3 +53,57 @@ fn test_smt() {
     let iter = smt.iter(None).unwrap();
     assert_eq!(iter.count(), 2);
 }
+
+#[test]
+fn test_walk_new_nodes() {
+    let node_store = InMemoryNodeStore::default();
+    let smt: SMTree<String, String, InMemoryNodeStore> = SMTree::new(node_store.clone(), None);
+    let mut visited = HashSet::new();
+    assert!(smt.walk_new_nodes(&mut visited).unwrap().is_empty());
+
+    smt.puts(
+        (0..10)
+            .map(|i| (format!("key{}", i), Some(format!("value{}", i))))
+            .collect::<Vec<_>>(),
+    )
+    .unwrap();
+    let values = smt.walk_new_nodes(&mut visited).unwrap();
+    assert_eq!(values.len(), 10);
+    assert!(visited.contains(&smt.root_hash()));
+    assert!(visited
+        .iter()
+        .all(|hash| node_store.get(hash).unwrap().is_some()));
+
+    // Only the nodes changed by the update are new, the shared subtrees are skipped
+    smt.put("key0".to_owned(), "value0_new".to_owned()).unwrap();
+    let values = smt.walk_new_nodes(&mut visited).unwrap();
+    assert_eq!(values, vec!["value0_new".to_owned()]);
+}
+
+#[test]
+fn test_verify_node() {
+    let node_store = InMemoryNodeStore::default();
+    let smt: SMTree<String, String, InMemoryNodeStore> = SMTree::new(node_store.clone(), None);
+    smt.puts(
+        (0..10)
+            .map(|i| (format!("key{}", i), Some(format!("value{}", i))))
+            .collect::<Vec<_>>(),
+    )
+    .unwrap();
+
+    // Download the tree from the root hash, every node is verified by its hash
+    let mut values = vec![];
+    let mut pending = vec![smt.root_hash()];
+    while let Some(hash) = pending.pop() {
+        let encoded_node = node_store.get(&hash).unwrap().unwrap();
+        let (children, value) = verify_node::<String, String>(hash, &encoded_node).unwrap();
+        pending.extend(children);
+        values.extend(value);
+    }
+    values.sort();
+    assert_eq!(values.len(), 10);
+    assert_eq!(values[0], "value0".to_owned());
+
+    let root_node = node_store.get(&smt.root_hash()).unwrap().unwrap();
+    assert!(verify_node::<String, String>(H256::zero(), &root_node).is_err());
+}
@@ -9,7 +9,11 @@ import { AccountAddress, FunctionId, TypeTag, Arg, StatePageView, IPage } from '
 import { BcsSerializer } from '../types/bcs'
 import {
   RoochTransaction,
-  RoochTransactionData,
+  RoochTransactionDataVersion,
+  RoochTransactionDataVersionVariantV0,
+  RoochTransactionDataVersionVariantV1,
+  RoochTransactionDataV0,
+  RoochTransactionDataV1,
   AccountAddress as BCSAccountAddress,
   Authenticator,
 } from '../generated/runtime/rooch_types/mod'
@@ -43,7 +47,10 @@ export class Account implements IAccount {
     this.authorizer = authorizer
   }
 
-  private async makeAuth(tsData: RoochTransactionData): Promise<IAuthorization> {
+  // The signing bytes are the encoding of the version without the variant tag
+  private async makeAuth(
+    tsData: RoochTransactionDataV0 | RoochTransactionDataV1,
+  ): Promise<IAuthorization> {
     const payload = (() => {
       const se = new BcsSerializer()
       tsData.serialize(se)
@@ -128,13 +135,36 @@ export class Account implements IAccount {
     const number = await this.getSequenceNumber()
     const bcsArgs = args.map((arg) => encodeArg(arg))
     const scriptFunction = encodeFunctionCall(funcId, tyArgs, bcsArgs)
-    const txData = new RoochTransactionData(
-      new BCSAccountAddress(addressToListTuple(this.address)),
-      BigInt(number),
-      BigInt(this.client.getChainId()),
-      BigInt(opts.maxGasAmount ?? DEFAULT_MAX_GAS_AMOUNT),
-      scriptFunction,
-    )
+    const sender = new BCSAccountAddress(addressToListTuple(this.address))
+    const sequenceNumber = BigInt(number)
+    const chainId = BigInt(this.client.getChainId())
+    const maxGasAmount = BigInt(opts.maxGasAmount ?? DEFAULT_MAX_GAS_AMOUNT)
+
+    // The transaction without expiration is encoded as V0, so it has the same hash as before the versions
+    let txData: RoochTransactionDataV0 | RoochTransactionDataV1
+    let versionedData: RoochTransactionDataVersion
+    if (opts.expirationTimestampSecs === undefined) {
+      const data = new RoochTransactionDataV0(
+        sender,
+        sequenceNumber,
+        chainId,
+        maxGasAmount,
+        scriptFunction,
+      )
+      txData = data
+      versionedData = new RoochTransactionDataVersionVariantV0(data)
+    } else {
+      const data = new RoochTransactionDataV1(
+        sender,
+        sequenceNumber,
+        chainId,
+        maxGasAmount,
+        scriptFunction,
+        BigInt(opts.expirationTimestampSecs),
+      )
+      txData = data
+      versionedData = new RoochTransactionDataVersionVariantV1(data)
+    }
 
     const authResult = await this.makeAuth(txData)
 
@@ -142,7 +172,7 @@ export class Account implements IAccount {
       BigInt(authResult.scheme),
       uint8Array2SeqNumber(authResult.payload),
     )
-    const ts = new RoochTransaction(txData, auth)
+    const ts = new RoochTransaction(versionedData, auth)
 
     const payload = (() => {
       const se = new BcsSerializer()
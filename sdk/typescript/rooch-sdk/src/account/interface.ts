@@ -5,6 +5,8 @@ import { AccountAddress, FunctionId, TypeTag, Arg, IPage } from '../types'
 
 export interface CallOption {
   maxGasAmount?: number
+  // The seconds since the unix epoch after which the transaction is rejected
+  expirationTimestampSecs?: number
 }
 
 export interface ISessionKey {
@@ -13,6 +13,7 @@ import {
   EventPageView,
   GlobalStateView,
   InscriptionStatePageView,
+  QueryOptions,
   StateOptions,
   StatePageView,
   StateView,
@@ -156,13 +157,16 @@ export class RoochClient implements IClient {
   }
 
   async getTransactionsByHashes(tx_hashes: string[]): Promise<TransactionWithInfoView | null[]> {
-    return await this.client.rooch_getTransactionsByHash(tx_hashes)
+    return await this.client.rooch_getTransactionsByHash(tx_hashes, {
+      decode: true,
+    } as QueryOptions)
   }
 
   async getTransactions(params: GetTransactionsParams): Promise<TransactionWithInfoPageView> {
     return this.client.rooch_getTransactionsByOrder(
       params.cursor.toString(),
       params.limit.toString(),
+      { decode: true } as QueryOptions,
     )
   }
 
@@ -173,6 +177,9 @@ export class RoochClient implements IClient {
       params.cursor.toString(),
       params.limit.toString(),
       { decode: true } as EventOptions,
+      params.descendingOrder ?? false,
+      params.fromSeq?.toString() as any,
+      params.toSeq?.toString() as any,
     )
   }
 
@@ -199,6 +206,7 @@ export class RoochClient implements IClient {
       params.cursor as any,
       params.limit.toString(),
       params.descending_order,
+      { decode: true } as QueryOptions,
     )
   }
 
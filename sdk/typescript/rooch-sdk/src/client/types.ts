@@ -32,7 +32,7 @@ export interface ListStatesParams {
 
 export interface QueryGlobalStatesParams {
   filter: GlobalStateFilterView
-  cursor: IndexerStateID | null
+  cursor: string | null
   limit: number
   descending_order: boolean
 }
@@ -67,4 +67,7 @@ export interface GetEventsParams {
   eventHandleType: string
   cursor: number
   limit: number
+  descendingOrder?: boolean
+  fromSeq?: number
+  toSeq?: number
 }